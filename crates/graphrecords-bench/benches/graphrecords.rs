@@ -0,0 +1,305 @@
+use criterion::{BatchSize, BenchmarkId, Criterion, criterion_group, criterion_main};
+use graphrecords_bench::{SIZES, dataframe_inputs, grouped_elements, record};
+use graphrecords_core::{
+    GraphRecord,
+    graphrecord::{
+        Group,
+        ingest::ShardedIngest,
+        overview::OverviewFormat,
+        querying::nodes::{EdgeDirection, NodeOperandGroupDiscriminator},
+    },
+};
+use graphrecords_synth::{ENCOUNTER_DIAGNOSIS_GROUP, PATIENT_GROUP};
+use std::{hint::black_box, thread};
+
+fn ingestion(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ingestion");
+
+    for patients in SIZES {
+        let inputs = dataframe_inputs(&record(patients));
+
+        group.bench_with_input(
+            BenchmarkId::new("from_dataframes", patients),
+            &inputs,
+            |b, inputs| {
+                b.iter(|| {
+                    GraphRecord::from_dataframes(inputs.nodes.clone(), inputs.edges.clone(), None)
+                        .unwrap()
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn concurrent_ingestion(c: &mut Criterion) {
+    let mut group = c.benchmark_group("concurrent_ingestion");
+
+    for patients in SIZES {
+        let elements = grouped_elements(&record(patients));
+
+        // Baseline adding every group on one thread
+        group.bench_with_input(
+            BenchmarkId::new("sequential", patients),
+            &elements,
+            |b, elements| {
+                b.iter_batched(
+                    || elements.clone(),
+                    |elements| {
+                        let mut graphrecord = GraphRecord::new();
+
+                        for (group, nodes) in elements.nodes {
+                            graphrecord.add_nodes_with_group(nodes, group).unwrap();
+                        }
+                        for (group, edges) in elements.edges {
+                            graphrecord.add_edges_with_group(edges, &group).unwrap();
+                        }
+
+                        graphrecord
+                    },
+                    BatchSize::LargeInput,
+                );
+            },
+        );
+
+        // One thread per group buffering into a ShardedIngest
+        group.bench_with_input(
+            BenchmarkId::new("sharded_ingest", patients),
+            &elements,
+            |b, elements| {
+                b.iter_batched(
+                    || elements.clone(),
+                    |elements| {
+                        let ingest = ShardedIngest::new();
+
+                        thread::scope(|scope| {
+                            for (group, nodes) in elements.nodes {
+                                let ingest = &ingest;
+
+                                scope.spawn(move || ingest.add_nodes(nodes, Some(group)));
+                            }
+                            for (group, edges) in elements.edges {
+                                let ingest = &ingest;
+
+                                scope.spawn(move || ingest.add_edges(edges, Some(group)));
+                            }
+                        });
+
+                        let mut graphrecord = GraphRecord::new();
+                        ingest.commit(&mut graphrecord).unwrap();
+
+                        graphrecord
+                    },
+                    BatchSize::LargeInput,
+                );
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn schema_inference(c: &mut Criterion) {
+    let mut group = c.benchmark_group("schema_inference");
+
+    for patients in SIZES {
+        let elements = grouped_elements(&record(patients));
+
+        // Every insertion updates the running attribute types of its group
+        group.bench_with_input(
+            BenchmarkId::new("add_node_with_group", patients),
+            &elements,
+            |b, elements| {
+                b.iter_batched(
+                    || elements.nodes.clone(),
+                    |nodes| {
+                        let mut graphrecord = GraphRecord::new();
+
+                        for (group, nodes) in nodes {
+                            for (node_index, attributes) in nodes {
+                                graphrecord
+                                    .add_node_with_group(node_index, attributes, group.clone())
+                                    .unwrap();
+                            }
+                        }
+
+                        graphrecord
+                    },
+                    BatchSize::LargeInput,
+                );
+            },
+        );
+
+        // One batch of all nodes, folded into the attribute types at once
+        group.bench_with_input(
+            BenchmarkId::new("add_nodes", patients),
+            &elements,
+            |b, elements| {
+                b.iter_batched(
+                    || {
+                        elements
+                            .nodes
+                            .iter()
+                            .flat_map(|(_, nodes)| nodes.clone())
+                            .collect::<Vec<_>>()
+                    },
+                    |nodes| {
+                        let mut graphrecord = GraphRecord::new();
+
+                        graphrecord.add_nodes(nodes).unwrap();
+
+                        graphrecord
+                    },
+                    BatchSize::LargeInput,
+                );
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn queries(c: &mut Criterion) {
+    let mut group = c.benchmark_group("queries");
+
+    for patients in SIZES {
+        let graphrecord = record(patients);
+
+        group.bench_with_input(
+            BenchmarkId::new("nodes", patients),
+            &graphrecord,
+            |b, graphrecord| {
+                b.iter(|| {
+                    graphrecord
+                        .query_nodes(|nodes| {
+                            nodes.in_group(Group::from(PATIENT_GROUP));
+                            nodes.attribute("age").greater_than(50);
+
+                            let encounters = nodes.neighbors(EdgeDirection::Outgoing);
+                            encounters.attribute("type").equal_to("inpatient");
+
+                            nodes.index()
+                        })
+                        .evaluate()
+                        .unwrap()
+                        .count()
+                });
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("edges", patients),
+            &graphrecord,
+            |b, graphrecord| {
+                b.iter(|| {
+                    graphrecord
+                        .query_edges(|edges| {
+                            edges.in_group(Group::from(ENCOUNTER_DIAGNOSIS_GROUP));
+                            edges.attribute("primary").equal_to(true);
+
+                            edges.index()
+                        })
+                        .evaluate()
+                        .unwrap()
+                        .count()
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn group_by(c: &mut Criterion) {
+    let mut group = c.benchmark_group("group_by");
+
+    for patients in SIZES {
+        let graphrecord = record(patients);
+
+        group.bench_with_input(
+            BenchmarkId::new("mean_age_by_gender", patients),
+            &graphrecord,
+            |b, graphrecord| {
+                b.iter(|| {
+                    graphrecord
+                        .query_nodes(|nodes| {
+                            nodes.in_group(Group::from(PATIENT_GROUP));
+
+                            nodes
+                                .group_by(NodeOperandGroupDiscriminator::Attribute("gender".into()))
+                                .attribute("age")
+                                .mean()
+                        })
+                        .evaluate()
+                        .unwrap()
+                        .count()
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn overview(c: &mut Criterion) {
+    let mut group = c.benchmark_group("overview");
+
+    for patients in SIZES {
+        let graphrecord = record(patients);
+
+        group.bench_with_input(
+            BenchmarkId::new("overview", patients),
+            &graphrecord,
+            |b, graphrecord| {
+                b.iter(|| {
+                    graphrecord
+                        .overview(None, OverviewFormat::Modern, None)
+                        .unwrap()
+                        .to_string()
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn serialization(c: &mut Criterion) {
+    let mut group = c.benchmark_group("serialization");
+
+    for patients in SIZES {
+        let graphrecord = record(patients);
+        let bytes = graphrecord.to_bytes().unwrap();
+
+        group.bench_with_input(
+            BenchmarkId::new("to_bytes", patients),
+            &graphrecord,
+            |b, graphrecord| {
+                b.iter(|| graphrecord.to_bytes().unwrap());
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("from_bytes", patients),
+            &bytes,
+            |b, bytes| {
+                b.iter(|| GraphRecord::from_bytes(black_box(bytes)).unwrap());
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    ingestion,
+    concurrent_ingestion,
+    schema_inference,
+    queries,
+    group_by,
+    overview,
+    serialization
+);
+criterion_main!(benches);
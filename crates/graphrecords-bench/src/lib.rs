@@ -0,0 +1,132 @@
+//! Fixtures shared by the benchmarks of this crate.
+//!
+//! Every benchmark runs on records generated by [`graphrecords_synth`] for
+//! each of the patient counts in [`SIZES`], so the results of different
+//! releases are comparable.
+
+use graphrecords_core::{
+    GraphRecord,
+    graphrecord::{Attributes, EdgeDataFrameInput, Group, NodeDataFrameInput, NodeIndex},
+};
+use graphrecords_synth::{SynthConfig, generate};
+
+/// Number of patients of the records every benchmark runs on.
+pub const SIZES: [usize; 3] = [100, 1_000, 10_000];
+
+/// Returns the synthetic record with `patients` patients. The seed is fixed,
+/// so every run benchmarks the same record.
+///
+/// # Panics
+///
+/// Panics if the record cannot be generated.
+#[must_use]
+pub fn record(patients: usize) -> GraphRecord {
+    generate(&SynthConfig {
+        patients,
+        ..SynthConfig::new(0)
+    })
+    .expect("Record must be generated")
+}
+
+/// Node and edge `DataFrame`s of a record, one pair per group.
+#[derive(Clone)]
+pub struct DataFrameInputs {
+    pub nodes: Vec<NodeDataFrameInput>,
+    pub edges: Vec<EdgeDataFrameInput>,
+}
+
+/// Exports every group of `graphrecord` into the inputs of
+/// [`GraphRecord::from_dataframes`].
+///
+/// # Panics
+///
+/// Panics if the record cannot be exported.
+#[must_use]
+pub fn dataframe_inputs(graphrecord: &GraphRecord) -> DataFrameInputs {
+    let export = graphrecord
+        .to_dataframes_sorted()
+        .expect("Record must be exported");
+
+    let mut nodes = Vec::new();
+    let mut edges = Vec::new();
+
+    for group_export in export.groups.into_values() {
+        if group_export.nodes.height() > 0 {
+            nodes.push((group_export.nodes, "node_index").into());
+        }
+
+        if group_export.edges.height() > 0 {
+            let dataframe = group_export
+                .edges
+                .drop("edge_index")
+                .expect("Edge export must have an edge_index column");
+
+            edges.push((dataframe, "source_node_index", "target_node_index").into());
+        }
+    }
+
+    DataFrameInputs { nodes, edges }
+}
+
+type Edge = (NodeIndex, NodeIndex, Attributes);
+
+/// Nodes and edges of a record, one batch per group.
+#[derive(Clone)]
+pub struct GroupedElements {
+    pub nodes: Vec<(Group, Vec<(NodeIndex, Attributes)>)>,
+    pub edges: Vec<(Group, Vec<Edge>)>,
+}
+
+/// Collects the nodes and edges of every group of `graphrecord`. Every node
+/// and edge of the synthetic records is in exactly one group.
+///
+/// # Panics
+///
+/// Panics if a group cannot be read.
+#[must_use]
+pub fn grouped_elements(graphrecord: &GraphRecord) -> GroupedElements {
+    let mut nodes = Vec::new();
+    let mut edges = Vec::new();
+
+    for group in graphrecord.groups_sorted() {
+        let group_nodes: Vec<_> = graphrecord
+            .nodes_in_group(group)
+            .expect("Group must exist")
+            .map(|node_index| {
+                let attributes = graphrecord
+                    .node_attributes(node_index)
+                    .expect("Node must exist");
+
+                (node_index.clone(), attributes.to_attributes())
+            })
+            .collect();
+
+        let group_edges: Vec<_> = graphrecord
+            .edges_in_group(group)
+            .expect("Group must exist")
+            .map(|edge_index| {
+                let (source_node_index, target_node_index) = graphrecord
+                    .edge_endpoints(edge_index)
+                    .expect("Edge must exist");
+                let attributes = graphrecord
+                    .edge_attributes(edge_index)
+                    .expect("Edge must exist");
+
+                (
+                    source_node_index.clone(),
+                    target_node_index.clone(),
+                    attributes.to_attributes(),
+                )
+            })
+            .collect();
+
+        if !group_nodes.is_empty() {
+            nodes.push((group.clone(), group_nodes));
+        }
+        if !group_edges.is_empty() {
+            edges.push((group.clone(), group_edges));
+        }
+    }
+
+    GroupedElements { nodes, edges }
+}
@@ -0,0 +1,167 @@
+use crate::{graphml, parquet};
+use clap::ValueEnum;
+use graphrecords_core::{
+    GraphRecord,
+    errors::{GraphRecordError, GraphRecordResult},
+};
+use std::{fs, path::Path};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum RecordFormat {
+    Ron,
+    /// Binary container format written by `GraphRecord::to_bytes`.
+    Container,
+    /// Directory of Parquet files, one pair per group.
+    Parquet,
+    Graphml,
+}
+
+impl RecordFormat {
+    pub fn infer(path: &Path) -> GraphRecordResult<Self> {
+        if path.is_dir() {
+            return Ok(Self::Parquet);
+        }
+
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("ron") => Ok(Self::Ron),
+            Some("grcb" | "bin") => Ok(Self::Container),
+            Some("parquet") => Ok(Self::Parquet),
+            Some("graphml" | "xml") => Ok(Self::Graphml),
+            _ => Err(GraphRecordError::ConversionError(format!(
+                "Cannot infer the format of {}, pass it explicitly",
+                path.display()
+            ))),
+        }
+    }
+
+    pub fn read(self, path: &Path) -> GraphRecordResult<GraphRecord> {
+        let read_error =
+            |_| GraphRecordError::ConversionError(format!("Failed to read {}", path.display()));
+
+        match self {
+            Self::Ron => GraphRecord::from_ron(path),
+            Self::Container => GraphRecord::from_bytes(&fs::read(path).map_err(read_error)?),
+            Self::Parquet => parquet::from_parquet(path),
+            Self::Graphml => graphml::from_graphml(&fs::read_to_string(path).map_err(read_error)?),
+        }
+    }
+
+    pub fn write(self, graphrecord: &GraphRecord, path: &Path) -> GraphRecordResult<()> {
+        let write_error =
+            |_| GraphRecordError::ConversionError(format!("Failed to write {}", path.display()));
+
+        match self {
+            Self::Ron => graphrecord.to_ron(path),
+            Self::Container => fs::write(path, graphrecord.to_bytes()?).map_err(write_error),
+            Self::Parquet => parquet::to_parquet(graphrecord, path),
+            Self::Graphml => {
+                fs::write(path, graphml::to_graphml(graphrecord)?).map_err(write_error)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::RecordFormat;
+    use graphrecords_core::{
+        GraphRecord,
+        graphrecord::{GraphRecordAttribute, Group},
+    };
+    use std::{collections::HashMap, path::PathBuf};
+
+    fn create_graphrecord() -> GraphRecord {
+        let mut graphrecord = GraphRecord::new();
+
+        graphrecord
+            .add_node(
+                "0".into(),
+                vec![("age".into(), 20.into())].into_iter().collect(),
+            )
+            .unwrap();
+        graphrecord
+            .add_node(
+                "1".into(),
+                vec![("age".into(), 40.into())].into_iter().collect(),
+            )
+            .unwrap();
+        graphrecord.add_node("2".into(), HashMap::new()).unwrap();
+        graphrecord
+            .add_edge("0".into(), "1".into(), HashMap::new())
+            .unwrap();
+        graphrecord
+            .add_edge("1".into(), "2".into(), HashMap::new())
+            .unwrap();
+        graphrecord
+            .add_group(
+                "a".into(),
+                Some(vec!["0".into(), "1".into()]),
+                Some(vec![0]),
+            )
+            .unwrap();
+        graphrecord
+            .add_group("b".into(), Some(vec!["1".into()]), Some(vec![0]))
+            .unwrap();
+
+        graphrecord
+    }
+
+    fn temporary_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("graphrecords-cli-{}-{name}", std::process::id()))
+    }
+
+    #[test]
+    fn test_infer() {
+        assert_eq!(
+            RecordFormat::Ron,
+            RecordFormat::infer("record.ron".as_ref()).unwrap()
+        );
+        assert_eq!(
+            RecordFormat::Graphml,
+            RecordFormat::infer("record.graphml".as_ref()).unwrap()
+        );
+        assert!(RecordFormat::infer("record".as_ref()).is_err());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let graphrecord = create_graphrecord();
+
+        for (format, name) in [
+            (RecordFormat::Ron, "record.ron"),
+            (RecordFormat::Container, "record.grcb"),
+            (RecordFormat::Parquet, "record"),
+        ] {
+            let path = temporary_path(name);
+
+            format.write(&graphrecord, &path).unwrap();
+            let restored = format.read(&path).unwrap();
+
+            if path.is_dir() {
+                std::fs::remove_dir_all(&path).unwrap();
+            } else {
+                std::fs::remove_file(&path).unwrap();
+            }
+
+            assert_eq!(3, restored.node_count(), "{format:?}");
+            assert_eq!(2, restored.edge_count(), "{format:?}");
+            assert_eq!(
+                2,
+                restored.nodes_in_group(&Group::from("a")).unwrap().count(),
+                "{format:?}"
+            );
+            assert_eq!(
+                1,
+                restored.edges_in_group(&Group::from("b")).unwrap().count(),
+                "{format:?}"
+            );
+            assert_eq!(
+                &HashMap::from([(GraphRecordAttribute::from("age"), 40.into())]),
+                restored
+                    .node_attributes(&GraphRecordAttribute::from("1"))
+                    .unwrap(),
+                "{format:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,416 @@
+//! Minimal `GraphML` support.
+//!
+//! Only nodes, edges and their attributes are exchanged. Groups and the schema
+//! have no `GraphML` counterpart and are dropped on export, node indices are read
+//! back as strings, and datetime and duration values are written as strings.
+
+use graphrecords_core::{
+    GraphRecord,
+    errors::{GraphRecordError, GraphRecordResult},
+    graphrecord::{AttributeMap, Attributes, GraphRecordAttribute, GraphRecordValue},
+};
+use quick_xml::{
+    Reader,
+    events::{BytesStart, Event},
+};
+use std::{collections::HashMap, fmt::Write};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KeyType {
+    String,
+    Long,
+    Double,
+    Boolean,
+}
+
+impl KeyType {
+    const fn of(value: &GraphRecordValue) -> Option<Self> {
+        match value {
+            GraphRecordValue::String(_)
+            | GraphRecordValue::DateTime(_)
+            | GraphRecordValue::Duration(_)
+            | GraphRecordValue::GeoPoint(_) => Some(Self::String),
+            GraphRecordValue::Int(_) => Some(Self::Long),
+            GraphRecordValue::Float(_) => Some(Self::Double),
+            GraphRecordValue::Bool(_) => Some(Self::Boolean),
+            GraphRecordValue::Null | GraphRecordValue::Missing => None,
+        }
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::String => "string",
+            Self::Long => "long",
+            Self::Double => "double",
+            Self::Boolean => "boolean",
+        }
+    }
+
+    fn parse(name: &str) -> Self {
+        match name {
+            "int" | "long" => Self::Long,
+            "float" | "double" => Self::Double,
+            "boolean" => Self::Boolean,
+            _ => Self::String,
+        }
+    }
+
+    fn read(self, text: &str) -> GraphRecordResult<GraphRecordValue> {
+        let invalid = || GraphRecordError::ConversionError(format!("Invalid GraphML value {text}"));
+
+        Ok(match self {
+            Self::String => text.into(),
+            Self::Long => text.trim().parse::<i64>().map_err(|_| invalid())?.into(),
+            Self::Double => text.trim().parse::<f64>().map_err(|_| invalid())?.into(),
+            Self::Boolean => text.trim().parse::<bool>().map_err(|_| invalid())?.into(),
+        })
+    }
+}
+
+struct Key {
+    id: String,
+    name: String,
+    data_type: KeyType,
+}
+
+fn escape(text: &str) -> String {
+    quick_xml::escape::escape(text).into_owned()
+}
+
+// Keys are typed in GraphML, so attributes holding values of different types
+// across elements fall back to strings.
+fn collect_keys<'a>(
+    domain: &str,
+    attributes: impl Iterator<Item = &'a AttributeMap>,
+) -> Vec<(GraphRecordAttribute, Key)> {
+    let mut types = HashMap::<&GraphRecordAttribute, KeyType>::new();
+
+    for attributes in attributes {
+        for (attribute, value) in attributes {
+            let Some(key_type) = KeyType::of(value) else {
+                continue;
+            };
+
+            types
+                .entry(attribute)
+                .and_modify(|existing| {
+                    if *existing != key_type {
+                        *existing = KeyType::String;
+                    }
+                })
+                .or_insert(key_type);
+        }
+    }
+
+    let mut keys: Vec<_> = types
+        .into_iter()
+        .map(|(attribute, key_type)| (attribute.clone(), key_type))
+        .collect();
+    keys.sort_by_key(|(attribute, _)| attribute.to_string());
+
+    keys.into_iter()
+        .enumerate()
+        .map(|(position, (attribute, key_type))| {
+            let name = attribute.to_string();
+
+            (
+                attribute,
+                Key {
+                    id: format!("{domain}{position}"),
+                    name,
+                    data_type: key_type,
+                },
+            )
+        })
+        .collect()
+}
+
+fn write_data(
+    output: &mut String,
+    keys: &HashMap<GraphRecordAttribute, Key>,
+    attributes: &AttributeMap,
+) {
+    let mut attributes: Vec<_> = attributes
+        .iter()
+        .filter(|(_, value)| !matches!(value, GraphRecordValue::Null | GraphRecordValue::Missing))
+        .collect();
+    attributes.sort_by_key(|(attribute, _)| attribute.to_string());
+
+    for (attribute, value) in attributes {
+        let key = &keys[attribute];
+
+        let _ = write!(
+            output,
+            "<data key=\"{}\">{}</data>",
+            key.id,
+            escape(&value.to_string())
+        );
+    }
+}
+
+pub fn to_graphml(graphrecord: &GraphRecord) -> GraphRecordResult<String> {
+    let mut node_indices: Vec<_> = graphrecord.node_indices().collect();
+    node_indices.sort_by_key(std::string::ToString::to_string);
+    let mut edge_indices: Vec<_> = graphrecord.edge_indices().collect();
+    edge_indices.sort_unstable();
+
+    let node_attributes = node_indices
+        .iter()
+        .map(|node_index| graphrecord.node_attributes(node_index))
+        .collect::<GraphRecordResult<Vec<_>>>()?;
+    let edge_attributes = edge_indices
+        .iter()
+        .map(|edge_index| graphrecord.edge_attributes(edge_index))
+        .collect::<GraphRecordResult<Vec<_>>>()?;
+
+    let node_keys = collect_keys("n", node_attributes.iter().copied());
+    let edge_keys = collect_keys("e", edge_attributes.iter().copied());
+
+    let mut output = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n",
+    );
+
+    for (domain, keys) in [("node", &node_keys), ("edge", &edge_keys)] {
+        for (_, key) in keys {
+            let _ = writeln!(
+                output,
+                "  <key id=\"{}\" for=\"{domain}\" attr.name=\"{}\" attr.type=\"{}\"/>",
+                key.id,
+                escape(&key.name),
+                key.data_type.name()
+            );
+        }
+    }
+
+    output.push_str("  <graph edgedefault=\"directed\">\n");
+
+    let node_keys: HashMap<_, _> = node_keys.into_iter().collect();
+    let edge_keys: HashMap<_, _> = edge_keys.into_iter().collect();
+
+    for (node_index, attributes) in node_indices.into_iter().zip(node_attributes) {
+        let _ = write!(
+            output,
+            "    <node id=\"{}\">",
+            escape(&node_index.to_string())
+        );
+        write_data(&mut output, &node_keys, attributes);
+        output.push_str("</node>\n");
+    }
+
+    for (edge_index, attributes) in edge_indices.into_iter().zip(edge_attributes) {
+        let (source, target) = graphrecord.edge_endpoints(edge_index)?;
+
+        let _ = write!(
+            output,
+            "    <edge id=\"e{edge_index}\" source=\"{}\" target=\"{}\">",
+            escape(&source.to_string()),
+            escape(&target.to_string())
+        );
+        write_data(&mut output, &edge_keys, attributes);
+        output.push_str("</edge>\n");
+    }
+
+    output.push_str("  </graph>\n</graphml>\n");
+
+    Ok(output)
+}
+
+enum Element {
+    Node(GraphRecordAttribute),
+    Edge(GraphRecordAttribute, GraphRecordAttribute),
+}
+
+fn xml_error(error: impl std::fmt::Display) -> GraphRecordError {
+    GraphRecordError::ConversionError(format!("Invalid GraphML: {error}"))
+}
+
+fn attribute(element: &BytesStart<'_>, name: &str) -> GraphRecordResult<Option<String>> {
+    for attribute in element.attributes() {
+        let attribute = attribute.map_err(xml_error)?;
+
+        if attribute.key.as_ref() == name.as_bytes() {
+            return Ok(Some(
+                attribute.unescape_value().map_err(xml_error)?.into_owned(),
+            ));
+        }
+    }
+
+    Ok(None)
+}
+
+fn required_attribute(element: &BytesStart<'_>, name: &str) -> GraphRecordResult<String> {
+    attribute(element, name)?.ok_or_else(|| {
+        GraphRecordError::ConversionError(format!(
+            "Invalid GraphML: missing attribute {name} on {}",
+            String::from_utf8_lossy(element.name().as_ref())
+        ))
+    })
+}
+
+pub fn from_graphml(text: &str) -> GraphRecordResult<GraphRecord> {
+    let mut reader = Reader::from_str(text);
+    reader.config_mut().trim_text(true);
+
+    let mut keys = HashMap::<String, (GraphRecordAttribute, KeyType)>::new();
+    let mut nodes = Vec::new();
+    let mut edges = Vec::new();
+
+    let mut element: Option<(Element, Attributes)> = None;
+    let mut data_key: Option<String> = None;
+
+    loop {
+        let event = reader.read_event().map_err(xml_error)?;
+
+        let (start, is_empty) = match &event {
+            Event::Start(start) => (Some(start), false),
+            Event::Empty(start) => (Some(start), true),
+            _ => (None, false),
+        };
+
+        if let Some(start) = start {
+            match start.name().as_ref() {
+                b"key" => {
+                    let id = required_attribute(start, "id")?;
+                    let name = attribute(start, "attr.name")?.unwrap_or_else(|| id.clone());
+                    let key_type = attribute(start, "attr.type")?
+                        .map_or(KeyType::String, |key_type| KeyType::parse(&key_type));
+
+                    keys.insert(id, (name.into(), key_type));
+                }
+                b"node" => {
+                    element = Some((
+                        Element::Node(required_attribute(start, "id")?.into()),
+                        Attributes::new(),
+                    ));
+                }
+                b"edge" => {
+                    element = Some((
+                        Element::Edge(
+                            required_attribute(start, "source")?.into(),
+                            required_attribute(start, "target")?.into(),
+                        ),
+                        Attributes::new(),
+                    ));
+                }
+                b"data" if !is_empty => data_key = Some(required_attribute(start, "key")?),
+                _ => {}
+            }
+
+            if !is_empty {
+                continue;
+            }
+        }
+
+        match event {
+            Event::Text(text) => {
+                if let (Some(key), Some((_, attributes))) = (&data_key, &mut element) {
+                    let (attribute, key_type) = keys.get(key).ok_or_else(|| {
+                        GraphRecordError::ConversionError(format!(
+                            "Invalid GraphML: unknown key {key}"
+                        ))
+                    })?;
+                    let text = text.unescape().map_err(xml_error)?;
+
+                    attributes.insert(attribute.clone(), key_type.read(&text)?);
+                }
+            }
+            Event::End(end) if end.name().as_ref() == b"data" => data_key = None,
+            Event::End(end) if matches!(end.name().as_ref(), b"node" | b"edge") => {
+                push_element(element.take(), &mut nodes, &mut edges);
+            }
+            Event::Empty(start) if matches!(start.name().as_ref(), b"node" | b"edge") => {
+                push_element(element.take(), &mut nodes, &mut edges);
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    GraphRecord::from_tuples(nodes, Some(edges), None)
+}
+
+fn push_element(
+    element: Option<(Element, Attributes)>,
+    nodes: &mut Vec<(GraphRecordAttribute, Attributes)>,
+    edges: &mut Vec<(GraphRecordAttribute, GraphRecordAttribute, Attributes)>,
+) {
+    match element {
+        Some((Element::Node(node_index), attributes)) => nodes.push((node_index, attributes)),
+        Some((Element::Edge(source, target), attributes)) => {
+            edges.push((source, target, attributes));
+        }
+        None => {}
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{from_graphml, to_graphml};
+    use graphrecords_core::{
+        GraphRecord,
+        graphrecord::{GraphRecordAttribute, GraphRecordValue},
+    };
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_round_trip() {
+        let mut graphrecord = GraphRecord::new();
+        graphrecord
+            .add_node(
+                "a & b".into(),
+                vec![
+                    ("age".into(), 20.into()),
+                    ("name".into(), "<alice>".into()),
+                    ("score".into(), 1.5.into()),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .unwrap();
+        graphrecord
+            .add_node(
+                "c".into(),
+                vec![("age".into(), "unknown".into())].into_iter().collect(),
+            )
+            .unwrap();
+        graphrecord
+            .add_edge(
+                "a & b".into(),
+                "c".into(),
+                vec![("weight".into(), true.into())].into_iter().collect(),
+            )
+            .unwrap();
+
+        let restored = from_graphml(&to_graphml(&graphrecord).unwrap()).unwrap();
+
+        assert_eq!(2, restored.node_count());
+        assert_eq!(1, restored.edge_count());
+
+        let attributes = restored
+            .node_attributes(&GraphRecordAttribute::from("a & b"))
+            .unwrap();
+        assert_eq!(
+            GraphRecordValue::from("20"),
+            attributes[&GraphRecordAttribute::from("age")]
+        );
+        assert_eq!(
+            GraphRecordValue::from("<alice>"),
+            attributes[&GraphRecordAttribute::from("name")]
+        );
+        assert_eq!(
+            GraphRecordValue::from(1.5),
+            attributes[&GraphRecordAttribute::from("score")]
+        );
+
+        let edge_index = *restored.edge_indices().next().unwrap();
+        assert_eq!(
+            &HashMap::from([(GraphRecordAttribute::from("weight"), true.into())]),
+            restored.edge_attributes(&edge_index).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_invalid_graphml() {
+        assert!(from_graphml("<graphml><graph><edge source=\"a\"/></graph></graphml>").is_err());
+    }
+}
@@ -0,0 +1,220 @@
+mod format;
+mod graphml;
+mod parquet;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use format::RecordFormat;
+use graphrecords_core::{
+    GraphRecord,
+    errors::{GraphRecordError, GraphRecordResult},
+    graphrecord::{
+        overview::OverviewFormat,
+        querying::plan::{QueryPlan, QueryPlanResult},
+        schema::Schema,
+    },
+};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+/// Inspect, convert, validate and query persisted `GraphRecord`s.
+#[derive(Debug, Parser)]
+#[command(version)]
+struct Arguments {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum OverviewStyle {
+    Modern,
+    Ascii,
+    Markdown,
+    Csv,
+}
+
+impl From<OverviewStyle> for OverviewFormat {
+    fn from(style: OverviewStyle) -> Self {
+        match style {
+            OverviewStyle::Modern => Self::Modern,
+            OverviewStyle::Ascii => Self::Ascii,
+            OverviewStyle::Markdown => Self::Markdown,
+            OverviewStyle::Csv => Self::Csv,
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Print the overview and structural statistics of a record.
+    Inspect {
+        path: PathBuf,
+
+        /// Format of the record. Inferred from the path when omitted.
+        #[arg(long)]
+        format: Option<RecordFormat>,
+
+        #[arg(long, value_enum, default_value = "modern")]
+        style: OverviewStyle,
+
+        /// Maximum length of the details column.
+        #[arg(long)]
+        truncate: Option<usize>,
+    },
+    /// Convert a record between formats.
+    Convert {
+        input: PathBuf,
+        output: PathBuf,
+
+        /// Format of the input. Inferred from the path when omitted.
+        #[arg(long)]
+        from: Option<RecordFormat>,
+
+        /// Format of the output. Inferred from the path when omitted.
+        #[arg(long)]
+        to: Option<RecordFormat>,
+    },
+    /// Check a record against a schema, or against its own schema.
+    Validate {
+        path: PathBuf,
+
+        /// Format of the record. Inferred from the path when omitted.
+        #[arg(long)]
+        format: Option<RecordFormat>,
+
+        /// RON file holding the schema to validate against.
+        #[arg(long)]
+        schema: Option<PathBuf>,
+    },
+    /// Run a query plan written in RON and print the matching indices.
+    Query {
+        path: PathBuf,
+
+        /// Query plan, e.g. `Nodes([InGroup(String("patient"))])`.
+        query: String,
+
+        /// Format of the record. Inferred from the path when omitted.
+        #[arg(long)]
+        format: Option<RecordFormat>,
+    },
+}
+
+fn read(path: &Path, format: Option<RecordFormat>) -> GraphRecordResult<GraphRecord> {
+    format
+        .map_or_else(|| RecordFormat::infer(path), Ok)?
+        .read(path)
+}
+
+fn inspect(
+    graphrecord: &GraphRecord,
+    style: OverviewStyle,
+    truncate: Option<usize>,
+) -> GraphRecordResult<()> {
+    let stats = graphrecord.stats();
+
+    println!("{}", graphrecord.overview(truncate, style.into(), None)?);
+
+    println!("Nodes: {}", stats.node_count);
+    println!("Edges: {}", stats.edge_count);
+    println!("Groups: {}", stats.groups.len());
+    println!("Density: {:.6}", stats.density);
+    if let Some(degree) = stats.degree {
+        println!(
+            "Degree: min {}, mean {:.2}, max {}",
+            degree.min, degree.mean, degree.max
+        );
+    }
+    println!("Connected components: {}", stats.connected_components);
+
+    Ok(())
+}
+
+fn validate(graphrecord: &mut GraphRecord, schema: Option<&Path>) -> GraphRecordResult<()> {
+    let schema = match schema {
+        Some(path) => {
+            let contents = fs::read_to_string(path).map_err(|_| {
+                GraphRecordError::ConversionError(format!("Failed to read {}", path.display()))
+            })?;
+
+            ron::from_str::<Schema>(&contents).map_err(|error| {
+                GraphRecordError::ConversionError(format!("Invalid schema: {error}"))
+            })?
+        }
+        None => graphrecord.get_schema().clone(),
+    };
+
+    graphrecord.set_schema(schema)
+}
+
+fn query(graphrecord: &GraphRecord, query: &str) -> GraphRecordResult<()> {
+    let plan = ron::from_str::<QueryPlan>(query)
+        .map_err(|error| GraphRecordError::QueryError(format!("Invalid query: {error}")))?;
+
+    match plan.evaluate(graphrecord)? {
+        QueryPlanResult::Nodes(node_indices) => {
+            for node_index in node_indices {
+                println!("{node_index}");
+            }
+        }
+        QueryPlanResult::Edges(edge_indices) => {
+            for edge_index in edge_indices {
+                println!("{edge_index}");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn run(command: Command) -> GraphRecordResult<()> {
+    match command {
+        Command::Inspect {
+            path,
+            format,
+            style,
+            truncate,
+        } => inspect(&read(&path, format)?, style, truncate),
+        Command::Convert {
+            input,
+            output,
+            from,
+            to,
+        } => {
+            let graphrecord = read(&input, from)?;
+
+            to.map_or_else(|| RecordFormat::infer(&output), Ok)?
+                .write(&graphrecord, &output)
+        }
+        Command::Validate {
+            path,
+            format,
+            schema,
+        } => {
+            validate(&mut read(&path, format)?, schema.as_deref())?;
+
+            println!("{} is valid", path.display());
+
+            Ok(())
+        }
+        Command::Query {
+            path,
+            query: plan,
+            format,
+        } => query(&read(&path, format)?, &plan),
+    }
+}
+
+fn main() -> ExitCode {
+    let arguments = Arguments::parse();
+
+    match run(arguments.command) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error[{}]: {}", error.code(), error.message());
+
+            ExitCode::FAILURE
+        }
+    }
+}
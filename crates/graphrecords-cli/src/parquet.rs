@@ -0,0 +1,221 @@
+//! Parquet directory layout.
+//!
+//! A record is stored as one pair of files per group, matching the
+//! [`GraphRecord::to_dataframes`] export:
+//!
+//! ```text
+//! <directory>/nodes.parquet
+//! <directory>/edges.parquet
+//! <directory>/groups/<group>/nodes.parquet
+//! <directory>/groups/<group>/edges.parquet
+//! ```
+//!
+//! The top-level files hold ungrouped nodes and edges. Group names are read
+//! back as strings, and the schema is inferred again on import.
+
+use graphrecords_core::{
+    GraphRecord,
+    errors::{GraphRecordError, GraphRecordResult},
+    graphrecord::{EdgeIndex, Group, NodeIndex},
+};
+use polars::prelude::{
+    AnyValue, BooleanChunked, DataFrame, ParquetReader, ParquetWriter, SerReader,
+};
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+    path::Path,
+};
+
+const NODE_INDEX_COLUMN: &str = "node_index";
+const EDGE_INDEX_COLUMN: &str = "edge_index";
+const SOURCE_NODE_INDEX_COLUMN: &str = "source_node_index";
+const TARGET_NODE_INDEX_COLUMN: &str = "target_node_index";
+
+fn io_error(path: &Path) -> GraphRecordError {
+    GraphRecordError::ConversionError(format!("Failed to access {}", path.display()))
+}
+
+fn write_dataframe(mut dataframe: DataFrame, path: &Path) -> GraphRecordResult<()> {
+    let file = File::create(path).map_err(|_| io_error(path))?;
+
+    ParquetWriter::new(file)
+        .finish(&mut dataframe)
+        .map_err(|_| io_error(path))?;
+
+    Ok(())
+}
+
+fn read_dataframe(path: &Path) -> GraphRecordResult<DataFrame> {
+    let file = File::open(path).map_err(|_| io_error(path))?;
+
+    ParquetReader::new(file)
+        .finish()
+        .map_err(|_| io_error(path))
+}
+
+fn mask(
+    dataframe: &DataFrame,
+    column: &str,
+    mut keep: impl FnMut(AnyValue<'_>) -> GraphRecordResult<bool>,
+) -> GraphRecordResult<BooleanChunked> {
+    dataframe
+        .column(column)
+        .map_err(|_| {
+            GraphRecordError::ConversionError(format!(
+                "Cannot find column with name {column} in dataframe"
+            ))
+        })?
+        .as_materialized_series()
+        .iter()
+        .map(|value| keep(value).map(Some))
+        .collect()
+}
+
+pub fn to_parquet(graphrecord: &GraphRecord, directory: &Path) -> GraphRecordResult<()> {
+    let export = graphrecord.to_dataframes()?;
+
+    fs::create_dir_all(directory).map_err(|_| io_error(directory))?;
+
+    write_dataframe(export.ungrouped.nodes, &directory.join("nodes.parquet"))?;
+    write_dataframe(export.ungrouped.edges, &directory.join("edges.parquet"))?;
+
+    for (group, group_export) in export.groups {
+        let group_directory = directory.join("groups").join(group.to_string());
+
+        fs::create_dir_all(&group_directory).map_err(|_| io_error(&group_directory))?;
+
+        write_dataframe(group_export.nodes, &group_directory.join("nodes.parquet"))?;
+        write_dataframe(group_export.edges, &group_directory.join("edges.parquet"))?;
+    }
+
+    Ok(())
+}
+
+// Nodes that belong to several groups appear in every group file, so only the
+// first occurrence is added. The indices of all rows are returned to restore
+// the group membership.
+fn add_nodes(
+    graphrecord: &mut GraphRecord,
+    dataframe: &DataFrame,
+) -> GraphRecordResult<Vec<NodeIndex>> {
+    let mut node_indices = Vec::new();
+    let new_nodes = mask(dataframe, NODE_INDEX_COLUMN, |value| {
+        let node_index = NodeIndex::try_from(value)?;
+        let is_new = !graphrecord.contains_node(&node_index);
+
+        node_indices.push(node_index);
+
+        Ok(is_new)
+    })?;
+
+    let new_nodes = dataframe
+        .filter(&new_nodes)
+        .map_err(|error| GraphRecordError::ConversionError(error.to_string()))?;
+
+    graphrecord.add_nodes_dataframes([(new_nodes, NODE_INDEX_COLUMN)])?;
+
+    Ok(node_indices)
+}
+
+// Edge indices are reassigned on import, so the original indices are mapped to
+// the new ones to deduplicate edges shared between groups.
+fn add_edges(
+    graphrecord: &mut GraphRecord,
+    dataframe: &DataFrame,
+    edge_indices: &mut HashMap<u32, EdgeIndex>,
+) -> GraphRecordResult<Vec<EdgeIndex>> {
+    let mut original_indices = Vec::new();
+    let new_edges = mask(dataframe, EDGE_INDEX_COLUMN, |value| {
+        let edge_index = value.extract::<u32>().ok_or_else(|| {
+            GraphRecordError::ConversionError(format!("Invalid edge index {value}"))
+        })?;
+        let is_new = !edge_indices.contains_key(&edge_index);
+
+        original_indices.push(edge_index);
+
+        Ok(is_new)
+    })?;
+
+    let new_edges = dataframe
+        .filter(&new_edges)
+        .and_then(|dataframe| dataframe.drop(EDGE_INDEX_COLUMN))
+        .map_err(|error| GraphRecordError::ConversionError(error.to_string()))?;
+
+    let mut added = graphrecord
+        .add_edges_dataframes([(
+            new_edges,
+            SOURCE_NODE_INDEX_COLUMN,
+            TARGET_NODE_INDEX_COLUMN,
+        )])?
+        .into_iter();
+
+    Ok(original_indices
+        .into_iter()
+        .map(|original_index| {
+            *edge_indices
+                .entry(original_index)
+                .or_insert_with(|| added.next().expect("One edge was added per new row"))
+        })
+        .collect())
+}
+
+pub fn from_parquet(directory: &Path) -> GraphRecordResult<GraphRecord> {
+    let mut groups = Vec::new();
+    let groups_directory = directory.join("groups");
+
+    if groups_directory.is_dir() {
+        for entry in fs::read_dir(&groups_directory).map_err(|_| io_error(&groups_directory))? {
+            let entry = entry.map_err(|_| io_error(&groups_directory))?;
+
+            groups.push((
+                Group::from(entry.file_name().to_string_lossy().into_owned()),
+                entry.path(),
+            ));
+        }
+    }
+
+    groups.sort_by_key(|(group, _)| group.to_string());
+
+    let mut graphrecord = GraphRecord::new();
+
+    add_nodes(
+        &mut graphrecord,
+        &read_dataframe(&directory.join("nodes.parquet"))?,
+    )?;
+
+    let mut nodes_in_groups = Vec::new();
+
+    for (_, group_directory) in &groups {
+        nodes_in_groups.push(add_nodes(
+            &mut graphrecord,
+            &read_dataframe(&group_directory.join("nodes.parquet"))?,
+        )?);
+    }
+
+    let mut edge_indices = HashMap::new();
+
+    add_edges(
+        &mut graphrecord,
+        &read_dataframe(&directory.join("edges.parquet"))?,
+        &mut edge_indices,
+    )?;
+
+    let mut edges_in_groups = Vec::new();
+
+    for (_, group_directory) in &groups {
+        edges_in_groups.push(add_edges(
+            &mut graphrecord,
+            &read_dataframe(&group_directory.join("edges.parquet"))?,
+            &mut edge_indices,
+        )?);
+    }
+
+    for (((group, _), node_indices), edge_indices) in
+        groups.into_iter().zip(nodes_in_groups).zip(edges_in_groups)
+    {
+        graphrecord.add_group(group, Some(node_indices), Some(edge_indices))?;
+    }
+
+    Ok(graphrecord)
+}
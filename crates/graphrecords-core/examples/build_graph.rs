@@ -0,0 +1,58 @@
+//! Times the construction of a record with string node indices, which is
+//! dominated by hashing. Compare hashers by running it with each of them:
+//!
+//! ```sh
+//! cargo run --release --example build_graph
+//! cargo run --release --example build_graph --features fxhash
+//! cargo run --release --example build_graph --features ahash
+//! cargo run --release --example build_graph --features std-hash
+//! ```
+
+use graphrecords_core::{GraphRecord, graphrecord::NodeIndex};
+use std::{collections::HashMap, time::Instant};
+
+const NODE_COUNT: usize = 1_000_000;
+const EDGES_PER_NODE: usize = 4;
+const RUNS: usize = 5;
+
+fn main() {
+    let nodes: Vec<_> = (0..NODE_COUNT)
+        .map(|i| {
+            (
+                NodeIndex::from(format!("patient_{i}")),
+                HashMap::from([("age".into(), ((i % 90) as i64).into())]),
+            )
+        })
+        .collect();
+    let edges: Vec<_> = (0..NODE_COUNT * EDGES_PER_NODE)
+        .map(|i| {
+            (
+                nodes[i % NODE_COUNT].0.clone(),
+                nodes[(i * 7_919) % NODE_COUNT].0.clone(),
+                HashMap::new(),
+            )
+        })
+        .collect();
+
+    let mut durations = Vec::with_capacity(RUNS);
+
+    for _ in 0..RUNS {
+        let start = Instant::now();
+
+        let graphrecord = GraphRecord::from_tuples(nodes.clone(), Some(edges.clone()), None)
+            .expect("Nodes and edges must be valid");
+
+        durations.push(start.elapsed());
+
+        assert_eq!(NODE_COUNT, graphrecord.node_count());
+    }
+
+    durations.sort_unstable();
+
+    println!(
+        "{NODE_COUNT} nodes, {} edges: median {:?}, min {:?}",
+        NODE_COUNT * EDGES_PER_NODE,
+        durations[RUNS / 2],
+        durations[0]
+    );
+}
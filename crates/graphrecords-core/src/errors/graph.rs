@@ -1,3 +1,5 @@
+use super::AttributeOwner;
+use crate::graphrecord::{GraphRecordAttribute, NodeIndex, datatypes::DataType};
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result},
@@ -8,6 +10,35 @@ pub enum GraphError {
     IndexError(String),
     AssertionError(String),
     SchemaError(String),
+    MissingNode {
+        index: NodeIndex,
+    },
+    TypeMismatch {
+        owner: AttributeOwner,
+        attribute: GraphRecordAttribute,
+        expected: DataType,
+        actual: DataType,
+    },
+}
+
+impl GraphError {
+    #[must_use]
+    pub fn message(&self) -> String {
+        match self {
+            Self::IndexError(message)
+            | Self::AssertionError(message)
+            | Self::SchemaError(message) => message.clone(),
+            Self::MissingNode { index } => format!("Cannot find node with index {index}"),
+            Self::TypeMismatch {
+                owner,
+                attribute,
+                expected,
+                actual,
+            } => format!(
+                "Attribute {attribute} of {owner} is of type {actual}. Expected {expected}."
+            ),
+        }
+    }
 }
 
 impl Error for GraphError {
@@ -16,6 +47,8 @@ impl Error for GraphError {
             Self::IndexError(message)
             | Self::AssertionError(message)
             | Self::SchemaError(message) => message,
+            Self::MissingNode { .. } => "missing node",
+            Self::TypeMismatch { .. } => "type mismatch",
         }
     }
 }
@@ -26,6 +59,8 @@ impl Display for GraphError {
             Self::IndexError(message) => write!(f, "IndexError: {message}"),
             Self::AssertionError(message) => write!(f, "AssertionError: {message}"),
             Self::SchemaError(message) => write!(f, "SchemaError: {message}"),
+            Self::MissingNode { .. } => write!(f, "MissingNode: {}", self.message()),
+            Self::TypeMismatch { .. } => write!(f, "TypeMismatch: {}", self.message()),
         }
     }
 }
@@ -33,6 +68,7 @@ impl Display for GraphError {
 #[cfg(test)]
 mod test {
     use super::GraphError;
+    use crate::{errors::AttributeOwner, graphrecord::datatypes::DataType};
 
     #[test]
     fn test_display() {
@@ -48,5 +84,19 @@ mod test {
             "SchemaError: value",
             GraphError::SchemaError("value".to_string()).to_string()
         );
+        assert_eq!(
+            "MissingNode: Cannot find node with index 0",
+            GraphError::MissingNode { index: 0.into() }.to_string()
+        );
+        assert_eq!(
+            "TypeMismatch: Attribute key of node with index 0 is of type Int. Expected Float.",
+            GraphError::TypeMismatch {
+                owner: AttributeOwner::Node(0.into()),
+                attribute: "key".into(),
+                expected: DataType::Float,
+                actual: DataType::Int,
+            }
+            .to_string()
+        );
     }
 }
@@ -1,3 +1,5 @@
+use super::AttributeOwner;
+use crate::graphrecord::{GraphRecordAttribute, Group, NodeIndex, datatypes::DataType};
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
@@ -11,6 +13,64 @@ pub enum GraphRecordError {
     AssertionError(String),
     SchemaError(String),
     QueryError(String),
+    ReadOnlyError(String),
+    MissingNode {
+        index: NodeIndex,
+    },
+    DuplicateGroup {
+        group: Group,
+    },
+    TypeMismatch {
+        owner: AttributeOwner,
+        attribute: GraphRecordAttribute,
+        expected: DataType,
+        actual: DataType,
+    },
+}
+
+impl GraphRecordError {
+    /// Stable identifier of the error kind.
+    ///
+    /// Codes are grouped by the general category of the error, with the
+    /// structured variants sharing the prefix of the category they refine.
+    #[must_use]
+    pub const fn code(&self) -> &'static str {
+        match self {
+            Self::IndexError(_) => "GR0100",
+            Self::MissingNode { .. } => "GR0101",
+            Self::KeyError(_) => "GR0200",
+            Self::ConversionError(_) => "GR0300",
+            Self::AssertionError(_) => "GR0400",
+            Self::DuplicateGroup { .. } => "GR0401",
+            Self::SchemaError(_) => "GR0500",
+            Self::TypeMismatch { .. } => "GR0501",
+            Self::QueryError(_) => "GR0600",
+            Self::ReadOnlyError(_) => "GR0700",
+        }
+    }
+
+    #[must_use]
+    pub fn message(&self) -> String {
+        match self {
+            Self::IndexError(message)
+            | Self::KeyError(message)
+            | Self::ConversionError(message)
+            | Self::AssertionError(message)
+            | Self::SchemaError(message)
+            | Self::QueryError(message)
+            | Self::ReadOnlyError(message) => message.clone(),
+            Self::MissingNode { index } => format!("Cannot find node with index {index}"),
+            Self::DuplicateGroup { group } => format!("Group {group} already exists"),
+            Self::TypeMismatch {
+                owner,
+                attribute,
+                expected,
+                actual,
+            } => format!(
+                "Attribute {attribute} of {owner} is of type {actual}. Expected {expected}."
+            ),
+        }
+    }
 }
 
 impl Error for GraphRecordError {
@@ -21,7 +81,11 @@ impl Error for GraphRecordError {
             | Self::ConversionError(message)
             | Self::AssertionError(message)
             | Self::SchemaError(message)
-            | Self::QueryError(message) => message,
+            | Self::QueryError(message)
+            | Self::ReadOnlyError(message) => message,
+            Self::MissingNode { .. } => "missing node",
+            Self::DuplicateGroup { .. } => "duplicate group",
+            Self::TypeMismatch { .. } => "type mismatch",
         }
     }
 }
@@ -35,6 +99,10 @@ impl Display for GraphRecordError {
             Self::AssertionError(message) => write!(f, "AssertionError: {message}"),
             Self::SchemaError(message) => write!(f, "SchemaError: {message}"),
             Self::QueryError(message) => write!(f, "QueryError: {message}"),
+            Self::ReadOnlyError(message) => write!(f, "ReadOnlyError: {message}"),
+            Self::MissingNode { .. } => write!(f, "MissingNode: {}", self.message()),
+            Self::DuplicateGroup { .. } => write!(f, "DuplicateGroup: {}", self.message()),
+            Self::TypeMismatch { .. } => write!(f, "TypeMismatch: {}", self.message()),
         }
     }
 }
@@ -42,6 +110,7 @@ impl Display for GraphRecordError {
 #[cfg(test)]
 mod test {
     use super::GraphRecordError;
+    use crate::{errors::AttributeOwner, graphrecord::datatypes::DataType};
 
     #[test]
     fn test_display() {
@@ -65,5 +134,59 @@ mod test {
             "SchemaError: value",
             GraphRecordError::SchemaError("value".to_string()).to_string()
         );
+        assert_eq!(
+            "ReadOnlyError: value",
+            GraphRecordError::ReadOnlyError("value".to_string()).to_string()
+        );
+        assert_eq!(
+            "MissingNode: Cannot find node with index 0",
+            GraphRecordError::MissingNode { index: 0.into() }.to_string()
+        );
+        assert_eq!(
+            "DuplicateGroup: Group group already exists",
+            GraphRecordError::DuplicateGroup {
+                group: "group".into()
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "TypeMismatch: Attribute key of edge with index 0 is of type Int. Expected Float.",
+            GraphRecordError::TypeMismatch {
+                owner: AttributeOwner::Edge(0),
+                attribute: "key".into(),
+                expected: DataType::Float,
+                actual: DataType::Int,
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn test_code() {
+        assert_eq!(
+            "GR0100",
+            GraphRecordError::IndexError("value".to_string()).code()
+        );
+        assert_eq!(
+            "GR0101",
+            GraphRecordError::MissingNode { index: 0.into() }.code()
+        );
+        assert_eq!(
+            "GR0401",
+            GraphRecordError::DuplicateGroup {
+                group: "group".into()
+            }
+            .code()
+        );
+        assert_eq!(
+            "GR0501",
+            GraphRecordError::TypeMismatch {
+                owner: AttributeOwner::Node(0.into()),
+                attribute: "key".into(),
+                expected: DataType::Float,
+                actual: DataType::Int,
+            }
+            .code()
+        );
     }
 }
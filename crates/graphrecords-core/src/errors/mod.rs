@@ -4,12 +4,42 @@ mod graphrecord;
 pub use graph::GraphError;
 pub use graphrecord::GraphRecordError;
 
+use crate::graphrecord::{EdgeIndex, NodeIndex};
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AttributeOwner {
+    Node(NodeIndex),
+    Edge(EdgeIndex),
+}
+
+impl Display for AttributeOwner {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Node(index) => write!(f, "node with index {index}"),
+            Self::Edge(index) => write!(f, "edge with index {index}"),
+        }
+    }
+}
+
 impl From<GraphError> for GraphRecordError {
     fn from(value: GraphError) -> Self {
         match value {
             GraphError::IndexError(value) => Self::IndexError(value),
             GraphError::AssertionError(value) => Self::AssertionError(value),
             GraphError::SchemaError(value) => Self::SchemaError(value),
+            GraphError::MissingNode { index } => Self::MissingNode { index },
+            GraphError::TypeMismatch {
+                owner,
+                attribute,
+                expected,
+                actual,
+            } => Self::TypeMismatch {
+                owner,
+                attribute,
+                expected,
+                actual,
+            },
         }
     }
 }
@@ -34,5 +64,9 @@ mod test {
             GraphRecordError::SchemaError("value".to_string()),
             GraphRecordError::from(GraphError::SchemaError("value".to_string()))
         );
+        assert_eq!(
+            GraphRecordError::MissingNode { index: 0.into() },
+            GraphRecordError::from(GraphError::MissingNode { index: 0.into() })
+        );
     }
 }
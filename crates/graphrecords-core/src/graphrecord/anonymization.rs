@@ -0,0 +1,347 @@
+//! k-anonymity checks over the quasi-identifiers of a node group, and the
+//! generalizations to reach a k threshold before the data leaves the record.
+
+use super::{GraphRecord, GraphRecordAttribute, GraphRecordValue, Group, NodeIndex};
+use crate::errors::{GraphRecordError, GraphRecordResult};
+use std::collections::HashMap;
+
+/// Coarsens an attribute value so that more nodes share it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Generalization {
+    /// Replaces a number with the range of width `width` it falls into, e.g.
+    /// an age of 42 with `"40-49"` for a width of 10.
+    Bucket(i64),
+    /// Keeps the first `length` characters of a string or integer and masks
+    /// the rest with `*`, e.g. the zip code 12345 with `"123**"` for a length
+    /// of 3.
+    Truncate(usize),
+}
+
+impl Generalization {
+    fn apply(
+        self,
+        node_index: &NodeIndex,
+        attribute: &GraphRecordAttribute,
+        value: &GraphRecordValue,
+    ) -> GraphRecordResult<GraphRecordValue> {
+        match (self, value) {
+            (_, GraphRecordValue::Null | GraphRecordValue::Missing) => Ok(value.clone()),
+            (Self::Bucket(width), GraphRecordValue::Int(value)) => {
+                Ok(Self::bucket_label(value.div_euclid(width) * width, width))
+            }
+            (Self::Bucket(width), GraphRecordValue::Float(value)) => Ok(Self::bucket_label(
+                (value / width as f64).floor() as i64 * width,
+                width,
+            )),
+            (Self::Truncate(length), GraphRecordValue::String(value)) => {
+                Ok(Self::truncate(value, length))
+            }
+            (Self::Truncate(length), GraphRecordValue::Int(value)) => {
+                Ok(Self::truncate(&value.to_string(), length))
+            }
+            (Self::Bucket(_), value) => Err(GraphRecordError::ConversionError(format!(
+                "Cannot bucket attribute {attribute} of node {node_index}, got {value}"
+            ))),
+            (Self::Truncate(_), value) => Err(GraphRecordError::ConversionError(format!(
+                "Cannot truncate attribute {attribute} of node {node_index}, got {value}"
+            ))),
+        }
+    }
+
+    fn bucket_label(lower: i64, width: i64) -> GraphRecordValue {
+        format!("{lower}-{}", lower + width - 1).into()
+    }
+
+    fn truncate(value: &str, length: usize) -> GraphRecordValue {
+        value
+            .chars()
+            .enumerate()
+            .map(|(position, character)| if position < length { character } else { '*' })
+            .collect::<String>()
+            .into()
+    }
+}
+
+impl GraphRecord {
+    /// Groups the nodes in `group` by their values of `quasi_identifiers`. A
+    /// missing attribute counts as a value of its own.
+    fn equivalence_classes(
+        &self,
+        group: &Group,
+        quasi_identifiers: &[GraphRecordAttribute],
+    ) -> GraphRecordResult<HashMap<Vec<GraphRecordValue>, Vec<&NodeIndex>>> {
+        let mut classes: HashMap<_, Vec<_>> = HashMap::new();
+
+        for node_index in self.nodes_in_group(group)? {
+            let attributes = self.node_attributes(node_index)?;
+
+            let key = quasi_identifiers
+                .iter()
+                .map(|attribute| {
+                    attributes
+                        .get(attribute)
+                        .cloned()
+                        .unwrap_or(GraphRecordValue::Missing)
+                })
+                .collect();
+
+            classes.entry(key).or_default().push(node_index);
+        }
+
+        Ok(classes)
+    }
+
+    /// Returns the size of the smallest set of nodes in `group` that share
+    /// their values of `quasi_identifiers`, or 0 if the group is empty.
+    pub fn k_anonymity(
+        &self,
+        group: &Group,
+        quasi_identifiers: &[GraphRecordAttribute],
+    ) -> GraphRecordResult<usize> {
+        Ok(self
+            .equivalence_classes(group, quasi_identifiers)?
+            .values()
+            .map(Vec::len)
+            .min()
+            .unwrap_or(0))
+    }
+
+    /// Returns the nodes in `group` that share their values of
+    /// `quasi_identifiers` with fewer than `k` nodes, themselves included.
+    pub fn k_anonymity_violations(
+        &self,
+        group: &Group,
+        quasi_identifiers: &[GraphRecordAttribute],
+        k: usize,
+    ) -> GraphRecordResult<Vec<NodeIndex>> {
+        let mut violations: Vec<NodeIndex> = self
+            .equivalence_classes(group, quasi_identifiers)?
+            .into_values()
+            .filter(|class| class.len() < k)
+            .flatten()
+            .cloned()
+            .collect();
+
+        violations.sort_by(GraphRecordAttribute::total_cmp);
+
+        Ok(violations)
+    }
+
+    /// Removes the nodes returned by [`GraphRecord::k_anonymity_violations`],
+    /// together with their edges, so that the rest of `group` is k-anonymous.
+    /// Returns the removed nodes.
+    pub fn enforce_k_anonymity(
+        &mut self,
+        group: &Group,
+        quasi_identifiers: &[GraphRecordAttribute],
+        k: usize,
+    ) -> GraphRecordResult<Vec<NodeIndex>> {
+        let violations = self.k_anonymity_violations(group, quasi_identifiers, k)?;
+
+        for node_index in &violations {
+            self.remove_node(node_index)?;
+        }
+
+        Ok(violations)
+    }
+
+    /// Replaces the `attribute` of every node in `group` with its
+    /// generalization. Nodes without the attribute are left as they are.
+    #[expect(clippy::missing_panics_doc, reason = "infallible")]
+    pub fn generalize(
+        &mut self,
+        group: &Group,
+        attribute: &GraphRecordAttribute,
+        generalization: Generalization,
+    ) -> GraphRecordResult<()> {
+        if matches!(generalization, Generalization::Bucket(width) if width <= 0) {
+            return Err(GraphRecordError::AssertionError(
+                "Bucket width must be positive".to_string(),
+            ));
+        }
+
+        let generalized_values = self
+            .nodes_in_group(group)?
+            .filter_map(|node_index| {
+                let value = self
+                    .node_attributes(node_index)
+                    .expect("Node must exist")
+                    .get(attribute)?;
+
+                Some(
+                    generalization
+                        .apply(node_index, attribute, value)
+                        .map(|value| (node_index.clone(), value)),
+                )
+            })
+            .collect::<GraphRecordResult<Vec<_>>>()?;
+
+        for (node_index, value) in generalized_values {
+            self.node_attributes_mut(&node_index)?
+                .update_attribute(attribute, value)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Generalization;
+    use crate::{
+        GraphRecord,
+        errors::GraphRecordError,
+        graphrecord::{GraphRecordValue, NodeIndex},
+    };
+    use std::collections::HashMap;
+
+    fn create_graphrecord() -> GraphRecord {
+        let patients: Vec<(i64, &str, &str)> = vec![
+            (41, "12345", "F"),
+            (47, "12399", "F"),
+            (45, "12310", "M"),
+            (52, "54321", "M"),
+            (58, "54399", "M"),
+        ];
+
+        let mut graphrecord = GraphRecord::from_tuples(
+            patients
+                .into_iter()
+                .enumerate()
+                .map(|(index, (age, zip_code, sex))| {
+                    (
+                        (index as i64).into(),
+                        HashMap::from([
+                            ("age".into(), age.into()),
+                            ("zip_code".into(), zip_code.into()),
+                            ("sex".into(), sex.into()),
+                        ]),
+                    )
+                })
+                .collect(),
+            None,
+            None,
+        )
+        .unwrap();
+
+        graphrecord
+            .add_group(
+                "patients".into(),
+                Some((0..5).map(Into::into).collect()),
+                None,
+            )
+            .unwrap();
+
+        graphrecord
+    }
+
+    #[test]
+    fn test_k_anonymity() {
+        let mut graphrecord = create_graphrecord();
+        let quasi_identifiers = ["age".into(), "zip_code".into()];
+
+        assert_eq!(
+            1,
+            graphrecord
+                .k_anonymity(&"patients".into(), &quasi_identifiers)
+                .unwrap()
+        );
+
+        graphrecord
+            .generalize(
+                &"patients".into(),
+                &"age".into(),
+                Generalization::Bucket(10),
+            )
+            .unwrap();
+        graphrecord
+            .generalize(
+                &"patients".into(),
+                &"zip_code".into(),
+                Generalization::Truncate(3),
+            )
+            .unwrap();
+
+        assert_eq!(
+            Some(&GraphRecordValue::from("40-49")),
+            graphrecord
+                .node_attributes(&0.into())
+                .unwrap()
+                .get(&"age".into())
+        );
+        assert_eq!(
+            Some(&GraphRecordValue::from("123**")),
+            graphrecord
+                .node_attributes(&0.into())
+                .unwrap()
+                .get(&"zip_code".into())
+        );
+        assert_eq!(
+            2,
+            graphrecord
+                .k_anonymity(&"patients".into(), &quasi_identifiers)
+                .unwrap()
+        );
+
+        graphrecord.add_group("empty".into(), None, None).unwrap();
+
+        assert_eq!(
+            0,
+            graphrecord
+                .k_anonymity(&"empty".into(), &quasi_identifiers)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_enforce_k_anonymity() {
+        let mut graphrecord = create_graphrecord();
+        let quasi_identifiers = ["sex".into()];
+
+        assert_eq!(
+            vec![NodeIndex::from(0), NodeIndex::from(1)],
+            graphrecord
+                .k_anonymity_violations(&"patients".into(), &quasi_identifiers, 3)
+                .unwrap()
+        );
+        assert_eq!(
+            vec![NodeIndex::from(0), NodeIndex::from(1)],
+            graphrecord
+                .enforce_k_anonymity(&"patients".into(), &quasi_identifiers, 3)
+                .unwrap()
+        );
+
+        assert_eq!(3, graphrecord.node_count());
+        assert_eq!(
+            3,
+            graphrecord
+                .k_anonymity(&"patients".into(), &quasi_identifiers)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_invalid_generalize() {
+        let mut graphrecord = create_graphrecord();
+
+        assert!(
+            graphrecord
+                .generalize(&"patients".into(), &"age".into(), Generalization::Bucket(0),)
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
+        assert!(
+            graphrecord
+                .generalize(
+                    &"patients".into(),
+                    &"sex".into(),
+                    Generalization::Bucket(10),
+                )
+                .is_err_and(|e| matches!(e, GraphRecordError::ConversionError(_)))
+        );
+        assert!(
+            graphrecord
+                .generalize(&"visits".into(), &"age".into(), Generalization::Truncate(3),)
+                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+        );
+    }
+}
@@ -0,0 +1,564 @@
+use super::{GraphRecord, GraphRecordAttribute, GraphRecordValue, Group, crosstab::Crosstab};
+use crate::errors::{GraphRecordError, GraphRecordResult};
+use statrs::distribution::{ChiSquared, ContinuousCDF, StudentsT};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CorrelationMethod {
+    Pearson,
+    Spearman,
+}
+
+/// Correlation between two numeric node attributes. The p-value belongs to
+/// the two-sided test of the coefficient being zero.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CorrelationTest {
+    pub coefficient: f64,
+    pub p_value: f64,
+    pub sample_size: usize,
+}
+
+/// Pearson's chi-square test of independence between two categorical node
+/// attributes, with Cramér's V as the effect size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChiSquareTest {
+    pub statistic: f64,
+    pub degrees_of_freedom: usize,
+    pub p_value: f64,
+    pub cramers_v: f64,
+    pub sample_size: usize,
+}
+
+const fn is_recorded(value: &GraphRecordValue) -> bool {
+    !value.is_null() && !value.is_missing()
+}
+
+fn numeric_pairs(
+    graphrecord: &GraphRecord,
+    group: &Group,
+    attribute_a: &GraphRecordAttribute,
+    attribute_b: &GraphRecordAttribute,
+) -> GraphRecordResult<(Vec<f64>, Vec<f64>)> {
+    let mut values_a = Vec::new();
+    let mut values_b = Vec::new();
+
+    for node_index in graphrecord.nodes_in_group(group)? {
+        let attributes = graphrecord.node_attributes(node_index)?;
+
+        // Nodes that lack one of the attributes or have a null or missing
+        // value of it are not part of the sample
+        let (Some(value_a), Some(value_b)) =
+            (attributes.get(attribute_a), attributes.get(attribute_b))
+        else {
+            continue;
+        };
+
+        if !is_recorded(value_a) || !is_recorded(value_b) {
+            continue;
+        }
+
+        for (attribute, value) in [(attribute_a, value_a), (attribute_b, value_b)] {
+            if value.is_nan() {
+                return Err(GraphRecordError::AssertionError(format!(
+                    "Correlation is undefined for NaN values, but node {node_index} has a NaN value of attribute {attribute}"
+                )));
+            }
+        }
+
+        values_a.push(f64::try_from(value_a.clone())?);
+        values_b.push(f64::try_from(value_b.clone())?);
+    }
+
+    Ok((values_a, values_b))
+}
+
+/// Returns the ranks of `values` starting at 1, giving tied values the mean of
+/// the ranks they span.
+fn ranks(values: &[f64]) -> Vec<f64> {
+    let mut order: Vec<_> = (0..values.len()).collect();
+    order.sort_by(|a, b| values[*a].total_cmp(&values[*b]));
+
+    let mut ranks = vec![0.0; values.len()];
+    let mut start = 0;
+
+    while start < order.len() {
+        let mut end = start + 1;
+
+        while end < order.len() && values[order[end]].total_cmp(&values[order[start]]).is_eq() {
+            end += 1;
+        }
+
+        let rank = (start + end + 1) as f64 / 2.0;
+
+        for position in &order[start..end] {
+            ranks[*position] = rank;
+        }
+
+        start = end;
+    }
+
+    ranks
+}
+
+fn pearson(values_a: &[f64], values_b: &[f64]) -> f64 {
+    let sample_size = values_a.len() as f64;
+    let mean_a = values_a.iter().sum::<f64>() / sample_size;
+    let mean_b = values_b.iter().sum::<f64>() / sample_size;
+
+    let (covariance, variance_a, variance_b) = values_a.iter().zip(values_b).fold(
+        (0.0, 0.0, 0.0),
+        |(covariance, variance_a, variance_b), (value_a, value_b)| {
+            let delta_a = value_a - mean_a;
+            let delta_b = value_b - mean_b;
+
+            (
+                delta_b.mul_add(delta_a, covariance),
+                delta_a.mul_add(delta_a, variance_a),
+                delta_b.mul_add(delta_b, variance_b),
+            )
+        },
+    );
+
+    (covariance / (variance_a * variance_b).sqrt()).clamp(-1.0, 1.0)
+}
+
+/// Returns the two-sided p-value of Welch's t-test for equal means. Returns
+/// `None` if a sample has fewer than 2 values or both samples are constant.
+pub(crate) fn welch_t_test(values_a: &[f64], values_b: &[f64]) -> Option<f64> {
+    if values_a.len() < 2 || values_b.len() < 2 {
+        return None;
+    }
+
+    let moments = |values: &[f64]| {
+        let count = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / count;
+        let variance = values
+            .iter()
+            .map(|value| (value - mean).powi(2))
+            .sum::<f64>()
+            / (count - 1.0);
+
+        (mean, variance / count, count)
+    };
+
+    let (mean_a, error_a, count_a) = moments(values_a);
+    let (mean_b, error_b, count_b) = moments(values_b);
+    let error = error_a + error_b;
+
+    if error <= 0.0 {
+        return None;
+    }
+
+    let statistic = (mean_a - mean_b) / error.sqrt();
+    let degrees_of_freedom =
+        error.powi(2) / (error_a.powi(2) / (count_a - 1.0) + error_b.powi(2) / (count_b - 1.0));
+
+    Some(
+        2.0 * StudentsT::new(0.0, 1.0, degrees_of_freedom)
+            .expect("Degrees of freedom must be positive")
+            .sf(statistic.abs()),
+    )
+}
+
+impl CorrelationTest {
+    pub(crate) fn new(
+        graphrecord: &GraphRecord,
+        group: &Group,
+        attribute_a: &GraphRecordAttribute,
+        attribute_b: &GraphRecordAttribute,
+        method: CorrelationMethod,
+    ) -> GraphRecordResult<Self> {
+        let (values_a, values_b) = numeric_pairs(graphrecord, group, attribute_a, attribute_b)?;
+        let sample_size = values_a.len();
+
+        if sample_size < 3 {
+            return Err(GraphRecordError::AssertionError(format!(
+                "Correlation needs at least 3 nodes with both attributes, got {sample_size}"
+            )));
+        }
+
+        let coefficient = match method {
+            CorrelationMethod::Pearson => pearson(&values_a, &values_b),
+            CorrelationMethod::Spearman => pearson(&ranks(&values_a), &ranks(&values_b)),
+        };
+
+        if coefficient.is_nan() {
+            return Err(GraphRecordError::AssertionError(
+                "Correlation is undefined for attributes with a constant value".to_string(),
+            ));
+        }
+
+        // The coefficient is tested with a t-distribution for both methods
+        let degrees_of_freedom = (sample_size - 2) as f64;
+        let p_value = if coefficient.abs() < 1.0 {
+            let statistic =
+                coefficient * (degrees_of_freedom / coefficient.mul_add(-coefficient, 1.0)).sqrt();
+
+            2.0 * StudentsT::new(0.0, 1.0, degrees_of_freedom)
+                .expect("Degrees of freedom must be positive")
+                .sf(statistic.abs())
+        } else {
+            0.0
+        };
+
+        Ok(Self {
+            coefficient,
+            p_value,
+            sample_size,
+        })
+    }
+}
+
+impl ChiSquareTest {
+    pub(crate) fn new(
+        graphrecord: &GraphRecord,
+        group: &Group,
+        attribute_a: &GraphRecordAttribute,
+        attribute_b: &GraphRecordAttribute,
+    ) -> GraphRecordResult<Self> {
+        let crosstab = Crosstab::new(graphrecord, group, attribute_a, attribute_b, None)?;
+
+        // Null and missing values are not categories of the test
+        let counts: Vec<Vec<f64>> = crosstab
+            .rows
+            .iter()
+            .zip(&crosstab.values)
+            .filter(|(row, _)| is_recorded(row))
+            .map(|(_, counts)| {
+                counts
+                    .iter()
+                    .zip(&crosstab.columns)
+                    .filter(|(_, column)| is_recorded(column))
+                    .map(|(count, _)| *count)
+                    .collect()
+            })
+            .collect();
+
+        Self::from_counts(&counts).ok_or_else(|| {
+            GraphRecordError::AssertionError(
+                "Chi-square test needs at least 2 distinct values of each attribute".to_string(),
+            )
+        })
+    }
+
+    /// Runs the test on a contingency table of counts. Returns `None` if the
+    /// table has fewer than 2 rows or columns, or a row or column without
+    /// counts.
+    pub(crate) fn from_counts(counts: &[Vec<f64>]) -> Option<Self> {
+        let row_count = counts.len();
+        let column_count = counts.first().map_or(0, Vec::len);
+
+        if row_count < 2 || column_count < 2 {
+            return None;
+        }
+
+        let row_totals: Vec<f64> = counts.iter().map(|row| row.iter().sum()).collect();
+        let column_totals: Vec<f64> = (0..column_count)
+            .map(|column| counts.iter().map(|row| row[column]).sum())
+            .collect();
+        let total: f64 = row_totals.iter().sum();
+
+        if row_totals
+            .iter()
+            .chain(&column_totals)
+            .any(|total| *total <= 0.0)
+        {
+            return None;
+        }
+
+        let statistic = counts
+            .iter()
+            .zip(&row_totals)
+            .flat_map(|(row, row_total)| {
+                row.iter()
+                    .zip(&column_totals)
+                    .map(move |(observed, column_total)| {
+                        let expected = row_total * column_total / total;
+
+                        (observed - expected).powi(2) / expected
+                    })
+            })
+            .sum::<f64>();
+
+        let degrees_of_freedom = (row_count - 1) * (column_count - 1);
+        let p_value = ChiSquared::new(degrees_of_freedom as f64)
+            .expect("Degrees of freedom must be positive")
+            .sf(statistic);
+
+        let smaller_dimension = row_count.min(column_count) - 1;
+        let cramers_v = (statistic / (total * smaller_dimension as f64)).sqrt();
+
+        Some(Self {
+            statistic,
+            degrees_of_freedom,
+            p_value,
+            cramers_v,
+            sample_size: total as usize,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ChiSquareTest, CorrelationMethod, ranks, welch_t_test};
+    use crate::{GraphRecord, errors::GraphRecordError, graphrecord::GraphRecordValue};
+    use std::collections::HashMap;
+
+    fn create_graphrecord() -> GraphRecord {
+        let patients: Vec<(i64, i64, &str, bool)> = vec![
+            (1, 2, "F", true),
+            (2, 1, "F", true),
+            (3, 4, "F", true),
+            (4, 3, "F", false),
+            (5, 5, "M", true),
+            (6, 5, "M", false),
+            (7, 5, "M", false),
+            (8, 5, "M", false),
+        ];
+
+        let mut graphrecord = GraphRecord::from_tuples(
+            patients
+                .into_iter()
+                .enumerate()
+                .map(|(index, (age, score, sex, smoker))| {
+                    (
+                        (index as i64).into(),
+                        HashMap::from([
+                            ("age".into(), age.into()),
+                            ("score".into(), score.into()),
+                            ("sex".into(), sex.into()),
+                            ("smoker".into(), smoker.into()),
+                        ]),
+                    )
+                })
+                .collect(),
+            None,
+            None,
+        )
+        .unwrap();
+
+        graphrecord
+            .add_group(
+                "patients".into(),
+                Some((0..8).map(Into::into).collect()),
+                None,
+            )
+            .unwrap();
+        graphrecord
+            .add_group("women".into(), Some((0..4).map(Into::into).collect()), None)
+            .unwrap();
+        graphrecord
+            .add_group("men".into(), Some((4..8).map(Into::into).collect()), None)
+            .unwrap();
+
+        graphrecord
+    }
+
+    #[test]
+    fn test_ranks() {
+        assert_eq!(vec![1.0, 2.5, 4.0, 2.5], ranks(&[1.0, 2.0, 3.0, 2.0]));
+    }
+
+    #[test]
+    fn test_welch_t_test() {
+        // Equal variances and sizes give t = -sqrt(6) with 4 degrees of freedom
+        let p_value = welch_t_test(&[1.0, 2.0, 3.0], &[3.0, 4.0, 5.0]).unwrap();
+
+        assert!((p_value - 0.070_483_996_910_22).abs() < 1e-9);
+
+        assert_eq!(None, welch_t_test(&[1.0], &[3.0, 4.0]));
+        assert_eq!(None, welch_t_test(&[1.0, 1.0], &[3.0, 3.0]));
+    }
+
+    #[test]
+    fn test_correlation() {
+        let mut graphrecord = create_graphrecord();
+
+        let correlation = graphrecord
+            .correlation(
+                &"women".into(),
+                &"age".into(),
+                &"score".into(),
+                CorrelationMethod::Pearson,
+            )
+            .unwrap();
+
+        assert_eq!(4, correlation.sample_size);
+        assert!((correlation.coefficient - 0.6).abs() < 1e-9);
+        assert!((correlation.p_value - 0.4).abs() < 1e-9);
+
+        // Spearman only depends on the order of the values
+        graphrecord
+            .node_attributes_mut(&2.into())
+            .unwrap()
+            .update_attribute(&"score".into(), GraphRecordValue::Int(100))
+            .unwrap();
+
+        let correlation = graphrecord
+            .correlation(
+                &"women".into(),
+                &"age".into(),
+                &"score".into(),
+                CorrelationMethod::Spearman,
+            )
+            .unwrap();
+
+        assert!((correlation.coefficient - 0.6).abs() < 1e-9);
+        assert!((correlation.p_value - 0.4).abs() < 1e-9);
+
+        let correlation = graphrecord
+            .correlation(
+                &"patients".into(),
+                &"age".into(),
+                &"age".into(),
+                CorrelationMethod::Pearson,
+            )
+            .unwrap();
+
+        assert!((correlation.coefficient - 1.0).abs() < f64::EPSILON);
+        assert!(correlation.p_value.abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_invalid_correlation() {
+        let graphrecord = create_graphrecord();
+
+        assert!(
+            graphrecord
+                .correlation(
+                    &"visits".into(),
+                    &"age".into(),
+                    &"score".into(),
+                    CorrelationMethod::Pearson,
+                )
+                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+        );
+        assert!(
+            graphrecord
+                .correlation(
+                    &"patients".into(),
+                    &"age".into(),
+                    &"sex".into(),
+                    CorrelationMethod::Pearson,
+                )
+                .is_err_and(|e| matches!(e, GraphRecordError::ConversionError(_)))
+        );
+        assert!(
+            graphrecord
+                .correlation(
+                    &"men".into(),
+                    &"age".into(),
+                    &"score".into(),
+                    CorrelationMethod::Pearson,
+                )
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
+        assert!(
+            graphrecord
+                .correlation(
+                    &"patients".into(),
+                    &"age".into(),
+                    &"weight".into(),
+                    CorrelationMethod::Pearson,
+                )
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
+    }
+
+    #[test]
+    fn test_chi_square() {
+        let graphrecord = create_graphrecord();
+
+        let chi_square = graphrecord
+            .chi_square(&"patients".into(), &"sex".into(), &"smoker".into())
+            .unwrap();
+
+        assert_eq!(8, chi_square.sample_size);
+        assert_eq!(1, chi_square.degrees_of_freedom);
+        assert!((chi_square.statistic - 2.0).abs() < 1e-9);
+        assert!((chi_square.p_value - 0.157_299_207_050_285_1).abs() < 1e-9);
+        assert!((chi_square.cramers_v - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_null_values() {
+        let mut graphrecord = create_graphrecord();
+
+        for (node_index, value) in [(8, GraphRecordValue::Null), (9, GraphRecordValue::Missing)] {
+            graphrecord
+                .add_node(
+                    node_index.into(),
+                    HashMap::from([
+                        ("age".into(), value.clone()),
+                        ("score".into(), 1.into()),
+                        ("sex".into(), value),
+                        ("smoker".into(), true.into()),
+                    ]),
+                )
+                .unwrap();
+            graphrecord
+                .add_node_to_group("patients".into(), node_index.into())
+                .unwrap();
+        }
+
+        let correlation = graphrecord
+            .correlation(
+                &"patients".into(),
+                &"age".into(),
+                &"score".into(),
+                CorrelationMethod::Pearson,
+            )
+            .unwrap();
+
+        assert_eq!(8, correlation.sample_size);
+
+        let chi_square = graphrecord
+            .chi_square(&"patients".into(), &"sex".into(), &"smoker".into())
+            .unwrap();
+
+        assert_eq!(8, chi_square.sample_size);
+        assert_eq!(1, chi_square.degrees_of_freedom);
+        assert!((chi_square.statistic - 2.0).abs() < 1e-9);
+
+        graphrecord
+            .node_attributes_mut(&8.into())
+            .unwrap()
+            .update_attribute(&"age".into(), f64::NAN.into())
+            .unwrap();
+
+        assert!(
+            graphrecord
+                .correlation(
+                    &"patients".into(),
+                    &"age".into(),
+                    &"score".into(),
+                    CorrelationMethod::Pearson,
+                )
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
+    }
+
+    #[test]
+    fn test_chi_square_from_counts() {
+        assert_eq!(None, ChiSquareTest::from_counts(&[vec![1.0, 2.0]]));
+        assert_eq!(
+            None,
+            ChiSquareTest::from_counts(&[vec![1.0, 2.0], vec![0.0, 0.0]])
+        );
+    }
+
+    #[test]
+    fn test_invalid_chi_square() {
+        let graphrecord = create_graphrecord();
+
+        assert!(
+            graphrecord
+                .chi_square(&"visits".into(), &"sex".into(), &"smoker".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+        );
+        assert!(
+            graphrecord
+                .chi_square(&"women".into(), &"sex".into(), &"smoker".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
+    }
+}
@@ -0,0 +1,492 @@
+//! Renaming and dropping an attribute across many nodes and edges at once,
+//! together with the schema.
+
+use super::{
+    AttributeMap, EdgeIndex, GraphRecord, GraphRecordAttribute, Group, NodeIndex,
+    schema::{Schema, SchemaType},
+    subscription::ChangeEvent,
+};
+use crate::errors::{GraphRecordError, GraphRecordResult};
+
+/// The nodes and edges an attribute is renamed or dropped for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AttributeScope {
+    /// Every node and edge, and every group of the schema.
+    All,
+    /// Every node, and the node attributes of every group of the schema.
+    Nodes,
+    /// Every edge, and the edge attributes of every group of the schema.
+    Edges,
+    /// The nodes and edges in the group, and the schema of the group.
+    Group(Group),
+}
+
+impl AttributeScope {
+    const fn includes_nodes(&self) -> bool {
+        !matches!(self, Self::Edges)
+    }
+
+    const fn includes_edges(&self) -> bool {
+        !matches!(self, Self::Nodes)
+    }
+}
+
+impl GraphRecord {
+    fn nodes_in_scope(&self, scope: &AttributeScope) -> GraphRecordResult<Vec<NodeIndex>> {
+        match scope {
+            AttributeScope::All | AttributeScope::Nodes => {
+                Ok(self.node_indices().cloned().collect())
+            }
+            AttributeScope::Edges => Ok(Vec::new()),
+            AttributeScope::Group(group) => Ok(self.nodes_in_group(group)?.cloned().collect()),
+        }
+    }
+
+    fn edges_in_scope(&self, scope: &AttributeScope) -> GraphRecordResult<Vec<EdgeIndex>> {
+        match scope {
+            AttributeScope::All | AttributeScope::Edges => {
+                Ok(self.edge_indices().copied().collect())
+            }
+            AttributeScope::Nodes => Ok(Vec::new()),
+            AttributeScope::Group(group) => Ok(self.edges_in_group(group)?.copied().collect()),
+        }
+    }
+
+    /// Applies `change_attributes` to every node and edge in `scope` and
+    /// `change_schema` to the schemas in `scope`.
+    fn change_attributes_in_scope(
+        &mut self,
+        scope: &AttributeScope,
+        change_attributes: impl Fn(&AttributeMap) -> GraphRecordResult<Option<AttributeMap>>,
+        change_schema: impl Fn(&mut Schema, Option<&Group>, bool),
+        changed: &[GraphRecordAttribute],
+    ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
+        let node_indices = self.nodes_in_scope(scope)?;
+        let edge_indices = self.edges_in_scope(scope)?;
+
+        let mut schema = self.schema.clone();
+
+        let schema_groups: Vec<Option<Group>> = match scope {
+            AttributeScope::Group(group) => vec![Some(group.clone())],
+            _ => std::iter::once(None)
+                .chain(self.schema.groups().keys().cloned().map(Some))
+                .collect(),
+        };
+
+        for group in &schema_groups {
+            if scope.includes_nodes() {
+                change_schema(&mut schema, group.as_ref(), true);
+            }
+            if scope.includes_edges() {
+                change_schema(&mut schema, group.as_ref(), false);
+            }
+        }
+
+        let mut node_changes = Vec::new();
+
+        for node_index in node_indices {
+            if let Some(attributes) = change_attributes(self.node_attributes(&node_index)?)? {
+                node_changes.push((node_index, attributes));
+            }
+        }
+
+        let mut edge_changes = Vec::new();
+
+        for edge_index in edge_indices {
+            if let Some(attributes) = change_attributes(self.edge_attributes(&edge_index)?)? {
+                edge_changes.push((edge_index, attributes));
+            }
+        }
+
+        self.apply_attribute_changes(schema, node_changes, edge_changes, changed)
+    }
+
+    /// Replaces the attributes of the nodes and edges in `node_changes` and
+    /// `edge_changes` and the schema with `schema`. The new attributes are
+    /// checked against `schema` for every group the nodes and edges belong to
+    /// before anything is modified, so either every change is applied or none.
+    /// The `changed` attributes are tagged with the active provenance tag.
+    pub(super) fn apply_attribute_changes(
+        &mut self,
+        mut schema: Schema,
+        node_changes: Vec<(NodeIndex, AttributeMap)>,
+        edge_changes: Vec<(EdgeIndex, AttributeMap)>,
+        changed: &[GraphRecordAttribute],
+    ) -> GraphRecordResult<()> {
+        for (node_index, attributes) in &node_changes {
+            let groups: Vec<_> = self.groups_of_node(node_index)?.cloned().collect();
+
+            if groups.is_empty() {
+                Self::check_node_schema(&mut schema, node_index, attributes, None)?;
+            }
+            for group in &groups {
+                Self::check_node_schema(&mut schema, node_index, attributes, Some(group))?;
+            }
+        }
+
+        for (edge_index, attributes) in &edge_changes {
+            let groups: Vec<_> = self.groups_of_edge(edge_index)?.cloned().collect();
+
+            if groups.is_empty() {
+                Self::check_edge_schema(&mut schema, edge_index, attributes, None)?;
+            }
+            for group in &groups {
+                Self::check_edge_schema(&mut schema, edge_index, attributes, Some(group))?;
+            }
+        }
+
+        self.schema = schema;
+        self.subscribers.notify(|| ChangeEvent::SchemaChanged);
+
+        for (node_index, attributes) in node_changes {
+            if let Some(provenance) = &mut self.provenance {
+                provenance.tag_node(&node_index, &attributes, changed);
+            }
+
+            *self.graph.node_attributes_mut(&node_index)? = attributes;
+
+            self.subscribers
+                .notify(|| ChangeEvent::NodeAttributesChanged { node_index });
+        }
+
+        for (edge_index, attributes) in edge_changes {
+            if let Some(provenance) = &mut self.provenance {
+                provenance.tag_edge(&edge_index, &attributes, changed);
+            }
+
+            *self.graph.edge_attributes_mut(&edge_index)? = attributes;
+
+            self.subscribers
+                .notify(|| ChangeEvent::EdgeAttributesChanged { edge_index });
+        }
+
+        Ok(())
+    }
+
+    fn check_node_schema(
+        schema: &mut Schema,
+        node_index: &NodeIndex,
+        attributes: &AttributeMap,
+        group: Option<&Group>,
+    ) -> GraphRecordResult<()> {
+        match schema.schema_type() {
+            SchemaType::Inferred => schema.update_node(attributes, group, false),
+            SchemaType::Provided => schema.validate_node(node_index, attributes, group)?,
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    fn check_edge_schema(
+        schema: &mut Schema,
+        edge_index: &EdgeIndex,
+        attributes: &AttributeMap,
+        group: Option<&Group>,
+    ) -> GraphRecordResult<()> {
+        match schema.schema_type() {
+            SchemaType::Inferred => schema.update_edge(attributes, group, false),
+            SchemaType::Provided => schema.validate_edge(edge_index, attributes, group)?,
+        }
+
+        Ok(())
+    }
+
+    /// Renames `attribute` to `new_attribute` on every node and edge in
+    /// `scope` that has it, and in the schemas of `scope`. Fails without
+    /// changing anything if a node or edge already has `new_attribute`, or if
+    /// the renamed attributes do not match the schema of another group the
+    /// nodes and edges belong to.
+    #[allow(clippy::needless_pass_by_value)]
+    #[expect(clippy::missing_panics_doc, reason = "infallible")]
+    pub fn rename_attribute(
+        &mut self,
+        scope: &AttributeScope,
+        attribute: &GraphRecordAttribute,
+        new_attribute: GraphRecordAttribute,
+    ) -> GraphRecordResult<()> {
+        if attribute == &new_attribute {
+            return self.assert_not_frozen();
+        }
+
+        self.change_attributes_in_scope(
+            scope,
+            |attributes| {
+                if !attributes.contains_key(attribute) {
+                    return Ok(None);
+                }
+
+                if attributes.contains_key(&new_attribute) {
+                    return Err(GraphRecordError::AssertionError(format!(
+                        "Cannot rename attribute {attribute} to {new_attribute}, because {new_attribute} already exists"
+                    )));
+                }
+
+                let mut attributes = attributes.clone();
+                let value = attributes
+                    .remove(attribute)
+                    .expect("Attribute must exist");
+                attributes.insert(new_attribute.clone(), value);
+
+                Ok(Some(attributes))
+            },
+            |schema, group, nodes| {
+                if nodes {
+                    schema.rename_node_attribute(attribute, new_attribute.clone(), group);
+                } else {
+                    schema.rename_edge_attribute(attribute, new_attribute.clone(), group);
+                }
+            },
+            std::slice::from_ref(&new_attribute),
+        )
+    }
+
+    /// Removes `attribute` from every node and edge in `scope` that has it,
+    /// and from the schemas of `scope`. Fails without changing anything if the
+    /// remaining attributes do not match the schema of another group the
+    /// nodes and edges belong to.
+    pub fn drop_attribute(
+        &mut self,
+        scope: &AttributeScope,
+        attribute: &GraphRecordAttribute,
+    ) -> GraphRecordResult<()> {
+        self.change_attributes_in_scope(
+            scope,
+            |attributes| {
+                if !attributes.contains_key(attribute) {
+                    return Ok(None);
+                }
+
+                let mut attributes = attributes.clone();
+                attributes.remove(attribute);
+
+                Ok(Some(attributes))
+            },
+            |schema, group, nodes| {
+                if nodes {
+                    schema.remove_node_attribute(attribute, group);
+                } else {
+                    schema.remove_edge_attribute(attribute, group);
+                }
+            },
+            &[],
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::AttributeScope;
+    use crate::{
+        GraphRecord,
+        errors::GraphRecordError,
+        graphrecord::{
+            GraphRecordValue,
+            datatypes::DataType,
+            schema::{AttributeType, GroupSchema, Schema},
+        },
+    };
+    use std::collections::HashMap;
+
+    fn create_graphrecord() -> GraphRecord {
+        let mut graphrecord = GraphRecord::from_tuples(
+            vec![
+                (0.into(), HashMap::from([("age".into(), 40.into())])),
+                (1.into(), HashMap::from([("age".into(), 50.into())])),
+                (2.into(), HashMap::from([("age".into(), 60.into())])),
+            ],
+            Some(vec![(
+                0.into(),
+                1.into(),
+                HashMap::from([("age".into(), 1.into())]),
+            )]),
+            None,
+        )
+        .unwrap();
+
+        graphrecord
+            .add_group("patients".into(), Some(vec![0.into(), 1.into()]), None)
+            .unwrap();
+
+        graphrecord
+    }
+
+    #[test]
+    fn test_rename_attribute() {
+        let mut graphrecord = create_graphrecord();
+
+        graphrecord
+            .rename_attribute(&AttributeScope::Nodes, &"age".into(), "years".into())
+            .unwrap();
+
+        assert_eq!(
+            Some(&GraphRecordValue::Int(40)),
+            graphrecord
+                .node_attributes(&0.into())
+                .unwrap()
+                .get(&"years".into())
+        );
+        assert!(
+            !graphrecord
+                .node_attributes(&2.into())
+                .unwrap()
+                .contains_key(&"age".into())
+        );
+        assert!(
+            graphrecord
+                .edge_attributes(&0)
+                .unwrap()
+                .contains_key(&"age".into())
+        );
+
+        let schema = graphrecord.get_schema();
+        assert!(schema.ungrouped().nodes().contains_key(&"years".into()));
+        assert!(!schema.ungrouped().nodes().contains_key(&"age".into()));
+        assert!(
+            schema
+                .group(&"patients".into())
+                .unwrap()
+                .nodes()
+                .contains_key(&"years".into())
+        );
+        assert!(schema.ungrouped().edges().contains_key(&"age".into()));
+    }
+
+    #[test]
+    fn test_rename_attribute_group() {
+        let mut graphrecord = create_graphrecord();
+
+        graphrecord
+            .rename_attribute(
+                &AttributeScope::Group("patients".into()),
+                &"age".into(),
+                "years".into(),
+            )
+            .unwrap();
+
+        assert!(
+            graphrecord
+                .node_attributes(&1.into())
+                .unwrap()
+                .contains_key(&"years".into())
+        );
+        assert!(
+            graphrecord
+                .node_attributes(&2.into())
+                .unwrap()
+                .contains_key(&"age".into())
+        );
+
+        assert!(
+            graphrecord
+                .rename_attribute(
+                    &AttributeScope::Group("visits".into()),
+                    &"age".into(),
+                    "years".into(),
+                )
+                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+        );
+    }
+
+    #[test]
+    fn test_invalid_rename_attribute() {
+        let mut graphrecord = create_graphrecord();
+
+        graphrecord
+            .node_attributes_mut(&2.into())
+            .unwrap()
+            .update_attribute(&"years".into(), 61.into())
+            .unwrap();
+
+        assert!(
+            graphrecord
+                .rename_attribute(&AttributeScope::All, &"age".into(), "years".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
+
+        assert!(
+            graphrecord
+                .node_attributes(&0.into())
+                .unwrap()
+                .contains_key(&"age".into())
+        );
+        assert!(
+            graphrecord
+                .get_schema()
+                .ungrouped()
+                .nodes()
+                .contains_key(&"age".into())
+        );
+    }
+
+    #[test]
+    fn test_drop_attribute() {
+        let mut graphrecord = create_graphrecord();
+
+        graphrecord
+            .drop_attribute(&AttributeScope::All, &"age".into())
+            .unwrap();
+
+        assert!(graphrecord.node_attributes(&0.into()).unwrap().is_empty());
+        assert!(graphrecord.edge_attributes(&0).unwrap().is_empty());
+        assert!(graphrecord.get_schema().ungrouped().nodes().is_empty());
+        assert!(
+            graphrecord
+                .get_schema()
+                .group(&"patients".into())
+                .unwrap()
+                .nodes()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_drop_attribute_provided_schema() {
+        let mut graphrecord = create_graphrecord();
+
+        let mut schema = Schema::new_provided(
+            HashMap::from([("patients".into(), GroupSchema::default())]),
+            GroupSchema::default(),
+        );
+        schema
+            .set_node_attribute(
+                &"age".into(),
+                DataType::Int,
+                AttributeType::Continuous,
+                Some(&"patients".into()),
+            )
+            .unwrap();
+        schema
+            .set_node_attribute(
+                &"age".into(),
+                DataType::Int,
+                AttributeType::Continuous,
+                None,
+            )
+            .unwrap();
+        schema
+            .set_edge_attribute(
+                &"age".into(),
+                DataType::Int,
+                AttributeType::Continuous,
+                None,
+            )
+            .unwrap();
+        graphrecord.set_schema(schema).unwrap();
+
+        graphrecord
+            .drop_attribute(&AttributeScope::Edges, &"age".into())
+            .unwrap();
+
+        assert!(graphrecord.edge_attributes(&0).unwrap().is_empty());
+        assert!(graphrecord.get_schema().ungrouped().edges().is_empty());
+        assert!(
+            graphrecord
+                .node_attributes(&0.into())
+                .unwrap()
+                .contains_key(&"age".into())
+        );
+    }
+}
@@ -1,8 +1,10 @@
 use crate::{
     GraphRecord,
     errors::{GraphRecordError, GraphRecordResult},
+    graphrecord::subscription::ChangeEvent,
     prelude::{
-        Attributes, EdgeIndex, GraphRecordAttribute, GraphRecordValue, Group, NodeIndex, SchemaType,
+        AttributeMap, Attributes, EdgeIndex, GraphRecordAttribute, GraphRecordValue, Group,
+        NodeIndex, SchemaType,
     },
 };
 
@@ -17,7 +19,9 @@ macro_rules! impl_attributes_mut {
         $get_attributes_fn:ident,
         $get_attributes_mut_fn:ident,
         $schema_update_fn:ident,
-        $schema_validate_fn:ident
+        $schema_validate_fn:ident,
+        $provenance_tag_fn:ident,
+        $changed_event:expr
     ) => {
         pub struct $struct_name<'a> {
             $index_field: &'a $index_type,
@@ -29,6 +33,8 @@ macro_rules! impl_attributes_mut {
                 $index_field: &'a $index_type,
                 graphrecord: &'a mut GraphRecord,
             ) -> GraphRecordResult<Self> {
+                graphrecord.assert_not_frozen()?;
+
                 if !graphrecord.$contains_fn($index_field) {
                     return Err(GraphRecordError::IndexError(format!(
                         concat!("Cannot find ", $entity, " with index {}"),
@@ -52,11 +58,13 @@ macro_rules! impl_attributes_mut {
 
             fn handle_schema(
                 &mut self,
-                attributes: &Attributes,
+                attributes: &mut AttributeMap,
                 groups: &[Group],
             ) -> GraphRecordResult<()> {
                 let schema = &mut self.graphrecord.schema;
 
+                schema.handle_non_finite_floats(attributes)?;
+
                 match schema.schema_type() {
                     SchemaType::Inferred => {
                         if groups.is_empty() {
@@ -85,17 +93,33 @@ macro_rules! impl_attributes_mut {
                 Ok(())
             }
 
-            fn set_attributes(&mut self, attributes: Attributes) {
+            fn tag_attributes<'b>(
+                &mut self,
+                attributes: &AttributeMap,
+                changed: impl IntoIterator<Item = &'b GraphRecordAttribute>,
+            ) {
+                if let Some(provenance) = &mut self.graphrecord.provenance {
+                    provenance.$provenance_tag_fn(self.$index_field, attributes, changed);
+                }
+            }
+
+            fn set_attributes(&mut self, attributes: AttributeMap) {
                 *self
                     .graphrecord
                     .graph
                     .$get_attributes_mut_fn(self.$index_field)
                     .expect(concat!($entity, " must exist.")) = attributes;
+
+                self.graphrecord
+                    .subscribers
+                    .notify(|| ($changed_event)(self.$index_field));
             }
 
             pub fn replace_attributes(&mut self, attributes: Attributes) -> GraphRecordResult<()> {
+                let mut attributes = AttributeMap::from(attributes);
                 let groups = self.get_groups();
-                self.handle_schema(&attributes, &groups)?;
+                self.handle_schema(&mut attributes, &groups)?;
+                self.tag_attributes(&attributes, attributes.keys());
                 self.set_attributes(attributes);
                 Ok(())
             }
@@ -112,12 +136,10 @@ macro_rules! impl_attributes_mut {
                     .$get_attributes_fn(self.$index_field)
                     .expect(concat!($entity, " must exist."))
                     .clone();
-                attributes
-                    .entry(attribute.clone())
-                    .and_modify(|v| *v = value.clone())
-                    .or_insert(value);
+                attributes.insert(attribute.clone(), value);
 
-                self.handle_schema(&attributes, &groups)?;
+                self.handle_schema(&mut attributes, &groups)?;
+                self.tag_attributes(&attributes, [attribute]);
                 self.set_attributes(attributes);
                 Ok(())
             }
@@ -142,7 +164,8 @@ macro_rules! impl_attributes_mut {
                     )));
                 };
 
-                self.handle_schema(&attributes, &groups)?;
+                self.handle_schema(&mut attributes, &groups)?;
+                self.tag_attributes(&attributes, std::iter::empty());
                 self.set_attributes(attributes);
                 Ok(removed_value)
             }
@@ -160,7 +183,11 @@ impl_attributes_mut!(
     node_attributes,
     node_attributes_mut,
     update_node,
-    validate_node
+    validate_node,
+    tag_node,
+    |node_index: &NodeIndex| ChangeEvent::NodeAttributesChanged {
+        node_index: node_index.clone()
+    }
 );
 
 impl_attributes_mut!(
@@ -173,5 +200,9 @@ impl_attributes_mut!(
     edge_attributes,
     edge_attributes_mut,
     update_edge,
-    validate_edge
+    validate_edge,
+    tag_edge,
+    |edge_index: &EdgeIndex| ChangeEvent::EdgeAttributesChanged {
+        edge_index: *edge_index
+    }
 );
@@ -0,0 +1,176 @@
+use super::{NodeIndex, graph::Graph};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Format of the node indices generated by [`super::GraphRecord::add_node_auto`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AutoIndexFormat {
+    /// Integer indices `0`, `1`, `2`, ...
+    #[default]
+    Numeric,
+    /// String indices made of the prefix and a number, e.g. `patient_0`.
+    Prefixed(String),
+}
+
+impl AutoIndexFormat {
+    fn node_index(&self, number: i64) -> NodeIndex {
+        match self {
+            Self::Numeric => number.into(),
+            Self::Prefixed(prefix) => format!("{prefix}{number}").into(),
+        }
+    }
+}
+
+/// Sequence of generated node indices.
+///
+/// The counter only moves forward, so indices of removed nodes are never
+/// generated again. Indices that are already taken are skipped.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub(super) struct NodeIndexSequence {
+    format: AutoIndexFormat,
+    next: i64,
+}
+
+impl NodeIndexSequence {
+    pub(super) const fn format(&self) -> &AutoIndexFormat {
+        &self.format
+    }
+
+    pub(super) fn set_format(&mut self, format: AutoIndexFormat) {
+        self.format = format;
+    }
+
+    #[cfg(feature = "serde")]
+    pub(super) const fn is_default(&self) -> bool {
+        matches!(self.format, AutoIndexFormat::Numeric) && self.next == 0
+    }
+
+    /// Returns the next unused node index along with the counter following
+    /// it, without advancing the sequence.
+    pub(super) fn peek(&self, graph: &Graph) -> (NodeIndex, i64) {
+        self.peek_from(graph, self.next)
+    }
+
+    /// Like [`NodeIndexSequence::peek`], but for `count` node indices.
+    pub(super) fn peek_many(&self, graph: &Graph, count: usize) -> (Vec<NodeIndex>, i64) {
+        let mut node_indices = Vec::with_capacity(count);
+        let mut next = self.next;
+
+        for _ in 0..count {
+            let (node_index, following) = self.peek_from(graph, next);
+
+            node_indices.push(node_index);
+            next = following;
+        }
+
+        (node_indices, next)
+    }
+
+    fn peek_from(&self, graph: &Graph, mut next: i64) -> (NodeIndex, i64) {
+        loop {
+            let node_index = self.format.node_index(next);
+            next += 1;
+
+            if !graph.contains_node(&node_index) {
+                return (node_index, next);
+            }
+        }
+    }
+
+    pub(super) const fn advance(&mut self, next: i64) {
+        self.next = next;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::AutoIndexFormat;
+    use crate::{GraphRecord, graphrecord::NodeIndex};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_add_node_auto() {
+        let mut graphrecord = GraphRecord::new();
+
+        graphrecord.add_node(1.into(), HashMap::new()).unwrap();
+
+        assert_eq!(
+            NodeIndex::from(0),
+            graphrecord.add_node_auto(HashMap::new()).unwrap()
+        );
+
+        // Taken indices are skipped
+        assert_eq!(
+            NodeIndex::from(2),
+            graphrecord.add_node_auto(HashMap::new()).unwrap()
+        );
+
+        // Indices of removed nodes are not generated again
+        graphrecord.remove_node(&2.into()).unwrap();
+
+        assert_eq!(
+            vec![NodeIndex::from(3), NodeIndex::from(4)],
+            graphrecord
+                .add_nodes_auto(vec![HashMap::new(), HashMap::new()])
+                .unwrap()
+        );
+
+        graphrecord
+            .set_auto_index_format(AutoIndexFormat::Prefixed("patient_".to_string()))
+            .unwrap();
+
+        assert_eq!(
+            NodeIndex::from("patient_5"),
+            graphrecord
+                .add_node_auto(HashMap::from([("age".into(), 42.into())]))
+                .unwrap()
+        );
+        assert_eq!(
+            &HashMap::from([("age".into(), 42.into())]),
+            graphrecord.node_attributes(&"patient_5".into()).unwrap()
+        );
+        assert_eq!(5, graphrecord.node_count());
+    }
+
+    #[test]
+    fn test_invalid_add_node_auto() {
+        let mut graphrecord = GraphRecord::new();
+
+        graphrecord.freeze();
+
+        assert!(graphrecord.add_node_auto(HashMap::new()).is_err());
+
+        graphrecord.unfreeze();
+
+        // Failed additions do not advance the sequence
+        assert_eq!(
+            NodeIndex::from(0),
+            graphrecord.add_node_auto(HashMap::new()).unwrap()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_auto_index_serde() {
+        let mut graphrecord = GraphRecord::new();
+
+        graphrecord
+            .set_auto_index_format(AutoIndexFormat::Prefixed("patient_".to_string()))
+            .unwrap();
+        let node_index = graphrecord.add_node_auto(HashMap::new()).unwrap();
+        graphrecord.remove_node(&node_index).unwrap();
+
+        let mut restored = GraphRecord::from_bytes(&graphrecord.to_bytes().unwrap()).unwrap();
+
+        assert_eq!(
+            &AutoIndexFormat::Prefixed("patient_".to_string()),
+            restored.auto_index_format()
+        );
+        assert_eq!(
+            NodeIndex::from("patient_1"),
+            restored.add_node_auto(HashMap::new()).unwrap()
+        );
+    }
+}
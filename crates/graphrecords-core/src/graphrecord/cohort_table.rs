@@ -0,0 +1,567 @@
+use super::{
+    GraphRecord, GraphRecordAttribute, GraphRecordValue, Group,
+    association::{ChiSquareTest, welch_t_test},
+};
+use crate::errors::{GraphRecordError, GraphRecordResult};
+use polars::{frame::DataFrame, prelude::Column};
+
+/// Summary of the values of a numeric attribute within one group. The
+/// standard deviation is the sample standard deviation, or 0 for a single
+/// value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumericSummary {
+    pub count: usize,
+    pub mean: f64,
+    pub std: f64,
+    pub median: f64,
+}
+
+impl NumericSummary {
+    fn new(values: &[f64]) -> Option<Self> {
+        if values.is_empty() {
+            return None;
+        }
+
+        let count = values.len();
+        let mean = values.iter().sum::<f64>() / count as f64;
+        let std = if count > 1 {
+            (values
+                .iter()
+                .map(|value| (value - mean).powi(2))
+                .sum::<f64>()
+                / (count - 1) as f64)
+                .sqrt()
+        } else {
+            0.0
+        };
+
+        let mut sorted = values.to_vec();
+        sorted.sort_by(f64::total_cmp);
+
+        let median = if count.is_multiple_of(2) {
+            f64::midpoint(sorted[count / 2 - 1], sorted[count / 2])
+        } else {
+            sorted[count / 2]
+        };
+
+        Some(Self {
+            count,
+            mean,
+            std,
+            median,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CategoryCounts {
+    pub value: GraphRecordValue,
+    pub count_a: usize,
+    pub count_b: usize,
+}
+
+/// Comparison of one attribute between the two groups of a `CohortTable`.
+///
+/// Numeric attributes are compared with Welch's t-test, categorical ones with
+/// a chi-square test. The p-value is `None` if the test is undefined for the
+/// data. `missing_a` and `missing_b` count the nodes of each group without a
+/// value of the attribute.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Characteristic {
+    Numeric {
+        summary_a: Option<NumericSummary>,
+        summary_b: Option<NumericSummary>,
+        p_value: Option<f64>,
+        missing_a: usize,
+        missing_b: usize,
+    },
+    Categorical {
+        categories: Vec<CategoryCounts>,
+        p_value: Option<f64>,
+        missing_a: usize,
+        missing_b: usize,
+    },
+}
+
+/// Baseline characteristics of two node groups, as reported in the first
+/// table of most clinical studies.
+///
+/// An attribute is numeric if all of its values in both groups are integers
+/// or floats, and categorical otherwise. Nodes that lack an attribute or have
+/// a null or missing value of it are left out of its characteristic and
+/// counted as missing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CohortTable {
+    pub group_a: Group,
+    pub group_b: Group,
+    pub size_a: usize,
+    pub size_b: usize,
+    pub characteristics: Vec<(GraphRecordAttribute, Characteristic)>,
+}
+
+fn attribute_values<'a>(
+    graphrecord: &'a GraphRecord,
+    group: &Group,
+    attribute: &GraphRecordAttribute,
+) -> GraphRecordResult<Vec<&'a GraphRecordValue>> {
+    Ok(graphrecord
+        .nodes_in_group(group)?
+        .filter_map(|node_index| {
+            graphrecord
+                .node_attributes(node_index)
+                .expect("Node must exist")
+                .get(attribute)
+        })
+        .collect())
+}
+
+impl Characteristic {
+    fn new(
+        values_a: &[&GraphRecordValue],
+        values_b: &[&GraphRecordValue],
+        size_a: usize,
+        size_b: usize,
+    ) -> Self {
+        fn recorded<'a>(values: &[&'a GraphRecordValue]) -> Vec<&'a GraphRecordValue> {
+            values
+                .iter()
+                .filter(|value| !value.is_null() && !value.is_missing())
+                .copied()
+                .collect()
+        }
+
+        let values_a = &recorded(values_a)[..];
+        let values_b = &recorded(values_b)[..];
+        let missing_a = size_a - values_a.len();
+        let missing_b = size_b - values_b.len();
+
+        let is_numeric = !(values_a.is_empty() && values_b.is_empty())
+            && values_a.iter().chain(values_b).all(|value| {
+                matches!(value, GraphRecordValue::Int(_) | GraphRecordValue::Float(_))
+            });
+
+        if is_numeric {
+            let to_numbers = |values: &[&GraphRecordValue]| -> Vec<f64> {
+                values
+                    .iter()
+                    .map(|value| f64::try_from((*value).clone()).expect("Value must be numeric"))
+                    .collect()
+            };
+
+            let numbers_a = to_numbers(values_a);
+            let numbers_b = to_numbers(values_b);
+
+            return Self::Numeric {
+                summary_a: NumericSummary::new(&numbers_a),
+                summary_b: NumericSummary::new(&numbers_b),
+                p_value: welch_t_test(&numbers_a, &numbers_b),
+                missing_a,
+                missing_b,
+            };
+        }
+
+        let mut values: Vec<_> = values_a.iter().chain(values_b).copied().collect();
+        values.sort_by(|a, b| a.total_cmp(b));
+        values.dedup();
+
+        let count = |values: &[&GraphRecordValue], category: &GraphRecordValue| {
+            values.iter().filter(|value| **value == category).count()
+        };
+
+        let categories: Vec<_> = values
+            .into_iter()
+            .map(|value| CategoryCounts {
+                value: value.clone(),
+                count_a: count(values_a, value),
+                count_b: count(values_b, value),
+            })
+            .collect();
+
+        let counts: [Vec<f64>; 2] = [
+            categories
+                .iter()
+                .map(|category| category.count_a as f64)
+                .collect(),
+            categories
+                .iter()
+                .map(|category| category.count_b as f64)
+                .collect(),
+        ];
+        let p_value = ChiSquareTest::from_counts(&counts).map(|test| test.p_value);
+
+        Self::Categorical {
+            categories,
+            p_value,
+            missing_a,
+            missing_b,
+        }
+    }
+}
+
+impl CohortTable {
+    pub(crate) fn new(
+        graphrecord: &GraphRecord,
+        group_a: &Group,
+        group_b: &Group,
+        attributes: &[GraphRecordAttribute],
+    ) -> GraphRecordResult<Self> {
+        if group_a == group_b {
+            return Err(GraphRecordError::AssertionError(format!(
+                "Cannot compare group {group_a} with itself"
+            )));
+        }
+
+        let size_a = graphrecord.nodes_in_group(group_a)?.count();
+        let size_b = graphrecord.nodes_in_group(group_b)?.count();
+
+        let characteristics = attributes
+            .iter()
+            .map(|attribute| {
+                let values_a = attribute_values(graphrecord, group_a, attribute)?;
+                let values_b = attribute_values(graphrecord, group_b, attribute)?;
+
+                Ok((
+                    attribute.clone(),
+                    Characteristic::new(&values_a, &values_b, size_a, size_b),
+                ))
+            })
+            .collect::<GraphRecordResult<_>>()?;
+
+        Ok(Self {
+            group_a: group_a.clone(),
+            group_b: group_b.clone(),
+            size_a,
+            size_b,
+            characteristics,
+        })
+    }
+
+    /// Renders the table as a `DataFrame` with the columns `attribute`,
+    /// `statistic`, one column per group named after it and `p_value`.
+    ///
+    /// Numeric attributes take a `mean ± std` and a `median` row, categorical
+    /// ones a row per value with its count and the share of the group's nodes
+    /// that have the attribute. Attributes with missing values take a
+    /// `missing` row with their count and share of the group. The p-value is
+    /// on the first row of an attribute.
+    pub fn to_dataframe(&self) -> GraphRecordResult<DataFrame> {
+        let mut attributes = vec!["n".to_string()];
+        let mut statistics = vec!["count".to_string()];
+        let mut cells_a = vec![self.size_a.to_string()];
+        let mut cells_b = vec![self.size_b.to_string()];
+        let mut p_values = vec![None];
+
+        let format_summary = |summary: Option<&NumericSummary>, median: bool| {
+            summary.map_or_else(String::new, |summary| {
+                if median {
+                    format!("{:.2}", summary.median)
+                } else {
+                    format!("{:.2} ± {:.2}", summary.mean, summary.std)
+                }
+            })
+        };
+
+        let format_count = |count: usize, total: usize| {
+            if total == 0 {
+                count.to_string()
+            } else {
+                format!("{count} ({:.1}%)", 100.0 * count as f64 / total as f64)
+            }
+        };
+
+        for (attribute, characteristic) in &self.characteristics {
+            let (Characteristic::Numeric {
+                missing_a,
+                missing_b,
+                ..
+            }
+            | Characteristic::Categorical {
+                missing_a,
+                missing_b,
+                ..
+            }) = characteristic;
+
+            match characteristic {
+                Characteristic::Numeric {
+                    summary_a,
+                    summary_b,
+                    p_value,
+                    ..
+                } => {
+                    for (statistic, median) in [("mean ± std", false), ("median", true)] {
+                        attributes.push(attribute.to_string());
+                        statistics.push(statistic.to_string());
+                        cells_a.push(format_summary(summary_a.as_ref(), median));
+                        cells_b.push(format_summary(summary_b.as_ref(), median));
+                        p_values.push(if median { None } else { *p_value });
+                    }
+                }
+                Characteristic::Categorical {
+                    categories,
+                    p_value,
+                    ..
+                } => {
+                    let total_a: usize = categories.iter().map(|category| category.count_a).sum();
+                    let total_b: usize = categories.iter().map(|category| category.count_b).sum();
+
+                    for (position, category) in categories.iter().enumerate() {
+                        attributes.push(attribute.to_string());
+                        statistics.push(category.value.to_string());
+                        cells_a.push(format_count(category.count_a, total_a));
+                        cells_b.push(format_count(category.count_b, total_b));
+                        p_values.push(if position == 0 { *p_value } else { None });
+                    }
+                }
+            }
+
+            if *missing_a > 0 || *missing_b > 0 {
+                attributes.push(attribute.to_string());
+                statistics.push("missing".to_string());
+                cells_a.push(format_count(*missing_a, self.size_a));
+                cells_b.push(format_count(*missing_b, self.size_b));
+                p_values.push(None);
+            }
+        }
+
+        DataFrame::new_infer_height(vec![
+            Column::new("attribute".into(), attributes),
+            Column::new("statistic".into(), statistics),
+            Column::new(self.group_a.to_string().into(), cells_a),
+            Column::new(self.group_b.to_string().into(), cells_b),
+            Column::new("p_value".into(), p_values),
+        ])
+        .map_err(|error| {
+            GraphRecordError::ConversionError(format!(
+                "Failed to create cohort table DataFrame: {error}"
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CategoryCounts, Characteristic, NumericSummary};
+    use crate::{GraphRecord, errors::GraphRecordError, graphrecord::GraphRecordValue};
+    use polars::prelude::PlSmallStr;
+    use std::collections::HashMap;
+
+    fn create_graphrecord() -> GraphRecord {
+        let patients: Vec<(i64, &str)> =
+            vec![(1, "F"), (2, "F"), (3, "M"), (3, "F"), (4, "M"), (5, "M")];
+
+        let mut graphrecord = GraphRecord::from_tuples(
+            patients
+                .into_iter()
+                .enumerate()
+                .map(|(index, (age, sex))| {
+                    (
+                        (index as i64).into(),
+                        HashMap::from([("age".into(), age.into()), ("sex".into(), sex.into())]),
+                    )
+                })
+                .collect(),
+            None,
+            None,
+        )
+        .unwrap();
+
+        graphrecord
+            .add_group(
+                "treated".into(),
+                Some((0..3).map(Into::into).collect()),
+                None,
+            )
+            .unwrap();
+        graphrecord
+            .add_group(
+                "control".into(),
+                Some((3..6).map(Into::into).collect()),
+                None,
+            )
+            .unwrap();
+
+        graphrecord
+    }
+
+    #[test]
+    fn test_cohort_table() {
+        let graphrecord = create_graphrecord();
+
+        let cohort_table = graphrecord
+            .cohort_table(
+                &"treated".into(),
+                &"control".into(),
+                &["age".into(), "sex".into(), "weight".into()],
+            )
+            .unwrap();
+
+        assert_eq!(3, cohort_table.size_a);
+        assert_eq!(3, cohort_table.size_b);
+
+        let Characteristic::Numeric {
+            summary_a,
+            summary_b,
+            p_value,
+            ..
+        } = &cohort_table.characteristics[0].1
+        else {
+            panic!("Age must be numeric");
+        };
+
+        assert_eq!(
+            Some(NumericSummary {
+                count: 3,
+                mean: 2.0,
+                std: 1.0,
+                median: 2.0,
+            }),
+            *summary_a
+        );
+        assert_eq!(
+            Some(NumericSummary {
+                count: 3,
+                mean: 4.0,
+                std: 1.0,
+                median: 4.0,
+            }),
+            *summary_b
+        );
+        assert!((p_value.unwrap() - 0.070_483_996_910_22).abs() < 1e-9);
+
+        let Characteristic::Categorical {
+            categories,
+            p_value,
+            ..
+        } = &cohort_table.characteristics[1].1
+        else {
+            panic!("Sex must be categorical");
+        };
+
+        assert_eq!(
+            &vec![
+                CategoryCounts {
+                    value: GraphRecordValue::from("F"),
+                    count_a: 2,
+                    count_b: 1,
+                },
+                CategoryCounts {
+                    value: GraphRecordValue::from("M"),
+                    count_a: 1,
+                    count_b: 2,
+                },
+            ],
+            categories
+        );
+        assert!(p_value.is_some());
+
+        assert_eq!(
+            Characteristic::Categorical {
+                categories: Vec::new(),
+                p_value: None,
+                missing_a: 3,
+                missing_b: 3,
+            },
+            cohort_table.characteristics[2].1
+        );
+
+        let dataframe = cohort_table.to_dataframe().unwrap();
+
+        assert_eq!(
+            vec!["attribute", "statistic", "treated", "control", "p_value"],
+            dataframe
+                .get_column_names()
+                .into_iter()
+                .map(PlSmallStr::as_str)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(6, dataframe.height());
+        assert_eq!(
+            Some("2.00 ± 1.00"),
+            dataframe.column("treated").unwrap().str().unwrap().get(1)
+        );
+        assert_eq!(
+            Some("1 (33.3%)"),
+            dataframe.column("treated").unwrap().str().unwrap().get(4)
+        );
+    }
+
+    #[test]
+    fn test_cohort_table_missing_values() {
+        let mut graphrecord = create_graphrecord();
+
+        for (node_index, value) in [(0, GraphRecordValue::Null), (4, GraphRecordValue::Missing)] {
+            graphrecord
+                .node_attributes_mut(&node_index.into())
+                .unwrap()
+                .update_attribute(&"age".into(), value.clone())
+                .unwrap();
+            graphrecord
+                .node_attributes_mut(&node_index.into())
+                .unwrap()
+                .update_attribute(&"sex".into(), value)
+                .unwrap();
+        }
+
+        let cohort_table = graphrecord
+            .cohort_table(
+                &"treated".into(),
+                &"control".into(),
+                &["age".into(), "sex".into()],
+            )
+            .unwrap();
+
+        let Characteristic::Numeric {
+            summary_a,
+            missing_a,
+            missing_b,
+            ..
+        } = &cohort_table.characteristics[0].1
+        else {
+            panic!("Age must be numeric");
+        };
+
+        assert_eq!(2, summary_a.unwrap().count);
+        assert_eq!((1, 1), (*missing_a, *missing_b));
+
+        let Characteristic::Categorical {
+            categories,
+            missing_a,
+            missing_b,
+            ..
+        } = &cohort_table.characteristics[1].1
+        else {
+            panic!("Sex must be categorical");
+        };
+
+        assert_eq!(2, categories.len());
+        assert_eq!((1, 1), (*missing_a, *missing_b));
+
+        let dataframe = cohort_table.to_dataframe().unwrap();
+
+        assert_eq!(7, dataframe.height());
+        assert_eq!(
+            Some("missing"),
+            dataframe.column("statistic").unwrap().str().unwrap().get(3)
+        );
+        assert_eq!(
+            Some("1 (33.3%)"),
+            dataframe.column("control").unwrap().str().unwrap().get(3)
+        );
+    }
+
+    #[test]
+    fn test_invalid_cohort_table() {
+        let graphrecord = create_graphrecord();
+
+        assert!(
+            graphrecord
+                .cohort_table(&"treated".into(), &"visits".into(), &["age".into()])
+                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+        );
+        assert!(
+            graphrecord
+                .cohort_table(&"treated".into(), &"treated".into(), &["age".into()])
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
+    }
+}
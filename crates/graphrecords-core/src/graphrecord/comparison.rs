@@ -0,0 +1,270 @@
+use super::{AttributeMap, GraphRecord, Group, NodeIndex};
+use graphrecords_utils::aliases::{GrHashMap, GrHashSet};
+
+struct EdgeContent<'a> {
+    attributes: &'a AttributeMap,
+    groups: GrHashSet<&'a Group>,
+}
+
+/// Groups the edges of `graphrecord` by their endpoints and direction. The
+/// endpoints of undirected edges are ordered, so an undirected edge matches
+/// one between the same nodes in either direction.
+fn edges_by_endpoints(
+    graphrecord: &GraphRecord,
+) -> GrHashMap<(&NodeIndex, &NodeIndex, bool), Vec<EdgeContent<'_>>> {
+    let mut edges: GrHashMap<_, Vec<_>> = GrHashMap::default();
+
+    for edge_index in graphrecord.edge_indices() {
+        let (source_node_index, target_node_index) = graphrecord
+            .edge_endpoints(edge_index)
+            .expect("Edge must exist");
+        let undirected = graphrecord
+            .is_edge_undirected(edge_index)
+            .expect("Edge must exist");
+
+        let key = if undirected && target_node_index.total_cmp(source_node_index).is_lt() {
+            (target_node_index, source_node_index, undirected)
+        } else {
+            (source_node_index, target_node_index, undirected)
+        };
+
+        edges.entry(key).or_default().push(EdgeContent {
+            attributes: graphrecord
+                .edge_attributes(edge_index)
+                .expect("Edge must exist"),
+            groups: graphrecord
+                .groups_of_edge(edge_index)
+                .expect("Edge must exist")
+                .collect(),
+        });
+    }
+
+    edges
+}
+
+/// Checks that every node of `graphrecord` exists in `other` with equal
+/// attributes. Group memberships must be equal if `exact` is set and included
+/// otherwise.
+fn nodes_included(graphrecord: &GraphRecord, other: &GraphRecord, exact: bool) -> bool {
+    graphrecord.node_indices().all(|node_index| {
+        let Ok(other_attributes) = other.node_attributes(node_index) else {
+            return false;
+        };
+
+        if graphrecord.node_attributes(node_index).ok() != Some(other_attributes) {
+            return false;
+        }
+
+        let groups: GrHashSet<_> = graphrecord
+            .groups_of_node(node_index)
+            .expect("Node must exist")
+            .collect();
+        let other_groups: GrHashSet<_> = other
+            .groups_of_node(node_index)
+            .expect("Node must exist")
+            .collect();
+
+        if exact {
+            groups == other_groups
+        } else {
+            groups.is_subset(&other_groups)
+        }
+    })
+}
+
+/// Matches every edge of `graphrecord` to a distinct edge of `other` with the
+/// same endpoints, direction and attributes. Group memberships must be equal if `exact`
+/// is set and included otherwise.
+fn edges_included(graphrecord: &GraphRecord, other: &GraphRecord, exact: bool) -> bool {
+    let mut candidates = edges_by_endpoints(other);
+
+    for (endpoints, edges) in edges_by_endpoints(graphrecord) {
+        let Some(other_edges) = candidates.get_mut(&endpoints) else {
+            return false;
+        };
+
+        for edge in edges {
+            let position = other_edges.iter().position(|other_edge| {
+                other_edge.attributes == edge.attributes
+                    && if exact {
+                        other_edge.groups == edge.groups
+                    } else {
+                        edge.groups.is_subset(&other_edge.groups)
+                    }
+            });
+
+            let Some(position) = position else {
+                return false;
+            };
+
+            other_edges.swap_remove(position);
+        }
+    }
+
+    true
+}
+
+pub(super) fn equals(graphrecord: &GraphRecord, other: &GraphRecord) -> bool {
+    if graphrecord.node_count() != other.node_count()
+        || graphrecord.edge_count() != other.edge_count()
+        || graphrecord.group_count() != other.group_count()
+    {
+        return false;
+    }
+
+    graphrecord
+        .groups()
+        .all(|group| other.contains_group(group))
+        && nodes_included(graphrecord, other, true)
+        && edges_included(graphrecord, other, true)
+}
+
+pub(super) fn is_subgraph_of(graphrecord: &GraphRecord, other: &GraphRecord) -> bool {
+    if graphrecord.node_count() > other.node_count()
+        || graphrecord.edge_count() > other.edge_count()
+    {
+        return false;
+    }
+
+    graphrecord
+        .groups()
+        .all(|group| other.contains_group(group))
+        && nodes_included(graphrecord, other, false)
+        && edges_included(graphrecord, other, false)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::GraphRecord;
+    use std::collections::HashMap;
+
+    fn create_graphrecord() -> GraphRecord {
+        let mut graphrecord = GraphRecord::new();
+
+        graphrecord
+            .add_node(
+                "0".into(),
+                HashMap::from([("lorem".into(), "ipsum".into())]),
+            )
+            .unwrap();
+        graphrecord.add_node("1".into(), HashMap::new()).unwrap();
+        graphrecord
+            .add_edge("0".into(), "1".into(), HashMap::new())
+            .unwrap();
+        graphrecord
+            .add_edge(
+                "0".into(),
+                "1".into(),
+                HashMap::from([("dolor".into(), "sit".into())]),
+            )
+            .unwrap();
+        graphrecord
+            .add_group("group".into(), Some(vec!["0".into()]), None)
+            .unwrap();
+
+        graphrecord
+    }
+
+    #[test]
+    fn test_equals() {
+        let graphrecord = create_graphrecord();
+
+        assert!(graphrecord.equals(&graphrecord.clone()));
+
+        // Edge indices are ignored
+        let mut renumbered = graphrecord.clone();
+        let edge_index = renumbered.edge_indices().copied().min().unwrap();
+        renumbered.remove_edge(&edge_index).unwrap();
+        renumbered
+            .add_edge("0".into(), "1".into(), HashMap::new())
+            .unwrap();
+        assert!(graphrecord.equals(&renumbered));
+
+        let mut modified = graphrecord.clone();
+        modified
+            .remove_node_from_group(&"group".into(), &"0".into())
+            .unwrap();
+        assert!(!graphrecord.equals(&modified));
+
+        let mut modified = graphrecord.clone();
+        modified
+            .node_attributes_mut(&"1".into())
+            .unwrap()
+            .update_attribute(&"lorem".into(), "ipsum".into())
+            .unwrap();
+        assert!(!graphrecord.equals(&modified));
+
+        // Undirected edges match in either direction, but not directed ones
+        let edge_index = *graphrecord.edge_indices().min().unwrap();
+        let mut undirected = graphrecord.clone();
+        undirected.set_edge_undirected(&edge_index, true).unwrap();
+        assert!(!graphrecord.equals(&undirected));
+
+        let mut reversed = graphrecord.clone();
+        reversed.remove_edge(&edge_index).unwrap();
+        let reversed_edge_index = reversed
+            .add_edge("1".into(), "0".into(), HashMap::new())
+            .unwrap();
+        assert!(!graphrecord.equals(&reversed));
+
+        reversed
+            .set_edge_undirected(&reversed_edge_index, true)
+            .unwrap();
+        assert!(undirected.equals(&reversed));
+    }
+
+    #[test]
+    fn test_is_subgraph_of() {
+        let graphrecord = create_graphrecord();
+
+        let mut subgraph = GraphRecord::new();
+        subgraph
+            .add_node(
+                "0".into(),
+                HashMap::from([("lorem".into(), "ipsum".into())]),
+            )
+            .unwrap();
+        subgraph.add_node("1".into(), HashMap::new()).unwrap();
+        subgraph
+            .add_edge(
+                "0".into(),
+                "1".into(),
+                HashMap::from([("dolor".into(), "sit".into())]),
+            )
+            .unwrap();
+
+        assert!(subgraph.is_subgraph_of(&graphrecord));
+        assert!(graphrecord.is_subgraph_of(&graphrecord));
+        assert!(!graphrecord.is_subgraph_of(&subgraph));
+
+        // Each edge can only be matched once
+        subgraph
+            .add_edge(
+                "0".into(),
+                "1".into(),
+                HashMap::from([("dolor".into(), "sit".into())]),
+            )
+            .unwrap();
+        assert!(!subgraph.is_subgraph_of(&graphrecord));
+
+        // Direction has to match, with undirected edges in either orientation
+        let mut subgraph = GraphRecord::new();
+        subgraph.add_node("0".into(), HashMap::new()).unwrap();
+        subgraph.add_node("1".into(), HashMap::new()).unwrap();
+        let edge_index = subgraph
+            .add_edge("1".into(), "0".into(), HashMap::new())
+            .unwrap();
+        subgraph.set_edge_undirected(&edge_index, true).unwrap();
+
+        let mut graphrecord = GraphRecord::new();
+        graphrecord.add_node("0".into(), HashMap::new()).unwrap();
+        graphrecord.add_node("1".into(), HashMap::new()).unwrap();
+        let edge_index = graphrecord
+            .add_edge("0".into(), "1".into(), HashMap::new())
+            .unwrap();
+        assert!(!subgraph.is_subgraph_of(&graphrecord));
+
+        graphrecord.set_edge_undirected(&edge_index, true).unwrap();
+        assert!(subgraph.is_subgraph_of(&graphrecord));
+    }
+}
@@ -0,0 +1,231 @@
+//! Concatenating records with disjoint nodes into one, e.g. the per-day
+//! records built by sharded ingestion.
+
+use super::{EdgeIndex, GraphRecord, graph::Graph};
+use crate::errors::GraphRecordResult;
+use std::collections::HashMap;
+
+impl GraphRecord {
+    /// Merges `records` into one record in a single pass.
+    ///
+    /// The records must not share any nodes. Their edges are renumbered in
+    /// the order of the records, so that edge indices of different records do
+    /// not collide. Groups that several records have are merged, and the
+    /// schemas are combined with [`Schema::union`](super::schema::Schema::union).
+    /// The settings and metadata of the first record are kept.
+    pub fn concat(records: Vec<Self>) -> GraphRecordResult<Self> {
+        if records.is_empty() {
+            return Ok(Self::new());
+        }
+
+        let node_count = records.iter().map(Self::node_count).sum();
+        let edge_count = records.iter().map(Self::edge_count).sum();
+
+        let mut graphrecord = Self {
+            graph: Graph::with_capacity(node_count, edge_count),
+            schema: records[0].schema.clone(),
+            metadata: records[0].metadata.clone(),
+            strict_queries: records[0].strict_queries,
+            float_tolerance: records[0].float_tolerance,
+            random_seed: records[0].random_seed,
+            ..Default::default()
+        };
+
+        for (position, mut record) in records.into_iter().enumerate() {
+            if position > 0 {
+                graphrecord.schema.union(&record.schema);
+            }
+
+            let node_indices: Vec<_> = record.node_indices().cloned().collect();
+
+            for node_index in node_indices {
+                let attributes = std::mem::take(record.graph.node_attributes_mut(&node_index)?);
+
+                graphrecord.graph.add_node(node_index, attributes)?;
+            }
+
+            let mut edge_indices: Vec<_> = record.edge_indices().copied().collect();
+            edge_indices.sort_unstable();
+
+            let mut edge_index_mapping: HashMap<EdgeIndex, EdgeIndex> =
+                HashMap::with_capacity(edge_indices.len());
+
+            for edge_index in edge_indices {
+                let (source_node_index, target_node_index) =
+                    record.graph.edge_endpoints(&edge_index)?;
+                let (source_node_index, target_node_index) =
+                    (source_node_index.clone(), target_node_index.clone());
+                let attributes = std::mem::take(record.graph.edge_attributes_mut(&edge_index)?);
+
+                let new_edge_index =
+                    graphrecord
+                        .graph
+                        .add_edge(source_node_index, target_node_index, attributes)?;
+
+                if record.graph.is_edge_undirected(&edge_index)? {
+                    graphrecord
+                        .graph
+                        .set_edge_undirected(&new_edge_index, true)?;
+                }
+
+                edge_index_mapping.insert(edge_index, new_edge_index);
+            }
+
+            let groups: Vec<_> = record.groups().cloned().collect();
+
+            for group in groups {
+                if !graphrecord.group_mapping.contains_group(&group) {
+                    graphrecord
+                        .group_mapping
+                        .add_group(group.clone(), None, None)?;
+                }
+
+                for node_index in record.nodes_in_group(&group)? {
+                    graphrecord
+                        .group_mapping
+                        .add_node_to_group(group.clone(), node_index.clone())?;
+                }
+
+                for edge_index in record.edges_in_group(&group)? {
+                    graphrecord
+                        .group_mapping
+                        .add_edge_to_group(group.clone(), edge_index_mapping[edge_index])?;
+                }
+
+                for (key, value) in record.group_mapping.group_metadata(&group)? {
+                    graphrecord.group_mapping.set_group_metadata(
+                        &group,
+                        key.clone(),
+                        value.clone(),
+                    )?;
+                }
+            }
+        }
+
+        Ok(graphrecord)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        GraphRecord,
+        errors::GraphRecordError,
+        graphrecord::{
+            NodeIndex,
+            datatypes::DataType,
+            schema::{AttributeDataType, SchemaType},
+        },
+    };
+    use std::collections::HashMap;
+
+    fn create_graphrecord(day: i64, edge_attribute: &str) -> GraphRecord {
+        let mut graphrecord = GraphRecord::from_tuples(
+            vec![
+                (
+                    format!("patient_{day}").into(),
+                    HashMap::from([("age".into(), 40.into())]),
+                ),
+                (format!("visit_{day}").into(), HashMap::new()),
+            ],
+            Some(vec![(
+                format!("patient_{day}").into(),
+                format!("visit_{day}").into(),
+                HashMap::from([(edge_attribute.into(), day.into())]),
+            )]),
+            None,
+        )
+        .unwrap();
+
+        graphrecord
+            .add_group(
+                "patients".into(),
+                Some(vec![format!("patient_{day}").into()]),
+                Some(vec![0]),
+            )
+            .unwrap();
+
+        graphrecord
+    }
+
+    #[test]
+    fn test_concat() {
+        let graphrecord = GraphRecord::concat(vec![
+            create_graphrecord(1, "weight"),
+            create_graphrecord(2, "weight"),
+        ])
+        .unwrap();
+
+        assert_eq!(4, graphrecord.node_count());
+        assert_eq!(2, graphrecord.edge_count());
+        assert_eq!(
+            (&"patient_2".into(), &"visit_2".into()),
+            graphrecord.edge_endpoints(&1).unwrap()
+        );
+
+        let mut patients: Vec<_> = graphrecord
+            .nodes_in_group(&"patients".into())
+            .unwrap()
+            .collect();
+        patients.sort_by(|a, b| a.total_cmp(b));
+
+        assert_eq!(
+            vec![&NodeIndex::from("patient_1"), &NodeIndex::from("patient_2")],
+            patients
+        );
+
+        let mut edges: Vec<_> = graphrecord
+            .edges_in_group(&"patients".into())
+            .unwrap()
+            .collect();
+        edges.sort_unstable();
+
+        assert_eq!(vec![&0, &1], edges);
+
+        assert_eq!(0, GraphRecord::concat(Vec::new()).unwrap().node_count());
+    }
+
+    #[test]
+    fn test_concat_schema_union() {
+        let mut first = create_graphrecord(1, "weight");
+        first.freeze_schema().unwrap();
+
+        let graphrecord =
+            GraphRecord::concat(vec![first, create_graphrecord(2, "duration")]).unwrap();
+        let schema = graphrecord.get_schema();
+
+        assert_eq!(&SchemaType::Inferred, schema.schema_type());
+        assert_eq!(
+            Some(&DataType::Int),
+            schema
+                .group(&"patients".into())
+                .unwrap()
+                .nodes()
+                .get(&"age".into())
+                .map(AttributeDataType::data_type)
+        );
+
+        for attribute in ["weight", "duration"] {
+            assert_eq!(
+                Some(&DataType::Option(Box::new(DataType::Int))),
+                schema
+                    .group(&"patients".into())
+                    .unwrap()
+                    .edges()
+                    .get(&attribute.into())
+                    .map(AttributeDataType::data_type)
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_concat() {
+        assert!(
+            GraphRecord::concat(vec![
+                create_graphrecord(1, "weight"),
+                create_graphrecord(1, "weight"),
+            ])
+            .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
+    }
+}
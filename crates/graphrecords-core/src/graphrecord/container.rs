@@ -0,0 +1,699 @@
+//! Versioned, chunked binary container for `GraphRecord`s.
+//!
+//! The container starts with a magic number and a format version, followed by
+//! a sequence of length-prefixed chunks. Nodes and edges are written in
+//! batches, so neither side has to materialize the whole record as a single
+//! serialized value. Readers skip chunk kinds they do not know, which keeps
+//! older readers working as long as the format version is unchanged.
+
+use super::{
+    AttributeMap, Attributes, EdgeIndex, GraphRecord, Group, NodeIndex, legacy::LegacyGraphRecord,
+    schema::Schema,
+};
+use crate::errors::{GraphRecordError, GraphRecordResult};
+#[cfg(feature = "plugins")]
+use crate::graphrecord::plugins::{DEFAULT_PLUGIN_PRIORITY, Plugin, PluginName, PluginRegistry};
+#[cfg(feature = "plugins")]
+use graphrecords_utils::aliases::GrHashMap;
+use serde::{Serialize, de::DeserializeOwned};
+use std::io::{ErrorKind, Read, Write};
+#[cfg(feature = "plugins")]
+use std::sync::Arc;
+
+const MAGIC: [u8; 4] = *b"GRCB";
+pub const CONTAINER_VERSION: u16 = 1;
+const CHUNK_SIZE: usize = 10_000;
+
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ChunkKind {
+    Settings = 0,
+    Schema = 1,
+    Nodes = 2,
+    Edges = 3,
+    Group = 4,
+    /// Plugins keyed by name, written by earlier versions without priorities.
+    #[cfg(feature = "plugins")]
+    Plugins = 5,
+    #[cfg(feature = "plugins")]
+    PrioritizedPlugins = 6,
+    Provenance = 7,
+    ValidTime = 8,
+    GroupMetadata = 9,
+    Metadata = 10,
+    NodeIndexSequence = 11,
+    UndirectedEdges = 12,
+    TextIndices = 13,
+    GeoIndices = 14,
+    FloatTolerance = 15,
+    RandomSeed = 16,
+    End = u8::MAX,
+}
+
+impl ChunkKind {
+    const fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            0 => Some(Self::Settings),
+            1 => Some(Self::Schema),
+            2 => Some(Self::Nodes),
+            3 => Some(Self::Edges),
+            4 => Some(Self::Group),
+            #[cfg(feature = "plugins")]
+            5 => Some(Self::Plugins),
+            #[cfg(feature = "plugins")]
+            6 => Some(Self::PrioritizedPlugins),
+            7 => Some(Self::Provenance),
+            8 => Some(Self::ValidTime),
+            9 => Some(Self::GroupMetadata),
+            10 => Some(Self::Metadata),
+            11 => Some(Self::NodeIndexSequence),
+            12 => Some(Self::UndirectedEdges),
+            13 => Some(Self::TextIndices),
+            14 => Some(Self::GeoIndices),
+            15 => Some(Self::FloatTolerance),
+            16 => Some(Self::RandomSeed),
+            u8::MAX => Some(Self::End),
+            _ => None,
+        }
+    }
+}
+
+type Settings = (bool, bool, bool, EdgeIndex, Vec<EdgeIndex>);
+type EdgeEntry = (EdgeIndex, NodeIndex, NodeIndex, AttributeMap);
+type GroupEntry = (Group, Vec<NodeIndex>, Vec<EdgeIndex>);
+
+fn io_error(error: &std::io::Error) -> GraphRecordError {
+    GraphRecordError::ConversionError(format!("Failed to transfer GraphRecord bytes: {error}"))
+}
+
+struct ChunkWriter<W: Write> {
+    writer: W,
+    buffer: Vec<u8>,
+}
+
+impl<W: Write> ChunkWriter<W> {
+    fn new(mut writer: W) -> GraphRecordResult<Self> {
+        writer
+            .write_all(&MAGIC)
+            .and_then(|()| writer.write_all(&CONTAINER_VERSION.to_le_bytes()))
+            .map_err(|error| io_error(&error))?;
+
+        Ok(Self {
+            writer,
+            buffer: Vec::new(),
+        })
+    }
+
+    fn write<T: Serialize + ?Sized>(
+        &mut self,
+        kind: ChunkKind,
+        value: &T,
+    ) -> GraphRecordResult<()> {
+        self.buffer.clear();
+
+        bincode::serialize_into(&mut self.buffer, value).map_err(|_| {
+            GraphRecordError::ConversionError("Could not serialize GraphRecord".to_string())
+        })?;
+
+        self.write_raw(kind)
+    }
+
+    fn write_raw(&mut self, kind: ChunkKind) -> GraphRecordResult<()> {
+        self.writer
+            .write_all(&[kind as u8])
+            .and_then(|()| {
+                self.writer
+                    .write_all(&(self.buffer.len() as u64).to_le_bytes())
+            })
+            .and_then(|()| self.writer.write_all(&self.buffer))
+            .map_err(|error| io_error(&error))
+    }
+
+    fn finish(mut self) -> GraphRecordResult<()> {
+        self.buffer.clear();
+        self.write_raw(ChunkKind::End)?;
+
+        self.writer.flush().map_err(|error| io_error(&error))
+    }
+}
+
+struct ChunkReader<R: Read> {
+    reader: R,
+    buffer: Vec<u8>,
+}
+
+impl<R: Read> ChunkReader<R> {
+    fn new(mut reader: R) -> GraphRecordResult<Self> {
+        let mut magic = [0; 4];
+        let mut version = [0; 2];
+
+        reader
+            .read_exact(&mut magic)
+            .and_then(|()| reader.read_exact(&mut version))
+            .map_err(|error| io_error(&error))?;
+
+        if magic != MAGIC {
+            return Err(GraphRecordError::ConversionError(
+                "Bytes are not a GraphRecord container".to_string(),
+            ));
+        }
+
+        let version = u16::from_le_bytes(version);
+
+        if version > CONTAINER_VERSION {
+            return Err(GraphRecordError::ConversionError(format!(
+                "GraphRecord container version {version} is newer than the supported version {CONTAINER_VERSION}"
+            )));
+        }
+
+        Ok(Self {
+            reader,
+            buffer: Vec::new(),
+        })
+    }
+
+    fn next(&mut self) -> GraphRecordResult<Option<ChunkKind>> {
+        loop {
+            let mut kind = [0; 1];
+            let mut length = [0; 8];
+
+            match self.reader.read_exact(&mut kind) {
+                Err(error) if error.kind() == ErrorKind::UnexpectedEof => {
+                    return Err(GraphRecordError::ConversionError(
+                        "GraphRecord container is truncated".to_string(),
+                    ));
+                }
+                result => result.map_err(|error| io_error(&error))?,
+            }
+
+            self.reader
+                .read_exact(&mut length)
+                .map_err(|error| io_error(&error))?;
+
+            let length = u64::from_le_bytes(length);
+
+            self.buffer.clear();
+            (&mut self.reader)
+                .take(length)
+                .read_to_end(&mut self.buffer)
+                .map_err(|error| io_error(&error))?;
+
+            if self.buffer.len() as u64 != length {
+                return Err(GraphRecordError::ConversionError(
+                    "GraphRecord container is truncated".to_string(),
+                ));
+            }
+
+            match ChunkKind::from_byte(kind[0]) {
+                Some(ChunkKind::End) => return Ok(None),
+                Some(kind) => return Ok(Some(kind)),
+                None => {}
+            }
+        }
+    }
+
+    fn value<T: DeserializeOwned>(&self) -> GraphRecordResult<T> {
+        bincode::deserialize(&self.buffer).map_err(|_| {
+            GraphRecordError::ConversionError("Could not deserialize GraphRecord".to_string())
+        })
+    }
+}
+
+impl GraphRecord {
+    /// Writes the record into `writer` using the versioned container format.
+    #[expect(clippy::missing_panics_doc, reason = "infallible")]
+    pub fn write_bytes<W: Write>(&self, writer: W) -> GraphRecordResult<()> {
+        let mut writer = ChunkWriter::new(writer)?;
+
+        let (edge_index_counter, free_edge_indices) = self.graph.edge_index_state();
+        let settings: Settings = (
+            self.frozen,
+            self.strict_queries,
+            self.graph.recycle_edge_indices(),
+            edge_index_counter,
+            free_edge_indices.clone(),
+        );
+
+        writer.write(ChunkKind::Settings, &settings)?;
+        writer.write(ChunkKind::Schema, &self.schema)?;
+
+        let nodes: Vec<_> = self.graph.nodes.iter().collect();
+
+        for chunk in nodes.chunks(CHUNK_SIZE) {
+            let chunk: Vec<_> = chunk
+                .iter()
+                .map(|(node_index, node)| (*node_index, &node.attributes))
+                .collect();
+
+            writer.write(ChunkKind::Nodes, &chunk)?;
+        }
+
+        let edges: Vec<_> = self.graph.edges.iter().collect();
+
+        for chunk in edges.chunks(CHUNK_SIZE) {
+            let chunk: Vec<_> = chunk
+                .iter()
+                .map(|(edge_index, edge)| {
+                    (
+                        *edge_index,
+                        &edge.source_node_index,
+                        &edge.target_node_index,
+                        &edge.attributes,
+                    )
+                })
+                .collect();
+
+            writer.write(ChunkKind::Edges, &chunk)?;
+        }
+
+        for group in self.group_mapping.groups() {
+            let nodes: Vec<_> = self
+                .group_mapping
+                .nodes_in_group(group)
+                .expect("Group must exist")
+                .collect();
+            let edges: Vec<_> = self
+                .group_mapping
+                .edges_in_group(group)
+                .expect("Group must exist")
+                .collect();
+
+            writer.write(ChunkKind::Group, &(group, nodes, edges))?;
+        }
+
+        for (group, metadata) in &self.group_mapping.group_metadata {
+            writer.write(ChunkKind::GroupMetadata, &(group, metadata))?;
+        }
+
+        #[cfg(feature = "plugins")]
+        writer.write(ChunkKind::PrioritizedPlugins, &self.plugins)?;
+
+        if let Some(provenance) = &self.provenance {
+            writer.write(ChunkKind::Provenance, provenance)?;
+        }
+
+        if !self.valid_time.is_empty() {
+            writer.write(ChunkKind::ValidTime, &self.valid_time)?;
+        }
+
+        if !self.metadata.is_empty() {
+            writer.write(ChunkKind::Metadata, &self.metadata)?;
+        }
+
+        if !self.node_index_sequence.is_default() {
+            writer.write(ChunkKind::NodeIndexSequence, &self.node_index_sequence)?;
+        }
+
+        if self.graph.has_undirected_edges() {
+            let undirected_edges: Vec<_> = self.graph.undirected_edge_indices().collect();
+
+            writer.write(ChunkKind::UndirectedEdges, &undirected_edges)?;
+        }
+
+        if !self.text_indices.is_empty() {
+            writer.write(ChunkKind::TextIndices, &self.text_indices)?;
+        }
+
+        if !self.geo_indices.is_empty() {
+            writer.write(ChunkKind::GeoIndices, &self.geo_indices)?;
+        }
+
+        if self.float_tolerance > 0.0 {
+            writer.write(ChunkKind::FloatTolerance, &self.float_tolerance)?;
+        }
+
+        if let Some(random_seed) = self.random_seed {
+            writer.write(ChunkKind::RandomSeed, &random_seed)?;
+        }
+
+        writer.finish()
+    }
+
+    /// Reads a record written by [`GraphRecord::write_bytes`].
+    pub fn read_bytes<R: Read>(reader: R) -> GraphRecordResult<Self> {
+        let mut reader = ChunkReader::new(reader)?;
+        let mut graphrecord = Self::new();
+        let mut settings = None;
+
+        while let Some(kind) = reader.next()? {
+            match kind {
+                ChunkKind::Settings => settings = Some(reader.value::<Settings>()?),
+                ChunkKind::Schema => graphrecord.schema = reader.value::<Schema>()?,
+                ChunkKind::Nodes => {
+                    for (node_index, attributes) in
+                        reader.value::<Vec<(NodeIndex, AttributeMap)>>()?
+                    {
+                        graphrecord.graph.add_node(node_index, attributes)?;
+                    }
+                }
+                ChunkKind::Edges => {
+                    for (edge_index, source_node_index, target_node_index, attributes) in
+                        reader.value::<Vec<EdgeEntry>>()?
+                    {
+                        graphrecord.graph.restore_edge(
+                            edge_index,
+                            source_node_index,
+                            target_node_index,
+                            attributes,
+                        )?;
+                    }
+                }
+                ChunkKind::Group => {
+                    let (group, nodes, edges) = reader.value::<GroupEntry>()?;
+
+                    graphrecord
+                        .group_mapping
+                        .add_group(group, Some(nodes), Some(edges))?;
+                }
+                #[cfg(feature = "plugins")]
+                ChunkKind::Plugins => {
+                    let plugins: GrHashMap<PluginName, Box<dyn Plugin>> = reader.value()?;
+
+                    let mut registry = PluginRegistry::default();
+                    for (name, plugin) in plugins {
+                        registry.insert(Some(name), plugin, DEFAULT_PLUGIN_PRIORITY);
+                    }
+
+                    graphrecord.plugins = Arc::new(registry);
+                }
+                #[cfg(feature = "plugins")]
+                ChunkKind::PrioritizedPlugins => graphrecord.plugins = reader.value()?,
+                ChunkKind::Provenance => graphrecord.provenance = Some(reader.value()?),
+                ChunkKind::ValidTime => graphrecord.valid_time = reader.value()?,
+                ChunkKind::Metadata => graphrecord.metadata = reader.value()?,
+                ChunkKind::NodeIndexSequence => graphrecord.node_index_sequence = reader.value()?,
+                ChunkKind::TextIndices => graphrecord.text_indices = reader.value()?,
+                ChunkKind::GeoIndices => graphrecord.geo_indices = reader.value()?,
+                ChunkKind::FloatTolerance => graphrecord.float_tolerance = reader.value()?,
+                ChunkKind::RandomSeed => graphrecord.random_seed = Some(reader.value()?),
+                ChunkKind::UndirectedEdges => {
+                    for edge_index in reader.value::<Vec<EdgeIndex>>()? {
+                        graphrecord.graph.set_edge_undirected(&edge_index, true)?;
+                    }
+                }
+                ChunkKind::GroupMetadata => {
+                    let (group, metadata) = reader.value::<(Group, Attributes)>()?;
+
+                    graphrecord
+                        .group_mapping
+                        .group_metadata
+                        .insert(group, metadata);
+                }
+                ChunkKind::End => unreachable!(),
+            }
+        }
+
+        if let Some((
+            frozen,
+            strict_queries,
+            recycle_edge_indices,
+            edge_index_counter,
+            free_edge_indices,
+        )) = settings
+        {
+            graphrecord.frozen = frozen;
+            graphrecord.strict_queries = strict_queries;
+            graphrecord
+                .graph
+                .set_recycle_edge_indices(recycle_edge_indices);
+            graphrecord
+                .graph
+                .restore_edge_index_state(edge_index_counter, free_edge_indices);
+        }
+
+        Ok(graphrecord)
+    }
+
+    pub fn to_bytes(&self) -> GraphRecordResult<Vec<u8>> {
+        let mut bytes = Vec::new();
+
+        self.write_bytes(&mut bytes)?;
+
+        Ok(bytes)
+    }
+
+    /// Reads a record from bytes produced by [`GraphRecord::to_bytes`].
+    ///
+    /// Bytes that do not start with the container header are read as a
+    /// plain bincode serialization of the record, the format used before the
+    /// container was introduced.
+    pub fn from_bytes(bytes: &[u8]) -> GraphRecordResult<Self> {
+        if bytes.starts_with(&MAGIC) {
+            return Self::read_bytes(bytes);
+        }
+
+        bincode::deserialize::<LegacyGraphRecord>(bytes)
+            .map_err(|_| {
+                GraphRecordError::ConversionError("Could not deserialize GraphRecord".to_string())
+            })?
+            .try_into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CONTAINER_VERSION, MAGIC};
+    use crate::{
+        GraphRecord,
+        graphrecord::{
+            GraphRecordAttribute, GraphRecordValue, datatypes::DataType, schema::SchemaType,
+        },
+    };
+    use chrono::{NaiveDate, TimeDelta};
+    use std::collections::{HashMap, HashSet};
+
+    fn create_graphrecord() -> GraphRecord {
+        let mut graphrecord = GraphRecord::from_tuples(
+            vec![
+                (
+                    "0".into(),
+                    HashMap::from([("lorem".into(), "ipsum".into())]),
+                ),
+                ("1".into(), HashMap::new()),
+                (2.into(), HashMap::from([("value".into(), 1.5.into())])),
+            ],
+            Some(vec![
+                ("0".into(), "1".into(), HashMap::new()),
+                (
+                    "1".into(),
+                    2.into(),
+                    HashMap::from([("weight".into(), 3.into())]),
+                ),
+                (2.into(), "0".into(), HashMap::new()),
+            ]),
+            None,
+        )
+        .unwrap();
+
+        graphrecord
+            .add_group("group".into(), Some(vec!["0".into()]), Some(vec![1]))
+            .unwrap();
+        graphrecord
+            .set_group_metadata(&"group".into(), "color".into(), "red".into())
+            .unwrap();
+        graphrecord.set_edge_undirected(&2, true).unwrap();
+        graphrecord.remove_edge(&0).unwrap();
+        graphrecord
+            .create_text_index("group".into(), "lorem".into())
+            .unwrap();
+        graphrecord
+            .create_geo_index("group".into(), "location".into())
+            .unwrap();
+        graphrecord.set_float_tolerance(1e-6).unwrap();
+        graphrecord.set_random_seed(Some(42));
+
+        graphrecord
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let graphrecord = create_graphrecord();
+
+        let bytes = graphrecord.to_bytes().unwrap();
+
+        assert!(bytes.starts_with(&MAGIC));
+
+        let restored = GraphRecord::from_bytes(&bytes).unwrap();
+
+        assert_eq!(graphrecord.node_count(), restored.node_count());
+        assert_eq!(graphrecord.edge_count(), restored.edge_count());
+        assert_eq!(
+            graphrecord.node_attributes(&2.into()).unwrap(),
+            restored.node_attributes(&2.into()).unwrap()
+        );
+        assert_eq!(
+            graphrecord.edge_endpoints(&1).unwrap(),
+            restored.edge_endpoints(&1).unwrap()
+        );
+        assert_eq!(
+            vec![&GraphRecordAttribute::from("0")],
+            restored
+                .nodes_in_group(&"group".into())
+                .unwrap()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![&1],
+            restored
+                .edges_in_group(&"group".into())
+                .unwrap()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(
+                &GraphRecordAttribute::from("color"),
+                &GraphRecordValue::from("red")
+            )],
+            restored
+                .group_metadata(&"group".into())
+                .unwrap()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(vec![&2], restored.undirected_edges().collect::<Vec<_>>());
+        assert_eq!(graphrecord.text_indices(), restored.text_indices());
+        assert_eq!(graphrecord.geo_indices(), restored.geo_indices());
+        assert!((graphrecord.float_tolerance() - restored.float_tolerance()).abs() < f64::EPSILON);
+        assert_eq!(Some(42), restored.random_seed());
+        assert_eq!(
+            graphrecord.get_schema().schema_type(),
+            restored.get_schema().schema_type()
+        );
+        assert_eq!(SchemaType::Inferred, *restored.get_schema().schema_type());
+
+        let mut restored = restored;
+        let edge_index = restored
+            .add_edge("0".into(), "1".into(), HashMap::new())
+            .unwrap();
+
+        assert_eq!(3, edge_index);
+    }
+
+    #[test]
+    fn test_legacy_bytes() {
+        // Written by `bincode::serialize` before the container was introduced
+        let bytes = include_bytes!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/fixtures/legacy_graphrecord.bin"
+        ));
+
+        let mut graphrecord = GraphRecord::from_bytes(bytes).unwrap();
+
+        assert_eq!(3, graphrecord.node_count());
+        assert_eq!(2, graphrecord.edge_count());
+
+        let datetime = NaiveDate::from_ymd_opt(2024, 1, 2)
+            .unwrap()
+            .and_hms_opt(3, 4, 5)
+            .unwrap();
+
+        assert_eq!(
+            &HashMap::from([
+                ("string".into(), "value".into()),
+                ("int".into(), 1.into()),
+                ("float".into(), 1.5.into()),
+                ("bool".into(), true.into()),
+                ("datetime".into(), datetime.into()),
+                ("duration".into(), TimeDelta::seconds(90).into()),
+                ("mixed".into(), 1.into()),
+            ]),
+            graphrecord.node_attributes(&"0".into()).unwrap()
+        );
+        assert_eq!(
+            &HashMap::from([
+                ("int".into(), GraphRecordValue::Null),
+                ("mixed".into(), "one".into()),
+            ]),
+            graphrecord.node_attributes(&1.into()).unwrap()
+        );
+        assert_eq!(
+            (&"0".into(), &1.into()),
+            graphrecord.edge_endpoints(&0).unwrap()
+        );
+        assert_eq!(
+            &HashMap::from([("note".into(), GraphRecordValue::Null)]),
+            graphrecord.edge_attributes(&2).unwrap()
+        );
+        assert!(graphrecord.edge_endpoints(&1).is_err());
+        assert_eq!(
+            vec![&2],
+            graphrecord
+                .outgoing_edges(&"2".into())
+                .unwrap()
+                .collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            HashSet::from([&GraphRecordAttribute::from("0"), &1.into()]),
+            graphrecord
+                .nodes_in_group(&"group".into())
+                .unwrap()
+                .collect::<HashSet<_>>()
+        );
+        assert_eq!(
+            vec![&2],
+            graphrecord
+                .edges_in_group(&"group".into())
+                .unwrap()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            0,
+            graphrecord.nodes_in_group(&"empty".into()).unwrap().count()
+        );
+
+        let schema = graphrecord.get_schema();
+
+        assert_eq!(SchemaType::Inferred, *schema.schema_type());
+        assert_eq!(
+            &DataType::Option(Box::new(DataType::Int)),
+            schema.ungrouped().nodes()[&"int".into()].data_type()
+        );
+        assert_eq!(
+            &DataType::Union((Box::new(DataType::Int), Box::new(DataType::String))),
+            schema.group(&"group".into()).unwrap().nodes()[&"mixed".into()].data_type()
+        );
+
+        assert_eq!(
+            3,
+            graphrecord
+                .add_edge("0".into(), "2".into(), HashMap::new())
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_variant_indices() {
+        let variant_index = |bytes: Vec<u8>| u32::from_le_bytes(bytes[..4].try_into().unwrap());
+
+        // Chunks refer to variants by position, so existing variants must keep
+        // their index
+        assert_eq!(
+            6,
+            variant_index(bincode::serialize(&GraphRecordValue::Null).unwrap())
+        );
+        assert_eq!(
+            7,
+            variant_index(bincode::serialize(&GraphRecordValue::Missing).unwrap())
+        );
+        assert_eq!(
+            6,
+            variant_index(bincode::serialize(&DataType::Null).unwrap())
+        );
+        assert_eq!(
+            10,
+            variant_index(bincode::serialize(&DataType::Missing).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_invalid_bytes() {
+        let bytes = create_graphrecord().to_bytes().unwrap();
+
+        assert!(GraphRecord::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+
+        let mut newer = bytes;
+        newer[4..6].copy_from_slice(&(CONTAINER_VERSION + 1).to_le_bytes());
+
+        assert!(GraphRecord::from_bytes(&newer).is_err());
+    }
+}
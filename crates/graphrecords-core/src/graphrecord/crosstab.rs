@@ -0,0 +1,226 @@
+use super::{GraphRecord, GraphRecordAttribute, GraphRecordValue, Group};
+use crate::errors::GraphRecordResult;
+use graphrecords_utils::aliases::GrHashMap;
+
+/// How the cells of a `Crosstab` are normalized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrosstabNormalization {
+    /// Divides every cell by the total count.
+    All,
+    /// Divides every cell by the count of its row.
+    Rows,
+    /// Divides every cell by the count of its column.
+    Columns,
+}
+
+/// Contingency table of the values of two node attributes.
+///
+/// `rows` holds the distinct values of the first attribute and `columns` the
+/// distinct values of the second one, both sorted. `values[row][column]` is
+/// the number of nodes with that pair of values, or its share if the table is
+/// normalized.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Crosstab {
+    pub rows: Vec<GraphRecordValue>,
+    pub columns: Vec<GraphRecordValue>,
+    pub values: Vec<Vec<f64>>,
+}
+
+fn positions(values: &mut Vec<GraphRecordValue>) -> GrHashMap<GraphRecordValue, usize> {
+    values.sort_by(GraphRecordValue::total_cmp);
+    values.dedup();
+
+    values
+        .iter()
+        .enumerate()
+        .map(|(position, value)| (value.clone(), position))
+        .collect()
+}
+
+impl Crosstab {
+    pub(crate) fn new(
+        graphrecord: &GraphRecord,
+        group: &Group,
+        row_attribute: &GraphRecordAttribute,
+        column_attribute: &GraphRecordAttribute,
+        normalization: Option<CrosstabNormalization>,
+    ) -> GraphRecordResult<Self> {
+        // Nodes that lack one of the attributes are not part of the table
+        let pairs: Vec<_> = graphrecord
+            .nodes_in_group(group)?
+            .filter_map(|node_index| {
+                let attributes = graphrecord
+                    .node_attributes(node_index)
+                    .expect("Node must exist");
+
+                Some((
+                    attributes.get(row_attribute)?,
+                    attributes.get(column_attribute)?,
+                ))
+            })
+            .collect();
+
+        let mut rows: Vec<_> = pairs.iter().map(|(row, _)| (*row).clone()).collect();
+        let mut columns: Vec<_> = pairs.iter().map(|(_, column)| (*column).clone()).collect();
+
+        let row_positions = positions(&mut rows);
+        let column_positions = positions(&mut columns);
+
+        let mut values = vec![vec![0.0; columns.len()]; rows.len()];
+
+        for (row, column) in &pairs {
+            values[row_positions[*row]][column_positions[*column]] += 1.0;
+        }
+
+        match normalization {
+            Some(CrosstabNormalization::All) => {
+                let total = pairs.len() as f64;
+
+                for value in values.iter_mut().flatten() {
+                    *value /= total;
+                }
+            }
+            Some(CrosstabNormalization::Rows) => {
+                for row in &mut values {
+                    let total: f64 = row.iter().sum();
+
+                    for value in row {
+                        *value /= total;
+                    }
+                }
+            }
+            Some(CrosstabNormalization::Columns) => {
+                for column in 0..columns.len() {
+                    let total: f64 = values.iter().map(|row| row[column]).sum();
+
+                    for row in &mut values {
+                        row[column] /= total;
+                    }
+                }
+            }
+            None => {}
+        }
+
+        Ok(Self {
+            rows,
+            columns,
+            values,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::CrosstabNormalization;
+    use crate::{GraphRecord, errors::GraphRecordError, graphrecord::GraphRecordValue};
+    use std::collections::HashMap;
+
+    fn create_graphrecord() -> GraphRecord {
+        let mut graphrecord = GraphRecord::from_tuples(
+            vec![
+                (
+                    "0".into(),
+                    HashMap::from([("sex".into(), "F".into()), ("smoker".into(), true.into())]),
+                ),
+                (
+                    "1".into(),
+                    HashMap::from([("sex".into(), "F".into()), ("smoker".into(), false.into())]),
+                ),
+                (
+                    "2".into(),
+                    HashMap::from([("sex".into(), "M".into()), ("smoker".into(), true.into())]),
+                ),
+                (
+                    "3".into(),
+                    HashMap::from([("sex".into(), "F".into()), ("smoker".into(), true.into())]),
+                ),
+                ("4".into(), HashMap::from([("sex".into(), "M".into())])),
+            ],
+            None,
+            None,
+        )
+        .unwrap();
+
+        graphrecord
+            .add_group(
+                "patients".into(),
+                Some(vec![
+                    "0".into(),
+                    "1".into(),
+                    "2".into(),
+                    "3".into(),
+                    "4".into(),
+                ]),
+                None,
+            )
+            .unwrap();
+
+        graphrecord
+    }
+
+    #[test]
+    fn test_crosstab() {
+        let graphrecord = create_graphrecord();
+
+        let crosstab = graphrecord
+            .crosstab(&"patients".into(), &"sex".into(), &"smoker".into(), None)
+            .unwrap();
+
+        assert_eq!(vec![GraphRecordValue::from("F"), "M".into()], crosstab.rows);
+        assert_eq!(
+            vec![GraphRecordValue::from(false), true.into()],
+            crosstab.columns
+        );
+        assert_eq!(vec![vec![1.0, 2.0], vec![0.0, 1.0]], crosstab.values);
+
+        let crosstab = graphrecord
+            .crosstab(
+                &"patients".into(),
+                &"sex".into(),
+                &"smoker".into(),
+                Some(CrosstabNormalization::All),
+            )
+            .unwrap();
+
+        assert_eq!(vec![vec![0.25, 0.5], vec![0.0, 0.25]], crosstab.values);
+
+        let crosstab = graphrecord
+            .crosstab(
+                &"patients".into(),
+                &"sex".into(),
+                &"smoker".into(),
+                Some(CrosstabNormalization::Rows),
+            )
+            .unwrap();
+
+        assert_eq!(
+            vec![vec![1.0 / 3.0, 2.0 / 3.0], vec![0.0, 1.0]],
+            crosstab.values
+        );
+
+        let crosstab = graphrecord
+            .crosstab(
+                &"patients".into(),
+                &"sex".into(),
+                &"smoker".into(),
+                Some(CrosstabNormalization::Columns),
+            )
+            .unwrap();
+
+        assert_eq!(
+            vec![vec![1.0, 2.0 / 3.0], vec![0.0, 1.0 / 3.0]],
+            crosstab.values
+        );
+    }
+
+    #[test]
+    fn test_invalid_crosstab() {
+        let graphrecord = create_graphrecord();
+
+        assert!(
+            graphrecord
+                .crosstab(&"visits".into(), &"sex".into(), &"smoker".into(), None)
+                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+        );
+    }
+}
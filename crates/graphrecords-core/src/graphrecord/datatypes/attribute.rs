@@ -74,6 +74,21 @@ impl PartialOrd for GraphRecordAttribute {
     }
 }
 
+impl GraphRecordAttribute {
+    /// Total order over all attributes, placing integers before strings.
+    ///
+    /// Agrees with [`PartialOrd`] wherever the latter is defined.
+    #[must_use]
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Int(value), Self::Int(other)) => value.cmp(other),
+            (Self::String(value), Self::String(other)) => value.cmp(other),
+            (Self::Int(_), Self::String(_)) => Ordering::Less,
+            (Self::String(_), Self::Int(_)) => Ordering::Greater,
+        }
+    }
+}
+
 impl Display for GraphRecordAttribute {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -410,6 +425,28 @@ mod test {
         assert!(!(GraphRecordAttribute::Int(1) <= GraphRecordAttribute::String("a".to_string())));
     }
 
+    #[test]
+    fn test_total_cmp() {
+        let mut attributes = vec![
+            GraphRecordAttribute::String("b".to_string()),
+            GraphRecordAttribute::Int(1),
+            GraphRecordAttribute::String("a".to_string()),
+            GraphRecordAttribute::Int(0),
+        ];
+
+        attributes.sort_by(GraphRecordAttribute::total_cmp);
+
+        assert_eq!(
+            vec![
+                GraphRecordAttribute::Int(0),
+                GraphRecordAttribute::Int(1),
+                GraphRecordAttribute::String("a".to_string()),
+                GraphRecordAttribute::String("b".to_string()),
+            ],
+            attributes
+        );
+    }
+
     #[test]
     fn test_starts_with() {
         assert!(
@@ -0,0 +1,164 @@
+use crate::errors::{GraphRecordError, GraphRecordResult};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// Mean radius of the earth in kilometers.
+const EARTH_RADIUS_KM: f64 = 6371.0088;
+
+/// A location on the earth's surface in degrees of latitude and longitude.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GeoPoint {
+    latitude: f64,
+    longitude: f64,
+}
+
+impl GeoPoint {
+    pub fn new(latitude: f64, longitude: f64) -> GraphRecordResult<Self> {
+        if !latitude.is_finite() || !(-90.0..=90.0).contains(&latitude) {
+            return Err(GraphRecordError::AssertionError(format!(
+                "Latitude must be between -90 and 90 degrees, got {latitude}"
+            )));
+        }
+
+        if !longitude.is_finite() || !(-180.0..=180.0).contains(&longitude) {
+            return Err(GraphRecordError::AssertionError(format!(
+                "Longitude must be between -180 and 180 degrees, got {longitude}"
+            )));
+        }
+
+        Ok(Self {
+            latitude,
+            longitude,
+        })
+    }
+
+    #[must_use]
+    pub const fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    #[must_use]
+    pub const fn longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    /// Returns the great-circle distance to `other` in kilometers, using the
+    /// haversine formula on a spherical earth.
+    #[must_use]
+    pub fn distance_km(&self, other: &Self) -> f64 {
+        let latitude = self.latitude.to_radians();
+        let other_latitude = other.latitude.to_radians();
+        let delta_latitude = (other.latitude - self.latitude).to_radians();
+        let delta_longitude = (other.longitude - self.longitude).to_radians();
+
+        let haversine = (latitude.cos() * other_latitude.cos()).mul_add(
+            (delta_longitude / 2.0).sin().powi(2),
+            (delta_latitude / 2.0).sin().powi(2),
+        );
+
+        2.0 * EARTH_RADIUS_KM * haversine.sqrt().min(1.0).asin()
+    }
+
+    /// Returns the south-west and north-east corners of a box containing every
+    /// point within `radius_km`. The box spans all longitudes if the circle
+    /// reaches a pole or the antimeridian.
+    pub(crate) fn bounding_box(&self, radius_km: f64) -> (Self, Self) {
+        let angular_radius = radius_km / EARTH_RADIUS_KM;
+        let latitude = self.latitude.to_radians();
+        let min_latitude = latitude - angular_radius;
+        let max_latitude = latitude + angular_radius;
+
+        let south_west = |longitude: f64| Self {
+            latitude: min_latitude.to_degrees().max(-90.0),
+            longitude,
+        };
+        let north_east = |longitude: f64| Self {
+            latitude: max_latitude.to_degrees().min(90.0),
+            longitude,
+        };
+
+        if min_latitude <= -std::f64::consts::FRAC_PI_2
+            || max_latitude >= std::f64::consts::FRAC_PI_2
+        {
+            return (south_west(-180.0), north_east(180.0));
+        }
+
+        let delta_longitude = (angular_radius.sin() / latitude.cos()).asin().to_degrees();
+        let min_longitude = self.longitude - delta_longitude;
+        let max_longitude = self.longitude + delta_longitude;
+
+        if min_longitude < -180.0 || max_longitude > 180.0 {
+            return (south_west(-180.0), north_east(180.0));
+        }
+
+        (south_west(min_longitude), north_east(max_longitude))
+    }
+}
+
+impl Display for GeoPoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.latitude, self.longitude)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::GeoPoint;
+    use crate::errors::GraphRecordError;
+
+    #[test]
+    fn test_new() {
+        let point = GeoPoint::new(52.52, 13.405).unwrap();
+
+        assert!((point.latitude() - 52.52).abs() < f64::EPSILON);
+        assert!((point.longitude() - 13.405).abs() < f64::EPSILON);
+        assert_eq!("(52.52, 13.405)", point.to_string());
+
+        assert!(
+            GeoPoint::new(90.5, 0.0)
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
+        assert!(
+            GeoPoint::new(0.0, -180.5)
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
+        assert!(
+            GeoPoint::new(f64::NAN, 0.0)
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
+    }
+
+    #[test]
+    fn test_distance_km() {
+        let berlin = GeoPoint::new(52.52, 13.405).unwrap();
+        let munich = GeoPoint::new(48.1351, 11.582).unwrap();
+
+        assert!(berlin.distance_km(&berlin).abs() < f64::EPSILON);
+        assert!((berlin.distance_km(&munich) - 504.4).abs() < 0.1);
+        assert!((berlin.distance_km(&munich) - munich.distance_km(&berlin)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let berlin = GeoPoint::new(52.52, 13.405).unwrap();
+        let (south_west, north_east) = berlin.bounding_box(100.0);
+
+        assert!(south_west.latitude() < 52.52 && 52.52 < north_east.latitude());
+        assert!(south_west.longitude() < 13.405 && 13.405 < north_east.longitude());
+
+        for point in [
+            GeoPoint::new(north_east.latitude(), 13.405).unwrap(),
+            GeoPoint::new(52.52, south_west.longitude()).unwrap(),
+        ] {
+            assert!((berlin.distance_km(&point) - 100.0).abs() < 1.0);
+        }
+
+        let (south_west, north_east) = GeoPoint::new(89.5, 0.0).unwrap().bounding_box(100.0);
+
+        assert!((north_east.latitude() - 90.0).abs() < f64::EPSILON);
+        assert!((south_west.longitude() + 180.0).abs() < f64::EPSILON);
+        assert!((north_east.longitude() - 180.0).abs() < f64::EPSILON);
+    }
+}
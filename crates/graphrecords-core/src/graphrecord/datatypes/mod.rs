@@ -1,7 +1,8 @@
 mod attribute;
+mod geo_point;
 mod value;
 
-pub use self::{attribute::GraphRecordAttribute, value::GraphRecordValue};
+pub use self::{attribute::GraphRecordAttribute, geo_point::GeoPoint, value::GraphRecordValue};
 use super::EdgeIndex;
 use crate::errors::GraphRecordResult;
 #[cfg(feature = "serde")]
@@ -22,6 +23,13 @@ pub enum DataType {
     Any,
     Union((Box<Self>, Box<Self>)),
     Option(Box<Self>),
+    /// The data type of [`GraphRecordValue::Missing`]. Unlike `Null`, it is
+    /// not accepted by `Option` data types, so a schema can tell values that
+    /// were never recorded apart from recorded absences, e.g. with
+    /// `Union[Bool, Missing]` for a test that was either done or not done.
+    Missing,
+    // Serialized schemas refer to variants by position, so new variants go last.
+    GeoPoint,
 }
 
 // TODO: Add tests for Duration
@@ -34,7 +42,9 @@ impl From<GraphRecordValue> for DataType {
             GraphRecordValue::Bool(_) => Self::Bool,
             GraphRecordValue::DateTime(_) => Self::DateTime,
             GraphRecordValue::Duration(_) => Self::Duration,
+            GraphRecordValue::GeoPoint(_) => Self::GeoPoint,
             GraphRecordValue::Null => Self::Null,
+            GraphRecordValue::Missing => Self::Missing,
         }
     }
 }
@@ -49,7 +59,9 @@ impl From<&GraphRecordValue> for DataType {
             GraphRecordValue::Bool(_) => Self::Bool,
             GraphRecordValue::DateTime(_) => Self::DateTime,
             GraphRecordValue::Duration(_) => Self::Duration,
+            GraphRecordValue::GeoPoint(_) => Self::GeoPoint,
             GraphRecordValue::Null => Self::Null,
+            GraphRecordValue::Missing => Self::Missing,
         }
     }
 }
@@ -89,13 +101,17 @@ impl PartialEq for DataType {
                     | (Self::Float, Self::Float)
                     | (Self::Bool, Self::Bool)
                     | (Self::DateTime, Self::DateTime)
+                    | (Self::GeoPoint, Self::GeoPoint)
                     | (Self::Null, Self::Null)
                     | (Self::Any, Self::Any)
+                    | (Self::Missing, Self::Missing)
             ),
         }
     }
 }
 
+impl Eq for DataType {}
+
 // TODO: Add tests for Duration
 impl Display for DataType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -106,6 +122,7 @@ impl Display for DataType {
             Self::Bool => write!(f, "Bool"),
             Self::DateTime => write!(f, "DateTime"),
             Self::Duration => write!(f, "Duration"),
+            Self::GeoPoint => write!(f, "GeoPoint"),
             Self::Null => write!(f, "Null"),
             Self::Any => write!(f, "Any"),
             Self::Union((first_datatype, second_datatype)) => {
@@ -120,6 +137,7 @@ impl Display for DataType {
                 data_type.fmt(f)?;
                 write!(f, "]")
             }
+            Self::Missing => write!(f, "Missing"),
         }
     }
 }
@@ -142,11 +160,31 @@ impl DataType {
                     | (Self::Bool, Self::Bool)
                     | (Self::DateTime, Self::DateTime)
                     | (Self::Duration, Self::Duration)
+                    | (Self::GeoPoint, Self::GeoPoint)
                     | (Self::Null, Self::Null)
                     | (Self::Any, Self::Any)
+                    | (Self::Missing, Self::Missing)
             ),
         }
     }
+
+    /// Returns the data type of the recorded values, ignoring a `Missing`
+    /// alternative of a union.
+    pub(crate) fn recorded(&self) -> &Self {
+        match self {
+            Self::Union((first_datatype, second_datatype))
+                if matches!(**second_datatype, Self::Missing) =>
+            {
+                first_datatype
+            }
+            Self::Union((first_datatype, second_datatype))
+                if matches!(**first_datatype, Self::Missing) =>
+            {
+                second_datatype
+            }
+            data_type => data_type,
+        }
+    }
 }
 
 pub trait StartsWith {
@@ -254,7 +292,7 @@ pub trait Slice {
 
 #[cfg(test)]
 mod test {
-    use super::{DataType, GraphRecordValue};
+    use super::{DataType, GeoPoint, GraphRecordValue};
     use chrono::NaiveDateTime;
 
     #[test]
@@ -281,7 +319,12 @@ mod test {
             DataType::DateTime,
             DataType::from(GraphRecordValue::DateTime(NaiveDateTime::MIN))
         );
+        assert_eq!(
+            DataType::GeoPoint,
+            DataType::from(GraphRecordValue::GeoPoint(GeoPoint::new(0.0, 0.0).unwrap()))
+        );
         assert_eq!(DataType::Null, DataType::from(GraphRecordValue::Null));
+        assert_eq!(DataType::Missing, DataType::from(GraphRecordValue::Missing));
     }
 
     #[test]
@@ -303,7 +346,17 @@ mod test {
             DataType::DateTime,
             DataType::from(&GraphRecordValue::DateTime(NaiveDateTime::MIN))
         );
+        assert_eq!(
+            DataType::GeoPoint,
+            DataType::from(&GraphRecordValue::GeoPoint(
+                GeoPoint::new(0.0, 0.0).unwrap()
+            ))
+        );
         assert_eq!(DataType::Null, DataType::from(&GraphRecordValue::Null));
+        assert_eq!(
+            DataType::Missing,
+            DataType::from(&GraphRecordValue::Missing)
+        );
     }
 
     #[test]
@@ -313,8 +366,11 @@ mod test {
         assert!(DataType::Float == DataType::Float);
         assert!(DataType::Bool == DataType::Bool);
         assert!(DataType::DateTime == DataType::DateTime);
+        assert!(DataType::GeoPoint == DataType::GeoPoint);
         assert!(DataType::Null == DataType::Null);
         assert!(DataType::Any == DataType::Any);
+        assert!(DataType::Missing == DataType::Missing);
+        assert!(DataType::Missing != DataType::Null);
 
         assert!(
             DataType::Union((Box::new(DataType::String), Box::new(DataType::Int)))
@@ -398,8 +454,10 @@ mod test {
         assert_eq!("Float", format!("{}", DataType::Float));
         assert_eq!("Bool", format!("{}", DataType::Bool));
         assert_eq!("DateTime", format!("{}", DataType::DateTime));
+        assert_eq!("GeoPoint", format!("{}", DataType::GeoPoint));
         assert_eq!("Null", format!("{}", DataType::Null));
         assert_eq!("Any", format!("{}", DataType::Any));
+        assert_eq!("Missing", format!("{}", DataType::Missing));
         assert_eq!(
             "Union[String, Int]",
             format!(
@@ -420,6 +478,7 @@ mod test {
         assert!(DataType::Float.evaluate(&DataType::Float));
         assert!(DataType::Bool.evaluate(&DataType::Bool));
         assert!(DataType::DateTime.evaluate(&DataType::DateTime));
+        assert!(DataType::GeoPoint.evaluate(&DataType::GeoPoint));
         assert!(DataType::Null.evaluate(&DataType::Null));
         assert!(DataType::Any.evaluate(&DataType::Any));
 
@@ -449,6 +508,16 @@ mod test {
         );
         assert!(DataType::Option(Box::new(DataType::String)).evaluate(&DataType::Null));
         assert!(DataType::Option(Box::new(DataType::String)).evaluate(&DataType::String));
+        assert!(!DataType::Option(Box::new(DataType::String)).evaluate(&DataType::Missing));
+
+        assert!(DataType::Missing.evaluate(&DataType::Missing));
+        assert!(!DataType::Missing.evaluate(&DataType::Null));
+        assert!(!DataType::Null.evaluate(&DataType::Missing));
+        assert!(
+            DataType::Union((Box::new(DataType::Bool), Box::new(DataType::Missing)))
+                .evaluate(&DataType::Missing)
+        );
+        assert!(DataType::Any.evaluate(&DataType::Missing));
 
         assert!(DataType::Any.evaluate(&DataType::String));
 
@@ -1,6 +1,6 @@
 use super::{
-    Abs, Ceil, Contains, EndsWith, Floor, Lowercase, Mod, Pow, Round, Slice, Sqrt, StartsWith,
-    Trim, TrimEnd, TrimStart, Uppercase,
+    Abs, Ceil, Contains, EndsWith, Floor, GeoPoint, Lowercase, Mod, Pow, Round, Slice, Sqrt,
+    StartsWith, Trim, TrimEnd, TrimStart, Uppercase,
 };
 use crate::errors::{GraphRecordError, GraphRecordResult};
 use chrono::{DateTime, NaiveDateTime, TimeDelta};
@@ -25,6 +25,11 @@ pub enum GraphRecordValue {
     Duration(TimeDelta),
     #[default]
     Null,
+    /// A value that was never recorded, as opposed to [`GraphRecordValue::Null`]
+    /// which records the absence of a value.
+    Missing,
+    // Serialized records refer to variants by position, so new variants go last.
+    GeoPoint(GeoPoint),
 }
 
 impl From<&str> for GraphRecordValue {
@@ -40,6 +45,7 @@ implement_from_for_wrapper!(GraphRecordValue, f64, Float);
 implement_from_for_wrapper!(GraphRecordValue, bool, Bool);
 implement_from_for_wrapper!(GraphRecordValue, NaiveDateTime, DateTime);
 implement_from_for_wrapper!(GraphRecordValue, TimeDelta, Duration);
+implement_from_for_wrapper!(GraphRecordValue, GeoPoint, GeoPoint);
 
 impl<T> From<Option<T>> for GraphRecordValue
 where
@@ -53,6 +59,59 @@ where
     }
 }
 
+impl TryFrom<GraphRecordValue> for f64 {
+    type Error = GraphRecordError;
+
+    fn try_from(value: GraphRecordValue) -> Result<Self, Self::Error> {
+        match value {
+            GraphRecordValue::Float(value) => Ok(value),
+            GraphRecordValue::Int(value) => Ok(value as Self),
+            _ => Err(GraphRecordError::ConversionError(format!(
+                "Cannot convert {value} into f64"
+            ))),
+        }
+    }
+}
+
+impl TryFrom<GraphRecordValue> for i64 {
+    type Error = GraphRecordError;
+
+    fn try_from(value: GraphRecordValue) -> Result<Self, Self::Error> {
+        match value {
+            GraphRecordValue::Int(value) => Ok(value),
+            _ => Err(GraphRecordError::ConversionError(format!(
+                "Cannot convert {value} into i64"
+            ))),
+        }
+    }
+}
+
+impl TryFrom<GraphRecordValue> for bool {
+    type Error = GraphRecordError;
+
+    fn try_from(value: GraphRecordValue) -> Result<Self, Self::Error> {
+        match value {
+            GraphRecordValue::Bool(value) => Ok(value),
+            _ => Err(GraphRecordError::ConversionError(format!(
+                "Cannot convert {value} into bool"
+            ))),
+        }
+    }
+}
+
+impl TryFrom<GraphRecordValue> for String {
+    type Error = GraphRecordError;
+
+    fn try_from(value: GraphRecordValue) -> Result<Self, Self::Error> {
+        match value {
+            GraphRecordValue::String(value) => Ok(value),
+            _ => Err(GraphRecordError::ConversionError(format!(
+                "Cannot convert {value} into String"
+            ))),
+        }
+    }
+}
+
 fn canonicalize_float(value: f64) -> f64 {
     if value.is_nan() {
         f64::NAN
@@ -88,7 +147,8 @@ impl PartialEq for GraphRecordValue {
             (Self::Bool(value), Self::Bool(other)) => value == other,
             (Self::DateTime(value), Self::DateTime(other)) => value == other,
             (Self::Duration(value), Self::Duration(other)) => value == other,
-            (Self::Null, Self::Null) => true,
+            (Self::GeoPoint(value), Self::GeoPoint(other)) => value == other,
+            (Self::Null, Self::Null) | (Self::Missing, Self::Missing) => true,
             _ => false,
         }
     }
@@ -97,14 +157,50 @@ impl PartialEq for GraphRecordValue {
 impl Eq for GraphRecordValue {}
 
 impl GraphRecordValue {
+    #[must_use]
+    pub const fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+
+    #[must_use]
+    pub const fn is_missing(&self) -> bool {
+        matches!(self, Self::Missing)
+    }
+
+    /// Returns whether the value is a Float that is NaN.
+    #[must_use]
+    pub const fn is_nan(&self) -> bool {
+        matches!(self, Self::Float(value) if value.is_nan())
+    }
+
+    /// Like `==`, but two numbers of which at least one is a float are also
+    /// equal if they differ by at most `tolerance`.
+    #[must_use]
+    pub fn approx_eq(&self, other: &Self, tolerance: f64) -> bool {
+        if self == other {
+            return true;
+        }
+
+        match (self, other) {
+            (Self::Float(value), Self::Float(other)) => (value - other).abs() <= tolerance,
+            (Self::Int(int_value), Self::Float(float_value))
+            | (Self::Float(float_value), Self::Int(int_value)) => {
+                (*int_value as f64 - float_value).abs() <= tolerance
+            }
+            _ => false,
+        }
+    }
+
     const fn variant_rank(&self) -> u8 {
         match self {
-            Self::Null => 0,
-            Self::Bool(_) => 1,
-            Self::Int(_) | Self::Float(_) => 2,
-            Self::String(_) => 3,
-            Self::DateTime(_) => 4,
-            Self::Duration(_) => 5,
+            Self::Missing => 0,
+            Self::Null => 1,
+            Self::Bool(_) => 2,
+            Self::Int(_) | Self::Float(_) => 3,
+            Self::String(_) => 4,
+            Self::DateTime(_) => 5,
+            Self::Duration(_) => 6,
+            Self::GeoPoint(_) => 7,
         }
     }
 
@@ -119,7 +215,11 @@ impl GraphRecordValue {
             (Self::Bool(value), Self::Bool(other)) => value.cmp(other),
             (Self::DateTime(value), Self::DateTime(other)) => value.cmp(other),
             (Self::Duration(value), Self::Duration(other)) => value.cmp(other),
-            (Self::Null, Self::Null) => Ordering::Equal,
+            (Self::GeoPoint(value), Self::GeoPoint(other)) => value
+                .latitude()
+                .total_cmp(&other.latitude())
+                .then_with(|| value.longitude().total_cmp(&other.longitude())),
+            (Self::Null, Self::Null) | (Self::Missing, Self::Missing) => Ordering::Equal,
             _ => self.variant_rank().cmp(&other.variant_rank()),
         }
     }
@@ -132,6 +232,8 @@ impl GraphRecordValue {
             Self::DateTime(_) => 3_u8.hash(state),
             Self::Duration(_) => 4_u8.hash(state),
             Self::Null => 5_u8.hash(state),
+            Self::Missing => 6_u8.hash(state),
+            Self::GeoPoint(_) => 7_u8.hash(state),
         }
     }
 }
@@ -150,7 +252,11 @@ impl Hash for GraphRecordValue {
             Self::Bool(value) => value.hash(state),
             Self::DateTime(value) => value.hash(state),
             Self::Duration(value) => value.hash(state),
-            Self::Null => {}
+            Self::GeoPoint(value) => {
+                canonicalize_float(value.latitude()).to_bits().hash(state);
+                canonicalize_float(value.longitude()).to_bits().hash(state);
+            }
+            Self::Null | Self::Missing => {}
         }
     }
 }
@@ -167,7 +273,7 @@ impl PartialOrd for GraphRecordValue {
             (Self::Bool(value), Self::Bool(other)) => Some(value.cmp(other)),
             (Self::DateTime(value), Self::DateTime(other)) => Some(value.cmp(other)),
             (Self::Duration(value), Self::Duration(other)) => Some(value.cmp(other)),
-            (Self::Null, Self::Null) => Some(Ordering::Equal),
+            (Self::Null, Self::Null) | (Self::Missing, Self::Missing) => Some(Ordering::Equal),
             _ => None,
         }
     }
@@ -183,7 +289,9 @@ impl Display for GraphRecordValue {
             Self::Bool(value) => write!(f, "{value}"),
             Self::DateTime(value) => write!(f, "{value}"),
             Self::Duration(value) => write!(f, "{value}"),
+            Self::GeoPoint(value) => write!(f, "{value}"),
             Self::Null => write!(f, "Null"),
+            Self::Missing => write!(f, "Missing"),
         }
     }
 }
@@ -331,6 +439,18 @@ impl Add for GraphRecordValue {
             (Self::Null, Self::Null) => Err(GraphRecordError::AssertionError(
                 "Cannot add None to None".to_string(),
             )),
+            (Self::GeoPoint(value), rhs) => Err(GraphRecordError::AssertionError(format!(
+                "Cannot add {rhs} to {value}"
+            ))),
+            (value, Self::GeoPoint(rhs)) => Err(GraphRecordError::AssertionError(format!(
+                "Cannot add {rhs} to {value}"
+            ))),
+            (Self::Missing, other) => Err(GraphRecordError::AssertionError(format!(
+                "Cannot add {other} to Missing"
+            ))),
+            (value, Self::Missing) => Err(GraphRecordError::AssertionError(format!(
+                "Cannot add Missing to {value}"
+            ))),
         }
     }
 }
@@ -478,6 +598,18 @@ impl Sub for GraphRecordValue {
             (Self::Null, Self::Null) => Err(GraphRecordError::AssertionError(
                 "Cannot subtract None from None".to_string(),
             )),
+            (Self::GeoPoint(value), rhs) => Err(GraphRecordError::AssertionError(format!(
+                "Cannot subtract {rhs} from {value}"
+            ))),
+            (value, Self::GeoPoint(rhs)) => Err(GraphRecordError::AssertionError(format!(
+                "Cannot subtract {rhs} from {value}"
+            ))),
+            (Self::Missing, other) => Err(GraphRecordError::AssertionError(format!(
+                "Cannot subtract {other} from Missing"
+            ))),
+            (value, Self::Missing) => Err(GraphRecordError::AssertionError(format!(
+                "Cannot subtract Missing from {value}"
+            ))),
         }
     }
 }
@@ -635,6 +767,18 @@ impl Mul for GraphRecordValue {
             (Self::Null, Self::Null) => Err(GraphRecordError::AssertionError(
                 "Cannot multiplty None with None".to_string(),
             )),
+            (Self::GeoPoint(value), rhs) => Err(GraphRecordError::AssertionError(format!(
+                "Cannot multiply {value} with {rhs}"
+            ))),
+            (value, Self::GeoPoint(rhs)) => Err(GraphRecordError::AssertionError(format!(
+                "Cannot multiply {value} with {rhs}"
+            ))),
+            (Self::Missing, other) => Err(GraphRecordError::AssertionError(format!(
+                "Cannot multiply Missing with {other}"
+            ))),
+            (value, Self::Missing) => Err(GraphRecordError::AssertionError(format!(
+                "Cannot multiply {value} with Missing"
+            ))),
         }
     }
 }
@@ -786,6 +930,18 @@ impl Div for GraphRecordValue {
             (Self::Null, Self::Null) => Err(GraphRecordError::AssertionError(
                 "Cannot divide None by None".to_string(),
             )),
+            (Self::GeoPoint(value), rhs) => Err(GraphRecordError::AssertionError(format!(
+                "Cannot divide {value} by {rhs}"
+            ))),
+            (value, Self::GeoPoint(rhs)) => Err(GraphRecordError::AssertionError(format!(
+                "Cannot divide {value} by {rhs}"
+            ))),
+            (Self::Missing, other) => Err(GraphRecordError::AssertionError(format!(
+                "Cannot divide Missing by {other}"
+            ))),
+            (value, Self::Missing) => Err(GraphRecordError::AssertionError(format!(
+                "Cannot divide {value} by Missing"
+            ))),
         }
     }
 }
@@ -941,6 +1097,18 @@ impl Pow for GraphRecordValue {
             (Self::Null, Self::Null) => Err(GraphRecordError::AssertionError(
                 "Cannot raise None to the power of None".to_string(),
             )),
+            (Self::GeoPoint(value), rhs) => Err(GraphRecordError::AssertionError(format!(
+                "Cannot raise {value} to the power of {rhs}"
+            ))),
+            (value, Self::GeoPoint(rhs)) => Err(GraphRecordError::AssertionError(format!(
+                "Cannot raise {value} to the power of {rhs}"
+            ))),
+            (Self::Missing, other) => Err(GraphRecordError::AssertionError(format!(
+                "Cannot raise Missing to the power of {other}"
+            ))),
+            (value, Self::Missing) => Err(GraphRecordError::AssertionError(format!(
+                "Cannot raise {value} to the power of Missing"
+            ))),
         }
     }
 }
@@ -1088,6 +1256,18 @@ impl Mod for GraphRecordValue {
             (Self::Null, Self::Null) => Err(GraphRecordError::AssertionError(
                 "Cannot mod None with None".to_string(),
             )),
+            (Self::GeoPoint(value), rhs) => Err(GraphRecordError::AssertionError(format!(
+                "Cannot mod {value} with {rhs}"
+            ))),
+            (value, Self::GeoPoint(rhs)) => Err(GraphRecordError::AssertionError(format!(
+                "Cannot mod {value} with {rhs}"
+            ))),
+            (Self::Missing, other) => Err(GraphRecordError::AssertionError(format!(
+                "Cannot mod Missing with {other}"
+            ))),
+            (value, Self::Missing) => Err(GraphRecordError::AssertionError(format!(
+                "Cannot mod {value} with Missing"
+            ))),
         }
     }
 }
@@ -1273,12 +1453,15 @@ mod test {
     use crate::{
         errors::GraphRecordError,
         graphrecord::datatypes::{
-            Abs, Ceil, Floor, Lowercase, Mod, Pow, Round, Slice, Sqrt, Trim, TrimEnd, TrimStart,
-            Uppercase,
+            Abs, Ceil, Floor, GeoPoint, Lowercase, Mod, Pow, Round, Slice, Sqrt, Trim, TrimEnd,
+            TrimStart, Uppercase,
         },
     };
-    use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
-    use std::hash::{Hash, Hasher};
+    use chrono::{NaiveDate, NaiveDateTime, NaiveTime, TimeDelta};
+    use std::{
+        cmp::Ordering,
+        hash::{Hash, Hasher},
+    };
 
     #[test]
     fn test_default() {
@@ -1287,6 +1470,55 @@ mod test {
         assert_eq!(GraphRecordValue::Null, value);
     }
 
+    #[test]
+    fn test_missing() {
+        assert_eq!(GraphRecordValue::Missing, GraphRecordValue::Missing);
+        assert_ne!(GraphRecordValue::Null, GraphRecordValue::Missing);
+
+        assert!(GraphRecordValue::Missing.is_missing());
+        assert!(!GraphRecordValue::Missing.is_null());
+        assert!(GraphRecordValue::Null.is_null());
+        assert!(!GraphRecordValue::Null.is_missing());
+
+        assert_eq!("Missing", GraphRecordValue::Missing.to_string());
+
+        assert!(
+            (GraphRecordValue::Missing + GraphRecordValue::Int(1))
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
+        assert!(
+            (GraphRecordValue::Int(1) * GraphRecordValue::Missing)
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
+    }
+
+    #[test]
+    fn test_geo_point() {
+        let point = GeoPoint::new(52.52, 13.405).unwrap();
+        let value = GraphRecordValue::from(point);
+
+        assert_eq!(GraphRecordValue::GeoPoint(point), value);
+        assert_ne!(
+            GraphRecordValue::GeoPoint(GeoPoint::new(48.1351, 11.582).unwrap()),
+            value
+        );
+        assert_eq!("(52.52, 13.405)", value.to_string());
+        assert_eq!(None, value.partial_cmp(&value));
+        assert_eq!(
+            Ordering::Less,
+            GraphRecordValue::Duration(TimeDelta::seconds(1)).total_cmp(&value)
+        );
+
+        assert!(
+            (value.clone() + GraphRecordValue::Int(1))
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
+        assert!(
+            (GraphRecordValue::Float(1.0) - value)
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
+    }
+
     #[test]
     fn test_from_str() {
         let value = GraphRecordValue::from("value");
@@ -1340,6 +1572,27 @@ mod test {
         assert_eq!(GraphRecordValue::Null, value);
     }
 
+    #[test]
+    fn test_try_into_primitive() {
+        assert!((f64::try_from(GraphRecordValue::Float(1.5)).unwrap() - 1.5).abs() < f64::EPSILON);
+        assert!((f64::try_from(GraphRecordValue::Int(1)).unwrap() - 1.0).abs() < f64::EPSILON);
+        assert_eq!(1, i64::try_from(GraphRecordValue::Int(1)).unwrap());
+        assert!(bool::try_from(GraphRecordValue::Bool(true)).unwrap());
+        assert_eq!(
+            "value",
+            String::try_from(GraphRecordValue::from("value")).unwrap()
+        );
+
+        assert!(
+            f64::try_from(GraphRecordValue::from("value"))
+                .is_err_and(|e| matches!(e, GraphRecordError::ConversionError(_)))
+        );
+        assert!(
+            i64::try_from(GraphRecordValue::Float(1.5))
+                .is_err_and(|e| matches!(e, GraphRecordError::ConversionError(_)))
+        );
+    }
+
     #[test]
     fn test_partial_eq() {
         assert!(
@@ -3338,4 +3591,22 @@ mod test {
         assert_eq!(b, c);
         assert_ne!(a, c);
     }
+
+    #[test]
+    fn test_approx_eq() {
+        let value = GraphRecordValue::Float(0.1 + 0.2);
+
+        assert_ne!(value, GraphRecordValue::Float(0.3));
+        assert!(value.approx_eq(&GraphRecordValue::Float(0.3), 1e-9));
+        assert!(!value.approx_eq(&GraphRecordValue::Float(0.3), 0.0));
+        assert!(GraphRecordValue::Float(1.0005).approx_eq(&GraphRecordValue::Int(1), 1e-3));
+        assert!(!GraphRecordValue::Int(1).approx_eq(&GraphRecordValue::Int(2), 1.0 - 1e-9));
+        assert!(
+            GraphRecordValue::Float(f64::NAN).approx_eq(&GraphRecordValue::Float(f64::NAN), 0.0)
+        );
+        assert!(
+            !GraphRecordValue::String("0.3".to_string())
+                .approx_eq(&GraphRecordValue::Float(0.3), 1.0)
+        );
+    }
 }
@@ -0,0 +1,413 @@
+//! Trial runs of mutating operations, e.g. to validate a batch against the
+//! schema of a record before applying it.
+
+use super::{
+    AttributeMap, Attributes, EdgeIndex, GraphRecord, Group, NodeIndex,
+    overlay::GraphRecordOverlay,
+    querying::{
+        ReturnOperand, Selection, edges::EdgeOperand, nodes::NodeOperand, wrapper::Wrapper,
+    },
+    schema::Schema,
+    versioned::GraphRecordDiff,
+};
+use crate::errors::GraphRecordResult;
+use std::{mem, sync::Arc};
+
+/// Result of [`GraphRecord::dry_run`].
+#[derive(Debug)]
+pub struct DryRun<T> {
+    /// What the operation returned, including the error it failed with.
+    pub result: GraphRecordResult<T>,
+    /// Changes the operation staged before it returned. If it failed, these
+    /// are the changes made up to the failure.
+    pub diff: GraphRecordDiff,
+}
+
+impl<T> DryRun<T> {
+    #[must_use]
+    pub const fn is_ok(&self) -> bool {
+        self.result.is_ok()
+    }
+}
+
+/// Overlay a dry run stages its changes in.
+///
+/// Unlike [`GraphRecordOverlay`], it can neither be cloned nor hand out the
+/// record it reads through, so the record lent to the dry run cannot outlive
+/// it.
+#[derive(Debug)]
+pub struct DryRunOverlay {
+    overlay: GraphRecordOverlay,
+}
+
+impl DryRunOverlay {
+    #[must_use]
+    pub const fn get_schema(&self) -> &Schema {
+        self.overlay.get_schema()
+    }
+
+    /// Returns `true` if no changes are staged.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.overlay.is_empty()
+    }
+
+    #[must_use]
+    pub fn contains_node(&self, node_index: &NodeIndex) -> bool {
+        self.overlay.contains_node(node_index)
+    }
+
+    #[must_use]
+    pub fn contains_edge(&self, edge_index: &EdgeIndex) -> bool {
+        self.overlay.contains_edge(edge_index)
+    }
+
+    #[must_use]
+    pub fn node_count(&self) -> usize {
+        self.overlay.node_count()
+    }
+
+    #[must_use]
+    pub fn edge_count(&self) -> usize {
+        self.overlay.edge_count()
+    }
+
+    pub fn node_indices(&self) -> impl Iterator<Item = &NodeIndex> {
+        self.overlay.node_indices()
+    }
+
+    pub fn edge_indices(&self) -> impl Iterator<Item = &EdgeIndex> {
+        self.overlay.edge_indices()
+    }
+
+    pub fn node_attributes(&self, node_index: &NodeIndex) -> GraphRecordResult<&AttributeMap> {
+        self.overlay.node_attributes(node_index)
+    }
+
+    pub fn edge_attributes(&self, edge_index: &EdgeIndex) -> GraphRecordResult<&AttributeMap> {
+        self.overlay.edge_attributes(edge_index)
+    }
+
+    pub fn edge_endpoints(
+        &self,
+        edge_index: &EdgeIndex,
+    ) -> GraphRecordResult<(&NodeIndex, &NodeIndex)> {
+        self.overlay.edge_endpoints(edge_index)
+    }
+
+    pub fn outgoing_edges(
+        &self,
+        node_index: &NodeIndex,
+    ) -> GraphRecordResult<impl Iterator<Item = &EdgeIndex> + use<'_>> {
+        self.overlay.outgoing_edges(node_index)
+    }
+
+    pub fn incoming_edges(
+        &self,
+        node_index: &NodeIndex,
+    ) -> GraphRecordResult<impl Iterator<Item = &EdgeIndex> + use<'_>> {
+        self.overlay.incoming_edges(node_index)
+    }
+
+    pub fn groups(&self) -> impl Iterator<Item = &Group> {
+        self.overlay.groups()
+    }
+
+    #[must_use]
+    pub fn contains_group(&self, group: &Group) -> bool {
+        self.overlay.contains_group(group)
+    }
+
+    pub fn nodes_in_group(
+        &self,
+        group: &Group,
+    ) -> GraphRecordResult<impl Iterator<Item = &NodeIndex> + use<'_>> {
+        self.overlay.nodes_in_group(group)
+    }
+
+    pub fn edges_in_group(
+        &self,
+        group: &Group,
+    ) -> GraphRecordResult<impl Iterator<Item = &EdgeIndex> + use<'_>> {
+        self.overlay.edges_in_group(group)
+    }
+
+    pub fn ungrouped_nodes(&self) -> impl Iterator<Item = &NodeIndex> {
+        self.overlay.ungrouped_nodes()
+    }
+
+    pub fn ungrouped_edges(&self) -> impl Iterator<Item = &EdgeIndex> {
+        self.overlay.ungrouped_edges()
+    }
+
+    pub fn groups_of_node(
+        &self,
+        node_index: &NodeIndex,
+    ) -> GraphRecordResult<impl Iterator<Item = &Group> + use<'_>> {
+        self.overlay.groups_of_node(node_index)
+    }
+
+    pub fn groups_of_edge(
+        &self,
+        edge_index: &EdgeIndex,
+    ) -> GraphRecordResult<impl Iterator<Item = &Group> + use<'_>> {
+        self.overlay.groups_of_edge(edge_index)
+    }
+
+    pub fn add_node(
+        &mut self,
+        node_index: NodeIndex,
+        attributes: Attributes,
+    ) -> GraphRecordResult<()> {
+        self.overlay.add_node(node_index, attributes)
+    }
+
+    pub fn replace_node_attributes(
+        &mut self,
+        node_index: NodeIndex,
+        attributes: Attributes,
+    ) -> GraphRecordResult<()> {
+        self.overlay.replace_node_attributes(node_index, attributes)
+    }
+
+    /// Removes the node and all of its edges.
+    pub fn remove_node(&mut self, node_index: &NodeIndex) -> GraphRecordResult<Attributes> {
+        self.overlay.remove_node(node_index)
+    }
+
+    pub fn add_edge(
+        &mut self,
+        source_node_index: NodeIndex,
+        target_node_index: NodeIndex,
+        attributes: Attributes,
+    ) -> GraphRecordResult<EdgeIndex> {
+        self.overlay
+            .add_edge(source_node_index, target_node_index, attributes)
+    }
+
+    pub fn replace_edge_attributes(
+        &mut self,
+        edge_index: EdgeIndex,
+        attributes: Attributes,
+    ) -> GraphRecordResult<()> {
+        self.overlay.replace_edge_attributes(edge_index, attributes)
+    }
+
+    pub fn remove_edge(&mut self, edge_index: &EdgeIndex) -> GraphRecordResult<Attributes> {
+        self.overlay.remove_edge(edge_index)
+    }
+
+    /// Changes staged so far, see [`GraphRecordOverlay::diff`].
+    #[must_use]
+    pub fn diff(&self) -> GraphRecordDiff {
+        self.overlay.diff()
+    }
+
+    /// Like [`GraphRecord::query_nodes`], run against the record as seen
+    /// through the staged changes.
+    pub fn query_nodes<'a, Q, R>(&'a self, query: Q) -> Selection<'a, R>
+    where
+        Q: FnOnce(&Wrapper<NodeOperand>) -> R,
+        R: ReturnOperand<'a>,
+    {
+        self.overlay.query_nodes(query)
+    }
+
+    /// Like [`GraphRecord::query_edges`], run against the record as seen
+    /// through the staged changes.
+    pub fn query_edges<'a, Q, R>(&'a self, query: Q) -> Selection<'a, R>
+    where
+        Q: FnOnce(&Wrapper<EdgeOperand>) -> R,
+        R: ReturnOperand<'a>,
+    {
+        self.overlay.query_edges(query)
+    }
+}
+
+/// Record moved out of its place to serve as the base of a dry run.
+struct LentRecord<'a> {
+    graphrecord: &'a mut GraphRecord,
+    base: Option<Arc<GraphRecord>>,
+}
+
+impl<'a> LentRecord<'a> {
+    fn new(graphrecord: &'a mut GraphRecord) -> Self {
+        let base = Arc::new(mem::take(graphrecord));
+
+        Self {
+            graphrecord,
+            base: Some(base),
+        }
+    }
+
+    fn base(&self) -> Arc<GraphRecord> {
+        self.base.clone().expect("Record must be lent")
+    }
+
+    /// Moves the record back into its place.
+    ///
+    /// The overlay of the dry run must be dropped before. The record is never
+    /// copied, so if something still holds it, which only swapping the
+    /// overlays of nested dry runs can do, this panics.
+    fn restore(mut self) {
+        let base = self.base.take().expect("Record must be lent");
+
+        *self.graphrecord =
+            Arc::try_unwrap(base).expect("Record lent to a dry run must not outlive it");
+    }
+}
+
+impl Drop for LentRecord<'_> {
+    /// Moves the record back if the dry run panicked before restoring it.
+    fn drop(&mut self) {
+        if let Some(base) = self.base.take()
+            && let Ok(base) = Arc::try_unwrap(base)
+        {
+            *self.graphrecord = base;
+        }
+    }
+}
+
+impl GraphRecord {
+    /// Runs `operation` against an overlay of the record and discards the
+    /// overlay afterwards.
+    ///
+    /// The overlay reads the record as it is without copying it. Changes are
+    /// staged in the overlay and validated like any other mutation, including
+    /// the hooks of the plugins of the record, but the record itself is not
+    /// changed. Subscribers are not notified. A frozen record fails the run
+    /// without running `operation`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the overlay of the dry run was swapped with the one of a
+    /// nested dry run, as the record could then only be restored by copying
+    /// it.
+    pub fn dry_run<T>(
+        &mut self,
+        operation: impl FnOnce(&mut DryRunOverlay) -> GraphRecordResult<T>,
+    ) -> DryRun<T> {
+        if let Err(error) = self.assert_not_frozen() {
+            return DryRun {
+                result: Err(error),
+                diff: GraphRecordDiff::default(),
+            };
+        }
+
+        let lent = LentRecord::new(self);
+        let mut overlay = DryRunOverlay {
+            overlay: GraphRecordOverlay::new(lent.base()),
+        };
+
+        let result = operation(&mut overlay);
+        let diff = overlay.diff();
+
+        drop(overlay);
+        lent.restore();
+
+        DryRun { result, diff }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        GraphRecord,
+        errors::GraphRecordError,
+        graphrecord::schema::{GroupSchema, Schema},
+    };
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_dry_run() {
+        let mut graphrecord = GraphRecord::new();
+        graphrecord.add_node("0".into(), HashMap::new()).unwrap();
+
+        let attributes: *const _ = graphrecord.node_attributes(&"0".into()).unwrap();
+
+        let dry_run = graphrecord.dry_run(|graphrecord| {
+            graphrecord.add_node("1".into(), HashMap::new())?;
+            graphrecord.add_edge("0".into(), "1".into(), HashMap::new())
+        });
+
+        assert!(dry_run.is_ok());
+        assert_eq!(0, dry_run.result.unwrap());
+        assert_eq!(vec![("1".into(), HashMap::new())], dry_run.diff.added_nodes);
+        assert_eq!(1, dry_run.diff.added_edges.len());
+
+        assert_eq!(1, graphrecord.node_count());
+        assert_eq!(0, graphrecord.edge_count());
+
+        // The record is moved back, not copied
+        assert!(std::ptr::eq(
+            attributes,
+            graphrecord.node_attributes(&"0".into()).unwrap()
+        ));
+    }
+
+    #[test]
+    fn test_dry_run_error() {
+        let mut graphrecord =
+            GraphRecord::with_schema(Schema::new_provided(HashMap::new(), GroupSchema::default()));
+        graphrecord.add_node("0".into(), HashMap::new()).unwrap();
+
+        let dry_run = graphrecord.dry_run(|graphrecord| {
+            graphrecord.add_node("1".into(), HashMap::new())?;
+            graphrecord.add_node("2".into(), HashMap::from([("age".into(), 20.into())]))
+        });
+
+        assert!(
+            dry_run
+                .result
+                .is_err_and(|error| matches!(error, GraphRecordError::SchemaError(_)))
+        );
+        assert_eq!(vec![("1".into(), HashMap::new())], dry_run.diff.added_nodes);
+        assert_eq!(1, graphrecord.node_count());
+
+        graphrecord.freeze();
+
+        let dry_run = graphrecord.dry_run(|graphrecord| graphrecord.remove_node(&"0".into()));
+
+        assert!(
+            dry_run
+                .result
+                .is_err_and(|error| matches!(error, GraphRecordError::ReadOnlyError(_)))
+        );
+        assert!(dry_run.diff.removed_nodes.is_empty());
+    }
+
+    #[cfg(feature = "plugins")]
+    #[test]
+    fn test_dry_run_plugins() {
+        use crate::graphrecord::plugins::MappingPlugin;
+
+        let mut graphrecord = GraphRecord::new();
+        graphrecord
+            .add_plugin(
+                "mapping".into(),
+                Box::new(MappingPlugin::new(
+                    HashMap::from([(
+                        "code".into(),
+                        HashMap::from([("GLU".into(), "2345-7".into())]),
+                    )]),
+                    HashMap::new(),
+                )),
+            )
+            .unwrap();
+
+        let dry_run = graphrecord.dry_run(|graphrecord| {
+            graphrecord.add_node("0".into(), HashMap::from([("code".into(), "GLU".into())]))
+        });
+
+        assert!(dry_run.is_ok());
+        assert_eq!(
+            vec![(
+                "0".into(),
+                HashMap::from([("code".into(), "2345-7".into())])
+            )],
+            dry_run.diff.added_nodes
+        );
+        assert_eq!(0, graphrecord.node_count());
+        assert!(graphrecord.plugin_names().next().is_some());
+    }
+}
@@ -0,0 +1,340 @@
+use super::{
+    AttributeMap, GraphRecord, GraphRecordAttribute, GraphRecordValue,
+    schema::{AttributeDataType, GroupSchema, Schema, SchemaType},
+};
+use std::{
+    collections::HashMap,
+    hash::{Hash, Hasher},
+};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// 64-bit FNV-1a hasher writing integers as little endian, so hashes are the
+/// same across platforms and processes.
+struct StableHasher(u64);
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self(FNV_OFFSET_BASIS)
+    }
+}
+
+impl Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_i16(&mut self, i: i16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_i32(&mut self, i: i32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_i64(&mut self, i: i64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_i128(&mut self, i: i128) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_isize(&mut self, i: isize) {
+        self.write_i64(i as i64);
+    }
+}
+
+fn hash_one(value: impl Hash) -> u64 {
+    let mut hasher = StableHasher::default();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Combines hashes independently of their order.
+fn combine(mut hashes: Vec<u64>) -> u64 {
+    hashes.sort_unstable();
+
+    let mut hasher = StableHasher::default();
+    hasher.write_usize(hashes.len());
+    for hash in hashes {
+        hasher.write_u64(hash);
+    }
+    hasher.finish()
+}
+
+// The `Hash` implementations of attributes and values do not distinguish
+// between variants that compare equal, so the variant is hashed explicitly.
+fn attribute_hash(attribute: &GraphRecordAttribute) -> u64 {
+    match attribute {
+        GraphRecordAttribute::Int(value) => hash_one((0_u8, value)),
+        GraphRecordAttribute::String(value) => hash_one((1_u8, value)),
+    }
+}
+
+fn value_hash(value: &GraphRecordValue) -> u64 {
+    let variant: u8 = match value {
+        GraphRecordValue::String(_) => 0,
+        GraphRecordValue::Int(_) => 1,
+        GraphRecordValue::Float(_) => 2,
+        GraphRecordValue::Bool(_) => 3,
+        GraphRecordValue::DateTime(_) => 4,
+        GraphRecordValue::Duration(_) => 5,
+        GraphRecordValue::Null => 6,
+        GraphRecordValue::Missing => 7,
+        GraphRecordValue::GeoPoint(_) => 8,
+    };
+
+    hash_one((variant, value))
+}
+
+fn attributes_hash(attributes: &AttributeMap) -> u64 {
+    combine(
+        attributes
+            .iter()
+            .map(|(key, value)| hash_one((attribute_hash(key), value_hash(value))))
+            .collect(),
+    )
+}
+
+fn groups_hash<'a>(groups: impl Iterator<Item = &'a GraphRecordAttribute>) -> u64 {
+    combine(groups.map(attribute_hash).collect())
+}
+
+fn attribute_schema_hash(mapping: &HashMap<GraphRecordAttribute, AttributeDataType>) -> u64 {
+    combine(
+        mapping
+            .iter()
+            .map(|(attribute, data_type)| {
+                hash_one((
+                    attribute_hash(attribute),
+                    data_type.data_type().to_string(),
+                    *data_type.attribute_type() as u8,
+                ))
+            })
+            .collect(),
+    )
+}
+
+fn group_schema_hash(group_schema: &GroupSchema) -> u64 {
+    hash_one((
+        attribute_schema_hash(group_schema.nodes()),
+        attribute_schema_hash(group_schema.edges()),
+    ))
+}
+
+fn schema_hash(schema: &Schema) -> u64 {
+    let groups = combine(
+        schema
+            .groups()
+            .iter()
+            .map(|(group, group_schema)| {
+                hash_one((attribute_hash(group), group_schema_hash(group_schema)))
+            })
+            .collect(),
+    );
+
+    hash_one((
+        matches!(schema.schema_type(), SchemaType::Provided),
+        group_schema_hash(schema.ungrouped()),
+        groups,
+    ))
+}
+
+/// Hashes the content of `graphrecord`.
+///
+/// Edges are identified by their endpoints, direction, attributes and groups
+/// rather than their index, so records that differ only in edge numbering, e.g. after
+/// [`GraphRecord::compact`], share a fingerprint.
+pub(super) fn fingerprint(graphrecord: &GraphRecord) -> u64 {
+    let nodes = combine(
+        graphrecord
+            .node_indices()
+            .map(|node_index| {
+                let attributes = graphrecord
+                    .node_attributes(node_index)
+                    .expect("Node must exist");
+                let groups = graphrecord
+                    .groups_of_node(node_index)
+                    .expect("Node must exist");
+
+                hash_one((
+                    attribute_hash(node_index),
+                    attributes_hash(attributes),
+                    groups_hash(groups),
+                ))
+            })
+            .collect(),
+    );
+
+    let edges = combine(
+        graphrecord
+            .edge_indices()
+            .map(|edge_index| {
+                let (source_node_index, target_node_index) = graphrecord
+                    .edge_endpoints(edge_index)
+                    .expect("Edge must exist");
+                let attributes = graphrecord
+                    .edge_attributes(edge_index)
+                    .expect("Edge must exist");
+                let groups = graphrecord
+                    .groups_of_edge(edge_index)
+                    .expect("Edge must exist");
+                let undirected = graphrecord
+                    .is_edge_undirected(edge_index)
+                    .expect("Edge must exist");
+
+                let source_hash = attribute_hash(source_node_index);
+                let target_hash = attribute_hash(target_node_index);
+
+                // The endpoints of an undirected edge are an unordered pair
+                let endpoints = if undirected {
+                    (source_hash.min(target_hash), source_hash.max(target_hash))
+                } else {
+                    (source_hash, target_hash)
+                };
+
+                hash_one((
+                    endpoints,
+                    undirected,
+                    attributes_hash(attributes),
+                    groups_hash(groups),
+                ))
+            })
+            .collect(),
+    );
+
+    hash_one((
+        nodes,
+        edges,
+        groups_hash(graphrecord.groups()),
+        schema_hash(graphrecord.get_schema()),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        GraphRecord,
+        graphrecord::{Attributes, NodeIndex},
+    };
+    use std::collections::HashMap;
+
+    fn create_graphrecord(reversed: bool) -> GraphRecord {
+        let mut nodes: Vec<(NodeIndex, Attributes)> = vec![
+            (
+                "0".into(),
+                HashMap::from([("lorem".into(), "ipsum".into())]),
+            ),
+            ("1".into(), HashMap::from([("dolor".into(), 1.into())])),
+            (2.into(), HashMap::new()),
+        ];
+        let mut edges: Vec<(NodeIndex, NodeIndex, Attributes)> = vec![
+            ("0".into(), "1".into(), HashMap::new()),
+            (
+                "1".into(),
+                2.into(),
+                HashMap::from([("sit".into(), 1.5.into())]),
+            ),
+        ];
+
+        if reversed {
+            nodes.reverse();
+            edges.reverse();
+        }
+
+        let mut graphrecord = GraphRecord::from_tuples(nodes, Some(edges), None).unwrap();
+        graphrecord
+            .add_group("group".into(), Some(vec!["0".into()]), None)
+            .unwrap();
+
+        graphrecord
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        let graphrecord = create_graphrecord(false);
+
+        assert_eq!(
+            graphrecord.fingerprint(),
+            create_graphrecord(false).fingerprint()
+        );
+        assert_eq!(
+            graphrecord.fingerprint(),
+            create_graphrecord(true).fingerprint()
+        );
+
+        let mut modified = graphrecord.clone();
+        modified
+            .node_attributes_mut(&"1".into())
+            .unwrap()
+            .update_attribute(&"dolor".into(), 1.0.into())
+            .unwrap();
+        assert_ne!(graphrecord.fingerprint(), modified.fingerprint());
+
+        let mut modified = graphrecord.clone();
+        modified
+            .remove_node_from_group(&"group".into(), &"0".into())
+            .unwrap();
+        assert_ne!(graphrecord.fingerprint(), modified.fingerprint());
+
+        let mut modified = graphrecord.clone();
+        modified.add_group("empty".into(), None, None).unwrap();
+        assert_ne!(graphrecord.fingerprint(), modified.fingerprint());
+    }
+
+    #[test]
+    fn test_fingerprint_undirected() {
+        let graphrecord = create_graphrecord(false);
+        let edge_index = graphrecord
+            .edges_connecting(vec![&"0".into()], vec![&"1".into()])
+            .next()
+            .copied()
+            .unwrap();
+
+        let mut undirected = graphrecord.clone();
+        undirected.set_edge_undirected(&edge_index, true).unwrap();
+        assert_ne!(graphrecord.fingerprint(), undirected.fingerprint());
+
+        // An undirected edge hashes the same in either orientation
+        let mut reversed = graphrecord.clone();
+        reversed.remove_edge(&edge_index).unwrap();
+        let reversed_edge_index = reversed
+            .add_edge("1".into(), "0".into(), HashMap::new())
+            .unwrap();
+        assert_ne!(graphrecord.fingerprint(), reversed.fingerprint());
+
+        reversed
+            .set_edge_undirected(&reversed_edge_index, true)
+            .unwrap();
+        assert_eq!(undirected.fingerprint(), reversed.fingerprint());
+    }
+}
@@ -0,0 +1,77 @@
+//! Read-only ownership of a record.
+
+use super::GraphRecord;
+use std::ops::Deref;
+
+/// A [`GraphRecord`] that can only be read, created by
+/// [`GraphRecord::into_frozen`].
+///
+/// The record is only reachable through shared references, so it cannot be
+/// modified without [`FrozenGraphRecord::into_inner`]. It is frozen as well,
+/// so clones of it stay read-only until they are unfrozen.
+#[derive(Debug, Clone)]
+pub struct FrozenGraphRecord(GraphRecord);
+
+impl Deref for FrozenGraphRecord {
+    type Target = GraphRecord;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<GraphRecord> for FrozenGraphRecord {
+    fn as_ref(&self) -> &GraphRecord {
+        &self.0
+    }
+}
+
+impl FrozenGraphRecord {
+    /// Returns the unfrozen record.
+    #[must_use]
+    pub fn into_inner(mut self) -> GraphRecord {
+        self.0.unfreeze();
+
+        self.0
+    }
+}
+
+impl GraphRecord {
+    /// Freezes the record and wraps it so that it can only be read.
+    #[must_use]
+    pub const fn into_frozen(mut self) -> FrozenGraphRecord {
+        self.freeze();
+
+        FrozenGraphRecord(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{GraphRecord, errors::GraphRecordError};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_into_frozen() {
+        let mut graphrecord = GraphRecord::new();
+        graphrecord.add_node("0".into(), HashMap::new()).unwrap();
+
+        let frozen = graphrecord.into_frozen();
+
+        assert!(frozen.is_frozen());
+        assert_eq!(1, frozen.node_count());
+
+        let mut clone = GraphRecord::clone(&frozen);
+
+        assert!(
+            clone
+                .add_node("1".into(), HashMap::new())
+                .is_err_and(|error| matches!(error, GraphRecordError::ReadOnlyError(_)))
+        );
+
+        let mut graphrecord = frozen.into_inner();
+
+        assert!(!graphrecord.is_frozen());
+        graphrecord.add_node("1".into(), HashMap::new()).unwrap();
+    }
+}
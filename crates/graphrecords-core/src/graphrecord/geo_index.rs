@@ -0,0 +1,391 @@
+//! R-tree indices over a `GeoPoint` attribute of the nodes in a group.
+//!
+//! An index is created with [`GraphRecord::create_geo_index`] and used by the
+//! `within_radius` operation of value operands. Like text indices, it is
+//! rebuilt lazily on the first query after the record changed.
+
+use super::{GraphRecord, GraphRecordAttribute, GraphRecordValue, Group, NodeIndex};
+use crate::{
+    errors::{GraphRecordError, GraphRecordResult},
+    graphrecord::{datatypes::GeoPoint, subscription::ChangeToken},
+};
+use graphrecords_utils::aliases::GrHashMap;
+use parking_lot::RwLock;
+use rstar::{AABB, RTree, primitives::GeomWithData};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+type GeoIndexEntry = GeomWithData<[f64; 2], (NodeIndex, GeoPoint)>;
+
+#[derive(Debug, Clone)]
+struct GeoIndexState {
+    change_token: ChangeToken,
+    tree: RTree<GeoIndexEntry>,
+}
+
+impl GeoIndexState {
+    fn build(graphrecord: &GraphRecord, group: &Group, attribute: &GraphRecordAttribute) -> Self {
+        let mut entries = Vec::new();
+
+        // A removed group leaves the index empty until it is added again
+        if let Ok(nodes) = graphrecord.nodes_in_group(group) {
+            for node_index in nodes {
+                let Some(GraphRecordValue::GeoPoint(point)) = graphrecord
+                    .node_attributes(node_index)
+                    .expect("Node must exist")
+                    .get(attribute)
+                else {
+                    continue;
+                };
+
+                entries.push(GeomWithData::new(
+                    [point.longitude(), point.latitude()],
+                    (node_index.clone(), *point),
+                ));
+            }
+        }
+
+        Self {
+            change_token: graphrecord.change_token(),
+            tree: RTree::bulk_load(entries),
+        }
+    }
+
+    fn within_radius(&self, center: &GeoPoint, radius_km: f64) -> GrHashMap<NodeIndex, GeoPoint> {
+        let (south_west, north_east) = center.bounding_box(radius_km);
+        let envelope = AABB::from_corners(
+            [south_west.longitude(), south_west.latitude()],
+            [north_east.longitude(), north_east.latitude()],
+        );
+
+        self.tree
+            .locate_in_envelope(&envelope)
+            .filter(|entry| entry.data.1.distance_km(center) <= radius_km)
+            .map(|entry| entry.data.clone())
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct GeoIndex {
+    group: Group,
+    attribute: GraphRecordAttribute,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    state: RwLock<Option<GeoIndexState>>,
+}
+
+impl Clone for GeoIndex {
+    fn clone(&self) -> Self {
+        Self {
+            group: self.group.clone(),
+            attribute: self.attribute.clone(),
+            state: RwLock::new(self.state.read().clone()),
+        }
+    }
+}
+
+impl GeoIndex {
+    fn is_stale(&self, change_token: ChangeToken) -> bool {
+        self.state
+            .read()
+            .as_ref()
+            .is_none_or(|state| state.change_token != change_token)
+    }
+
+    fn with_state<R>(&self, graphrecord: &GraphRecord, f: impl FnOnce(&GeoIndexState) -> R) -> R {
+        let change_token = graphrecord.change_token();
+
+        {
+            let state = self.state.read();
+
+            if let Some(state) = state
+                .as_ref()
+                .filter(|state| state.change_token == change_token)
+            {
+                return f(state);
+            }
+        }
+
+        let mut state = self.state.write();
+
+        if state
+            .as_ref()
+            .is_none_or(|state| state.change_token != change_token)
+        {
+            *state = Some(GeoIndexState::build(
+                graphrecord,
+                &self.group,
+                &self.attribute,
+            ));
+        }
+
+        f(state.as_ref().expect("State must be built"))
+    }
+}
+
+/// Geo indices of a `GraphRecord`. Only the definitions of the indices are
+/// persisted, their contents are built on demand.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GeoIndexStore {
+    indices: Vec<GeoIndex>,
+}
+
+impl GeoIndexStore {
+    #[cfg(feature = "serde")]
+    pub const fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Builds the contents of every index that is outdated, instead of waiting
+    /// for the next query. Returns the number of indices built.
+    pub fn rebuild_stale(&self, graphrecord: &GraphRecord) -> usize {
+        let change_token = graphrecord.change_token();
+        let mut rebuilt = 0;
+
+        for index in &self.indices {
+            if index.is_stale(change_token) {
+                index.with_state(graphrecord, |_| ());
+                rebuilt += 1;
+            }
+        }
+
+        rebuilt
+    }
+}
+
+impl GraphRecord {
+    /// Creates an r-tree index over the `GeoPoint` values of `attribute` on the
+    /// nodes in `group`, speeding up `within_radius` on the values of that
+    /// attribute.
+    pub fn create_geo_index(
+        &mut self,
+        group: Group,
+        attribute: GraphRecordAttribute,
+    ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
+        if !self.contains_group(&group) {
+            return Err(GraphRecordError::IndexError(format!(
+                "Cannot find group {group}"
+            )));
+        }
+
+        if self
+            .geo_indices
+            .indices
+            .iter()
+            .any(|index| index.group == group && index.attribute == attribute)
+        {
+            return Err(GraphRecordError::AssertionError(format!(
+                "Geo index on attribute {attribute} of group {group} already exists"
+            )));
+        }
+
+        let state = GeoIndexState::build(self, &group, &attribute);
+
+        self.geo_indices.indices.push(GeoIndex {
+            group,
+            attribute,
+            state: RwLock::new(Some(state)),
+        });
+
+        Ok(())
+    }
+
+    pub fn drop_geo_index(
+        &mut self,
+        group: &Group,
+        attribute: &GraphRecordAttribute,
+    ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
+        let position = self
+            .geo_indices
+            .indices
+            .iter()
+            .position(|index| &index.group == group && &index.attribute == attribute)
+            .ok_or_else(|| {
+                GraphRecordError::KeyError(format!(
+                    "Geo index on attribute {attribute} of group {group} does not exist"
+                ))
+            })?;
+
+        self.geo_indices.indices.remove(position);
+
+        Ok(())
+    }
+
+    /// Returns the group and attribute of every geo index in creation order.
+    #[must_use]
+    pub fn geo_indices(&self) -> Vec<(&Group, &GraphRecordAttribute)> {
+        self.geo_indices
+            .indices
+            .iter()
+            .map(|index| (&index.group, &index.attribute))
+            .collect()
+    }
+
+    /// Returns the indexed nodes whose `attribute` lies within `radius_km` of
+    /// `center`, together with the indexed point. Returns `None` if there is no
+    /// geo index on `attribute`. Nodes outside of the indexed groups are not
+    /// covered, so the caller has to measure their distance itself.
+    pub(crate) fn geo_index_within_radius(
+        &self,
+        attribute: &GraphRecordAttribute,
+        center: &GeoPoint,
+        radius_km: f64,
+    ) -> Option<GrHashMap<NodeIndex, GeoPoint>> {
+        let mut indices = self
+            .geo_indices
+            .indices
+            .iter()
+            .filter(|index| &index.attribute == attribute)
+            .peekable();
+
+        indices.peek()?;
+
+        Some(
+            indices
+                .flat_map(|index| {
+                    index.with_state(self, |state| state.within_radius(center, radius_km))
+                })
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        GraphRecord,
+        errors::GraphRecordError,
+        graphrecord::{GraphRecordAttribute, GraphRecordValue, datatypes::GeoPoint},
+    };
+    use std::collections::HashMap;
+
+    fn create_graphrecord() -> GraphRecord {
+        let mut graphrecord = GraphRecord::from_tuples(
+            vec![
+                (
+                    "berlin".into(),
+                    HashMap::from([(
+                        "location".into(),
+                        GeoPoint::new(52.52, 13.405).unwrap().into(),
+                    )]),
+                ),
+                (
+                    "potsdam".into(),
+                    HashMap::from([(
+                        "location".into(),
+                        GeoPoint::new(52.3906, 13.0645).unwrap().into(),
+                    )]),
+                ),
+                (
+                    "munich".into(),
+                    HashMap::from([(
+                        "location".into(),
+                        GeoPoint::new(48.1351, 11.582).unwrap().into(),
+                    )]),
+                ),
+            ],
+            None,
+            None,
+        )
+        .unwrap();
+
+        graphrecord
+            .add_group(
+                "facilities".into(),
+                Some(vec!["berlin".into(), "potsdam".into(), "munich".into()]),
+                None,
+            )
+            .unwrap();
+
+        graphrecord
+    }
+
+    #[test]
+    fn test_geo_index() {
+        let mut graphrecord = create_graphrecord();
+        let attribute = GraphRecordAttribute::from("location");
+        let berlin = GeoPoint::new(52.52, 13.405).unwrap();
+
+        assert_eq!(
+            None,
+            graphrecord.geo_index_within_radius(&attribute, &berlin, 50.0)
+        );
+
+        graphrecord
+            .create_geo_index("facilities".into(), attribute.clone())
+            .unwrap();
+
+        assert_eq!(
+            vec![(&GraphRecordAttribute::from("facilities"), &attribute)],
+            graphrecord.geo_indices()
+        );
+
+        let mut nodes: Vec<_> = graphrecord
+            .geo_index_within_radius(&attribute, &berlin, 50.0)
+            .unwrap()
+            .into_keys()
+            .collect();
+        nodes.sort_by_key(ToString::to_string);
+
+        assert_eq!(
+            vec![GraphRecordAttribute::from("berlin"), "potsdam".into()],
+            nodes
+        );
+
+        // The index is rebuilt after the record changed
+        let munich = GeoPoint::new(48.1351, 11.582).unwrap();
+
+        graphrecord
+            .node_attributes_mut(&"potsdam".into())
+            .unwrap()
+            .update_attribute(&attribute, GraphRecordValue::GeoPoint(munich))
+            .unwrap();
+
+        assert_eq!(
+            vec![GraphRecordAttribute::from("berlin")],
+            graphrecord
+                .geo_index_within_radius(&attribute, &berlin, 50.0)
+                .unwrap()
+                .into_keys()
+                .collect::<Vec<_>>()
+        );
+
+        graphrecord
+            .drop_geo_index(&"facilities".into(), &attribute)
+            .unwrap();
+
+        assert!(graphrecord.geo_indices().is_empty());
+    }
+
+    #[test]
+    fn test_invalid_geo_index() {
+        let mut graphrecord = create_graphrecord();
+
+        assert!(
+            graphrecord
+                .create_geo_index("patients".into(), "location".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+        );
+
+        graphrecord
+            .create_geo_index("facilities".into(), "location".into())
+            .unwrap();
+
+        assert!(
+            graphrecord
+                .create_geo_index("facilities".into(), "location".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
+        assert!(
+            graphrecord
+                .drop_geo_index(&"facilities".into(), &"address".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::KeyError(_)))
+        );
+    }
+}
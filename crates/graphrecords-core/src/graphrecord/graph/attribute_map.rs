@@ -0,0 +1,547 @@
+use super::Attributes;
+use crate::graphrecord::{GraphRecordAttribute, GraphRecordValue};
+use graphrecords_utils::aliases::GrBuildHasher;
+#[cfg(feature = "serde")]
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{MapAccess, Visitor},
+    ser::SerializeMap,
+};
+use std::{
+    collections::{HashMap, hash_map},
+    fmt, mem,
+    ops::Index,
+    slice, vec,
+};
+
+type Entry = (GraphRecordAttribute, GraphRecordValue);
+type Table = HashMap<GraphRecordAttribute, GraphRecordValue, GrBuildHasher>;
+
+/// Number of attributes up to which they are stored in a plain vector.
+pub const SMALL_CAPACITY: usize = 8;
+
+#[derive(Clone)]
+enum Storage {
+    Small(Vec<Entry>),
+    Large(Box<Table>),
+}
+
+/// Attributes of a node or edge as they are stored in a `GraphRecord`.
+///
+/// Most nodes and edges have a handful of attributes, for which a hash table
+/// wastes memory on spare buckets and control bytes. Up to
+/// [`SMALL_CAPACITY`] attributes are therefore kept in a vector and looked up
+/// by a linear scan, and only wider nodes and edges switch to a hash table.
+/// The order of the attributes is unspecified, as for [`Attributes`].
+#[derive(Clone)]
+pub struct AttributeMap(Storage);
+
+impl Default for AttributeMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AttributeMap {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(Storage::Small(Vec::new()))
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        match &self.0 {
+            Storage::Small(entries) => entries.len(),
+            Storage::Large(entries) => entries.len(),
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[must_use]
+    pub fn get(&self, attribute: &GraphRecordAttribute) -> Option<&GraphRecordValue> {
+        match &self.0 {
+            Storage::Small(entries) => entries
+                .iter()
+                .find(|(key, _)| key == attribute)
+                .map(|(_, value)| value),
+            Storage::Large(entries) => entries.get(attribute),
+        }
+    }
+
+    pub fn get_mut(&mut self, attribute: &GraphRecordAttribute) -> Option<&mut GraphRecordValue> {
+        match &mut self.0 {
+            Storage::Small(entries) => entries
+                .iter_mut()
+                .find(|(key, _)| key == attribute)
+                .map(|(_, value)| value),
+            Storage::Large(entries) => entries.get_mut(attribute),
+        }
+    }
+
+    #[must_use]
+    pub fn contains_key(&self, attribute: &GraphRecordAttribute) -> bool {
+        self.get(attribute).is_some()
+    }
+
+    pub fn insert(
+        &mut self,
+        attribute: GraphRecordAttribute,
+        value: GraphRecordValue,
+    ) -> Option<GraphRecordValue> {
+        match &mut self.0 {
+            Storage::Small(entries) => {
+                if let Some((_, existing)) = entries.iter_mut().find(|(key, _)| *key == attribute) {
+                    return Some(mem::replace(existing, value));
+                }
+
+                if entries.len() < SMALL_CAPACITY {
+                    entries.push((attribute, value));
+                } else {
+                    let mut large: Table = mem::take(entries).into_iter().collect();
+                    large.insert(attribute, value);
+
+                    self.0 = Storage::Large(Box::new(large));
+                }
+
+                None
+            }
+            Storage::Large(entries) => entries.insert(attribute, value),
+        }
+    }
+
+    pub fn remove(&mut self, attribute: &GraphRecordAttribute) -> Option<GraphRecordValue> {
+        match &mut self.0 {
+            Storage::Small(entries) => {
+                let position = entries.iter().position(|(key, _)| key == attribute)?;
+
+                Some(entries.swap_remove(position).1)
+            }
+            Storage::Large(entries) => entries.remove(attribute),
+        }
+    }
+
+    pub fn retain(
+        &mut self,
+        mut keep: impl FnMut(&GraphRecordAttribute, &mut GraphRecordValue) -> bool,
+    ) {
+        match &mut self.0 {
+            Storage::Small(entries) => entries.retain_mut(|(key, value)| keep(key, value)),
+            Storage::Large(entries) => entries.retain(|key, value| keep(key, value)),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.0 = Storage::Small(Vec::new());
+    }
+
+    /// Releases spare capacity, and moves the attributes back into a vector
+    /// if few enough are left.
+    pub fn shrink_to_fit(&mut self) {
+        match &mut self.0 {
+            Storage::Small(entries) => entries.shrink_to_fit(),
+            Storage::Large(entries) if entries.len() <= SMALL_CAPACITY => {
+                let entries: Vec<_> = mem::take(entries.as_mut()).into_iter().collect();
+
+                self.0 = Storage::Small(entries);
+            }
+            Storage::Large(entries) => entries.shrink_to_fit(),
+        }
+    }
+
+    #[must_use]
+    pub fn iter(&self) -> Iter<'_> {
+        match &self.0 {
+            Storage::Small(entries) => Iter::Small(entries.iter()),
+            Storage::Large(entries) => Iter::Large(entries.iter()),
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_> {
+        match &mut self.0 {
+            Storage::Small(entries) => IterMut::Small(entries.iter_mut()),
+            Storage::Large(entries) => IterMut::Large(entries.iter_mut()),
+        }
+    }
+
+    #[must_use]
+    pub fn keys(&self) -> impl ExactSizeIterator<Item = &GraphRecordAttribute> {
+        self.iter().map(|(attribute, _)| attribute)
+    }
+
+    #[must_use]
+    pub fn values(&self) -> impl ExactSizeIterator<Item = &GraphRecordValue> {
+        self.iter().map(|(_, value)| value)
+    }
+
+    pub fn values_mut(&mut self) -> impl ExactSizeIterator<Item = &mut GraphRecordValue> {
+        self.iter_mut().map(|(_, value)| value)
+    }
+
+    /// Copies the attributes into an [`Attributes`] hash map.
+    #[must_use]
+    pub fn to_attributes(&self) -> Attributes {
+        self.iter()
+            .map(|(attribute, value)| (attribute.clone(), value.clone()))
+            .collect()
+    }
+
+    /// Bytes of the allocated entries, without the heap memory of the
+    /// attributes and values themselves.
+    pub(crate) fn entries_size(&self) -> usize {
+        match &self.0 {
+            Storage::Small(entries) => entries.capacity() * size_of::<Entry>(),
+            // Hash tables store one control byte per bucket next to the entries
+            Storage::Large(entries) => {
+                size_of::<Table>() + entries.capacity() * (size_of::<Entry>() + 1)
+            }
+        }
+    }
+}
+
+/// Read access to attributes, whether given as [`Attributes`] or stored as an
+/// [`AttributeMap`].
+pub trait ReadAttributes {
+    fn get(&self, attribute: &GraphRecordAttribute) -> Option<&GraphRecordValue>;
+
+    fn iter(&self) -> impl Iterator<Item = (&GraphRecordAttribute, &GraphRecordValue)>;
+
+    fn len(&self) -> usize;
+
+    fn contains_key(&self, attribute: &GraphRecordAttribute) -> bool {
+        self.get(attribute).is_some()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl ReadAttributes for Attributes {
+    fn get(&self, attribute: &GraphRecordAttribute) -> Option<&GraphRecordValue> {
+        Self::get(self, attribute)
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (&GraphRecordAttribute, &GraphRecordValue)> {
+        Self::iter(self)
+    }
+
+    fn len(&self) -> usize {
+        Self::len(self)
+    }
+}
+
+impl ReadAttributes for AttributeMap {
+    fn get(&self, attribute: &GraphRecordAttribute) -> Option<&GraphRecordValue> {
+        Self::get(self, attribute)
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (&GraphRecordAttribute, &GraphRecordValue)> {
+        Self::iter(self)
+    }
+
+    fn len(&self) -> usize {
+        Self::len(self)
+    }
+}
+
+pub enum Iter<'a> {
+    Small(slice::Iter<'a, Entry>),
+    Large(hash_map::Iter<'a, GraphRecordAttribute, GraphRecordValue>),
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = (&'a GraphRecordAttribute, &'a GraphRecordValue);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Small(entries) => entries.next().map(|(attribute, value)| (attribute, value)),
+            Self::Large(entries) => entries.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::Small(entries) => entries.size_hint(),
+            Self::Large(entries) => entries.size_hint(),
+        }
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {}
+
+pub enum IterMut<'a> {
+    Small(slice::IterMut<'a, Entry>),
+    Large(hash_map::IterMut<'a, GraphRecordAttribute, GraphRecordValue>),
+}
+
+impl<'a> Iterator for IterMut<'a> {
+    type Item = (&'a GraphRecordAttribute, &'a mut GraphRecordValue);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Small(entries) => entries
+                .next()
+                .map(|(attribute, value)| (&*attribute, value)),
+            Self::Large(entries) => entries.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::Small(entries) => entries.size_hint(),
+            Self::Large(entries) => entries.size_hint(),
+        }
+    }
+}
+
+impl ExactSizeIterator for IterMut<'_> {}
+
+pub enum IntoIter {
+    Small(vec::IntoIter<Entry>),
+    Large(hash_map::IntoIter<GraphRecordAttribute, GraphRecordValue>),
+}
+
+impl Iterator for IntoIter {
+    type Item = Entry;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Small(entries) => entries.next(),
+            Self::Large(entries) => entries.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::Small(entries) => entries.size_hint(),
+            Self::Large(entries) => entries.size_hint(),
+        }
+    }
+}
+
+impl ExactSizeIterator for IntoIter {}
+
+impl IntoIterator for AttributeMap {
+    type Item = Entry;
+    type IntoIter = IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        match self.0 {
+            Storage::Small(entries) => IntoIter::Small(entries.into_iter()),
+            Storage::Large(entries) => IntoIter::Large(entries.into_iter()),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a AttributeMap {
+    type Item = (&'a GraphRecordAttribute, &'a GraphRecordValue);
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut AttributeMap {
+    type Item = (&'a GraphRecordAttribute, &'a mut GraphRecordValue);
+    type IntoIter = IterMut<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl Index<&GraphRecordAttribute> for AttributeMap {
+    type Output = GraphRecordValue;
+
+    /// # Panics
+    ///
+    /// Panics if the attribute is not present.
+    fn index(&self, attribute: &GraphRecordAttribute) -> &Self::Output {
+        self.get(attribute).expect("Attribute must exist")
+    }
+}
+
+impl Extend<Entry> for AttributeMap {
+    fn extend<T: IntoIterator<Item = Entry>>(&mut self, entries: T) {
+        for (attribute, value) in entries {
+            self.insert(attribute, value);
+        }
+    }
+}
+
+impl FromIterator<Entry> for AttributeMap {
+    fn from_iter<T: IntoIterator<Item = Entry>>(entries: T) -> Self {
+        let mut attributes = Self::new();
+        attributes.extend(entries);
+        attributes.shrink_to_fit();
+
+        attributes
+    }
+}
+
+impl From<Attributes> for AttributeMap {
+    fn from(attributes: Attributes) -> Self {
+        attributes.into_iter().collect()
+    }
+}
+
+impl From<AttributeMap> for Attributes {
+    fn from(attributes: AttributeMap) -> Self {
+        attributes.into_iter().collect()
+    }
+}
+
+impl PartialEq for AttributeMap {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(attribute, value)| other.get(attribute) == Some(value))
+    }
+}
+
+impl PartialEq<Attributes> for AttributeMap {
+    fn eq(&self, other: &Attributes) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(attribute, value)| other.get(attribute) == Some(value))
+    }
+}
+
+impl PartialEq<AttributeMap> for Attributes {
+    fn eq(&self, other: &AttributeMap) -> bool {
+        other == self
+    }
+}
+
+impl fmt::Debug for AttributeMap {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.debug_map().entries(self.iter()).finish()
+    }
+}
+
+// Serialized as a map, like `Attributes`, so that the format of stored records
+// does not depend on how the attributes are kept in memory
+#[cfg(feature = "serde")]
+impl Serialize for AttributeMap {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.len()))?;
+
+        for (attribute, value) in self {
+            map.serialize_entry(attribute, value)?;
+        }
+
+        map.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for AttributeMap {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct AttributeMapVisitor;
+
+        impl<'de> Visitor<'de> for AttributeMapVisitor {
+            type Value = AttributeMap;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a map of attributes")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut attributes = AttributeMap::new();
+
+                while let Some((attribute, value)) = map.next_entry()? {
+                    attributes.insert(attribute, value);
+                }
+
+                attributes.shrink_to_fit();
+
+                Ok(attributes)
+            }
+        }
+
+        deserializer.deserialize_map(AttributeMapVisitor)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{AttributeMap, SMALL_CAPACITY, Storage};
+    use crate::graphrecord::{Attributes, GraphRecordAttribute, GraphRecordValue};
+
+    fn create_attribute_map(count: usize) -> AttributeMap {
+        (0..)
+            .take(count)
+            .map(|index: i64| (index.into(), index.into()))
+            .collect()
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut attributes = create_attribute_map(SMALL_CAPACITY);
+
+        assert!(matches!(attributes.0, Storage::Small(_)));
+        assert_eq!(
+            Some(GraphRecordValue::from(0)),
+            attributes.insert(0.into(), "0".into())
+        );
+        assert_eq!(Some(&"0".into()), attributes.get(&0.into()));
+
+        assert_eq!(None, attributes.insert("new".into(), 0.into()));
+
+        assert!(matches!(attributes.0, Storage::Large(_)));
+        assert_eq!(SMALL_CAPACITY + 1, attributes.len());
+        assert_eq!(Some(&"0".into()), attributes.get(&0.into()));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut attributes = create_attribute_map(SMALL_CAPACITY + 1);
+
+        assert_eq!(
+            Some(GraphRecordValue::from(0)),
+            attributes.remove(&0.into())
+        );
+        assert_eq!(None, attributes.remove(&0.into()));
+
+        attributes.shrink_to_fit();
+
+        assert!(matches!(attributes.0, Storage::Small(_)));
+        assert_eq!(
+            create_attribute_map(SMALL_CAPACITY + 1).len() - 1,
+            attributes.len()
+        );
+        assert!(!attributes.contains_key(&0.into()));
+        assert!(attributes.contains_key(&1.into()));
+    }
+
+    #[test]
+    fn test_attributes_conversion() {
+        for count in [0, 2, SMALL_CAPACITY + 2] {
+            let attributes: Attributes = create_attribute_map(count).into();
+
+            let attribute_map = AttributeMap::from(attributes.clone());
+
+            assert_eq!(attribute_map, attributes);
+            assert_eq!(attributes, attribute_map.to_attributes());
+            assert_eq!(attributes, Attributes::from(attribute_map));
+        }
+    }
+
+    #[test]
+    fn test_entries_size() {
+        let attributes = create_attribute_map(3);
+
+        // Few attributes need no more than their own entries
+        assert_eq!(
+            3 * size_of::<(GraphRecordAttribute, GraphRecordValue)>(),
+            attributes.entries_size()
+        );
+    }
+}
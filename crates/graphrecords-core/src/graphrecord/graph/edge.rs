@@ -1,18 +1,20 @@
-use super::{Attributes, NodeIndex};
+use super::{AttributeMap, NodeIndex};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Edge {
-    pub(crate) attributes: Attributes,
+    pub(crate) attributes: AttributeMap,
     pub(crate) source_node_index: NodeIndex,
     pub(crate) target_node_index: NodeIndex,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) undirected: bool,
 }
 
 impl Edge {
     pub const fn new(
-        attributes: Attributes,
+        attributes: AttributeMap,
         source_node_index: NodeIndex,
         target_node_index: NodeIndex,
     ) -> Self {
@@ -20,6 +22,7 @@ impl Edge {
             attributes,
             source_node_index,
             target_node_index,
+            undirected: false,
         }
     }
 }
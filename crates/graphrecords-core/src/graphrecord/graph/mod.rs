@@ -1,14 +1,16 @@
+mod attribute_map;
 mod edge;
 mod node;
 
 use super::{GraphRecordAttribute, GraphRecordValue, group_mapping::GroupMapping};
 use crate::errors::GraphError;
+pub use attribute_map::{AttributeMap, ReadAttributes};
 use edge::Edge;
-use graphrecords_utils::aliases::{GrHashMap, GrHashSet};
+use graphrecords_utils::aliases::{GrBuildHasher, GrHashMap, GrHashSet};
 use node::Node;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::{collections::HashMap, mem};
 
 pub type NodeIndex = GraphRecordAttribute;
 pub type EdgeIndex = u32;
@@ -20,23 +22,35 @@ pub(super) struct Graph {
     pub(crate) nodes: GrHashMap<NodeIndex, Node>,
     pub(crate) edges: GrHashMap<EdgeIndex, Edge>,
     edge_index_counter: u32,
+    #[cfg_attr(feature = "serde", serde(default))]
+    recycle_edge_indices: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    free_edge_indices: Vec<EdgeIndex>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    undirected_edge_count: usize,
 }
 
 #[allow(dead_code)]
 impl Graph {
     pub fn new() -> Self {
         Self {
-            nodes: GrHashMap::new(),
-            edges: GrHashMap::new(),
+            nodes: GrHashMap::default(),
+            edges: GrHashMap::default(),
             edge_index_counter: 0,
+            recycle_edge_indices: false,
+            free_edge_indices: Vec::new(),
+            undirected_edge_count: 0,
         }
     }
 
     pub fn with_capacity(node_capacity: usize, edge_capacity: usize) -> Self {
         Self {
-            nodes: GrHashMap::with_capacity(node_capacity),
-            edges: GrHashMap::with_capacity(edge_capacity),
+            nodes: GrHashMap::with_capacity_and_hasher(node_capacity, GrBuildHasher::default()),
+            edges: GrHashMap::with_capacity_and_hasher(edge_capacity, GrBuildHasher::default()),
             edge_index_counter: 0,
+            recycle_edge_indices: false,
+            free_edge_indices: Vec::new(),
+            undirected_edge_count: 0,
         }
     }
 
@@ -45,12 +59,162 @@ impl Graph {
         self.edges.clear();
 
         self.edge_index_counter = 0;
+        self.free_edge_indices.clear();
+        self.undirected_edge_count = 0;
     }
 
     pub fn clear_edges(&mut self) {
         self.edges.clear();
 
         self.edge_index_counter = 0;
+        self.free_edge_indices.clear();
+        self.undirected_edge_count = 0;
+    }
+
+    pub const fn recycle_edge_indices(&self) -> bool {
+        self.recycle_edge_indices
+    }
+
+    pub fn set_recycle_edge_indices(&mut self, recycle_edge_indices: bool) {
+        self.recycle_edge_indices = recycle_edge_indices;
+
+        if !recycle_edge_indices {
+            self.free_edge_indices = Vec::new();
+        }
+    }
+
+    fn next_edge_index(&mut self) -> EdgeIndex {
+        if let Some(edge_index) = self.free_edge_indices.pop() {
+            return edge_index;
+        }
+
+        let edge_index = self.edge_index_counter;
+        self.edge_index_counter += 1;
+
+        edge_index
+    }
+
+    /// Returns the indices the next added edges will get, in order.
+    pub fn peek_edge_indices(&self) -> impl Iterator<Item = EdgeIndex> + '_ {
+        self.free_edge_indices
+            .iter()
+            .rev()
+            .copied()
+            .chain(self.edge_index_counter..)
+    }
+
+    fn free_edge_index(&mut self, edge_index: EdgeIndex) {
+        if self.recycle_edge_indices {
+            self.free_edge_indices.push(edge_index);
+        }
+    }
+
+    pub fn compact(&mut self, group_mapping: &mut GroupMapping) -> HashMap<EdgeIndex, EdgeIndex> {
+        let mut edge_indices: Vec<_> = self.edges.keys().copied().collect();
+        edge_indices.sort_unstable();
+
+        let remap: HashMap<_, _> = edge_indices
+            .into_iter()
+            .enumerate()
+            .map(|(new_edge_index, edge_index)| (edge_index, new_edge_index as EdgeIndex))
+            .collect();
+
+        self.edges = mem::take(&mut self.edges)
+            .into_iter()
+            .map(|(edge_index, edge)| (remap[&edge_index], edge))
+            .collect();
+
+        for node in self.nodes.values_mut() {
+            node.outgoing_edge_indices = node
+                .outgoing_edge_indices
+                .iter()
+                .map(|edge_index| remap[edge_index])
+                .collect();
+            node.incoming_edge_indices = node
+                .incoming_edge_indices
+                .iter()
+                .map(|edge_index| remap[edge_index])
+                .collect();
+        }
+
+        self.nodes.shrink_to_fit();
+
+        group_mapping.remap_edges(&remap);
+
+        self.edge_index_counter = self.edges.len() as EdgeIndex;
+        self.free_edge_indices = Vec::new();
+
+        remap
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        for node in self.nodes.values_mut() {
+            node.attributes.shrink_to_fit();
+            node.outgoing_edge_indices.shrink_to_fit();
+            node.incoming_edge_indices.shrink_to_fit();
+        }
+
+        for edge in self.edges.values_mut() {
+            edge.attributes.shrink_to_fit();
+        }
+
+        self.nodes.shrink_to_fit();
+        self.edges.shrink_to_fit();
+        self.free_edge_indices.shrink_to_fit();
+    }
+
+    pub const fn edge_index_state(&self) -> (EdgeIndex, &Vec<EdgeIndex>) {
+        (self.edge_index_counter, &self.free_edge_indices)
+    }
+
+    pub fn restore_edge_index_state(
+        &mut self,
+        edge_index_counter: EdgeIndex,
+        free_edge_indices: Vec<EdgeIndex>,
+    ) {
+        self.edge_index_counter = edge_index_counter;
+        self.free_edge_indices = free_edge_indices;
+    }
+
+    pub fn restore_edge(
+        &mut self,
+        edge_index: EdgeIndex,
+        source_node_index: NodeIndex,
+        target_node_index: NodeIndex,
+        attributes: AttributeMap,
+    ) -> Result<(), GraphError> {
+        if self.edges.contains_key(&edge_index) {
+            return Err(GraphError::AssertionError(format!(
+                "Edge with index {edge_index} already exists"
+            )));
+        }
+
+        if !self.nodes.contains_key(&target_node_index) {
+            return Err(GraphError::MissingNode {
+                index: target_node_index,
+            });
+        }
+
+        self.nodes
+            .get_mut(&source_node_index)
+            .ok_or_else(|| GraphError::MissingNode {
+                index: source_node_index.clone(),
+            })?
+            .outgoing_edge_indices
+            .insert(edge_index);
+
+        self.nodes
+            .get_mut(&target_node_index)
+            .expect("Node must exist")
+            .incoming_edge_indices
+            .insert(edge_index);
+
+        self.edges.insert(
+            edge_index,
+            Edge::new(attributes, source_node_index, target_node_index),
+        );
+
+        Ok(())
     }
 
     pub fn node_count(&self) -> usize {
@@ -64,7 +228,7 @@ impl Graph {
     pub fn add_node(
         &mut self,
         node_index: NodeIndex,
-        attributes: Attributes,
+        attributes: AttributeMap,
     ) -> Result<(), GraphError> {
         if self.nodes.contains_key(&node_index) {
             return Err(GraphError::AssertionError(format!(
@@ -84,9 +248,12 @@ impl Graph {
         node_index: &NodeIndex,
         group_mapping: &mut GroupMapping,
     ) -> Result<Attributes, GraphError> {
-        let node = self.nodes.remove(node_index).ok_or_else(|| {
-            GraphError::IndexError(format!("Cannot find node with index {node_index}"))
-        })?;
+        let node = self
+            .nodes
+            .remove(node_index)
+            .ok_or_else(|| GraphError::MissingNode {
+                index: node_index.clone(),
+            })?;
 
         let edge_indices = node
             .outgoing_edge_indices
@@ -120,9 +287,15 @@ impl Graph {
                 }
                 (false, false) => unreachable!(),
             }
+
+            if edge.undirected {
+                self.undirected_edge_count -= 1;
+            }
+
+            self.free_edge_index(*edge_index);
         }
 
-        Ok(node.attributes)
+        Ok(node.attributes.into())
     }
 
     pub fn contains_node(&self, node_index: &NodeIndex) -> bool {
@@ -133,22 +306,27 @@ impl Graph {
         &mut self,
         source_node_index: NodeIndex,
         target_node_index: NodeIndex,
-        attributes: Attributes,
+        attributes: AttributeMap,
     ) -> Result<EdgeIndex, GraphError> {
         if !self.nodes.contains_key(&target_node_index) {
-            return Err(GraphError::IndexError(format!(
-                "Cannot find node with index {target_node_index}"
-            )));
+            return Err(GraphError::MissingNode {
+                index: target_node_index.clone(),
+            });
         }
 
-        let edge_index = self.edge_index_counter;
-        self.edge_index_counter += 1;
+        if !self.nodes.contains_key(&source_node_index) {
+            return Err(GraphError::MissingNode {
+                index: source_node_index.clone(),
+            });
+        }
 
-        let outgoing_node = self.nodes.get_mut(&source_node_index).ok_or_else(|| {
-            GraphError::IndexError(format!("Cannot find node with index {source_node_index}"))
-        })?;
+        let edge_index = self.next_edge_index();
 
-        outgoing_node.outgoing_edge_indices.insert(edge_index);
+        self.nodes
+            .get_mut(&source_node_index)
+            .expect("Node must exist")
+            .outgoing_edge_indices
+            .insert(edge_index);
 
         let incoming_node = self
             .nodes
@@ -182,15 +360,21 @@ impl Graph {
             .outgoing_edge_indices
             .remove(edge_index);
 
-        Ok(edge.attributes)
+        if edge.undirected {
+            self.undirected_edge_count -= 1;
+        }
+
+        self.free_edge_index(*edge_index);
+
+        Ok(edge.attributes.into())
     }
 
-    pub fn node_attributes(&self, node_index: &NodeIndex) -> Result<&Attributes, GraphError> {
+    pub fn node_attributes(&self, node_index: &NodeIndex) -> Result<&AttributeMap, GraphError> {
         Ok(&self
             .nodes
             .get(node_index)
-            .ok_or_else(|| {
-                GraphError::IndexError(format!("Cannot find node with index {node_index}"))
+            .ok_or_else(|| GraphError::MissingNode {
+                index: node_index.clone(),
             })?
             .attributes)
     }
@@ -198,21 +382,21 @@ impl Graph {
     pub fn node_attributes_mut(
         &mut self,
         node_index: &NodeIndex,
-    ) -> Result<&mut Attributes, GraphError> {
+    ) -> Result<&mut AttributeMap, GraphError> {
         Ok(&mut self
             .nodes
             .get_mut(node_index)
-            .ok_or_else(|| {
-                GraphError::IndexError(format!("Cannot find node with index {node_index}"))
+            .ok_or_else(|| GraphError::MissingNode {
+                index: node_index.clone(),
             })?
             .attributes)
     }
 
-    pub fn nodes_attributes(&self) -> impl Iterator<Item = &Attributes> {
+    pub fn nodes_attributes(&self) -> impl Iterator<Item = &AttributeMap> {
         self.nodes.values().map(|node| &node.attributes)
     }
 
-    pub fn nodes_attributes_mut(&mut self) -> impl Iterator<Item = &mut Attributes> {
+    pub fn nodes_attributes_mut(&mut self) -> impl Iterator<Item = &mut AttributeMap> {
         self.nodes.iter_mut().map(|(_, node)| &mut node.attributes)
     }
 
@@ -221,7 +405,7 @@ impl Graph {
     }
 
     #[allow(clippy::trivially_copy_pass_by_ref)]
-    pub fn edge_attributes(&self, edge_index: &EdgeIndex) -> Result<&Attributes, GraphError> {
+    pub fn edge_attributes(&self, edge_index: &EdgeIndex) -> Result<&AttributeMap, GraphError> {
         Ok(&self
             .edges
             .get(edge_index)
@@ -235,7 +419,7 @@ impl Graph {
     pub fn edge_attributes_mut(
         &mut self,
         edge_index: &EdgeIndex,
-    ) -> Result<&mut Attributes, GraphError> {
+    ) -> Result<&mut AttributeMap, GraphError> {
         Ok(&mut self
             .edges
             .get_mut(edge_index)
@@ -245,11 +429,11 @@ impl Graph {
             .attributes)
     }
 
-    pub fn edges_attributes(&self) -> impl Iterator<Item = &Attributes> {
+    pub fn edges_attributes(&self) -> impl Iterator<Item = &AttributeMap> {
         self.edges.values().map(|edge| &edge.attributes)
     }
 
-    pub fn edges_attributes_mut(&mut self) -> impl Iterator<Item = &mut Attributes> {
+    pub fn edges_attributes_mut(&mut self) -> impl Iterator<Item = &mut AttributeMap> {
         self.edges.values_mut().map(|edge| &mut edge.attributes)
     }
 
@@ -265,32 +449,100 @@ impl Graph {
         Ok((&edge.source_node_index, &edge.target_node_index))
     }
 
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub fn is_edge_undirected(&self, edge_index: &EdgeIndex) -> Result<bool, GraphError> {
+        Ok(self
+            .edges
+            .get(edge_index)
+            .ok_or_else(|| {
+                GraphError::IndexError(format!("Cannot find edge with index {edge_index}"))
+            })?
+            .undirected)
+    }
+
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub fn set_edge_undirected(
+        &mut self,
+        edge_index: &EdgeIndex,
+        undirected: bool,
+    ) -> Result<(), GraphError> {
+        let edge = self.edges.get_mut(edge_index).ok_or_else(|| {
+            GraphError::IndexError(format!("Cannot find edge with index {edge_index}"))
+        })?;
+
+        match (edge.undirected, undirected) {
+            (false, true) => self.undirected_edge_count += 1,
+            (true, false) => self.undirected_edge_count -= 1,
+            _ => {}
+        }
+
+        edge.undirected = undirected;
+
+        Ok(())
+    }
+
+    pub fn undirected_edge_indices(&self) -> impl Iterator<Item = &EdgeIndex> {
+        self.edges
+            .iter()
+            .filter(|(_, edge)| edge.undirected)
+            .map(|(edge_index, _)| edge_index)
+    }
+
+    pub const fn has_undirected_edges(&self) -> bool {
+        self.undirected_edge_count > 0
+    }
+
+    /// Undirected edges in `edge_indices` that can be traversed in reverse.
+    /// Self-loops are left out, as they are already part of both edge sets of
+    /// their node.
+    fn reversible_edges<'a>(
+        &'a self,
+        edge_indices: &'a GrHashSet<EdgeIndex>,
+    ) -> impl Iterator<Item = &'a EdgeIndex> + 'a {
+        let edge_indices = self.has_undirected_edges().then_some(edge_indices);
+
+        edge_indices
+            .into_iter()
+            .flatten()
+            .filter(move |edge_index| {
+                let edge = self.edges.get(*edge_index).expect("Edge must exist");
+
+                edge.undirected && edge.source_node_index != edge.target_node_index
+            })
+    }
+
     pub fn outgoing_edges(
         &self,
         node_index: &NodeIndex,
     ) -> Result<impl Iterator<Item = &EdgeIndex> + use<'_>, GraphError> {
-        Ok(self
+        let node = self
             .nodes
             .get(node_index)
-            .ok_or_else(|| {
-                GraphError::IndexError(format!("Cannot find node with index {node_index}"))
-            })?
+            .ok_or_else(|| GraphError::MissingNode {
+                index: node_index.clone(),
+            })?;
+
+        Ok(node
             .outgoing_edge_indices
-            .iter())
+            .iter()
+            .chain(self.reversible_edges(&node.incoming_edge_indices)))
     }
 
     pub fn incoming_edges(
         &self,
         node_index: &NodeIndex,
     ) -> Result<impl Iterator<Item = &EdgeIndex> + use<'_>, GraphError> {
-        Ok(self
+        let node = self
             .nodes
             .get(node_index)
-            .ok_or_else(|| {
-                GraphError::IndexError(format!("Cannot find node with index {node_index}"))
-            })?
+            .ok_or_else(|| GraphError::MissingNode {
+                index: node_index.clone(),
+            })?;
+
+        Ok(node
             .incoming_edge_indices
-            .iter())
+            .iter()
+            .chain(self.reversible_edges(&node.outgoing_edge_indices)))
     }
 
     pub fn edge_indices(&self) -> impl Iterator<Item = &EdgeIndex> {
@@ -322,6 +574,40 @@ impl Graph {
                     result.push(edge_index);
                 }
             }
+            for edge_index in self.reversible_edges(&node.incoming_edge_indices) {
+                let edge = self.edges.get(edge_index).expect("Edge must exist");
+
+                if target_set.contains(&edge.source_node_index) {
+                    result.push(edge_index);
+                }
+            }
+        }
+
+        result.into_iter()
+    }
+
+    pub fn induced_edges<'a, N>(&'a self, node_indices: N) -> impl Iterator<Item = &'a EdgeIndex>
+    where
+        N: IntoIterator<Item = &'a NodeIndex>,
+    {
+        let node_set: GrHashSet<&NodeIndex> = node_indices.into_iter().collect();
+
+        let mut result = Vec::new();
+
+        for node_index in &node_set {
+            let Some(node) = self.nodes.get(*node_index) else {
+                continue;
+            };
+
+            // Every edge is an outgoing edge of exactly one node, so it is
+            // only collected once
+            for edge_index in &node.outgoing_edge_indices {
+                let edge = self.edges.get(edge_index).expect("Edge must exist");
+
+                if node_set.contains(&edge.target_node_index) {
+                    result.push(edge_index);
+                }
+            }
         }
 
         result.into_iter()
@@ -339,7 +625,7 @@ impl Graph {
         let first_set: GrHashSet<&NodeIndex> = first_node_indices.into_iter().collect();
         let second_set: GrHashSet<&NodeIndex> = second_node_indices.into_iter().collect();
 
-        let mut result = GrHashSet::new();
+        let mut result = GrHashSet::default();
 
         for source_index in &first_set {
             let Some(node) = self.nodes.get(*source_index) else {
@@ -395,12 +681,14 @@ impl Graph {
         &self,
         node_index: &NodeIndex,
     ) -> Result<impl Iterator<Item = &NodeIndex> + use<'_>, GraphError> {
-        Ok(self
+        let node = self
             .nodes
             .get(node_index)
-            .ok_or_else(|| {
-                GraphError::IndexError(format!("Cannot find node with index {node_index}"))
-            })?
+            .ok_or_else(|| GraphError::MissingNode {
+                index: node_index.clone(),
+            })?;
+
+        Ok(node
             .outgoing_edge_indices
             .iter()
             .map(|edge_index| {
@@ -409,7 +697,17 @@ impl Graph {
                     .get(edge_index)
                     .expect("Edge must exist")
                     .target_node_index
-            }))
+            })
+            .chain(
+                self.reversible_edges(&node.incoming_edge_indices)
+                    .map(|edge_index| {
+                        &self
+                            .edges
+                            .get(edge_index)
+                            .expect("Edge must exist")
+                            .source_node_index
+                    }),
+            ))
     }
 
     // TODO: Add tests
@@ -417,12 +715,14 @@ impl Graph {
         &self,
         node_index: &NodeIndex,
     ) -> Result<impl Iterator<Item = &NodeIndex> + use<'_>, GraphError> {
-        Ok(self
+        let node = self
             .nodes
             .get(node_index)
-            .ok_or_else(|| {
-                GraphError::IndexError(format!("Cannot find node with index {node_index}"))
-            })?
+            .ok_or_else(|| GraphError::MissingNode {
+                index: node_index.clone(),
+            })?;
+
+        Ok(node
             .incoming_edge_indices
             .iter()
             .map(|edge_index| {
@@ -431,16 +731,29 @@ impl Graph {
                     .get(edge_index)
                     .expect("Edge must exist")
                     .source_node_index
-            }))
+            })
+            .chain(
+                self.reversible_edges(&node.outgoing_edge_indices)
+                    .map(|edge_index| {
+                        &self
+                            .edges
+                            .get(edge_index)
+                            .expect("Edge must exist")
+                            .target_node_index
+                    }),
+            ))
     }
 
     pub fn neighbors_undirected(
         &self,
         node_index: &NodeIndex,
     ) -> Result<impl Iterator<Item = &NodeIndex> + use<'_>, GraphError> {
-        let node = self.nodes.get(node_index).ok_or_else(|| {
-            GraphError::IndexError(format!("Cannot find node with index {node_index}"))
-        })?;
+        let node = self
+            .nodes
+            .get(node_index)
+            .ok_or_else(|| GraphError::MissingNode {
+                index: node_index.clone(),
+            })?;
 
         Ok(node
             .outgoing_edge_indices
@@ -466,7 +779,7 @@ impl Graph {
 
 #[cfg(test)]
 mod test {
-    use super::{Attributes, Graph, NodeIndex};
+    use super::{AttributeMap, Attributes, Graph, NodeIndex};
     use crate::{errors::GraphError, graphrecord::group_mapping::GroupMapping};
     use std::collections::HashMap;
 
@@ -525,12 +838,12 @@ mod test {
         let mut graph = Graph::with_capacity(nodes.len(), edges.len());
 
         for (node_index, attributes) in nodes {
-            graph.add_node(node_index, attributes).unwrap();
+            graph.add_node(node_index, attributes.into()).unwrap();
         }
 
         for (source_node_index, target_node_index, attributes) in edges {
             graph
-                .add_edge(source_node_index, target_node_index, attributes)
+                .add_edge(source_node_index, target_node_index, attributes.into())
                 .unwrap();
         }
 
@@ -563,7 +876,7 @@ mod test {
 
         assert_eq!(0, graph.node_count());
 
-        graph.add_node("0".into(), HashMap::new()).unwrap();
+        graph.add_node("0".into(), AttributeMap::new()).unwrap();
 
         assert_eq!(1, graph.node_count());
     }
@@ -572,13 +885,13 @@ mod test {
     fn test_edge_count() {
         let mut graph = Graph::new();
 
-        graph.add_node("0".into(), HashMap::new()).unwrap();
-        graph.add_node("1".into(), HashMap::new()).unwrap();
+        graph.add_node("0".into(), AttributeMap::new()).unwrap();
+        graph.add_node("1".into(), AttributeMap::new()).unwrap();
 
         assert_eq!(0, graph.edge_count());
 
         graph
-            .add_edge("0".into(), "1".into(), HashMap::new())
+            .add_edge("0".into(), "1".into(), AttributeMap::new())
             .unwrap();
 
         assert_eq!(1, graph.edge_count());
@@ -590,7 +903,7 @@ mod test {
 
         assert_eq!(0, graph.node_count());
 
-        graph.add_node("0".into(), HashMap::new()).unwrap();
+        graph.add_node("0".into(), AttributeMap::new()).unwrap();
 
         assert_eq!(1, graph.node_count());
     }
@@ -601,7 +914,7 @@ mod test {
 
         assert!(
             graph
-                .add_node("0".into(), HashMap::new())
+                .add_node("0".into(), AttributeMap::new())
                 .is_err_and(|e| matches!(e, GraphError::AssertionError(_)))
         );
     }
@@ -622,8 +935,10 @@ mod test {
 
         let mut graph = Graph::new();
 
-        graph.add_node(0.into(), HashMap::new()).unwrap();
-        graph.add_edge(0.into(), 0.into(), HashMap::new()).unwrap();
+        graph.add_node(0.into(), AttributeMap::new()).unwrap();
+        graph
+            .add_edge(0.into(), 0.into(), AttributeMap::new())
+            .unwrap();
 
         assert_eq!(1, graph.node_count());
         assert_eq!(1, graph.edge_count());
@@ -645,7 +960,7 @@ mod test {
         assert!(
             graph
                 .remove_node(&"50".into(), &mut GroupMapping::default())
-                .is_err_and(|e| matches!(e, GraphError::IndexError(_)))
+                .is_err_and(|e| matches!(e, GraphError::MissingNode { .. }))
         );
     }
 
@@ -665,7 +980,7 @@ mod test {
         assert_eq!(4, graph.edge_count());
 
         graph
-            .add_edge("0".into(), "3".into(), HashMap::new())
+            .add_edge("0".into(), "3".into(), AttributeMap::new())
             .unwrap();
 
         assert_eq!(5, graph.edge_count());
@@ -674,20 +989,20 @@ mod test {
     #[test]
     fn test_invalid_add_edge() {
         let mut graph = Graph::new();
-        graph.add_node(0.into(), HashMap::new()).unwrap();
+        graph.add_node(0.into(), AttributeMap::new()).unwrap();
 
         // Adding an edge pointing to a non-existing node should fail
         assert!(
             graph
-                .add_edge("0".into(), "50".into(), HashMap::new())
-                .is_err_and(|e| matches!(e, GraphError::IndexError(_)))
+                .add_edge("0".into(), "50".into(), AttributeMap::new())
+                .is_err_and(|e| matches!(e, GraphError::MissingNode { .. }))
         );
 
         // Adding an edge from a non-existing node should fail
         assert!(
             graph
-                .add_edge("50".into(), "0".into(), HashMap::new())
-                .is_err_and(|e| matches!(e, GraphError::IndexError(_)))
+                .add_edge("50".into(), "0".into(), AttributeMap::new())
+                .is_err_and(|e| matches!(e, GraphError::MissingNode { .. }))
         );
     }
 
@@ -732,7 +1047,7 @@ mod test {
         assert!(
             graph
                 .node_attributes(&"50".into())
-                .is_err_and(|e| matches!(e, GraphError::IndexError(_)))
+                .is_err_and(|e| matches!(e, GraphError::MissingNode { .. }))
         );
     }
 
@@ -744,7 +1059,10 @@ mod test {
 
         assert_eq!(&create_nodes()[0].1, attributes);
 
-        let new_attributes = HashMap::from([("0".into(), "1".into()), ("2".into(), "3".into())]);
+        let new_attributes = AttributeMap::from(HashMap::from([
+            ("0".into(), "1".into()),
+            ("2".into(), "3".into()),
+        ]));
 
         attributes.clone_from(&new_attributes);
 
@@ -759,7 +1077,7 @@ mod test {
         assert!(
             graph
                 .node_attributes_mut(&"50".into())
-                .is_err_and(|e| matches!(e, GraphError::IndexError(_)))
+                .is_err_and(|e| matches!(e, GraphError::MissingNode { .. }))
         );
     }
 
@@ -769,7 +1087,7 @@ mod test {
 
         let all_attributes: Vec<_> = create_nodes()
             .into_iter()
-            .map(|(_, attributes)| attributes)
+            .map(|(_, attributes)| AttributeMap::from(attributes))
             .collect();
 
         for attributes in graph.nodes_attributes() {
@@ -783,13 +1101,16 @@ mod test {
 
         let all_attributes: Vec<_> = create_nodes()
             .into_iter()
-            .map(|(_, attributes)| attributes)
+            .map(|(_, attributes)| AttributeMap::from(attributes))
             .collect();
 
-        let new_attributes = HashMap::from([("0".into(), "1".into()), ("2".into(), "3".into())]);
+        let new_attributes = AttributeMap::from(HashMap::from([
+            ("0".into(), "1".into()),
+            ("2".into(), "3".into()),
+        ]));
 
         for attributes in graph.nodes_attributes_mut() {
-            assert!(all_attributes.contains(attributes));
+            assert!(all_attributes.contains(&*attributes));
 
             attributes.clone_from(&new_attributes);
         }
@@ -840,7 +1161,10 @@ mod test {
 
         assert_eq!(&create_edges()[0].2, attributes);
 
-        let new_attributes = HashMap::from([("0".into(), "1".into()), ("2".into(), "3".into())]);
+        let new_attributes = AttributeMap::from(HashMap::from([
+            ("0".into(), "1".into()),
+            ("2".into(), "3".into()),
+        ]));
 
         attributes.clone_from(&new_attributes);
 
@@ -865,7 +1189,7 @@ mod test {
 
         let all_attributes: Vec<_> = create_edges()
             .into_iter()
-            .map(|(_, _, attributes)| attributes)
+            .map(|(_, _, attributes)| AttributeMap::from(attributes))
             .collect();
 
         for attributes in graph.edges_attributes() {
@@ -879,13 +1203,16 @@ mod test {
 
         let all_attributes: Vec<_> = create_edges()
             .into_iter()
-            .map(|(_, _, attributes)| attributes)
+            .map(|(_, _, attributes)| AttributeMap::from(attributes))
             .collect();
 
-        let new_attributes = HashMap::from([("0".into(), "1".into()), ("2".into(), "3".into())]);
+        let new_attributes = AttributeMap::from(HashMap::from([
+            ("0".into(), "1".into()),
+            ("2".into(), "3".into()),
+        ]));
 
         for attributes in graph.edges_attributes_mut() {
-            assert!(all_attributes.contains(attributes));
+            assert!(all_attributes.contains(&*attributes));
 
             attributes.clone_from(&new_attributes);
         }
@@ -1011,7 +1338,7 @@ mod test {
         assert!(
             graph
                 .neighbors_outgoing(&"50".into())
-                .is_err_and(|e| matches!(e, GraphError::IndexError(_)))
+                .is_err_and(|e| matches!(e, GraphError::MissingNode { .. }))
         );
     }
 
@@ -1033,6 +1360,70 @@ mod test {
         assert!(
             graph
                 .neighbors_undirected(&"50".into())
+                .is_err_and(|e| matches!(e, GraphError::MissingNode { .. }))
+        );
+    }
+
+    #[test]
+    fn test_set_edge_undirected() {
+        let mut graph = create_graph();
+
+        assert!(!graph.is_edge_undirected(&2).unwrap());
+
+        graph.set_edge_undirected(&2, true).unwrap();
+
+        assert!(graph.is_edge_undirected(&2).unwrap());
+        assert_eq!(
+            vec![&2],
+            graph.undirected_edge_indices().collect::<Vec<_>>()
+        );
+
+        // The edge from 1 to 2 can now be traversed from 2 to 1
+        let neighbors: Vec<_> = graph.neighbors_outgoing(&"2".into()).unwrap().collect();
+        assert_eq!(vec![&NodeIndex::from("1")], neighbors);
+
+        let mut neighbors: Vec<_> = graph.neighbors_incoming(&"1".into()).unwrap().collect();
+        neighbors.sort_unstable_by(|a, b| a.total_cmp(b));
+        assert_eq!(
+            vec![&NodeIndex::from("0"), &NodeIndex::from("2")],
+            neighbors
+        );
+
+        let outgoing_edges: Vec<_> = graph.outgoing_edges(&"2".into()).unwrap().collect();
+        assert_eq!(vec![&2], outgoing_edges);
+
+        let first_index = "2".into();
+        let second_index = "1".into();
+        let edges_connecting: Vec<_> = graph
+            .edges_connecting(vec![&first_index], vec![&second_index])
+            .collect();
+        assert_eq!(vec![&2], edges_connecting);
+
+        // Undirected edges are only returned once for their own direction
+        assert_eq!(2, graph.outgoing_edges(&"1".into()).unwrap().count());
+
+        graph.set_edge_undirected(&2, false).unwrap();
+
+        assert_eq!(0, graph.neighbors_outgoing(&"2".into()).unwrap().count());
+
+        graph.set_edge_undirected(&2, true).unwrap();
+        graph.remove_edge(&2).unwrap();
+
+        assert!(!graph.has_undirected_edges());
+    }
+
+    #[test]
+    fn test_invalid_set_edge_undirected() {
+        let mut graph = create_graph();
+
+        assert!(
+            graph
+                .set_edge_undirected(&50, true)
+                .is_err_and(|e| matches!(e, GraphError::IndexError(_)))
+        );
+        assert!(
+            graph
+                .is_edge_undirected(&50)
                 .is_err_and(|e| matches!(e, GraphError::IndexError(_)))
         );
     }
@@ -1,8 +1,9 @@
-use super::{EdgeIndex, GraphRecordAttribute, NodeIndex};
+use super::{Attributes, EdgeIndex, GraphRecordAttribute, GraphRecordValue, NodeIndex};
 use crate::errors::{GraphRecordError, GraphRecordResult};
 use graphrecords_utils::aliases::{GrHashMap, GrHashMapEntry, GrHashSet};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, mem};
 
 pub type Group = GraphRecordAttribute;
 
@@ -13,6 +14,8 @@ pub(super) struct GroupMapping {
     pub(super) edges_in_group: GrHashMap<Group, GrHashSet<EdgeIndex>>,
     pub(super) groups_of_node: GrHashMap<NodeIndex, GrHashSet<Group>>,
     pub(super) groups_of_edge: GrHashMap<EdgeIndex, GrHashSet<Group>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(super) group_metadata: GrHashMap<Group, Attributes>,
 }
 
 impl GroupMapping {
@@ -24,9 +27,7 @@ impl GroupMapping {
         edge_indices: Option<Vec<EdgeIndex>>,
     ) -> GraphRecordResult<()> {
         if self.nodes_in_group.contains_key(&group) {
-            return Err(GraphRecordError::AssertionError(format!(
-                "Group {group} already exists"
-            )));
+            return Err(GraphRecordError::DuplicateGroup { group });
         }
 
         let node_indices = node_indices.unwrap_or_default();
@@ -137,6 +138,8 @@ impl GroupMapping {
                 .remove(group);
         }
 
+        self.group_metadata.remove(group);
+
         Ok(())
     }
 
@@ -181,14 +184,21 @@ impl GroupMapping {
             .get_mut(group)
             .ok_or_else(|| GraphRecordError::IndexError(format!("Cannot find group {group}")))?;
 
-        nodes_in_group
-            .remove(node_index)
-            .then_some(())
-            .ok_or_else(|| {
-                GraphRecordError::AssertionError(format!(
-                    "Node with index {node_index} not in group {group}"
-                ))
-            })
+        if !nodes_in_group.remove(node_index) {
+            return Err(GraphRecordError::AssertionError(format!(
+                "Node with index {node_index} not in group {group}"
+            )));
+        }
+
+        if let Some(groups_of_node) = self.groups_of_node.get_mut(node_index) {
+            groups_of_node.remove(group);
+
+            if groups_of_node.is_empty() {
+                self.groups_of_node.remove(node_index);
+            }
+        }
+
+        Ok(())
     }
 
     #[allow(clippy::trivially_copy_pass_by_ref)]
@@ -202,14 +212,21 @@ impl GroupMapping {
             .get_mut(group)
             .ok_or_else(|| GraphRecordError::IndexError(format!("Cannot find group {group}")))?;
 
-        edges_in_group
-            .remove(edge_index)
-            .then_some(())
-            .ok_or_else(|| {
-                GraphRecordError::AssertionError(format!(
-                    "Edge with index {edge_index} not in group {group}"
-                ))
-            })
+        if !edges_in_group.remove(edge_index) {
+            return Err(GraphRecordError::AssertionError(format!(
+                "Edge with index {edge_index} not in group {group}"
+            )));
+        }
+
+        if let Some(groups_of_edge) = self.groups_of_edge.get_mut(edge_index) {
+            groups_of_edge.remove(group);
+
+            if groups_of_edge.is_empty() {
+                self.groups_of_edge.remove(edge_index);
+            }
+        }
+
+        Ok(())
     }
 
     pub fn groups(&self) -> impl Iterator<Item = &Group> {
@@ -255,18 +272,144 @@ impl GroupMapping {
         self.nodes_in_group.contains_key(group)
     }
 
+    pub fn group_metadata(
+        &self,
+        group: &Group,
+    ) -> GraphRecordResult<impl Iterator<Item = (&GraphRecordAttribute, &GraphRecordValue)> + use<'_>>
+    {
+        if !self.contains_group(group) {
+            return Err(GraphRecordError::IndexError(format!(
+                "Cannot find group {group}"
+            )));
+        }
+
+        Ok(self.group_metadata.get(group).into_iter().flatten())
+    }
+
+    pub fn set_group_metadata(
+        &mut self,
+        group: &Group,
+        key: GraphRecordAttribute,
+        value: GraphRecordValue,
+    ) -> GraphRecordResult<()> {
+        if !self.contains_group(group) {
+            return Err(GraphRecordError::IndexError(format!(
+                "Cannot find group {group}"
+            )));
+        }
+
+        self.group_metadata
+            .entry(group.clone())
+            .or_default()
+            .insert(key, value);
+
+        Ok(())
+    }
+
+    pub fn remove_group_metadata(
+        &mut self,
+        group: &Group,
+        key: &GraphRecordAttribute,
+    ) -> GraphRecordResult<GraphRecordValue> {
+        if !self.contains_group(group) {
+            return Err(GraphRecordError::IndexError(format!(
+                "Cannot find group {group}"
+            )));
+        }
+
+        let metadata = self.group_metadata.get_mut(group);
+
+        let value = metadata
+            .and_then(|metadata| metadata.remove(key))
+            .ok_or_else(|| {
+                GraphRecordError::KeyError(format!(
+                    "Cannot find metadata key {key} for group {group}"
+                ))
+            })?;
+
+        if self
+            .group_metadata
+            .get(group)
+            .is_some_and(HashMap::is_empty)
+        {
+            self.group_metadata.remove(group);
+        }
+
+        Ok(value)
+    }
+
+    pub fn remap_edges(&mut self, remap: &HashMap<EdgeIndex, EdgeIndex>) {
+        for edges_in_group in self.edges_in_group.values_mut() {
+            *edges_in_group = edges_in_group
+                .iter()
+                .map(|edge_index| remap[edge_index])
+                .collect();
+        }
+
+        self.groups_of_edge = mem::take(&mut self.groups_of_edge)
+            .into_iter()
+            .map(|(edge_index, groups)| (remap[&edge_index], groups))
+            .collect();
+    }
+
+    pub fn reserve_group(
+        &mut self,
+        group: &Group,
+        nodes: usize,
+        edges: usize,
+    ) -> GraphRecordResult<()> {
+        let (Some(nodes_in_group), Some(edges_in_group)) = (
+            self.nodes_in_group.get_mut(group),
+            self.edges_in_group.get_mut(group),
+        ) else {
+            return Err(GraphRecordError::IndexError(format!(
+                "Cannot find group {group}"
+            )));
+        };
+
+        nodes_in_group.reserve(nodes);
+        edges_in_group.reserve(edges);
+
+        Ok(())
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        for nodes_in_group in self.nodes_in_group.values_mut() {
+            nodes_in_group.shrink_to_fit();
+        }
+        for edges_in_group in self.edges_in_group.values_mut() {
+            edges_in_group.shrink_to_fit();
+        }
+        for groups_of_node in self.groups_of_node.values_mut() {
+            groups_of_node.shrink_to_fit();
+        }
+        for groups_of_edge in self.groups_of_edge.values_mut() {
+            groups_of_edge.shrink_to_fit();
+        }
+
+        self.nodes_in_group.shrink_to_fit();
+        self.edges_in_group.shrink_to_fit();
+        self.groups_of_node.shrink_to_fit();
+        self.groups_of_edge.shrink_to_fit();
+        self.group_metadata.shrink_to_fit();
+    }
+
     pub fn clear(&mut self) {
         self.nodes_in_group.clear();
         self.edges_in_group.clear();
         self.groups_of_node.clear();
         self.groups_of_edge.clear();
+        self.group_metadata.clear();
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::GroupMapping;
-    use crate::errors::GraphRecordError;
+    use crate::{
+        errors::GraphRecordError,
+        graphrecord::{GraphRecordAttribute, GraphRecordValue},
+    };
 
     #[test]
     fn test_add_group() {
@@ -307,7 +450,7 @@ mod test {
         assert!(
             group_mapping
                 .add_group("0".into(), None, None)
-                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+                .is_err_and(|e| matches!(e, GraphRecordError::DuplicateGroup { .. }))
         );
     }
 
@@ -471,6 +614,7 @@ mod test {
             1,
             group_mapping.nodes_in_group(&"0".into()).unwrap().count()
         );
+        assert_eq!(0, group_mapping.groups_of_node(&"0".into()).count());
     }
 
     #[test]
@@ -517,6 +661,7 @@ mod test {
             1,
             group_mapping.edges_in_group(&"0".into()).unwrap().count()
         );
+        assert_eq!(0, group_mapping.groups_of_edge(&0).count());
     }
 
     #[test]
@@ -647,6 +792,82 @@ mod test {
         assert!(group_mapping.contains_group(&"0".into()));
     }
 
+    #[test]
+    fn test_group_metadata() {
+        let mut group_mapping = GroupMapping::default();
+
+        group_mapping.add_group("0".into(), None, None).unwrap();
+
+        assert_eq!(
+            0,
+            group_mapping.group_metadata(&"0".into()).unwrap().count()
+        );
+
+        group_mapping
+            .set_group_metadata(&"0".into(), "color".into(), "red".into())
+            .unwrap();
+        group_mapping
+            .set_group_metadata(&"0".into(), "color".into(), "blue".into())
+            .unwrap();
+
+        assert_eq!(
+            vec![(
+                &GraphRecordAttribute::from("color"),
+                &GraphRecordValue::from("blue")
+            )],
+            group_mapping
+                .group_metadata(&"0".into())
+                .unwrap()
+                .collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            GraphRecordValue::from("blue"),
+            group_mapping
+                .remove_group_metadata(&"0".into(), &"color".into())
+                .unwrap()
+        );
+        assert!(group_mapping.group_metadata.is_empty());
+
+        // Metadata is removed along with its group
+        group_mapping
+            .set_group_metadata(&"0".into(), "color".into(), "red".into())
+            .unwrap();
+        group_mapping.remove_group(&"0".into()).unwrap();
+        group_mapping.add_group("0".into(), None, None).unwrap();
+
+        assert_eq!(
+            0,
+            group_mapping.group_metadata(&"0".into()).unwrap().count()
+        );
+    }
+
+    #[test]
+    fn test_invalid_group_metadata() {
+        let mut group_mapping = GroupMapping::default();
+
+        // Group does not exist
+        assert!(
+            group_mapping
+                .set_group_metadata(&"0".into(), "color".into(), "red".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+        );
+        assert!(
+            group_mapping
+                .group_metadata(&"0".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+        );
+
+        group_mapping.add_group("0".into(), None, None).unwrap();
+
+        // Key does not exist
+        assert!(
+            group_mapping
+                .remove_group_metadata(&"0".into(), &"color".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::KeyError(_)))
+        );
+    }
+
     #[test]
     fn test_clear() {
         let mut group_mapping = GroupMapping::default();
@@ -0,0 +1,644 @@
+use super::{
+    Attributes, GraphRecord, GraphRecordAttribute, GraphRecordValue, Group, NodeIndex,
+    schema::{Schema, SchemaType},
+};
+#[cfg(feature = "plugins")]
+use super::{
+    EdgeIndex,
+    plugins::{
+        PostAddEdgesContext, PostAddEdgesWithGroupContext, PostAddNodesContext,
+        PostAddNodesWithGroupContext, PreAddEdgesContext, PreAddEdgesWithGroupContext,
+        PreAddNodesContext, PreAddNodesWithGroupContext,
+    },
+};
+use crate::errors::{GraphRecordError, GraphRecordResult};
+use graphrecords_utils::aliases::{GrBuildHasher, GrHashMap, GrHashSet};
+use parking_lot::{Mutex, MutexGuard};
+use std::{borrow::Cow, hash::BuildHasher, mem};
+
+pub const DEFAULT_SHARD_COUNT: usize = 16;
+
+#[derive(Debug, Default)]
+struct Shard {
+    nodes: GrHashMap<Option<Group>, Vec<(NodeIndex, Attributes)>>,
+    edges: GrHashMap<Option<Group>, Vec<(NodeIndex, NodeIndex, Attributes)>>,
+}
+
+/// Buffered data of one group, drained from the shard at position `shard`.
+#[derive(Debug, Clone)]
+struct Batch<T> {
+    shard: usize,
+    group: Option<Group>,
+    items: Vec<T>,
+}
+
+type NodeBatch = Batch<(NodeIndex, Attributes)>;
+type EdgeBatch = Batch<(NodeIndex, NodeIndex, Attributes)>;
+
+/// Staging area for concurrent ingestion into a `GraphRecord`.
+///
+/// Nodes and edges are buffered in shards that are locked independently. All
+/// insertions into one group land in the same shard, so threads writing to
+/// different groups rarely contend. Ungrouped nodes and edges are spread by
+/// their (source) node index. The buffered data is applied to a `GraphRecord`
+/// with [`ShardedIngest::commit`], which takes the record exclusively, so
+/// only buffering runs concurrently.
+#[derive(Debug)]
+pub struct ShardedIngest {
+    shards: Vec<Mutex<Shard>>,
+    hash_builder: GrBuildHasher,
+}
+
+impl Default for ShardedIngest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ShardedIngest {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_shard_count(DEFAULT_SHARD_COUNT)
+    }
+
+    #[must_use]
+    pub fn with_shard_count(shard_count: usize) -> Self {
+        Self {
+            shards: (0..shard_count.max(1))
+                .map(|_| Mutex::new(Shard::default()))
+                .collect(),
+            hash_builder: GrBuildHasher::default(),
+        }
+    }
+
+    #[must_use]
+    pub const fn shard_count(&self) -> usize {
+        self.shards.len()
+    }
+
+    fn shard(&self, key: &GraphRecordAttribute) -> &Mutex<Shard> {
+        let hash = self.hash_builder.hash_one(key) as usize;
+
+        &self.shards[hash % self.shards.len()]
+    }
+
+    pub fn add_node(&self, node_index: NodeIndex, attributes: Attributes, group: Option<Group>) {
+        let mut shard = self.shard(group.as_ref().unwrap_or(&node_index)).lock();
+
+        shard
+            .nodes
+            .entry(group)
+            .or_default()
+            .push((node_index, attributes));
+    }
+
+    pub fn add_nodes(&self, nodes: Vec<(NodeIndex, Attributes)>, group: Option<Group>) {
+        match group {
+            Some(group) => {
+                let mut shard = self.shard(&group).lock();
+
+                shard.nodes.entry(Some(group)).or_default().extend(nodes);
+            }
+            None => {
+                for (node_index, attributes) in nodes {
+                    self.add_node(node_index, attributes, None);
+                }
+            }
+        }
+    }
+
+    pub fn add_edge(
+        &self,
+        source_node_index: NodeIndex,
+        target_node_index: NodeIndex,
+        attributes: Attributes,
+        group: Option<Group>,
+    ) {
+        let mut shard = self
+            .shard(group.as_ref().unwrap_or(&source_node_index))
+            .lock();
+
+        shard.edges.entry(group).or_default().push((
+            source_node_index,
+            target_node_index,
+            attributes,
+        ));
+    }
+
+    pub fn add_edges(&self, edges: Vec<(NodeIndex, NodeIndex, Attributes)>, group: Option<Group>) {
+        match group {
+            Some(group) => {
+                let mut shard = self.shard(&group).lock();
+
+                shard.edges.entry(Some(group)).or_default().extend(edges);
+            }
+            None => {
+                for (source_node_index, target_node_index, attributes) in edges {
+                    self.add_edge(source_node_index, target_node_index, attributes, None);
+                }
+            }
+        }
+    }
+
+    #[must_use]
+    pub fn pending_node_count(&self) -> usize {
+        self.shards
+            .iter()
+            .map(|shard| shard.lock().nodes.values().map(Vec::len).sum::<usize>())
+            .sum()
+    }
+
+    #[must_use]
+    pub fn pending_edge_count(&self) -> usize {
+        self.shards
+            .iter()
+            .map(|shard| shard.lock().edges.values().map(Vec::len).sum::<usize>())
+            .sum()
+    }
+
+    /// Drains all buffered nodes and edges into `graphrecord`.
+    ///
+    /// The commit is all-or-nothing. The shards stay locked until the buffered
+    /// data was passed through the pre-insertion plugin hooks and validated
+    /// against the record. If a hook fails, a node already exists, an edge
+    /// references a missing node or the schema rejects an attribute, nothing is
+    /// added. Without plugins, all data stays buffered. With plugins, the
+    /// buffered data is dropped, since the pre-insertion hooks take it over and
+    /// may have changed it. Only then are the nodes added, all of
+    /// them before any edge, so edges may reference nodes that were buffered
+    /// in a different shard. The post-insertion hooks run once everything was
+    /// added.
+    pub fn commit(&self, graphrecord: &mut GraphRecord) -> GraphRecordResult<()> {
+        graphrecord.assert_not_frozen()?;
+
+        let mut shards: Vec<_> = self.shards.iter().map(Mutex::lock).collect();
+
+        let mut node_batches = Vec::new();
+        let mut edge_batches = Vec::new();
+
+        for (position, shard) in shards.iter_mut().enumerate() {
+            let Shard { nodes, edges } = mem::take(&mut **shard);
+
+            node_batches.extend(nodes.into_iter().map(|(group, items)| Batch {
+                shard: position,
+                group,
+                items,
+            }));
+            edge_batches.extend(edges.into_iter().map(|(group, items)| Batch {
+                shard: position,
+                group,
+                items,
+            }));
+        }
+
+        #[cfg(feature = "plugins")]
+        let has_plugins = graphrecord.plugins.iter().next().is_some();
+
+        if let Err(error) = Self::prepare(&mut node_batches, &mut edge_batches, graphrecord) {
+            #[cfg(feature = "plugins")]
+            if has_plugins {
+                return Err(error);
+            }
+
+            Self::restore(&mut shards, node_batches, edge_batches);
+
+            return Err(error);
+        }
+
+        drop(shards);
+
+        #[cfg(feature = "plugins")]
+        let added_nodes = has_plugins.then(|| node_batches.clone());
+
+        for batch in node_batches {
+            match batch.group {
+                Some(group) => graphrecord.add_nodes_with_group_impl(batch.items, group)?,
+                None => graphrecord.add_nodes_impl(batch.items)?,
+            }
+        }
+
+        #[cfg_attr(not(feature = "plugins"), allow(unused_variables))]
+        let added_edges = edge_batches
+            .into_iter()
+            .map(|batch| {
+                let edge_indices = match &batch.group {
+                    Some(group) => graphrecord.add_edges_with_group_impl(batch.items, group)?,
+                    None => graphrecord.add_edges_impl(batch.items)?,
+                };
+
+                Ok((batch.group, edge_indices))
+            })
+            .collect::<GraphRecordResult<Vec<_>>>()?;
+
+        #[cfg(feature = "plugins")]
+        if let Some(added_nodes) = added_nodes {
+            Self::run_post_hooks(graphrecord, added_nodes, added_edges)?;
+        }
+
+        Ok(())
+    }
+
+    /// Runs the pre-insertion plugin hooks on the batches and validates the
+    /// result against the record.
+    #[cfg_attr(not(feature = "plugins"), allow(clippy::needless_pass_by_ref_mut))]
+    fn prepare(
+        node_batches: &mut [NodeBatch],
+        edge_batches: &mut [EdgeBatch],
+        graphrecord: &mut GraphRecord,
+    ) -> GraphRecordResult<()> {
+        #[cfg(feature = "plugins")]
+        Self::run_pre_hooks(graphrecord, node_batches, edge_batches)?;
+
+        Self::validate(node_batches, edge_batches, graphrecord)
+    }
+
+    #[cfg(feature = "plugins")]
+    fn run_pre_hooks(
+        graphrecord: &mut GraphRecord,
+        node_batches: &mut [NodeBatch],
+        edge_batches: &mut [EdgeBatch],
+    ) -> GraphRecordResult<()> {
+        let plugins = graphrecord.plugins.clone();
+
+        for batch in node_batches {
+            let nodes = mem::take(&mut batch.items);
+
+            if let Some(group) = batch.group.take() {
+                let context = plugins.iter().try_fold(
+                    PreAddNodesWithGroupContext { nodes, group },
+                    |context, (_, plugin)| plugin.pre_add_nodes_with_group(graphrecord, context),
+                )?;
+
+                batch.items = context.nodes;
+                batch.group = Some(context.group);
+            } else {
+                let context = plugins
+                    .iter()
+                    .try_fold(PreAddNodesContext { nodes }, |context, (_, plugin)| {
+                        plugin.pre_add_nodes(graphrecord, context)
+                    })?;
+
+                batch.items = context.nodes;
+            }
+        }
+
+        for batch in edge_batches {
+            let edges = mem::take(&mut batch.items);
+
+            if let Some(group) = batch.group.take() {
+                let context = plugins.iter().try_fold(
+                    PreAddEdgesWithGroupContext { edges, group },
+                    |context, (_, plugin)| plugin.pre_add_edges_with_group(graphrecord, context),
+                )?;
+
+                batch.items = context.edges;
+                batch.group = Some(context.group);
+            } else {
+                let context = plugins
+                    .iter()
+                    .try_fold(PreAddEdgesContext { edges }, |context, (_, plugin)| {
+                        plugin.pre_add_edges(graphrecord, context)
+                    })?;
+
+                batch.items = context.edges;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "plugins")]
+    fn run_post_hooks(
+        graphrecord: &mut GraphRecord,
+        added_nodes: Vec<NodeBatch>,
+        added_edges: Vec<(Option<Group>, Vec<EdgeIndex>)>,
+    ) -> GraphRecordResult<()> {
+        let plugins = graphrecord.plugins.clone();
+
+        for batch in added_nodes {
+            if let Some(group) = batch.group {
+                let context = PostAddNodesWithGroupContext {
+                    nodes: batch.items,
+                    group,
+                };
+
+                plugins.iter().try_for_each(|(_, plugin)| {
+                    plugin.post_add_nodes_with_group(graphrecord, context.clone())
+                })?;
+            } else {
+                let context = PostAddNodesContext { nodes: batch.items };
+
+                plugins.iter().try_for_each(|(_, plugin)| {
+                    plugin.post_add_nodes(graphrecord, context.clone())
+                })?;
+            }
+        }
+
+        for (group, edge_indices) in added_edges {
+            if group.is_some() {
+                let context = PostAddEdgesWithGroupContext { edge_indices };
+
+                plugins.iter().try_for_each(|(_, plugin)| {
+                    plugin.post_add_edges_with_group(graphrecord, context.clone())
+                })?;
+            } else {
+                let context = PostAddEdgesContext { edge_indices };
+
+                plugins.iter().try_for_each(|(_, plugin)| {
+                    plugin.post_add_edges(graphrecord, context.clone())
+                })?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Puts the batches back into the shards they were drained from.
+    fn restore(
+        shards: &mut [MutexGuard<'_, Shard>],
+        node_batches: Vec<NodeBatch>,
+        edge_batches: Vec<EdgeBatch>,
+    ) {
+        for batch in node_batches {
+            shards[batch.shard]
+                .nodes
+                .entry(batch.group)
+                .or_default()
+                .extend(batch.items);
+        }
+        for batch in edge_batches {
+            shards[batch.shard]
+                .edges
+                .entry(batch.group)
+                .or_default()
+                .extend(batch.items);
+        }
+    }
+
+    /// Checks that adding the batches in [`ShardedIngest::commit`] order
+    /// cannot fail because of the record or its schema.
+    fn validate(
+        node_batches: &[NodeBatch],
+        edge_batches: &[EdgeBatch],
+        graphrecord: &GraphRecord,
+    ) -> GraphRecordResult<()> {
+        let schema = &graphrecord.schema;
+        let mut node_indices = GrHashSet::default();
+
+        for batch in node_batches {
+            for (node_index, attributes) in &batch.items {
+                if graphrecord.contains_node(node_index) || !node_indices.insert(node_index) {
+                    return Err(GraphRecordError::AssertionError(format!(
+                        "Node with index {node_index} already exists"
+                    )));
+                }
+
+                validate_attributes(schema, attributes, |attributes| {
+                    schema.validate_node(node_index, attributes, batch.group.as_ref())
+                })?;
+            }
+        }
+
+        let mut edge_indices = graphrecord.graph.peek_edge_indices();
+
+        for batch in edge_batches {
+            for (source_node_index, target_node_index, attributes) in &batch.items {
+                for node_index in [target_node_index, source_node_index] {
+                    if !graphrecord.contains_node(node_index) && !node_indices.contains(node_index)
+                    {
+                        return Err(GraphRecordError::MissingNode {
+                            index: node_index.clone(),
+                        });
+                    }
+                }
+
+                let edge_index = edge_indices.next().expect("Edge indices are unbounded");
+
+                validate_attributes(schema, attributes, |attributes| {
+                    schema.validate_edge(&edge_index, attributes, batch.group.as_ref())
+                })?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Validates `attributes` like they are validated when added, with `validate`
+/// checking them against a provided schema.
+pub(super) fn validate_attributes<E>(
+    schema: &Schema,
+    attributes: &Attributes,
+    validate: impl FnOnce(&Attributes) -> Result<(), E>,
+) -> GraphRecordResult<()>
+where
+    GraphRecordError: From<E>,
+{
+    let has_non_finite_floats = attributes
+        .values()
+        .any(|value| matches!(value, GraphRecordValue::Float(value) if !value.is_finite()));
+
+    let attributes = if has_non_finite_floats {
+        let mut attributes = attributes.clone();
+        schema.handle_non_finite_floats(&mut attributes)?;
+
+        Cow::Owned(attributes)
+    } else {
+        Cow::Borrowed(attributes)
+    };
+
+    if *schema.schema_type() == SchemaType::Provided {
+        validate(&attributes)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::ShardedIngest;
+    use crate::{
+        GraphRecord,
+        errors::GraphRecordError,
+        graphrecord::schema::{GroupSchema, Schema},
+    };
+    #[cfg(feature = "plugins")]
+    use crate::{
+        errors::GraphRecordResult,
+        graphrecord::plugins::{Plugin, PreAddEdgesContext},
+    };
+    use std::{collections::HashMap, thread};
+
+    #[cfg(feature = "plugins")]
+    #[derive(Debug, Clone)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    struct RejectEdgesPlugin;
+
+    #[cfg(feature = "plugins")]
+    #[cfg_attr(feature = "serde", typetag::serde)]
+    impl Plugin for RejectEdgesPlugin {
+        fn clone_box(&self) -> Box<dyn Plugin> {
+            Box::new(self.clone())
+        }
+
+        fn pre_add_edges(
+            &self,
+            _graphrecord: &mut GraphRecord,
+            _context: PreAddEdgesContext,
+        ) -> GraphRecordResult<PreAddEdgesContext> {
+            Err(GraphRecordError::AssertionError(
+                "Edges are rejected".to_string(),
+            ))
+        }
+    }
+
+    #[test]
+    fn test_commit() {
+        let ingest = ShardedIngest::with_shard_count(4);
+
+        ingest.add_node("0".into(), HashMap::new(), None);
+        ingest.add_node("1".into(), HashMap::new(), Some("group".into()));
+        ingest.add_edge("0".into(), "1".into(), HashMap::new(), Some("group".into()));
+        ingest.add_edge("1".into(), "0".into(), HashMap::new(), None);
+
+        assert_eq!(2, ingest.pending_node_count());
+        assert_eq!(2, ingest.pending_edge_count());
+
+        let mut graphrecord = GraphRecord::new();
+
+        ingest.commit(&mut graphrecord).unwrap();
+
+        assert_eq!(0, ingest.pending_node_count());
+        assert_eq!(0, ingest.pending_edge_count());
+
+        assert_eq!(2, graphrecord.node_count());
+        assert_eq!(2, graphrecord.edge_count());
+        assert_eq!(
+            1,
+            graphrecord.nodes_in_group(&"group".into()).unwrap().count()
+        );
+        assert_eq!(
+            1,
+            graphrecord.edges_in_group(&"group".into()).unwrap().count()
+        );
+    }
+
+    #[test]
+    fn test_concurrent_add() {
+        let ingest = ShardedIngest::new();
+
+        thread::scope(|scope| {
+            for thread_index in 0..4_i64 {
+                let ingest = &ingest;
+
+                scope.spawn(move || {
+                    let group = format!("group_{thread_index}");
+
+                    for node in 0..100_i64 {
+                        ingest.add_node(
+                            (thread_index * 100 + node).into(),
+                            HashMap::new(),
+                            Some(group.clone().into()),
+                        );
+                    }
+                });
+            }
+        });
+
+        let mut graphrecord = GraphRecord::new();
+
+        ingest.commit(&mut graphrecord).unwrap();
+
+        assert_eq!(400, graphrecord.node_count());
+        assert_eq!(4, graphrecord.group_count());
+    }
+
+    #[test]
+    fn test_invalid_commit() {
+        let ingest = ShardedIngest::new();
+
+        ingest.add_node("0".into(), HashMap::new(), None);
+        ingest.add_node("1".into(), HashMap::new(), Some("group".into()));
+        ingest.add_edge("0".into(), "1".into(), HashMap::new(), Some("group".into()));
+
+        // Adding an edge pointing to a non-existing node should fail on commit
+        ingest.add_edge("0".into(), "2".into(), HashMap::new(), None);
+
+        let mut graphrecord = GraphRecord::new();
+
+        assert!(
+            ingest
+                .commit(&mut graphrecord)
+                .is_err_and(|e| matches!(e, GraphRecordError::MissingNode { .. }))
+        );
+
+        // Nothing should be applied and the data should stay buffered
+        assert_eq!(0, graphrecord.node_count());
+        assert_eq!(0, graphrecord.group_count());
+        assert_eq!(2, ingest.pending_node_count());
+        assert_eq!(2, ingest.pending_edge_count());
+
+        ingest.add_node("2".into(), HashMap::new(), None);
+        ingest.commit(&mut graphrecord).unwrap();
+
+        assert_eq!(3, graphrecord.node_count());
+        assert_eq!(2, graphrecord.edge_count());
+
+        // Adding an existing node should fail on commit
+        ingest.add_node("3".into(), HashMap::new(), None);
+        ingest.add_node("0".into(), HashMap::new(), None);
+
+        assert!(
+            ingest
+                .commit(&mut graphrecord)
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
+        assert_eq!(3, graphrecord.node_count());
+    }
+
+    #[test]
+    fn test_invalid_commit_schema() {
+        let ingest = ShardedIngest::new();
+
+        ingest.add_node("0".into(), HashMap::new(), None);
+        ingest.add_node("1".into(), HashMap::from([("age".into(), 20.into())]), None);
+
+        let mut graphrecord =
+            GraphRecord::with_schema(Schema::new_provided(HashMap::new(), GroupSchema::default()));
+
+        // Adding a node with an attribute the schema lacks should fail on commit
+        assert!(
+            ingest
+                .commit(&mut graphrecord)
+                .is_err_and(|e| matches!(e, GraphRecordError::SchemaError(_)))
+        );
+        assert_eq!(0, graphrecord.node_count());
+    }
+
+    #[cfg(feature = "plugins")]
+    #[test]
+    fn test_invalid_commit_plugin() {
+        let ingest = ShardedIngest::new();
+
+        ingest.add_node("0".into(), HashMap::new(), None);
+        ingest.add_node("1".into(), HashMap::new(), Some("group".into()));
+        ingest.add_edge("0".into(), "1".into(), HashMap::new(), None);
+
+        let mut graphrecord = GraphRecord::new();
+        graphrecord
+            .add_plugin("reject".into(), Box::new(RejectEdgesPlugin))
+            .unwrap();
+
+        // A failing hook should stop the commit before any node is added
+        assert!(
+            ingest
+                .commit(&mut graphrecord)
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
+        assert_eq!(0, graphrecord.node_count());
+
+        // The hooks took the buffered data over, so it is dropped
+        assert_eq!(0, ingest.pending_node_count());
+        assert_eq!(0, ingest.pending_edge_count());
+    }
+}
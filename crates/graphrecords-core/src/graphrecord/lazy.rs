@@ -0,0 +1,262 @@
+//! Ingestion of polars `LazyFrame`s, e.g. `scan_parquet` or `scan_csv`
+//! pipelines, in batches of rows, so that the whole table never has to be
+//! materialized at once.
+
+use super::{
+    EdgeDataFrameInput, EdgeIndex, GraphRecord, MissingNodePolicy, NodeDataFrameInput,
+    NullHandling, schema::Schema,
+};
+use crate::errors::{GraphRecordError, GraphRecordResult};
+use polars::prelude::{DataFrame, IdxSize, LazyFrame};
+
+#[derive(Clone)]
+pub struct NodeLazyFrameInput {
+    pub lazyframe: LazyFrame,
+    pub index_column: String,
+    pub nulls: NullHandling,
+}
+
+#[derive(Clone)]
+pub struct EdgeLazyFrameInput {
+    pub lazyframe: LazyFrame,
+    pub source_index_column: String,
+    pub target_index_column: String,
+    pub nulls: NullHandling,
+    pub missing_nodes: MissingNodePolicy,
+}
+
+impl<S> From<(LazyFrame, S)> for NodeLazyFrameInput
+where
+    S: Into<String>,
+{
+    fn from(val: (LazyFrame, S)) -> Self {
+        Self {
+            lazyframe: val.0,
+            index_column: val.1.into(),
+            nulls: NullHandling::default(),
+        }
+    }
+}
+
+impl<S> From<(LazyFrame, S, S)> for EdgeLazyFrameInput
+where
+    S: Into<String>,
+{
+    fn from(val: (LazyFrame, S, S)) -> Self {
+        Self {
+            lazyframe: val.0,
+            source_index_column: val.1.into(),
+            target_index_column: val.2.into(),
+            nulls: NullHandling::default(),
+            missing_nodes: MissingNodePolicy::default(),
+        }
+    }
+}
+
+/// Collects `lazyframe` in batches of at most `batch_size` rows. Every batch
+/// is a slice of the query, which polars pushes down into the scan, so only
+/// the rows of the current batch are read.
+fn batches(
+    lazyframe: LazyFrame,
+    batch_size: usize,
+) -> impl Iterator<Item = GraphRecordResult<DataFrame>> {
+    let batch_size = batch_size.max(1);
+    let mut offset = 0;
+    let mut finished = false;
+
+    std::iter::from_fn(move || {
+        if finished {
+            return None;
+        }
+
+        let batch = lazyframe
+            .clone()
+            .slice(offset, batch_size as IdxSize)
+            .collect()
+            .map_err(|error| {
+                GraphRecordError::ConversionError(format!("Failed to collect LazyFrame: {error}"))
+            });
+
+        match batch {
+            Ok(batch) if batch.height() == 0 => None,
+            Ok(batch) => {
+                finished = batch.height() < batch_size;
+                offset += batch.height() as i64;
+
+                Some(Ok(batch))
+            }
+            Err(error) => {
+                finished = true;
+
+                Some(Err(error))
+            }
+        }
+    })
+}
+
+impl GraphRecord {
+    /// Like [`GraphRecord::from_dataframes`], but collects the frames in
+    /// batches of at most `batch_size` rows. All nodes are added before the
+    /// first edge.
+    pub fn from_lazyframes(
+        nodes_lazyframes: impl IntoIterator<Item = impl Into<NodeLazyFrameInput>>,
+        edges_lazyframes: impl IntoIterator<Item = impl Into<EdgeLazyFrameInput>>,
+        schema: Option<Schema>,
+        batch_size: usize,
+    ) -> GraphRecordResult<Self> {
+        let mut graphrecord = Self::with_capacity(0, 0, schema);
+
+        graphrecord.add_nodes_lazyframes(nodes_lazyframes, batch_size)?;
+        graphrecord.add_edges_lazyframes(edges_lazyframes, batch_size)?;
+
+        Ok(graphrecord)
+    }
+
+    /// Adds the nodes of every frame in batches of at most `batch_size` rows.
+    /// Every batch is added with [`GraphRecord::add_nodes_dataframes`], so
+    /// the nodes of earlier batches are kept if a later batch fails.
+    pub fn add_nodes_lazyframes(
+        &mut self,
+        nodes_lazyframes: impl IntoIterator<Item = impl Into<NodeLazyFrameInput>>,
+        batch_size: usize,
+    ) -> GraphRecordResult<()> {
+        for input in nodes_lazyframes {
+            let input = input.into();
+
+            for batch in batches(input.lazyframe, batch_size) {
+                self.add_nodes_dataframes(vec![NodeDataFrameInput {
+                    dataframe: batch?,
+                    index_column: input.index_column.clone(),
+                    nulls: input.nulls.clone(),
+                }])?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds the edges of every frame in batches of at most `batch_size` rows
+    /// and returns their indices. Every batch is added with
+    /// [`GraphRecord::add_edges_dataframes`], so the edges of earlier batches
+    /// are kept if a later batch fails.
+    pub fn add_edges_lazyframes(
+        &mut self,
+        edges_lazyframes: impl IntoIterator<Item = impl Into<EdgeLazyFrameInput>>,
+        batch_size: usize,
+    ) -> GraphRecordResult<Vec<EdgeIndex>> {
+        let mut edge_indices = Vec::new();
+
+        for input in edges_lazyframes {
+            let input = input.into();
+
+            for batch in batches(input.lazyframe, batch_size) {
+                edge_indices.extend(self.add_edges_dataframes(vec![EdgeDataFrameInput {
+                    dataframe: batch?,
+                    source_index_column: input.source_index_column.clone(),
+                    target_index_column: input.target_index_column.clone(),
+                    nulls: input.nulls.clone(),
+                    missing_nodes: input.missing_nodes.clone(),
+                }])?);
+            }
+        }
+
+        Ok(edge_indices)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::batches;
+    use crate::{GraphRecord, errors::GraphRecordError, graphrecord::GraphRecordValue};
+    use polars::prelude::{DataFrame, IntoLazy, LazyFrame, NamedFrom, Series, col, lit};
+
+    fn create_nodes_lazyframe() -> LazyFrame {
+        let s0 = Series::new("index".into(), &["0", "1", "2", "3", "4"]);
+        let s1 = Series::new("age".into(), &[20, 30, 40, 50, 60]);
+
+        DataFrame::new(5, vec![s0.into(), s1.into()])
+            .unwrap()
+            .lazy()
+    }
+
+    fn create_edges_lazyframe() -> LazyFrame {
+        let s0 = Series::new("from".into(), &["0", "1", "2"]);
+        let s1 = Series::new("to".into(), &["1", "2", "3"]);
+
+        DataFrame::new(3, vec![s0.into(), s1.into()])
+            .unwrap()
+            .lazy()
+    }
+
+    #[test]
+    fn test_batches() {
+        let heights: Vec<_> = batches(create_nodes_lazyframe(), 2)
+            .map(|batch| batch.unwrap().height())
+            .collect();
+
+        assert_eq!(vec![2, 2, 1], heights);
+        assert_eq!(1, batches(create_nodes_lazyframe(), 0).take(1).count());
+    }
+
+    #[test]
+    fn test_from_lazyframes() {
+        let graphrecord = GraphRecord::from_lazyframes(
+            vec![(
+                create_nodes_lazyframe().filter(col("age").gt(lit(20))),
+                "index",
+            )],
+            vec![(create_edges_lazyframe(), "from", "to")],
+            None,
+            2,
+        );
+
+        assert!(
+            graphrecord.is_err_and(|error| matches!(error, GraphRecordError::MissingNode { .. }))
+        );
+
+        let graphrecord = GraphRecord::from_lazyframes(
+            vec![(create_nodes_lazyframe(), "index")],
+            vec![(create_edges_lazyframe(), "from", "to")],
+            None,
+            2,
+        )
+        .unwrap();
+
+        assert_eq!(5, graphrecord.node_count());
+        assert_eq!(3, graphrecord.edge_count());
+        assert_eq!(
+            &GraphRecordValue::Int(40),
+            graphrecord
+                .node_attributes(&"2".into())
+                .unwrap()
+                .get(&"age".into())
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_add_edges_lazyframes() {
+        let mut graphrecord = GraphRecord::new();
+
+        graphrecord
+            .add_nodes_lazyframes(vec![(create_nodes_lazyframe(), "index")], 4)
+            .unwrap();
+
+        let edge_indices = graphrecord
+            .add_edges_lazyframes(vec![(create_edges_lazyframe(), "from", "to")], 1)
+            .unwrap();
+
+        assert_eq!(vec![0, 1, 2], edge_indices);
+    }
+
+    #[test]
+    fn test_invalid_add_nodes_lazyframes() {
+        let mut graphrecord = GraphRecord::new();
+
+        assert!(
+            graphrecord
+                .add_nodes_lazyframes(vec![(create_nodes_lazyframe(), "missing")], 2)
+                .is_err_and(|error| matches!(error, GraphRecordError::ConversionError(_)))
+        );
+    }
+}
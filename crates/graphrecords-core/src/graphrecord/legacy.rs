@@ -0,0 +1,158 @@
+//! Layout of records serialized before the versioned container was
+//! introduced, when `GraphRecord` was written as a single bincode value.
+//!
+//! Bincode is positional, so the current types can not read these bytes:
+//! fields were added to the record and its parts. The types in this module are
+//! frozen copies of that layout and must not be changed. Values and data types
+//! only gained variants after the last one, so they are read as they are.
+
+use super::{
+    Attributes, EdgeIndex, GraphRecord, Group, NodeIndex,
+    datatypes::GraphRecordAttribute,
+    schema::{AttributeDataType, AttributeSchema, GroupSchema, Schema, SchemaType},
+};
+use crate::errors::GraphRecordError;
+#[cfg(feature = "plugins")]
+use crate::graphrecord::plugins::{DEFAULT_PLUGIN_PRIORITY, Plugin, PluginName, PluginRegistry};
+use graphrecords_utils::aliases::{GrHashMap, GrHashSet};
+use serde::Deserialize;
+use std::collections::HashMap;
+#[cfg(feature = "plugins")]
+use std::sync::Arc;
+
+type LegacyAttributeSchema = HashMap<GraphRecordAttribute, AttributeDataType>;
+
+#[derive(Debug, Deserialize)]
+struct LegacyGroupSchema {
+    nodes: LegacyAttributeSchema,
+    edges: LegacyAttributeSchema,
+}
+
+impl From<LegacyGroupSchema> for GroupSchema {
+    fn from(value: LegacyGroupSchema) -> Self {
+        Self::new(
+            AttributeSchema::new(value.nodes),
+            AttributeSchema::new(value.edges),
+        )
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct LegacySchema {
+    groups: HashMap<Group, LegacyGroupSchema>,
+    ungrouped: LegacyGroupSchema,
+    r#type: SchemaType,
+}
+
+impl From<LegacySchema> for Schema {
+    fn from(value: LegacySchema) -> Self {
+        let groups = value
+            .groups
+            .into_iter()
+            .map(|(group, schema)| (group, schema.into()))
+            .collect();
+        let ungrouped = value.ungrouped.into();
+
+        match value.r#type {
+            SchemaType::Inferred => Self::new_inferred(groups, ungrouped),
+            SchemaType::Provided => Self::new_provided(groups, ungrouped),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct LegacyNode {
+    attributes: Attributes,
+    #[allow(dead_code, reason = "rebuilt from the edges")]
+    outgoing_edge_indices: GrHashSet<EdgeIndex>,
+    #[allow(dead_code, reason = "rebuilt from the edges")]
+    incoming_edge_indices: GrHashSet<EdgeIndex>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LegacyEdge {
+    attributes: Attributes,
+    source_node_index: NodeIndex,
+    target_node_index: NodeIndex,
+}
+
+#[derive(Debug, Deserialize)]
+struct LegacyGraph {
+    nodes: GrHashMap<NodeIndex, LegacyNode>,
+    edges: GrHashMap<EdgeIndex, LegacyEdge>,
+    edge_index_counter: EdgeIndex,
+}
+
+#[derive(Debug, Deserialize)]
+struct LegacyGroupMapping {
+    nodes_in_group: GrHashMap<Group, GrHashSet<NodeIndex>>,
+    edges_in_group: GrHashMap<Group, GrHashSet<EdgeIndex>>,
+    #[allow(dead_code, reason = "rebuilt from the groups")]
+    groups_of_node: GrHashMap<NodeIndex, GrHashSet<Group>>,
+    #[allow(dead_code, reason = "rebuilt from the groups")]
+    groups_of_edge: GrHashMap<EdgeIndex, GrHashSet<Group>>,
+}
+
+/// A `GraphRecord` as serialized before the versioned container.
+#[derive(Debug, Deserialize)]
+pub struct LegacyGraphRecord {
+    graph: LegacyGraph,
+    group_mapping: LegacyGroupMapping,
+    schema: LegacySchema,
+
+    #[cfg(feature = "plugins")]
+    plugins: GrHashMap<PluginName, Box<dyn Plugin>>,
+}
+
+impl TryFrom<LegacyGraphRecord> for GraphRecord {
+    type Error = GraphRecordError;
+
+    fn try_from(value: LegacyGraphRecord) -> Result<Self, Self::Error> {
+        let mut graphrecord = Self::new();
+
+        for (node_index, node) in value.graph.nodes {
+            graphrecord
+                .graph
+                .add_node(node_index, node.attributes.into())?;
+        }
+
+        for (edge_index, edge) in value.graph.edges {
+            graphrecord.graph.restore_edge(
+                edge_index,
+                edge.source_node_index,
+                edge.target_node_index,
+                edge.attributes.into(),
+            )?;
+        }
+
+        graphrecord
+            .graph
+            .restore_edge_index_state(value.graph.edge_index_counter, Vec::new());
+
+        let mut edges_in_group = value.group_mapping.edges_in_group;
+
+        for (group, nodes) in value.group_mapping.nodes_in_group {
+            let edges = edges_in_group.remove(&group).unwrap_or_default();
+
+            graphrecord.group_mapping.add_group(
+                group,
+                Some(nodes.into_iter().collect()),
+                Some(edges.into_iter().collect()),
+            )?;
+        }
+
+        graphrecord.schema = value.schema.into();
+
+        #[cfg(feature = "plugins")]
+        {
+            let mut registry = PluginRegistry::default();
+            for (name, plugin) in value.plugins {
+                registry.insert(Some(name), plugin, DEFAULT_PLUGIN_PRIORITY);
+            }
+
+            graphrecord.plugins = Arc::new(registry);
+        }
+
+        Ok(graphrecord)
+    }
+}
@@ -0,0 +1,94 @@
+//! Housekeeping that applications can schedule, e.g. in idle periods of a
+//! long-running service, so that later operations do not pay for it.
+
+use super::GraphRecord;
+
+/// Actions taken by [`GraphRecord::maintain`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MaintenanceReport {
+    /// Estimated heap memory released by shrinking nodes, edges and groups,
+    /// in bytes.
+    pub bytes_released: usize,
+    /// Number of text indices that were outdated and built anew.
+    pub text_indices_rebuilt: usize,
+    /// Number of geo indices that were outdated and built anew.
+    pub geo_indices_rebuilt: usize,
+}
+
+impl GraphRecord {
+    /// Performs housekeeping and reports the actions taken.
+    ///
+    /// Releases capacity that nodes, edges and groups no longer use, and
+    /// builds the text and geo indices that are outdated after changes to the
+    /// record, so the next query using them does not have to. The content of
+    /// the record is not changed.
+    pub fn maintain(&mut self) -> MaintenanceReport {
+        let memory_usage = self.memory_usage().total();
+
+        self.shrink_to_fit();
+
+        let bytes_released = memory_usage.saturating_sub(self.memory_usage().total());
+
+        MaintenanceReport {
+            bytes_released,
+            text_indices_rebuilt: self.text_indices.rebuild_stale(self),
+            geo_indices_rebuilt: self.geo_indices.rebuild_stale(self),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::MaintenanceReport;
+    use crate::GraphRecord;
+    use std::collections::HashMap;
+
+    fn create_graphrecord() -> GraphRecord {
+        let mut graphrecord = GraphRecord::new();
+
+        graphrecord
+            .add_nodes_with_group(
+                (0..100)
+                    .map(|index| {
+                        (
+                            index.into(),
+                            HashMap::from([("note".into(), "Heart failure".into())]),
+                        )
+                    })
+                    .collect(),
+                "patients".into(),
+            )
+            .unwrap();
+
+        graphrecord
+            .create_text_index("patients".into(), "note".into())
+            .unwrap();
+
+        graphrecord
+    }
+
+    #[test]
+    fn test_maintain() {
+        let mut graphrecord = create_graphrecord();
+
+        for index in 1..100 {
+            graphrecord.remove_node(&index.into()).unwrap();
+        }
+
+        let report = graphrecord.maintain();
+
+        assert!(report.bytes_released > 0);
+        assert_eq!(1, report.text_indices_rebuilt);
+        assert_eq!(0, report.geo_indices_rebuilt);
+
+        assert_eq!(MaintenanceReport::default(), graphrecord.maintain());
+    }
+
+    #[test]
+    fn test_maintain_unchanged() {
+        let mut graphrecord = create_graphrecord();
+        graphrecord.shrink_to_fit();
+
+        assert_eq!(0, graphrecord.maintain().text_indices_rebuilt);
+    }
+}
@@ -0,0 +1,571 @@
+//! Propensity score matching between a treated and a control node group.
+//!
+//! Propensity scores are fitted with a logistic regression of the group
+//! membership on the covariates, and every treated node is greedily matched to
+//! the closest unmatched control node.
+
+use super::{EdgeIndex, GraphRecord, GraphRecordAttribute, GraphRecordValue, Group, NodeIndex};
+use crate::errors::{GraphRecordError, GraphRecordResult};
+use graphrecords_utils::aliases::GrHashSet;
+use std::collections::{BTreeSet, HashMap};
+
+const MAX_ITERATIONS: usize = 100;
+const TOLERANCE: f64 = 1e-8;
+/// Keeps the fit finite if the covariates perfectly separate the groups.
+const RIDGE: f64 = 1e-4;
+
+fn covariate_value(
+    node_index: &NodeIndex,
+    attribute: &GraphRecordAttribute,
+    value: Option<&GraphRecordValue>,
+) -> GraphRecordResult<f64> {
+    match value {
+        Some(GraphRecordValue::Int(value)) => Ok(*value as f64),
+        Some(GraphRecordValue::Float(value)) => Ok(*value),
+        Some(GraphRecordValue::Bool(value)) => Ok(f64::from(u8::from(*value))),
+        Some(value) => Err(GraphRecordError::ConversionError(format!(
+            "Covariate {attribute} of node {node_index} must be numeric or boolean, got {value}"
+        ))),
+        None => Err(GraphRecordError::KeyError(format!(
+            "Cannot find covariate {attribute} of node {node_index}"
+        ))),
+    }
+}
+
+/// Solves `matrix * x = vector` with Gaussian elimination and partial
+/// pivoting. Returns `None` if the matrix is singular.
+fn solve(mut matrix: Vec<Vec<f64>>, mut vector: Vec<f64>) -> Option<Vec<f64>> {
+    let size = vector.len();
+
+    for column in 0..size {
+        let pivot = (column..size).max_by(|a, b| {
+            matrix[*a][column]
+                .abs()
+                .total_cmp(&matrix[*b][column].abs())
+        })?;
+
+        if matrix[pivot][column].abs() < f64::EPSILON {
+            return None;
+        }
+
+        matrix.swap(column, pivot);
+        vector.swap(column, pivot);
+
+        let pivot_equation = matrix[column].clone();
+        let pivot_value = vector[column];
+
+        for (equation, value) in matrix.iter_mut().zip(&mut vector).skip(column + 1) {
+            let factor = equation[column] / pivot_equation[column];
+
+            for (coefficient, pivot_coefficient) in
+                equation.iter_mut().zip(&pivot_equation).skip(column)
+            {
+                *coefficient -= factor * pivot_coefficient;
+            }
+
+            *value -= factor * pivot_value;
+        }
+    }
+
+    let mut solution = vec![0.0; size];
+
+    for (row, (equation, value)) in matrix.iter().zip(&vector).enumerate().rev() {
+        let sum: f64 = equation[row + 1..]
+            .iter()
+            .zip(&solution[row + 1..])
+            .map(|(coefficient, solution)| coefficient * solution)
+            .sum();
+
+        solution[row] = (value - sum) / equation[row];
+    }
+
+    Some(solution)
+}
+
+fn sigmoid(value: f64) -> f64 {
+    1.0 / (1.0 + (-value).exp())
+}
+
+/// Fits a logistic regression with an intercept using Newton's method and
+/// returns the predicted probabilities. Covariates are standardized first so
+/// the ridge penalty treats them alike.
+fn fit_logistic_regression(mut features: Vec<Vec<f64>>, labels: &[f64]) -> Vec<f64> {
+    let sample_size = features.len() as f64;
+    let feature_count = features.first().map_or(0, Vec::len);
+
+    for feature in 0..feature_count {
+        let mean = features.iter().map(|row| row[feature]).sum::<f64>() / sample_size;
+        let std = (features
+            .iter()
+            .map(|row| (row[feature] - mean).powi(2))
+            .sum::<f64>()
+            / sample_size)
+            .sqrt();
+
+        for row in &mut features {
+            row[feature] = if std > 0.0 {
+                (row[feature] - mean) / std
+            } else {
+                0.0
+            };
+        }
+    }
+
+    for row in &mut features {
+        row.insert(0, 1.0);
+    }
+
+    let size = feature_count + 1;
+    let mut weights = vec![0.0; size];
+
+    for _ in 0..MAX_ITERATIONS {
+        let mut gradient: Vec<f64> = weights.iter().map(|weight| -RIDGE * weight).collect();
+        let mut hessian = vec![vec![0.0; size]; size];
+
+        for (diagonal, row) in hessian.iter_mut().enumerate() {
+            row[diagonal] = RIDGE;
+        }
+
+        for (row, label) in features.iter().zip(labels) {
+            let linear: f64 = row.iter().zip(&weights).map(|(x, w)| x * w).sum();
+            let probability = sigmoid(linear);
+            let variance = probability * (1.0 - probability);
+
+            for ((gradient, hessian_row), x_i) in gradient.iter_mut().zip(&mut hessian).zip(row) {
+                *gradient += (label - probability) * x_i;
+
+                for (hessian, x_j) in hessian_row.iter_mut().zip(row) {
+                    *hessian += variance * x_i * x_j;
+                }
+            }
+        }
+
+        let Some(step) = solve(hessian, gradient) else {
+            break;
+        };
+
+        for (weight, step) in weights.iter_mut().zip(&step) {
+            *weight += step;
+        }
+
+        if step.iter().all(|step| step.abs() < TOLERANCE) {
+            break;
+        }
+    }
+
+    features
+        .iter()
+        .map(|row| sigmoid(row.iter().zip(&weights).map(|(x, w)| x * w).sum()))
+        .collect()
+}
+
+impl GraphRecord {
+    /// Returns the propensity of every node in `treated` and `control` to be in
+    /// `treated`, fitted with a logistic regression on `covariates`. Integer,
+    /// float and boolean covariates are supported and every node needs all of
+    /// them.
+    pub fn propensity_scores(
+        &self,
+        treated: &Group,
+        control: &Group,
+        covariates: &[GraphRecordAttribute],
+    ) -> GraphRecordResult<HashMap<NodeIndex, f64>> {
+        let treated_nodes: Vec<_> = self.nodes_in_group(treated)?.collect();
+        let control_nodes: Vec<_> = self.nodes_in_group(control)?.collect();
+
+        let control_node_set: GrHashSet<_> = control_nodes.iter().copied().collect();
+
+        if let Some(node_index) = treated_nodes
+            .iter()
+            .find(|node_index| control_node_set.contains(*node_index))
+        {
+            return Err(GraphRecordError::AssertionError(format!(
+                "Node {node_index} cannot be in both the treated and the control group"
+            )));
+        }
+
+        if treated_nodes.is_empty() || control_nodes.is_empty() {
+            return Err(GraphRecordError::AssertionError(
+                "Treated and control group must not be empty".to_string(),
+            ));
+        }
+
+        let nodes: Vec<_> = treated_nodes
+            .iter()
+            .chain(&control_nodes)
+            .copied()
+            .collect();
+
+        let features = nodes
+            .iter()
+            .map(|node_index| {
+                let attributes = self.node_attributes(node_index)?;
+
+                covariates
+                    .iter()
+                    .map(|covariate| {
+                        covariate_value(node_index, covariate, attributes.get(covariate))
+                    })
+                    .collect()
+            })
+            .collect::<GraphRecordResult<Vec<Vec<f64>>>>()?;
+
+        let labels: Vec<_> = std::iter::repeat_n(1.0, treated_nodes.len())
+            .chain(std::iter::repeat_n(0.0, control_nodes.len()))
+            .collect();
+
+        let scores = fit_logistic_regression(features, &labels);
+
+        Ok(nodes.into_iter().cloned().zip(scores).collect())
+    }
+
+    /// Matches every node in `treated` to the unmatched node in `control` with
+    /// the closest propensity score, and adds an edge from the treated to the
+    /// control node to the new `edge_group` for every pair. Treated nodes are
+    /// matched in the order of their indices. With a `caliper`, pairs whose
+    /// scores differ by more than it are left unmatched.
+    ///
+    /// The edges carry the difference of the scores as the `distance`
+    /// attribute. Returns the matched pairs together with their edges.
+    pub fn match_propensity_scores(
+        &mut self,
+        treated: &Group,
+        control: &Group,
+        covariates: &[GraphRecordAttribute],
+        edge_group: Group,
+        caliper: Option<f64>,
+    ) -> GraphRecordResult<Vec<(NodeIndex, NodeIndex, EdgeIndex)>> {
+        if self.contains_group(&edge_group) {
+            return Err(GraphRecordError::DuplicateGroup { group: edge_group });
+        }
+
+        let scores = self.propensity_scores(treated, control, covariates)?;
+
+        let mut treated_nodes: Vec<_> = self.nodes_in_group(treated)?.collect();
+        treated_nodes.sort_by(|a, b| a.total_cmp(b));
+
+        let mut control_nodes: Vec<_> = self.nodes_in_group(control)?.collect();
+        control_nodes.sort_by(|a, b| {
+            scores[*a]
+                .total_cmp(&scores[*b])
+                .then_with(|| a.total_cmp(b))
+        });
+
+        let control_scores: Vec<_> = control_nodes
+            .iter()
+            .map(|control_node| scores[*control_node])
+            .collect();
+        let first_with_score = |score: f64| {
+            control_scores.partition_point(|control_score| control_score.total_cmp(&score).is_lt())
+        };
+
+        // Positions in `control_nodes` of the control nodes that are not
+        // matched yet
+        let mut unmatched: BTreeSet<_> = (0..control_nodes.len()).collect();
+
+        let mut pairs = Vec::new();
+
+        for treated_node in treated_nodes {
+            let treated_score = scores[treated_node];
+            let position = first_with_score(treated_score);
+
+            // Of the unmatched nodes closest from above and from below, the
+            // one with the lowest index among those with the same score
+            let above = unmatched.range(position..).next().copied();
+            let below = unmatched
+                .range(..position)
+                .next_back()
+                .and_then(|below| {
+                    unmatched
+                        .range(first_with_score(control_scores[*below])..)
+                        .next()
+                })
+                .copied();
+
+            let closest = below
+                .into_iter()
+                .chain(above)
+                .map(|position| (position, (control_scores[position] - treated_score).abs()))
+                .min_by(|(a, a_distance), (b, b_distance)| {
+                    a_distance
+                        .total_cmp(b_distance)
+                        .then_with(|| control_nodes[*a].total_cmp(control_nodes[*b]))
+                });
+
+            let Some((position, distance)) = closest else {
+                break;
+            };
+
+            if caliper.is_some_and(|caliper| distance > caliper) {
+                continue;
+            }
+
+            unmatched.remove(&position);
+
+            pairs.push((
+                treated_node.clone(),
+                control_nodes[position].clone(),
+                distance,
+            ));
+        }
+
+        let edges = pairs
+            .iter()
+            .map(|(treated_node, control_node, distance)| {
+                (
+                    treated_node.clone(),
+                    control_node.clone(),
+                    HashMap::from([("distance".into(), (*distance).into())]),
+                )
+            })
+            .collect();
+
+        let edge_indices = self.add_edges_with_group(edges, &edge_group)?;
+
+        Ok(pairs
+            .into_iter()
+            .zip(edge_indices)
+            .map(|((treated_node, control_node, _), edge_index)| {
+                (treated_node, control_node, edge_index)
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::solve;
+    use crate::{
+        GraphRecord,
+        errors::GraphRecordError,
+        graphrecord::{GraphRecordAttribute, GraphRecordValue, NodeIndex},
+    };
+    use std::collections::HashMap;
+
+    fn create_graphrecord() -> GraphRecord {
+        let mut graphrecord = GraphRecord::from_tuples(
+            vec![
+                ("t0", 50, true),
+                ("t1", 60, false),
+                ("c0", 20, false),
+                ("c1", 49, true),
+                ("c2", 61, false),
+                ("c3", 30, true),
+            ]
+            .into_iter()
+            .map(|(node_index, age, smoker)| {
+                (
+                    node_index.into(),
+                    HashMap::from([("age".into(), age.into()), ("smoker".into(), smoker.into())]),
+                )
+            })
+            .collect(),
+            None,
+            None,
+        )
+        .unwrap();
+
+        graphrecord
+            .add_group("treated".into(), Some(vec!["t0".into(), "t1".into()]), None)
+            .unwrap();
+        graphrecord
+            .add_group(
+                "control".into(),
+                Some(vec!["c0".into(), "c1".into(), "c2".into(), "c3".into()]),
+                None,
+            )
+            .unwrap();
+
+        graphrecord
+    }
+
+    #[test]
+    fn test_solve() {
+        let solution = solve(vec![vec![0.0, 2.0], vec![1.0, 1.0]], vec![4.0, 3.0]).unwrap();
+
+        assert!((solution[0] - 1.0).abs() < 1e-12);
+        assert!((solution[1] - 2.0).abs() < 1e-12);
+
+        assert_eq!(
+            None,
+            solve(vec![vec![1.0, 2.0], vec![2.0, 4.0]], vec![1.0, 2.0])
+        );
+    }
+
+    #[test]
+    fn test_propensity_scores() {
+        let graphrecord = create_graphrecord();
+
+        let scores = graphrecord
+            .propensity_scores(&"treated".into(), &"control".into(), &["age".into()])
+            .unwrap();
+
+        assert_eq!(6, scores.len());
+        assert!(scores.values().all(|score| 0.0 < *score && *score < 1.0));
+
+        let score = |node_index: &str| scores[&NodeIndex::from(node_index)];
+
+        assert!(score("c0") < score("c3"));
+        assert!(score("c3") < score("c1"));
+        assert!(score("c1") < score("t0"));
+        assert!(score("t0") < score("t1"));
+        assert!(score("t1") < score("c2"));
+    }
+
+    #[test]
+    fn test_match_propensity_scores() {
+        let mut graphrecord = create_graphrecord();
+
+        let pairs = graphrecord
+            .match_propensity_scores(
+                &"treated".into(),
+                &"control".into(),
+                &["age".into()],
+                "matches".into(),
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                (NodeIndex::from("t0"), NodeIndex::from("c1")),
+                ("t1".into(), "c2".into()),
+            ],
+            pairs
+                .iter()
+                .map(|(treated, control, _)| (treated.clone(), control.clone()))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            2,
+            graphrecord
+                .edges_in_group(&"matches".into())
+                .unwrap()
+                .count()
+        );
+
+        let (_, _, edge_index) = &pairs[0];
+
+        assert!(matches!(
+            graphrecord
+                .edge_attributes(edge_index)
+                .unwrap()
+                .get(&GraphRecordAttribute::from("distance")),
+            Some(GraphRecordValue::Float(distance)) if *distance >= 0.0
+        ));
+
+        // Pairs further apart than the caliper stay unmatched
+        let pairs = graphrecord
+            .match_propensity_scores(
+                &"treated".into(),
+                &"control".into(),
+                &["age".into()],
+                "close_matches".into(),
+                Some(0.0),
+            )
+            .unwrap();
+
+        assert!(pairs.is_empty());
+        assert!(graphrecord.contains_group(&"close_matches".into()));
+    }
+
+    #[test]
+    fn test_match_propensity_scores_ties() {
+        let mut graphrecord = GraphRecord::from_tuples(
+            vec![("t0", 50), ("t1", 50), ("c1", 50), ("c0", 50), ("c2", 20)]
+                .into_iter()
+                .map(|(node_index, age)| {
+                    (
+                        node_index.into(),
+                        HashMap::from([("age".into(), age.into())]),
+                    )
+                })
+                .collect(),
+            None,
+            None,
+        )
+        .unwrap();
+        graphrecord
+            .add_group("treated".into(), Some(vec!["t0".into(), "t1".into()]), None)
+            .unwrap();
+        graphrecord
+            .add_group(
+                "control".into(),
+                Some(vec!["c1".into(), "c0".into(), "c2".into()]),
+                None,
+            )
+            .unwrap();
+
+        let pairs = graphrecord
+            .match_propensity_scores(
+                &"treated".into(),
+                &"control".into(),
+                &["age".into()],
+                "matches".into(),
+                None,
+            )
+            .unwrap();
+
+        // Control nodes with the same score are matched in the order of their
+        // indices
+        assert_eq!(
+            vec![
+                (NodeIndex::from("t0"), NodeIndex::from("c0")),
+                ("t1".into(), "c1".into()),
+            ],
+            pairs
+                .iter()
+                .map(|(treated, control, _)| (treated.clone(), control.clone()))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_invalid_match_propensity_scores() {
+        let mut graphrecord = create_graphrecord();
+
+        assert!(
+            graphrecord
+                .match_propensity_scores(
+                    &"treated".into(),
+                    &"control".into(),
+                    &["age".into()],
+                    "treated".into(),
+                    None,
+                )
+                .is_err_and(|e| matches!(e, GraphRecordError::DuplicateGroup { .. }))
+        );
+        assert!(
+            graphrecord
+                .propensity_scores(&"treated".into(), &"visits".into(), &["age".into()])
+                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+        );
+        assert!(
+            graphrecord
+                .propensity_scores(&"treated".into(), &"control".into(), &["weight".into()])
+                .is_err_and(|e| matches!(e, GraphRecordError::KeyError(_)))
+        );
+
+        graphrecord
+            .node_attributes_mut(&"c0".into())
+            .unwrap()
+            .update_attribute(&"age".into(), "old".into())
+            .unwrap();
+
+        assert!(
+            graphrecord
+                .propensity_scores(&"treated".into(), &"control".into(), &["age".into()])
+                .is_err_and(|e| matches!(e, GraphRecordError::ConversionError(_)))
+        );
+
+        graphrecord
+            .add_node_to_group("control".into(), "t0".into())
+            .unwrap();
+
+        assert!(
+            graphrecord
+                .propensity_scores(&"treated".into(), &"control".into(), &["smoker".into()])
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
+    }
+}
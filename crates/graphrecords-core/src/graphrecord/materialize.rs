@@ -0,0 +1,355 @@
+//! Storing the values computed by a query as attributes, so that derived
+//! values like a BMI do not need to be recomputed by every query using them,
+//! and the nodes or edges a query selects as groups, e.g. to save a cohort.
+
+use super::{
+    EdgeIndex, GraphRecord, GraphRecordAttribute, GraphRecordValue, Group, NodeIndex,
+    querying::{
+        edges::{EdgeIndicesOperand, EdgeOperand},
+        nodes::{NodeIndicesOperand, NodeOperand},
+        values::{EdgeMultipleValuesWithIndexOperand, NodeMultipleValuesWithIndexOperand},
+        wrapper::Wrapper,
+    },
+};
+use crate::errors::GraphRecordResult;
+
+impl GraphRecord {
+    /// Evaluates `query` and stores every resulting value as `attribute` of
+    /// the node it belongs to, replacing a previous value. The new attributes
+    /// are checked against the schema before any node is modified, so either
+    /// all values are stored or none. Returns the number of nodes written.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn materialize_node_attribute<Q>(
+        &mut self,
+        attribute: GraphRecordAttribute,
+        query: Q,
+    ) -> GraphRecordResult<usize>
+    where
+        Q: FnOnce(&Wrapper<NodeOperand>) -> Wrapper<NodeMultipleValuesWithIndexOperand>,
+    {
+        self.assert_not_frozen()?;
+
+        let values: Vec<(NodeIndex, GraphRecordValue)> = self
+            .query_nodes(query)
+            .evaluate()?
+            .map(|(node_index, value)| (node_index.clone(), value))
+            .collect();
+
+        let node_changes = values
+            .into_iter()
+            .map(|(node_index, value)| {
+                let mut attributes = self.node_attributes(&node_index)?.clone();
+                attributes.insert(attribute.clone(), value);
+
+                self.schema.handle_non_finite_floats(&mut attributes)?;
+
+                Ok((node_index, attributes))
+            })
+            .collect::<GraphRecordResult<Vec<_>>>()?;
+
+        let count = node_changes.len();
+
+        self.apply_attribute_changes(
+            self.schema.clone(),
+            node_changes,
+            Vec::new(),
+            std::slice::from_ref(&attribute),
+        )?;
+
+        Ok(count)
+    }
+
+    /// Like [`GraphRecord::materialize_node_attribute`], but for edges.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn materialize_edge_attribute<Q>(
+        &mut self,
+        attribute: GraphRecordAttribute,
+        query: Q,
+    ) -> GraphRecordResult<usize>
+    where
+        Q: FnOnce(&Wrapper<EdgeOperand>) -> Wrapper<EdgeMultipleValuesWithIndexOperand>,
+    {
+        self.assert_not_frozen()?;
+
+        let values: Vec<(EdgeIndex, GraphRecordValue)> = self
+            .query_edges(query)
+            .evaluate()?
+            .map(|(edge_index, value)| (*edge_index, value))
+            .collect();
+
+        let edge_changes = values
+            .into_iter()
+            .map(|(edge_index, value)| {
+                let mut attributes = self.edge_attributes(&edge_index)?.clone();
+                attributes.insert(attribute.clone(), value);
+
+                self.schema.handle_non_finite_floats(&mut attributes)?;
+
+                Ok((edge_index, attributes))
+            })
+            .collect::<GraphRecordResult<Vec<_>>>()?;
+
+        let count = edge_changes.len();
+
+        self.apply_attribute_changes(
+            self.schema.clone(),
+            Vec::new(),
+            edge_changes,
+            std::slice::from_ref(&attribute),
+        )?;
+
+        Ok(count)
+    }
+
+    /// Evaluates `query` and adds a group containing the resulting nodes. The
+    /// nodes are checked against the schema of the group before the group is
+    /// added, so either the group is added with all nodes or not at all.
+    /// Returns the number of nodes in the group.
+    pub fn group_from_node_query<Q>(&mut self, group: Group, query: Q) -> GraphRecordResult<usize>
+    where
+        Q: FnOnce(&Wrapper<NodeOperand>) -> Wrapper<NodeIndicesOperand>,
+    {
+        let node_indices: Vec<NodeIndex> = self.query_nodes(query).evaluate()?.collect();
+
+        let count = node_indices.len();
+
+        self.add_group(group, Some(node_indices), None)?;
+
+        Ok(count)
+    }
+
+    /// Like [`GraphRecord::group_from_node_query`], but for edges.
+    pub fn group_from_edge_query<Q>(&mut self, group: Group, query: Q) -> GraphRecordResult<usize>
+    where
+        Q: FnOnce(&Wrapper<EdgeOperand>) -> Wrapper<EdgeIndicesOperand>,
+    {
+        let edge_indices: Vec<EdgeIndex> = self.query_edges(query).evaluate()?.collect();
+
+        let count = edge_indices.len();
+
+        self.add_group(group, None, Some(edge_indices))?;
+
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        GraphRecord,
+        errors::GraphRecordError,
+        graphrecord::{
+            GraphRecordValue, NodeIndex,
+            datatypes::DataType,
+            querying::wrapper::Wrapper,
+            schema::{AttributeType, GroupSchema, Schema},
+        },
+    };
+    use std::collections::HashMap;
+
+    fn create_graphrecord() -> GraphRecord {
+        GraphRecord::from_tuples(
+            vec![
+                (0.into(), HashMap::from([("weight".into(), 70.into())])),
+                (1.into(), HashMap::from([("weight".into(), 80.into())])),
+                (2.into(), HashMap::new()),
+            ],
+            Some(vec![(
+                0.into(),
+                1.into(),
+                HashMap::from([("duration".into(), 2.into())]),
+            )]),
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_materialize_node_attribute() {
+        let mut graphrecord = create_graphrecord();
+
+        let count = graphrecord
+            .materialize_node_attribute("weight_in_grams".into(), |nodes| {
+                let weight = nodes.attribute("weight");
+                weight.mul(1000);
+
+                weight
+            })
+            .unwrap();
+
+        assert_eq!(2, count);
+        assert_eq!(
+            Some(&GraphRecordValue::Int(70000)),
+            graphrecord
+                .node_attributes(&0.into())
+                .unwrap()
+                .get(&"weight_in_grams".into())
+        );
+        assert!(
+            !graphrecord
+                .node_attributes(&2.into())
+                .unwrap()
+                .contains_key(&"weight_in_grams".into())
+        );
+        assert!(
+            graphrecord
+                .get_schema()
+                .ungrouped()
+                .nodes()
+                .contains_key(&"weight_in_grams".into())
+        );
+    }
+
+    #[test]
+    fn test_materialize_edge_attribute() {
+        let mut graphrecord = create_graphrecord();
+
+        let count = graphrecord
+            .materialize_edge_attribute("duration_in_hours".into(), |edges| {
+                let duration = edges.attribute("duration");
+                duration.mul(24);
+
+                duration
+            })
+            .unwrap();
+
+        assert_eq!(1, count);
+        assert_eq!(
+            Some(&GraphRecordValue::Int(48)),
+            graphrecord
+                .edge_attributes(&0)
+                .unwrap()
+                .get(&"duration_in_hours".into())
+        );
+    }
+
+    #[test]
+    fn test_group_from_node_query() {
+        let mut graphrecord = create_graphrecord();
+
+        let count = graphrecord
+            .group_from_node_query("heavy".into(), |nodes| {
+                nodes.attribute("weight").greater_than(75);
+
+                nodes.index()
+            })
+            .unwrap();
+
+        assert_eq!(1, count);
+        assert_eq!(
+            vec![&NodeIndex::from(1)],
+            graphrecord
+                .nodes_in_group(&"heavy".into())
+                .unwrap()
+                .collect::<Vec<_>>()
+        );
+        assert!(
+            graphrecord
+                .get_schema()
+                .group(&"heavy".into())
+                .unwrap()
+                .nodes()
+                .contains_key(&"weight".into())
+        );
+
+        assert!(
+            graphrecord
+                .group_from_node_query("heavy".into(), Wrapper::index)
+                .is_err_and(|e| matches!(e, GraphRecordError::DuplicateGroup { .. }))
+        );
+    }
+
+    #[test]
+    fn test_group_from_edge_query() {
+        let mut graphrecord = create_graphrecord();
+
+        let count = graphrecord
+            .group_from_edge_query("long".into(), |edges| {
+                edges.attribute("duration").greater_than(1);
+
+                edges.index()
+            })
+            .unwrap();
+
+        assert_eq!(1, count);
+        assert_eq!(
+            vec![&0],
+            graphrecord
+                .edges_in_group(&"long".into())
+                .unwrap()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_invalid_group_from_node_query() {
+        let mut graphrecord = create_graphrecord();
+
+        let mut schema = Schema::new_provided(HashMap::new(), GroupSchema::default());
+        schema
+            .set_node_attribute(
+                &"weight".into(),
+                DataType::Option(Box::new(DataType::Int)),
+                AttributeType::Continuous,
+                None,
+            )
+            .unwrap();
+        schema
+            .set_edge_attribute(
+                &"duration".into(),
+                DataType::Int,
+                AttributeType::Continuous,
+                None,
+            )
+            .unwrap();
+        graphrecord.set_schema(schema).unwrap();
+
+        assert!(
+            graphrecord
+                .group_from_node_query("heavy".into(), Wrapper::index)
+                .is_err_and(|e| matches!(e, GraphRecordError::SchemaError(_)))
+        );
+        assert!(!graphrecord.contains_group(&"heavy".into()));
+    }
+
+    #[test]
+    fn test_invalid_materialize_node_attribute() {
+        let mut graphrecord = create_graphrecord();
+
+        let mut schema = Schema::new_provided(HashMap::new(), GroupSchema::default());
+        schema
+            .set_node_attribute(
+                &"weight".into(),
+                DataType::Option(Box::new(DataType::Int)),
+                AttributeType::Continuous,
+                None,
+            )
+            .unwrap();
+        schema
+            .set_edge_attribute(
+                &"duration".into(),
+                DataType::Int,
+                AttributeType::Continuous,
+                None,
+            )
+            .unwrap();
+        graphrecord.set_schema(schema).unwrap();
+
+        assert!(
+            graphrecord
+                .materialize_node_attribute("weight_in_grams".into(), |nodes| {
+                    let weight = nodes.attribute("weight");
+                    weight.mul(1000);
+
+                    weight
+                })
+                .is_err_and(|e| matches!(e, GraphRecordError::SchemaError(_)))
+        );
+        assert!(
+            !graphrecord
+                .node_attributes(&0.into())
+                .unwrap()
+                .contains_key(&"weight_in_grams".into())
+        );
+    }
+}
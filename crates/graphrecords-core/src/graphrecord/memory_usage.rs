@@ -0,0 +1,142 @@
+use super::{AttributeMap, GraphRecord, GraphRecordAttribute, GraphRecordValue};
+use graphrecords_utils::aliases::{GrHashMap, GrHashSet};
+use std::{collections::HashMap, hash::BuildHasher, mem::size_of};
+
+pub(crate) trait HeapSize {
+    fn heap_size(&self) -> usize;
+}
+
+impl HeapSize for GraphRecordAttribute {
+    fn heap_size(&self) -> usize {
+        match self {
+            Self::Int(_) => 0,
+            Self::String(value) => value.capacity(),
+        }
+    }
+}
+
+impl HeapSize for GraphRecordValue {
+    fn heap_size(&self) -> usize {
+        match self {
+            Self::String(value) => value.capacity(),
+            _ => 0,
+        }
+    }
+}
+
+impl HeapSize for u32 {
+    fn heap_size(&self) -> usize {
+        0
+    }
+}
+
+// Hash tables store one control byte per bucket next to the entries.
+const fn table_size<T>(capacity: usize) -> usize {
+    capacity * (size_of::<T>() + 1)
+}
+
+impl<K: HeapSize, V: HeapSize, S: BuildHasher> HeapSize for HashMap<K, V, S> {
+    fn heap_size(&self) -> usize {
+        table_size::<(K, V)>(self.capacity())
+            + self
+                .iter()
+                .map(|(key, value)| key.heap_size() + value.heap_size())
+                .sum::<usize>()
+    }
+}
+
+impl HeapSize for AttributeMap {
+    fn heap_size(&self) -> usize {
+        self.entries_size()
+            + self
+                .iter()
+                .map(|(key, value)| key.heap_size() + value.heap_size())
+                .sum::<usize>()
+    }
+}
+
+fn entries_size<K, V>(map: &GrHashMap<K, V>) -> usize {
+    table_size::<(K, V)>(map.capacity())
+}
+
+impl<K: HeapSize, V: HeapSize> HeapSize for GrHashMap<K, V> {
+    fn heap_size(&self) -> usize {
+        entries_size(self)
+            + self
+                .iter()
+                .map(|(key, value)| key.heap_size() + value.heap_size())
+                .sum::<usize>()
+    }
+}
+
+impl<T: HeapSize> HeapSize for GrHashSet<T> {
+    fn heap_size(&self) -> usize {
+        table_size::<T>(self.capacity()) + self.iter().map(HeapSize::heap_size).sum::<usize>()
+    }
+}
+
+/// Estimated heap memory held by a `GraphRecord`, in bytes.
+///
+/// The estimate accounts for hash table capacity and string buffers, but not
+/// for allocator overhead or padding, so it is a lower bound.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoryUsage {
+    pub nodes: usize,
+    pub node_attributes: usize,
+    pub edges: usize,
+    pub edge_attributes: usize,
+    pub groups: usize,
+}
+
+impl MemoryUsage {
+    pub(crate) fn new(graphrecord: &GraphRecord) -> Self {
+        let graph = &graphrecord.graph;
+        let group_mapping = &graphrecord.group_mapping;
+
+        let nodes = entries_size(&graph.nodes)
+            + graph
+                .nodes
+                .iter()
+                .map(|(node_index, node)| {
+                    node_index.heap_size()
+                        + node.outgoing_edge_indices.heap_size()
+                        + node.incoming_edge_indices.heap_size()
+                })
+                .sum::<usize>();
+        let node_attributes = graph
+            .nodes
+            .values()
+            .map(|node| node.attributes.heap_size())
+            .sum();
+
+        let edges = entries_size(&graph.edges)
+            + graph
+                .edges
+                .values()
+                .map(|edge| edge.source_node_index.heap_size() + edge.target_node_index.heap_size())
+                .sum::<usize>();
+        let edge_attributes = graph
+            .edges
+            .values()
+            .map(|edge| edge.attributes.heap_size())
+            .sum();
+
+        let groups = group_mapping.nodes_in_group.heap_size()
+            + group_mapping.edges_in_group.heap_size()
+            + group_mapping.groups_of_node.heap_size()
+            + group_mapping.groups_of_edge.heap_size();
+
+        Self {
+            nodes,
+            node_attributes,
+            edges,
+            edge_attributes,
+            groups,
+        }
+    }
+
+    #[must_use]
+    pub const fn total(&self) -> usize {
+        self.nodes + self.node_attributes + self.edges + self.edge_attributes + self.groups
+    }
+}
@@ -0,0 +1,97 @@
+use super::Attributes;
+use chrono::NaiveDateTime;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Descriptive header of a record, persisted along with it so that shared
+/// records are self-describing.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RecordMetadata {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub created_at: Option<NaiveDateTime>,
+    pub source_datasets: Vec<String>,
+    pub properties: Attributes,
+}
+
+impl RecordMetadata {
+    #[must_use]
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: Some(title.into()),
+            ..Default::default()
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::RecordMetadata;
+    use crate::{GraphRecord, errors::GraphRecordError};
+    use chrono::NaiveDate;
+    use std::collections::HashMap;
+
+    fn create_metadata() -> RecordMetadata {
+        RecordMetadata {
+            description: Some("Monthly refresh".to_string()),
+            created_at: NaiveDate::from_ymd_opt(2024, 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0),
+            source_datasets: vec!["claims".to_string(), "lab".to_string()],
+            properties: HashMap::from([("version".into(), 3.into())]),
+            ..RecordMetadata::new("Cohort")
+        }
+    }
+
+    #[test]
+    fn test_metadata() {
+        let mut graphrecord = GraphRecord::new();
+
+        assert!(graphrecord.metadata().is_empty());
+
+        let previous = graphrecord.set_metadata(create_metadata()).unwrap();
+
+        assert!(previous.is_empty());
+        assert_eq!(&create_metadata(), graphrecord.metadata());
+
+        graphrecord.clear().unwrap();
+
+        // Metadata describes the record and is kept when clearing it
+        assert_eq!(&create_metadata(), graphrecord.metadata());
+
+        graphrecord.freeze();
+
+        assert!(
+            graphrecord
+                .set_metadata(RecordMetadata::default())
+                .is_err_and(|e| matches!(e, GraphRecordError::ReadOnlyError(_)))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_metadata_serde() {
+        let mut graphrecord = GraphRecord::new();
+
+        graphrecord.set_metadata(create_metadata()).unwrap();
+
+        let restored = GraphRecord::from_bytes(&graphrecord.to_bytes().unwrap()).unwrap();
+
+        assert_eq!(&create_metadata(), restored.metadata());
+
+        let restored: GraphRecord = ron::from_str(&ron::to_string(&graphrecord).unwrap()).unwrap();
+
+        assert_eq!(&create_metadata(), restored.metadata());
+
+        let restored: GraphRecord =
+            bincode::deserialize(&bincode::serialize(&graphrecord).unwrap()).unwrap();
+
+        assert_eq!(&create_metadata(), restored.metadata());
+    }
+}
@@ -1,59 +1,136 @@
+pub mod anonymization;
+pub mod association;
+pub mod attribute_scope;
 pub mod attributes;
+pub mod auto_index;
+pub mod cohort_table;
+mod comparison;
+mod concat;
 #[cfg(feature = "connectors")]
 pub mod connector;
+#[cfg(feature = "serde")]
+pub mod container;
+pub mod crosstab;
 pub mod datatypes;
+pub mod dry_run;
+mod fingerprint;
+pub mod frozen;
+mod geo_index;
 mod graph;
 mod group_mapping;
+pub mod ingest;
+#[cfg(feature = "lazy")]
+mod lazy;
+#[cfg(feature = "serde")]
+mod legacy;
+pub mod maintenance;
+mod matching;
+mod materialize;
+pub mod memory_usage;
+pub mod metadata;
+pub mod overlay;
 pub mod overview;
+#[cfg(feature = "petgraph")]
+pub mod petgraph;
 #[cfg(feature = "plugins")]
 pub mod plugins;
 mod polars;
+pub mod provenance;
 pub mod querying;
+pub mod relation;
 pub mod schema;
+mod split;
+pub mod stats;
+pub mod subscription;
+pub mod survival;
+mod text_index;
+pub mod udf;
+mod valid_time;
+pub mod versioned;
+pub mod view;
 
 pub use self::{
     datatypes::{GraphRecordAttribute, GraphRecordValue},
-    graph::{Attributes, EdgeIndex, NodeIndex},
+    graph::{AttributeMap, Attributes, EdgeIndex, NodeIndex, ReadAttributes},
     group_mapping::Group,
+    valid_time::AttributeVersion,
 };
 use crate::errors::GraphRecordResult;
 #[cfg(feature = "plugins")]
-use crate::graphrecord::plugins::{Plugin, PluginName};
+use crate::graphrecord::plugins::PluginRegistry;
 use crate::{
     errors::GraphRecordError,
     graphrecord::{
         attributes::{EdgeAttributesMut, NodeAttributesMut},
-        overview::{DEFAULT_TRUNCATE_DETAILS, GroupOverview, Overview},
+        overview::{
+            DEFAULT_TRUNCATE_DETAILS, GroupOverview, Overview, OverviewFormat, OverviewSample,
+        },
         polars::DataFramesExport,
     },
 };
 use ::polars::frame::DataFrame;
+use association::{ChiSquareTest, CorrelationMethod, CorrelationTest};
+use auto_index::{AutoIndexFormat, NodeIndexSequence};
+use chrono::{NaiveDateTime, TimeDelta};
+use cohort_table::CohortTable;
+use crosstab::{Crosstab, CrosstabNormalization};
+use geo_index::GeoIndexStore;
 use graph::Graph;
-#[cfg(feature = "plugins")]
-use graphrecords_utils::aliases::GrHashMap;
 use graphrecords_utils::aliases::GrHashSet;
 use group_mapping::GroupMapping;
+use itertools::Either;
+#[cfg(feature = "lazy")]
+pub use lazy::{EdgeLazyFrameInput, NodeLazyFrameInput};
+use memory_usage::MemoryUsage;
+use metadata::RecordMetadata;
+use overlay::OverlayLayer;
+pub use polars::{DataFramesExportOptions, NullHandling, NullPolicy};
 use polars::{dataframe_to_edges, dataframe_to_nodes};
+use provenance::{ProvenanceStore, ProvenanceTag};
 use querying::{
-    ReturnOperand, Selection, edges::EdgeOperand, nodes::NodeOperand, wrapper::Wrapper,
+    ReturnOperand, Selection,
+    edges::EdgeOperand,
+    nodes::{EdgeDirection, NodeOperand},
+    wrapper::Wrapper,
 };
-use schema::{GroupSchema, Schema, SchemaType};
+use schema::{ConversionMode, GroupSchema, Schema, SchemaType};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use stats::Stats;
 #[cfg(feature = "plugins")]
 use std::sync::Arc;
 use std::{
     collections::{HashMap, hash_map::Entry},
     fmt::{Display, Formatter},
     mem,
+    sync::mpsc::Receiver,
 };
 #[cfg(feature = "serde")]
 use std::{fs, path::Path};
+use subscription::{ChangeEvent, ChangeToken, Subscribers};
+use survival::{EventRate, TimeToEvent};
+use text_index::TextIndexStore;
+use udf::UdfRegistry;
+use valid_time::ValidTimeStore;
+use view::{AsOfView, GroupPairView, TimeWindowView};
 
 #[derive(Debug, Clone)]
 pub struct NodeDataFrameInput {
     pub dataframe: DataFrame,
     pub index_column: String,
+    pub nulls: NullHandling,
+}
+
+/// How edges whose source or target node is not in the record are
+/// handled when they are added from data frames.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum MissingNodePolicy {
+    /// Fails with [`GraphRecordError::MissingNode`].
+    #[default]
+    Fail,
+    /// Adds the missing nodes with empty attributes before the edges,
+    /// optionally to `group`.
+    Create { group: Option<Group> },
 }
 
 #[derive(Debug, Clone)]
@@ -61,6 +138,8 @@ pub struct EdgeDataFrameInput {
     pub dataframe: DataFrame,
     pub source_index_column: String,
     pub target_index_column: String,
+    pub nulls: NullHandling,
+    pub missing_nodes: MissingNodePolicy,
 }
 
 impl<D, S> From<(D, S)> for NodeDataFrameInput
@@ -72,6 +151,7 @@ where
         Self {
             dataframe: val.0.into(),
             index_column: val.1.into(),
+            nulls: NullHandling::default(),
         }
     }
 }
@@ -86,47 +166,26 @@ where
             dataframe: val.0.into(),
             source_index_column: val.1.into(),
             target_index_column: val.2.into(),
+            nulls: NullHandling::default(),
+            missing_nodes: MissingNodePolicy::default(),
         }
     }
 }
 
 fn node_dataframes_to_tuples(
     nodes_dataframes: impl IntoIterator<Item = impl Into<NodeDataFrameInput>>,
+    mode: ConversionMode,
 ) -> GraphRecordResult<Vec<(NodeIndex, Attributes)>> {
     let nodes = nodes_dataframes
         .into_iter()
         .map(|dataframe_input| {
             let dataframe_input = dataframe_input.into();
 
-            dataframe_to_nodes(dataframe_input.dataframe, &dataframe_input.index_column)
-        })
-        .collect::<GraphRecordResult<Vec<_>>>()?
-        .into_iter()
-        .flatten()
-        .collect();
-
-    Ok(nodes)
-}
-
-#[allow(clippy::type_complexity)]
-fn dataframes_to_tuples(
-    nodes_dataframes: impl IntoIterator<Item = impl Into<NodeDataFrameInput>>,
-    edges_dataframes: impl IntoIterator<Item = impl Into<EdgeDataFrameInput>>,
-) -> GraphRecordResult<(
-    Vec<(NodeIndex, Attributes)>,
-    Vec<(NodeIndex, NodeIndex, Attributes)>,
-)> {
-    let nodes = node_dataframes_to_tuples(nodes_dataframes)?;
-
-    let edges = edges_dataframes
-        .into_iter()
-        .map(|dataframe_input| {
-            let dataframe_input = dataframe_input.into();
-
-            dataframe_to_edges(
+            dataframe_to_nodes(
                 dataframe_input.dataframe,
-                &dataframe_input.source_index_column,
-                &dataframe_input.target_index_column,
+                &dataframe_input.index_column,
+                mode,
+                &dataframe_input.nulls,
             )
         })
         .collect::<GraphRecordResult<Vec<_>>>()?
@@ -134,7 +193,7 @@ fn dataframes_to_tuples(
         .flatten()
         .collect();
 
-    Ok((nodes, edges))
+    Ok(nodes)
 }
 
 #[derive(Default, Debug, Clone)]
@@ -144,16 +203,50 @@ pub struct GraphRecord {
     graph: Graph,
     group_mapping: GroupMapping,
     schema: Schema,
+    #[cfg_attr(feature = "serde", serde(default))]
+    frozen: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    strict_queries: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    float_tolerance: f64,
+    #[cfg_attr(feature = "serde", serde(default))]
+    random_seed: Option<u64>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    subscribers: Subscribers,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    operation_context: Attributes,
+    #[cfg_attr(feature = "serde", serde(default))]
+    provenance: Option<ProvenanceStore>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    valid_time: ValidTimeStore,
+    #[cfg_attr(feature = "serde", serde(default))]
+    metadata: RecordMetadata,
+    #[cfg_attr(feature = "serde", serde(default))]
+    node_index_sequence: NodeIndexSequence,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    udfs: UdfRegistry,
+    #[cfg_attr(feature = "serde", serde(default))]
+    text_indices: TextIndexStore,
+    #[cfg_attr(feature = "serde", serde(default))]
+    geo_indices: GeoIndexStore,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    layer: Option<Box<OverlayLayer>>,
 
     #[cfg(feature = "plugins")]
-    plugins: Arc<GrHashMap<PluginName, Box<dyn Plugin>>>,
+    plugins: Arc<PluginRegistry>,
 }
 
 impl Display for GraphRecord {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let overview = Overview::new(self, Some(DEFAULT_TRUNCATE_DETAILS))
-            .map_err(|_| std::fmt::Error)?
-            .to_string();
+        let overview = Overview::new(
+            self,
+            Some(DEFAULT_TRUNCATE_DETAILS),
+            OverviewFormat::default(),
+            None,
+            None,
+        )
+        .map_err(|_| std::fmt::Error)?
+        .to_string();
 
         write!(f, "{overview}")
     }
@@ -211,16 +304,28 @@ impl GraphRecord {
         edges_dataframes: impl IntoIterator<Item = impl Into<EdgeDataFrameInput>>,
         schema: Option<Schema>,
     ) -> GraphRecordResult<Self> {
-        let (nodes, edges) = dataframes_to_tuples(nodes_dataframes, edges_dataframes)?;
+        let mode = schema
+            .as_ref()
+            .map_or_else(ConversionMode::default, Schema::conversion_mode);
+
+        let nodes = node_dataframes_to_tuples(nodes_dataframes, mode)?;
+
+        let mut graphrecord = Self::from_tuples(nodes, None, schema)?;
 
-        Self::from_tuples(nodes, Some(edges), schema)
+        graphrecord.add_edges_dataframes_impl(edges_dataframes)?;
+
+        Ok(graphrecord)
     }
 
     pub fn from_nodes_dataframes(
         nodes_dataframes: impl IntoIterator<Item = impl Into<NodeDataFrameInput>>,
         schema: Option<Schema>,
     ) -> GraphRecordResult<Self> {
-        let nodes = node_dataframes_to_tuples(nodes_dataframes)?;
+        let mode = schema
+            .as_ref()
+            .map_or_else(ConversionMode::default, Schema::conversion_mode);
+
+        let nodes = node_dataframes_to_tuples(nodes_dataframes, mode)?;
 
         Self::from_tuples(nodes, None, schema)
     }
@@ -268,8 +373,26 @@ impl GraphRecord {
         DataFramesExport::new(self)
     }
 
+    /// Like [`GraphRecord::to_dataframes`], but with rows sorted by their
+    /// index and columns in a fixed order, so repeated exports of the same
+    /// record are identical.
+    pub fn to_dataframes_sorted(&self) -> GraphRecordResult<DataFramesExport> {
+        DataFramesExport::new_sorted(self)
+    }
+
+    /// Like [`GraphRecord::to_dataframes`], but only exports the groups and
+    /// attribute columns selected by `options`.
+    pub fn to_dataframes_with_options(
+        &self,
+        options: &DataFramesExportOptions,
+    ) -> GraphRecordResult<DataFramesExport> {
+        DataFramesExport::with_options(self, options)
+    }
+
     #[allow(clippy::too_many_lines)]
     fn set_schema_impl(&mut self, mut schema: Schema) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
         let mut nodes_group_cache = HashMap::<&Group, usize>::new();
         let mut nodes_ungrouped_visited = false;
         let mut edges_group_cache = HashMap::<&Group, usize>::new();
@@ -284,7 +407,7 @@ impl GraphRecord {
             if groups_of_node.is_empty() {
                 match schema.schema_type() {
                     SchemaType::Inferred => {
-                        let nodes_in_groups = self.group_mapping.nodes_in_group.len();
+                        let nodes_in_groups = self.group_mapping.groups_of_node.len();
 
                         let nodes_not_in_groups = self.graph.node_count() - nodes_in_groups;
 
@@ -339,7 +462,7 @@ impl GraphRecord {
             if groups_of_edge.is_empty() {
                 match schema.schema_type() {
                     SchemaType::Inferred => {
-                        let edges_in_groups = self.group_mapping.edges_in_group.len();
+                        let edges_in_groups = self.group_mapping.groups_of_edge.len();
 
                         let edges_not_in_groups = self.graph.edge_count() - edges_in_groups;
 
@@ -387,6 +510,8 @@ impl GraphRecord {
 
         mem::swap(&mut self.schema, &mut schema);
 
+        self.subscribers.notify(|| ChangeEvent::SchemaChanged);
+
         Ok(())
     }
 
@@ -404,665 +529,698 @@ impl GraphRecord {
         &self.schema
     }
 
-    const fn freeze_schema_impl(&mut self) {
+    fn freeze_schema_impl(&mut self) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
         self.schema.freeze();
+
+        self.subscribers.notify(|| ChangeEvent::SchemaChanged);
+
+        Ok(())
     }
 
-    const fn unfreeze_schema_impl(&mut self) {
+    fn unfreeze_schema_impl(&mut self) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
         self.schema.unfreeze();
+
+        self.subscribers.notify(|| ChangeEvent::SchemaChanged);
+
+        Ok(())
     }
 
-    pub fn node_indices(&self) -> impl Iterator<Item = &NodeIndex> {
-        self.graph.node_indices()
+    /// Marks the `GraphRecord` as read-only.
+    ///
+    /// While frozen, every method changing the data of the record fails with a
+    /// [`GraphRecordError::ReadOnlyError`] before any plugin hook runs.
+    /// Methods that release or reserve memory without changing the record,
+    /// like [`GraphRecord::shrink_to_fit`], and settings that only affect
+    /// reading, like the query settings and the operation context, are still
+    /// allowed. The flag is preserved when cloning and serializing the record.
+    ///
+    /// [`GraphRecord::into_frozen`] makes the record read-only at compile time
+    /// instead.
+    pub const fn freeze(&mut self) {
+        self.frozen = true;
     }
 
-    pub fn node_attributes(&self, node_index: &NodeIndex) -> GraphRecordResult<&Attributes> {
-        self.graph
-            .node_attributes(node_index)
-            .map_err(GraphRecordError::from)
+    pub const fn unfreeze(&mut self) {
+        self.frozen = false;
     }
 
-    pub fn node_attributes_mut<'a>(
-        &'a mut self,
-        node_index: &'a NodeIndex,
-    ) -> GraphRecordResult<NodeAttributesMut<'a>> {
-        NodeAttributesMut::new(node_index, self)
+    #[must_use]
+    pub const fn is_frozen(&self) -> bool {
+        self.frozen
     }
 
-    pub fn outgoing_edges(
-        &self,
-        node_index: &NodeIndex,
-    ) -> GraphRecordResult<impl Iterator<Item = &EdgeIndex> + use<'_>> {
-        self.graph
-            .outgoing_edges(node_index)
-            .map_err(GraphRecordError::from)
+    fn assert_not_frozen(&self) -> GraphRecordResult<()> {
+        if self.frozen {
+            return Err(GraphRecordError::ReadOnlyError(
+                "GraphRecord is frozen and cannot be modified".to_string(),
+            ));
+        }
+
+        Ok(())
     }
 
-    pub fn incoming_edges(
-        &self,
-        node_index: &NodeIndex,
-    ) -> GraphRecordResult<impl Iterator<Item = &EdgeIndex> + use<'_>> {
-        self.graph
-            .incoming_edges(node_index)
-            .map_err(GraphRecordError::from)
+    /// Controls whether queries validate accessed attributes against the schema.
+    ///
+    /// When enabled, `attribute` lookups on node and edge operands fail with a
+    /// [`GraphRecordError::SchemaError`] listing close matches if the
+    /// attribute is not part of the schema of the groups the operand is
+    /// restricted to, instead of silently matching nothing.
+    pub const fn set_strict_queries(&mut self, strict_queries: bool) {
+        self.strict_queries = strict_queries;
     }
 
-    pub fn edge_indices(&self) -> impl Iterator<Item = &EdgeIndex> {
-        self.graph.edge_indices()
+    #[must_use]
+    pub const fn strict_queries(&self) -> bool {
+        self.strict_queries
     }
 
-    pub fn edge_attributes(&self, edge_index: &EdgeIndex) -> GraphRecordResult<&Attributes> {
-        self.graph
-            .edge_attributes(edge_index)
-            .map_err(GraphRecordError::from)
+    /// Sets the absolute tolerance for `equal_to` and `not_equal_to` in queries.
+    ///
+    /// Two numbers of which at least one is a float are considered equal if
+    /// they differ by at most `tolerance`, so values that went through a unit
+    /// conversion still match. Defaults to 0, which compares exactly.
+    pub fn set_float_tolerance(&mut self, tolerance: f64) -> GraphRecordResult<()> {
+        if tolerance.is_nan() || tolerance < 0.0 {
+            return Err(GraphRecordError::AssertionError(
+                "Float tolerance must be non-negative".to_string(),
+            ));
+        }
+
+        self.float_tolerance = tolerance;
+
+        Ok(())
     }
 
-    pub fn edge_attributes_mut<'a>(
-        &'a mut self,
-        edge_index: &'a EdgeIndex,
-    ) -> GraphRecordResult<EdgeAttributesMut<'a>> {
-        EdgeAttributesMut::new(edge_index, self)
+    #[must_use]
+    pub const fn float_tolerance(&self) -> f64 {
+        self.float_tolerance
     }
 
-    pub fn edge_endpoints(
-        &self,
-        edge_index: &EdgeIndex,
-    ) -> GraphRecordResult<(&NodeIndex, &NodeIndex)> {
-        self.graph
-            .edge_endpoints(edge_index)
-            .map_err(GraphRecordError::from)
+    /// Seeds the `random` operations of queries, so that they pick the same
+    /// nodes, edges, values and attributes on every evaluation. The candidates
+    /// are sorted before picking, so the result does not depend on the
+    /// iteration order of the record. `None`, the default, picks unseeded.
+    pub const fn set_random_seed(&mut self, seed: Option<u64>) {
+        self.random_seed = seed;
     }
 
-    pub fn edges_connecting<'a>(
-        &'a self,
-        outgoing_node_indices: Vec<&'a NodeIndex>,
-        incoming_node_indices: Vec<&'a NodeIndex>,
-    ) -> impl Iterator<Item = &'a EdgeIndex> + 'a {
-        self.graph
-            .edges_connecting(outgoing_node_indices, incoming_node_indices)
+    #[must_use]
+    pub const fn random_seed(&self) -> Option<u64> {
+        self.random_seed
     }
 
-    pub fn edges_connecting_undirected<'a>(
-        &'a self,
-        first_node_indices: Vec<&'a NodeIndex>,
-        second_node_indices: Vec<&'a NodeIndex>,
-    ) -> impl Iterator<Item = &'a EdgeIndex> + 'a {
-        self.graph
-            .edges_connecting_undirected(first_node_indices, second_node_indices)
+    /// Returns a receiver for the [`ChangeEvent`]s of all subsequent mutations.
+    ///
+    /// Events are sent after a mutation succeeded, so failed operations are not
+    /// reported. Dropping the receiver ends the subscription. Unlike plugins,
+    /// subscribers cannot intercept or alter mutations.
+    pub fn subscribe(&self) -> Receiver<ChangeEvent> {
+        self.subscribers.subscribe()
     }
 
-    fn add_node_impl(
+    /// Returns a token identifying the current modification state.
+    ///
+    /// Pass it to [`GraphRecord::is_modified_since`] later on to cheaply check
+    /// whether caches or saved files derived from the record are stale.
+    #[must_use]
+    pub fn change_token(&self) -> ChangeToken {
+        self.subscribers.change_token()
+    }
+
+    /// Returns `true` if the record was mutated after `token` was taken.
+    #[must_use]
+    pub fn is_modified_since(&self, token: ChangeToken) -> bool {
+        self.change_token() != token
+    }
+
+    /// Runs `operation` with `metadata` added to the operation context.
+    ///
+    /// The operation context describes who performs the current mutations and
+    /// why. Plugin hooks read it from the record they are called with. Nested
+    /// calls extend the outer context, overriding keys they share, and the
+    /// outer context is restored once `operation` returns.
+    pub fn with_operation_context<T>(
         &mut self,
-        node_index: NodeIndex,
-        attributes: Attributes,
-    ) -> GraphRecordResult<()> {
-        match self.schema.schema_type() {
-            SchemaType::Inferred => {
-                let nodes_in_groups = self.group_mapping.nodes_in_group.len();
+        metadata: Attributes,
+        operation: impl FnOnce(&mut Self) -> GraphRecordResult<T>,
+    ) -> GraphRecordResult<T> {
+        let mut context = self.operation_context.clone();
+        context.extend(metadata);
 
-                let nodes_not_in_groups = self.graph.node_count() - nodes_in_groups;
+        let previous = self.set_operation_context(context);
 
-                self.schema
-                    .update_node(&attributes, None, nodes_not_in_groups == 0);
-            }
-            SchemaType::Provided => {
-                self.schema.validate_node(&node_index, &attributes, None)?;
-            }
-        }
+        let result = operation(self);
 
-        self.graph
-            .add_node(node_index, attributes)
-            .map_err(GraphRecordError::from)
+        self.set_operation_context(previous);
+
+        result
     }
 
-    // TODO: Add tests
-    #[allow(clippy::needless_pass_by_value)]
-    fn add_node_with_group_impl(
-        &mut self,
-        node_index: NodeIndex,
-        attributes: Attributes,
-        group: Group,
-    ) -> GraphRecordResult<()> {
-        match self.schema.schema_type() {
-            SchemaType::Inferred => {
-                let nodes_in_group = self
-                    .group_mapping
-                    .nodes_in_group
-                    .get(&group)
-                    .map_or(0, GrHashSet::len);
+    #[must_use]
+    pub const fn operation_context(&self) -> &Attributes {
+        &self.operation_context
+    }
 
-                self.schema
-                    .update_node(&attributes, Some(&group), nodes_in_group == 0);
-            }
-            SchemaType::Provided => {
-                self.schema
-                    .validate_node(&node_index, &attributes, Some(&group))?;
-            }
-        }
+    /// Replaces the operation context and returns the previous one.
+    ///
+    /// Prefer [`GraphRecord::with_operation_context`], which restores the
+    /// previous context automatically.
+    pub const fn set_operation_context(&mut self, context: Attributes) -> Attributes {
+        mem::replace(&mut self.operation_context, context)
+    }
 
-        self.graph
-            .add_node(node_index.clone(), attributes)
-            .map_err(GraphRecordError::from)?;
+    #[must_use]
+    pub const fn auto_index_format(&self) -> &AutoIndexFormat {
+        self.node_index_sequence.format()
+    }
 
-        self.group_mapping
-            .add_node_to_group(group, node_index.clone())
-            .inspect_err(|_| {
-                self.graph
-                    .remove_node(&node_index, &mut self.group_mapping)
-                    .expect("Node must exist");
-            })
+    /// Sets the format of the node indices generated by
+    /// [`GraphRecord::add_node_auto`]. The sequence continues from where it
+    /// stopped, so previously generated indices are not reused.
+    pub fn set_auto_index_format(&mut self, format: AutoIndexFormat) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
+        self.node_index_sequence.set_format(format);
+
+        Ok(())
     }
 
-    fn add_node_with_groups_impl(
+    /// Adds a node under the next unused index of the record's node index
+    /// sequence and returns that index.
+    pub fn add_node_auto(&mut self, attributes: Attributes) -> GraphRecordResult<NodeIndex> {
+        let (node_index, next) = self.node_index_sequence.peek(&self.graph);
+
+        self.add_node(node_index.clone(), attributes)?;
+
+        self.node_index_sequence.advance(next);
+
+        Ok(node_index)
+    }
+
+    /// Like [`GraphRecord::add_node_auto`], but for multiple nodes at once.
+    /// Indices are returned in the order of `attributes`.
+    pub fn add_nodes_auto(
         &mut self,
-        node_index: NodeIndex,
-        attributes: Attributes,
-        groups: impl AsRef<[Group]>,
-    ) -> GraphRecordResult<()> {
-        let groups = groups.as_ref();
+        attributes: Vec<Attributes>,
+    ) -> GraphRecordResult<Vec<NodeIndex>> {
+        let (node_indices, next) = self
+            .node_index_sequence
+            .peek_many(&self.graph, attributes.len());
 
-        match groups.split_first() {
-            None => self.add_node_impl(node_index, attributes),
-            Some((first, rest)) => {
-                self.add_node_with_group_impl(node_index.clone(), attributes, first.clone())?;
+        self.add_nodes(node_indices.iter().cloned().zip(attributes).collect())?;
 
-                for group in rest {
-                    self.add_node_to_group_impl(group.clone(), node_index.clone())
-                        .inspect_err(|_| {
-                            self.graph
-                                .remove_node(&node_index, &mut self.group_mapping)
-                                .expect("Node must exist");
-                        })?;
-                }
+        self.node_index_sequence.advance(next);
 
-                Ok(())
-            }
-        }
+        Ok(node_indices)
     }
 
-    fn remove_node_impl(&mut self, node_index: &NodeIndex) -> GraphRecordResult<Attributes> {
-        self.group_mapping.remove_node(node_index);
+    #[must_use]
+    pub const fn metadata(&self) -> &RecordMetadata {
+        &self.metadata
+    }
 
-        self.graph
-            .remove_node(node_index, &mut self.group_mapping)
-            .map_err(GraphRecordError::from)
+    /// Replaces the descriptive header of the record and returns the previous
+    /// one. The header is kept when the record is cleared.
+    pub fn set_metadata(&mut self, metadata: RecordMetadata) -> GraphRecordResult<RecordMetadata> {
+        self.assert_not_frozen()?;
+
+        let previous = mem::replace(&mut self.metadata, metadata);
+
+        self.subscribers.notify(|| ChangeEvent::MetadataChanged);
+
+        Ok(previous)
     }
 
-    fn add_nodes_impl(&mut self, nodes: Vec<(NodeIndex, Attributes)>) -> GraphRecordResult<()> {
-        for (node_index, attributes) in nodes {
-            self.add_node_impl(node_index, attributes)?;
-        }
+    /// Starts recording a [`ProvenanceTag`] for every attribute value written
+    /// from now on. Does nothing if provenance tracking is already enabled.
+    pub fn enable_provenance(&mut self) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
+        self.provenance.get_or_insert_with(ProvenanceStore::default);
 
         Ok(())
     }
 
-    // TODO: Add tests
-    #[allow(clippy::needless_pass_by_value)]
-    fn add_nodes_with_group_impl(
-        &mut self,
-        nodes: Vec<(NodeIndex, Attributes)>,
-        group: Group,
-    ) -> GraphRecordResult<()> {
-        if !self.contains_group(&group) {
-            self.add_group_impl(group.clone(), None, None)?;
-        }
+    /// Stops provenance tracking and drops all recorded tags.
+    pub fn disable_provenance(&mut self) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
 
-        for (node_index, attributes) in nodes {
-            self.add_node_with_group_impl(node_index, attributes, group.clone())?;
-        }
+        self.provenance = None;
 
         Ok(())
     }
 
-    fn add_nodes_with_groups_impl(
+    #[must_use]
+    pub const fn provenance_enabled(&self) -> bool {
+        self.provenance.is_some()
+    }
+
+    /// Sets the tag attached to attribute values written from now on and
+    /// returns the previous one.
+    ///
+    /// Values written while no tag is set have no provenance. The tag is not
+    /// persisted with the record.
+    pub fn set_provenance_tag(
         &mut self,
-        nodes: Vec<(NodeIndex, Attributes)>,
-        groups: impl AsRef<[Group]>,
-    ) -> GraphRecordResult<()> {
-        let groups = groups.as_ref();
+        tag: Option<ProvenanceTag>,
+    ) -> GraphRecordResult<Option<ProvenanceTag>> {
+        self.assert_not_frozen()?;
 
-        for group in groups {
-            if !self.contains_group(group) {
-                self.add_group_impl(group.clone(), None, None)?;
-            }
-        }
+        let provenance = self.provenance.as_mut().ok_or_else(|| {
+            GraphRecordError::AssertionError("Provenance tracking is not enabled".to_string())
+        })?;
 
-        for (node_index, attributes) in nodes {
-            self.add_node_with_groups_impl(node_index, attributes, groups)?;
-        }
+        Ok(provenance.set_active_tag(tag))
+    }
 
-        Ok(())
+    #[must_use]
+    pub fn provenance_tag(&self) -> Option<&ProvenanceTag> {
+        self.provenance.as_ref()?.active_tag()
     }
 
-    fn add_nodes_dataframes_impl(
-        &mut self,
-        nodes_dataframes: impl IntoIterator<Item = impl Into<NodeDataFrameInput>>,
-    ) -> GraphRecordResult<()> {
-        let nodes = nodes_dataframes
-            .into_iter()
-            .map(|dataframe_input| {
-                let dataframe_input = dataframe_input.into();
+    pub fn node_attribute_provenance(
+        &self,
+        node_index: &NodeIndex,
+        attribute: &GraphRecordAttribute,
+    ) -> GraphRecordResult<Option<&ProvenanceTag>> {
+        if let Some(layer) = &self.layer {
+            return layer.node_attribute_provenance(node_index, attribute);
+        }
 
-                dataframe_to_nodes(dataframe_input.dataframe, &dataframe_input.index_column)
-            })
-            .collect::<Result<Vec<_>, _>>()?
-            .into_iter()
-            .flatten()
-            .collect();
+        self.node_attributes(node_index)?;
 
-        self.add_nodes_impl(nodes)
+        Ok(self
+            .provenance
+            .as_ref()
+            .and_then(|provenance| provenance.node(node_index, attribute)))
     }
 
-    // TODO: Add tests
-    fn add_nodes_dataframes_with_group_impl(
-        &mut self,
-        nodes_dataframes: impl IntoIterator<Item = impl Into<NodeDataFrameInput>>,
-        group: Group,
-    ) -> GraphRecordResult<()> {
-        let nodes = nodes_dataframes
-            .into_iter()
-            .map(|dataframe_input| {
-                let dataframe_input = dataframe_input.into();
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub fn edge_attribute_provenance(
+        &self,
+        edge_index: &EdgeIndex,
+        attribute: &GraphRecordAttribute,
+    ) -> GraphRecordResult<Option<&ProvenanceTag>> {
+        if let Some(layer) = &self.layer {
+            return layer.edge_attribute_provenance(edge_index, attribute);
+        }
 
-                dataframe_to_nodes(dataframe_input.dataframe, &dataframe_input.index_column)
-            })
-            .collect::<Result<Vec<_>, _>>()?
-            .into_iter()
-            .flatten()
-            .collect();
+        self.edge_attributes(edge_index)?;
 
-        self.add_nodes_with_group_impl(nodes, group)
+        Ok(self
+            .provenance
+            .as_ref()
+            .and_then(|provenance| provenance.edge(edge_index, attribute)))
     }
 
-    fn add_nodes_dataframes_with_groups_impl(
+    fn tag_added_node(&mut self, node_index: &NodeIndex) {
+        if let Some(provenance) = &mut self.provenance {
+            let attributes = self
+                .graph
+                .node_attributes(node_index)
+                .expect("Node must exist");
+
+            provenance.tag_node(node_index, attributes, attributes.keys());
+        }
+    }
+
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    fn tag_added_edge(&mut self, edge_index: &EdgeIndex) {
+        if let Some(provenance) = &mut self.provenance {
+            let attributes = self
+                .graph
+                .edge_attributes(edge_index)
+                .expect("Edge must exist");
+
+            provenance.tag_edge(edge_index, attributes, attributes.keys());
+        }
+    }
+
+    /// Drops the provenance tags and attribute versions of a removed node.
+    fn remove_node_metadata(&mut self, node_index: &NodeIndex) {
+        if let Some(provenance) = &mut self.provenance {
+            provenance.remove_node(node_index);
+        }
+        self.valid_time.remove_node(node_index);
+    }
+
+    /// Like [`GraphRecord::remove_node_metadata`], but for a removed edge.
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    fn remove_edge_metadata(&mut self, edge_index: &EdgeIndex) {
+        if let Some(provenance) = &mut self.provenance {
+            provenance.remove_edge(edge_index);
+        }
+        self.valid_time.remove_edge(edge_index);
+    }
+
+    /// Adds a version of a node attribute that is valid within the interval
+    /// of `version`.
+    ///
+    /// Versions are kept apart from the current attribute values and are
+    /// resolved by [`GraphRecord::as_of`]. The intervals of the versions of an
+    /// attribute must not overlap. Versions are not validated against the
+    /// schema.
+    pub fn add_node_attribute_version(
         &mut self,
-        nodes_dataframes: impl IntoIterator<Item = impl Into<NodeDataFrameInput>>,
-        groups: impl AsRef<[Group]>,
+        node_index: &NodeIndex,
+        attribute: GraphRecordAttribute,
+        version: AttributeVersion,
     ) -> GraphRecordResult<()> {
-        let nodes = nodes_dataframes
-            .into_iter()
-            .map(|dataframe_input| {
-                let dataframe_input = dataframe_input.into();
+        self.assert_not_frozen()?;
+        self.node_attributes(node_index)?;
 
-                dataframe_to_nodes(dataframe_input.dataframe, &dataframe_input.index_column)
-            })
-            .collect::<Result<Vec<_>, _>>()?
-            .into_iter()
-            .flatten()
-            .collect();
+        self.valid_time
+            .add_node_version(node_index, attribute, version)?;
 
-        self.add_nodes_with_groups_impl(nodes, groups)
+        self.subscribers
+            .notify(|| ChangeEvent::NodeAttributesChanged {
+                node_index: node_index.clone(),
+            });
+
+        Ok(())
     }
 
-    #[allow(clippy::needless_pass_by_value)]
-    fn add_edge_impl(
+    /// Like [`GraphRecord::add_node_attribute_version`], but for an edge.
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub fn add_edge_attribute_version(
         &mut self,
-        source_node_index: NodeIndex,
-        target_node_index: NodeIndex,
-        attributes: Attributes,
-    ) -> GraphRecordResult<EdgeIndex> {
-        let edge_index = self
-            .graph
-            .add_edge(source_node_index, target_node_index, attributes.clone())
-            .map_err(GraphRecordError::from)?;
+        edge_index: &EdgeIndex,
+        attribute: GraphRecordAttribute,
+        version: AttributeVersion,
+    ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+        self.edge_attributes(edge_index)?;
 
-        match self.schema.schema_type() {
-            SchemaType::Inferred => {
-                let edges_in_groups = self.group_mapping.edges_in_group.len();
+        self.valid_time
+            .add_edge_version(edge_index, attribute, version)?;
 
-                let edges_not_in_groups = self.graph.edge_count() - edges_in_groups;
+        self.subscribers
+            .notify(|| ChangeEvent::EdgeAttributesChanged {
+                edge_index: *edge_index,
+            });
 
-                self.schema
-                    .update_edge(&attributes, None, edges_not_in_groups <= 1);
+        Ok(())
+    }
 
-                Ok(edge_index)
-            }
-            SchemaType::Provided => {
-                match self.schema.validate_edge(&edge_index, &attributes, None) {
-                    Ok(()) => Ok(edge_index),
-                    Err(e) => {
-                        self.graph
-                            .remove_edge(&edge_index)
-                            .expect("Edge must exist");
+    /// Returns the versions of a node attribute, sorted by the start of their
+    /// valid time.
+    pub fn node_attribute_versions(
+        &self,
+        node_index: &NodeIndex,
+        attribute: &GraphRecordAttribute,
+    ) -> GraphRecordResult<&[AttributeVersion]> {
+        self.node_attributes(node_index)?;
 
-                        Err(e.into())
-                    }
-                }
-            }
-        }
+        Ok(self.valid_time.node_versions(node_index, attribute))
     }
 
-    // TODO: Add tests
-    #[allow(clippy::needless_pass_by_value)]
-    fn add_edge_with_group_impl(
-        &mut self,
-        source_node_index: NodeIndex,
-        target_node_index: NodeIndex,
-        attributes: Attributes,
-        group: Group,
-    ) -> GraphRecordResult<EdgeIndex> {
-        let edge_index = self
-            .graph
-            .add_edge(source_node_index, target_node_index, attributes.clone())
-            .map_err(GraphRecordError::from)?;
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub fn edge_attribute_versions(
+        &self,
+        edge_index: &EdgeIndex,
+        attribute: &GraphRecordAttribute,
+    ) -> GraphRecordResult<&[AttributeVersion]> {
+        self.edge_attributes(edge_index)?;
 
-        match self.schema.schema_type() {
-            SchemaType::Inferred => {
-                let edges_in_group = self
-                    .group_mapping
-                    .edges_in_group
-                    .get(&group)
-                    .map_or(0, GrHashSet::len);
+        Ok(self.valid_time.edge_versions(edge_index, attribute))
+    }
 
-                self.schema
-                    .update_edge(&attributes, Some(&group), edges_in_group == 0);
-            }
-            SchemaType::Provided => {
-                self.schema
-                    .validate_edge(&edge_index, &attributes, Some(&group))
-                    .inspect_err(|_| {
-                        self.graph
-                            .remove_edge(&edge_index)
-                            .expect("Edge must exist");
-                    })?;
-            }
+    /// Reassigns edge indices so that they are contiguous, starting at 0.
+    ///
+    /// The relative order of the edges is kept. Returns a table mapping every
+    /// previous edge index to its new index. Edge indices held outside of the
+    /// record, including in plugins, are not updated.
+    pub fn compact(&mut self) -> GraphRecordResult<HashMap<EdgeIndex, EdgeIndex>> {
+        self.assert_not_frozen()?;
+
+        let remap = self.graph.compact(&mut self.group_mapping);
+
+        if let Some(provenance) = &mut self.provenance {
+            provenance.reindex_edges(&remap);
         }
+        self.valid_time.reindex_edges(&remap);
 
-        self.group_mapping
-            .add_edge_to_group(group, edge_index)
-            .inspect_err(|_| {
-                self.graph
-                    .remove_edge(&edge_index)
-                    .expect("Edge must exist");
-            })?;
+        self.subscribers.notify(|| ChangeEvent::EdgesReindexed);
 
-        Ok(edge_index)
+        Ok(remap)
     }
 
-    fn add_edge_with_groups_impl(
+    /// Controls whether indices of removed edges are reused for new edges.
+    ///
+    /// Disabled by default, in which case edge indices grow monotonically.
+    pub fn set_recycle_edge_indices(
         &mut self,
-        source_node_index: NodeIndex,
-        target_node_index: NodeIndex,
-        attributes: Attributes,
-        groups: impl AsRef<[Group]>,
-    ) -> GraphRecordResult<EdgeIndex> {
-        let groups = groups.as_ref();
+        recycle_edge_indices: bool,
+    ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
 
-        match groups.split_first() {
-            None => self.add_edge_impl(source_node_index, target_node_index, attributes),
-            Some((first, rest)) => {
-                let edge_index = self.add_edge_with_group_impl(
-                    source_node_index,
-                    target_node_index,
-                    attributes,
-                    first.clone(),
-                )?;
+        self.graph.set_recycle_edge_indices(recycle_edge_indices);
 
-                for group in rest {
-                    self.add_edge_to_group_impl(group.clone(), edge_index)
-                        .inspect_err(|_| {
-                            self.graph
-                                .remove_edge(&edge_index)
-                                .expect("Edge must exist");
-                        })?;
-                }
+        Ok(())
+    }
 
-                Ok(edge_index)
-            }
+    #[must_use]
+    pub const fn recycle_edge_indices(&self) -> bool {
+        self.graph.recycle_edge_indices()
+    }
+
+    pub fn node_indices(&self) -> impl Iterator<Item = &NodeIndex> {
+        match &self.layer {
+            Some(layer) => Either::Left(layer.node_indices()),
+            None => Either::Right(self.graph.node_indices()),
         }
     }
 
-    #[allow(clippy::trivially_copy_pass_by_ref)]
-    fn remove_edge_impl(&mut self, edge_index: &EdgeIndex) -> GraphRecordResult<Attributes> {
-        self.group_mapping.remove_edge(edge_index);
+    /// Like [`GraphRecord::node_indices`], but ordered by
+    /// [`GraphRecordAttribute::total_cmp`] instead of hash order.
+    pub fn node_indices_sorted(&self) -> impl Iterator<Item = &NodeIndex> {
+        let mut node_indices: Vec<_> = self.node_indices().collect();
+        node_indices.sort_unstable_by(|a, b| a.total_cmp(b));
+
+        node_indices.into_iter()
+    }
+
+    pub fn node_attributes(&self, node_index: &NodeIndex) -> GraphRecordResult<&AttributeMap> {
+        if let Some(layer) = &self.layer {
+            return layer.node_attributes(node_index);
+        }
 
         self.graph
-            .remove_edge(edge_index)
+            .node_attributes(node_index)
             .map_err(GraphRecordError::from)
     }
 
-    fn add_edges_impl(
-        &mut self,
-        edges: Vec<(NodeIndex, NodeIndex, Attributes)>,
-    ) -> GraphRecordResult<Vec<EdgeIndex>> {
-        edges
-            .into_iter()
-            .map(|(source_node_index, target_node_index, attributes)| {
-                self.add_edge_impl(source_node_index, target_node_index, attributes)
-            })
-            .collect()
+    pub fn node_attributes_mut<'a>(
+        &'a mut self,
+        node_index: &'a NodeIndex,
+    ) -> GraphRecordResult<NodeAttributesMut<'a>> {
+        NodeAttributesMut::new(node_index, self)
     }
 
-    // TODO: Add tests
-    fn add_edges_with_group_impl(
-        &mut self,
-        edges: Vec<(NodeIndex, NodeIndex, Attributes)>,
-        group: &Group,
-    ) -> GraphRecordResult<Vec<EdgeIndex>> {
-        if !self.contains_group(group) {
-            self.add_group_impl(group.clone(), None, None)?;
+    pub fn outgoing_edges(
+        &self,
+        node_index: &NodeIndex,
+    ) -> GraphRecordResult<impl Iterator<Item = &EdgeIndex> + use<'_>> {
+        if let Some(layer) = &self.layer {
+            return layer.outgoing_edges(node_index).map(Either::Left);
         }
 
-        edges
-            .into_iter()
-            .map(|(source_node_index, target_node_index, attributes)| {
-                self.add_edge_with_group_impl(
-                    source_node_index,
-                    target_node_index,
-                    attributes,
-                    group.clone(),
-                )
-            })
-            .collect()
+        self.graph
+            .outgoing_edges(node_index)
+            .map(Either::Right)
+            .map_err(GraphRecordError::from)
     }
 
-    fn add_edges_with_groups_impl(
-        &mut self,
-        edges: Vec<(NodeIndex, NodeIndex, Attributes)>,
-        groups: impl AsRef<[Group]>,
-    ) -> GraphRecordResult<Vec<EdgeIndex>> {
-        let groups = groups.as_ref();
+    pub fn incoming_edges(
+        &self,
+        node_index: &NodeIndex,
+    ) -> GraphRecordResult<impl Iterator<Item = &EdgeIndex> + use<'_>> {
+        if let Some(layer) = &self.layer {
+            return layer.incoming_edges(node_index).map(Either::Left);
+        }
 
-        for group in groups {
-            if !self.contains_group(group) {
-                self.add_group_impl(group.clone(), None, None)?;
-            }
+        self.graph
+            .incoming_edges(node_index)
+            .map(Either::Right)
+            .map_err(GraphRecordError::from)
+    }
+
+    pub fn edge_indices(&self) -> impl Iterator<Item = &EdgeIndex> {
+        match &self.layer {
+            Some(layer) => Either::Left(layer.edge_indices()),
+            None => Either::Right(self.graph.edge_indices()),
         }
+    }
 
-        edges
-            .into_iter()
-            .map(|(source_node_index, target_node_index, attributes)| {
-                self.add_edge_with_groups_impl(
-                    source_node_index,
-                    target_node_index,
-                    attributes,
-                    groups,
-                )
-            })
-            .collect()
-    }
+    /// Like [`GraphRecord::edge_indices`], but in ascending order. Edge indices
+    /// are assigned incrementally, so this is insertion order unless the record
+    /// was compacted.
+    pub fn edge_indices_sorted(&self) -> impl Iterator<Item = &EdgeIndex> {
+        let mut edge_indices: Vec<_> = self.edge_indices().collect();
+        edge_indices.sort_unstable();
 
-    fn add_edges_dataframes_impl(
-        &mut self,
-        edges_dataframes: impl IntoIterator<Item = impl Into<EdgeDataFrameInput>>,
-    ) -> GraphRecordResult<Vec<EdgeIndex>> {
-        let edges = edges_dataframes
-            .into_iter()
-            .map(|dataframe_input| {
-                let dataframe_input = dataframe_input.into();
+        edge_indices.into_iter()
+    }
 
-                dataframe_to_edges(
-                    dataframe_input.dataframe,
-                    &dataframe_input.source_index_column,
-                    &dataframe_input.target_index_column,
-                )
-            })
-            .collect::<Result<Vec<_>, _>>()?
-            .into_iter()
-            .flatten()
-            .collect();
+    pub fn edge_attributes(&self, edge_index: &EdgeIndex) -> GraphRecordResult<&AttributeMap> {
+        if let Some(layer) = &self.layer {
+            return layer.edge_attributes(edge_index);
+        }
 
-        self.add_edges_impl(edges)
+        self.graph
+            .edge_attributes(edge_index)
+            .map_err(GraphRecordError::from)
     }
 
-    // TODO: Add tests
-    fn add_edges_dataframes_with_group_impl(
-        &mut self,
-        edges_dataframes: impl IntoIterator<Item = impl Into<EdgeDataFrameInput>>,
-        group: &Group,
-    ) -> GraphRecordResult<Vec<EdgeIndex>> {
-        let edges = edges_dataframes
-            .into_iter()
-            .map(|dataframe_input| {
-                let dataframe_input = dataframe_input.into();
+    pub fn edge_attributes_mut<'a>(
+        &'a mut self,
+        edge_index: &'a EdgeIndex,
+    ) -> GraphRecordResult<EdgeAttributesMut<'a>> {
+        EdgeAttributesMut::new(edge_index, self)
+    }
 
-                dataframe_to_edges(
-                    dataframe_input.dataframe,
-                    &dataframe_input.source_index_column,
-                    &dataframe_input.target_index_column,
-                )
-            })
-            .collect::<Result<Vec<_>, _>>()?
-            .into_iter()
-            .flatten()
-            .collect();
+    pub fn edge_endpoints(
+        &self,
+        edge_index: &EdgeIndex,
+    ) -> GraphRecordResult<(&NodeIndex, &NodeIndex)> {
+        if let Some(layer) = &self.layer {
+            return layer.edge_endpoints(edge_index);
+        }
 
-        self.add_edges_with_group_impl(edges, group)
+        self.graph
+            .edge_endpoints(edge_index)
+            .map_err(GraphRecordError::from)
     }
 
-    fn add_edges_dataframes_with_groups_impl(
+    /// Undirected edges keep their source and target, but are also traversed
+    /// from target to source by the neighbor, edge and query methods.
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub fn set_edge_undirected(
         &mut self,
-        edges_dataframes: impl IntoIterator<Item = impl Into<EdgeDataFrameInput>>,
-        groups: impl AsRef<[Group]>,
-    ) -> GraphRecordResult<Vec<EdgeIndex>> {
-        let edges = edges_dataframes
-            .into_iter()
-            .map(|dataframe_input| {
-                let dataframe_input = dataframe_input.into();
+        edge_index: &EdgeIndex,
+        undirected: bool,
+    ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
 
-                dataframe_to_edges(
-                    dataframe_input.dataframe,
-                    &dataframe_input.source_index_column,
-                    &dataframe_input.target_index_column,
-                )
-            })
-            .collect::<Result<Vec<_>, _>>()?
-            .into_iter()
-            .flatten()
-            .collect();
+        self.graph.set_edge_undirected(edge_index, undirected)?;
 
-        self.add_edges_with_groups_impl(edges, groups)
-    }
+        self.subscribers
+            .notify(|| ChangeEvent::EdgeDirectionChanged {
+                edge_index: *edge_index,
+            });
 
-    fn add_group_impl(
-        &mut self,
-        group: Group,
-        node_indices: Option<Vec<NodeIndex>>,
-        edge_indices: Option<Vec<EdgeIndex>>,
-    ) -> GraphRecordResult<()> {
-        if self.group_mapping.contains_group(&group) {
-            return Err(GraphRecordError::AssertionError(format!(
-                "Group {group} already exists"
-            )));
-        }
+        Ok(())
+    }
 
-        if let Some(ref node_indices) = node_indices {
-            for node_index in node_indices {
-                if !self.graph.contains_node(node_index) {
-                    return Err(GraphRecordError::IndexError(format!(
-                        "Cannot find node with index {node_index}",
-                    )));
-                }
-            }
-        }
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub fn is_edge_undirected(&self, edge_index: &EdgeIndex) -> GraphRecordResult<bool> {
+        self.graph
+            .is_edge_undirected(edge_index)
+            .map_err(GraphRecordError::from)
+    }
 
-        if let Some(ref edge_indices) = edge_indices {
-            for edge_index in edge_indices {
-                if !self.graph.contains_edge(edge_index) {
-                    return Err(GraphRecordError::IndexError(format!(
-                        "Cannot find edge with index {edge_index}",
-                    )));
-                }
-            }
-        }
+    pub fn undirected_edges(&self) -> impl Iterator<Item = &EdgeIndex> {
+        self.graph.undirected_edge_indices()
+    }
 
-        match self.schema.schema_type() {
-            SchemaType::Inferred => {
-                if !self.schema.groups().contains_key(&group) {
-                    self.schema
-                        .add_group(group.clone(), GroupSchema::default())?;
-                }
+    pub fn edges_connecting<'a>(
+        &'a self,
+        outgoing_node_indices: Vec<&'a NodeIndex>,
+        incoming_node_indices: Vec<&'a NodeIndex>,
+    ) -> impl Iterator<Item = &'a EdgeIndex> + 'a {
+        self.graph
+            .edges_connecting(outgoing_node_indices, incoming_node_indices)
+    }
 
-                if let Some(ref node_indices) = node_indices {
-                    let mut empty = true;
+    pub fn edges_connecting_undirected<'a>(
+        &'a self,
+        first_node_indices: Vec<&'a NodeIndex>,
+        second_node_indices: Vec<&'a NodeIndex>,
+    ) -> impl Iterator<Item = &'a EdgeIndex> + 'a {
+        self.graph
+            .edges_connecting_undirected(first_node_indices, second_node_indices)
+    }
 
-                    for node_index in node_indices {
-                        let node_attributes = self.graph.node_attributes(node_index)?;
+    /// Returns the edges whose source and target are both in `node_indices`.
+    /// Indices of nodes that do not exist are ignored.
+    pub fn induced_edges<'a>(
+        &'a self,
+        node_indices: Vec<&'a NodeIndex>,
+    ) -> impl Iterator<Item = &'a EdgeIndex> + 'a {
+        self.graph.induced_edges(node_indices)
+    }
 
-                        self.schema
-                            .update_node(node_attributes, Some(&group), empty);
+    fn add_node_impl(
+        &mut self,
+        node_index: NodeIndex,
+        attributes: Attributes,
+    ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
 
-                        empty = false;
-                    }
-                }
+        let mut attributes = AttributeMap::from(attributes);
 
-                if let Some(ref edge_indices) = edge_indices {
-                    let mut empty = true;
+        self.schema.handle_non_finite_floats(&mut attributes)?;
 
-                    for edge_index in edge_indices {
-                        let edge_attributes = self.graph.edge_attributes(edge_index)?;
+        match self.schema.schema_type() {
+            SchemaType::Inferred => {
+                let nodes_in_groups = self.group_mapping.groups_of_node.len();
 
-                        self.schema
-                            .update_edge(edge_attributes, Some(&group), empty);
+                let nodes_not_in_groups = self.graph.node_count() - nodes_in_groups;
 
-                        empty = false;
-                    }
-                }
+                self.schema
+                    .update_node(&attributes, None, nodes_not_in_groups == 0);
             }
             SchemaType::Provided => {
-                if !self.schema.groups().contains_key(&group) {
-                    return Err(GraphRecordError::SchemaError(format!(
-                        "Group {group} is not defined in the schema"
-                    )));
-                }
+                self.schema.validate_node(&node_index, &attributes, None)?;
+            }
+        }
 
-                if let Some(ref node_indices) = node_indices {
-                    for node_index in node_indices {
-                        let node_attributes = self.graph.node_attributes(node_index)?;
+        self.insert_node(node_index, attributes)
+    }
 
-                        self.schema
-                            .validate_node(node_index, node_attributes, Some(&group))?;
-                    }
-                }
+    /// Adds a node whose attributes were already folded into or validated
+    /// against the schema.
+    fn insert_node(
+        &mut self,
+        node_index: NodeIndex,
+        attributes: AttributeMap,
+    ) -> GraphRecordResult<()> {
+        let added_node_index =
+            (self.subscribers.is_active() || self.provenance.is_some()).then(|| node_index.clone());
 
-                if let Some(ref edge_indices) = edge_indices {
-                    for edge_index in edge_indices {
-                        let edge_attributes = self.graph.edge_attributes(edge_index)?;
+        self.graph
+            .add_node(node_index, attributes)
+            .map_err(GraphRecordError::from)?;
 
-                        self.schema
-                            .validate_edge(edge_index, edge_attributes, Some(&group))?;
-                    }
-                }
-            }
-        }
+        if let Some(node_index) = added_node_index {
+            self.tag_added_node(&node_index);
 
-        self.group_mapping
-            .add_group(group, node_indices, edge_indices)
-            .expect("Group must not exist");
+            self.subscribers
+                .notify(|| ChangeEvent::NodeAdded { node_index });
+        } else {
+            self.subscribers.record_change();
+        }
 
         Ok(())
     }
 
-    fn remove_group_impl(&mut self, group: &Group) -> GraphRecordResult<()> {
-        self.group_mapping.remove_group(group)
-    }
-
-    fn add_node_to_group_impl(
+    // TODO: Add tests
+    #[allow(clippy::needless_pass_by_value)]
+    fn add_node_with_group_impl(
         &mut self,
-        group: Group,
         node_index: NodeIndex,
+        attributes: Attributes,
+        group: Group,
     ) -> GraphRecordResult<()> {
-        let node_attributes = self.graph.node_attributes(&node_index)?;
+        self.assert_not_frozen()?;
+
+        let mut attributes = AttributeMap::from(attributes);
+
+        self.schema.handle_non_finite_floats(&mut attributes)?;
 
         match self.schema.schema_type() {
             SchemaType::Inferred => {
@@ -1073,661 +1231,2024 @@ impl GraphRecord {
                     .map_or(0, GrHashSet::len);
 
                 self.schema
-                    .update_node(node_attributes, Some(&group), nodes_in_group == 0);
+                    .update_node(&attributes, Some(&group), nodes_in_group == 0);
             }
             SchemaType::Provided => {
                 self.schema
-                    .validate_node(&node_index, node_attributes, Some(&group))?;
+                    .validate_node(&node_index, &attributes, Some(&group))?;
             }
         }
 
-        self.group_mapping.add_node_to_group(group, node_index)
+        self.insert_node_with_group(node_index, attributes, group)
     }
 
+    /// Adds a node to `group` whose attributes were already folded into or
+    /// validated against the schema of the group.
     #[allow(clippy::needless_pass_by_value)]
-    fn add_node_to_groups_impl(
+    fn insert_node_with_group(
         &mut self,
-        groups: impl AsRef<[Group]>,
         node_index: NodeIndex,
+        attributes: AttributeMap,
+        group: Group,
     ) -> GraphRecordResult<()> {
-        groups
-            .as_ref()
-            .iter()
-            .try_for_each(|group| self.add_node_to_group_impl(group.clone(), node_index.clone()))
+        self.graph
+            .add_node(node_index.clone(), attributes)
+            .map_err(GraphRecordError::from)?;
+
+        let group_added = !self.group_mapping.contains_group(&group);
+
+        self.group_mapping
+            .add_node_to_group(group.clone(), node_index.clone())
+            .inspect_err(|_| {
+                self.graph
+                    .remove_node(&node_index, &mut self.group_mapping)
+                    .expect("Node must exist");
+            })?;
+
+        self.tag_added_node(&node_index);
+
+        self.subscribers.notify(|| ChangeEvent::NodeAdded {
+            node_index: node_index.clone(),
+        });
+        if group_added {
+            self.subscribers.notify(|| ChangeEvent::GroupAdded {
+                group: group.clone(),
+            });
+        }
+        self.subscribers
+            .notify(|| ChangeEvent::NodeAddedToGroup { group, node_index });
+
+        Ok(())
     }
 
-    fn add_nodes_to_groups_impl(
+    fn add_node_with_groups_impl(
         &mut self,
+        node_index: NodeIndex,
+        attributes: Attributes,
         groups: impl AsRef<[Group]>,
-        node_indices: Vec<NodeIndex>,
     ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
         let groups = groups.as_ref();
 
-        node_indices
-            .into_iter()
-            .try_for_each(|node_index| self.add_node_to_groups_impl(groups, node_index))
-    }
+        match groups.split_first() {
+            None => self.add_node_impl(node_index, attributes),
+            Some((first, rest)) => {
+                self.add_node_with_group_impl(node_index.clone(), attributes, first.clone())?;
 
-    fn add_edge_to_group_impl(
-        &mut self,
-        group: Group,
-        edge_index: EdgeIndex,
-    ) -> GraphRecordResult<()> {
-        let edge_attributes = self.graph.edge_attributes(&edge_index)?;
+                for group in rest {
+                    self.add_node_to_group_impl(group.clone(), node_index.clone())
+                        .inspect_err(|_| {
+                            self.graph
+                                .remove_node(&node_index, &mut self.group_mapping)
+                                .expect("Node must exist");
 
-        match self.schema.schema_type() {
-            SchemaType::Inferred => {
-                let edges_in_group = self
-                    .group_mapping
-                    .edges_in_group
-                    .get(&group)
-                    .map_or(0, GrHashSet::len);
+                            self.remove_node_metadata(&node_index);
 
-                self.schema
-                    .update_edge(edge_attributes, Some(&group), edges_in_group == 0);
-            }
-            SchemaType::Provided => {
-                self.schema
-                    .validate_edge(&edge_index, edge_attributes, Some(&group))?;
+                            self.subscribers.notify(|| ChangeEvent::NodeRemoved {
+                                node_index: node_index.clone(),
+                            });
+                        })?;
+                }
+
+                Ok(())
             }
         }
-
-        self.group_mapping.add_edge_to_group(group, edge_index)
     }
 
-    fn add_edge_to_groups_impl(
-        &mut self,
-        groups: impl AsRef<[Group]>,
-        edge_index: EdgeIndex,
-    ) -> GraphRecordResult<()> {
-        groups
-            .as_ref()
-            .iter()
-            .try_for_each(|group| self.add_edge_to_group_impl(group.clone(), edge_index))
-    }
+    fn remove_node_impl(&mut self, node_index: &NodeIndex) -> GraphRecordResult<Attributes> {
+        self.assert_not_frozen()?;
+
+        let edge_indices = if (self.subscribers.is_active()
+            || self.provenance.is_some()
+            || !self.valid_time.is_empty())
+            && self.contains_node(node_index)
+        {
+            self.graph
+                .outgoing_edges(node_index)?
+                .chain(self.graph.incoming_edges(node_index)?)
+                .copied()
+                .collect::<GrHashSet<_>>()
+        } else {
+            GrHashSet::default()
+        };
 
-    fn add_edges_to_groups_impl(
-        &mut self,
-        groups: impl AsRef<[Group]>,
-        edge_indices: Vec<EdgeIndex>,
-    ) -> GraphRecordResult<()> {
-        let groups = groups.as_ref();
+        self.group_mapping.remove_node(node_index);
 
-        edge_indices
-            .into_iter()
-            .try_for_each(|edge_index| self.add_edge_to_groups_impl(groups, edge_index))
-    }
+        let attributes = self
+            .graph
+            .remove_node(node_index, &mut self.group_mapping)
+            .map_err(GraphRecordError::from)?;
 
-    fn remove_node_from_group_impl(
-        &mut self,
-        group: &Group,
-        node_index: &NodeIndex,
-    ) -> GraphRecordResult<()> {
-        if !self.graph.contains_node(node_index) {
-            return Err(GraphRecordError::IndexError(format!(
-                "Cannot find node with index {node_index}",
-            )));
+        self.remove_node_metadata(node_index);
+        for edge_index in &edge_indices {
+            self.remove_edge_metadata(edge_index);
         }
 
-        self.group_mapping.remove_node_from_group(group, node_index)
-    }
+        for edge_index in edge_indices {
+            self.subscribers
+                .notify(|| ChangeEvent::EdgeRemoved { edge_index });
+        }
+        self.subscribers.notify(|| ChangeEvent::NodeRemoved {
+            node_index: node_index.clone(),
+        });
 
-    fn remove_node_from_groups_impl(
-        &mut self,
-        groups: impl AsRef<[Group]>,
-        node_index: &NodeIndex,
-    ) -> GraphRecordResult<()> {
-        groups
-            .as_ref()
-            .iter()
-            .try_for_each(|group| self.remove_node_from_group_impl(group, node_index))
+        Ok(attributes)
     }
 
-    fn remove_nodes_from_groups_impl(
-        &mut self,
-        groups: impl AsRef<[Group]>,
-        node_indices: &[NodeIndex],
-    ) -> GraphRecordResult<()> {
-        let groups = groups.as_ref();
+    fn add_nodes_impl(&mut self, nodes: Vec<(NodeIndex, Attributes)>) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
 
-        node_indices
-            .iter()
-            .try_for_each(|node_index| self.remove_node_from_groups_impl(groups, node_index))
-    }
+        if matches!(self.schema.schema_type(), SchemaType::Provided) {
+            for (node_index, attributes) in nodes {
+                self.add_node_impl(node_index, attributes)?;
+            }
 
-    #[allow(clippy::trivially_copy_pass_by_ref)]
-    fn remove_edge_from_group_impl(
-        &mut self,
-        group: &Group,
-        edge_index: &EdgeIndex,
-    ) -> GraphRecordResult<()> {
-        if !self.graph.contains_edge(edge_index) {
-            return Err(GraphRecordError::IndexError(format!(
-                "Cannot find edge with index {edge_index}",
-            )));
+            return Ok(());
         }
 
-        self.group_mapping.remove_edge_from_group(group, edge_index)
-    }
+        let nodes = self.prepare_node_attributes(nodes)?;
 
-    #[allow(clippy::trivially_copy_pass_by_ref)]
-    fn remove_edge_from_groups_impl(
-        &mut self,
-        groups: impl AsRef<[Group]>,
-        edge_index: &EdgeIndex,
-    ) -> GraphRecordResult<()> {
-        groups
-            .as_ref()
-            .iter()
-            .try_for_each(|group| self.remove_edge_from_group_impl(group, edge_index))
-    }
+        let nodes_in_groups = self.group_mapping.groups_of_node.len();
 
-    fn remove_edges_from_groups_impl(
-        &mut self,
-        groups: impl AsRef<[Group]>,
-        edge_indices: &[EdgeIndex],
-    ) -> GraphRecordResult<()> {
-        let groups = groups.as_ref();
+        let nodes_not_in_groups = self.graph.node_count() - nodes_in_groups;
 
-        edge_indices
-            .iter()
-            .try_for_each(|edge_index| self.remove_edge_from_groups_impl(groups, edge_index))
-    }
+        self.schema.update_nodes(
+            nodes.iter().map(|(_, attributes)| attributes),
+            None,
+            nodes_not_in_groups == 0,
+        );
 
-    pub fn groups(&self) -> impl Iterator<Item = &Group> {
-        self.group_mapping.groups()
+        for (node_index, attributes) in nodes {
+            self.insert_node(node_index, attributes)?;
+        }
+
+        Ok(())
     }
 
-    pub fn nodes_in_group(
+    /// Converts the attributes of a batch of nodes and handles their
+    /// non-finite floats, so an inferred schema can take in the whole batch
+    /// at once.
+    fn prepare_node_attributes(
         &self,
-        group: &Group,
-    ) -> GraphRecordResult<impl Iterator<Item = &NodeIndex> + use<'_>> {
-        self.group_mapping.nodes_in_group(group)
-    }
+        nodes: Vec<(NodeIndex, Attributes)>,
+    ) -> GraphRecordResult<Vec<(NodeIndex, AttributeMap)>> {
+        nodes
+            .into_iter()
+            .map(|(node_index, attributes)| {
+                let mut attributes = AttributeMap::from(attributes);
 
-    pub fn ungrouped_nodes(&self) -> impl Iterator<Item = &NodeIndex> {
-        let nodes_in_groups: GrHashSet<_> = self
-            .groups()
-            .flat_map(|group| {
-                #[expect(clippy::missing_panics_doc, reason = "infallible")]
-                self.nodes_in_group(group).expect("Group must exist")
-            })
-            .collect();
+                self.schema.handle_non_finite_floats(&mut attributes)?;
 
-        self.graph
-            .node_indices()
-            .filter(move |node_index| !nodes_in_groups.contains(*node_index))
+                Ok((node_index, attributes))
+            })
+            .collect()
     }
 
-    pub fn edges_in_group(
-        &self,
-        group: &Group,
-    ) -> GraphRecordResult<impl Iterator<Item = &EdgeIndex> + use<'_>> {
-        self.group_mapping.edges_in_group(group)
-    }
+    // TODO: Add tests
+    #[allow(clippy::needless_pass_by_value)]
+    fn add_nodes_with_group_impl(
+        &mut self,
+        nodes: Vec<(NodeIndex, Attributes)>,
+        group: Group,
+    ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
 
-    pub fn ungrouped_edges(&self) -> impl Iterator<Item = &EdgeIndex> {
-        let edges_in_groups: GrHashSet<_> = self
-            .groups()
-            .flat_map(|group| {
-                #[expect(clippy::missing_panics_doc, reason = "infallible")]
-                self.edges_in_group(group).expect("Group must exist")
-            })
-            .collect();
+        if !self.contains_group(&group) {
+            self.add_group_impl(group.clone(), None, None)?;
+        }
 
-        self.graph
-            .edge_indices()
-            .filter(move |edge_index| !edges_in_groups.contains(*edge_index))
-    }
+        if matches!(self.schema.schema_type(), SchemaType::Provided) {
+            for (node_index, attributes) in nodes {
+                self.add_node_with_group_impl(node_index, attributes, group.clone())?;
+            }
 
-    pub fn groups_of_node(
-        &self,
-        node_index: &NodeIndex,
-    ) -> GraphRecordResult<impl Iterator<Item = &Group> + use<'_>> {
-        if !self.graph.contains_node(node_index) {
-            return Err(GraphRecordError::IndexError(format!(
-                "Cannot find node with index {node_index}",
-            )));
+            return Ok(());
         }
 
-        Ok(self.group_mapping.groups_of_node(node_index))
-    }
+        let nodes = self.prepare_node_attributes(nodes)?;
 
-    pub fn groups_of_edge(
-        &self,
-        edge_index: &EdgeIndex,
-    ) -> GraphRecordResult<impl Iterator<Item = &Group> + use<'_>> {
-        if !self.graph.contains_edge(edge_index) {
-            return Err(GraphRecordError::IndexError(format!(
-                "Cannot find edge with index {edge_index}",
-            )));
+        let nodes_in_group = self
+            .group_mapping
+            .nodes_in_group
+            .get(&group)
+            .map_or(0, GrHashSet::len);
+
+        self.schema.update_nodes(
+            nodes.iter().map(|(_, attributes)| attributes),
+            Some(&group),
+            nodes_in_group == 0,
+        );
+
+        for (node_index, attributes) in nodes {
+            self.insert_node_with_group(node_index, attributes, group.clone())?;
         }
 
-        Ok(self.group_mapping.groups_of_edge(edge_index))
+        Ok(())
     }
 
-    #[must_use]
-    pub fn node_count(&self) -> usize {
-        self.graph.node_count()
-    }
+    fn add_nodes_with_groups_impl(
+        &mut self,
+        nodes: Vec<(NodeIndex, Attributes)>,
+        groups: impl AsRef<[Group]>,
+    ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
 
-    #[must_use]
-    pub fn edge_count(&self) -> usize {
-        self.graph.edge_count()
-    }
+        let groups = groups.as_ref();
 
-    #[must_use]
-    pub fn group_count(&self) -> usize {
-        self.group_mapping.group_count()
-    }
+        for group in groups {
+            if !self.contains_group(group) {
+                self.add_group_impl(group.clone(), None, None)?;
+            }
+        }
 
-    #[must_use]
-    pub fn contains_node(&self, node_index: &NodeIndex) -> bool {
-        self.graph.contains_node(node_index)
-    }
+        for (node_index, attributes) in nodes {
+            self.add_node_with_groups_impl(node_index, attributes, groups)?;
+        }
 
-    #[must_use]
-    pub fn contains_edge(&self, edge_index: &EdgeIndex) -> bool {
-        self.graph.contains_edge(edge_index)
+        Ok(())
     }
 
-    #[must_use]
-    pub fn contains_group(&self, group: &Group) -> bool {
-        self.group_mapping.contains_group(group)
-    }
+    fn add_nodes_dataframes_impl(
+        &mut self,
+        nodes_dataframes: impl IntoIterator<Item = impl Into<NodeDataFrameInput>>,
+    ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
 
-    pub fn neighbors_outgoing(
-        &self,
-        node_index: &NodeIndex,
-    ) -> GraphRecordResult<impl Iterator<Item = &NodeIndex> + use<'_>> {
-        self.graph
-            .neighbors_outgoing(node_index)
-            .map_err(GraphRecordError::from)
+        let mode = self.schema.conversion_mode();
+
+        let nodes = nodes_dataframes
+            .into_iter()
+            .map(|dataframe_input| {
+                let dataframe_input = dataframe_input.into();
+
+                dataframe_to_nodes(
+                    dataframe_input.dataframe,
+                    &dataframe_input.index_column,
+                    mode,
+                    &dataframe_input.nulls,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .flatten()
+            .collect();
+
+        self.add_nodes_impl(nodes)
     }
 
     // TODO: Add tests
-    pub fn neighbors_incoming(
-        &self,
-        node_index: &NodeIndex,
-    ) -> GraphRecordResult<impl Iterator<Item = &NodeIndex> + use<'_>> {
-        self.graph
-            .neighbors_incoming(node_index)
-            .map_err(GraphRecordError::from)
-    }
-
-    pub fn neighbors_undirected(
-        &self,
-        node_index: &NodeIndex,
-    ) -> GraphRecordResult<impl Iterator<Item = &NodeIndex> + use<'_>> {
-        self.graph
-            .neighbors_undirected(node_index)
-            .map_err(GraphRecordError::from)
-    }
-
-    fn clear_impl(&mut self) {
-        self.graph.clear();
-        self.group_mapping.clear();
-    }
-
-    pub fn query_nodes<'a, Q, R>(&'a self, query: Q) -> Selection<'a, R>
-    where
-        Q: FnOnce(&Wrapper<NodeOperand>) -> R,
-        R: ReturnOperand<'a>,
-    {
-        Selection::new_node(self, query)
-    }
-
-    pub fn query_edges<'a, Q, R>(&'a self, query: Q) -> Selection<'a, R>
-    where
-        Q: FnOnce(&Wrapper<EdgeOperand>) -> R,
-        R: ReturnOperand<'a>,
-    {
-        Selection::new_edge(self, query)
-    }
-
-    pub fn overview(&self, truncate_details: Option<usize>) -> GraphRecordResult<Overview> {
-        Overview::new(self, truncate_details)
-    }
-
-    pub fn group_overview(
-        &self,
-        group: &Group,
-        truncate_details: Option<usize>,
-    ) -> GraphRecordResult<GroupOverview> {
-        GroupOverview::new(self, Some(group), truncate_details)
-    }
-}
-
-#[cfg(not(feature = "plugins"))]
-impl GraphRecord {
-    pub fn set_schema(&mut self, schema: Schema) -> GraphRecordResult<()> {
-        self.set_schema_impl(schema)
-    }
-
-    pub const fn freeze_schema(&mut self) -> GraphRecordResult<()> {
-        self.freeze_schema_impl();
-
-        Ok(())
-    }
-
-    pub const fn unfreeze_schema(&mut self) -> GraphRecordResult<()> {
-        self.unfreeze_schema_impl();
-
-        Ok(())
-    }
-
-    pub fn add_node(
-        &mut self,
-        node_index: NodeIndex,
-        attributes: Attributes,
-    ) -> GraphRecordResult<()> {
-        self.add_node_impl(node_index, attributes)
-    }
-
-    #[allow(clippy::needless_pass_by_value)]
-    pub fn add_node_with_group(
+    fn add_nodes_dataframes_with_group_impl(
         &mut self,
-        node_index: NodeIndex,
-        attributes: Attributes,
+        nodes_dataframes: impl IntoIterator<Item = impl Into<NodeDataFrameInput>>,
         group: Group,
     ) -> GraphRecordResult<()> {
-        self.add_node_with_group_impl(node_index, attributes, group)
-    }
+        self.assert_not_frozen()?;
 
-    pub fn add_node_with_groups(
-        &mut self,
-        node_index: NodeIndex,
-        attributes: Attributes,
-        groups: impl AsRef<[Group]>,
-    ) -> GraphRecordResult<()> {
-        self.add_node_with_groups_impl(node_index, attributes, groups)
-    }
+        let mode = self.schema.conversion_mode();
 
-    pub fn remove_node(&mut self, node_index: &NodeIndex) -> GraphRecordResult<Attributes> {
-        self.remove_node_impl(node_index)
-    }
+        let nodes = nodes_dataframes
+            .into_iter()
+            .map(|dataframe_input| {
+                let dataframe_input = dataframe_input.into();
 
-    pub fn add_nodes(&mut self, nodes: Vec<(NodeIndex, Attributes)>) -> GraphRecordResult<()> {
-        self.add_nodes_impl(nodes)
-    }
+                dataframe_to_nodes(
+                    dataframe_input.dataframe,
+                    &dataframe_input.index_column,
+                    mode,
+                    &dataframe_input.nulls,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .flatten()
+            .collect();
 
-    #[allow(clippy::needless_pass_by_value)]
-    pub fn add_nodes_with_group(
-        &mut self,
-        nodes: Vec<(NodeIndex, Attributes)>,
-        group: Group,
-    ) -> GraphRecordResult<()> {
         self.add_nodes_with_group_impl(nodes, group)
     }
 
-    pub fn add_nodes_with_groups(
+    fn add_nodes_dataframes_with_groups_impl(
         &mut self,
-        nodes: Vec<(NodeIndex, Attributes)>,
+        nodes_dataframes: impl IntoIterator<Item = impl Into<NodeDataFrameInput>>,
         groups: impl AsRef<[Group]>,
     ) -> GraphRecordResult<()> {
-        self.add_nodes_with_groups_impl(nodes, groups)
-    }
+        self.assert_not_frozen()?;
 
-    pub fn add_nodes_dataframes(
-        &mut self,
-        nodes_dataframes: impl IntoIterator<Item = impl Into<NodeDataFrameInput>>,
-    ) -> GraphRecordResult<()> {
-        self.add_nodes_dataframes_impl(nodes_dataframes)
-    }
+        let mode = self.schema.conversion_mode();
 
-    pub fn add_nodes_dataframes_with_group(
-        &mut self,
-        nodes_dataframes: impl IntoIterator<Item = impl Into<NodeDataFrameInput>>,
-        group: Group,
-    ) -> GraphRecordResult<()> {
-        self.add_nodes_dataframes_with_group_impl(nodes_dataframes, group)
-    }
+        let nodes = nodes_dataframes
+            .into_iter()
+            .map(|dataframe_input| {
+                let dataframe_input = dataframe_input.into();
 
-    pub fn add_nodes_dataframes_with_groups(
-        &mut self,
-        nodes_dataframes: impl IntoIterator<Item = impl Into<NodeDataFrameInput>>,
-        groups: impl AsRef<[Group]>,
-    ) -> GraphRecordResult<()> {
-        self.add_nodes_dataframes_with_groups_impl(nodes_dataframes, groups)
+                dataframe_to_nodes(
+                    dataframe_input.dataframe,
+                    &dataframe_input.index_column,
+                    mode,
+                    &dataframe_input.nulls,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .flatten()
+            .collect();
+
+        self.add_nodes_with_groups_impl(nodes, groups)
     }
 
     #[allow(clippy::needless_pass_by_value)]
-    pub fn add_edge(
+    fn add_edge_impl(
         &mut self,
         source_node_index: NodeIndex,
         target_node_index: NodeIndex,
         attributes: Attributes,
     ) -> GraphRecordResult<EdgeIndex> {
-        self.add_edge_impl(source_node_index, target_node_index, attributes)
+        self.assert_not_frozen()?;
+
+        let mut attributes = AttributeMap::from(attributes);
+
+        self.schema.handle_non_finite_floats(&mut attributes)?;
+
+        let edge_index = self
+            .graph
+            .add_edge(source_node_index, target_node_index, attributes.clone())
+            .map_err(GraphRecordError::from)?;
+
+        match self.schema.schema_type() {
+            SchemaType::Inferred => {
+                let edges_in_groups = self.group_mapping.groups_of_edge.len();
+
+                let edges_not_in_groups = self.graph.edge_count() - edges_in_groups;
+
+                self.schema
+                    .update_edge(&attributes, None, edges_not_in_groups <= 1);
+            }
+            SchemaType::Provided => {
+                if let Err(e) = self.schema.validate_edge(&edge_index, &attributes, None) {
+                    self.graph
+                        .remove_edge(&edge_index)
+                        .expect("Edge must exist");
+
+                    return Err(e.into());
+                }
+            }
+        }
+
+        self.tag_added_edge(&edge_index);
+
+        self.subscribers
+            .notify(|| ChangeEvent::EdgeAdded { edge_index });
+
+        Ok(edge_index)
     }
 
+    // TODO: Add tests
     #[allow(clippy::needless_pass_by_value)]
-    pub fn add_edge_with_group(
+    fn add_edge_with_group_impl(
         &mut self,
         source_node_index: NodeIndex,
         target_node_index: NodeIndex,
         attributes: Attributes,
         group: Group,
     ) -> GraphRecordResult<EdgeIndex> {
-        self.add_edge_with_group_impl(source_node_index, target_node_index, attributes, group)
+        self.assert_not_frozen()?;
+
+        let mut attributes = AttributeMap::from(attributes);
+
+        self.schema.handle_non_finite_floats(&mut attributes)?;
+
+        let edge_index = self
+            .graph
+            .add_edge(source_node_index, target_node_index, attributes.clone())
+            .map_err(GraphRecordError::from)?;
+
+        match self.schema.schema_type() {
+            SchemaType::Inferred => {
+                let edges_in_group = self
+                    .group_mapping
+                    .edges_in_group
+                    .get(&group)
+                    .map_or(0, GrHashSet::len);
+
+                self.schema
+                    .update_edge(&attributes, Some(&group), edges_in_group == 0);
+            }
+            SchemaType::Provided => {
+                self.schema
+                    .validate_edge(&edge_index, &attributes, Some(&group))
+                    .inspect_err(|_| {
+                        self.graph
+                            .remove_edge(&edge_index)
+                            .expect("Edge must exist");
+                    })?;
+            }
+        }
+
+        let group_added = !self.group_mapping.contains_group(&group);
+
+        self.group_mapping
+            .add_edge_to_group(group.clone(), edge_index)
+            .inspect_err(|_| {
+                self.graph
+                    .remove_edge(&edge_index)
+                    .expect("Edge must exist");
+            })?;
+
+        self.tag_added_edge(&edge_index);
+
+        self.subscribers
+            .notify(|| ChangeEvent::EdgeAdded { edge_index });
+        if group_added {
+            self.subscribers.notify(|| ChangeEvent::GroupAdded {
+                group: group.clone(),
+            });
+        }
+        self.subscribers
+            .notify(|| ChangeEvent::EdgeAddedToGroup { group, edge_index });
+
+        Ok(edge_index)
     }
 
-    pub fn add_edge_with_groups(
+    fn add_edge_with_groups_impl(
         &mut self,
         source_node_index: NodeIndex,
         target_node_index: NodeIndex,
         attributes: Attributes,
         groups: impl AsRef<[Group]>,
     ) -> GraphRecordResult<EdgeIndex> {
-        self.add_edge_with_groups_impl(source_node_index, target_node_index, attributes, groups)
-    }
+        self.assert_not_frozen()?;
 
-    pub fn remove_edge(&mut self, edge_index: &EdgeIndex) -> GraphRecordResult<Attributes> {
-        self.remove_edge_impl(edge_index)
-    }
+        let groups = groups.as_ref();
 
-    pub fn add_edges(
-        &mut self,
-        edges: Vec<(NodeIndex, NodeIndex, Attributes)>,
-    ) -> GraphRecordResult<Vec<EdgeIndex>> {
-        self.add_edges_impl(edges)
-    }
+        match groups.split_first() {
+            None => self.add_edge_impl(source_node_index, target_node_index, attributes),
+            Some((first, rest)) => {
+                let edge_index = self.add_edge_with_group_impl(
+                    source_node_index,
+                    target_node_index,
+                    attributes,
+                    first.clone(),
+                )?;
 
-    pub fn add_edges_with_group(
-        &mut self,
-        edges: Vec<(NodeIndex, NodeIndex, Attributes)>,
-        group: &Group,
-    ) -> GraphRecordResult<Vec<EdgeIndex>> {
-        self.add_edges_with_group_impl(edges, group)
-    }
+                for group in rest {
+                    self.add_edge_to_group_impl(group.clone(), edge_index)
+                        .inspect_err(|_| {
+                            self.graph
+                                .remove_edge(&edge_index)
+                                .expect("Edge must exist");
 
-    pub fn add_edges_with_groups(
-        &mut self,
-        edges: Vec<(NodeIndex, NodeIndex, Attributes)>,
-        groups: impl AsRef<[Group]>,
-    ) -> GraphRecordResult<Vec<EdgeIndex>> {
-        self.add_edges_with_groups_impl(edges, groups)
+                            self.remove_edge_metadata(&edge_index);
+
+                            self.subscribers
+                                .notify(|| ChangeEvent::EdgeRemoved { edge_index });
+                        })?;
+                }
+
+                Ok(edge_index)
+            }
+        }
     }
 
-    pub fn add_edges_dataframes(
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    fn remove_edge_impl(&mut self, edge_index: &EdgeIndex) -> GraphRecordResult<Attributes> {
+        self.assert_not_frozen()?;
+
+        self.group_mapping.remove_edge(edge_index);
+
+        let attributes = self
+            .graph
+            .remove_edge(edge_index)
+            .map_err(GraphRecordError::from)?;
+
+        self.remove_edge_metadata(edge_index);
+
+        self.subscribers.notify(|| ChangeEvent::EdgeRemoved {
+            edge_index: *edge_index,
+        });
+
+        Ok(attributes)
+    }
+
+    fn add_edges_impl(
         &mut self,
-        edges_dataframes: impl IntoIterator<Item = impl Into<EdgeDataFrameInput>>,
+        edges: Vec<(NodeIndex, NodeIndex, Attributes)>,
     ) -> GraphRecordResult<Vec<EdgeIndex>> {
-        self.add_edges_dataframes_impl(edges_dataframes)
+        self.assert_not_frozen()?;
+
+        edges
+            .into_iter()
+            .map(|(source_node_index, target_node_index, attributes)| {
+                self.add_edge_impl(source_node_index, target_node_index, attributes)
+            })
+            .collect()
     }
 
-    pub fn add_edges_dataframes_with_group(
+    // TODO: Add tests
+    fn add_edges_with_group_impl(
         &mut self,
-        edges_dataframes: impl IntoIterator<Item = impl Into<EdgeDataFrameInput>>,
+        edges: Vec<(NodeIndex, NodeIndex, Attributes)>,
         group: &Group,
     ) -> GraphRecordResult<Vec<EdgeIndex>> {
-        self.add_edges_dataframes_with_group_impl(edges_dataframes, group)
+        self.assert_not_frozen()?;
+
+        if !self.contains_group(group) {
+            self.add_group_impl(group.clone(), None, None)?;
+        }
+
+        edges
+            .into_iter()
+            .map(|(source_node_index, target_node_index, attributes)| {
+                self.add_edge_with_group_impl(
+                    source_node_index,
+                    target_node_index,
+                    attributes,
+                    group.clone(),
+                )
+            })
+            .collect()
     }
 
-    pub fn add_edges_dataframes_with_groups(
+    fn add_edges_with_groups_impl(
         &mut self,
-        edges_dataframes: impl IntoIterator<Item = impl Into<EdgeDataFrameInput>>,
+        edges: Vec<(NodeIndex, NodeIndex, Attributes)>,
         groups: impl AsRef<[Group]>,
     ) -> GraphRecordResult<Vec<EdgeIndex>> {
-        self.add_edges_dataframes_with_groups_impl(edges_dataframes, groups)
-    }
+        self.assert_not_frozen()?;
 
-    pub fn add_group(
-        &mut self,
-        group: Group,
-        node_indices: Option<Vec<NodeIndex>>,
-        edge_indices: Option<Vec<EdgeIndex>>,
-    ) -> GraphRecordResult<()> {
-        self.add_group_impl(group, node_indices, edge_indices)
-    }
+        let groups = groups.as_ref();
 
-    pub fn remove_group(&mut self, group: &Group) -> GraphRecordResult<()> {
-        self.remove_group_impl(group)
-    }
+        for group in groups {
+            if !self.contains_group(group) {
+                self.add_group_impl(group.clone(), None, None)?;
+            }
+        }
 
-    pub fn add_node_to_group(
-        &mut self,
-        group: Group,
-        node_index: NodeIndex,
-    ) -> GraphRecordResult<()> {
-        self.add_node_to_group_impl(group, node_index)
+        edges
+            .into_iter()
+            .map(|(source_node_index, target_node_index, attributes)| {
+                self.add_edge_with_groups_impl(
+                    source_node_index,
+                    target_node_index,
+                    attributes,
+                    groups,
+                )
+            })
+            .collect()
     }
 
-    pub fn add_node_to_groups(
+    /// Converts the edges of all data frames, then adds the nodes missing for
+    /// inputs with [`MissingNodePolicy::Create`], so that no node is created
+    /// if a data frame fails to convert.
+    fn edge_dataframes_to_tuples(
         &mut self,
-        groups: impl AsRef<[Group]>,
-        node_index: NodeIndex,
-    ) -> GraphRecordResult<()> {
-        self.add_node_to_groups_impl(groups, node_index)
-    }
+        edges_dataframes: impl IntoIterator<Item = impl Into<EdgeDataFrameInput>>,
+    ) -> GraphRecordResult<Vec<(NodeIndex, NodeIndex, Attributes)>> {
+        let mode = self.schema.conversion_mode();
 
-    pub fn add_nodes_to_groups(
-        &mut self,
-        groups: impl AsRef<[Group]>,
-        node_indices: Vec<NodeIndex>,
-    ) -> GraphRecordResult<()> {
-        self.add_nodes_to_groups_impl(groups, node_indices)
-    }
+        let inputs = edges_dataframes
+            .into_iter()
+            .map(|dataframe_input| {
+                let dataframe_input = dataframe_input.into();
 
-    pub fn add_edge_to_group(
-        &mut self,
-        group: Group,
-        edge_index: EdgeIndex,
-    ) -> GraphRecordResult<()> {
-        self.add_edge_to_group_impl(group, edge_index)
-    }
+                let edges = dataframe_to_edges(
+                    dataframe_input.dataframe,
+                    &dataframe_input.source_index_column,
+                    &dataframe_input.target_index_column,
+                    mode,
+                    &dataframe_input.nulls,
+                )?;
 
-    pub fn add_edge_to_groups(
-        &mut self,
-        groups: impl AsRef<[Group]>,
-        edge_index: EdgeIndex,
-    ) -> GraphRecordResult<()> {
-        self.add_edge_to_groups_impl(groups, edge_index)
-    }
+                Ok((edges, dataframe_input.missing_nodes))
+            })
+            .collect::<GraphRecordResult<Vec<_>>>()?;
+
+        for (edges, missing_nodes) in &inputs {
+            let MissingNodePolicy::Create { group } = missing_nodes else {
+                continue;
+            };
+
+            let mut seen = GrHashSet::default();
+
+            let nodes: Vec<_> = edges
+                .iter()
+                .flat_map(|(source_node_index, target_node_index, _)| {
+                    [source_node_index, target_node_index]
+                })
+                .filter(|node_index| {
+                    !self.graph.contains_node(node_index) && seen.insert(*node_index)
+                })
+                .map(|node_index| (node_index.clone(), Attributes::new()))
+                .collect();
 
-    pub fn add_edges_to_groups(
-        &mut self,
-        groups: impl AsRef<[Group]>,
-        edge_indices: Vec<EdgeIndex>,
-    ) -> GraphRecordResult<()> {
-        self.add_edges_to_groups_impl(groups, edge_indices)
-    }
+            match group {
+                Some(group) => self.add_nodes_with_group_impl(nodes, group.clone())?,
+                None => self.add_nodes_impl(nodes)?,
+            }
+        }
 
-    pub fn remove_node_from_group(
-        &mut self,
-        group: &Group,
-        node_index: &NodeIndex,
-    ) -> GraphRecordResult<()> {
-        self.remove_node_from_group_impl(group, node_index)
+        Ok(inputs.into_iter().flat_map(|(edges, _)| edges).collect())
     }
 
-    pub fn remove_node_from_groups(
+    fn add_edges_dataframes_impl(
         &mut self,
-        groups: impl AsRef<[Group]>,
-        node_index: &NodeIndex,
-    ) -> GraphRecordResult<()> {
-        self.remove_node_from_groups_impl(groups, node_index)
-    }
+        edges_dataframes: impl IntoIterator<Item = impl Into<EdgeDataFrameInput>>,
+    ) -> GraphRecordResult<Vec<EdgeIndex>> {
+        self.assert_not_frozen()?;
 
-    pub fn remove_nodes_from_groups(
-        &mut self,
-        groups: impl AsRef<[Group]>,
-        node_indices: &[NodeIndex],
-    ) -> GraphRecordResult<()> {
-        self.remove_nodes_from_groups_impl(groups, node_indices)
+        let edges = self.edge_dataframes_to_tuples(edges_dataframes)?;
+
+        self.add_edges_impl(edges)
     }
 
-    pub fn remove_edge_from_group(
+    // TODO: Add tests
+    fn add_edges_dataframes_with_group_impl(
         &mut self,
+        edges_dataframes: impl IntoIterator<Item = impl Into<EdgeDataFrameInput>>,
         group: &Group,
-        edge_index: &EdgeIndex,
-    ) -> GraphRecordResult<()> {
-        self.remove_edge_from_group_impl(group, edge_index)
+    ) -> GraphRecordResult<Vec<EdgeIndex>> {
+        self.assert_not_frozen()?;
+
+        let edges = self.edge_dataframes_to_tuples(edges_dataframes)?;
+
+        self.add_edges_with_group_impl(edges, group)
     }
 
-    pub fn remove_edge_from_groups(
+    fn add_edges_dataframes_with_groups_impl(
         &mut self,
+        edges_dataframes: impl IntoIterator<Item = impl Into<EdgeDataFrameInput>>,
         groups: impl AsRef<[Group]>,
-        edge_index: &EdgeIndex,
-    ) -> GraphRecordResult<()> {
-        self.remove_edge_from_groups_impl(groups, edge_index)
+    ) -> GraphRecordResult<Vec<EdgeIndex>> {
+        self.assert_not_frozen()?;
+
+        let edges = self.edge_dataframes_to_tuples(edges_dataframes)?;
+
+        self.add_edges_with_groups_impl(edges, groups)
     }
 
-    pub fn remove_edges_from_groups(
+    fn add_group_impl(
         &mut self,
-        groups: impl AsRef<[Group]>,
-        edge_indices: &[EdgeIndex],
+        group: Group,
+        node_indices: Option<Vec<NodeIndex>>,
+        edge_indices: Option<Vec<EdgeIndex>>,
     ) -> GraphRecordResult<()> {
-        self.remove_edges_from_groups_impl(groups, edge_indices)
-    }
-
-    pub fn clear(&mut self) -> GraphRecordResult<()> {
-        self.clear_impl();
+        self.assert_not_frozen()?;
 
-        Ok(())
-    }
-}
+        if self.group_mapping.contains_group(&group) {
+            return Err(GraphRecordError::DuplicateGroup { group });
+        }
 
-#[cfg(test)]
-mod test {
-    use super::{
-        Attributes, EdgeDataFrameInput, GraphRecord, GraphRecordAttribute, NodeDataFrameInput,
-        NodeIndex,
-    };
-    use crate::{
-        errors::GraphRecordError,
-        graphrecord::{
-            SchemaType,
-            datatypes::DataType,
-            schema::{AttributeSchema, GroupSchema, Schema},
-        },
-    };
-    use polars::prelude::{DataFrame, NamedFrom, PolarsError, Series};
-    use std::collections::HashMap;
-    #[cfg(feature = "serde")]
-    use std::fs;
+        if let Some(ref node_indices) = node_indices {
+            for node_index in node_indices {
+                if !self.graph.contains_node(node_index) {
+                    return Err(GraphRecordError::MissingNode {
+                        index: node_index.clone(),
+                    });
+                }
+            }
+        }
 
-    fn create_nodes() -> Vec<(NodeIndex, Attributes)> {
-        vec![
-            (
-                "0".into(),
-                HashMap::from([("lorem".into(), "ipsum".into())]),
-            ),
-            (
-                "1".into(),
-                HashMap::from([("amet".into(), "consectetur".into())]),
-            ),
-            (
-                "2".into(),
-                HashMap::from([("adipiscing".into(), "elit".into())]),
-            ),
-            ("3".into(), HashMap::new()),
-        ]
-    }
+        if let Some(ref edge_indices) = edge_indices {
+            for edge_index in edge_indices {
+                if !self.graph.contains_edge(edge_index) {
+                    return Err(GraphRecordError::IndexError(format!(
+                        "Cannot find edge with index {edge_index}",
+                    )));
+                }
+            }
+        }
 
-    fn create_edges() -> Vec<(NodeIndex, NodeIndex, Attributes)> {
-        vec![
-            (
+        match self.schema.schema_type() {
+            SchemaType::Inferred => {
+                if !self.schema.groups().contains_key(&group) {
+                    self.schema
+                        .add_group(group.clone(), GroupSchema::default())?;
+                }
+
+                if let Some(ref node_indices) = node_indices {
+                    let mut empty = true;
+
+                    for node_index in node_indices {
+                        let node_attributes = self.graph.node_attributes(node_index)?;
+
+                        self.schema
+                            .update_node(node_attributes, Some(&group), empty);
+
+                        empty = false;
+                    }
+                }
+
+                if let Some(ref edge_indices) = edge_indices {
+                    let mut empty = true;
+
+                    for edge_index in edge_indices {
+                        let edge_attributes = self.graph.edge_attributes(edge_index)?;
+
+                        self.schema
+                            .update_edge(edge_attributes, Some(&group), empty);
+
+                        empty = false;
+                    }
+                }
+            }
+            SchemaType::Provided => {
+                if !self.schema.groups().contains_key(&group) {
+                    return Err(GraphRecordError::SchemaError(format!(
+                        "Group {group} is not defined in the schema"
+                    )));
+                }
+
+                if let Some(ref node_indices) = node_indices {
+                    for node_index in node_indices {
+                        let node_attributes = self.graph.node_attributes(node_index)?;
+
+                        self.schema
+                            .validate_node(node_index, node_attributes, Some(&group))?;
+                    }
+                }
+
+                if let Some(ref edge_indices) = edge_indices {
+                    for edge_index in edge_indices {
+                        let edge_attributes = self.graph.edge_attributes(edge_index)?;
+
+                        self.schema
+                            .validate_edge(edge_index, edge_attributes, Some(&group))?;
+                    }
+                }
+            }
+        }
+
+        let group_added = self.subscribers.is_active().then(|| group.clone());
+
+        self.group_mapping
+            .add_group(group, node_indices, edge_indices)
+            .expect("Group must not exist");
+
+        if let Some(group) = group_added {
+            self.subscribers.notify(|| ChangeEvent::GroupAdded {
+                group: group.clone(),
+            });
+
+            for node_index in self.group_mapping.nodes_in_group(&group)? {
+                self.subscribers.notify(|| ChangeEvent::NodeAddedToGroup {
+                    group: group.clone(),
+                    node_index: node_index.clone(),
+                });
+            }
+
+            for edge_index in self.group_mapping.edges_in_group(&group)? {
+                self.subscribers.notify(|| ChangeEvent::EdgeAddedToGroup {
+                    group: group.clone(),
+                    edge_index: *edge_index,
+                });
+            }
+        } else {
+            self.subscribers.record_change();
+        }
+
+        Ok(())
+    }
+
+    fn remove_group_impl(&mut self, group: &Group) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
+        self.group_mapping.remove_group(group)?;
+
+        self.subscribers.notify(|| ChangeEvent::GroupRemoved {
+            group: group.clone(),
+        });
+
+        Ok(())
+    }
+
+    fn add_node_to_group_impl(
+        &mut self,
+        group: Group,
+        node_index: NodeIndex,
+    ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
+        let node_attributes = self.graph.node_attributes(&node_index)?;
+
+        match self.schema.schema_type() {
+            SchemaType::Inferred => {
+                let nodes_in_group = self
+                    .group_mapping
+                    .nodes_in_group
+                    .get(&group)
+                    .map_or(0, GrHashSet::len);
+
+                self.schema
+                    .update_node(node_attributes, Some(&group), nodes_in_group == 0);
+            }
+            SchemaType::Provided => {
+                self.schema
+                    .validate_node(&node_index, node_attributes, Some(&group))?;
+            }
+        }
+
+        let group_added = !self.group_mapping.contains_group(&group);
+
+        self.group_mapping
+            .add_node_to_group(group.clone(), node_index.clone())?;
+
+        if group_added {
+            self.subscribers.notify(|| ChangeEvent::GroupAdded {
+                group: group.clone(),
+            });
+        }
+        self.subscribers
+            .notify(|| ChangeEvent::NodeAddedToGroup { group, node_index });
+
+        Ok(())
+    }
+
+    #[allow(clippy::needless_pass_by_value)]
+    fn add_node_to_groups_impl(
+        &mut self,
+        groups: impl AsRef<[Group]>,
+        node_index: NodeIndex,
+    ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
+        groups
+            .as_ref()
+            .iter()
+            .try_for_each(|group| self.add_node_to_group_impl(group.clone(), node_index.clone()))
+    }
+
+    fn add_nodes_to_groups_impl(
+        &mut self,
+        groups: impl AsRef<[Group]>,
+        node_indices: Vec<NodeIndex>,
+    ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
+        let groups = groups.as_ref();
+
+        node_indices
+            .into_iter()
+            .try_for_each(|node_index| self.add_node_to_groups_impl(groups, node_index))
+    }
+
+    fn add_edge_to_group_impl(
+        &mut self,
+        group: Group,
+        edge_index: EdgeIndex,
+    ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
+        let edge_attributes = self.graph.edge_attributes(&edge_index)?;
+
+        match self.schema.schema_type() {
+            SchemaType::Inferred => {
+                let edges_in_group = self
+                    .group_mapping
+                    .edges_in_group
+                    .get(&group)
+                    .map_or(0, GrHashSet::len);
+
+                self.schema
+                    .update_edge(edge_attributes, Some(&group), edges_in_group == 0);
+            }
+            SchemaType::Provided => {
+                self.schema
+                    .validate_edge(&edge_index, edge_attributes, Some(&group))?;
+            }
+        }
+
+        let group_added = !self.group_mapping.contains_group(&group);
+
+        self.group_mapping
+            .add_edge_to_group(group.clone(), edge_index)?;
+
+        if group_added {
+            self.subscribers.notify(|| ChangeEvent::GroupAdded {
+                group: group.clone(),
+            });
+        }
+        self.subscribers
+            .notify(|| ChangeEvent::EdgeAddedToGroup { group, edge_index });
+
+        Ok(())
+    }
+
+    fn add_edge_to_groups_impl(
+        &mut self,
+        groups: impl AsRef<[Group]>,
+        edge_index: EdgeIndex,
+    ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
+        groups
+            .as_ref()
+            .iter()
+            .try_for_each(|group| self.add_edge_to_group_impl(group.clone(), edge_index))
+    }
+
+    fn add_edges_to_groups_impl(
+        &mut self,
+        groups: impl AsRef<[Group]>,
+        edge_indices: Vec<EdgeIndex>,
+    ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
+        let groups = groups.as_ref();
+
+        edge_indices
+            .into_iter()
+            .try_for_each(|edge_index| self.add_edge_to_groups_impl(groups, edge_index))
+    }
+
+    fn remove_node_from_group_impl(
+        &mut self,
+        group: &Group,
+        node_index: &NodeIndex,
+    ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
+        if !self.graph.contains_node(node_index) {
+            return Err(GraphRecordError::MissingNode {
+                index: node_index.clone(),
+            });
+        }
+
+        self.group_mapping
+            .remove_node_from_group(group, node_index)?;
+
+        self.subscribers
+            .notify(|| ChangeEvent::NodeRemovedFromGroup {
+                group: group.clone(),
+                node_index: node_index.clone(),
+            });
+
+        Ok(())
+    }
+
+    fn remove_node_from_groups_impl(
+        &mut self,
+        groups: impl AsRef<[Group]>,
+        node_index: &NodeIndex,
+    ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
+        groups
+            .as_ref()
+            .iter()
+            .try_for_each(|group| self.remove_node_from_group_impl(group, node_index))
+    }
+
+    fn remove_nodes_from_groups_impl(
+        &mut self,
+        groups: impl AsRef<[Group]>,
+        node_indices: &[NodeIndex],
+    ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
+        let groups = groups.as_ref();
+
+        node_indices
+            .iter()
+            .try_for_each(|node_index| self.remove_node_from_groups_impl(groups, node_index))
+    }
+
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    fn remove_edge_from_group_impl(
+        &mut self,
+        group: &Group,
+        edge_index: &EdgeIndex,
+    ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
+        if !self.graph.contains_edge(edge_index) {
+            return Err(GraphRecordError::IndexError(format!(
+                "Cannot find edge with index {edge_index}",
+            )));
+        }
+
+        self.group_mapping
+            .remove_edge_from_group(group, edge_index)?;
+
+        self.subscribers
+            .notify(|| ChangeEvent::EdgeRemovedFromGroup {
+                group: group.clone(),
+                edge_index: *edge_index,
+            });
+
+        Ok(())
+    }
+
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    fn remove_edge_from_groups_impl(
+        &mut self,
+        groups: impl AsRef<[Group]>,
+        edge_index: &EdgeIndex,
+    ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
+        groups
+            .as_ref()
+            .iter()
+            .try_for_each(|group| self.remove_edge_from_group_impl(group, edge_index))
+    }
+
+    fn remove_edges_from_groups_impl(
+        &mut self,
+        groups: impl AsRef<[Group]>,
+        edge_indices: &[EdgeIndex],
+    ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
+        let groups = groups.as_ref();
+
+        edge_indices
+            .iter()
+            .try_for_each(|edge_index| self.remove_edge_from_groups_impl(groups, edge_index))
+    }
+
+    /// Groups of the record, or of the base record if this record reads
+    /// through an overlay, which does not stage group changes.
+    fn group_mapping(&self) -> &GroupMapping {
+        self.layer
+            .as_ref()
+            .map_or(&self.group_mapping, |layer| layer.group_mapping())
+    }
+
+    pub fn groups(&self) -> impl Iterator<Item = &Group> {
+        self.group_mapping().groups()
+    }
+
+    /// Like [`GraphRecord::groups`], but ordered by
+    /// [`GraphRecordAttribute::total_cmp`] instead of hash order.
+    pub fn groups_sorted(&self) -> impl Iterator<Item = &Group> {
+        let mut groups: Vec<_> = self.groups().collect();
+        groups.sort_unstable_by(|a, b| a.total_cmp(b));
+
+        groups.into_iter()
+    }
+
+    pub fn nodes_in_group(
+        &self,
+        group: &Group,
+    ) -> GraphRecordResult<impl Iterator<Item = &NodeIndex> + use<'_>> {
+        if let Some(layer) = &self.layer {
+            return layer.nodes_in_group(group).map(Either::Left);
+        }
+
+        self.group_mapping.nodes_in_group(group).map(Either::Right)
+    }
+
+    pub fn ungrouped_nodes(&self) -> impl Iterator<Item = &NodeIndex> {
+        let nodes_in_groups: GrHashSet<_> = self
+            .groups()
+            .flat_map(|group| {
+                #[expect(clippy::missing_panics_doc, reason = "infallible")]
+                self.nodes_in_group(group).expect("Group must exist")
+            })
+            .collect();
+
+        self.node_indices()
+            .filter(move |node_index| !nodes_in_groups.contains(*node_index))
+    }
+
+    pub fn edges_in_group(
+        &self,
+        group: &Group,
+    ) -> GraphRecordResult<impl Iterator<Item = &EdgeIndex> + use<'_>> {
+        if let Some(layer) = &self.layer {
+            return layer.edges_in_group(group).map(Either::Left);
+        }
+
+        self.group_mapping.edges_in_group(group).map(Either::Right)
+    }
+
+    pub fn group_metadata(
+        &self,
+        group: &Group,
+    ) -> GraphRecordResult<impl Iterator<Item = (&GraphRecordAttribute, &GraphRecordValue)> + use<'_>>
+    {
+        self.group_mapping().group_metadata(group)
+    }
+
+    /// Sets a metadata entry of a group, e.g. its description or color.
+    ///
+    /// Metadata describes the group itself and is removed along with it.
+    pub fn set_group_metadata(
+        &mut self,
+        group: &Group,
+        key: GraphRecordAttribute,
+        value: GraphRecordValue,
+    ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
+        self.group_mapping.set_group_metadata(group, key, value)?;
+
+        self.subscribers
+            .notify(|| ChangeEvent::GroupMetadataChanged {
+                group: group.clone(),
+            });
+
+        Ok(())
+    }
+
+    pub fn remove_group_metadata(
+        &mut self,
+        group: &Group,
+        key: &GraphRecordAttribute,
+    ) -> GraphRecordResult<GraphRecordValue> {
+        self.assert_not_frozen()?;
+
+        let value = self.group_mapping.remove_group_metadata(group, key)?;
+
+        self.subscribers
+            .notify(|| ChangeEvent::GroupMetadataChanged {
+                group: group.clone(),
+            });
+
+        Ok(value)
+    }
+
+    pub fn ungrouped_edges(&self) -> impl Iterator<Item = &EdgeIndex> {
+        let edges_in_groups: GrHashSet<_> = self
+            .groups()
+            .flat_map(|group| {
+                #[expect(clippy::missing_panics_doc, reason = "infallible")]
+                self.edges_in_group(group).expect("Group must exist")
+            })
+            .collect();
+
+        self.edge_indices()
+            .filter(move |edge_index| !edges_in_groups.contains(*edge_index))
+    }
+
+    pub fn groups_of_node(
+        &self,
+        node_index: &NodeIndex,
+    ) -> GraphRecordResult<impl Iterator<Item = &Group> + use<'_>> {
+        if let Some(layer) = &self.layer {
+            return layer.groups_of_node(node_index).map(Either::Left);
+        }
+
+        if !self.graph.contains_node(node_index) {
+            return Err(GraphRecordError::MissingNode {
+                index: node_index.clone(),
+            });
+        }
+
+        Ok(Either::Right(self.group_mapping.groups_of_node(node_index)))
+    }
+
+    pub fn groups_of_edge(
+        &self,
+        edge_index: &EdgeIndex,
+    ) -> GraphRecordResult<impl Iterator<Item = &Group> + use<'_>> {
+        if let Some(layer) = &self.layer {
+            return layer.groups_of_edge(edge_index).map(Either::Left);
+        }
+
+        if !self.graph.contains_edge(edge_index) {
+            return Err(GraphRecordError::IndexError(format!(
+                "Cannot find edge with index {edge_index}",
+            )));
+        }
+
+        Ok(Either::Right(self.group_mapping.groups_of_edge(edge_index)))
+    }
+
+    #[must_use]
+    pub fn node_count(&self) -> usize {
+        self.layer
+            .as_ref()
+            .map_or_else(|| self.graph.node_count(), |layer| layer.node_count())
+    }
+
+    #[must_use]
+    pub fn edge_count(&self) -> usize {
+        self.layer
+            .as_ref()
+            .map_or_else(|| self.graph.edge_count(), |layer| layer.edge_count())
+    }
+
+    #[must_use]
+    pub fn group_count(&self) -> usize {
+        self.group_mapping().group_count()
+    }
+
+    #[must_use]
+    pub fn memory_usage(&self) -> MemoryUsage {
+        MemoryUsage::new(self)
+    }
+
+    /// Reserves capacity for at least `nodes` more nodes and `edges` more edges
+    /// in `group`, so that adding them in bulk does not reallocate repeatedly.
+    /// [`GraphRecord::with_capacity`] only sizes the nodes and edges of the
+    /// record.
+    pub fn reserve_group(
+        &mut self,
+        group: &Group,
+        nodes: usize,
+        edges: usize,
+    ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
+        self.group_mapping.reserve_group(group, nodes, edges)
+    }
+
+    /// Releases capacity that nodes, edges and groups no longer use, e.g. after
+    /// bulk deletions. The released memory shows up in
+    /// [`GraphRecord::memory_usage`].
+    pub fn shrink_to_fit(&mut self) {
+        self.graph.shrink_to_fit();
+        self.group_mapping.shrink_to_fit();
+    }
+
+    #[must_use]
+    pub fn stats(&self) -> Stats {
+        Stats::new(self)
+    }
+
+    /// Compares the `attributes` of the nodes in `group_a` and `group_b` in a
+    /// baseline characteristics table.
+    pub fn cohort_table(
+        &self,
+        group_a: &Group,
+        group_b: &Group,
+        attributes: &[GraphRecordAttribute],
+    ) -> GraphRecordResult<CohortTable> {
+        CohortTable::new(self, group_a, group_b, attributes)
+    }
+
+    /// Returns the contingency table of `row_attribute` and `column_attribute`
+    /// over the nodes in `group`. Nodes that lack one of the attributes are
+    /// skipped.
+    pub fn crosstab(
+        &self,
+        group: &Group,
+        row_attribute: &GraphRecordAttribute,
+        column_attribute: &GraphRecordAttribute,
+        normalization: Option<CrosstabNormalization>,
+    ) -> GraphRecordResult<Crosstab> {
+        Crosstab::new(self, group, row_attribute, column_attribute, normalization)
+    }
+
+    /// Returns the correlation between the numeric `attribute_a` and
+    /// `attribute_b` over the nodes in `group`. Nodes that lack one of the
+    /// attributes or have a null or missing value of it are skipped, NaN
+    /// values are an error.
+    pub fn correlation(
+        &self,
+        group: &Group,
+        attribute_a: &GraphRecordAttribute,
+        attribute_b: &GraphRecordAttribute,
+        method: CorrelationMethod,
+    ) -> GraphRecordResult<CorrelationTest> {
+        CorrelationTest::new(self, group, attribute_a, attribute_b, method)
+    }
+
+    /// Tests the independence of the categorical `attribute_a` and
+    /// `attribute_b` over the nodes in `group`. Nodes that lack one of the
+    /// attributes or have a null or missing value of it are skipped.
+    pub fn chi_square(
+        &self,
+        group: &Group,
+        attribute_a: &GraphRecordAttribute,
+        attribute_b: &GraphRecordAttribute,
+    ) -> GraphRecordResult<ChiSquareTest> {
+        ChiSquareTest::new(self, group, attribute_a, attribute_b)
+    }
+
+    /// Returns the number of edges between every ordered pair of source and
+    /// target groups. `None` stands for the ungrouped nodes.
+    #[must_use]
+    pub fn group_adjacency_counts(&self) -> HashMap<(Option<Group>, Option<Group>), usize> {
+        stats::group_adjacency_counts(self)
+    }
+
+    /// Returns the time from the `index_date_attribute` of every node in
+    /// `cohort_group` to its first event, or to `censor_date` if it has none.
+    ///
+    /// Events are the edges in `event_edge_group` that touch a node. Their
+    /// `index_date_attribute` holds either the datetime of the event or the
+    /// duration since the index date. Events before the index date or after
+    /// the censor date are ignored.
+    pub fn time_to_event(
+        &self,
+        cohort_group: &Group,
+        index_date_attribute: &GraphRecordAttribute,
+        event_edge_group: &Group,
+        censor_date: NaiveDateTime,
+    ) -> GraphRecordResult<HashMap<NodeIndex, TimeToEvent>> {
+        survival::time_to_event(
+            self,
+            cohort_group,
+            index_date_attribute,
+            event_edge_group,
+            censor_date,
+        )
+    }
+
+    /// Returns for every node in `cohort_group` whether it has an event
+    /// within `[start, end)` relative to its `index_date_attribute`.
+    ///
+    /// Events are read as in [`GraphRecord::time_to_event`]. Nodes with an
+    /// event within the `washout` period right before the window are left out,
+    /// so that only new events count.
+    pub fn events_in_window(
+        &self,
+        cohort_group: &Group,
+        index_date_attribute: &GraphRecordAttribute,
+        event_edge_group: &Group,
+        start: TimeDelta,
+        end: TimeDelta,
+        washout: Option<TimeDelta>,
+    ) -> GraphRecordResult<HashMap<NodeIndex, bool>> {
+        survival::events_in_window(
+            self,
+            cohort_group,
+            index_date_attribute,
+            event_edge_group,
+            start,
+            end,
+            washout,
+        )
+    }
+
+    /// Returns the share of the nodes at risk that have an event within the
+    /// window, as counted by [`GraphRecord::events_in_window`].
+    pub fn event_rate_in_window(
+        &self,
+        cohort_group: &Group,
+        index_date_attribute: &GraphRecordAttribute,
+        event_edge_group: &Group,
+        start: TimeDelta,
+        end: TimeDelta,
+        washout: Option<TimeDelta>,
+    ) -> GraphRecordResult<EventRate> {
+        Ok(EventRate::new(&self.events_in_window(
+            cohort_group,
+            index_date_attribute,
+            event_edge_group,
+            start,
+            end,
+            washout,
+        )?))
+    }
+
+    /// Returns a hash over the nodes, edges, attributes, groups and schema.
+    ///
+    /// The fingerprint does not depend on iteration or insertion order and is
+    /// stable across processes and platforms, so two records can be compared
+    /// without a deep diff. Edge indices are not part of the fingerprint.
+    #[must_use]
+    pub fn fingerprint(&self) -> u64 {
+        fingerprint::fingerprint(self)
+    }
+
+    /// Returns `true` if both records contain the same nodes, edges, attributes
+    /// and groups.
+    ///
+    /// Edges are compared by their endpoints, attributes and groups, so edge
+    /// index numbering is ignored. Schemas are not compared.
+    #[must_use]
+    pub fn equals(&self, other: &Self) -> bool {
+        comparison::equals(self, other)
+    }
+
+    /// Returns `true` if every node, edge and group of this record is also
+    /// part of `other`, with equal attributes.
+    ///
+    /// Edges are matched as in [`GraphRecord::equals`], and each edge of
+    /// `other` can only be matched once. Schemas are not compared.
+    #[must_use]
+    pub fn is_subgraph_of(&self, other: &Self) -> bool {
+        comparison::is_subgraph_of(self, other)
+    }
+
+    #[must_use]
+    pub fn contains_node(&self, node_index: &NodeIndex) -> bool {
+        self.layer.as_ref().map_or_else(
+            || self.graph.contains_node(node_index),
+            |layer| layer.contains_node(node_index),
+        )
+    }
+
+    #[must_use]
+    pub fn contains_edge(&self, edge_index: &EdgeIndex) -> bool {
+        self.layer.as_ref().map_or_else(
+            || self.graph.contains_edge(edge_index),
+            |layer| layer.contains_edge(edge_index),
+        )
+    }
+
+    /// Returns the index as stored by the record, so it lives as long as the
+    /// record.
+    pub(crate) fn node_index_ref(&self, node_index: &NodeIndex) -> Option<&NodeIndex> {
+        if let Some(layer) = &self.layer {
+            return layer.node_index_ref(node_index);
+        }
+
+        self.graph
+            .nodes
+            .get_key_value(node_index)
+            .map(|(node_index, _)| node_index)
+    }
+
+    /// Like [`GraphRecord::node_index_ref`], but for edges.
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub(crate) fn edge_index_ref(&self, edge_index: &EdgeIndex) -> Option<&EdgeIndex> {
+        if let Some(layer) = &self.layer {
+            return layer.edge_index_ref(edge_index);
+        }
+
+        self.graph
+            .edges
+            .get_key_value(edge_index)
+            .map(|(edge_index, _)| edge_index)
+    }
+
+    /// Number of edges starting at the node plus the number of edges ending
+    /// at it. Like the degrees in the stats, a self-loop counts twice.
+    pub(crate) fn degree(&self, node_index: &NodeIndex) -> GraphRecordResult<usize> {
+        if let Some(layer) = &self.layer {
+            return layer.degree(node_index);
+        }
+
+        let node =
+            self.graph
+                .nodes
+                .get(node_index)
+                .ok_or_else(|| GraphRecordError::MissingNode {
+                    index: node_index.clone(),
+                })?;
+
+        Ok(node.outgoing_edge_indices.len() + node.incoming_edge_indices.len())
+    }
+
+    #[must_use]
+    pub fn contains_group(&self, group: &Group) -> bool {
+        self.group_mapping().contains_group(group)
+    }
+
+    pub fn neighbors_outgoing(
+        &self,
+        node_index: &NodeIndex,
+    ) -> GraphRecordResult<impl Iterator<Item = &NodeIndex> + use<'_>> {
+        if let Some(layer) = &self.layer {
+            return layer.neighbors_outgoing(node_index).map(Either::Left);
+        }
+
+        self.graph
+            .neighbors_outgoing(node_index)
+            .map(Either::Right)
+            .map_err(GraphRecordError::from)
+    }
+
+    // TODO: Add tests
+    pub fn neighbors_incoming(
+        &self,
+        node_index: &NodeIndex,
+    ) -> GraphRecordResult<impl Iterator<Item = &NodeIndex> + use<'_>> {
+        if let Some(layer) = &self.layer {
+            return layer.neighbors_incoming(node_index).map(Either::Left);
+        }
+
+        self.graph
+            .neighbors_incoming(node_index)
+            .map(Either::Right)
+            .map_err(GraphRecordError::from)
+    }
+
+    pub fn neighbors_undirected(
+        &self,
+        node_index: &NodeIndex,
+    ) -> GraphRecordResult<impl Iterator<Item = &NodeIndex> + use<'_>> {
+        if let Some(layer) = &self.layer {
+            return layer.neighbors_undirected(node_index).map(Either::Left);
+        }
+
+        self.graph
+            .neighbors_undirected(node_index)
+            .map(Either::Right)
+            .map_err(GraphRecordError::from)
+    }
+
+    /// Returns the neighbors of `node_index` that are reachable via edges in
+    /// `edge_group` (if given) for which `edge_predicate` holds. Like
+    /// [`GraphRecord::neighbors_undirected`], [`EdgeDirection::Both`] yields
+    /// every neighbor only once.
+    #[allow(clippy::needless_pass_by_value)]
+    #[expect(clippy::missing_panics_doc, reason = "infallible")]
+    pub fn neighbors_filtered<P>(
+        &self,
+        node_index: &NodeIndex,
+        direction: EdgeDirection,
+        edge_group: Option<&Group>,
+        edge_predicate: P,
+    ) -> GraphRecordResult<impl Iterator<Item = &NodeIndex> + use<'_, P>>
+    where
+        P: Fn(&EdgeIndex, &AttributeMap) -> bool,
+    {
+        let edges_in_group: Option<GrHashSet<_>> = edge_group
+            .map(|group| self.edges_in_group(group).map(Iterator::collect))
+            .transpose()?;
+
+        let edge_indices: Vec<_> = match direction {
+            EdgeDirection::Outgoing => self.outgoing_edges(node_index)?.collect(),
+            EdgeDirection::Incoming => self.incoming_edges(node_index)?.collect(),
+            EdgeDirection::Both => self
+                .outgoing_edges(node_index)?
+                .chain(self.incoming_edges(node_index)?)
+                .collect(),
+        };
+
+        let neighbors = edge_indices
+            .into_iter()
+            .filter(|edge_index| {
+                edges_in_group
+                    .as_ref()
+                    .is_none_or(|edges| edges.contains(edge_index))
+            })
+            .filter(|&edge_index| {
+                edge_predicate(
+                    edge_index,
+                    self.edge_attributes(edge_index).expect("Edge must exist"),
+                )
+            })
+            .map(|edge_index| {
+                let (source_node_index, target_node_index) =
+                    self.edge_endpoints(edge_index).expect("Edge must exist");
+
+                if source_node_index == node_index {
+                    target_node_index
+                } else {
+                    source_node_index
+                }
+            });
+
+        let mut seen = GrHashSet::default();
+
+        Ok(neighbors
+            .filter(|neighbor| !matches!(direction, EdgeDirection::Both) || seen.insert(*neighbor))
+            .collect::<Vec<_>>()
+            .into_iter())
+    }
+
+    fn clear_impl(&mut self) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
+        self.graph.clear();
+        self.group_mapping.clear();
+
+        if let Some(provenance) = &mut self.provenance {
+            provenance.clear();
+        }
+        self.valid_time.clear();
+
+        self.subscribers.notify(|| ChangeEvent::Cleared);
+
+        Ok(())
+    }
+
+    pub fn query_nodes<'a, Q, R>(&'a self, query: Q) -> Selection<'a, R>
+    where
+        Q: FnOnce(&Wrapper<NodeOperand>) -> R,
+        R: ReturnOperand<'a>,
+    {
+        Selection::new_node(self, query)
+    }
+
+    pub fn query_edges<'a, Q, R>(&'a self, query: Q) -> Selection<'a, R>
+    where
+        Q: FnOnce(&Wrapper<EdgeOperand>) -> R,
+        R: ReturnOperand<'a>,
+    {
+        Selection::new_edge(self, query)
+    }
+
+    /// Returns a view of the nodes of `first` and `second` and the edges
+    /// between them.
+    pub fn group_pair_view(
+        &self,
+        first: Group,
+        second: Group,
+    ) -> GraphRecordResult<GroupPairView<'_>> {
+        GroupPairView::new(self, first, second)
+    }
+
+    /// Copies the record into a petgraph [`DiGraph`](::petgraph::graph::DiGraph),
+    /// converting the `weight_attribute` of every edge into `W`.
+    ///
+    /// Fails if an edge has no `weight_attribute` or its value cannot be
+    /// converted.
+    #[cfg(feature = "petgraph")]
+    pub fn as_petgraph<W>(
+        &self,
+        weight_attribute: &GraphRecordAttribute,
+    ) -> GraphRecordResult<petgraph::PetgraphExport<W>>
+    where
+        W: TryFrom<GraphRecordValue>,
+        W::Error: Display,
+    {
+        petgraph::PetgraphExport::new(self, weight_attribute)
+    }
+
+    /// Returns a view exposing the attribute values valid at `time`.
+    #[must_use]
+    pub const fn as_of(&self, time: NaiveDateTime) -> AsOfView<'_> {
+        AsOfView::new(self, time)
+    }
+
+    /// Returns a view of the nodes and edges whose `attribute` lies within
+    /// `[start, end)`.
+    #[must_use]
+    pub const fn time_window_view(
+        &self,
+        attribute: GraphRecordAttribute,
+        start: GraphRecordValue,
+        end: GraphRecordValue,
+    ) -> TimeWindowView<'_> {
+        TimeWindowView::new(self, attribute, start, end)
+    }
+
+    /// Categorical attributes with more distinct values than
+    /// `categorical_threshold` only list their `categorical_threshold` most
+    /// frequent values and the count of all other values. All distinct values
+    /// are listed if the threshold is `None`.
+    pub fn overview(
+        &self,
+        truncate_details: Option<usize>,
+        format: OverviewFormat,
+        categorical_threshold: Option<usize>,
+    ) -> GraphRecordResult<Overview> {
+        Overview::new(self, truncate_details, format, None, categorical_threshold)
+    }
+
+    /// Like [`GraphRecord::overview`], but estimates the attribute statistics
+    /// of every group from a sample of at most `sample_size` nodes and edges.
+    /// Sampling is reproducible for a given `seed`.
+    pub fn overview_sampled(
+        &self,
+        sample_size: usize,
+        seed: u64,
+        truncate_details: Option<usize>,
+        format: OverviewFormat,
+        categorical_threshold: Option<usize>,
+    ) -> GraphRecordResult<Overview> {
+        Overview::new(
+            self,
+            truncate_details,
+            format,
+            Some(OverviewSample { sample_size, seed }),
+            categorical_threshold,
+        )
+    }
+
+    /// Like [`GraphRecord::overview`], but only for the nodes and edges of
+    /// `group`.
+    pub fn group_overview(
+        &self,
+        group: &Group,
+        truncate_details: Option<usize>,
+        format: OverviewFormat,
+        categorical_threshold: Option<usize>,
+    ) -> GraphRecordResult<GroupOverview> {
+        GroupOverview::new(
+            self,
+            Some(group),
+            truncate_details,
+            format,
+            None,
+            categorical_threshold,
+        )
+    }
+}
+
+#[cfg(not(feature = "plugins"))]
+impl GraphRecord {
+    pub fn set_schema(&mut self, schema: Schema) -> GraphRecordResult<()> {
+        self.set_schema_impl(schema)
+    }
+
+    pub fn freeze_schema(&mut self) -> GraphRecordResult<()> {
+        self.freeze_schema_impl()
+    }
+
+    pub fn unfreeze_schema(&mut self) -> GraphRecordResult<()> {
+        self.unfreeze_schema_impl()
+    }
+
+    pub fn add_node(
+        &mut self,
+        node_index: NodeIndex,
+        attributes: Attributes,
+    ) -> GraphRecordResult<()> {
+        self.add_node_impl(node_index, attributes)
+    }
+
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn add_node_with_group(
+        &mut self,
+        node_index: NodeIndex,
+        attributes: Attributes,
+        group: Group,
+    ) -> GraphRecordResult<()> {
+        self.add_node_with_group_impl(node_index, attributes, group)
+    }
+
+    pub fn add_node_with_groups(
+        &mut self,
+        node_index: NodeIndex,
+        attributes: Attributes,
+        groups: impl AsRef<[Group]>,
+    ) -> GraphRecordResult<()> {
+        self.add_node_with_groups_impl(node_index, attributes, groups)
+    }
+
+    pub fn remove_node(&mut self, node_index: &NodeIndex) -> GraphRecordResult<Attributes> {
+        self.remove_node_impl(node_index)
+    }
+
+    pub fn add_nodes(&mut self, nodes: Vec<(NodeIndex, Attributes)>) -> GraphRecordResult<()> {
+        self.add_nodes_impl(nodes)
+    }
+
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn add_nodes_with_group(
+        &mut self,
+        nodes: Vec<(NodeIndex, Attributes)>,
+        group: Group,
+    ) -> GraphRecordResult<()> {
+        self.add_nodes_with_group_impl(nodes, group)
+    }
+
+    pub fn add_nodes_with_groups(
+        &mut self,
+        nodes: Vec<(NodeIndex, Attributes)>,
+        groups: impl AsRef<[Group]>,
+    ) -> GraphRecordResult<()> {
+        self.add_nodes_with_groups_impl(nodes, groups)
+    }
+
+    pub fn add_nodes_dataframes(
+        &mut self,
+        nodes_dataframes: impl IntoIterator<Item = impl Into<NodeDataFrameInput>>,
+    ) -> GraphRecordResult<()> {
+        self.add_nodes_dataframes_impl(nodes_dataframes)
+    }
+
+    pub fn add_nodes_dataframes_with_group(
+        &mut self,
+        nodes_dataframes: impl IntoIterator<Item = impl Into<NodeDataFrameInput>>,
+        group: Group,
+    ) -> GraphRecordResult<()> {
+        self.add_nodes_dataframes_with_group_impl(nodes_dataframes, group)
+    }
+
+    pub fn add_nodes_dataframes_with_groups(
+        &mut self,
+        nodes_dataframes: impl IntoIterator<Item = impl Into<NodeDataFrameInput>>,
+        groups: impl AsRef<[Group]>,
+    ) -> GraphRecordResult<()> {
+        self.add_nodes_dataframes_with_groups_impl(nodes_dataframes, groups)
+    }
+
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn add_edge(
+        &mut self,
+        source_node_index: NodeIndex,
+        target_node_index: NodeIndex,
+        attributes: Attributes,
+    ) -> GraphRecordResult<EdgeIndex> {
+        self.add_edge_impl(source_node_index, target_node_index, attributes)
+    }
+
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn add_edge_with_group(
+        &mut self,
+        source_node_index: NodeIndex,
+        target_node_index: NodeIndex,
+        attributes: Attributes,
+        group: Group,
+    ) -> GraphRecordResult<EdgeIndex> {
+        self.add_edge_with_group_impl(source_node_index, target_node_index, attributes, group)
+    }
+
+    pub fn add_edge_with_groups(
+        &mut self,
+        source_node_index: NodeIndex,
+        target_node_index: NodeIndex,
+        attributes: Attributes,
+        groups: impl AsRef<[Group]>,
+    ) -> GraphRecordResult<EdgeIndex> {
+        self.add_edge_with_groups_impl(source_node_index, target_node_index, attributes, groups)
+    }
+
+    pub fn remove_edge(&mut self, edge_index: &EdgeIndex) -> GraphRecordResult<Attributes> {
+        self.remove_edge_impl(edge_index)
+    }
+
+    pub fn add_edges(
+        &mut self,
+        edges: Vec<(NodeIndex, NodeIndex, Attributes)>,
+    ) -> GraphRecordResult<Vec<EdgeIndex>> {
+        self.add_edges_impl(edges)
+    }
+
+    pub fn add_edges_with_group(
+        &mut self,
+        edges: Vec<(NodeIndex, NodeIndex, Attributes)>,
+        group: &Group,
+    ) -> GraphRecordResult<Vec<EdgeIndex>> {
+        self.add_edges_with_group_impl(edges, group)
+    }
+
+    pub fn add_edges_with_groups(
+        &mut self,
+        edges: Vec<(NodeIndex, NodeIndex, Attributes)>,
+        groups: impl AsRef<[Group]>,
+    ) -> GraphRecordResult<Vec<EdgeIndex>> {
+        self.add_edges_with_groups_impl(edges, groups)
+    }
+
+    pub fn add_edges_dataframes(
+        &mut self,
+        edges_dataframes: impl IntoIterator<Item = impl Into<EdgeDataFrameInput>>,
+    ) -> GraphRecordResult<Vec<EdgeIndex>> {
+        self.add_edges_dataframes_impl(edges_dataframes)
+    }
+
+    pub fn add_edges_dataframes_with_group(
+        &mut self,
+        edges_dataframes: impl IntoIterator<Item = impl Into<EdgeDataFrameInput>>,
+        group: &Group,
+    ) -> GraphRecordResult<Vec<EdgeIndex>> {
+        self.add_edges_dataframes_with_group_impl(edges_dataframes, group)
+    }
+
+    pub fn add_edges_dataframes_with_groups(
+        &mut self,
+        edges_dataframes: impl IntoIterator<Item = impl Into<EdgeDataFrameInput>>,
+        groups: impl AsRef<[Group]>,
+    ) -> GraphRecordResult<Vec<EdgeIndex>> {
+        self.add_edges_dataframes_with_groups_impl(edges_dataframes, groups)
+    }
+
+    pub fn add_group(
+        &mut self,
+        group: Group,
+        node_indices: Option<Vec<NodeIndex>>,
+        edge_indices: Option<Vec<EdgeIndex>>,
+    ) -> GraphRecordResult<()> {
+        self.add_group_impl(group, node_indices, edge_indices)
+    }
+
+    pub fn remove_group(&mut self, group: &Group) -> GraphRecordResult<()> {
+        self.remove_group_impl(group)
+    }
+
+    pub fn add_node_to_group(
+        &mut self,
+        group: Group,
+        node_index: NodeIndex,
+    ) -> GraphRecordResult<()> {
+        self.add_node_to_group_impl(group, node_index)
+    }
+
+    pub fn add_node_to_groups(
+        &mut self,
+        groups: impl AsRef<[Group]>,
+        node_index: NodeIndex,
+    ) -> GraphRecordResult<()> {
+        self.add_node_to_groups_impl(groups, node_index)
+    }
+
+    pub fn add_nodes_to_groups(
+        &mut self,
+        groups: impl AsRef<[Group]>,
+        node_indices: Vec<NodeIndex>,
+    ) -> GraphRecordResult<()> {
+        self.add_nodes_to_groups_impl(groups, node_indices)
+    }
+
+    pub fn add_edge_to_group(
+        &mut self,
+        group: Group,
+        edge_index: EdgeIndex,
+    ) -> GraphRecordResult<()> {
+        self.add_edge_to_group_impl(group, edge_index)
+    }
+
+    pub fn add_edge_to_groups(
+        &mut self,
+        groups: impl AsRef<[Group]>,
+        edge_index: EdgeIndex,
+    ) -> GraphRecordResult<()> {
+        self.add_edge_to_groups_impl(groups, edge_index)
+    }
+
+    pub fn add_edges_to_groups(
+        &mut self,
+        groups: impl AsRef<[Group]>,
+        edge_indices: Vec<EdgeIndex>,
+    ) -> GraphRecordResult<()> {
+        self.add_edges_to_groups_impl(groups, edge_indices)
+    }
+
+    pub fn remove_node_from_group(
+        &mut self,
+        group: &Group,
+        node_index: &NodeIndex,
+    ) -> GraphRecordResult<()> {
+        self.remove_node_from_group_impl(group, node_index)
+    }
+
+    pub fn remove_node_from_groups(
+        &mut self,
+        groups: impl AsRef<[Group]>,
+        node_index: &NodeIndex,
+    ) -> GraphRecordResult<()> {
+        self.remove_node_from_groups_impl(groups, node_index)
+    }
+
+    pub fn remove_nodes_from_groups(
+        &mut self,
+        groups: impl AsRef<[Group]>,
+        node_indices: &[NodeIndex],
+    ) -> GraphRecordResult<()> {
+        self.remove_nodes_from_groups_impl(groups, node_indices)
+    }
+
+    pub fn remove_edge_from_group(
+        &mut self,
+        group: &Group,
+        edge_index: &EdgeIndex,
+    ) -> GraphRecordResult<()> {
+        self.remove_edge_from_group_impl(group, edge_index)
+    }
+
+    pub fn remove_edge_from_groups(
+        &mut self,
+        groups: impl AsRef<[Group]>,
+        edge_index: &EdgeIndex,
+    ) -> GraphRecordResult<()> {
+        self.remove_edge_from_groups_impl(groups, edge_index)
+    }
+
+    pub fn remove_edges_from_groups(
+        &mut self,
+        groups: impl AsRef<[Group]>,
+        edge_indices: &[EdgeIndex],
+    ) -> GraphRecordResult<()> {
+        self.remove_edges_from_groups_impl(groups, edge_indices)
+    }
+
+    pub fn clear(&mut self) -> GraphRecordResult<()> {
+        self.clear_impl()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        Attributes, DataFramesExportOptions, EdgeDataFrameInput, GraphRecord, GraphRecordAttribute,
+        GraphRecordValue, Group, MissingNodePolicy, NodeDataFrameInput, NodeIndex, NullHandling,
+    };
+    use crate::{
+        errors::GraphRecordError,
+        graphrecord::{
+            SchemaType,
+            datatypes::DataType,
+            overview::{AttributeOverviewData, OverviewFormat, StructureOverview},
+            querying::nodes::EdgeDirection,
+            schema::{AttributeSchema, GroupSchema, NonFiniteFloatPolicy, Schema},
+            stats::{DegreeSummary, GroupStats},
+        },
+    };
+    use polars::prelude::{DataFrame, NamedFrom, PolarsError, Series};
+    use std::collections::HashMap;
+    #[cfg(feature = "serde")]
+    use std::fs;
+
+    fn create_nodes() -> Vec<(NodeIndex, Attributes)> {
+        vec![
+            (
+                "0".into(),
+                HashMap::from([("lorem".into(), "ipsum".into())]),
+            ),
+            (
+                "1".into(),
+                HashMap::from([("amet".into(), "consectetur".into())]),
+            ),
+            (
+                "2".into(),
+                HashMap::from([("adipiscing".into(), "elit".into())]),
+            ),
+            ("3".into(), HashMap::new()),
+        ]
+    }
+
+    fn create_edges() -> Vec<(NodeIndex, NodeIndex, Attributes)> {
+        vec![
+            (
                 "0".into(),
                 "1".into(),
                 HashMap::from([
@@ -1745,1114 +3266,1740 @@ mod test {
             ),
             (
                 "1".into(),
-                "2".into(),
-                HashMap::from([("incididunt".into(), "ut".into())]),
-            ),
-            ("0".into(), "2".into(), HashMap::new()),
-        ]
+                "2".into(),
+                HashMap::from([("incididunt".into(), "ut".into())]),
+            ),
+            ("0".into(), "2".into(), HashMap::new()),
+        ]
+    }
+
+    fn create_nodes_dataframe() -> Result<DataFrame, PolarsError> {
+        let s0 = Series::new("index".into(), &["0", "1"]);
+        let s1 = Series::new("attribute".into(), &[1, 2]);
+        DataFrame::new(2, vec![s0.into(), s1.into()])
+    }
+
+    fn create_edges_dataframe() -> Result<DataFrame, PolarsError> {
+        let s0 = Series::new("from".into(), &["0", "1"]);
+        let s1 = Series::new("to".into(), &["1", "0"]);
+        let s2 = Series::new("attribute".into(), &[1, 2]);
+        DataFrame::new(2, vec![s0.into(), s1.into(), s2.into()])
+    }
+
+    fn create_graphrecord() -> GraphRecord {
+        let nodes = create_nodes();
+        let edges = create_edges();
+
+        GraphRecord::from_tuples(nodes, Some(edges), None).unwrap()
+    }
+
+    #[test]
+    fn test_from_tuples() {
+        let graphrecord = create_graphrecord();
+
+        assert_eq!(4, graphrecord.node_count());
+        assert_eq!(4, graphrecord.edge_count());
+    }
+
+    #[test]
+    fn test_invalid_from_tuples() {
+        let nodes = create_nodes();
+
+        // Adding an edge pointing to a non-existing node should fail
+        assert!(
+            GraphRecord::from_tuples(
+                nodes.clone(),
+                Some(vec![("0".into(), "50".into(), HashMap::new())]),
+                None
+            )
+            .is_err_and(|e| matches!(e, GraphRecordError::MissingNode { .. }))
+        );
+
+        // Adding an edge from a non-existing should fail
+        assert!(
+            GraphRecord::from_tuples(
+                nodes,
+                Some(vec![("50".into(), "0".into(), HashMap::new())]),
+                None
+            )
+            .is_err_and(|e| matches!(e, GraphRecordError::MissingNode { .. }))
+        );
+    }
+
+    #[test]
+    fn test_from_dataframes() {
+        let nodes_dataframe = create_nodes_dataframe().unwrap();
+        let edges_dataframe = create_edges_dataframe().unwrap();
+
+        let graphrecord = GraphRecord::from_dataframes(
+            vec![(nodes_dataframe, "index".to_string())],
+            vec![(edges_dataframe, "from".to_string(), "to".to_string())],
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(2, graphrecord.node_count());
+        assert_eq!(2, graphrecord.edge_count());
+    }
+
+    #[test]
+    fn test_from_nodes_dataframes() {
+        let nodes_dataframe = create_nodes_dataframe().unwrap();
+
+        let graphrecord =
+            GraphRecord::from_nodes_dataframes(vec![(nodes_dataframe, "index".to_string())], None)
+                .unwrap();
+
+        assert_eq!(2, graphrecord.node_count());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_ron() {
+        let graphrecord = create_graphrecord();
+
+        let mut file_path = std::env::temp_dir().into_os_string();
+        file_path.push("/graphrecord_test/");
+
+        fs::create_dir_all(&file_path).unwrap();
+
+        file_path.push("test.ron");
+
+        graphrecord.to_ron(&file_path).unwrap();
+
+        let loaded_graphrecord = GraphRecord::from_ron(&file_path).unwrap();
+
+        assert_eq!(graphrecord.node_count(), loaded_graphrecord.node_count());
+        assert_eq!(graphrecord.edge_count(), loaded_graphrecord.edge_count());
+    }
+
+    #[test]
+    fn test_set_schema() {
+        let mut graphrecord = GraphRecord::new();
+
+        let group_schema = GroupSchema::new(
+            AttributeSchema::from([("attribute".into(), DataType::Int.into())]),
+            AttributeSchema::from([("attribute".into(), DataType::Int.into())]),
+        );
+
+        graphrecord
+            .add_node("0".into(), HashMap::from([("attribute".into(), 1.into())]))
+            .unwrap();
+        graphrecord
+            .add_node("1".into(), HashMap::from([("attribute".into(), 1.into())]))
+            .unwrap();
+        graphrecord
+            .add_edge(
+                "0".into(),
+                "1".into(),
+                HashMap::from([("attribute".into(), 1.into())]),
+            )
+            .unwrap();
+
+        let schema = Schema::new_provided(HashMap::default(), group_schema.clone());
+
+        assert!(graphrecord.set_schema(schema.clone()).is_ok());
+
+        assert_eq!(schema, *graphrecord.get_schema());
+
+        let mut graphrecord = GraphRecord::new();
+
+        graphrecord
+            .add_node("0".into(), HashMap::from([("attribute".into(), 1.into())]))
+            .unwrap();
+        graphrecord
+            .add_node("1".into(), HashMap::from([("attribute".into(), 1.into())]))
+            .unwrap();
+        graphrecord
+            .add_node("2".into(), HashMap::from([("attribute".into(), 1.into())]))
+            .unwrap();
+        graphrecord
+            .add_edge(
+                "0".into(),
+                "1".into(),
+                HashMap::from([("attribute".into(), 1.into())]),
+            )
+            .unwrap();
+        graphrecord
+            .add_edge(
+                "0".into(),
+                "1".into(),
+                HashMap::from([("attribute".into(), 1.into())]),
+            )
+            .unwrap();
+        graphrecord
+            .add_edge(
+                "0".into(),
+                "1".into(),
+                HashMap::from([("attribute".into(), 1.into())]),
+            )
+            .unwrap();
+
+        let schema = Schema::new_inferred(
+            HashMap::from([
+                ("0".into(), group_schema.clone()),
+                ("1".into(), group_schema.clone()),
+            ]),
+            group_schema,
+        );
+
+        graphrecord
+            .add_group(
+                "0".into(),
+                Some(vec!["0".into(), "1".into()]),
+                Some(vec![0, 1]),
+            )
+            .unwrap();
+        graphrecord
+            .add_group(
+                "1".into(),
+                Some(vec!["0".into(), "1".into()]),
+                Some(vec![0, 1]),
+            )
+            .unwrap();
+
+        let inferred_schema = Schema::new_inferred(HashMap::default(), GroupSchema::default());
+
+        assert!(graphrecord.set_schema(inferred_schema).is_ok());
+
+        assert_eq!(schema, *graphrecord.get_schema());
+    }
+
+    #[test]
+    fn test_invalid_set_schema() {
+        let mut graphrecord = GraphRecord::new();
+
+        graphrecord
+            .add_node("0".into(), HashMap::from([("attribute2".into(), 1.into())]))
+            .unwrap();
+
+        let schema = Schema::new_provided(
+            HashMap::default(),
+            GroupSchema::new(
+                AttributeSchema::from([("attribute".into(), DataType::Int.into())]),
+                AttributeSchema::from([("attribute".into(), DataType::Int.into())]),
+            ),
+        );
+
+        let previous_schema = graphrecord.get_schema().clone();
+
+        assert!(
+            graphrecord
+                .set_schema(schema.clone())
+                .is_err_and(|e| { matches!(e, GraphRecordError::SchemaError(_)) })
+        );
+
+        assert_eq!(previous_schema, *graphrecord.get_schema());
+
+        let mut graphrecord = GraphRecord::new();
+
+        graphrecord
+            .add_node("0".into(), HashMap::from([("attribute".into(), 1.into())]))
+            .unwrap();
+        graphrecord
+            .add_node("1".into(), HashMap::from([("attribute".into(), 1.into())]))
+            .unwrap();
+        graphrecord
+            .add_edge(
+                "0".into(),
+                "1".into(),
+                HashMap::from([("attribute2".into(), 1.into())]),
+            )
+            .unwrap();
+
+        let previous_schema = graphrecord.get_schema().clone();
+
+        assert!(
+            graphrecord
+                .set_schema(schema)
+                .is_err_and(|e| { matches!(e, GraphRecordError::SchemaError(_)) })
+        );
+
+        assert_eq!(previous_schema, *graphrecord.get_schema());
     }
 
-    fn create_nodes_dataframe() -> Result<DataFrame, PolarsError> {
-        let s0 = Series::new("index".into(), &["0", "1"]);
-        let s1 = Series::new("attribute".into(), &[1, 2]);
-        DataFrame::new(2, vec![s0.into(), s1.into()])
+    #[test]
+    fn test_freeze_schema() {
+        let mut graphrecord = GraphRecord::new();
+
+        assert_eq!(
+            SchemaType::Inferred,
+            *graphrecord.get_schema().schema_type()
+        );
+
+        graphrecord.freeze_schema().unwrap();
+
+        assert_eq!(
+            SchemaType::Provided,
+            *graphrecord.get_schema().schema_type()
+        );
     }
 
-    fn create_edges_dataframe() -> Result<DataFrame, PolarsError> {
-        let s0 = Series::new("from".into(), &["0", "1"]);
-        let s1 = Series::new("to".into(), &["1", "0"]);
-        let s2 = Series::new("attribute".into(), &[1, 2]);
-        DataFrame::new(2, vec![s0.into(), s1.into(), s2.into()])
+    #[test]
+    fn test_unfreeze_schema() {
+        let schema = Schema::new_provided(HashMap::default(), GroupSchema::default());
+        let mut graphrecord = GraphRecord::with_schema(schema);
+
+        assert_eq!(
+            *graphrecord.get_schema().schema_type(),
+            SchemaType::Provided
+        );
+
+        graphrecord.unfreeze_schema().unwrap();
+
+        assert_eq!(
+            *graphrecord.get_schema().schema_type(),
+            SchemaType::Inferred
+        );
     }
 
-    fn create_graphrecord() -> GraphRecord {
-        let nodes = create_nodes();
-        let edges = create_edges();
+    #[test]
+    fn test_freeze() {
+        let mut graphrecord = create_graphrecord();
 
-        GraphRecord::from_tuples(nodes, Some(edges), None).unwrap()
+        assert!(!graphrecord.is_frozen());
+
+        graphrecord.freeze();
+
+        assert!(graphrecord.is_frozen());
+        assert!(graphrecord.clone().is_frozen());
+
+        graphrecord.unfreeze();
+
+        assert!(!graphrecord.is_frozen());
+
+        graphrecord.add_node("4".into(), HashMap::new()).unwrap();
+
+        assert_eq!(5, graphrecord.node_count());
     }
 
     #[test]
-    fn test_from_tuples() {
+    fn test_invalid_freeze() {
+        let mut graphrecord = create_graphrecord();
+
+        graphrecord.freeze();
+
+        // Mutating a frozen GraphRecord should fail
+        assert!(
+            graphrecord
+                .add_node("4".into(), HashMap::new())
+                .is_err_and(|e| matches!(e, GraphRecordError::ReadOnlyError(_)))
+        );
+        assert!(
+            graphrecord
+                .add_edge("0".into(), "3".into(), HashMap::new())
+                .is_err_and(|e| matches!(e, GraphRecordError::ReadOnlyError(_)))
+        );
+        assert!(
+            graphrecord
+                .remove_node(&"0".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::ReadOnlyError(_)))
+        );
+        assert!(
+            graphrecord
+                .add_group("0".into(), None, None)
+                .is_err_and(|e| matches!(e, GraphRecordError::ReadOnlyError(_)))
+        );
+        assert!(
+            graphrecord
+                .node_attributes_mut(&"0".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::ReadOnlyError(_)))
+        );
+        assert!(
+            graphrecord
+                .freeze_schema()
+                .is_err_and(|e| matches!(e, GraphRecordError::ReadOnlyError(_)))
+        );
+        assert!(
+            graphrecord
+                .clear()
+                .is_err_and(|e| matches!(e, GraphRecordError::ReadOnlyError(_)))
+        );
+
+        assert_eq!(4, graphrecord.node_count());
+        assert_eq!(4, graphrecord.edge_count());
+
+        // Settings of how the data is stored should not change either
+        assert!(
+            graphrecord
+                .enable_provenance()
+                .is_err_and(|e| matches!(e, GraphRecordError::ReadOnlyError(_)))
+        );
+        assert!(
+            graphrecord
+                .set_recycle_edge_indices(true)
+                .is_err_and(|e| matches!(e, GraphRecordError::ReadOnlyError(_)))
+        );
+
+        assert!(!graphrecord.provenance_enabled());
+        assert!(!graphrecord.recycle_edge_indices());
+
+        // Settings that only affect reading can still be changed
+        graphrecord.set_strict_queries(true);
+        graphrecord.set_float_tolerance(1e-6).unwrap();
+        graphrecord.set_random_seed(Some(0));
+        graphrecord.set_operation_context(HashMap::from([("user".into(), "lorem".into())]));
+
+        assert!(graphrecord.strict_queries());
+        assert_eq!(Some(0), graphrecord.random_seed());
+        assert_eq!(1, graphrecord.operation_context().len());
+    }
+
+    #[test]
+    fn test_node_indices() {
+        let graphrecord = create_graphrecord();
+
+        let node_indices: Vec<_> = create_nodes()
+            .into_iter()
+            .map(|(node_index, _)| node_index)
+            .collect();
+
+        for node_index in graphrecord.node_indices() {
+            assert!(node_indices.contains(node_index));
+        }
+    }
+
+    #[test]
+    fn test_node_attributes() {
+        let graphrecord = create_graphrecord();
+
+        let attributes = graphrecord.node_attributes(&"0".into()).unwrap();
+
+        assert_eq!(&create_nodes()[0].1, attributes);
+    }
+
+    #[test]
+    fn test_invalid_node_attributes() {
+        let graphrecord = create_graphrecord();
+
+        // Querying a non-existing node should fail
+        assert!(
+            graphrecord
+                .node_attributes(&"50".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::MissingNode { .. }))
+        );
+    }
+
+    #[test]
+    fn test_node_attributes_mut() {
+        let mut graphrecord = create_graphrecord();
+
+        let node_index = "0".into();
+        let mut attributes = graphrecord.node_attributes_mut(&node_index).unwrap();
+
+        let new_attributes = HashMap::from([("0".into(), "1".into()), ("2".into(), "3".into())]);
+
+        attributes
+            .replace_attributes(new_attributes.clone())
+            .unwrap();
+
+        assert_eq!(
+            &new_attributes,
+            graphrecord.node_attributes(&node_index).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_invalid_node_attributes_mut() {
+        let mut graphrecord = create_graphrecord();
+
+        // Accessing the node attributes of a non-existing node should fail
+        assert!(
+            graphrecord
+                .node_attributes_mut(&"50".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+        );
+    }
+
+    #[test]
+    fn test_outgoing_edges() {
+        let graphrecord = create_graphrecord();
+
+        let edges = graphrecord.outgoing_edges(&"0".into()).unwrap();
+
+        assert_eq!(2, edges.count());
+    }
+
+    #[test]
+    fn test_invalid_outgoing_edges() {
         let graphrecord = create_graphrecord();
 
-        assert_eq!(4, graphrecord.node_count());
-        assert_eq!(4, graphrecord.edge_count());
+        assert!(
+            graphrecord
+                .outgoing_edges(&"50".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::MissingNode { .. }))
+        );
+    }
+
+    #[test]
+    fn test_incoming_edges() {
+        let graphrecord = create_graphrecord();
+
+        let edges = graphrecord.incoming_edges(&"2".into()).unwrap();
+
+        assert_eq!(2, edges.count());
+    }
+
+    #[test]
+    fn test_invalid_incoming_edges() {
+        let graphrecord = create_graphrecord();
+
+        assert!(
+            graphrecord
+                .incoming_edges(&"50".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::MissingNode { .. }))
+        );
+    }
+
+    #[test]
+    fn test_edge_indices() {
+        let graphrecord = create_graphrecord();
+        let edges = [0, 1, 2, 3];
+
+        for edge in graphrecord.edge_indices() {
+            assert!(edges.contains(edge));
+        }
+    }
+
+    #[test]
+    fn test_edge_attributes() {
+        let graphrecord = create_graphrecord();
+
+        let attributes = graphrecord.edge_attributes(&0).unwrap();
+
+        assert_eq!(&create_edges()[0].2, attributes);
+    }
+
+    #[test]
+    fn test_invalid_edge_attributes() {
+        let graphrecord = create_graphrecord();
+
+        // Querying a non-existing node should fail
+        assert!(
+            graphrecord
+                .edge_attributes(&50)
+                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+        );
+    }
+
+    #[test]
+    fn test_edge_attributes_mut() {
+        let mut graphrecord = create_graphrecord();
+
+        let mut attributes = graphrecord.edge_attributes_mut(&0).unwrap();
+
+        let new_attributes = HashMap::from([("0".into(), "1".into()), ("2".into(), "3".into())]);
+
+        attributes
+            .replace_attributes(new_attributes.clone())
+            .unwrap();
+
+        assert_eq!(&new_attributes, graphrecord.edge_attributes(&0).unwrap());
     }
 
     #[test]
-    fn test_invalid_from_tuples() {
-        let nodes = create_nodes();
-
-        // Adding an edge pointing to a non-existing node should fail
-        assert!(
-            GraphRecord::from_tuples(
-                nodes.clone(),
-                Some(vec![("0".into(), "50".into(), HashMap::new())]),
-                None
-            )
-            .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
-        );
+    fn test_invalid_edge_attributes_mut() {
+        let mut graphrecord = create_graphrecord();
 
-        // Adding an edge from a non-existing should fail
+        // Accessing the edge attributes of a non-existing edge should fail
         assert!(
-            GraphRecord::from_tuples(
-                nodes,
-                Some(vec![("50".into(), "0".into(), HashMap::new())]),
-                None
-            )
-            .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+            graphrecord
+                .edge_attributes_mut(&50)
+                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
         );
     }
 
     #[test]
-    fn test_from_dataframes() {
-        let nodes_dataframe = create_nodes_dataframe().unwrap();
-        let edges_dataframe = create_edges_dataframe().unwrap();
+    fn test_edge_endpoints() {
+        let graphrecord = create_graphrecord();
 
-        let graphrecord = GraphRecord::from_dataframes(
-            vec![(nodes_dataframe, "index".to_string())],
-            vec![(edges_dataframe, "from".to_string(), "to".to_string())],
-            None,
-        )
-        .unwrap();
+        let edge = &create_edges()[0];
 
-        assert_eq!(2, graphrecord.node_count());
-        assert_eq!(2, graphrecord.edge_count());
+        let endpoints = graphrecord.edge_endpoints(&0).unwrap();
+
+        assert_eq!(&edge.0, endpoints.0);
+
+        assert_eq!(&edge.1, endpoints.1);
     }
 
     #[test]
-    fn test_from_nodes_dataframes() {
-        let nodes_dataframe = create_nodes_dataframe().unwrap();
-
-        let graphrecord =
-            GraphRecord::from_nodes_dataframes(vec![(nodes_dataframe, "index".to_string())], None)
-                .unwrap();
+    fn test_invalid_edge_endpoints() {
+        let graphrecord = create_graphrecord();
 
-        assert_eq!(2, graphrecord.node_count());
+        // Accessing the edge endpoints of a non-existing edge should fail
+        assert!(
+            graphrecord
+                .edge_endpoints(&50)
+                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+        );
     }
 
     #[test]
-    #[cfg(feature = "serde")]
-    fn test_ron() {
+    fn test_edges_connecting() {
         let graphrecord = create_graphrecord();
 
-        let mut file_path = std::env::temp_dir().into_os_string();
-        file_path.push("/graphrecord_test/");
+        let first_index = "0".into();
+        let second_index = "1".into();
+        let edges_connecting =
+            graphrecord.edges_connecting(vec![&first_index], vec![&second_index]);
 
-        fs::create_dir_all(&file_path).unwrap();
+        assert_eq!(vec![&0], edges_connecting.collect::<Vec<_>>());
 
-        file_path.push("test.ron");
+        let first_index = "0".into();
+        let second_index = "3".into();
+        let edges_connecting =
+            graphrecord.edges_connecting(vec![&first_index], vec![&second_index]);
 
-        graphrecord.to_ron(&file_path).unwrap();
+        assert_eq!(0, edges_connecting.count());
 
-        let loaded_graphrecord = GraphRecord::from_ron(&file_path).unwrap();
+        let first_index = "0".into();
+        let second_index = "1".into();
+        let third_index = "2".into();
+        let mut edges_connecting: Vec<_> = graphrecord
+            .edges_connecting(vec![&first_index, &second_index], vec![&third_index])
+            .collect();
 
-        assert_eq!(graphrecord.node_count(), loaded_graphrecord.node_count());
-        assert_eq!(graphrecord.edge_count(), loaded_graphrecord.edge_count());
+        edges_connecting.sort();
+        assert_eq!(vec![&2, &3], edges_connecting);
+
+        let first_index = "0".into();
+        let second_index = "1".into();
+        let third_index = "2".into();
+        let fourth_index = "3".into();
+        let mut edges_connecting: Vec<_> = graphrecord
+            .edges_connecting(
+                vec![&first_index, &second_index],
+                vec![&third_index, &fourth_index],
+            )
+            .collect();
+
+        edges_connecting.sort();
+        assert_eq!(vec![&2, &3], edges_connecting);
     }
 
     #[test]
-    fn test_set_schema() {
-        let mut graphrecord = GraphRecord::new();
+    fn test_set_edge_undirected() {
+        let mut graphrecord = create_graphrecord();
 
-        let group_schema = GroupSchema::new(
-            AttributeSchema::from([("attribute".into(), DataType::Int.into())]),
-            AttributeSchema::from([("attribute".into(), DataType::Int.into())]),
-        );
+        graphrecord.set_edge_undirected(&2, true).unwrap();
 
-        graphrecord
-            .add_node("0".into(), HashMap::from([("attribute".into(), 1.into())]))
-            .unwrap();
-        graphrecord
-            .add_node("1".into(), HashMap::from([("attribute".into(), 1.into())]))
-            .unwrap();
-        graphrecord
-            .add_edge(
-                "0".into(),
-                "1".into(),
-                HashMap::from([("attribute".into(), 1.into())]),
-            )
-            .unwrap();
+        assert!(graphrecord.is_edge_undirected(&2).unwrap());
+        assert_eq!(vec![&2], graphrecord.undirected_edges().collect::<Vec<_>>());
 
-        let schema = Schema::new_provided(HashMap::default(), group_schema.clone());
+        let neighbors: Vec<_> = graphrecord
+            .neighbors_outgoing(&"2".into())
+            .unwrap()
+            .collect();
+        assert_eq!(vec![&NodeIndex::from("1")], neighbors);
 
-        assert!(graphrecord.set_schema(schema.clone()).is_ok());
+        let first_index = "2".into();
+        let second_index = "1".into();
+        let edges_connecting: Vec<_> = graphrecord
+            .edges_connecting(vec![&first_index], vec![&second_index])
+            .collect();
+        assert_eq!(vec![&2], edges_connecting);
 
-        assert_eq!(schema, *graphrecord.get_schema());
+        let neighbors: Vec<_> = graphrecord
+            .query_nodes(|nodes| {
+                nodes.index().equal_to("2");
 
-        let mut graphrecord = GraphRecord::new();
+                nodes.neighbors(EdgeDirection::Outgoing).index()
+            })
+            .evaluate()
+            .unwrap()
+            .collect();
+        assert_eq!(vec![NodeIndex::from("1")], neighbors);
 
-        graphrecord
-            .add_node("0".into(), HashMap::from([("attribute".into(), 1.into())]))
-            .unwrap();
-        graphrecord
-            .add_node("1".into(), HashMap::from([("attribute".into(), 1.into())]))
-            .unwrap();
-        graphrecord
-            .add_node("2".into(), HashMap::from([("attribute".into(), 1.into())]))
-            .unwrap();
-        graphrecord
-            .add_edge(
-                "0".into(),
-                "1".into(),
-                HashMap::from([("attribute".into(), 1.into())]),
-            )
-            .unwrap();
-        graphrecord
-            .add_edge(
-                "0".into(),
-                "1".into(),
-                HashMap::from([("attribute".into(), 1.into())]),
-            )
-            .unwrap();
-        graphrecord
-            .add_edge(
-                "0".into(),
-                "1".into(),
-                HashMap::from([("attribute".into(), 1.into())]),
-            )
-            .unwrap();
+        let edges: Vec<_> = graphrecord
+            .query_nodes(|nodes| {
+                nodes.index().equal_to("2");
 
-        let schema = Schema::new_inferred(
-            HashMap::from([
-                ("0".into(), group_schema.clone()),
-                ("1".into(), group_schema.clone()),
-            ]),
-            group_schema,
+                nodes.edges(EdgeDirection::Outgoing).index()
+            })
+            .evaluate()
+            .unwrap()
+            .collect();
+        assert_eq!(vec![2], edges);
+
+        let export = graphrecord.to_dataframes_sorted().unwrap();
+        let undirected = export.ungrouped.edges.column("undirected").unwrap();
+        assert_eq!(
+            vec![Some(false), Some(false), Some(true), Some(false)],
+            undirected.bool().unwrap().iter().collect::<Vec<_>>()
         );
 
-        graphrecord
-            .add_group(
-                "0".into(),
-                Some(vec!["0".into(), "1".into()]),
-                Some(vec![0, 1]),
-            )
-            .unwrap();
-        graphrecord
-            .add_group(
-                "1".into(),
-                Some(vec!["0".into(), "1".into()]),
-                Some(vec![0, 1]),
-            )
-            .unwrap();
+        graphrecord.freeze();
 
-        let inferred_schema = Schema::new_inferred(HashMap::default(), GroupSchema::default());
+        assert!(
+            graphrecord
+                .set_edge_undirected(&2, false)
+                .is_err_and(|e| matches!(e, GraphRecordError::ReadOnlyError(_)))
+        );
 
-        assert!(graphrecord.set_schema(inferred_schema).is_ok());
+        graphrecord.unfreeze();
 
-        assert_eq!(schema, *graphrecord.get_schema());
+        assert!(
+            graphrecord
+                .set_edge_undirected(&50, true)
+                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+        );
     }
 
     #[test]
-    fn test_invalid_set_schema() {
-        let mut graphrecord = GraphRecord::new();
+    fn test_edges_connecting_undirected() {
+        let graphrecord = create_graphrecord();
 
-        graphrecord
-            .add_node("0".into(), HashMap::from([("attribute2".into(), 1.into())]))
-            .unwrap();
+        let first_index = "0".into();
+        let second_index = "1".into();
+        let mut edges_connecting: Vec<_> = graphrecord
+            .edges_connecting_undirected(vec![&first_index], vec![&second_index])
+            .collect();
 
-        let schema = Schema::new_provided(
-            HashMap::default(),
-            GroupSchema::new(
-                AttributeSchema::from([("attribute".into(), DataType::Int.into())]),
-                AttributeSchema::from([("attribute".into(), DataType::Int.into())]),
-            ),
-        );
+        edges_connecting.sort();
+        assert_eq!(vec![&0, &1], edges_connecting);
+    }
 
-        let previous_schema = graphrecord.get_schema().clone();
+    #[test]
+    fn test_induced_edges() {
+        let graphrecord = create_graphrecord();
 
-        assert!(
-            graphrecord
-                .set_schema(schema.clone())
-                .is_err_and(|e| { matches!(e, GraphRecordError::SchemaError(_)) })
-        );
+        let first_index = "0".into();
+        let second_index = "1".into();
+        let mut induced_edges: Vec<_> = graphrecord
+            .induced_edges(vec![&first_index, &second_index])
+            .collect();
 
-        assert_eq!(previous_schema, *graphrecord.get_schema());
+        induced_edges.sort();
+        assert_eq!(vec![&0, &1], induced_edges);
+
+        // Nodes that do not exist are ignored
+        let first_index = "0".into();
+        let second_index = "2".into();
+        let third_index = "50".into();
+        let induced_edges: Vec<_> = graphrecord
+            .induced_edges(vec![&first_index, &second_index, &third_index])
+            .collect();
+
+        assert_eq!(vec![&3], induced_edges);
+    }
 
+    #[test]
+    fn test_add_node() {
         let mut graphrecord = GraphRecord::new();
 
-        graphrecord
-            .add_node("0".into(), HashMap::from([("attribute".into(), 1.into())]))
-            .unwrap();
-        graphrecord
-            .add_node("1".into(), HashMap::from([("attribute".into(), 1.into())]))
-            .unwrap();
-        graphrecord
-            .add_edge(
-                "0".into(),
-                "1".into(),
-                HashMap::from([("attribute2".into(), 1.into())]),
-            )
-            .unwrap();
+        assert_eq!(0, graphrecord.node_count());
+
+        graphrecord.add_node("0".into(), HashMap::new()).unwrap();
 
-        let previous_schema = graphrecord.get_schema().clone();
+        assert_eq!(1, graphrecord.node_count());
 
-        assert!(
-            graphrecord
-                .set_schema(schema)
-                .is_err_and(|e| { matches!(e, GraphRecordError::SchemaError(_)) })
-        );
+        graphrecord.freeze_schema().unwrap();
 
-        assert_eq!(previous_schema, *graphrecord.get_schema());
+        graphrecord.add_node("1".into(), HashMap::new()).unwrap();
+
+        assert_eq!(2, graphrecord.node_count());
     }
 
     #[test]
-    fn test_freeze_schema() {
-        let mut graphrecord = GraphRecord::new();
+    fn test_invalid_add_node() {
+        let mut graphrecord = create_graphrecord();
 
-        assert_eq!(
-            SchemaType::Inferred,
-            *graphrecord.get_schema().schema_type()
+        assert!(
+            graphrecord
+                .add_node("0".into(), HashMap::new())
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
         );
 
         graphrecord.freeze_schema().unwrap();
 
-        assert_eq!(
-            SchemaType::Provided,
-            *graphrecord.get_schema().schema_type()
+        assert!(
+            graphrecord
+                .add_node("4".into(), HashMap::from([("attribute".into(), 1.into())]))
+                .is_err_and(|e| matches!(e, GraphRecordError::SchemaError(_)))
         );
     }
 
     #[test]
-    fn test_unfreeze_schema() {
-        let schema = Schema::new_provided(HashMap::default(), GroupSchema::default());
-        let mut graphrecord = GraphRecord::with_schema(schema);
+    fn test_remove_node() {
+        let mut graphrecord = create_graphrecord();
+
+        graphrecord
+            .add_group("group".into(), Some(vec!["0".into()]), Some(vec![0]))
+            .unwrap();
 
+        let nodes = create_nodes();
+
+        assert_eq!(4, graphrecord.node_count());
+        assert_eq!(4, graphrecord.edge_count());
         assert_eq!(
-            *graphrecord.get_schema().schema_type(),
-            SchemaType::Provided
+            1,
+            graphrecord
+                .nodes_in_group(&("group".into()))
+                .unwrap()
+                .count()
+        );
+        assert_eq!(
+            1,
+            graphrecord
+                .edges_in_group(&("group".into()))
+                .unwrap()
+                .count()
         );
 
-        graphrecord.unfreeze_schema().unwrap();
+        assert_eq!(nodes[0].1, graphrecord.remove_node(&"0".into()).unwrap());
 
+        assert_eq!(3, graphrecord.node_count());
+        assert_eq!(1, graphrecord.edge_count());
         assert_eq!(
-            *graphrecord.get_schema().schema_type(),
-            SchemaType::Inferred
+            0,
+            graphrecord
+                .nodes_in_group(&("group".into()))
+                .unwrap()
+                .count()
+        );
+        assert_eq!(
+            0,
+            graphrecord
+                .edges_in_group(&("group".into()))
+                .unwrap()
+                .count()
         );
-    }
-
-    #[test]
-    fn test_node_indices() {
-        let graphrecord = create_graphrecord();
 
-        let node_indices: Vec<_> = create_nodes()
-            .into_iter()
-            .map(|(node_index, _)| node_index)
-            .collect();
+        let mut graphrecord = GraphRecord::new();
 
-        for node_index in graphrecord.node_indices() {
-            assert!(node_indices.contains(node_index));
-        }
-    }
+        graphrecord.add_node(0.into(), HashMap::new()).unwrap();
+        graphrecord
+            .add_edge(0.into(), 0.into(), HashMap::new())
+            .unwrap();
 
-    #[test]
-    fn test_node_attributes() {
-        let graphrecord = create_graphrecord();
+        assert_eq!(1, graphrecord.node_count());
+        assert_eq!(1, graphrecord.edge_count());
 
-        let attributes = graphrecord.node_attributes(&"0".into()).unwrap();
+        assert!(graphrecord.remove_node(&0.into()).is_ok());
 
-        assert_eq!(&create_nodes()[0].1, attributes);
+        assert_eq!(0, graphrecord.node_count());
+        assert_eq!(0, graphrecord.edge_count());
     }
 
     #[test]
-    fn test_invalid_node_attributes() {
-        let graphrecord = create_graphrecord();
+    fn test_invalid_remove_node() {
+        let mut graphrecord = create_graphrecord();
 
-        // Querying a non-existing node should fail
+        // Removing a non-existing node should fail
         assert!(
             graphrecord
-                .node_attributes(&"50".into())
-                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+                .remove_node(&"50".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::MissingNode { .. }))
         );
     }
 
     #[test]
-    fn test_node_attributes_mut() {
-        let mut graphrecord = create_graphrecord();
+    fn test_add_nodes() {
+        let mut graphrecord = GraphRecord::new();
 
-        let node_index = "0".into();
-        let mut attributes = graphrecord.node_attributes_mut(&node_index).unwrap();
+        assert_eq!(0, graphrecord.node_count());
 
-        let new_attributes = HashMap::from([("0".into(), "1".into()), ("2".into(), "3".into())]);
+        let nodes = create_nodes();
 
-        attributes
-            .replace_attributes(new_attributes.clone())
-            .unwrap();
+        graphrecord.add_nodes(nodes).unwrap();
 
-        assert_eq!(
-            &new_attributes,
-            graphrecord.node_attributes(&node_index).unwrap()
-        );
+        assert_eq!(4, graphrecord.node_count());
     }
 
     #[test]
-    fn test_invalid_node_attributes_mut() {
+    fn test_invalid_add_nodes() {
         let mut graphrecord = create_graphrecord();
 
-        // Accessing the node attributes of a non-existing node should fail
+        let nodes = create_nodes();
+
         assert!(
             graphrecord
-                .node_attributes_mut(&"50".into())
-                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+                .add_nodes(nodes)
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
         );
     }
 
     #[test]
-    fn test_outgoing_edges() {
-        let graphrecord = create_graphrecord();
+    fn test_add_nodes_dataframe() {
+        let mut graphrecord = GraphRecord::new();
 
-        let edges = graphrecord.outgoing_edges(&"0".into()).unwrap();
+        assert_eq!(0, graphrecord.node_count());
 
-        assert_eq!(2, edges.count());
+        let nodes_dataframe = create_nodes_dataframe().unwrap();
+
+        graphrecord
+            .add_nodes_dataframes(vec![(nodes_dataframe, "index".to_string())])
+            .unwrap();
+
+        assert_eq!(2, graphrecord.node_count());
     }
 
     #[test]
-    fn test_invalid_outgoing_edges() {
-        let graphrecord = create_graphrecord();
+    fn test_add_edge() {
+        let mut graphrecord = create_graphrecord();
+
+        assert_eq!(4, graphrecord.edge_count());
 
+        graphrecord
+            .add_edge("0".into(), "3".into(), HashMap::new())
+            .unwrap();
+
+        assert_eq!(5, graphrecord.edge_count());
+
+        graphrecord.freeze_schema().unwrap();
+
+        graphrecord
+            .add_edge("0".into(), "3".into(), HashMap::new())
+            .unwrap();
+
+        assert_eq!(6, graphrecord.edge_count());
+    }
+
+    #[test]
+    fn test_invalid_add_edge() {
+        let mut graphrecord = GraphRecord::new();
+
+        let nodes = create_nodes();
+
+        graphrecord.add_nodes(nodes).unwrap();
+
+        // Adding an edge pointing to a non-existing node should fail
         assert!(
             graphrecord
-                .outgoing_edges(&"50".into())
-                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+                .add_edge("0".into(), "50".into(), HashMap::new())
+                .is_err_and(|e| matches!(e, GraphRecordError::MissingNode { .. }))
+        );
+
+        // Adding an edge from a non-existing node should fail
+        assert!(
+            graphrecord
+                .add_edge("50".into(), "0".into(), HashMap::new())
+                .is_err_and(|e| matches!(e, GraphRecordError::MissingNode { .. }))
+        );
+
+        graphrecord.freeze_schema().unwrap();
+
+        assert!(
+            graphrecord
+                .add_edge(
+                    "0".into(),
+                    "3".into(),
+                    HashMap::from([("attribute".into(), 1.into())])
+                )
+                .is_err_and(|e| matches!(e, GraphRecordError::SchemaError(_)))
         );
     }
 
     #[test]
-    fn test_incoming_edges() {
-        let graphrecord = create_graphrecord();
+    fn test_remove_edge() {
+        let mut graphrecord = create_graphrecord();
 
-        let edges = graphrecord.incoming_edges(&"2".into()).unwrap();
+        let edges = create_edges();
 
-        assert_eq!(2, edges.count());
+        assert_eq!(edges[0].2, graphrecord.remove_edge(&0).unwrap());
     }
 
     #[test]
-    fn test_invalid_incoming_edges() {
-        let graphrecord = create_graphrecord();
+    fn test_invalid_remove_edge() {
+        let mut graphrecord = create_graphrecord();
 
+        // Removing a non-existing edge should fail
         assert!(
             graphrecord
-                .incoming_edges(&"50".into())
+                .remove_edge(&50)
                 .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
         );
     }
 
     #[test]
-    fn test_edge_indices() {
-        let graphrecord = create_graphrecord();
-        let edges = [0, 1, 2, 3];
+    fn test_compact() {
+        let mut graphrecord = create_graphrecord();
 
-        for edge in graphrecord.edge_indices() {
-            assert!(edges.contains(edge));
-        }
-    }
+        graphrecord
+            .add_group("group".into(), None, Some(vec![3]))
+            .unwrap();
 
-    #[test]
-    fn test_edge_attributes() {
-        let graphrecord = create_graphrecord();
+        graphrecord.remove_edge(&0).unwrap();
+        graphrecord.remove_edge(&2).unwrap();
 
-        let attributes = graphrecord.edge_attributes(&0).unwrap();
+        let remap = graphrecord.compact().unwrap();
 
-        assert_eq!(&create_edges()[0].2, attributes);
-    }
+        assert_eq!(HashMap::from([(1, 0), (3, 1)]), remap);
 
-    #[test]
-    fn test_invalid_edge_attributes() {
-        let graphrecord = create_graphrecord();
+        let mut edge_indices: Vec<_> = graphrecord.edge_indices().copied().collect();
+        edge_indices.sort_unstable();
 
-        // Querying a non-existing node should fail
-        assert!(
+        assert_eq!(vec![0, 1], edge_indices);
+        assert_eq!(
+            (&"1".into(), &"0".into()),
+            graphrecord.edge_endpoints(&0).unwrap()
+        );
+        assert_eq!(
+            vec![&1],
             graphrecord
-                .edge_attributes(&50)
-                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+                .edges_in_group(&"group".into())
+                .unwrap()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![&GraphRecordAttribute::from("group")],
+            graphrecord.groups_of_edge(&1).unwrap().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![&1],
+            graphrecord
+                .outgoing_edges(&"0".into())
+                .unwrap()
+                .collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            2,
+            graphrecord
+                .add_edge("0".into(), "1".into(), HashMap::new())
+                .unwrap()
         );
     }
 
     #[test]
-    fn test_edge_attributes_mut() {
+    fn test_invalid_compact() {
         let mut graphrecord = create_graphrecord();
 
-        let mut attributes = graphrecord.edge_attributes_mut(&0).unwrap();
-
-        let new_attributes = HashMap::from([("0".into(), "1".into()), ("2".into(), "3".into())]);
-
-        attributes
-            .replace_attributes(new_attributes.clone())
-            .unwrap();
-
-        assert_eq!(&new_attributes, graphrecord.edge_attributes(&0).unwrap());
-    }
-
-    #[test]
-    fn test_invalid_edge_attributes_mut() {
-        let mut graphrecord = create_graphrecord();
+        graphrecord.freeze();
 
-        // Accessing the edge attributes of a non-existing edge should fail
+        // Compacting a frozen GraphRecord should fail
         assert!(
             graphrecord
-                .edge_attributes_mut(&50)
-                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+                .compact()
+                .is_err_and(|e| matches!(e, GraphRecordError::ReadOnlyError(_)))
         );
     }
 
     #[test]
-    fn test_edge_endpoints() {
-        let graphrecord = create_graphrecord();
+    fn test_recycle_edge_indices() {
+        let mut graphrecord = create_graphrecord();
 
-        let edge = &create_edges()[0];
+        assert!(!graphrecord.recycle_edge_indices());
 
-        let endpoints = graphrecord.edge_endpoints(&0).unwrap();
+        graphrecord.remove_edge(&1).unwrap();
 
-        assert_eq!(&edge.0, endpoints.0);
+        assert_eq!(
+            4,
+            graphrecord
+                .add_edge("0".into(), "1".into(), HashMap::new())
+                .unwrap()
+        );
 
-        assert_eq!(&edge.1, endpoints.1);
-    }
+        graphrecord.set_recycle_edge_indices(true).unwrap();
 
-    #[test]
-    fn test_invalid_edge_endpoints() {
-        let graphrecord = create_graphrecord();
+        assert!(graphrecord.recycle_edge_indices());
 
-        // Accessing the edge endpoints of a non-existing edge should fail
-        assert!(
+        graphrecord.remove_edge(&2).unwrap();
+        graphrecord.remove_node(&"2".into()).unwrap();
+
+        assert_eq!(
+            3,
             graphrecord
-                .edge_endpoints(&50)
-                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+                .add_edge("0".into(), "1".into(), HashMap::new())
+                .unwrap()
+        );
+        assert_eq!(
+            2,
+            graphrecord
+                .add_edge("0".into(), "1".into(), HashMap::new())
+                .unwrap()
+        );
+        assert_eq!(
+            5,
+            graphrecord
+                .add_edge("0".into(), "1".into(), HashMap::new())
+                .unwrap()
         );
     }
 
     #[test]
-    fn test_edges_connecting() {
-        let graphrecord = create_graphrecord();
-
-        let first_index = "0".into();
-        let second_index = "1".into();
-        let edges_connecting =
-            graphrecord.edges_connecting(vec![&first_index], vec![&second_index]);
-
-        assert_eq!(vec![&0], edges_connecting.collect::<Vec<_>>());
+    fn test_add_edges() {
+        let mut graphrecord = GraphRecord::new();
 
-        let first_index = "0".into();
-        let second_index = "3".into();
-        let edges_connecting =
-            graphrecord.edges_connecting(vec![&first_index], vec![&second_index]);
+        let nodes = create_nodes();
 
-        assert_eq!(0, edges_connecting.count());
+        graphrecord.add_nodes(nodes).unwrap();
 
-        let first_index = "0".into();
-        let second_index = "1".into();
-        let third_index = "2".into();
-        let mut edges_connecting: Vec<_> = graphrecord
-            .edges_connecting(vec![&first_index, &second_index], vec![&third_index])
-            .collect();
+        assert_eq!(0, graphrecord.edge_count());
 
-        edges_connecting.sort();
-        assert_eq!(vec![&2, &3], edges_connecting);
+        let edges = create_edges();
 
-        let first_index = "0".into();
-        let second_index = "1".into();
-        let third_index = "2".into();
-        let fourth_index = "3".into();
-        let mut edges_connecting: Vec<_> = graphrecord
-            .edges_connecting(
-                vec![&first_index, &second_index],
-                vec![&third_index, &fourth_index],
-            )
-            .collect();
+        graphrecord.add_edges(edges).unwrap();
 
-        edges_connecting.sort();
-        assert_eq!(vec![&2, &3], edges_connecting);
+        assert_eq!(4, graphrecord.edge_count());
     }
 
     #[test]
-    fn test_edges_connecting_undirected() {
-        let graphrecord = create_graphrecord();
+    fn test_add_edges_dataframe() {
+        let mut graphrecord = GraphRecord::new();
 
-        let first_index = "0".into();
-        let second_index = "1".into();
-        let mut edges_connecting: Vec<_> = graphrecord
-            .edges_connecting_undirected(vec![&first_index], vec![&second_index])
-            .collect();
+        let nodes = create_nodes();
 
-        edges_connecting.sort();
-        assert_eq!(vec![&0, &1], edges_connecting);
+        graphrecord.add_nodes(nodes).unwrap();
+
+        assert_eq!(0, graphrecord.edge_count());
+
+        let edges = create_edges_dataframe().unwrap();
+
+        graphrecord
+            .add_edges_dataframes(vec![(edges, "from", "to")])
+            .unwrap();
+
+        assert_eq!(2, graphrecord.edge_count());
     }
 
     #[test]
-    fn test_add_node() {
-        let mut graphrecord = GraphRecord::new();
+    fn test_add_group() {
+        let mut graphrecord = create_graphrecord();
 
-        assert_eq!(0, graphrecord.node_count());
+        assert_eq!(0, graphrecord.group_count());
 
-        graphrecord.add_node("0".into(), HashMap::new()).unwrap();
+        graphrecord.add_group("0".into(), None, None).unwrap();
 
-        assert_eq!(1, graphrecord.node_count());
+        assert_eq!(1, graphrecord.group_count());
 
-        graphrecord.freeze_schema().unwrap();
+        graphrecord
+            .add_group("1".into(), Some(vec!["0".into(), "1".into()]), None)
+            .unwrap();
 
-        graphrecord.add_node("1".into(), HashMap::new()).unwrap();
+        assert_eq!(2, graphrecord.group_count());
 
-        assert_eq!(2, graphrecord.node_count());
+        assert_eq!(2, graphrecord.nodes_in_group(&"1".into()).unwrap().count());
     }
 
     #[test]
-    fn test_invalid_add_node() {
+    fn test_invalid_add_group() {
         let mut graphrecord = create_graphrecord();
 
+        // Adding a group with a non-existing node should fail
+        assert!(
+            graphrecord
+                .add_group("0".into(), Some(vec!["50".into()]), None)
+                .is_err_and(|e| matches!(e, GraphRecordError::MissingNode { .. }))
+        );
+
+        // Adding a group with a non-existing edge should fail
         assert!(
             graphrecord
-                .add_node("0".into(), HashMap::new())
-                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+                .add_group("0".into(), None, Some(vec![50]))
+                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+        );
+
+        graphrecord.add_group("0".into(), None, None).unwrap();
+
+        // Adding an already existing group should fail
+        assert!(
+            graphrecord
+                .add_group("0".into(), None, None)
+                .is_err_and(|e| matches!(e, GraphRecordError::DuplicateGroup { .. }))
         );
 
         graphrecord.freeze_schema().unwrap();
 
         assert!(
             graphrecord
-                .add_node("4".into(), HashMap::from([("attribute".into(), 1.into())]))
+                .add_group("2".into(), None, None)
+                .is_err_and(|e| matches!(e, GraphRecordError::SchemaError(_)))
+        );
+
+        graphrecord.remove_group(&"0".into()).unwrap();
+
+        assert!(
+            graphrecord
+                .add_group("0".into(), Some(vec!["0".into()]), None)
+                .is_err_and(|e| matches!(e, GraphRecordError::SchemaError(_)))
+        );
+        assert!(
+            graphrecord
+                .add_group("0".into(), None, Some(vec![0]))
                 .is_err_and(|e| matches!(e, GraphRecordError::SchemaError(_)))
         );
     }
 
     #[test]
-    fn test_remove_node() {
+    fn test_remove_group() {
+        let mut graphrecord = create_graphrecord();
+
+        graphrecord.add_group("0".into(), None, None).unwrap();
+
+        assert_eq!(1, graphrecord.group_count());
+
+        graphrecord.remove_group(&"0".into()).unwrap();
+
+        assert_eq!(0, graphrecord.group_count());
+    }
+
+    #[test]
+    fn test_invalid_remove_group() {
+        let mut graphrecord = GraphRecord::new();
+
+        // Removing a non-existing group should fail
+        assert!(
+            graphrecord
+                .remove_group(&"0".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+        );
+    }
+
+    #[test]
+    fn test_group_metadata() {
         let mut graphrecord = create_graphrecord();
 
         graphrecord
-            .add_group("group".into(), Some(vec!["0".into()]), Some(vec![0]))
+            .add_group("0".into(), Some(vec!["0".into()]), None)
+            .unwrap();
+        graphrecord
+            .set_group_metadata(&"0".into(), "description".into(), "Patients".into())
             .unwrap();
 
-        let nodes = create_nodes();
+        let overview = graphrecord
+            .overview(None, OverviewFormat::Csv, None)
+            .unwrap();
 
-        assert_eq!(4, graphrecord.node_count());
-        assert_eq!(4, graphrecord.edge_count());
         assert_eq!(
-            1,
-            graphrecord
-                .nodes_in_group(&("group".into()))
-                .unwrap()
-                .count()
+            HashMap::from([("description".into(), "Patients".into())]),
+            overview.grouped_overviews[&GraphRecordAttribute::from("0")].metadata
         );
-        assert_eq!(
-            1,
-            graphrecord
-                .edges_in_group(&("group".into()))
-                .unwrap()
-                .count()
+        assert!(
+            overview
+                .to_string()
+                .contains("Group,Key,Value\n0,description,Patients\n")
         );
+        assert!(overview.ungrouped_overview.metadata.is_empty());
 
-        assert_eq!(nodes[0].1, graphrecord.remove_node(&"0".into()).unwrap());
+        graphrecord.freeze();
 
-        assert_eq!(3, graphrecord.node_count());
-        assert_eq!(1, graphrecord.edge_count());
-        assert_eq!(
-            0,
-            graphrecord
-                .nodes_in_group(&("group".into()))
-                .unwrap()
-                .count()
-        );
-        assert_eq!(
-            0,
+        assert!(
             graphrecord
-                .edges_in_group(&("group".into()))
-                .unwrap()
-                .count()
+                .set_group_metadata(&"0".into(), "color".into(), "red".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::ReadOnlyError(_)))
         );
+    }
 
-        let mut graphrecord = GraphRecord::new();
+    #[test]
+    fn test_add_node_to_group() {
+        let mut graphrecord = create_graphrecord();
 
-        graphrecord.add_node(0.into(), HashMap::new()).unwrap();
         graphrecord
-            .add_edge(0.into(), 0.into(), HashMap::new())
+            .add_group("0".into(), Some(vec!["0".into(), "1".into()]), None)
             .unwrap();
 
-        assert_eq!(1, graphrecord.node_count());
-        assert_eq!(1, graphrecord.edge_count());
+        assert_eq!(2, graphrecord.nodes_in_group(&"0".into()).unwrap().count());
 
-        assert!(graphrecord.remove_node(&0.into()).is_ok());
+        graphrecord
+            .add_node_to_group("0".into(), "2".into())
+            .unwrap();
+
+        assert_eq!(3, graphrecord.nodes_in_group(&"0".into()).unwrap().count());
+
+        graphrecord
+            .add_node("4".into(), HashMap::from([("test".into(), "test".into())]))
+            .unwrap();
+
+        graphrecord
+            .add_group("1".into(), Some(vec!["4".into()]), None)
+            .unwrap();
 
-        assert_eq!(0, graphrecord.node_count());
-        assert_eq!(0, graphrecord.edge_count());
-    }
+        graphrecord.freeze_schema().unwrap();
 
-    #[test]
-    fn test_invalid_remove_node() {
-        let mut graphrecord = create_graphrecord();
+        graphrecord
+            .add_node("5".into(), HashMap::from([("test".into(), "test".into())]))
+            .unwrap();
 
-        // Removing a non-existing node should fail
         assert!(
             graphrecord
-                .remove_node(&"50".into())
-                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+                .add_node_to_group("1".into(), "5".into())
+                .is_ok()
         );
-    }
 
-    #[test]
-    fn test_add_nodes() {
-        let mut graphrecord = GraphRecord::new();
-
-        assert_eq!(0, graphrecord.node_count());
-
-        let nodes = create_nodes();
-
-        graphrecord.add_nodes(nodes).unwrap();
-
-        assert_eq!(4, graphrecord.node_count());
+        assert_eq!(2, graphrecord.nodes_in_group(&"1".into()).unwrap().count());
     }
 
     #[test]
-    fn test_invalid_add_nodes() {
+    fn test_invalid_add_node_to_group() {
         let mut graphrecord = create_graphrecord();
 
-        let nodes = create_nodes();
+        graphrecord
+            .add_group("0".into(), Some(vec!["0".into()]), None)
+            .unwrap();
 
+        // Adding a non-existing node to a group should fail
         assert!(
             graphrecord
-                .add_nodes(nodes)
+                .add_node_to_group("0".into(), "50".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::MissingNode { .. }))
+        );
+
+        // Adding a node to a group that already is in the group should fail
+        assert!(
+            graphrecord
+                .add_node_to_group("0".into(), "0".into())
                 .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
         );
-    }
 
-    #[test]
-    fn test_add_nodes_dataframe() {
         let mut graphrecord = GraphRecord::new();
 
-        assert_eq!(0, graphrecord.node_count());
-
-        let nodes_dataframe = create_nodes_dataframe().unwrap();
-
         graphrecord
-            .add_nodes_dataframes(vec![(nodes_dataframe, "index".to_string())])
+            .add_node("0".into(), HashMap::from([("test".into(), "test".into())]))
             .unwrap();
+        graphrecord.add_group("group".into(), None, None).unwrap();
 
-        assert_eq!(2, graphrecord.node_count());
+        graphrecord.freeze_schema().unwrap();
+
+        assert!(
+            graphrecord
+                .add_node_to_group("group".into(), "0".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::SchemaError(_)))
+        );
     }
 
     #[test]
-    fn test_add_edge() {
+    fn test_add_node_to_groups() {
         let mut graphrecord = create_graphrecord();
 
-        assert_eq!(4, graphrecord.edge_count());
-
         graphrecord
-            .add_edge("0".into(), "3".into(), HashMap::new())
+            .add_group("0".into(), Some(vec!["0".into()]), None)
+            .unwrap();
+        graphrecord
+            .add_group("1".into(), Some(vec!["1".into()]), None)
             .unwrap();
-
-        assert_eq!(5, graphrecord.edge_count());
-
-        graphrecord.freeze_schema().unwrap();
 
         graphrecord
-            .add_edge("0".into(), "3".into(), HashMap::new())
+            .add_node_to_groups(&["0".into(), "1".into()], "2".into())
             .unwrap();
 
-        assert_eq!(6, graphrecord.edge_count());
+        assert_eq!(2, graphrecord.nodes_in_group(&"0".into()).unwrap().count());
+        assert_eq!(2, graphrecord.nodes_in_group(&"1".into()).unwrap().count());
     }
 
     #[test]
-    fn test_invalid_add_edge() {
-        let mut graphrecord = GraphRecord::new();
-
-        let nodes = create_nodes();
+    fn test_invalid_add_node_to_groups() {
+        let mut graphrecord = create_graphrecord();
 
-        graphrecord.add_nodes(nodes).unwrap();
+        graphrecord
+            .add_group("0".into(), Some(vec!["0".into()]), None)
+            .unwrap();
+        graphrecord
+            .add_group("1".into(), Some(vec!["1".into()]), None)
+            .unwrap();
 
-        // Adding an edge pointing to a non-existing node should fail
         assert!(
             graphrecord
-                .add_edge("0".into(), "50".into(), HashMap::new())
-                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+                .add_node_to_groups(&["0".into(), "1".into()], "50".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::MissingNode { .. }))
         );
 
-        // Adding an edge from a non-existing node should fail
         assert!(
             graphrecord
-                .add_edge("50".into(), "0".into(), HashMap::new())
-                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+                .add_node_to_groups(&["0".into(), "1".into()], "0".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
         );
 
+        let mut graphrecord = GraphRecord::new();
+
+        graphrecord
+            .add_node("0".into(), HashMap::from([("test".into(), "test".into())]))
+            .unwrap();
+        graphrecord.add_group("group".into(), None, None).unwrap();
+        graphrecord.add_group("group2".into(), None, None).unwrap();
+
         graphrecord.freeze_schema().unwrap();
 
         assert!(
             graphrecord
-                .add_edge(
-                    "0".into(),
-                    "3".into(),
-                    HashMap::from([("attribute".into(), 1.into())])
-                )
+                .add_node_to_groups(&["group".into(), "group2".into()], "0".into())
                 .is_err_and(|e| matches!(e, GraphRecordError::SchemaError(_)))
         );
     }
 
     #[test]
-    fn test_remove_edge() {
+    fn test_add_edge_to_group() {
         let mut graphrecord = create_graphrecord();
 
-        let edges = create_edges();
-
-        assert_eq!(edges[0].2, graphrecord.remove_edge(&0).unwrap());
-    }
+        graphrecord
+            .add_group("0".into(), None, Some(vec![0, 1]))
+            .unwrap();
 
-    #[test]
-    fn test_invalid_remove_edge() {
-        let mut graphrecord = create_graphrecord();
+        assert_eq!(2, graphrecord.edges_in_group(&"0".into()).unwrap().count());
 
-        // Removing a non-existing edge should fail
-        assert!(
-            graphrecord
-                .remove_edge(&50)
-                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
-        );
-    }
+        graphrecord.add_edge_to_group("0".into(), 2).unwrap();
 
-    #[test]
-    fn test_add_edges() {
-        let mut graphrecord = GraphRecord::new();
+        assert_eq!(3, graphrecord.edges_in_group(&"0".into()).unwrap().count());
 
-        let nodes = create_nodes();
+        graphrecord
+            .add_edge("0".into(), "1".into(), HashMap::new())
+            .unwrap();
 
-        graphrecord.add_nodes(nodes).unwrap();
+        graphrecord
+            .add_group("1".into(), None, Some(vec![3]))
+            .unwrap();
 
-        assert_eq!(0, graphrecord.edge_count());
+        graphrecord.freeze_schema().unwrap();
 
-        let edges = create_edges();
+        let edge_index = graphrecord
+            .add_edge("0".into(), "1".into(), HashMap::new())
+            .unwrap();
 
-        graphrecord.add_edges(edges).unwrap();
+        assert!(
+            graphrecord
+                .add_edge_to_group("1".into(), edge_index)
+                .is_ok()
+        );
 
-        assert_eq!(4, graphrecord.edge_count());
+        assert_eq!(2, graphrecord.edges_in_group(&"1".into()).unwrap().count());
     }
 
     #[test]
-    fn test_add_edges_dataframe() {
-        let mut graphrecord = GraphRecord::new();
+    fn test_invalid_add_edge_to_group() {
+        let mut graphrecord = create_graphrecord();
 
-        let nodes = create_nodes();
+        graphrecord
+            .add_group("0".into(), None, Some(vec![0]))
+            .unwrap();
 
-        graphrecord.add_nodes(nodes).unwrap();
+        // Adding a non-existing edge to a group should fail
+        assert!(
+            graphrecord
+                .add_edge_to_group("0".into(), 50)
+                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+        );
 
-        assert_eq!(0, graphrecord.edge_count());
+        // Adding an edge to a group that already is in the group should fail
+        assert!(
+            graphrecord
+                .add_edge_to_group("0".into(), 0)
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
 
-        let edges = create_edges_dataframe().unwrap();
+        let mut graphrecord = GraphRecord::new();
 
+        graphrecord.add_node("0".into(), HashMap::new()).unwrap();
         graphrecord
-            .add_edges_dataframes(vec![(edges, "from", "to")])
+            .add_edge(
+                "0".into(),
+                "0".into(),
+                HashMap::from([("test".into(), "test".into())]),
+            )
             .unwrap();
+        graphrecord.add_group("group".into(), None, None).unwrap();
 
-        assert_eq!(2, graphrecord.edge_count());
+        graphrecord.freeze_schema().unwrap();
+
+        assert!(
+            graphrecord
+                .add_edge_to_group("group".into(), 0)
+                .is_err_and(|e| matches!(e, GraphRecordError::SchemaError(_)))
+        );
     }
 
     #[test]
-    fn test_add_group() {
+    fn test_add_edge_to_groups() {
         let mut graphrecord = create_graphrecord();
 
-        assert_eq!(0, graphrecord.group_count());
-
-        graphrecord.add_group("0".into(), None, None).unwrap();
-
-        assert_eq!(1, graphrecord.group_count());
-
         graphrecord
-            .add_group("1".into(), Some(vec!["0".into(), "1".into()]), None)
+            .add_group("0".into(), None, Some(vec![0]))
+            .unwrap();
+        graphrecord
+            .add_group("1".into(), None, Some(vec![1]))
             .unwrap();
 
-        assert_eq!(2, graphrecord.group_count());
+        graphrecord
+            .add_edge_to_groups(&["0".into(), "1".into()], 2)
+            .unwrap();
 
-        assert_eq!(2, graphrecord.nodes_in_group(&"1".into()).unwrap().count());
+        assert_eq!(2, graphrecord.edges_in_group(&"0".into()).unwrap().count());
+        assert_eq!(2, graphrecord.edges_in_group(&"1".into()).unwrap().count());
     }
 
     #[test]
-    fn test_invalid_add_group() {
+    fn test_invalid_add_edge_to_groups() {
         let mut graphrecord = create_graphrecord();
 
-        // Adding a group with a non-existing node should fail
-        assert!(
-            graphrecord
-                .add_group("0".into(), Some(vec!["50".into()]), None)
-                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
-        );
+        graphrecord
+            .add_group("0".into(), None, Some(vec![0]))
+            .unwrap();
+        graphrecord
+            .add_group("1".into(), None, Some(vec![1]))
+            .unwrap();
 
-        // Adding a group with a non-existing edge should fail
         assert!(
             graphrecord
-                .add_group("0".into(), None, Some(vec![50]))
+                .add_edge_to_groups(&["0".into(), "1".into()], 50)
                 .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
         );
 
-        graphrecord.add_group("0".into(), None, None).unwrap();
-
-        // Adding an already existing group should fail
         assert!(
             graphrecord
-                .add_group("0".into(), None, None)
+                .add_edge_to_groups(&["0".into(), "1".into()], 0)
                 .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
         );
 
-        graphrecord.freeze_schema().unwrap();
+        let mut graphrecord = GraphRecord::new();
 
-        assert!(
-            graphrecord
-                .add_group("2".into(), None, None)
-                .is_err_and(|e| matches!(e, GraphRecordError::SchemaError(_)))
-        );
+        graphrecord.add_node("0".into(), HashMap::new()).unwrap();
+        graphrecord
+            .add_edge(
+                "0".into(),
+                "0".into(),
+                HashMap::from([("test".into(), "test".into())]),
+            )
+            .unwrap();
+        graphrecord.add_group("group".into(), None, None).unwrap();
+        graphrecord.add_group("group2".into(), None, None).unwrap();
 
-        graphrecord.remove_group(&"0".into()).unwrap();
+        graphrecord.freeze_schema().unwrap();
 
         assert!(
             graphrecord
-                .add_group("0".into(), Some(vec!["0".into()]), None)
-                .is_err_and(|e| matches!(e, GraphRecordError::SchemaError(_)))
-        );
-        assert!(
-            graphrecord
-                .add_group("0".into(), None, Some(vec![0]))
+                .add_edge_to_groups(&["group".into(), "group2".into()], 0)
                 .is_err_and(|e| matches!(e, GraphRecordError::SchemaError(_)))
         );
     }
 
     #[test]
-    fn test_remove_group() {
+    fn test_remove_node_from_group() {
         let mut graphrecord = create_graphrecord();
 
-        graphrecord.add_group("0".into(), None, None).unwrap();
+        graphrecord
+            .add_group("0".into(), Some(vec!["0".into(), "1".into()]), None)
+            .unwrap();
 
-        assert_eq!(1, graphrecord.group_count());
+        assert_eq!(2, graphrecord.nodes_in_group(&"0".into()).unwrap().count());
 
-        graphrecord.remove_group(&"0".into()).unwrap();
+        graphrecord
+            .remove_node_from_group(&"0".into(), &"0".into())
+            .unwrap();
 
-        assert_eq!(0, graphrecord.group_count());
+        assert_eq!(1, graphrecord.nodes_in_group(&"0".into()).unwrap().count());
     }
 
     #[test]
-    fn test_invalid_remove_group() {
-        let mut graphrecord = GraphRecord::new();
+    fn test_invalid_remove_node_from_group() {
+        let mut graphrecord = create_graphrecord();
 
-        // Removing a non-existing group should fail
+        graphrecord
+            .add_group("0".into(), Some(vec!["0".into()]), None)
+            .unwrap();
+
+        // Removing a node from a non-existing group should fail
         assert!(
             graphrecord
-                .remove_group(&"0".into())
+                .remove_node_from_group(&"50".into(), &"0".into())
                 .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
         );
+
+        // Removing a non-existing node from a group should fail
+        assert!(
+            graphrecord
+                .remove_node_from_group(&"0".into(), &"50".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::MissingNode { .. }))
+        );
+
+        // Removing a node from a group it is not in should fail
+        assert!(
+            graphrecord
+                .remove_node_from_group(&"0".into(), &"1".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
     }
 
     #[test]
-    fn test_add_node_to_group() {
+    fn test_remove_node_from_groups() {
         let mut graphrecord = create_graphrecord();
 
         graphrecord
             .add_group("0".into(), Some(vec!["0".into(), "1".into()]), None)
             .unwrap();
-
-        assert_eq!(2, graphrecord.nodes_in_group(&"0".into()).unwrap().count());
-
         graphrecord
-            .add_node_to_group("0".into(), "2".into())
+            .add_group("1".into(), Some(vec!["0".into(), "2".into()]), None)
             .unwrap();
 
-        assert_eq!(3, graphrecord.nodes_in_group(&"0".into()).unwrap().count());
-
         graphrecord
-            .add_node("4".into(), HashMap::from([("test".into(), "test".into())]))
+            .remove_node_from_groups(&["0".into(), "1".into()], &"0".into())
             .unwrap();
 
-        graphrecord
-            .add_group("1".into(), Some(vec!["4".into()]), None)
-            .unwrap();
+        assert_eq!(1, graphrecord.nodes_in_group(&"0".into()).unwrap().count());
+        assert_eq!(1, graphrecord.nodes_in_group(&"1".into()).unwrap().count());
+    }
 
-        graphrecord.freeze_schema().unwrap();
+    #[test]
+    fn test_invalid_remove_node_from_groups() {
+        let mut graphrecord = create_graphrecord();
 
         graphrecord
-            .add_node("5".into(), HashMap::from([("test".into(), "test".into())]))
+            .add_group("0".into(), Some(vec!["0".into()]), None)
+            .unwrap();
+        graphrecord
+            .add_group("1".into(), Some(vec!["1".into()]), None)
             .unwrap();
 
         assert!(
             graphrecord
-                .add_node_to_group("1".into(), "5".into())
-                .is_ok()
+                .remove_node_from_groups(&["0".into(), "1".into()], &"50".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::MissingNode { .. }))
         );
 
-        assert_eq!(2, graphrecord.nodes_in_group(&"1".into()).unwrap().count());
+        assert!(
+            graphrecord
+                .remove_node_from_groups(&["0".into(), "1".into()], &"1".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
     }
 
     #[test]
-    fn test_invalid_add_node_to_group() {
+    fn test_remove_edge_from_group() {
         let mut graphrecord = create_graphrecord();
 
         graphrecord
-            .add_group("0".into(), Some(vec!["0".into()]), None)
+            .add_group("0".into(), None, Some(vec![0, 1]))
             .unwrap();
 
-        // Adding a non-existing node to a group should fail
+        assert_eq!(2, graphrecord.edges_in_group(&"0".into()).unwrap().count());
+
+        graphrecord.remove_edge_from_group(&"0".into(), &0).unwrap();
+
+        assert_eq!(1, graphrecord.edges_in_group(&"0".into()).unwrap().count());
+    }
+
+    #[test]
+    fn test_invalid_remove_edge_from_group() {
+        let mut graphrecord = create_graphrecord();
+
+        graphrecord
+            .add_group("0".into(), None, Some(vec![0]))
+            .unwrap();
+
+        // Removing an edge from a non-existing group should fail
         assert!(
             graphrecord
-                .add_node_to_group("0".into(), "50".into())
+                .remove_edge_from_group(&"50".into(), &0)
                 .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
         );
 
-        // Adding a node to a group that already is in the group should fail
+        // Removing a non-existing edge from a group should fail
         assert!(
             graphrecord
-                .add_node_to_group("0".into(), "0".into())
-                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+                .remove_edge_from_group(&"0".into(), &50)
+                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
         );
 
-        let mut graphrecord = GraphRecord::new();
-
-        graphrecord
-            .add_node("0".into(), HashMap::from([("test".into(), "test".into())]))
-            .unwrap();
-        graphrecord.add_group("group".into(), None, None).unwrap();
-
-        graphrecord.freeze_schema().unwrap();
-
+        // Removing an edge from a group it is not in should fail
         assert!(
             graphrecord
-                .add_node_to_group("group".into(), "0".into())
-                .is_err_and(|e| matches!(e, GraphRecordError::SchemaError(_)))
+                .remove_edge_from_group(&"0".into(), &1)
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
         );
     }
 
     #[test]
-    fn test_add_node_to_groups() {
+    fn test_remove_edge_from_groups() {
         let mut graphrecord = create_graphrecord();
 
         graphrecord
-            .add_group("0".into(), Some(vec!["0".into()]), None)
+            .add_group("0".into(), None, Some(vec![0, 1]))
             .unwrap();
         graphrecord
-            .add_group("1".into(), Some(vec!["1".into()]), None)
+            .add_group("1".into(), None, Some(vec![0, 2]))
             .unwrap();
 
         graphrecord
-            .add_node_to_groups(&["0".into(), "1".into()], "2".into())
+            .remove_edge_from_groups(&["0".into(), "1".into()], &0)
             .unwrap();
 
-        assert_eq!(2, graphrecord.nodes_in_group(&"0".into()).unwrap().count());
-        assert_eq!(2, graphrecord.nodes_in_group(&"1".into()).unwrap().count());
+        assert_eq!(1, graphrecord.edges_in_group(&"0".into()).unwrap().count());
+        assert_eq!(1, graphrecord.edges_in_group(&"1".into()).unwrap().count());
     }
 
     #[test]
-    fn test_invalid_add_node_to_groups() {
+    fn test_invalid_remove_edge_from_groups() {
         let mut graphrecord = create_graphrecord();
 
         graphrecord
-            .add_group("0".into(), Some(vec!["0".into()]), None)
+            .add_group("0".into(), None, Some(vec![0]))
             .unwrap();
         graphrecord
-            .add_group("1".into(), Some(vec!["1".into()]), None)
+            .add_group("1".into(), None, Some(vec![1]))
             .unwrap();
 
         assert!(
             graphrecord
-                .add_node_to_groups(&["0".into(), "1".into()], "50".into())
+                .remove_edge_from_groups(&["0".into(), "1".into()], &50)
                 .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
         );
 
         assert!(
             graphrecord
-                .add_node_to_groups(&["0".into(), "1".into()], "0".into())
+                .remove_edge_from_groups(&["0".into(), "1".into()], &1)
                 .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
         );
-
-        let mut graphrecord = GraphRecord::new();
-
-        graphrecord
-            .add_node("0".into(), HashMap::from([("test".into(), "test".into())]))
-            .unwrap();
-        graphrecord.add_group("group".into(), None, None).unwrap();
-        graphrecord.add_group("group2".into(), None, None).unwrap();
-
-        graphrecord.freeze_schema().unwrap();
-
-        assert!(
-            graphrecord
-                .add_node_to_groups(&["group".into(), "group2".into()], "0".into())
-                .is_err_and(|e| matches!(e, GraphRecordError::SchemaError(_)))
-        );
     }
 
     #[test]
-    fn test_add_edge_to_group() {
+    fn test_add_nodes_to_groups() {
         let mut graphrecord = create_graphrecord();
 
         graphrecord
-            .add_group("0".into(), None, Some(vec![0, 1]))
+            .add_group("0".into(), Some(vec!["0".into()]), None)
             .unwrap();
-
-        assert_eq!(2, graphrecord.edges_in_group(&"0".into()).unwrap().count());
-
-        graphrecord.add_edge_to_group("0".into(), 2).unwrap();
-
-        assert_eq!(3, graphrecord.edges_in_group(&"0".into()).unwrap().count());
-
         graphrecord
-            .add_edge("0".into(), "1".into(), HashMap::new())
+            .add_group("1".into(), Some(vec!["1".into()]), None)
             .unwrap();
 
         graphrecord
-            .add_group("1".into(), None, Some(vec![3]))
-            .unwrap();
-
-        graphrecord.freeze_schema().unwrap();
-
-        let edge_index = graphrecord
-            .add_edge("0".into(), "1".into(), HashMap::new())
+            .add_nodes_to_groups(&["0".into(), "1".into()], vec!["2".into(), "3".into()])
             .unwrap();
 
-        assert!(
-            graphrecord
-                .add_edge_to_group("1".into(), edge_index)
-                .is_ok()
-        );
-
-        assert_eq!(2, graphrecord.edges_in_group(&"1".into()).unwrap().count());
+        assert_eq!(3, graphrecord.nodes_in_group(&"0".into()).unwrap().count());
+        assert_eq!(3, graphrecord.nodes_in_group(&"1".into()).unwrap().count());
     }
 
     #[test]
-    fn test_invalid_add_edge_to_group() {
+    fn test_invalid_add_nodes_to_groups() {
         let mut graphrecord = create_graphrecord();
 
         graphrecord
-            .add_group("0".into(), None, Some(vec![0]))
+            .add_group("0".into(), Some(vec!["0".into()]), None)
+            .unwrap();
+        graphrecord
+            .add_group("1".into(), Some(vec!["1".into()]), None)
             .unwrap();
 
-        // Adding a non-existing edge to a group should fail
         assert!(
             graphrecord
-                .add_edge_to_group("0".into(), 50)
-                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+                .add_nodes_to_groups(&["0".into(), "1".into()], vec!["50".into()],)
+                .is_err_and(|e| matches!(e, GraphRecordError::MissingNode { .. }))
         );
 
-        // Adding an edge to a group that already is in the group should fail
         assert!(
             graphrecord
-                .add_edge_to_group("0".into(), 0)
+                .add_nodes_to_groups(&["0".into(), "1".into()], vec!["0".into()],)
                 .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
         );
 
         let mut graphrecord = GraphRecord::new();
 
-        graphrecord.add_node("0".into(), HashMap::new()).unwrap();
         graphrecord
-            .add_edge(
-                "0".into(),
-                "0".into(),
-                HashMap::from([("test".into(), "test".into())]),
-            )
+            .add_node("0".into(), HashMap::from([("test".into(), "test".into())]))
             .unwrap();
         graphrecord.add_group("group".into(), None, None).unwrap();
+        graphrecord.add_group("group2".into(), None, None).unwrap();
 
         graphrecord.freeze_schema().unwrap();
 
         assert!(
             graphrecord
-                .add_edge_to_group("group".into(), 0)
+                .add_nodes_to_groups(&["group".into(), "group2".into()], vec!["0".into()],)
                 .is_err_and(|e| matches!(e, GraphRecordError::SchemaError(_)))
         );
     }
 
     #[test]
-    fn test_add_edge_to_groups() {
+    fn test_add_edges_to_groups() {
         let mut graphrecord = create_graphrecord();
 
         graphrecord
@@ -2863,15 +5010,15 @@ mod test {
             .unwrap();
 
         graphrecord
-            .add_edge_to_groups(&["0".into(), "1".into()], 2)
+            .add_edges_to_groups(&["0".into(), "1".into()], vec![2, 3])
             .unwrap();
 
-        assert_eq!(2, graphrecord.edges_in_group(&"0".into()).unwrap().count());
-        assert_eq!(2, graphrecord.edges_in_group(&"1".into()).unwrap().count());
+        assert_eq!(3, graphrecord.edges_in_group(&"0".into()).unwrap().count());
+        assert_eq!(3, graphrecord.edges_in_group(&"1".into()).unwrap().count());
     }
 
     #[test]
-    fn test_invalid_add_edge_to_groups() {
+    fn test_invalid_add_edges_to_groups() {
         let mut graphrecord = create_graphrecord();
 
         graphrecord
@@ -2883,13 +5030,13 @@ mod test {
 
         assert!(
             graphrecord
-                .add_edge_to_groups(&["0".into(), "1".into()], 50)
+                .add_edges_to_groups(&["0".into(), "1".into()], vec![50])
                 .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
         );
 
         assert!(
             graphrecord
-                .add_edge_to_groups(&["0".into(), "1".into()], 0)
+                .add_edges_to_groups(&["0".into(), "1".into()], vec![0])
                 .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
         );
 
@@ -2910,795 +5057,1290 @@ mod test {
 
         assert!(
             graphrecord
-                .add_edge_to_groups(&["group".into(), "group2".into()], 0)
+                .add_edges_to_groups(&["group".into(), "group2".into()], vec![0])
                 .is_err_and(|e| matches!(e, GraphRecordError::SchemaError(_)))
         );
     }
 
     #[test]
-    fn test_remove_node_from_group() {
+    fn test_remove_nodes_from_groups() {
         let mut graphrecord = create_graphrecord();
 
         graphrecord
-            .add_group("0".into(), Some(vec!["0".into(), "1".into()]), None)
+            .add_group(
+                "0".into(),
+                Some(vec!["0".into(), "1".into(), "2".into()]),
+                None,
+            )
+            .unwrap();
+        graphrecord
+            .add_group(
+                "1".into(),
+                Some(vec!["0".into(), "1".into(), "2".into()]),
+                None,
+            )
             .unwrap();
-
-        assert_eq!(2, graphrecord.nodes_in_group(&"0".into()).unwrap().count());
 
         graphrecord
-            .remove_node_from_group(&"0".into(), &"0".into())
+            .remove_nodes_from_groups(&["0".into(), "1".into()], &["0".into(), "1".into()])
             .unwrap();
 
         assert_eq!(1, graphrecord.nodes_in_group(&"0".into()).unwrap().count());
+        assert_eq!(1, graphrecord.nodes_in_group(&"1".into()).unwrap().count());
     }
 
     #[test]
-    fn test_invalid_remove_node_from_group() {
+    fn test_invalid_remove_nodes_from_groups() {
         let mut graphrecord = create_graphrecord();
 
         graphrecord
             .add_group("0".into(), Some(vec!["0".into()]), None)
             .unwrap();
+        graphrecord
+            .add_group("1".into(), Some(vec!["1".into()]), None)
+            .unwrap();
 
-        // Removing a node from a non-existing group should fail
-        assert!(
-            graphrecord
-                .remove_node_from_group(&"50".into(), &"0".into())
-                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
-        );
-
-        // Removing a non-existing node from a group should fail
         assert!(
             graphrecord
-                .remove_node_from_group(&"0".into(), &"50".into())
-                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+                .remove_nodes_from_groups(&["0".into(), "1".into()], &["50".into()],)
+                .is_err_and(|e| matches!(e, GraphRecordError::MissingNode { .. }))
         );
 
-        // Removing a node from a group it is not in should fail
         assert!(
             graphrecord
-                .remove_node_from_group(&"0".into(), &"1".into())
+                .remove_nodes_from_groups(&["0".into(), "1".into()], &["1".into()],)
                 .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
         );
     }
 
     #[test]
-    fn test_remove_node_from_groups() {
+    fn test_remove_edges_from_groups() {
         let mut graphrecord = create_graphrecord();
 
         graphrecord
-            .add_group("0".into(), Some(vec!["0".into(), "1".into()]), None)
+            .add_group("0".into(), None, Some(vec![0, 1, 2]))
             .unwrap();
         graphrecord
-            .add_group("1".into(), Some(vec!["0".into(), "2".into()]), None)
+            .add_group("1".into(), None, Some(vec![0, 1, 2]))
             .unwrap();
 
         graphrecord
-            .remove_node_from_groups(&["0".into(), "1".into()], &"0".into())
+            .remove_edges_from_groups(&["0".into(), "1".into()], &[0, 1])
             .unwrap();
 
-        assert_eq!(1, graphrecord.nodes_in_group(&"0".into()).unwrap().count());
-        assert_eq!(1, graphrecord.nodes_in_group(&"1".into()).unwrap().count());
+        assert_eq!(1, graphrecord.edges_in_group(&"0".into()).unwrap().count());
+        assert_eq!(1, graphrecord.edges_in_group(&"1".into()).unwrap().count());
     }
 
     #[test]
-    fn test_invalid_remove_node_from_groups() {
+    fn test_invalid_remove_edges_from_groups() {
         let mut graphrecord = create_graphrecord();
 
         graphrecord
-            .add_group("0".into(), Some(vec!["0".into()]), None)
+            .add_group("0".into(), None, Some(vec![0]))
             .unwrap();
         graphrecord
-            .add_group("1".into(), Some(vec!["1".into()]), None)
+            .add_group("1".into(), None, Some(vec![1]))
             .unwrap();
 
         assert!(
             graphrecord
-                .remove_node_from_groups(&["0".into(), "1".into()], &"50".into())
+                .remove_edges_from_groups(&["0".into(), "1".into()], &[50])
                 .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
         );
 
         assert!(
             graphrecord
-                .remove_node_from_groups(&["0".into(), "1".into()], &"1".into())
+                .remove_edges_from_groups(&["0".into(), "1".into()], &[1])
                 .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
         );
     }
 
     #[test]
-    fn test_remove_edge_from_group() {
+    fn test_add_node_with_groups() {
         let mut graphrecord = create_graphrecord();
 
+        graphrecord.add_group("0".into(), None, None).unwrap();
+        graphrecord.add_group("1".into(), None, None).unwrap();
+
         graphrecord
-            .add_group("0".into(), None, Some(vec![0, 1]))
+            .add_node_with_groups(
+                "4".into(),
+                HashMap::from([("lorem".into(), "ipsum".into())]),
+                &["0".into(), "1".into()],
+            )
             .unwrap();
 
-        assert_eq!(2, graphrecord.edges_in_group(&"0".into()).unwrap().count());
+        assert_eq!(5, graphrecord.node_count());
+        assert_eq!(1, graphrecord.nodes_in_group(&"0".into()).unwrap().count());
+        assert_eq!(1, graphrecord.nodes_in_group(&"1".into()).unwrap().count());
+    }
 
-        graphrecord.remove_edge_from_group(&"0".into(), &0).unwrap();
+    #[test]
+    fn test_invalid_add_node_with_groups() {
+        let mut graphrecord = create_graphrecord();
 
-        assert_eq!(1, graphrecord.edges_in_group(&"0".into()).unwrap().count());
+        graphrecord.add_group("0".into(), None, None).unwrap();
+        graphrecord.add_group("1".into(), None, None).unwrap();
+
+        assert!(
+            graphrecord
+                .add_node_with_groups("0".into(), HashMap::new(), &["0".into(), "1".into()],)
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
     }
 
     #[test]
-    fn test_invalid_remove_edge_from_group() {
+    fn test_add_edge_with_groups() {
         let mut graphrecord = create_graphrecord();
 
-        graphrecord
-            .add_group("0".into(), None, Some(vec![0]))
+        graphrecord.add_group("0".into(), None, None).unwrap();
+        graphrecord.add_group("1".into(), None, None).unwrap();
+
+        let edge_index = graphrecord
+            .add_edge_with_groups(
+                "0".into(),
+                "1".into(),
+                HashMap::from([("sed".into(), "do".into())]),
+                &["0".into(), "1".into()],
+            )
             .unwrap();
 
-        // Removing an edge from a non-existing group should fail
+        assert_eq!(5, graphrecord.edge_count());
+        assert_eq!(4, edge_index);
+        assert_eq!(1, graphrecord.edges_in_group(&"0".into()).unwrap().count());
+        assert_eq!(1, graphrecord.edges_in_group(&"1".into()).unwrap().count());
+    }
+
+    #[test]
+    fn test_invalid_add_edge_with_groups() {
+        let mut graphrecord = create_graphrecord();
+
+        graphrecord.add_group("0".into(), None, None).unwrap();
+        graphrecord.add_group("1".into(), None, None).unwrap();
+
         assert!(
             graphrecord
-                .remove_edge_from_group(&"50".into(), &0)
-                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+                .add_edge_with_groups(
+                    "50".into(),
+                    "0".into(),
+                    HashMap::new(),
+                    &["0".into(), "1".into()],
+                )
+                .is_err_and(|e| matches!(e, GraphRecordError::MissingNode { .. }))
         );
 
-        // Removing a non-existing edge from a group should fail
         assert!(
             graphrecord
-                .remove_edge_from_group(&"0".into(), &50)
-                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+                .add_edge_with_groups(
+                    "0".into(),
+                    "50".into(),
+                    HashMap::new(),
+                    &["0".into(), "1".into()],
+                )
+                .is_err_and(|e| matches!(e, GraphRecordError::MissingNode { .. }))
         );
+    }
+
+    #[test]
+    fn test_add_nodes_with_groups() {
+        let mut graphrecord = GraphRecord::new();
+
+        graphrecord.add_group("0".into(), None, None).unwrap();
+        graphrecord.add_group("1".into(), None, None).unwrap();
+
+        graphrecord
+            .add_nodes_with_groups(
+                vec![
+                    (
+                        "0".into(),
+                        HashMap::from([("lorem".into(), "ipsum".into())]),
+                    ),
+                    (
+                        "1".into(),
+                        HashMap::from([("amet".into(), "consectetur".into())]),
+                    ),
+                ],
+                &["0".into(), "1".into()],
+            )
+            .unwrap();
+
+        assert_eq!(2, graphrecord.node_count());
+        assert_eq!(2, graphrecord.nodes_in_group(&"0".into()).unwrap().count());
+        assert_eq!(2, graphrecord.nodes_in_group(&"1".into()).unwrap().count());
+    }
+
+    #[test]
+    fn test_invalid_add_nodes_with_groups() {
+        let mut graphrecord = create_graphrecord();
+
+        graphrecord.add_group("0".into(), None, None).unwrap();
+        graphrecord.add_group("1".into(), None, None).unwrap();
 
-        // Removing an edge from a group it is not in should fail
         assert!(
             graphrecord
-                .remove_edge_from_group(&"0".into(), &1)
+                .add_nodes_with_groups(
+                    vec![("0".into(), HashMap::new())],
+                    &["0".into(), "1".into()],
+                )
                 .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
         );
     }
 
     #[test]
-    fn test_remove_edge_from_groups() {
+    fn test_add_edges_with_groups() {
         let mut graphrecord = create_graphrecord();
 
-        graphrecord
-            .add_group("0".into(), None, Some(vec![0, 1]))
-            .unwrap();
-        graphrecord
-            .add_group("1".into(), None, Some(vec![0, 2]))
-            .unwrap();
+        graphrecord.add_group("0".into(), None, None).unwrap();
+        graphrecord.add_group("1".into(), None, None).unwrap();
 
-        graphrecord
-            .remove_edge_from_groups(&["0".into(), "1".into()], &0)
+        let edge_indices = graphrecord
+            .add_edges_with_groups(
+                vec![
+                    (
+                        "0".into(),
+                        "1".into(),
+                        HashMap::from([("sed".into(), "do".into())]),
+                    ),
+                    (
+                        "1".into(),
+                        "0".into(),
+                        HashMap::from([("sed".into(), "do".into())]),
+                    ),
+                ],
+                &["0".into(), "1".into()],
+            )
             .unwrap();
 
-        assert_eq!(1, graphrecord.edges_in_group(&"0".into()).unwrap().count());
-        assert_eq!(1, graphrecord.edges_in_group(&"1".into()).unwrap().count());
+        assert_eq!(6, graphrecord.edge_count());
+        assert_eq!(vec![4, 5], edge_indices);
+        assert_eq!(2, graphrecord.edges_in_group(&"0".into()).unwrap().count());
+        assert_eq!(2, graphrecord.edges_in_group(&"1".into()).unwrap().count());
     }
 
     #[test]
-    fn test_invalid_remove_edge_from_groups() {
+    fn test_invalid_add_edges_with_groups() {
         let mut graphrecord = create_graphrecord();
 
-        graphrecord
-            .add_group("0".into(), None, Some(vec![0]))
-            .unwrap();
-        graphrecord
-            .add_group("1".into(), None, Some(vec![1]))
-            .unwrap();
+        graphrecord.add_group("0".into(), None, None).unwrap();
+        graphrecord.add_group("1".into(), None, None).unwrap();
 
         assert!(
             graphrecord
-                .remove_edge_from_groups(&["0".into(), "1".into()], &50)
-                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+                .add_edges_with_groups(
+                    vec![("50".into(), "0".into(), HashMap::new())],
+                    &["0".into(), "1".into()],
+                )
+                .is_err_and(|e| matches!(e, GraphRecordError::MissingNode { .. }))
         );
 
         assert!(
             graphrecord
-                .remove_edge_from_groups(&["0".into(), "1".into()], &1)
-                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+                .add_edges_with_groups(
+                    vec![("0".into(), "50".into(), HashMap::new())],
+                    &["0".into(), "1".into()],
+                )
+                .is_err_and(|e| matches!(e, GraphRecordError::MissingNode { .. }))
         );
     }
 
     #[test]
-    fn test_add_nodes_to_groups() {
-        let mut graphrecord = create_graphrecord();
+    fn test_add_nodes_dataframes_with_groups() {
+        let mut graphrecord = GraphRecord::new();
+
+        graphrecord.add_group("0".into(), None, None).unwrap();
+        graphrecord.add_group("1".into(), None, None).unwrap();
+
+        let nodes_dataframe = create_nodes_dataframe().unwrap();
 
         graphrecord
-            .add_group("0".into(), Some(vec!["0".into()]), None)
-            .unwrap();
-        graphrecord
-            .add_group("1".into(), Some(vec!["1".into()]), None)
+            .add_nodes_dataframes_with_groups(
+                vec![NodeDataFrameInput {
+                    dataframe: nodes_dataframe,
+                    index_column: "index".to_string(),
+                    nulls: NullHandling::default(),
+                }],
+                &["0".into(), "1".into()],
+            )
             .unwrap();
 
-        graphrecord
-            .add_nodes_to_groups(&["0".into(), "1".into()], vec!["2".into(), "3".into()])
+        assert_eq!(2, graphrecord.node_count());
+        assert_eq!(2, graphrecord.nodes_in_group(&"0".into()).unwrap().count());
+        assert_eq!(2, graphrecord.nodes_in_group(&"1".into()).unwrap().count());
+    }
+
+    #[test]
+    fn test_add_edges_dataframes_with_groups() {
+        let mut graphrecord = GraphRecord::new();
+
+        let nodes = create_nodes();
+
+        graphrecord.add_nodes(nodes).unwrap();
+
+        graphrecord.add_group("0".into(), None, None).unwrap();
+        graphrecord.add_group("1".into(), None, None).unwrap();
+
+        let edges_dataframe = create_edges_dataframe().unwrap();
+
+        let edge_indices = graphrecord
+            .add_edges_dataframes_with_groups(
+                vec![EdgeDataFrameInput {
+                    dataframe: edges_dataframe,
+                    source_index_column: "from".to_string(),
+                    target_index_column: "to".to_string(),
+                    nulls: NullHandling::default(),
+                    missing_nodes: MissingNodePolicy::default(),
+                }],
+                &["0".into(), "1".into()],
+            )
             .unwrap();
 
-        assert_eq!(3, graphrecord.nodes_in_group(&"0".into()).unwrap().count());
-        assert_eq!(3, graphrecord.nodes_in_group(&"1".into()).unwrap().count());
+        assert_eq!(2, graphrecord.edge_count());
+        assert_eq!(2, edge_indices.len());
+        assert_eq!(2, graphrecord.edges_in_group(&"0".into()).unwrap().count());
+        assert_eq!(2, graphrecord.edges_in_group(&"1".into()).unwrap().count());
     }
 
     #[test]
-    fn test_invalid_add_nodes_to_groups() {
-        let mut graphrecord = create_graphrecord();
+    fn test_add_edges_dataframes_missing_nodes() {
+        let mut graphrecord = GraphRecord::new();
 
         graphrecord
-            .add_group("0".into(), Some(vec!["0".into()]), None)
-            .unwrap();
-        graphrecord
-            .add_group("1".into(), Some(vec!["1".into()]), None)
+            .add_node("0".into(), HashMap::from([("age".into(), 20.into())]))
             .unwrap();
 
+        // Adding edges with missing nodes should fail by default
         assert!(
             graphrecord
-                .add_nodes_to_groups(&["0".into(), "1".into()], vec!["50".into()],)
-                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
-        );
-
-        assert!(
-            graphrecord
-                .add_nodes_to_groups(&["0".into(), "1".into()], vec!["0".into()],)
-                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+                .add_edges_dataframes(vec![(create_edges_dataframe().unwrap(), "from", "to")])
+                .is_err_and(|e| matches!(e, GraphRecordError::MissingNode { .. }))
         );
+        assert_eq!(1, graphrecord.node_count());
 
-        let mut graphrecord = GraphRecord::new();
-
-        graphrecord
-            .add_node("0".into(), HashMap::from([("test".into(), "test".into())]))
+        let edge_indices = graphrecord
+            .add_edges_dataframes(vec![EdgeDataFrameInput {
+                dataframe: create_edges_dataframe().unwrap(),
+                source_index_column: "from".to_string(),
+                target_index_column: "to".to_string(),
+                nulls: NullHandling::default(),
+                missing_nodes: MissingNodePolicy::Create {
+                    group: Some("created".into()),
+                },
+            }])
             .unwrap();
-        graphrecord.add_group("group".into(), None, None).unwrap();
-        graphrecord.add_group("group2".into(), None, None).unwrap();
 
-        graphrecord.freeze_schema().unwrap();
+        assert_eq!(2, edge_indices.len());
+        assert_eq!(2, graphrecord.node_count());
+        assert_eq!(
+            vec![&NodeIndex::from("1")],
+            graphrecord
+                .nodes_in_group(&"created".into())
+                .unwrap()
+                .collect::<Vec<_>>()
+        );
+        assert!(graphrecord.node_attributes(&"1".into()).unwrap().is_empty());
 
-        assert!(
+        // Existing nodes keep their attributes
+        assert_eq!(
+            &GraphRecordValue::from(20),
             graphrecord
-                .add_nodes_to_groups(&["group".into(), "group2".into()], vec!["0".into()],)
-                .is_err_and(|e| matches!(e, GraphRecordError::SchemaError(_)))
+                .node_attributes(&"0".into())
+                .unwrap()
+                .get(&"age".into())
+                .unwrap()
         );
     }
 
     #[test]
-    fn test_add_edges_to_groups() {
+    fn test_groups() {
         let mut graphrecord = create_graphrecord();
 
-        graphrecord
-            .add_group("0".into(), None, Some(vec![0]))
-            .unwrap();
-        graphrecord
-            .add_group("1".into(), None, Some(vec![1]))
-            .unwrap();
+        graphrecord.add_group("0".into(), None, None).unwrap();
 
-        graphrecord
-            .add_edges_to_groups(&["0".into(), "1".into()], vec![2, 3])
-            .unwrap();
+        let groups: Vec<_> = graphrecord.groups().collect();
 
-        assert_eq!(3, graphrecord.edges_in_group(&"0".into()).unwrap().count());
-        assert_eq!(3, graphrecord.edges_in_group(&"1".into()).unwrap().count());
+        assert_eq!(vec![&(GraphRecordAttribute::from("0"))], groups);
     }
 
     #[test]
-    fn test_invalid_add_edges_to_groups() {
+    fn test_nodes_in_group() {
         let mut graphrecord = create_graphrecord();
 
-        graphrecord
-            .add_group("0".into(), None, Some(vec![0]))
-            .unwrap();
-        graphrecord
-            .add_group("1".into(), None, Some(vec![1]))
-            .unwrap();
-
-        assert!(
-            graphrecord
-                .add_edges_to_groups(&["0".into(), "1".into()], vec![50])
-                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
-        );
-
-        assert!(
-            graphrecord
-                .add_edges_to_groups(&["0".into(), "1".into()], vec![0])
-                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
-        );
+        graphrecord.add_group("0".into(), None, None).unwrap();
 
-        let mut graphrecord = GraphRecord::new();
+        assert_eq!(0, graphrecord.nodes_in_group(&"0".into()).unwrap().count());
 
-        graphrecord.add_node("0".into(), HashMap::new()).unwrap();
         graphrecord
-            .add_edge(
-                "0".into(),
-                "0".into(),
-                HashMap::from([("test".into(), "test".into())]),
-            )
+            .add_group("1".into(), Some(vec!["0".into()]), None)
             .unwrap();
-        graphrecord.add_group("group".into(), None, None).unwrap();
-        graphrecord.add_group("group2".into(), None, None).unwrap();
 
-        graphrecord.freeze_schema().unwrap();
+        assert_eq!(1, graphrecord.nodes_in_group(&"1".into()).unwrap().count());
+    }
+
+    #[test]
+    fn test_invalid_nodes_in_group() {
+        let graphrecord = create_graphrecord();
 
+        // Querying a non-existing group should fail
         assert!(
             graphrecord
-                .add_edges_to_groups(&["group".into(), "group2".into()], vec![0])
-                .is_err_and(|e| matches!(e, GraphRecordError::SchemaError(_)))
+                .nodes_in_group(&"0".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
         );
     }
 
     #[test]
-    fn test_remove_nodes_from_groups() {
+    fn test_edges_in_group() {
         let mut graphrecord = create_graphrecord();
 
-        graphrecord
-            .add_group(
-                "0".into(),
-                Some(vec!["0".into(), "1".into(), "2".into()]),
-                None,
-            )
-            .unwrap();
-        graphrecord
-            .add_group(
-                "1".into(),
-                Some(vec!["0".into(), "1".into(), "2".into()]),
-                None,
-            )
-            .unwrap();
+        graphrecord.add_group("0".into(), None, None).unwrap();
+
+        assert_eq!(0, graphrecord.edges_in_group(&"0".into()).unwrap().count());
 
         graphrecord
-            .remove_nodes_from_groups(&["0".into(), "1".into()], &["0".into(), "1".into()])
+            .add_group("1".into(), None, Some(vec![0]))
             .unwrap();
 
-        assert_eq!(1, graphrecord.nodes_in_group(&"0".into()).unwrap().count());
-        assert_eq!(1, graphrecord.nodes_in_group(&"1".into()).unwrap().count());
+        assert_eq!(1, graphrecord.edges_in_group(&"1".into()).unwrap().count());
     }
 
     #[test]
-    fn test_invalid_remove_nodes_from_groups() {
-        let mut graphrecord = create_graphrecord();
-
-        graphrecord
-            .add_group("0".into(), Some(vec!["0".into()]), None)
-            .unwrap();
-        graphrecord
-            .add_group("1".into(), Some(vec!["1".into()]), None)
-            .unwrap();
+    fn test_invalid_edges_in_group() {
+        let graphrecord = create_graphrecord();
 
+        // Querying a non-existing group should fail
         assert!(
             graphrecord
-                .remove_nodes_from_groups(&["0".into(), "1".into()], &["50".into()],)
+                .edges_in_group(&"0".into())
                 .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
         );
-
-        assert!(
-            graphrecord
-                .remove_nodes_from_groups(&["0".into(), "1".into()], &["1".into()],)
-                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
-        );
     }
 
     #[test]
-    fn test_remove_edges_from_groups() {
+    fn test_groups_of_node() {
         let mut graphrecord = create_graphrecord();
 
         graphrecord
-            .add_group("0".into(), None, Some(vec![0, 1, 2]))
-            .unwrap();
-        graphrecord
-            .add_group("1".into(), None, Some(vec![0, 1, 2]))
+            .add_group("0".into(), Some(vec!["0".into()]), None)
             .unwrap();
 
-        graphrecord
-            .remove_edges_from_groups(&["0".into(), "1".into()], &[0, 1])
-            .unwrap();
+        assert_eq!(1, graphrecord.groups_of_node(&"0".into()).unwrap().count());
+    }
 
-        assert_eq!(1, graphrecord.edges_in_group(&"0".into()).unwrap().count());
-        assert_eq!(1, graphrecord.edges_in_group(&"1".into()).unwrap().count());
+    #[test]
+    fn test_invalid_groups_of_node() {
+        let graphrecord = create_graphrecord();
+
+        // Queyring the groups of a non-existing node should fail
+        assert!(
+            graphrecord
+                .groups_of_node(&"50".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::MissingNode { .. }))
+        );
     }
 
     #[test]
-    fn test_invalid_remove_edges_from_groups() {
+    fn test_groups_of_edge() {
         let mut graphrecord = create_graphrecord();
 
         graphrecord
             .add_group("0".into(), None, Some(vec![0]))
             .unwrap();
-        graphrecord
-            .add_group("1".into(), None, Some(vec![1]))
-            .unwrap();
 
+        assert_eq!(1, graphrecord.groups_of_edge(&0).unwrap().count());
+    }
+
+    #[test]
+    fn test_invalid_groups_of_edge() {
+        let graphrecord = create_graphrecord();
+
+        // Queyring the groups of a non-existing edge should fail
         assert!(
             graphrecord
-                .remove_edges_from_groups(&["0".into(), "1".into()], &[50])
+                .groups_of_edge(&50)
                 .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
         );
+    }
 
-        assert!(
-            graphrecord
-                .remove_edges_from_groups(&["0".into(), "1".into()], &[1])
-                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
-        );
+    #[test]
+    fn test_node_count() {
+        let mut graphrecord = GraphRecord::new();
+
+        assert_eq!(0, graphrecord.node_count());
+
+        graphrecord.add_node("0".into(), HashMap::new()).unwrap();
+
+        assert_eq!(1, graphrecord.node_count());
     }
 
     #[test]
-    fn test_add_node_with_groups() {
-        let mut graphrecord = create_graphrecord();
+    fn test_edge_count() {
+        let mut graphrecord = GraphRecord::new();
 
-        graphrecord.add_group("0".into(), None, None).unwrap();
-        graphrecord.add_group("1".into(), None, None).unwrap();
+        graphrecord.add_node("0".into(), HashMap::new()).unwrap();
+        graphrecord.add_node("1".into(), HashMap::new()).unwrap();
+
+        assert_eq!(0, graphrecord.edge_count());
 
         graphrecord
-            .add_node_with_groups(
-                "4".into(),
-                HashMap::from([("lorem".into(), "ipsum".into())]),
-                &["0".into(), "1".into()],
-            )
+            .add_edge("0".into(), "1".into(), HashMap::new())
             .unwrap();
 
-        assert_eq!(5, graphrecord.node_count());
-        assert_eq!(1, graphrecord.nodes_in_group(&"0".into()).unwrap().count());
-        assert_eq!(1, graphrecord.nodes_in_group(&"1".into()).unwrap().count());
+        assert_eq!(1, graphrecord.edge_count());
     }
 
     #[test]
-    fn test_invalid_add_node_with_groups() {
+    fn test_group_count() {
         let mut graphrecord = create_graphrecord();
 
+        assert_eq!(0, graphrecord.group_count());
+
         graphrecord.add_group("0".into(), None, None).unwrap();
-        graphrecord.add_group("1".into(), None, None).unwrap();
 
-        assert!(
-            graphrecord
-                .add_node_with_groups("0".into(), HashMap::new(), &["0".into(), "1".into()],)
-                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
-        );
+        assert_eq!(1, graphrecord.group_count());
     }
 
     #[test]
-    fn test_add_edge_with_groups() {
+    fn test_memory_usage() {
+        assert_eq!(0, GraphRecord::new().memory_usage().total());
+
         let mut graphrecord = create_graphrecord();
 
-        graphrecord.add_group("0".into(), None, None).unwrap();
-        graphrecord.add_group("1".into(), None, None).unwrap();
+        let memory_usage = graphrecord.memory_usage();
 
-        let edge_index = graphrecord
-            .add_edge_with_groups(
-                "0".into(),
-                "1".into(),
-                HashMap::from([("sed".into(), "do".into())]),
-                &["0".into(), "1".into()],
-            )
+        assert!(memory_usage.nodes > 0);
+        assert!(memory_usage.node_attributes > 0);
+        assert!(memory_usage.edges > 0);
+        assert!(memory_usage.edge_attributes > 0);
+        assert_eq!(0, memory_usage.groups);
+
+        graphrecord
+            .add_group("0".into(), Some(vec!["0".into()]), None)
+            .unwrap();
+
+        assert!(graphrecord.memory_usage().groups > 0);
+    }
+
+    #[test]
+    fn test_shrink_to_fit() {
+        let mut graphrecord = GraphRecord::with_capacity(1000, 1000, None);
+        graphrecord
+            .add_node("0".into(), HashMap::from([("key".into(), "value".into())]))
             .unwrap();
 
-        assert_eq!(5, graphrecord.edge_count());
-        assert_eq!(4, edge_index);
-        assert_eq!(1, graphrecord.edges_in_group(&"0".into()).unwrap().count());
-        assert_eq!(1, graphrecord.edges_in_group(&"1".into()).unwrap().count());
+        let memory_usage = graphrecord.memory_usage();
+
+        graphrecord.shrink_to_fit();
+
+        assert!(graphrecord.memory_usage().nodes < memory_usage.nodes);
+        assert!(graphrecord.memory_usage().edges < memory_usage.edges);
+        assert_eq!(1, graphrecord.node_count());
     }
 
     #[test]
-    fn test_invalid_add_edge_with_groups() {
+    fn test_reserve_group() {
         let mut graphrecord = create_graphrecord();
 
         graphrecord.add_group("0".into(), None, None).unwrap();
-        graphrecord.add_group("1".into(), None, None).unwrap();
 
-        assert!(
-            graphrecord
-                .add_edge_with_groups(
-                    "50".into(),
-                    "0".into(),
-                    HashMap::new(),
-                    &["0".into(), "1".into()],
-                )
-                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
-        );
+        let memory_usage = graphrecord.memory_usage();
+
+        graphrecord.reserve_group(&"0".into(), 1000, 1000).unwrap();
+
+        assert!(graphrecord.memory_usage().groups > memory_usage.groups);
+
+        graphrecord.shrink_to_fit();
+
+        assert!(graphrecord.memory_usage().groups <= memory_usage.groups);
+    }
+
+    #[test]
+    fn test_invalid_reserve_group() {
+        let mut graphrecord = create_graphrecord();
 
         assert!(
             graphrecord
-                .add_edge_with_groups(
-                    "0".into(),
-                    "50".into(),
-                    HashMap::new(),
-                    &["0".into(), "1".into()],
-                )
+                .reserve_group(&"0".into(), 1000, 1000)
                 .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
         );
     }
 
     #[test]
-    fn test_add_nodes_with_groups() {
-        let mut graphrecord = GraphRecord::new();
+    fn test_stats() {
+        let stats = GraphRecord::new().stats();
 
-        graphrecord.add_group("0".into(), None, None).unwrap();
-        graphrecord.add_group("1".into(), None, None).unwrap();
+        assert_eq!(0, stats.node_count);
+        assert!(stats.density.abs() < f64::EPSILON);
+        assert_eq!(None, stats.degree);
+        assert_eq!(0, stats.connected_components);
+
+        let mut graphrecord = create_graphrecord();
 
         graphrecord
-            .add_nodes_with_groups(
-                vec![
-                    (
-                        "0".into(),
-                        HashMap::from([("lorem".into(), "ipsum".into())]),
-                    ),
-                    (
-                        "1".into(),
-                        HashMap::from([("amet".into(), "consectetur".into())]),
-                    ),
-                ],
-                &["0".into(), "1".into()],
+            .add_group(
+                "0".into(),
+                Some(vec!["0".into(), "1".into()]),
+                Some(vec![0]),
             )
             .unwrap();
 
-        assert_eq!(2, graphrecord.node_count());
-        assert_eq!(2, graphrecord.nodes_in_group(&"0".into()).unwrap().count());
-        assert_eq!(2, graphrecord.nodes_in_group(&"1".into()).unwrap().count());
-    }
+        let stats = graphrecord.stats();
 
-    #[test]
-    fn test_invalid_add_nodes_with_groups() {
-        let mut graphrecord = create_graphrecord();
+        assert_eq!(4, stats.node_count);
+        assert_eq!(4, stats.edge_count);
+        assert!((stats.density - 4.0 / 12.0).abs() < f64::EPSILON);
+        assert_eq!(
+            Some(DegreeSummary {
+                min: 0,
+                mean: 2.0,
+                max: 3,
+            }),
+            stats.degree
+        );
+        assert_eq!(2, stats.connected_components);
+        assert_eq!(
+            HashMap::from([(
+                "0".into(),
+                GroupStats {
+                    node_count: 2,
+                    edge_count: 1,
+                }
+            )]),
+            stats.groups
+        );
 
-        graphrecord.add_group("0".into(), None, None).unwrap();
-        graphrecord.add_group("1".into(), None, None).unwrap();
+        graphrecord.set_edge_undirected(&2, true).unwrap();
+        graphrecord
+            .add_edge("3".into(), "3".into(), HashMap::new())
+            .unwrap();
+        let self_loop = graphrecord
+            .add_edge("3".into(), "3".into(), HashMap::new())
+            .unwrap();
+        graphrecord.set_edge_undirected(&self_loop, true).unwrap();
 
-        assert!(
-            graphrecord
-                .add_nodes_with_groups(
-                    vec![("0".into(), HashMap::new())],
-                    &["0".into(), "1".into()],
-                )
-                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
-        );
+        let stats = graphrecord.stats();
+
+        assert_eq!(6, stats.edge_count);
+        assert!((stats.density - 7.0 / 12.0).abs() < f64::EPSILON);
     }
 
     #[test]
-    fn test_add_edges_with_groups() {
+    fn test_group_adjacency_counts() {
         let mut graphrecord = create_graphrecord();
 
-        graphrecord.add_group("0".into(), None, None).unwrap();
-        graphrecord.add_group("1".into(), None, None).unwrap();
+        assert_eq!(
+            HashMap::from([((None, None), 4)]),
+            graphrecord.group_adjacency_counts()
+        );
 
-        let edge_indices = graphrecord
-            .add_edges_with_groups(
-                vec![
-                    (
-                        "0".into(),
-                        "1".into(),
-                        HashMap::from([("sed".into(), "do".into())]),
-                    ),
-                    (
-                        "1".into(),
-                        "0".into(),
-                        HashMap::from([("sed".into(), "do".into())]),
-                    ),
-                ],
-                &["0".into(), "1".into()],
-            )
+        graphrecord
+            .add_group("0".into(), Some(vec!["0".into(), "1".into()]), None)
+            .unwrap();
+        graphrecord
+            .add_group("1".into(), Some(vec!["1".into(), "2".into()]), None)
             .unwrap();
 
-        assert_eq!(6, graphrecord.edge_count());
-        assert_eq!(vec![4, 5], edge_indices);
-        assert_eq!(2, graphrecord.edges_in_group(&"0".into()).unwrap().count());
-        assert_eq!(2, graphrecord.edges_in_group(&"1".into()).unwrap().count());
+        let counts = graphrecord.group_adjacency_counts();
+
+        assert_eq!(9, counts.len());
+        assert_eq!(2, counts[&(Some("0".into()), Some("0".into()))]);
+        assert_eq!(3, counts[&(Some("0".into()), Some("1".into()))]);
+        assert_eq!(1, counts[&(Some("1".into()), Some("0".into()))]);
+        assert_eq!(1, counts[&(Some("1".into()), Some("1".into()))]);
+        assert_eq!(0, counts[&(None, Some("1".into()))]);
+        assert_eq!(0, counts[&(None, None)]);
     }
 
     #[test]
-    fn test_invalid_add_edges_with_groups() {
-        let mut graphrecord = create_graphrecord();
+    fn test_overview_format() {
+        let graphrecord = create_graphrecord();
 
-        graphrecord.add_group("0".into(), None, None).unwrap();
-        graphrecord.add_group("1".into(), None, None).unwrap();
+        let overview = graphrecord
+            .overview(None, OverviewFormat::Csv, None)
+            .unwrap()
+            .to_string();
 
         assert!(
-            graphrecord
-                .add_edges_with_groups(
-                    vec![("50".into(), "0".into(), HashMap::new())],
-                    &["0".into(), "1".into()],
-                )
-                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+            overview.starts_with("Group,Node Count,Attribute,Attribute Type,Data Type,Details\n")
         );
+        assert!(overview.contains("Group,Edge Count,Attribute,Attribute Type,Data Type,Details\n"));
+
+        let overview = graphrecord
+            .overview(None, OverviewFormat::Markdown, None)
+            .unwrap()
+            .to_string();
+
+        assert!(overview.starts_with("### Node Overview\n\n| Group"));
+        assert!(!overview.contains('\u{2500}'));
+
+        let overview = graphrecord
+            .overview(None, OverviewFormat::Ascii, None)
+            .unwrap()
+            .to_string();
+
+        assert!(overview.starts_with('+'));
+        assert!(overview.is_ascii());
 
         assert!(
             graphrecord
-                .add_edges_with_groups(
-                    vec![("0".into(), "50".into(), HashMap::new())],
-                    &["0".into(), "1".into()],
-                )
-                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+                .group_overview(&"0".into(), None, OverviewFormat::Csv, None)
+                .is_err()
         );
     }
 
     #[test]
-    fn test_add_nodes_dataframes_with_groups() {
-        let mut graphrecord = GraphRecord::new();
+    fn test_overview_to_html() {
+        let mut graphrecord = create_graphrecord();
 
-        graphrecord.add_group("0".into(), None, None).unwrap();
-        graphrecord.add_group("1".into(), None, None).unwrap();
+        graphrecord
+            .add_group("<group>".into(), Some(vec!["0".into()]), None)
+            .unwrap();
 
-        let nodes_dataframe = create_nodes_dataframe().unwrap();
+        let html = graphrecord
+            .overview(None, OverviewFormat::default(), None)
+            .unwrap()
+            .to_html();
+
+        assert!(html.starts_with("<div class=\"graphrecords-overview\">"));
+        assert!(html.ends_with("</div>"));
+        assert!(html.contains("<h4>Ungrouped</h4>"));
+        assert!(html.contains("<h4>&lt;group&gt;</h4>"));
+        assert!(html.contains("<td>lorem</td>"));
+
+        let html = graphrecord
+            .group_overview(&"<group>".into(), None, OverviewFormat::default(), None)
+            .unwrap()
+            .to_html();
+
+        assert!(html.contains("Node Overview (Node Count: 1)"));
+        assert!(!html.contains("<h4>"));
+    }
+
+    #[test]
+    fn test_overview_sampled() {
+        let mut graphrecord = GraphRecord::new();
 
         graphrecord
-            .add_nodes_dataframes_with_groups(
-                vec![NodeDataFrameInput {
-                    dataframe: nodes_dataframe,
-                    index_column: "index".to_string(),
-                }],
-                &["0".into(), "1".into()],
+            .add_nodes(
+                (0..100)
+                    .map(|index| {
+                        (
+                            index.into(),
+                            HashMap::from([("value".into(), index.into())]),
+                        )
+                    })
+                    .collect(),
             )
             .unwrap();
 
-        assert_eq!(2, graphrecord.node_count());
-        assert_eq!(2, graphrecord.nodes_in_group(&"0".into()).unwrap().count());
-        assert_eq!(2, graphrecord.nodes_in_group(&"1".into()).unwrap().count());
+        let overview = graphrecord
+            .overview_sampled(10, 42, None, OverviewFormat::default(), None)
+            .unwrap();
+
+        assert!(overview.is_approximate());
+        assert!(
+            !graphrecord
+                .overview(None, OverviewFormat::default(), None)
+                .unwrap()
+                .is_approximate()
+        );
+        assert_eq!(100, overview.ungrouped_overview.node_overview.count);
+        assert!(overview.to_string().contains("approximate, sample size 10"));
+        assert_eq!(
+            overview.to_string(),
+            graphrecord
+                .overview_sampled(10, 42, None, OverviewFormat::default(), None)
+                .unwrap()
+                .to_string()
+        );
+
+        let overview = graphrecord
+            .overview_sampled(1000, 42, None, OverviewFormat::default(), None)
+            .unwrap();
+
+        let AttributeOverviewData::Continuous { min, mean, max } =
+            &overview.ungrouped_overview.node_overview.attributes
+                [&GraphRecordAttribute::from("value")]
+                .data
+        else {
+            panic!("Attribute must be continuous");
+        };
+
+        assert_eq!(&GraphRecordValue::Int(0), min);
+        assert_eq!(&GraphRecordValue::Float(49.5), mean);
+        assert_eq!(&GraphRecordValue::Int(99), max);
     }
 
     #[test]
-    fn test_add_edges_dataframes_with_groups() {
+    fn test_overview_missing() {
         let mut graphrecord = GraphRecord::new();
 
-        let nodes = create_nodes();
+        graphrecord
+            .add_nodes(vec![
+                (
+                    "0".into(),
+                    HashMap::from([("value".into(), 1.into()), ("flag".into(), true.into())]),
+                ),
+                (
+                    "1".into(),
+                    HashMap::from([("value".into(), 3.into()), ("flag".into(), true.into())]),
+                ),
+                (
+                    "2".into(),
+                    HashMap::from([
+                        ("value".into(), GraphRecordValue::Null),
+                        ("flag".into(), GraphRecordValue::Null),
+                    ]),
+                ),
+                (
+                    "3".into(),
+                    HashMap::from([
+                        ("value".into(), GraphRecordValue::Missing),
+                        ("flag".into(), GraphRecordValue::Missing),
+                    ]),
+                ),
+            ])
+            .unwrap();
 
-        graphrecord.add_nodes(nodes).unwrap();
+        let overview = graphrecord
+            .overview(None, OverviewFormat::default(), None)
+            .unwrap();
+        let attributes = &overview.ungrouped_overview.node_overview.attributes;
 
-        graphrecord.add_group("0".into(), None, None).unwrap();
-        graphrecord.add_group("1".into(), None, None).unwrap();
+        let AttributeOverviewData::Continuous { min, mean, max } =
+            &attributes[&GraphRecordAttribute::from("value")].data
+        else {
+            panic!("Attribute must be continuous");
+        };
 
-        let edges_dataframe = create_edges_dataframe().unwrap();
+        assert_eq!(&GraphRecordValue::Int(1), min);
+        assert_eq!(&GraphRecordValue::Float(2.0), mean);
+        assert_eq!(&GraphRecordValue::Int(3), max);
 
-        let edge_indices = graphrecord
-            .add_edges_dataframes_with_groups(
-                vec![EdgeDataFrameInput {
-                    dataframe: edges_dataframe,
-                    source_index_column: "from".to_string(),
-                    target_index_column: "to".to_string(),
-                }],
-                &["0".into(), "1".into()],
-            )
-            .unwrap();
+        let AttributeOverviewData::Categorical { value_counts, .. } =
+            &attributes[&GraphRecordAttribute::from("flag")].data
+        else {
+            panic!("Attribute must be categorical");
+        };
 
-        assert_eq!(2, graphrecord.edge_count());
-        assert_eq!(2, edge_indices.len());
-        assert_eq!(2, graphrecord.edges_in_group(&"0".into()).unwrap().count());
-        assert_eq!(2, graphrecord.edges_in_group(&"1".into()).unwrap().count());
+        assert_eq!(
+            &vec![
+                (GraphRecordValue::Bool(true), 2),
+                (GraphRecordValue::Null, 1)
+            ],
+            value_counts
+        );
     }
 
     #[test]
-    fn test_groups() {
-        let mut graphrecord = create_graphrecord();
+    fn test_overview_categorical_threshold() {
+        let mut graphrecord = GraphRecord::new();
 
-        graphrecord.add_group("0".into(), None, None).unwrap();
+        graphrecord
+            .add_nodes(
+                (0..10)
+                    .map(|index| {
+                        (
+                            index.into(),
+                            HashMap::from([("code".into(), (index % 4).into())]),
+                        )
+                    })
+                    .collect(),
+            )
+            .unwrap();
 
-        let groups: Vec<_> = graphrecord.groups().collect();
+        let mut schema = graphrecord.get_schema().clone();
+        schema.infer_attribute_types(&graphrecord);
+        graphrecord.set_schema(schema).unwrap();
 
-        assert_eq!(vec![&(GraphRecordAttribute::from("0"))], groups);
-    }
+        let categorical = |categorical_threshold| {
+            let overview = graphrecord
+                .overview(None, OverviewFormat::default(), categorical_threshold)
+                .unwrap();
 
-    #[test]
-    fn test_nodes_in_group() {
-        let mut graphrecord = create_graphrecord();
+            let AttributeOverviewData::Categorical {
+                value_counts,
+                other_count,
+            } = overview.ungrouped_overview.node_overview.attributes
+                [&GraphRecordAttribute::from("code")]
+                .data
+                .clone()
+            else {
+                panic!("Attribute must be categorical");
+            };
 
-        graphrecord.add_group("0".into(), None, None).unwrap();
+            (value_counts, other_count)
+        };
 
-        assert_eq!(0, graphrecord.nodes_in_group(&"0".into()).unwrap().count());
+        assert_eq!(
+            (
+                vec![(0.into(), 3), (1.into(), 3), (2.into(), 2), (3.into(), 2)],
+                0
+            ),
+            categorical(None)
+        );
+        assert_eq!(categorical(None), categorical(Some(4)));
+        assert_eq!(
+            (vec![(0.into(), 3), (1.into(), 3)], 4),
+            categorical(Some(2))
+        );
 
-        graphrecord
-            .add_group("1".into(), Some(vec!["0".into()]), None)
-            .unwrap();
+        let overview = graphrecord
+            .overview(None, OverviewFormat::Csv, Some(2))
+            .unwrap()
+            .to_string();
 
-        assert_eq!(1, graphrecord.nodes_in_group(&"1".into()).unwrap().count());
+        assert!(overview.contains("Most frequent values: [0 (3), 1 (3)]"));
+        assert!(overview.contains("Other value count: 4"));
     }
 
     #[test]
-    fn test_invalid_nodes_in_group() {
-        let graphrecord = create_graphrecord();
+    fn test_overview_structure() {
+        let mut graphrecord = GraphRecord::new();
 
-        // Querying a non-existing group should fail
-        assert!(
-            graphrecord
-                .nodes_in_group(&"0".into())
-                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+        graphrecord
+            .add_nodes(
+                (0..6)
+                    .map(|index| (index.to_string().into(), HashMap::new()))
+                    .collect(),
+            )
+            .unwrap();
+        graphrecord
+            .add_edges(vec![
+                ("0".into(), "3".into(), HashMap::new()),
+                ("1".into(), "3".into(), HashMap::new()),
+                ("2".into(), "4".into(), HashMap::new()),
+                ("0".into(), "1".into(), HashMap::new()),
+            ])
+            .unwrap();
+        graphrecord
+            .add_group(
+                "patient".into(),
+                Some(vec!["0".into(), "1".into(), "2".into(), "5".into()]),
+                None,
+            )
+            .unwrap();
+        graphrecord
+            .add_group("diagnosis".into(), Some(vec!["3".into()]), None)
+            .unwrap();
+
+        let overview = graphrecord
+            .overview(None, OverviewFormat::Csv, None)
+            .unwrap();
+
+        assert_eq!(
+            StructureOverview {
+                ungrouped_endpoints: 1,
+                isolated_nodes: 1,
+                average_degree: 1.25,
+                connected_groups: vec![("diagnosis".into(), 2)],
+            },
+            overview.grouped_overviews[&GraphRecordAttribute::from("patient")].structure
+        );
+        assert_eq!(
+            StructureOverview {
+                ungrouped_endpoints: 0,
+                isolated_nodes: 0,
+                average_degree: 2.0,
+                connected_groups: vec![("patient".into(), 2)],
+            },
+            overview.grouped_overviews[&GraphRecordAttribute::from("diagnosis")].structure
+        );
+        assert_eq!(
+            StructureOverview {
+                ungrouped_endpoints: 0,
+                isolated_nodes: 0,
+                average_degree: 1.0,
+                connected_groups: vec![("patient".into(), 1)],
+            },
+            overview.ungrouped_overview.structure
+        );
+        assert!(
+            overview
+                .to_string()
+                .contains("patient,1,1,1.25,diagnosis (2)\n")
         );
     }
 
     #[test]
-    fn test_edges_in_group() {
+    fn test_strict_queries() {
         let mut graphrecord = create_graphrecord();
 
-        graphrecord.add_group("0".into(), None, None).unwrap();
+        assert!(!graphrecord.strict_queries());
 
-        assert_eq!(0, graphrecord.edges_in_group(&"0".into()).unwrap().count());
+        let query = |graphrecord: &GraphRecord, attribute: &str| {
+            graphrecord
+                .query_nodes(|nodes| {
+                    nodes.attribute(attribute).equal_to("ipsum");
+
+                    nodes.index()
+                })
+                .evaluate()
+                .map(Iterator::count)
+        };
+
+        assert_eq!(0, query(&graphrecord, "lorm").unwrap());
+
+        graphrecord.set_strict_queries(true);
+
+        assert_eq!(1, query(&graphrecord, "lorem").unwrap());
+        assert!(matches!(
+            query(&graphrecord, "lorm").unwrap_err(),
+            GraphRecordError::SchemaError(message) if message.contains("Did you mean: lorem?")
+        ));
 
         graphrecord
-            .add_group("1".into(), None, Some(vec![0]))
+            .add_group("0".into(), Some(vec!["1".into()]), None)
             .unwrap();
 
-        assert_eq!(1, graphrecord.edges_in_group(&"1".into()).unwrap().count());
+        let result = graphrecord
+            .query_nodes(|nodes| {
+                nodes.in_group(GraphRecordAttribute::from("0"));
+
+                nodes.attribute("lorem")
+            })
+            .evaluate()
+            .map(Iterator::count);
+
+        assert!(matches!(
+            result.unwrap_err(),
+            GraphRecordError::SchemaError(message) if message.contains("of group 0")
+        ));
     }
 
     #[test]
-    fn test_invalid_edges_in_group() {
-        let graphrecord = create_graphrecord();
+    fn test_float_tolerance() {
+        let mut graphrecord = GraphRecord::from_tuples(
+            vec![
+                (
+                    "0".into(),
+                    HashMap::from([("weight".into(), (0.1 + 0.2).into())]),
+                ),
+                ("1".into(), HashMap::from([("weight".into(), 0.5.into())])),
+            ],
+            None,
+            None,
+        )
+        .unwrap();
 
-        // Querying a non-existing group should fail
-        assert!(
+        let query = |graphrecord: &GraphRecord| {
             graphrecord
-                .edges_in_group(&"0".into())
-                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
-        );
-    }
+                .query_nodes(|nodes| {
+                    nodes.attribute("weight").equal_to(0.3);
 
-    #[test]
-    fn test_groups_of_node() {
-        let mut graphrecord = create_graphrecord();
+                    nodes.index()
+                })
+                .evaluate()
+                .map(Iterator::count)
+        };
 
-        graphrecord
-            .add_group("0".into(), Some(vec!["0".into()]), None)
-            .unwrap();
+        assert_eq!(0, query(&graphrecord).unwrap());
 
-        assert_eq!(1, graphrecord.groups_of_node(&"0".into()).unwrap().count());
-    }
+        graphrecord.set_float_tolerance(1e-9).unwrap();
 
-    #[test]
-    fn test_invalid_groups_of_node() {
-        let graphrecord = create_graphrecord();
+        assert_eq!(1, query(&graphrecord).unwrap());
+
+        let count = graphrecord
+            .query_nodes(|nodes| {
+                nodes.attribute("weight").not_equal_to(0.3);
+
+                nodes.index()
+            })
+            .evaluate()
+            .unwrap()
+            .count();
+
+        assert_eq!(1, count);
+
+        let count = graphrecord
+            .query_nodes(|nodes| {
+                nodes.attribute("weight").is_close(0.45, 0.1);
+
+                nodes.index()
+            })
+            .evaluate()
+            .unwrap()
+            .count();
+
+        assert_eq!(1, count);
 
-        // Queyring the groups of a non-existing node should fail
         assert!(
             graphrecord
-                .groups_of_node(&"50".into())
-                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+                .set_float_tolerance(-1.0)
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
+        assert!(
+            graphrecord
+                .set_float_tolerance(f64::NAN)
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
         );
     }
 
     #[test]
-    fn test_groups_of_edge() {
-        let mut graphrecord = create_graphrecord();
+    fn test_random_seed() {
+        let nodes: Vec<_> = (0..100)
+            .map(|index| {
+                (
+                    index.into(),
+                    HashMap::from([("value".into(), (index * 2).into())]),
+                )
+            })
+            .collect();
 
-        graphrecord
-            .add_group("0".into(), None, Some(vec![0]))
-            .unwrap();
+        let mut graphrecord = GraphRecord::from_tuples(nodes.clone(), None, None).unwrap();
+        let mut reversed =
+            GraphRecord::from_tuples(nodes.into_iter().rev().collect(), None, None).unwrap();
 
-        assert_eq!(1, graphrecord.groups_of_edge(&0).unwrap().count());
-    }
+        assert_eq!(None, graphrecord.random_seed());
 
-    #[test]
-    fn test_invalid_groups_of_edge() {
-        let graphrecord = create_graphrecord();
+        graphrecord.set_random_seed(Some(42));
+        reversed.set_random_seed(Some(42));
 
-        // Queyring the groups of a non-existing edge should fail
-        assert!(
+        let random_index = |graphrecord: &GraphRecord| {
             graphrecord
-                .groups_of_edge(&50)
-                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
-        );
+                .query_nodes(|nodes| nodes.index().random())
+                .evaluate()
+                .unwrap()
+        };
+        let random_value = |graphrecord: &GraphRecord| {
+            graphrecord
+                .query_nodes(|nodes| nodes.attribute("value").random())
+                .evaluate()
+                .unwrap()
+                .map(|(index, value)| (index.clone(), value))
+        };
+
+        assert_eq!(Some(42), graphrecord.random_seed());
+        assert!(random_index(&graphrecord).is_some());
+        assert_eq!(random_index(&graphrecord), random_index(&graphrecord));
+        assert_eq!(random_index(&graphrecord), random_index(&reversed));
+        assert_eq!(random_value(&graphrecord), random_value(&reversed));
+
+        graphrecord.set_random_seed(None);
+
+        assert!(random_index(&graphrecord).is_some());
     }
 
     #[test]
-    fn test_node_count() {
-        let mut graphrecord = GraphRecord::new();
+    fn test_random_weighted() {
+        let create_graphrecord = |weights: [GraphRecordValue; 3]| {
+            GraphRecord::from_tuples(
+                vec![("0".into(), HashMap::new()), ("1".into(), HashMap::new())],
+                Some(
+                    weights
+                        .into_iter()
+                        .map(|weight| {
+                            (
+                                "0".into(),
+                                "1".into(),
+                                HashMap::from([("weight".into(), weight)]),
+                            )
+                        })
+                        .collect(),
+                ),
+                None,
+            )
+            .unwrap()
+        };
+        let random_weighted = |graphrecord: &GraphRecord| {
+            graphrecord
+                .query_edges(|edges| edges.attribute("weight").random_weighted("weight"))
+                .evaluate()
+                .map(|value| value.map(|(index, _)| *index))
+        };
 
-        assert_eq!(0, graphrecord.node_count());
+        let graphrecord = create_graphrecord([0.into(), 2.5.into(), 0.into()]);
 
-        graphrecord.add_node("0".into(), HashMap::new()).unwrap();
+        assert_eq!(Some(1), random_weighted(&graphrecord).unwrap());
 
-        assert_eq!(1, graphrecord.node_count());
+        let graphrecord = create_graphrecord([0.into(), 0.into(), 0.0.into()]);
+
+        assert_eq!(None, random_weighted(&graphrecord).unwrap());
+
+        let graphrecord = create_graphrecord([1.into(), (-1).into(), 1.into()]);
+
+        assert!(
+            random_weighted(&graphrecord)
+                .is_err_and(|e| matches!(e, GraphRecordError::QueryError(_)))
+        );
+
+        let graphrecord = create_graphrecord([1.into(), "1".into(), 1.into()]);
+
+        assert!(
+            random_weighted(&graphrecord)
+                .is_err_and(|e| matches!(e, GraphRecordError::QueryError(_)))
+        );
     }
 
     #[test]
-    fn test_edge_count() {
+    fn test_non_finite_float_policy() {
         let mut graphrecord = GraphRecord::new();
 
-        graphrecord.add_node("0".into(), HashMap::new()).unwrap();
-        graphrecord.add_node("1".into(), HashMap::new()).unwrap();
-
-        assert_eq!(0, graphrecord.edge_count());
-
         graphrecord
-            .add_edge("0".into(), "1".into(), HashMap::new())
+            .add_node("0".into(), HashMap::from([("value".into(), 1.0.into())]))
+            .unwrap();
+        graphrecord
+            .add_node(
+                "1".into(),
+                HashMap::from([("value".into(), f64::NAN.into())]),
+            )
+            .unwrap();
+        graphrecord
+            .add_node("2".into(), HashMap::from([("value".into(), 3.0.into())]))
             .unwrap();
 
-        assert_eq!(1, graphrecord.edge_count());
-    }
+        let mean = graphrecord
+            .query_nodes(|nodes| nodes.attribute("value").mean())
+            .evaluate()
+            .unwrap();
+        assert_eq!(Some(GraphRecordValue::Float(2.0)), mean);
 
-    #[test]
-    fn test_group_count() {
-        let mut graphrecord = create_graphrecord();
+        let max = graphrecord
+            .query_nodes(|nodes| nodes.attribute("value").max())
+            .evaluate()
+            .unwrap()
+            .map(|(_, value)| value);
+        assert_eq!(Some(GraphRecordValue::Float(3.0)), max);
 
-        assert_eq!(0, graphrecord.group_count());
+        let mut schema = graphrecord.get_schema().clone();
+        schema.set_non_finite_float_policy(NonFiniteFloatPolicy::Reject);
+        graphrecord.set_schema(schema).unwrap();
 
-        graphrecord.add_group("0".into(), None, None).unwrap();
+        assert!(
+            graphrecord
+                .add_node(
+                    "3".into(),
+                    HashMap::from([("value".into(), f64::INFINITY.into())])
+                )
+                .is_err_and(|e| matches!(e, GraphRecordError::SchemaError(_)))
+        );
+        assert!(
+            graphrecord
+                .node_attributes_mut(&"0".into())
+                .unwrap()
+                .update_attribute(&"value".into(), f64::NAN.into())
+                .is_err_and(|e| matches!(e, GraphRecordError::SchemaError(_)))
+        );
 
-        assert_eq!(1, graphrecord.group_count());
+        let mut schema = graphrecord.get_schema().clone();
+        schema.set_non_finite_float_policy(NonFiniteFloatPolicy::CoerceToNull);
+        graphrecord.set_schema(schema).unwrap();
+
+        graphrecord
+            .add_node(
+                "3".into(),
+                HashMap::from([("value".into(), f64::NEG_INFINITY.into())]),
+            )
+            .unwrap();
+
+        assert_eq!(
+            &GraphRecordValue::Null,
+            graphrecord
+                .node_attributes(&"3".into())
+                .unwrap()
+                .get(&"value".into())
+                .unwrap()
+        );
     }
 
     #[test]
@@ -3747,10 +6389,252 @@ mod test {
         assert!(
             graphrecord
                 .neighbors_outgoing(&"0".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::MissingNode { .. }))
+        );
+    }
+
+    #[test]
+    fn test_neighbors_filtered() {
+        let mut graphrecord = create_graphrecord();
+
+        let neighbors = |graphrecord: &GraphRecord, direction, group: Option<&Group>| {
+            let mut neighbors: Vec<_> = graphrecord
+                .neighbors_filtered(&"0".into(), direction, group, |_, attributes| {
+                    attributes.contains_key(&"sed".into())
+                })
+                .unwrap()
+                .cloned()
+                .collect();
+            neighbors.sort_by_key(ToString::to_string);
+
+            neighbors
+        };
+
+        assert_eq!(
+            vec![NodeIndex::from("1")],
+            neighbors(&graphrecord, EdgeDirection::Outgoing, None)
+        );
+        assert_eq!(
+            vec![NodeIndex::from("1")],
+            neighbors(&graphrecord, EdgeDirection::Incoming, None)
+        );
+        assert_eq!(
+            vec![NodeIndex::from("1")],
+            neighbors(&graphrecord, EdgeDirection::Both, None)
+        );
+
+        graphrecord
+            .add_group("group".into(), None, Some(vec![1, 3]))
+            .unwrap();
+
+        assert!(neighbors(&graphrecord, EdgeDirection::Outgoing, Some(&"group".into())).is_empty());
+        assert_eq!(
+            vec![NodeIndex::from("1")],
+            neighbors(&graphrecord, EdgeDirection::Incoming, Some(&"group".into()))
+        );
+        assert_eq!(
+            vec![NodeIndex::from("2")],
+            graphrecord
+                .neighbors_filtered(
+                    &"0".into(),
+                    EdgeDirection::Outgoing,
+                    Some(&"group".into()),
+                    |_, _| true
+                )
+                .unwrap()
+                .cloned()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_invalid_neighbors_filtered() {
+        let graphrecord = create_graphrecord();
+
+        assert!(
+            graphrecord
+                .neighbors_filtered(&"50".into(), EdgeDirection::Both, None, |_, _| true)
+                .is_err_and(|e| matches!(e, GraphRecordError::MissingNode { .. }))
+        );
+        assert!(
+            graphrecord
+                .neighbors_filtered(
+                    &"0".into(),
+                    EdgeDirection::Both,
+                    Some(&"group".into()),
+                    |_, _| true
+                )
                 .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
         );
     }
 
+    #[test]
+    fn test_sorted_indices() {
+        let mut graphrecord = create_graphrecord();
+
+        graphrecord.add_node(10.into(), HashMap::new()).unwrap();
+        graphrecord.add_group("b".into(), None, None).unwrap();
+        graphrecord.add_group("a".into(), None, None).unwrap();
+
+        assert_eq!(
+            vec![
+                NodeIndex::from(10),
+                "0".into(),
+                "1".into(),
+                "2".into(),
+                "3".into()
+            ],
+            graphrecord
+                .node_indices_sorted()
+                .cloned()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![0, 1, 2, 3],
+            graphrecord
+                .edge_indices_sorted()
+                .copied()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![Group::from("a"), "b".into()],
+            graphrecord.groups_sorted().cloned().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_to_dataframes_sorted() {
+        let graphrecord = create_graphrecord();
+
+        let export = graphrecord.to_dataframes_sorted().unwrap();
+
+        assert_eq!(
+            vec!["node_index", "adipiscing", "amet", "lorem"],
+            export
+                .ungrouped
+                .nodes
+                .get_column_names()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![
+                "edge_index",
+                "source_node_index",
+                "target_node_index",
+                "eiusmod",
+                "incididunt",
+                "sed"
+            ],
+            export
+                .ungrouped
+                .edges
+                .get_column_names()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        );
+
+        let node_indices = export.ungrouped.nodes.column("node_index").unwrap();
+        assert_eq!(
+            vec![Some("0"), Some("1"), Some("2"), Some("3")],
+            node_indices.str().unwrap().iter().collect::<Vec<_>>()
+        );
+
+        // Repeated exports are identical
+        assert!(
+            export
+                .ungrouped
+                .nodes
+                .equals_missing(&graphrecord.to_dataframes_sorted().unwrap().ungrouped.nodes)
+        );
+    }
+
+    #[test]
+    fn test_to_dataframes_with_options() {
+        let mut graphrecord = create_graphrecord();
+
+        graphrecord
+            .add_group(
+                "a".into(),
+                Some(vec!["0".into(), "1".into()]),
+                Some(vec![0]),
+            )
+            .unwrap();
+        graphrecord
+            .add_group("b".into(), Some(vec!["0".into()]), None)
+            .unwrap();
+
+        let export = graphrecord
+            .to_dataframes_with_options(&DataFramesExportOptions {
+                groups: Some(vec!["a".into()]),
+                ungrouped: false,
+                node_attributes: Some(vec!["lorem".into()]),
+                edge_attributes: Some(Vec::new()),
+                group_membership: true,
+                sorted: true,
+                ..Default::default()
+            })
+            .unwrap();
+
+        assert_eq!(
+            vec![Group::from("a")],
+            export.groups.keys().cloned().collect::<Vec<_>>()
+        );
+        assert_eq!(0, export.ungrouped.nodes.height());
+        assert_eq!(0, export.ungrouped.edges.height());
+
+        let group_export = &export.groups[&Group::from("a")];
+
+        assert_eq!(
+            vec!["node_index", "groups", "lorem"],
+            group_export
+                .nodes
+                .get_column_names()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![
+                "edge_index",
+                "source_node_index",
+                "target_node_index",
+                "groups"
+            ],
+            group_export
+                .edges
+                .get_column_names()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        );
+
+        let groups = group_export
+            .nodes
+            .column("groups")
+            .unwrap()
+            .list()
+            .unwrap()
+            .get_as_series(0)
+            .unwrap();
+        assert_eq!(
+            vec![Some("a"), Some("b")],
+            groups.str().unwrap().iter().collect::<Vec<_>>()
+        );
+
+        // Selecting a group that does not exist fails
+        assert!(
+            graphrecord
+                .to_dataframes_with_options(&DataFramesExportOptions {
+                    groups: Some(vec!["c".into()]),
+                    ..Default::default()
+                })
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_neighbors_undirected() {
         let graphrecord = create_graphrecord();
@@ -3769,7 +6653,7 @@ mod test {
         assert!(
             graphrecord
                 .neighbors_undirected(&"50".into())
-                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+                .is_err_and(|e| matches!(e, GraphRecordError::MissingNode { .. }))
         );
     }
 
@@ -3783,4 +6667,37 @@ mod test {
         assert_eq!(0, graphrecord.edge_count());
         assert_eq!(0, graphrecord.group_count());
     }
+
+    #[test]
+    fn test_with_operation_context() {
+        let mut graphrecord = GraphRecord::new();
+
+        let result = graphrecord.with_operation_context(
+            HashMap::from([("user".into(), "alice".into())]),
+            |graphrecord| {
+                graphrecord.with_operation_context(
+                    HashMap::from([("reason".into(), "import".into())]),
+                    |graphrecord| {
+                        assert_eq!(2, graphrecord.operation_context().len());
+
+                        graphrecord.add_node("0".into(), HashMap::new())
+                    },
+                )?;
+
+                assert_eq!(1, graphrecord.operation_context().len());
+                assert_eq!(
+                    Some(&GraphRecordValue::from("alice")),
+                    graphrecord
+                        .operation_context()
+                        .get(&GraphRecordAttribute::from("user"))
+                );
+
+                graphrecord.add_node("0".into(), HashMap::new())
+            },
+        );
+
+        assert!(result.is_err());
+        assert!(graphrecord.operation_context().is_empty());
+        assert_eq!(1, graphrecord.node_count());
+    }
 }
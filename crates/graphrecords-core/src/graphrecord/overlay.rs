@@ -0,0 +1,1321 @@
+//! Branches of a shared base record that only store their own changes, e.g.
+//! one scenario per user over a single large record.
+
+use super::{
+    AttributeMap, Attributes, EdgeIndex, GraphRecord, GraphRecordAttribute, Group, GroupMapping,
+    NodeIndex,
+    provenance::ProvenanceTag,
+    querying::{
+        ReturnOperand, Selection, edges::EdgeOperand, nodes::NodeOperand, wrapper::Wrapper,
+    },
+    schema::{Schema, SchemaType},
+    versioned::{GraphRecordDiff, GroupMembers},
+};
+use crate::errors::{GraphRecordError, GraphRecordResult};
+use graphrecords_utils::aliases::{GrHashMap, GrHashSet};
+use itertools::Either;
+use std::{iter, sync::Arc};
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn missing_edge(edge_index: &EdgeIndex) -> GraphRecordError {
+    GraphRecordError::IndexError(format!("Cannot find edge with index {edge_index}"))
+}
+
+/// Staged changes of an overlay, read through by the record the overlay
+/// queries.
+///
+/// Staged nodes and edges shadow the ones of the base record. Edges added by
+/// the overlay are also kept in adjacency sets of their own, so the edges of a
+/// node are the ones of the base record that are not removed, followed by the
+/// added ones. The base record is never layered itself, so it is read
+/// through its graph directly.
+#[derive(Debug, Clone)]
+pub(super) struct OverlayLayer {
+    base: Arc<GraphRecord>,
+    nodes: GrHashMap<NodeIndex, AttributeMap>,
+    removed_nodes: GrHashSet<NodeIndex>,
+    edges: GrHashMap<EdgeIndex, (NodeIndex, NodeIndex, AttributeMap)>,
+    removed_edges: GrHashSet<EdgeIndex>,
+    outgoing_edges: GrHashMap<NodeIndex, GrHashSet<EdgeIndex>>,
+    incoming_edges: GrHashMap<NodeIndex, GrHashSet<EdgeIndex>>,
+    edge_index_counter: EdgeIndex,
+}
+
+impl OverlayLayer {
+    fn new(base: Arc<GraphRecord>) -> Self {
+        let (edge_index_counter, _) = base.graph.edge_index_state();
+
+        Self {
+            base,
+            nodes: GrHashMap::default(),
+            removed_nodes: GrHashSet::default(),
+            edges: GrHashMap::default(),
+            removed_edges: GrHashSet::default(),
+            outgoing_edges: GrHashMap::default(),
+            incoming_edges: GrHashMap::default(),
+            edge_index_counter,
+        }
+    }
+
+    fn contains_base_node(&self, node_index: &NodeIndex) -> bool {
+        !self.removed_nodes.contains(node_index) && self.base.contains_node(node_index)
+    }
+
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    fn contains_base_edge(&self, edge_index: &EdgeIndex) -> bool {
+        !self.removed_edges.contains(edge_index) && self.base.contains_edge(edge_index)
+    }
+
+    pub(super) fn contains_node(&self, node_index: &NodeIndex) -> bool {
+        self.nodes.contains_key(node_index) || self.contains_base_node(node_index)
+    }
+
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub(super) fn contains_edge(&self, edge_index: &EdgeIndex) -> bool {
+        self.edges.contains_key(edge_index) || self.contains_base_edge(edge_index)
+    }
+
+    pub(super) fn node_count(&self) -> usize {
+        let added_nodes = self
+            .nodes
+            .keys()
+            .filter(|node_index| !self.contains_base_node(node_index))
+            .count();
+
+        self.base.node_count() - self.removed_nodes.len() + added_nodes
+    }
+
+    pub(super) fn edge_count(&self) -> usize {
+        let added_edges = self
+            .edges
+            .keys()
+            .filter(|edge_index| !self.contains_base_edge(edge_index))
+            .count();
+
+        self.base.edge_count() - self.removed_edges.len() + added_edges
+    }
+
+    pub(super) fn node_indices(&self) -> impl Iterator<Item = &NodeIndex> {
+        self.base
+            .graph
+            .node_indices()
+            .filter(|node_index| {
+                !self.removed_nodes.contains(*node_index) && !self.nodes.contains_key(*node_index)
+            })
+            .chain(self.nodes.keys())
+    }
+
+    pub(super) fn edge_indices(&self) -> impl Iterator<Item = &EdgeIndex> {
+        self.base
+            .graph
+            .edge_indices()
+            .filter(|edge_index| {
+                !self.removed_edges.contains(*edge_index) && !self.edges.contains_key(*edge_index)
+            })
+            .chain(self.edges.keys())
+    }
+
+    /// Returns the index as stored by the overlay or the base record, so it
+    /// lives as long as the layer.
+    pub(super) fn node_index_ref(&self, node_index: &NodeIndex) -> Option<&NodeIndex> {
+        if let Some((node_index, _)) = self.nodes.get_key_value(node_index) {
+            return Some(node_index);
+        }
+
+        if !self.contains_base_node(node_index) {
+            return None;
+        }
+
+        self.base
+            .graph
+            .nodes
+            .get_key_value(node_index)
+            .map(|(node_index, _)| node_index)
+    }
+
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub(super) fn edge_index_ref(&self, edge_index: &EdgeIndex) -> Option<&EdgeIndex> {
+        if let Some((edge_index, _)) = self.edges.get_key_value(edge_index) {
+            return Some(edge_index);
+        }
+
+        if !self.contains_base_edge(edge_index) {
+            return None;
+        }
+
+        self.base
+            .graph
+            .edges
+            .get_key_value(edge_index)
+            .map(|(edge_index, _)| edge_index)
+    }
+
+    pub(super) fn node_attributes(
+        &self,
+        node_index: &NodeIndex,
+    ) -> GraphRecordResult<&AttributeMap> {
+        if let Some(attributes) = self.nodes.get(node_index) {
+            return Ok(attributes);
+        }
+
+        if !self.contains_base_node(node_index) {
+            return Err(GraphRecordError::MissingNode {
+                index: node_index.clone(),
+            });
+        }
+
+        Ok(self.base.graph.node_attributes(node_index)?)
+    }
+
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub(super) fn edge_attributes(
+        &self,
+        edge_index: &EdgeIndex,
+    ) -> GraphRecordResult<&AttributeMap> {
+        if let Some((_, _, attributes)) = self.edges.get(edge_index) {
+            return Ok(attributes);
+        }
+
+        if !self.contains_base_edge(edge_index) {
+            return Err(missing_edge(edge_index));
+        }
+
+        Ok(self.base.graph.edge_attributes(edge_index)?)
+    }
+
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub(super) fn edge_endpoints(
+        &self,
+        edge_index: &EdgeIndex,
+    ) -> GraphRecordResult<(&NodeIndex, &NodeIndex)> {
+        if let Some((source_node_index, target_node_index, _)) = self.edges.get(edge_index) {
+            return Ok((source_node_index, target_node_index));
+        }
+
+        if !self.contains_base_edge(edge_index) {
+            return Err(missing_edge(edge_index));
+        }
+
+        Ok(self.base.graph.edge_endpoints(edge_index)?)
+    }
+
+    /// Edges of the base record that are not removed, followed by the added
+    /// ones.
+    fn chain_edges<'a>(
+        &'a self,
+        base_edges: Option<impl Iterator<Item = &'a EdgeIndex>>,
+        added_edges: Option<&'a GrHashSet<EdgeIndex>>,
+    ) -> impl Iterator<Item = &'a EdgeIndex> {
+        base_edges
+            .into_iter()
+            .flatten()
+            .filter(|edge_index| !self.removed_edges.contains(*edge_index))
+            .chain(added_edges.into_iter().flatten())
+    }
+
+    pub(super) fn outgoing_edges(
+        &self,
+        node_index: &NodeIndex,
+    ) -> GraphRecordResult<impl Iterator<Item = &EdgeIndex> + use<'_>> {
+        if !self.contains_node(node_index) {
+            return Err(GraphRecordError::MissingNode {
+                index: node_index.clone(),
+            });
+        }
+
+        let base_edges = if self.contains_base_node(node_index) {
+            Some(self.base.graph.outgoing_edges(node_index)?)
+        } else {
+            None
+        };
+
+        Ok(self.chain_edges(base_edges, self.outgoing_edges.get(node_index)))
+    }
+
+    pub(super) fn incoming_edges(
+        &self,
+        node_index: &NodeIndex,
+    ) -> GraphRecordResult<impl Iterator<Item = &EdgeIndex> + use<'_>> {
+        if !self.contains_node(node_index) {
+            return Err(GraphRecordError::MissingNode {
+                index: node_index.clone(),
+            });
+        }
+
+        let base_edges = if self.contains_base_node(node_index) {
+            Some(self.base.graph.incoming_edges(node_index)?)
+        } else {
+            None
+        };
+
+        Ok(self.chain_edges(base_edges, self.incoming_edges.get(node_index)))
+    }
+
+    /// Number of edges starting at the node plus the number of edges ending
+    /// at it, so a self-loop counts twice.
+    pub(super) fn degree(&self, node_index: &NodeIndex) -> GraphRecordResult<usize> {
+        let outgoing_edges = self
+            .outgoing_edges(node_index)?
+            .filter(|edge_index| self.endpoints(edge_index).0 == node_index)
+            .count();
+        let incoming_edges = self
+            .incoming_edges(node_index)?
+            .filter(|edge_index| self.endpoints(edge_index).1 == node_index)
+            .count();
+
+        Ok(outgoing_edges + incoming_edges)
+    }
+
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    fn endpoints(&self, edge_index: &EdgeIndex) -> (&NodeIndex, &NodeIndex) {
+        self.edge_endpoints(edge_index).expect("Edge must exist")
+    }
+
+    /// The other endpoint of each of `edge_indices`. Undirected edges of the
+    /// base record are traversed in both directions, so the node can be
+    /// either of their endpoints.
+    fn other_endpoints<'a>(
+        &'a self,
+        node_index: &'a NodeIndex,
+        edge_indices: impl Iterator<Item = &'a EdgeIndex>,
+    ) -> impl Iterator<Item = &'a NodeIndex> {
+        edge_indices.map(move |edge_index| {
+            let (source_node_index, target_node_index) = self.endpoints(edge_index);
+
+            if source_node_index == node_index {
+                target_node_index
+            } else {
+                source_node_index
+            }
+        })
+    }
+
+    fn stored_node_index(&self, node_index: &NodeIndex) -> GraphRecordResult<&NodeIndex> {
+        self.node_index_ref(node_index)
+            .ok_or_else(|| GraphRecordError::MissingNode {
+                index: node_index.clone(),
+            })
+    }
+
+    pub(super) fn neighbors_outgoing(
+        &self,
+        node_index: &NodeIndex,
+    ) -> GraphRecordResult<impl Iterator<Item = &NodeIndex> + use<'_>> {
+        let node_index = self.stored_node_index(node_index)?;
+
+        Ok(self.other_endpoints(node_index, self.outgoing_edges(node_index)?))
+    }
+
+    pub(super) fn neighbors_incoming(
+        &self,
+        node_index: &NodeIndex,
+    ) -> GraphRecordResult<impl Iterator<Item = &NodeIndex> + use<'_>> {
+        let node_index = self.stored_node_index(node_index)?;
+
+        Ok(self.other_endpoints(node_index, self.incoming_edges(node_index)?))
+    }
+
+    pub(super) fn neighbors_undirected(
+        &self,
+        node_index: &NodeIndex,
+    ) -> GraphRecordResult<impl Iterator<Item = &NodeIndex> + use<'_>> {
+        Ok(self
+            .neighbors_outgoing(node_index)?
+            .chain(self.neighbors_incoming(node_index)?)
+            .collect::<GrHashSet<_>>()
+            .into_iter())
+    }
+
+    pub(super) fn group_mapping(&self) -> &GroupMapping {
+        &self.base.group_mapping
+    }
+
+    /// Members of the group in the base record that the overlay has not
+    /// removed. Added nodes are in no group.
+    pub(super) fn nodes_in_group(
+        &self,
+        group: &Group,
+    ) -> GraphRecordResult<impl Iterator<Item = &NodeIndex> + use<'_>> {
+        Ok(self
+            .base
+            .group_mapping
+            .nodes_in_group(group)?
+            .filter(|node_index| !self.removed_nodes.contains(*node_index)))
+    }
+
+    /// Like [`Self::nodes_in_group`], but for edges.
+    pub(super) fn edges_in_group(
+        &self,
+        group: &Group,
+    ) -> GraphRecordResult<impl Iterator<Item = &EdgeIndex> + use<'_>> {
+        Ok(self
+            .base
+            .group_mapping
+            .edges_in_group(group)?
+            .filter(|edge_index| !self.removed_edges.contains(*edge_index)))
+    }
+
+    /// Groups of the base record, as the overlay does not stage group
+    /// changes. Added nodes are in no group.
+    pub(super) fn groups_of_node(
+        &self,
+        node_index: &NodeIndex,
+    ) -> GraphRecordResult<impl Iterator<Item = &Group> + use<'_>> {
+        if self.contains_base_node(node_index) {
+            return Ok(Either::Left(
+                self.base.group_mapping.groups_of_node(node_index),
+            ));
+        }
+
+        if !self.nodes.contains_key(node_index) {
+            return Err(GraphRecordError::MissingNode {
+                index: node_index.clone(),
+            });
+        }
+
+        Ok(Either::Right(iter::empty()))
+    }
+
+    /// Like [`Self::groups_of_node`], but for edges.
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub(super) fn groups_of_edge(
+        &self,
+        edge_index: &EdgeIndex,
+    ) -> GraphRecordResult<impl Iterator<Item = &Group> + use<'_>> {
+        if self.contains_base_edge(edge_index) {
+            return Ok(Either::Left(
+                self.base.group_mapping.groups_of_edge(edge_index),
+            ));
+        }
+
+        if !self.edges.contains_key(edge_index) {
+            return Err(missing_edge(edge_index));
+        }
+
+        Ok(Either::Right(iter::empty()))
+    }
+
+    /// Provenance recorded by the base record. Staged attributes have none.
+    pub(super) fn node_attribute_provenance(
+        &self,
+        node_index: &NodeIndex,
+        attribute: &GraphRecordAttribute,
+    ) -> GraphRecordResult<Option<&ProvenanceTag>> {
+        if self.nodes.contains_key(node_index) {
+            return Ok(None);
+        }
+
+        self.node_attributes(node_index)?;
+
+        Ok(self
+            .base
+            .provenance
+            .as_ref()
+            .and_then(|provenance| provenance.node(node_index, attribute)))
+    }
+
+    /// Like [`Self::node_attribute_provenance`], but for edges.
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub(super) fn edge_attribute_provenance(
+        &self,
+        edge_index: &EdgeIndex,
+        attribute: &GraphRecordAttribute,
+    ) -> GraphRecordResult<Option<&ProvenanceTag>> {
+        if self.edges.contains_key(edge_index) {
+            return Ok(None);
+        }
+
+        self.edge_attributes(edge_index)?;
+
+        Ok(self
+            .base
+            .provenance
+            .as_ref()
+            .and_then(|provenance| provenance.edge(edge_index, attribute)))
+    }
+
+    /// Applies the non-finite float policy and validates or infers the schema
+    /// like [`GraphRecord::add_node`] does.
+    fn prepare_node_attributes(
+        &self,
+        schema: &mut Schema,
+        node_index: &NodeIndex,
+        attributes: &mut AttributeMap,
+    ) -> GraphRecordResult<()> {
+        let groups: Vec<_> = if self.contains_node(node_index) {
+            self.groups_of_node(node_index)?.collect()
+        } else {
+            Vec::new()
+        };
+        let groups: Vec<_> = if groups.is_empty() {
+            vec![None]
+        } else {
+            groups.into_iter().map(Some).collect()
+        };
+
+        schema.handle_non_finite_floats(attributes)?;
+
+        for group in groups {
+            match schema.schema_type() {
+                SchemaType::Inferred => schema.update_node(attributes, group, false),
+                SchemaType::Provided => schema.validate_node(node_index, attributes, group)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Like [`Self::prepare_node_attributes`], but for edges.
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    fn prepare_edge_attributes(
+        &self,
+        schema: &mut Schema,
+        edge_index: &EdgeIndex,
+        attributes: &mut AttributeMap,
+    ) -> GraphRecordResult<()> {
+        let groups: Vec<_> = if self.contains_edge(edge_index) {
+            self.groups_of_edge(edge_index)?.collect()
+        } else {
+            Vec::new()
+        };
+        let groups: Vec<_> = if groups.is_empty() {
+            vec![None]
+        } else {
+            groups.into_iter().map(Some).collect()
+        };
+
+        schema.handle_non_finite_floats(attributes)?;
+
+        for group in groups {
+            match schema.schema_type() {
+                SchemaType::Inferred => schema.update_edge(attributes, group, false),
+                SchemaType::Provided => schema.validate_edge(edge_index, attributes, group)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Stages an edge. Edges the base record does not have are also added to
+    /// the adjacency sets of their endpoints.
+    fn stage_edge(
+        &mut self,
+        edge_index: EdgeIndex,
+        source_node_index: NodeIndex,
+        target_node_index: NodeIndex,
+        attributes: AttributeMap,
+    ) {
+        if !self.contains_base_edge(&edge_index) {
+            self.outgoing_edges
+                .entry(source_node_index.clone())
+                .or_default()
+                .insert(edge_index);
+            self.incoming_edges
+                .entry(target_node_index.clone())
+                .or_default()
+                .insert(edge_index);
+        }
+
+        self.edges.insert(
+            edge_index,
+            (source_node_index, target_node_index, attributes),
+        );
+    }
+
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    fn unstage_edge(&mut self, edge_index: &EdgeIndex) -> Option<AttributeMap> {
+        let (source_node_index, target_node_index, attributes) = self.edges.remove(edge_index)?;
+
+        for (adjacency, node_index) in [
+            (&mut self.outgoing_edges, &source_node_index),
+            (&mut self.incoming_edges, &target_node_index),
+        ] {
+            if let Some(edge_indices) = adjacency.get_mut(node_index) {
+                edge_indices.remove(edge_index);
+
+                if edge_indices.is_empty() {
+                    adjacency.remove(node_index);
+                }
+            }
+        }
+
+        Some(attributes)
+    }
+
+    fn remove_node(&mut self, node_index: &NodeIndex) -> GraphRecordResult<AttributeMap> {
+        let contains_base_node = self.contains_base_node(node_index);
+
+        let attributes = match self.nodes.remove(node_index) {
+            Some(attributes) => attributes,
+            None if contains_base_node => self.base.graph.node_attributes(node_index)?.clone(),
+            None => {
+                return Err(GraphRecordError::MissingNode {
+                    index: node_index.clone(),
+                });
+            }
+        };
+
+        if contains_base_node {
+            let base_edges: Vec<_> = self
+                .base
+                .graph
+                .outgoing_edges(node_index)?
+                .chain(self.base.graph.incoming_edges(node_index)?)
+                .copied()
+                .collect();
+
+            for edge_index in &base_edges {
+                self.edges.remove(edge_index);
+            }
+
+            self.removed_edges.extend(base_edges);
+            self.removed_nodes.insert(node_index.clone());
+        }
+
+        let added_edges: Vec<_> = self
+            .outgoing_edges
+            .get(node_index)
+            .into_iter()
+            .chain(self.incoming_edges.get(node_index))
+            .flatten()
+            .copied()
+            .collect();
+
+        for edge_index in &added_edges {
+            self.unstage_edge(edge_index);
+        }
+
+        Ok(attributes)
+    }
+
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    fn remove_edge(&mut self, edge_index: &EdgeIndex) -> GraphRecordResult<AttributeMap> {
+        let contains_base_edge = self.contains_base_edge(edge_index);
+
+        let attributes = match self.unstage_edge(edge_index) {
+            Some(attributes) => attributes,
+            None if contains_base_edge => self.base.graph.edge_attributes(edge_index)?.clone(),
+            None => return Err(missing_edge(edge_index)),
+        };
+
+        if contains_base_edge {
+            self.removed_edges.insert(*edge_index);
+        }
+
+        Ok(attributes)
+    }
+}
+
+/// Additions and removals relative to a base record that is shared, not
+/// copied.
+///
+/// Nodes and edges are read from the staged changes first and from the base
+/// record otherwise. Queries run against a record that reads through the
+/// staged changes to the base record, so they see the changes without the
+/// base record being copied. Added edges get indices past the ones the base
+/// record has assigned, so indices of the base record stay valid. Groups are
+/// those of the base record, without the nodes and edges removed by the
+/// overlay.
+///
+/// Adding and removing nodes and edges runs the hooks of the plugins of the
+/// base record. Hooks get the record as seen through the staged changes. It is
+/// frozen, so hooks can rewrite their contexts, but a hook that changes the
+/// record fails the operation.
+#[derive(Debug, Clone)]
+pub struct GraphRecordOverlay {
+    pub(super) graphrecord: GraphRecord,
+}
+
+impl GraphRecordOverlay {
+    #[must_use]
+    pub fn new(base: Arc<GraphRecord>) -> Self {
+        let graphrecord = GraphRecord {
+            schema: base.schema.clone(),
+            frozen: true,
+            strict_queries: base.strict_queries,
+            float_tolerance: base.float_tolerance,
+            random_seed: base.random_seed,
+            udfs: base.udfs.clone(),
+            #[cfg(feature = "plugins")]
+            plugins: base.plugins.clone(),
+            layer: Some(Box::new(OverlayLayer::new(base))),
+            ..Default::default()
+        };
+
+        Self { graphrecord }
+    }
+
+    fn layer(&self) -> &OverlayLayer {
+        self.graphrecord
+            .layer
+            .as_deref()
+            .expect("Overlay record must have a layer")
+    }
+
+    #[must_use]
+    pub fn base(&self) -> &Arc<GraphRecord> {
+        &self.layer().base
+    }
+
+    /// Schema of the base record, updated by the staged changes if it is
+    /// inferred.
+    #[must_use]
+    pub const fn get_schema(&self) -> &Schema {
+        &self.graphrecord.schema
+    }
+
+    /// Returns `true` if no changes are staged.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        let layer = self.layer();
+
+        layer.nodes.is_empty()
+            && layer.removed_nodes.is_empty()
+            && layer.edges.is_empty()
+            && layer.removed_edges.is_empty()
+    }
+
+    #[must_use]
+    pub fn contains_node(&self, node_index: &NodeIndex) -> bool {
+        self.graphrecord.contains_node(node_index)
+    }
+
+    #[must_use]
+    pub fn contains_edge(&self, edge_index: &EdgeIndex) -> bool {
+        self.graphrecord.contains_edge(edge_index)
+    }
+
+    #[must_use]
+    pub fn node_count(&self) -> usize {
+        self.graphrecord.node_count()
+    }
+
+    #[must_use]
+    pub fn edge_count(&self) -> usize {
+        self.graphrecord.edge_count()
+    }
+
+    pub fn node_indices(&self) -> impl Iterator<Item = &NodeIndex> {
+        self.graphrecord.node_indices()
+    }
+
+    pub fn edge_indices(&self) -> impl Iterator<Item = &EdgeIndex> {
+        self.graphrecord.edge_indices()
+    }
+
+    pub fn node_attributes(&self, node_index: &NodeIndex) -> GraphRecordResult<&AttributeMap> {
+        self.graphrecord.node_attributes(node_index)
+    }
+
+    pub fn edge_attributes(&self, edge_index: &EdgeIndex) -> GraphRecordResult<&AttributeMap> {
+        self.graphrecord.edge_attributes(edge_index)
+    }
+
+    pub fn edge_endpoints(
+        &self,
+        edge_index: &EdgeIndex,
+    ) -> GraphRecordResult<(&NodeIndex, &NodeIndex)> {
+        self.graphrecord.edge_endpoints(edge_index)
+    }
+
+    pub fn outgoing_edges(
+        &self,
+        node_index: &NodeIndex,
+    ) -> GraphRecordResult<impl Iterator<Item = &EdgeIndex> + use<'_>> {
+        self.graphrecord.outgoing_edges(node_index)
+    }
+
+    pub fn incoming_edges(
+        &self,
+        node_index: &NodeIndex,
+    ) -> GraphRecordResult<impl Iterator<Item = &EdgeIndex> + use<'_>> {
+        self.graphrecord.incoming_edges(node_index)
+    }
+
+    pub fn groups(&self) -> impl Iterator<Item = &Group> {
+        self.graphrecord.groups()
+    }
+
+    #[must_use]
+    pub fn contains_group(&self, group: &Group) -> bool {
+        self.graphrecord.contains_group(group)
+    }
+
+    pub fn nodes_in_group(
+        &self,
+        group: &Group,
+    ) -> GraphRecordResult<impl Iterator<Item = &NodeIndex> + use<'_>> {
+        self.graphrecord.nodes_in_group(group)
+    }
+
+    pub fn edges_in_group(
+        &self,
+        group: &Group,
+    ) -> GraphRecordResult<impl Iterator<Item = &EdgeIndex> + use<'_>> {
+        self.graphrecord.edges_in_group(group)
+    }
+
+    pub fn ungrouped_nodes(&self) -> impl Iterator<Item = &NodeIndex> {
+        self.graphrecord.ungrouped_nodes()
+    }
+
+    pub fn ungrouped_edges(&self) -> impl Iterator<Item = &EdgeIndex> {
+        self.graphrecord.ungrouped_edges()
+    }
+
+    pub fn groups_of_node(
+        &self,
+        node_index: &NodeIndex,
+    ) -> GraphRecordResult<impl Iterator<Item = &Group> + use<'_>> {
+        self.graphrecord.groups_of_node(node_index)
+    }
+
+    pub fn groups_of_edge(
+        &self,
+        edge_index: &EdgeIndex,
+    ) -> GraphRecordResult<impl Iterator<Item = &Group> + use<'_>> {
+        self.graphrecord.groups_of_edge(edge_index)
+    }
+
+    /// Splits the overlay into its staged changes and the schema they update.
+    fn layer_mut(&mut self) -> (&mut OverlayLayer, &mut Schema) {
+        let layer = self
+            .graphrecord
+            .layer
+            .as_deref_mut()
+            .expect("Overlay record must have a layer");
+
+        (layer, &mut self.graphrecord.schema)
+    }
+
+    pub(super) fn add_node_impl(
+        &mut self,
+        node_index: NodeIndex,
+        attributes: Attributes,
+    ) -> GraphRecordResult<()> {
+        if self.contains_node(&node_index) {
+            return Err(GraphRecordError::AssertionError(format!(
+                "Node with index {node_index} already exists"
+            )));
+        }
+
+        let (layer, schema) = self.layer_mut();
+
+        let mut attributes = attributes.into();
+        layer.prepare_node_attributes(schema, &node_index, &mut attributes)?;
+
+        layer.nodes.insert(node_index, attributes);
+
+        Ok(())
+    }
+
+    pub fn replace_node_attributes(
+        &mut self,
+        node_index: NodeIndex,
+        attributes: Attributes,
+    ) -> GraphRecordResult<()> {
+        let (layer, schema) = self.layer_mut();
+
+        if !layer.contains_node(&node_index) {
+            return Err(GraphRecordError::MissingNode { index: node_index });
+        }
+
+        let mut attributes = attributes.into();
+        layer.prepare_node_attributes(schema, &node_index, &mut attributes)?;
+
+        layer.nodes.insert(node_index, attributes);
+
+        Ok(())
+    }
+
+    pub(super) fn remove_node_impl(
+        &mut self,
+        node_index: &NodeIndex,
+    ) -> GraphRecordResult<Attributes> {
+        let (layer, _) = self.layer_mut();
+
+        layer.remove_node(node_index).map(Into::into)
+    }
+
+    pub(super) fn add_edge_impl(
+        &mut self,
+        source_node_index: NodeIndex,
+        target_node_index: NodeIndex,
+        attributes: Attributes,
+    ) -> GraphRecordResult<EdgeIndex> {
+        let (layer, schema) = self.layer_mut();
+
+        for node_index in [&source_node_index, &target_node_index] {
+            if !layer.contains_node(node_index) {
+                return Err(GraphRecordError::MissingNode {
+                    index: node_index.clone(),
+                });
+            }
+        }
+
+        let edge_index = layer.edge_index_counter;
+
+        let mut attributes = attributes.into();
+        layer.prepare_edge_attributes(schema, &edge_index, &mut attributes)?;
+
+        layer.stage_edge(edge_index, source_node_index, target_node_index, attributes);
+        layer.edge_index_counter += 1;
+
+        Ok(edge_index)
+    }
+
+    pub fn replace_edge_attributes(
+        &mut self,
+        edge_index: EdgeIndex,
+        attributes: Attributes,
+    ) -> GraphRecordResult<()> {
+        let (layer, schema) = self.layer_mut();
+
+        let (source_node_index, target_node_index) = layer.edge_endpoints(&edge_index)?;
+        let (source_node_index, target_node_index) =
+            (source_node_index.clone(), target_node_index.clone());
+
+        let mut attributes = attributes.into();
+        layer.prepare_edge_attributes(schema, &edge_index, &mut attributes)?;
+
+        layer.stage_edge(edge_index, source_node_index, target_node_index, attributes);
+
+        Ok(())
+    }
+
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub(super) fn remove_edge_impl(
+        &mut self,
+        edge_index: &EdgeIndex,
+    ) -> GraphRecordResult<Attributes> {
+        let (layer, _) = self.layer_mut();
+
+        layer.remove_edge(edge_index).map(Into::into)
+    }
+
+    /// Returns the staged changes as the changes turning the base record into
+    /// the one the overlay reads as.
+    #[must_use]
+    pub fn diff(&self) -> GraphRecordDiff {
+        let layer = self.layer();
+        let mut diff = GraphRecordDiff::default();
+
+        for (node_index, attributes) in &layer.nodes {
+            if !layer.contains_base_node(node_index) {
+                diff.added_nodes
+                    .push((node_index.clone(), attributes.to_attributes()));
+            } else if layer.base.node_attributes(node_index).ok() != Some(attributes) {
+                diff.changed_nodes
+                    .push((node_index.clone(), attributes.to_attributes()));
+            }
+        }
+
+        for (edge_index, (source_node_index, target_node_index, attributes)) in &layer.edges {
+            if !layer.contains_base_edge(edge_index) {
+                diff.added_edges.push((
+                    *edge_index,
+                    source_node_index.clone(),
+                    target_node_index.clone(),
+                    attributes.to_attributes(),
+                ));
+            } else if layer.base.edge_attributes(edge_index).ok() != Some(attributes) {
+                diff.changed_edges
+                    .push((*edge_index, attributes.to_attributes()));
+            }
+        }
+
+        diff.removed_nodes = layer.removed_nodes.iter().cloned().collect();
+        diff.removed_edges = layer.removed_edges.iter().copied().collect();
+
+        diff.added_nodes
+            .sort_unstable_by(|a, b| a.0.total_cmp(&b.0));
+        diff.changed_nodes
+            .sort_unstable_by(|a, b| a.0.total_cmp(&b.0));
+        diff.removed_nodes
+            .sort_unstable_by(GraphRecordAttribute::total_cmp);
+        diff.added_edges.sort_unstable_by_key(|edge| edge.0);
+        diff.changed_edges.sort_unstable_by_key(|edge| edge.0);
+        diff.removed_edges.sort_unstable();
+
+        let mut groups: Vec<_> = layer.base.groups().collect();
+        groups.sort_unstable_by(|a, b| a.total_cmp(b));
+
+        for group in groups {
+            let Some(mut members) = GroupMembers::new(&layer.base, group) else {
+                continue;
+            };
+
+            let member_count = members.nodes.len() + members.edges.len();
+
+            members
+                .nodes
+                .retain(|node_index| !layer.removed_nodes.contains(node_index));
+            members
+                .edges
+                .retain(|edge_index| !layer.removed_edges.contains(edge_index));
+
+            if members.nodes.len() + members.edges.len() != member_count {
+                diff.changed_groups.push((group.clone(), Some(members)));
+            }
+        }
+
+        diff
+    }
+
+    /// Copies the base record and applies the staged changes to the copy.
+    ///
+    /// Reads and queries don't need this, it is for continuing with the
+    /// result as a record of its own.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the staged changes do not apply to the base record, which
+    /// the overlay rules out when staging them.
+    #[must_use]
+    pub fn to_graphrecord(&self) -> GraphRecord {
+        let layer = self.layer();
+        let mut graphrecord = (*layer.base).clone();
+
+        self.diff()
+            .apply(&mut graphrecord)
+            .expect("Staged changes must apply to the base record");
+
+        graphrecord.schema = self.graphrecord.schema.clone();
+        graphrecord
+            .group_mapping
+            .group_metadata
+            .clone_from(&layer.base.group_mapping.group_metadata);
+
+        let free_edge_indices = graphrecord.graph.edge_index_state().1.clone();
+        graphrecord
+            .graph
+            .restore_edge_index_state(layer.edge_index_counter, free_edge_indices);
+
+        graphrecord
+    }
+
+    /// Like [`GraphRecord::query_nodes`], run against the base record as seen
+    /// through the staged changes.
+    pub fn query_nodes<'a, Q, R>(&'a self, query: Q) -> Selection<'a, R>
+    where
+        Q: FnOnce(&Wrapper<NodeOperand>) -> R,
+        R: ReturnOperand<'a>,
+    {
+        self.graphrecord.query_nodes(query)
+    }
+
+    /// Like [`GraphRecord::query_edges`], run against the base record as seen
+    /// through the staged changes.
+    pub fn query_edges<'a, Q, R>(&'a self, query: Q) -> Selection<'a, R>
+    where
+        Q: FnOnce(&Wrapper<EdgeOperand>) -> R,
+        R: ReturnOperand<'a>,
+    {
+        self.graphrecord.query_edges(query)
+    }
+}
+
+#[cfg(not(feature = "plugins"))]
+impl GraphRecordOverlay {
+    pub fn add_node(
+        &mut self,
+        node_index: NodeIndex,
+        attributes: Attributes,
+    ) -> GraphRecordResult<()> {
+        self.add_node_impl(node_index, attributes)
+    }
+
+    /// Removes the node and all of its edges.
+    pub fn remove_node(&mut self, node_index: &NodeIndex) -> GraphRecordResult<Attributes> {
+        self.remove_node_impl(node_index)
+    }
+
+    pub fn add_edge(
+        &mut self,
+        source_node_index: NodeIndex,
+        target_node_index: NodeIndex,
+        attributes: Attributes,
+    ) -> GraphRecordResult<EdgeIndex> {
+        self.add_edge_impl(source_node_index, target_node_index, attributes)
+    }
+
+    pub fn remove_edge(&mut self, edge_index: &EdgeIndex) -> GraphRecordResult<Attributes> {
+        self.remove_edge_impl(edge_index)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::GraphRecordOverlay;
+    use crate::{
+        GraphRecord,
+        errors::GraphRecordError,
+        graphrecord::{
+            Group, NodeIndex,
+            querying::nodes::EdgeDirection,
+            schema::{GroupSchema, Schema},
+            versioned::GraphRecordDiff,
+        },
+    };
+    use std::{collections::HashMap, sync::Arc};
+
+    fn create_base() -> Arc<GraphRecord> {
+        let mut graphrecord = GraphRecord::new();
+
+        graphrecord
+            .add_node("0".into(), HashMap::from([("age".into(), 20.into())]))
+            .unwrap();
+        graphrecord
+            .add_node("1".into(), HashMap::from([("age".into(), 30.into())]))
+            .unwrap();
+        graphrecord
+            .add_node("2".into(), HashMap::from([("age".into(), 40.into())]))
+            .unwrap();
+        graphrecord
+            .add_edge("0".into(), "1".into(), HashMap::new())
+            .unwrap();
+        graphrecord
+            .add_edge("1".into(), "2".into(), HashMap::new())
+            .unwrap();
+        graphrecord
+            .add_group("group".into(), Some(vec!["0".into(), "1".into()]), None)
+            .unwrap();
+
+        Arc::new(graphrecord)
+    }
+
+    #[test]
+    fn test_overlay() {
+        let base = create_base();
+        let mut overlay = GraphRecordOverlay::new(base.clone());
+
+        assert!(overlay.is_empty());
+
+        overlay
+            .add_node("3".into(), HashMap::from([("age".into(), 50.into())]))
+            .unwrap();
+        overlay
+            .replace_node_attributes("0".into(), HashMap::from([("age".into(), 25.into())]))
+            .unwrap();
+        overlay.remove_node(&"1".into()).unwrap();
+        let edge_index = overlay
+            .add_edge("2".into(), "3".into(), HashMap::new())
+            .unwrap();
+
+        assert_eq!(2, edge_index);
+        assert_eq!(3, overlay.node_count());
+        assert_eq!(1, overlay.edge_count());
+        assert!(!overlay.contains_node(&"1".into()));
+        assert!(!overlay.contains_edge(&0));
+        assert_eq!(
+            &HashMap::from([("age".into(), 25.into())]),
+            overlay.node_attributes(&"0".into()).unwrap()
+        );
+        assert_eq!(
+            (&"2".into(), &"3".into()),
+            overlay.edge_endpoints(&edge_index).unwrap()
+        );
+
+        assert_eq!(3, base.node_count());
+        assert_eq!(2, base.edge_count());
+
+        let graphrecord = overlay.to_graphrecord();
+
+        assert_eq!(3, graphrecord.node_count());
+        assert_eq!(1, graphrecord.edge_count());
+        assert_eq!(
+            vec![&NodeIndex::from("0")],
+            graphrecord
+                .nodes_in_group(&"group".into())
+                .unwrap()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(GraphRecordDiff::new(&base, &graphrecord), overlay.diff());
+
+        let mut node_indices: Vec<_> = overlay
+            .query_nodes(|nodes| {
+                nodes.attribute("age").greater_than(30);
+
+                nodes.index()
+            })
+            .evaluate()
+            .unwrap()
+            .collect();
+        node_indices.sort_unstable_by(NodeIndex::total_cmp);
+
+        assert_eq!(
+            vec![NodeIndex::from("2"), NodeIndex::from("3")],
+            node_indices
+        );
+    }
+
+    #[test]
+    fn test_overlay_readd_node() {
+        let mut overlay = GraphRecordOverlay::new(create_base());
+
+        overlay.remove_node(&"1".into()).unwrap();
+        overlay.add_node("1".into(), HashMap::new()).unwrap();
+
+        assert_eq!(3, overlay.node_count());
+        assert_eq!(0, overlay.edge_count());
+        assert!(overlay.node_attributes(&"1".into()).unwrap().is_empty());
+        assert_eq!(0, overlay.to_graphrecord().edge_count());
+    }
+
+    #[test]
+    fn test_overlay_groups() {
+        let mut overlay = GraphRecordOverlay::new(create_base());
+
+        overlay.add_node("3".into(), HashMap::new()).unwrap();
+        overlay.remove_node(&"0".into()).unwrap();
+
+        assert_eq!(
+            vec![&NodeIndex::from("1")],
+            overlay
+                .nodes_in_group(&"group".into())
+                .unwrap()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(0, overlay.edges_in_group(&"group".into()).unwrap().count());
+
+        let mut ungrouped_nodes: Vec<_> = overlay.ungrouped_nodes().collect();
+        ungrouped_nodes.sort_unstable_by(|a, b| a.total_cmp(b));
+
+        assert_eq!(
+            vec![&NodeIndex::from("2"), &NodeIndex::from("3")],
+            ungrouped_nodes
+        );
+        assert_eq!(vec![&1], overlay.ungrouped_edges().collect::<Vec<_>>());
+        assert!(overlay.contains_group(&"group".into()));
+        assert_eq!(0, overlay.groups_of_node(&"3".into()).unwrap().count());
+
+        let mut node_indices: Vec<_> = overlay
+            .query_nodes(|nodes| {
+                nodes.in_group(Group::from("group"));
+
+                nodes.index()
+            })
+            .evaluate()
+            .unwrap()
+            .collect();
+        node_indices.sort_unstable_by(NodeIndex::total_cmp);
+
+        assert_eq!(vec![NodeIndex::from("1")], node_indices);
+
+        // A node that is removed and added again is no longer in the group
+        overlay.remove_node(&"1".into()).unwrap();
+        overlay.add_node("1".into(), HashMap::new()).unwrap();
+
+        assert_eq!(0, overlay.nodes_in_group(&"group".into()).unwrap().count());
+        assert_eq!(3, overlay.ungrouped_nodes().count());
+    }
+
+    #[test]
+    fn test_overlay_query_edges() {
+        let base = create_base();
+        let mut overlay = GraphRecordOverlay::new(base.clone());
+
+        overlay.remove_edge(&0).unwrap();
+        let edge_index = overlay
+            .add_edge("2".into(), "0".into(), HashMap::new())
+            .unwrap();
+
+        assert_eq!(
+            vec![&edge_index],
+            overlay
+                .outgoing_edges(&"2".into())
+                .unwrap()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![&1],
+            overlay
+                .incoming_edges(&"2".into())
+                .unwrap()
+                .collect::<Vec<_>>()
+        );
+
+        let query = |overlay: &GraphRecordOverlay| {
+            let mut node_indices: Vec<_> = overlay
+                .query_nodes(|nodes| {
+                    nodes
+                        .neighbors(EdgeDirection::Outgoing)
+                        .index()
+                        .equal_to("0");
+
+                    nodes.index()
+                })
+                .evaluate()
+                .unwrap()
+                .collect();
+            node_indices.sort_unstable_by(NodeIndex::total_cmp);
+
+            node_indices
+        };
+
+        assert_eq!(vec![NodeIndex::from("2")], query(&overlay));
+
+        let mut degrees: Vec<_> = overlay
+            .query_nodes(|nodes| nodes.degree(EdgeDirection::Both))
+            .evaluate()
+            .unwrap()
+            .collect();
+        degrees.sort_unstable_by(|a, b| a.0.total_cmp(b.0));
+
+        assert_eq!(
+            vec![
+                (&NodeIndex::from("0"), 1.into()),
+                (&NodeIndex::from("1"), 1.into()),
+                (&NodeIndex::from("2"), 2.into()),
+            ],
+            degrees
+        );
+
+        // The base record is read through the overlay, not changed by it
+        assert!(base.contains_edge(&0));
+        assert_eq!(
+            vec![&NodeIndex::from("1")],
+            base.neighbors_outgoing(&"0".into())
+                .unwrap()
+                .collect::<Vec<_>>()
+        );
+
+        overlay.remove_node(&"0".into()).unwrap();
+
+        assert!(!overlay.contains_edge(&edge_index));
+        assert_eq!(0, overlay.outgoing_edges(&"2".into()).unwrap().count());
+        assert!(query(&overlay).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_overlay() {
+        let mut overlay = GraphRecordOverlay::new(create_base());
+
+        assert!(
+            overlay
+                .add_node("0".into(), HashMap::new())
+                .is_err_and(|error| matches!(error, GraphRecordError::AssertionError(_)))
+        );
+        assert!(
+            overlay
+                .add_edge("0".into(), "4".into(), HashMap::new())
+                .is_err_and(|error| matches!(error, GraphRecordError::MissingNode { .. }))
+        );
+        assert!(
+            overlay
+                .remove_edge(&5)
+                .is_err_and(|error| matches!(error, GraphRecordError::IndexError(_)))
+        );
+
+        let mut overlay = GraphRecordOverlay::new(Arc::new(GraphRecord::with_schema(
+            Schema::new_provided(HashMap::new(), GroupSchema::default()),
+        )));
+
+        assert!(
+            overlay
+                .add_node("0".into(), HashMap::from([("age".into(), 20.into())]))
+                .is_err_and(|error| matches!(error, GraphRecordError::SchemaError(_)))
+        );
+        assert!(overlay.is_empty());
+    }
+}
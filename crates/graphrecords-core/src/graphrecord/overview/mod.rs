@@ -7,15 +7,19 @@ use crate::{
     errors::{GraphRecordError, GraphRecordResult},
     graphrecord::overview::tabled_modifiers::MergeDuplicatesVerticalByColumn,
     prelude::{
-        AttributeType, DataType, GraphRecordAttribute, GraphRecordValue, Group, GroupSchema,
+        AttributeType, Attributes, DataType, EdgeIndex, GraphRecordAttribute, GraphRecordValue,
+        Group, GroupSchema, NodeIndex,
     },
 };
-use graphrecords_utils::aliases::GrHashMap;
+use graphrecords_utils::aliases::{GrHashMap, GrHashSet};
 use itertools::Itertools;
+use rand::{SeedableRng, rngs::StdRng, seq::IteratorRandom};
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use std::{
+    cmp::Ordering,
     collections::HashSet,
-    fmt::{Display, Formatter},
+    fmt::{Display, Formatter, Write},
+    hash::Hash,
 };
 use tabled::{
     builder::Builder,
@@ -24,10 +28,283 @@ use tabled::{
 
 pub const DEFAULT_TRUNCATE_DETAILS: usize = 80;
 
+/// Number of groups listed in [`StructureOverview::connected_groups`].
+pub const TOP_CONNECTED_GROUPS: usize = 3;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OverviewFormat {
+    #[default]
+    Modern,
+    Ascii,
+    Markdown,
+    Csv,
+}
+
+/// Restricts overview statistics to a reproducible sample of each group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OverviewSample {
+    pub sample_size: usize,
+    pub seed: u64,
+}
+
+impl OverviewSample {
+    // Indices are sorted before sampling so the result does not depend on
+    // hash map iteration order.
+    fn apply<T: Eq + Hash>(
+        self,
+        indices: HashSet<T>,
+        compare: impl FnMut(&T, &T) -> Ordering,
+    ) -> HashSet<T> {
+        if indices.len() <= self.sample_size {
+            return indices;
+        }
+
+        let mut rng = StdRng::seed_from_u64(self.seed);
+
+        indices
+            .into_iter()
+            .sorted_by(compare)
+            .choose_multiple(&mut rng, self.sample_size)
+            .into_iter()
+            .collect()
+    }
+
+    fn sample_nodes(self, node_indices: HashSet<NodeIndex>) -> HashSet<NodeIndex> {
+        self.apply(node_indices, |a, b| match (a, b) {
+            (GraphRecordAttribute::Int(a), GraphRecordAttribute::Int(b)) => a.cmp(b),
+            (GraphRecordAttribute::String(a), GraphRecordAttribute::String(b)) => a.cmp(b),
+            (GraphRecordAttribute::Int(_), GraphRecordAttribute::String(_)) => Ordering::Less,
+            (GraphRecordAttribute::String(_), GraphRecordAttribute::Int(_)) => Ordering::Greater,
+        })
+    }
+
+    fn sample_edges(self, edge_indices: HashSet<EdgeIndex>) -> HashSet<EdgeIndex> {
+        self.apply(edge_indices, Ord::cmp)
+    }
+}
+
+/// Counts the values of a Categorical attribute, most frequent first. With
+/// more distinct values than `categorical_threshold`, only the
+/// `categorical_threshold` most frequent ones are kept and the remaining
+/// values are counted together.
+fn categorical_overview(
+    values: impl Iterator<Item = GraphRecordValue>,
+    categorical_threshold: Option<usize>,
+) -> AttributeOverviewData {
+    let mut value_counts: Vec<_> = values
+        .sorted_by(GraphRecordValue::total_cmp)
+        .dedup_with_count()
+        .map(|(count, value)| (value, count))
+        .collect();
+
+    // The sort is stable, so values with the same count stay in value order.
+    value_counts.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+    let other_count = categorical_threshold
+        .filter(|threshold| value_counts.len() > *threshold)
+        .map_or(0, |threshold| {
+            value_counts
+                .drain(threshold..)
+                .map(|(_, count)| count)
+                .sum()
+        });
+
+    AttributeOverviewData::Categorical {
+        value_counts,
+        other_count,
+    }
+}
+
+fn overview_title(title: &str, sample: Option<OverviewSample>) -> String {
+    sample.map_or_else(
+        || title.to_string(),
+        |sample| format!("{title} (approximate, sample size {})", sample.sample_size),
+    )
+}
+
+fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn write_table(
+    f: &mut Formatter<'_>,
+    title: &str,
+    builder: Builder,
+    merge_columns: Vec<usize>,
+    truncate_details: Option<usize>,
+    format: OverviewFormat,
+) -> std::fmt::Result {
+    match format {
+        OverviewFormat::Modern | OverviewFormat::Ascii => {
+            let mut table = builder.build();
+            match format {
+                OverviewFormat::Ascii => table.with(Style::ascii()),
+                _ => table.with(Style::modern()),
+            };
+            table.with(Panel::header(title));
+            table.with(MergeDuplicatesVerticalByColumn::new(merge_columns));
+            table.with(Alignment::center_vertical());
+            table.with(BorderCorrection {});
+
+            if let Some(truncate_details) = truncate_details {
+                table.modify(Columns::last(), Width::truncate(truncate_details));
+            }
+
+            writeln!(f, "{table}")
+        }
+        OverviewFormat::Markdown => {
+            let records: Vec<Vec<String>> = builder.into();
+
+            // Markdown tables cannot hold line breaks or merged cells, so
+            // every row is rendered in full on a single line.
+            let mut table = Builder::from(
+                records
+                    .into_iter()
+                    .map(|record| {
+                        record
+                            .into_iter()
+                            .map(|field| field.replace('\n', "<br>"))
+                            .collect()
+                    })
+                    .collect::<Vec<Vec<_>>>(),
+            )
+            .build();
+            table.with(Style::markdown());
+
+            if let Some(truncate_details) = truncate_details {
+                table.modify(Columns::last(), Width::truncate(truncate_details));
+            }
+
+            writeln!(f, "### {title}\n\n{table}\n")
+        }
+        OverviewFormat::Csv => {
+            let records: Vec<Vec<String>> = builder.into();
+
+            for mut record in records {
+                if let (Some(truncate_details), Some(details)) =
+                    (truncate_details, record.last_mut())
+                {
+                    *details = details.chars().take(truncate_details).collect();
+                }
+
+                writeln!(
+                    f,
+                    "{}",
+                    record.iter().map(|field| escape_csv_field(field)).join(",")
+                )?;
+            }
+
+            writeln!(f)
+        }
+    }
+}
+
+const HTML_STYLE: &str = "<style>\
+.graphrecords-overview table { border-collapse: collapse; margin-bottom: 1em; }\
+.graphrecords-overview th, .graphrecords-overview td { \
+border: 1px solid #ccc; padding: 4px 8px; text-align: left; vertical-align: top; }\
+</style>";
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn write_html_table(
+    f: &mut impl Write,
+    title: &str,
+    count_header: &str,
+    count: usize,
+    attributes: &GrHashMap<GraphRecordAttribute, AttributeOverview>,
+    truncate_details: Option<usize>,
+) -> std::fmt::Result {
+    write!(
+        f,
+        "<table><caption>{title} ({count_header}: {count})</caption>\
+         <tr><th>Attribute</th><th>Attribute Type</th><th>Data Type</th><th>Details</th></tr>"
+    )?;
+
+    for (attribute, overview) in attributes
+        .iter()
+        .sorted_by_cached_key(|(attribute, _)| attribute.to_string())
+    {
+        let mut details = overview.data.details();
+
+        if let Some(truncate_details) = truncate_details {
+            details = details.chars().take(truncate_details).collect();
+        }
+
+        write!(
+            f,
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+            escape_html(&attribute.to_string()),
+            overview.data.attribute_type_name(),
+            escape_html(&overview.data_type.to_string()),
+            escape_html(&details).replace('\n', "<br>"),
+        )?;
+    }
+
+    write!(f, "</table>")
+}
+
+fn write_html_structure_table(
+    f: &mut impl Write,
+    structure: &StructureOverview,
+) -> std::fmt::Result {
+    write!(
+        f,
+        "<table><caption>Structure Overview</caption><tr><th>Ungrouped Endpoints</th>\
+         <th>Isolated Nodes</th><th>Average Degree</th><th>Connected Groups</th></tr><tr>"
+    )?;
+
+    for field in structure.record() {
+        write!(f, "<td>{}</td>", escape_html(&field))?;
+    }
+
+    write!(f, "</tr></table>")
+}
+
+fn metadata_records(metadata: &Attributes) -> Vec<[String; 2]> {
+    metadata
+        .iter()
+        .map(|(key, value)| [key.to_string(), value.to_string()])
+        .sorted()
+        .collect()
+}
+
+fn write_html_metadata_table(f: &mut impl Write, metadata: &Attributes) -> std::fmt::Result {
+    write!(
+        f,
+        "<table><caption>Group Metadata</caption><tr><th>Key</th><th>Value</th></tr>"
+    )?;
+
+    for [key, value] in metadata_records(metadata) {
+        write!(
+            f,
+            "<tr><td>{}</td><td>{}</td></tr>",
+            escape_html(&key),
+            escape_html(&value).replace('\n', "<br>"),
+        )?;
+    }
+
+    write!(f, "</table>")
+}
+
 #[derive(Debug, Clone)]
 pub enum AttributeOverviewData {
+    /// `value_counts` holds every distinct value with the number of its
+    /// occurrences, most frequent first. `other_count` is the number of
+    /// values left out of `value_counts` because the attribute has more
+    /// distinct values than the categorical threshold of the overview.
     Categorical {
-        distinct_values: Vec<GraphRecordValue>,
+        value_counts: Vec<(GraphRecordValue, usize)>,
+        other_count: usize,
     },
     Continuous {
         min: GraphRecordValue,
@@ -55,14 +332,23 @@ impl AttributeOverviewData {
 
     fn details(&self) -> String {
         match self {
-            Self::Categorical { distinct_values } => {
-                format!(
-                    "Distinct values: [{}]",
-                    distinct_values
-                        .iter()
-                        .map(std::string::ToString::to_string)
-                        .join(", ")
-                )
+            Self::Categorical {
+                value_counts,
+                other_count,
+            } => {
+                let distinct_values = value_counts
+                    .iter()
+                    .map(|(value, count)| format!("{value} ({count})"))
+                    .join(", ");
+
+                if *other_count == 0 {
+                    format!("Distinct values: [{distinct_values}]")
+                } else {
+                    format!(
+                        "Most frequent values: [{distinct_values}]\n\
+                         Other value count: {other_count}"
+                    )
+                }
             }
             Self::Continuous { min, mean, max } => {
                 format!("Min: {min}\nMean: {mean}\nMax: {max}")
@@ -89,6 +375,8 @@ pub struct NodeGroupOverview {
     pub attributes: GrHashMap<GraphRecordAttribute, AttributeOverview>,
 
     truncate_details: Option<usize>,
+    format: OverviewFormat,
+    sample: Option<OverviewSample>,
 }
 
 impl Display for NodeGroupOverview {
@@ -119,33 +407,47 @@ impl Display for NodeGroupOverview {
             builder.push_record([&self.count.to_string(), "-", "-", "-", "-"]);
         }
 
-        let mut table = builder.build();
-        table.with(Style::modern());
-        table.with(Panel::header("Node Overview"));
-        table.with(MergeDuplicatesVerticalByColumn::new(vec![0]));
-        table.with(Alignment::center_vertical());
-        table.with(BorderCorrection {});
-
-        if let Some(truncate_details) = self.truncate_details {
-            table.modify(Columns::last(), Width::truncate(truncate_details));
-        }
-
-        writeln!(f, "{table}")
+        write_table(
+            f,
+            &overview_title("Node Overview", self.sample),
+            builder,
+            vec![0],
+            self.truncate_details,
+            self.format,
+        )
     }
 }
 
 impl NodeGroupOverview {
+    fn write_html(&self, f: &mut impl Write) -> std::fmt::Result {
+        write_html_table(
+            f,
+            &overview_title("Node Overview", self.sample),
+            "Node Count",
+            self.count,
+            &self.attributes,
+            self.truncate_details,
+        )
+    }
+
     fn new(
         graphrecord: &GraphRecord,
         group_schema: &GroupSchema,
         group: Option<&Group>,
         truncate_details: Option<usize>,
+        format: OverviewFormat,
+        sample: Option<OverviewSample>,
+        categorical_threshold: Option<usize>,
     ) -> GraphRecordResult<Self> {
         let nodes_in_group: HashSet<_> = match group {
             Some(group) => graphrecord.nodes_in_group(group)?.cloned().collect(),
             None => graphrecord.ungrouped_nodes().cloned().collect(),
         };
         let count = nodes_in_group.len();
+        let nodes_in_group = match sample {
+            Some(sample) => sample.sample_nodes(nodes_in_group),
+            None => nodes_in_group,
+        };
 
         let attributes: GrHashMap<_, _> = group_schema
             .nodes()
@@ -160,19 +462,20 @@ impl NodeGroupOverview {
                             .query_nodes(|nodes| {
                                 nodes.index().is_in(nodes_in_group.clone());
 
-                                nodes.attribute(key.clone())
+                                let values = nodes.attribute(key.clone());
+
+                                values.exclude(|values| {
+                                    values.is_missing();
+                                });
+
+                                values
                             })
                             .evaluate()?
-                            .map(|(_, value)| value)
-                            .sorted_by(GraphRecordValue::total_cmp)
-                            .dedup_by(|a, b| a == b)
-                            .collect();
+                            .map(|(_, value)| value);
 
                         AttributeOverview {
                             data_type,
-                            data: AttributeOverviewData::Categorical {
-                                distinct_values: values,
-                            },
+                            data: categorical_overview(values, categorical_threshold),
                         }
                     }
                     AttributeType::Continuous => {
@@ -185,6 +488,9 @@ impl NodeGroupOverview {
                                 values.exclude(|values| {
                                     values.is_null();
                                 });
+                                values.exclude(|values| {
+                                    values.is_missing();
+                                });
 
                                 (values.min(), values.mean(), values.max())
                             })
@@ -209,6 +515,9 @@ impl NodeGroupOverview {
                                 values.exclude(|values| {
                                     values.is_null();
                                 });
+                                values.exclude(|values| {
+                                    values.is_missing();
+                                });
 
                                 (values.min(), values.max())
                             })
@@ -228,7 +537,13 @@ impl NodeGroupOverview {
                             .query_nodes(|nodes| {
                                 nodes.index().is_in(nodes_in_group.clone());
 
-                                nodes.attribute(key.clone())
+                                let values = nodes.attribute(key.clone());
+
+                                values.exclude(|values| {
+                                    values.is_missing();
+                                });
+
+                                values
                             })
                             .evaluate()
                             .unwrap()
@@ -252,6 +567,8 @@ impl NodeGroupOverview {
             count,
             attributes,
             truncate_details,
+            format,
+            sample,
         })
     }
 }
@@ -262,6 +579,8 @@ pub struct EdgeGroupOverview {
     pub attributes: GrHashMap<GraphRecordAttribute, AttributeOverview>,
 
     truncate_details: Option<usize>,
+    format: OverviewFormat,
+    sample: Option<OverviewSample>,
 }
 
 impl Display for EdgeGroupOverview {
@@ -288,33 +607,47 @@ impl Display for EdgeGroupOverview {
             ]);
         }
 
-        let mut table = builder.build();
-        table.with(Style::modern());
-        table.with(Panel::header("Edge Overview"));
-        table.with(MergeDuplicatesVerticalByColumn::new(vec![0]));
-        table.with(Alignment::center_vertical());
-        table.with(BorderCorrection {});
-
-        if let Some(truncate_details) = self.truncate_details {
-            table.modify(Columns::last(), Width::truncate(truncate_details));
-        }
-
-        writeln!(f, "{table}")
+        write_table(
+            f,
+            &overview_title("Edge Overview", self.sample),
+            builder,
+            vec![0],
+            self.truncate_details,
+            self.format,
+        )
     }
 }
 
 impl EdgeGroupOverview {
+    fn write_html(&self, f: &mut impl Write) -> std::fmt::Result {
+        write_html_table(
+            f,
+            &overview_title("Edge Overview", self.sample),
+            "Edge Count",
+            self.count,
+            &self.attributes,
+            self.truncate_details,
+        )
+    }
+
     fn new(
         graphrecord: &GraphRecord,
         group_schema: &GroupSchema,
         group: Option<&Group>,
         truncate_details: Option<usize>,
+        format: OverviewFormat,
+        sample: Option<OverviewSample>,
+        categorical_threshold: Option<usize>,
     ) -> GraphRecordResult<Self> {
         let edges_in_group: HashSet<_> = match group {
             Some(group) => graphrecord.edges_in_group(group)?.copied().collect(),
             None => graphrecord.ungrouped_edges().copied().collect(),
         };
         let count = edges_in_group.len();
+        let edges_in_group = match sample {
+            Some(sample) => sample.sample_edges(edges_in_group),
+            None => edges_in_group,
+        };
 
         let attributes: GrHashMap<_, _> = group_schema
             .edges()
@@ -329,19 +662,20 @@ impl EdgeGroupOverview {
                             .query_edges(|edges| {
                                 edges.index().is_in(edges_in_group.clone());
 
-                                edges.attribute(key.clone())
+                                let values = edges.attribute(key.clone());
+
+                                values.exclude(|values| {
+                                    values.is_missing();
+                                });
+
+                                values
                             })
                             .evaluate()?
-                            .map(|(_, value)| value)
-                            .sorted_by(GraphRecordValue::total_cmp)
-                            .dedup_by(|a, b| a == b)
-                            .collect();
+                            .map(|(_, value)| value);
 
                         AttributeOverview {
                             data_type,
-                            data: AttributeOverviewData::Categorical {
-                                distinct_values: values,
-                            },
+                            data: categorical_overview(values, categorical_threshold),
                         }
                     }
                     AttributeType::Continuous => {
@@ -354,6 +688,9 @@ impl EdgeGroupOverview {
                                 values.exclude(|values| {
                                     values.is_null();
                                 });
+                                values.exclude(|values| {
+                                    values.is_missing();
+                                });
 
                                 (values.min(), values.mean(), values.max())
                             })
@@ -378,6 +715,9 @@ impl EdgeGroupOverview {
                                 values.exclude(|values| {
                                     values.is_null();
                                 });
+                                values.exclude(|values| {
+                                    values.is_missing();
+                                });
 
                                 (values.min(), values.max())
                             })
@@ -396,7 +736,13 @@ impl EdgeGroupOverview {
                             .query_edges(|edges| {
                                 edges.index().is_in(edges_in_group.clone());
 
-                                edges.attribute(key.clone())
+                                let values = edges.attribute(key.clone());
+
+                                values.exclude(|values| {
+                                    values.is_missing();
+                                });
+
+                                values
                             })
                             .evaluate()?
                             .map(|(_, value)| value)
@@ -419,6 +765,116 @@ impl EdgeGroupOverview {
             count,
             attributes,
             truncate_details,
+            format,
+            sample,
+        })
+    }
+}
+
+/// Connectivity of the nodes of a group. Unlike the attribute statistics, it
+/// is always computed from all nodes and edges, also for sampled overviews.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructureOverview {
+    /// Distinct nodes outside of every group that share an edge with a node
+    /// of the group.
+    pub ungrouped_endpoints: usize,
+    /// Nodes of the group without any edge.
+    pub isolated_nodes: usize,
+    /// Mean number of distinct edges of the nodes of the group.
+    pub average_degree: f64,
+    /// The [`TOP_CONNECTED_GROUPS`] other groups sharing the most edges with
+    /// the nodes of the group, with the number of these edges, most connected
+    /// first.
+    pub connected_groups: Vec<(Group, usize)>,
+}
+
+impl StructureOverview {
+    fn record(&self) -> [String; 4] {
+        let connected_groups = if self.connected_groups.is_empty() {
+            "-".to_string()
+        } else {
+            self.connected_groups
+                .iter()
+                .map(|(group, count)| format!("{group} ({count})"))
+                .join(", ")
+        };
+
+        [
+            self.ungrouped_endpoints.to_string(),
+            self.isolated_nodes.to_string(),
+            format!("{:.2}", self.average_degree),
+            connected_groups,
+        ]
+    }
+
+    fn new(graphrecord: &GraphRecord, group: Option<&Group>) -> GraphRecordResult<Self> {
+        let nodes: Vec<_> = match group {
+            Some(group) => graphrecord.nodes_in_group(group)?.collect(),
+            None => graphrecord.ungrouped_nodes().collect(),
+        };
+
+        let mut degree_sum = 0;
+        let mut isolated_nodes = 0;
+        let mut ungrouped_endpoints = GrHashSet::default();
+        let mut connected_groups = GrHashMap::<_, usize>::default();
+
+        for node_index in &nodes {
+            // Undirected edges are both outgoing and incoming edges of their
+            // endpoints, so they are deduplicated.
+            let edges: GrHashSet<_> = graphrecord
+                .outgoing_edges(node_index)?
+                .chain(graphrecord.incoming_edges(node_index)?)
+                .collect();
+
+            degree_sum += edges.len();
+
+            if edges.is_empty() {
+                isolated_nodes += 1;
+            }
+
+            for edge_index in edges {
+                let (source_node_index, target_node_index) =
+                    graphrecord.edge_endpoints(edge_index)?;
+                let other_node_index = if source_node_index == *node_index {
+                    target_node_index
+                } else {
+                    source_node_index
+                };
+
+                let mut other_groups = graphrecord.groups_of_node(other_node_index)?.peekable();
+
+                if other_groups.peek().is_none() {
+                    ungrouped_endpoints.insert(other_node_index);
+                }
+
+                for other_group in other_groups.filter(|other_group| group != Some(*other_group)) {
+                    *connected_groups.entry(other_group).or_default() += 1;
+                }
+            }
+        }
+
+        let average_degree = if nodes.is_empty() {
+            0.0
+        } else {
+            degree_sum as f64 / nodes.len() as f64
+        };
+
+        let connected_groups = connected_groups
+            .into_iter()
+            .sorted_by(|(group_a, count_a), (group_b, count_b)| {
+                count_b
+                    .cmp(count_a)
+                    .then_with(|| group_a.total_cmp(group_b))
+            })
+            .take(TOP_CONNECTED_GROUPS)
+            .map(|(group, count)| (group.clone(), count))
+            .collect();
+
+        Ok(Self {
+            ungrouped_endpoints: ungrouped_endpoints.len(),
+            isolated_nodes,
+            average_degree,
+            connected_groups,
         })
     }
 }
@@ -427,13 +883,41 @@ impl EdgeGroupOverview {
 pub struct GroupOverview {
     pub node_overview: NodeGroupOverview,
     pub edge_overview: EdgeGroupOverview,
+    pub structure: StructureOverview,
+    pub metadata: Attributes,
 }
 
 impl GroupOverview {
+    fn write_html(&self, f: &mut impl Write) -> std::fmt::Result {
+        if !self.metadata.is_empty() {
+            write_html_metadata_table(f, &self.metadata)?;
+        }
+
+        self.node_overview.write_html(f)?;
+        self.edge_overview.write_html(f)?;
+        write_html_structure_table(f, &self.structure)
+    }
+
+    #[must_use]
+    #[expect(clippy::missing_panics_doc, reason = "infallible")]
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+
+        write!(html, "<div class=\"graphrecords-overview\">{HTML_STYLE}")
+            .and_then(|()| self.write_html(&mut html))
+            .and_then(|()| write!(html, "</div>"))
+            .expect("Writing to a String must succeed");
+
+        html
+    }
+
     pub(crate) fn new(
         graphrecord: &GraphRecord,
         group: Option<&Group>,
         truncate_details: Option<usize>,
+        format: OverviewFormat,
+        sample: Option<OverviewSample>,
+        categorical_threshold: Option<usize>,
     ) -> GraphRecordResult<Self> {
         let schema = &graphrecord.schema;
 
@@ -442,27 +926,81 @@ impl GroupOverview {
             None => schema.ungrouped(),
         };
 
+        let metadata = match group {
+            Some(g) => graphrecord
+                .group_metadata(g)?
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect(),
+            None => Attributes::new(),
+        };
+
         Ok(Self {
             node_overview: NodeGroupOverview::new(
                 graphrecord,
                 group_schema,
                 group,
                 truncate_details,
+                format,
+                sample,
+                categorical_threshold,
             )?,
             edge_overview: EdgeGroupOverview::new(
                 graphrecord,
                 group_schema,
                 group,
                 truncate_details,
+                format,
+                sample,
+                categorical_threshold,
             )?,
+            structure: StructureOverview::new(graphrecord, group)?,
+            metadata,
         })
     }
 }
 
 impl Display for GroupOverview {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if !self.metadata.is_empty() {
+            let mut builder = Builder::new();
+
+            builder.push_record(["Key", "Value"]);
+
+            for record in metadata_records(&self.metadata) {
+                builder.push_record(record);
+            }
+
+            write_table(
+                f,
+                "Group Metadata",
+                builder,
+                Vec::new(),
+                self.node_overview.truncate_details,
+                self.node_overview.format,
+            )?;
+        }
+
         writeln!(f, "{}", self.node_overview)?;
-        writeln!(f, "{}", self.edge_overview)
+        writeln!(f, "{}", self.edge_overview)?;
+
+        let mut builder = Builder::new();
+
+        builder.push_record([
+            "Ungrouped Endpoints",
+            "Isolated Nodes",
+            "Average Degree",
+            "Connected Groups",
+        ]);
+        builder.push_record(self.structure.record());
+
+        write_table(
+            f,
+            "Structure Overview",
+            builder,
+            Vec::new(),
+            self.node_overview.truncate_details,
+            self.node_overview.format,
+        )
     }
 }
 
@@ -472,6 +1010,8 @@ pub struct Overview {
     pub grouped_overviews: GrHashMap<Group, GroupOverview>,
 
     truncate_details: Option<usize>,
+    format: OverviewFormat,
+    sample: Option<OverviewSample>,
 }
 
 impl Display for Overview {
@@ -512,18 +1052,14 @@ impl Display for Overview {
             }
         }
 
-        let mut table = builder.build();
-        table.with(Style::modern());
-        table.with(Panel::header("Node Overview"));
-        table.with(MergeDuplicatesVerticalByColumn::new(vec![0, 1]));
-        table.with(Alignment::center_vertical());
-        table.with(BorderCorrection {});
-
-        if let Some(truncate_details) = self.truncate_details {
-            table.modify(Columns::last(), Width::truncate(truncate_details));
-        }
-
-        writeln!(f, "{table}")?;
+        write_table(
+            f,
+            &overview_title("Node Overview", self.sample),
+            builder,
+            vec![0, 1],
+            self.truncate_details,
+            self.format,
+        )?;
 
         let mut builder = Builder::new();
 
@@ -561,38 +1097,162 @@ impl Display for Overview {
             }
         }
 
-        let mut table = builder.build();
-        table.with(Style::modern());
-        table.with(Panel::header("Edge Overview"));
-        table.with(MergeDuplicatesVerticalByColumn::new(vec![0, 1]));
-        table.with(Alignment::center_vertical());
-        table.with(BorderCorrection {});
+        write_table(
+            f,
+            &overview_title("Edge Overview", self.sample),
+            builder,
+            vec![0, 1],
+            self.truncate_details,
+            self.format,
+        )?;
+
+        let mut builder = Builder::new();
+
+        builder.push_record([
+            "Group",
+            "Ungrouped Endpoints",
+            "Isolated Nodes",
+            "Average Degree",
+            "Connected Groups",
+        ]);
+
+        for (group, group_overview) in std::iter::once((None, &self.ungrouped_overview))
+            .chain(self.grouped_overviews.iter().map(|(g, o)| (Some(g), o)))
+        {
+            let group_name =
+                group.map_or_else(|| "Ungrouped".to_string(), std::string::ToString::to_string);
+            let [
+                ungrouped_endpoints,
+                isolated_nodes,
+                average_degree,
+                connected_groups,
+            ] = group_overview.structure.record();
+
+            builder.push_record([
+                group_name,
+                ungrouped_endpoints,
+                isolated_nodes,
+                average_degree,
+                connected_groups,
+            ]);
+        }
+
+        write_table(
+            f,
+            "Structure Overview",
+            builder,
+            Vec::new(),
+            self.truncate_details,
+            self.format,
+        )?;
+
+        let mut grouped_metadata: Vec<_> = self
+            .grouped_overviews
+            .iter()
+            .filter(|(_, group_overview)| !group_overview.metadata.is_empty())
+            .collect();
+
+        if grouped_metadata.is_empty() {
+            return Ok(());
+        }
+
+        grouped_metadata.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+        let mut builder = Builder::new();
+
+        builder.push_record(["Group", "Key", "Value"]);
+
+        for (group, group_overview) in grouped_metadata {
+            let group_name = group.to_string();
 
-        if let Some(truncate_details) = self.truncate_details {
-            table.modify(Columns::last(), Width::truncate(truncate_details));
+            for [key, value] in metadata_records(&group_overview.metadata) {
+                builder.push_record([&group_name, &key, &value]);
+            }
         }
 
-        writeln!(f, "{table}")
+        write_table(
+            f,
+            "Group Metadata",
+            builder,
+            vec![0],
+            self.truncate_details,
+            self.format,
+        )
     }
 }
 
 impl Overview {
+    fn write_html(&self, f: &mut impl Write) -> std::fmt::Result {
+        write!(f, "<div class=\"graphrecords-overview\">{HTML_STYLE}")?;
+
+        for (group_name, group_overview) in
+            std::iter::once(("Ungrouped".to_string(), &self.ungrouped_overview)).chain(
+                self.grouped_overviews
+                    .iter()
+                    .map(|(group, overview)| (group.to_string(), overview))
+                    .sorted_by(|(a, _), (b, _)| a.cmp(b)),
+            )
+        {
+            write!(f, "<h4>{}</h4>", escape_html(&group_name))?;
+            group_overview.write_html(f)?;
+        }
+
+        write!(f, "</div>")
+    }
+
+    #[must_use]
+    pub const fn is_approximate(&self) -> bool {
+        self.sample.is_some()
+    }
+
+    /// Renders the overview as an HTML fragment with one section per group,
+    /// suitable for rich notebook output.
+    #[must_use]
+    #[expect(clippy::missing_panics_doc, reason = "infallible")]
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+
+        self.write_html(&mut html)
+            .expect("Writing to a String must succeed");
+
+        html
+    }
+
     pub(crate) fn new(
         graphrecord: &GraphRecord,
         truncate_details: Option<usize>,
+        format: OverviewFormat,
+        sample: Option<OverviewSample>,
+        categorical_threshold: Option<usize>,
     ) -> GraphRecordResult<Self> {
         Ok(Self {
-            ungrouped_overview: GroupOverview::new(graphrecord, None, truncate_details)?,
+            ungrouped_overview: GroupOverview::new(
+                graphrecord,
+                None,
+                truncate_details,
+                format,
+                sample,
+                categorical_threshold,
+            )?,
             grouped_overviews: graphrecord
                 .groups()
                 .map(|group| {
                     Ok::<_, GraphRecordError>((
                         group.clone(),
-                        GroupOverview::new(graphrecord, Some(group), truncate_details)?,
+                        GroupOverview::new(
+                            graphrecord,
+                            Some(group),
+                            truncate_details,
+                            format,
+                            sample,
+                            categorical_threshold,
+                        )?,
                     ))
                 })
                 .collect::<Result<_, _>>()?,
             truncate_details,
+            format,
+            sample,
         })
     }
 }
@@ -0,0 +1,133 @@
+use super::{GraphRecord, GraphRecordAttribute, GraphRecordValue, NodeIndex};
+use crate::errors::{GraphRecordError, GraphRecordResult};
+use ::petgraph::graph::{DiGraph, NodeIndex as PetgraphNodeIndex};
+use graphrecords_utils::aliases::GrHashMap;
+
+/// A petgraph copy of a [`GraphRecord`], keeping the record's node indices as
+/// node weights.
+#[derive(Debug, Clone)]
+pub struct PetgraphExport<W> {
+    pub graph: DiGraph<NodeIndex, W>,
+    pub node_indices: GrHashMap<NodeIndex, PetgraphNodeIndex>,
+}
+
+impl<W> PetgraphExport<W>
+where
+    W: TryFrom<GraphRecordValue>,
+    W::Error: std::fmt::Display,
+{
+    /// Nodes and edges are inserted in sorted order, so the resulting
+    /// petgraph indices are the same for records with equal content.
+    pub(super) fn new(
+        graphrecord: &GraphRecord,
+        weight_attribute: &GraphRecordAttribute,
+    ) -> GraphRecordResult<Self> {
+        let mut graph = DiGraph::with_capacity(graphrecord.node_count(), graphrecord.edge_count());
+        let mut node_indices = GrHashMap::default();
+
+        for node_index in graphrecord.node_indices_sorted() {
+            let petgraph_index = graph.add_node(node_index.clone());
+            node_indices.insert(node_index.clone(), petgraph_index);
+        }
+
+        for edge_index in graphrecord.edge_indices_sorted() {
+            let (source_node_index, target_node_index) = graphrecord.edge_endpoints(edge_index)?;
+
+            let value = graphrecord
+                .edge_attributes(edge_index)?
+                .get(weight_attribute)
+                .ok_or_else(|| {
+                    GraphRecordError::KeyError(format!(
+                        "Cannot find attribute {weight_attribute} for edge {edge_index}"
+                    ))
+                })?;
+
+            let weight = W::try_from(value.clone()).map_err(|error| {
+                GraphRecordError::ConversionError(format!(
+                    "Cannot convert weight of edge {edge_index}: {error}"
+                ))
+            })?;
+
+            graph.add_edge(
+                node_indices[source_node_index],
+                node_indices[target_node_index],
+                weight,
+            );
+        }
+
+        Ok(Self {
+            graph,
+            node_indices,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{GraphRecord, errors::GraphRecordError, graphrecord::NodeIndex};
+    use ::petgraph::algo::dijkstra;
+    use std::collections::HashMap;
+
+    fn create_graphrecord() -> GraphRecord {
+        let mut graphrecord = GraphRecord::new();
+
+        graphrecord.add_node("0".into(), HashMap::new()).unwrap();
+        graphrecord.add_node("1".into(), HashMap::new()).unwrap();
+        graphrecord.add_node("2".into(), HashMap::new()).unwrap();
+        graphrecord
+            .add_edge(
+                "0".into(),
+                "1".into(),
+                HashMap::from([("weight".into(), 1.into())]),
+            )
+            .unwrap();
+        graphrecord
+            .add_edge(
+                "1".into(),
+                "2".into(),
+                HashMap::from([("weight".into(), 2.5.into())]),
+            )
+            .unwrap();
+
+        graphrecord
+    }
+
+    #[test]
+    fn test_as_petgraph() {
+        let graphrecord = create_graphrecord();
+
+        let export = graphrecord.as_petgraph::<f64>(&"weight".into()).unwrap();
+
+        assert_eq!(3, export.graph.node_count());
+        assert_eq!(2, export.graph.edge_count());
+
+        let start = export.node_indices[&NodeIndex::from("0")];
+        let end = export.node_indices[&NodeIndex::from("2")];
+        assert_eq!("2", export.graph[end].to_string());
+
+        let distances = dijkstra(&export.graph, start, Some(end), |edge| *edge.weight());
+        assert!((distances[&end] - 3.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_invalid_as_petgraph() {
+        let mut graphrecord = create_graphrecord();
+
+        // Weight cannot be converted
+        assert!(
+            graphrecord
+                .as_petgraph::<i64>(&"weight".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::ConversionError(_)))
+        );
+
+        // Weight attribute does not exist
+        graphrecord
+            .add_edge("2".into(), "0".into(), HashMap::new())
+            .unwrap();
+        assert!(
+            graphrecord
+                .as_petgraph::<f64>(&"weight".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::KeyError(_)))
+        );
+    }
+}
@@ -1,7 +1,6 @@
-use graphrecords_utils::aliases::GrHashMap;
-
 use super::{
-    Plugin, PostAddEdgeContext, PostAddEdgeToGroupContext, PostAddEdgeToGroupsContext,
+    DEFAULT_PLUGIN_PRIORITY, Plugin, PluginHandle, PluginPriority, PluginRegistry,
+    PostAddEdgeContext, PostAddEdgeToGroupContext, PostAddEdgeToGroupsContext,
     PostAddEdgeWithGroupContext, PostAddEdgeWithGroupsContext, PostAddEdgesContext,
     PostAddEdgesDataframesContext, PostAddEdgesDataframesWithGroupContext,
     PostAddEdgesDataframesWithGroupsContext, PostAddEdgesToGroupsContext,
@@ -14,12 +13,12 @@ use super::{
     PostRemoveEdgeFromGroupContext, PostRemoveEdgeFromGroupsContext,
     PostRemoveEdgesFromGroupsContext, PostRemoveGroupContext, PostRemoveNodeContext,
     PostRemoveNodeFromGroupContext, PostRemoveNodeFromGroupsContext,
-    PostRemoveNodesFromGroupsContext, PreAddEdgeContext, PreAddEdgeToGroupContext,
-    PreAddEdgeToGroupsContext, PreAddEdgeWithGroupContext, PreAddEdgeWithGroupsContext,
-    PreAddEdgesContext, PreAddEdgesDataframesContext, PreAddEdgesDataframesWithGroupContext,
-    PreAddEdgesDataframesWithGroupsContext, PreAddEdgesToGroupsContext,
-    PreAddEdgesWithGroupContext, PreAddEdgesWithGroupsContext, PreAddGroupContext,
-    PreAddNodeContext, PreAddNodeToGroupContext, PreAddNodeToGroupsContext,
+    PostRemoveNodesFromGroupsContext, PostSetSchemaContext, PreAddEdgeContext,
+    PreAddEdgeToGroupContext, PreAddEdgeToGroupsContext, PreAddEdgeWithGroupContext,
+    PreAddEdgeWithGroupsContext, PreAddEdgesContext, PreAddEdgesDataframesContext,
+    PreAddEdgesDataframesWithGroupContext, PreAddEdgesDataframesWithGroupsContext,
+    PreAddEdgesToGroupsContext, PreAddEdgesWithGroupContext, PreAddEdgesWithGroupsContext,
+    PreAddGroupContext, PreAddNodeContext, PreAddNodeToGroupContext, PreAddNodeToGroupsContext,
     PreAddNodeWithGroupContext, PreAddNodeWithGroupsContext, PreAddNodesContext,
     PreAddNodesDataframesContext, PreAddNodesDataframesWithGroupContext,
     PreAddNodesDataframesWithGroupsContext, PreAddNodesToGroupsContext,
@@ -38,11 +37,41 @@ use std::sync::Arc;
 pub type PluginName = GraphRecordAttribute;
 
 impl GraphRecord {
+    /// Creates a `GraphRecord` with `plugins` registered at
+    /// [`DEFAULT_PLUGIN_PRIORITY`], in iteration order.
     pub fn with_plugins(
-        plugins: GrHashMap<PluginName, Box<dyn Plugin>>,
+        plugins: impl IntoIterator<Item = (PluginName, Box<dyn Plugin>)>,
+    ) -> GraphRecordResult<Self> {
+        Self::with_prioritized_plugins(
+            plugins
+                .into_iter()
+                .map(|(name, plugin)| (name, plugin, DEFAULT_PLUGIN_PRIORITY)),
+        )
+    }
+
+    /// Creates a `GraphRecord` with `plugins` registered at the given
+    /// priorities.
+    ///
+    /// Hooks run from the highest to the lowest priority. Plugins with the same
+    /// priority run in registration order. Plugins are initialized in hook
+    /// order once all of them are registered.
+    pub fn with_prioritized_plugins(
+        plugins: impl IntoIterator<Item = (PluginName, Box<dyn Plugin>, PluginPriority)>,
     ) -> GraphRecordResult<Self> {
+        let mut registry = PluginRegistry::default();
+
+        for (name, plugin, priority) in plugins {
+            if registry.contains(&name) {
+                return Err(GraphRecordError::KeyError(format!(
+                    "Plugin with name '{name}' already exists"
+                )));
+            }
+
+            registry.insert(Some(name), plugin, priority);
+        }
+
         let mut graphrecord = Self {
-            plugins: Arc::new(plugins),
+            plugins: Arc::new(registry),
             ..Default::default()
         };
 
@@ -55,12 +84,17 @@ impl GraphRecord {
         Ok(graphrecord)
     }
 
-    pub fn add_plugin(
+    fn register_plugin_impl(
         &mut self,
-        name: PluginName,
+        name: Option<PluginName>,
         plugin: Box<dyn Plugin>,
-    ) -> GraphRecordResult<()> {
-        if self.plugins.contains_key(&name) {
+        priority: PluginPriority,
+    ) -> GraphRecordResult<PluginHandle> {
+        self.assert_not_frozen()?;
+
+        if let Some(name) = &name
+            && self.plugins.contains(name)
+        {
             return Err(GraphRecordError::KeyError(format!(
                 "Plugin with name '{name}' already exists"
             )));
@@ -70,30 +104,111 @@ impl GraphRecord {
 
         let plugins = Arc::make_mut(&mut self.plugins);
 
-        plugins.insert(name, plugin);
+        Ok(plugins.insert(name, plugin, priority))
+    }
+
+    pub fn add_plugin(
+        &mut self,
+        name: PluginName,
+        plugin: Box<dyn Plugin>,
+    ) -> GraphRecordResult<()> {
+        self.add_plugin_with_priority(name, plugin, DEFAULT_PLUGIN_PRIORITY)
+    }
+
+    /// Registers `plugin` after all plugins with a priority greater than or
+    /// equal to `priority`.
+    ///
+    /// The plugin is initialized before it is registered. Plugins added or
+    /// removed from within a hook take effect with the next operation; the
+    /// running operation keeps calling the plugins it started with.
+    pub fn add_plugin_with_priority(
+        &mut self,
+        name: PluginName,
+        plugin: Box<dyn Plugin>,
+        priority: PluginPriority,
+    ) -> GraphRecordResult<()> {
+        self.register_plugin_impl(Some(name), plugin, priority)?;
 
         Ok(())
     }
 
-    pub fn remove_plugin(&mut self, name: &PluginName) -> GraphRecordResult<()> {
-        let plugin = {
-            let plugins = Arc::make_mut(&mut self.plugins);
+    /// Registers an unnamed plugin and returns the handle to unregister it.
+    ///
+    /// Follows the same rules as [`GraphRecord::add_plugin_with_priority`].
+    pub fn register_plugin(&mut self, plugin: Box<dyn Plugin>) -> GraphRecordResult<PluginHandle> {
+        self.register_plugin_impl(None, plugin, DEFAULT_PLUGIN_PRIORITY)
+    }
 
-            plugins.remove(name).ok_or_else(|| {
-                GraphRecordError::KeyError(format!("Plugin with name '{name}' does not exist"))
-            })?
-        };
+    pub fn register_plugin_with_priority(
+        &mut self,
+        plugin: Box<dyn Plugin>,
+        priority: PluginPriority,
+    ) -> GraphRecordResult<PluginHandle> {
+        self.register_plugin_impl(None, plugin, priority)
+    }
+
+    /// Removes a plugin and finalizes it.
+    ///
+    /// If called from within a hook, the running operation still calls the
+    /// remaining hooks of the removed plugin.
+    pub fn unregister_plugin(&mut self, handle: PluginHandle) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
+        let plugin = Arc::make_mut(&mut self.plugins)
+            .remove(handle)
+            .ok_or_else(|| {
+                GraphRecordError::KeyError(format!("Plugin with handle {handle} does not exist"))
+            })?;
 
         plugin.finalize(self)?;
 
         Ok(())
     }
 
+    pub fn remove_plugin(&mut self, name: &PluginName) -> GraphRecordResult<()> {
+        let handle = self.plugin_handle(name)?;
+
+        self.unregister_plugin(handle)
+    }
+
+    pub fn plugin_handle(&self, name: &PluginName) -> GraphRecordResult<PluginHandle> {
+        self.plugins.handle(name).ok_or_else(|| {
+            GraphRecordError::KeyError(format!("Plugin with name '{name}' does not exist"))
+        })
+    }
+
+    #[expect(clippy::missing_panics_doc, reason = "infallible")]
+    pub fn plugin_priority(&self, name: &PluginName) -> GraphRecordResult<PluginPriority> {
+        let handle = self.plugin_handle(name)?;
+
+        Ok(self.plugins.priority(handle).expect("Plugin must exist"))
+    }
+
+    /// Moves a plugin to `priority`, placing it after all plugins with a
+    /// priority greater than or equal to `priority`. The plugin is neither
+    /// finalized nor initialized again.
+    pub fn set_plugin_priority(
+        &mut self,
+        name: &PluginName,
+        priority: PluginPriority,
+    ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
+        let handle = self.plugin_handle(name)?;
+
+        Arc::make_mut(&mut self.plugins).set_priority(handle, priority);
+
+        Ok(())
+    }
+
+    /// Returns the names of all named plugins in the order their hooks run.
     pub fn plugin_names(&self) -> impl Iterator<Item = &PluginName> {
-        self.plugins.keys()
+        self.plugins.names()
     }
 
     pub fn set_schema(&mut self, schema: Schema) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreSetSchemaContext { schema };
@@ -103,11 +218,24 @@ impl GraphRecord {
                 plugin.pre_set_schema(self, pre_context)
             })?;
 
+        if plugins.is_empty() {
+            return self.set_schema_impl(pre_context.schema);
+        }
+
+        let old_schema = self.schema.clone();
+
         self.set_schema_impl(pre_context.schema)?;
 
+        let new_schema = self.schema.clone();
+        let post_context = PostSetSchemaContext {
+            changes: old_schema.diff(&new_schema),
+            old_schema,
+            new_schema,
+        };
+
         plugins
             .iter()
-            .try_for_each(|(_, plugin)| plugin.post_set_schema(self))?;
+            .try_for_each(|(_, plugin)| plugin.post_set_schema(self, post_context.clone()))?;
 
         Ok(())
     }
@@ -117,12 +245,14 @@ impl GraphRecord {
     }
 
     pub fn freeze_schema(&mut self) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
         for (_, plugin) in plugins.iter() {
             plugin.pre_freeze_schema(self)?;
         }
 
-        self.freeze_schema_impl();
+        self.freeze_schema_impl()?;
 
         for (_, plugin) in plugins.iter() {
             plugin.post_freeze_schema(self)?;
@@ -131,19 +261,19 @@ impl GraphRecord {
         Ok(())
     }
 
-    pub const fn freeze_schema_bypass_plugins(&mut self) -> GraphRecordResult<()> {
-        self.freeze_schema_impl();
-
-        Ok(())
+    pub fn freeze_schema_bypass_plugins(&mut self) -> GraphRecordResult<()> {
+        self.freeze_schema_impl()
     }
 
     pub fn unfreeze_schema(&mut self) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
         for (_, plugin) in plugins.iter() {
             plugin.pre_unfreeze_schema(self)?;
         }
 
-        self.unfreeze_schema_impl();
+        self.unfreeze_schema_impl()?;
 
         for (_, plugin) in plugins.iter() {
             plugin.post_unfreeze_schema(self)?;
@@ -152,10 +282,8 @@ impl GraphRecord {
         Ok(())
     }
 
-    pub const fn unfreeze_schema_bypass_plugins(&mut self) -> GraphRecordResult<()> {
-        self.unfreeze_schema_impl();
-
-        Ok(())
+    pub fn unfreeze_schema_bypass_plugins(&mut self) -> GraphRecordResult<()> {
+        self.unfreeze_schema_impl()
     }
 
     pub fn add_node(
@@ -163,6 +291,8 @@ impl GraphRecord {
         node_index: NodeIndex,
         attributes: Attributes,
     ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreAddNodeContext {
@@ -201,6 +331,8 @@ impl GraphRecord {
         attributes: Attributes,
         group: Group,
     ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreAddNodeWithGroupContext {
@@ -244,6 +376,8 @@ impl GraphRecord {
         attributes: Attributes,
         groups: impl AsRef<[Group]>,
     ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreAddNodeWithGroupsContext {
@@ -285,6 +419,8 @@ impl GraphRecord {
     }
 
     pub fn remove_node(&mut self, node_index: &NodeIndex) -> GraphRecordResult<Attributes> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreRemoveNodeContext {
@@ -317,6 +453,8 @@ impl GraphRecord {
     }
 
     pub fn add_nodes(&mut self, nodes: Vec<(NodeIndex, Attributes)>) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreAddNodesContext { nodes };
@@ -352,6 +490,8 @@ impl GraphRecord {
         nodes: Vec<(NodeIndex, Attributes)>,
         group: Group,
     ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreAddNodesWithGroupContext { nodes, group };
@@ -388,6 +528,8 @@ impl GraphRecord {
         nodes: Vec<(NodeIndex, Attributes)>,
         groups: impl AsRef<[Group]>,
     ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreAddNodesWithGroupsContext {
@@ -426,6 +568,8 @@ impl GraphRecord {
         &mut self,
         nodes_dataframes: impl IntoIterator<Item = impl Into<NodeDataFrameInput>>,
     ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreAddNodesDataframesContext {
@@ -462,6 +606,8 @@ impl GraphRecord {
         nodes_dataframes: impl IntoIterator<Item = impl Into<NodeDataFrameInput>>,
         group: Group,
     ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreAddNodesDataframesWithGroupContext {
@@ -504,6 +650,8 @@ impl GraphRecord {
         nodes_dataframes: Vec<NodeDataFrameInput>,
         groups: impl AsRef<[Group]>,
     ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreAddNodesDataframesWithGroupsContext {
@@ -548,6 +696,8 @@ impl GraphRecord {
         target_node_index: NodeIndex,
         attributes: Attributes,
     ) -> GraphRecordResult<EdgeIndex> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreAddEdgeContext {
@@ -593,6 +743,8 @@ impl GraphRecord {
         attributes: Attributes,
         group: Group,
     ) -> GraphRecordResult<EdgeIndex> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreAddEdgeWithGroupContext {
@@ -640,6 +792,8 @@ impl GraphRecord {
         attributes: Attributes,
         groups: impl AsRef<[Group]>,
     ) -> GraphRecordResult<EdgeIndex> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreAddEdgeWithGroupsContext {
@@ -684,6 +838,8 @@ impl GraphRecord {
     }
 
     pub fn remove_edge(&mut self, edge_index: &EdgeIndex) -> GraphRecordResult<Attributes> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreRemoveEdgeContext {
@@ -719,6 +875,8 @@ impl GraphRecord {
         &mut self,
         edges: Vec<(NodeIndex, NodeIndex, Attributes)>,
     ) -> GraphRecordResult<Vec<EdgeIndex>> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreAddEdgesContext { edges };
@@ -753,6 +911,8 @@ impl GraphRecord {
         edges: Vec<(NodeIndex, NodeIndex, Attributes)>,
         group: &Group,
     ) -> GraphRecordResult<Vec<EdgeIndex>> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreAddEdgesWithGroupContext {
@@ -791,6 +951,8 @@ impl GraphRecord {
         edges: Vec<(NodeIndex, NodeIndex, Attributes)>,
         groups: impl AsRef<[Group]>,
     ) -> GraphRecordResult<Vec<EdgeIndex>> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreAddEdgesWithGroupsContext {
@@ -830,6 +992,8 @@ impl GraphRecord {
         &mut self,
         edges_dataframes: impl IntoIterator<Item = impl Into<EdgeDataFrameInput>>,
     ) -> GraphRecordResult<Vec<EdgeIndex>> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreAddEdgesDataframesContext {
@@ -866,6 +1030,8 @@ impl GraphRecord {
         edges_dataframes: impl IntoIterator<Item = impl Into<EdgeDataFrameInput>>,
         group: &Group,
     ) -> GraphRecordResult<Vec<EdgeIndex>> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreAddEdgesDataframesWithGroupContext {
@@ -908,6 +1074,8 @@ impl GraphRecord {
         edges_dataframes: Vec<EdgeDataFrameInput>,
         groups: impl AsRef<[Group]>,
     ) -> GraphRecordResult<Vec<EdgeIndex>> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreAddEdgesDataframesWithGroupsContext {
@@ -951,6 +1119,8 @@ impl GraphRecord {
         node_indices: Option<Vec<NodeIndex>>,
         edge_indices: Option<Vec<EdgeIndex>>,
     ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreAddGroupContext {
@@ -993,6 +1163,8 @@ impl GraphRecord {
     }
 
     pub fn remove_group(&mut self, group: &Group) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreRemoveGroupContext {
@@ -1026,6 +1198,8 @@ impl GraphRecord {
         group: Group,
         node_index: NodeIndex,
     ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreAddNodeToGroupContext { group, node_index };
@@ -1062,6 +1236,8 @@ impl GraphRecord {
         groups: impl AsRef<[Group]>,
         node_index: NodeIndex,
     ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreAddNodeToGroupsContext {
@@ -1101,6 +1277,8 @@ impl GraphRecord {
         groups: impl AsRef<[Group]>,
         node_indices: Vec<NodeIndex>,
     ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreAddNodesToGroupsContext {
@@ -1140,6 +1318,8 @@ impl GraphRecord {
         group: Group,
         edge_index: EdgeIndex,
     ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreAddEdgeToGroupContext { group, edge_index };
@@ -1176,6 +1356,8 @@ impl GraphRecord {
         groups: impl AsRef<[Group]>,
         edge_index: EdgeIndex,
     ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreAddEdgeToGroupsContext {
@@ -1215,6 +1397,8 @@ impl GraphRecord {
         groups: impl AsRef<[Group]>,
         edge_indices: Vec<EdgeIndex>,
     ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreAddEdgesToGroupsContext {
@@ -1254,6 +1438,8 @@ impl GraphRecord {
         group: &Group,
         node_index: &NodeIndex,
     ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreRemoveNodeFromGroupContext {
@@ -1293,6 +1479,8 @@ impl GraphRecord {
         groups: impl AsRef<[Group]>,
         node_index: &NodeIndex,
     ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreRemoveNodeFromGroupsContext {
@@ -1332,6 +1520,8 @@ impl GraphRecord {
         groups: impl AsRef<[Group]>,
         node_indices: &[NodeIndex],
     ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreRemoveNodesFromGroupsContext {
@@ -1371,6 +1561,8 @@ impl GraphRecord {
         group: &Group,
         edge_index: &EdgeIndex,
     ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreRemoveEdgeFromGroupContext {
@@ -1410,6 +1602,8 @@ impl GraphRecord {
         groups: impl AsRef<[Group]>,
         edge_index: &EdgeIndex,
     ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreRemoveEdgeFromGroupsContext {
@@ -1449,6 +1643,8 @@ impl GraphRecord {
         groups: impl AsRef<[Group]>,
         edge_indices: &[EdgeIndex],
     ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         let pre_context = PreRemoveEdgesFromGroupsContext {
@@ -1484,13 +1680,15 @@ impl GraphRecord {
     }
 
     pub fn clear(&mut self) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
         let plugins = self.plugins.clone();
 
         for (_, plugin) in plugins.iter() {
             plugin.pre_clear(self)?;
         }
 
-        self.clear_impl();
+        self.clear_impl()?;
 
         for (_, plugin) in plugins.iter() {
             plugin.post_clear(self)?;
@@ -1500,8 +1698,6 @@ impl GraphRecord {
     }
 
     pub fn clear_bypass_plugins(&mut self) -> GraphRecordResult<()> {
-        self.clear_impl();
-
-        Ok(())
+        self.clear_impl()
     }
 }
@@ -0,0 +1,601 @@
+use super::{
+    Plugin, PreAddEdgeContext, PreAddEdgeWithGroupContext, PreAddEdgeWithGroupsContext,
+    PreAddEdgesContext, PreAddEdgesDataframesContext, PreAddEdgesDataframesWithGroupContext,
+    PreAddEdgesDataframesWithGroupsContext, PreAddEdgesWithGroupContext,
+    PreAddEdgesWithGroupsContext, PreAddNodeContext, PreAddNodeWithGroupContext,
+    PreAddNodeWithGroupsContext, PreAddNodesContext, PreAddNodesDataframesContext,
+    PreAddNodesDataframesWithGroupContext, PreAddNodesDataframesWithGroupsContext,
+    PreAddNodesWithGroupContext, PreAddNodesWithGroupsContext,
+};
+use crate::{
+    GraphRecord,
+    errors::{GraphRecordError, GraphRecordResult},
+    graphrecord::{EdgeDataFrameInput, NodeDataFrameInput},
+    prelude::{Attributes, GraphRecordAttribute, GraphRecordValue},
+};
+use parking_lot::RwLock;
+use polars::{datatypes::AnyValue, frame::DataFrame, prelude::Series};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+/// Dictionaries of the values to replace, per attribute.
+pub type ValueMappings = HashMap<GraphRecordAttribute, HashMap<GraphRecordValue, GraphRecordValue>>;
+
+/// Values per attribute that had no entry in the dictionary of their
+/// attribute.
+pub type UnmappedValues = HashMap<GraphRecordAttribute, HashSet<GraphRecordValue>>;
+
+#[derive(Debug, Default)]
+struct UnmappedReport {
+    nodes: UnmappedValues,
+    edges: UnmappedValues,
+}
+
+/// Replaces the attribute values of added nodes and edges with the values
+/// from a dictionary per attribute, e.g. to translate local lab codes into
+/// LOINC codes while ingesting them.
+///
+/// Values without an entry in the dictionary of their attribute are kept as
+/// they are and reported by [`MappingPlugin::unmapped_node_values`] and
+/// [`MappingPlugin::unmapped_edge_values`]. Clones of the plugin share that
+/// report, so a clone kept aside sees what the registered plugin could not map.
+/// Null and missing values are neither mapped nor reported.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MappingPlugin {
+    node_mappings: ValueMappings,
+    edge_mappings: ValueMappings,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    unmapped: Arc<RwLock<UnmappedReport>>,
+}
+
+fn map_attributes(
+    mappings: &ValueMappings,
+    unmapped: &mut UnmappedValues,
+    attributes: &mut Attributes,
+) {
+    for (attribute, value) in attributes.iter_mut() {
+        let Some(mapping) = mappings.get(attribute) else {
+            continue;
+        };
+
+        if value.is_null() || value.is_missing() {
+            continue;
+        }
+
+        match mapping.get(value) {
+            Some(mapped_value) => *value = mapped_value.clone(),
+            None => {
+                unmapped
+                    .entry(attribute.clone())
+                    .or_default()
+                    .insert(value.clone());
+            }
+        }
+    }
+}
+
+fn map_dataframe(
+    mappings: &ValueMappings,
+    unmapped: &mut UnmappedValues,
+    dataframe: &mut DataFrame,
+    index_columns: &[&str],
+) -> GraphRecordResult<()> {
+    let column_names: Vec<_> = dataframe
+        .get_column_names()
+        .into_iter()
+        .filter(|name| !index_columns.contains(&name.as_str()))
+        .cloned()
+        .collect();
+
+    for column_name in column_names {
+        let attribute = GraphRecordAttribute::from(column_name.as_str());
+
+        let Some(mapping) = mappings.get(&attribute) else {
+            continue;
+        };
+
+        let values = dataframe
+            .column(&column_name)
+            .expect("Column must exist")
+            .as_materialized_series()
+            .iter()
+            .map(|value| {
+                let value = GraphRecordValue::try_from(value)?;
+
+                if value.is_null() {
+                    return Ok(value.into());
+                }
+
+                if let Some(mapped_value) = mapping.get(&value) {
+                    return Ok(mapped_value.clone().into());
+                }
+
+                unmapped
+                    .entry(attribute.clone())
+                    .or_default()
+                    .insert(value.clone());
+
+                Ok(value.into())
+            })
+            .collect::<GraphRecordResult<Vec<AnyValue>>>()?;
+
+        let series =
+            Series::from_any_values(column_name.clone(), &values, false).map_err(|error| {
+                GraphRecordError::ConversionError(format!(
+                    "Cannot map values of column {column_name}: {error}"
+                ))
+            })?;
+
+        dataframe.with_column(series.into()).map_err(|error| {
+            GraphRecordError::ConversionError(format!(
+                "Cannot map values of column {column_name}: {error}"
+            ))
+        })?;
+    }
+
+    Ok(())
+}
+
+impl MappingPlugin {
+    #[must_use]
+    pub fn new(node_mappings: ValueMappings, edge_mappings: ValueMappings) -> Self {
+        Self {
+            node_mappings,
+            edge_mappings,
+            unmapped: Arc::default(),
+        }
+    }
+
+    #[must_use]
+    pub const fn node_mappings(&self) -> &ValueMappings {
+        &self.node_mappings
+    }
+
+    #[must_use]
+    pub const fn edge_mappings(&self) -> &ValueMappings {
+        &self.edge_mappings
+    }
+
+    /// Returns the node attribute values that had no entry in their
+    /// dictionary since the plugin was created or last cleared.
+    #[must_use]
+    pub fn unmapped_node_values(&self) -> UnmappedValues {
+        self.unmapped.read().nodes.clone()
+    }
+
+    /// Returns the edge attribute values that had no entry in their
+    /// dictionary since the plugin was created or last cleared.
+    #[must_use]
+    pub fn unmapped_edge_values(&self) -> UnmappedValues {
+        self.unmapped.read().edges.clone()
+    }
+
+    pub fn clear_unmapped_values(&self) {
+        *self.unmapped.write() = UnmappedReport::default();
+    }
+
+    fn map_nodes<'a>(&self, nodes: impl IntoIterator<Item = &'a mut Attributes>) {
+        let mut unmapped = self.unmapped.write();
+
+        for attributes in nodes {
+            map_attributes(&self.node_mappings, &mut unmapped.nodes, attributes);
+        }
+    }
+
+    fn map_edges<'a>(&self, edges: impl IntoIterator<Item = &'a mut Attributes>) {
+        let mut unmapped = self.unmapped.write();
+
+        for attributes in edges {
+            map_attributes(&self.edge_mappings, &mut unmapped.edges, attributes);
+        }
+    }
+
+    fn map_nodes_dataframes(
+        &self,
+        nodes_dataframes: &mut [NodeDataFrameInput],
+    ) -> GraphRecordResult<()> {
+        let mut unmapped = self.unmapped.write();
+
+        nodes_dataframes.iter_mut().try_for_each(|input| {
+            map_dataframe(
+                &self.node_mappings,
+                &mut unmapped.nodes,
+                &mut input.dataframe,
+                &[input.index_column.as_str()],
+            )
+        })
+    }
+
+    fn map_edges_dataframes(
+        &self,
+        edges_dataframes: &mut [EdgeDataFrameInput],
+    ) -> GraphRecordResult<()> {
+        let mut unmapped = self.unmapped.write();
+
+        edges_dataframes.iter_mut().try_for_each(|input| {
+            map_dataframe(
+                &self.edge_mappings,
+                &mut unmapped.edges,
+                &mut input.dataframe,
+                &[
+                    input.source_index_column.as_str(),
+                    input.target_index_column.as_str(),
+                ],
+            )
+        })
+    }
+}
+
+#[cfg_attr(feature = "serde", typetag::serde)]
+impl Plugin for MappingPlugin {
+    fn clone_box(&self) -> Box<dyn Plugin> {
+        Box::new(self.clone())
+    }
+
+    fn pre_add_node(
+        &self,
+        _graphrecord: &mut GraphRecord,
+        mut context: PreAddNodeContext,
+    ) -> GraphRecordResult<PreAddNodeContext> {
+        self.map_nodes([&mut context.attributes]);
+
+        Ok(context)
+    }
+
+    fn pre_add_node_with_group(
+        &self,
+        _graphrecord: &mut GraphRecord,
+        mut context: PreAddNodeWithGroupContext,
+    ) -> GraphRecordResult<PreAddNodeWithGroupContext> {
+        self.map_nodes([&mut context.attributes]);
+
+        Ok(context)
+    }
+
+    fn pre_add_node_with_groups(
+        &self,
+        _graphrecord: &mut GraphRecord,
+        mut context: PreAddNodeWithGroupsContext,
+    ) -> GraphRecordResult<PreAddNodeWithGroupsContext> {
+        self.map_nodes([&mut context.attributes]);
+
+        Ok(context)
+    }
+
+    fn pre_add_nodes(
+        &self,
+        _graphrecord: &mut GraphRecord,
+        mut context: PreAddNodesContext,
+    ) -> GraphRecordResult<PreAddNodesContext> {
+        self.map_nodes(context.nodes.iter_mut().map(|(_, attributes)| attributes));
+
+        Ok(context)
+    }
+
+    fn pre_add_nodes_with_group(
+        &self,
+        _graphrecord: &mut GraphRecord,
+        mut context: PreAddNodesWithGroupContext,
+    ) -> GraphRecordResult<PreAddNodesWithGroupContext> {
+        self.map_nodes(context.nodes.iter_mut().map(|(_, attributes)| attributes));
+
+        Ok(context)
+    }
+
+    fn pre_add_nodes_with_groups(
+        &self,
+        _graphrecord: &mut GraphRecord,
+        mut context: PreAddNodesWithGroupsContext,
+    ) -> GraphRecordResult<PreAddNodesWithGroupsContext> {
+        self.map_nodes(context.nodes.iter_mut().map(|(_, attributes)| attributes));
+
+        Ok(context)
+    }
+
+    fn pre_add_nodes_dataframes(
+        &self,
+        _graphrecord: &mut GraphRecord,
+        mut context: PreAddNodesDataframesContext,
+    ) -> GraphRecordResult<PreAddNodesDataframesContext> {
+        self.map_nodes_dataframes(&mut context.nodes_dataframes)?;
+
+        Ok(context)
+    }
+
+    fn pre_add_nodes_dataframes_with_group(
+        &self,
+        _graphrecord: &mut GraphRecord,
+        mut context: PreAddNodesDataframesWithGroupContext,
+    ) -> GraphRecordResult<PreAddNodesDataframesWithGroupContext> {
+        self.map_nodes_dataframes(&mut context.nodes_dataframes)?;
+
+        Ok(context)
+    }
+
+    fn pre_add_nodes_dataframes_with_groups(
+        &self,
+        _graphrecord: &mut GraphRecord,
+        mut context: PreAddNodesDataframesWithGroupsContext,
+    ) -> GraphRecordResult<PreAddNodesDataframesWithGroupsContext> {
+        self.map_nodes_dataframes(&mut context.nodes_dataframes)?;
+
+        Ok(context)
+    }
+
+    fn pre_add_edge(
+        &self,
+        _graphrecord: &mut GraphRecord,
+        mut context: PreAddEdgeContext,
+    ) -> GraphRecordResult<PreAddEdgeContext> {
+        self.map_edges([&mut context.attributes]);
+
+        Ok(context)
+    }
+
+    fn pre_add_edge_with_group(
+        &self,
+        _graphrecord: &mut GraphRecord,
+        mut context: PreAddEdgeWithGroupContext,
+    ) -> GraphRecordResult<PreAddEdgeWithGroupContext> {
+        self.map_edges([&mut context.attributes]);
+
+        Ok(context)
+    }
+
+    fn pre_add_edge_with_groups(
+        &self,
+        _graphrecord: &mut GraphRecord,
+        mut context: PreAddEdgeWithGroupsContext,
+    ) -> GraphRecordResult<PreAddEdgeWithGroupsContext> {
+        self.map_edges([&mut context.attributes]);
+
+        Ok(context)
+    }
+
+    fn pre_add_edges(
+        &self,
+        _graphrecord: &mut GraphRecord,
+        mut context: PreAddEdgesContext,
+    ) -> GraphRecordResult<PreAddEdgesContext> {
+        self.map_edges(
+            context
+                .edges
+                .iter_mut()
+                .map(|(_, _, attributes)| attributes),
+        );
+
+        Ok(context)
+    }
+
+    fn pre_add_edges_with_group(
+        &self,
+        _graphrecord: &mut GraphRecord,
+        mut context: PreAddEdgesWithGroupContext,
+    ) -> GraphRecordResult<PreAddEdgesWithGroupContext> {
+        self.map_edges(
+            context
+                .edges
+                .iter_mut()
+                .map(|(_, _, attributes)| attributes),
+        );
+
+        Ok(context)
+    }
+
+    fn pre_add_edges_with_groups(
+        &self,
+        _graphrecord: &mut GraphRecord,
+        mut context: PreAddEdgesWithGroupsContext,
+    ) -> GraphRecordResult<PreAddEdgesWithGroupsContext> {
+        self.map_edges(
+            context
+                .edges
+                .iter_mut()
+                .map(|(_, _, attributes)| attributes),
+        );
+
+        Ok(context)
+    }
+
+    fn pre_add_edges_dataframes(
+        &self,
+        _graphrecord: &mut GraphRecord,
+        mut context: PreAddEdgesDataframesContext,
+    ) -> GraphRecordResult<PreAddEdgesDataframesContext> {
+        self.map_edges_dataframes(&mut context.edges_dataframes)?;
+
+        Ok(context)
+    }
+
+    fn pre_add_edges_dataframes_with_group(
+        &self,
+        _graphrecord: &mut GraphRecord,
+        mut context: PreAddEdgesDataframesWithGroupContext,
+    ) -> GraphRecordResult<PreAddEdgesDataframesWithGroupContext> {
+        self.map_edges_dataframes(&mut context.edges_dataframes)?;
+
+        Ok(context)
+    }
+
+    fn pre_add_edges_dataframes_with_groups(
+        &self,
+        _graphrecord: &mut GraphRecord,
+        mut context: PreAddEdgesDataframesWithGroupsContext,
+    ) -> GraphRecordResult<PreAddEdgesDataframesWithGroupsContext> {
+        self.map_edges_dataframes(&mut context.edges_dataframes)?;
+
+        Ok(context)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{MappingPlugin, ValueMappings};
+    use crate::{
+        GraphRecord,
+        errors::GraphRecordError,
+        graphrecord::{GraphRecordAttribute, GraphRecordValue},
+    };
+    use polars::prelude::{DataFrame, NamedFrom, Series};
+    use std::collections::{HashMap, HashSet};
+
+    fn create_mappings() -> ValueMappings {
+        HashMap::from([(
+            "code".into(),
+            HashMap::from([
+                ("GLU".into(), "2345-7".into()),
+                ("HBA1C".into(), "4548-4".into()),
+            ]),
+        )])
+    }
+
+    #[test]
+    fn test_map_nodes() {
+        let plugin = MappingPlugin::new(create_mappings(), HashMap::new());
+        let mut graphrecord = GraphRecord::new();
+
+        graphrecord
+            .add_plugin("mapping".into(), Box::new(plugin.clone()))
+            .unwrap();
+
+        graphrecord
+            .add_node("0".into(), HashMap::from([("code".into(), "GLU".into())]))
+            .unwrap();
+        graphrecord
+            .add_nodes(vec![
+                ("1".into(), HashMap::from([("code".into(), "HBA1C".into())])),
+                ("2".into(), HashMap::from([("code".into(), "LDL".into())])),
+                ("3".into(), HashMap::from([("name".into(), "GLU".into())])),
+            ])
+            .unwrap();
+
+        let code = |node_index: &str| {
+            graphrecord
+                .node_attributes(&node_index.into())
+                .unwrap()
+                .get(&"code".into())
+                .cloned()
+        };
+
+        assert_eq!(Some(GraphRecordValue::from("2345-7")), code("0"));
+        assert_eq!(Some(GraphRecordValue::from("4548-4")), code("1"));
+        assert_eq!(Some(GraphRecordValue::from("LDL")), code("2"));
+        assert_eq!(
+            Some(&GraphRecordValue::from("GLU")),
+            graphrecord
+                .node_attributes(&"3".into())
+                .unwrap()
+                .get(&"name".into())
+        );
+
+        assert_eq!(
+            HashMap::from([(
+                GraphRecordAttribute::from("code"),
+                HashSet::from([GraphRecordValue::from("LDL")])
+            )]),
+            plugin.unmapped_node_values()
+        );
+        assert!(plugin.unmapped_edge_values().is_empty());
+
+        plugin.clear_unmapped_values();
+
+        assert!(plugin.unmapped_node_values().is_empty());
+    }
+
+    #[test]
+    fn test_frozen_map_nodes() {
+        let plugin = MappingPlugin::new(create_mappings(), HashMap::new());
+        let mut graphrecord = GraphRecord::new();
+
+        graphrecord
+            .add_plugin("mapping".into(), Box::new(plugin.clone()))
+            .unwrap();
+        graphrecord.freeze();
+
+        // The hooks of a frozen record should not run
+        assert!(
+            graphrecord
+                .add_node("0".into(), HashMap::from([("code".into(), "LDL".into())]))
+                .is_err_and(|e| matches!(e, GraphRecordError::ReadOnlyError(_)))
+        );
+        assert!(plugin.unmapped_node_values().is_empty());
+    }
+
+    #[test]
+    fn test_map_edges() {
+        let plugin = MappingPlugin::new(HashMap::new(), create_mappings());
+        let mut graphrecord = GraphRecord::new();
+
+        graphrecord
+            .add_nodes(vec![
+                ("0".into(), HashMap::new()),
+                ("1".into(), HashMap::new()),
+            ])
+            .unwrap();
+        graphrecord
+            .add_plugin("mapping".into(), Box::new(plugin.clone()))
+            .unwrap();
+
+        let edge_index = graphrecord
+            .add_edge(
+                "0".into(),
+                "1".into(),
+                HashMap::from([("code".into(), "GLU".into())]),
+            )
+            .unwrap();
+
+        assert_eq!(
+            Some(&GraphRecordValue::from("2345-7")),
+            graphrecord
+                .edge_attributes(&edge_index)
+                .unwrap()
+                .get(&"code".into())
+        );
+        assert!(plugin.unmapped_node_values().is_empty());
+    }
+
+    #[test]
+    fn test_map_nodes_dataframes() {
+        let plugin = MappingPlugin::new(create_mappings(), HashMap::new());
+        let mut graphrecord = GraphRecord::new();
+
+        graphrecord
+            .add_plugin("mapping".into(), Box::new(plugin.clone()))
+            .unwrap();
+
+        let s0 = Series::new("index".into(), &["0", "1"]);
+        let s1 = Series::new("code".into(), &["GLU", "LDL"]);
+        let nodes = DataFrame::new(2, vec![s0.into(), s1.into()]).unwrap();
+
+        graphrecord
+            .add_nodes_dataframes(vec![(nodes, "index".to_string())])
+            .unwrap();
+
+        assert_eq!(
+            Some(&GraphRecordValue::from("2345-7")),
+            graphrecord
+                .node_attributes(&"0".into())
+                .unwrap()
+                .get(&"code".into())
+        );
+        assert_eq!(
+            Some(&GraphRecordValue::from("LDL")),
+            graphrecord
+                .node_attributes(&"1".into())
+                .unwrap()
+                .get(&"code".into())
+        );
+        assert_eq!(
+            HashMap::from([(
+                GraphRecordAttribute::from("code"),
+                HashSet::from([GraphRecordValue::from("LDL")])
+            )]),
+            plugin.unmapped_node_values()
+        );
+    }
+}
@@ -1,12 +1,18 @@
 mod graphrecord;
+mod mapping;
+mod overlay;
+mod registry;
 
 use crate::{
     GraphRecord,
     errors::GraphRecordResult,
-    graphrecord::{EdgeDataFrameInput, NodeDataFrameInput},
+    graphrecord::{EdgeDataFrameInput, NodeDataFrameInput, schema::SchemaDiff},
     prelude::{Attributes, EdgeIndex, Group, NodeIndex, Schema},
 };
 pub use graphrecord::PluginName;
+pub use mapping::{MappingPlugin, UnmappedValues, ValueMappings};
+pub(crate) use registry::PluginRegistry;
+pub use registry::{DEFAULT_PLUGIN_PRIORITY, PluginHandle, PluginPriority};
 use std::fmt::Debug;
 
 #[derive(Debug, Clone)]
@@ -14,6 +20,14 @@ pub struct PreSetSchemaContext {
     pub schema: Schema,
 }
 
+/// `changes` is the [`Schema::diff`] of `old_schema` and `new_schema`.
+#[derive(Debug, Clone)]
+pub struct PostSetSchemaContext {
+    pub old_schema: Schema,
+    pub new_schema: Schema,
+    pub changes: SchemaDiff,
+}
+
 #[derive(Debug, Clone)]
 pub struct PreAddNodeContext {
     pub node_index: NodeIndex,
@@ -434,7 +448,11 @@ pub trait Plugin: Debug + Send + Sync {
         Ok(context)
     }
 
-    fn post_set_schema(&self, graphrecord: &mut GraphRecord) -> GraphRecordResult<()> {
+    fn post_set_schema(
+        &self,
+        graphrecord: &mut GraphRecord,
+        context: PostSetSchemaContext,
+    ) -> GraphRecordResult<()> {
         Ok(())
     }
 
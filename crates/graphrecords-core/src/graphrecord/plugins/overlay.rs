@@ -0,0 +1,124 @@
+use super::{
+    PostAddEdgeContext, PostAddNodeContext, PostRemoveEdgeContext, PostRemoveNodeContext,
+    PreAddEdgeContext, PreAddNodeContext, PreRemoveEdgeContext, PreRemoveNodeContext,
+};
+use crate::{
+    errors::GraphRecordResult,
+    graphrecord::overlay::GraphRecordOverlay,
+    prelude::{Attributes, EdgeIndex, NodeIndex},
+};
+
+impl GraphRecordOverlay {
+    pub fn add_node(
+        &mut self,
+        node_index: NodeIndex,
+        attributes: Attributes,
+    ) -> GraphRecordResult<()> {
+        let plugins = self.graphrecord.plugins.clone();
+
+        let pre_context = PreAddNodeContext {
+            node_index: node_index.clone(),
+            attributes,
+        };
+        let pre_context = plugins
+            .iter()
+            .try_fold(pre_context, |pre_context, (_, plugin)| {
+                plugin.pre_add_node(&mut self.graphrecord, pre_context)
+            })?;
+
+        self.add_node_impl(pre_context.node_index, pre_context.attributes)?;
+
+        let post_context = PostAddNodeContext { node_index };
+
+        plugins.iter().try_for_each(|(_, plugin)| {
+            plugin.post_add_node(&mut self.graphrecord, post_context.clone())
+        })?;
+
+        Ok(())
+    }
+
+    /// Removes the node and all of its edges.
+    pub fn remove_node(&mut self, node_index: &NodeIndex) -> GraphRecordResult<Attributes> {
+        let plugins = self.graphrecord.plugins.clone();
+
+        let pre_context = PreRemoveNodeContext {
+            node_index: node_index.clone(),
+        };
+        let pre_context = plugins
+            .iter()
+            .try_fold(pre_context, |pre_context, (_, plugin)| {
+                plugin.pre_remove_node(&mut self.graphrecord, pre_context)
+            })?;
+
+        let attributes = self.remove_node_impl(&pre_context.node_index)?;
+
+        let post_context = PostRemoveNodeContext {
+            node_index: pre_context.node_index,
+        };
+
+        plugins.iter().try_for_each(|(_, plugin)| {
+            plugin.post_remove_node(&mut self.graphrecord, post_context.clone())
+        })?;
+
+        Ok(attributes)
+    }
+
+    pub fn add_edge(
+        &mut self,
+        source_node_index: NodeIndex,
+        target_node_index: NodeIndex,
+        attributes: Attributes,
+    ) -> GraphRecordResult<EdgeIndex> {
+        let plugins = self.graphrecord.plugins.clone();
+
+        let pre_context = PreAddEdgeContext {
+            source_node_index,
+            target_node_index,
+            attributes,
+        };
+        let pre_context = plugins
+            .iter()
+            .try_fold(pre_context, |pre_context, (_, plugin)| {
+                plugin.pre_add_edge(&mut self.graphrecord, pre_context)
+            })?;
+
+        let edge_index = self.add_edge_impl(
+            pre_context.source_node_index,
+            pre_context.target_node_index,
+            pre_context.attributes,
+        )?;
+
+        let post_context = PostAddEdgeContext { edge_index };
+
+        plugins.iter().try_for_each(|(_, plugin)| {
+            plugin.post_add_edge(&mut self.graphrecord, post_context.clone())
+        })?;
+
+        Ok(edge_index)
+    }
+
+    pub fn remove_edge(&mut self, edge_index: &EdgeIndex) -> GraphRecordResult<Attributes> {
+        let plugins = self.graphrecord.plugins.clone();
+
+        let pre_context = PreRemoveEdgeContext {
+            edge_index: *edge_index,
+        };
+        let pre_context = plugins
+            .iter()
+            .try_fold(pre_context, |pre_context, (_, plugin)| {
+                plugin.pre_remove_edge(&mut self.graphrecord, pre_context)
+            })?;
+
+        let attributes = self.remove_edge_impl(&pre_context.edge_index)?;
+
+        let post_context = PostRemoveEdgeContext {
+            edge_index: pre_context.edge_index,
+        };
+
+        plugins.iter().try_for_each(|(_, plugin)| {
+            plugin.post_remove_edge(&mut self.graphrecord, post_context.clone())
+        })?;
+
+        Ok(attributes)
+    }
+}
@@ -0,0 +1,205 @@
+use super::{Plugin, PluginName};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+pub type PluginPriority = i32;
+
+pub const DEFAULT_PLUGIN_PRIORITY: PluginPriority = 0;
+
+/// Identifies a plugin registered on a `GraphRecord`, named or not.
+///
+/// Handles are unique within a record and are not reused after the plugin
+/// was removed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PluginHandle(u64);
+
+impl PluginHandle {
+    #[must_use]
+    pub const fn id(self) -> u64 {
+        self.0
+    }
+}
+
+impl From<u64> for PluginHandle {
+    fn from(id: u64) -> Self {
+        Self(id)
+    }
+}
+
+impl Display for PluginHandle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct RegisteredPlugin {
+    handle: PluginHandle,
+    name: Option<PluginName>,
+    priority: PluginPriority,
+    plugin: Box<dyn Plugin>,
+}
+
+/// Plugins of a `GraphRecord`, kept in the order their hooks run.
+///
+/// Hooks run from the highest to the lowest priority. Plugins with the same
+/// priority run in the order they were registered.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PluginRegistry {
+    plugins: Vec<RegisteredPlugin>,
+    next_handle: u64,
+}
+
+impl PluginRegistry {
+    fn position(&self, handle: PluginHandle) -> Option<usize> {
+        self.plugins
+            .iter()
+            .position(|registered| registered.handle == handle)
+    }
+
+    fn insert_registered(&mut self, registered: RegisteredPlugin) {
+        let position = self
+            .plugins
+            .partition_point(|other| other.priority >= registered.priority);
+
+        self.plugins.insert(position, registered);
+    }
+
+    pub fn handle(&self, name: &PluginName) -> Option<PluginHandle> {
+        self.plugins
+            .iter()
+            .find(|registered| registered.name.as_ref() == Some(name))
+            .map(|registered| registered.handle)
+    }
+
+    pub fn contains(&self, name: &PluginName) -> bool {
+        self.handle(name).is_some()
+    }
+
+    pub fn insert(
+        &mut self,
+        name: Option<PluginName>,
+        plugin: Box<dyn Plugin>,
+        priority: PluginPriority,
+    ) -> PluginHandle {
+        let handle = PluginHandle(self.next_handle);
+        self.next_handle += 1;
+
+        self.insert_registered(RegisteredPlugin {
+            handle,
+            name,
+            priority,
+            plugin,
+        });
+
+        handle
+    }
+
+    pub fn remove(&mut self, handle: PluginHandle) -> Option<Box<dyn Plugin>> {
+        let position = self.position(handle)?;
+
+        Some(self.plugins.remove(position).plugin)
+    }
+
+    pub fn priority(&self, handle: PluginHandle) -> Option<PluginPriority> {
+        self.position(handle)
+            .map(|position| self.plugins[position].priority)
+    }
+
+    /// Moves the plugin behind all plugins with a priority greater than or
+    /// equal to `priority`. Returns `false` if the plugin does not exist.
+    pub fn set_priority(&mut self, handle: PluginHandle, priority: PluginPriority) -> bool {
+        let Some(position) = self.position(handle) else {
+            return false;
+        };
+
+        let mut registered = self.plugins.remove(position);
+        registered.priority = priority;
+
+        self.insert_registered(registered);
+
+        true
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.plugins.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (PluginHandle, &dyn Plugin)> {
+        self.plugins
+            .iter()
+            .map(|registered| (registered.handle, registered.plugin.as_ref()))
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &PluginName> {
+        self.plugins
+            .iter()
+            .filter_map(|registered| registered.name.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{DEFAULT_PLUGIN_PRIORITY, PluginRegistry};
+    use crate::graphrecord::plugins::{Plugin, PluginName};
+
+    #[derive(Debug, Clone)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    struct NoopPlugin;
+
+    #[cfg_attr(feature = "serde", typetag::serde)]
+    impl Plugin for NoopPlugin {
+        fn clone_box(&self) -> Box<dyn Plugin> {
+            Box::new(self.clone())
+        }
+    }
+
+    #[test]
+    fn test_order() {
+        let mut registry = PluginRegistry::default();
+
+        registry.insert(
+            Some("audit".into()),
+            Box::new(NoopPlugin),
+            DEFAULT_PLUGIN_PRIORITY,
+        );
+        let validation = registry.insert(Some("validation".into()), Box::new(NoopPlugin), 10);
+        let anonymous = registry.insert(None, Box::new(NoopPlugin), DEFAULT_PLUGIN_PRIORITY);
+        registry.insert(Some("cleanup".into()), Box::new(NoopPlugin), -10);
+
+        assert_eq!(
+            vec![
+                PluginName::from("validation"),
+                PluginName::from("audit"),
+                PluginName::from("cleanup"),
+            ],
+            registry.names().cloned().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Some(anonymous),
+            registry.iter().nth(2).map(|(handle, _)| handle)
+        );
+
+        assert_eq!(Some(10), registry.priority(validation));
+        assert!(registry.set_priority(validation, -20));
+        assert_eq!(
+            Some(&PluginName::from("validation")),
+            registry.names().last()
+        );
+
+        assert_eq!(Some(validation), registry.handle(&"validation".into()));
+        assert!(registry.remove(validation).is_some());
+        assert!(registry.remove(validation).is_none());
+        assert!(!registry.contains(&"validation".into()));
+
+        // Handles are not reused
+        assert_ne!(
+            validation,
+            registry.insert(None, Box::new(NoopPlugin), DEFAULT_PLUGIN_PRIORITY)
+        );
+    }
+}
@@ -1,12 +1,18 @@
 use crate::{
     GraphRecord,
     errors::{GraphRecordError, GraphRecordResult},
-    graphrecord::{Attributes, GraphRecordAttribute, GraphRecordValue, NodeIndex},
+    graphrecord::{
+        Attributes, GraphRecordAttribute, GraphRecordValue, NodeIndex, schema::ConversionMode,
+    },
     prelude::{EdgeIndex, Group},
 };
 use chrono::{DateTime, TimeDelta};
 use graphrecords_utils::aliases::{GrHashMap, GrHashSet};
-use polars::{datatypes::AnyValue, frame::DataFrame, prelude::Column};
+use polars::{
+    datatypes::AnyValue,
+    frame::DataFrame,
+    prelude::{Column, NamedFrom, PlSmallStr, Series, TimeUnit},
+};
 use std::collections::HashMap;
 
 // TODO: Add tests for Duration
@@ -110,7 +116,8 @@ impl From<GraphRecordValue> for AnyValue<'_> {
 
                 AnyValue::Duration(duration_ms, polars::prelude::TimeUnit::Milliseconds)
             }
-            GraphRecordValue::Null => AnyValue::Null,
+            GraphRecordValue::GeoPoint(value) => AnyValue::StringOwned(value.to_string().into()),
+            GraphRecordValue::Null | GraphRecordValue::Missing => AnyValue::Null,
         }
     }
 }
@@ -124,9 +131,106 @@ impl From<GraphRecordAttribute> for AnyValue<'_> {
     }
 }
 
+/// How null values in an attribute column of a data frame are ingested.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NullPolicy {
+    /// Stores Null as the value of the attribute.
+    #[default]
+    Keep,
+    /// Leaves the attribute out of the node or edge.
+    Drop,
+    /// Fails the ingestion with a [`GraphRecordError::ConversionError`].
+    Fail,
+}
+
+/// [`NullPolicy`] of the attribute columns of an ingested data frame. Columns
+/// without their own policy use `default`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct NullHandling {
+    pub default: NullPolicy,
+    pub columns: HashMap<String, NullPolicy>,
+}
+
+impl From<NullPolicy> for NullHandling {
+    fn from(default: NullPolicy) -> Self {
+        Self {
+            default,
+            columns: HashMap::new(),
+        }
+    }
+}
+
+impl NullHandling {
+    #[must_use]
+    pub fn with_column(mut self, column: impl Into<String>, policy: NullPolicy) -> Self {
+        self.columns.insert(column.into(), policy);
+
+        self
+    }
+
+    #[must_use]
+    pub fn policy(&self, column: &str) -> NullPolicy {
+        self.columns.get(column).copied().unwrap_or(self.default)
+    }
+}
+
+/// Converts an attribute value of a data frame. In [`ConversionMode::Strict`],
+/// Datetimes with a timezone are rejected, as the timezone would be dropped.
+fn anyvalue_to_value(
+    value: AnyValue<'_>,
+    mode: ConversionMode,
+) -> GraphRecordResult<GraphRecordValue> {
+    match (mode, &value) {
+        // The value itself is not formatted, as that requires the timezones
+        // feature of polars
+        (ConversionMode::Strict, AnyValue::Datetime(timestamp, _, Some(timezone))) => {
+            Err(GraphRecordError::ConversionError(format!(
+                "Cannot convert Datetime {timestamp} into GraphRecordValue without dropping timezone {timezone}"
+            )))
+        }
+        _ => value.try_into(),
+    }
+}
+
+/// Converts the next value of every attribute column into the attributes of
+/// a node or edge, applying the [`NullPolicy`] of the column to Null values.
+fn next_attributes<'a>(
+    columns: &mut [(impl Iterator<Item = AnyValue<'a>>, PlSmallStr, NullPolicy)],
+    mode: ConversionMode,
+) -> GraphRecordResult<Attributes> {
+    let mut attributes = Attributes::with_capacity(columns.len());
+
+    for (column, column_name, policy) in columns {
+        let value = anyvalue_to_value(
+            column
+                .next()
+                .expect("Should have as many iterations as rows"),
+            mode,
+        )?;
+
+        if matches!(value, GraphRecordValue::Null) {
+            match policy {
+                NullPolicy::Keep => {}
+                NullPolicy::Drop => continue,
+                NullPolicy::Fail => {
+                    return Err(GraphRecordError::ConversionError(format!(
+                        "Column {column_name} contains Null values"
+                    )));
+                }
+            }
+        }
+
+        attributes.insert(column_name.as_str().into(), value);
+    }
+
+    Ok(attributes)
+}
+
 pub fn dataframe_to_nodes(
     mut nodes: DataFrame,
     index_column_name: &str,
+    mode: ConversionMode,
+    nulls: &NullHandling,
 ) -> GraphRecordResult<Vec<(NodeIndex, Attributes)>> {
     if nodes.max_n_chunks() > 1 {
         nodes.rechunk_mut();
@@ -153,22 +257,20 @@ pub fn dataframe_to_nodes(
         .columns()
         .iter()
         .filter(|column| attribute_column_names.contains(column.name()))
-        .map(|s| (s.as_materialized_series().iter(), s.name().clone()))
+        .map(|s| {
+            (
+                s.as_materialized_series().iter(),
+                s.name().clone(),
+                nulls.policy(s.name()),
+            )
+        })
         .collect();
 
     index
         .map(|index_value| {
             Ok((
                 index_value.try_into()?,
-                columns
-                    .iter_mut()
-                    .map(|(column, column_name)| {
-                        Ok((
-                            column_name.as_str().into(),
-                            column.next().expect("msg").try_into()?,
-                        ))
-                    })
-                    .collect::<GraphRecordResult<_>>()?,
+                next_attributes(&mut columns, mode)?,
             ))
         })
         .collect()
@@ -178,6 +280,8 @@ pub fn dataframe_to_edges(
     mut edges: DataFrame,
     source_index_column_name: &str,
     target_index_column_name: &str,
+    mode: ConversionMode,
+    nulls: &NullHandling,
 ) -> GraphRecordResult<Vec<(NodeIndex, NodeIndex, Attributes)>> {
     if edges.max_n_chunks() > 1 {
         edges.rechunk_mut();
@@ -213,7 +317,13 @@ pub fn dataframe_to_edges(
         .columns()
         .iter()
         .filter(|column| attribute_column_names.contains(column.name()))
-        .map(|s| (s.as_materialized_series().iter(), s.name().clone()))
+        .map(|s| {
+            (
+                s.as_materialized_series().iter(),
+                s.name().clone(),
+                nulls.policy(s.name()),
+            )
+        })
         .collect();
 
     source_index
@@ -222,30 +332,163 @@ pub fn dataframe_to_edges(
             Ok((
                 source_index_value.try_into()?,
                 target_index_value.try_into()?,
-                columns
-                    .iter_mut()
-                    .map(|(column, column_name)| {
-                        Ok((
-                            column_name.as_str().into(),
-                            column
-                                .next()
-                                .expect("Should have as many iterations as rows")
-                                .try_into()?,
-                        ))
-                    })
-                    .collect::<GraphRecordResult<_>>()?,
+                next_attributes(&mut columns, mode)?,
             ))
         })
         .collect()
 }
 
+/// Orders columns by their position in `leading`, followed by all other
+/// columns in [`GraphRecordAttribute::total_cmp`] order.
+fn sort_columns(
+    columns: &mut [(GraphRecordAttribute, Vec<AnyValue<'_>>)],
+    leading: &[&GraphRecordAttribute],
+) {
+    let position = |attribute: &GraphRecordAttribute| {
+        leading
+            .iter()
+            .position(|leading| *leading == attribute)
+            .unwrap_or(leading.len())
+    };
+
+    columns.sort_unstable_by(|(a, _), (b, _)| {
+        position(a).cmp(&position(b)).then_with(|| a.total_cmp(b))
+    });
+}
+
+/// Restricts what [`GraphRecord::to_dataframes_with_options`] exports, so
+/// targeted extracts do not materialize the whole record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataFramesExportOptions {
+    /// Groups to export. All groups are exported if `None`.
+    pub groups: Option<Vec<Group>>,
+    /// Whether the ungrouped nodes and edges are exported. If `false`, the
+    /// ungrouped frames only hold the index columns and no rows.
+    pub ungrouped: bool,
+    /// Node attribute columns to export. All attributes of the group schema
+    /// are exported if `None`; attributes missing from the schema are skipped.
+    pub node_attributes: Option<Vec<GraphRecordAttribute>>,
+    /// Edge attribute columns to export, like `node_attributes`.
+    pub edge_attributes: Option<Vec<GraphRecordAttribute>>,
+    /// Whether a `groups` column with the groups of every node and edge is
+    /// added to the frames.
+    pub group_membership: bool,
+    /// Whether rows and columns are sorted, like [`DataFramesExport::new_sorted`].
+    pub sorted: bool,
+    /// In [`ConversionMode::Strict`], Datetimes and Durations are exported
+    /// with nanosecond precision, and values that cannot be exported without
+    /// changing their data type fail the export.
+    pub mode: ConversionMode,
+}
+
+impl Default for DataFramesExportOptions {
+    fn default() -> Self {
+        Self {
+            groups: None,
+            ungrouped: true,
+            node_attributes: None,
+            edge_attributes: None,
+            group_membership: false,
+            sorted: false,
+            mode: ConversionMode::Lenient,
+        }
+    }
+}
+
+/// Converts an attribute value for a data frame. [`ConversionMode::Lenient`]
+/// exports `DateTime` and `Duration` values in milliseconds and `GeoPoint`
+/// values as strings.
+fn value_to_anyvalue(
+    value: GraphRecordValue,
+    mode: ConversionMode,
+) -> GraphRecordResult<AnyValue<'static>> {
+    if mode == ConversionMode::Lenient {
+        return Ok(value.into());
+    }
+
+    match value {
+        GraphRecordValue::DateTime(datetime) => datetime
+            .and_utc()
+            .timestamp_nanos_opt()
+            .map(|timestamp| AnyValue::Datetime(timestamp, TimeUnit::Nanoseconds, None))
+            .ok_or_else(|| {
+                GraphRecordError::ConversionError(format!(
+                    "Cannot convert {datetime} into a Datetime with nanosecond precision"
+                ))
+            }),
+        GraphRecordValue::Duration(duration) => duration
+            .num_nanoseconds()
+            .map(|nanoseconds| AnyValue::Duration(nanoseconds, TimeUnit::Nanoseconds))
+            .ok_or_else(|| {
+                GraphRecordError::ConversionError(format!(
+                    "Cannot convert {duration} into a Duration with nanosecond precision"
+                ))
+            }),
+        GraphRecordValue::GeoPoint(_) => Err(GraphRecordError::ConversionError(format!(
+            "Cannot convert {value} into a DataFrame value without changing its data type"
+        ))),
+        value => Ok(value.into()),
+    }
+}
+
+/// Builds a column from `values`. In [`ConversionMode::Strict`], columns whose
+/// values have different data types are rejected, as polars would cast them
+/// to a common supertype, e.g. large Ints to Floats.
+fn values_to_column(
+    attribute_name: &GraphRecordAttribute,
+    values: Vec<AnyValue<'_>>,
+    mode: ConversionMode,
+) -> GraphRecordResult<Column> {
+    if mode == ConversionMode::Strict {
+        let mut data_types = values
+            .iter()
+            .filter(|value| !value.is_null())
+            .map(AnyValue::dtype);
+
+        let first = data_types.next();
+        let other = first
+            .as_ref()
+            .and_then(|first| data_types.find(|data_type| data_type != first));
+
+        if let (Some(first), Some(other)) = (first, other) {
+            return Err(GraphRecordError::ConversionError(format!(
+                "Column {attribute_name} mixes values of data types {first} and {other}"
+            )));
+        }
+    }
+
+    Ok(Column::new(attribute_name.to_string().into(), values))
+}
+
+/// Returns the groups of a node or edge as a list value, ordered by
+/// [`GraphRecordAttribute::total_cmp`].
+fn group_membership_value<'a>(groups: impl Iterator<Item = &'a Group>) -> AnyValue<'static> {
+    let mut groups: Vec<_> = groups.collect();
+    groups.sort_unstable_by(|a, b| a.total_cmp(b));
+
+    AnyValue::List(Series::new(
+        PlSmallStr::EMPTY,
+        groups
+            .into_iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+    ))
+}
+
 pub struct DataFramesGroupExport {
     pub nodes: DataFrame,
     pub edges: DataFrame,
 }
 
 impl DataFramesGroupExport {
-    fn new(graphrecord: &GraphRecord, group: Option<&Group>) -> GraphRecordResult<Self> {
+    #[allow(clippy::too_many_lines)]
+    fn new(
+        graphrecord: &GraphRecord,
+        group: Option<&Group>,
+        options: &DataFramesExportOptions,
+    ) -> GraphRecordResult<Self> {
+        let sorted = options.sorted;
+        let skipped = group.is_none() && !options.ungrouped;
         let group_schema = match group {
             Some(group) => graphrecord.get_schema().group(group)?,
             None => graphrecord.get_schema().ungrouped(),
@@ -255,11 +498,19 @@ impl DataFramesGroupExport {
             None => "ungrouped".to_string(),
         };
 
-        let node_indices: Box<dyn Iterator<Item = &NodeIndex>> = match group {
+        let mut node_indices: Box<dyn Iterator<Item = &NodeIndex>> = match group {
             Some(group) => Box::new(graphrecord.nodes_in_group(group)?),
+            None if skipped => Box::new(std::iter::empty()),
             None => Box::new(graphrecord.ungrouped_nodes()),
         };
 
+        if sorted {
+            let mut sorted_node_indices: Vec<_> = node_indices.collect();
+            sorted_node_indices.sort_unstable_by(|a, b| a.total_cmp(b));
+
+            node_indices = Box::new(sorted_node_indices.into_iter());
+        }
+
         let group_node_attributes = node_indices.map(|node_index| {
             (
                 node_index,
@@ -269,7 +520,16 @@ impl DataFramesGroupExport {
             )
         });
 
-        let node_attributes: Vec<_> = group_schema.nodes().keys().collect();
+        let node_attributes: Vec<_> = group_schema
+            .nodes()
+            .keys()
+            .filter(|attribute| {
+                options
+                    .node_attributes
+                    .as_ref()
+                    .is_none_or(|selected| selected.contains(attribute))
+            })
+            .collect();
 
         let mut node_columns: GrHashMap<GraphRecordAttribute, Vec<AnyValue>> = node_attributes
             .iter()
@@ -284,13 +544,39 @@ impl DataFramesGroupExport {
             ));
         }
 
+        let groups_attribute = options
+            .group_membership
+            .then(|| GraphRecordAttribute::String("groups".into()));
+
+        if groups_attribute
+            .as_ref()
+            .is_some_and(|attribute| node_columns.contains_key(attribute))
+        {
+            return Err(GraphRecordError::ConversionError(
+                "Node attribute name 'groups' is reserved when exporting group membership".into(),
+            ));
+        }
+
         node_columns.insert(node_index_attribute.clone(), Vec::new());
+        if let Some(groups_attribute) = &groups_attribute {
+            node_columns.insert(groups_attribute.clone(), Vec::new());
+        }
 
         for (node_index, attributes) in group_node_attributes {
             node_columns
                 .get_mut(&node_index_attribute)
                 .expect("Attribute must exist in columns")
                 .push(node_index.clone().into());
+            if let Some(groups_attribute) = &groups_attribute {
+                node_columns
+                    .get_mut(groups_attribute)
+                    .expect("Attribute must exist in columns")
+                    .push(group_membership_value(
+                        graphrecord
+                            .groups_of_node(node_index)
+                            .expect("Node index must exist"),
+                    ));
+            }
 
             for attribute_name in &node_attributes {
                 let attribute_value = attributes
@@ -301,14 +587,23 @@ impl DataFramesGroupExport {
                 node_columns
                     .get_mut(*attribute_name)
                     .expect("Attribute must exist in columns")
-                    .push(attribute_value.into());
+                    .push(value_to_anyvalue(attribute_value, options.mode)?);
             }
         }
 
+        let mut node_columns: Vec<_> = node_columns.into_iter().collect();
+
+        if sorted {
+            let mut index_attributes = vec![&node_index_attribute];
+            index_attributes.extend(&groups_attribute);
+
+            sort_columns(&mut node_columns, &index_attributes);
+        }
+
         let node_columns: Vec<_> = node_columns
             .into_iter()
-            .map(|(attribute_name, values)| Column::new(attribute_name.to_string().into(), values))
-            .collect();
+            .map(|(attribute_name, values)| values_to_column(&attribute_name, values, options.mode))
+            .collect::<GraphRecordResult<_>>()?;
 
         let node_dataframe = DataFrame::new_infer_height(node_columns).map_err(|_| {
             GraphRecordError::ConversionError(format!(
@@ -316,11 +611,19 @@ impl DataFramesGroupExport {
             ))
         })?;
 
-        let edge_indices: Box<dyn Iterator<Item = &EdgeIndex>> = match group {
+        let mut edge_indices: Box<dyn Iterator<Item = &EdgeIndex>> = match group {
             Some(group) => Box::new(graphrecord.edges_in_group(group)?),
+            None if skipped => Box::new(std::iter::empty()),
             None => Box::new(graphrecord.ungrouped_edges()),
         };
 
+        if sorted {
+            let mut sorted_edge_indices: Vec<_> = edge_indices.collect();
+            sorted_edge_indices.sort_unstable();
+
+            edge_indices = Box::new(sorted_edge_indices.into_iter());
+        }
+
         let group_edge_attributes = edge_indices.map(|edge_index| {
             let edge_endpoints = graphrecord
                 .edge_endpoints(edge_index)
@@ -335,7 +638,16 @@ impl DataFramesGroupExport {
             )
         });
 
-        let edge_attributes: Vec<_> = group_schema.edges().keys().collect();
+        let edge_attributes: Vec<_> = group_schema
+            .edges()
+            .keys()
+            .filter(|attribute| {
+                options
+                    .edge_attributes
+                    .as_ref()
+                    .is_none_or(|selected| selected.contains(attribute))
+            })
+            .collect();
 
         let mut edge_columns: GrHashMap<GraphRecordAttribute, Vec<AnyValue>> = edge_attributes
             .iter()
@@ -345,6 +657,10 @@ impl DataFramesGroupExport {
         let edge_index_attribute = GraphRecordAttribute::String("edge_index".into());
         let source_node_index_attribute = GraphRecordAttribute::String("source_node_index".into());
         let target_node_index_attribute = GraphRecordAttribute::String("target_node_index".into());
+        let undirected_attribute = graphrecord
+            .graph
+            .has_undirected_edges()
+            .then(|| GraphRecordAttribute::String("undirected".into()));
 
         if edge_columns.contains_key(&edge_index_attribute) {
             return Err(GraphRecordError::ConversionError(
@@ -361,10 +677,33 @@ impl DataFramesGroupExport {
                 "Edge attribute name 'target_node_index' is reserved".into(),
             ));
         }
+        if undirected_attribute
+            .as_ref()
+            .is_some_and(|attribute| edge_columns.contains_key(attribute))
+        {
+            return Err(GraphRecordError::ConversionError(
+                "Edge attribute name 'undirected' is reserved in records with undirected edges"
+                    .into(),
+            ));
+        }
+        if groups_attribute
+            .as_ref()
+            .is_some_and(|attribute| edge_columns.contains_key(attribute))
+        {
+            return Err(GraphRecordError::ConversionError(
+                "Edge attribute name 'groups' is reserved when exporting group membership".into(),
+            ));
+        }
 
         edge_columns.insert(edge_index_attribute.clone(), Vec::new());
         edge_columns.insert(source_node_index_attribute.clone(), Vec::new());
         edge_columns.insert(target_node_index_attribute.clone(), Vec::new());
+        if let Some(undirected_attribute) = &undirected_attribute {
+            edge_columns.insert(undirected_attribute.clone(), Vec::new());
+        }
+        if let Some(groups_attribute) = &groups_attribute {
+            edge_columns.insert(groups_attribute.clone(), Vec::new());
+        }
 
         for (edge_index, edge_endpoints, attributes) in group_edge_attributes {
             edge_columns
@@ -379,6 +718,26 @@ impl DataFramesGroupExport {
                 .get_mut(&target_node_index_attribute)
                 .expect("Attribute must exist in columns")
                 .push(edge_endpoints.1.clone().into());
+            if let Some(undirected_attribute) = &undirected_attribute {
+                edge_columns
+                    .get_mut(undirected_attribute)
+                    .expect("Attribute must exist in columns")
+                    .push(AnyValue::Boolean(
+                        graphrecord
+                            .is_edge_undirected(edge_index)
+                            .expect("Edge index must exist"),
+                    ));
+            }
+            if let Some(groups_attribute) = &groups_attribute {
+                edge_columns
+                    .get_mut(groups_attribute)
+                    .expect("Attribute must exist in columns")
+                    .push(group_membership_value(
+                        graphrecord
+                            .groups_of_edge(edge_index)
+                            .expect("Edge index must exist"),
+                    ));
+            }
 
             for attribute_name in &edge_attributes {
                 let attribute_value = attributes
@@ -389,14 +748,28 @@ impl DataFramesGroupExport {
                 edge_columns
                     .get_mut(*attribute_name)
                     .expect("Attribute must exist in columns")
-                    .push(attribute_value.into());
+                    .push(value_to_anyvalue(attribute_value, options.mode)?);
             }
         }
 
+        let mut edge_columns: Vec<_> = edge_columns.into_iter().collect();
+
+        if sorted {
+            let mut index_attributes = vec![
+                &edge_index_attribute,
+                &source_node_index_attribute,
+                &target_node_index_attribute,
+            ];
+            index_attributes.extend(&undirected_attribute);
+            index_attributes.extend(&groups_attribute);
+
+            sort_columns(&mut edge_columns, &index_attributes);
+        }
+
         let edge_columns: Vec<_> = edge_columns
             .into_iter()
-            .map(|(attribute_name, values)| Column::new(attribute_name.to_string().into(), values))
-            .collect();
+            .map(|(attribute_name, values)| values_to_column(&attribute_name, values, options.mode))
+            .collect::<GraphRecordResult<_>>()?;
 
         let edge_dataframe = DataFrame::new_infer_height(edge_columns).map_err(|_| {
             GraphRecordError::ConversionError(format!(
@@ -418,14 +791,40 @@ pub struct DataFramesExport {
 
 impl DataFramesExport {
     pub fn new(graphrecord: &GraphRecord) -> GraphRecordResult<Self> {
-        let ungrouped = DataFramesGroupExport::new(graphrecord, None)?;
+        Self::with_options(graphrecord, &DataFramesExportOptions::default())
+    }
+
+    /// Exports rows sorted by their index and columns in a fixed order: the
+    /// index columns first, followed by the attributes in
+    /// [`GraphRecordAttribute::total_cmp`] order.
+    pub fn new_sorted(graphrecord: &GraphRecord) -> GraphRecordResult<Self> {
+        Self::with_options(
+            graphrecord,
+            &DataFramesExportOptions {
+                sorted: true,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Exports only the groups and attribute columns selected by `options`.
+    /// Selecting a group that does not exist is an error.
+    pub fn with_options(
+        graphrecord: &GraphRecord,
+        options: &DataFramesExportOptions,
+    ) -> GraphRecordResult<Self> {
+        let ungrouped = DataFramesGroupExport::new(graphrecord, None, options)?;
+
+        let groups: Box<dyn Iterator<Item = &Group>> = match &options.groups {
+            Some(groups) => Box::new(groups.iter()),
+            None => Box::new(graphrecord.groups()),
+        };
 
-        let groups = graphrecord
-            .groups()
+        let groups = groups
             .map(|group| {
                 Ok::<_, GraphRecordError>((
                     group.clone(),
-                    DataFramesGroupExport::new(graphrecord, Some(group))?,
+                    DataFramesGroupExport::new(graphrecord, Some(group), options)?,
                 ))
             })
             .collect::<Result<_, _>>()?;
@@ -436,9 +835,12 @@ impl DataFramesExport {
 
 #[cfg(test)]
 mod test {
-    use super::{GraphRecordValue, dataframe_to_edges, dataframe_to_nodes};
-    use crate::errors::GraphRecordError;
-    use chrono::NaiveDateTime;
+    use super::{
+        DataFramesExport, DataFramesExportOptions, GraphRecordValue, NullHandling, NullPolicy,
+        anyvalue_to_value, dataframe_to_edges, dataframe_to_nodes,
+    };
+    use crate::{GraphRecord, errors::GraphRecordError, graphrecord::schema::ConversionMode};
+    use chrono::{DateTime, NaiveDateTime, TimeDelta};
     use polars::prelude::*;
     use std::collections::HashMap;
 
@@ -565,7 +967,13 @@ mod test {
         let s1 = Series::new("attribute".into(), &[1, 2]);
         let nodes_dataframe = DataFrame::new(2, vec![s0.into(), s1.into()]).unwrap();
 
-        let nodes = dataframe_to_nodes(nodes_dataframe, "index").unwrap();
+        let nodes = dataframe_to_nodes(
+            nodes_dataframe,
+            "index",
+            ConversionMode::Lenient,
+            &NullHandling::default(),
+        )
+        .unwrap();
 
         assert_eq!(
             vec![
@@ -584,8 +992,54 @@ mod test {
 
         // Providing the wrong index column name should fail
         assert!(
-            dataframe_to_nodes(nodes_dataframe, "wrong_column")
-                .is_err_and(|e| matches!(e, GraphRecordError::ConversionError(_)))
+            dataframe_to_nodes(
+                nodes_dataframe,
+                "wrong_column",
+                ConversionMode::Lenient,
+                &NullHandling::default()
+            )
+            .is_err_and(|e| matches!(e, GraphRecordError::ConversionError(_)))
+        );
+    }
+
+    #[test]
+    fn test_dataframe_to_nodes_null_handling() {
+        let s0 = Series::new("index".into(), &["0", "1"]);
+        let s1 = Series::new("dropped".into(), &[Some(1), None]);
+        let s2 = Series::new("kept".into(), &[None, Some(2)]);
+        let nodes_dataframe = DataFrame::new(2, vec![s0.into(), s1.into(), s2.into()]).unwrap();
+
+        let nodes = dataframe_to_nodes(
+            nodes_dataframe.clone(),
+            "index",
+            ConversionMode::Lenient,
+            &NullHandling::from(NullPolicy::Drop).with_column("kept", NullPolicy::Keep),
+        )
+        .unwrap();
+
+        assert_eq!(
+            vec![
+                (
+                    "0".into(),
+                    HashMap::from([
+                        ("dropped".into(), 1.into()),
+                        ("kept".into(), GraphRecordValue::Null)
+                    ])
+                ),
+                ("1".into(), HashMap::from([("kept".into(), 2.into())]))
+            ],
+            nodes
+        );
+
+        // Null values in a column with the Fail policy should fail
+        assert!(
+            dataframe_to_nodes(
+                nodes_dataframe,
+                "index",
+                ConversionMode::Lenient,
+                &NullHandling::default().with_column("dropped", NullPolicy::Fail)
+            )
+            .is_err_and(|e| matches!(e, GraphRecordError::ConversionError(_)))
         );
     }
 
@@ -596,7 +1050,14 @@ mod test {
         let s2 = Series::new("attribute".into(), &[1, 2]);
         let edges_dataframe = DataFrame::new(2, vec![s0.into(), s1.into(), s2.into()]).unwrap();
 
-        let edges = dataframe_to_edges(edges_dataframe, "source", "target").unwrap();
+        let edges = dataframe_to_edges(
+            edges_dataframe,
+            "source",
+            "target",
+            ConversionMode::Lenient,
+            &NullHandling::default(),
+        )
+        .unwrap();
 
         assert_eq!(
             vec![
@@ -624,14 +1085,133 @@ mod test {
 
         // Providing the wrong source index column name should fail
         assert!(
-            dataframe_to_edges(edges_dataframe.clone(), "wrong_column", "target")
-                .is_err_and(|e| matches!(e, GraphRecordError::ConversionError(_)))
+            dataframe_to_edges(
+                edges_dataframe.clone(),
+                "wrong_column",
+                "target",
+                ConversionMode::Lenient,
+                &NullHandling::default()
+            )
+            .is_err_and(|e| matches!(e, GraphRecordError::ConversionError(_)))
         );
 
         // Providing the wrong target index column name should fail
         assert!(
-            dataframe_to_edges(edges_dataframe, "source", "wrong_column")
+            dataframe_to_edges(
+                edges_dataframe,
+                "source",
+                "wrong_column",
+                ConversionMode::Lenient,
+                &NullHandling::default()
+            )
+            .is_err_and(|e| matches!(e, GraphRecordError::ConversionError(_)))
+        );
+    }
+
+    #[test]
+    fn test_strict_anyvalue_to_value() {
+        let any_value = AnyValue::Datetime(0, TimeUnit::Milliseconds, Some(&TimeZone::UTC));
+
+        assert_eq!(
+            GraphRecordValue::DateTime(DateTime::UNIX_EPOCH.naive_utc()),
+            anyvalue_to_value(any_value.clone(), ConversionMode::Lenient).unwrap()
+        );
+
+        // Dropping the timezone should fail in strict mode
+        assert!(
+            anyvalue_to_value(any_value, ConversionMode::Strict)
                 .is_err_and(|e| matches!(e, GraphRecordError::ConversionError(_)))
         );
     }
+
+    #[test]
+    fn test_strict_export_round_trip() {
+        let datetime = DateTime::UNIX_EPOCH.naive_utc() + TimeDelta::nanoseconds(1);
+        let duration = TimeDelta::nanoseconds(1);
+
+        let graphrecord = GraphRecord::from_tuples(
+            vec![(
+                "0".into(),
+                HashMap::from([
+                    ("datetime".into(), datetime.into()),
+                    ("duration".into(), duration.into()),
+                ]),
+            )],
+            None,
+            None,
+        )
+        .unwrap();
+
+        let export = DataFramesExport::with_options(
+            &graphrecord,
+            &DataFramesExportOptions {
+                mode: ConversionMode::Strict,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let nodes = dataframe_to_nodes(
+            export.ungrouped.nodes,
+            "node_index",
+            ConversionMode::Strict,
+            &NullHandling::default(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            vec![(
+                "0".into(),
+                HashMap::from([
+                    ("datetime".into(), datetime.into()),
+                    ("duration".into(), duration.into()),
+                ])
+            )],
+            nodes
+        );
+
+        // The lenient export truncates to milliseconds
+        let export = DataFramesExport::new(&graphrecord).unwrap();
+
+        let nodes = dataframe_to_nodes(
+            export.ungrouped.nodes,
+            "node_index",
+            ConversionMode::Lenient,
+            &NullHandling::default(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            &GraphRecordValue::DateTime(DateTime::UNIX_EPOCH.naive_utc()),
+            nodes[0].1.get(&"datetime".into()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_invalid_strict_export() {
+        let graphrecord = GraphRecord::from_tuples(
+            vec![
+                ("0".into(), HashMap::from([("attribute".into(), 1.into())])),
+                (
+                    "1".into(),
+                    HashMap::from([("attribute".into(), "value".into())]),
+                ),
+            ],
+            None,
+            None,
+        )
+        .unwrap();
+
+        // Mixing data types in a column should fail in strict mode
+        assert!(
+            DataFramesExport::with_options(
+                &graphrecord,
+                &DataFramesExportOptions {
+                    mode: ConversionMode::Strict,
+                    ..Default::default()
+                },
+            )
+            .is_err_and(|e| matches!(e, GraphRecordError::ConversionError(_)))
+        );
+    }
 }
@@ -0,0 +1,322 @@
+use super::{EdgeIndex, GraphRecordAttribute, NodeIndex, ReadAttributes};
+use chrono::NaiveDateTime;
+use graphrecords_utils::aliases::GrHashMap;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, hash::Hash};
+
+/// Source of an attribute value, e.g. the feed and load batch it came from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ProvenanceTag {
+    pub source: String,
+    pub batch_id: Option<String>,
+    pub timestamp: NaiveDateTime,
+}
+
+impl ProvenanceTag {
+    #[must_use]
+    pub const fn new(source: String, batch_id: Option<String>, timestamp: NaiveDateTime) -> Self {
+        Self {
+            source,
+            batch_id,
+            timestamp,
+        }
+    }
+}
+
+type AttributeProvenance = GrHashMap<GraphRecordAttribute, ProvenanceTag>;
+
+/// Provenance tags of the attribute values of a record.
+///
+/// Values written while an active tag is set are tagged with it. Values
+/// written without an active tag lose their previous tag, so a tag always
+/// describes the current value.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub(super) struct ProvenanceStore {
+    #[cfg_attr(feature = "serde", serde(skip))]
+    active_tag: Option<ProvenanceTag>,
+    nodes: GrHashMap<NodeIndex, AttributeProvenance>,
+    edges: GrHashMap<EdgeIndex, AttributeProvenance>,
+}
+
+fn tag<'a, I: Eq + Hash + Clone>(
+    entries: &mut GrHashMap<I, AttributeProvenance>,
+    index: &I,
+    active_tag: Option<&ProvenanceTag>,
+    attributes: &impl ReadAttributes,
+    changed: impl IntoIterator<Item = &'a GraphRecordAttribute>,
+) {
+    let provenance = entries.entry(index.clone()).or_default();
+
+    provenance.retain(|attribute, _| attributes.contains_key(attribute));
+
+    for attribute in changed {
+        match active_tag {
+            Some(tag) => {
+                provenance.insert(attribute.clone(), tag.clone());
+            }
+            None => {
+                provenance.remove(attribute);
+            }
+        }
+    }
+
+    if provenance.is_empty() {
+        entries.remove(index);
+    }
+}
+
+impl ProvenanceStore {
+    pub(super) const fn active_tag(&self) -> Option<&ProvenanceTag> {
+        self.active_tag.as_ref()
+    }
+
+    pub(super) const fn set_active_tag(
+        &mut self,
+        tag: Option<ProvenanceTag>,
+    ) -> Option<ProvenanceTag> {
+        std::mem::replace(&mut self.active_tag, tag)
+    }
+
+    pub(super) fn node(
+        &self,
+        node_index: &NodeIndex,
+        attribute: &GraphRecordAttribute,
+    ) -> Option<&ProvenanceTag> {
+        self.nodes.get(node_index)?.get(attribute)
+    }
+
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub(super) fn edge(
+        &self,
+        edge_index: &EdgeIndex,
+        attribute: &GraphRecordAttribute,
+    ) -> Option<&ProvenanceTag> {
+        self.edges.get(edge_index)?.get(attribute)
+    }
+
+    /// Tags the `changed` attributes of a node with the active tag, and drops
+    /// tags of attributes that are no longer part of `attributes`.
+    pub(super) fn tag_node<'a>(
+        &mut self,
+        node_index: &NodeIndex,
+        attributes: &impl ReadAttributes,
+        changed: impl IntoIterator<Item = &'a GraphRecordAttribute>,
+    ) {
+        tag(
+            &mut self.nodes,
+            node_index,
+            self.active_tag.as_ref(),
+            attributes,
+            changed,
+        );
+    }
+
+    /// Like [`ProvenanceStore::tag_node`], but for an edge.
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub(super) fn tag_edge<'a>(
+        &mut self,
+        edge_index: &EdgeIndex,
+        attributes: &impl ReadAttributes,
+        changed: impl IntoIterator<Item = &'a GraphRecordAttribute>,
+    ) {
+        tag(
+            &mut self.edges,
+            edge_index,
+            self.active_tag.as_ref(),
+            attributes,
+            changed,
+        );
+    }
+
+    pub(super) fn remove_node(&mut self, node_index: &NodeIndex) {
+        self.nodes.remove(node_index);
+    }
+
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub(super) fn remove_edge(&mut self, edge_index: &EdgeIndex) {
+        self.edges.remove(edge_index);
+    }
+
+    pub(super) fn reindex_edges(&mut self, remap: &HashMap<EdgeIndex, EdgeIndex>) {
+        self.edges = self
+            .edges
+            .drain()
+            .filter_map(|(edge_index, provenance)| {
+                remap
+                    .get(&edge_index)
+                    .map(|edge_index| (*edge_index, provenance))
+            })
+            .collect();
+    }
+
+    pub(super) fn clear(&mut self) {
+        self.nodes.clear();
+        self.edges.clear();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ProvenanceTag;
+    use crate::{GraphRecord, errors::GraphRecordError, graphrecord::GraphRecordValue};
+    use chrono::NaiveDateTime;
+    use std::collections::HashMap;
+
+    fn create_tag(source: &str) -> ProvenanceTag {
+        ProvenanceTag::new(source.to_string(), None, NaiveDateTime::MIN)
+    }
+
+    #[test]
+    fn test_provenance() {
+        let mut graphrecord = GraphRecord::new();
+
+        assert!(
+            graphrecord
+                .set_provenance_tag(Some(create_tag("lab")))
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
+
+        graphrecord.enable_provenance().unwrap();
+        graphrecord
+            .set_provenance_tag(Some(create_tag("lab")))
+            .unwrap();
+
+        graphrecord
+            .add_node(
+                "0".into(),
+                HashMap::from([("lorem".into(), "ipsum".into())]),
+            )
+            .unwrap();
+        graphrecord.add_node("1".into(), HashMap::new()).unwrap();
+        graphrecord
+            .add_edge(
+                "0".into(),
+                "1".into(),
+                HashMap::from([("dolor".into(), "sit".into())]),
+            )
+            .unwrap();
+
+        assert_eq!(
+            Some(&create_tag("lab")),
+            graphrecord
+                .node_attribute_provenance(&"0".into(), &"lorem".into())
+                .unwrap()
+        );
+        assert_eq!(
+            Some(&create_tag("lab")),
+            graphrecord
+                .edge_attribute_provenance(&0, &"dolor".into())
+                .unwrap()
+        );
+
+        graphrecord
+            .set_provenance_tag(Some(create_tag("claims")))
+            .unwrap();
+        graphrecord
+            .node_attributes_mut(&"1".into())
+            .unwrap()
+            .update_attribute(&"lorem".into(), "amet".into())
+            .unwrap();
+
+        // Only the updated value is retagged
+        assert_eq!(
+            Some(&create_tag("claims")),
+            graphrecord
+                .node_attribute_provenance(&"1".into(), &"lorem".into())
+                .unwrap()
+        );
+        assert_eq!(
+            Some(&create_tag("lab")),
+            graphrecord
+                .node_attribute_provenance(&"0".into(), &"lorem".into())
+                .unwrap()
+        );
+
+        let mut sources: Vec<_> = graphrecord
+            .query_nodes(|nodes| {
+                let values = nodes.attribute("lorem");
+                values.provenance();
+
+                values
+            })
+            .evaluate()
+            .unwrap()
+            .map(|(_, value)| value)
+            .collect();
+        sources.sort_by(GraphRecordValue::total_cmp);
+
+        assert_eq!(
+            vec![
+                GraphRecordValue::from("claims"),
+                GraphRecordValue::from("lab")
+            ],
+            sources
+        );
+
+        // Values written without an active tag lose their tag
+        graphrecord.set_provenance_tag(None).unwrap();
+        graphrecord
+            .node_attributes_mut(&"0".into())
+            .unwrap()
+            .update_attribute(&"lorem".into(), "dolor".into())
+            .unwrap();
+
+        assert_eq!(
+            None,
+            graphrecord
+                .node_attribute_provenance(&"0".into(), &"lorem".into())
+                .unwrap()
+        );
+
+        graphrecord.remove_edge(&0).unwrap();
+        graphrecord.compact().unwrap();
+        graphrecord
+            .set_provenance_tag(Some(create_tag("lab")))
+            .unwrap();
+        let edge_index = graphrecord
+            .add_edge("0".into(), "1".into(), HashMap::new())
+            .unwrap();
+
+        assert_eq!(
+            None,
+            graphrecord
+                .edge_attribute_provenance(&edge_index, &"dolor".into())
+                .unwrap()
+        );
+        assert!(
+            graphrecord
+                .node_attribute_provenance(&"50".into(), &"lorem".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::MissingNode { .. }))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_provenance_serde() {
+        let mut graphrecord = GraphRecord::new();
+
+        graphrecord.enable_provenance().unwrap();
+        graphrecord
+            .set_provenance_tag(Some(create_tag("lab")))
+            .unwrap();
+        graphrecord
+            .add_node(
+                "0".into(),
+                HashMap::from([("lorem".into(), "ipsum".into())]),
+            )
+            .unwrap();
+
+        let restored = GraphRecord::from_bytes(&graphrecord.to_bytes().unwrap()).unwrap();
+
+        assert!(restored.provenance_enabled());
+        assert_eq!(
+            Some(&create_tag("lab")),
+            restored
+                .node_attribute_provenance(&"0".into(), &"lorem".into())
+                .unwrap()
+        );
+    }
+}
@@ -100,9 +100,10 @@ impl<'a, O: 'a + RootOperand> EvaluateBackward<'a>
                     MultipleKind::Sum => {
                         Box::new(AttributesTreeOperation::<O>::get_sum(partition)?)
                     }
-                    MultipleKind::Random => {
-                        Box::new(AttributesTreeOperation::<O>::get_random(partition)?)
-                    }
+                    MultipleKind::Random => Box::new(AttributesTreeOperation::<O>::get_random(
+                        graphrecord,
+                        partition,
+                    )?),
                 };
 
                 Ok((key, reduced_partition))
@@ -158,7 +159,10 @@ impl<'a, O: 'a + RootOperand> EvaluateBackward<'a>
                         MultipleAttributesWithIndexOperation::<O>::get_min(partition)?
                     }
                     SingleKindWithIndex::Random => {
-                        MultipleAttributesWithIndexOperation::<O>::get_random(partition)
+                        MultipleAttributesWithIndexOperation::<O>::get_random(
+                            graphrecord,
+                            partition,
+                        )
                     }
                 };
 
@@ -223,7 +227,10 @@ impl<'a, O: 'a + RootOperand> EvaluateBackward<'a>
                         MultipleAttributesWithoutIndexOperation::<O>::get_sum(partition)?
                     }
                     SingleKindWithoutIndex::Random => {
-                        MultipleAttributesWithoutIndexOperation::<O>::get_random(partition)
+                        MultipleAttributesWithoutIndexOperation::<O>::get_random(
+                            graphrecord,
+                            partition,
+                        )
                     }
                 };
 
@@ -11,7 +11,7 @@ use crate::{
     GraphRecord,
     errors::GraphRecordResult,
     graphrecord::{
-        Attributes, EdgeIndex, GraphRecordAttribute, NodeIndex,
+        AttributeMap, EdgeIndex, GraphRecordAttribute, NodeIndex,
         querying::{
             BoxedIterator, DeepClone, RootOperand, attributes::operation::AttributesTreeOperation,
             group_by::GroupOperand,
@@ -33,7 +33,7 @@ pub use operation::{
 };
 use std::fmt::Display;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum AttributesTreeContext<O: RootOperand> {
     Operand(O),
     GroupByOperand(GroupOperand<AttributesTreeOperand<O>>),
@@ -58,7 +58,7 @@ impl<O: RootOperand> AttributesTreeContext<O> {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum MultipleAttributesWithIndexContext<O: RootOperand> {
     AttributesTree {
         operand: AttributesTreeOperand<O>,
@@ -110,9 +110,10 @@ impl<O: RootOperand> MultipleAttributesWithIndexContext<O> {
                     MultipleKind::Sum => {
                         Box::new(AttributesTreeOperation::<O>::get_sum(attributes)?)
                     }
-                    MultipleKind::Random => {
-                        Box::new(AttributesTreeOperation::<O>::get_random(attributes)?)
-                    }
+                    MultipleKind::Random => Box::new(AttributesTreeOperation::<O>::get_random(
+                        graphrecord,
+                        attributes,
+                    )?),
                 }
             }
             Self::SingleAttributeWithIndexGroupByOperand(operand) => Box::new(
@@ -131,7 +132,7 @@ impl<O: RootOperand> MultipleAttributesWithIndexContext<O> {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum MultipleAttributesWithoutIndexContext<O: RootOperand> {
     GroupByOperand(GroupOperand<SingleAttributeWithoutIndexOperand<O>>),
 }
@@ -162,7 +163,7 @@ impl<O: RootOperand> MultipleAttributesWithoutIndexContext<O> {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SingleAttributeWithoutIndexContext<O: RootOperand> {
     MultipleAttributesWithIndexOperand(MultipleAttributesWithIndexOperand<O>),
     MultipleAttributesWithoutIndexOperand(MultipleAttributesWithoutIndexOperand<O>),
@@ -202,14 +203,14 @@ impl<O: RootOperand> SingleAttributeWithoutIndexContext<O> {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SingleKindWithIndex {
     Max,
     Min,
     Random,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SingleKindWithoutIndex {
     Max,
     Min,
@@ -218,7 +219,7 @@ pub enum SingleKindWithoutIndex {
     Random,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum MultipleKind {
     Max,
     Min,
@@ -227,7 +228,7 @@ pub enum MultipleKind {
     Random,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SingleComparisonKind {
     GreaterThan,
     GreaterThanOrEqualTo,
@@ -240,13 +241,13 @@ pub enum SingleComparisonKind {
     Contains,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum MultipleComparisonKind {
     IsIn,
     IsNotIn,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum BinaryArithmeticKind {
     Add,
     Sub,
@@ -267,7 +268,7 @@ impl Display for BinaryArithmeticKind {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum UnaryArithmeticKind {
     Abs,
     Trim,
@@ -281,14 +282,14 @@ pub trait GetAttributes {
     fn get_attributes<'a>(
         &'a self,
         graphrecord: &'a GraphRecord,
-    ) -> GraphRecordResult<&'a Attributes>;
+    ) -> GraphRecordResult<&'a AttributeMap>;
 }
 
 impl GetAttributes for NodeIndex {
     fn get_attributes<'a>(
         &'a self,
         graphrecord: &'a GraphRecord,
-    ) -> GraphRecordResult<&'a Attributes> {
+    ) -> GraphRecordResult<&'a AttributeMap> {
         graphrecord.node_attributes(self)
     }
 }
@@ -297,7 +298,7 @@ impl GetAttributes for EdgeIndex {
     fn get_attributes<'a>(
         &'a self,
         graphrecord: &'a GraphRecord,
-    ) -> GraphRecordResult<&'a Attributes> {
+    ) -> GraphRecordResult<&'a AttributeMap> {
         graphrecord.edge_attributes(self)
     }
 }
@@ -306,7 +307,7 @@ impl<T: GetAttributes> GetAttributes for &T {
     fn get_attributes<'b>(
         &'b self,
         graphrecord: &'b GraphRecord,
-    ) -> GraphRecordResult<&'b Attributes> {
+    ) -> GraphRecordResult<&'b AttributeMap> {
         (*self).get_attributes(graphrecord)
     }
 }
@@ -22,6 +22,7 @@ use crate::{
                     MultipleAttributesWithoutIndexOperation, SingleAttributeWithoutIndexOperation,
                 },
             },
+            hash_unordered,
             operand_traits::{
                 Abs, Add, Contains, Count, EitherOr, EndsWith, EqualTo, Exclude, GreaterThan,
                 GreaterThanOrEqualTo, IsIn, IsInt, IsMax, IsMin, IsNotIn, IsString, LessThan,
@@ -33,9 +34,13 @@ use crate::{
     },
 };
 use graphrecords_utils::aliases::GrHashSet;
-use std::collections::HashSet;
+use std::{
+    collections::HashSet,
+    hash::{Hash, Hasher},
+    mem,
+};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SingleAttributeComparisonOperand {
     NodeSingleAttributeWithIndexOperand(NodeSingleAttributeWithIndexOperand),
     NodeSingleAttributeWithoutIndexOperand(NodeSingleAttributeWithoutIndexOperand),
@@ -141,7 +146,7 @@ impl SingleAttributeComparisonOperand {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum MultipleAttributesComparisonOperand {
     NodeMultipleAttributesWithIndexOperand(NodeMultipleAttributesWithIndexOperand),
     NodeMultipleAttributesWithoutIndexOperand(NodeMultipleAttributesWithoutIndexOperand),
@@ -150,6 +155,20 @@ pub enum MultipleAttributesComparisonOperand {
     Attributes(GrHashSet<GraphRecordAttribute>),
 }
 
+impl Hash for MultipleAttributesComparisonOperand {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+
+        match self {
+            Self::NodeMultipleAttributesWithIndexOperand(operand) => operand.hash(state),
+            Self::NodeMultipleAttributesWithoutIndexOperand(operand) => operand.hash(state),
+            Self::EdgeMultipleAttributesWithIndexOperand(operand) => operand.hash(state),
+            Self::EdgeMultipleAttributesWithoutIndexOperand(operand) => operand.hash(state),
+            Self::Attributes(attributes) => hash_unordered(attributes.iter(), state),
+        }
+    }
+}
+
 impl DeepClone for MultipleAttributesComparisonOperand {
     fn deep_clone(&self) -> Self {
         match self {
@@ -283,7 +302,7 @@ impl MultipleAttributesComparisonOperand {
 pub type NodeAttributesTreeOperand = AttributesTreeOperand<NodeOperand>;
 pub type EdgeAttributesTreeOperand = AttributesTreeOperand<EdgeOperand>;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AttributesTreeOperand<O: RootOperand> {
     context: AttributesTreeContext<O>,
     operations: Vec<AttributesTreeOperation<O>>,
@@ -796,7 +815,7 @@ impl<O: RootOperand> Wrapper<AttributesTreeOperand<O>> {
 pub type NodeMultipleAttributesWithIndexOperand = MultipleAttributesWithIndexOperand<NodeOperand>;
 pub type EdgeMultipleAttributesWithIndexOperand = MultipleAttributesWithIndexOperand<EdgeOperand>;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct MultipleAttributesWithIndexOperand<O: RootOperand> {
     pub(crate) context: MultipleAttributesWithIndexContext<O>,
     operations: Vec<MultipleAttributesWithIndexOperation<O>>,
@@ -1337,7 +1356,7 @@ pub type NodeMultipleAttributesWithoutIndexOperand =
 pub type EdgeMultipleAttributesWithoutIndexOperand =
     MultipleAttributesWithoutIndexOperand<EdgeOperand>;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct MultipleAttributesWithoutIndexOperand<O: RootOperand> {
     context: MultipleAttributesWithoutIndexContext<O>,
     operations: Vec<MultipleAttributesWithoutIndexOperation<O>>,
@@ -1848,7 +1867,7 @@ impl<O: RootOperand> Wrapper<MultipleAttributesWithoutIndexOperand<O>> {
 pub type NodeSingleAttributeWithIndexOperand = SingleAttributeWithIndexOperand<NodeOperand>;
 pub type EdgeSingleAttributeWithIndexOperand = SingleAttributeWithIndexOperand<EdgeOperand>;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SingleAttributeWithIndexOperand<O: RootOperand> {
     context: MultipleAttributesWithIndexOperand<O>,
     pub(crate) kind: SingleKindWithIndex,
@@ -1905,7 +1924,7 @@ impl<'a, O: 'a + RootOperand> EvaluateBackward<'a> for SingleAttributeWithIndexO
     ) -> GraphRecordResult<Self::ReturnValue> {
         let attributes = self.context.evaluate_backward(graphrecord)?;
 
-        let attribute = self.reduce_input(attributes)?;
+        let attribute = self.reduce_input(graphrecord, attributes)?;
 
         self.evaluate_forward(graphrecord, attribute)
     }
@@ -1917,6 +1936,7 @@ impl<'a, O: 'a + RootOperand> ReduceInput<'a> for SingleAttributeWithIndexOperan
     #[inline]
     fn reduce_input(
         &self,
+        graphrecord: &'a GraphRecord,
         attributes: <Self::Context as EvaluateBackward<'a>>::ReturnValue,
     ) -> GraphRecordResult<<Self as EvaluateForward<'a>>::InputValue> {
         Ok(match self.kind {
@@ -1927,7 +1947,7 @@ impl<'a, O: 'a + RootOperand> ReduceInput<'a> for SingleAttributeWithIndexOperan
                 MultipleAttributesWithIndexOperation::<O>::get_min(attributes)?
             }
             SingleKindWithIndex::Random => {
-                MultipleAttributesWithIndexOperation::<O>::get_random(attributes)
+                MultipleAttributesWithIndexOperation::<O>::get_random(graphrecord, attributes)
             }
         })
     }
@@ -2303,7 +2323,7 @@ impl<O: RootOperand> Wrapper<SingleAttributeWithIndexOperand<O>> {
 pub type NodeSingleAttributeWithoutIndexOperand = SingleAttributeWithoutIndexOperand<NodeOperand>;
 pub type EdgeSingleAttributeWithoutIndexOperand = SingleAttributeWithoutIndexOperand<EdgeOperand>;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SingleAttributeWithoutIndexOperand<O: RootOperand> {
     context: SingleAttributeWithoutIndexContext<O>,
     pub(crate) kind: SingleKindWithoutIndex,
@@ -2374,7 +2394,7 @@ impl<'a, O: 'a + RootOperand> EvaluateBackward<'a> for SingleAttributeWithoutInd
                 MultipleAttributesWithoutIndexOperation::<O>::get_sum(attributes)?
             }
             SingleKindWithoutIndex::Random => {
-                MultipleAttributesWithoutIndexOperation::<O>::get_random(attributes)
+                MultipleAttributesWithoutIndexOperation::<O>::get_random(graphrecord, attributes)
             }
         };
 
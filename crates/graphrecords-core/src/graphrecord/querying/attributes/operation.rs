@@ -17,7 +17,7 @@ use crate::{
         },
         querying::{
             BoxedIterator, DeepClone, EvaluateForward, EvaluateForwardGrouped, GroupedIterator,
-            RootOperand,
+            Index, QueryRng, RootOperand,
             attributes::{
                 MultipleAttributesWithIndexContext, MultipleAttributesWithoutIndexOperand,
                 MultipleKind, SingleKindWithoutIndex, operand::SingleAttributeWithoutIndexOperand,
@@ -29,14 +29,13 @@ use crate::{
 };
 use graphrecords_utils::aliases::{GrHashMap, GrHashSet};
 use itertools::Itertools;
-use rand::{rng, seq::IteratorRandom};
 use std::{
     cmp::Ordering,
     collections::HashMap,
     ops::{Add, Mul, Range, Sub},
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum AttributesTreeOperation<O: RootOperand> {
     AttributesOperation {
         operand: Wrapper<MultipleAttributesWithIndexOperand<O>>,
@@ -280,15 +279,26 @@ impl<O: RootOperand> AttributesTreeOperation<O> {
 
     #[inline]
     pub(crate) fn get_random<'a>(
+        graphrecord: &GraphRecord,
         attributes: impl Iterator<Item = (&'a O::Index, Vec<GraphRecordAttribute>)>,
     ) -> GraphRecordResult<impl Iterator<Item = (&'a O::Index, GraphRecordAttribute)>>
     where
         O: 'a,
     {
+        let mut rng = QueryRng::new(graphrecord);
+
+        let mut attributes: Vec<_> = attributes.collect();
+
+        if rng.is_seeded() {
+            attributes.sort_by(|(index, _), (other, _)| index.total_cmp(other));
+        }
+
         Ok(attributes
+            .into_iter()
             .map(|(index, attributes)| {
-                let first_attribute =
-                    attributes.into_iter().choose(&mut rng()).ok_or_else(|| {
+                let first_attribute = rng
+                    .choose(attributes.into_iter(), GraphRecordAttribute::total_cmp)
+                    .ok_or_else(|| {
                         GraphRecordError::QueryError("No attributes to compare".to_string())
                     })?;
 
@@ -325,7 +335,7 @@ impl<O: RootOperand> AttributesTreeOperation<O> {
             MultipleKind::Min => Box::new(Self::get_min(attributes_1)?),
             MultipleKind::Count => Box::new(Self::get_count(attributes_1)),
             MultipleKind::Sum => Box::new(Self::get_sum(attributes_1)?),
-            MultipleKind::Random => Box::new(Self::get_random(attributes_1)?),
+            MultipleKind::Random => Box::new(Self::get_random(graphrecord, attributes_1)?),
         };
 
         let result = operand.evaluate_forward(graphrecord, multiple_operand_attributes)?;
@@ -923,7 +933,7 @@ impl<O: RootOperand> AttributesTreeOperation<O> {
                     MultipleKind::Min => Box::new(Self::get_min(attributes)?),
                     MultipleKind::Count => Box::new(Self::get_count(attributes)),
                     MultipleKind::Sum => Box::new(Self::get_sum(attributes)?),
-                    MultipleKind::Random => Box::new(Self::get_random(attributes)?),
+                    MultipleKind::Random => Box::new(Self::get_random(graphrecord, attributes)?),
                 };
 
                 Ok((key, attributes))
@@ -1055,7 +1065,7 @@ impl<O: RootOperand> AttributesTreeOperation<O> {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum MultipleAttributesWithIndexOperation<O: RootOperand> {
     AttributeWithIndexOperation {
         operand: Wrapper<SingleAttributeWithIndexOperand<O>>,
@@ -1268,9 +1278,11 @@ impl<O: RootOperand> MultipleAttributesWithIndexOperation<O> {
 
     #[inline]
     pub(crate) fn get_random<'a>(
+        graphrecord: &GraphRecord,
         attributes: impl Iterator<Item = (&'a O::Index, GraphRecordAttribute)>,
     ) -> Option<(&'a O::Index, GraphRecordAttribute)> {
-        attributes.choose(&mut rng())
+        QueryRng::new(graphrecord)
+            .choose(attributes, |(index, _), (other, _)| index.total_cmp(other))
     }
 
     #[inline]
@@ -1289,7 +1301,7 @@ impl<O: RootOperand> MultipleAttributesWithIndexOperation<O> {
         let attribute = match kind {
             SingleKindWithIndex::Max => Self::get_max(attributes_1)?,
             SingleKindWithIndex::Min => Self::get_min(attributes_1)?,
-            SingleKindWithIndex::Random => Self::get_random(attributes_1),
+            SingleKindWithIndex::Random => Self::get_random(graphrecord, attributes_1),
         };
 
         Ok(match operand.evaluate_forward(graphrecord, attribute)? {
@@ -1326,7 +1338,7 @@ impl<O: RootOperand> MultipleAttributesWithIndexOperation<O> {
                 MultipleAttributesWithoutIndexOperation::<O>::get_sum(attributes_1)?
             }
             SingleKindWithoutIndex::Random => {
-                MultipleAttributesWithoutIndexOperation::<O>::get_random(attributes_1)
+                MultipleAttributesWithoutIndexOperation::<O>::get_random(graphrecord, attributes_1)
             }
         };
 
@@ -1837,7 +1849,7 @@ impl<O: RootOperand> MultipleAttributesWithIndexOperation<O> {
                 let attribute = match kind {
                     SingleKindWithIndex::Max => Self::get_max(attributes)?,
                     SingleKindWithIndex::Min => Self::get_min(attributes)?,
-                    SingleKindWithIndex::Random => Self::get_random(attributes),
+                    SingleKindWithIndex::Random => Self::get_random(graphrecord, attributes),
                 };
 
                 Ok((key, attribute))
@@ -1897,7 +1909,10 @@ impl<O: RootOperand> MultipleAttributesWithIndexOperation<O> {
                         MultipleAttributesWithoutIndexOperation::<O>::get_sum(attributes)?
                     }
                     SingleKindWithoutIndex::Random => {
-                        MultipleAttributesWithoutIndexOperation::<O>::get_random(attributes)
+                        MultipleAttributesWithoutIndexOperation::<O>::get_random(
+                            graphrecord,
+                            attributes,
+                        )
                     }
                 };
 
@@ -2048,7 +2063,7 @@ impl<O: RootOperand> MultipleAttributesWithIndexOperation<O> {
         Ok(Box::new(attributes))
     }
 }
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum MultipleAttributesWithoutIndexOperation<O: RootOperand> {
     AttributeOperation {
         operand: Wrapper<SingleAttributeWithoutIndexOperand<O>>,
@@ -2288,9 +2303,10 @@ impl<O: RootOperand> MultipleAttributesWithoutIndexOperation<O> {
 
     #[inline]
     pub(crate) fn get_random(
+        graphrecord: &GraphRecord,
         attributes: impl Iterator<Item = GraphRecordAttribute>,
     ) -> Option<GraphRecordAttribute> {
-        attributes.choose(&mut rng())
+        QueryRng::new(graphrecord).choose(attributes, GraphRecordAttribute::total_cmp)
     }
 
     #[inline]
@@ -2308,7 +2324,7 @@ impl<O: RootOperand> MultipleAttributesWithoutIndexOperation<O> {
             SingleKindWithoutIndex::Min => Self::get_min(attributes_1)?,
             SingleKindWithoutIndex::Count => Some(Self::get_count(attributes_1)),
             SingleKindWithoutIndex::Sum => Self::get_sum(attributes_1)?,
-            SingleKindWithoutIndex::Random => Self::get_random(attributes_1),
+            SingleKindWithoutIndex::Random => Self::get_random(graphrecord, attributes_1),
         };
 
         Ok(match operand.evaluate_forward(graphrecord, attribute)? {
@@ -2509,7 +2525,7 @@ impl<O: RootOperand> MultipleAttributesWithoutIndexOperation<O> {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SingleAttributeWithIndexOperation<O: RootOperand> {
     SingleAttributeComparisonOperation {
         operand: SingleAttributeComparisonOperand,
@@ -2980,7 +2996,7 @@ impl<O: RootOperand> SingleAttributeWithIndexOperation<O> {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SingleAttributeWithoutIndexOperation<O: RootOperand> {
     SingleAttributeComparisonOperation {
         operand: SingleAttributeComparisonOperand,
@@ -5,7 +5,7 @@ use crate::{
     graphrecord::querying::{
         BoxedIterator, DeepClone, EvaluateBackward, EvaluateForward, EvaluateForwardGrouped,
         GroupedIterator,
-        edges::{EdgeIndexOperand, EdgeIndicesOperand, EdgeIndicesOperandContext},
+        edges::{self, EdgeIndexOperand, EdgeIndicesOperand, EdgeIndicesOperandContext},
         group_by::{GroupBy, GroupOperand, GroupedOperand, PartitionGroups, Ungroup},
         nodes::NodeOperand,
         wrapper::Wrapper,
@@ -13,10 +13,14 @@ use crate::{
     prelude::GraphRecordAttribute,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum EdgeOperandContext {
     Discriminator(<EdgeOperand as GroupBy>::Discriminator),
     Edges(GroupOperand<NodeOperand>),
+    Subgroups {
+        operand: Box<GroupOperand<EdgeOperand>>,
+        discriminator: EdgeOperandGroupDiscriminator,
+    },
 }
 
 impl DeepClone for EdgeOperandContext {
@@ -24,6 +28,13 @@ impl DeepClone for EdgeOperandContext {
         match self {
             Self::Discriminator(discriminator) => Self::Discriminator(discriminator.clone()),
             Self::Edges(operand) => Self::Edges(operand.deep_clone()),
+            Self::Subgroups {
+                operand,
+                discriminator,
+            } => Self::Subgroups {
+                operand: operand.deep_clone(),
+                discriminator: discriminator.deep_clone(),
+            },
         }
     }
 }
@@ -44,12 +55,37 @@ impl GroupedOperand for EdgeOperand {
     type Context = EdgeOperandContext;
 }
 
-#[derive(Debug, Clone)]
+impl Wrapper<GroupOperand<EdgeOperand>> {
+    /// Partitions every group further by `discriminator`. The subgroups are
+    /// keyed by a tuple key of the outer and the inner key.
+    #[must_use]
+    pub fn group_by(&self, discriminator: EdgeOperandGroupDiscriminator) -> Self {
+        let group_operand = self.0.read().deep_clone();
+
+        let operand = Wrapper::<EdgeOperand>::new(Some(edges::EdgeOperandContext::GroupBy {
+            operand: Box::new(group_operand.operand.0.read().deep_clone()),
+        }));
+
+        Self::new(
+            EdgeOperandContext::Subgroups {
+                operand: Box::new(group_operand),
+                discriminator,
+            },
+            operand,
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum EdgeOperandGroupDiscriminator {
     SourceNode,
     TargetNode,
     Parallel,
     Attribute(GraphRecordAttribute),
+    /// Partitions edges by the pair of groups of their source and target
+    /// node. An edge whose endpoints are in several groups is part of every
+    /// pair, and an endpoint in no group contributes `None`.
+    EndpointGroups,
 }
 
 impl DeepClone for EdgeOperandGroupDiscriminator {
@@ -59,6 +95,7 @@ impl DeepClone for EdgeOperandGroupDiscriminator {
             Self::TargetNode => Self::TargetNode,
             Self::Parallel => Self::Parallel,
             Self::Attribute(attr) => Self::Attribute(attr.clone()),
+            Self::EndpointGroups => Self::EndpointGroups,
         }
     }
 }
@@ -79,7 +116,9 @@ impl<'a> EvaluateForward<'a> for GroupOperand<EdgeOperand> {
                 self.operand
                     .evaluate_forward_grouped(graphrecord, Box::new(partitions))
             }
-            EdgeOperandContext::Edges(_) => unreachable!(),
+            EdgeOperandContext::Edges(_) | EdgeOperandContext::Subgroups { .. } => {
+                unreachable!()
+            }
         }
     }
 }
@@ -140,7 +179,7 @@ impl<'a> EvaluateBackward<'a> for GroupOperand<EdgeIndexOperand> {
 
         let indices: Vec<_> = partitions
             .map(|(key, partition)| {
-                let reduced_partition = self.operand.reduce_input(partition)?;
+                let reduced_partition = self.operand.reduce_input(graphrecord, partition)?;
 
                 Ok((key, reduced_partition))
             })
@@ -21,7 +21,7 @@ pub use operand::{
 pub use operation::EdgeOperation;
 use std::fmt::Display;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum EdgeOperandContext {
     Edges {
         operand: Box<NodeOperand>,
@@ -46,7 +46,7 @@ impl DeepClone for EdgeOperandContext {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum EdgeIndicesOperandContext {
     EdgeOperand(EdgeOperand),
     EdgeIndexGroupByOperand(GroupOperand<EdgeIndexOperand>),
@@ -92,7 +92,7 @@ impl<'a> EvaluateBackward<'a> for EdgeIndicesOperandContext {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SingleKind {
     Max,
     Min,
@@ -101,7 +101,7 @@ pub enum SingleKind {
     Random,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SingleComparisonKind {
     GreaterThan,
     GreaterThanOrEqualTo,
@@ -114,13 +114,13 @@ pub enum SingleComparisonKind {
     Contains,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum MultipleComparisonKind {
     IsIn,
     IsNotIn,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum BinaryArithmeticKind {
     Add,
     Sub,
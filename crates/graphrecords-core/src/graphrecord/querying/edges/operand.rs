@@ -7,19 +7,23 @@ use crate::{
     GraphRecord,
     errors::GraphRecordResult,
     graphrecord::{
-        EdgeIndex, GraphRecordAttribute, Group,
+        EdgeIndex, GraphRecordAttribute, Group, NodeIndex,
+        datatypes::GeoPoint,
+        provenance::ProvenanceTag,
         querying::{
             BoxedIterator, DeepClone, EvaluateBackward, EvaluateForward, EvaluateForwardGrouped,
-            GroupedIterator, ReduceInput, RootOperand,
+            GroupedIterator, ReduceInput, RootOperand, SubqueryCache,
             attributes::{AttributesTreeContext, AttributesTreeOperand},
             edges::{EdgeIndicesOperandContext, EdgeOperandGroupDiscriminator, group_by},
-            group_by::{GroupKey, GroupOperand, PartitionGroups},
+            group_by::{GroupKey, GroupOperand, PartitionGroups, partition_subgroups},
+            hash_unordered,
             nodes::{self, NodeOperand},
             operand_traits::{
-                Add, Attribute, Attributes, Contains, Count, EitherOr, EndsWith, EqualTo, Exclude,
-                GreaterThan, GreaterThanOrEqualTo, HasAttribute, InGroup, Index, IsIn, IsMax,
-                IsMin, IsNotIn, LessThan, LessThanOrEqualTo, Max, Min, Mod, Mul, NotEqualTo, Pow,
-                Random, SourceNode, StartsWith, Sub, Sum, TargetNode,
+                Add, AllOf, Attribute, Attributes, Contains, Count, EitherOr, EndsWith, EqualTo,
+                Exclude, GreaterThan, GreaterThanOrEqualTo, HasAttribute, InGroup, Index,
+                InducedBy, IsIn, IsMax, IsMin, IsNotIn, LessThan, LessThanOrEqualTo, Limit, Max,
+                Min, Mod, Mul, NoneOf, NotEqualTo, Pow, Random, Skip, Sort, SourceNode, StartsWith,
+                Sub, Sum, TargetNode,
             },
             values::{self, MultipleValuesWithIndexOperand},
             wrapper::{CardinalityWrapper, Wrapper},
@@ -27,13 +31,15 @@ use crate::{
     },
     prelude::GraphRecordValue,
 };
-use graphrecords_utils::aliases::GrHashSet;
+use graphrecords_utils::aliases::{GrHashMap, GrHashSet};
 use std::{
     collections::{HashMap, HashSet},
     fmt::Debug,
+    hash::{Hash, Hasher},
+    mem,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct EdgeOperand {
     context: Option<EdgeOperandContext>,
     operations: Vec<EdgeOperation>,
@@ -84,44 +90,19 @@ impl RootOperand for EdgeOperand {
         &self,
         graphrecord: &'a GraphRecord,
     ) -> GraphRecordResult<BoxedIterator<'a, &'a Self::Index>> {
-        let edge_indices: BoxedIterator<_> = match &self.context {
-            Some(EdgeOperandContext::Edges { operand, kind }) => {
-                let node_indices = operand.evaluate_backward(graphrecord)?;
-
-                match kind {
-                    nodes::EdgeDirection::Incoming => {
-                        Box::new(node_indices.flat_map(|node_index| {
-                            graphrecord
-                                .incoming_edges(node_index)
-                                .expect("Node must exist.")
-                        }))
-                    }
-                    nodes::EdgeDirection::Outgoing => {
-                        Box::new(node_indices.flat_map(|node_index| {
-                            graphrecord
-                                .outgoing_edges(node_index)
-                                .expect("Node must exist.")
-                        }))
-                    }
-                    nodes::EdgeDirection::Both => Box::new(node_indices.flat_map(|node_index| {
-                        graphrecord
-                            .incoming_edges(node_index)
-                            .expect("Node must exist")
-                            .chain(
-                                graphrecord
-                                    .outgoing_edges(node_index)
-                                    .expect("Node must exist"),
-                            )
-                    })),
-                }
-            }
-            Some(EdgeOperandContext::GroupBy { operand }) => {
-                operand.evaluate_backward(graphrecord)?
-            }
-            None => Box::new(graphrecord.edge_indices()),
-        };
-
-        self.evaluate_forward(graphrecord, edge_indices)
+        SubqueryCache::evaluate(
+            graphrecord,
+            &self.context,
+            &self.operations,
+            || self.evaluate_context(graphrecord),
+            |edge_indices, operations| {
+                operations
+                    .iter()
+                    .try_fold(edge_indices, |edge_indices, operation| {
+                        operation.evaluate(graphrecord, edge_indices)
+                    })
+            },
+        )
     }
 
     fn _evaluate_backward_grouped_operand<'a>(
@@ -134,6 +115,17 @@ impl RootOperand for EdgeOperand {
 
                 Ok(Self::partition(graphrecord, edge_indices, discriminator))
             }
+            group_by::EdgeOperandContext::Subgroups {
+                operand,
+                discriminator,
+            } => {
+                let partitions = operand.evaluate_backward(graphrecord)?;
+
+                group_operand.operand.evaluate_forward_grouped(
+                    graphrecord,
+                    partition_subgroups::<Self>(graphrecord, partitions, discriminator.clone()),
+                )
+            }
             group_by::EdgeOperandContext::Edges(operand) => {
                 let partitions = operand.evaluate_backward(graphrecord)?;
 
@@ -299,6 +291,40 @@ impl RootOperand for EdgeOperand {
                     )
                 }))
             }
+            EdgeOperandGroupDiscriminator::EndpointGroups => {
+                let mut buckets: HashMap<
+                    (Option<&'a Group>, Option<&'a Group>),
+                    Vec<&'a EdgeIndex>,
+                > = HashMap::new();
+
+                for edge_index in edge_indices {
+                    let endpoints = graphrecord
+                        .edge_endpoints(edge_index)
+                        .expect("Edge must exist");
+
+                    let source_groups = Self::endpoint_groups(graphrecord, endpoints.0);
+                    let target_groups = Self::endpoint_groups(graphrecord, endpoints.1);
+
+                    for source_group in &source_groups {
+                        for target_group in &target_groups {
+                            buckets
+                                .entry((*source_group, *target_group))
+                                .or_default()
+                                .push(edge_index);
+                        }
+                    }
+                }
+
+                Box::new(buckets.into_iter().map(|(key, group)| {
+                    (
+                        GroupKey::TupleKey((
+                            Box::new(GroupKey::OptionalGroup(key.0)),
+                            Box::new(GroupKey::OptionalGroup(key.1)),
+                        )),
+                        Box::new(group.into_iter()) as BoxedIterator<_>,
+                    )
+                }))
+            }
         }
     }
 
@@ -307,6 +333,75 @@ impl RootOperand for EdgeOperand {
     ) -> BoxedIterator<'a, &'a Self::Index> {
         Box::new(edge_indices.flat_map(|(_, edge_indices)| edge_indices))
     }
+
+    fn _validate_attribute(
+        &self,
+        graphrecord: &GraphRecord,
+        attribute: &GraphRecordAttribute,
+    ) -> GraphRecordResult<()> {
+        if !graphrecord.strict_queries() {
+            return Ok(());
+        }
+
+        let groups: Vec<_> = self
+            .operations
+            .iter()
+            .filter_map(|operation| match operation {
+                EdgeOperation::InGroup { group } => Some(group),
+                _ => None,
+            })
+            .flat_map(|group| match group {
+                CardinalityWrapper::Single(group) => vec![group.clone()],
+                CardinalityWrapper::Multiple(groups, _) => groups.clone(),
+            })
+            .collect();
+
+        Ok(graphrecord.get_schema().validate_edge_attribute(
+            attribute,
+            (!groups.is_empty()).then_some(groups.as_slice()),
+        )?)
+    }
+
+    fn _get_provenance<'a>(
+        graphrecord: &'a GraphRecord,
+        index: &Self::Index,
+        attribute: &GraphRecordAttribute,
+    ) -> Option<&'a ProvenanceTag> {
+        graphrecord
+            .edge_attribute_provenance(index, attribute)
+            .ok()
+            .flatten()
+    }
+
+    // Text indices only cover nodes
+    fn _text_index_matches(
+        _graphrecord: &GraphRecord,
+        _index: &Self::Index,
+        _attribute: &GraphRecordAttribute,
+        _text: &str,
+        _tokens: &[String],
+    ) -> Option<bool> {
+        None
+    }
+
+    // Geo indices only cover nodes
+    fn _geo_index_within_radius(
+        _graphrecord: &GraphRecord,
+        _attribute: &GraphRecordAttribute,
+        _center: &GeoPoint,
+        _radius_km: f64,
+    ) -> Option<GrHashMap<Self::Index, GeoPoint>> {
+        None
+    }
+
+    // Degrees only exist for nodes
+    fn _get_degrees<'a>(
+        _graphrecord: &'a GraphRecord,
+        _edge_indices: BoxedIterator<'a, &'a Self::Index>,
+        _direction: &nodes::EdgeDirection,
+    ) -> BoxedIterator<'a, (&'a Self::Index, GraphRecordValue)> {
+        Box::new(std::iter::empty())
+    }
 }
 
 impl Attribute for EdgeOperand {
@@ -406,6 +501,21 @@ impl TargetNode for EdgeOperand {
     }
 }
 
+impl InducedBy for EdgeOperand {
+    type QueryOperand = NodeOperand;
+
+    fn induced_by<Q>(&mut self, query: Q)
+    where
+        Q: FnOnce(&mut Wrapper<Self::QueryOperand>),
+    {
+        let mut operand = Wrapper::<NodeOperand>::new(None);
+
+        query(&mut operand);
+
+        self.operations.push(EdgeOperation::InducedBy { operand });
+    }
+}
+
 impl EitherOr for EdgeOperand {
     type QueryOperand = Self;
 
@@ -427,6 +537,52 @@ impl EitherOr for EdgeOperand {
     }
 }
 
+impl AllOf for EdgeOperand {
+    type QueryOperand = Self;
+
+    fn all_of<I, Q>(&mut self, queries: I)
+    where
+        I: IntoIterator<Item = Q>,
+        Q: FnOnce(&mut Wrapper<Self::QueryOperand>),
+    {
+        let operands = queries
+            .into_iter()
+            .map(|query| {
+                let mut operand = Wrapper::<Self>::new(self.context.deep_clone());
+
+                query(&mut operand);
+
+                operand
+            })
+            .collect();
+
+        self.operations.push(EdgeOperation::AllOf { operands });
+    }
+}
+
+impl NoneOf for EdgeOperand {
+    type QueryOperand = Self;
+
+    fn none_of<I, Q>(&mut self, queries: I)
+    where
+        I: IntoIterator<Item = Q>,
+        Q: FnOnce(&mut Wrapper<Self::QueryOperand>),
+    {
+        let operands = queries
+            .into_iter()
+            .map(|query| {
+                let mut operand = Wrapper::<Self>::new(self.context.deep_clone());
+
+                query(&mut operand);
+
+                operand
+            })
+            .collect();
+
+        self.operations.push(EdgeOperation::NoneOf { operands });
+    }
+}
+
 impl Exclude for EdgeOperand {
     type QueryOperand = Self;
 
@@ -443,6 +599,67 @@ impl Exclude for EdgeOperand {
 }
 
 impl EdgeOperand {
+    fn evaluate_context<'a>(
+        &self,
+        graphrecord: &'a GraphRecord,
+    ) -> GraphRecordResult<BoxedIterator<'a, &'a EdgeIndex>> {
+        let edge_indices: BoxedIterator<_> = match &self.context {
+            Some(EdgeOperandContext::Edges { operand, kind }) => {
+                let node_indices = operand.evaluate_backward(graphrecord)?;
+
+                match kind {
+                    nodes::EdgeDirection::Incoming => {
+                        Box::new(node_indices.flat_map(|node_index| {
+                            graphrecord
+                                .incoming_edges(node_index)
+                                .expect("Node must exist.")
+                        }))
+                    }
+                    nodes::EdgeDirection::Outgoing => {
+                        Box::new(node_indices.flat_map(|node_index| {
+                            graphrecord
+                                .outgoing_edges(node_index)
+                                .expect("Node must exist.")
+                        }))
+                    }
+                    nodes::EdgeDirection::Both => Box::new(node_indices.flat_map(|node_index| {
+                        graphrecord
+                            .incoming_edges(node_index)
+                            .expect("Node must exist")
+                            .chain(
+                                graphrecord
+                                    .outgoing_edges(node_index)
+                                    .expect("Node must exist"),
+                            )
+                    })),
+                }
+            }
+            Some(EdgeOperandContext::GroupBy { operand }) => {
+                operand.evaluate_backward(graphrecord)?
+            }
+            None => Box::new(graphrecord.edge_indices()),
+        };
+
+        Ok(edge_indices)
+    }
+
+    fn endpoint_groups<'a>(
+        graphrecord: &'a GraphRecord,
+        node_index: &NodeIndex,
+    ) -> Vec<Option<&'a Group>> {
+        let groups: Vec<_> = graphrecord
+            .groups_of_node(node_index)
+            .expect("Node must exist")
+            .map(Some)
+            .collect();
+
+        if groups.is_empty() {
+            vec![None]
+        } else {
+            groups
+        }
+    }
+
     pub(crate) const fn new(context: Option<EdgeOperandContext>) -> Self {
         Self {
             context,
@@ -457,7 +674,7 @@ impl Wrapper<EdgeOperand> {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum EdgeIndexComparisonOperand {
     Operand(EdgeIndexOperand),
     Index(EdgeIndex),
@@ -502,12 +719,23 @@ impl EdgeIndexComparisonOperand {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EdgeIndicesComparisonOperand {
     Operand(EdgeIndicesOperand),
     Indices(GrHashSet<EdgeIndex>),
 }
 
+impl Hash for EdgeIndicesComparisonOperand {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+
+        match self {
+            Self::Operand(operand) => operand.hash(state),
+            Self::Indices(indices) => hash_unordered(indices.iter(), state),
+        }
+    }
+}
+
 impl DeepClone for EdgeIndicesComparisonOperand {
     fn deep_clone(&self) -> Self {
         match self {
@@ -565,7 +793,7 @@ impl EdgeIndicesComparisonOperand {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct EdgeIndicesOperand {
     context: EdgeIndicesOperandContext,
     operations: Vec<EdgeIndicesOperation>,
@@ -905,6 +1133,25 @@ impl IsMin for EdgeIndicesOperand {
     }
 }
 
+impl Sort for EdgeIndicesOperand {
+    fn sort(&mut self, descending: bool) {
+        self.operations
+            .push(EdgeIndicesOperation::Sort { descending });
+    }
+}
+
+impl Skip for EdgeIndicesOperand {
+    fn skip(&mut self, count: usize) {
+        self.operations.push(EdgeIndicesOperation::Skip(count));
+    }
+}
+
+impl Limit for EdgeIndicesOperand {
+    fn limit(&mut self, count: usize) {
+        self.operations.push(EdgeIndicesOperation::Limit(count));
+    }
+}
+
 impl EitherOr for EdgeIndicesOperand {
     type QueryOperand = Self;
 
@@ -966,7 +1213,7 @@ impl Wrapper<EdgeIndicesOperand> {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct EdgeIndexOperand {
     context: EdgeIndicesOperand,
     pub(crate) kind: SingleKind,
@@ -1023,7 +1270,7 @@ impl<'a> EvaluateBackward<'a> for EdgeIndexOperand {
     ) -> GraphRecordResult<Self::ReturnValue> {
         let edge_indices = self.context.evaluate_backward(graphrecord)?;
 
-        let edge_index = self.reduce_input(edge_indices)?;
+        let edge_index = self.reduce_input(graphrecord, edge_indices)?;
 
         self.evaluate_forward(graphrecord, edge_index)
     }
@@ -1035,6 +1282,7 @@ impl<'a> ReduceInput<'a> for EdgeIndexOperand {
     #[inline]
     fn reduce_input(
         &self,
+        graphrecord: &'a GraphRecord,
         edge_indices: <Self::Context as EvaluateBackward<'a>>::ReturnValue,
     ) -> GraphRecordResult<<Self as EvaluateForward<'a>>::InputValue> {
         Ok(match self.kind {
@@ -1042,7 +1290,7 @@ impl<'a> ReduceInput<'a> for EdgeIndexOperand {
             SingleKind::Min => EdgeIndicesOperation::get_min(edge_indices),
             SingleKind::Count => Some(EdgeIndicesOperation::get_count(edge_indices)),
             SingleKind::Sum => Some(EdgeIndicesOperation::get_sum(edge_indices)),
-            SingleKind::Random => EdgeIndicesOperation::get_random(edge_indices),
+            SingleKind::Random => EdgeIndicesOperation::get_random(graphrecord, edge_indices),
         })
     }
 }
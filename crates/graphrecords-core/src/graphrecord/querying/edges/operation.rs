@@ -9,10 +9,11 @@ use crate::{
     GraphRecord,
     errors::{GraphRecordError, GraphRecordResult},
     graphrecord::{
-        EdgeIndex, GraphRecordAttribute, GraphRecordValue, Group,
+        EdgeIndex, GraphRecordAttribute, GraphRecordValue, Group, NodeIndex,
         datatypes::{Contains, EndsWith, Mod, StartsWith},
         querying::{
-            BoxedIterator, DeepClone, EvaluateForward, EvaluateForwardGrouped, GroupedIterator,
+            BoxedIterator, DeepClone, EvaluateBackward, EvaluateForward, EvaluateForwardGrouped,
+            GroupedIterator, QueryRng, RootOperand,
             attributes::AttributesTreeOperand,
             edges::SingleKind,
             group_by::{GroupOperand, PartitionGroups},
@@ -25,10 +26,9 @@ use crate::{
 };
 use graphrecords_utils::aliases::GrHashSet;
 use itertools::Itertools;
-use rand::{rng, seq::IteratorRandom};
 use std::ops::{Add, Mul, Sub};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum EdgeOperation {
     Values {
         operand: Wrapper<MultipleValuesWithIndexOperand<EdgeOperand>>,
@@ -53,6 +53,9 @@ pub enum EdgeOperation {
     TargetNode {
         operand: Wrapper<NodeOperand>,
     },
+    InducedBy {
+        operand: Wrapper<NodeOperand>,
+    },
 
     EitherOr {
         either: Wrapper<EdgeOperand>,
@@ -61,6 +64,12 @@ pub enum EdgeOperation {
     Exclude {
         operand: Wrapper<EdgeOperand>,
     },
+    AllOf {
+        operands: Vec<Wrapper<EdgeOperand>>,
+    },
+    NoneOf {
+        operands: Vec<Wrapper<EdgeOperand>>,
+    },
 
     GroupBy {
         operand: Wrapper<GroupOperand<EdgeOperand>>,
@@ -91,6 +100,9 @@ impl DeepClone for EdgeOperation {
             Self::TargetNode { operand } => Self::TargetNode {
                 operand: operand.deep_clone(),
             },
+            Self::InducedBy { operand } => Self::InducedBy {
+                operand: operand.deep_clone(),
+            },
             Self::EitherOr { either, or } => Self::EitherOr {
                 either: either.deep_clone(),
                 or: or.deep_clone(),
@@ -98,6 +110,12 @@ impl DeepClone for EdgeOperation {
             Self::Exclude { operand } => Self::Exclude {
                 operand: operand.deep_clone(),
             },
+            Self::AllOf { operands } => Self::AllOf {
+                operands: operands.deep_clone(),
+            },
+            Self::NoneOf { operands } => Self::NoneOf {
+                operands: operands.deep_clone(),
+            },
             Self::GroupBy { operand } => Self::GroupBy {
                 operand: operand.deep_clone(),
             },
@@ -143,21 +161,13 @@ impl EdgeOperation {
                 edge_indices,
                 operand,
             )?),
+            Self::InducedBy { operand } => Box::new(Self::evaluate_induced_by(
+                graphrecord,
+                edge_indices,
+                Self::get_induced_node_indices(graphrecord, operand)?,
+            )),
             Self::EitherOr { either, or } => {
-                let (edge_indices_1, rest) = edge_indices.tee();
-                let (edge_indices_2, edge_indices_3) = rest.tee();
-
-                let either_set: GrHashSet<_> = either
-                    .evaluate_forward(graphrecord, Box::new(edge_indices_1))?
-                    .collect();
-                let or_set: GrHashSet<_> = or
-                    .evaluate_forward(graphrecord, Box::new(edge_indices_2))?
-                    .collect();
-
-                Box::new(
-                    edge_indices_3
-                        .filter(move |edge| either_set.contains(edge) || or_set.contains(edge)),
-                )
+                Self::evaluate_either_or(graphrecord, edge_indices, either, or)?
             }
             Self::Exclude { operand } => {
                 let (edge_indices_1, edge_indices_2) = edge_indices.tee();
@@ -168,6 +178,10 @@ impl EdgeOperation {
 
                 Box::new(edge_indices_2.filter(move |node_index| !result.contains(node_index)))
             }
+            Self::AllOf { operands } => Self::evaluate_all_of(graphrecord, edge_indices, operands)?,
+            Self::NoneOf { operands } => {
+                Self::evaluate_none_of(graphrecord, edge_indices, operands)?
+            }
             Self::GroupBy { operand } => {
                 Box::new(Self::evaluate_group_by(graphrecord, edge_indices, operand)?)
             }
@@ -201,11 +215,14 @@ impl EdgeOperation {
     where
         T: Iterator<Item = &'a EdgeIndex> + 'a,
     {
-        let MultipleValuesWithIndexContext::Operand((_, ref attribute)) = operand.0.read().context
+        let MultipleValuesWithIndexContext::Operand((ref context, ref attribute)) =
+            operand.0.read().context
         else {
             unreachable!()
         };
 
+        context._validate_attribute(graphrecord, attribute)?;
+
         let values = Self::get_values(graphrecord, edge_indices, attribute.clone());
 
         Ok(operand
@@ -392,6 +409,32 @@ impl EdgeOperation {
         }))
     }
 
+    #[inline]
+    fn get_induced_node_indices<'a>(
+        graphrecord: &'a GraphRecord,
+        operand: &Wrapper<NodeOperand>,
+    ) -> GraphRecordResult<GrHashSet<&'a NodeIndex>> {
+        Ok(operand.evaluate_backward(graphrecord)?.collect())
+    }
+
+    #[inline]
+    fn evaluate_induced_by<'a, T>(
+        graphrecord: &'a GraphRecord,
+        edge_indices: T,
+        node_indices: GrHashSet<&'a NodeIndex>,
+    ) -> impl Iterator<Item = &'a EdgeIndex> + use<'a, T>
+    where
+        T: Iterator<Item = &'a EdgeIndex> + 'a,
+    {
+        edge_indices.filter(move |edge_index| {
+            let (source_node_index, target_node_index) = graphrecord
+                .edge_endpoints(edge_index)
+                .expect("Edge must exist");
+
+            node_indices.contains(source_node_index) && node_indices.contains(target_node_index)
+        })
+    }
+
     fn evaluate_group_by<'a, T>(
         graphrecord: &'a GraphRecord,
         edge_indices: T,
@@ -466,6 +509,22 @@ impl EdgeOperation {
                 edge_indices,
                 operand,
             )?),
+            Self::InducedBy { operand } => {
+                // The node query does not depend on the groups, so it is only
+                // evaluated once
+                let node_indices = Self::get_induced_node_indices(graphrecord, operand)?;
+
+                Box::new(edge_indices.map(move |(key, edge_indices)| {
+                    (
+                        key,
+                        Box::new(Self::evaluate_induced_by(
+                            graphrecord,
+                            edge_indices,
+                            node_indices.clone(),
+                        )) as BoxedIterator<_>,
+                    )
+                }))
+            }
             Self::EitherOr { either, or } => Box::new(Self::evaluate_either_or_grouped(
                 graphrecord,
                 edge_indices,
@@ -477,6 +536,12 @@ impl EdgeOperation {
                 edge_indices,
                 operand,
             )?),
+            Self::AllOf { operands } => {
+                Self::evaluate_all_of_grouped(graphrecord, edge_indices, operands)?
+            }
+            Self::NoneOf { operands } => {
+                Self::evaluate_none_of_grouped(graphrecord, edge_indices, operands)?
+            }
             Self::GroupBy { operand: _ } => unreachable!(),
         })
     }
@@ -487,11 +552,14 @@ impl EdgeOperation {
         edge_indices: GroupedIterator<'a, BoxedIterator<'a, &'a EdgeIndex>>,
         operand: &Wrapper<MultipleValuesWithIndexOperand<EdgeOperand>>,
     ) -> GraphRecordResult<GroupedIterator<'a, BoxedIterator<'a, &'a EdgeIndex>>> {
-        let MultipleValuesWithIndexContext::Operand((_, ref attribute)) = operand.0.read().context
+        let MultipleValuesWithIndexContext::Operand((ref context, ref attribute)) =
+            operand.0.read().context
         else {
             unreachable!()
         };
 
+        context._validate_attribute(graphrecord, attribute)?;
+
         let values: Vec<_> = edge_indices
             .map(|(key, edge_indices)| {
                 (
@@ -739,9 +807,113 @@ impl EdgeOperation {
 
         Ok(Box::new(edge_indices))
     }
+
+    #[inline]
+    fn evaluate_either_or<'a>(
+        graphrecord: &'a GraphRecord,
+        edge_indices: impl Iterator<Item = &'a EdgeIndex>,
+        either: &Wrapper<EdgeOperand>,
+        or: &Wrapper<EdgeOperand>,
+    ) -> GraphRecordResult<BoxedIterator<'a, &'a EdgeIndex>> {
+        let edge_indices: Vec<_> = edge_indices.collect();
+
+        let either_set: GrHashSet<_> = either
+            .evaluate_forward(graphrecord, Box::new(edge_indices.clone().into_iter()))?
+            .collect();
+
+        // Indices matched by `either` do not need to be checked by `or`
+        #[expect(
+            clippy::needless_collect,
+            reason = "the boxed iterator cannot borrow `either_set`"
+        )]
+        let remaining: Vec<_> = edge_indices
+            .iter()
+            .filter(|index| !either_set.contains(*index))
+            .copied()
+            .collect();
+        let or_set: GrHashSet<_> = or
+            .evaluate_forward(graphrecord, Box::new(remaining.into_iter()))?
+            .collect();
+
+        Ok(Box::new(edge_indices.into_iter().filter(move |index| {
+            either_set.contains(index) || or_set.contains(index)
+        })))
+    }
+
+    #[inline]
+    fn evaluate_all_of<'a>(
+        graphrecord: &'a GraphRecord,
+        edge_indices: impl Iterator<Item = &'a EdgeIndex>,
+        operands: &[Wrapper<EdgeOperand>],
+    ) -> GraphRecordResult<BoxedIterator<'a, &'a EdgeIndex>> {
+        let mut edge_indices: Vec<_> = edge_indices.collect();
+
+        // Every operand only checks the indices all previous operands matched
+        for operand in operands {
+            if edge_indices.is_empty() {
+                break;
+            }
+
+            edge_indices = operand
+                .evaluate_forward(graphrecord, Box::new(edge_indices.into_iter()))?
+                .collect();
+        }
+
+        Ok(Box::new(edge_indices.into_iter()))
+    }
+
+    #[inline]
+    fn evaluate_none_of<'a>(
+        graphrecord: &'a GraphRecord,
+        edge_indices: impl Iterator<Item = &'a EdgeIndex>,
+        operands: &[Wrapper<EdgeOperand>],
+    ) -> GraphRecordResult<BoxedIterator<'a, &'a EdgeIndex>> {
+        let mut edge_indices: Vec<_> = edge_indices.collect();
+
+        // Every operand only checks the indices no previous operand matched
+        for operand in operands {
+            if edge_indices.is_empty() {
+                break;
+            }
+
+            let matched: GrHashSet<_> = operand
+                .evaluate_forward(graphrecord, Box::new(edge_indices.clone().into_iter()))?
+                .collect();
+
+            edge_indices.retain(|index| !matched.contains(index));
+        }
+
+        Ok(Box::new(edge_indices.into_iter()))
+    }
+
+    #[inline]
+    fn evaluate_all_of_grouped<'a>(
+        graphrecord: &'a GraphRecord,
+        edge_indices: GroupedIterator<'a, BoxedIterator<'a, &'a EdgeIndex>>,
+        operands: &[Wrapper<EdgeOperand>],
+    ) -> GraphRecordResult<GroupedIterator<'a, BoxedIterator<'a, &'a EdgeIndex>>> {
+        operands
+            .iter()
+            .try_fold(edge_indices, |edge_indices, operand| {
+                operand.evaluate_forward_grouped(graphrecord, edge_indices)
+            })
+    }
+
+    #[inline]
+    fn evaluate_none_of_grouped<'a>(
+        graphrecord: &'a GraphRecord,
+        edge_indices: GroupedIterator<'a, BoxedIterator<'a, &'a EdgeIndex>>,
+        operands: &[Wrapper<EdgeOperand>],
+    ) -> GraphRecordResult<GroupedIterator<'a, BoxedIterator<'a, &'a EdgeIndex>>> {
+        operands
+            .iter()
+            .try_fold(edge_indices, |edge_indices, operand| {
+                Self::evaluate_exclude_grouped(graphrecord, edge_indices, operand)
+            })
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum EdgeIndicesOperation {
     EdgeIndexOperation {
         operand: Wrapper<EdgeIndexOperand>,
@@ -762,6 +934,12 @@ pub enum EdgeIndicesOperation {
     IsMax,
     IsMin,
 
+    Sort {
+        descending: bool,
+    },
+    Skip(usize),
+    Limit(usize),
+
     EitherOr {
         either: Wrapper<EdgeIndicesOperand>,
         or: Wrapper<EdgeIndicesOperand>,
@@ -799,6 +977,11 @@ impl DeepClone for EdgeIndicesOperation {
             },
             Self::IsMax => Self::IsMax,
             Self::IsMin => Self::IsMin,
+            Self::Sort { descending } => Self::Sort {
+                descending: *descending,
+            },
+            Self::Skip(count) => Self::Skip(*count),
+            Self::Limit(count) => Self::Limit(*count),
             Self::EitherOr { either, or } => Self::EitherOr {
                 either: either.deep_clone(),
                 or: or.deep_clone(),
@@ -839,6 +1022,9 @@ impl EdgeIndicesOperation {
             ),
             Self::IsMax => Self::evaluate_is_max(indices),
             Self::IsMin => Self::evaluate_is_min(indices),
+            Self::Sort { descending } => Box::new(Self::evaluate_sort(indices, *descending)),
+            Self::Skip(count) => Box::new(indices.skip(*count)),
+            Self::Limit(count) => Box::new(indices.take(*count)),
             Self::EitherOr { either, or } => {
                 Self::evaluate_either_or(graphrecord, indices, either, or)?
             }
@@ -867,8 +1053,11 @@ impl EdgeIndicesOperation {
     }
 
     #[inline]
-    pub(crate) fn get_random(indices: impl Iterator<Item = EdgeIndex>) -> Option<EdgeIndex> {
-        indices.choose(&mut rng())
+    pub(crate) fn get_random(
+        graphrecord: &GraphRecord,
+        indices: impl Iterator<Item = EdgeIndex>,
+    ) -> Option<EdgeIndex> {
+        QueryRng::new(graphrecord).choose(indices, EdgeIndex::cmp)
     }
 
     #[inline]
@@ -886,7 +1075,7 @@ impl EdgeIndicesOperation {
             SingleKind::Min => Self::get_min(indices_1),
             SingleKind::Count => Some(Self::get_count(indices_1)),
             SingleKind::Sum => Some(Self::get_sum(indices_1)),
-            SingleKind::Random => Self::get_random(indices_1),
+            SingleKind::Random => Self::get_random(graphrecord, indices_1),
         };
 
         Ok(match operand.evaluate_forward(graphrecord, index)? {
@@ -1012,6 +1201,24 @@ impl EdgeIndicesOperation {
         Box::new(indices_2.filter(move |index| *index == min_index))
     }
 
+    #[inline]
+    fn evaluate_sort(
+        indices: impl Iterator<Item = EdgeIndex>,
+        descending: bool,
+    ) -> impl Iterator<Item = EdgeIndex> {
+        let mut indices: Vec<_> = indices.collect();
+
+        indices.sort_by(|index, other| {
+            if descending {
+                other.cmp(index)
+            } else {
+                index.cmp(other)
+            }
+        });
+
+        indices.into_iter()
+    }
+
     #[inline]
     fn evaluate_either_or<'a>(
         graphrecord: &'a GraphRecord,
@@ -1111,6 +1318,30 @@ impl EdgeIndicesOperation {
                 edge_indices
                     .map(move |(key, edge_indices)| (key, Self::evaluate_is_min(edge_indices))),
             ),
+            Self::Sort { descending } => {
+                let descending = *descending;
+
+                Box::new(edge_indices.map(move |(key, edge_indices)| {
+                    (
+                        key,
+                        Box::new(Self::evaluate_sort(edge_indices, descending)) as BoxedIterator<_>,
+                    )
+                }))
+            }
+            Self::Skip(count) => {
+                let count = *count;
+
+                Box::new(edge_indices.map(move |(key, edge_indices)| {
+                    (key, Box::new(edge_indices.skip(count)) as BoxedIterator<_>)
+                }))
+            }
+            Self::Limit(count) => {
+                let count = *count;
+
+                Box::new(edge_indices.map(move |(key, edge_indices)| {
+                    (key, Box::new(edge_indices.take(count)) as BoxedIterator<_>)
+                }))
+            }
             Self::EitherOr { either, or } => Box::new(Self::evaluate_either_or_grouped(
                 graphrecord,
                 edge_indices,
@@ -1164,7 +1395,7 @@ impl EdgeIndicesOperation {
                         SingleKind::Min => Self::get_min(edge_indices),
                         SingleKind::Count => Some(Self::get_count(edge_indices)),
                         SingleKind::Sum => Some(Self::get_sum(edge_indices)),
-                        SingleKind::Random => Self::get_random(edge_indices),
+                        SingleKind::Random => Self::get_random(graphrecord, edge_indices),
                     },
                 ))
             })
@@ -1252,7 +1483,7 @@ impl EdgeIndicesOperation {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum EdgeIndexOperation {
     EdgeIndexComparisonOperation {
         operand: EdgeIndexComparisonOperand,
@@ -1,17 +1,21 @@
-use super::{DeepClone, wrapper::Wrapper};
+use super::{DeepClone, EvaluateBackward, wrapper::Wrapper};
 use crate::{
     GraphRecord,
-    graphrecord::querying::GroupedIterator,
-    prelude::{GraphRecordValue, NodeIndex},
+    errors::GraphRecordResult,
+    graphrecord::querying::{
+        BoxedIterator, GroupedIterator, RootOperand,
+        values::{EdgeSingleValueWithoutIndexOperand, NodeSingleValueWithoutIndexOperand},
+    },
+    prelude::{GraphRecordValue, Group, NodeIndex},
 };
-use std::fmt::Debug;
+use std::{cmp::Ordering, fmt::Debug, hash::Hash};
 
 pub trait GroupedOperand {
-    type Context: Debug + Clone + DeepClone;
+    type Context: Debug + Clone + DeepClone + Eq + Hash;
 }
 
 pub trait GroupBy: GroupedOperand {
-    type Discriminator: Debug + Clone + DeepClone;
+    type Discriminator: Debug + Clone + DeepClone + Eq + Hash;
 
     fn group_by(&mut self, discriminator: Self::Discriminator) -> Wrapper<GroupOperand<Self>>
     where
@@ -30,6 +34,151 @@ pub enum GroupKey<'a> {
     Value(&'a GraphRecordValue),
     OptionalValue(Option<&'a GraphRecordValue>),
     TupleKey((Box<Self>, Box<Self>)),
+    OptionalGroup(Option<&'a Group>),
+}
+
+impl GroupKey<'_> {
+    const fn variant_rank(&self) -> u8 {
+        match self {
+            Self::NodeIndex(_) => 0,
+            Self::Value(_) => 1,
+            Self::OptionalValue(_) => 2,
+            Self::TupleKey(_) => 3,
+            Self::OptionalGroup(_) => 4,
+        }
+    }
+
+    /// Total order over all keys, placing missing values and groups first
+    /// and keys of different kinds in the order of their variants.
+    ///
+    /// Agrees with [`PartialOrd`] wherever the latter is defined.
+    #[must_use]
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::NodeIndex(value), Self::NodeIndex(other)) => value.total_cmp(other),
+            (Self::Value(value), Self::Value(other)) => value.total_cmp(other),
+            (Self::OptionalValue(value), Self::OptionalValue(other)) => match (value, other) {
+                (Some(value), Some(other)) => value.total_cmp(other),
+                _ => value.is_some().cmp(&other.is_some()),
+            },
+            (Self::TupleKey((left, right)), Self::TupleKey((other_left, other_right))) => left
+                .total_cmp(other_left)
+                .then_with(|| right.total_cmp(other_right)),
+            (Self::OptionalGroup(group), Self::OptionalGroup(other)) => match (group, other) {
+                (Some(group), Some(other)) => group.total_cmp(other),
+                _ => group.is_some().cmp(&other.is_some()),
+            },
+            _ => self.variant_rank().cmp(&other.variant_rank()),
+        }
+    }
+}
+
+/// Single value computed per group that groups can be ordered by, see
+/// [`Wrapper::sorted_by_aggregate`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GroupAggregate {
+    Node(Wrapper<GroupOperand<NodeSingleValueWithoutIndexOperand>>),
+    Edge(Wrapper<GroupOperand<EdgeSingleValueWithoutIndexOperand>>),
+}
+
+impl DeepClone for GroupAggregate {
+    fn deep_clone(&self) -> Self {
+        match self {
+            Self::Node(operand) => Self::Node(operand.deep_clone()),
+            Self::Edge(operand) => Self::Edge(operand.deep_clone()),
+        }
+    }
+}
+
+impl From<Wrapper<GroupOperand<NodeSingleValueWithoutIndexOperand>>> for GroupAggregate {
+    fn from(operand: Wrapper<GroupOperand<NodeSingleValueWithoutIndexOperand>>) -> Self {
+        Self::Node(operand)
+    }
+}
+
+impl From<Wrapper<GroupOperand<EdgeSingleValueWithoutIndexOperand>>> for GroupAggregate {
+    fn from(operand: Wrapper<GroupOperand<EdgeSingleValueWithoutIndexOperand>>) -> Self {
+        Self::Edge(operand)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GroupOrder {
+    Key,
+    Aggregate(GroupAggregate),
+}
+
+impl DeepClone for GroupOrder {
+    fn deep_clone(&self) -> Self {
+        match self {
+            Self::Key => Self::Key,
+            Self::Aggregate(aggregate) => Self::Aggregate(aggregate.deep_clone()),
+        }
+    }
+}
+
+impl GroupOrder {
+    pub(crate) fn sort<'a, V>(
+        &self,
+        graphrecord: &'a GraphRecord,
+        groups: GroupedIterator<'a, V>,
+    ) -> GraphRecordResult<Vec<(GroupKey<'a>, V)>> {
+        let mut groups: Vec<_> = groups.collect();
+
+        let aggregates: Vec<_> = match self {
+            Self::Key => {
+                groups.sort_by(|(key, _), (other, _)| key.total_cmp(other));
+
+                return Ok(groups);
+            }
+            Self::Aggregate(GroupAggregate::Node(operand)) => {
+                operand.evaluate_backward(graphrecord)?.collect()
+            }
+            Self::Aggregate(GroupAggregate::Edge(operand)) => {
+                operand.evaluate_backward(graphrecord)?.collect()
+            }
+        };
+
+        let aggregate = |key: &GroupKey<'a>| {
+            aggregates
+                .iter()
+                .find(|(aggregate_key, _)| aggregate_key == key)
+                .and_then(|(_, value)| value.as_ref())
+        };
+
+        // Groups without an aggregate come last, ties are ordered by key
+        groups.sort_by(|(key, _), (other, _)| {
+            match (aggregate(key), aggregate(other)) {
+                (Some(value), Some(other_value)) => value.total_cmp(other_value),
+                (value, other_value) => other_value.is_some().cmp(&value.is_some()),
+            }
+            .then_with(|| key.total_cmp(other))
+        });
+
+        Ok(groups)
+    }
+}
+
+/// Partitions every group of `partitions` further by `discriminator`. The
+/// subgroups are keyed by a [`GroupKey::TupleKey`] of the outer and the inner
+/// key, and are partitioned one group at a time as they are consumed.
+pub(crate) fn partition_subgroups<'a, O>(
+    graphrecord: &'a GraphRecord,
+    partitions: GroupedIterator<'a, BoxedIterator<'a, &'a O::Index>>,
+    discriminator: O::Discriminator,
+) -> GroupedIterator<'a, BoxedIterator<'a, &'a O::Index>>
+where
+    O: RootOperand + 'a,
+    O::Discriminator: 'a,
+{
+    Box::new(partitions.flat_map(move |(key, partition)| {
+        O::_partition(graphrecord, partition, &discriminator).map(move |(subkey, subpartition)| {
+            (
+                GroupKey::TupleKey((Box::new(key.clone()), Box::new(subkey))),
+                subpartition,
+            )
+        })
+    }))
 }
 
 pub trait PartitionGroups<'a>: GroupBy {
@@ -57,10 +206,11 @@ impl<O: Ungroup> Wrapper<O> {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct GroupOperand<O: GroupedOperand> {
     pub(crate) context: O::Context,
     pub(crate) operand: Wrapper<O>,
+    pub(crate) order: Option<GroupOrder>,
 }
 
 impl<O: GroupedOperand + DeepClone> DeepClone for GroupOperand<O> {
@@ -68,13 +218,18 @@ impl<O: GroupedOperand + DeepClone> DeepClone for GroupOperand<O> {
         Self {
             context: self.context.deep_clone(),
             operand: self.operand.deep_clone(),
+            order: self.order.deep_clone(),
         }
     }
 }
 
 impl<O: GroupedOperand> GroupOperand<O> {
     pub(crate) const fn new(context: O::Context, operand: Wrapper<O>) -> Self {
-        Self { context, operand }
+        Self {
+            context,
+            operand,
+            order: None,
+        }
     }
 }
 
@@ -82,4 +237,17 @@ impl<O: GroupedOperand> Wrapper<GroupOperand<O>> {
     pub(crate) fn new(context: O::Context, operand: Wrapper<O>) -> Self {
         GroupOperand::new(context, operand).into()
     }
+
+    /// Returns the groups ordered by their keys instead of in arbitrary
+    /// order.
+    pub fn sorted_by_key(&self) {
+        self.0.write().order = Some(GroupOrder::Key);
+    }
+
+    /// Returns the groups in ascending order of `aggregate`, a single value
+    /// computed per group of the same grouping. Groups without a value come
+    /// last, and groups with equal values are ordered by their keys.
+    pub fn sorted_by_aggregate(&self, aggregate: impl Into<GroupAggregate>) {
+        self.0.write().order = Some(GroupOrder::Aggregate(aggregate.into()));
+    }
 }
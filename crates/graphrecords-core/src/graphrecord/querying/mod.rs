@@ -3,10 +3,15 @@ pub mod edges;
 pub mod group_by;
 pub mod nodes;
 mod operand_traits;
+pub mod plan;
+mod subquery_cache;
 pub mod values;
 pub mod wrapper;
 
-use super::{EdgeIndex, GraphRecord, GraphRecordAttribute, GraphRecordValue, NodeIndex, Wrapper};
+use super::{
+    EdgeIndex, GraphRecord, GraphRecordAttribute, GraphRecordValue, NodeIndex, Wrapper,
+    datatypes::GeoPoint, provenance::ProvenanceTag,
+};
 use crate::{
     errors::GraphRecordResult,
     graphrecord::querying::{
@@ -25,17 +30,27 @@ use attributes::{
     NodeSingleAttributeWithIndexOperand,
 };
 use edges::{EdgeIndexOperand, EdgeIndicesOperand, EdgeOperand};
+use graphrecords_utils::aliases::GrHashMap;
 use group_by::{GroupOperand, GroupedOperand};
 use itertools::Itertools;
-use nodes::{NodeIndexOperand, NodeIndicesOperand, NodeOperand};
+use nodes::{EdgeDirection, NodeIndexOperand, NodeIndicesOperand, NodeOperand};
+use rand::{
+    Rng, SeedableRng,
+    rngs::{StdRng, ThreadRng},
+    seq::IteratorRandom,
+};
 use std::{
+    cmp::Ordering,
     fmt::{Debug, Display},
-    hash::Hash,
+    hash::{BuildHasher, BuildHasherDefault, DefaultHasher, Hash, Hasher},
 };
+pub(crate) use subquery_cache::SubqueryCache;
 use values::{
-    EdgeMultipleValuesWithIndexOperand, EdgeMultipleValuesWithoutIndexOperand,
-    EdgeSingleValueWithIndexOperand, GetValues, NodeMultipleValuesWithIndexOperand,
-    NodeMultipleValuesWithoutIndexOperand, NodeSingleValueWithIndexOperand,
+    EdgeHistogramOperand, EdgeMultipleValuesWithIndexOperand,
+    EdgeMultipleValuesWithoutIndexOperand, EdgeQuantilesOperand, EdgeSingleValueWithIndexOperand,
+    EdgeValueCountsOperand, GetValues, NodeHistogramOperand, NodeMultipleValuesWithIndexOperand,
+    NodeMultipleValuesWithoutIndexOperand, NodeQuantilesOperand, NodeSingleValueWithIndexOperand,
+    NodeValueCountsOperand,
 };
 
 macro_rules! impl_return_operand_for_tuples {
@@ -51,6 +66,13 @@ macro_rules! impl_return_operand_for_tuples {
 
                 Ok(($($T,)+))
             }
+
+            #[allow(non_snake_case)]
+            fn operand_count(&self) -> usize {
+                let ($($T,)+) = self;
+
+                0 $(+ $T.operand_count())+
+            }
         }
     };
 }
@@ -83,19 +105,40 @@ macro_rules! impl_direct_return_operand {
     };
 }
 
-pub trait Index: Eq + Clone + Hash + Display + GetAttributes {}
+pub trait Index: Eq + Clone + Hash + Display + GetAttributes {
+    fn total_cmp(&self, other: &Self) -> Ordering;
+}
 
-impl Index for NodeIndex {}
+impl Index for NodeIndex {
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        Self::total_cmp(self, other)
+    }
+}
 
-impl Index for EdgeIndex {}
+impl Index for EdgeIndex {
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        self.cmp(other)
+    }
+}
 
-impl<I: Index> Index for &I {}
+impl<I: Index> Index for &I {
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        I::total_cmp(self, other)
+    }
+}
 
 pub trait RootOperand:
-    GetAllAttributes<Self::Index> + GetValues<Self::Index> + GroupedOperand + Debug + Clone + DeepClone
+    GetAllAttributes<Self::Index>
+    + GetValues<Self::Index>
+    + GroupedOperand
+    + Debug
+    + Clone
+    + DeepClone
+    + Eq
+    + Hash
 {
     type Index: Index;
-    type Discriminator: Debug + Clone + DeepClone;
+    type Discriminator: Debug + Clone + DeepClone + Eq + Hash;
 
     fn _evaluate_forward<'a>(
         &self,
@@ -130,6 +173,45 @@ pub trait RootOperand:
     fn _merge<'a>(
         indices: GroupedIterator<'a, BoxedIterator<'a, &'a Self::Index>>,
     ) -> BoxedIterator<'a, &'a Self::Index>;
+
+    fn _validate_attribute(
+        &self,
+        graphrecord: &GraphRecord,
+        attribute: &GraphRecordAttribute,
+    ) -> GraphRecordResult<()>;
+
+    fn _get_provenance<'a>(
+        graphrecord: &'a GraphRecord,
+        index: &Self::Index,
+        attribute: &GraphRecordAttribute,
+    ) -> Option<&'a ProvenanceTag>;
+
+    /// Looks `text` up in the text indices of `attribute`. Returns `None` if no
+    /// text index covers the value.
+    fn _text_index_matches(
+        graphrecord: &GraphRecord,
+        index: &Self::Index,
+        attribute: &GraphRecordAttribute,
+        text: &str,
+        tokens: &[String],
+    ) -> Option<bool>;
+
+    /// Looks up the indices within `radius_km` of `center` in the geo indices
+    /// of `attribute`. Returns `None` if there is no geo index on `attribute`.
+    fn _geo_index_within_radius(
+        graphrecord: &GraphRecord,
+        attribute: &GraphRecordAttribute,
+        center: &GeoPoint,
+        radius_km: f64,
+    ) -> Option<GrHashMap<Self::Index, GeoPoint>>;
+
+    /// Returns the number of edges in `direction` of every index as an `Int`
+    /// value. Indices without a degree are skipped.
+    fn _get_degrees<'a>(
+        graphrecord: &'a GraphRecord,
+        indices: BoxedIterator<'a, &'a Self::Index>,
+        direction: &EdgeDirection,
+    ) -> BoxedIterator<'a, (&'a Self::Index, GraphRecordValue)>;
 }
 
 impl<'a, O> EvaluateForward<'a> for O
@@ -150,6 +232,50 @@ where
 
 pub type GroupedIterator<'a, O> = BoxedIterator<'a, (GroupKey<'a>, O)>;
 
+/// Hashes `elements` independently of their order, so that equal sets hash
+/// equally.
+pub(crate) fn hash_unordered<'a, T, H>(
+    elements: impl ExactSizeIterator<Item = &'a T>,
+    state: &mut H,
+) where
+    T: Hash + 'a,
+    H: Hasher,
+{
+    // The element hasher must be the same for every set, so it is not seeded
+    let build_hasher = BuildHasherDefault::<DefaultHasher>::default();
+
+    elements.len().hash(state);
+    elements
+        .map(|element| build_hasher.hash_one(element))
+        .fold(0, u64::wrapping_add)
+        .hash(state);
+}
+
+/// A float parameter of an operation, e.g. a quantile or a radius. It is
+/// compared and hashed by its bits, so that operations are `Eq` and `Hash`.
+#[derive(Debug, Clone, Copy)]
+pub struct QueryFloat(pub f64);
+
+impl From<f64> for QueryFloat {
+    fn from(value: f64) -> Self {
+        Self(value)
+    }
+}
+
+impl PartialEq for QueryFloat {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_bits() == other.0.to_bits()
+    }
+}
+
+impl Eq for QueryFloat {}
+
+impl Hash for QueryFloat {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
 pub(crate) fn tee_grouped_iterator<'a, O: 'a + Clone>(
     iterator: GroupedIterator<'a, BoxedIterator<'a, O>>,
 ) -> (
@@ -313,6 +439,7 @@ pub trait ReduceInput<'a>: EvaluateForward<'a> {
 
     fn reduce_input(
         &self,
+        graphrecord: &'a GraphRecord,
         values: <Self::Context as EvaluateBackward<'a>>::ReturnValue,
     ) -> GraphRecordResult<<Self as EvaluateForward<'a>>::InputValue>;
 }
@@ -320,12 +447,13 @@ pub trait ReduceInput<'a>: EvaluateForward<'a> {
 impl<'a, O> Wrapper<O> {
     pub(crate) fn reduce_input(
         &self,
+        graphrecord: &'a GraphRecord,
         values: <<O as ReduceInput<'a>>::Context as EvaluateBackward<'a>>::ReturnValue,
     ) -> GraphRecordResult<<O as EvaluateForward<'a>>::InputValue>
     where
         O: ReduceInput<'a>,
     {
-        self.0.read().reduce_input(values)
+        self.0.read().reduce_input(graphrecord, values)
     }
 }
 
@@ -354,6 +482,84 @@ impl<T: DeepClone> DeepClone for Vec<T> {
 
 pub(crate) type BoxedIterator<'a, T> = Box<dyn Iterator<Item = T> + 'a>;
 
+/// Source of randomness of the `random` operations, seeded from
+/// [`GraphRecord::random_seed`] if the record has a seed.
+pub(crate) enum QueryRng {
+    Seeded(Box<StdRng>),
+    Unseeded(ThreadRng),
+}
+
+impl QueryRng {
+    pub(crate) fn new(graphrecord: &GraphRecord) -> Self {
+        SubqueryCache::mark_nondeterministic();
+
+        match graphrecord.random_seed() {
+            Some(seed) => Self::Seeded(Box::new(StdRng::seed_from_u64(seed))),
+            None => Self::Unseeded(rand::rng()),
+        }
+    }
+
+    pub(crate) const fn is_seeded(&self) -> bool {
+        matches!(self, Self::Seeded(_))
+    }
+
+    fn random_fraction(&mut self) -> f64 {
+        match self {
+            Self::Seeded(rng) => rng.random(),
+            Self::Unseeded(rng) => rng.random(),
+        }
+    }
+
+    /// Picks a random item of `items`. Seeded picks sort the items by
+    /// `compare` first, so they do not depend on the iteration order.
+    pub(crate) fn choose<T>(
+        &mut self,
+        items: impl Iterator<Item = T>,
+        compare: impl FnMut(&T, &T) -> Ordering,
+    ) -> Option<T> {
+        match self {
+            Self::Seeded(rng) => {
+                let mut items: Vec<_> = items.collect();
+                items.sort_by(compare);
+
+                items.into_iter().choose(rng)
+            }
+            Self::Unseeded(rng) => items.choose(rng),
+        }
+    }
+
+    /// Picks a random item of `items` with a probability proportional to its
+    /// non-negative weight. Returns `None` if all weights are 0.
+    pub(crate) fn choose_weighted<T>(
+        &mut self,
+        items: impl Iterator<Item = (T, f64)>,
+        mut compare: impl FnMut(&T, &T) -> Ordering,
+    ) -> Option<T> {
+        let mut items: Vec<_> = items.filter(|(_, weight)| *weight > 0.0).collect();
+
+        if self.is_seeded() {
+            items.sort_by(|(item, _), (other, _)| compare(item, other));
+        }
+
+        let total: f64 = items.iter().map(|(_, weight)| weight).sum();
+        let mut threshold = self.random_fraction() * total;
+
+        let mut picked = None;
+
+        for (item, weight) in items {
+            picked = Some(item);
+
+            if threshold < weight {
+                break;
+            }
+
+            threshold -= weight;
+        }
+
+        picked
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Selection<'a, R: ReturnOperand<'a>> {
     graphrecord: &'a GraphRecord,
@@ -385,7 +591,15 @@ impl<'a, R: ReturnOperand<'a>> Selection<'a, R> {
         }
     }
 
+    /// Evaluates the return operand. If it consists of several operands,
+    /// subqueries they have in common are evaluated only once.
     pub fn evaluate(&self) -> GraphRecordResult<R::ReturnValue> {
+        if self.return_operand.operand_count() > 1 {
+            return SubqueryCache::scope(self.graphrecord, || {
+                self.return_operand.evaluate(self.graphrecord)
+            });
+        }
+
         self.return_operand.evaluate(self.graphrecord)
     }
 }
@@ -394,6 +608,12 @@ pub trait ReturnOperand<'a> {
     type ReturnValue;
 
     fn evaluate(&self, graphrecord: &'a GraphRecord) -> GraphRecordResult<Self::ReturnValue>;
+
+    /// Returns the number of operands that are evaluated, e.g. the number of
+    /// elements of a tuple.
+    fn operand_count(&self) -> usize {
+        1
+    }
 }
 
 impl_iterator_return_operand!(
@@ -422,17 +642,30 @@ impl_direct_return_operand!(
     NodeSingleValueWithoutIndexOperand     => Option<GraphRecordValue>,
     EdgeSingleValueWithIndexOperand        => Option<(&'a EdgeIndex, GraphRecordValue)>,
     EdgeSingleValueWithoutIndexOperand     => Option<GraphRecordValue>,
+    NodeValueCountsOperand                 => Vec<(GraphRecordValue, usize)>,
+    EdgeValueCountsOperand                 => Vec<(GraphRecordValue, usize)>,
+    NodeHistogramOperand                   => Vec<(f64, f64, usize)>,
+    EdgeHistogramOperand                   => Vec<(f64, f64, usize)>,
+    NodeQuantilesOperand                   => Vec<(f64, f64)>,
+    EdgeQuantilesOperand                   => Vec<(f64, f64)>,
 );
 
-impl<'a, O: GroupedOperand> ReturnOperand<'a> for Wrapper<GroupOperand<O>>
+impl<'a, O: GroupedOperand, V: 'a> ReturnOperand<'a> for Wrapper<GroupOperand<O>>
 where
-    GroupOperand<O>: EvaluateBackward<'a>,
+    GroupOperand<O>: EvaluateBackward<'a, ReturnValue = GroupedIterator<'a, V>>,
     Wrapper<O>: ReturnOperand<'a>,
 {
-    type ReturnValue = <Self as EvaluateBackward<'a>>::ReturnValue;
+    type ReturnValue = GroupedIterator<'a, V>;
 
     fn evaluate(&self, graphrecord: &'a GraphRecord) -> GraphRecordResult<Self::ReturnValue> {
-        self.evaluate_backward(graphrecord)
+        let groups = self.evaluate_backward(graphrecord)?;
+
+        let order = self.0.read().order.clone();
+
+        match order {
+            Some(order) => Ok(Box::new(order.sort(graphrecord, groups)?.into_iter())),
+            None => Ok(groups),
+        }
     }
 }
 
@@ -460,6 +693,10 @@ impl<'a, R: ReturnOperand<'a>> ReturnOperand<'a> for &R {
     fn evaluate(&self, graphrecord: &'a GraphRecord) -> GraphRecordResult<Self::ReturnValue> {
         R::evaluate(self, graphrecord)
     }
+
+    fn operand_count(&self) -> usize {
+        R::operand_count(self)
+    }
 }
 
 impl<'a, R: ReturnOperand<'a>> ReturnOperand<'a> for &mut R {
@@ -468,4 +705,8 @@ impl<'a, R: ReturnOperand<'a>> ReturnOperand<'a> for &mut R {
     fn evaluate(&self, graphrecord: &'a GraphRecord) -> GraphRecordResult<Self::ReturnValue> {
         R::evaluate(self, graphrecord)
     }
+
+    fn operand_count(&self) -> usize {
+        R::operand_count(self)
+    }
 }
@@ -6,17 +6,21 @@ use crate::{
         DeepClone, EvaluateBackward, EvaluateForward, EvaluateForwardGrouped, GroupedIterator,
         edges::EdgeOperand,
         group_by::{GroupBy, GroupOperand, GroupedOperand, PartitionGroups, Ungroup},
-        nodes::{NodeIndexOperand, NodeIndicesOperand, NodeIndicesOperandContext},
+        nodes::{self, NodeIndexOperand, NodeIndicesOperand, NodeIndicesOperandContext},
         wrapper::Wrapper,
     },
     prelude::GraphRecordAttribute,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum NodeOperandContext {
     Discriminator(<NodeOperand as GroupBy>::Discriminator),
     Nodes(Box<GroupOperand<NodeOperand>>),
     Edges(Box<GroupOperand<EdgeOperand>>),
+    Subgroups {
+        operand: Box<GroupOperand<NodeOperand>>,
+        discriminator: NodeOperandGroupDiscriminator,
+    },
 }
 
 impl DeepClone for NodeOperandContext {
@@ -25,6 +29,13 @@ impl DeepClone for NodeOperandContext {
             Self::Discriminator(discriminator) => Self::Discriminator(discriminator.clone()),
             Self::Nodes(operand) => Self::Nodes(operand.deep_clone()),
             Self::Edges(operand) => Self::Edges(operand.deep_clone()),
+            Self::Subgroups {
+                operand,
+                discriminator,
+            } => Self::Subgroups {
+                operand: operand.deep_clone(),
+                discriminator: discriminator.deep_clone(),
+            },
         }
     }
 }
@@ -51,7 +62,28 @@ impl GroupedOperand for NodeOperand {
     type Context = NodeOperandContext;
 }
 
-#[derive(Debug, Clone)]
+impl Wrapper<GroupOperand<NodeOperand>> {
+    /// Partitions every group further by `discriminator`. The subgroups are
+    /// keyed by a tuple key of the outer and the inner key.
+    #[must_use]
+    pub fn group_by(&self, discriminator: NodeOperandGroupDiscriminator) -> Self {
+        let group_operand = self.0.read().deep_clone();
+
+        let operand = Wrapper::<NodeOperand>::new(Some(nodes::NodeOperandContext::GroupBy {
+            operand: Box::new(group_operand.operand.0.read().deep_clone()),
+        }));
+
+        Self::new(
+            NodeOperandContext::Subgroups {
+                operand: Box::new(group_operand),
+                discriminator,
+            },
+            operand,
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum NodeOperandGroupDiscriminator {
     Attribute(GraphRecordAttribute),
 }
@@ -100,7 +132,7 @@ impl<'a> EvaluateBackward<'a> for GroupOperand<NodeIndicesOperand> {
         let partitions = self.context.evaluate_backward(graphrecord)?;
 
         let indices = Box::new(partitions.map(|(key, partition)| {
-            let reduced_partition = self.operand.reduce_input(partition)?;
+            let reduced_partition = self.operand.reduce_input(graphrecord, partition)?;
 
             Ok((key, reduced_partition))
         }))
@@ -140,7 +172,7 @@ impl<'a> EvaluateBackward<'a> for GroupOperand<NodeIndexOperand> {
 
         let indices: Vec<_> = partitions
             .map(|(key, partition)| {
-                let reduced_partition = self.operand.reduce_input(partition)?;
+                let reduced_partition = self.operand.reduce_input(graphrecord, partition)?;
 
                 Ok((key, reduced_partition))
             })
@@ -17,7 +17,7 @@ pub use operand::{
 pub use operation::{EdgeDirection, NodeOperation};
 use std::fmt::Display;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum NodeOperandContext {
     Neighbors {
         operand: Box<NodeOperand>,
@@ -54,7 +54,7 @@ impl DeepClone for NodeOperandContext {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum NodeIndicesOperandContext {
     NodeOperand(NodeOperand),
     NodeIndexGroupByOperand(GroupOperand<NodeIndexOperand>),
@@ -100,7 +100,7 @@ impl<'a> EvaluateBackward<'a> for NodeIndicesOperandContext {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SingleKind {
     Max,
     Min,
@@ -109,7 +109,7 @@ pub enum SingleKind {
     Random,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SingleComparisonKind {
     GreaterThan,
     GreaterThanOrEqualTo,
@@ -122,13 +122,13 @@ pub enum SingleComparisonKind {
     Contains,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum MultipleComparisonKind {
     IsIn,
     IsNotIn,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum BinaryArithmeticKind {
     Add,
     Sub,
@@ -149,7 +149,7 @@ impl Display for BinaryArithmeticKind {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum UnaryArithmeticKind {
     Abs,
     Trim,
@@ -8,22 +8,26 @@ use crate::{
     errors::GraphRecordResult,
     graphrecord::{
         GraphRecordAttribute, Group, NodeIndex,
+        datatypes::GeoPoint,
+        provenance::ProvenanceTag,
         querying::{
             BoxedIterator, DeepClone, EvaluateBackward, EvaluateForward, EvaluateForwardGrouped,
-            GroupedIterator, ReduceInput, RootOperand,
+            GroupedIterator, ReduceInput, RootOperand, SubqueryCache,
             attributes::{AttributesTreeContext, AttributesTreeOperand},
             edges::{self, EdgeOperand},
-            group_by::{GroupKey, GroupOperand, PartitionGroups},
+            group_by::{GroupKey, GroupOperand, PartitionGroups, partition_subgroups},
+            hash_unordered,
             nodes::{
                 NodeIndicesOperandContext,
                 group_by::{self, NodeOperandGroupDiscriminator},
             },
             operand_traits::{
-                Abs, Add, Attribute, Attributes, Contains, Count, Edges, EitherOr, EndsWith,
-                EqualTo, Exclude, GreaterThan, GreaterThanOrEqualTo, HasAttribute, InGroup, Index,
-                IsIn, IsInt, IsMax, IsMin, IsNotIn, IsString, LessThan, LessThanOrEqualTo,
-                Lowercase, Max, Min, Mod, Mul, Neighbors, NotEqualTo, Pow, Random, Slice,
-                StartsWith, Sub, Sum, Trim, TrimEnd, TrimStart, Uppercase,
+                Abs, Add, AllOf, Attribute, Attributes, Contains, Count, Degree, Edges, EitherOr,
+                EndsWith, EqualTo, Exclude, GreaterThan, GreaterThanOrEqualTo, HasAttribute,
+                InGroup, Index, IsIn, IsInt, IsMax, IsMin, IsNotIn, IsString, LessThan,
+                LessThanOrEqualTo, Limit, Lowercase, Max, Min, Mod, Mul, Neighbors, NoneOf,
+                NotEqualTo, Pow, Random, Skip, Slice, Sort, StartsWith, Sub, Sum, Trim, TrimEnd,
+                TrimStart, Uppercase,
             },
             values::{self, MultipleValuesWithIndexOperand},
             wrapper::{CardinalityWrapper, Wrapper},
@@ -31,9 +35,15 @@ use crate::{
     },
     prelude::GraphRecordValue,
 };
-use std::{collections::HashSet, fmt::Debug};
+use graphrecords_utils::aliases::GrHashMap;
+use std::{
+    collections::HashSet,
+    fmt::Debug,
+    hash::{Hash, Hasher},
+    mem,
+};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct NodeOperand {
     context: Option<NodeOperandContext>,
     operations: Vec<NodeOperation>,
@@ -80,55 +90,19 @@ impl RootOperand for NodeOperand {
         &self,
         graphrecord: &'a GraphRecord,
     ) -> GraphRecordResult<BoxedIterator<'a, &'a Self::Index>> {
-        let node_indices: BoxedIterator<_> = match &self.context {
-            Some(NodeOperandContext::Neighbors { operand, direction }) => {
-                let node_indices = operand.evaluate_backward(graphrecord)?;
-
-                match direction {
-                    EdgeDirection::Incoming => Box::new(node_indices.flat_map(move |node_index| {
-                        graphrecord
-                            .neighbors_incoming(node_index)
-                            .expect("Node must exist")
-                    })),
-                    EdgeDirection::Outgoing => Box::new(node_indices.flat_map(move |node_index| {
-                        graphrecord
-                            .neighbors_outgoing(node_index)
-                            .expect("Node must exist")
-                    })),
-                    EdgeDirection::Both => Box::new(node_indices.flat_map(move |node_index| {
-                        graphrecord
-                            .neighbors_undirected(node_index)
-                            .expect("Node must exist")
-                    })),
-                }
-            }
-            Some(NodeOperandContext::SourceNode { operand }) => {
-                let edge_indices = operand.evaluate_backward(graphrecord)?;
-
-                Box::new(edge_indices.map(move |edge_index| {
-                    graphrecord
-                        .edge_endpoints(edge_index)
-                        .expect("Node must exist")
-                        .0
-                }))
-            }
-            Some(NodeOperandContext::TargetNode { operand }) => {
-                let edge_indices = operand.evaluate_backward(graphrecord)?;
-
-                Box::new(edge_indices.map(move |edge_index| {
-                    graphrecord
-                        .edge_endpoints(edge_index)
-                        .expect("Node must exist")
-                        .1
-                }))
-            }
-            Some(NodeOperandContext::GroupBy { operand }) => {
-                operand.evaluate_backward(graphrecord)?
-            }
-            None => Box::new(graphrecord.node_indices()),
-        };
-
-        self.evaluate_forward(graphrecord, node_indices)
+        SubqueryCache::evaluate(
+            graphrecord,
+            &self.context,
+            &self.operations,
+            || self.evaluate_context(graphrecord),
+            |node_indices, operations| {
+                operations
+                    .iter()
+                    .try_fold(node_indices, |node_indices, operation| {
+                        operation.evaluate(graphrecord, node_indices)
+                    })
+            },
+        )
     }
 
     fn _evaluate_backward_grouped_operand<'a>(
@@ -141,6 +115,17 @@ impl RootOperand for NodeOperand {
 
                 Ok(Self::partition(graphrecord, node_indices, discriminator))
             }
+            group_by::NodeOperandContext::Subgroups {
+                operand,
+                discriminator,
+            } => {
+                let partitions = operand.evaluate_backward(graphrecord)?;
+
+                group_operand.operand.evaluate_forward_grouped(
+                    graphrecord,
+                    partition_subgroups::<Self>(graphrecord, partitions, discriminator.clone()),
+                )
+            }
             group_by::NodeOperandContext::Nodes(operand) => {
                 let partitions = operand.evaluate_backward(graphrecord)?;
 
@@ -289,6 +274,88 @@ impl RootOperand for NodeOperand {
     ) -> BoxedIterator<'a, &'a Self::Index> {
         Box::new(node_indices.flat_map(|(_, node_indices)| node_indices))
     }
+
+    fn _validate_attribute(
+        &self,
+        graphrecord: &GraphRecord,
+        attribute: &GraphRecordAttribute,
+    ) -> GraphRecordResult<()> {
+        if !graphrecord.strict_queries() {
+            return Ok(());
+        }
+
+        let groups: Vec<_> = self
+            .operations
+            .iter()
+            .filter_map(|operation| match operation {
+                NodeOperation::InGroup { group } => Some(group),
+                _ => None,
+            })
+            .flat_map(|group| match group {
+                CardinalityWrapper::Single(group) => vec![group.clone()],
+                CardinalityWrapper::Multiple(groups, _) => groups.clone(),
+            })
+            .collect();
+
+        Ok(graphrecord.get_schema().validate_node_attribute(
+            attribute,
+            (!groups.is_empty()).then_some(groups.as_slice()),
+        )?)
+    }
+
+    fn _get_provenance<'a>(
+        graphrecord: &'a GraphRecord,
+        index: &Self::Index,
+        attribute: &GraphRecordAttribute,
+    ) -> Option<&'a ProvenanceTag> {
+        graphrecord
+            .node_attribute_provenance(index, attribute)
+            .ok()
+            .flatten()
+    }
+
+    fn _text_index_matches(
+        graphrecord: &GraphRecord,
+        index: &Self::Index,
+        attribute: &GraphRecordAttribute,
+        text: &str,
+        tokens: &[String],
+    ) -> Option<bool> {
+        graphrecord.text_index_matches(index, attribute, text, tokens)
+    }
+
+    fn _geo_index_within_radius(
+        graphrecord: &GraphRecord,
+        attribute: &GraphRecordAttribute,
+        center: &GeoPoint,
+        radius_km: f64,
+    ) -> Option<GrHashMap<Self::Index, GeoPoint>> {
+        graphrecord.geo_index_within_radius(attribute, center, radius_km)
+    }
+
+    fn _get_degrees<'a>(
+        graphrecord: &'a GraphRecord,
+        node_indices: BoxedIterator<'a, &'a Self::Index>,
+        direction: &EdgeDirection,
+    ) -> BoxedIterator<'a, (&'a Self::Index, GraphRecordValue)> {
+        let direction = direction.clone();
+
+        Box::new(node_indices.map(move |node_index| {
+            let degree = match direction {
+                EdgeDirection::Incoming => graphrecord
+                    .incoming_edges(node_index)
+                    .expect("Node must exist")
+                    .count(),
+                EdgeDirection::Outgoing => graphrecord
+                    .outgoing_edges(node_index)
+                    .expect("Node must exist")
+                    .count(),
+                EdgeDirection::Both => graphrecord.degree(node_index).expect("Node must exist"),
+            };
+
+            (node_index, GraphRecordValue::Int(degree as i64))
+        }))
+    }
 }
 
 impl Attribute for NodeOperand {
@@ -307,6 +374,22 @@ impl Attribute for NodeOperand {
     }
 }
 
+impl Degree for NodeOperand {
+    type ReturnOperand = MultipleValuesWithIndexOperand<Self>;
+
+    fn degree(&mut self, direction: EdgeDirection) -> Wrapper<Self::ReturnOperand> {
+        let operand = Wrapper::<Self::ReturnOperand>::new(
+            values::MultipleValuesWithIndexContext::Degree((self.deep_clone(), direction)),
+        );
+
+        self.operations.push(NodeOperation::Values {
+            operand: operand.clone(),
+        });
+
+        operand
+    }
+}
+
 impl Attributes for NodeOperand {
     type ReturnOperand = AttributesTreeOperand<Self>;
 
@@ -411,6 +494,52 @@ impl EitherOr for NodeOperand {
     }
 }
 
+impl AllOf for NodeOperand {
+    type QueryOperand = Self;
+
+    fn all_of<I, Q>(&mut self, queries: I)
+    where
+        I: IntoIterator<Item = Q>,
+        Q: FnOnce(&mut Wrapper<Self::QueryOperand>),
+    {
+        let operands = queries
+            .into_iter()
+            .map(|query| {
+                let mut operand = Wrapper::<Self::QueryOperand>::new(self.context.clone());
+
+                query(&mut operand);
+
+                operand
+            })
+            .collect();
+
+        self.operations.push(NodeOperation::AllOf { operands });
+    }
+}
+
+impl NoneOf for NodeOperand {
+    type QueryOperand = Self;
+
+    fn none_of<I, Q>(&mut self, queries: I)
+    where
+        I: IntoIterator<Item = Q>,
+        Q: FnOnce(&mut Wrapper<Self::QueryOperand>),
+    {
+        let operands = queries
+            .into_iter()
+            .map(|query| {
+                let mut operand = Wrapper::<Self::QueryOperand>::new(self.context.clone());
+
+                query(&mut operand);
+
+                operand
+            })
+            .collect();
+
+        self.operations.push(NodeOperation::NoneOf { operands });
+    }
+}
+
 impl Exclude for NodeOperand {
     type QueryOperand = Self;
 
@@ -427,6 +556,61 @@ impl Exclude for NodeOperand {
 }
 
 impl NodeOperand {
+    fn evaluate_context<'a>(
+        &self,
+        graphrecord: &'a GraphRecord,
+    ) -> GraphRecordResult<BoxedIterator<'a, &'a NodeIndex>> {
+        let node_indices: BoxedIterator<_> = match &self.context {
+            Some(NodeOperandContext::Neighbors { operand, direction }) => {
+                let node_indices = operand.evaluate_backward(graphrecord)?;
+
+                match direction {
+                    EdgeDirection::Incoming => Box::new(node_indices.flat_map(move |node_index| {
+                        graphrecord
+                            .neighbors_incoming(node_index)
+                            .expect("Node must exist")
+                    })),
+                    EdgeDirection::Outgoing => Box::new(node_indices.flat_map(move |node_index| {
+                        graphrecord
+                            .neighbors_outgoing(node_index)
+                            .expect("Node must exist")
+                    })),
+                    EdgeDirection::Both => Box::new(node_indices.flat_map(move |node_index| {
+                        graphrecord
+                            .neighbors_undirected(node_index)
+                            .expect("Node must exist")
+                    })),
+                }
+            }
+            Some(NodeOperandContext::SourceNode { operand }) => {
+                let edge_indices = operand.evaluate_backward(graphrecord)?;
+
+                Box::new(edge_indices.map(move |edge_index| {
+                    graphrecord
+                        .edge_endpoints(edge_index)
+                        .expect("Node must exist")
+                        .0
+                }))
+            }
+            Some(NodeOperandContext::TargetNode { operand }) => {
+                let edge_indices = operand.evaluate_backward(graphrecord)?;
+
+                Box::new(edge_indices.map(move |edge_index| {
+                    graphrecord
+                        .edge_endpoints(edge_index)
+                        .expect("Node must exist")
+                        .1
+                }))
+            }
+            Some(NodeOperandContext::GroupBy { operand }) => {
+                operand.evaluate_backward(graphrecord)?
+            }
+            None => Box::new(graphrecord.node_indices()),
+        };
+
+        Ok(node_indices)
+    }
+
     pub(crate) const fn new(context: Option<NodeOperandContext>) -> Self {
         Self {
             context,
@@ -441,7 +625,7 @@ impl Wrapper<NodeOperand> {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum NodeIndexComparisonOperand {
     Operand(NodeIndexOperand),
     Index(NodeIndex),
@@ -486,12 +670,23 @@ impl NodeIndexComparisonOperand {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum NodeIndicesComparisonOperand {
     Operand(NodeIndicesOperand),
     Indices(HashSet<NodeIndex>),
 }
 
+impl Hash for NodeIndicesComparisonOperand {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+
+        match self {
+            Self::Operand(operand) => operand.hash(state),
+            Self::Indices(indices) => hash_unordered(indices.iter(), state),
+        }
+    }
+}
+
 impl DeepClone for NodeIndicesComparisonOperand {
     fn deep_clone(&self) -> Self {
         match self {
@@ -543,7 +738,7 @@ impl NodeIndicesComparisonOperand {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct NodeIndicesOperand {
     context: NodeIndicesOperandContext,
     operations: Vec<NodeIndicesOperation>,
@@ -610,6 +805,7 @@ impl<'a> ReduceInput<'a> for NodeIndicesOperand {
     #[inline]
     fn reduce_input(
         &self,
+        _graphrecord: &'a GraphRecord,
         node_indices: <Self::Context as EvaluateBackward<'a>>::ReturnValue,
     ) -> GraphRecordResult<<Self as EvaluateForward<'a>>::InputValue> {
         Ok(Box::new(node_indices.cloned()))
@@ -968,6 +1164,25 @@ impl IsMin for NodeIndicesOperand {
     }
 }
 
+impl Sort for NodeIndicesOperand {
+    fn sort(&mut self, descending: bool) {
+        self.operations
+            .push(NodeIndicesOperation::Sort { descending });
+    }
+}
+
+impl Skip for NodeIndicesOperand {
+    fn skip(&mut self, count: usize) {
+        self.operations.push(NodeIndicesOperation::Skip(count));
+    }
+}
+
+impl Limit for NodeIndicesOperand {
+    fn limit(&mut self, count: usize) {
+        self.operations.push(NodeIndicesOperation::Limit(count));
+    }
+}
+
 impl EitherOr for NodeIndicesOperand {
     type QueryOperand = Self;
 
@@ -1029,7 +1244,7 @@ impl Wrapper<NodeIndicesOperand> {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct NodeIndexOperand {
     context: NodeIndicesOperand,
     pub(crate) kind: SingleKind,
@@ -1086,7 +1301,7 @@ impl<'a> EvaluateBackward<'a> for NodeIndexOperand {
     ) -> GraphRecordResult<Self::ReturnValue> {
         let node_indices = self.context.evaluate_backward(graphrecord)?;
 
-        let node_index = self.reduce_input(node_indices)?;
+        let node_index = self.reduce_input(graphrecord, node_indices)?;
 
         self.evaluate_forward(graphrecord, node_index)
     }
@@ -1098,6 +1313,7 @@ impl<'a> ReduceInput<'a> for NodeIndexOperand {
     #[inline]
     fn reduce_input(
         &self,
+        graphrecord: &'a GraphRecord,
         node_indices: <Self::Context as EvaluateBackward<'a>>::ReturnValue,
     ) -> GraphRecordResult<<Self as EvaluateForward<'a>>::InputValue> {
         Ok(match self.kind {
@@ -1105,7 +1321,7 @@ impl<'a> ReduceInput<'a> for NodeIndexOperand {
             SingleKind::Min => NodeIndicesOperation::get_min(node_indices)?,
             SingleKind::Count => Some(NodeIndicesOperation::get_count(node_indices)),
             SingleKind::Sum => NodeIndicesOperation::get_sum(node_indices)?,
-            SingleKind::Random => NodeIndicesOperation::get_random(node_indices),
+            SingleKind::Random => NodeIndicesOperation::get_random(graphrecord, node_indices),
         })
     }
 }
@@ -16,6 +16,7 @@ use crate::{
         },
         querying::{
             BoxedIterator, DeepClone, EvaluateForward, EvaluateForwardGrouped, GroupedIterator,
+            QueryRng, RootOperand,
             attributes::AttributesTreeOperand,
             edges::EdgeOperand,
             group_by::{GroupOperand, PartitionGroups},
@@ -27,22 +28,20 @@ use crate::{
 };
 use graphrecords_utils::aliases::GrHashSet;
 use itertools::Itertools;
-use rand::{rng, seq::IteratorRandom};
 use roaring::RoaringBitmap;
 use std::{
     cmp::Ordering,
-    collections::HashSet,
     ops::{Add, Mul, Range, Sub},
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum EdgeDirection {
     Incoming,
     Outgoing,
     Both,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum NodeOperation {
     Values {
         operand: Wrapper<MultipleValuesWithIndexOperand<NodeOperand>>,
@@ -78,6 +77,12 @@ pub enum NodeOperation {
     Exclude {
         operand: Wrapper<NodeOperand>,
     },
+    AllOf {
+        operands: Vec<Wrapper<NodeOperand>>,
+    },
+    NoneOf {
+        operands: Vec<Wrapper<NodeOperand>>,
+    },
 
     GroupBy {
         operand: Wrapper<GroupOperand<NodeOperand>>,
@@ -120,6 +125,12 @@ impl DeepClone for NodeOperation {
             Self::Exclude { operand } => Self::Exclude {
                 operand: operand.deep_clone(),
             },
+            Self::AllOf { operands } => Self::AllOf {
+                operands: operands.deep_clone(),
+            },
+            Self::NoneOf { operands } => Self::NoneOf {
+                operands: operands.deep_clone(),
+            },
             Self::GroupBy { operand } => Self::GroupBy {
                 operand: operand.deep_clone(),
             },
@@ -171,19 +182,7 @@ impl NodeOperation {
                 drection.clone(),
             )?),
             Self::EitherOr { either, or } => {
-                let (node_indices_1, rest) = node_indices.tee();
-                let (node_indices_2, node_indices_3) = rest.tee();
-
-                let either_set: HashSet<&NodeIndex> = either
-                    .evaluate_forward(graphrecord, Box::new(node_indices_1))?
-                    .collect();
-                let or_set: HashSet<&NodeIndex> = or
-                    .evaluate_forward(graphrecord, Box::new(node_indices_2))?
-                    .collect();
-
-                Box::new(node_indices_3.filter(move |node_index| {
-                    either_set.contains(node_index) || or_set.contains(node_index)
-                }))
+                Self::evaluate_either_or(graphrecord, node_indices, either, or)?
             }
             Self::Exclude { operand } => {
                 let (node_indices_1, node_indices_2) = node_indices.tee();
@@ -194,6 +193,10 @@ impl NodeOperation {
 
                 Box::new(node_indices_2.filter(move |node_index| !result.contains(node_index)))
             }
+            Self::AllOf { operands } => Self::evaluate_all_of(graphrecord, node_indices, operands)?,
+            Self::NoneOf { operands } => {
+                Self::evaluate_none_of(graphrecord, node_indices, operands)?
+            }
             Self::GroupBy { operand } => {
                 Box::new(Self::evaluate_group_by(graphrecord, node_indices, operand)?)
             }
@@ -227,15 +230,24 @@ impl NodeOperation {
     where
         T: Iterator<Item = &'a NodeIndex> + 'a,
     {
-        let MultipleValuesWithIndexContext::Operand((_, ref attribute)) = operand.0.read().context
-        else {
-            unreachable!()
-        };
+        let values: BoxedIterator<_> = match operand.0.read().context {
+            MultipleValuesWithIndexContext::Operand((ref context, ref attribute)) => {
+                context._validate_attribute(graphrecord, attribute)?;
 
-        let values = Self::get_values(graphrecord, node_indices, attribute.clone());
+                Box::new(Self::get_values(
+                    graphrecord,
+                    node_indices,
+                    attribute.clone(),
+                ))
+            }
+            MultipleValuesWithIndexContext::Degree((_, ref direction)) => {
+                NodeOperand::_get_degrees(graphrecord, Box::new(node_indices), direction)
+            }
+            _ => unreachable!(),
+        };
 
         Ok(operand
-            .evaluate_forward(graphrecord, Box::new(values))?
+            .evaluate_forward(graphrecord, values)?
             .map(|value| value.0))
     }
 
@@ -548,6 +560,12 @@ impl NodeOperation {
             Self::Exclude { operand } => {
                 Self::evaluate_exclude_grouped(graphrecord, node_indices, operand)?
             }
+            Self::AllOf { operands } => {
+                Self::evaluate_all_of_grouped(graphrecord, node_indices, operands)?
+            }
+            Self::NoneOf { operands } => {
+                Self::evaluate_none_of_grouped(graphrecord, node_indices, operands)?
+            }
             Self::GroupBy { operand: _ } => unreachable!(),
         })
     }
@@ -558,22 +576,32 @@ impl NodeOperation {
         node_indices: GroupedIterator<'a, BoxedIterator<'a, &'a NodeIndex>>,
         operand: &Wrapper<MultipleValuesWithIndexOperand<NodeOperand>>,
     ) -> GraphRecordResult<GroupedIterator<'a, BoxedIterator<'a, &'a NodeIndex>>> {
-        let MultipleValuesWithIndexContext::Operand((_, ref attribute)) = operand.0.read().context
-        else {
-            unreachable!()
-        };
+        let values: Vec<_> = match operand.0.read().context {
+            MultipleValuesWithIndexContext::Operand((ref context, ref attribute)) => {
+                context._validate_attribute(graphrecord, attribute)?;
 
-        let values: Vec<_> = node_indices
-            .map(|(key, node_indices)| {
-                (
+                node_indices
+                    .map(|(key, node_indices)| {
+                        (
+                            key,
+                            Box::new(Self::get_values(graphrecord, node_indices, attribute.clone()))
+                                as <MultipleValuesWithIndexOperand<NodeOperand> as EvaluateForward<
+                                    'a,
+                                >>::InputValue,
+                        )
+                    })
+                    .collect()
+            }
+            MultipleValuesWithIndexContext::Degree((_, ref direction)) => node_indices
+                .map(|(key, node_indices)| {
+                    (
                         key,
-                        Box::new(Self::get_values(graphrecord, node_indices, attribute.clone()))
-                            as <MultipleValuesWithIndexOperand<NodeOperand> as EvaluateForward<
-                                'a,
-                            >>::InputValue,
+                        NodeOperand::_get_degrees(graphrecord, node_indices, direction),
                     )
-            })
-            .collect();
+                })
+                .collect(),
+            _ => unreachable!(),
+        };
 
         Ok(Box::new(
             operand
@@ -874,9 +902,113 @@ impl NodeOperation {
 
         Ok(Box::new(node_indices))
     }
+
+    #[inline]
+    fn evaluate_either_or<'a>(
+        graphrecord: &'a GraphRecord,
+        node_indices: impl Iterator<Item = &'a NodeIndex>,
+        either: &Wrapper<NodeOperand>,
+        or: &Wrapper<NodeOperand>,
+    ) -> GraphRecordResult<BoxedIterator<'a, &'a NodeIndex>> {
+        let node_indices: Vec<_> = node_indices.collect();
+
+        let either_set: GrHashSet<_> = either
+            .evaluate_forward(graphrecord, Box::new(node_indices.clone().into_iter()))?
+            .collect();
+
+        // Indices matched by `either` do not need to be checked by `or`
+        #[expect(
+            clippy::needless_collect,
+            reason = "the boxed iterator cannot borrow `either_set`"
+        )]
+        let remaining: Vec<_> = node_indices
+            .iter()
+            .filter(|index| !either_set.contains(*index))
+            .copied()
+            .collect();
+        let or_set: GrHashSet<_> = or
+            .evaluate_forward(graphrecord, Box::new(remaining.into_iter()))?
+            .collect();
+
+        Ok(Box::new(node_indices.into_iter().filter(move |index| {
+            either_set.contains(index) || or_set.contains(index)
+        })))
+    }
+
+    #[inline]
+    fn evaluate_all_of<'a>(
+        graphrecord: &'a GraphRecord,
+        node_indices: impl Iterator<Item = &'a NodeIndex>,
+        operands: &[Wrapper<NodeOperand>],
+    ) -> GraphRecordResult<BoxedIterator<'a, &'a NodeIndex>> {
+        let mut node_indices: Vec<_> = node_indices.collect();
+
+        // Every operand only checks the indices all previous operands matched
+        for operand in operands {
+            if node_indices.is_empty() {
+                break;
+            }
+
+            node_indices = operand
+                .evaluate_forward(graphrecord, Box::new(node_indices.into_iter()))?
+                .collect();
+        }
+
+        Ok(Box::new(node_indices.into_iter()))
+    }
+
+    #[inline]
+    fn evaluate_none_of<'a>(
+        graphrecord: &'a GraphRecord,
+        node_indices: impl Iterator<Item = &'a NodeIndex>,
+        operands: &[Wrapper<NodeOperand>],
+    ) -> GraphRecordResult<BoxedIterator<'a, &'a NodeIndex>> {
+        let mut node_indices: Vec<_> = node_indices.collect();
+
+        // Every operand only checks the indices no previous operand matched
+        for operand in operands {
+            if node_indices.is_empty() {
+                break;
+            }
+
+            let matched: GrHashSet<_> = operand
+                .evaluate_forward(graphrecord, Box::new(node_indices.clone().into_iter()))?
+                .collect();
+
+            node_indices.retain(|index| !matched.contains(index));
+        }
+
+        Ok(Box::new(node_indices.into_iter()))
+    }
+
+    #[inline]
+    fn evaluate_all_of_grouped<'a>(
+        graphrecord: &'a GraphRecord,
+        node_indices: GroupedIterator<'a, BoxedIterator<'a, &'a NodeIndex>>,
+        operands: &[Wrapper<NodeOperand>],
+    ) -> GraphRecordResult<GroupedIterator<'a, BoxedIterator<'a, &'a NodeIndex>>> {
+        operands
+            .iter()
+            .try_fold(node_indices, |node_indices, operand| {
+                operand.evaluate_forward_grouped(graphrecord, node_indices)
+            })
+    }
+
+    #[inline]
+    fn evaluate_none_of_grouped<'a>(
+        graphrecord: &'a GraphRecord,
+        node_indices: GroupedIterator<'a, BoxedIterator<'a, &'a NodeIndex>>,
+        operands: &[Wrapper<NodeOperand>],
+    ) -> GraphRecordResult<GroupedIterator<'a, BoxedIterator<'a, &'a NodeIndex>>> {
+        operands
+            .iter()
+            .try_fold(node_indices, |node_indices, operand| {
+                Self::evaluate_exclude_grouped(graphrecord, node_indices, operand)
+            })
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum NodeIndicesOperation {
     NodeIndexOperation {
         operand: Wrapper<NodeIndexOperand>,
@@ -905,6 +1037,12 @@ pub enum NodeIndicesOperation {
     IsMax,
     IsMin,
 
+    Sort {
+        descending: bool,
+    },
+    Skip(usize),
+    Limit(usize),
+
     EitherOr {
         either: Wrapper<NodeIndicesOperand>,
         or: Wrapper<NodeIndicesOperand>,
@@ -948,6 +1086,11 @@ impl DeepClone for NodeIndicesOperation {
             Self::IsInt => Self::IsInt,
             Self::IsMax => Self::IsMax,
             Self::IsMin => Self::IsMin,
+            Self::Sort { descending } => Self::Sort {
+                descending: *descending,
+            },
+            Self::Skip(count) => Self::Skip(*count),
+            Self::Limit(count) => Self::Limit(*count),
             Self::EitherOr { either, or } => Self::EitherOr {
                 either: either.deep_clone(),
                 or: or.deep_clone(),
@@ -994,6 +1137,9 @@ impl NodeIndicesOperation {
             Self::IsInt => Box::new(Self::evaluate_is_int(indices)),
             Self::IsMax => Self::evaluate_is_max(indices)?,
             Self::IsMin => Self::evaluate_is_min(indices)?,
+            Self::Sort { descending } => Box::new(Self::evaluate_sort(indices, *descending)),
+            Self::Skip(count) => Box::new(indices.skip(*count)),
+            Self::Limit(count) => Box::new(indices.take(*count)),
             Self::EitherOr { either, or } => {
                 Self::evaluate_either_or(graphrecord, indices, either, or)?
             }
@@ -1089,8 +1235,11 @@ impl NodeIndicesOperation {
     }
 
     #[inline]
-    pub(crate) fn get_random(indices: impl Iterator<Item = NodeIndex>) -> Option<NodeIndex> {
-        indices.choose(&mut rng())
+    pub(crate) fn get_random(
+        graphrecord: &GraphRecord,
+        indices: impl Iterator<Item = NodeIndex>,
+    ) -> Option<NodeIndex> {
+        QueryRng::new(graphrecord).choose(indices, NodeIndex::total_cmp)
     }
 
     #[inline]
@@ -1108,7 +1257,7 @@ impl NodeIndicesOperation {
             SingleKind::Min => Self::get_min(indices_1)?,
             SingleKind::Count => Some(Self::get_count(indices_1)),
             SingleKind::Sum => Self::get_sum(indices_1)?,
-            SingleKind::Random => Self::get_random(indices_1),
+            SingleKind::Random => Self::get_random(graphrecord, indices_1),
         };
 
         Ok(match operand.evaluate_forward(graphrecord, index)? {
@@ -1284,6 +1433,24 @@ impl NodeIndicesOperation {
         Ok(Box::new(indices_2.filter(move |index| *index == min_index)))
     }
 
+    #[inline]
+    fn evaluate_sort(
+        indices: impl Iterator<Item = NodeIndex>,
+        descending: bool,
+    ) -> impl Iterator<Item = NodeIndex> {
+        let mut indices: Vec<_> = indices.collect();
+
+        indices.sort_by(|index, other| {
+            if descending {
+                other.total_cmp(index)
+            } else {
+                index.total_cmp(other)
+            }
+        });
+
+        indices.into_iter()
+    }
+
     #[inline]
     fn evaluate_either_or<'a>(
         graphrecord: &'a GraphRecord,
@@ -1435,6 +1602,30 @@ impl NodeIndicesOperation {
                     .collect::<GraphRecordResult<Vec<_>>>()?
                     .into_iter(),
             ),
+            Self::Sort { descending } => {
+                let descending = *descending;
+
+                Box::new(node_indices.map(move |(key, node_indices)| {
+                    (
+                        key,
+                        Box::new(Self::evaluate_sort(node_indices, descending)) as BoxedIterator<_>,
+                    )
+                }))
+            }
+            Self::Skip(count) => {
+                let count = *count;
+
+                Box::new(node_indices.map(move |(key, node_indices)| {
+                    (key, Box::new(node_indices.skip(count)) as BoxedIterator<_>)
+                }))
+            }
+            Self::Limit(count) => {
+                let count = *count;
+
+                Box::new(node_indices.map(move |(key, node_indices)| {
+                    (key, Box::new(node_indices.take(count)) as BoxedIterator<_>)
+                }))
+            }
             Self::EitherOr { either, or } => {
                 Self::evaluate_either_or_grouped(graphrecord, node_indices, either, or)?
             }
@@ -1483,7 +1674,7 @@ impl NodeIndicesOperation {
                         SingleKind::Min => Self::get_min(node_indices)?,
                         SingleKind::Count => Some(Self::get_count(node_indices)),
                         SingleKind::Sum => Self::get_sum(node_indices)?,
-                        SingleKind::Random => Self::get_random(node_indices),
+                        SingleKind::Random => Self::get_random(graphrecord, node_indices),
                     },
                 ))
             })
@@ -1571,7 +1762,7 @@ impl NodeIndicesOperation {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum NodeIndexOperation {
     NodeIndexComparisonOperation {
         operand: NodeIndexComparisonOperand,
@@ -1,8 +1,13 @@
-use crate::graphrecord::querying::{
-    DeepClone,
-    group_by::{GroupOperand, GroupedOperand},
-    wrapper::Wrapper,
+use crate::graphrecord::{
+    GraphRecordAttribute,
+    querying::{
+        DeepClone,
+        group_by::{GroupOperand, GroupedOperand},
+        values::RollingAggregation,
+        wrapper::Wrapper,
+    },
 };
+use chrono::TimeDelta;
 
 pub trait Max {
     type ReturnOperand;
@@ -60,6 +65,121 @@ where
     }
 }
 
+pub trait FirstBy {
+    type ReturnOperand;
+
+    fn first_by(
+        &mut self,
+        order_by_attribute: GraphRecordAttribute,
+    ) -> Wrapper<Self::ReturnOperand>;
+}
+
+impl<O: FirstBy> Wrapper<O> {
+    /// Selects the value whose index has the smallest value of `order_by_attribute`,
+    /// e.g. the earliest measurement when ordering by a timestamp.
+    #[must_use]
+    pub fn first_by(
+        &self,
+        order_by_attribute: impl Into<GraphRecordAttribute>,
+    ) -> Wrapper<O::ReturnOperand> {
+        self.0.write().first_by(order_by_attribute.into())
+    }
+}
+
+impl<O: GroupedOperand + FirstBy> FirstBy for GroupOperand<O>
+where
+    Self: DeepClone,
+    O::ReturnOperand: GroupedOperand,
+    <O::ReturnOperand as GroupedOperand>::Context: From<Self>,
+{
+    type ReturnOperand = GroupOperand<O::ReturnOperand>;
+
+    fn first_by(
+        &mut self,
+        order_by_attribute: GraphRecordAttribute,
+    ) -> Wrapper<Self::ReturnOperand> {
+        let operand = self.operand.first_by(order_by_attribute);
+
+        Wrapper::<GroupOperand<O::ReturnOperand>>::new(self.deep_clone().into(), operand)
+    }
+}
+
+pub trait LastBy {
+    type ReturnOperand;
+
+    fn last_by(&mut self, order_by_attribute: GraphRecordAttribute)
+    -> Wrapper<Self::ReturnOperand>;
+}
+
+impl<O: LastBy> Wrapper<O> {
+    /// Selects the value whose index has the largest value of `order_by_attribute`,
+    /// e.g. the most recent measurement when ordering by a timestamp.
+    #[must_use]
+    pub fn last_by(
+        &self,
+        order_by_attribute: impl Into<GraphRecordAttribute>,
+    ) -> Wrapper<O::ReturnOperand> {
+        self.0.write().last_by(order_by_attribute.into())
+    }
+}
+
+impl<O: GroupedOperand + LastBy> LastBy for GroupOperand<O>
+where
+    Self: DeepClone,
+    O::ReturnOperand: GroupedOperand,
+    <O::ReturnOperand as GroupedOperand>::Context: From<Self>,
+{
+    type ReturnOperand = GroupOperand<O::ReturnOperand>;
+
+    fn last_by(
+        &mut self,
+        order_by_attribute: GraphRecordAttribute,
+    ) -> Wrapper<Self::ReturnOperand> {
+        let operand = self.operand.last_by(order_by_attribute);
+
+        Wrapper::<GroupOperand<O::ReturnOperand>>::new(self.deep_clone().into(), operand)
+    }
+}
+
+pub trait RandomWeighted {
+    type ReturnOperand;
+
+    fn random_weighted(
+        &mut self,
+        weight_attribute: GraphRecordAttribute,
+    ) -> Wrapper<Self::ReturnOperand>;
+}
+
+impl<O: RandomWeighted> Wrapper<O> {
+    /// Selects a random value with a probability proportional to the value of
+    /// `weight_attribute` of its index, e.g. for importance sampling of edges.
+    #[must_use]
+    pub fn random_weighted(
+        &self,
+        weight_attribute: impl Into<GraphRecordAttribute>,
+    ) -> Wrapper<O::ReturnOperand> {
+        self.0.write().random_weighted(weight_attribute.into())
+    }
+}
+
+impl<O: GroupedOperand + RandomWeighted> RandomWeighted for GroupOperand<O>
+where
+    Self: DeepClone,
+    O::ReturnOperand: GroupedOperand,
+    <O::ReturnOperand as GroupedOperand>::Context: From<Self>,
+{
+    type ReturnOperand = GroupOperand<O::ReturnOperand>;
+
+    fn random_weighted(
+        &mut self,
+        weight_attribute: GraphRecordAttribute,
+    ) -> Wrapper<Self::ReturnOperand> {
+        let operand = self.operand.random_weighted(weight_attribute);
+
+        Wrapper::<GroupOperand<O::ReturnOperand>>::new(self.deep_clone().into(), operand)
+    }
+}
+
 pub trait Count {
     type ReturnOperand;
 
@@ -88,6 +208,60 @@ where
     }
 }
 
+pub trait CountDistinct {
+    type ReturnOperand;
+
+    fn count_distinct(&mut self) -> Wrapper<Self::ReturnOperand>;
+}
+
+impl<O: CountDistinct> Wrapper<O> {
+    #[must_use]
+    pub fn count_distinct(&self) -> Wrapper<O::ReturnOperand> {
+        self.0.write().count_distinct()
+    }
+}
+
+impl<O: GroupedOperand + CountDistinct> CountDistinct for GroupOperand<O>
+where
+    Self: DeepClone,
+    O::ReturnOperand: GroupedOperand,
+    <O::ReturnOperand as GroupedOperand>::Context: From<Self>,
+{
+    type ReturnOperand = GroupOperand<O::ReturnOperand>;
+
+    fn count_distinct(&mut self) -> Wrapper<Self::ReturnOperand> {
+        let operand = self.operand.count_distinct();
+
+        Wrapper::<GroupOperand<O::ReturnOperand>>::new(self.deep_clone().into(), operand)
+    }
+}
+
+pub trait ValueCounts {
+    type ReturnOperand;
+
+    fn value_counts(&mut self) -> Wrapper<Self::ReturnOperand>;
+}
+
+impl<O: ValueCounts> Wrapper<O> {
+    #[must_use]
+    pub fn value_counts(&self) -> Wrapper<O::ReturnOperand> {
+        self.0.write().value_counts()
+    }
+}
+
+pub trait Histogram {
+    type ReturnOperand;
+
+    fn histogram(&mut self, bins: usize) -> Wrapper<Self::ReturnOperand>;
+}
+
+impl<O: Histogram> Wrapper<O> {
+    #[must_use]
+    pub fn histogram(&self, bins: usize) -> Wrapper<O::ReturnOperand> {
+        self.0.write().histogram(bins)
+    }
+}
+
 pub trait Sum {
     type ReturnOperand;
 
@@ -172,6 +346,47 @@ where
     }
 }
 
+pub trait Quantile {
+    type ReturnOperand;
+
+    fn quantile(&mut self, quantile: f64) -> Wrapper<Self::ReturnOperand>;
+}
+
+impl<O: Quantile> Wrapper<O> {
+    #[must_use]
+    pub fn quantile(&self, quantile: f64) -> Wrapper<O::ReturnOperand> {
+        self.0.write().quantile(quantile)
+    }
+}
+
+impl<O: GroupedOperand + Quantile> Quantile for GroupOperand<O>
+where
+    Self: DeepClone,
+    O::ReturnOperand: GroupedOperand,
+    <O::ReturnOperand as GroupedOperand>::Context: From<Self>,
+{
+    type ReturnOperand = GroupOperand<O::ReturnOperand>;
+
+    fn quantile(&mut self, quantile: f64) -> Wrapper<Self::ReturnOperand> {
+        let operand = self.operand.quantile(quantile);
+
+        Wrapper::<GroupOperand<O::ReturnOperand>>::new(self.deep_clone().into(), operand)
+    }
+}
+
+pub trait Quantiles {
+    type ReturnOperand;
+
+    fn quantiles(&mut self, quantiles: Vec<f64>) -> Wrapper<Self::ReturnOperand>;
+}
+
+impl<O: Quantiles> Wrapper<O> {
+    #[must_use]
+    pub fn quantiles(&self, quantiles: Vec<f64>) -> Wrapper<O::ReturnOperand> {
+        self.0.write().quantiles(quantiles)
+    }
+}
+
 pub trait Mode {
     type ReturnOperand;
 
@@ -255,3 +470,85 @@ where
         Wrapper::<GroupOperand<O::ReturnOperand>>::new(self.deep_clone().into(), operand)
     }
 }
+
+pub trait Rolling {
+    fn rolling(
+        &mut self,
+        window: TimeDelta,
+        aggregation: RollingAggregation,
+        order_by_attribute: GraphRecordAttribute,
+    );
+}
+
+impl<O: Rolling> Wrapper<O> {
+    pub fn rolling(
+        &self,
+        window: TimeDelta,
+        aggregation: RollingAggregation,
+        order_by_attribute: impl Into<GraphRecordAttribute>,
+    ) {
+        self.0
+            .write()
+            .rolling(window, aggregation, order_by_attribute.into());
+    }
+}
+
+impl<O: GroupedOperand + Rolling> Rolling for GroupOperand<O> {
+    fn rolling(
+        &mut self,
+        window: TimeDelta,
+        aggregation: RollingAggregation,
+        order_by_attribute: GraphRecordAttribute,
+    ) {
+        self.operand
+            .rolling(window, aggregation, order_by_attribute);
+    }
+}
+
+pub trait Cumsum {
+    fn cumsum(&mut self, order_by_attribute: GraphRecordAttribute);
+}
+
+impl<O: Cumsum> Wrapper<O> {
+    pub fn cumsum(&self, order_by_attribute: impl Into<GraphRecordAttribute>) {
+        self.0.write().cumsum(order_by_attribute.into());
+    }
+}
+
+impl<O: GroupedOperand + Cumsum> Cumsum for GroupOperand<O> {
+    fn cumsum(&mut self, order_by_attribute: GraphRecordAttribute) {
+        self.operand.cumsum(order_by_attribute);
+    }
+}
+
+pub trait Lag {
+    fn lag(&mut self, n: usize, order_by_attribute: GraphRecordAttribute);
+}
+
+impl<O: Lag> Wrapper<O> {
+    pub fn lag(&self, n: usize, order_by_attribute: impl Into<GraphRecordAttribute>) {
+        self.0.write().lag(n, order_by_attribute.into());
+    }
+}
+
+impl<O: GroupedOperand + Lag> Lag for GroupOperand<O> {
+    fn lag(&mut self, n: usize, order_by_attribute: GraphRecordAttribute) {
+        self.operand.lag(n, order_by_attribute);
+    }
+}
+
+pub trait Lead {
+    fn lead(&mut self, n: usize, order_by_attribute: GraphRecordAttribute);
+}
+
+impl<O: Lead> Wrapper<O> {
+    pub fn lead(&self, n: usize, order_by_attribute: impl Into<GraphRecordAttribute>) {
+        self.0.write().lead(n, order_by_attribute.into());
+    }
+}
+
+impl<O: GroupedOperand + Lead> Lead for GroupOperand<O> {
+    fn lead(&mut self, n: usize, order_by_attribute: GraphRecordAttribute) {
+        self.operand.lead(n, order_by_attribute);
+    }
+}
@@ -138,3 +138,19 @@ impl<O: GroupedOperand + Abs> Abs for GroupOperand<O> {
         self.operand.abs();
     }
 }
+
+pub trait Apply {
+    fn apply(&mut self, udf: String);
+}
+
+impl<O: Apply> Wrapper<O> {
+    pub fn apply(&self, udf: impl Into<String>) {
+        self.0.write().apply(udf.into());
+    }
+}
+
+impl<O: GroupedOperand + Apply> Apply for GroupOperand<O> {
+    fn apply(&mut self, udf: String) {
+        self.operand.apply(udf);
+    }
+}
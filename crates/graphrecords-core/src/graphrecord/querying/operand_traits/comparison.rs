@@ -122,3 +122,43 @@ impl<O: GroupedOperand + NotEqualTo> NotEqualTo for GroupOperand<O> {
         self.operand.not_equal_to(value);
     }
 }
+
+pub trait IsClose {
+    type ComparisonOperand;
+
+    fn is_close<V: Into<Self::ComparisonOperand>>(&mut self, value: V, tolerance: f64);
+}
+
+impl<O: IsClose> Wrapper<O> {
+    pub fn is_close<V: Into<O::ComparisonOperand>>(&self, value: V, tolerance: f64) {
+        self.0.write().is_close(value, tolerance);
+    }
+}
+
+impl<O: GroupedOperand + IsClose> IsClose for GroupOperand<O> {
+    type ComparisonOperand = O::ComparisonOperand;
+
+    fn is_close<V: Into<Self::ComparisonOperand>>(&mut self, value: V, tolerance: f64) {
+        self.operand.is_close(value, tolerance);
+    }
+}
+
+pub trait WithinRadius {
+    type ComparisonOperand;
+
+    fn within_radius<V: Into<Self::ComparisonOperand>>(&mut self, point: V, radius_km: f64);
+}
+
+impl<O: WithinRadius> Wrapper<O> {
+    pub fn within_radius<V: Into<O::ComparisonOperand>>(&self, point: V, radius_km: f64) {
+        self.0.write().within_radius(point, radius_km);
+    }
+}
+
+impl<O: GroupedOperand + WithinRadius> WithinRadius for GroupOperand<O> {
+    type ComparisonOperand = O::ComparisonOperand;
+
+    fn within_radius<V: Into<Self::ComparisonOperand>>(&mut self, point: V, radius_km: f64) {
+        self.operand.within_radius(point, radius_km);
+    }
+}
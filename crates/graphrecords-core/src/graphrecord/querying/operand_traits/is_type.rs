@@ -83,6 +83,22 @@ impl<O: GroupedOperand + IsNull> IsNull for GroupOperand<O> {
     }
 }
 
+pub trait IsMissing {
+    fn is_missing(&mut self);
+}
+
+impl<O: IsMissing> Wrapper<O> {
+    pub fn is_missing(&self) {
+        self.0.write().is_missing();
+    }
+}
+
+impl<O: GroupedOperand + IsMissing> IsMissing for GroupOperand<O> {
+    fn is_missing(&mut self) {
+        self.operand.is_missing();
+    }
+}
+
 pub trait IsString {
     fn is_string(&mut self);
 }
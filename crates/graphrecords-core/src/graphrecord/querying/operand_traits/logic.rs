@@ -50,6 +50,16 @@ impl<O: Exclude> Wrapper<O> {
     {
         self.0.write().exclude(query);
     }
+
+    /// Keeps the entries not matched by `query`. The query can combine any
+    /// number of predicates, including nested `either_or` and `all_of`
+    /// groups, and is negated as a whole.
+    pub fn not_<Q>(&self, query: Q)
+    where
+        Q: FnOnce(&mut Wrapper<O::QueryOperand>),
+    {
+        self.exclude(query);
+    }
 }
 
 impl<O: GroupedOperand + Exclude> Exclude for GroupOperand<O> {
@@ -63,6 +73,110 @@ impl<O: GroupedOperand + Exclude> Exclude for GroupOperand<O> {
     }
 }
 
+pub trait AllOf {
+    type QueryOperand;
+
+    fn all_of<I, Q>(&mut self, queries: I)
+    where
+        I: IntoIterator<Item = Q>,
+        Q: FnOnce(&mut Wrapper<Self::QueryOperand>);
+}
+
+impl<O: AllOf> Wrapper<O> {
+    /// Keeps the entries matched by every query in `queries`. Every query only
+    /// checks the entries all previous queries matched, and the remaining
+    /// queries are skipped once no entries are left.
+    pub fn all_of<I, Q>(&self, queries: I)
+    where
+        I: IntoIterator<Item = Q>,
+        Q: FnOnce(&mut Wrapper<O::QueryOperand>),
+    {
+        self.0.write().all_of(queries);
+    }
+}
+
+impl<O: GroupedOperand + AllOf> AllOf for GroupOperand<O> {
+    type QueryOperand = O::QueryOperand;
+
+    fn all_of<I, Q>(&mut self, queries: I)
+    where
+        I: IntoIterator<Item = Q>,
+        Q: FnOnce(&mut Wrapper<Self::QueryOperand>),
+    {
+        self.operand.all_of(queries);
+    }
+}
+
+pub trait NoneOf {
+    type QueryOperand;
+
+    fn none_of<I, Q>(&mut self, queries: I)
+    where
+        I: IntoIterator<Item = Q>,
+        Q: FnOnce(&mut Wrapper<Self::QueryOperand>);
+}
+
+impl<O: NoneOf> Wrapper<O> {
+    /// Keeps the entries matched by none of the queries in `queries`. Every
+    /// query only checks the entries no previous query matched, and the
+    /// remaining queries are skipped once no entries are left.
+    pub fn none_of<I, Q>(&self, queries: I)
+    where
+        I: IntoIterator<Item = Q>,
+        Q: FnOnce(&mut Wrapper<O::QueryOperand>),
+    {
+        self.0.write().none_of(queries);
+    }
+}
+
+impl<O: GroupedOperand + NoneOf> NoneOf for GroupOperand<O> {
+    type QueryOperand = O::QueryOperand;
+
+    fn none_of<I, Q>(&mut self, queries: I)
+    where
+        I: IntoIterator<Item = Q>,
+        Q: FnOnce(&mut Wrapper<Self::QueryOperand>),
+    {
+        self.operand.none_of(queries);
+    }
+}
+
+pub trait When {
+    type QueryOperand;
+    type ValueOperand;
+
+    fn when<Q, T, E>(&mut self, query: Q, then: T, otherwise: E)
+    where
+        Q: FnOnce(&mut Wrapper<Self::QueryOperand>),
+        T: Into<Self::ValueOperand>,
+        E: Into<Self::ValueOperand>;
+}
+
+impl<O: When> Wrapper<O> {
+    pub fn when<Q, T, E>(&self, query: Q, then: T, otherwise: E)
+    where
+        Q: FnOnce(&mut Wrapper<O::QueryOperand>),
+        T: Into<O::ValueOperand>,
+        E: Into<O::ValueOperand>,
+    {
+        self.0.write().when(query, then, otherwise);
+    }
+}
+
+impl<O: GroupedOperand + When> When for GroupOperand<O> {
+    type QueryOperand = O::QueryOperand;
+    type ValueOperand = O::ValueOperand;
+
+    fn when<Q, T, E>(&mut self, query: Q, then: T, otherwise: E)
+    where
+        Q: FnOnce(&mut Wrapper<Self::QueryOperand>),
+        T: Into<Self::ValueOperand>,
+        E: Into<Self::ValueOperand>,
+    {
+        self.operand.when(query, then, otherwise);
+    }
+}
+
 pub trait Random {
     type ReturnOperand;
 
@@ -122,3 +236,71 @@ impl<O: GroupedOperand + IsMin> IsMin for GroupOperand<O> {
         self.operand.is_min();
     }
 }
+
+pub trait Distinct {
+    fn distinct(&mut self);
+}
+
+impl<O: Distinct> Wrapper<O> {
+    pub fn distinct(&self) {
+        self.0.write().distinct();
+    }
+}
+
+impl<O: GroupedOperand + Distinct> Distinct for GroupOperand<O> {
+    fn distinct(&mut self) {
+        self.operand.distinct();
+    }
+}
+
+pub trait Sort {
+    fn sort(&mut self, descending: bool);
+}
+
+impl<O: Sort> Wrapper<O> {
+    /// Orders the entries by their value, which [`Wrapper::skip`] and
+    /// [`Wrapper::limit`] rely on to return stable pages.
+    pub fn sort(&self, descending: bool) {
+        self.0.write().sort(descending);
+    }
+}
+
+impl<O: GroupedOperand + Sort> Sort for GroupOperand<O> {
+    fn sort(&mut self, descending: bool) {
+        self.operand.sort(descending);
+    }
+}
+
+pub trait Skip {
+    fn skip(&mut self, count: usize);
+}
+
+impl<O: Skip> Wrapper<O> {
+    /// Drops the first `count` entries.
+    pub fn skip(&self, count: usize) {
+        self.0.write().skip(count);
+    }
+}
+
+impl<O: GroupedOperand + Skip> Skip for GroupOperand<O> {
+    fn skip(&mut self, count: usize) {
+        self.operand.skip(count);
+    }
+}
+
+pub trait Limit {
+    fn limit(&mut self, count: usize);
+}
+
+impl<O: Limit> Wrapper<O> {
+    /// Keeps at most the first `count` entries.
+    pub fn limit(&self, count: usize) {
+        self.0.write().limit(count);
+    }
+}
+
+impl<O: GroupedOperand + Limit> Limit for GroupOperand<O> {
+    fn limit(&mut self, count: usize) {
+        self.operand.limit(count);
+    }
+}
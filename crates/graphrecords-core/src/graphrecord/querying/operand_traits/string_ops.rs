@@ -158,3 +158,71 @@ impl<O: GroupedOperand + Contains> Contains for GroupOperand<O> {
         self.operand.contains(value);
     }
 }
+
+pub trait LevenshteinAtMost {
+    type ComparisonOperand;
+
+    fn levenshtein_at_most<V: Into<Self::ComparisonOperand>>(&mut self, target: V, distance: usize);
+}
+
+impl<O: LevenshteinAtMost> Wrapper<O> {
+    pub fn levenshtein_at_most<V: Into<O::ComparisonOperand>>(&self, target: V, distance: usize) {
+        self.0.write().levenshtein_at_most(target, distance);
+    }
+}
+
+impl<O: GroupedOperand + LevenshteinAtMost> LevenshteinAtMost for GroupOperand<O> {
+    type ComparisonOperand = O::ComparisonOperand;
+
+    fn levenshtein_at_most<V: Into<Self::ComparisonOperand>>(
+        &mut self,
+        target: V,
+        distance: usize,
+    ) {
+        self.operand.levenshtein_at_most(target, distance);
+    }
+}
+
+pub trait JaroWinklerAtLeast {
+    type ComparisonOperand;
+
+    fn jaro_winkler_at_least<V: Into<Self::ComparisonOperand>>(
+        &mut self,
+        target: V,
+        similarity: f64,
+    );
+}
+
+impl<O: JaroWinklerAtLeast> Wrapper<O> {
+    pub fn jaro_winkler_at_least<V: Into<O::ComparisonOperand>>(&self, target: V, similarity: f64) {
+        self.0.write().jaro_winkler_at_least(target, similarity);
+    }
+}
+
+impl<O: GroupedOperand + JaroWinklerAtLeast> JaroWinklerAtLeast for GroupOperand<O> {
+    type ComparisonOperand = O::ComparisonOperand;
+
+    fn jaro_winkler_at_least<V: Into<Self::ComparisonOperand>>(
+        &mut self,
+        target: V,
+        similarity: f64,
+    ) {
+        self.operand.jaro_winkler_at_least(target, similarity);
+    }
+}
+
+pub trait TextSearch {
+    fn text_search(&mut self, query: String);
+}
+
+impl<O: TextSearch> Wrapper<O> {
+    pub fn text_search(&self, query: impl Into<String>) {
+        self.0.write().text_search(query.into());
+    }
+}
+
+impl<O: GroupedOperand + TextSearch> TextSearch for GroupOperand<O> {
+    fn text_search(&mut self, query: String) {
+        self.operand.text_search(query);
+    }
+}
@@ -37,6 +37,34 @@ where
     }
 }
 
+pub trait Degree {
+    type ReturnOperand;
+
+    fn degree(&mut self, direction: EdgeDirection) -> Wrapper<Self::ReturnOperand>;
+}
+
+impl<O: Degree> Wrapper<O> {
+    #[must_use]
+    pub fn degree(&self, direction: EdgeDirection) -> Wrapper<O::ReturnOperand> {
+        self.0.write().degree(direction)
+    }
+}
+
+impl<O: GroupedOperand + Degree> Degree for GroupOperand<O>
+where
+    Self: DeepClone,
+    O::ReturnOperand: GroupedOperand,
+    <O::ReturnOperand as GroupedOperand>::Context: From<Self>,
+{
+    type ReturnOperand = GroupOperand<O::ReturnOperand>;
+
+    fn degree(&mut self, direction: EdgeDirection) -> Wrapper<Self::ReturnOperand> {
+        let operand = self.operand.degree(direction);
+
+        Wrapper::<GroupOperand<O::ReturnOperand>>::new(self.deep_clone().into(), operand)
+    }
+}
+
 pub trait Attributes {
     type ReturnOperand;
 
@@ -209,6 +237,34 @@ where
     }
 }
 
+pub trait InducedBy {
+    type QueryOperand;
+
+    fn induced_by<Q>(&mut self, query: Q)
+    where
+        Q: FnOnce(&mut Wrapper<Self::QueryOperand>);
+}
+
+impl<O: InducedBy> Wrapper<O> {
+    pub fn induced_by<Q>(&self, query: Q)
+    where
+        Q: FnOnce(&mut Wrapper<O::QueryOperand>),
+    {
+        self.0.write().induced_by(query);
+    }
+}
+
+impl<O: GroupedOperand + InducedBy> InducedBy for GroupOperand<O> {
+    type QueryOperand = O::QueryOperand;
+
+    fn induced_by<Q>(&mut self, query: Q)
+    where
+        Q: FnOnce(&mut Wrapper<Self::QueryOperand>),
+    {
+        self.operand.induced_by(query);
+    }
+}
+
 pub trait ToValues {
     type ReturnOperand;
 
@@ -236,3 +292,19 @@ where
         Wrapper::<GroupOperand<O::ReturnOperand>>::new(self.deep_clone().into(), operand)
     }
 }
+
+pub trait Provenance {
+    fn provenance(&mut self);
+}
+
+impl<O: Provenance> Wrapper<O> {
+    pub fn provenance(&self) {
+        self.0.write().provenance();
+    }
+}
+
+impl<O: GroupedOperand + Provenance> Provenance for GroupOperand<O> {
+    fn provenance(&mut self) {
+        self.operand.provenance();
+    }
+}
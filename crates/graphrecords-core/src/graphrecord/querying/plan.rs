@@ -0,0 +1,199 @@
+//! Data-only description of a query that can be stored, sent over the wire and
+//! evaluated later.
+//!
+//! Queries built through [`GraphRecord::query_nodes`] and
+//! [`GraphRecord::query_edges`] are closures and cannot leave the process that
+//! created them. A [`QueryPlan`] covers the filtering subset of the query API
+//! and serializes with the `serde` feature, so it can be used by clients that
+//! cannot run Rust or Python code.
+
+use super::{
+    RootOperand,
+    operand_traits::{Attribute, Exclude, HasAttribute, InGroup},
+    values::MultipleValuesWithIndexOperand,
+    wrapper::Wrapper,
+};
+use crate::{
+    errors::GraphRecordResult,
+    graphrecord::{
+        EdgeIndex, GraphRecord, GraphRecordAttribute, GraphRecordValue, Group, NodeIndex,
+    },
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Comparison {
+    EqualTo,
+    NotEqualTo,
+    GreaterThan,
+    GreaterThanOrEqualTo,
+    LessThan,
+    LessThanOrEqualTo,
+    StartsWith,
+    EndsWith,
+    Contains,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Filter {
+    InGroup(Group),
+    HasAttribute(GraphRecordAttribute),
+    Attribute {
+        attribute: GraphRecordAttribute,
+        comparison: Comparison,
+        value: GraphRecordValue,
+    },
+    Exclude(Vec<Self>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum QueryPlan {
+    Nodes(Vec<Filter>),
+    Edges(Vec<Filter>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum QueryPlanResult {
+    Nodes(Vec<NodeIndex>),
+    Edges(Vec<EdgeIndex>),
+}
+
+fn apply_filters<O>(operand: &Wrapper<O>, filters: &[Filter])
+where
+    O: RootOperand
+        + InGroup
+        + HasAttribute
+        + Attribute<ReturnOperand = MultipleValuesWithIndexOperand<O>>
+        + Exclude<QueryOperand = O>,
+{
+    for filter in filters {
+        match filter {
+            Filter::InGroup(group) => operand.in_group(group.clone()),
+            Filter::HasAttribute(attribute) => operand.has_attribute(attribute.clone()),
+            Filter::Attribute {
+                attribute,
+                comparison,
+                value,
+            } => {
+                let values = operand.attribute(attribute.clone());
+                let value = value.clone();
+
+                match comparison {
+                    Comparison::EqualTo => values.equal_to(value),
+                    Comparison::NotEqualTo => values.not_equal_to(value),
+                    Comparison::GreaterThan => values.greater_than(value),
+                    Comparison::GreaterThanOrEqualTo => values.greater_than_or_equal_to(value),
+                    Comparison::LessThan => values.less_than(value),
+                    Comparison::LessThanOrEqualTo => values.less_than_or_equal_to(value),
+                    Comparison::StartsWith => values.starts_with(value),
+                    Comparison::EndsWith => values.ends_with(value),
+                    Comparison::Contains => values.contains(value),
+                }
+            }
+            Filter::Exclude(filters) => operand.exclude(|operand| apply_filters(operand, filters)),
+        }
+    }
+}
+
+impl QueryPlan {
+    pub fn evaluate(&self, graphrecord: &GraphRecord) -> GraphRecordResult<QueryPlanResult> {
+        match self {
+            Self::Nodes(filters) => graphrecord
+                .query_nodes(|nodes| {
+                    apply_filters(nodes, filters);
+
+                    nodes.index()
+                })
+                .evaluate()
+                .map(|indices| QueryPlanResult::Nodes(indices.collect())),
+            Self::Edges(filters) => graphrecord
+                .query_edges(|edges| {
+                    apply_filters(edges, filters);
+
+                    edges.index()
+                })
+                .evaluate()
+                .map(|indices| QueryPlanResult::Edges(indices.collect())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Comparison, Filter, QueryPlan, QueryPlanResult};
+    use crate::graphrecord::{GraphRecord, GraphRecordAttribute};
+    use std::collections::HashMap;
+
+    fn create_graphrecord() -> GraphRecord {
+        let mut graphrecord = GraphRecord::new();
+
+        graphrecord
+            .add_node(
+                "0".into(),
+                vec![("age".into(), 20.into())].into_iter().collect(),
+            )
+            .unwrap();
+        graphrecord
+            .add_node(
+                "1".into(),
+                vec![("age".into(), 40.into())].into_iter().collect(),
+            )
+            .unwrap();
+        graphrecord.add_node("2".into(), HashMap::new()).unwrap();
+        graphrecord
+            .add_edge("0".into(), "1".into(), HashMap::new())
+            .unwrap();
+        graphrecord
+            .add_group("group".into(), Some(vec!["0".into(), "2".into()]), None)
+            .unwrap();
+
+        graphrecord
+    }
+
+    #[test]
+    fn test_evaluate_nodes() {
+        let graphrecord = create_graphrecord();
+
+        let plan = QueryPlan::Nodes(vec![Filter::Attribute {
+            attribute: "age".into(),
+            comparison: Comparison::GreaterThan,
+            value: 30.into(),
+        }]);
+
+        assert_eq!(
+            QueryPlanResult::Nodes(vec!["1".into()]),
+            plan.evaluate(&graphrecord).unwrap()
+        );
+
+        let plan = QueryPlan::Nodes(vec![
+            Filter::InGroup("group".into()),
+            Filter::Exclude(vec![Filter::HasAttribute("age".into())]),
+        ]);
+
+        assert_eq!(
+            QueryPlanResult::Nodes(vec![GraphRecordAttribute::from("2")]),
+            plan.evaluate(&graphrecord).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_evaluate_edges() {
+        let graphrecord = create_graphrecord();
+
+        assert_eq!(
+            QueryPlanResult::Edges(vec![0]),
+            QueryPlan::Edges(Vec::new()).evaluate(&graphrecord).unwrap()
+        );
+        assert_eq!(
+            QueryPlanResult::Edges(Vec::new()),
+            QueryPlan::Edges(vec![Filter::InGroup("group".into())])
+                .evaluate(&graphrecord)
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,393 @@
+//! Deduplication of structurally equal subqueries within one evaluation.
+//!
+//! The operands of a tuple return operand are built independently, so a
+//! subquery like `nodes.in_group(x)` that several of them start from is a
+//! separate, but structurally equal, operand tree in each of them. While a
+//! [`Selection`](super::Selection) of several operands is evaluated, the
+//! indices node and edge operands evaluate to are cached by their context and
+//! operations. An operand whose operations start with the operations of an
+//! operand evaluated before continues from its indices instead of being
+//! evaluated again.
+//!
+//! Entries are keyed by a hash of the context and the operations, computed
+//! once per operation for all prefixes together. Operands that ran a random
+//! operation or a UDF are not cached, since evaluating them again may give a
+//! different result.
+
+use super::{
+    BoxedIterator,
+    edges::{EdgeOperandContext, EdgeOperation},
+    nodes::{NodeOperandContext, NodeOperation},
+};
+use crate::{
+    errors::GraphRecordResult,
+    graphrecord::{EdgeIndex, GraphRecord, NodeIndex},
+};
+use graphrecords_utils::aliases::GrHashMap;
+use std::{
+    cell::{Cell, RefCell},
+    hash::{DefaultHasher, Hash, Hasher},
+    ptr,
+};
+
+type Indices<'a, I> = BoxedIterator<'a, &'a I>;
+
+pub struct Entry<I: CachedIndex> {
+    context: I::Context,
+    operations: Vec<I::Operation>,
+    indices: Vec<I>,
+}
+
+/// Entries by the hash of their context and operations.
+type Entries<I> = GrHashMap<u64, Vec<Entry<I>>>;
+
+thread_local! {
+    /// Caches of the evaluations running on this thread, innermost last.
+    static CACHES: RefCell<Vec<SubqueryCache>> = const { RefCell::new(Vec::new()) };
+
+    /// Whether a random operation or a UDF ran in the operand being evaluated.
+    static NONDETERMINISTIC: Cell<bool> = const { Cell::new(false) };
+
+    #[cfg(test)]
+    static HITS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// Cache of the node and edge operands evaluated within one evaluation of a
+/// `GraphRecord`. The record is borrowed for the whole evaluation, so it
+/// cannot change while the entries exist.
+pub struct SubqueryCache {
+    graphrecord: *const GraphRecord,
+    nodes: Entries<NodeIndex>,
+    edges: Entries<EdgeIndex>,
+}
+
+struct SubqueryCacheScope;
+
+impl Drop for SubqueryCacheScope {
+    fn drop(&mut self) {
+        CACHES.with_borrow_mut(Vec::pop);
+    }
+}
+
+pub trait CachedIndex: Clone + Sized + 'static {
+    type Context: Clone + Eq + Hash + 'static;
+    type Operation: Clone + Eq + Hash + 'static;
+
+    fn entries(cache: &mut SubqueryCache) -> &mut Entries<Self>;
+
+    fn resolve<'a>(graphrecord: &'a GraphRecord, index: &Self) -> &'a Self;
+}
+
+impl CachedIndex for NodeIndex {
+    type Context = Option<NodeOperandContext>;
+    type Operation = NodeOperation;
+
+    fn entries(cache: &mut SubqueryCache) -> &mut Entries<Self> {
+        &mut cache.nodes
+    }
+
+    fn resolve<'a>(graphrecord: &'a GraphRecord, index: &Self) -> &'a Self {
+        graphrecord.node_index_ref(index).expect("Node must exist")
+    }
+}
+
+impl CachedIndex for EdgeIndex {
+    type Context = Option<EdgeOperandContext>;
+    type Operation = EdgeOperation;
+
+    fn entries(cache: &mut SubqueryCache) -> &mut Entries<Self> {
+        &mut cache.edges
+    }
+
+    fn resolve<'a>(graphrecord: &'a GraphRecord, index: &Self) -> &'a Self {
+        graphrecord.edge_index_ref(index).expect("Edge must exist")
+    }
+}
+
+impl SubqueryCache {
+    /// Runs `f` with a cache for the evaluations of `graphrecord` on this
+    /// thread. The cache is dropped when `f` returns.
+    pub fn scope<R>(graphrecord: &GraphRecord, f: impl FnOnce() -> R) -> R {
+        CACHES.with_borrow_mut(|caches| {
+            caches.push(Self {
+                graphrecord,
+                nodes: GrHashMap::default(),
+                edges: GrHashMap::default(),
+            });
+        });
+
+        let _scope = SubqueryCacheScope;
+
+        f()
+    }
+
+    /// Runs `f` with the innermost cache if it belongs to `graphrecord`.
+    fn with_cache<R>(graphrecord: &GraphRecord, f: impl FnOnce(&mut Self) -> R) -> Option<R> {
+        CACHES.with_borrow_mut(|caches| {
+            caches
+                .last_mut()
+                .filter(|cache| ptr::eq(cache.graphrecord, graphrecord))
+                .map(f)
+        })
+    }
+
+    /// Marks the operand being evaluated as not cacheable, because it ran a
+    /// random operation or a UDF.
+    pub fn mark_nondeterministic() {
+        NONDETERMINISTIC.set(true);
+    }
+
+    /// Hashes of the context followed by each prefix of `operations`, so that
+    /// every operation is only hashed once.
+    fn prefix_hashes<I: CachedIndex>(
+        context: &I::Context,
+        operations: &[I::Operation],
+    ) -> Vec<u64> {
+        let mut hasher = DefaultHasher::new();
+        context.hash(&mut hasher);
+
+        let mut hashes = Vec::with_capacity(operations.len() + 1);
+        hashes.push(hasher.finish());
+
+        for operation in operations {
+            operation.hash(&mut hasher);
+            hashes.push(hasher.finish());
+        }
+
+        hashes
+    }
+
+    /// Evaluates `operations` on the indices of `context`, starting from the
+    /// indices of the longest prefix of `operations` that was evaluated
+    /// before in the active scope.
+    pub fn evaluate<'a, I: CachedIndex>(
+        graphrecord: &'a GraphRecord,
+        context: &I::Context,
+        operations: &[I::Operation],
+        evaluate_context: impl FnOnce() -> GraphRecordResult<Indices<'a, I>>,
+        evaluate_operations: impl FnOnce(
+            Indices<'a, I>,
+            &[I::Operation],
+        ) -> GraphRecordResult<Indices<'a, I>>,
+    ) -> GraphRecordResult<Indices<'a, I>> {
+        if Self::with_cache(graphrecord, |_| ()).is_none() {
+            return evaluate_operations(evaluate_context()?, operations);
+        }
+
+        let hashes = Self::prefix_hashes::<I>(context, operations);
+
+        let cached = Self::with_cache(graphrecord, |cache| {
+            let entries = I::entries(cache);
+
+            (0..=operations.len()).rev().find_map(|position| {
+                entries
+                    .get(&hashes[position])?
+                    .iter()
+                    .find(|entry| {
+                        entry.context == *context && entry.operations == operations[..position]
+                    })
+                    .map(|entry| {
+                        let indices: Vec<_> = entry
+                            .indices
+                            .iter()
+                            .map(|index| I::resolve(graphrecord, index))
+                            .collect();
+
+                        (position, indices)
+                    })
+            })
+        })
+        .flatten();
+
+        #[cfg(test)]
+        if cached.is_some() {
+            HITS.set(HITS.get() + 1);
+        }
+
+        if let Some((position, indices)) = &cached
+            && *position == operations.len()
+        {
+            return Ok(Box::new(indices.clone().into_iter()));
+        }
+
+        // The cache is not borrowed while evaluating, as the operations can
+        // contain further operands that are looked up in it
+        let outer_nondeterministic = NONDETERMINISTIC.replace(false);
+
+        let indices = match cached {
+            Some((position, indices)) => {
+                evaluate_operations(Box::new(indices.into_iter()), &operations[position..])
+                    .map(Iterator::collect::<Vec<_>>)
+            }
+            None => evaluate_context()
+                .and_then(|indices| evaluate_operations(indices, operations))
+                .map(Iterator::collect::<Vec<_>>),
+        };
+
+        let nondeterministic = NONDETERMINISTIC.replace(outer_nondeterministic);
+        NONDETERMINISTIC.set(outer_nondeterministic || nondeterministic);
+
+        let indices = indices?;
+
+        if !nondeterministic {
+            Self::with_cache(graphrecord, |cache| {
+                I::entries(cache)
+                    .entry(hashes[operations.len()])
+                    .or_default()
+                    .push(Entry {
+                        context: context.clone(),
+                        operations: operations.to_vec(),
+                        indices: indices.iter().map(|index| (*index).clone()).collect(),
+                    });
+            });
+        }
+
+        Ok(Box::new(indices.into_iter()))
+    }
+
+    #[cfg(test)]
+    pub fn hits() -> usize {
+        HITS.get()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{NONDETERMINISTIC, SubqueryCache};
+    use crate::{
+        GraphRecord,
+        graphrecord::{
+            GraphRecordValue, Group, NodeIndex,
+            querying::{
+                QueryRng,
+                nodes::{NodeIndicesOperand, NodeOperand},
+                values::NodeMultipleValuesWithIndexOperand,
+                wrapper::Wrapper,
+            },
+        },
+    };
+    use std::{
+        collections::HashMap,
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+    };
+
+    fn create_graphrecord() -> GraphRecord {
+        let mut graphrecord = GraphRecord::new();
+
+        graphrecord
+            .add_nodes_with_group(
+                vec![
+                    ("0".into(), HashMap::from([("age".into(), 40.into())])),
+                    ("1".into(), HashMap::from([("age".into(), 50.into())])),
+                ],
+                "patients".into(),
+            )
+            .unwrap();
+        graphrecord
+            .add_node("2".into(), HashMap::from([("age".into(), 60.into())]))
+            .unwrap();
+
+        graphrecord
+    }
+
+    #[test]
+    fn test_subquery_cache() {
+        let graphrecord = create_graphrecord();
+
+        let hits = SubqueryCache::hits();
+
+        let (ages, indices) = graphrecord
+            .query_nodes(|nodes| {
+                nodes.in_group(Group::from("patients"));
+
+                (nodes.attribute("age"), nodes.index())
+            })
+            .evaluate()
+            .unwrap();
+
+        let mut indices: Vec<_> = indices.collect();
+        indices.sort_by(NodeIndex::total_cmp);
+
+        assert_eq!(2, ages.count());
+        assert_eq!(vec![NodeIndex::from("0"), NodeIndex::from("1")], indices);
+        assert_eq!(hits + 1, SubqueryCache::hits());
+
+        assert_eq!(
+            3,
+            graphrecord
+                .query_nodes(Wrapper::index)
+                .evaluate()
+                .unwrap()
+                .count()
+        );
+        assert_eq!(hits + 1, SubqueryCache::hits());
+    }
+
+    fn query(
+        nodes: &Wrapper<NodeOperand>,
+    ) -> (
+        Wrapper<NodeMultipleValuesWithIndexOperand>,
+        Wrapper<NodeIndicesOperand>,
+    ) {
+        nodes.in_group(Group::from("patients"));
+
+        (nodes.attribute("age"), nodes.index())
+    }
+
+    #[test]
+    fn test_subquery_cache_is_local_to_evaluation() {
+        let graphrecord = create_graphrecord();
+
+        let hits = SubqueryCache::hits();
+
+        for _ in 0..2 {
+            let (ages, indices) = graphrecord.query_nodes(query).evaluate().unwrap();
+
+            assert_eq!(2, ages.count());
+            assert_eq!(2, indices.count());
+        }
+
+        // Every evaluation only reuses its own first operand
+        assert_eq!(hits + 2, SubqueryCache::hits());
+    }
+
+    #[test]
+    fn test_subquery_cache_skips_nondeterministic_operands() {
+        let mut graphrecord = create_graphrecord();
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let udf_calls = calls.clone();
+        graphrecord
+            .register_udf("double", move |value| {
+                udf_calls.fetch_add(1, Ordering::Relaxed);
+
+                value * GraphRecordValue::Int(2)
+            })
+            .unwrap();
+
+        let (ages, indices) = graphrecord
+            .query_nodes(|nodes| {
+                let age = nodes.attribute("age");
+                age.apply("double");
+                age.greater_than(90);
+
+                (nodes.attribute("age"), nodes.index())
+            })
+            .evaluate()
+            .unwrap();
+
+        assert_eq!(2, ages.count());
+        assert_eq!(2, indices.count());
+
+        // Both outputs evaluate the operand applying the UDF on their own
+        assert_eq!(6, calls.load(Ordering::Relaxed));
+
+        NONDETERMINISTIC.set(false);
+        QueryRng::new(&graphrecord);
+
+        assert!(NONDETERMINISTIC.get());
+    }
+}
@@ -18,7 +18,7 @@ use crate::{
 };
 use std::fmt::Debug;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum MultipleValuesWithIndexOperandContext<O: RootOperand> {
     RootOperand(GroupOperand<O>),
     MultipleAttributesOperand(GroupOperand<MultipleAttributesWithIndexOperand<O>>),
@@ -68,19 +68,30 @@ where
     ) -> GraphRecordResult<Self::ReturnValue> {
         match &self.context {
             MultipleValuesWithIndexOperandContext::RootOperand(context) => {
+                if let MultipleValuesWithIndexContext::Operand((operand, attribute)) =
+                    &self.operand.0.read().context
+                {
+                    operand._validate_attribute(graphrecord, attribute)?;
+                }
+
                 let partitions = context.evaluate_backward(graphrecord)?;
 
                 let values: Vec<_> = partitions
                     .map(|(key, partition)| {
-                        let MultipleValuesWithIndexContext::Operand((_, attribute)) =
-                            &self.operand.0.read().context
-                        else {
-                            unreachable!()
-                        };
-
-                        let reduced_partition: BoxedIterator<_> = Box::new(
-                            O::get_values_from_indices(graphrecord, attribute.clone(), partition),
-                        );
+                        let reduced_partition: BoxedIterator<_> =
+                            match &self.operand.0.read().context {
+                                MultipleValuesWithIndexContext::Operand((_, attribute)) => {
+                                    Box::new(O::get_values_from_indices(
+                                        graphrecord,
+                                        attribute.clone(),
+                                        partition,
+                                    ))
+                                }
+                                MultipleValuesWithIndexContext::Degree((_, direction)) => {
+                                    O::_get_degrees(graphrecord, partition, direction)
+                                }
+                                _ => unreachable!(),
+                            };
 
                         (key, reduced_partition)
                     })
@@ -127,6 +138,22 @@ impl<O: RootOperand> Ungroup for GroupOperand<MultipleValuesWithIndexOperand<O>>
     }
 }
 
+impl<O: RootOperand> Wrapper<GroupOperand<MultipleValuesWithIndexOperand<O>>> {
+    /// Selects the index with the largest value in each group, together with
+    /// that value, e.g. the encounter with the highest cost per patient.
+    #[must_use]
+    pub fn argmax(&self) -> Wrapper<GroupOperand<SingleValueWithIndexOperand<O>>> {
+        self.max()
+    }
+
+    /// Selects the index with the smallest value in each group, together with
+    /// that value.
+    #[must_use]
+    pub fn argmin(&self) -> Wrapper<GroupOperand<SingleValueWithIndexOperand<O>>> {
+        self.min()
+    }
+}
+
 impl<O: RootOperand> GroupedOperand for SingleValueWithIndexOperand<O> {
     type Context = GroupOperand<MultipleValuesWithIndexOperand<O>>;
 }
@@ -145,7 +172,7 @@ impl<'a, O: 'a + RootOperand> EvaluateBackward<'a>
 
         let values: Vec<_> = partitions
             .map(|(key, partition)| {
-                let reduced_partition = self.operand.reduce_input(partition)?;
+                let reduced_partition = self.operand.reduce_input(graphrecord, partition)?;
 
                 Ok((key, reduced_partition))
             })
@@ -205,6 +232,11 @@ impl<'a, O: 'a + RootOperand> EvaluateBackward<'a>
                     SingleKindWithoutIndex::Median => {
                         MultipleValuesWithoutIndexOperation::<O>::get_median(partition)?
                     }
+                    SingleKindWithoutIndex::Quantile(quantile) => {
+                        MultipleValuesWithoutIndexOperation::<O>::get_quantile(
+                            partition, quantile.0,
+                        )?
+                    }
                     SingleKindWithoutIndex::Mode => {
                         MultipleValuesWithoutIndexOperation::<O>::get_mode(partition)
                     }
@@ -217,11 +249,14 @@ impl<'a, O: 'a + RootOperand> EvaluateBackward<'a>
                     SingleKindWithoutIndex::Count => Some(
                         MultipleValuesWithoutIndexOperation::<O>::get_count(partition),
                     ),
+                    SingleKindWithoutIndex::CountDistinct => Some(
+                        MultipleValuesWithoutIndexOperation::<O>::get_count_distinct(partition),
+                    ),
                     SingleKindWithoutIndex::Sum => {
                         MultipleValuesWithoutIndexOperation::<O>::get_sum(partition)?
                     }
                     SingleKindWithoutIndex::Random => {
-                        MultipleValuesWithoutIndexOperation::<O>::get_random(partition)
+                        MultipleValuesWithoutIndexOperation::<O>::get_random(graphrecord, partition)
                     }
                 };
 
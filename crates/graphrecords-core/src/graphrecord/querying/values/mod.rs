@@ -1,35 +1,45 @@
 mod group_by;
 mod operand;
 mod operation;
+mod vectorized;
 
 use super::{
-    BoxedIterator, EvaluateBackward, Index, RootOperand,
+    BoxedIterator, EvaluateBackward, Index, QueryFloat, RootOperand,
     attributes::{MultipleAttributesWithIndexOperand, MultipleAttributesWithIndexOperation},
     edges::EdgeOperand,
     group_by::GroupOperand,
-    nodes::NodeOperand,
+    nodes::{EdgeDirection, NodeOperand},
 };
 use crate::{
     GraphRecord,
-    errors::GraphRecordResult,
+    errors::{GraphRecordError, GraphRecordResult},
     graphrecord::{
-        EdgeIndex, GraphRecordAttribute, GraphRecordValue, NodeIndex, querying::DeepClone,
+        EdgeIndex, GraphRecordAttribute, GraphRecordValue, NodeIndex,
+        datatypes::{DataType, GeoPoint},
+        querying::DeepClone,
     },
 };
+#[cfg(feature = "parallel")]
+use itertools::Either;
 pub use operand::{
-    EdgeMultipleValuesWithIndexOperand, EdgeMultipleValuesWithoutIndexOperand,
-    EdgeSingleValueWithIndexOperand, EdgeSingleValueWithoutIndexOperand,
+    EdgeHistogramOperand, EdgeMultipleValuesWithIndexOperand,
+    EdgeMultipleValuesWithoutIndexOperand, EdgeQuantilesOperand, EdgeSingleValueWithIndexOperand,
+    EdgeSingleValueWithoutIndexOperand, EdgeValueCountsOperand, HistogramOperand,
     MultipleValuesComparisonOperand, MultipleValuesWithIndexOperand,
-    MultipleValuesWithoutIndexOperand, NodeMultipleValuesWithIndexOperand,
-    NodeMultipleValuesWithoutIndexOperand, NodeSingleValueWithIndexOperand,
-    NodeSingleValueWithoutIndexOperand, SingleValueComparisonOperand, SingleValueWithIndexOperand,
-    SingleValueWithoutIndexOperand,
+    MultipleValuesWithoutIndexOperand, NodeHistogramOperand, NodeMultipleValuesWithIndexOperand,
+    NodeMultipleValuesWithoutIndexOperand, NodeQuantilesOperand, NodeSingleValueWithIndexOperand,
+    NodeSingleValueWithoutIndexOperand, NodeValueCountsOperand, QuantilesOperand,
+    SingleValueComparisonOperand, SingleValueWithIndexOperand, SingleValueWithoutIndexOperand,
+    ValueCountsOperand,
 };
+#[cfg(feature = "parallel")]
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use std::fmt::Display;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum MultipleValuesWithIndexContext<O: RootOperand> {
     Operand((O, GraphRecordAttribute)),
+    Degree((O, EdgeDirection)),
     MultipleAttributesOperand(MultipleAttributesWithIndexOperand<O>),
     SingleValueWithIndexGroupByOperand(GroupOperand<SingleValueWithIndexOperand<O>>),
     MultipleValuesWithIndexGroupByOperand(GroupOperand<MultipleValuesWithIndexOperand<O>>),
@@ -45,8 +55,15 @@ impl<O: RootOperand> MultipleValuesWithIndexContext<O> {
     {
         let values: BoxedIterator<_> = match self {
             Self::Operand((operand, attribute)) => {
+                operand._validate_attribute(graphrecord, attribute)?;
+
                 Box::new(operand.get_values(graphrecord, attribute.clone())?)
             }
+            Self::Degree((operand, direction)) => O::_get_degrees(
+                graphrecord,
+                operand.evaluate_backward(graphrecord)?,
+                direction,
+            ),
             Self::MultipleAttributesOperand(operand) => {
                 let attributes = operand.evaluate_backward(graphrecord)?;
 
@@ -71,7 +88,7 @@ impl<O: RootOperand> MultipleValuesWithIndexContext<O> {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum MultipleValuesWithoutIndexContext<O: RootOperand> {
     GroupByOperand(GroupOperand<SingleValueWithoutIndexOperand<O>>),
 }
@@ -96,7 +113,7 @@ impl<O: RootOperand> MultipleValuesWithoutIndexContext<O> {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SingleValueWithoutIndexContext<O: RootOperand> {
     MultipleValuesWithIndexOperand(MultipleValuesWithIndexOperand<O>),
     MultipleValuesWithoutIndexOperand(MultipleValuesWithoutIndexOperand<O>),
@@ -136,28 +153,50 @@ impl<O: RootOperand> SingleValueWithoutIndexContext<O> {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SingleKindWithIndex {
     Max,
     Min,
     Random,
+    FirstBy(GraphRecordAttribute),
+    LastBy(GraphRecordAttribute),
+    RandomWeighted(GraphRecordAttribute),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SingleKindWithoutIndex {
     Max,
     Min,
     Mean,
     Median,
+    Quantile(QueryFloat),
     Mode,
     Std,
     Var,
     Count,
+    CountDistinct,
     Sum,
     Random,
 }
 
-#[derive(Debug, Clone)]
+/// Aggregation applied to the values inside each window of
+/// [`Rolling::rolling`](super::operand_traits::Rolling::rolling).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RollingAggregation {
+    Mean,
+    Sum,
+}
+
+impl Display for RollingAggregation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Mean => write!(f, "mean"),
+            Self::Sum => write!(f, "sum"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SingleComparisonKind {
     GreaterThan,
     GreaterThanOrEqualTo,
@@ -170,13 +209,114 @@ pub enum SingleComparisonKind {
     Contains,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum MultipleComparisonKind {
     IsIn,
     IsNotIn,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FuzzyComparisonKind {
+    LevenshteinAtMost(usize),
+    JaroWinklerAtLeast(QueryFloat),
+    IsClose(QueryFloat),
+}
+
+impl FuzzyComparisonKind {
+    /// Returns whether `value` is similar enough to `target`. Strings are
+    /// compared by edit distance or similarity and numbers by their absolute
+    /// difference, values of any other data type never match.
+    pub(crate) fn matches(self, value: &GraphRecordValue, target: &GraphRecordValue) -> bool {
+        match (self, value, target) {
+            (
+                Self::LevenshteinAtMost(distance),
+                GraphRecordValue::String(value),
+                GraphRecordValue::String(target),
+            ) => strsim::levenshtein(value, target) <= distance,
+            (
+                Self::JaroWinklerAtLeast(similarity),
+                GraphRecordValue::String(value),
+                GraphRecordValue::String(target),
+            ) => strsim::jaro_winkler(value, target) >= similarity.0,
+            (
+                Self::IsClose(tolerance),
+                GraphRecordValue::Int(value),
+                GraphRecordValue::Int(target),
+            ) => value.abs_diff(*target) as f64 <= tolerance.0,
+            (Self::IsClose(tolerance), value, target) => value.approx_eq(target, tolerance.0),
+            _ => false,
+        }
+    }
+
+    pub(crate) fn get_target(
+        self,
+        graphrecord: &GraphRecord,
+        operand: &SingleValueComparisonOperand,
+    ) -> GraphRecordResult<GraphRecordValue> {
+        let target = operand
+            .evaluate_backward(graphrecord)?
+            .ok_or_else(|| GraphRecordError::QueryError("No value to compare".to_string()))?;
+
+        match (self, &target) {
+            (
+                Self::LevenshteinAtMost(_) | Self::JaroWinklerAtLeast(_),
+                GraphRecordValue::String(_),
+            )
+            | (Self::IsClose(_), GraphRecordValue::Int(_) | GraphRecordValue::Float(_)) => {
+                Ok(target)
+            }
+            (Self::IsClose(_), _) => Err(GraphRecordError::QueryError(format!(
+                "Cannot compare approximately against a value of data type {}. Consider using an Int or Float value",
+                DataType::from(&target)
+            ))),
+            _ => Err(GraphRecordError::QueryError(format!(
+                "Cannot fuzzy match against a value of data type {}. Consider using a String value",
+                DataType::from(&target)
+            ))),
+        }
+    }
+}
+
+/// Resolves the center of a `within_radius` operation, checking that the radius
+/// is usable.
+pub(crate) fn get_radius_center(
+    graphrecord: &GraphRecord,
+    operand: &SingleValueComparisonOperand,
+    radius_km: f64,
+) -> GraphRecordResult<GeoPoint> {
+    if radius_km.is_nan() || radius_km < 0.0 {
+        return Err(GraphRecordError::QueryError(format!(
+            "Radius must be a non-negative number of kilometers, got {radius_km}"
+        )));
+    }
+
+    match operand.evaluate_backward(graphrecord)? {
+        Some(GraphRecordValue::GeoPoint(center)) => Ok(center),
+        Some(center) => Err(GraphRecordError::QueryError(format!(
+            "Cannot measure distances from a value of data type {}. Consider using a GeoPoint value",
+            DataType::from(&center)
+        ))),
+        None => Err(GraphRecordError::QueryError(
+            "No value to compare".to_string(),
+        )),
+    }
+}
+
+/// Returns whether `value` lies within `radius_km` of `center`. Values of any
+/// data type other than `GeoPoint` never match.
+pub(crate) fn is_within_radius(
+    value: &GraphRecordValue,
+    center: &GeoPoint,
+    radius_km: f64,
+) -> bool {
+    let GraphRecordValue::GeoPoint(point) = value else {
+        return false;
+    };
+
+    point.distance_km(center) <= radius_km
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum BinaryArithmeticKind {
     Add,
     Sub,
@@ -199,7 +339,7 @@ impl Display for BinaryArithmeticKind {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum UnaryArithmeticKind {
     Round,
     Ceil,
@@ -213,6 +353,49 @@ pub enum UnaryArithmeticKind {
     Uppercase,
 }
 
+/// Number of indices from which looking up their values is split across the
+/// rayon thread pool.
+#[cfg(feature = "parallel")]
+const PARALLEL_SCAN_THRESHOLD: usize = 4096;
+
+/// Whether an index iterator may reach [`PARALLEL_SCAN_THRESHOLD`], judged by
+/// its size hint. Iterators without an upper bound may.
+#[cfg(feature = "parallel")]
+fn may_scan_in_parallel<I: Iterator>(indices: &I) -> bool {
+    indices
+        .size_hint()
+        .1
+        .is_none_or(|upper| upper >= PARALLEL_SCAN_THRESHOLD)
+}
+
+/// Looks up the attribute values of an index snapshot, splitting the work
+/// across the rayon thread pool once the snapshot is large enough to amortize
+/// the overhead. The order of the snapshot is preserved.
+#[cfg(feature = "parallel")]
+fn par_get_values<'a, I, F>(
+    indices: Vec<&'a I>,
+    get_value: F,
+) -> impl Iterator<Item = (&'a I, GraphRecordValue)> + 'a
+where
+    I: Index + Sync + 'a,
+    F: Fn(&'a I) -> Option<GraphRecordValue> + Sync + Send + 'a,
+{
+    if indices.len() < PARALLEL_SCAN_THRESHOLD {
+        return Either::Left(
+            indices
+                .into_iter()
+                .filter_map(move |index| Some((index, get_value(index)?))),
+        );
+    }
+
+    let values: Vec<_> = indices
+        .into_par_iter()
+        .filter_map(|index| Some((index, get_value(index)?)))
+        .collect();
+
+    Either::Right(values.into_iter())
+}
+
 pub trait GetValues<I: Index> {
     fn get_values<'a>(
         &self,
@@ -242,6 +425,29 @@ impl GetValues<NodeIndex> for NodeOperand {
     {
         let node_indices = self.evaluate_backward(graphrecord)?;
 
+        #[cfg(feature = "parallel")]
+        {
+            if may_scan_in_parallel(&node_indices) {
+                return Ok(Either::Left(par_get_values(
+                    node_indices.collect(),
+                    move |node_index| {
+                        graphrecord
+                            .node_attributes(node_index)
+                            .expect("Node must exist")
+                            .get(&attribute)
+                            .cloned()
+                    },
+                )));
+            }
+
+            Ok(Either::Right(Self::get_values_from_indices(
+                graphrecord,
+                attribute,
+                node_indices,
+            )))
+        }
+
+        #[cfg(not(feature = "parallel"))]
         Ok(Self::get_values_from_indices(
             graphrecord,
             attribute,
@@ -280,6 +486,29 @@ impl GetValues<EdgeIndex> for EdgeOperand {
     {
         let edge_indices = self.evaluate_backward(graphrecord)?;
 
+        #[cfg(feature = "parallel")]
+        {
+            if may_scan_in_parallel(&edge_indices) {
+                return Ok(Either::Left(par_get_values(
+                    edge_indices.collect(),
+                    move |edge_index| {
+                        graphrecord
+                            .edge_attributes(edge_index)
+                            .expect("Edge must exist")
+                            .get(&attribute)
+                            .cloned()
+                    },
+                )));
+            }
+
+            Ok(Either::Right(Self::get_values_from_indices(
+                graphrecord,
+                attribute,
+                edge_indices,
+            )))
+        }
+
+        #[cfg(not(feature = "parallel"))]
         Ok(Self::get_values_from_indices(
             graphrecord,
             attribute,
@@ -307,3 +536,80 @@ impl GetValues<EdgeIndex> for EdgeOperand {
         })
     }
 }
+
+#[cfg(all(test, feature = "parallel"))]
+mod test {
+    use super::{GetValues, PARALLEL_SCAN_THRESHOLD};
+    use crate::{
+        GraphRecord,
+        errors::GraphRecordError,
+        graphrecord::{GraphRecordValue, NodeIndex, querying::nodes::NodeOperand},
+    };
+    use std::collections::HashMap;
+
+    fn create_graphrecord() -> GraphRecord {
+        let node_count = i64::try_from(PARALLEL_SCAN_THRESHOLD * 2).unwrap();
+
+        // Every other node lacks the attribute, so the scan also filters
+        GraphRecord::from_tuples(
+            (0..node_count)
+                .map(|node_index| {
+                    let attributes = if node_index % 2 == 0 {
+                        HashMap::from([("age".into(), (node_index % 100).into())])
+                    } else {
+                        HashMap::new()
+                    };
+
+                    (node_index.into(), attributes)
+                })
+                .collect(),
+            None,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_par_get_values() {
+        let graphrecord = create_graphrecord();
+
+        let values: Vec<(NodeIndex, GraphRecordValue)> = graphrecord
+            .query_nodes(|nodes| nodes.attribute("age"))
+            .evaluate()
+            .unwrap()
+            .map(|(node_index, value)| (node_index.clone(), value))
+            .collect();
+
+        let expected: Vec<_> = NodeOperand::get_values_from_indices(
+            &graphrecord,
+            "age".into(),
+            graphrecord.node_indices(),
+        )
+        .map(|(node_index, value)| (node_index.clone(), value))
+        .collect();
+
+        assert_eq!(PARALLEL_SCAN_THRESHOLD, values.len());
+        // The parallel scan should keep the order of the sequential one
+        assert_eq!(expected, values);
+    }
+
+    #[test]
+    fn test_invalid_par_get_values() {
+        let mut graphrecord = create_graphrecord();
+
+        graphrecord
+            .add_node(
+                "text".into(),
+                HashMap::from([("age".into(), "unknown".into())]),
+            )
+            .unwrap();
+
+        // Errors on the values of the parallel scan should be reported
+        assert!(
+            graphrecord
+                .query_nodes(|nodes| nodes.attribute("age").max())
+                .evaluate()
+                .is_err_and(|e| matches!(e, GraphRecordError::QueryError(_)))
+        );
+    }
+}
@@ -1,25 +1,29 @@
 use super::{
-    BinaryArithmeticKind, MultipleComparisonKind, MultipleValuesWithIndexContext,
-    SingleComparisonKind, SingleKindWithIndex, UnaryArithmeticKind,
+    BinaryArithmeticKind, FuzzyComparisonKind, MultipleComparisonKind,
+    MultipleValuesWithIndexContext, SingleComparisonKind, SingleKindWithIndex, UnaryArithmeticKind,
     operation::{MultipleValuesWithIndexOperation, SingleValueWithIndexOperation},
 };
 use crate::{
     GraphRecord,
     errors::GraphRecordResult,
     graphrecord::{
-        EdgeOperand, GraphRecordValue, NodeOperand, Wrapper,
+        EdgeOperand, GraphRecordAttribute, GraphRecordValue, NodeOperand, Wrapper,
         querying::{
             BoxedIterator, DeepClone, EvaluateBackward, EvaluateForward, EvaluateForwardGrouped,
-            GroupedIterator, ReduceInput, RootOperand,
+            GroupedIterator, QueryFloat, ReduceInput, RootOperand,
             operand_traits::{
-                Abs, Add, Ceil, Contains, Count, Div, EitherOr, EndsWith, EqualTo, Exclude, Floor,
-                GreaterThan, GreaterThanOrEqualTo, IsBool, IsDateTime, IsDuration, IsFloat, IsIn,
-                IsInt, IsMax, IsMin, IsNotIn, IsNull, IsString, LessThan, LessThanOrEqualTo,
-                Lowercase, Max, Mean, Median, Min, Mod, Mode, Mul, NotEqualTo, Pow, Random, Round,
-                Slice, Sqrt, StartsWith, Std, Sub, Sum, Trim, TrimEnd, TrimStart, Uppercase, Var,
+                Abs, Add, Apply, Ceil, Contains, Count, CountDistinct, Cumsum, Distinct, Div,
+                EitherOr, EndsWith, EqualTo, Exclude, FirstBy, Floor, GreaterThan,
+                GreaterThanOrEqualTo, Histogram, IsBool, IsClose, IsDateTime, IsDuration, IsFloat,
+                IsIn, IsInt, IsMax, IsMin, IsMissing, IsNotIn, IsNull, IsString,
+                JaroWinklerAtLeast, Lag, LastBy, Lead, LessThan, LessThanOrEqualTo,
+                LevenshteinAtMost, Limit, Lowercase, Max, Mean, Median, Min, Mod, Mode, Mul,
+                NotEqualTo, Pow, Provenance, Quantile, Quantiles, Random, RandomWeighted, Rolling,
+                Round, Skip, Slice, Sort, Sqrt, StartsWith, Std, Sub, Sum, TextSearch, Trim,
+                TrimEnd, TrimStart, Uppercase, ValueCounts, Var, When, WithinRadius,
             },
             values::{
-                MultipleValuesWithoutIndexContext, SingleKindWithoutIndex,
+                MultipleValuesWithoutIndexContext, RollingAggregation, SingleKindWithoutIndex,
                 SingleValueWithoutIndexContext,
                 operation::{
                     MultipleValuesWithoutIndexOperation, SingleValueWithoutIndexOperation,
@@ -28,8 +32,9 @@ use crate::{
         },
     },
 };
+use chrono::TimeDelta;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SingleValueComparisonOperand {
     NodeSingleValueWithIndexOperand(NodeSingleValueWithIndexOperand),
     NodeSingleValueWithoutIndexOperand(NodeSingleValueWithoutIndexOperand),
@@ -135,7 +140,7 @@ impl SingleValueComparisonOperand {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum MultipleValuesComparisonOperand {
     NodeMultipleValuesWithIndexOperand(NodeMultipleValuesWithIndexOperand),
     NodeMultipleValuesWithoutIndexOperand(NodeMultipleValuesWithoutIndexOperand),
@@ -254,7 +259,7 @@ impl MultipleValuesComparisonOperand {
 pub type NodeMultipleValuesWithIndexOperand = MultipleValuesWithIndexOperand<NodeOperand>;
 pub type EdgeMultipleValuesWithIndexOperand = MultipleValuesWithIndexOperand<EdgeOperand>;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct MultipleValuesWithIndexOperand<O: RootOperand> {
     pub(crate) context: MultipleValuesWithIndexContext<O>,
     operations: Vec<MultipleValuesWithIndexOperation<O>>,
@@ -347,6 +352,69 @@ impl<O: RootOperand> Min for MultipleValuesWithIndexOperand<O> {
     }
 }
 
+impl<O: RootOperand> FirstBy for MultipleValuesWithIndexOperand<O> {
+    type ReturnOperand = SingleValueWithIndexOperand<O>;
+
+    fn first_by(
+        &mut self,
+        order_by_attribute: GraphRecordAttribute,
+    ) -> Wrapper<Self::ReturnOperand> {
+        let operand = Wrapper::<Self::ReturnOperand>::new(
+            self.deep_clone(),
+            SingleKindWithIndex::FirstBy(order_by_attribute),
+        );
+
+        self.operations
+            .push(MultipleValuesWithIndexOperation::ValueWithIndexOperation {
+                operand: operand.clone(),
+            });
+
+        operand
+    }
+}
+
+impl<O: RootOperand> RandomWeighted for MultipleValuesWithIndexOperand<O> {
+    type ReturnOperand = SingleValueWithIndexOperand<O>;
+
+    fn random_weighted(
+        &mut self,
+        weight_attribute: GraphRecordAttribute,
+    ) -> Wrapper<Self::ReturnOperand> {
+        let operand = Wrapper::<Self::ReturnOperand>::new(
+            self.deep_clone(),
+            SingleKindWithIndex::RandomWeighted(weight_attribute),
+        );
+
+        self.operations
+            .push(MultipleValuesWithIndexOperation::ValueWithIndexOperation {
+                operand: operand.clone(),
+            });
+
+        operand
+    }
+}
+
+impl<O: RootOperand> LastBy for MultipleValuesWithIndexOperand<O> {
+    type ReturnOperand = SingleValueWithIndexOperand<O>;
+
+    fn last_by(
+        &mut self,
+        order_by_attribute: GraphRecordAttribute,
+    ) -> Wrapper<Self::ReturnOperand> {
+        let operand = Wrapper::<Self::ReturnOperand>::new(
+            self.deep_clone(),
+            SingleKindWithIndex::LastBy(order_by_attribute),
+        );
+
+        self.operations
+            .push(MultipleValuesWithIndexOperation::ValueWithIndexOperation {
+                operand: operand.clone(),
+            });
+
+        operand
+    }
+}
+
 impl<O: RootOperand> Mean for MultipleValuesWithIndexOperand<O> {
     type ReturnOperand = SingleValueWithoutIndexOperand<O>;
 
@@ -385,6 +453,25 @@ impl<O: RootOperand> Median for MultipleValuesWithIndexOperand<O> {
     }
 }
 
+impl<O: RootOperand> Quantile for MultipleValuesWithIndexOperand<O> {
+    type ReturnOperand = SingleValueWithoutIndexOperand<O>;
+
+    fn quantile(&mut self, quantile: f64) -> Wrapper<Self::ReturnOperand> {
+        let operand = Wrapper::<Self::ReturnOperand>::new(
+            SingleValueWithoutIndexContext::MultipleValuesWithIndexOperand(self.deep_clone()),
+            SingleKindWithoutIndex::Quantile(quantile.into()),
+        );
+
+        self.operations.push(
+            MultipleValuesWithIndexOperation::ValueWithoutIndexOperation {
+                operand: operand.clone(),
+            },
+        );
+
+        operand
+    }
+}
+
 impl<O: RootOperand> Mode for MultipleValuesWithIndexOperand<O> {
     type ReturnOperand = SingleValueWithoutIndexOperand<O>;
 
@@ -461,6 +548,57 @@ impl<O: RootOperand> Count for MultipleValuesWithIndexOperand<O> {
     }
 }
 
+impl<O: RootOperand> CountDistinct for MultipleValuesWithIndexOperand<O> {
+    type ReturnOperand = SingleValueWithoutIndexOperand<O>;
+
+    fn count_distinct(&mut self) -> Wrapper<Self::ReturnOperand> {
+        let operand = Wrapper::<Self::ReturnOperand>::new(
+            SingleValueWithoutIndexContext::MultipleValuesWithIndexOperand(self.deep_clone()),
+            SingleKindWithoutIndex::CountDistinct,
+        );
+
+        self.operations.push(
+            MultipleValuesWithIndexOperation::ValueWithoutIndexOperation {
+                operand: operand.clone(),
+            },
+        );
+
+        operand
+    }
+}
+
+impl<O: RootOperand> ValueCounts for MultipleValuesWithIndexOperand<O> {
+    type ReturnOperand = ValueCountsOperand<O>;
+
+    fn value_counts(&mut self) -> Wrapper<Self::ReturnOperand> {
+        Wrapper::<Self::ReturnOperand>::new(
+            SingleValueWithoutIndexContext::MultipleValuesWithIndexOperand(self.deep_clone()),
+        )
+    }
+}
+
+impl<O: RootOperand> Histogram for MultipleValuesWithIndexOperand<O> {
+    type ReturnOperand = HistogramOperand<O>;
+
+    fn histogram(&mut self, bins: usize) -> Wrapper<Self::ReturnOperand> {
+        Wrapper::<Self::ReturnOperand>::new(
+            SingleValueWithoutIndexContext::MultipleValuesWithIndexOperand(self.deep_clone()),
+            bins,
+        )
+    }
+}
+
+impl<O: RootOperand> Quantiles for MultipleValuesWithIndexOperand<O> {
+    type ReturnOperand = QuantilesOperand<O>;
+
+    fn quantiles(&mut self, quantiles: Vec<f64>) -> Wrapper<Self::ReturnOperand> {
+        Wrapper::<Self::ReturnOperand>::new(
+            SingleValueWithoutIndexContext::MultipleValuesWithIndexOperand(self.deep_clone()),
+            quantiles,
+        )
+    }
+}
+
 impl<O: RootOperand> Sum for MultipleValuesWithIndexOperand<O> {
     type ReturnOperand = SingleValueWithoutIndexOperand<O>;
 
@@ -613,6 +751,68 @@ impl<O: RootOperand> Contains for MultipleValuesWithIndexOperand<O> {
     }
 }
 
+impl<O: RootOperand> LevenshteinAtMost for MultipleValuesWithIndexOperand<O> {
+    type ComparisonOperand = SingleValueComparisonOperand;
+
+    fn levenshtein_at_most<V: Into<Self::ComparisonOperand>>(
+        &mut self,
+        target: V,
+        distance: usize,
+    ) {
+        self.operations
+            .push(MultipleValuesWithIndexOperation::FuzzyComparisonOperation {
+                operand: target.into(),
+                kind: FuzzyComparisonKind::LevenshteinAtMost(distance),
+            });
+    }
+}
+
+impl<O: RootOperand> JaroWinklerAtLeast for MultipleValuesWithIndexOperand<O> {
+    type ComparisonOperand = SingleValueComparisonOperand;
+
+    fn jaro_winkler_at_least<V: Into<Self::ComparisonOperand>>(
+        &mut self,
+        target: V,
+        similarity: f64,
+    ) {
+        self.operations
+            .push(MultipleValuesWithIndexOperation::FuzzyComparisonOperation {
+                operand: target.into(),
+                kind: FuzzyComparisonKind::JaroWinklerAtLeast(similarity.into()),
+            });
+    }
+}
+
+impl<O: RootOperand> IsClose for MultipleValuesWithIndexOperand<O> {
+    type ComparisonOperand = SingleValueComparisonOperand;
+
+    fn is_close<V: Into<Self::ComparisonOperand>>(&mut self, value: V, tolerance: f64) {
+        self.operations
+            .push(MultipleValuesWithIndexOperation::FuzzyComparisonOperation {
+                operand: value.into(),
+                kind: FuzzyComparisonKind::IsClose(tolerance.into()),
+            });
+    }
+}
+
+impl<O: RootOperand> WithinRadius for MultipleValuesWithIndexOperand<O> {
+    type ComparisonOperand = SingleValueComparisonOperand;
+
+    fn within_radius<V: Into<Self::ComparisonOperand>>(&mut self, point: V, radius_km: f64) {
+        let attribute = match &self.context {
+            MultipleValuesWithIndexContext::Operand((_, attribute)) => Some(attribute.clone()),
+            _ => None,
+        };
+
+        self.operations
+            .push(MultipleValuesWithIndexOperation::WithinRadius {
+                attribute,
+                operand: point.into(),
+                radius_km: radius_km.into(),
+            });
+    }
+}
+
 impl<O: RootOperand> IsIn for MultipleValuesWithIndexOperand<O> {
     type ComparisonOperand = MultipleValuesComparisonOperand;
 
@@ -753,6 +953,13 @@ impl<O: RootOperand> Abs for MultipleValuesWithIndexOperand<O> {
     }
 }
 
+impl<O: RootOperand> Apply for MultipleValuesWithIndexOperand<O> {
+    fn apply(&mut self, udf: String) {
+        self.operations
+            .push(MultipleValuesWithIndexOperation::Apply { udf });
+    }
+}
+
 impl<O: RootOperand> Sqrt for MultipleValuesWithIndexOperand<O> {
     fn sqrt(&mut self) {
         self.operations
@@ -863,6 +1070,37 @@ impl<O: RootOperand> IsNull for MultipleValuesWithIndexOperand<O> {
     }
 }
 
+impl<O: RootOperand> IsMissing for MultipleValuesWithIndexOperand<O> {
+    fn is_missing(&mut self) {
+        self.operations
+            .push(MultipleValuesWithIndexOperation::IsMissing);
+    }
+}
+
+impl<O: RootOperand> Provenance for MultipleValuesWithIndexOperand<O> {
+    fn provenance(&mut self) {
+        let attribute = match &self.context {
+            MultipleValuesWithIndexContext::Operand((_, attribute)) => Some(attribute.clone()),
+            _ => None,
+        };
+
+        self.operations
+            .push(MultipleValuesWithIndexOperation::Provenance { attribute });
+    }
+}
+
+impl<O: RootOperand> TextSearch for MultipleValuesWithIndexOperand<O> {
+    fn text_search(&mut self, query: String) {
+        let attribute = match &self.context {
+            MultipleValuesWithIndexContext::Operand((_, attribute)) => Some(attribute.clone()),
+            _ => None,
+        };
+
+        self.operations
+            .push(MultipleValuesWithIndexOperation::TextSearch { attribute, query });
+    }
+}
+
 impl<O: RootOperand> IsMax for MultipleValuesWithIndexOperand<O> {
     fn is_max(&mut self) {
         self.operations
@@ -877,6 +1115,76 @@ impl<O: RootOperand> IsMin for MultipleValuesWithIndexOperand<O> {
     }
 }
 
+impl<O: RootOperand> Distinct for MultipleValuesWithIndexOperand<O> {
+    fn distinct(&mut self) {
+        self.operations
+            .push(MultipleValuesWithIndexOperation::Distinct);
+    }
+}
+
+impl<O: RootOperand> Sort for MultipleValuesWithIndexOperand<O> {
+    fn sort(&mut self, descending: bool) {
+        self.operations
+            .push(MultipleValuesWithIndexOperation::Sort { descending });
+    }
+}
+
+impl<O: RootOperand> Skip for MultipleValuesWithIndexOperand<O> {
+    fn skip(&mut self, count: usize) {
+        self.operations
+            .push(MultipleValuesWithIndexOperation::Skip(count));
+    }
+}
+
+impl<O: RootOperand> Limit for MultipleValuesWithIndexOperand<O> {
+    fn limit(&mut self, count: usize) {
+        self.operations
+            .push(MultipleValuesWithIndexOperation::Limit(count));
+    }
+}
+
+impl<O: RootOperand> Rolling for MultipleValuesWithIndexOperand<O> {
+    fn rolling(
+        &mut self,
+        window: TimeDelta,
+        aggregation: RollingAggregation,
+        order_by_attribute: GraphRecordAttribute,
+    ) {
+        self.operations
+            .push(MultipleValuesWithIndexOperation::Rolling {
+                window,
+                aggregation,
+                order_by_attribute,
+            });
+    }
+}
+
+impl<O: RootOperand> Cumsum for MultipleValuesWithIndexOperand<O> {
+    fn cumsum(&mut self, order_by_attribute: GraphRecordAttribute) {
+        self.operations
+            .push(MultipleValuesWithIndexOperation::Cumsum { order_by_attribute });
+    }
+}
+
+impl<O: RootOperand> Lag for MultipleValuesWithIndexOperand<O> {
+    fn lag(&mut self, n: usize, order_by_attribute: GraphRecordAttribute) {
+        self.operations.push(MultipleValuesWithIndexOperation::Lag {
+            n,
+            order_by_attribute,
+        });
+    }
+}
+
+impl<O: RootOperand> Lead for MultipleValuesWithIndexOperand<O> {
+    fn lead(&mut self, n: usize, order_by_attribute: GraphRecordAttribute) {
+        self.operations
+            .push(MultipleValuesWithIndexOperation::Lead {
+                n,
+                order_by_attribute,
+            });
+    }
+}
+
 impl<O: RootOperand> EitherOr for MultipleValuesWithIndexOperand<O> {
     type QueryOperand = Self;
 
@@ -915,6 +1223,29 @@ impl<O: RootOperand> Exclude for MultipleValuesWithIndexOperand<O> {
     }
 }
 
+impl<O: RootOperand> When for MultipleValuesWithIndexOperand<O> {
+    type QueryOperand = Self;
+    type ValueOperand = SingleValueComparisonOperand;
+
+    fn when<Q, T, E>(&mut self, query: Q, then: T, otherwise: E)
+    where
+        Q: FnOnce(&mut Wrapper<Self::QueryOperand>),
+        T: Into<Self::ValueOperand>,
+        E: Into<Self::ValueOperand>,
+    {
+        let mut operand = Wrapper::<Self::QueryOperand>::new(self.context.clone());
+
+        query(&mut operand);
+
+        self.operations
+            .push(MultipleValuesWithIndexOperation::When {
+                operand,
+                then: Box::new(then.into()),
+                otherwise: Box::new(otherwise.into()),
+            });
+    }
+}
+
 impl<O: RootOperand> MultipleValuesWithIndexOperand<O> {
     pub(crate) const fn new(context: MultipleValuesWithIndexContext<O>) -> Self {
         Self {
@@ -937,12 +1268,24 @@ impl<O: RootOperand> Wrapper<MultipleValuesWithIndexOperand<O>> {
     pub(crate) fn push_merge_operation(&self, operand: Self) {
         self.0.write().push_merge_operation(operand);
     }
+
+    /// Selects the index with the largest value, together with that value.
+    #[must_use]
+    pub fn argmax(&self) -> Wrapper<SingleValueWithIndexOperand<O>> {
+        self.max()
+    }
+
+    /// Selects the index with the smallest value, together with that value.
+    #[must_use]
+    pub fn argmin(&self) -> Wrapper<SingleValueWithIndexOperand<O>> {
+        self.min()
+    }
 }
 
 pub type NodeMultipleValuesWithoutIndexOperand = MultipleValuesWithoutIndexOperand<NodeOperand>;
 pub type EdgeMultipleValuesWithoutIndexOperand = MultipleValuesWithoutIndexOperand<EdgeOperand>;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct MultipleValuesWithoutIndexOperand<O: RootOperand> {
     pub(crate) context: MultipleValuesWithoutIndexContext<O>,
     operations: Vec<MultipleValuesWithoutIndexOperation<O>>,
@@ -1061,6 +1404,24 @@ impl<O: RootOperand> Median for MultipleValuesWithoutIndexOperand<O> {
     }
 }
 
+impl<O: RootOperand> Quantile for MultipleValuesWithoutIndexOperand<O> {
+    type ReturnOperand = SingleValueWithoutIndexOperand<O>;
+
+    fn quantile(&mut self, quantile: f64) -> Wrapper<Self::ReturnOperand> {
+        let operand = Wrapper::<SingleValueWithoutIndexOperand<O>>::new(
+            SingleValueWithoutIndexContext::MultipleValuesWithoutIndexOperand(self.deep_clone()),
+            SingleKindWithoutIndex::Quantile(quantile.into()),
+        );
+
+        self.operations
+            .push(MultipleValuesWithoutIndexOperation::ValueOperation {
+                operand: operand.clone(),
+            });
+
+        operand
+    }
+}
+
 impl<O: RootOperand> Mode for MultipleValuesWithoutIndexOperand<O> {
     type ReturnOperand = SingleValueWithoutIndexOperand<O>;
 
@@ -1133,6 +1494,56 @@ impl<O: RootOperand> Count for MultipleValuesWithoutIndexOperand<O> {
     }
 }
 
+impl<O: RootOperand> CountDistinct for MultipleValuesWithoutIndexOperand<O> {
+    type ReturnOperand = SingleValueWithoutIndexOperand<O>;
+
+    fn count_distinct(&mut self) -> Wrapper<Self::ReturnOperand> {
+        let operand = Wrapper::<SingleValueWithoutIndexOperand<O>>::new(
+            SingleValueWithoutIndexContext::MultipleValuesWithoutIndexOperand(self.deep_clone()),
+            SingleKindWithoutIndex::CountDistinct,
+        );
+
+        self.operations
+            .push(MultipleValuesWithoutIndexOperation::ValueOperation {
+                operand: operand.clone(),
+            });
+
+        operand
+    }
+}
+
+impl<O: RootOperand> ValueCounts for MultipleValuesWithoutIndexOperand<O> {
+    type ReturnOperand = ValueCountsOperand<O>;
+
+    fn value_counts(&mut self) -> Wrapper<Self::ReturnOperand> {
+        Wrapper::<Self::ReturnOperand>::new(
+            SingleValueWithoutIndexContext::MultipleValuesWithoutIndexOperand(self.deep_clone()),
+        )
+    }
+}
+
+impl<O: RootOperand> Histogram for MultipleValuesWithoutIndexOperand<O> {
+    type ReturnOperand = HistogramOperand<O>;
+
+    fn histogram(&mut self, bins: usize) -> Wrapper<Self::ReturnOperand> {
+        Wrapper::<Self::ReturnOperand>::new(
+            SingleValueWithoutIndexContext::MultipleValuesWithoutIndexOperand(self.deep_clone()),
+            bins,
+        )
+    }
+}
+
+impl<O: RootOperand> Quantiles for MultipleValuesWithoutIndexOperand<O> {
+    type ReturnOperand = QuantilesOperand<O>;
+
+    fn quantiles(&mut self, quantiles: Vec<f64>) -> Wrapper<Self::ReturnOperand> {
+        Wrapper::<Self::ReturnOperand>::new(
+            SingleValueWithoutIndexContext::MultipleValuesWithoutIndexOperand(self.deep_clone()),
+            quantiles,
+        )
+    }
+}
+
 impl<O: RootOperand> Sum for MultipleValuesWithoutIndexOperand<O> {
     type ReturnOperand = SingleValueWithoutIndexOperand<O>;
 
@@ -1286,6 +1697,65 @@ impl<O: RootOperand> Contains for MultipleValuesWithoutIndexOperand<O> {
     }
 }
 
+impl<O: RootOperand> LevenshteinAtMost for MultipleValuesWithoutIndexOperand<O> {
+    type ComparisonOperand = SingleValueComparisonOperand;
+
+    fn levenshtein_at_most<V: Into<Self::ComparisonOperand>>(
+        &mut self,
+        target: V,
+        distance: usize,
+    ) {
+        self.operations.push(
+            MultipleValuesWithoutIndexOperation::FuzzyComparisonOperation {
+                operand: target.into(),
+                kind: FuzzyComparisonKind::LevenshteinAtMost(distance),
+            },
+        );
+    }
+}
+
+impl<O: RootOperand> JaroWinklerAtLeast for MultipleValuesWithoutIndexOperand<O> {
+    type ComparisonOperand = SingleValueComparisonOperand;
+
+    fn jaro_winkler_at_least<V: Into<Self::ComparisonOperand>>(
+        &mut self,
+        target: V,
+        similarity: f64,
+    ) {
+        self.operations.push(
+            MultipleValuesWithoutIndexOperation::FuzzyComparisonOperation {
+                operand: target.into(),
+                kind: FuzzyComparisonKind::JaroWinklerAtLeast(similarity.into()),
+            },
+        );
+    }
+}
+
+impl<O: RootOperand> IsClose for MultipleValuesWithoutIndexOperand<O> {
+    type ComparisonOperand = SingleValueComparisonOperand;
+
+    fn is_close<V: Into<Self::ComparisonOperand>>(&mut self, value: V, tolerance: f64) {
+        self.operations.push(
+            MultipleValuesWithoutIndexOperation::FuzzyComparisonOperation {
+                operand: value.into(),
+                kind: FuzzyComparisonKind::IsClose(tolerance.into()),
+            },
+        );
+    }
+}
+
+impl<O: RootOperand> WithinRadius for MultipleValuesWithoutIndexOperand<O> {
+    type ComparisonOperand = SingleValueComparisonOperand;
+
+    fn within_radius<V: Into<Self::ComparisonOperand>>(&mut self, point: V, radius_km: f64) {
+        self.operations
+            .push(MultipleValuesWithoutIndexOperation::WithinRadius {
+                operand: point.into(),
+                radius_km: radius_km.into(),
+            });
+    }
+}
+
 impl<O: RootOperand> IsIn for MultipleValuesWithoutIndexOperand<O> {
     type ComparisonOperand = MultipleValuesComparisonOperand;
 
@@ -1430,6 +1900,13 @@ impl<O: RootOperand> Abs for MultipleValuesWithoutIndexOperand<O> {
     }
 }
 
+impl<O: RootOperand> Apply for MultipleValuesWithoutIndexOperand<O> {
+    fn apply(&mut self, udf: String) {
+        self.operations
+            .push(MultipleValuesWithoutIndexOperation::Apply { udf });
+    }
+}
+
 impl<O: RootOperand> Sqrt for MultipleValuesWithoutIndexOperand<O> {
     fn sqrt(&mut self) {
         self.operations.push(
@@ -1546,6 +2023,13 @@ impl<O: RootOperand> IsNull for MultipleValuesWithoutIndexOperand<O> {
     }
 }
 
+impl<O: RootOperand> IsMissing for MultipleValuesWithoutIndexOperand<O> {
+    fn is_missing(&mut self) {
+        self.operations
+            .push(MultipleValuesWithoutIndexOperation::IsMissing);
+    }
+}
+
 impl<O: RootOperand> IsMax for MultipleValuesWithoutIndexOperand<O> {
     fn is_max(&mut self) {
         self.operations
@@ -1560,6 +2044,34 @@ impl<O: RootOperand> IsMin for MultipleValuesWithoutIndexOperand<O> {
     }
 }
 
+impl<O: RootOperand> Distinct for MultipleValuesWithoutIndexOperand<O> {
+    fn distinct(&mut self) {
+        self.operations
+            .push(MultipleValuesWithoutIndexOperation::Distinct);
+    }
+}
+
+impl<O: RootOperand> Sort for MultipleValuesWithoutIndexOperand<O> {
+    fn sort(&mut self, descending: bool) {
+        self.operations
+            .push(MultipleValuesWithoutIndexOperation::Sort { descending });
+    }
+}
+
+impl<O: RootOperand> Skip for MultipleValuesWithoutIndexOperand<O> {
+    fn skip(&mut self, count: usize) {
+        self.operations
+            .push(MultipleValuesWithoutIndexOperation::Skip(count));
+    }
+}
+
+impl<O: RootOperand> Limit for MultipleValuesWithoutIndexOperand<O> {
+    fn limit(&mut self, count: usize) {
+        self.operations
+            .push(MultipleValuesWithoutIndexOperation::Limit(count));
+    }
+}
+
 impl<O: RootOperand> EitherOr for MultipleValuesWithoutIndexOperand<O> {
     type QueryOperand = Self;
 
@@ -1616,7 +2128,7 @@ impl<O: RootOperand> Wrapper<MultipleValuesWithoutIndexOperand<O>> {
 pub type NodeSingleValueWithIndexOperand = SingleValueWithIndexOperand<NodeOperand>;
 pub type EdgeSingleValueWithIndexOperand = SingleValueWithIndexOperand<EdgeOperand>;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SingleValueWithIndexOperand<O: RootOperand> {
     context: MultipleValuesWithIndexOperand<O>,
     pub(crate) kind: SingleKindWithIndex,
@@ -1671,7 +2183,7 @@ impl<'a, O: 'a + RootOperand> EvaluateBackward<'a> for SingleValueWithIndexOpera
     ) -> GraphRecordResult<Self::ReturnValue> {
         let values = self.context.evaluate_backward(graphrecord)?;
 
-        let value = self.reduce_input(values)?;
+        let value = self.reduce_input(graphrecord, values)?;
 
         self.evaluate_forward(graphrecord, value)
     }
@@ -1683,13 +2195,27 @@ impl<'a, O: 'a + RootOperand> ReduceInput<'a> for SingleValueWithIndexOperand<O>
     #[inline]
     fn reduce_input(
         &self,
+        graphrecord: &'a GraphRecord,
         values: <Self::Context as EvaluateBackward<'a>>::ReturnValue,
     ) -> GraphRecordResult<<Self as EvaluateForward<'a>>::InputValue> {
-        Ok(match self.kind {
+        Ok(match &self.kind {
             SingleKindWithIndex::Max => MultipleValuesWithIndexOperation::<O>::get_max(values)?,
             SingleKindWithIndex::Min => MultipleValuesWithIndexOperation::<O>::get_min(values)?,
             SingleKindWithIndex::Random => {
-                MultipleValuesWithIndexOperation::<O>::get_random(values)
+                MultipleValuesWithIndexOperation::<O>::get_random(graphrecord, values)
+            }
+            SingleKindWithIndex::FirstBy(attribute) => {
+                MultipleValuesWithIndexOperation::<O>::get_first_by(graphrecord, values, attribute)?
+            }
+            SingleKindWithIndex::LastBy(attribute) => {
+                MultipleValuesWithIndexOperation::<O>::get_last_by(graphrecord, values, attribute)?
+            }
+            SingleKindWithIndex::RandomWeighted(attribute) => {
+                MultipleValuesWithIndexOperation::<O>::get_random_weighted(
+                    graphrecord,
+                    values,
+                    attribute,
+                )?
             }
         })
     }
@@ -1812,6 +2338,62 @@ impl<O: RootOperand> Contains for SingleValueWithIndexOperand<O> {
     }
 }
 
+impl<O: RootOperand> LevenshteinAtMost for SingleValueWithIndexOperand<O> {
+    type ComparisonOperand = SingleValueComparisonOperand;
+
+    fn levenshtein_at_most<V: Into<Self::ComparisonOperand>>(
+        &mut self,
+        target: V,
+        distance: usize,
+    ) {
+        self.operations
+            .push(SingleValueWithIndexOperation::FuzzyComparisonOperation {
+                operand: target.into(),
+                kind: FuzzyComparisonKind::LevenshteinAtMost(distance),
+            });
+    }
+}
+
+impl<O: RootOperand> JaroWinklerAtLeast for SingleValueWithIndexOperand<O> {
+    type ComparisonOperand = SingleValueComparisonOperand;
+
+    fn jaro_winkler_at_least<V: Into<Self::ComparisonOperand>>(
+        &mut self,
+        target: V,
+        similarity: f64,
+    ) {
+        self.operations
+            .push(SingleValueWithIndexOperation::FuzzyComparisonOperation {
+                operand: target.into(),
+                kind: FuzzyComparisonKind::JaroWinklerAtLeast(similarity.into()),
+            });
+    }
+}
+
+impl<O: RootOperand> IsClose for SingleValueWithIndexOperand<O> {
+    type ComparisonOperand = SingleValueComparisonOperand;
+
+    fn is_close<V: Into<Self::ComparisonOperand>>(&mut self, value: V, tolerance: f64) {
+        self.operations
+            .push(SingleValueWithIndexOperation::FuzzyComparisonOperation {
+                operand: value.into(),
+                kind: FuzzyComparisonKind::IsClose(tolerance.into()),
+            });
+    }
+}
+
+impl<O: RootOperand> WithinRadius for SingleValueWithIndexOperand<O> {
+    type ComparisonOperand = SingleValueComparisonOperand;
+
+    fn within_radius<V: Into<Self::ComparisonOperand>>(&mut self, point: V, radius_km: f64) {
+        self.operations
+            .push(SingleValueWithIndexOperation::WithinRadius {
+                operand: point.into(),
+                radius_km: radius_km.into(),
+            });
+    }
+}
+
 impl<O: RootOperand> IsIn for SingleValueWithIndexOperand<O> {
     type ComparisonOperand = MultipleValuesComparisonOperand;
 
@@ -1946,6 +2528,13 @@ impl<O: RootOperand> Abs for SingleValueWithIndexOperand<O> {
     }
 }
 
+impl<O: RootOperand> Apply for SingleValueWithIndexOperand<O> {
+    fn apply(&mut self, udf: String) {
+        self.operations
+            .push(SingleValueWithIndexOperation::Apply { udf });
+    }
+}
+
 impl<O: RootOperand> Sqrt for SingleValueWithIndexOperand<O> {
     fn sqrt(&mut self) {
         self.operations
@@ -2052,6 +2641,13 @@ impl<O: RootOperand> IsNull for SingleValueWithIndexOperand<O> {
     }
 }
 
+impl<O: RootOperand> IsMissing for SingleValueWithIndexOperand<O> {
+    fn is_missing(&mut self) {
+        self.operations
+            .push(SingleValueWithIndexOperation::IsMissing);
+    }
+}
+
 impl<O: RootOperand> EitherOr for SingleValueWithIndexOperand<O> {
     type QueryOperand = Self;
 
@@ -2127,7 +2723,7 @@ impl<O: RootOperand> Wrapper<SingleValueWithIndexOperand<O>> {
 pub type NodeSingleValueWithoutIndexOperand = SingleValueWithoutIndexOperand<NodeOperand>;
 pub type EdgeSingleValueWithoutIndexOperand = SingleValueWithoutIndexOperand<EdgeOperand>;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SingleValueWithoutIndexOperand<O: RootOperand> {
     context: SingleValueWithoutIndexContext<O>,
     pub(crate) kind: SingleKindWithoutIndex,
@@ -2195,6 +2791,9 @@ impl<'a, O: 'a + RootOperand> EvaluateBackward<'a> for SingleValueWithoutIndexOp
             SingleKindWithoutIndex::Median => {
                 MultipleValuesWithoutIndexOperation::<O>::get_median(values)?
             }
+            SingleKindWithoutIndex::Quantile(quantile) => {
+                MultipleValuesWithoutIndexOperation::<O>::get_quantile(values, quantile.0)?
+            }
             SingleKindWithoutIndex::Mode => {
                 MultipleValuesWithoutIndexOperation::<O>::get_mode(values)
             }
@@ -2207,11 +2806,14 @@ impl<'a, O: 'a + RootOperand> EvaluateBackward<'a> for SingleValueWithoutIndexOp
             SingleKindWithoutIndex::Count => {
                 Some(MultipleValuesWithoutIndexOperation::<O>::get_count(values))
             }
+            SingleKindWithoutIndex::CountDistinct => {
+                Some(MultipleValuesWithoutIndexOperation::<O>::get_count_distinct(values))
+            }
             SingleKindWithoutIndex::Sum => {
                 MultipleValuesWithoutIndexOperation::<O>::get_sum(values)?
             }
             SingleKindWithoutIndex::Random => {
-                MultipleValuesWithoutIndexOperation::<O>::get_random(values)
+                MultipleValuesWithoutIndexOperation::<O>::get_random(graphrecord, values)
             }
         };
 
@@ -2336,6 +2938,62 @@ impl<O: RootOperand> Contains for SingleValueWithoutIndexOperand<O> {
     }
 }
 
+impl<O: RootOperand> LevenshteinAtMost for SingleValueWithoutIndexOperand<O> {
+    type ComparisonOperand = SingleValueComparisonOperand;
+
+    fn levenshtein_at_most<V: Into<Self::ComparisonOperand>>(
+        &mut self,
+        target: V,
+        distance: usize,
+    ) {
+        self.operations
+            .push(SingleValueWithoutIndexOperation::FuzzyComparisonOperation {
+                operand: target.into(),
+                kind: FuzzyComparisonKind::LevenshteinAtMost(distance),
+            });
+    }
+}
+
+impl<O: RootOperand> JaroWinklerAtLeast for SingleValueWithoutIndexOperand<O> {
+    type ComparisonOperand = SingleValueComparisonOperand;
+
+    fn jaro_winkler_at_least<V: Into<Self::ComparisonOperand>>(
+        &mut self,
+        target: V,
+        similarity: f64,
+    ) {
+        self.operations
+            .push(SingleValueWithoutIndexOperation::FuzzyComparisonOperation {
+                operand: target.into(),
+                kind: FuzzyComparisonKind::JaroWinklerAtLeast(similarity.into()),
+            });
+    }
+}
+
+impl<O: RootOperand> IsClose for SingleValueWithoutIndexOperand<O> {
+    type ComparisonOperand = SingleValueComparisonOperand;
+
+    fn is_close<V: Into<Self::ComparisonOperand>>(&mut self, value: V, tolerance: f64) {
+        self.operations
+            .push(SingleValueWithoutIndexOperation::FuzzyComparisonOperation {
+                operand: value.into(),
+                kind: FuzzyComparisonKind::IsClose(tolerance.into()),
+            });
+    }
+}
+
+impl<O: RootOperand> WithinRadius for SingleValueWithoutIndexOperand<O> {
+    type ComparisonOperand = SingleValueComparisonOperand;
+
+    fn within_radius<V: Into<Self::ComparisonOperand>>(&mut self, point: V, radius_km: f64) {
+        self.operations
+            .push(SingleValueWithoutIndexOperation::WithinRadius {
+                operand: point.into(),
+                radius_km: radius_km.into(),
+            });
+    }
+}
+
 impl<O: RootOperand> IsIn for SingleValueWithoutIndexOperand<O> {
     type ComparisonOperand = MultipleValuesComparisonOperand;
 
@@ -2476,6 +3134,13 @@ impl<O: RootOperand> Abs for SingleValueWithoutIndexOperand<O> {
     }
 }
 
+impl<O: RootOperand> Apply for SingleValueWithoutIndexOperand<O> {
+    fn apply(&mut self, udf: String) {
+        self.operations
+            .push(SingleValueWithoutIndexOperation::Apply { udf });
+    }
+}
+
 impl<O: RootOperand> Sqrt for SingleValueWithoutIndexOperand<O> {
     fn sqrt(&mut self) {
         self.operations
@@ -2586,6 +3251,13 @@ impl<O: RootOperand> IsNull for SingleValueWithoutIndexOperand<O> {
     }
 }
 
+impl<O: RootOperand> IsMissing for SingleValueWithoutIndexOperand<O> {
+    fn is_missing(&mut self) {
+        self.operations
+            .push(SingleValueWithoutIndexOperation::IsMissing);
+    }
+}
+
 impl<O: RootOperand> EitherOr for SingleValueWithoutIndexOperand<O> {
     type QueryOperand = Self;
 
@@ -2660,3 +3332,126 @@ impl<O: RootOperand> Wrapper<SingleValueWithoutIndexOperand<O>> {
         self.0.write().push_merge_operation(operand);
     }
 }
+
+pub type NodeValueCountsOperand = ValueCountsOperand<NodeOperand>;
+pub type EdgeValueCountsOperand = ValueCountsOperand<EdgeOperand>;
+
+/// Frequency table of the values, as `(value, count)` pairs ordered by
+/// descending count.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ValueCountsOperand<O: RootOperand> {
+    context: SingleValueWithoutIndexContext<O>,
+}
+
+impl<O: RootOperand> DeepClone for ValueCountsOperand<O> {
+    fn deep_clone(&self) -> Self {
+        Self {
+            context: self.context.deep_clone(),
+        }
+    }
+}
+
+impl<'a, O: 'a + RootOperand> EvaluateBackward<'a> for ValueCountsOperand<O> {
+    type ReturnValue = Vec<(GraphRecordValue, usize)>;
+
+    fn evaluate_backward(
+        &self,
+        graphrecord: &'a GraphRecord,
+    ) -> GraphRecordResult<Self::ReturnValue> {
+        let values = self.context.get_values(graphrecord)?;
+
+        Ok(MultipleValuesWithoutIndexOperation::<O>::get_value_counts(
+            values,
+        ))
+    }
+}
+
+impl<O: RootOperand> Wrapper<ValueCountsOperand<O>> {
+    pub(crate) fn new(context: SingleValueWithoutIndexContext<O>) -> Self {
+        ValueCountsOperand { context }.into()
+    }
+}
+
+pub type NodeHistogramOperand = HistogramOperand<NodeOperand>;
+pub type EdgeHistogramOperand = HistogramOperand<EdgeOperand>;
+
+/// Histogram of numeric values over equal-width bins between their minimum and
+/// maximum, as `(lower, upper, count)` triples.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HistogramOperand<O: RootOperand> {
+    context: SingleValueWithoutIndexContext<O>,
+    bins: usize,
+}
+
+impl<O: RootOperand> DeepClone for HistogramOperand<O> {
+    fn deep_clone(&self) -> Self {
+        Self {
+            context: self.context.deep_clone(),
+            bins: self.bins,
+        }
+    }
+}
+
+impl<'a, O: 'a + RootOperand> EvaluateBackward<'a> for HistogramOperand<O> {
+    type ReturnValue = Vec<(f64, f64, usize)>;
+
+    fn evaluate_backward(
+        &self,
+        graphrecord: &'a GraphRecord,
+    ) -> GraphRecordResult<Self::ReturnValue> {
+        let values = self.context.get_values(graphrecord)?;
+
+        MultipleValuesWithoutIndexOperation::<O>::get_histogram(values, self.bins)
+    }
+}
+
+impl<O: RootOperand> Wrapper<HistogramOperand<O>> {
+    pub(crate) fn new(context: SingleValueWithoutIndexContext<O>, bins: usize) -> Self {
+        HistogramOperand { context, bins }.into()
+    }
+}
+
+pub type NodeQuantilesOperand = QuantilesOperand<NodeOperand>;
+pub type EdgeQuantilesOperand = QuantilesOperand<EdgeOperand>;
+
+/// Several quantiles of numeric values at once, as `(quantile, value)` pairs in
+/// the order they were requested.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QuantilesOperand<O: RootOperand> {
+    context: SingleValueWithoutIndexContext<O>,
+    quantiles: Vec<QueryFloat>,
+}
+
+impl<O: RootOperand> DeepClone for QuantilesOperand<O> {
+    fn deep_clone(&self) -> Self {
+        Self {
+            context: self.context.deep_clone(),
+            quantiles: self.quantiles.clone(),
+        }
+    }
+}
+
+impl<'a, O: 'a + RootOperand> EvaluateBackward<'a> for QuantilesOperand<O> {
+    type ReturnValue = Vec<(f64, f64)>;
+
+    fn evaluate_backward(
+        &self,
+        graphrecord: &'a GraphRecord,
+    ) -> GraphRecordResult<Self::ReturnValue> {
+        let values = self.context.get_values(graphrecord)?;
+
+        let quantiles: Vec<_> = self.quantiles.iter().map(|quantile| quantile.0).collect();
+
+        MultipleValuesWithoutIndexOperation::<O>::get_quantiles(values, &quantiles)
+    }
+}
+
+impl<O: RootOperand> Wrapper<QuantilesOperand<O>> {
+    pub(crate) fn new(context: SingleValueWithoutIndexContext<O>, quantiles: Vec<f64>) -> Self {
+        QuantilesOperand {
+            context,
+            quantiles: quantiles.into_iter().map(QueryFloat).collect(),
+        }
+        .into()
+    }
+}
@@ -1,6 +1,7 @@
 use super::{
-    BinaryArithmeticKind, MultipleComparisonKind, SingleComparisonKind, SingleKindWithIndex,
-    UnaryArithmeticKind,
+    BinaryArithmeticKind, FuzzyComparisonKind, MultipleComparisonKind, RollingAggregation,
+    SingleComparisonKind, SingleKindWithIndex, UnaryArithmeticKind, get_radius_center,
+    is_within_radius,
     operand::{
         MultipleValuesComparisonOperand, MultipleValuesWithIndexOperand,
         SingleValueComparisonOperand, SingleValueWithIndexOperand,
@@ -10,24 +11,28 @@ use crate::{
     GraphRecord,
     errors::{GraphRecordError, GraphRecordResult},
     graphrecord::{
-        GraphRecordValue, Wrapper,
+        GraphRecordAttribute, GraphRecordValue, Wrapper,
         datatypes::{
             Abs, Ceil, Contains, DataType, EndsWith, Floor, Lowercase, Mod, Pow, Round, Slice,
             Sqrt, StartsWith, Trim, TrimEnd, TrimStart, Uppercase,
         },
         querying::{
             BoxedIterator, DeepClone, EvaluateForward, EvaluateForwardGrouped, GroupedIterator,
-            RootOperand, tee_grouped_iterator,
+            Index, QueryFloat, QueryRng, RootOperand, SubqueryCache,
+            attributes::GetAttributes,
+            tee_grouped_iterator,
             values::{
                 SingleKindWithoutIndex,
                 operand::{MultipleValuesWithoutIndexOperand, SingleValueWithoutIndexOperand},
+                vectorized,
             },
         },
+        text_index,
     },
 };
-use graphrecords_utils::aliases::GrHashSet;
+use chrono::{NaiveDateTime, TimeDelta};
+use graphrecords_utils::aliases::{GrHashMap, GrHashSet};
 use itertools::Itertools;
-use rand::{rng, seq::IteratorRandom};
 use std::{
     cmp::Ordering,
     ops::{Add, Div, Mul, Range, Sub},
@@ -55,7 +60,7 @@ macro_rules! get_median {
     };
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum MultipleValuesWithIndexOperation<O: RootOperand> {
     ValueWithIndexOperation {
         operand: Wrapper<SingleValueWithIndexOperand<O>>,
@@ -71,6 +76,15 @@ pub enum MultipleValuesWithIndexOperation<O: RootOperand> {
         operand: MultipleValuesComparisonOperand,
         kind: MultipleComparisonKind,
     },
+    FuzzyComparisonOperation {
+        operand: SingleValueComparisonOperand,
+        kind: FuzzyComparisonKind,
+    },
+    WithinRadius {
+        attribute: Option<GraphRecordAttribute>,
+        operand: SingleValueComparisonOperand,
+        radius_km: QueryFloat,
+    },
     BinaryArithmeticOperation {
         operand: SingleValueComparisonOperand,
         kind: BinaryArithmeticKind,
@@ -78,6 +92,9 @@ pub enum MultipleValuesWithIndexOperation<O: RootOperand> {
     UnaryArithmeticOperation {
         kind: UnaryArithmeticKind,
     },
+    Apply {
+        udf: String,
+    },
 
     Slice(Range<usize>),
 
@@ -88,10 +105,44 @@ pub enum MultipleValuesWithIndexOperation<O: RootOperand> {
     IsDateTime,
     IsDuration,
     IsNull,
+    IsMissing,
 
     IsMax,
     IsMin,
 
+    Distinct,
+
+    Sort {
+        descending: bool,
+    },
+    Skip(usize),
+    Limit(usize),
+
+    Rolling {
+        window: TimeDelta,
+        aggregation: RollingAggregation,
+        order_by_attribute: GraphRecordAttribute,
+    },
+    Cumsum {
+        order_by_attribute: GraphRecordAttribute,
+    },
+    Lag {
+        n: usize,
+        order_by_attribute: GraphRecordAttribute,
+    },
+    Lead {
+        n: usize,
+        order_by_attribute: GraphRecordAttribute,
+    },
+
+    Provenance {
+        attribute: Option<GraphRecordAttribute>,
+    },
+    TextSearch {
+        attribute: Option<GraphRecordAttribute>,
+        query: String,
+    },
+
     EitherOr {
         either: Wrapper<MultipleValuesWithIndexOperand<O>>,
         or: Wrapper<MultipleValuesWithIndexOperand<O>>,
@@ -99,6 +150,11 @@ pub enum MultipleValuesWithIndexOperation<O: RootOperand> {
     Exclude {
         operand: Wrapper<MultipleValuesWithIndexOperand<O>>,
     },
+    When {
+        operand: Wrapper<MultipleValuesWithIndexOperand<O>>,
+        then: Box<SingleValueComparisonOperand>,
+        otherwise: Box<SingleValueComparisonOperand>,
+    },
 
     Merge {
         operand: Wrapper<MultipleValuesWithIndexOperand<O>>,
@@ -126,6 +182,19 @@ impl<O: RootOperand> DeepClone for MultipleValuesWithIndexOperation<O> {
                     kind: kind.clone(),
                 }
             }
+            Self::FuzzyComparisonOperation { operand, kind } => Self::FuzzyComparisonOperation {
+                operand: operand.deep_clone(),
+                kind: *kind,
+            },
+            Self::WithinRadius {
+                attribute,
+                operand,
+                radius_km,
+            } => Self::WithinRadius {
+                attribute: attribute.clone(),
+                operand: operand.deep_clone(),
+                radius_km: *radius_km,
+            },
             Self::BinaryArithmeticOperation { operand, kind } => Self::BinaryArithmeticOperation {
                 operand: operand.deep_clone(),
                 kind: kind.clone(),
@@ -133,6 +202,7 @@ impl<O: RootOperand> DeepClone for MultipleValuesWithIndexOperation<O> {
             Self::UnaryArithmeticOperation { kind } => {
                 Self::UnaryArithmeticOperation { kind: kind.clone() }
             }
+            Self::Apply { udf } => Self::Apply { udf: udf.clone() },
             Self::Slice(range) => Self::Slice(range.clone()),
             Self::IsString => Self::IsString,
             Self::IsInt => Self::IsInt,
@@ -141,8 +211,48 @@ impl<O: RootOperand> DeepClone for MultipleValuesWithIndexOperation<O> {
             Self::IsDateTime => Self::IsDateTime,
             Self::IsDuration => Self::IsDuration,
             Self::IsNull => Self::IsNull,
+            Self::IsMissing => Self::IsMissing,
             Self::IsMax => Self::IsMax,
             Self::IsMin => Self::IsMin,
+            Self::Distinct => Self::Distinct,
+            Self::Sort { descending } => Self::Sort {
+                descending: *descending,
+            },
+            Self::Skip(count) => Self::Skip(*count),
+            Self::Limit(count) => Self::Limit(*count),
+            Self::Rolling {
+                window,
+                aggregation,
+                order_by_attribute,
+            } => Self::Rolling {
+                window: *window,
+                aggregation: *aggregation,
+                order_by_attribute: order_by_attribute.clone(),
+            },
+            Self::Cumsum { order_by_attribute } => Self::Cumsum {
+                order_by_attribute: order_by_attribute.clone(),
+            },
+            Self::Lag {
+                n,
+                order_by_attribute,
+            } => Self::Lag {
+                n: *n,
+                order_by_attribute: order_by_attribute.clone(),
+            },
+            Self::Lead {
+                n,
+                order_by_attribute,
+            } => Self::Lead {
+                n: *n,
+                order_by_attribute: order_by_attribute.clone(),
+            },
+            Self::Provenance { attribute } => Self::Provenance {
+                attribute: attribute.clone(),
+            },
+            Self::TextSearch { attribute, query } => Self::TextSearch {
+                attribute: attribute.clone(),
+                query: query.clone(),
+            },
             Self::EitherOr { either, or } => Self::EitherOr {
                 either: either.deep_clone(),
                 or: or.deep_clone(),
@@ -150,6 +260,15 @@ impl<O: RootOperand> DeepClone for MultipleValuesWithIndexOperation<O> {
             Self::Exclude { operand } => Self::Exclude {
                 operand: operand.deep_clone(),
             },
+            Self::When {
+                operand,
+                then,
+                otherwise,
+            } => Self::When {
+                operand: operand.deep_clone(),
+                then: then.deep_clone(),
+                otherwise: otherwise.deep_clone(),
+            },
             Self::Merge { operand } => Self::Merge {
                 operand: operand.deep_clone(),
             },
@@ -189,12 +308,35 @@ impl<O: RootOperand> MultipleValuesWithIndexOperation<O> {
                     kind,
                 )?
             }
+            Self::FuzzyComparisonOperation { operand, kind } => {
+                let target = kind.get_target(graphrecord, operand)?;
+                let kind = *kind;
+
+                Box::new(values.filter(move |(_, value)| kind.matches(value, &target)))
+            }
+            Self::WithinRadius {
+                attribute,
+                operand,
+                radius_km,
+            } => Box::new(
+                Self::evaluate_within_radius(
+                    graphrecord,
+                    values,
+                    attribute.as_ref(),
+                    operand,
+                    radius_km.0,
+                )?
+                .into_iter(),
+            ),
             Self::BinaryArithmeticOperation { operand, kind } => Box::new(
                 Self::evaluate_binary_arithmetic_operation(graphrecord, values, operand, kind)?,
             ),
             Self::UnaryArithmeticOperation { kind } => Box::new(
                 Self::evaluate_unary_arithmetic_operation(values, kind.clone()),
             ),
+            Self::Apply { udf } => {
+                Box::new(Self::evaluate_apply(graphrecord, values, udf)?.into_iter())
+            }
             Self::Slice(range) => Box::new(Self::evaluate_slice(values, range.clone())),
             Self::IsString => Box::new(Self::evaluate_is_string(values)),
             Self::IsInt => Box::new(Self::evaluate_is_int(values)),
@@ -203,20 +345,75 @@ impl<O: RootOperand> MultipleValuesWithIndexOperation<O> {
             Self::IsDateTime => Box::new(Self::evaluate_is_datetime(values)),
             Self::IsDuration => Box::new(Self::evaluate_is_duration(values)),
             Self::IsNull => Box::new(Self::evaluate_is_null(values)),
+            Self::IsMissing => Box::new(Self::evaluate_is_missing(values)),
             Self::IsMax => Self::evaluate_is_max(values)?,
             Self::IsMin => Self::evaluate_is_min(values)?,
+            Self::Distinct => Box::new(Self::evaluate_distinct(values)),
+            Self::Sort { descending } => Box::new(Self::evaluate_sort(values, *descending)),
+            Self::Skip(count) => Box::new(values.skip(*count)),
+            Self::Limit(count) => Box::new(values.take(*count)),
+            Self::Rolling {
+                window,
+                aggregation,
+                order_by_attribute,
+            } => Box::new(Self::evaluate_rolling(
+                graphrecord,
+                values,
+                *window,
+                *aggregation,
+                order_by_attribute,
+            )?),
+            Self::Cumsum { order_by_attribute } => Box::new(Self::evaluate_cumsum(
+                graphrecord,
+                values,
+                order_by_attribute,
+            )?),
+            Self::Lag {
+                n,
+                order_by_attribute,
+            } => Box::new(Self::evaluate_lag(
+                graphrecord,
+                values,
+                *n,
+                order_by_attribute,
+            )?),
+            Self::Lead {
+                n,
+                order_by_attribute,
+            } => Box::new(Self::evaluate_lead(
+                graphrecord,
+                values,
+                *n,
+                order_by_attribute,
+            )?),
+            Self::Provenance { attribute } => Box::new(Self::evaluate_provenance(
+                graphrecord,
+                values,
+                attribute.clone(),
+            )?),
+            Self::TextSearch { attribute, query } => Box::new(
+                Self::evaluate_text_search(graphrecord, values, attribute.as_ref(), query)?
+                    .into_iter(),
+            ),
             Self::EitherOr { either, or } => {
                 Self::evaluate_either_or(graphrecord, values, either, or)?
             }
             Self::Exclude { operand } => Self::evaluate_exclude(graphrecord, values, operand)?,
+            Self::When {
+                operand,
+                then,
+                otherwise,
+            } => Self::evaluate_when(graphrecord, values, operand, then, otherwise)?,
             Self::Merge { operand: _ } => unreachable!(),
         })
     }
 
     #[inline]
     pub(crate) fn get_max<'a>(
-        mut values: impl Iterator<Item = (&'a O::Index, GraphRecordValue)>,
+        values: impl Iterator<Item = (&'a O::Index, GraphRecordValue)>,
     ) -> GraphRecordResult<Option<(&'a O::Index, GraphRecordValue)>> {
+        let mut values = values.filter(|(_, value)| !value.is_nan());
+
         let max_value = values.next();
 
         let Some(max_value) = max_value else {
@@ -243,8 +440,10 @@ impl<O: RootOperand> MultipleValuesWithIndexOperation<O> {
 
     #[inline]
     pub(crate) fn get_min<'a>(
-        mut values: impl Iterator<Item = (&'a O::Index, GraphRecordValue)>,
+        values: impl Iterator<Item = (&'a O::Index, GraphRecordValue)>,
     ) -> GraphRecordResult<Option<(&'a O::Index, GraphRecordValue)>> {
+        let mut values = values.filter(|(_, value)| !value.is_nan());
+
         let min_value = values.next();
 
         let Some(min_value) = min_value else {
@@ -271,9 +470,104 @@ impl<O: RootOperand> MultipleValuesWithIndexOperation<O> {
 
     #[inline]
     pub(crate) fn get_random<'a>(
+        graphrecord: &GraphRecord,
         values: impl Iterator<Item = (&'a O::Index, GraphRecordValue)>,
     ) -> Option<(&'a O::Index, GraphRecordValue)> {
-        values.choose(&mut rng())
+        QueryRng::new(graphrecord).choose(values, |(index, _), (other, _)| index.total_cmp(other))
+    }
+
+    /// Returns a random value, picked with a probability proportional to the
+    /// value of `attribute` of its index. Indices without `attribute` are
+    /// skipped.
+    #[inline]
+    pub(crate) fn get_random_weighted<'a>(
+        graphrecord: &'a GraphRecord,
+        values: impl Iterator<Item = (&'a O::Index, GraphRecordValue)>,
+        attribute: &GraphRecordAttribute,
+    ) -> GraphRecordResult<Option<(&'a O::Index, GraphRecordValue)>> {
+        let mut weighted_values = Vec::new();
+
+        for (index, value) in values {
+            let Some(weight) = index.get_attributes(graphrecord)?.get(attribute) else {
+                continue;
+            };
+
+            let weight = match weight {
+                GraphRecordValue::Int(weight) => *weight as f64,
+                GraphRecordValue::Float(weight) => *weight,
+                _ => {
+                    return Err(GraphRecordError::QueryError(format!(
+                        "Cannot use value of data type {} as weight. Weights must be ints or floats",
+                        DataType::from(weight)
+                    )));
+                }
+            };
+
+            if !weight.is_finite() || weight < 0.0 {
+                return Err(GraphRecordError::QueryError(format!(
+                    "Weights must be finite and non-negative, got {weight}"
+                )));
+            }
+
+            weighted_values.push(((index, value), weight));
+        }
+
+        Ok(QueryRng::new(graphrecord)
+            .choose_weighted(weighted_values.into_iter(), |(index, _), (other, _)| {
+                index.total_cmp(other)
+            }))
+    }
+
+    /// Returns the value of the index with the smallest value of `attribute`.
+    /// Indices without `attribute` are skipped.
+    #[inline]
+    pub(crate) fn get_first_by<'a>(
+        graphrecord: &'a GraphRecord,
+        values: impl Iterator<Item = (&'a O::Index, GraphRecordValue)>,
+        attribute: &GraphRecordAttribute,
+    ) -> GraphRecordResult<Option<(&'a O::Index, GraphRecordValue)>> {
+        let values: Vec<_> = values.collect();
+
+        let first =
+            Self::get_min(Self::get_order_values(graphrecord, &values, attribute)?.into_iter())?;
+
+        Ok(first.and_then(|(first_index, _)| {
+            values.into_iter().find(|(index, _)| *index == first_index)
+        }))
+    }
+
+    /// Returns the value of the index with the largest value of `attribute`.
+    /// Indices without `attribute` are skipped.
+    #[inline]
+    pub(crate) fn get_last_by<'a>(
+        graphrecord: &'a GraphRecord,
+        values: impl Iterator<Item = (&'a O::Index, GraphRecordValue)>,
+        attribute: &GraphRecordAttribute,
+    ) -> GraphRecordResult<Option<(&'a O::Index, GraphRecordValue)>> {
+        let values: Vec<_> = values.collect();
+
+        let last =
+            Self::get_max(Self::get_order_values(graphrecord, &values, attribute)?.into_iter())?;
+
+        Ok(last
+            .and_then(|(last_index, _)| values.into_iter().find(|(index, _)| *index == last_index)))
+    }
+
+    #[inline]
+    fn get_order_values<'a>(
+        graphrecord: &'a GraphRecord,
+        values: &[(&'a O::Index, GraphRecordValue)],
+        attribute: &GraphRecordAttribute,
+    ) -> GraphRecordResult<Vec<(&'a O::Index, GraphRecordValue)>> {
+        let mut order_values = Vec::new();
+
+        for (index, _) in values {
+            if let Some(value) = index.get_attributes(graphrecord)?.get(attribute) {
+                order_values.push((*index, value.clone()));
+            }
+        }
+
+        Ok(order_values)
     }
 
     #[inline]
@@ -292,7 +586,16 @@ impl<O: RootOperand> MultipleValuesWithIndexOperation<O> {
         let value = match kind {
             SingleKindWithIndex::Max => Self::get_max(values_1)?,
             SingleKindWithIndex::Min => Self::get_min(values_1)?,
-            SingleKindWithIndex::Random => Self::get_random(values_1),
+            SingleKindWithIndex::Random => Self::get_random(graphrecord, values_1),
+            SingleKindWithIndex::FirstBy(attribute) => {
+                Self::get_first_by(graphrecord, values_1, attribute)?
+            }
+            SingleKindWithIndex::LastBy(attribute) => {
+                Self::get_last_by(graphrecord, values_1, attribute)?
+            }
+            SingleKindWithIndex::RandomWeighted(attribute) => {
+                Self::get_random_weighted(graphrecord, values_1, attribute)?
+            }
         };
 
         Ok(match operand.evaluate_forward(graphrecord, value)? {
@@ -328,6 +631,9 @@ impl<O: RootOperand> MultipleValuesWithIndexOperation<O> {
             SingleKindWithoutIndex::Median => {
                 MultipleValuesWithoutIndexOperation::<O>::get_median(values_1)?
             }
+            SingleKindWithoutIndex::Quantile(quantile) => {
+                MultipleValuesWithoutIndexOperation::<O>::get_quantile(values_1, quantile.0)?
+            }
             SingleKindWithoutIndex::Mode => {
                 MultipleValuesWithoutIndexOperation::<O>::get_mode(values_1)
             }
@@ -340,11 +646,14 @@ impl<O: RootOperand> MultipleValuesWithIndexOperation<O> {
             SingleKindWithoutIndex::Count => Some(
                 MultipleValuesWithoutIndexOperation::<O>::get_count(values_1),
             ),
+            SingleKindWithoutIndex::CountDistinct => {
+                Some(MultipleValuesWithoutIndexOperation::<O>::get_count_distinct(values_1))
+            }
             SingleKindWithoutIndex::Sum => {
                 MultipleValuesWithoutIndexOperation::<O>::get_sum(values_1)?
             }
             SingleKindWithoutIndex::Random => {
-                MultipleValuesWithoutIndexOperation::<O>::get_random(values_1)
+                MultipleValuesWithoutIndexOperation::<O>::get_random(graphrecord, values_1)
             }
         };
 
@@ -364,6 +673,22 @@ impl<O: RootOperand> MultipleValuesWithIndexOperation<O> {
         let comparison_value = comparison_operand
             .evaluate_backward(graphrecord)?
             .ok_or_else(|| GraphRecordError::QueryError("No value to compare".to_string()))?;
+        let tolerance = graphrecord.float_tolerance();
+
+        let values: BoxedIterator<'a, _> =
+            if vectorized::supports_comparison(&comparison_value, kind) {
+                match vectorized::filter_by_comparison(
+                    Box::new(values),
+                    |(_, value)| value,
+                    &comparison_value,
+                    kind,
+                ) {
+                    Ok(values) => return Ok(Box::new(values.into_iter())),
+                    Err(values) => values,
+                }
+            } else {
+                Box::new(values)
+            };
 
         match kind {
             SingleComparisonKind::GreaterThan => Ok(Box::new(
@@ -378,12 +703,16 @@ impl<O: RootOperand> MultipleValuesWithIndexOperation<O> {
             SingleComparisonKind::LessThanOrEqualTo => Ok(Box::new(
                 values.filter(move |(_, value)| value <= &comparison_value),
             )),
-            SingleComparisonKind::EqualTo => Ok(Box::new(
-                values.filter(move |(_, value)| value == &comparison_value),
-            )),
-            SingleComparisonKind::NotEqualTo => Ok(Box::new(
-                values.filter(move |(_, value)| value != &comparison_value),
-            )),
+            SingleComparisonKind::EqualTo => {
+                Ok(Box::new(values.filter(move |(_, value)| {
+                    value.approx_eq(&comparison_value, tolerance)
+                })))
+            }
+            SingleComparisonKind::NotEqualTo => {
+                Ok(Box::new(values.filter(move |(_, value)| {
+                    !value.approx_eq(&comparison_value, tolerance)
+                })))
+            }
             SingleComparisonKind::StartsWith => {
                 Ok(Box::new(values.filter(move |(_, value)| {
                     value.starts_with(&comparison_value)
@@ -493,6 +822,21 @@ impl<O: RootOperand> MultipleValuesWithIndexOperation<O> {
         })
     }
 
+    #[inline]
+    fn evaluate_apply<'a>(
+        graphrecord: &GraphRecord,
+        values: impl Iterator<Item = (&'a O::Index, GraphRecordValue)>,
+        udf: &str,
+    ) -> GraphRecordResult<Vec<(&'a O::Index, GraphRecordValue)>>
+    where
+        O: 'a,
+    {
+        let udf = graphrecord.udf(udf)?;
+        SubqueryCache::mark_nondeterministic();
+
+        values.map(|(t, value)| Ok((t, udf(value)?))).collect()
+    }
+
     #[inline]
     fn evaluate_slice<'a>(
         values: impl Iterator<Item = (&'a O::Index, GraphRecordValue)>,
@@ -574,6 +918,101 @@ impl<O: RootOperand> MultipleValuesWithIndexOperation<O> {
         values.filter(|(_, value)| matches!(value, GraphRecordValue::Null))
     }
 
+    #[inline]
+    fn evaluate_is_missing<'a>(
+        values: impl Iterator<Item = (&'a O::Index, GraphRecordValue)>,
+    ) -> impl Iterator<Item = (&'a O::Index, GraphRecordValue)>
+    where
+        O: 'a,
+    {
+        values.filter(|(_, value)| matches!(value, GraphRecordValue::Missing))
+    }
+
+    #[inline]
+    fn evaluate_provenance<'a>(
+        graphrecord: &'a GraphRecord,
+        values: impl Iterator<Item = (&'a O::Index, GraphRecordValue)> + 'a,
+        attribute: Option<GraphRecordAttribute>,
+    ) -> GraphRecordResult<impl Iterator<Item = (&'a O::Index, GraphRecordValue)> + 'a>
+    where
+        O: 'a,
+    {
+        let attribute = attribute.ok_or_else(|| {
+            GraphRecordError::QueryError(
+                "Provenance can only be queried for the values of a single attribute".to_string(),
+            )
+        })?;
+
+        Ok(values.map(move |(index, _)| {
+            let source = O::_get_provenance(graphrecord, index, &attribute)
+                .map_or(GraphRecordValue::Null, |tag| tag.source.clone().into());
+
+            (index, source)
+        }))
+    }
+
+    #[inline]
+    fn evaluate_text_search<'a>(
+        graphrecord: &'a GraphRecord,
+        values: impl Iterator<Item = (&'a O::Index, GraphRecordValue)> + 'a,
+        attribute: Option<&GraphRecordAttribute>,
+        query: &str,
+    ) -> GraphRecordResult<Vec<(&'a O::Index, GraphRecordValue)>>
+    where
+        O: 'a,
+    {
+        let tokens: Vec<_> = text_index::tokenize(query).unique().collect();
+
+        if tokens.is_empty() {
+            return Err(GraphRecordError::QueryError(
+                "Text search query must contain at least one word".to_string(),
+            ));
+        }
+
+        Ok(values
+            .filter(|(index, value)| {
+                let GraphRecordValue::String(text) = value else {
+                    return false;
+                };
+
+                attribute
+                    .and_then(|attribute| {
+                        O::_text_index_matches(graphrecord, index, attribute, text, &tokens)
+                    })
+                    .unwrap_or_else(|| text_index::contains_tokens(text, &tokens))
+            })
+            .collect())
+    }
+
+    #[inline]
+    fn evaluate_within_radius<'a>(
+        graphrecord: &'a GraphRecord,
+        values: impl Iterator<Item = (&'a O::Index, GraphRecordValue)> + 'a,
+        attribute: Option<&GraphRecordAttribute>,
+        operand: &SingleValueComparisonOperand,
+        radius_km: f64,
+    ) -> GraphRecordResult<Vec<(&'a O::Index, GraphRecordValue)>>
+    where
+        O: 'a,
+    {
+        let center = get_radius_center(graphrecord, operand, radius_km)?;
+        let indexed = attribute.and_then(|attribute| {
+            O::_geo_index_within_radius(graphrecord, attribute, &center, radius_km)
+        });
+
+        // Values that are not among the indexed points within the radius may
+        // still belong to nodes the index does not cover, so they are measured
+        Ok(values
+            .filter(|(index, value)| {
+                indexed
+                    .as_ref()
+                    .and_then(|indexed| indexed.get(*index))
+                    .is_some_and(|point| value == &GraphRecordValue::GeoPoint(*point))
+                    || is_within_radius(value, &center, radius_km)
+            })
+            .collect())
+    }
+
     #[inline]
     fn evaluate_is_max<'a>(
         values: impl Iterator<Item = (&'a O::Index, GraphRecordValue)> + 'a,
@@ -614,6 +1053,243 @@ impl<O: RootOperand> MultipleValuesWithIndexOperation<O> {
         ))
     }
 
+    #[inline]
+    fn evaluate_distinct<'a>(
+        values: impl Iterator<Item = (&'a O::Index, GraphRecordValue)> + 'a,
+    ) -> impl Iterator<Item = (&'a O::Index, GraphRecordValue)> + 'a
+    where
+        O: 'a,
+    {
+        values.unique_by(|(_, value)| value.clone())
+    }
+
+    #[inline]
+    fn evaluate_sort<'a>(
+        values: impl Iterator<Item = (&'a O::Index, GraphRecordValue)>,
+        descending: bool,
+    ) -> impl Iterator<Item = (&'a O::Index, GraphRecordValue)>
+    where
+        O: 'a,
+    {
+        let mut values: Vec<_> = values.collect();
+
+        values.sort_by(|(_, value), (_, other)| {
+            if descending {
+                other.total_cmp(value)
+            } else {
+                value.total_cmp(other)
+            }
+        });
+
+        values.into_iter()
+    }
+
+    /// Sorts the values by the `DateTime` attribute `order_by_attribute` of their
+    /// indices. Values whose index lacks the attribute are dropped.
+    fn order_by_time<'a>(
+        graphrecord: &'a GraphRecord,
+        values: impl Iterator<Item = (&'a O::Index, GraphRecordValue)>,
+        order_by_attribute: &GraphRecordAttribute,
+    ) -> GraphRecordResult<Vec<(NaiveDateTime, &'a O::Index, GraphRecordValue)>>
+    where
+        O: 'a,
+    {
+        let values: Vec<_> = values.collect();
+
+        let times: GrHashMap<_, _> = O::get_values_from_indices(
+            graphrecord,
+            order_by_attribute.clone(),
+            values
+                .iter()
+                .map(|(index, _)| *index)
+                .collect::<Vec<_>>()
+                .into_iter(),
+        )
+        .collect();
+
+        let mut values = values
+            .into_iter()
+            .filter_map(|(index, value)| times.get(index).map(|time| (time, index, value)))
+            .map(|(time, index, value)| match time {
+                GraphRecordValue::DateTime(time) => Ok((*time, index, value)),
+                _ => Err(GraphRecordError::QueryError(format!(
+                    "Cannot order values by attribute {order_by_attribute} of data type {}. Consider using a DateTime attribute",
+                    DataType::from(time)
+                ))),
+            })
+            .collect::<GraphRecordResult<Vec<_>>>()?;
+
+        // A stable sort keeps values with equal times in their original order
+        values.sort_by_key(|(time, _, _)| *time);
+
+        Ok(values)
+    }
+
+    #[inline]
+    fn evaluate_rolling<'a>(
+        graphrecord: &'a GraphRecord,
+        values: impl Iterator<Item = (&'a O::Index, GraphRecordValue)> + 'a,
+        window: TimeDelta,
+        aggregation: RollingAggregation,
+        order_by_attribute: &GraphRecordAttribute,
+    ) -> GraphRecordResult<impl Iterator<Item = (&'a O::Index, GraphRecordValue)> + 'a>
+    where
+        O: 'a,
+    {
+        if window <= TimeDelta::zero() {
+            return Err(GraphRecordError::QueryError(
+                "Rolling window must be positive".to_string(),
+            ));
+        }
+
+        let values = Self::order_by_time(graphrecord, values, order_by_attribute)?
+            .into_iter()
+            .map(|(time, index, value)| {
+                let value = match value {
+                    GraphRecordValue::Int(value) => value as f64,
+                    GraphRecordValue::Float(value) => value,
+                    _ => {
+                        return Err(GraphRecordError::QueryError(format!(
+                            "Cannot calculate rolling {} of data type {}. Consider narrowing down the values using .is_int() or .is_float()",
+                            aggregation,
+                            DataType::from(value)
+                        )));
+                    }
+                };
+
+                Ok((time, index, value))
+            })
+            .collect::<GraphRecordResult<Vec<_>>>()?;
+
+        let mut start = 0;
+        let mut sum = 0.0;
+
+        let mut rolled = Vec::with_capacity(values.len());
+
+        for (end, (time, index, value)) in values.iter().enumerate() {
+            sum += value;
+
+            // The window covers the times in (time - window, time]
+            let window_start = time.checked_sub_signed(window);
+
+            while window_start.is_some_and(|window_start| values[start].0 <= window_start) {
+                sum -= values[start].2;
+                start += 1;
+            }
+
+            let value = match aggregation {
+                RollingAggregation::Mean => sum / (end - start + 1) as f64,
+                RollingAggregation::Sum => sum,
+            };
+
+            rolled.push((*index, GraphRecordValue::Float(value)));
+        }
+
+        Ok(rolled.into_iter())
+    }
+
+    #[inline]
+    fn evaluate_cumsum<'a>(
+        graphrecord: &'a GraphRecord,
+        values: impl Iterator<Item = (&'a O::Index, GraphRecordValue)> + 'a,
+        order_by_attribute: &GraphRecordAttribute,
+    ) -> GraphRecordResult<impl Iterator<Item = (&'a O::Index, GraphRecordValue)> + 'a>
+    where
+        O: 'a,
+    {
+        let values = Self::order_by_time(graphrecord, values, order_by_attribute)?;
+
+        let mut sum: Option<GraphRecordValue> = None;
+
+        let values = values
+            .into_iter()
+            .map(|(_, index, value)| {
+                let value = match sum.take() {
+                    Some(sum) => {
+                        let first_dtype = DataType::from(&sum);
+                        let second_dtype = DataType::from(&value);
+
+                        sum.add(value).map_err(|_| {
+                            GraphRecordError::QueryError(format!(
+                                "Cannot add values of data types {first_dtype} and {second_dtype}. Consider narrowing down the values using .is_string(), .is_int(), .is_float(), .is_bool(), .is_datetime() or .is_duration()"
+                            ))
+                        })?
+                    }
+                    None => value,
+                };
+
+                sum = Some(value.clone());
+
+                Ok((index, value))
+            })
+            .collect::<GraphRecordResult<Vec<_>>>()?;
+
+        Ok(values.into_iter())
+    }
+
+    /// Replaces the value at each position in time order with the value at
+    /// position `source(position)`, or with null if there is no such value.
+    #[inline]
+    fn evaluate_shift<'a>(
+        graphrecord: &'a GraphRecord,
+        values: impl Iterator<Item = (&'a O::Index, GraphRecordValue)> + 'a,
+        order_by_attribute: &GraphRecordAttribute,
+        source: impl Fn(usize) -> Option<usize>,
+    ) -> GraphRecordResult<Vec<(&'a O::Index, GraphRecordValue)>>
+    where
+        O: 'a,
+    {
+        let values = Self::order_by_time(graphrecord, values, order_by_attribute)?;
+
+        Ok(values
+            .iter()
+            .enumerate()
+            .map(|(position, (_, index, _))| {
+                let value = source(position)
+                    .and_then(|source| values.get(source))
+                    .map_or(GraphRecordValue::Null, |(_, _, value)| value.clone());
+
+                (*index, value)
+            })
+            .collect())
+    }
+
+    #[inline]
+    fn evaluate_lag<'a>(
+        graphrecord: &'a GraphRecord,
+        values: impl Iterator<Item = (&'a O::Index, GraphRecordValue)> + 'a,
+        n: usize,
+        order_by_attribute: &GraphRecordAttribute,
+    ) -> GraphRecordResult<impl Iterator<Item = (&'a O::Index, GraphRecordValue)> + 'a>
+    where
+        O: 'a,
+    {
+        Ok(
+            Self::evaluate_shift(graphrecord, values, order_by_attribute, move |position| {
+                position.checked_sub(n)
+            })?
+            .into_iter(),
+        )
+    }
+
+    #[inline]
+    fn evaluate_lead<'a>(
+        graphrecord: &'a GraphRecord,
+        values: impl Iterator<Item = (&'a O::Index, GraphRecordValue)> + 'a,
+        n: usize,
+        order_by_attribute: &GraphRecordAttribute,
+    ) -> GraphRecordResult<impl Iterator<Item = (&'a O::Index, GraphRecordValue)> + 'a>
+    where
+        O: 'a,
+    {
+        Ok(
+            Self::evaluate_shift(graphrecord, values, order_by_attribute, move |position| {
+                position.checked_add(n)
+            })?
+            .into_iter(),
+        )
+    }
+
     #[inline]
     fn evaluate_either_or<'a>(
         graphrecord: &'a GraphRecord,
@@ -654,6 +1330,51 @@ impl<O: RootOperand> MultipleValuesWithIndexOperation<O> {
 
         Ok(Box::new(values_2.filter(move |(t, _)| !result.contains(t))))
     }
+
+    #[inline]
+    fn get_when_values(
+        graphrecord: &GraphRecord,
+        then: &SingleValueComparisonOperand,
+        otherwise: &SingleValueComparisonOperand,
+    ) -> GraphRecordResult<(GraphRecordValue, GraphRecordValue)> {
+        let then = then
+            .evaluate_backward(graphrecord)?
+            .ok_or_else(|| GraphRecordError::QueryError("No value to use for then".to_string()))?;
+        let otherwise = otherwise.evaluate_backward(graphrecord)?.ok_or_else(|| {
+            GraphRecordError::QueryError("No value to use for otherwise".to_string())
+        })?;
+
+        Ok((then, otherwise))
+    }
+
+    #[inline]
+    fn evaluate_when<'a>(
+        graphrecord: &'a GraphRecord,
+        values: impl Iterator<Item = (&'a O::Index, GraphRecordValue)> + 'a,
+        operand: &Wrapper<MultipleValuesWithIndexOperand<O>>,
+        then: &SingleValueComparisonOperand,
+        otherwise: &SingleValueComparisonOperand,
+    ) -> GraphRecordResult<BoxedIterator<'a, (&'a O::Index, GraphRecordValue)>>
+    where
+        O: 'a,
+    {
+        let (values_1, values_2) = Itertools::tee(values);
+
+        let matched: GrHashSet<_> = operand
+            .evaluate_forward(graphrecord, Box::new(values_1))?
+            .map(|(t, _)| t)
+            .collect();
+
+        let (then, otherwise) = Self::get_when_values(graphrecord, then, otherwise)?;
+
+        Ok(Box::new(values_2.map(move |(t, _)| {
+            if matched.contains(t) {
+                (t, then.clone())
+            } else {
+                (t, otherwise.clone())
+            }
+        })))
+    }
 }
 
 impl<O: RootOperand> MultipleValuesWithIndexOperation<O> {
@@ -705,6 +1426,44 @@ impl<O: RootOperand> MultipleValuesWithIndexOperation<O> {
                     .collect::<GraphRecordResult<Vec<_>>>()?
                     .into_iter(),
             ),
+            Self::FuzzyComparisonOperation { operand, kind } => {
+                let target = kind.get_target(graphrecord, operand)?;
+                let kind = *kind;
+
+                Box::new(values.map(move |(key, values)| {
+                    let target = target.clone();
+
+                    (
+                        key,
+                        Box::new(values.filter(move |(_, value)| kind.matches(value, &target)))
+                            as BoxedIterator<_>,
+                    )
+                }))
+            }
+            Self::WithinRadius {
+                attribute,
+                operand,
+                radius_km,
+            } => Box::new(
+                values
+                    .map(move |(key, values)| {
+                        Ok((
+                            key,
+                            Box::new(
+                                Self::evaluate_within_radius(
+                                    graphrecord,
+                                    values,
+                                    attribute.as_ref(),
+                                    operand,
+                                    radius_km.0,
+                                )?
+                                .into_iter(),
+                            ) as BoxedIterator<_>,
+                        ))
+                    })
+                    .collect::<GraphRecordResult<Vec<_>>>()?
+                    .into_iter(),
+            ),
             Self::BinaryArithmeticOperation { operand, kind } => Box::new(
                 values
                     .map(move |(key, values)| {
@@ -734,6 +1493,18 @@ impl<O: RootOperand> MultipleValuesWithIndexOperation<O> {
                     )
                 }))
             }
+            Self::Apply { udf } => Box::new(
+                values
+                    .map(move |(key, values)| {
+                        Ok((
+                            key,
+                            Box::new(Self::evaluate_apply(graphrecord, values, udf)?.into_iter())
+                                as BoxedIterator<_>,
+                        ))
+                    })
+                    .collect::<GraphRecordResult<Vec<_>>>()?
+                    .into_iter(),
+            ),
             Self::Slice(range) => {
                 let range = range.clone();
 
@@ -786,6 +1557,12 @@ impl<O: RootOperand> MultipleValuesWithIndexOperation<O> {
                     Box::new(Self::evaluate_is_null(values)) as BoxedIterator<_>,
                 )
             })),
+            Self::IsMissing => Box::new(values.map(move |(key, values)| {
+                (
+                    key,
+                    Box::new(Self::evaluate_is_missing(values)) as BoxedIterator<_>,
+                )
+            })),
             Self::IsMax => Box::new(
                 values
                     .map(move |(key, values)| {
@@ -808,12 +1585,155 @@ impl<O: RootOperand> MultipleValuesWithIndexOperation<O> {
                     .collect::<GraphRecordResult<Vec<_>>>()?
                     .into_iter(),
             ),
+            Self::Distinct => Box::new(values.map(move |(key, values)| {
+                (
+                    key,
+                    Box::new(Self::evaluate_distinct(values)) as BoxedIterator<_>,
+                )
+            })),
+            Self::Sort { descending } => {
+                let descending = *descending;
+
+                Box::new(values.map(move |(key, values)| {
+                    (
+                        key,
+                        Box::new(Self::evaluate_sort(values, descending)) as BoxedIterator<_>,
+                    )
+                }))
+            }
+            Self::Skip(count) => {
+                let count = *count;
+
+                Box::new(values.map(move |(key, values)| {
+                    (key, Box::new(values.skip(count)) as BoxedIterator<_>)
+                }))
+            }
+            Self::Limit(count) => {
+                let count = *count;
+
+                Box::new(values.map(move |(key, values)| {
+                    (key, Box::new(values.take(count)) as BoxedIterator<_>)
+                }))
+            }
+            Self::Rolling {
+                window,
+                aggregation,
+                order_by_attribute,
+            } => Box::new(
+                values
+                    .map(move |(key, values)| {
+                        Ok((
+                            key,
+                            Box::new(Self::evaluate_rolling(
+                                graphrecord,
+                                values,
+                                *window,
+                                *aggregation,
+                                order_by_attribute,
+                            )?) as BoxedIterator<_>,
+                        ))
+                    })
+                    .collect::<GraphRecordResult<Vec<_>>>()?
+                    .into_iter(),
+            ),
+            Self::Cumsum { order_by_attribute } => Box::new(
+                values
+                    .map(move |(key, values)| {
+                        Ok((
+                            key,
+                            Box::new(Self::evaluate_cumsum(
+                                graphrecord,
+                                values,
+                                order_by_attribute,
+                            )?) as BoxedIterator<_>,
+                        ))
+                    })
+                    .collect::<GraphRecordResult<Vec<_>>>()?
+                    .into_iter(),
+            ),
+            Self::Lag {
+                n,
+                order_by_attribute,
+            } => Box::new(
+                values
+                    .map(move |(key, values)| {
+                        Ok((
+                            key,
+                            Box::new(Self::evaluate_lag(
+                                graphrecord,
+                                values,
+                                *n,
+                                order_by_attribute,
+                            )?) as BoxedIterator<_>,
+                        ))
+                    })
+                    .collect::<GraphRecordResult<Vec<_>>>()?
+                    .into_iter(),
+            ),
+            Self::Lead {
+                n,
+                order_by_attribute,
+            } => Box::new(
+                values
+                    .map(move |(key, values)| {
+                        Ok((
+                            key,
+                            Box::new(Self::evaluate_lead(
+                                graphrecord,
+                                values,
+                                *n,
+                                order_by_attribute,
+                            )?) as BoxedIterator<_>,
+                        ))
+                    })
+                    .collect::<GraphRecordResult<Vec<_>>>()?
+                    .into_iter(),
+            ),
+            Self::Provenance { attribute } => Box::new(
+                values
+                    .map(move |(key, values)| {
+                        Ok((
+                            key,
+                            Box::new(Self::evaluate_provenance(
+                                graphrecord,
+                                values,
+                                attribute.clone(),
+                            )?) as BoxedIterator<_>,
+                        ))
+                    })
+                    .collect::<GraphRecordResult<Vec<_>>>()?
+                    .into_iter(),
+            ),
+            Self::TextSearch { attribute, query } => Box::new(
+                values
+                    .map(move |(key, values)| {
+                        Ok((
+                            key,
+                            Box::new(
+                                Self::evaluate_text_search(
+                                    graphrecord,
+                                    values,
+                                    attribute.as_ref(),
+                                    query,
+                                )?
+                                .into_iter(),
+                            ) as BoxedIterator<_>,
+                        ))
+                    })
+                    .collect::<GraphRecordResult<Vec<_>>>()?
+                    .into_iter(),
+            ),
             Self::EitherOr { either, or } => {
                 Self::evaluate_either_or_grouped(graphrecord, values, either, or)?
             }
             Self::Exclude { operand } => {
                 Self::evaluate_exclude_grouped(graphrecord, values, operand)?
             }
+            Self::When {
+                operand,
+                then,
+                otherwise,
+            } => Self::evaluate_when_grouped(graphrecord, values, operand, then, otherwise)?,
             Self::Merge { operand } => {
                 let (values_1, values_2) = tee_grouped_iterator(values);
 
@@ -854,7 +1774,16 @@ impl<O: RootOperand> MultipleValuesWithIndexOperation<O> {
                 let value = match kind {
                     SingleKindWithIndex::Max => Self::get_max(values)?,
                     SingleKindWithIndex::Min => Self::get_min(values)?,
-                    SingleKindWithIndex::Random => Self::get_random(values),
+                    SingleKindWithIndex::Random => Self::get_random(graphrecord, values),
+                    SingleKindWithIndex::FirstBy(attribute) => {
+                        Self::get_first_by(graphrecord, values, attribute)?
+                    }
+                    SingleKindWithIndex::LastBy(attribute) => {
+                        Self::get_last_by(graphrecord, values, attribute)?
+                    }
+                    SingleKindWithIndex::RandomWeighted(attribute) => {
+                        Self::get_random_weighted(graphrecord, values, attribute)?
+                    }
                 };
 
                 Ok((key, value))
@@ -909,6 +1838,9 @@ impl<O: RootOperand> MultipleValuesWithIndexOperation<O> {
                     SingleKindWithoutIndex::Median => {
                         MultipleValuesWithoutIndexOperation::<O>::get_median(values)?
                     }
+                    SingleKindWithoutIndex::Quantile(quantile) => {
+                        MultipleValuesWithoutIndexOperation::<O>::get_quantile(values, quantile.0)?
+                    }
                     SingleKindWithoutIndex::Mode => {
                         MultipleValuesWithoutIndexOperation::<O>::get_mode(values)
                     }
@@ -921,11 +1853,14 @@ impl<O: RootOperand> MultipleValuesWithIndexOperation<O> {
                     SingleKindWithoutIndex::Count => {
                         Some(MultipleValuesWithoutIndexOperation::<O>::get_count(values))
                     }
+                    SingleKindWithoutIndex::CountDistinct => {
+                        Some(MultipleValuesWithoutIndexOperation::<O>::get_count_distinct(values))
+                    }
                     SingleKindWithoutIndex::Sum => {
                         MultipleValuesWithoutIndexOperation::<O>::get_sum(values)?
                     }
                     SingleKindWithoutIndex::Random => {
-                        MultipleValuesWithoutIndexOperation::<O>::get_random(values)
+                        MultipleValuesWithoutIndexOperation::<O>::get_random(graphrecord, values)
                     }
                 };
 
@@ -989,10 +1924,45 @@ impl<O: RootOperand> MultipleValuesWithIndexOperation<O> {
 
     #[allow(clippy::type_complexity)]
     #[inline]
-    fn evaluate_exclude_grouped<'a>(
+    fn evaluate_exclude_grouped<'a>(
+        graphrecord: &'a GraphRecord,
+        values: GroupedIterator<'a, BoxedIterator<'a, (&'a O::Index, GraphRecordValue)>>,
+        operand: &Wrapper<MultipleValuesWithIndexOperand<O>>,
+    ) -> GraphRecordResult<GroupedIterator<'a, BoxedIterator<'a, (&'a O::Index, GraphRecordValue)>>>
+    where
+        O: 'a,
+    {
+        let (values_1, values_2) = tee_grouped_iterator(values);
+
+        let mut result: Vec<_> = operand
+            .evaluate_forward_grouped(graphrecord, values_1)?
+            .collect();
+
+        let values = values_2.map(move |(key, values)| {
+            let values_position = result
+                .iter()
+                .position(|(k, _)| k == &key)
+                .expect("Entry must exist");
+
+            let excluded_values: Vec<_> = result.remove(values_position).1.collect();
+
+            let values: BoxedIterator<_> =
+                Box::new(values.filter(move |value| !excluded_values.contains(value)));
+
+            (key, values)
+        });
+
+        Ok(Box::new(values))
+    }
+
+    #[allow(clippy::type_complexity)]
+    #[inline]
+    fn evaluate_when_grouped<'a>(
         graphrecord: &'a GraphRecord,
         values: GroupedIterator<'a, BoxedIterator<'a, (&'a O::Index, GraphRecordValue)>>,
         operand: &Wrapper<MultipleValuesWithIndexOperand<O>>,
+        then: &SingleValueComparisonOperand,
+        otherwise: &SingleValueComparisonOperand,
     ) -> GraphRecordResult<GroupedIterator<'a, BoxedIterator<'a, (&'a O::Index, GraphRecordValue)>>>
     where
         O: 'a,
@@ -1003,16 +1973,26 @@ impl<O: RootOperand> MultipleValuesWithIndexOperation<O> {
             .evaluate_forward_grouped(graphrecord, values_1)?
             .collect();
 
+        let (then, otherwise) = Self::get_when_values(graphrecord, then, otherwise)?;
+
         let values = values_2.map(move |(key, values)| {
             let values_position = result
                 .iter()
                 .position(|(k, _)| k == &key)
                 .expect("Entry must exist");
 
-            let excluded_values: Vec<_> = result.remove(values_position).1.collect();
+            let matched: GrHashSet<_> = result.remove(values_position).1.map(|(t, _)| t).collect();
 
-            let values: BoxedIterator<_> =
-                Box::new(values.filter(move |value| !excluded_values.contains(value)));
+            let then = then.clone();
+            let otherwise = otherwise.clone();
+
+            let values: BoxedIterator<_> = Box::new(values.map(move |(t, _)| {
+                if matched.contains(t) {
+                    (t, then.clone())
+                } else {
+                    (t, otherwise.clone())
+                }
+            }));
 
             (key, values)
         });
@@ -1021,7 +2001,7 @@ impl<O: RootOperand> MultipleValuesWithIndexOperation<O> {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum MultipleValuesWithoutIndexOperation<O: RootOperand> {
     ValueOperation {
         operand: Wrapper<SingleValueWithoutIndexOperand<O>>,
@@ -1034,6 +2014,14 @@ pub enum MultipleValuesWithoutIndexOperation<O: RootOperand> {
         operand: MultipleValuesComparisonOperand,
         kind: MultipleComparisonKind,
     },
+    FuzzyComparisonOperation {
+        operand: SingleValueComparisonOperand,
+        kind: FuzzyComparisonKind,
+    },
+    WithinRadius {
+        operand: SingleValueComparisonOperand,
+        radius_km: QueryFloat,
+    },
     BinaryArithmeticOperation {
         operand: SingleValueComparisonOperand,
         kind: BinaryArithmeticKind,
@@ -1041,6 +2029,9 @@ pub enum MultipleValuesWithoutIndexOperation<O: RootOperand> {
     UnaryArithmeticOperation {
         kind: UnaryArithmeticKind,
     },
+    Apply {
+        udf: String,
+    },
 
     Slice(Range<usize>),
 
@@ -1051,10 +2042,19 @@ pub enum MultipleValuesWithoutIndexOperation<O: RootOperand> {
     IsDateTime,
     IsDuration,
     IsNull,
+    IsMissing,
 
     IsMax,
     IsMin,
 
+    Distinct,
+
+    Sort {
+        descending: bool,
+    },
+    Skip(usize),
+    Limit(usize),
+
     EitherOr {
         either: Wrapper<MultipleValuesWithoutIndexOperand<O>>,
         or: Wrapper<MultipleValuesWithoutIndexOperand<O>>,
@@ -1082,6 +2082,14 @@ impl<O: RootOperand> DeepClone for MultipleValuesWithoutIndexOperation<O> {
                     kind: kind.clone(),
                 }
             }
+            Self::FuzzyComparisonOperation { operand, kind } => Self::FuzzyComparisonOperation {
+                operand: operand.deep_clone(),
+                kind: *kind,
+            },
+            Self::WithinRadius { operand, radius_km } => Self::WithinRadius {
+                operand: operand.deep_clone(),
+                radius_km: *radius_km,
+            },
             Self::BinaryArithmeticOperation { operand, kind } => Self::BinaryArithmeticOperation {
                 operand: operand.deep_clone(),
                 kind: kind.clone(),
@@ -1089,6 +2097,7 @@ impl<O: RootOperand> DeepClone for MultipleValuesWithoutIndexOperation<O> {
             Self::UnaryArithmeticOperation { kind } => {
                 Self::UnaryArithmeticOperation { kind: kind.clone() }
             }
+            Self::Apply { udf } => Self::Apply { udf: udf.clone() },
             Self::Slice(range) => Self::Slice(range.clone()),
             Self::IsString => Self::IsString,
             Self::IsInt => Self::IsInt,
@@ -1097,8 +2106,15 @@ impl<O: RootOperand> DeepClone for MultipleValuesWithoutIndexOperation<O> {
             Self::IsDateTime => Self::IsDateTime,
             Self::IsDuration => Self::IsDuration,
             Self::IsNull => Self::IsNull,
+            Self::IsMissing => Self::IsMissing,
             Self::IsMax => Self::IsMax,
             Self::IsMin => Self::IsMin,
+            Self::Distinct => Self::Distinct,
+            Self::Sort { descending } => Self::Sort {
+                descending: *descending,
+            },
+            Self::Skip(count) => Self::Skip(*count),
+            Self::Limit(count) => Self::Limit(*count),
             Self::EitherOr { either, or } => Self::EitherOr {
                 either: either.deep_clone(),
                 or: or.deep_clone(),
@@ -1139,12 +2155,27 @@ impl<O: RootOperand> MultipleValuesWithoutIndexOperation<O> {
                     kind,
                 )?
             }
+            Self::FuzzyComparisonOperation { operand, kind } => {
+                let target = kind.get_target(graphrecord, operand)?;
+                let kind = *kind;
+
+                Box::new(values.filter(move |value| kind.matches(value, &target)))
+            }
+            Self::WithinRadius { operand, radius_km } => {
+                let radius_km = *radius_km;
+                let center = get_radius_center(graphrecord, operand, radius_km.0)?;
+
+                Box::new(values.filter(move |value| is_within_radius(value, &center, radius_km.0)))
+            }
             Self::BinaryArithmeticOperation { operand, kind } => Box::new(
                 Self::evaluate_binary_arithmetic_operation(graphrecord, values, operand, kind)?,
             ),
             Self::UnaryArithmeticOperation { kind } => Box::new(
                 Self::evaluate_unary_arithmetic_operation(values, kind.clone()),
             ),
+            Self::Apply { udf } => {
+                Box::new(Self::evaluate_apply(graphrecord, values, udf)?.into_iter())
+            }
             Self::Slice(range) => Box::new(Self::evaluate_slice(values, range.clone())),
             Self::IsString => Box::new(Self::evaluate_is_string(values)),
             Self::IsInt => Box::new(Self::evaluate_is_int(values)),
@@ -1153,8 +2184,13 @@ impl<O: RootOperand> MultipleValuesWithoutIndexOperation<O> {
             Self::IsDateTime => Box::new(Self::evaluate_is_datetime(values)),
             Self::IsDuration => Box::new(Self::evaluate_is_duration(values)),
             Self::IsNull => Box::new(Self::evaluate_is_null(values)),
+            Self::IsMissing => Box::new(Self::evaluate_is_missing(values)),
             Self::IsMax => Self::evaluate_is_max(values)?,
             Self::IsMin => Self::evaluate_is_min(values)?,
+            Self::Distinct => Box::new(Self::evaluate_distinct(values)),
+            Self::Sort { descending } => Box::new(Self::evaluate_sort(values, *descending)),
+            Self::Skip(count) => Box::new(values.skip(*count)),
+            Self::Limit(count) => Box::new(values.take(*count)),
             Self::EitherOr { either, or } => {
                 Self::evaluate_either_or(graphrecord, values, either, or)?
             }
@@ -1164,8 +2200,10 @@ impl<O: RootOperand> MultipleValuesWithoutIndexOperation<O> {
 
     #[inline]
     pub(crate) fn get_max(
-        mut values: impl Iterator<Item = GraphRecordValue>,
+        values: impl Iterator<Item = GraphRecordValue>,
     ) -> GraphRecordResult<Option<GraphRecordValue>> {
+        let mut values = values.filter(|value| !value.is_nan());
+
         let max_value = values.next();
 
         let Some(max_value) = max_value else {
@@ -1192,8 +2230,10 @@ impl<O: RootOperand> MultipleValuesWithoutIndexOperation<O> {
 
     #[inline]
     pub(crate) fn get_min(
-        mut values: impl Iterator<Item = GraphRecordValue>,
+        values: impl Iterator<Item = GraphRecordValue>,
     ) -> GraphRecordResult<Option<GraphRecordValue>> {
+        let mut values = values.filter(|value| !value.is_nan());
+
         let min_value = values.next();
 
         let Some(min_value) = min_value else {
@@ -1220,8 +2260,10 @@ impl<O: RootOperand> MultipleValuesWithoutIndexOperation<O> {
 
     #[inline]
     pub(crate) fn get_mean(
-        mut values: impl Iterator<Item = GraphRecordValue>,
+        values: impl Iterator<Item = GraphRecordValue>,
     ) -> GraphRecordResult<Option<GraphRecordValue>> {
+        let mut values = values.filter(|value| !value.is_nan());
+
         let first_value = values.next();
 
         let Some(first_value) = first_value else {
@@ -1246,8 +2288,10 @@ impl<O: RootOperand> MultipleValuesWithoutIndexOperation<O> {
     // TODO: This is a temporary solution. It should be optimized.
     #[inline]
     pub(crate) fn get_median(
-        mut values: impl Iterator<Item = GraphRecordValue>,
+        values: impl Iterator<Item = GraphRecordValue>,
     ) -> GraphRecordResult<Option<GraphRecordValue>> {
+        let mut values = values.filter(|value| !value.is_nan());
+
         let first_value = values.next();
 
         let Some(first_value) = first_value else {
@@ -1331,6 +2375,88 @@ impl<O: RootOperand> MultipleValuesWithoutIndexOperation<O> {
         Ok(Some(median))
     }
 
+    #[inline]
+    pub(crate) fn get_quantile(
+        values: impl Iterator<Item = GraphRecordValue>,
+        quantile: f64,
+    ) -> GraphRecordResult<Option<GraphRecordValue>> {
+        Self::validate_quantile(quantile)?;
+
+        let mut values = Self::get_quantile_values(values)?;
+
+        if values.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(GraphRecordValue::Float(Self::select_quantile(
+            &mut values,
+            quantile,
+        ))))
+    }
+
+    #[inline]
+    pub(crate) fn get_quantiles(
+        values: impl Iterator<Item = GraphRecordValue>,
+        quantiles: &[f64],
+    ) -> GraphRecordResult<Vec<(f64, f64)>> {
+        for quantile in quantiles {
+            Self::validate_quantile(*quantile)?;
+        }
+
+        let mut values = Self::get_quantile_values(values)?;
+
+        if values.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        Ok(quantiles
+            .iter()
+            .map(|quantile| (*quantile, Self::select_quantile(&mut values, *quantile)))
+            .collect())
+    }
+
+    fn validate_quantile(quantile: f64) -> GraphRecordResult<()> {
+        if (0.0..=1.0).contains(&quantile) {
+            Ok(())
+        } else {
+            Err(GraphRecordError::QueryError(format!(
+                "Quantile must be between 0 and 1, got {quantile}"
+            )))
+        }
+    }
+
+    fn get_quantile_values(
+        values: impl Iterator<Item = GraphRecordValue>,
+    ) -> GraphRecordResult<Vec<f64>> {
+        values
+            .map(|value| match value {
+                GraphRecordValue::Int(value) => Ok(value as f64),
+                GraphRecordValue::Float(value) => Ok(value),
+                _ => Err(GraphRecordError::QueryError(format!(
+                    "Cannot calculate quantile of data type {}. Consider narrowing down the values using .is_int() or .is_float()",
+                    DataType::from(value)
+                ))),
+            })
+            .collect()
+    }
+
+    /// Interpolates linearly between the two values closest to the quantile.
+    /// These are found by selection rather than sorting, so each quantile takes
+    /// O(n) time.
+    fn select_quantile(values: &mut [f64], quantile: f64) -> f64 {
+        let position = quantile * (values.len() - 1) as f64;
+        let lower_index = position.floor() as usize;
+        let fraction = position - lower_index as f64;
+
+        let (_, lower, upper) = values.select_nth_unstable_by(lower_index, f64::total_cmp);
+        let lower = *lower;
+
+        match upper.iter().copied().reduce(f64::min) {
+            Some(upper) if fraction > 0.0 => (upper - lower).mul_add(fraction, lower),
+            _ => lower,
+        }
+    }
+
     // TODO: This is a temporary solution. It should be optimized.
     #[inline]
     pub(crate) fn get_mode(
@@ -1428,6 +2554,83 @@ impl<O: RootOperand> MultipleValuesWithoutIndexOperation<O> {
         GraphRecordValue::Int(values.count() as i64)
     }
 
+    #[inline]
+    pub(crate) fn get_count_distinct(
+        values: impl Iterator<Item = GraphRecordValue>,
+    ) -> GraphRecordValue {
+        GraphRecordValue::Int(values.collect::<GrHashSet<_>>().len() as i64)
+    }
+
+    #[inline]
+    pub(crate) fn get_value_counts(
+        values: impl Iterator<Item = GraphRecordValue>,
+    ) -> Vec<(GraphRecordValue, usize)> {
+        let mut value_counts: Vec<_> = values.counts().into_iter().collect();
+
+        value_counts.sort_by(|(value_1, count_1), (value_2, count_2)| {
+            count_2
+                .cmp(count_1)
+                .then_with(|| value_1.partial_cmp(value_2).unwrap_or(Ordering::Equal))
+        });
+
+        value_counts
+    }
+
+    #[inline]
+    pub(crate) fn get_histogram(
+        values: impl Iterator<Item = GraphRecordValue>,
+        bins: usize,
+    ) -> GraphRecordResult<Vec<(f64, f64, usize)>> {
+        if bins == 0 {
+            return Err(GraphRecordError::QueryError(
+                "A histogram must have at least one bin".to_string(),
+            ));
+        }
+
+        let values = values
+            .map(|value| match value {
+                GraphRecordValue::Int(value) => Ok(value as f64),
+                GraphRecordValue::Float(value) => Ok(value),
+                _ => Err(GraphRecordError::QueryError(format!(
+                    "Cannot compute a histogram of values of data type {}. Consider narrowing down the values using .is_int() or .is_float()",
+                    DataType::from(value)
+                ))),
+            })
+            .collect::<GraphRecordResult<Vec<_>>>()?;
+
+        let Some(min) = values.iter().copied().reduce(f64::min) else {
+            return Ok(Vec::new());
+        };
+        let max = values.iter().copied().fold(min, f64::max);
+
+        let width = (max - min) / bins as f64;
+
+        let mut counts = vec![0; bins];
+
+        for value in values {
+            // The maximum belongs to the last bin, which is closed on both ends
+            let bin = if width > 0.0 {
+                (((value - min) / width) as usize).min(bins - 1)
+            } else {
+                0
+            };
+
+            counts[bin] += 1;
+        }
+
+        Ok(counts
+            .into_iter()
+            .enumerate()
+            .map(|(bin, count)| {
+                (
+                    (bin as f64).mul_add(width, min),
+                    ((bin + 1) as f64).mul_add(width, min),
+                    count,
+                )
+            })
+            .collect())
+    }
+
     #[inline]
     // 🥊💥
     pub(crate) fn get_sum(
@@ -1455,9 +2658,10 @@ impl<O: RootOperand> MultipleValuesWithoutIndexOperation<O> {
 
     #[inline]
     pub(crate) fn get_random(
+        graphrecord: &GraphRecord,
         values: impl Iterator<Item = GraphRecordValue>,
     ) -> Option<GraphRecordValue> {
-        values.choose(&mut rng())
+        QueryRng::new(graphrecord).choose(values, GraphRecordValue::total_cmp)
     }
 
     #[inline]
@@ -1475,12 +2679,14 @@ impl<O: RootOperand> MultipleValuesWithoutIndexOperation<O> {
             SingleKindWithoutIndex::Min => Self::get_min(values_1)?,
             SingleKindWithoutIndex::Mean => Self::get_mean(values_1)?,
             SingleKindWithoutIndex::Median => Self::get_median(values_1)?,
+            SingleKindWithoutIndex::Quantile(quantile) => Self::get_quantile(values_1, quantile.0)?,
             SingleKindWithoutIndex::Mode => Self::get_mode(values_1),
             SingleKindWithoutIndex::Std => Self::get_std(values_1)?,
             SingleKindWithoutIndex::Var => Self::get_var(values_1)?,
             SingleKindWithoutIndex::Count => Some(Self::get_count(values_1)),
+            SingleKindWithoutIndex::CountDistinct => Some(Self::get_count_distinct(values_1)),
             SingleKindWithoutIndex::Sum => Self::get_sum(values_1)?,
-            SingleKindWithoutIndex::Random => Self::get_random(values_1),
+            SingleKindWithoutIndex::Random => Self::get_random(graphrecord, values_1),
         };
 
         Ok(match operand.evaluate_forward(graphrecord, value)? {
@@ -1499,6 +2705,22 @@ impl<O: RootOperand> MultipleValuesWithoutIndexOperation<O> {
         let comparison_value = comparison_operand
             .evaluate_backward(graphrecord)?
             .ok_or_else(|| GraphRecordError::QueryError("No value to compare".to_string()))?;
+        let tolerance = graphrecord.float_tolerance();
+
+        let values: BoxedIterator<'a, _> =
+            if vectorized::supports_comparison(&comparison_value, kind) {
+                match vectorized::filter_by_comparison(
+                    Box::new(values),
+                    |value| value,
+                    &comparison_value,
+                    kind,
+                ) {
+                    Ok(values) => return Ok(Box::new(values.into_iter())),
+                    Err(values) => values,
+                }
+            } else {
+                Box::new(values)
+            };
 
         match kind {
             SingleComparisonKind::GreaterThan => Ok(Box::new(
@@ -1513,12 +2735,16 @@ impl<O: RootOperand> MultipleValuesWithoutIndexOperation<O> {
             SingleComparisonKind::LessThanOrEqualTo => Ok(Box::new(
                 values.filter(move |value| value <= &comparison_value),
             )),
-            SingleComparisonKind::EqualTo => Ok(Box::new(
-                values.filter(move |value| value == &comparison_value),
-            )),
-            SingleComparisonKind::NotEqualTo => Ok(Box::new(
-                values.filter(move |value| value != &comparison_value),
-            )),
+            SingleComparisonKind::EqualTo => {
+                Ok(Box::new(values.filter(move |value| {
+                    value.approx_eq(&comparison_value, tolerance)
+                })))
+            }
+            SingleComparisonKind::NotEqualTo => {
+                Ok(Box::new(values.filter(move |value| {
+                    !value.approx_eq(&comparison_value, tolerance)
+                })))
+            }
             SingleComparisonKind::StartsWith => Ok(Box::new(
                 values.filter(move |value| value.starts_with(&comparison_value)),
             )),
@@ -1614,6 +2840,18 @@ impl<O: RootOperand> MultipleValuesWithoutIndexOperation<O> {
         })
     }
 
+    #[inline]
+    fn evaluate_apply(
+        graphrecord: &GraphRecord,
+        values: impl Iterator<Item = GraphRecordValue>,
+        udf: &str,
+    ) -> GraphRecordResult<Vec<GraphRecordValue>> {
+        let udf = graphrecord.udf(udf)?;
+        SubqueryCache::mark_nondeterministic();
+
+        values.map(udf.as_ref()).collect()
+    }
+
     #[inline]
     fn evaluate_slice<T>(
         values: T,
@@ -1681,6 +2919,14 @@ impl<O: RootOperand> MultipleValuesWithoutIndexOperation<O> {
         values.filter(|value| matches!(value, GraphRecordValue::Null))
     }
 
+    #[inline]
+    fn evaluate_is_missing<T>(values: T) -> impl Iterator<Item = GraphRecordValue> + use<O, T>
+    where
+        T: Iterator<Item = GraphRecordValue>,
+    {
+        values.filter(|value| matches!(value, GraphRecordValue::Missing))
+    }
+
     #[inline]
     fn evaluate_is_max<'a>(
         values: impl Iterator<Item = GraphRecordValue> + 'a,
@@ -1717,6 +2963,35 @@ impl<O: RootOperand> MultipleValuesWithoutIndexOperation<O> {
         Ok(Box::new(values_2.filter(move |value| *value == min_value)))
     }
 
+    #[inline]
+    fn evaluate_distinct<T>(values: T) -> impl Iterator<Item = GraphRecordValue> + use<O, T>
+    where
+        T: Iterator<Item = GraphRecordValue>,
+    {
+        values.unique()
+    }
+
+    #[inline]
+    fn evaluate_sort<T>(
+        values: T,
+        descending: bool,
+    ) -> impl Iterator<Item = GraphRecordValue> + use<O, T>
+    where
+        T: Iterator<Item = GraphRecordValue>,
+    {
+        let mut values: Vec<_> = values.collect();
+
+        values.sort_by(|value, other| {
+            if descending {
+                other.total_cmp(value)
+            } else {
+                value.total_cmp(other)
+            }
+        });
+
+        values.into_iter()
+    }
+
     #[inline]
     fn evaluate_either_or<'a>(
         graphrecord: &'a GraphRecord,
@@ -1757,7 +3032,7 @@ impl<O: RootOperand> MultipleValuesWithoutIndexOperation<O> {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SingleValueWithIndexOperation<O: RootOperand> {
     SingleValueComparisonOperation {
         operand: SingleValueComparisonOperand,
@@ -1767,6 +3042,14 @@ pub enum SingleValueWithIndexOperation<O: RootOperand> {
         operand: MultipleValuesComparisonOperand,
         kind: MultipleComparisonKind,
     },
+    FuzzyComparisonOperation {
+        operand: SingleValueComparisonOperand,
+        kind: FuzzyComparisonKind,
+    },
+    WithinRadius {
+        operand: SingleValueComparisonOperand,
+        radius_km: QueryFloat,
+    },
     BinaryArithmeticOperation {
         operand: SingleValueComparisonOperand,
         kind: BinaryArithmeticKind,
@@ -1774,6 +3057,9 @@ pub enum SingleValueWithIndexOperation<O: RootOperand> {
     UnaryArithmeticOperation {
         kind: UnaryArithmeticKind,
     },
+    Apply {
+        udf: String,
+    },
 
     Slice(Range<usize>),
 
@@ -1784,6 +3070,7 @@ pub enum SingleValueWithIndexOperation<O: RootOperand> {
     IsDateTime,
     IsDuration,
     IsNull,
+    IsMissing,
 
     EitherOr {
         either: Wrapper<SingleValueWithIndexOperand<O>>,
@@ -1813,6 +3100,14 @@ impl<O: RootOperand> DeepClone for SingleValueWithIndexOperation<O> {
                     kind: kind.clone(),
                 }
             }
+            Self::FuzzyComparisonOperation { operand, kind } => Self::FuzzyComparisonOperation {
+                operand: operand.deep_clone(),
+                kind: *kind,
+            },
+            Self::WithinRadius { operand, radius_km } => Self::WithinRadius {
+                operand: operand.deep_clone(),
+                radius_km: *radius_km,
+            },
             Self::BinaryArithmeticOperation { operand, kind } => Self::BinaryArithmeticOperation {
                 operand: operand.deep_clone(),
                 kind: kind.clone(),
@@ -1820,6 +3115,7 @@ impl<O: RootOperand> DeepClone for SingleValueWithIndexOperation<O> {
             Self::UnaryArithmeticOperation { kind } => {
                 Self::UnaryArithmeticOperation { kind: kind.clone() }
             }
+            Self::Apply { udf } => Self::Apply { udf: udf.clone() },
             Self::Slice(range) => Self::Slice(range.clone()),
             Self::IsString => Self::IsString,
             Self::IsInt => Self::IsInt,
@@ -1828,6 +3124,7 @@ impl<O: RootOperand> DeepClone for SingleValueWithIndexOperation<O> {
             Self::IsDateTime => Self::IsDateTime,
             Self::IsDuration => Self::IsDuration,
             Self::IsNull => Self::IsNull,
+            Self::IsMissing => Self::IsMissing,
             Self::EitherOr { either, or } => Self::EitherOr {
                 either: either.deep_clone(),
                 or: or.deep_clone(),
@@ -1867,12 +3164,23 @@ impl<O: RootOperand> SingleValueWithIndexOperation<O> {
                     kind,
                 )?
             }
+            Self::FuzzyComparisonOperation { operand, kind } => {
+                let target = kind.get_target(graphrecord, operand)?;
+
+                kind.matches(&value.1, &target).then_some(value)
+            }
+            Self::WithinRadius { operand, radius_km } => {
+                let center = get_radius_center(graphrecord, operand, radius_km.0)?;
+
+                is_within_radius(&value.1, &center, radius_km.0).then_some(value)
+            }
             Self::BinaryArithmeticOperation { operand, kind } => {
                 Self::evaluate_binary_arithmetic_operation(graphrecord, value, operand, kind)?
             }
             Self::UnaryArithmeticOperation { kind } => {
                 Some(Self::evaluate_unary_arithmetic_operation(value, kind))
             }
+            Self::Apply { udf } => Some(Self::evaluate_apply(graphrecord, value, udf)?),
             Self::Slice(range) => Some(Self::evaluate_slice(value, range)),
             Self::IsString => Self::evaluate_is_string(value),
             Self::IsInt => Self::evaluate_is_int(value),
@@ -1881,6 +3189,7 @@ impl<O: RootOperand> SingleValueWithIndexOperation<O> {
             Self::IsDateTime => Self::evaluate_is_datetime(value),
             Self::IsDuration => Self::evaluate_is_duration(value),
             Self::IsNull => Self::evaluate_is_null(value),
+            Self::IsMissing => Self::evaluate_is_missing(value),
             Self::EitherOr { either, or } => {
                 Self::evaluate_either_or(graphrecord, value, either, or)?
             }
@@ -1906,14 +3215,15 @@ impl<O: RootOperand> SingleValueWithIndexOperation<O> {
         let comparison_value = comparison_operand
             .evaluate_backward(graphrecord)?
             .ok_or_else(|| GraphRecordError::QueryError("No value to compare".to_string()))?;
+        let tolerance = graphrecord.float_tolerance();
 
         let comparison_result = match kind {
             SingleComparisonKind::GreaterThan => value.1 > comparison_value,
             SingleComparisonKind::GreaterThanOrEqualTo => value.1 >= comparison_value,
             SingleComparisonKind::LessThan => value.1 < comparison_value,
             SingleComparisonKind::LessThanOrEqualTo => value.1 <= comparison_value,
-            SingleComparisonKind::EqualTo => value.1 == comparison_value,
-            SingleComparisonKind::NotEqualTo => value.1 != comparison_value,
+            SingleComparisonKind::EqualTo => value.1.approx_eq(&comparison_value, tolerance),
+            SingleComparisonKind::NotEqualTo => !value.1.approx_eq(&comparison_value, tolerance),
             SingleComparisonKind::StartsWith => value.1.starts_with(&comparison_value),
             SingleComparisonKind::EndsWith => value.1.ends_with(&comparison_value),
             SingleComparisonKind::Contains => value.1.contains(&comparison_value),
@@ -1979,6 +3289,18 @@ impl<O: RootOperand> SingleValueWithIndexOperation<O> {
         }
     }
 
+    #[inline]
+    fn evaluate_apply<'a>(
+        graphrecord: &GraphRecord,
+        value: (&'a O::Index, GraphRecordValue),
+        udf: &str,
+    ) -> GraphRecordResult<(&'a O::Index, GraphRecordValue)> {
+        let udf = graphrecord.udf(udf)?;
+        SubqueryCache::mark_nondeterministic();
+
+        Ok((value.0, udf(value.1)?))
+    }
+
     #[inline]
     fn evaluate_slice<'a>(
         value: (&'a O::Index, GraphRecordValue),
@@ -2057,6 +3379,16 @@ impl<O: RootOperand> SingleValueWithIndexOperation<O> {
         }
     }
 
+    #[inline]
+    fn evaluate_is_missing(
+        value: (&O::Index, GraphRecordValue),
+    ) -> Option<(&O::Index, GraphRecordValue)> {
+        match value.1 {
+            GraphRecordValue::Missing => Some(value),
+            _ => None,
+        }
+    }
+
     #[inline]
     fn evaluate_either_or<'a>(
         graphrecord: &'a GraphRecord,
@@ -2129,6 +3461,25 @@ impl<O: RootOperand> SingleValueWithIndexOperation<O> {
                     .collect::<GraphRecordResult<Vec<_>>>()?
                     .into_iter(),
             ),
+            Self::FuzzyComparisonOperation { operand, kind } => {
+                let target = kind.get_target(graphrecord, operand)?;
+                let kind = *kind;
+
+                Box::new(values.map(move |(key, value)| {
+                    (key, value.filter(|(_, value)| kind.matches(value, &target)))
+                }))
+            }
+            Self::WithinRadius { operand, radius_km } => {
+                let radius_km = *radius_km;
+                let center = get_radius_center(graphrecord, operand, radius_km.0)?;
+
+                Box::new(values.map(move |(key, value)| {
+                    (
+                        key,
+                        value.filter(|(_, value)| is_within_radius(value, &center, radius_km.0)),
+                    )
+                }))
+            }
             Self::BinaryArithmeticOperation { operand, kind } => Box::new(
                 values
                     .map(move |(key, value)| {
@@ -2163,6 +3514,18 @@ impl<O: RootOperand> SingleValueWithIndexOperation<O> {
                     )
                 }))
             }
+            Self::Apply { udf } => Box::new(
+                values
+                    .map(move |(key, value)| {
+                        let Some(value) = value else {
+                            return Ok((key, None));
+                        };
+
+                        Ok((key, Some(Self::evaluate_apply(graphrecord, value, udf)?)))
+                    })
+                    .collect::<GraphRecordResult<Vec<_>>>()?
+                    .into_iter(),
+            ),
             Self::Slice(range) => {
                 let range = range.clone();
 
@@ -2223,6 +3586,13 @@ impl<O: RootOperand> SingleValueWithIndexOperation<O> {
 
                 (key, Self::evaluate_is_null(value))
             })),
+            Self::IsMissing => Box::new(values.map(move |(key, value)| {
+                let Some(value) = value else {
+                    return (key, None);
+                };
+
+                (key, Self::evaluate_is_missing(value))
+            })),
             Self::EitherOr { either, or } => {
                 Self::evaluate_either_or_grouped(graphrecord, values, either, or)?
             }
@@ -2321,7 +3691,7 @@ impl<O: RootOperand> SingleValueWithIndexOperation<O> {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SingleValueWithoutIndexOperation<O: RootOperand> {
     SingleValueComparisonOperation {
         operand: SingleValueComparisonOperand,
@@ -2331,6 +3701,14 @@ pub enum SingleValueWithoutIndexOperation<O: RootOperand> {
         operand: MultipleValuesComparisonOperand,
         kind: MultipleComparisonKind,
     },
+    FuzzyComparisonOperation {
+        operand: SingleValueComparisonOperand,
+        kind: FuzzyComparisonKind,
+    },
+    WithinRadius {
+        operand: SingleValueComparisonOperand,
+        radius_km: QueryFloat,
+    },
     BinaryArithmeticOperation {
         operand: SingleValueComparisonOperand,
         kind: BinaryArithmeticKind,
@@ -2338,6 +3716,9 @@ pub enum SingleValueWithoutIndexOperation<O: RootOperand> {
     UnaryArithmeticOperation {
         kind: UnaryArithmeticKind,
     },
+    Apply {
+        udf: String,
+    },
 
     Slice(Range<usize>),
 
@@ -2348,6 +3729,7 @@ pub enum SingleValueWithoutIndexOperation<O: RootOperand> {
     IsDateTime,
     IsDuration,
     IsNull,
+    IsMissing,
 
     EitherOr {
         either: Wrapper<SingleValueWithoutIndexOperand<O>>,
@@ -2377,6 +3759,14 @@ impl<O: RootOperand> DeepClone for SingleValueWithoutIndexOperation<O> {
                     kind: kind.clone(),
                 }
             }
+            Self::FuzzyComparisonOperation { operand, kind } => Self::FuzzyComparisonOperation {
+                operand: operand.deep_clone(),
+                kind: *kind,
+            },
+            Self::WithinRadius { operand, radius_km } => Self::WithinRadius {
+                operand: operand.deep_clone(),
+                radius_km: *radius_km,
+            },
             Self::BinaryArithmeticOperation { operand, kind } => Self::BinaryArithmeticOperation {
                 operand: operand.deep_clone(),
                 kind: kind.clone(),
@@ -2384,6 +3774,7 @@ impl<O: RootOperand> DeepClone for SingleValueWithoutIndexOperation<O> {
             Self::UnaryArithmeticOperation { kind } => {
                 Self::UnaryArithmeticOperation { kind: kind.clone() }
             }
+            Self::Apply { udf } => Self::Apply { udf: udf.clone() },
             Self::Slice(range) => Self::Slice(range.clone()),
             Self::IsString => Self::IsString,
             Self::IsInt => Self::IsInt,
@@ -2392,6 +3783,7 @@ impl<O: RootOperand> DeepClone for SingleValueWithoutIndexOperation<O> {
             Self::IsDateTime => Self::IsDateTime,
             Self::IsDuration => Self::IsDuration,
             Self::IsNull => Self::IsNull,
+            Self::IsMissing => Self::IsMissing,
             Self::EitherOr { either, or } => Self::EitherOr {
                 either: either.deep_clone(),
                 or: or.deep_clone(),
@@ -2428,12 +3820,23 @@ impl<O: RootOperand> SingleValueWithoutIndexOperation<O> {
                     kind,
                 )
             }
+            Self::FuzzyComparisonOperation { operand, kind } => {
+                let target = kind.get_target(graphrecord, operand)?;
+
+                Ok(kind.matches(&value, &target).then_some(value))
+            }
+            Self::WithinRadius { operand, radius_km } => {
+                let center = get_radius_center(graphrecord, operand, radius_km.0)?;
+
+                Ok(is_within_radius(&value, &center, radius_km.0).then_some(value))
+            }
             Self::BinaryArithmeticOperation { operand, kind } => {
                 Self::evaluate_binary_arithmetic_operation(graphrecord, value, operand, kind)
             }
             Self::UnaryArithmeticOperation { kind } => {
                 Ok(Some(Self::evaluate_unary_arithmetic_operation(value, kind)))
             }
+            Self::Apply { udf } => Ok(Some(Self::evaluate_apply(graphrecord, value, udf)?)),
             Self::Slice(range) => Ok(Some(Self::evaluate_slice(value, range))),
             Self::IsString => Ok(Self::evaluate_is_string(value)),
             Self::IsInt => Ok(Self::evaluate_is_int(value)),
@@ -2442,6 +3845,7 @@ impl<O: RootOperand> SingleValueWithoutIndexOperation<O> {
             Self::IsDateTime => Ok(Self::evaluate_is_datetime(value)),
             Self::IsDuration => Ok(Self::evaluate_is_duration(value)),
             Self::IsNull => Ok(Self::evaluate_is_null(value)),
+            Self::IsMissing => Ok(Self::evaluate_is_missing(value)),
             Self::EitherOr { either, or } => {
                 Self::evaluate_either_or(graphrecord, value, either, or)
             }
@@ -2467,14 +3871,15 @@ impl<O: RootOperand> SingleValueWithoutIndexOperation<O> {
         let comparison_value = comparison_operand
             .evaluate_backward(graphrecord)?
             .ok_or_else(|| GraphRecordError::QueryError("No value to compare".to_string()))?;
+        let tolerance = graphrecord.float_tolerance();
 
         let comparison_result = match kind {
             SingleComparisonKind::GreaterThan => value > comparison_value,
             SingleComparisonKind::GreaterThanOrEqualTo => value >= comparison_value,
             SingleComparisonKind::LessThan => value < comparison_value,
             SingleComparisonKind::LessThanOrEqualTo => value <= comparison_value,
-            SingleComparisonKind::EqualTo => value == comparison_value,
-            SingleComparisonKind::NotEqualTo => value != comparison_value,
+            SingleComparisonKind::EqualTo => value.approx_eq(&comparison_value, tolerance),
+            SingleComparisonKind::NotEqualTo => !value.approx_eq(&comparison_value, tolerance),
             SingleComparisonKind::StartsWith => value.starts_with(&comparison_value),
             SingleComparisonKind::EndsWith => value.ends_with(&comparison_value),
             SingleComparisonKind::Contains => value.contains(&comparison_value),
@@ -2540,6 +3945,18 @@ impl<O: RootOperand> SingleValueWithoutIndexOperation<O> {
         }
     }
 
+    #[inline]
+    fn evaluate_apply(
+        graphrecord: &GraphRecord,
+        value: GraphRecordValue,
+        udf: &str,
+    ) -> GraphRecordResult<GraphRecordValue> {
+        let udf = graphrecord.udf(udf)?;
+        SubqueryCache::mark_nondeterministic();
+
+        udf(value)
+    }
+
     #[inline]
     fn evaluate_slice(value: GraphRecordValue, range: &Range<usize>) -> GraphRecordValue {
         value.slice(range.clone())
@@ -2601,6 +4018,14 @@ impl<O: RootOperand> SingleValueWithoutIndexOperation<O> {
         }
     }
 
+    #[inline]
+    fn evaluate_is_missing(value: GraphRecordValue) -> Option<GraphRecordValue> {
+        match value {
+            GraphRecordValue::Missing => Some(value),
+            _ => None,
+        }
+    }
+
     #[inline]
     fn evaluate_either_or(
         graphrecord: &GraphRecord,
@@ -2670,6 +4095,25 @@ impl<O: RootOperand> SingleValueWithoutIndexOperation<O> {
                     .collect::<GraphRecordResult<Vec<_>>>()?
                     .into_iter(),
             ),
+            Self::FuzzyComparisonOperation { operand, kind } => {
+                let target = kind.get_target(graphrecord, operand)?;
+                let kind = *kind;
+
+                Box::new(values.map(move |(key, value)| {
+                    (key, value.filter(|value| kind.matches(value, &target)))
+                }))
+            }
+            Self::WithinRadius { operand, radius_km } => {
+                let radius_km = *radius_km;
+                let center = get_radius_center(graphrecord, operand, radius_km.0)?;
+
+                Box::new(values.map(move |(key, value)| {
+                    (
+                        key,
+                        value.filter(|value| is_within_radius(value, &center, radius_km.0)),
+                    )
+                }))
+            }
             Self::BinaryArithmeticOperation { operand, kind } => Box::new(
                 values
                     .map(move |(key, value)| {
@@ -2704,6 +4148,18 @@ impl<O: RootOperand> SingleValueWithoutIndexOperation<O> {
                     )
                 }))
             }
+            Self::Apply { udf } => Box::new(
+                values
+                    .map(move |(key, value)| {
+                        let Some(value) = value else {
+                            return Ok((key, None));
+                        };
+
+                        Ok((key, Some(Self::evaluate_apply(graphrecord, value, udf)?)))
+                    })
+                    .collect::<GraphRecordResult<Vec<_>>>()?
+                    .into_iter(),
+            ),
             Self::Slice(range) => {
                 let range = range.clone();
 
@@ -2764,6 +4220,13 @@ impl<O: RootOperand> SingleValueWithoutIndexOperation<O> {
 
                 (key, Self::evaluate_is_null(value))
             })),
+            Self::IsMissing => Box::new(values.map(move |(key, value)| {
+                let Some(value) = value else {
+                    return (key, None);
+                };
+
+                (key, Self::evaluate_is_missing(value))
+            })),
             Self::EitherOr { either, or } => {
                 Self::evaluate_either_or_grouped(graphrecord, values, either, or)?
             }
@@ -0,0 +1,358 @@
+use super::SingleComparisonKind;
+use crate::graphrecord::{GraphRecordValue, querying::BoxedIterator};
+use std::iter;
+
+/// Moves `values` into a typed buffer as long as `extract` accepts them.
+///
+/// At the first rejected value, the values consumed so far are chained back in
+/// front of the rejected value and the remaining iterator, so nothing past that
+/// value is consumed and the caller can fall back to comparing value by value.
+fn collect_buffer<'a, T: 'a, N>(
+    mut values: BoxedIterator<'a, T>,
+    get_value: impl Fn(&T) -> &GraphRecordValue,
+    extract: impl Fn(&GraphRecordValue) -> Option<N>,
+) -> Result<(Vec<T>, Vec<N>), BoxedIterator<'a, T>> {
+    let mut items = Vec::new();
+    let mut buffer = Vec::new();
+
+    while let Some(item) = values.next() {
+        let Some(value) = extract(get_value(&item)) else {
+            return Err(Box::new(
+                items.into_iter().chain(iter::once(item)).chain(values),
+            ));
+        };
+
+        buffer.push(value);
+        items.push(item);
+    }
+
+    Ok((items, buffer))
+}
+
+fn comparison_mask<T: PartialOrd + Copy>(
+    buffer: &[T],
+    comparison_value: T,
+    kind: &SingleComparisonKind,
+) -> Vec<bool> {
+    match kind {
+        SingleComparisonKind::GreaterThan => buffer
+            .iter()
+            .map(|value| *value > comparison_value)
+            .collect(),
+        SingleComparisonKind::GreaterThanOrEqualTo => buffer
+            .iter()
+            .map(|value| *value >= comparison_value)
+            .collect(),
+        SingleComparisonKind::LessThan => buffer
+            .iter()
+            .map(|value| *value < comparison_value)
+            .collect(),
+        SingleComparisonKind::LessThanOrEqualTo => buffer
+            .iter()
+            .map(|value| *value <= comparison_value)
+            .collect(),
+        SingleComparisonKind::EqualTo => buffer
+            .iter()
+            .map(|value| *value == comparison_value)
+            .collect(),
+        SingleComparisonKind::NotEqualTo => buffer
+            .iter()
+            .map(|value| *value != comparison_value)
+            .collect(),
+        SingleComparisonKind::StartsWith
+        | SingleComparisonKind::EndsWith
+        | SingleComparisonKind::Contains => unreachable!(),
+    }
+}
+
+fn select<T>(items: Vec<T>, mask: &[bool]) -> Vec<T> {
+    items
+        .into_iter()
+        .zip(mask)
+        .filter_map(|(item, keep)| keep.then_some(item))
+        .collect()
+}
+
+const fn as_int(value: &GraphRecordValue) -> Option<i64> {
+    match value {
+        GraphRecordValue::Int(value) => Some(*value),
+        _ => None,
+    }
+}
+
+const fn as_float(value: &GraphRecordValue) -> Option<f64> {
+    match value {
+        GraphRecordValue::Float(value) => Some(*value),
+        _ => None,
+    }
+}
+
+// Ordering between an int and a float compares the int as a float, so both
+// fit into one float buffer.
+const fn as_int_or_float(value: &GraphRecordValue) -> Option<f64> {
+    match value {
+        GraphRecordValue::Int(value) => Some(*value as f64),
+        GraphRecordValue::Float(value) => Some(*value),
+        _ => None,
+    }
+}
+
+// Equality between floats follows the NaN, int/float and tolerance rules of
+// `GraphRecordValue`, so only integer equality is vectorized.
+pub const fn supports_comparison(
+    comparison_value: &GraphRecordValue,
+    kind: &SingleComparisonKind,
+) -> bool {
+    matches!(
+        (comparison_value, kind),
+        (
+            GraphRecordValue::Int(_),
+            SingleComparisonKind::GreaterThan
+                | SingleComparisonKind::GreaterThanOrEqualTo
+                | SingleComparisonKind::LessThan
+                | SingleComparisonKind::LessThanOrEqualTo
+                | SingleComparisonKind::EqualTo
+                | SingleComparisonKind::NotEqualTo
+        ) | (
+            GraphRecordValue::Float(_),
+            SingleComparisonKind::GreaterThan
+                | SingleComparisonKind::GreaterThanOrEqualTo
+                | SingleComparisonKind::LessThan
+                | SingleComparisonKind::LessThanOrEqualTo
+        )
+    )
+}
+
+/// Filters `values` by comparing their numeric values against
+/// `comparison_value` over a contiguous typed buffer.
+///
+/// The first value decides the buffer type. If a later value does not fit
+/// into it, the values are handed back as an error without consuming the
+/// rest, so the caller can fall back to comparing value by value.
+pub fn filter_by_comparison<'a, T: 'a>(
+    values: BoxedIterator<'a, T>,
+    get_value: impl Fn(&T) -> &GraphRecordValue,
+    comparison_value: &GraphRecordValue,
+    kind: &SingleComparisonKind,
+) -> Result<Vec<T>, BoxedIterator<'a, T>> {
+    let mut values = values.peekable();
+
+    let Some(first) = values.peek() else {
+        return Ok(Vec::new());
+    };
+    let first_is_float = matches!(get_value(first), GraphRecordValue::Float(_));
+
+    let values: BoxedIterator<'a, T> = Box::new(values);
+
+    match (comparison_value, first_is_float) {
+        (GraphRecordValue::Int(comparison_value), false) => {
+            let (items, buffer) = collect_buffer(values, get_value, as_int)?;
+
+            Ok(select(
+                items,
+                &comparison_mask(&buffer, *comparison_value, kind),
+            ))
+        }
+        (GraphRecordValue::Int(comparison_value), true)
+            if !matches!(
+                kind,
+                SingleComparisonKind::EqualTo | SingleComparisonKind::NotEqualTo
+            ) =>
+        {
+            let (items, buffer) = collect_buffer(values, get_value, as_float)?;
+
+            Ok(select(
+                items,
+                &comparison_mask(&buffer, *comparison_value as f64, kind),
+            ))
+        }
+        (GraphRecordValue::Float(comparison_value), _) => {
+            let (items, buffer) = collect_buffer(values, get_value, as_int_or_float)?;
+
+            Ok(select(
+                items,
+                &comparison_mask(&buffer, *comparison_value, kind),
+            ))
+        }
+        _ => Err(values),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{filter_by_comparison, supports_comparison};
+    use crate::graphrecord::{GraphRecordValue, querying::values::SingleComparisonKind};
+    use std::cell::Cell;
+
+    const ORDERINGS: [SingleComparisonKind; 4] = [
+        SingleComparisonKind::GreaterThan,
+        SingleComparisonKind::GreaterThanOrEqualTo,
+        SingleComparisonKind::LessThan,
+        SingleComparisonKind::LessThanOrEqualTo,
+    ];
+
+    fn compare(
+        value: &GraphRecordValue,
+        comparison_value: &GraphRecordValue,
+        kind: &SingleComparisonKind,
+    ) -> bool {
+        match kind {
+            SingleComparisonKind::GreaterThan => value > comparison_value,
+            SingleComparisonKind::GreaterThanOrEqualTo => value >= comparison_value,
+            SingleComparisonKind::LessThan => value < comparison_value,
+            SingleComparisonKind::LessThanOrEqualTo => value <= comparison_value,
+            SingleComparisonKind::EqualTo => value == comparison_value,
+            SingleComparisonKind::NotEqualTo => value != comparison_value,
+            _ => unreachable!(),
+        }
+    }
+
+    fn filter(
+        values: &[GraphRecordValue],
+        comparison_value: &GraphRecordValue,
+        kind: &SingleComparisonKind,
+    ) -> Result<Vec<GraphRecordValue>, Vec<GraphRecordValue>> {
+        filter_by_comparison(
+            Box::new(values.iter().cloned()),
+            |value| value,
+            comparison_value,
+            kind,
+        )
+        .map_err(Iterator::collect)
+    }
+
+    fn assert_fast_path(
+        values: &[GraphRecordValue],
+        comparison_value: &GraphRecordValue,
+        kinds: &[SingleComparisonKind],
+    ) {
+        for kind in kinds {
+            let expected: Vec<_> = values
+                .iter()
+                .filter(|value| compare(value, comparison_value, kind))
+                .cloned()
+                .collect();
+
+            assert_eq!(Ok(expected), filter(values, comparison_value, kind));
+        }
+    }
+
+    #[test]
+    fn test_supports_comparison() {
+        assert!(supports_comparison(
+            &GraphRecordValue::Int(1),
+            &SingleComparisonKind::EqualTo
+        ));
+        assert!(supports_comparison(
+            &GraphRecordValue::Float(1.0),
+            &SingleComparisonKind::LessThan
+        ));
+        assert!(!supports_comparison(
+            &GraphRecordValue::Float(1.0),
+            &SingleComparisonKind::EqualTo
+        ));
+        assert!(!supports_comparison(
+            &GraphRecordValue::String("1".to_string()),
+            &SingleComparisonKind::GreaterThan
+        ));
+    }
+
+    #[test]
+    fn test_filter_by_comparison() {
+        let ints = [1, 2, 3, -4].map(GraphRecordValue::Int);
+        let floats = [1.5, 2.0, f64::NAN, -4.0].map(GraphRecordValue::Float);
+
+        let int_kinds = [
+            ORDERINGS.as_slice(),
+            &[
+                SingleComparisonKind::EqualTo,
+                SingleComparisonKind::NotEqualTo,
+            ],
+        ]
+        .concat();
+
+        assert_fast_path(&ints, &GraphRecordValue::Int(2), &int_kinds);
+        assert_fast_path(&ints, &GraphRecordValue::Float(1.5), &ORDERINGS);
+        assert_fast_path(&floats, &GraphRecordValue::Int(2), &ORDERINGS);
+        assert_fast_path(&floats, &GraphRecordValue::Float(1.5), &ORDERINGS);
+
+        assert_eq!(
+            Ok(Vec::new()),
+            filter(
+                &[],
+                &GraphRecordValue::Int(1),
+                &SingleComparisonKind::EqualTo
+            )
+        );
+    }
+
+    #[test]
+    fn test_filter_by_comparison_mixed() {
+        let mixed = [
+            GraphRecordValue::Int(1),
+            GraphRecordValue::Float(2.5),
+            GraphRecordValue::Int(3),
+        ];
+
+        // Ints and floats share a float buffer when compared to a float
+        assert_fast_path(&mixed, &GraphRecordValue::Float(2.0), &ORDERINGS);
+
+        // Compared to an int, the ints would need an int buffer
+        assert_eq!(
+            Err(mixed.to_vec()),
+            filter(
+                &mixed,
+                &GraphRecordValue::Int(2),
+                &SingleComparisonKind::GreaterThan
+            )
+        );
+
+        let values = [GraphRecordValue::Int(1), GraphRecordValue::Null];
+
+        assert_eq!(
+            Err(values.to_vec()),
+            filter(
+                &values,
+                &GraphRecordValue::Float(0.5),
+                &SingleComparisonKind::GreaterThan
+            )
+        );
+    }
+
+    #[test]
+    fn test_filter_by_comparison_float_equality() {
+        let floats = [2.0, 2.5].map(GraphRecordValue::Float);
+
+        // Float equality against an int follows the int/float rules of
+        // `GraphRecordValue`, so it is left to the fallback
+        for kind in [
+            SingleComparisonKind::EqualTo,
+            SingleComparisonKind::NotEqualTo,
+        ] {
+            assert_eq!(
+                Err(floats.to_vec()),
+                filter(&floats, &GraphRecordValue::Int(2), &kind)
+            );
+        }
+    }
+
+    #[test]
+    fn test_filter_by_comparison_fallback_is_lazy() {
+        let consumed = Cell::new(0);
+        let values = ["a", "b", "c"].map(|value| GraphRecordValue::String(value.to_string()));
+
+        let result = filter_by_comparison(
+            Box::new(values.iter().inspect(|_| consumed.set(consumed.get() + 1))),
+            |value| value,
+            &GraphRecordValue::Int(1),
+            &SingleComparisonKind::GreaterThan,
+        );
+
+        // Only the first value is looked at before falling back
+        assert_eq!(1, consumed.get());
+        assert_eq!(
+            values.iter().collect::<Vec<_>>(),
+            result.err().unwrap().collect::<Vec<_>>()
+        );
+    }
+}
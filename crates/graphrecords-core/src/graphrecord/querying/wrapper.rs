@@ -1,6 +1,9 @@
 use super::DeepClone;
 use parking_lot::RwLock;
-use std::sync::Arc;
+use std::{
+    hash::{Hash, Hasher},
+    sync::Arc,
+};
 
 #[repr(transparent)]
 #[derive(Debug, Clone)]
@@ -21,14 +24,31 @@ where
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+// Wrappers are compared by the operands they hold, so structurally equal
+// queries are equal no matter whether they share their operands. Operands are
+// compared while they are evaluated, so their locks are taken recursively.
+impl<T: PartialEq> PartialEq for Wrapper<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0) || *self.0.read_recursive() == *other.0.read_recursive()
+    }
+}
+
+impl<T: Eq> Eq for Wrapper<T> {}
+
+impl<T: Hash> Hash for Wrapper<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.read_recursive().hash(state);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum MatchMode {
     Any,
     #[default]
     All,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum CardinalityWrapper<T> {
     Single(T),
     Multiple(Vec<T>, MatchMode),
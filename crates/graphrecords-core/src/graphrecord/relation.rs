@@ -0,0 +1,361 @@
+//! Relations between more than two nodes, e.g. a prescription linking a
+//! patient, a drug and a prescriber.
+//!
+//! Relations are stored as reified relation nodes: the relation itself is a
+//! node carrying the attributes of the relation, with one edge to each member
+//! node. The edges carry the role of their member in [`ROLE_ATTRIBUTE`] and its
+//! position among the members in [`POSITION_ATTRIBUTE`], so relations can be
+//! queried like any other node and edge.
+
+use super::{
+    Attributes, EdgeIndex, GraphRecord, GraphRecordAttribute, GraphRecordValue, NodeIndex,
+    ingest::validate_attributes,
+};
+use crate::errors::{GraphRecordError, GraphRecordResult};
+use std::collections::HashMap;
+
+/// Edge attribute holding the role of a relation member.
+pub const ROLE_ATTRIBUTE: &str = "role";
+
+/// Edge attribute holding the position of a relation member, starting at 0.
+pub const POSITION_ATTRIBUTE: &str = "position";
+
+impl GraphRecord {
+    /// Adds a relation node connected to each of `members` by an edge from the
+    /// relation node, labeled with the role and the position of the member.
+    /// Returns the indices of these edges in the order of `members`.
+    ///
+    /// The relation node, its members and all attributes are validated against
+    /// the record and its schema before anything is added, so an invalid
+    /// relation leaves the record unchanged. A failing plugin hook can still
+    /// stop the insertion after the relation node was added.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn add_relation(
+        &mut self,
+        relation_index: NodeIndex,
+        attributes: Attributes,
+        members: Vec<(String, NodeIndex)>,
+    ) -> GraphRecordResult<Vec<EdgeIndex>> {
+        self.assert_not_frozen()?;
+
+        if members.len() < 2 {
+            return Err(GraphRecordError::AssertionError(
+                "A relation must have at least two members".to_string(),
+            ));
+        }
+
+        if self.contains_node(&relation_index) {
+            return Err(GraphRecordError::AssertionError(format!(
+                "Node with index {relation_index} already exists"
+            )));
+        }
+
+        if let Some((_, node_index)) = members
+            .iter()
+            .find(|(_, node_index)| !self.contains_node(node_index))
+        {
+            return Err(GraphRecordError::MissingNode {
+                index: node_index.clone(),
+            });
+        }
+
+        let edges: Vec<_> = members
+            .into_iter()
+            .enumerate()
+            .map(|(position, (role, node_index))| {
+                (
+                    relation_index.clone(),
+                    node_index,
+                    HashMap::from([
+                        (ROLE_ATTRIBUTE.into(), role.into()),
+                        (POSITION_ATTRIBUTE.into(), (position as i64).into()),
+                    ]),
+                )
+            })
+            .collect();
+
+        self.validate_relation(&relation_index, &attributes, &edges)?;
+
+        self.add_node(relation_index, attributes)?;
+
+        self.add_edges(edges)
+    }
+
+    fn validate_relation(
+        &self,
+        relation_index: &NodeIndex,
+        attributes: &Attributes,
+        edges: &[(NodeIndex, NodeIndex, Attributes)],
+    ) -> GraphRecordResult<()> {
+        let schema = &self.schema;
+
+        validate_attributes(schema, attributes, |attributes| {
+            schema.validate_node(relation_index, attributes, None)
+        })?;
+
+        for (edge_index, (_, _, attributes)) in self.graph.peek_edge_indices().zip(edges) {
+            validate_attributes(schema, attributes, |attributes| {
+                schema.validate_edge(&edge_index, attributes, None)
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the role and node index of each member of a relation added by
+    /// [`GraphRecord::add_relation`], in the order the members were given.
+    #[expect(clippy::missing_panics_doc, reason = "infallible")]
+    pub fn relation_members(
+        &self,
+        relation_index: &NodeIndex,
+    ) -> GraphRecordResult<Vec<(&GraphRecordValue, &NodeIndex)>> {
+        let node =
+            self.graph
+                .nodes
+                .get(relation_index)
+                .ok_or_else(|| GraphRecordError::MissingNode {
+                    index: relation_index.clone(),
+                })?;
+
+        let role_attribute = GraphRecordAttribute::from(ROLE_ATTRIBUTE);
+        let position_attribute = GraphRecordAttribute::from(POSITION_ATTRIBUTE);
+
+        let mut members: Vec<_> = node
+            .outgoing_edge_indices
+            .iter()
+            .filter_map(|edge_index| {
+                let edge = self.graph.edges.get(edge_index).expect("Edge must exist");
+
+                let role = edge.attributes.get(&role_attribute)?;
+                let GraphRecordValue::Int(position) = edge.attributes.get(&position_attribute)?
+                else {
+                    return None;
+                };
+
+                Some((*position, role, &edge.target_node_index))
+            })
+            .collect();
+
+        members.sort_unstable_by_key(|(position, _, _)| *position);
+
+        Ok(members
+            .into_iter()
+            .map(|(_, role, node_index)| (role, node_index))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{POSITION_ATTRIBUTE, ROLE_ATTRIBUTE};
+    use crate::{
+        GraphRecord,
+        errors::GraphRecordError,
+        graphrecord::{
+            GraphRecordValue, NodeIndex,
+            datatypes::DataType,
+            schema::{AttributeSchema, GroupSchema, Schema},
+        },
+    };
+    use std::collections::HashMap;
+
+    fn create_graphrecord() -> GraphRecord {
+        GraphRecord::from_tuples(
+            vec![
+                ("patient".into(), HashMap::new()),
+                ("drug".into(), HashMap::new()),
+                ("prescriber".into(), HashMap::new()),
+            ],
+            None,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_add_relation() {
+        let mut graphrecord = create_graphrecord();
+
+        let edge_indices = graphrecord
+            .add_relation(
+                "prescription".into(),
+                HashMap::from([("dose".into(), 20.into())]),
+                vec![
+                    ("patient".to_string(), "patient".into()),
+                    ("drug".to_string(), "drug".into()),
+                    ("prescriber".to_string(), "prescriber".into()),
+                ],
+            )
+            .unwrap();
+
+        assert_eq!(vec![0, 1, 2], edge_indices);
+        assert_eq!(
+            &HashMap::from([
+                (ROLE_ATTRIBUTE.into(), "drug".into()),
+                (POSITION_ATTRIBUTE.into(), 1.into()),
+            ]),
+            graphrecord.edge_attributes(&1).unwrap()
+        );
+        assert_eq!(
+            vec![
+                (
+                    &GraphRecordValue::from("patient"),
+                    &NodeIndex::from("patient")
+                ),
+                (&GraphRecordValue::from("drug"), &NodeIndex::from("drug")),
+                (
+                    &GraphRecordValue::from("prescriber"),
+                    &NodeIndex::from("prescriber")
+                ),
+            ],
+            graphrecord
+                .relation_members(&"prescription".into())
+                .unwrap()
+        );
+
+        graphrecord.remove_node(&"prescription".into()).unwrap();
+
+        assert_eq!(0, graphrecord.edge_count());
+    }
+
+    #[test]
+    fn test_relation_members_order() {
+        let mut graphrecord = create_graphrecord();
+        graphrecord.set_recycle_edge_indices(true).unwrap();
+
+        graphrecord
+            .add_edges(vec![
+                ("patient".into(), "drug".into(), HashMap::new()),
+                ("patient".into(), "prescriber".into(), HashMap::new()),
+            ])
+            .unwrap();
+        graphrecord.remove_edge(&0).unwrap();
+        graphrecord.remove_edge(&1).unwrap();
+
+        // Recycled edge indices are handed out in reverse
+        let edge_indices = graphrecord
+            .add_relation(
+                "prescription".into(),
+                HashMap::new(),
+                vec![
+                    ("patient".to_string(), "patient".into()),
+                    ("drug".to_string(), "drug".into()),
+                ],
+            )
+            .unwrap();
+
+        assert_eq!(vec![1, 0], edge_indices);
+        assert_eq!(
+            vec![
+                (
+                    &GraphRecordValue::from("patient"),
+                    &NodeIndex::from("patient")
+                ),
+                (&GraphRecordValue::from("drug"), &NodeIndex::from("drug")),
+            ],
+            graphrecord
+                .relation_members(&"prescription".into())
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_invalid_add_relation() {
+        let mut graphrecord = create_graphrecord();
+
+        assert!(
+            graphrecord
+                .add_relation(
+                    "prescription".into(),
+                    HashMap::new(),
+                    vec![("patient".to_string(), "patient".into())],
+                )
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
+
+        assert!(
+            graphrecord
+                .add_relation(
+                    "prescription".into(),
+                    HashMap::new(),
+                    vec![
+                        ("patient".to_string(), "patient".into()),
+                        ("drug".to_string(), "50".into()),
+                    ],
+                )
+                .is_err_and(|e| matches!(e, GraphRecordError::MissingNode { .. }))
+        );
+
+        assert!(
+            graphrecord
+                .add_relation(
+                    "patient".into(),
+                    HashMap::new(),
+                    vec![
+                        ("patient".to_string(), "patient".into()),
+                        ("drug".to_string(), "drug".into()),
+                    ],
+                )
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
+
+        let mut provided = GraphRecord::with_schema(Schema::new_provided(
+            HashMap::new(),
+            GroupSchema::new(
+                AttributeSchema::default(),
+                AttributeSchema::new(HashMap::from([(
+                    ROLE_ATTRIBUTE.into(),
+                    DataType::String.into(),
+                )])),
+            ),
+        ));
+        provided
+            .add_nodes(vec![
+                ("patient".into(), HashMap::new()),
+                ("drug".into(), HashMap::new()),
+            ])
+            .unwrap();
+        let receiver = provided.subscribe();
+
+        // The schema lacks the position attribute of the edges
+        assert!(
+            provided
+                .add_relation(
+                    "prescription".into(),
+                    HashMap::new(),
+                    vec![
+                        ("patient".to_string(), "patient".into()),
+                        ("drug".to_string(), "drug".into()),
+                    ],
+                )
+                .is_err_and(|e| matches!(e, GraphRecordError::SchemaError(_)))
+        );
+        assert_eq!(2, provided.node_count());
+        assert!(receiver.try_recv().is_err());
+
+        // Failed additions leave the record unchanged
+        assert_eq!(3, graphrecord.node_count());
+        assert_eq!(0, graphrecord.edge_count());
+
+        assert!(
+            graphrecord
+                .relation_members(&"50".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::MissingNode { .. }))
+        );
+
+        graphrecord.freeze();
+
+        assert!(
+            graphrecord
+                .add_relation(
+                    "prescription".into(),
+                    HashMap::new(),
+                    vec![
+                        ("patient".to_string(), "patient".into()),
+                        ("drug".to_string(), "drug".into()),
+                    ],
+                )
+                .is_err_and(|e| matches!(e, GraphRecordError::ReadOnlyError(_)))
+        );
+    }
+}
@@ -1,16 +1,41 @@
-use super::{Attributes, EdgeIndex, GraphRecord, Group, NodeIndex};
+use super::{EdgeIndex, GraphRecord, Group, NodeIndex, ReadAttributes};
 use crate::{
-    errors::GraphError,
-    graphrecord::{GraphRecordAttribute, datatypes::DataType},
+    errors::{AttributeOwner, GraphError},
+    graphrecord::{GraphRecordAttribute, GraphRecordValue, datatypes::DataType},
 };
-use graphrecords_utils::aliases::GrHashMap;
+use graphrecords_utils::aliases::{GrHashMap, GrHashSet};
+use itertools::Itertools;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::{HashMap, hash_map::Entry},
+    collections::HashMap,
+    fmt::{Display, Formatter},
     ops::Deref,
 };
 
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let b: Vec<_> = b.chars().collect();
+    let mut distances: Vec<_> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut previous = distances[0];
+        distances[0] = i + 1;
+
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous + usize::from(a_char != *b_char);
+            previous = distances[j + 1];
+            distances[j + 1] = substitution.min(previous + 1).min(distances[j] + 1);
+        }
+    }
+
+    distances[b.len()]
+}
+
+fn is_close_match(attribute: &str, candidate: &str) -> bool {
+    attribute.eq_ignore_ascii_case(candidate)
+        || levenshtein_distance(attribute, candidate) <= (attribute.chars().count() / 3).max(1)
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AttributeType {
@@ -24,12 +49,21 @@ impl AttributeType {
     #[must_use]
     pub fn infer(data_type: &DataType) -> Self {
         match data_type {
-            DataType::String | DataType::Null | DataType::Any => Self::Unstructured,
+            DataType::String
+            | DataType::GeoPoint
+            | DataType::Null
+            | DataType::Any
+            | DataType::Missing => Self::Unstructured,
             DataType::Int | DataType::Float => Self::Continuous,
             DataType::Bool => Self::Categorical,
             DataType::DateTime | DataType::Duration => Self::Temporal,
             DataType::Union((first_dataype, second_dataype)) => {
-                Self::infer(first_dataype).merge(Self::infer(second_dataype))
+                match (first_dataype.as_ref(), second_dataype.as_ref()) {
+                    (DataType::Missing, dataype) | (dataype, DataType::Missing) => {
+                        Self::infer(dataype)
+                    }
+                    _ => Self::infer(first_dataype).merge(Self::infer(second_dataype)),
+                }
             }
             DataType::Option(dataype) => Self::infer(dataype),
         }
@@ -48,27 +82,175 @@ impl AttributeType {
     }
 }
 
-impl DataType {
-    fn merge(&self, other: &Self) -> Self {
-        if self.evaluate(other) {
-            self.clone()
+/// Heuristics of [`Schema::infer_attribute_types_with`].
+///
+/// Attributes of data type String or Int are Categorical if they have at most
+/// `max_categories` distinct values and these make up at most
+/// `max_distinct_ratio` of their non-null values. The ratio keeps attributes
+/// whose few values are all different, like the ages of a handful of
+/// patients, from being Categorical. All other attributes get the attribute
+/// type inferred from their data type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeTypeInference {
+    pub max_categories: usize,
+    pub max_distinct_ratio: f64,
+    /// Attribute types used instead of the inferred ones, for every group.
+    pub overrides: HashMap<GraphRecordAttribute, AttributeType>,
+}
+
+impl Default for AttributeTypeInference {
+    fn default() -> Self {
+        Self {
+            max_categories: 50,
+            max_distinct_ratio: 0.5,
+            overrides: HashMap::new(),
+        }
+    }
+}
+
+impl AttributeTypeInference {
+    #[must_use]
+    pub fn with_override(
+        mut self,
+        attribute: impl Into<GraphRecordAttribute>,
+        attribute_type: AttributeType,
+    ) -> Self {
+        self.overrides.insert(attribute.into(), attribute_type);
+        self
+    }
+
+    fn infer<'a>(
+        &self,
+        attribute: &GraphRecordAttribute,
+        data_type: &DataType,
+        values: impl Iterator<Item = &'a GraphRecordValue>,
+    ) -> AttributeType {
+        if let Some(attribute_type) = self.overrides.get(attribute) {
+            return *attribute_type;
+        }
+
+        let inner_data_type = match data_type {
+            DataType::Option(data_type) => data_type.as_ref(),
+            data_type => data_type,
+        }
+        .recorded();
+
+        if !matches!(inner_data_type, DataType::String | DataType::Int) {
+            return AttributeType::infer(data_type);
+        }
+
+        let mut count = 0_usize;
+        let mut distinct_values = GrHashSet::default();
+
+        for value in values {
+            if matches!(value, GraphRecordValue::Null | GraphRecordValue::Missing) {
+                continue;
+            }
+
+            count += 1;
+            distinct_values.insert(value);
+
+            if distinct_values.len() > self.max_categories {
+                return AttributeType::infer(data_type);
+            }
+        }
+
+        if count > 0 && distinct_values.len() as f64 <= self.max_distinct_ratio * count as f64 {
+            AttributeType::Categorical
         } else {
-            match (self, other) {
-                (Self::Null, _) => Self::Option(Box::new(other.clone())),
-                (_, Self::Null) => Self::Option(Box::new(self.clone())),
-                (_, Self::Any) => Self::Any,
-                (Self::Option(option1), Self::Option(option2)) => {
-                    Self::Option(Box::new(option1.merge(option2)))
+            AttributeType::infer(data_type)
+        }
+    }
+}
+
+/// Rules that widen one data type into another during schema inference.
+///
+/// When an attribute is seen with two data types that are not already
+/// compatible, they are merged into the closest data type both of them widen
+/// into instead of a Union. The rules form a lattice, so with `Int -> Float`
+/// and `Float -> String` an Int and a String are merged into a String. Without
+/// any rules, which is the default, the data types are merged into a Union.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DataTypeWidening(Vec<(DataType, DataType)>);
+
+impl DataTypeWidening {
+    /// Widens Int into Float, so that a column mixing 1 and 1.5 is inferred
+    /// as Float.
+    #[must_use]
+    pub fn numeric() -> Self {
+        Self(vec![(DataType::Int, DataType::Float)])
+    }
+
+    /// Adds a rule that widens `from` into `into`.
+    #[must_use]
+    pub fn with_rule(mut self, from: DataType, into: DataType) -> Self {
+        self.0.push((from, into));
+        self
+    }
+
+    #[must_use]
+    pub fn rules(&self) -> &[(DataType, DataType)] {
+        &self.0
+    }
+
+    /// Returns `data_type` followed by every data type it widens into, the
+    /// closest ones first.
+    fn widenings(&self, data_type: &DataType) -> Vec<DataType> {
+        let mut widenings = vec![data_type.clone()];
+        let mut position = 0;
+
+        while let Some(current) = widenings.get(position).cloned() {
+            for (from, into) in &self.0 {
+                if from == &current && !widenings.contains(into) {
+                    widenings.push(into.clone());
                 }
-                (Self::Option(option), _) => Self::Option(Box::new(option.merge(other))),
-                (_, Self::Option(option)) => Self::Option(Box::new(self.merge(option))),
-                _ => Self::Union((Box::new(self.clone()), Box::new(other.clone()))),
             }
+
+            position += 1;
         }
+
+        widenings
+    }
+
+    fn join(&self, first: &DataType, second: &DataType) -> Option<DataType> {
+        if self.0.is_empty() {
+            return None;
+        }
+
+        let second_widenings = self.widenings(second);
+
+        self.widenings(first)
+            .into_iter()
+            .find(|data_type| second_widenings.contains(data_type))
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+impl DataType {
+    fn merge(&self, other: &Self, widening: &DataTypeWidening) -> Self {
+        if self.evaluate(other) {
+            return self.clone();
+        }
+
+        if let Some(widened) = widening.join(self, other) {
+            return widened;
+        }
+
+        match (self, other) {
+            (Self::Null, _) => Self::Option(Box::new(other.clone())),
+            (_, Self::Null) => Self::Option(Box::new(self.clone())),
+            (_, Self::Any) => Self::Any,
+            (Self::Option(option1), Self::Option(option2)) => {
+                Self::Option(Box::new(option1.merge(option2, widening)))
+            }
+            (Self::Option(option), _) => Self::Option(Box::new(option.merge(other, widening))),
+            (_, Self::Option(option)) => Self::Option(Box::new(self.merge(option, widening))),
+            _ => Self::Union((Box::new(self.clone()), Box::new(other.clone()))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AttributeDataType {
     data_type: DataType,
@@ -79,10 +261,14 @@ impl AttributeDataType {
     fn validate(data_type: &DataType, attribute_type: AttributeType) -> Result<(), GraphError> {
         match (attribute_type, data_type) {
             (AttributeType::Categorical | AttributeType::Unstructured, _)
-            | (AttributeType::Continuous, DataType::Int | DataType::Float | DataType::Null)
-            | (AttributeType::Temporal, DataType::DateTime | DataType::Duration | DataType::Null) => {
-                Ok(())
-            }
+            | (
+                AttributeType::Continuous,
+                DataType::Int | DataType::Float | DataType::Null | DataType::Missing,
+            )
+            | (
+                AttributeType::Temporal,
+                DataType::DateTime | DataType::Duration | DataType::Null | DataType::Missing,
+            ) => Ok(()),
 
             (_, DataType::Option(option)) => Self::validate(option, attribute_type),
             (_, DataType::Union((first_datatype, second_datatype))) => {
@@ -119,19 +305,17 @@ impl AttributeDataType {
         &self.attribute_type
     }
 
-    fn merge(&mut self, other: &Self) {
-        match (self.data_type.clone(), other.data_type.clone()) {
-            (DataType::Null, _) => {
-                self.data_type = self.data_type.merge(&other.data_type);
-                self.attribute_type = other.attribute_type;
-            }
-            (_, DataType::Null) => {
-                self.data_type = self.data_type.merge(&other.data_type);
-            }
-            _ => {
-                self.data_type = self.data_type.merge(&other.data_type);
-                self.attribute_type = self.attribute_type.merge(other.attribute_type);
-            }
+    fn merge(&mut self, other: &Self, widening: &DataTypeWidening) {
+        self.attribute_type = match (&self.data_type, &other.data_type) {
+            (DataType::Null | DataType::Missing, _) => other.attribute_type,
+            (_, DataType::Null | DataType::Missing) => self.attribute_type,
+            _ => self.attribute_type.merge(other.attribute_type),
+        };
+
+        // Most insertions repeat an already known data type, in which case the
+        // data type is left untouched instead of being rebuilt.
+        if !self.data_type.evaluate(&other.data_type) {
+            self.data_type = self.data_type.merge(&other.data_type, widening);
         }
     }
 }
@@ -174,19 +358,22 @@ impl AttributeSchemaKind<'_> {
         }
     }
 
-    fn error_message_expected(
+    fn type_mismatch(
         &self,
         key: &GraphRecordAttribute,
         data_type: &DataType,
         expected_data_type: &DataType,
-    ) -> String {
-        match self {
-            Self::Node(index) => format!(
-                "Attribute {key} of node with index {index} is of type {data_type}. Expected {expected_data_type}."
-            ),
-            Self::Edge(index) => format!(
-                "Attribute {key} of edge with index {index} is of type {data_type}. Expected {expected_data_type}."
-            ),
+    ) -> GraphError {
+        let owner = match self {
+            Self::Node(index) => AttributeOwner::Node((*index).clone()),
+            Self::Edge(index) => AttributeOwner::Edge(**index),
+        };
+
+        GraphError::TypeMismatch {
+            owner,
+            attribute: key.clone(),
+            expected: expected_data_type.clone(),
+            actual: data_type.clone(),
         }
     }
 
@@ -208,7 +395,7 @@ impl AttributeSchemaKind<'_> {
 
 type AttributeSchemaMapping = HashMap<GraphRecordAttribute, AttributeDataType>;
 
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AttributeSchema(AttributeSchemaMapping);
 
@@ -237,23 +424,19 @@ impl AttributeSchema {
 
     fn validate(
         &self,
-        attributes: &Attributes,
+        attributes: &impl ReadAttributes,
         kind: &AttributeSchemaKind,
     ) -> Result<(), GraphError> {
         let mut matched_count = 0;
         let mut attributes_not_in_schema = Vec::new();
 
-        for (key, value) in attributes {
+        for (key, value) in attributes.iter() {
             match self.0.get(key) {
                 Some(schema) => {
                     let data_type = DataType::from(value);
 
                     if !schema.data_type.evaluate(&data_type) {
-                        return Err(GraphError::SchemaError(kind.error_message_expected(
-                            key,
-                            &data_type,
-                            &schema.data_type,
-                        )));
+                        return Err(kind.type_mismatch(key, &data_type, &schema.data_type));
                     }
 
                     matched_count += 1;
@@ -284,44 +467,157 @@ impl AttributeSchema {
         Ok(())
     }
 
-    fn update(&mut self, attributes: &Attributes, empty: bool) {
+    fn update(
+        &mut self,
+        attributes: &impl ReadAttributes,
+        empty: bool,
+        widening: &DataTypeWidening,
+    ) {
         for (attribute, data_type) in &mut self.0 {
-            if !attributes.contains_key(attribute) {
-                data_type.data_type = data_type.data_type.merge(&DataType::Null);
+            if !attributes.contains_key(attribute) && !data_type.data_type.evaluate(&DataType::Null)
+            {
+                data_type.data_type = data_type.data_type.merge(&DataType::Null, widening);
             }
         }
 
-        for (attribute, value) in attributes {
+        for (attribute, value) in attributes.iter() {
             let data_type = DataType::from(value);
             let attribute_type = AttributeType::infer(&data_type);
 
             let mut attribute_data_type = AttributeDataType::new(data_type, attribute_type)
                 .expect("AttributeType was inferred from DataType.");
 
-            match self.0.entry(attribute.clone()) {
-                Entry::Occupied(entry) => {
-                    entry.into_mut().merge(&attribute_data_type);
-                }
-                Entry::Vacant(entry) => {
-                    if !empty {
-                        attribute_data_type.data_type =
-                            attribute_data_type.data_type.merge(&DataType::Null);
-                    }
+            if let Some(existing) = self.0.get_mut(attribute) {
+                existing.merge(&attribute_data_type, widening);
 
-                    entry.insert(attribute_data_type);
-                }
+                continue;
             }
+
+            if !empty {
+                attribute_data_type.data_type = attribute_data_type
+                    .data_type
+                    .merge(&DataType::Null, widening);
+            }
+
+            self.0.insert(attribute.clone(), attribute_data_type);
         }
     }
 
     #[must_use]
-    pub fn infer(attributes: Vec<&Attributes>) -> Self {
+    pub fn infer(attributes: Vec<&impl ReadAttributes>) -> Self {
+        Self::infer_with_widening(attributes, &DataTypeWidening::default())
+    }
+
+    fn infer_attribute_types(
+        &mut self,
+        attributes: &[&impl ReadAttributes],
+        inference: &AttributeTypeInference,
+    ) -> Result<(), GraphError> {
+        for (attribute, attribute_data_type) in &mut self.0 {
+            let attribute_type = inference.infer(
+                attribute,
+                &attribute_data_type.data_type,
+                attributes
+                    .iter()
+                    .filter_map(|attributes| attributes.get(attribute)),
+            );
+
+            AttributeDataType::validate(&attribute_data_type.data_type, attribute_type).map_err(
+                |error| {
+                    GraphError::SchemaError(format!(
+                        "Cannot use {attribute_type:?} for attribute {attribute}. {}",
+                        error.message()
+                    ))
+                },
+            )?;
+
+            attribute_data_type.attribute_type = attribute_type;
+        }
+
+        Ok(())
+    }
+
+    fn rename(
+        &mut self,
+        attribute: &GraphRecordAttribute,
+        new_attribute: GraphRecordAttribute,
+        widening: &DataTypeWidening,
+    ) {
+        let Some(attribute_data_type) = self.0.remove(attribute) else {
+            return;
+        };
+
+        self.0
+            .entry(new_attribute)
+            .and_modify(|value| value.merge(&attribute_data_type, widening))
+            .or_insert(attribute_data_type);
+    }
+
+    /// Merges `other` into the schema and returns the attributes whose data
+    /// types differ, with their data types in both schemas. Attributes that
+    /// only one of the schemas has become optional, unless the other schema is
+    /// empty.
+    fn merge(
+        &mut self,
+        other: &Self,
+        strategy: ConflictStrategy,
+        widening: &DataTypeWidening,
+    ) -> Vec<(GraphRecordAttribute, AttributeDataType, AttributeDataType)> {
+        if other.0.is_empty() {
+            return Vec::new();
+        }
+
+        let empty = self.0.is_empty();
+
+        for (attribute, data_type) in &mut self.0 {
+            if !other.0.contains_key(attribute) && !data_type.data_type.evaluate(&DataType::Null) {
+                data_type.data_type = data_type.data_type.merge(&DataType::Null, widening);
+            }
+        }
+
+        let mut conflicts = Vec::new();
+
+        for (attribute, other_data_type) in &other.0 {
+            if let Some(existing) = self.0.get_mut(attribute) {
+                if existing != other_data_type {
+                    conflicts.push((attribute.clone(), existing.clone(), other_data_type.clone()));
+
+                    match strategy {
+                        ConflictStrategy::Widen | ConflictStrategy::Reject => {
+                            existing.merge(other_data_type, widening);
+                        }
+                        ConflictStrategy::KeepFirst => {}
+                        ConflictStrategy::KeepSecond => *existing = other_data_type.clone(),
+                    }
+                }
+
+                continue;
+            }
+
+            let mut attribute_data_type = other_data_type.clone();
+
+            if !empty {
+                attribute_data_type.data_type = attribute_data_type
+                    .data_type
+                    .merge(&DataType::Null, widening);
+            }
+
+            self.0.insert(attribute.clone(), attribute_data_type);
+        }
+
+        conflicts
+    }
+
+    fn infer_with_widening(
+        attributes: Vec<&impl ReadAttributes>,
+        widening: &DataTypeWidening,
+    ) -> Self {
         let mut schema = Self::default();
 
         let mut empty = true;
 
         for attributes in attributes {
-            schema.update(attributes, empty);
+            schema.update(attributes, empty, widening);
 
             empty = false;
         }
@@ -330,7 +626,7 @@ impl AttributeSchema {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GroupSchema {
     nodes: AttributeSchema,
@@ -356,7 +652,7 @@ impl GroupSchema {
     pub fn validate_node<'a>(
         &self,
         index: &'a NodeIndex,
-        attributes: &'a Attributes,
+        attributes: &'a impl ReadAttributes,
     ) -> Result<(), GraphError> {
         self.nodes
             .validate(attributes, &AttributeSchemaKind::Node(index))
@@ -365,26 +661,69 @@ impl GroupSchema {
     pub fn validate_edge<'a>(
         &self,
         index: &'a EdgeIndex,
-        attributes: &'a Attributes,
+        attributes: &'a impl ReadAttributes,
     ) -> Result<(), GraphError> {
         self.edges
             .validate(attributes, &AttributeSchemaKind::Edge(index))
     }
 
     #[must_use]
-    pub fn infer(nodes: Vec<&Attributes>, edges: Vec<&Attributes>) -> Self {
+    pub fn infer(nodes: Vec<&impl ReadAttributes>, edges: Vec<&impl ReadAttributes>) -> Self {
+        Self::infer_with_widening(nodes, edges, &DataTypeWidening::default())
+    }
+
+    fn infer_with_widening(
+        nodes: Vec<&impl ReadAttributes>,
+        edges: Vec<&impl ReadAttributes>,
+        widening: &DataTypeWidening,
+    ) -> Self {
         Self {
-            nodes: AttributeSchema::infer(nodes),
-            edges: AttributeSchema::infer(edges),
+            nodes: AttributeSchema::infer_with_widening(nodes, widening),
+            edges: AttributeSchema::infer_with_widening(edges, widening),
         }
     }
 
-    pub(crate) fn update_node(&mut self, attributes: &Attributes, empty: bool) {
-        self.nodes.update(attributes, empty);
+    fn update_node(
+        &mut self,
+        attributes: &impl ReadAttributes,
+        empty: bool,
+        widening: &DataTypeWidening,
+    ) {
+        self.nodes.update(attributes, empty, widening);
     }
 
-    pub(crate) fn update_edge(&mut self, attributes: &Attributes, empty: bool) {
-        self.edges.update(attributes, empty);
+    fn update_edge(
+        &mut self,
+        attributes: &impl ReadAttributes,
+        empty: bool,
+        widening: &DataTypeWidening,
+    ) {
+        self.edges.update(attributes, empty, widening);
+    }
+
+    fn merge(
+        &mut self,
+        other: &Self,
+        group: Option<&Group>,
+        strategy: ConflictStrategy,
+        widening: &DataTypeWidening,
+    ) -> (Vec<SchemaConflict>, Vec<SchemaConflict>) {
+        let to_conflicts = |conflicts: Vec<(_, _, _)>| {
+            conflicts
+                .into_iter()
+                .map(|(attribute, first, second)| SchemaConflict {
+                    group: group.cloned(),
+                    attribute,
+                    first,
+                    second,
+                })
+                .collect()
+        };
+
+        (
+            to_conflicts(self.nodes.merge(&other.nodes, strategy, widening)),
+            to_conflicts(self.edges.merge(&other.edges, strategy, widening)),
+        )
     }
 }
 
@@ -396,12 +735,193 @@ pub enum SchemaType {
     Provided,
 }
 
-#[derive(Debug, Clone, PartialEq, Default)]
+/// How Float values that are NaN or infinite are handled when they are added
+/// to a record, either as part of a node or edge or as an updated
+/// attribute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NonFiniteFloatPolicy {
+    /// Stores the values as they are. Aggregations like `mean`, `min` and
+    /// `max` in queries skip NaN values.
+    #[default]
+    Allow,
+    /// Fails the operation with a [`GraphError::SchemaError`].
+    Reject,
+    /// Stores the values as Null instead.
+    CoerceToNull,
+}
+
+/// How values of polars data frames are converted when nodes and edges are
+/// added from data frames or exported to data frames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ConversionMode {
+    /// Converts values even if the conversion loses information, like the
+    /// timezone of a Datetime.
+    #[default]
+    Lenient,
+    /// Fails the conversion with a `ConversionError` if it would lose
+    /// information, so that values round-trip exactly.
+    Strict,
+}
+
+/// How [`Schema::merge`] resolves an attribute that the merged schemas give
+/// different data types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConflictStrategy {
+    /// Merges the data types using the widening of the first schema.
+    #[default]
+    Widen,
+    /// Keeps the data type of the first schema.
+    KeepFirst,
+    /// Keeps the data type of the second schema.
+    KeepSecond,
+    /// Fails the merge with a [`GraphError::SchemaError`].
+    Reject,
+}
+
+/// Attribute that two merged schemas give different data types, either in the
+/// schema of a group or in the schema of ungrouped entities.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaConflict {
+    pub group: Option<Group>,
+    pub attribute: GraphRecordAttribute,
+    pub first: AttributeDataType,
+    pub second: AttributeDataType,
+}
+
+/// Orders attributes of ungrouped entities first, then by group and
+/// attribute.
+fn cmp_position(
+    (group, attribute): (&Option<Group>, &GraphRecordAttribute),
+    (other_group, other_attribute): (&Option<Group>, &GraphRecordAttribute),
+) -> std::cmp::Ordering {
+    let group_ordering = match (group, other_group) {
+        (None, None) => std::cmp::Ordering::Equal,
+        (None, Some(_)) => std::cmp::Ordering::Less,
+        (Some(_), None) => std::cmp::Ordering::Greater,
+        (Some(group), Some(other_group)) => group.total_cmp(other_group),
+    };
+
+    group_ordering.then_with(|| attribute.total_cmp(other_attribute))
+}
+
+impl SchemaConflict {
+    fn cmp_position(&self, other: &Self) -> std::cmp::Ordering {
+        cmp_position(
+            (&self.group, &self.attribute),
+            (&other.group, &other.attribute),
+        )
+    }
+}
+
+impl Display for SchemaConflict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.group {
+            Some(group) => write!(
+                f,
+                "Attribute {} of group {group} is {} in the first schema and {} in the second",
+                self.attribute, self.first.data_type, self.second.data_type
+            ),
+            None => write!(
+                f,
+                "Attribute {} is {} in the first schema and {} in the second",
+                self.attribute, self.first.data_type, self.second.data_type
+            ),
+        }
+    }
+}
+
+/// Result of [`Schema::merge`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaMerge {
+    pub schema: Schema,
+    pub node_conflicts: Vec<SchemaConflict>,
+    pub edge_conflicts: Vec<SchemaConflict>,
+}
+
+/// Attribute that only one of two schemas has or that they give different
+/// data or attribute types. `old` is `None` for an added attribute, `new` is
+/// `None` for a removed one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaChange {
+    pub group: Option<Group>,
+    pub attribute: GraphRecordAttribute,
+    pub old: Option<AttributeDataType>,
+    pub new: Option<AttributeDataType>,
+}
+
+impl SchemaChange {
+    fn cmp_position(&self, other: &Self) -> std::cmp::Ordering {
+        cmp_position(
+            (&self.group, &self.attribute),
+            (&other.group, &other.attribute),
+        )
+    }
+
+    fn collect(
+        group: Option<&Group>,
+        old: Option<&AttributeSchemaMapping>,
+        new: Option<&AttributeSchemaMapping>,
+        changes: &mut Vec<Self>,
+    ) {
+        let old = old.into_iter().flatten();
+        let new = new.into_iter().flatten();
+
+        let attributes: GrHashSet<_> = old.clone().chain(new.clone()).map(|(key, _)| key).collect();
+        let old: GrHashMap<_, _> = old.collect();
+        let new: GrHashMap<_, _> = new.collect();
+
+        for attribute in attributes {
+            let old_data_type = old.get(attribute).copied();
+            let new_data_type = new.get(attribute).copied();
+
+            if old_data_type == new_data_type {
+                continue;
+            }
+
+            changes.push(Self {
+                group: group.cloned(),
+                attribute: attribute.clone(),
+                old: old_data_type.cloned(),
+                new: new_data_type.cloned(),
+            });
+        }
+    }
+}
+
+/// Result of [`Schema::diff`]. The attributes of added and removed groups are
+/// listed as changes as well.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SchemaDiff {
+    pub added_groups: Vec<Group>,
+    pub removed_groups: Vec<Group>,
+    pub node_changes: Vec<SchemaChange>,
+    pub edge_changes: Vec<SchemaChange>,
+}
+
+impl SchemaDiff {
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.added_groups.is_empty()
+            && self.removed_groups.is_empty()
+            && self.node_changes.is_empty()
+            && self.edge_changes.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Schema {
     groups: HashMap<Group, GroupSchema>,
     ungrouped: GroupSchema,
     r#type: SchemaType,
+    #[cfg_attr(feature = "serde", serde(default))]
+    non_finite_float_policy: NonFiniteFloatPolicy,
+    #[cfg_attr(feature = "serde", serde(default))]
+    widening: DataTypeWidening,
+    #[cfg_attr(feature = "serde", serde(default))]
+    conversion_mode: ConversionMode,
 }
 
 impl Schema {
@@ -411,6 +931,9 @@ impl Schema {
             groups,
             ungrouped,
             r#type: SchemaType::Inferred,
+            non_finite_float_policy: NonFiniteFloatPolicy::Allow,
+            widening: DataTypeWidening(Vec::new()),
+            conversion_mode: ConversionMode::Lenient,
         }
     }
 
@@ -420,11 +943,22 @@ impl Schema {
             groups,
             ungrouped,
             r#type: SchemaType::Provided,
+            non_finite_float_policy: NonFiniteFloatPolicy::Allow,
+            widening: DataTypeWidening(Vec::new()),
+            conversion_mode: ConversionMode::Lenient,
         }
     }
 
     #[must_use]
     pub fn infer(graphrecord: &GraphRecord) -> Self {
+        Self::infer_with_widening(graphrecord, DataTypeWidening::default())
+    }
+
+    /// Like [`Schema::infer`], but merges differing data types of an attribute
+    /// using `widening`. The widening is kept for the data types inferred when
+    /// the record grows.
+    #[must_use]
+    pub fn infer_with_widening(graphrecord: &GraphRecord, widening: DataTypeWidening) -> Self {
         let mut group_mapping: GrHashMap<_, _> = graphrecord
             .groups()
             .map(|group| (group, (Vec::new(), Vec::new())))
@@ -487,13 +1021,17 @@ impl Schema {
                         .map(|edge| graphrecord.edge_attributes(edge).expect("Edge must exist."))
                         .collect();
 
-                    let schema = GroupSchema::infer(node_attributes, edge_attributes);
+                    let schema = GroupSchema::infer_with_widening(
+                        node_attributes,
+                        edge_attributes,
+                        &widening,
+                    );
 
                     (group.clone(), schema)
                 });
 
         #[expect(clippy::missing_panics_doc, reason = "infallible")]
-        let ungrouped_schema = GroupSchema::infer(
+        let ungrouped_schema = GroupSchema::infer_with_widening(
             ungrouped
                 .0
                 .into_iter()
@@ -504,13 +1042,81 @@ impl Schema {
                 .into_iter()
                 .map(|edge| graphrecord.edge_attributes(edge).expect("Edge must exist."))
                 .collect::<Vec<_>>(),
+            &widening,
         );
 
         Self {
             groups: group_schemas.collect(),
             ungrouped: ungrouped_schema,
             r#type: SchemaType::Inferred,
+            non_finite_float_policy: NonFiniteFloatPolicy::Allow,
+            widening,
+            conversion_mode: ConversionMode::Lenient,
+        }
+    }
+
+    /// Infers the attribute types of the schema from the values in
+    /// `graphrecord` with the default [`AttributeTypeInference`], e.g. to
+    /// treat String and Int attributes with few distinct values, like a
+    /// gender or a diagnosis code, as Categorical in the overview.
+    #[expect(clippy::missing_panics_doc, reason = "infallible")]
+    pub fn infer_attribute_types(&mut self, graphrecord: &GraphRecord) {
+        self.infer_attribute_types_with(graphrecord, &AttributeTypeInference::default())
+            .expect("Inferred attribute types must match their data types.");
+    }
+
+    /// Like [`Schema::infer_attribute_types`], but with the heuristics and
+    /// overrides of `inference`. The attribute types of every group are
+    /// inferred from the nodes and edges of the group, the data types are
+    /// kept. Fails without changing the schema if an override does not match
+    /// the data type of its attribute.
+    pub fn infer_attribute_types_with(
+        &mut self,
+        graphrecord: &GraphRecord,
+        inference: &AttributeTypeInference,
+    ) -> Result<(), GraphError> {
+        let mut groups = self.groups.clone();
+
+        for (group, schema) in &mut groups {
+            let nodes: Vec<_> = graphrecord
+                .nodes_in_group(group)
+                .map(|nodes| {
+                    nodes
+                        .filter_map(|node| graphrecord.node_attributes(node).ok())
+                        .collect()
+                })
+                .unwrap_or_default();
+            let edges: Vec<_> = graphrecord
+                .edges_in_group(group)
+                .map(|edges| {
+                    edges
+                        .filter_map(|edge| graphrecord.edge_attributes(edge).ok())
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            schema.nodes.infer_attribute_types(&nodes, inference)?;
+            schema.edges.infer_attribute_types(&edges, inference)?;
         }
+
+        let mut ungrouped = self.ungrouped.clone();
+
+        let nodes: Vec<_> = graphrecord
+            .ungrouped_nodes()
+            .filter_map(|node| graphrecord.node_attributes(node).ok())
+            .collect();
+        let edges: Vec<_> = graphrecord
+            .ungrouped_edges()
+            .filter_map(|edge| graphrecord.edge_attributes(edge).ok())
+            .collect();
+
+        ungrouped.nodes.infer_attribute_types(&nodes, inference)?;
+        ungrouped.edges.infer_attribute_types(&edges, inference)?;
+
+        self.groups = groups;
+        self.ungrouped = ungrouped;
+
+        Ok(())
     }
 
     #[must_use]
@@ -537,7 +1143,7 @@ impl Schema {
     pub fn validate_node<'a>(
         &self,
         index: &'a NodeIndex,
-        attributes: &'a Attributes,
+        attributes: &'a impl ReadAttributes,
         group: Option<&'a Group>,
     ) -> Result<(), GraphError> {
         match group {
@@ -555,7 +1161,7 @@ impl Schema {
     pub fn validate_edge<'a>(
         &self,
         index: &'a EdgeIndex,
-        attributes: &'a Attributes,
+        attributes: &'a impl ReadAttributes,
         group: Option<&'a Group>,
     ) -> Result<(), GraphError> {
         match group {
@@ -570,38 +1176,137 @@ impl Schema {
         }
     }
 
+    /// Checks that `attribute` is part of the node schema of any of `groups`,
+    /// or of any group and the ungrouped schema if `groups` is `None`.
+    pub fn validate_node_attribute(
+        &self,
+        attribute: &GraphRecordAttribute,
+        groups: Option<&[Group]>,
+    ) -> Result<(), GraphError> {
+        self.validate_attribute(attribute, groups, "node", GroupSchema::nodes)
+    }
+
+    /// Checks that `attribute` is part of the edge schema of any of `groups`,
+    /// or of any group and the ungrouped schema if `groups` is `None`.
+    pub fn validate_edge_attribute(
+        &self,
+        attribute: &GraphRecordAttribute,
+        groups: Option<&[Group]>,
+    ) -> Result<(), GraphError> {
+        self.validate_attribute(attribute, groups, "edge", GroupSchema::edges)
+    }
+
+    fn validate_attribute(
+        &self,
+        attribute: &GraphRecordAttribute,
+        groups: Option<&[Group]>,
+        kind: &str,
+        attributes: impl Fn(&GroupSchema) -> &AttributeSchemaMapping,
+    ) -> Result<(), GraphError> {
+        let group_schemas: Vec<_> = match groups {
+            Some(groups) => groups
+                .iter()
+                .filter_map(|group| self.groups.get(group))
+                .collect(),
+            None => std::iter::once(&self.ungrouped)
+                .chain(self.groups.values())
+                .collect(),
+        };
+
+        if group_schemas
+            .iter()
+            .any(|group_schema| attributes(group_schema).contains_key(attribute))
+        {
+            return Ok(());
+        }
+
+        let attribute_name = attribute.to_string();
+        let close_matches = group_schemas
+            .iter()
+            .flat_map(|group_schema| attributes(group_schema).keys())
+            .map(ToString::to_string)
+            .filter(|candidate| is_close_match(&attribute_name, candidate))
+            .unique()
+            .sorted()
+            .collect::<Vec<_>>();
+
+        let suggestion = if close_matches.is_empty() {
+            String::new()
+        } else {
+            format!(" Did you mean: {}?", close_matches.join(", "))
+        };
+
+        Err(GraphError::SchemaError(match groups {
+            Some(groups) => format!(
+                "Attribute {attribute} not found in {kind} schema of group {}.{suggestion}",
+                groups.iter().join(", ")
+            ),
+            None => format!("Attribute {attribute} not found in {kind} schema.{suggestion}"),
+        }))
+    }
+
+    /// Returns the schema of `group`, adding an empty one if it is missing.
+    /// Unlike an entry lookup, this only clones the group when it is added,
+    /// not on every update of an existing group.
+    fn group_schema_mut<'a>(
+        groups: &'a mut HashMap<Group, GroupSchema>,
+        group: &Group,
+    ) -> &'a mut GroupSchema {
+        if !groups.contains_key(group) {
+            groups.insert(group.clone(), GroupSchema::default());
+        }
+
+        groups.get_mut(group).expect("Group schema must exist")
+    }
+
     pub(crate) fn update_node(
         &mut self,
-        attributes: &Attributes,
+        attributes: &impl ReadAttributes,
         group: Option<&Group>,
         empty: bool,
     ) {
-        match group {
-            Some(group) => {
-                self.groups
-                    .entry(group.clone())
-                    .or_default()
-                    .update_node(attributes, empty);
-            }
-            None => self.ungrouped.update_node(attributes, empty),
+        let schema = match group {
+            Some(group) => Self::group_schema_mut(&mut self.groups, group),
+            None => &mut self.ungrouped,
+        };
+
+        schema.update_node(attributes, empty, &self.widening);
+    }
+
+    /// Folds the attributes of a batch of nodes into the running attribute
+    /// types of `group`, looking its schema up once for the whole batch. Like
+    /// adding the nodes one by one, only the first can be the first of the
+    /// group.
+    pub(crate) fn update_nodes<'a, A: ReadAttributes + 'a>(
+        &mut self,
+        attributes: impl IntoIterator<Item = &'a A>,
+        group: Option<&Group>,
+        mut empty: bool,
+    ) {
+        let schema = match group {
+            Some(group) => Self::group_schema_mut(&mut self.groups, group),
+            None => &mut self.ungrouped,
+        };
+
+        for attributes in attributes {
+            schema.update_node(attributes, empty, &self.widening);
+
+            empty = false;
         }
     }
 
     pub(crate) fn update_edge(
         &mut self,
-        attributes: &Attributes,
+        attributes: &impl ReadAttributes,
         group: Option<&Group>,
         empty: bool,
     ) {
-        match group {
-            Some(group) => {
-                self.groups
-                    .entry(group.clone())
-                    .or_default()
-                    .update_edge(attributes, empty);
-            }
-            None => self.ungrouped.update_edge(attributes, empty),
-        }
+        let schema = match group {
+            Some(group) => Self::group_schema_mut(&mut self.groups, group),
+            None => &mut self.ungrouped,
+        };
+
+        schema.update_edge(attributes, empty, &self.widening);
     }
 
     pub fn set_node_attribute(
@@ -676,7 +1381,7 @@ impl Schema {
                     .nodes
                     .0
                     .entry(attribute.clone())
-                    .and_modify(|value| value.merge(&attribute_data_type))
+                    .and_modify(|value| value.merge(&attribute_data_type, &self.widening))
                     .or_insert(attribute_data_type);
             }
             None => {
@@ -684,7 +1389,7 @@ impl Schema {
                     .nodes
                     .0
                     .entry(attribute.clone())
-                    .and_modify(|value| value.merge(&attribute_data_type))
+                    .and_modify(|value| value.merge(&attribute_data_type, &self.widening))
                     .or_insert(attribute_data_type);
             }
         }
@@ -708,7 +1413,7 @@ impl Schema {
                     .edges
                     .0
                     .entry(attribute.clone())
-                    .and_modify(|value| value.merge(&attribute_data_type))
+                    .and_modify(|value| value.merge(&attribute_data_type, &self.widening))
                     .or_insert(attribute_data_type);
             }
             None => {
@@ -716,7 +1421,7 @@ impl Schema {
                     .edges
                     .0
                     .entry(attribute.clone())
-                    .and_modify(|value| value.merge(&attribute_data_type))
+                    .and_modify(|value| value.merge(&attribute_data_type, &self.widening))
                     .or_insert(attribute_data_type);
             }
         }
@@ -758,6 +1463,48 @@ impl Schema {
         }
     }
 
+    /// Renames `attribute` to `new_attribute` in the node schema of `group`,
+    /// or of the ungrouped nodes if `group` is `None`. If `new_attribute` is
+    /// already part of the schema, both data types are merged.
+    pub fn rename_node_attribute(
+        &mut self,
+        attribute: &GraphRecordAttribute,
+        new_attribute: GraphRecordAttribute,
+        group: Option<&Group>,
+    ) {
+        let group_schema = match group {
+            Some(group) => match self.groups.get_mut(group) {
+                Some(group_schema) => group_schema,
+                None => return,
+            },
+            None => &mut self.ungrouped,
+        };
+
+        group_schema
+            .nodes
+            .rename(attribute, new_attribute, &self.widening);
+    }
+
+    /// Like [`Schema::rename_node_attribute`], but for the edge schema.
+    pub fn rename_edge_attribute(
+        &mut self,
+        attribute: &GraphRecordAttribute,
+        new_attribute: GraphRecordAttribute,
+        group: Option<&Group>,
+    ) {
+        let group_schema = match group {
+            Some(group) => match self.groups.get_mut(group) {
+                Some(group_schema) => group_schema,
+                None => return,
+            },
+            None => &mut self.ungrouped,
+        };
+
+        group_schema
+            .edges
+            .rename(attribute, new_attribute, &self.widening);
+    }
+
     pub fn add_group(&mut self, group: Group, schema: GroupSchema) -> Result<(), GraphError> {
         if self.groups.contains_key(&group) {
             return Err(GraphError::SchemaError(format!(
@@ -765,32 +1512,233 @@ impl Schema {
             )));
         }
 
-        self.groups.insert(group, schema);
+        self.groups.insert(group, schema);
+
+        Ok(())
+    }
+
+    pub fn remove_group(&mut self, group: &Group) {
+        self.groups.remove(group);
+    }
+
+    pub const fn freeze(&mut self) {
+        self.r#type = SchemaType::Provided;
+    }
+
+    pub const fn unfreeze(&mut self) {
+        self.r#type = SchemaType::Inferred;
+    }
+
+    #[must_use]
+    pub const fn non_finite_float_policy(&self) -> NonFiniteFloatPolicy {
+        self.non_finite_float_policy
+    }
+
+    /// Sets how NaN and infinite Float values are handled. The policy only
+    /// applies to values added after it is set, existing values are kept.
+    pub const fn set_non_finite_float_policy(&mut self, policy: NonFiniteFloatPolicy) {
+        self.non_finite_float_policy = policy;
+    }
+
+    #[must_use]
+    pub const fn conversion_mode(&self) -> ConversionMode {
+        self.conversion_mode
+    }
+
+    /// Sets how values of data frames are converted when nodes and edges are
+    /// added from data frames.
+    pub const fn set_conversion_mode(&mut self, mode: ConversionMode) {
+        self.conversion_mode = mode;
+    }
+
+    #[must_use]
+    pub const fn widening(&self) -> &DataTypeWidening {
+        &self.widening
+    }
+
+    /// Sets the widening used when data types of an attribute are merged. The
+    /// data types already in the schema are kept, use
+    /// [`Schema::infer_with_widening`] to infer them again.
+    pub fn set_widening(&mut self, widening: DataTypeWidening) {
+        self.widening = widening;
+    }
+
+    /// Merges `other` into the schema using the widening of the schema. The
+    /// schemas of groups that both have are merged, an attribute that only one
+    /// of them has becomes optional. The schema stays provided only if both
+    /// schemas are provided.
+    pub fn union(&mut self, other: &Self) {
+        self.merge_into(other, ConflictStrategy::Widen);
+    }
+
+    /// Like [`Schema::union`], but returns a new schema and the attributes
+    /// whose data types differ between the schemas, resolved by `strategy`.
+    pub fn merge(
+        &self,
+        other: &Self,
+        strategy: ConflictStrategy,
+    ) -> Result<SchemaMerge, GraphError> {
+        let mut schema = self.clone();
+
+        let (mut node_conflicts, mut edge_conflicts) = schema.merge_into(other, strategy);
+
+        node_conflicts.sort_by(SchemaConflict::cmp_position);
+        edge_conflicts.sort_by(SchemaConflict::cmp_position);
+
+        let first_conflict = node_conflicts.first().or_else(|| edge_conflicts.first());
+
+        if let (ConflictStrategy::Reject, Some(conflict)) = (strategy, first_conflict) {
+            return Err(GraphError::SchemaError(format!(
+                "Cannot merge schemas. {conflict}"
+            )));
+        }
+
+        Ok(SchemaMerge {
+            schema,
+            node_conflicts,
+            edge_conflicts,
+        })
+    }
+
+    /// Returns the groups and attributes that differ between the schema and
+    /// `new`, e.g. to report how a schema evolved when it is replaced.
+    #[must_use]
+    pub fn diff(&self, new: &Self) -> SchemaDiff {
+        let mut node_changes = Vec::new();
+        let mut edge_changes = Vec::new();
+
+        SchemaChange::collect(
+            None,
+            Some(self.ungrouped.nodes()),
+            Some(new.ungrouped.nodes()),
+            &mut node_changes,
+        );
+        SchemaChange::collect(
+            None,
+            Some(self.ungrouped.edges()),
+            Some(new.ungrouped.edges()),
+            &mut edge_changes,
+        );
+
+        let groups: GrHashSet<_> = self.groups.keys().chain(new.groups.keys()).collect();
+
+        for group in groups {
+            let old_schema = self.groups.get(group);
+            let new_schema = new.groups.get(group);
+
+            SchemaChange::collect(
+                Some(group),
+                old_schema.map(GroupSchema::nodes),
+                new_schema.map(GroupSchema::nodes),
+                &mut node_changes,
+            );
+            SchemaChange::collect(
+                Some(group),
+                old_schema.map(GroupSchema::edges),
+                new_schema.map(GroupSchema::edges),
+                &mut edge_changes,
+            );
+        }
+
+        node_changes.sort_by(SchemaChange::cmp_position);
+        edge_changes.sort_by(SchemaChange::cmp_position);
+
+        let missing_groups = |groups: &HashMap<Group, GroupSchema>,
+                              other: &HashMap<Group, GroupSchema>|
+         -> Vec<Group> {
+            groups
+                .keys()
+                .filter(|group| !other.contains_key(*group))
+                .cloned()
+                .sorted_by(Group::total_cmp)
+                .collect()
+        };
+
+        SchemaDiff {
+            added_groups: missing_groups(&new.groups, &self.groups),
+            removed_groups: missing_groups(&self.groups, &new.groups),
+            node_changes,
+            edge_changes,
+        }
+    }
+
+    fn merge_into(
+        &mut self,
+        other: &Self,
+        strategy: ConflictStrategy,
+    ) -> (Vec<SchemaConflict>, Vec<SchemaConflict>) {
+        let (mut node_conflicts, mut edge_conflicts) =
+            self.ungrouped
+                .merge(&other.ungrouped, None, strategy, &self.widening);
+
+        for (group, group_schema) in &other.groups {
+            match self.groups.get_mut(group) {
+                Some(schema) => {
+                    let (nodes, edges) =
+                        schema.merge(group_schema, Some(group), strategy, &self.widening);
+
+                    node_conflicts.extend(nodes);
+                    edge_conflicts.extend(edges);
+                }
+                None => {
+                    self.groups.insert(group.clone(), group_schema.clone());
+                }
+            }
+        }
+
+        if other.r#type == SchemaType::Inferred {
+            self.r#type = SchemaType::Inferred;
+        }
 
-        Ok(())
+        (node_conflicts, edge_conflicts)
     }
 
-    pub fn remove_group(&mut self, group: &Group) {
-        self.groups.remove(group);
-    }
+    /// Applies the [`NonFiniteFloatPolicy`] to `attributes` before they are
+    /// stored.
+    pub(crate) fn handle_non_finite_floats<A>(&self, attributes: &mut A) -> Result<(), GraphError>
+    where
+        for<'a> &'a mut A:
+            IntoIterator<Item = (&'a GraphRecordAttribute, &'a mut GraphRecordValue)>,
+    {
+        if self.non_finite_float_policy == NonFiniteFloatPolicy::Allow {
+            return Ok(());
+        }
 
-    pub const fn freeze(&mut self) {
-        self.r#type = SchemaType::Provided;
-    }
+        for (key, value) in attributes {
+            let GraphRecordValue::Float(float) = value else {
+                continue;
+            };
 
-    pub const fn unfreeze(&mut self) {
-        self.r#type = SchemaType::Inferred;
+            if float.is_finite() {
+                continue;
+            }
+
+            match self.non_finite_float_policy {
+                NonFiniteFloatPolicy::Allow => {}
+                NonFiniteFloatPolicy::Reject => {
+                    return Err(GraphError::SchemaError(format!(
+                        "Attribute {key} is {float}, but the schema rejects non-finite floats."
+                    )));
+                }
+                NonFiniteFloatPolicy::CoerceToNull => *value = GraphRecordValue::Null,
+            }
+        }
+
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod test {
-    use super::{AttributeDataType, GroupSchema};
+    use super::{
+        AttributeDataType, AttributeTypeInference, ConflictStrategy, DataTypeWidening, GroupSchema,
+        SchemaChange, SchemaConflict, SchemaDiff,
+    };
     use crate::{
         GraphRecord,
         graphrecord::{
             Attributes, Schema, SchemaType,
-            datatypes::DataType,
+            datatypes::{DataType, GraphRecordValue},
             schema::{AttributeSchema, AttributeSchemaKind, AttributeType},
         },
     };
@@ -822,6 +1770,10 @@ mod test {
             AttributeType::infer(&DataType::Duration),
             AttributeType::Temporal
         );
+        assert_eq!(
+            AttributeType::infer(&DataType::GeoPoint),
+            AttributeType::Unstructured
+        );
         assert_eq!(
             AttributeType::infer(&DataType::Null),
             AttributeType::Unstructured
@@ -841,6 +1793,17 @@ mod test {
             AttributeType::infer(&DataType::Option(Box::new(DataType::Int))),
             AttributeType::Continuous
         );
+        assert_eq!(
+            AttributeType::infer(&DataType::Missing),
+            AttributeType::Unstructured
+        );
+        assert_eq!(
+            AttributeType::infer(&DataType::Union((
+                Box::new(DataType::Missing),
+                Box::new(DataType::Int)
+            ))),
+            AttributeType::Continuous
+        );
     }
 
     #[test]
@@ -920,36 +1883,61 @@ mod test {
 
     #[test]
     fn test_data_type_merge() {
-        assert_eq!(DataType::Int.merge(&DataType::Int), DataType::Int);
+        let widening = DataTypeWidening::default();
+
+        assert_eq!(
+            DataType::Int.merge(&DataType::Int, &widening),
+            DataType::Int
+        );
         assert_eq!(
-            DataType::Int.merge(&DataType::Float),
+            DataType::Int.merge(&DataType::Float, &widening),
             DataType::Union((Box::new(DataType::Int), Box::new(DataType::Float)))
         );
         assert_eq!(
-            DataType::Int.merge(&DataType::Null),
+            DataType::Int.merge(&DataType::Null, &widening),
             DataType::Option(Box::new(DataType::Int))
         );
         assert_eq!(
-            DataType::Null.merge(&DataType::Int),
+            DataType::Null.merge(&DataType::Int, &widening),
             DataType::Option(Box::new(DataType::Int))
         );
-        assert_eq!(DataType::Null.merge(&DataType::Null), DataType::Null);
-        assert_eq!(DataType::Int.merge(&DataType::Any), DataType::Any);
-        assert_eq!(DataType::Any.merge(&DataType::Int), DataType::Any);
         assert_eq!(
-            DataType::Option(Box::new(DataType::Int)).merge(&DataType::String),
+            DataType::Null.merge(&DataType::Null, &widening),
+            DataType::Null
+        );
+        assert_eq!(
+            DataType::Int.merge(&DataType::Missing, &widening),
+            DataType::Union((Box::new(DataType::Int), Box::new(DataType::Missing)))
+        );
+        assert_eq!(
+            DataType::Option(Box::new(DataType::Int)).merge(&DataType::Missing, &widening),
+            DataType::Option(Box::new(DataType::Union((
+                Box::new(DataType::Int),
+                Box::new(DataType::Missing)
+            ))))
+        );
+        assert_eq!(
+            DataType::Int.merge(&DataType::Any, &widening),
+            DataType::Any
+        );
+        assert_eq!(
+            DataType::Any.merge(&DataType::Int, &widening),
+            DataType::Any
+        );
+        assert_eq!(
+            DataType::Option(Box::new(DataType::Int)).merge(&DataType::String, &widening),
             DataType::Option(Box::new(DataType::Union((
                 Box::new(DataType::Int),
                 Box::new(DataType::String)
             ))))
         );
         assert_eq!(
-            DataType::Int.merge(&DataType::Option(Box::new(DataType::Int))),
+            DataType::Int.merge(&DataType::Option(Box::new(DataType::Int)), &widening),
             DataType::Option(Box::new(DataType::Int))
         );
         assert_eq!(
             DataType::Option(Box::new(DataType::Int))
-                .merge(&DataType::Option(Box::new(DataType::String))),
+                .merge(&DataType::Option(Box::new(DataType::String)), &widening),
             DataType::Option(Box::new(DataType::Union((
                 Box::new(DataType::Int),
                 Box::new(DataType::String)
@@ -957,6 +1945,39 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_data_type_widening() {
+        let widening = DataTypeWidening::numeric();
+
+        assert_eq!(
+            DataType::Int.merge(&DataType::Float, &widening),
+            DataType::Float
+        );
+        assert_eq!(
+            DataType::Float.merge(&DataType::Int, &widening),
+            DataType::Float
+        );
+        assert_eq!(
+            DataType::Option(Box::new(DataType::Int)).merge(&DataType::Float, &widening),
+            DataType::Option(Box::new(DataType::Float))
+        );
+        assert_eq!(
+            DataType::Int.merge(&DataType::String, &widening),
+            DataType::Union((Box::new(DataType::Int), Box::new(DataType::String)))
+        );
+
+        let widening = widening.with_rule(DataType::Float, DataType::String);
+
+        assert_eq!(
+            DataType::Int.merge(&DataType::String, &widening),
+            DataType::String
+        );
+        assert_eq!(
+            DataType::Bool.merge(&DataType::String, &widening),
+            DataType::Union((Box::new(DataType::Bool), Box::new(DataType::String)))
+        );
+    }
+
     #[test]
     fn test_attribute_data_type_new() {
         assert!(AttributeDataType::new(DataType::String, AttributeType::Categorical).is_ok());
@@ -1086,6 +2107,7 @@ mod test {
         attribute_data_type.merge(
             &AttributeDataType::new(DataType::Float, AttributeType::Continuous)
                 .expect("AttributeType was inferred from DataType."),
+            &DataTypeWidening::default(),
         );
 
         assert_eq!(
@@ -1138,26 +2160,22 @@ mod test {
     }
 
     #[test]
-    fn test_attribute_schema_kind_error_message_expected() {
+    fn test_attribute_schema_kind_type_mismatch() {
         let index = 0;
         let key = "key";
         let data_type = DataType::Int;
         let expected_data_type = DataType::Float;
 
         assert_eq!(
-            AttributeSchemaKind::Node(&(index.into())).error_message_expected(
-                &(key.into()),
-                &data_type,
-                &expected_data_type
-            ),
+            AttributeSchemaKind::Node(&(index.into()))
+                .type_mismatch(&(key.into()), &data_type, &expected_data_type)
+                .message(),
             "Attribute key of node with index 0 is of type Int. Expected Float."
         );
         assert_eq!(
-            AttributeSchemaKind::Edge(&(index as u32)).error_message_expected(
-                &(key.into()),
-                &data_type,
-                &expected_data_type
-            ),
+            AttributeSchemaKind::Edge(&(index as u32))
+                .type_mismatch(&(key.into()), &data_type, &expected_data_type)
+                .message(),
             "Attribute key of edge with index 0 is of type Int. Expected Float."
         );
     }
@@ -1242,7 +2260,9 @@ mod test {
         assert!(
             attribute_schema
                 .validate(&attributes, &AttributeSchemaKind::Node(&0.into()))
-                .is_err_and(|error| { matches!(error, crate::errors::GraphError::SchemaError(_)) })
+                .is_err_and(|error| {
+                    matches!(error, crate::errors::GraphError::TypeMismatch { .. })
+                })
         );
 
         let attributes: Attributes = vec![
@@ -1268,7 +2288,7 @@ mod test {
                 .into_iter()
                 .collect();
 
-        schema.update(&attributes, true);
+        schema.update(&attributes, true, &DataTypeWidening::default());
 
         assert_eq!(schema.0.len(), 2);
         assert_eq!(
@@ -1285,7 +2305,7 @@ mod test {
                 .into_iter()
                 .collect();
 
-        schema.update(&new_attributes, false);
+        schema.update(&new_attributes, false, &DataTypeWidening::default());
 
         assert_eq!(schema.0.len(), 3);
         assert_eq!(
@@ -1300,6 +2320,24 @@ mod test {
             schema.0.get(&"key3".into()).unwrap().data_type(),
             &DataType::Option(Box::new(DataType::Bool))
         );
+
+        let expected_schema = schema.clone();
+
+        schema.update(&new_attributes, false, &DataTypeWidening::default());
+        schema.update(&attributes, false, &DataTypeWidening::default());
+
+        assert_eq!(
+            schema.0.get(&"key1".into()).unwrap(),
+            expected_schema.0.get(&"key1".into()).unwrap()
+        );
+        assert_eq!(
+            schema.0.get(&"key2".into()).unwrap(),
+            expected_schema.0.get(&"key2".into()).unwrap()
+        );
+        assert_eq!(
+            schema.0.get(&"key3".into()).unwrap(),
+            expected_schema.0.get(&"key3".into()).unwrap()
+        );
     }
 
     #[test]
@@ -1412,7 +2450,9 @@ mod test {
         assert!(
             group_schema
                 .validate_node(&0.into(), &attributes)
-                .is_err_and(|error| { matches!(error, crate::errors::GraphError::SchemaError(_)) })
+                .is_err_and(|error| {
+                    matches!(error, crate::errors::GraphError::TypeMismatch { .. })
+                })
         );
     }
 
@@ -1450,7 +2490,9 @@ mod test {
         assert!(
             group_schema
                 .validate_edge(&0, &attributes)
-                .is_err_and(|error| { matches!(error, crate::errors::GraphError::SchemaError(_)) })
+                .is_err_and(|error| {
+                    matches!(error, crate::errors::GraphError::TypeMismatch { .. })
+                })
         );
     }
 
@@ -1527,7 +2569,7 @@ mod test {
         let mut group_schema = GroupSchema::default();
         let attributes = Attributes::from([("key1".into(), 0.into()), ("key2".into(), 0.0.into())]);
 
-        group_schema.update_node(&attributes, true);
+        group_schema.update_node(&attributes, true, &DataTypeWidening::default());
 
         assert_eq!(group_schema.nodes().len(), 2);
         assert_eq!(
@@ -1554,7 +2596,7 @@ mod test {
         let attributes =
             Attributes::from([("key3".into(), true.into()), ("key4".into(), "test".into())]);
 
-        group_schema.update_edge(&attributes, true);
+        group_schema.update_edge(&attributes, true, &DataTypeWidening::default());
 
         assert_eq!(group_schema.edges().len(), 2);
         assert_eq!(
@@ -1608,6 +2650,189 @@ mod test {
         assert_eq!(schema.group(&"test".into()).unwrap().edges().len(), 1);
     }
 
+    #[test]
+    fn test_schema_infer_with_widening() {
+        let mut graphrecord = GraphRecord::new();
+        graphrecord
+            .add_node(0.into(), Attributes::from([("key1".into(), 1.into())]))
+            .unwrap();
+        graphrecord
+            .add_node(1.into(), Attributes::from([("key1".into(), 1.5.into())]))
+            .unwrap();
+
+        let schema = Schema::infer_with_widening(&graphrecord, DataTypeWidening::numeric());
+
+        assert_eq!(
+            schema
+                .ungrouped()
+                .nodes()
+                .get(&"key1".into())
+                .unwrap()
+                .data_type(),
+            &DataType::Float
+        );
+
+        graphrecord.set_schema(schema).unwrap();
+        graphrecord
+            .add_node(2.into(), Attributes::from([("key1".into(), 2.into())]))
+            .unwrap();
+
+        assert_eq!(
+            graphrecord
+                .get_schema()
+                .ungrouped()
+                .nodes()
+                .get(&"key1".into())
+                .unwrap()
+                .data_type(),
+            &DataType::Float
+        );
+    }
+
+    #[test]
+    fn test_schema_infer_attribute_types() {
+        let mut graphrecord = GraphRecord::new();
+
+        for index in 0..10 {
+            graphrecord
+                .add_node(
+                    index.into(),
+                    Attributes::from([
+                        (
+                            "gender".into(),
+                            (if index % 2 == 0 { "M" } else { "F" }).into(),
+                        ),
+                        ("age".into(), (20 + index).into()),
+                        ("flag".into(), (index % 2).into()),
+                        ("name".into(), format!("name_{index}").into()),
+                    ]),
+                )
+                .unwrap();
+        }
+
+        let attribute_type = |schema: &Schema, attribute: &str| {
+            *schema
+                .ungrouped()
+                .nodes()
+                .get(&attribute.into())
+                .unwrap()
+                .attribute_type()
+        };
+
+        let mut schema = Schema::infer(&graphrecord);
+
+        assert_eq!(
+            AttributeType::Unstructured,
+            attribute_type(&schema, "gender")
+        );
+        assert_eq!(AttributeType::Continuous, attribute_type(&schema, "flag"));
+
+        schema.infer_attribute_types(&graphrecord);
+
+        assert_eq!(
+            AttributeType::Categorical,
+            attribute_type(&schema, "gender")
+        );
+        assert_eq!(AttributeType::Continuous, attribute_type(&schema, "age"));
+        assert_eq!(AttributeType::Categorical, attribute_type(&schema, "flag"));
+        assert_eq!(AttributeType::Unstructured, attribute_type(&schema, "name"));
+
+        schema
+            .infer_attribute_types_with(
+                &graphrecord,
+                &AttributeTypeInference::default().with_override("flag", AttributeType::Continuous),
+            )
+            .unwrap();
+
+        assert_eq!(AttributeType::Continuous, attribute_type(&schema, "flag"));
+
+        assert!(
+            schema
+                .infer_attribute_types_with(
+                    &graphrecord,
+                    &AttributeTypeInference::default()
+                        .with_override("name", AttributeType::Temporal),
+                )
+                .is_err()
+        );
+        assert_eq!(AttributeType::Continuous, attribute_type(&schema, "flag"));
+    }
+
+    #[test]
+    fn test_schema_infer_missing() {
+        let mut graphrecord = GraphRecord::new();
+
+        for index in 0..10 {
+            let (tested, age, gender) = if index % 3 == 0 {
+                (
+                    GraphRecordValue::Missing,
+                    GraphRecordValue::Missing,
+                    GraphRecordValue::Missing,
+                )
+            } else {
+                (
+                    (index % 2 == 0).into(),
+                    (20 + index).into(),
+                    (if index % 2 == 0 { "M" } else { "F" }).into(),
+                )
+            };
+
+            graphrecord
+                .add_node(
+                    index.into(),
+                    Attributes::from([
+                        ("tested".into(), tested),
+                        ("age".into(), age),
+                        ("gender".into(), gender),
+                    ]),
+                )
+                .unwrap();
+        }
+
+        let mut schema = Schema::infer(&graphrecord);
+        schema.infer_attribute_types(&graphrecord);
+
+        let attribute = |attribute: &str| {
+            schema
+                .ungrouped()
+                .nodes()
+                .get(&attribute.into())
+                .unwrap()
+                .clone()
+        };
+
+        let tested = attribute("tested");
+        assert!(tested.data_type().evaluate(&DataType::Union((
+            Box::new(DataType::Bool),
+            Box::new(DataType::Missing)
+        ))));
+        assert!(!tested.data_type().evaluate(&DataType::Null));
+        assert_eq!(AttributeType::Categorical, *tested.attribute_type());
+
+        assert_eq!(
+            AttributeType::Continuous,
+            *attribute("age").attribute_type()
+        );
+        assert_eq!(
+            AttributeType::Categorical,
+            *attribute("gender").attribute_type()
+        );
+
+        assert!(
+            schema
+                .validate_node(
+                    &0.into(),
+                    &Attributes::from([
+                        ("tested".into(), GraphRecordValue::Null),
+                        ("age".into(), 30.into()),
+                        ("gender".into(), "F".into()),
+                    ]),
+                    None,
+                )
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_schema_groups() {
         let schema = Schema::new_inferred(
@@ -1693,6 +2918,58 @@ mod test {
         assert!(schema.validate_edge(&0, &invalid_attributes, None).is_err());
     }
 
+    #[test]
+    fn test_schema_validate_node_attribute() {
+        let mut schema = Schema::new_inferred(
+            HashMap::new(),
+            GroupSchema::new(AttributeSchema::default(), AttributeSchema::default()),
+        );
+        schema
+            .set_node_attribute(
+                &"age".into(),
+                DataType::Int,
+                AttributeType::Continuous,
+                None,
+            )
+            .unwrap();
+        schema
+            .set_node_attribute(
+                &"gender".into(),
+                DataType::String,
+                AttributeType::Categorical,
+                Some(&"patient".into()),
+            )
+            .unwrap();
+
+        assert!(schema.validate_node_attribute(&"age".into(), None).is_ok());
+        assert!(
+            schema
+                .validate_node_attribute(&"gender".into(), None)
+                .is_ok()
+        );
+        assert!(
+            schema
+                .validate_node_attribute(&"gender".into(), Some(&["patient".into()]))
+                .is_ok()
+        );
+
+        assert_eq!(
+            "SchemaError: Attribute agee not found in node schema. Did you mean: age?",
+            schema
+                .validate_node_attribute(&"agee".into(), None)
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            "SchemaError: Attribute age not found in node schema of group patient.",
+            schema
+                .validate_node_attribute(&"age".into(), Some(&["patient".into()]))
+                .unwrap_err()
+                .to_string()
+        );
+        assert!(schema.validate_edge_attribute(&"age".into(), None).is_err());
+    }
+
     #[test]
     fn test_schema_update_node() {
         let mut schema = Schema::new_inferred(
@@ -1724,6 +3001,38 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_schema_update_nodes() {
+        let attributes = [
+            Attributes::from([("key1".into(), 0.into())]),
+            Attributes::from([("key1".into(), "0".into()), ("key2".into(), 0.0.into())]),
+            Attributes::from([("key2".into(), 0.into())]),
+        ];
+
+        let mut schema = Schema::new_inferred(HashMap::new(), GroupSchema::default());
+        schema.update_nodes(&attributes, Some(&"group".into()), true);
+
+        let mut expected_schema = Schema::new_inferred(HashMap::new(), GroupSchema::default());
+        for (position, attributes) in attributes.iter().enumerate() {
+            expected_schema.update_node(attributes, Some(&"group".into()), position == 0);
+        }
+
+        assert_eq!(expected_schema, schema);
+        assert_eq!(
+            &DataType::Option(Box::new(DataType::Union((
+                Box::new(DataType::Int),
+                Box::new(DataType::String)
+            )))),
+            schema
+                .group(&"group".into())
+                .unwrap()
+                .nodes()
+                .get(&"key1".into())
+                .unwrap()
+                .data_type()
+        );
+    }
+
     #[test]
     fn test_schema_update_edge() {
         let mut schema = Schema::new_inferred(
@@ -2131,4 +3440,121 @@ mod test {
         schema.unfreeze();
         assert_eq!(schema.schema_type(), &SchemaType::Inferred);
     }
+
+    #[test]
+    fn test_schema_diff() {
+        let group_schema = |data_type: DataType| {
+            GroupSchema::new(
+                AttributeSchema::new(HashMap::from([("age".into(), data_type.into())])),
+                AttributeSchema::default(),
+            )
+        };
+
+        let old = Schema::new_provided(
+            HashMap::from([
+                ("patients".into(), group_schema(DataType::Int)),
+                ("visits".into(), group_schema(DataType::Int)),
+            ]),
+            group_schema(DataType::Int),
+        );
+        let new = Schema::new_provided(
+            HashMap::from([
+                ("patients".into(), group_schema(DataType::Float)),
+                ("diagnoses".into(), group_schema(DataType::Int)),
+            ]),
+            group_schema(DataType::Int),
+        );
+
+        assert!(old.diff(&old).is_empty());
+        assert_eq!(
+            SchemaDiff {
+                added_groups: vec!["diagnoses".into()],
+                removed_groups: vec!["visits".into()],
+                node_changes: vec![
+                    SchemaChange {
+                        group: Some("diagnoses".into()),
+                        attribute: "age".into(),
+                        old: None,
+                        new: Some(DataType::Int.into()),
+                    },
+                    SchemaChange {
+                        group: Some("patients".into()),
+                        attribute: "age".into(),
+                        old: Some(DataType::Int.into()),
+                        new: Some(DataType::Float.into()),
+                    },
+                    SchemaChange {
+                        group: Some("visits".into()),
+                        attribute: "age".into(),
+                        old: Some(DataType::Int.into()),
+                        new: None,
+                    },
+                ],
+                edge_changes: Vec::new(),
+            },
+            old.diff(&new)
+        );
+    }
+
+    #[test]
+    fn test_schema_merge() {
+        let group_schema = |data_type: DataType| {
+            GroupSchema::new(
+                AttributeSchema::new(HashMap::from([("age".into(), data_type.into())])),
+                AttributeSchema::default(),
+            )
+        };
+
+        let first = Schema::new_provided(
+            HashMap::from([("patients".into(), group_schema(DataType::Int))]),
+            group_schema(DataType::Int),
+        );
+        let second = Schema::new_provided(
+            HashMap::from([
+                ("patients".into(), group_schema(DataType::Float)),
+                ("visits".into(), group_schema(DataType::String)),
+            ]),
+            group_schema(DataType::Int),
+        );
+
+        let merge = first.merge(&second, ConflictStrategy::Widen).unwrap();
+
+        assert_eq!(SchemaType::Provided, merge.schema.r#type);
+        assert_eq!(2, merge.schema.groups().len());
+        assert_eq!(
+            DataType::Union((Box::new(DataType::Int), Box::new(DataType::Float))),
+            merge.schema.group(&"patients".into()).unwrap().nodes()[&"age".into()].data_type
+        );
+        assert_eq!(
+            vec![SchemaConflict {
+                group: Some("patients".into()),
+                attribute: "age".into(),
+                first: DataType::Int.into(),
+                second: DataType::Float.into(),
+            }],
+            merge.node_conflicts
+        );
+        assert!(merge.edge_conflicts.is_empty());
+
+        let merge = first.merge(&second, ConflictStrategy::KeepFirst).unwrap();
+
+        assert_eq!(
+            DataType::Int,
+            merge.schema.group(&"patients".into()).unwrap().nodes()[&"age".into()].data_type
+        );
+
+        let merge = first.merge(&second, ConflictStrategy::KeepSecond).unwrap();
+
+        assert_eq!(
+            DataType::Float,
+            merge.schema.group(&"patients".into()).unwrap().nodes()[&"age".into()].data_type
+        );
+
+        assert!(
+            first
+                .merge(&second, ConflictStrategy::Reject)
+                .is_err_and(|error| matches!(error, crate::errors::GraphError::SchemaError(_)))
+        );
+        assert!(first.merge(&first, ConflictStrategy::Reject).is_ok());
+    }
 }
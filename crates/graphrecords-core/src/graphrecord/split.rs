@@ -0,0 +1,227 @@
+//! Splitting a record into independent records per group, e.g. to hand every
+//! department of a hospital only its own slice of the data.
+
+use super::{
+    GraphRecord, Group,
+    schema::{Schema, SchemaType},
+};
+use crate::errors::GraphRecordResult;
+use std::collections::{HashMap, HashSet};
+
+impl GraphRecord {
+    /// Returns one record per group in `groups`, containing the nodes of the
+    /// group, the edges between them and the group itself.
+    ///
+    /// An inferred schema is inferred anew from every split record. A provided
+    /// schema keeps only the schema of the group and of ungrouped entities.
+    /// Edge indices, edge directions, group metadata and the record metadata
+    /// are carried over.
+    pub fn split_by_groups(&self, groups: &[Group]) -> GraphRecordResult<HashMap<Group, Self>> {
+        groups
+            .iter()
+            .map(|group| Ok((group.clone(), self.split_group(group)?)))
+            .collect()
+    }
+
+    fn split_group(&self, group: &Group) -> GraphRecordResult<Self> {
+        let node_indices: Vec<_> = self.nodes_in_group(group)?.collect();
+        let edge_indices: HashSet<_> = self.induced_edges(node_indices.clone()).collect();
+
+        let mut graphrecord = Self::new();
+
+        for node_index in &node_indices {
+            graphrecord.graph.add_node(
+                (*node_index).clone(),
+                self.node_attributes(node_index)?.clone(),
+            )?;
+        }
+
+        for edge_index in &edge_indices {
+            let (source_node_index, target_node_index) = self.edge_endpoints(edge_index)?;
+
+            graphrecord.graph.restore_edge(
+                **edge_index,
+                source_node_index.clone(),
+                target_node_index.clone(),
+                self.edge_attributes(edge_index)?.clone(),
+            )?;
+
+            if self.graph.is_edge_undirected(edge_index)? {
+                graphrecord.graph.set_edge_undirected(edge_index, true)?;
+            }
+        }
+
+        graphrecord
+            .graph
+            .restore_edge_index_state(self.graph.edge_index_state().0, Vec::new());
+
+        graphrecord.group_mapping.add_group(
+            group.clone(),
+            Some(node_indices.into_iter().cloned().collect()),
+            Some(
+                self.edges_in_group(group)?
+                    .filter(|edge_index| edge_indices.contains(edge_index))
+                    .copied()
+                    .collect(),
+            ),
+        )?;
+
+        for (key, value) in self.group_mapping.group_metadata(group)? {
+            graphrecord
+                .group_mapping
+                .set_group_metadata(group, key.clone(), value.clone())?;
+        }
+
+        graphrecord.schema = match self.schema.schema_type() {
+            SchemaType::Inferred => {
+                Schema::infer_with_widening(&graphrecord, self.schema.widening().clone())
+            }
+            SchemaType::Provided => {
+                let groups = self
+                    .schema
+                    .group(group)
+                    .map(|schema| HashMap::from([(group.clone(), schema.clone())]))
+                    .unwrap_or_default();
+
+                let mut schema = Schema::new_provided(groups, self.schema.ungrouped().clone());
+                schema.set_widening(self.schema.widening().clone());
+
+                schema
+            }
+        };
+        graphrecord
+            .schema
+            .set_non_finite_float_policy(self.schema.non_finite_float_policy());
+        graphrecord
+            .schema
+            .set_conversion_mode(self.schema.conversion_mode());
+
+        graphrecord.metadata = self.metadata.clone();
+        graphrecord.strict_queries = self.strict_queries;
+        graphrecord.float_tolerance = self.float_tolerance;
+        graphrecord.random_seed = self.random_seed;
+
+        Ok(graphrecord)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        GraphRecord,
+        errors::GraphRecordError,
+        graphrecord::{
+            NodeIndex,
+            datatypes::DataType,
+            schema::{AttributeDataType, Schema, SchemaType},
+        },
+    };
+    use std::collections::HashMap;
+
+    fn create_graphrecord() -> GraphRecord {
+        let mut graphrecord = GraphRecord::new();
+
+        graphrecord
+            .add_nodes_with_group(
+                vec![
+                    ("0".into(), HashMap::from([("ward".into(), "A".into())])),
+                    ("1".into(), HashMap::from([("ward".into(), "A".into())])),
+                ],
+                "cardiology".into(),
+            )
+            .unwrap();
+        graphrecord
+            .add_nodes_with_group(
+                vec![("2".into(), HashMap::from([("age".into(), 40.into())]))],
+                "oncology".into(),
+            )
+            .unwrap();
+
+        graphrecord
+            .add_edge("0".into(), "2".into(), HashMap::new())
+            .unwrap();
+        graphrecord
+            .add_edge("0".into(), "1".into(), HashMap::new())
+            .unwrap();
+        graphrecord
+            .add_edge_to_group("cardiology".into(), 1)
+            .unwrap();
+
+        graphrecord
+    }
+
+    #[test]
+    fn test_split_by_groups() {
+        let graphrecord = create_graphrecord();
+
+        let records = graphrecord
+            .split_by_groups(&["cardiology".into(), "oncology".into()])
+            .unwrap();
+
+        let cardiology = &records[&"cardiology".into()];
+
+        assert_eq!(2, cardiology.node_count());
+        assert_eq!(vec![&1], cardiology.edge_indices().collect::<Vec<_>>());
+        assert_eq!(
+            vec![&1],
+            cardiology
+                .edges_in_group(&"cardiology".into())
+                .unwrap()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![&NodeIndex::from("cardiology")],
+            cardiology.groups().collect::<Vec<_>>()
+        );
+        assert!(
+            cardiology
+                .get_schema()
+                .group(&"cardiology".into())
+                .unwrap()
+                .nodes()
+                .contains_key(&"ward".into())
+        );
+        assert!(cardiology.get_schema().group(&"oncology".into()).is_err());
+
+        let oncology = &records[&"oncology".into()];
+
+        assert_eq!(1, oncology.node_count());
+        assert_eq!(0, oncology.edge_count());
+    }
+
+    #[test]
+    fn test_split_by_groups_provided_schema() {
+        let mut graphrecord = create_graphrecord();
+
+        let mut schema = Schema::infer(&graphrecord);
+        schema.freeze();
+        graphrecord.set_schema(schema).unwrap();
+
+        let records = graphrecord.split_by_groups(&["oncology".into()]).unwrap();
+
+        let schema = records[&"oncology".into()].get_schema();
+
+        assert_eq!(&SchemaType::Provided, schema.schema_type());
+        assert_eq!(1, schema.groups().len());
+        assert_eq!(
+            Some(&DataType::Int),
+            schema
+                .group(&"oncology".into())
+                .unwrap()
+                .nodes()
+                .get(&"age".into())
+                .map(AttributeDataType::data_type)
+        );
+    }
+
+    #[test]
+    fn test_invalid_split_by_groups() {
+        let graphrecord = create_graphrecord();
+
+        assert!(
+            graphrecord
+                .split_by_groups(&["radiology".into()])
+                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+        );
+    }
+}
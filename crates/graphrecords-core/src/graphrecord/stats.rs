@@ -0,0 +1,211 @@
+use super::{GraphRecord, Group, NodeIndex};
+use graphrecords_utils::aliases::{GrHashMap, GrHashSet};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DegreeSummary {
+    pub min: usize,
+    pub mean: f64,
+    pub max: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GroupStats {
+    pub node_count: usize,
+    pub edge_count: usize,
+}
+
+/// Lightweight structural summary of a `GraphRecord`.
+///
+/// Degrees count both incoming and outgoing edges, and connected components
+/// are computed ignoring edge direction. The density is the share of ordered
+/// node pairs connected by an edge, where an undirected edge between two
+/// different nodes connects them in both directions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub node_count: usize,
+    pub edge_count: usize,
+    pub density: f64,
+    pub degree: Option<DegreeSummary>,
+    pub connected_components: usize,
+    pub groups: HashMap<Group, GroupStats>,
+}
+
+struct DisjointSet {
+    parents: Vec<usize>,
+}
+
+impl DisjointSet {
+    fn new(size: usize) -> Self {
+        Self {
+            parents: (0..size).collect(),
+        }
+    }
+
+    fn find(&mut self, mut element: usize) -> usize {
+        while self.parents[element] != element {
+            self.parents[element] = self.parents[self.parents[element]];
+            element = self.parents[element];
+        }
+
+        element
+    }
+
+    fn union(&mut self, first: usize, second: usize) {
+        let first = self.find(first);
+        let second = self.find(second);
+
+        if first != second {
+            self.parents[second] = first;
+        }
+    }
+}
+
+impl Stats {
+    pub(crate) fn new(graphrecord: &GraphRecord) -> Self {
+        let graph = &graphrecord.graph;
+
+        let node_count = graph.node_count();
+        let edge_count = graph.edge_count();
+
+        let reversed_edge_count = if graph.has_undirected_edges() {
+            graph
+                .edges
+                .values()
+                .filter(|edge| edge.undirected && edge.source_node_index != edge.target_node_index)
+                .count()
+        } else {
+            0
+        };
+
+        let density = if node_count > 1 {
+            (edge_count + reversed_edge_count) as f64 / (node_count * (node_count - 1)) as f64
+        } else {
+            0.0
+        };
+
+        let degree = graph
+            .nodes
+            .values()
+            .map(|node| node.outgoing_edge_indices.len() + node.incoming_edge_indices.len())
+            .fold(None, |summary: Option<(usize, usize, usize)>, degree| {
+                Some(summary.map_or((degree, degree, degree), |(min, sum, max)| {
+                    (min.min(degree), sum + degree, max.max(degree))
+                }))
+            })
+            .map(|(min, sum, max)| DegreeSummary {
+                min,
+                mean: sum as f64 / node_count as f64,
+                max,
+            });
+
+        let positions: GrHashMap<_, _> = graph
+            .nodes
+            .keys()
+            .enumerate()
+            .map(|(position, node_index)| (node_index, position))
+            .collect();
+
+        let mut components = DisjointSet::new(node_count);
+
+        for edge in graph.edges.values() {
+            components.union(
+                positions[&edge.source_node_index],
+                positions[&edge.target_node_index],
+            );
+        }
+
+        let connected_components = (0..node_count)
+            .map(|position| components.find(position))
+            .collect::<GrHashSet<_>>()
+            .len();
+
+        let groups = graphrecord
+            .group_mapping
+            .nodes_in_group
+            .iter()
+            .map(|(group, nodes_in_group)| {
+                let edge_count = graphrecord
+                    .group_mapping
+                    .edges_in_group
+                    .get(group)
+                    .map_or(0, GrHashSet::len);
+
+                (
+                    group.clone(),
+                    GroupStats {
+                        node_count: nodes_in_group.len(),
+                        edge_count,
+                    },
+                )
+            })
+            .collect();
+
+        Self {
+            node_count,
+            edge_count,
+            density,
+            degree,
+            connected_components,
+            groups,
+        }
+    }
+}
+
+/// Counts the edges between every ordered pair of source and target groups,
+/// with `None` standing for nodes that are in no group. Every pair is present,
+/// even if no edge connects it. An edge whose endpoints are in several groups
+/// is counted once for every combination of their groups.
+pub(crate) fn group_adjacency_counts(
+    graphrecord: &GraphRecord,
+) -> HashMap<(Option<Group>, Option<Group>), usize> {
+    let groups: Vec<_> = graphrecord
+        .group_mapping
+        .nodes_in_group
+        .keys()
+        .map(Some)
+        .chain(std::iter::once(None))
+        .collect();
+
+    let mut counts: GrHashMap<_, _> = groups
+        .iter()
+        .flat_map(|source_group| {
+            groups
+                .iter()
+                .map(move |target_group| ((*source_group, *target_group), 0))
+        })
+        .collect();
+
+    let groups_of_node = |node_index: &NodeIndex| {
+        let groups: Vec<_> = graphrecord
+            .group_mapping
+            .groups_of_node(node_index)
+            .map(Some)
+            .collect();
+
+        if groups.is_empty() {
+            vec![None]
+        } else {
+            groups
+        }
+    };
+
+    for edge in graphrecord.graph.edges.values() {
+        let target_groups = groups_of_node(&edge.target_node_index);
+
+        for source_group in groups_of_node(&edge.source_node_index) {
+            for target_group in &target_groups {
+                *counts
+                    .get_mut(&(source_group, *target_group))
+                    .expect("Group pair must exist") += 1;
+            }
+        }
+    }
+
+    counts
+        .into_iter()
+        .map(|((source_group, target_group), count)| {
+            ((source_group.cloned(), target_group.cloned()), count)
+        })
+        .collect()
+}
@@ -0,0 +1,237 @@
+use super::{EdgeIndex, Group, NodeIndex};
+use parking_lot::Mutex;
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    mpsc::{self, Receiver, Sender},
+};
+
+static NEXT_CHANGE: AtomicU64 = AtomicU64::new(1);
+
+/// Mutation applied to a `GraphRecord`, as delivered to subscribers.
+///
+/// Compound operations are reported as their individual steps, e.g. adding a
+/// node with a group emits [`ChangeEvent::NodeAdded`] followed by
+/// [`ChangeEvent::NodeAddedToGroup`]. Removing a node emits
+/// [`ChangeEvent::EdgeRemoved`] for each of its edges before
+/// [`ChangeEvent::NodeRemoved`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChangeEvent {
+    SchemaChanged,
+    MetadataChanged,
+    NodeAdded { node_index: NodeIndex },
+    NodeRemoved { node_index: NodeIndex },
+    NodeAttributesChanged { node_index: NodeIndex },
+    EdgeAdded { edge_index: EdgeIndex },
+    EdgeRemoved { edge_index: EdgeIndex },
+    EdgeAttributesChanged { edge_index: EdgeIndex },
+    EdgeDirectionChanged { edge_index: EdgeIndex },
+    GroupAdded { group: Group },
+    GroupRemoved { group: Group },
+    GroupMetadataChanged { group: Group },
+    NodeAddedToGroup { group: Group, node_index: NodeIndex },
+    NodeRemovedFromGroup { group: Group, node_index: NodeIndex },
+    EdgeAddedToGroup { group: Group, edge_index: EdgeIndex },
+    EdgeRemovedFromGroup { group: Group, edge_index: EdgeIndex },
+    EdgesReindexed,
+    Cleared,
+}
+
+/// Snapshot of the modification state of a `GraphRecord`.
+///
+/// Every change draws a new value from a process-wide sequence, so tokens
+/// increase monotonically and a record that was replaced or diverged from a
+/// clone does not report the token of another record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ChangeToken(u64);
+
+impl ChangeToken {
+    #[must_use]
+    pub const fn value(self) -> u64 {
+        self.0
+    }
+}
+
+impl From<u64> for ChangeToken {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+/// Channels of all receivers returned by `GraphRecord::subscribe`, along with
+/// the token of the last change.
+///
+/// Subscriptions belong to one record: clones start without subscribers but
+/// keep the change token. Senders whose receiver was dropped are removed on
+/// the next event.
+#[derive(Debug, Default)]
+pub(crate) struct Subscribers {
+    senders: Mutex<Vec<Sender<ChangeEvent>>>,
+    last_change: AtomicU64,
+}
+
+impl Clone for Subscribers {
+    fn clone(&self) -> Self {
+        Self {
+            senders: Mutex::default(),
+            last_change: AtomicU64::new(self.last_change.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+impl Subscribers {
+    pub(crate) fn change_token(&self) -> ChangeToken {
+        ChangeToken(self.last_change.load(Ordering::Relaxed))
+    }
+
+    /// Advances the change token without sending an event. [`Self::notify`]
+    /// does this implicitly.
+    pub(crate) fn record_change(&self) {
+        self.last_change.store(
+            NEXT_CHANGE.fetch_add(1, Ordering::Relaxed),
+            Ordering::Relaxed,
+        );
+    }
+
+    pub(crate) fn subscribe(&self) -> Receiver<ChangeEvent> {
+        let (sender, receiver) = mpsc::channel();
+
+        self.senders.lock().push(sender);
+
+        receiver
+    }
+
+    pub(crate) fn is_active(&self) -> bool {
+        !self.senders.lock().is_empty()
+    }
+
+    /// Records a change and sends the event built by `event` to all
+    /// subscribers. The event is only built if there is at least one
+    /// subscriber.
+    pub(crate) fn notify(&self, event: impl FnOnce() -> ChangeEvent) {
+        self.record_change();
+
+        let mut senders = self.senders.lock();
+
+        if senders.is_empty() {
+            return;
+        }
+
+        let event = event();
+
+        senders.retain(|sender| sender.send(event.clone()).is_ok());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ChangeEvent;
+    use crate::GraphRecord;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_subscribe() {
+        let mut graphrecord = GraphRecord::new();
+
+        let receiver = graphrecord.subscribe();
+
+        graphrecord.add_node("0".into(), HashMap::new()).unwrap();
+        graphrecord
+            .add_node_with_group("1".into(), HashMap::new(), "group".into())
+            .unwrap();
+        let edge_index = graphrecord
+            .add_edge("0".into(), "1".into(), HashMap::new())
+            .unwrap();
+        graphrecord
+            .node_attributes_mut(&"0".into())
+            .unwrap()
+            .update_attribute(&"lorem".into(), "ipsum".into())
+            .unwrap();
+        graphrecord.remove_node(&"0".into()).unwrap();
+        graphrecord.remove_group(&"group".into()).unwrap();
+
+        // Failed mutations do not emit events
+        assert!(graphrecord.remove_node(&"0".into()).is_err());
+
+        graphrecord.clear().unwrap();
+
+        assert_eq!(
+            vec![
+                ChangeEvent::NodeAdded {
+                    node_index: "0".into()
+                },
+                ChangeEvent::NodeAdded {
+                    node_index: "1".into()
+                },
+                ChangeEvent::GroupAdded {
+                    group: "group".into()
+                },
+                ChangeEvent::NodeAddedToGroup {
+                    group: "group".into(),
+                    node_index: "1".into()
+                },
+                ChangeEvent::EdgeAdded { edge_index },
+                ChangeEvent::NodeAttributesChanged {
+                    node_index: "0".into()
+                },
+                ChangeEvent::EdgeRemoved { edge_index },
+                ChangeEvent::NodeRemoved {
+                    node_index: "0".into()
+                },
+                ChangeEvent::GroupRemoved {
+                    group: "group".into()
+                },
+                ChangeEvent::Cleared,
+            ],
+            receiver.try_iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_dropped_receiver() {
+        let mut graphrecord = GraphRecord::new();
+
+        let receiver = graphrecord.subscribe();
+        drop(graphrecord.subscribe());
+
+        graphrecord.add_node("0".into(), HashMap::new()).unwrap();
+
+        assert_eq!(1, receiver.try_iter().count());
+
+        // Clones do not inherit subscriptions
+        let mut cloned = graphrecord.clone();
+        cloned.add_node("1".into(), HashMap::new()).unwrap();
+
+        assert_eq!(0, receiver.try_iter().count());
+    }
+
+    #[test]
+    fn test_change_token() {
+        let mut graphrecord = GraphRecord::new();
+
+        let token = graphrecord.change_token();
+        assert!(!graphrecord.is_modified_since(token));
+
+        graphrecord.add_node("0".into(), HashMap::new()).unwrap();
+        assert!(graphrecord.is_modified_since(token));
+
+        let token = graphrecord.change_token();
+
+        // Read-only access and failed mutations do not modify the record
+        graphrecord.node_attributes(&"0".into()).unwrap();
+        assert!(graphrecord.add_node("0".into(), HashMap::new()).is_err());
+        assert!(!graphrecord.is_modified_since(token));
+
+        graphrecord.add_group("group".into(), None, None).unwrap();
+        assert!(graphrecord.is_modified_since(token));
+        assert!(graphrecord.change_token() > token);
+
+        // Clones keep the token until one of them changes
+        let token = graphrecord.change_token();
+        let mut cloned = graphrecord.clone();
+        assert!(!cloned.is_modified_since(token));
+
+        cloned.remove_group(&"group".into()).unwrap();
+        assert!(cloned.is_modified_since(token));
+        assert!(!graphrecord.is_modified_since(token));
+    }
+}
@@ -0,0 +1,561 @@
+//! Time-to-event and event window data of a cohort, ready to be fed into
+//! survival analyses such as Kaplan-Meier curves or into incidence and
+//! prevalence estimates.
+
+use super::{EdgeIndex, GraphRecord, GraphRecordAttribute, GraphRecordValue, Group, NodeIndex};
+use crate::errors::{GraphRecordError, GraphRecordResult};
+use chrono::{NaiveDateTime, TimeDelta};
+use std::collections::HashMap;
+
+/// Follow-up of a node from its index date until its first event or until it
+/// is censored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeToEvent {
+    /// Time from the index date to the first event, or to the censor date if
+    /// no event was observed.
+    pub duration: TimeDelta,
+    /// Whether an event was observed. `false` means the node is censored.
+    pub event: bool,
+}
+
+/// Share of the cohort nodes with an event within a window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EventRate {
+    /// Number of nodes with an event within the window.
+    pub count: usize,
+    /// Number of nodes at risk, excluding the ones with an event within the
+    /// washout period.
+    pub population: usize,
+    /// `count` divided by `population`, or `None` if no node is at risk.
+    pub rate: Option<f64>,
+}
+
+fn index_dates<'a>(
+    graphrecord: &'a GraphRecord,
+    cohort_group: &Group,
+    attribute: &GraphRecordAttribute,
+) -> GraphRecordResult<HashMap<&'a NodeIndex, NaiveDateTime>> {
+    graphrecord
+        .nodes_in_group(cohort_group)?
+        .map(
+            |node_index| match graphrecord.node_attributes(node_index)?.get(attribute) {
+                Some(GraphRecordValue::DateTime(value)) => Ok((node_index, *value)),
+                Some(value) => Err(GraphRecordError::ConversionError(format!(
+                    "Index date {attribute} of node {node_index} must be a datetime, got {value}"
+                ))),
+                None => Err(GraphRecordError::KeyError(format!(
+                    "Cannot find index date {attribute} of node {node_index}"
+                ))),
+            },
+        )
+        .collect()
+}
+
+/// Returns the time of an event relative to the index date. Events may carry
+/// either the datetime they happened at or the duration since the index date.
+fn event_time(
+    value: Option<&GraphRecordValue>,
+    edge_index: EdgeIndex,
+    attribute: &GraphRecordAttribute,
+    index_date: NaiveDateTime,
+) -> GraphRecordResult<TimeDelta> {
+    match value {
+        Some(GraphRecordValue::DateTime(value)) => Ok(*value - index_date),
+        Some(GraphRecordValue::Duration(value)) => Ok(*value),
+        Some(value) => Err(GraphRecordError::ConversionError(format!(
+            "Event time {attribute} of edge {edge_index} must be a datetime or a duration, \
+             got {value}"
+        ))),
+        None => Err(GraphRecordError::KeyError(format!(
+            "Cannot find event time {attribute} of edge {edge_index}"
+        ))),
+    }
+}
+
+/// Returns the times of the events of every node relative to its index date.
+/// The events of a node are the edges in `event_edge_group` touching it.
+fn event_times<'a>(
+    graphrecord: &GraphRecord,
+    index_dates: &HashMap<&'a NodeIndex, NaiveDateTime>,
+    attribute: &GraphRecordAttribute,
+    event_edge_group: &Group,
+) -> GraphRecordResult<HashMap<&'a NodeIndex, Vec<TimeDelta>>> {
+    let mut event_times: HashMap<&NodeIndex, Vec<TimeDelta>> = HashMap::new();
+
+    for edge_index in graphrecord.edges_in_group(event_edge_group)? {
+        let endpoints = graphrecord.edge_endpoints(edge_index)?;
+        let value = graphrecord.edge_attributes(edge_index)?.get(attribute);
+
+        for node_index in <[_; 2]>::from(endpoints) {
+            let Some((node_index, index_date)) = index_dates.get_key_value(node_index) else {
+                continue;
+            };
+
+            event_times.entry(*node_index).or_default().push(event_time(
+                value,
+                *edge_index,
+                attribute,
+                *index_date,
+            )?);
+        }
+    }
+
+    Ok(event_times)
+}
+
+pub(crate) fn time_to_event(
+    graphrecord: &GraphRecord,
+    cohort_group: &Group,
+    index_date_attribute: &GraphRecordAttribute,
+    event_edge_group: &Group,
+    censor_date: NaiveDateTime,
+) -> GraphRecordResult<HashMap<NodeIndex, TimeToEvent>> {
+    let index_dates = index_dates(graphrecord, cohort_group, index_date_attribute)?;
+
+    if let Some((node_index, _)) = index_dates
+        .iter()
+        .find(|(_, index_date)| **index_date > censor_date)
+    {
+        return Err(GraphRecordError::AssertionError(format!(
+            "Index date of node {node_index} lies after the censor date {censor_date}"
+        )));
+    }
+
+    let event_times = event_times(
+        graphrecord,
+        &index_dates,
+        index_date_attribute,
+        event_edge_group,
+    )?;
+
+    Ok(index_dates
+        .into_iter()
+        .map(|(node_index, index_date)| {
+            let follow_up = censor_date - index_date;
+
+            // Events before the index date or after the censor date are not
+            // part of the follow-up
+            let first_event = event_times.get(node_index).and_then(|times| {
+                times
+                    .iter()
+                    .filter(|time| **time >= TimeDelta::zero() && **time <= follow_up)
+                    .min()
+            });
+
+            let time_to_event = first_event.map_or(
+                TimeToEvent {
+                    duration: follow_up,
+                    event: false,
+                },
+                |duration| TimeToEvent {
+                    duration: *duration,
+                    event: true,
+                },
+            );
+
+            (node_index.clone(), time_to_event)
+        })
+        .collect())
+}
+
+pub(crate) fn events_in_window(
+    graphrecord: &GraphRecord,
+    cohort_group: &Group,
+    index_date_attribute: &GraphRecordAttribute,
+    event_edge_group: &Group,
+    start: TimeDelta,
+    end: TimeDelta,
+    washout: Option<TimeDelta>,
+) -> GraphRecordResult<HashMap<NodeIndex, bool>> {
+    if start >= end {
+        return Err(GraphRecordError::AssertionError(
+            "Start of the window must lie before its end".to_string(),
+        ));
+    }
+
+    if washout.is_some_and(|washout| washout < TimeDelta::zero()) {
+        return Err(GraphRecordError::AssertionError(
+            "Washout period must not be negative".to_string(),
+        ));
+    }
+
+    let index_dates = index_dates(graphrecord, cohort_group, index_date_attribute)?;
+    let event_times = event_times(
+        graphrecord,
+        &index_dates,
+        index_date_attribute,
+        event_edge_group,
+    )?;
+
+    Ok(index_dates
+        .into_keys()
+        .filter_map(|node_index| {
+            let times = event_times.get(node_index).map_or(&[][..], Vec::as_slice);
+
+            // Nodes with an event within the washout period right before the
+            // window are not at risk
+            let washed_out = washout.is_some_and(|washout| {
+                times
+                    .iter()
+                    .any(|time| *time >= start - washout && *time < start)
+            });
+
+            if washed_out {
+                return None;
+            }
+
+            let event = times.iter().any(|time| *time >= start && *time < end);
+
+            Some((node_index.clone(), event))
+        })
+        .collect())
+}
+
+impl EventRate {
+    pub(crate) fn new(events: &HashMap<NodeIndex, bool>) -> Self {
+        let count = events.values().filter(|event| **event).count();
+        let population = events.len();
+
+        Self {
+            count,
+            population,
+            rate: (population > 0).then(|| count as f64 / population as f64),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{EventRate, TimeToEvent};
+    use crate::{GraphRecord, errors::GraphRecordError};
+    use chrono::{NaiveDate, NaiveDateTime, TimeDelta};
+    use std::collections::HashMap;
+
+    fn date(month: u32, day: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2020, month, day)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+    }
+
+    fn create_graphrecord() -> GraphRecord {
+        let mut graphrecord = GraphRecord::from_tuples(
+            vec![
+                (
+                    "0".into(),
+                    HashMap::from([("time".into(), date(1, 1).into())]),
+                ),
+                (
+                    "1".into(),
+                    HashMap::from([("time".into(), date(1, 1).into())]),
+                ),
+                (
+                    "2".into(),
+                    HashMap::from([("time".into(), date(1, 10).into())]),
+                ),
+                (
+                    "3".into(),
+                    HashMap::from([("time".into(), date(1, 1).into())]),
+                ),
+                ("death".into(), HashMap::new()),
+            ],
+            None,
+            None,
+        )
+        .unwrap();
+
+        graphrecord
+            .add_group(
+                "patients".into(),
+                Some(vec!["0".into(), "1".into(), "2".into(), "3".into()]),
+                None,
+            )
+            .unwrap();
+
+        graphrecord
+            .add_edges_with_group(
+                vec![
+                    (
+                        "0".into(),
+                        "death".into(),
+                        HashMap::from([("time".into(), date(1, 21).into())]),
+                    ),
+                    (
+                        "0".into(),
+                        "death".into(),
+                        HashMap::from([("time".into(), date(1, 11).into())]),
+                    ),
+                    (
+                        "1".into(),
+                        "death".into(),
+                        HashMap::from([("time".into(), TimeDelta::days(5).into())]),
+                    ),
+                    (
+                        "2".into(),
+                        "death".into(),
+                        HashMap::from([("time".into(), date(1, 5).into())]),
+                    ),
+                    (
+                        "3".into(),
+                        "death".into(),
+                        HashMap::from([("time".into(), date(3, 1).into())]),
+                    ),
+                ],
+                &"deaths".into(),
+            )
+            .unwrap();
+
+        graphrecord
+    }
+
+    #[test]
+    fn test_time_to_event() {
+        let graphrecord = create_graphrecord();
+
+        let time_to_event = graphrecord
+            .time_to_event(
+                &"patients".into(),
+                &"time".into(),
+                &"deaths".into(),
+                date(1, 31),
+            )
+            .unwrap();
+
+        assert_eq!(
+            HashMap::from([
+                (
+                    "0".into(),
+                    TimeToEvent {
+                        duration: TimeDelta::days(10),
+                        event: true,
+                    }
+                ),
+                (
+                    "1".into(),
+                    TimeToEvent {
+                        duration: TimeDelta::days(5),
+                        event: true,
+                    }
+                ),
+                (
+                    "2".into(),
+                    TimeToEvent {
+                        duration: TimeDelta::days(21),
+                        event: false,
+                    }
+                ),
+                (
+                    "3".into(),
+                    TimeToEvent {
+                        duration: TimeDelta::days(30),
+                        event: false,
+                    }
+                ),
+            ]),
+            time_to_event
+        );
+    }
+
+    #[test]
+    fn test_invalid_time_to_event() {
+        let mut graphrecord = create_graphrecord();
+
+        assert!(
+            graphrecord
+                .time_to_event(
+                    &"visits".into(),
+                    &"time".into(),
+                    &"deaths".into(),
+                    date(1, 31),
+                )
+                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+        );
+        assert!(
+            graphrecord
+                .time_to_event(
+                    &"patients".into(),
+                    &"time".into(),
+                    &"visits".into(),
+                    date(1, 31),
+                )
+                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+        );
+        assert!(
+            graphrecord
+                .time_to_event(
+                    &"patients".into(),
+                    &"time".into(),
+                    &"deaths".into(),
+                    date(1, 5),
+                )
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
+
+        graphrecord
+            .add_node_to_group("patients".into(), "death".into())
+            .unwrap();
+
+        assert!(
+            graphrecord
+                .time_to_event(
+                    &"patients".into(),
+                    &"time".into(),
+                    &"deaths".into(),
+                    date(1, 31),
+                )
+                .is_err_and(|e| matches!(e, GraphRecordError::KeyError(_)))
+        );
+    }
+
+    #[test]
+    fn test_events_in_window() {
+        let graphrecord = create_graphrecord();
+
+        let events = graphrecord
+            .events_in_window(
+                &"patients".into(),
+                &"time".into(),
+                &"deaths".into(),
+                TimeDelta::zero(),
+                TimeDelta::days(30),
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(
+            HashMap::from([
+                ("0".into(), true),
+                ("1".into(), true),
+                ("2".into(), false),
+                ("3".into(), false),
+            ]),
+            events
+        );
+
+        let events = graphrecord
+            .events_in_window(
+                &"patients".into(),
+                &"time".into(),
+                &"deaths".into(),
+                TimeDelta::zero(),
+                TimeDelta::days(30),
+                Some(TimeDelta::days(7)),
+            )
+            .unwrap();
+
+        assert_eq!(
+            HashMap::from([("0".into(), true), ("1".into(), true), ("3".into(), false)]),
+            events
+        );
+
+        let events = graphrecord
+            .events_in_window(
+                &"patients".into(),
+                &"time".into(),
+                &"deaths".into(),
+                TimeDelta::days(-10),
+                TimeDelta::zero(),
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(
+            HashMap::from([
+                ("0".into(), false),
+                ("1".into(), false),
+                ("2".into(), true),
+                ("3".into(), false),
+            ]),
+            events
+        );
+    }
+
+    #[test]
+    fn test_event_rate_in_window() {
+        let mut graphrecord = create_graphrecord();
+
+        let event_rate = graphrecord
+            .event_rate_in_window(
+                &"patients".into(),
+                &"time".into(),
+                &"deaths".into(),
+                TimeDelta::zero(),
+                TimeDelta::days(30),
+                Some(TimeDelta::days(7)),
+            )
+            .unwrap();
+
+        assert_eq!(
+            EventRate {
+                count: 2,
+                population: 3,
+                rate: Some(2.0 / 3.0),
+            },
+            event_rate
+        );
+
+        graphrecord.add_group("empty".into(), None, None).unwrap();
+
+        let event_rate = graphrecord
+            .event_rate_in_window(
+                &"empty".into(),
+                &"time".into(),
+                &"deaths".into(),
+                TimeDelta::zero(),
+                TimeDelta::days(30),
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(
+            EventRate {
+                count: 0,
+                population: 0,
+                rate: None,
+            },
+            event_rate
+        );
+    }
+
+    #[test]
+    fn test_invalid_events_in_window() {
+        let graphrecord = create_graphrecord();
+
+        assert!(
+            graphrecord
+                .events_in_window(
+                    &"patients".into(),
+                    &"time".into(),
+                    &"deaths".into(),
+                    TimeDelta::days(30),
+                    TimeDelta::zero(),
+                    None,
+                )
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
+        assert!(
+            graphrecord
+                .events_in_window(
+                    &"patients".into(),
+                    &"time".into(),
+                    &"deaths".into(),
+                    TimeDelta::zero(),
+                    TimeDelta::days(30),
+                    Some(TimeDelta::days(-1)),
+                )
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
+        assert!(
+            graphrecord
+                .events_in_window(
+                    &"visits".into(),
+                    &"time".into(),
+                    &"deaths".into(),
+                    TimeDelta::zero(),
+                    TimeDelta::days(30),
+                    None,
+                )
+                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+        );
+    }
+}
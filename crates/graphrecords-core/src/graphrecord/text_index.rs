@@ -0,0 +1,436 @@
+//! Inverted indices over a string attribute of the nodes in a group.
+//!
+//! An index is created with [`GraphRecord::create_text_index`] and used by
+//! the `text_search` operation of value operands. It is rebuilt lazily on the
+//! first search after the record changed, so mutations do not pay for it.
+
+use super::{GraphRecord, GraphRecordAttribute, GraphRecordValue, Group, NodeIndex};
+use crate::{
+    errors::{GraphRecordError, GraphRecordResult},
+    graphrecord::subscription::ChangeToken,
+};
+use graphrecords_utils::aliases::{GrHashMap, GrHashSet};
+use parking_lot::RwLock;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Splits `text` into lowercase words, treating every character that is not
+/// alphanumeric as a separator.
+pub fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|character: char| !character.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(str::to_lowercase)
+}
+
+/// Returns whether `text` contains every token of `tokens` as a word.
+pub fn contains_tokens(text: &str, tokens: &[String]) -> bool {
+    let words: GrHashSet<_> = tokenize(text).collect();
+
+    tokens.iter().all(|token| words.contains(token))
+}
+
+#[derive(Debug, Clone)]
+struct TextIndexState {
+    change_token: ChangeToken,
+    postings: GrHashMap<String, GrHashSet<NodeIndex>>,
+}
+
+impl TextIndexState {
+    fn build(graphrecord: &GraphRecord, group: &Group, attribute: &GraphRecordAttribute) -> Self {
+        let mut postings: GrHashMap<String, GrHashSet<NodeIndex>> = GrHashMap::default();
+
+        // A removed group leaves the index empty until it is added again
+        if let Ok(nodes) = graphrecord.nodes_in_group(group) {
+            for node_index in nodes {
+                let Some(GraphRecordValue::String(text)) = graphrecord
+                    .node_attributes(node_index)
+                    .expect("Node must exist")
+                    .get(attribute)
+                else {
+                    continue;
+                };
+
+                for token in tokenize(text) {
+                    postings
+                        .entry(token)
+                        .or_default()
+                        .insert(node_index.clone());
+                }
+            }
+        }
+
+        Self {
+            change_token: graphrecord.change_token(),
+            postings,
+        }
+    }
+
+    fn matches(&self, node_index: &NodeIndex, tokens: &[String]) -> bool {
+        tokens.iter().all(|token| {
+            self.postings
+                .get(token)
+                .is_some_and(|nodes| nodes.contains(node_index))
+        })
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct TextIndex {
+    group: Group,
+    attribute: GraphRecordAttribute,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    state: RwLock<Option<TextIndexState>>,
+}
+
+impl Clone for TextIndex {
+    fn clone(&self) -> Self {
+        Self {
+            group: self.group.clone(),
+            attribute: self.attribute.clone(),
+            state: RwLock::new(self.state.read().clone()),
+        }
+    }
+}
+
+impl TextIndex {
+    fn is_stale(&self, change_token: ChangeToken) -> bool {
+        self.state
+            .read()
+            .as_ref()
+            .is_none_or(|state| state.change_token != change_token)
+    }
+
+    fn with_state<R>(&self, graphrecord: &GraphRecord, f: impl FnOnce(&TextIndexState) -> R) -> R {
+        let change_token = graphrecord.change_token();
+
+        {
+            let state = self.state.read();
+
+            if let Some(state) = state
+                .as_ref()
+                .filter(|state| state.change_token == change_token)
+            {
+                return f(state);
+            }
+        }
+
+        let mut state = self.state.write();
+
+        if state
+            .as_ref()
+            .is_none_or(|state| state.change_token != change_token)
+        {
+            *state = Some(TextIndexState::build(
+                graphrecord,
+                &self.group,
+                &self.attribute,
+            ));
+        }
+
+        f(state.as_ref().expect("State must be built"))
+    }
+
+    /// Returns `None` if the node is not in the group of the index or `text`
+    /// is not the current value of its attribute, e.g. because the query
+    /// transformed the value.
+    fn matches(
+        &self,
+        graphrecord: &GraphRecord,
+        node_index: &NodeIndex,
+        text: &str,
+        tokens: &[String],
+    ) -> Option<bool> {
+        if !graphrecord
+            .groups_of_node(node_index)
+            .ok()?
+            .any(|group| group == &self.group)
+        {
+            return None;
+        }
+
+        let Some(GraphRecordValue::String(value)) = graphrecord
+            .node_attributes(node_index)
+            .ok()?
+            .get(&self.attribute)
+        else {
+            return None;
+        };
+
+        if value != text {
+            return None;
+        }
+
+        Some(self.with_state(graphrecord, |state| state.matches(node_index, tokens)))
+    }
+}
+
+/// Text indices of a `GraphRecord`. Only the definitions of the indices are
+/// persisted, their contents are built on demand.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TextIndexStore {
+    indices: Vec<TextIndex>,
+}
+
+impl TextIndexStore {
+    #[cfg(feature = "serde")]
+    pub const fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Builds the contents of every index that is outdated, instead of waiting
+    /// for the next query. Returns the number of indices built.
+    pub fn rebuild_stale(&self, graphrecord: &GraphRecord) -> usize {
+        let change_token = graphrecord.change_token();
+        let mut rebuilt = 0;
+
+        for index in &self.indices {
+            if index.is_stale(change_token) {
+                index.with_state(graphrecord, |_| ());
+                rebuilt += 1;
+            }
+        }
+
+        rebuilt
+    }
+}
+
+impl GraphRecord {
+    /// Creates a text index over the string values of `attribute` on the nodes
+    /// in `group`, speeding up `text_search` on the values of that attribute.
+    pub fn create_text_index(
+        &mut self,
+        group: Group,
+        attribute: GraphRecordAttribute,
+    ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
+        if !self.contains_group(&group) {
+            return Err(GraphRecordError::IndexError(format!(
+                "Cannot find group {group}"
+            )));
+        }
+
+        if self
+            .text_indices
+            .indices
+            .iter()
+            .any(|index| index.group == group && index.attribute == attribute)
+        {
+            return Err(GraphRecordError::AssertionError(format!(
+                "Text index on attribute {attribute} of group {group} already exists"
+            )));
+        }
+
+        let state = TextIndexState::build(self, &group, &attribute);
+
+        self.text_indices.indices.push(TextIndex {
+            group,
+            attribute,
+            state: RwLock::new(Some(state)),
+        });
+
+        Ok(())
+    }
+
+    pub fn drop_text_index(
+        &mut self,
+        group: &Group,
+        attribute: &GraphRecordAttribute,
+    ) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
+        let position = self
+            .text_indices
+            .indices
+            .iter()
+            .position(|index| &index.group == group && &index.attribute == attribute)
+            .ok_or_else(|| {
+                GraphRecordError::KeyError(format!(
+                    "Text index on attribute {attribute} of group {group} does not exist"
+                ))
+            })?;
+
+        self.text_indices.indices.remove(position);
+
+        Ok(())
+    }
+
+    /// Returns the group and attribute of every text index in creation order.
+    #[must_use]
+    pub fn text_indices(&self) -> Vec<(&Group, &GraphRecordAttribute)> {
+        self.text_indices
+            .indices
+            .iter()
+            .map(|index| (&index.group, &index.attribute))
+            .collect()
+    }
+
+    /// Looks up whether the `text` of a node's `attribute` contains all
+    /// `tokens`. Returns `None` if no text index holds `text` for the node, in
+    /// which case the caller has to tokenize the text itself.
+    pub(crate) fn text_index_matches(
+        &self,
+        node_index: &NodeIndex,
+        attribute: &GraphRecordAttribute,
+        text: &str,
+        tokens: &[String],
+    ) -> Option<bool> {
+        self.text_indices
+            .indices
+            .iter()
+            .filter(|index| &index.attribute == attribute)
+            .find_map(|index| index.matches(self, node_index, text, tokens))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{contains_tokens, tokenize};
+    use crate::{
+        GraphRecord,
+        errors::GraphRecordError,
+        graphrecord::{GraphRecordAttribute, GraphRecordValue},
+    };
+    use std::collections::HashMap;
+
+    fn create_graphrecord() -> GraphRecord {
+        let mut graphrecord = GraphRecord::from_tuples(
+            vec![
+                (
+                    "0".into(),
+                    HashMap::from([("note".into(), "Acute heart failure.".into())]),
+                ),
+                (
+                    "1".into(),
+                    HashMap::from([("note".into(), "Heart murmur".into())]),
+                ),
+            ],
+            None,
+            None,
+        )
+        .unwrap();
+
+        graphrecord
+            .add_group("notes".into(), Some(vec!["0".into(), "1".into()]), None)
+            .unwrap();
+
+        graphrecord
+    }
+
+    #[test]
+    fn test_tokenize() {
+        assert_eq!(
+            vec!["acute", "heart", "failure", "nyha", "3"],
+            tokenize("Acute heart-failure (NYHA 3).").collect::<Vec<_>>()
+        );
+
+        assert!(contains_tokens(
+            "Acute heart failure",
+            &["failure".to_string(), "heart".to_string()]
+        ));
+        assert!(!contains_tokens(
+            "Acute heart failure",
+            &["renal".to_string()]
+        ));
+    }
+
+    #[test]
+    fn test_text_index() {
+        let mut graphrecord = create_graphrecord();
+        let attribute = GraphRecordAttribute::from("note");
+        let tokens = vec!["heart".to_string(), "failure".to_string()];
+
+        graphrecord
+            .create_text_index("notes".into(), attribute.clone())
+            .unwrap();
+
+        assert_eq!(
+            vec![(&GraphRecordAttribute::from("notes"), &attribute)],
+            graphrecord.text_indices()
+        );
+        assert_eq!(
+            Some(true),
+            graphrecord.text_index_matches(
+                &"0".into(),
+                &attribute,
+                "Acute heart failure.",
+                &tokens
+            )
+        );
+        assert_eq!(
+            Some(false),
+            graphrecord.text_index_matches(&"1".into(), &attribute, "Heart murmur", &tokens)
+        );
+
+        // Nodes outside the group are not covered by the index
+        graphrecord
+            .add_node(
+                "2".into(),
+                HashMap::from([("note".into(), "Heart failure".into())]),
+            )
+            .unwrap();
+
+        assert_eq!(
+            None,
+            graphrecord.text_index_matches(&"2".into(), &attribute, "Heart failure", &tokens)
+        );
+
+        // The index is rebuilt after the record changed
+        graphrecord
+            .node_attributes_mut(&"1".into())
+            .unwrap()
+            .update_attribute(
+                &attribute,
+                GraphRecordValue::String("Chronic heart failure".into()),
+            )
+            .unwrap();
+
+        assert_eq!(
+            Some(true),
+            graphrecord.text_index_matches(
+                &"1".into(),
+                &attribute,
+                "Chronic heart failure",
+                &tokens
+            )
+        );
+        assert_eq!(
+            None,
+            graphrecord.text_index_matches(&"1".into(), &attribute, "Heart murmur", &tokens)
+        );
+
+        graphrecord
+            .drop_text_index(&"notes".into(), &attribute)
+            .unwrap();
+
+        assert!(graphrecord.text_indices().is_empty());
+    }
+
+    #[test]
+    fn test_invalid_text_index() {
+        let mut graphrecord = create_graphrecord();
+
+        assert!(
+            graphrecord
+                .create_text_index("patients".into(), "note".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+        );
+
+        graphrecord
+            .create_text_index("notes".into(), "note".into())
+            .unwrap();
+
+        assert!(
+            graphrecord
+                .create_text_index("notes".into(), "note".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
+        assert!(
+            graphrecord
+                .drop_text_index(&"notes".into(), &"text".into())
+                .is_err_and(|e| matches!(e, GraphRecordError::KeyError(_)))
+        );
+    }
+}
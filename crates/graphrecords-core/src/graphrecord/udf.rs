@@ -0,0 +1,139 @@
+//! User-defined functions (UDFs) that queries apply to values by name.
+//!
+//! A UDF is registered on a `GraphRecord` under a name and called by the
+//! `apply` operation of value operands for each value, so domain-specific
+//! transforms do not need an operation of their own.
+
+use super::{GraphRecord, GraphRecordValue};
+use crate::errors::{GraphRecordError, GraphRecordResult};
+use graphrecords_utils::aliases::GrHashMap;
+use std::{
+    fmt::{Debug, Formatter},
+    sync::Arc,
+};
+
+/// Function transforming a single value, registered with
+/// [`GraphRecord::register_udf`].
+pub type Udf = Arc<dyn Fn(GraphRecordValue) -> GraphRecordResult<GraphRecordValue> + Send + Sync>;
+
+/// UDFs of a `GraphRecord` by name.
+///
+/// Clones share the registered functions, but registering or removing a UDF
+/// only affects the record it is called on.
+#[derive(Clone, Default)]
+pub(crate) struct UdfRegistry {
+    udfs: GrHashMap<String, Udf>,
+}
+
+impl Debug for UdfRegistry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.udfs.keys()).finish()
+    }
+}
+
+impl GraphRecord {
+    /// Registers `udf` under `name`, making it available to the `apply`
+    /// operation of queries on this record.
+    pub fn register_udf<F>(&mut self, name: impl Into<String>, udf: F) -> GraphRecordResult<()>
+    where
+        F: Fn(GraphRecordValue) -> GraphRecordResult<GraphRecordValue> + Send + Sync + 'static,
+    {
+        self.assert_not_frozen()?;
+
+        let name = name.into();
+
+        if self.udfs.udfs.contains_key(&name) {
+            return Err(GraphRecordError::KeyError(format!(
+                "UDF with name '{name}' already exists"
+            )));
+        }
+
+        self.udfs.udfs.insert(name, Arc::new(udf));
+
+        Ok(())
+    }
+
+    pub fn remove_udf(&mut self, name: &str) -> GraphRecordResult<()> {
+        self.assert_not_frozen()?;
+
+        self.udfs
+            .udfs
+            .remove(name)
+            .map(|_| ())
+            .ok_or_else(|| Self::missing_udf_error(name))
+    }
+
+    /// Returns the names of all registered UDFs in arbitrary order.
+    #[must_use]
+    pub fn udf_names(&self) -> Vec<&str> {
+        self.udfs.udfs.keys().map(String::as_str).collect()
+    }
+
+    pub(crate) fn udf(&self, name: &str) -> GraphRecordResult<&Udf> {
+        self.udfs
+            .udfs
+            .get(name)
+            .ok_or_else(|| Self::missing_udf_error(name))
+    }
+
+    fn missing_udf_error(name: &str) -> GraphRecordError {
+        GraphRecordError::KeyError(format!("UDF with name '{name}' does not exist"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        GraphRecord,
+        errors::{GraphRecordError, GraphRecordResult},
+        graphrecord::GraphRecordValue,
+    };
+
+    fn double(value: GraphRecordValue) -> GraphRecordResult<GraphRecordValue> {
+        value * GraphRecordValue::Int(2)
+    }
+
+    #[test]
+    fn test_register_udf() {
+        let mut graphrecord = GraphRecord::new();
+
+        graphrecord.register_udf("double", double).unwrap();
+
+        assert_eq!(vec!["double"], graphrecord.udf_names());
+        assert_eq!(
+            GraphRecordValue::Int(4),
+            graphrecord.udf("double").unwrap()(GraphRecordValue::Int(2)).unwrap()
+        );
+
+        // Clones keep the registered UDFs
+        assert_eq!(vec!["double"], graphrecord.clone().udf_names());
+
+        graphrecord.remove_udf("double").unwrap();
+
+        assert!(graphrecord.udf_names().is_empty());
+    }
+
+    #[test]
+    fn test_invalid_register_udf() {
+        let mut graphrecord = GraphRecord::new();
+
+        graphrecord.register_udf("double", double).unwrap();
+
+        assert!(
+            graphrecord
+                .register_udf("double", double)
+                .is_err_and(|e| matches!(e, GraphRecordError::KeyError(_)))
+        );
+
+        assert!(
+            graphrecord
+                .remove_udf("triple")
+                .is_err_and(|e| matches!(e, GraphRecordError::KeyError(_)))
+        );
+        assert!(
+            graphrecord
+                .udf("triple")
+                .is_err_and(|e| matches!(e, GraphRecordError::KeyError(_)))
+        );
+    }
+}
@@ -0,0 +1,402 @@
+use super::{AttributeMap, EdgeIndex, GraphRecordAttribute, GraphRecordValue, NodeIndex};
+use crate::errors::{GraphRecordError, GraphRecordResult};
+use chrono::NaiveDateTime;
+use graphrecords_utils::aliases::GrHashMap;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, hash::Hash};
+
+/// Value of an attribute during the valid-time interval
+/// `[valid_from, valid_to)`.
+///
+/// A missing bound leaves the interval open towards the past or the future.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AttributeVersion {
+    pub value: GraphRecordValue,
+    pub valid_from: Option<NaiveDateTime>,
+    pub valid_to: Option<NaiveDateTime>,
+}
+
+impl AttributeVersion {
+    #[must_use]
+    pub const fn new(
+        value: GraphRecordValue,
+        valid_from: Option<NaiveDateTime>,
+        valid_to: Option<NaiveDateTime>,
+    ) -> Self {
+        Self {
+            value,
+            valid_from,
+            valid_to,
+        }
+    }
+
+    #[must_use]
+    pub fn is_valid_at(&self, time: NaiveDateTime) -> bool {
+        self.valid_from.is_none_or(|valid_from| valid_from <= time)
+            && self.valid_to.is_none_or(|valid_to| time < valid_to)
+    }
+
+    fn starts_before_end_of(&self, other: &Self) -> bool {
+        self.valid_from
+            .is_none_or(|valid_from| other.valid_to.is_none_or(|valid_to| valid_from < valid_to))
+    }
+
+    fn overlaps(&self, other: &Self) -> bool {
+        self.starts_before_end_of(other) && other.starts_before_end_of(self)
+    }
+}
+
+type AttributeVersions = GrHashMap<GraphRecordAttribute, Vec<AttributeVersion>>;
+
+/// Valid-time versions of the attribute values of a record.
+///
+/// Versions are kept apart from the current attribute values, sorted by the
+/// start of their interval.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub(super) struct ValidTimeStore {
+    nodes: GrHashMap<NodeIndex, AttributeVersions>,
+    edges: GrHashMap<EdgeIndex, AttributeVersions>,
+}
+
+fn add_version<I: Eq + Hash + Clone>(
+    entries: &mut GrHashMap<I, AttributeVersions>,
+    index: &I,
+    attribute: GraphRecordAttribute,
+    version: AttributeVersion,
+) -> GraphRecordResult<()> {
+    if version
+        .valid_from
+        .zip(version.valid_to)
+        .is_some_and(|(valid_from, valid_to)| valid_from >= valid_to)
+    {
+        return Err(GraphRecordError::AssertionError(format!(
+            "Valid time of attribute {attribute} must start before it ends"
+        )));
+    }
+
+    let versions = entries
+        .get(index)
+        .and_then(|versions| versions.get(&attribute))
+        .map_or(&[][..], Vec::as_slice);
+
+    if versions.iter().any(|existing| existing.overlaps(&version)) {
+        return Err(GraphRecordError::AssertionError(format!(
+            "Valid time of attribute {attribute} overlaps an existing version"
+        )));
+    }
+
+    let versions = entries
+        .entry(index.clone())
+        .or_default()
+        .entry(attribute)
+        .or_default();
+
+    let position = versions.partition_point(|existing| existing.valid_from < version.valid_from);
+    versions.insert(position, version);
+
+    Ok(())
+}
+
+fn attributes_as_of(
+    versions: Option<&AttributeVersions>,
+    attributes: &AttributeMap,
+    time: NaiveDateTime,
+) -> AttributeMap {
+    let mut attributes = attributes.clone();
+
+    for (attribute, versions) in versions.into_iter().flatten() {
+        match versions.iter().find(|version| version.is_valid_at(time)) {
+            Some(version) => {
+                attributes.insert(attribute.clone(), version.value.clone());
+            }
+            None => {
+                attributes.remove(attribute);
+            }
+        }
+    }
+
+    attributes
+}
+
+impl ValidTimeStore {
+    pub(super) fn is_empty(&self) -> bool {
+        self.nodes.is_empty() && self.edges.is_empty()
+    }
+
+    pub(super) fn node_indices(&self) -> impl Iterator<Item = &NodeIndex> {
+        self.nodes.keys()
+    }
+
+    pub(super) fn edge_indices(&self) -> impl Iterator<Item = &EdgeIndex> {
+        self.edges.keys()
+    }
+
+    pub(super) fn node_versions(
+        &self,
+        node_index: &NodeIndex,
+        attribute: &GraphRecordAttribute,
+    ) -> &[AttributeVersion] {
+        self.nodes
+            .get(node_index)
+            .and_then(|versions| versions.get(attribute))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub(super) fn edge_versions(
+        &self,
+        edge_index: &EdgeIndex,
+        attribute: &GraphRecordAttribute,
+    ) -> &[AttributeVersion] {
+        self.edges
+            .get(edge_index)
+            .and_then(|versions| versions.get(attribute))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    pub(super) fn add_node_version(
+        &mut self,
+        node_index: &NodeIndex,
+        attribute: GraphRecordAttribute,
+        version: AttributeVersion,
+    ) -> GraphRecordResult<()> {
+        add_version(&mut self.nodes, node_index, attribute, version)
+    }
+
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub(super) fn add_edge_version(
+        &mut self,
+        edge_index: &EdgeIndex,
+        attribute: GraphRecordAttribute,
+        version: AttributeVersion,
+    ) -> GraphRecordResult<()> {
+        add_version(&mut self.edges, edge_index, attribute, version)
+    }
+
+    /// Returns `attributes` with every versioned attribute replaced by the
+    /// version valid at `time`, or removed if no version is.
+    pub(super) fn node_attributes_as_of(
+        &self,
+        node_index: &NodeIndex,
+        attributes: &AttributeMap,
+        time: NaiveDateTime,
+    ) -> AttributeMap {
+        attributes_as_of(self.nodes.get(node_index), attributes, time)
+    }
+
+    /// Like [`ValidTimeStore::node_attributes_as_of`], but for an edge.
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub(super) fn edge_attributes_as_of(
+        &self,
+        edge_index: &EdgeIndex,
+        attributes: &AttributeMap,
+        time: NaiveDateTime,
+    ) -> AttributeMap {
+        attributes_as_of(self.edges.get(edge_index), attributes, time)
+    }
+
+    pub(super) fn remove_node(&mut self, node_index: &NodeIndex) {
+        self.nodes.remove(node_index);
+    }
+
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub(super) fn remove_edge(&mut self, edge_index: &EdgeIndex) {
+        self.edges.remove(edge_index);
+    }
+
+    pub(super) fn reindex_edges(&mut self, remap: &HashMap<EdgeIndex, EdgeIndex>) {
+        self.edges = self
+            .edges
+            .drain()
+            .filter_map(|(edge_index, versions)| {
+                remap
+                    .get(&edge_index)
+                    .map(|edge_index| (*edge_index, versions))
+            })
+            .collect();
+    }
+
+    pub(super) fn clear(&mut self) {
+        self.nodes.clear();
+        self.edges.clear();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::AttributeVersion;
+    use crate::{GraphRecord, errors::GraphRecordError};
+    use chrono::{NaiveDate, NaiveDateTime};
+    use std::collections::HashMap;
+
+    fn date(year: i32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_is_valid_at() {
+        let version = AttributeVersion::new(1.into(), Some(date(2020)), Some(date(2022)));
+
+        assert!(!version.is_valid_at(date(2019)));
+        assert!(version.is_valid_at(date(2020)));
+        assert!(version.is_valid_at(date(2021)));
+        assert!(!version.is_valid_at(date(2022)));
+
+        let version = AttributeVersion::new(1.into(), None, Some(date(2022)));
+
+        assert!(version.is_valid_at(date(1900)));
+        assert!(!version.is_valid_at(date(2022)));
+    }
+
+    #[test]
+    fn test_add_attribute_version() {
+        let mut graphrecord = GraphRecord::new();
+
+        graphrecord.add_node("0".into(), HashMap::new()).unwrap();
+        graphrecord.add_node("1".into(), HashMap::new()).unwrap();
+        graphrecord
+            .add_edge("0".into(), "1".into(), HashMap::new())
+            .unwrap();
+
+        graphrecord
+            .add_node_attribute_version(
+                &"0".into(),
+                "weight".into(),
+                AttributeVersion::new(80.into(), Some(date(2021)), None),
+            )
+            .unwrap();
+        graphrecord
+            .add_node_attribute_version(
+                &"0".into(),
+                "weight".into(),
+                AttributeVersion::new(70.into(), Some(date(2020)), Some(date(2021))),
+            )
+            .unwrap();
+        graphrecord
+            .add_edge_attribute_version(
+                &0,
+                "dose".into(),
+                AttributeVersion::new(5.into(), None, Some(date(2020))),
+            )
+            .unwrap();
+
+        // Versions are sorted by the start of their valid time
+        assert_eq!(
+            vec![
+                AttributeVersion::new(70.into(), Some(date(2020)), Some(date(2021))),
+                AttributeVersion::new(80.into(), Some(date(2021)), None),
+            ],
+            graphrecord
+                .node_attribute_versions(&"0".into(), &"weight".into())
+                .unwrap()
+        );
+        assert_eq!(
+            1,
+            graphrecord
+                .edge_attribute_versions(&0, &"dose".into())
+                .unwrap()
+                .len()
+        );
+
+        graphrecord.remove_edge(&0).unwrap();
+        graphrecord
+            .add_edge("0".into(), "1".into(), HashMap::new())
+            .unwrap();
+        graphrecord.compact().unwrap();
+
+        assert!(
+            graphrecord
+                .edge_attribute_versions(&0, &"dose".into())
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_invalid_add_attribute_version() {
+        let mut graphrecord = GraphRecord::new();
+
+        graphrecord.add_node("0".into(), HashMap::new()).unwrap();
+        graphrecord
+            .add_node_attribute_version(
+                &"0".into(),
+                "weight".into(),
+                AttributeVersion::new(70.into(), Some(date(2020)), Some(date(2022))),
+            )
+            .unwrap();
+
+        // Node does not exist
+        assert!(
+            graphrecord
+                .add_node_attribute_version(
+                    &"50".into(),
+                    "weight".into(),
+                    AttributeVersion::new(70.into(), None, None),
+                )
+                .is_err_and(|e| matches!(e, GraphRecordError::MissingNode { .. }))
+        );
+
+        // Valid time ends before it starts
+        assert!(
+            graphrecord
+                .add_node_attribute_version(
+                    &"0".into(),
+                    "weight".into(),
+                    AttributeVersion::new(70.into(), Some(date(2024)), Some(date(2023))),
+                )
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
+
+        // Valid time overlaps an existing version
+        assert!(
+            graphrecord
+                .add_node_attribute_version(
+                    &"0".into(),
+                    "weight".into(),
+                    AttributeVersion::new(75.into(), Some(date(2021)), None),
+                )
+                .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
+
+        // Adjacent intervals do not overlap
+        graphrecord
+            .add_node_attribute_version(
+                &"0".into(),
+                "weight".into(),
+                AttributeVersion::new(75.into(), Some(date(2022)), None),
+            )
+            .unwrap();
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_attribute_version_serde() {
+        let mut graphrecord = GraphRecord::new();
+
+        graphrecord.add_node("0".into(), HashMap::new()).unwrap();
+        graphrecord
+            .add_node_attribute_version(
+                &"0".into(),
+                "weight".into(),
+                AttributeVersion::new(70.into(), Some(date(2020)), None),
+            )
+            .unwrap();
+
+        let restored = GraphRecord::from_bytes(&graphrecord.to_bytes().unwrap()).unwrap();
+
+        assert_eq!(
+            graphrecord
+                .node_attribute_versions(&"0".into(), &"weight".into())
+                .unwrap(),
+            restored
+                .node_attribute_versions(&"0".into(), &"weight".into())
+                .unwrap()
+        );
+    }
+}
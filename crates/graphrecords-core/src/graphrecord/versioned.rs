@@ -0,0 +1,509 @@
+use super::{
+    Attributes, EdgeIndex, GraphRecord, GraphRecordAttribute, Group, NodeIndex,
+    metadata::RecordMetadata, schema::Schema, valid_time::ValidTimeStore,
+};
+use crate::errors::{GraphRecordError, GraphRecordResult};
+use graphrecords_utils::aliases::{GrHashMap, GrHashSet};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+pub type Version = usize;
+
+pub const DEFAULT_SNAPSHOT_INTERVAL: usize = 10;
+
+/// Members of a group, sorted by index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GroupMembers {
+    pub nodes: Vec<NodeIndex>,
+    pub edges: Vec<EdgeIndex>,
+}
+
+impl GroupMembers {
+    pub(super) fn new(graphrecord: &GraphRecord, group: &Group) -> Option<Self> {
+        let mut nodes: Vec<_> = graphrecord.nodes_in_group(group).ok()?.cloned().collect();
+        nodes.sort_unstable_by(GraphRecordAttribute::total_cmp);
+
+        let mut edges: Vec<_> = graphrecord.edges_in_group(group).ok()?.copied().collect();
+        edges.sort_unstable();
+
+        Some(Self { nodes, edges })
+    }
+}
+
+/// Changes turning one record into another.
+///
+/// Nodes and edges are matched by index, so an edge whose endpoints changed
+/// counts as removed and added. Changed nodes and edges hold their new
+/// attributes, changed groups their new members or `None` if the group was
+/// removed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GraphRecordDiff {
+    pub added_nodes: Vec<(NodeIndex, Attributes)>,
+    pub removed_nodes: Vec<NodeIndex>,
+    pub changed_nodes: Vec<(NodeIndex, Attributes)>,
+    pub added_edges: Vec<(EdgeIndex, NodeIndex, NodeIndex, Attributes)>,
+    pub removed_edges: Vec<EdgeIndex>,
+    pub changed_edges: Vec<(EdgeIndex, Attributes)>,
+    pub changed_groups: Vec<(Group, Option<GroupMembers>)>,
+}
+
+impl GraphRecordDiff {
+    #[must_use]
+    #[expect(clippy::missing_panics_doc, reason = "infallible")]
+    pub fn new(from: &GraphRecord, to: &GraphRecord) -> Self {
+        let mut diff = Self::default();
+
+        for node_index in to.node_indices_sorted() {
+            let attributes = to.node_attributes(node_index).expect("Node must exist");
+
+            match from.node_attributes(node_index) {
+                Ok(from_attributes) if from_attributes == attributes => {}
+                Ok(_) => diff
+                    .changed_nodes
+                    .push((node_index.clone(), attributes.to_attributes())),
+                Err(_) => diff
+                    .added_nodes
+                    .push((node_index.clone(), attributes.to_attributes())),
+            }
+        }
+
+        diff.removed_nodes = from
+            .node_indices_sorted()
+            .filter(|node_index| !to.contains_node(node_index))
+            .cloned()
+            .collect();
+
+        for edge_index in to.edge_indices_sorted() {
+            let endpoints = to.edge_endpoints(edge_index).expect("Edge must exist");
+            let attributes = to.edge_attributes(edge_index).expect("Edge must exist");
+
+            if from.edge_endpoints(edge_index).ok() == Some(endpoints) {
+                if from.edge_attributes(edge_index).ok() != Some(attributes) {
+                    diff.changed_edges
+                        .push((*edge_index, attributes.to_attributes()));
+                }
+            } else {
+                diff.added_edges.push((
+                    *edge_index,
+                    endpoints.0.clone(),
+                    endpoints.1.clone(),
+                    attributes.to_attributes(),
+                ));
+            }
+        }
+
+        diff.removed_edges = from
+            .edge_indices_sorted()
+            .filter(|edge_index| {
+                to.edge_endpoints(edge_index).ok()
+                    != Some(from.edge_endpoints(edge_index).expect("Edge must exist"))
+            })
+            .copied()
+            .collect();
+
+        let mut groups: Vec<_> = from
+            .groups()
+            .chain(to.groups())
+            .collect::<GrHashSet<_>>()
+            .into_iter()
+            .collect();
+        groups.sort_unstable_by(|a, b| a.total_cmp(b));
+
+        for group in groups {
+            let members = GroupMembers::new(to, group);
+
+            if GroupMembers::new(from, group) != members {
+                diff.changed_groups.push((group.clone(), members));
+            }
+        }
+
+        diff
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.added_nodes.is_empty()
+            && self.removed_nodes.is_empty()
+            && self.changed_nodes.is_empty()
+            && self.added_edges.is_empty()
+            && self.removed_edges.is_empty()
+            && self.changed_edges.is_empty()
+            && self.changed_groups.is_empty()
+    }
+
+    pub(super) fn apply(&self, graphrecord: &mut GraphRecord) -> GraphRecordResult<()> {
+        for edge_index in &self.removed_edges {
+            graphrecord.group_mapping.remove_edge(edge_index);
+            graphrecord.graph.remove_edge(edge_index)?;
+        }
+
+        for node_index in &self.removed_nodes {
+            graphrecord.group_mapping.remove_node(node_index);
+            graphrecord
+                .graph
+                .remove_node(node_index, &mut graphrecord.group_mapping)?;
+        }
+
+        for (node_index, attributes) in &self.added_nodes {
+            graphrecord
+                .graph
+                .add_node(node_index.clone(), attributes.clone().into())?;
+        }
+
+        for (node_index, attributes) in &self.changed_nodes {
+            *graphrecord.graph.node_attributes_mut(node_index)? = attributes.clone().into();
+        }
+
+        for (edge_index, source_node_index, target_node_index, attributes) in &self.added_edges {
+            graphrecord.graph.restore_edge(
+                *edge_index,
+                source_node_index.clone(),
+                target_node_index.clone(),
+                attributes.clone().into(),
+            )?;
+        }
+
+        for (edge_index, attributes) in &self.changed_edges {
+            *graphrecord.graph.edge_attributes_mut(edge_index)? = attributes.clone().into();
+        }
+
+        for (group, members) in &self.changed_groups {
+            if graphrecord.group_mapping.contains_group(group) {
+                graphrecord.group_mapping.remove_group(group)?;
+            }
+
+            if let Some(members) = members {
+                graphrecord.group_mapping.add_group(
+                    group.clone(),
+                    Some(members.nodes.clone()),
+                    Some(members.edges.clone()),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Delta {
+    diff: GraphRecordDiff,
+    schema: Schema,
+    group_metadata: GrHashMap<Group, Attributes>,
+    metadata: RecordMetadata,
+    edge_index_state: (EdgeIndex, Vec<EdgeIndex>),
+    undirected_edges: Vec<EdgeIndex>,
+}
+
+#[derive(Debug, Clone)]
+enum Entry {
+    Snapshot(Box<GraphRecord>),
+    Delta(Box<Delta>),
+}
+
+/// Series of versions of a record, e.g. one per data refresh.
+///
+/// Every `snapshot_interval`-th version is stored as a full copy and all
+/// other versions as the changes to their predecessor. Versions capture the
+/// nodes, edges, edge directions, groups, schema and metadata of a record. Provenance tags
+/// and attribute versions are not kept.
+#[derive(Debug, Clone)]
+pub struct VersionedGraphRecord {
+    entries: Vec<Entry>,
+    head: Option<GraphRecord>,
+    snapshot_interval: usize,
+}
+
+impl Default for VersionedGraphRecord {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VersionedGraphRecord {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::with_snapshot_interval(DEFAULT_SNAPSHOT_INTERVAL)
+    }
+
+    /// An interval of 1 stores every version as a full copy. An interval of 0
+    /// is treated as 1.
+    #[must_use]
+    pub const fn with_snapshot_interval(snapshot_interval: usize) -> Self {
+        Self {
+            entries: Vec::new(),
+            head: None,
+            snapshot_interval: if snapshot_interval == 0 {
+                1
+            } else {
+                snapshot_interval
+            },
+        }
+    }
+
+    #[must_use]
+    pub const fn snapshot_interval(&self) -> usize {
+        self.snapshot_interval
+    }
+
+    #[must_use]
+    pub const fn version_count(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[must_use]
+    pub const fn latest_version(&self) -> Option<Version> {
+        self.entries.len().checked_sub(1)
+    }
+
+    /// Stores `graphrecord` as a new version and returns it.
+    pub fn commit(&mut self, graphrecord: &GraphRecord) -> Version {
+        let mut graphrecord = graphrecord.clone();
+        graphrecord.provenance = None;
+        graphrecord.valid_time = ValidTimeStore::default();
+
+        let version = self.entries.len();
+
+        let entry = match &self.head {
+            Some(head) if !version.is_multiple_of(self.snapshot_interval) => {
+                let (edge_index_counter, free_edge_indices) = graphrecord.graph.edge_index_state();
+
+                Entry::Delta(Box::new(Delta {
+                    diff: GraphRecordDiff::new(head, &graphrecord),
+                    schema: graphrecord.schema.clone(),
+                    group_metadata: graphrecord.group_mapping.group_metadata.clone(),
+                    metadata: graphrecord.metadata.clone(),
+                    edge_index_state: (edge_index_counter, free_edge_indices.clone()),
+                    undirected_edges: graphrecord
+                        .graph
+                        .undirected_edge_indices()
+                        .copied()
+                        .collect(),
+                }))
+            }
+            _ => Entry::Snapshot(Box::new(graphrecord.clone())),
+        };
+
+        self.entries.push(entry);
+        self.head = Some(graphrecord);
+
+        version
+    }
+
+    /// Rebuilds the record as it was at `version`.
+    #[expect(clippy::missing_panics_doc, reason = "infallible")]
+    pub fn checkout(&self, version: Version) -> GraphRecordResult<GraphRecord> {
+        if version >= self.entries.len() {
+            return Err(GraphRecordError::IndexError(format!(
+                "Cannot find version {version}"
+            )));
+        }
+
+        if Some(version) == self.latest_version() {
+            return Ok(self.head.clone().expect("Head must exist"));
+        }
+
+        let snapshot_version = version - version % self.snapshot_interval;
+
+        let Entry::Snapshot(snapshot) = &self.entries[snapshot_version] else {
+            unreachable!("Every snapshot interval starts with a snapshot");
+        };
+
+        let mut graphrecord = (**snapshot).clone();
+
+        for entry in &self.entries[snapshot_version + 1..=version] {
+            let Entry::Delta(delta) = entry else {
+                unreachable!("Only the start of a snapshot interval is a snapshot");
+            };
+
+            delta.diff.apply(&mut graphrecord)?;
+
+            graphrecord.schema.clone_from(&delta.schema);
+            graphrecord
+                .group_mapping
+                .group_metadata
+                .clone_from(&delta.group_metadata);
+            graphrecord.metadata.clone_from(&delta.metadata);
+
+            let (edge_index_counter, free_edge_indices) = delta.edge_index_state.clone();
+            graphrecord
+                .graph
+                .restore_edge_index_state(edge_index_counter, free_edge_indices);
+
+            let undirected_edges: Vec<_> = graphrecord
+                .graph
+                .undirected_edge_indices()
+                .copied()
+                .collect();
+
+            for edge_index in &undirected_edges {
+                graphrecord.graph.set_edge_undirected(edge_index, false)?;
+            }
+            for edge_index in &delta.undirected_edges {
+                graphrecord.graph.set_edge_undirected(edge_index, true)?;
+            }
+        }
+
+        Ok(graphrecord)
+    }
+
+    /// Returns the changes turning version `from` into version `to`.
+    pub fn diff(&self, from: Version, to: Version) -> GraphRecordResult<GraphRecordDiff> {
+        Ok(GraphRecordDiff::new(
+            &self.checkout(from)?,
+            &self.checkout(to)?,
+        ))
+    }
+
+    /// Drops all versions after `version`, making it the latest version.
+    pub fn rollback(&mut self, version: Version) -> GraphRecordResult<()> {
+        let graphrecord = self.checkout(version)?;
+
+        self.entries.truncate(version + 1);
+        self.head = Some(graphrecord);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{GraphRecordDiff, GroupMembers, VersionedGraphRecord};
+    use crate::{GraphRecord, errors::GraphRecordError, graphrecord::NodeIndex};
+    use std::collections::HashMap;
+
+    fn create_versions() -> (VersionedGraphRecord, Vec<GraphRecord>) {
+        let mut versioned = VersionedGraphRecord::with_snapshot_interval(2);
+        let mut graphrecord = GraphRecord::new();
+        let mut versions = Vec::new();
+
+        graphrecord
+            .add_node(
+                "0".into(),
+                HashMap::from([("lorem".into(), "ipsum".into())]),
+            )
+            .unwrap();
+        graphrecord.add_node("1".into(), HashMap::new()).unwrap();
+        graphrecord
+            .add_edge("0".into(), "1".into(), HashMap::new())
+            .unwrap();
+        versioned.commit(&graphrecord);
+        versions.push(graphrecord.clone());
+
+        graphrecord.add_node("2".into(), HashMap::new()).unwrap();
+        graphrecord
+            .add_edge(
+                "1".into(),
+                "2".into(),
+                HashMap::from([("dolor".into(), "sit".into())]),
+            )
+            .unwrap();
+        graphrecord
+            .add_group("group".into(), Some(vec!["0".into()]), None)
+            .unwrap();
+        versioned.commit(&graphrecord);
+        versions.push(graphrecord.clone());
+
+        graphrecord
+            .node_attributes_mut(&"0".into())
+            .unwrap()
+            .update_attribute(&"lorem".into(), "amet".into())
+            .unwrap();
+        graphrecord.remove_node(&"1".into()).unwrap();
+        versioned.commit(&graphrecord);
+        versions.push(graphrecord.clone());
+
+        graphrecord
+            .add_edge("0".into(), "2".into(), HashMap::new())
+            .unwrap();
+        graphrecord.remove_group(&"group".into()).unwrap();
+        versioned.commit(&graphrecord);
+        versions.push(graphrecord.clone());
+
+        (versioned, versions)
+    }
+
+    #[test]
+    fn test_checkout() {
+        let (versioned, versions) = create_versions();
+
+        assert_eq!(4, versioned.version_count());
+        assert_eq!(Some(3), versioned.latest_version());
+
+        for (version, expected) in versions.iter().enumerate() {
+            let graphrecord = versioned.checkout(version).unwrap();
+
+            assert!(graphrecord.equals(expected));
+            assert!(GraphRecordDiff::new(expected, &graphrecord).is_empty());
+        }
+
+        assert!(
+            versioned
+                .checkout(4)
+                .is_err_and(|e| matches!(e, GraphRecordError::IndexError(_)))
+        );
+    }
+
+    #[test]
+    fn test_diff() {
+        let (versioned, _) = create_versions();
+
+        let diff = versioned.diff(1, 2).unwrap();
+
+        assert!(diff.added_nodes.is_empty());
+        assert_eq!(vec![NodeIndex::from("1")], diff.removed_nodes);
+        assert_eq!(
+            vec![(
+                NodeIndex::from("0"),
+                HashMap::from([("lorem".into(), "amet".into())])
+            )],
+            diff.changed_nodes
+        );
+        assert_eq!(vec![0, 1], diff.removed_edges);
+        assert!(diff.changed_groups.is_empty());
+
+        let diff = versioned.diff(0, 3).unwrap();
+
+        assert_eq!(
+            vec![NodeIndex::from("2")],
+            diff.added_nodes
+                .into_iter()
+                .map(|(node_index, _)| node_index)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(1, diff.added_edges.len());
+        assert_eq!(vec![0], diff.removed_edges);
+        assert!(diff.changed_groups.is_empty());
+
+        let diff = versioned.diff(3, 2).unwrap();
+
+        assert_eq!(
+            vec![(
+                "group".into(),
+                Some(GroupMembers {
+                    nodes: vec!["0".into()],
+                    edges: Vec::new(),
+                })
+            )],
+            diff.changed_groups
+        );
+        assert!(versioned.diff(3, 3).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_rollback() {
+        let (mut versioned, versions) = create_versions();
+
+        versioned.rollback(1).unwrap();
+
+        assert_eq!(Some(1), versioned.latest_version());
+        assert!(versioned.checkout(1).unwrap().equals(&versions[1]));
+
+        let version = versioned.commit(&versions[3]);
+
+        assert_eq!(2, version);
+        assert!(versioned.checkout(2).unwrap().equals(&versions[3]));
+    }
+}
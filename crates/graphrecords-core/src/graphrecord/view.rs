@@ -0,0 +1,608 @@
+use super::{
+    AttributeMap, EdgeIndex, GraphRecord, GraphRecordAttribute, GraphRecordValue, Group, NodeIndex,
+    querying::{
+        ReturnOperand, Selection, edges::EdgeOperand, nodes::NodeOperand, wrapper::Wrapper,
+    },
+};
+use crate::errors::{GraphRecordError, GraphRecordResult};
+use chrono::NaiveDateTime;
+
+/// Read-only subset of a `GraphRecord`.
+///
+/// Views restrict iteration and queries to the nodes and edges they contain
+/// without copying any data. Queries run through [`GraphRecordView::query_nodes`]
+/// and [`GraphRecordView::query_edges`] start from the restricted subset, so
+/// any query of the underlying record can be scoped to a view.
+pub trait GraphRecordView<'a> {
+    fn graphrecord(&self) -> &'a GraphRecord;
+
+    fn contains_node(&self, node_index: &NodeIndex) -> bool;
+
+    fn contains_edge(&self, edge_index: &EdgeIndex) -> bool;
+
+    /// Restricts `nodes` to the nodes contained in the view.
+    fn restrict_nodes(&self, nodes: &Wrapper<NodeOperand>);
+
+    /// Restricts `edges` to the edges contained in the view.
+    fn restrict_edges(&self, edges: &Wrapper<EdgeOperand>);
+
+    fn node_indices(&self) -> impl Iterator<Item = &'a NodeIndex> {
+        self.graphrecord()
+            .node_indices()
+            .filter(|node_index| self.contains_node(node_index))
+    }
+
+    fn edge_indices(&self) -> impl Iterator<Item = &'a EdgeIndex> {
+        self.graphrecord()
+            .edge_indices()
+            .filter(|edge_index| self.contains_edge(edge_index))
+    }
+
+    fn node_count(&self) -> usize {
+        self.node_indices().count()
+    }
+
+    fn edge_count(&self) -> usize {
+        self.edge_indices().count()
+    }
+
+    fn query_nodes<Q, R>(&self, query: Q) -> Selection<'a, R>
+    where
+        Q: FnOnce(&Wrapper<NodeOperand>) -> R,
+        R: ReturnOperand<'a>,
+    {
+        self.graphrecord().query_nodes(|nodes| {
+            self.restrict_nodes(nodes);
+
+            query(nodes)
+        })
+    }
+
+    fn query_edges<Q, R>(&self, query: Q) -> Selection<'a, R>
+    where
+        Q: FnOnce(&Wrapper<EdgeOperand>) -> R,
+        R: ReturnOperand<'a>,
+    {
+        self.graphrecord().query_edges(|edges| {
+            self.restrict_edges(edges);
+
+            query(edges)
+        })
+    }
+}
+
+/// View of the nodes of two groups and the edges running between them, in
+/// either direction.
+///
+/// Edges between nodes of the same group are not part of the view, unless
+/// their endpoints also belong to the other group.
+#[derive(Debug, Clone)]
+pub struct GroupPairView<'a> {
+    graphrecord: &'a GraphRecord,
+    first: Group,
+    second: Group,
+}
+
+impl<'a> GroupPairView<'a> {
+    pub fn new(
+        graphrecord: &'a GraphRecord,
+        first: Group,
+        second: Group,
+    ) -> GraphRecordResult<Self> {
+        for group in [&first, &second] {
+            if !graphrecord.contains_group(group) {
+                return Err(GraphRecordError::IndexError(format!(
+                    "Cannot find group {group}"
+                )));
+            }
+        }
+
+        Ok(Self {
+            graphrecord,
+            first,
+            second,
+        })
+    }
+
+    #[must_use]
+    pub const fn first(&self) -> &Group {
+        &self.first
+    }
+
+    #[must_use]
+    pub const fn second(&self) -> &Group {
+        &self.second
+    }
+
+    fn in_group(&self, node_index: &NodeIndex, group: &Group) -> bool {
+        self.graphrecord
+            .groups_of_node(node_index)
+            .is_ok_and(|mut groups| groups.any(|node_group| node_group == group))
+    }
+
+    fn nodes_in_group(&self, group: &Group) -> impl Iterator<Item = &'a NodeIndex> + use<'a> {
+        self.graphrecord
+            .nodes_in_group(group)
+            .expect("Group must exist")
+    }
+}
+
+impl<'a> GraphRecordView<'a> for GroupPairView<'a> {
+    fn graphrecord(&self) -> &'a GraphRecord {
+        self.graphrecord
+    }
+
+    fn contains_node(&self, node_index: &NodeIndex) -> bool {
+        self.in_group(node_index, &self.first) || self.in_group(node_index, &self.second)
+    }
+
+    fn contains_edge(&self, edge_index: &EdgeIndex) -> bool {
+        let Ok((source_node_index, target_node_index)) =
+            self.graphrecord.edge_endpoints(edge_index)
+        else {
+            return false;
+        };
+
+        (self.in_group(source_node_index, &self.first)
+            && self.in_group(target_node_index, &self.second))
+            || (self.in_group(source_node_index, &self.second)
+                && self.in_group(target_node_index, &self.first))
+    }
+
+    fn restrict_nodes(&self, nodes: &Wrapper<NodeOperand>) {
+        let first = self.first.clone();
+        let second = self.second.clone();
+
+        nodes.either_or(
+            |nodes| {
+                nodes.in_group(first);
+            },
+            |nodes| {
+                nodes.in_group(second);
+            },
+        );
+    }
+
+    fn restrict_edges(&self, edges: &Wrapper<EdgeOperand>) {
+        let (first_source, first_target) = (self.first.clone(), self.second.clone());
+        let (second_source, second_target) = (self.second.clone(), self.first.clone());
+
+        edges.either_or(
+            |edges| {
+                edges.source_node().in_group(first_source);
+                edges.target_node().in_group(first_target);
+            },
+            |edges| {
+                edges.source_node().in_group(second_source);
+                edges.target_node().in_group(second_target);
+            },
+        );
+    }
+
+    fn node_indices(&self) -> impl Iterator<Item = &'a NodeIndex> {
+        self.nodes_in_group(&self.first).chain(
+            self.nodes_in_group(&self.second)
+                .filter(|node_index| !self.in_group(node_index, &self.first)),
+        )
+    }
+
+    fn edge_indices(&self) -> impl Iterator<Item = &'a EdgeIndex> {
+        // Edges from the first to the second group, followed by edges from the
+        // second to the first group that were not already part of the former
+        let outgoing = self
+            .nodes_in_group(&self.first)
+            .flat_map(move |node_index| {
+                self.graphrecord
+                    .outgoing_edges(node_index)
+                    .expect("Node must exist")
+                    .filter(move |edge_index| {
+                        let (_, target_node_index) = self
+                            .graphrecord
+                            .edge_endpoints(edge_index)
+                            .expect("Edge must exist");
+
+                        self.in_group(target_node_index, &self.second)
+                    })
+            });
+
+        let incoming = self
+            .nodes_in_group(&self.first)
+            .flat_map(move |node_index| {
+                self.graphrecord
+                    .incoming_edges(node_index)
+                    .expect("Node must exist")
+                    .filter(move |edge_index| {
+                        let (source_node_index, _) = self
+                            .graphrecord
+                            .edge_endpoints(edge_index)
+                            .expect("Edge must exist");
+
+                        self.in_group(source_node_index, &self.second)
+                            && !(self.in_group(source_node_index, &self.first)
+                                && self.in_group(node_index, &self.second))
+                    })
+            });
+
+        outgoing.chain(incoming)
+    }
+}
+
+/// View of the nodes and edges whose time attribute lies within
+/// `[start, end)`.
+///
+/// Nodes and edges without the time attribute are not restricted, so static
+/// entities stay part of the view. Edges are only part of the view if both of
+/// their endpoints are.
+#[derive(Debug, Clone)]
+pub struct TimeWindowView<'a> {
+    graphrecord: &'a GraphRecord,
+    attribute: GraphRecordAttribute,
+    start: GraphRecordValue,
+    end: GraphRecordValue,
+}
+
+impl<'a> TimeWindowView<'a> {
+    #[must_use]
+    pub const fn new(
+        graphrecord: &'a GraphRecord,
+        attribute: GraphRecordAttribute,
+        start: GraphRecordValue,
+        end: GraphRecordValue,
+    ) -> Self {
+        Self {
+            graphrecord,
+            attribute,
+            start,
+            end,
+        }
+    }
+
+    #[must_use]
+    pub const fn attribute(&self) -> &GraphRecordAttribute {
+        &self.attribute
+    }
+
+    #[must_use]
+    pub const fn start(&self) -> &GraphRecordValue {
+        &self.start
+    }
+
+    #[must_use]
+    pub const fn end(&self) -> &GraphRecordValue {
+        &self.end
+    }
+
+    fn is_outside(&self, value: &GraphRecordValue) -> bool {
+        value < &self.start || value >= &self.end
+    }
+
+    fn in_window(&self, attributes: &AttributeMap) -> bool {
+        attributes
+            .get(&self.attribute)
+            .is_none_or(|value| !self.is_outside(value))
+    }
+}
+
+impl<'a> GraphRecordView<'a> for TimeWindowView<'a> {
+    fn graphrecord(&self) -> &'a GraphRecord {
+        self.graphrecord
+    }
+
+    fn contains_node(&self, node_index: &NodeIndex) -> bool {
+        self.graphrecord
+            .node_attributes(node_index)
+            .is_ok_and(|attributes| self.in_window(attributes))
+    }
+
+    fn contains_edge(&self, edge_index: &EdgeIndex) -> bool {
+        let Ok(attributes) = self.graphrecord.edge_attributes(edge_index) else {
+            return false;
+        };
+        let (source_node_index, target_node_index) = self
+            .graphrecord
+            .edge_endpoints(edge_index)
+            .expect("Edge must exist");
+
+        self.in_window(attributes)
+            && self.contains_node(source_node_index)
+            && self.contains_node(target_node_index)
+    }
+
+    fn restrict_nodes(&self, nodes: &Wrapper<NodeOperand>) {
+        let attribute = self.attribute.clone();
+        let start = self.start.clone();
+        let end = self.end.clone();
+
+        nodes.exclude(|nodes| {
+            nodes.attribute(attribute).either_or(
+                |value| {
+                    value.less_than(start);
+                },
+                |value| {
+                    value.greater_than_or_equal_to(end);
+                },
+            );
+        });
+    }
+
+    fn restrict_edges(&self, edges: &Wrapper<EdgeOperand>) {
+        let attribute = self.attribute.clone();
+        let start = self.start.clone();
+        let end = self.end.clone();
+
+        edges.exclude(|edges| {
+            edges.attribute(attribute).either_or(
+                |value| {
+                    value.less_than(start);
+                },
+                |value| {
+                    value.greater_than_or_equal_to(end);
+                },
+            );
+        });
+
+        self.restrict_nodes(&edges.source_node());
+        self.restrict_nodes(&edges.target_node());
+    }
+}
+
+/// View of the attribute values valid at a point in time.
+///
+/// Attributes with versions expose the version valid at `time` and are absent
+/// if no version is. Attributes without versions expose their current value.
+#[derive(Debug, Clone)]
+pub struct AsOfView<'a> {
+    graphrecord: &'a GraphRecord,
+    time: NaiveDateTime,
+}
+
+impl<'a> AsOfView<'a> {
+    #[must_use]
+    pub const fn new(graphrecord: &'a GraphRecord, time: NaiveDateTime) -> Self {
+        Self { graphrecord, time }
+    }
+
+    #[must_use]
+    pub const fn graphrecord(&self) -> &'a GraphRecord {
+        self.graphrecord
+    }
+
+    #[must_use]
+    pub const fn time(&self) -> NaiveDateTime {
+        self.time
+    }
+
+    pub fn node_attributes(&self, node_index: &NodeIndex) -> GraphRecordResult<AttributeMap> {
+        let attributes = self.graphrecord.node_attributes(node_index)?;
+
+        Ok(self
+            .graphrecord
+            .valid_time
+            .node_attributes_as_of(node_index, attributes, self.time))
+    }
+
+    pub fn edge_attributes(&self, edge_index: &EdgeIndex) -> GraphRecordResult<AttributeMap> {
+        let attributes = self.graphrecord.edge_attributes(edge_index)?;
+
+        Ok(self
+            .graphrecord
+            .valid_time
+            .edge_attributes_as_of(edge_index, attributes, self.time))
+    }
+
+    /// Copies the view into a record without attribute versions, so that it
+    /// can be queried.
+    #[must_use]
+    #[expect(clippy::missing_panics_doc, reason = "infallible")]
+    pub fn to_graphrecord(&self) -> GraphRecord {
+        let mut graphrecord = self.graphrecord.clone();
+        let valid_time = std::mem::take(&mut graphrecord.valid_time);
+
+        for node_index in valid_time.node_indices() {
+            let attributes = graphrecord
+                .graph
+                .node_attributes_mut(node_index)
+                .expect("Node must exist");
+
+            *attributes = valid_time.node_attributes_as_of(node_index, attributes, self.time);
+        }
+
+        for edge_index in valid_time.edge_indices() {
+            let attributes = graphrecord
+                .graph
+                .edge_attributes_mut(edge_index)
+                .expect("Edge must exist");
+
+            *attributes = valid_time.edge_attributes_as_of(edge_index, attributes, self.time);
+        }
+
+        graphrecord
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{GraphRecordView, GroupPairView, TimeWindowView};
+    use crate::{
+        GraphRecord,
+        graphrecord::{AttributeVersion, GraphRecordValue, NodeIndex, querying::wrapper::Wrapper},
+    };
+    use chrono::{NaiveDate, NaiveDateTime};
+    use std::collections::HashMap;
+
+    fn create_graphrecord() -> GraphRecord {
+        let mut graphrecord = GraphRecord::new();
+
+        graphrecord
+            .add_nodes_with_group(
+                vec![("0".into(), HashMap::new()), ("1".into(), HashMap::new())],
+                "patient".into(),
+            )
+            .unwrap();
+        graphrecord
+            .add_nodes_with_group(
+                vec![
+                    ("2".into(), HashMap::from([("time".into(), 5.into())])),
+                    ("3".into(), HashMap::from([("time".into(), 15.into())])),
+                ],
+                "diagnosis".into(),
+            )
+            .unwrap();
+        graphrecord.add_node("4".into(), HashMap::new()).unwrap();
+
+        for (source, target, time) in [("0", "2", 5), ("1", "3", 15), ("2", "0", 6), ("0", "1", 7)]
+        {
+            graphrecord
+                .add_edge(
+                    source.into(),
+                    target.into(),
+                    HashMap::from([("time".into(), time.into())]),
+                )
+                .unwrap();
+        }
+        graphrecord
+            .add_edge("0".into(), "4".into(), HashMap::new())
+            .unwrap();
+
+        graphrecord
+    }
+
+    fn sorted_nodes<'a>(node_indices: impl Iterator<Item = &'a NodeIndex>) -> Vec<NodeIndex> {
+        let mut node_indices: Vec<_> = node_indices.cloned().collect();
+        node_indices.sort_by(NodeIndex::total_cmp);
+        node_indices
+    }
+
+    #[test]
+    fn test_group_pair_view() {
+        let graphrecord = create_graphrecord();
+
+        let view = GroupPairView::new(&graphrecord, "patient".into(), "diagnosis".into()).unwrap();
+
+        assert_eq!(
+            vec![NodeIndex::from("0"), "1".into(), "2".into(), "3".into()],
+            sorted_nodes(view.node_indices())
+        );
+
+        let mut edge_indices: Vec<_> = view.edge_indices().copied().collect();
+        edge_indices.sort_unstable();
+        assert_eq!(vec![0, 1, 2], edge_indices);
+
+        let mut queried: Vec<_> = view
+            .query_edges(Wrapper::index)
+            .evaluate()
+            .unwrap()
+            .collect();
+        queried.sort_unstable();
+        assert_eq!(edge_indices, queried);
+
+        assert_eq!(
+            sorted_nodes(view.node_indices()),
+            sorted_nodes(
+                view.query_nodes(Wrapper::index)
+                    .evaluate()
+                    .unwrap()
+                    .collect::<Vec<_>>()
+                    .iter()
+            )
+        );
+
+        assert!(GroupPairView::new(&graphrecord, "patient".into(), "missing".into()).is_err());
+    }
+
+    #[test]
+    fn test_time_window_view() {
+        let graphrecord = create_graphrecord();
+
+        let view = TimeWindowView::new(&graphrecord, "time".into(), 0.into(), 10.into());
+
+        assert_eq!(
+            vec![NodeIndex::from("0"), "1".into(), "2".into(), "4".into()],
+            sorted_nodes(view.node_indices())
+        );
+        assert_eq!(
+            sorted_nodes(view.node_indices()),
+            sorted_nodes(
+                view.query_nodes(Wrapper::index)
+                    .evaluate()
+                    .unwrap()
+                    .collect::<Vec<_>>()
+                    .iter()
+            )
+        );
+
+        let mut edge_indices: Vec<_> = view.edge_indices().copied().collect();
+        edge_indices.sort_unstable();
+        assert_eq!(vec![0, 2, 3, 4], edge_indices);
+
+        let mut queried: Vec<_> = view
+            .query_edges(Wrapper::index)
+            .evaluate()
+            .unwrap()
+            .collect();
+        queried.sort_unstable();
+        assert_eq!(edge_indices, queried);
+    }
+
+    #[test]
+    fn test_as_of_view() {
+        let date = |year| -> NaiveDateTime {
+            NaiveDate::from_ymd_opt(year, 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+        };
+
+        let mut graphrecord = create_graphrecord();
+        graphrecord
+            .node_attributes_mut(&"0".into())
+            .unwrap()
+            .update_attribute(&"weight".into(), 90.into())
+            .unwrap();
+        graphrecord
+            .add_node_attribute_version(
+                &"0".into(),
+                "weight".into(),
+                AttributeVersion::new(70.into(), Some(date(2020)), Some(date(2021))),
+            )
+            .unwrap();
+        graphrecord
+            .add_node_attribute_version(
+                &"0".into(),
+                "weight".into(),
+                AttributeVersion::new(80.into(), Some(date(2021)), None),
+            )
+            .unwrap();
+
+        let view = graphrecord.as_of(date(2020));
+        assert_eq!(
+            HashMap::from([("weight".into(), 70.into())]),
+            view.node_attributes(&"0".into()).unwrap()
+        );
+
+        // No version is valid yet
+        let view = graphrecord.as_of(date(2019));
+        assert!(view.node_attributes(&"0".into()).unwrap().is_empty());
+
+        // Attributes without versions keep their current value
+        assert_eq!(
+            graphrecord.node_attributes(&"2".into()).unwrap(),
+            &view.node_attributes(&"2".into()).unwrap()
+        );
+
+        let snapshot = graphrecord.as_of(date(2022)).to_graphrecord();
+        let weights: Vec<_> = snapshot
+            .query_nodes(|nodes| nodes.attribute("weight"))
+            .evaluate()
+            .unwrap()
+            .map(|(_, value)| value)
+            .collect();
+        assert_eq!(vec![GraphRecordValue::from(80)], weights);
+        assert!(
+            snapshot
+                .node_attribute_versions(&"0".into(), &"weight".into())
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -1,6 +1,10 @@
 pub use crate::graphrecord::{
-    Attributes, EdgeIndex, Group, NodeIndex,
+    AttributeMap, Attributes, EdgeIndex, Group, NodeIndex, ReadAttributes,
     datatypes::{DataType, GraphRecordAttribute, GraphRecordValue},
     querying::nodes::EdgeDirection,
-    schema::{AttributeDataType, AttributeSchema, AttributeType, GroupSchema, Schema, SchemaType},
+    schema::{
+        AttributeDataType, AttributeSchema, AttributeType, AttributeTypeInference,
+        ConflictStrategy, ConversionMode, DataTypeWidening, GroupSchema, NonFiniteFloatPolicy,
+        Schema, SchemaType,
+    },
 };
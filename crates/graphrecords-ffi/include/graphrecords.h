@@ -0,0 +1,70 @@
+/*
+ * C ABI of graphrecords-ffi.
+ *
+ * Indices, attributes and query plans are passed as JSON strings. Strings and
+ * buffers returned through output parameters are owned by the caller and
+ * released with gr_string_free and gr_bytes_free.
+ */
+
+#ifndef GRAPHRECORDS_H
+#define GRAPHRECORDS_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef enum GrStatus {
+  GR_STATUS_OK = 0,
+  GR_STATUS_NULL_POINTER = 1,
+  GR_STATUS_INVALID_ARGUMENT = 2,
+  GR_STATUS_PANIC = 3,
+  GR_STATUS_INDEX_ERROR = 100,
+  GR_STATUS_MISSING_NODE = 101,
+  GR_STATUS_KEY_ERROR = 200,
+  GR_STATUS_CONVERSION_ERROR = 300,
+  GR_STATUS_ASSERTION_ERROR = 400,
+  GR_STATUS_DUPLICATE_GROUP = 401,
+  GR_STATUS_SCHEMA_ERROR = 500,
+  GR_STATUS_TYPE_MISMATCH = 501,
+  GR_STATUS_QUERY_ERROR = 600,
+  GR_STATUS_READ_ONLY_ERROR = 700,
+} GrStatus;
+
+typedef struct GrGraphRecord GrGraphRecord;
+
+const char *gr_last_error_message(void);
+
+GrGraphRecord *gr_graphrecord_new(void);
+void gr_graphrecord_free(GrGraphRecord *graphrecord);
+GrStatus gr_graphrecord_from_bytes(const uint8_t *bytes, size_t length, GrGraphRecord **output);
+GrStatus gr_graphrecord_to_bytes(const GrGraphRecord *graphrecord, uint8_t **output, size_t *length);
+
+void gr_bytes_free(uint8_t *bytes, size_t length);
+void gr_string_free(char *string);
+
+size_t gr_node_count(const GrGraphRecord *graphrecord);
+size_t gr_edge_count(const GrGraphRecord *graphrecord);
+
+GrStatus gr_add_node(GrGraphRecord *graphrecord, const char *node_index, const char *attributes);
+GrStatus gr_node_attributes(const GrGraphRecord *graphrecord, const char *node_index, char **output);
+GrStatus gr_remove_node(GrGraphRecord *graphrecord, const char *node_index);
+
+GrStatus gr_add_edge(GrGraphRecord *graphrecord, const char *source_node_index,
+                     const char *target_node_index, const char *attributes, uint32_t *output);
+GrStatus gr_edge_attributes(const GrGraphRecord *graphrecord, uint32_t edge_index, char **output);
+GrStatus gr_remove_edge(GrGraphRecord *graphrecord, uint32_t edge_index);
+
+GrStatus gr_add_group(GrGraphRecord *graphrecord, const char *group);
+GrStatus gr_remove_group(GrGraphRecord *graphrecord, const char *group);
+GrStatus gr_add_node_to_group(GrGraphRecord *graphrecord, const char *group, const char *node_index);
+
+GrStatus gr_query(const GrGraphRecord *graphrecord, const char *plan, char **output);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* GRAPHRECORDS_H */
@@ -0,0 +1,86 @@
+//! Conversion between plain JSON and the attributes and values of a record.
+//!
+//! Indices and attribute names are JSON strings or integers. Attributes are
+//! JSON objects, so their names are always read as strings. Values are JSON
+//! strings, integers, floats, booleans or `null`; values that JSON cannot
+//! represent, like dates, are written as strings.
+
+use crate::error::FfiError;
+use graphrecords_core::graphrecord::{
+    AttributeMap, Attributes, GraphRecordAttribute, GraphRecordValue,
+};
+use serde_json::{Map, Number, Value};
+
+pub fn parse_json(json: &str) -> Result<Value, FfiError> {
+    serde_json::from_str(json)
+        .map_err(|error| FfiError::InvalidArgument(format!("Invalid JSON: {error}")))
+}
+
+pub fn json_to_attribute(json: &Value) -> Result<GraphRecordAttribute, FfiError> {
+    match json {
+        Value::String(value) => Ok(GraphRecordAttribute::String(value.clone())),
+        Value::Number(value) => value
+            .as_i64()
+            .map(GraphRecordAttribute::Int)
+            .ok_or_else(|| {
+                FfiError::InvalidArgument(format!(
+                    "Cannot convert {value} into GraphRecordAttribute"
+                ))
+            }),
+        _ => Err(FfiError::InvalidArgument(format!(
+            "Cannot convert {json} into GraphRecordAttribute"
+        ))),
+    }
+}
+
+fn json_to_value(json: &Value) -> Result<GraphRecordValue, FfiError> {
+    match json {
+        Value::Null => Ok(GraphRecordValue::Null),
+        Value::Bool(value) => Ok(GraphRecordValue::Bool(*value)),
+        Value::String(value) => Ok(GraphRecordValue::String(value.clone())),
+        Value::Number(value) => Ok(value.as_i64().map_or_else(
+            || GraphRecordValue::Float(value.as_f64().unwrap_or(f64::NAN)),
+            GraphRecordValue::Int,
+        )),
+        _ => Err(FfiError::InvalidArgument(format!(
+            "Cannot convert {json} into GraphRecordValue"
+        ))),
+    }
+}
+
+pub fn json_to_attributes(json: &Value) -> Result<Attributes, FfiError> {
+    let Value::Object(object) = json else {
+        return Err(FfiError::InvalidArgument(format!(
+            "Cannot convert {json} into Attributes"
+        )));
+    };
+
+    object
+        .iter()
+        .map(|(key, value)| Ok((key.as_str().into(), json_to_value(value)?)))
+        .collect()
+}
+
+fn value_to_json(value: &GraphRecordValue) -> Value {
+    match value {
+        GraphRecordValue::String(value) => Value::String(value.clone()),
+        GraphRecordValue::Int(value) => Value::Number((*value).into()),
+        GraphRecordValue::Float(value) => {
+            Number::from_f64(*value).map_or(Value::Null, Value::Number)
+        }
+        GraphRecordValue::Bool(value) => Value::Bool(*value),
+        GraphRecordValue::Null | GraphRecordValue::Missing => Value::Null,
+        GraphRecordValue::DateTime(_)
+        | GraphRecordValue::Duration(_)
+        | GraphRecordValue::GeoPoint(_) => Value::String(value.to_string()),
+    }
+}
+
+pub fn attributes_to_json(attributes: &AttributeMap) -> Value {
+    Value::Object(
+        attributes
+            .iter()
+            .map(|(key, value)| (key.to_string(), value_to_json(value)))
+            .collect::<Map<_, _>>(),
+    )
+}
@@ -0,0 +1,117 @@
+use graphrecords_core::errors::GraphRecordError;
+use std::{
+    cell::RefCell,
+    ffi::{CString, c_char},
+    panic::{AssertUnwindSafe, catch_unwind},
+    ptr,
+};
+
+/// Status returned by every fallible function. The codes of errors of the
+/// record match the numeric part of [`GraphRecordError::code`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GrStatus {
+    Ok = 0,
+    NullPointer = 1,
+    InvalidArgument = 2,
+    Panic = 3,
+    IndexError = 100,
+    MissingNode = 101,
+    KeyError = 200,
+    ConversionError = 300,
+    AssertionError = 400,
+    DuplicateGroup = 401,
+    SchemaError = 500,
+    TypeMismatch = 501,
+    QueryError = 600,
+    ReadOnlyError = 700,
+}
+
+#[derive(Debug)]
+pub enum FfiError {
+    NullPointer(&'static str),
+    InvalidArgument(String),
+    GraphRecord(GraphRecordError),
+}
+
+impl From<GraphRecordError> for FfiError {
+    fn from(error: GraphRecordError) -> Self {
+        Self::GraphRecord(error)
+    }
+}
+
+impl FfiError {
+    const fn status(&self) -> GrStatus {
+        match self {
+            Self::NullPointer(_) => GrStatus::NullPointer,
+            Self::InvalidArgument(_) => GrStatus::InvalidArgument,
+            Self::GraphRecord(error) => match error {
+                GraphRecordError::IndexError(_) => GrStatus::IndexError,
+                GraphRecordError::MissingNode { .. } => GrStatus::MissingNode,
+                GraphRecordError::KeyError(_) => GrStatus::KeyError,
+                GraphRecordError::ConversionError(_) => GrStatus::ConversionError,
+                GraphRecordError::AssertionError(_) => GrStatus::AssertionError,
+                GraphRecordError::DuplicateGroup { .. } => GrStatus::DuplicateGroup,
+                GraphRecordError::SchemaError(_) => GrStatus::SchemaError,
+                GraphRecordError::TypeMismatch { .. } => GrStatus::TypeMismatch,
+                GraphRecordError::QueryError(_) => GrStatus::QueryError,
+                GraphRecordError::ReadOnlyError(_) => GrStatus::ReadOnlyError,
+            },
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            Self::NullPointer(argument) => format!("{argument} must not be null"),
+            Self::InvalidArgument(message) => message.clone(),
+            Self::GraphRecord(error) => format!("[{}] {}", error.code(), error.message()),
+        }
+    }
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_last_error(message: Option<String>) {
+    let message = message.map(|message| {
+        CString::new(message.replace('\0', " ")).expect("Message must not contain NUL")
+    });
+
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = message);
+}
+
+/// Runs `function`, recording its error as the last error of the thread.
+/// Panics are caught, as unwinding into the caller is undefined behavior.
+pub fn run(function: impl FnOnce() -> Result<(), FfiError>) -> GrStatus {
+    match catch_unwind(AssertUnwindSafe(function)) {
+        Ok(Ok(())) => {
+            set_last_error(None);
+
+            GrStatus::Ok
+        }
+        Ok(Err(error)) => {
+            set_last_error(Some(error.message()));
+
+            error.status()
+        }
+        Err(_) => {
+            set_last_error(Some("Unexpected panic".to_string()));
+
+            GrStatus::Panic
+        }
+    }
+}
+
+/// Returns the message of the last error on the calling thread, or null if
+/// the last call succeeded. The string is owned by the library and valid
+/// until the next call on the same thread.
+#[unsafe(no_mangle)]
+pub extern "C" fn gr_last_error_message() -> *const c_char {
+    LAST_ERROR.with(|last_error| {
+        last_error
+            .borrow()
+            .as_ref()
+            .map_or_else(ptr::null, |message| message.as_ptr())
+    })
+}
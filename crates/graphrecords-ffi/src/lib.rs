@@ -0,0 +1,634 @@
+//! C ABI for embedding a `GraphRecord` in services written in other languages,
+//! e.g. C++ or Java through JNI or the foreign function API. The functions are
+//! declared in `include/graphrecords.h`.
+//!
+//! A record is an opaque [`GrGraphRecord`] handle created with
+//! [`gr_graphrecord_new`] or [`gr_graphrecord_from_bytes`] and released with
+//! [`gr_graphrecord_free`]. Fallible functions return a [`GrStatus`], and the
+//! message of the error is available through [`gr_last_error_message`].
+//! Indices, attributes and query plans are passed as JSON strings. Strings and
+//! byte buffers returned by the library are owned by the caller and released
+//! with [`gr_string_free`] and [`gr_bytes_free`].
+//!
+//! A handle must not be used from several threads at the same time.
+
+mod conversion;
+mod error;
+
+use conversion::{attributes_to_json, json_to_attribute, json_to_attributes, parse_json};
+use error::{FfiError, run};
+use graphrecords_core::{
+    GraphRecord,
+    graphrecord::{Attributes, EdgeIndex, GraphRecordAttribute, querying::plan::QueryPlan},
+};
+use std::{
+    ffi::{CStr, CString, c_char},
+    mem::MaybeUninit,
+    ptr, slice,
+};
+
+pub use error::{GrStatus, gr_last_error_message};
+
+/// Opaque handle of a record.
+#[derive(Debug, Default)]
+pub struct GrGraphRecord(GraphRecord);
+
+unsafe fn graphrecord_ref<'a>(
+    graphrecord: *const GrGraphRecord,
+) -> Result<&'a GraphRecord, FfiError> {
+    unsafe { graphrecord.as_ref() }
+        .map(|graphrecord| &graphrecord.0)
+        .ok_or(FfiError::NullPointer("graphrecord"))
+}
+
+unsafe fn graphrecord_mut<'a>(
+    graphrecord: *mut GrGraphRecord,
+) -> Result<&'a mut GraphRecord, FfiError> {
+    unsafe { graphrecord.as_mut() }
+        .map(|graphrecord| &mut graphrecord.0)
+        .ok_or(FfiError::NullPointer("graphrecord"))
+}
+
+unsafe fn str_argument<'a>(
+    argument: *const c_char,
+    name: &'static str,
+) -> Result<&'a str, FfiError> {
+    if argument.is_null() {
+        return Err(FfiError::NullPointer(name));
+    }
+
+    unsafe { CStr::from_ptr(argument) }
+        .to_str()
+        .map_err(|_| FfiError::InvalidArgument(format!("{name} must be valid UTF-8")))
+}
+
+unsafe fn attribute_argument(
+    argument: *const c_char,
+    name: &'static str,
+) -> Result<GraphRecordAttribute, FfiError> {
+    json_to_attribute(&parse_json(unsafe { str_argument(argument, name) }?)?)
+}
+
+unsafe fn attributes_argument(
+    argument: *const c_char,
+    name: &'static str,
+) -> Result<Attributes, FfiError> {
+    json_to_attributes(&parse_json(unsafe { str_argument(argument, name) }?)?)
+}
+
+// Output pointers are checked before any work is done, so a null output
+// neither applies a change nor leaks the value that would have been written.
+unsafe fn output_argument<'a, T>(
+    output: *mut T,
+    name: &'static str,
+) -> Result<&'a mut MaybeUninit<T>, FfiError> {
+    unsafe { output.cast::<MaybeUninit<T>>().as_mut() }.ok_or(FfiError::NullPointer(name))
+}
+
+fn write_string(output: &mut MaybeUninit<*mut c_char>, value: String) -> Result<(), FfiError> {
+    let value = CString::new(value)
+        .map_err(|_| FfiError::InvalidArgument("Output contains NUL".to_string()))?;
+
+    output.write(value.into_raw());
+
+    Ok(())
+}
+
+/// Creates an empty record.
+#[unsafe(no_mangle)]
+pub extern "C" fn gr_graphrecord_new() -> *mut GrGraphRecord {
+    Box::into_raw(Box::default())
+}
+
+/// Releases a record. Null is ignored.
+///
+/// # Safety
+///
+/// `graphrecord` must be null or a handle returned by this library that was
+/// not released yet.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gr_graphrecord_free(graphrecord: *mut GrGraphRecord) {
+    if !graphrecord.is_null() {
+        drop(unsafe { Box::from_raw(graphrecord) });
+    }
+}
+
+/// Reads a record from the binary container format and writes its handle to
+/// `output`.
+///
+/// # Safety
+///
+/// `bytes` must point to `length` readable bytes and `output` must be valid
+/// for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gr_graphrecord_from_bytes(
+    bytes: *const u8,
+    length: usize,
+    output: *mut *mut GrGraphRecord,
+) -> GrStatus {
+    run(|| {
+        if bytes.is_null() {
+            return Err(FfiError::NullPointer("bytes"));
+        }
+        let output = unsafe { output_argument(output, "output") }?;
+
+        let graphrecord = GraphRecord::from_bytes(unsafe { slice::from_raw_parts(bytes, length) })?;
+
+        output.write(Box::into_raw(Box::new(GrGraphRecord(graphrecord))));
+
+        Ok(())
+    })
+}
+
+/// Writes a record in the binary container format. The buffer is released
+/// with [`gr_bytes_free`].
+///
+/// # Safety
+///
+/// `graphrecord` must be a valid handle, and `output` and `length` must be
+/// valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gr_graphrecord_to_bytes(
+    graphrecord: *const GrGraphRecord,
+    output: *mut *mut u8,
+    length: *mut usize,
+) -> GrStatus {
+    run(|| {
+        let output = unsafe { output_argument(output, "output") }?;
+        let length = unsafe { output_argument(length, "length") }?;
+
+        let bytes = unsafe { graphrecord_ref(graphrecord) }?
+            .to_bytes()?
+            .into_boxed_slice();
+
+        length.write(bytes.len());
+        output.write(Box::into_raw(bytes).cast::<u8>());
+
+        Ok(())
+    })
+}
+
+/// Releases a buffer returned by [`gr_graphrecord_to_bytes`]. Null is
+/// ignored.
+///
+/// # Safety
+///
+/// `bytes` must be null or a buffer of `length` bytes returned by this
+/// library that was not released yet.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gr_bytes_free(bytes: *mut u8, length: usize) {
+    if !bytes.is_null() {
+        drop(unsafe { Box::from_raw(ptr::slice_from_raw_parts_mut(bytes, length)) });
+    }
+}
+
+/// Releases a string returned by this library. Null is ignored.
+///
+/// # Safety
+///
+/// `string` must be null or a string returned by this library that was not
+/// released yet.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gr_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        drop(unsafe { CString::from_raw(string) });
+    }
+}
+
+/// Returns the number of nodes, or 0 if `graphrecord` is null.
+///
+/// # Safety
+///
+/// `graphrecord` must be null or a valid handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gr_node_count(graphrecord: *const GrGraphRecord) -> usize {
+    unsafe { graphrecord_ref(graphrecord) }.map_or(0, GraphRecord::node_count)
+}
+
+/// Returns the number of edges, or 0 if `graphrecord` is null.
+///
+/// # Safety
+///
+/// `graphrecord` must be null or a valid handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gr_edge_count(graphrecord: *const GrGraphRecord) -> usize {
+    unsafe { graphrecord_ref(graphrecord) }.map_or(0, GraphRecord::edge_count)
+}
+
+/// Adds a node with the index and attributes given as JSON.
+///
+/// # Safety
+///
+/// `graphrecord` must be a valid handle, and `node_index` and `attributes`
+/// NUL-terminated strings.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gr_add_node(
+    graphrecord: *mut GrGraphRecord,
+    node_index: *const c_char,
+    attributes: *const c_char,
+) -> GrStatus {
+    run(|| {
+        let node_index = unsafe { attribute_argument(node_index, "node_index") }?;
+        let attributes = unsafe { attributes_argument(attributes, "attributes") }?;
+
+        Ok(unsafe { graphrecord_mut(graphrecord) }?.add_node(node_index, attributes)?)
+    })
+}
+
+/// Writes the attributes of a node as a JSON object to `output`.
+///
+/// # Safety
+///
+/// `graphrecord` must be a valid handle, `node_index` a NUL-terminated string
+/// and `output` valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gr_node_attributes(
+    graphrecord: *const GrGraphRecord,
+    node_index: *const c_char,
+    output: *mut *mut c_char,
+) -> GrStatus {
+    run(|| {
+        let node_index = unsafe { attribute_argument(node_index, "node_index") }?;
+        let output = unsafe { output_argument(output, "output") }?;
+
+        let attributes = unsafe { graphrecord_ref(graphrecord) }?.node_attributes(&node_index)?;
+
+        write_string(output, attributes_to_json(attributes).to_string())
+    })
+}
+
+/// Removes a node and its edges.
+///
+/// # Safety
+///
+/// `graphrecord` must be a valid handle and `node_index` a NUL-terminated
+/// string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gr_remove_node(
+    graphrecord: *mut GrGraphRecord,
+    node_index: *const c_char,
+) -> GrStatus {
+    run(|| {
+        let node_index = unsafe { attribute_argument(node_index, "node_index") }?;
+
+        unsafe { graphrecord_mut(graphrecord) }?.remove_node(&node_index)?;
+
+        Ok(())
+    })
+}
+
+/// Adds an edge between two nodes and writes its index to `output`.
+///
+/// # Safety
+///
+/// `graphrecord` must be a valid handle, `source_node_index`,
+/// `target_node_index` and `attributes` NUL-terminated strings and `output`
+/// valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gr_add_edge(
+    graphrecord: *mut GrGraphRecord,
+    source_node_index: *const c_char,
+    target_node_index: *const c_char,
+    attributes: *const c_char,
+    output: *mut EdgeIndex,
+) -> GrStatus {
+    run(|| {
+        let source_node_index =
+            unsafe { attribute_argument(source_node_index, "source_node_index") }?;
+        let target_node_index =
+            unsafe { attribute_argument(target_node_index, "target_node_index") }?;
+        let attributes = unsafe { attributes_argument(attributes, "attributes") }?;
+        let output = unsafe { output_argument(output, "output") }?;
+
+        let edge_index = unsafe { graphrecord_mut(graphrecord) }?.add_edge(
+            source_node_index,
+            target_node_index,
+            attributes,
+        )?;
+
+        output.write(edge_index);
+
+        Ok(())
+    })
+}
+
+/// Writes the attributes of an edge as a JSON object to `output`.
+///
+/// # Safety
+///
+/// `graphrecord` must be a valid handle and `output` valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gr_edge_attributes(
+    graphrecord: *const GrGraphRecord,
+    edge_index: EdgeIndex,
+    output: *mut *mut c_char,
+) -> GrStatus {
+    run(|| {
+        let output = unsafe { output_argument(output, "output") }?;
+
+        let attributes = unsafe { graphrecord_ref(graphrecord) }?.edge_attributes(&edge_index)?;
+
+        write_string(output, attributes_to_json(attributes).to_string())
+    })
+}
+
+/// Removes an edge.
+///
+/// # Safety
+///
+/// `graphrecord` must be a valid handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gr_remove_edge(
+    graphrecord: *mut GrGraphRecord,
+    edge_index: EdgeIndex,
+) -> GrStatus {
+    run(|| {
+        unsafe { graphrecord_mut(graphrecord) }?.remove_edge(&edge_index)?;
+
+        Ok(())
+    })
+}
+
+/// Adds an empty group.
+///
+/// # Safety
+///
+/// `graphrecord` must be a valid handle and `group` a NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gr_add_group(
+    graphrecord: *mut GrGraphRecord,
+    group: *const c_char,
+) -> GrStatus {
+    run(|| {
+        let group = unsafe { attribute_argument(group, "group") }?;
+
+        Ok(unsafe { graphrecord_mut(graphrecord) }?.add_group(group, None, None)?)
+    })
+}
+
+/// Removes a group. Its nodes and edges are kept.
+///
+/// # Safety
+///
+/// `graphrecord` must be a valid handle and `group` a NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gr_remove_group(
+    graphrecord: *mut GrGraphRecord,
+    group: *const c_char,
+) -> GrStatus {
+    run(|| {
+        let group = unsafe { attribute_argument(group, "group") }?;
+
+        Ok(unsafe { graphrecord_mut(graphrecord) }?.remove_group(&group)?)
+    })
+}
+
+/// Adds a node to a group.
+///
+/// # Safety
+///
+/// `graphrecord` must be a valid handle, and `group` and `node_index`
+/// NUL-terminated strings.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gr_add_node_to_group(
+    graphrecord: *mut GrGraphRecord,
+    group: *const c_char,
+    node_index: *const c_char,
+) -> GrStatus {
+    run(|| {
+        let group = unsafe { attribute_argument(group, "group") }?;
+        let node_index = unsafe { attribute_argument(node_index, "node_index") }?;
+
+        Ok(unsafe { graphrecord_mut(graphrecord) }?.add_node_to_group(group, node_index)?)
+    })
+}
+
+/// Evaluates a query plan and writes the matching indices as JSON to
+/// `output`. Plans and results use the JSON representation of
+/// [`QueryPlan`] and its result, as accepted by `graphrecords-server`.
+///
+/// # Safety
+///
+/// `graphrecord` must be a valid handle, `plan` a NUL-terminated string and
+/// `output` valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gr_query(
+    graphrecord: *const GrGraphRecord,
+    plan: *const c_char,
+    output: *mut *mut c_char,
+) -> GrStatus {
+    run(|| {
+        let plan: QueryPlan = serde_json::from_str(unsafe { str_argument(plan, "plan") }?)
+            .map_err(|error| FfiError::InvalidArgument(format!("Invalid query plan: {error}")))?;
+        let output = unsafe { output_argument(output, "output") }?;
+
+        let result = plan.evaluate(unsafe { graphrecord_ref(graphrecord) }?)?;
+
+        let result = serde_json::to_string(&result)
+            .map_err(|error| FfiError::InvalidArgument(error.to_string()))?;
+
+        write_string(output, result)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        EdgeIndex, GrStatus, gr_add_edge, gr_add_group, gr_add_node, gr_add_node_to_group,
+        gr_bytes_free, gr_edge_attributes, gr_edge_count, gr_graphrecord_free,
+        gr_graphrecord_from_bytes, gr_graphrecord_new, gr_graphrecord_to_bytes,
+        gr_last_error_message, gr_node_attributes, gr_node_count, gr_query, gr_remove_edge,
+        gr_remove_node, gr_string_free,
+    };
+    use std::{
+        ffi::{CStr, CString, c_char},
+        ptr,
+    };
+
+    fn c_string(value: &str) -> CString {
+        CString::new(value).unwrap()
+    }
+
+    unsafe fn take_string(string: *mut c_char) -> String {
+        let value = unsafe { CStr::from_ptr(string) }
+            .to_str()
+            .unwrap()
+            .to_string();
+
+        unsafe { gr_string_free(string) };
+
+        value
+    }
+
+    #[test]
+    fn test_crud() {
+        let graphrecord = gr_graphrecord_new();
+
+        unsafe {
+            assert_eq!(
+                GrStatus::Ok,
+                gr_add_node(
+                    graphrecord,
+                    c_string("\"0\"").as_ptr(),
+                    c_string("{\"age\": 40}").as_ptr()
+                )
+            );
+            assert_eq!(
+                GrStatus::Ok,
+                gr_add_node(graphrecord, c_string("1").as_ptr(), c_string("{}").as_ptr())
+            );
+
+            let mut edge_index = EdgeIndex::MAX;
+            assert_eq!(
+                GrStatus::Ok,
+                gr_add_edge(
+                    graphrecord,
+                    c_string("\"0\"").as_ptr(),
+                    c_string("1").as_ptr(),
+                    c_string("{\"weight\": 0.5}").as_ptr(),
+                    &raw mut edge_index,
+                )
+            );
+            assert_eq!(0, edge_index);
+            assert_eq!(2, gr_node_count(graphrecord));
+            assert_eq!(1, gr_edge_count(graphrecord));
+
+            let mut output = ptr::null_mut();
+            assert_eq!(
+                GrStatus::Ok,
+                gr_node_attributes(graphrecord, c_string("\"0\"").as_ptr(), &raw mut output)
+            );
+            assert_eq!("{\"age\":40}", take_string(output));
+
+            assert_eq!(
+                GrStatus::Ok,
+                gr_edge_attributes(graphrecord, 0, &raw mut output)
+            );
+            assert_eq!("{\"weight\":0.5}", take_string(output));
+
+            assert_eq!(GrStatus::Ok, gr_remove_edge(graphrecord, 0));
+            assert_eq!(
+                GrStatus::Ok,
+                gr_remove_node(graphrecord, c_string("1").as_ptr())
+            );
+            assert_eq!(1, gr_node_count(graphrecord));
+            assert_eq!(0, gr_edge_count(graphrecord));
+            assert!(gr_last_error_message().is_null());
+
+            gr_graphrecord_free(graphrecord);
+        }
+    }
+
+    #[test]
+    fn test_query() {
+        let graphrecord = gr_graphrecord_new();
+
+        unsafe {
+            for (node_index, age) in [("\"0\"", 20), ("\"1\"", 40)] {
+                gr_add_node(
+                    graphrecord,
+                    c_string(node_index).as_ptr(),
+                    c_string(&format!("{{\"age\": {age}}}")).as_ptr(),
+                );
+            }
+            gr_add_group(graphrecord, c_string("\"group\"").as_ptr());
+            gr_add_node_to_group(
+                graphrecord,
+                c_string("\"group\"").as_ptr(),
+                c_string("\"1\"").as_ptr(),
+            );
+
+            let mut output = ptr::null_mut();
+            assert_eq!(
+                GrStatus::Ok,
+                gr_query(
+                    graphrecord,
+                    c_string("{\"Nodes\": [{\"InGroup\": {\"String\": \"group\"}}]}").as_ptr(),
+                    &raw mut output,
+                )
+            );
+            assert_eq!("{\"Nodes\":[{\"String\":\"1\"}]}", take_string(output));
+
+            gr_graphrecord_free(graphrecord);
+        }
+    }
+
+    #[test]
+    fn test_bytes() {
+        let graphrecord = gr_graphrecord_new();
+
+        unsafe {
+            gr_add_node(graphrecord, c_string("0").as_ptr(), c_string("{}").as_ptr());
+
+            let mut bytes = ptr::null_mut();
+            let mut length = 0;
+            assert_eq!(
+                GrStatus::Ok,
+                gr_graphrecord_to_bytes(graphrecord, &raw mut bytes, &raw mut length)
+            );
+
+            let mut copy = ptr::null_mut();
+            assert_eq!(
+                GrStatus::Ok,
+                gr_graphrecord_from_bytes(bytes, length, &raw mut copy)
+            );
+            assert_eq!(1, gr_node_count(copy));
+
+            gr_bytes_free(bytes, length);
+            gr_graphrecord_free(copy);
+            gr_graphrecord_free(graphrecord);
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        let graphrecord = gr_graphrecord_new();
+
+        unsafe {
+            let mut output = ptr::null_mut();
+            assert_eq!(
+                GrStatus::MissingNode,
+                gr_node_attributes(graphrecord, c_string("0").as_ptr(), &raw mut output)
+            );
+            assert!(
+                CStr::from_ptr(gr_last_error_message())
+                    .to_str()
+                    .unwrap()
+                    .starts_with("[GR0101]")
+            );
+
+            assert_eq!(
+                GrStatus::InvalidArgument,
+                gr_add_node(graphrecord, c_string("[").as_ptr(), c_string("{}").as_ptr())
+            );
+            assert_eq!(
+                GrStatus::NullPointer,
+                gr_add_node(graphrecord, ptr::null(), c_string("{}").as_ptr())
+            );
+            assert_eq!(GrStatus::NullPointer, gr_remove_edge(ptr::null_mut(), 0));
+
+            // A null output is rejected before anything is changed
+            gr_add_node(graphrecord, c_string("0").as_ptr(), c_string("{}").as_ptr());
+            assert_eq!(
+                GrStatus::NullPointer,
+                gr_add_edge(
+                    graphrecord,
+                    c_string("0").as_ptr(),
+                    c_string("0").as_ptr(),
+                    c_string("{}").as_ptr(),
+                    ptr::null_mut(),
+                )
+            );
+            assert_eq!(0, gr_edge_count(graphrecord));
+
+            let mut bytes = ptr::null_mut();
+            assert_eq!(
+                GrStatus::NullPointer,
+                gr_graphrecord_to_bytes(graphrecord, &raw mut bytes, ptr::null_mut())
+            );
+            assert!(bytes.is_null());
+
+            gr_graphrecord_free(graphrecord);
+        }
+    }
+}
@@ -33,7 +33,7 @@ impl FromPyObject<'_, '_> for PyGraphRecord {
         let bytes = ob.call_method0("_to_bytes")?;
         let py_bytes: &Bound<'_, PyBytes> = bytes.cast()?;
 
-        let graphrecord = bincode::deserialize(py_bytes.as_bytes())
+        let graphrecord = GraphRecord::from_bytes(py_bytes.as_bytes())
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
 
         Ok(Self(graphrecord))
@@ -46,7 +46,9 @@ impl<'py> IntoPyObject<'py> for PyGraphRecord {
     type Error = PyErr;
 
     fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
-        let bytes = bincode::serialize(&self.0)
+        let bytes = self
+            .0
+            .to_bytes()
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
 
         let py_bytes = PyBytes::new(py, &bytes);
@@ -0,0 +1,687 @@
+"""Stub generator for the compiled `graphrecords._graphrecords` extension.
+
+The classes, members, parameters and defaults of the stubs are taken from what
+the extension registers at runtime, so methods that are added, renamed or
+removed in Rust show up in the stubs. Annotations are kept from the existing
+stubs. Members that have no stub yet are annotated with `Any` and should be
+refined by hand.
+
+Usage:
+    python crates/graphrecords-python/generate_stubs.py          # Write the stubs
+    python crates/graphrecords-python/generate_stubs.py --check  # Fail on drift
+"""
+
+from __future__ import annotations
+
+import argparse
+import ast
+import logging
+import shutil
+import subprocess
+import sys
+from pathlib import Path
+from types import ModuleType
+from typing import Dict, List, Optional, Sequence, Set
+
+import graphrecords._graphrecords as extension
+
+logging.basicConfig(
+    level=logging.INFO,
+    format="%(levelname)s: %(message)s",
+)
+logger = logging.getLogger(__name__)
+
+PROJECT_ROOT = Path(__file__).resolve().parents[2]
+STUBS_ROOT = PROJECT_ROOT / "graphrecords" / "_graphrecords"
+
+CONSTRUCTORS = {"__init__", "__new__"}
+RECEIVERS = {"self", "cls"}
+
+
+def parse_signature(
+    text_signature: Optional[str], receiver: Optional[str]
+) -> Optional[ast.arguments]:
+    """Parse a runtime `__text_signature__` into function arguments.
+
+    Args:
+        text_signature (Optional[str]): Signature as exposed by PyO3.
+        receiver (Optional[str]): Name of the first argument to prepend if the
+            signature has none, e.g. `self` for constructors.
+
+    Returns:
+        Optional[ast.arguments]: The parsed arguments, or None if the signature
+            is missing or cannot be parsed.
+    """
+    if text_signature is None:
+        return None
+
+    source = (
+        text_signature.replace("$self", "self")
+        .replace("$cls", "cls")
+        .replace("$type", "cls")
+    )
+
+    try:
+        function = ast.parse(f"def function{source}: ...").body[0]
+    except SyntaxError:
+        return None
+
+    if not isinstance(function, ast.FunctionDef):
+        return None
+
+    arguments = function.args
+
+    if receiver is not None:
+        receivers = arguments.posonlyargs or arguments.args
+        receivers.insert(0, ast.arg(arg=receiver))
+
+    return arguments
+
+
+def all_arguments(arguments: ast.arguments) -> List[ast.arg]:
+    """Collect every argument of a function in declaration order.
+
+    Args:
+        arguments (ast.arguments): Arguments of the function.
+
+    Returns:
+        List[ast.arg]: The positional, variadic and keyword arguments.
+    """
+    return [
+        *arguments.posonlyargs,
+        *arguments.args,
+        *([arguments.vararg] if arguments.vararg else []),
+        *arguments.kwonlyargs,
+        *([arguments.kwarg] if arguments.kwarg else []),
+    ]
+
+
+def defaults_by_name(arguments: ast.arguments) -> Dict[str, ast.expr]:
+    """Map the arguments of a function to their default values.
+
+    Args:
+        arguments (ast.arguments): Arguments of the function.
+
+    Returns:
+        Dict[str, ast.expr]: Default value of every argument that has one.
+    """
+    positional = [*arguments.posonlyargs, *arguments.args]
+    with_default = positional[len(positional) - len(arguments.defaults) :]
+
+    defaults = {
+        argument.arg: default
+        for argument, default in zip(with_default, arguments.defaults, strict=True)
+    }
+    defaults.update(
+        {
+            argument.arg: default
+            for argument, default in zip(
+                arguments.kwonlyargs, arguments.kw_defaults, strict=True
+            )
+            if default is not None
+        }
+    )
+
+    return defaults
+
+
+def any_annotation() -> ast.expr:
+    """Create the annotation used for members without a stub.
+
+    Returns:
+        ast.expr: A reference to `typing.Any`.
+    """
+    return ast.Name(id="Any", ctx=ast.Load())
+
+
+def merge_arguments(
+    runtime: ast.arguments, stub: Optional[ast.arguments]
+) -> ast.arguments:
+    """Annotate the runtime arguments with the annotations of the stub.
+
+    Args:
+        runtime (ast.arguments): Arguments exposed by the extension.
+        stub (Optional[ast.arguments]): Arguments of the existing stub.
+
+    Returns:
+        ast.arguments: The runtime arguments with annotations and defaults
+            taken from the stub where it declares the same argument.
+    """
+    stub_arguments = (
+        {argument.arg: argument for argument in all_arguments(stub)} if stub else {}
+    )
+    stub_defaults = defaults_by_name(stub) if stub else {}
+
+    for argument in all_arguments(runtime):
+        if argument.arg in RECEIVERS:
+            continue
+
+        stub_argument = stub_arguments.get(argument.arg)
+
+        argument.annotation = (
+            stub_argument.annotation
+            if stub_argument is not None and stub_argument.annotation is not None
+            else any_annotation()
+        )
+
+    positional = [*runtime.posonlyargs, *runtime.args]
+    with_default = positional[len(positional) - len(runtime.defaults) :]
+
+    runtime.defaults = [
+        stub_defaults.get(argument.arg, default)
+        for argument, default in zip(with_default, runtime.defaults, strict=True)
+    ]
+    runtime.kw_defaults = [
+        stub_defaults.get(argument.arg, default) if default is not None else None
+        for argument, default in zip(
+            runtime.kwonlyargs, runtime.kw_defaults, strict=True
+        )
+    ]
+
+    return runtime
+
+
+def generate_function(
+    name: str,
+    arguments: ast.arguments,
+    decorators: Sequence[str],
+    stub: Optional[ast.FunctionDef],
+) -> ast.FunctionDef:
+    """Generate the stub of a function from its runtime arguments.
+
+    Args:
+        name (str): Name of the function.
+        arguments (ast.arguments): Arguments exposed by the extension.
+        decorators (Sequence[str]): Decorators of a function without a stub.
+        stub (Optional[ast.FunctionDef]): Existing stub of the function.
+
+    Returns:
+        ast.FunctionDef: The stub of the function.
+    """
+    function = ast.parse(f"def {name}(): ...").body[0]
+    assert isinstance(function, ast.FunctionDef)
+
+    function.args = merge_arguments(arguments, stub.args if stub else None)
+
+    if stub is not None:
+        function.decorator_list = stub.decorator_list
+        function.returns = stub.returns
+    else:
+        function.decorator_list = [
+            ast.Name(id=decorator, ctx=ast.Load()) for decorator in decorators
+        ]
+        function.returns = (
+            ast.Constant(value=None) if name in CONSTRUCTORS else any_annotation()
+        )
+
+    return function
+
+
+def stub_member_name(statement: ast.stmt) -> Optional[str]:
+    """Get the name a stub statement declares.
+
+    Args:
+        statement (ast.stmt): Statement of a stub module or class.
+
+    Returns:
+        Optional[str]: The declared name, or None if the statement does not
+            declare a single name.
+    """
+    if isinstance(statement, (ast.FunctionDef, ast.ClassDef)):
+        return statement.name
+
+    if isinstance(statement, ast.AnnAssign) and isinstance(
+        statement.target, ast.Name
+    ):
+        return statement.target.id
+
+    if (
+        isinstance(statement, ast.Assign)
+        and len(statement.targets) == 1
+        and isinstance(statement.targets[0], ast.Name)
+    ):
+        return statement.targets[0].id
+
+    return None
+
+
+def group_stub_members(body: Sequence[ast.stmt]) -> Dict[str, List[ast.stmt]]:
+    """Group the statements of a stub body by the name they declare.
+
+    Args:
+        body (Sequence[ast.stmt]): Statements of a stub class.
+
+    Returns:
+        Dict[str, List[ast.stmt]]: The statements of every declared name, in
+            the order of the stub.
+    """
+    members: Dict[str, List[ast.stmt]] = {}
+
+    for statement in body:
+        name = stub_member_name(statement)
+
+        if name is not None:
+            members.setdefault(name, []).append(statement)
+
+    return members
+
+
+def first_function(statements: Sequence[ast.stmt]) -> Optional[ast.FunctionDef]:
+    """Get the first function among the statements of a stub member.
+
+    Args:
+        statements (Sequence[ast.stmt]): Statements declaring the member.
+
+    Returns:
+        Optional[ast.FunctionDef]: The first function, if there is one.
+    """
+    return next(
+        (
+            statement
+            for statement in statements
+            if isinstance(statement, ast.FunctionDef)
+        ),
+        None,
+    )
+
+
+def generate_constructor(
+    cls: type, name: str, stub: Sequence[ast.stmt]
+) -> List[ast.stmt]:
+    """Generate the stub of the constructor of a class.
+
+    Args:
+        cls (type): Class exposed by the extension.
+        name (str): Name of the constructor in the stub.
+        stub (Sequence[ast.stmt]): Existing stub of the constructor.
+
+    Returns:
+        List[ast.stmt]: The stub of the constructor, or nothing if the class
+            cannot be constructed from Python.
+    """
+    receiver = "cls" if name == "__new__" else "self"
+    arguments = parse_signature(getattr(cls, "__text_signature__", None), receiver)
+
+    if arguments is None:
+        return list(stub)
+
+    return [generate_function(name, arguments, [], first_function(stub))]
+
+
+def generate_method(
+    name: str, member: object, stub: Sequence[ast.stmt]
+) -> List[ast.stmt]:
+    """Generate the stub of a method, static method or class method.
+
+    Args:
+        name (str): Name of the method.
+        member (object): Method exposed by the extension.
+        stub (Sequence[ast.stmt]): Existing stub of the method.
+
+    Returns:
+        List[ast.stmt]: The stub of the method.
+    """
+    if isinstance(member, staticmethod):
+        decorators, receiver = ["staticmethod"], None
+        member = member.__func__
+    elif type(member).__name__ == "classmethod_descriptor":
+        decorators, receiver = ["classmethod"], "cls"
+    else:
+        decorators, receiver = [], "self"
+
+    function = first_function(stub)
+    arguments = parse_signature(getattr(member, "__text_signature__", None), None)
+
+    if arguments is None:
+        if function is not None:
+            return list(stub)
+
+        arguments = parse_signature("(*args, **kwargs)", receiver)
+        assert arguments is not None
+
+    return [generate_function(name, arguments, decorators, function)]
+
+
+def generate_property(name: str, stub: Sequence[ast.stmt]) -> List[ast.stmt]:
+    """Generate the stub of a property.
+
+    Args:
+        name (str): Name of the property.
+        stub (Sequence[ast.stmt]): Existing stub of the property, including
+            its setter.
+
+    Returns:
+        List[ast.stmt]: The stub of the property.
+    """
+    if stub:
+        return list(stub)
+
+    arguments = parse_signature("($self)", None)
+    assert arguments is not None
+
+    return [generate_function(name, arguments, ["property"], None)]
+
+
+def generate_member(
+    cls: type, name: str, stub: Sequence[ast.stmt]
+) -> List[ast.stmt]:
+    """Generate the stub of a member of a class.
+
+    Args:
+        cls (type): Class exposed by the extension.
+        name (str): Name of the member.
+        stub (Sequence[ast.stmt]): Existing stub of the member.
+
+    Returns:
+        List[ast.stmt]: The stub of the member, or nothing if the class does
+            not have the member at runtime.
+    """
+    if name in CONSTRUCTORS:
+        return generate_constructor(cls, name, stub)
+
+    if name not in cls.__dict__:
+        return list(stub) if hasattr(cls, name) else []
+
+    member = cls.__dict__[name]
+
+    if isinstance(member, type):
+        stub_class = next(
+            (
+                statement
+                for statement in stub
+                if isinstance(statement, ast.ClassDef)
+            ),
+            None,
+        )
+        return [generate_class(member, name, stub_class)]
+
+    if isinstance(member, cls):
+        return list(stub) or [ast.parse(f"{name} = ...").body[0]]
+
+    if type(member).__name__ == "getset_descriptor":
+        return generate_property(name, stub)
+
+    if callable(member) or isinstance(member, staticmethod):
+        return generate_method(name, member, stub)
+
+    return list(stub) or [ast.parse(f"{name}: {type(member).__name__}").body[0]]
+
+
+def runtime_member_names(cls: type) -> List[str]:
+    """List the members of a class that belong in its stub.
+
+    Args:
+        cls (type): Class exposed by the extension.
+
+    Returns:
+        List[str]: The public members of the class, and its constructor if it
+            can be constructed from Python.
+    """
+    names = [name for name in cls.__dict__ if not name.startswith("_")]
+
+    if getattr(cls, "__text_signature__", None) is not None:
+        names.insert(0, "__init__")
+
+    return names
+
+
+def generate_class(
+    cls: type, name: str, stub: Optional[ast.ClassDef]
+) -> ast.ClassDef:
+    """Generate the stub of a class.
+
+    Args:
+        cls (type): Class exposed by the extension.
+        name (str): Name of the class in the stub.
+        stub (Optional[ast.ClassDef]): Existing stub of the class.
+
+    Returns:
+        ast.ClassDef: The stub of the class.
+    """
+    generated = ast.parse(f"class {name}: ...").body[0]
+    assert isinstance(generated, ast.ClassDef)
+
+    stub_members = group_stub_members(stub.body) if stub else {}
+
+    names = list(stub_members)
+    for member in runtime_member_names(cls):
+        if member in names or (member == "__init__" and "__new__" in names):
+            continue
+
+        if member == "__init__":
+            names.insert(0, member)
+        else:
+            names.append(member)
+
+    body = [
+        statement
+        for member in names
+        for statement in generate_member(cls, member, stub_members.get(member, []))
+    ]
+
+    generated.body = body or [ast.Expr(value=ast.Constant(value=...))]
+    generated.bases = (
+        stub.bases
+        if stub is not None
+        else [
+            ast.Name(id=base.__name__, ctx=ast.Load())
+            for base in cls.__bases__
+            if base is not object
+        ]
+    )
+
+    return generated
+
+
+def generate_module(module: ModuleType, stub: ast.Module) -> ast.Module:
+    """Generate the stub of a submodule of the extension.
+
+    Args:
+        module (ModuleType): Submodule exposed by the extension.
+        stub (ast.Module): Existing stub of the submodule.
+
+    Returns:
+        ast.Module: The stub of the submodule.
+    """
+    body: List[ast.stmt] = []
+    declared: Set[str] = set()
+
+    for statement in stub.body:
+        name = stub_member_name(statement)
+
+        if isinstance(statement, ast.ClassDef):
+            runtime = getattr(module, statement.name, None)
+
+            if isinstance(runtime, type):
+                body.append(generate_class(runtime, statement.name, statement))
+        else:
+            body.append(statement)
+
+        if name is not None:
+            declared.add(name)
+
+    for name, value in vars(module).items():
+        if name.startswith("_") or name in declared:
+            continue
+
+        if isinstance(value, type):
+            body.append(generate_class(value, name, None))
+        elif not isinstance(value, ModuleType):
+            body.append(ast.parse(f"{name}: {type(value).__name__}").body[0])
+
+    generated = ast.Module(body=body, type_ignores=[])
+
+    if uses_any(generated) and not imports_any(generated):
+        generated.body.insert(0, ast.parse("from typing import Any").body[0])
+
+    return generated
+
+
+def uses_any(module: ast.Module) -> bool:
+    """Check whether a stub references `Any`.
+
+    Args:
+        module (ast.Module): Stub module.
+
+    Returns:
+        bool: True if `Any` is referenced anywhere in the stub.
+    """
+    return any(
+        isinstance(node, ast.Name) and node.id == "Any" for node in ast.walk(module)
+    )
+
+
+def imports_any(module: ast.Module) -> bool:
+    """Check whether a stub imports `Any` from `typing`.
+
+    Args:
+        module (ast.Module): Stub module.
+
+    Returns:
+        bool: True if the stub imports `typing.Any`.
+    """
+    return any(
+        isinstance(statement, ast.ImportFrom)
+        and statement.module == "typing"
+        and any(alias.name == "Any" for alias in statement.names)
+        for statement in module.body
+    )
+
+
+def trailing_comments(source: str) -> Dict[str, str]:
+    """Collect the trailing comments of a stub, e.g. `noqa` directives.
+
+    Args:
+        source (str): Source of the stub.
+
+    Returns:
+        Dict[str, str]: The comment of every commented line, keyed by the
+            stripped code of the line.
+    """
+    comments: Dict[str, str] = {}
+
+    for line in source.splitlines():
+        code, separator, comment = line.partition("  # ")
+
+        if separator and code.strip():
+            comments[code.strip()] = comment
+
+    return comments
+
+
+def format_stub(source: str, path: Path) -> str:
+    """Sort the imports of a stub and format it with ruff.
+
+    Args:
+        source (str): Source of the stub.
+        path (Path): Path of the stub, used to apply the stub rules of ruff.
+
+    Returns:
+        str: The formatted source.
+
+    Raises:
+        RuntimeError: If ruff is not installed.
+    """
+    ruff = shutil.which("ruff")
+
+    if ruff is None:
+        msg = "ruff is required to format the generated stubs"
+        raise RuntimeError(msg)
+
+    sorted_source = subprocess.run(
+        [
+            ruff,
+            "check",
+            "--select",
+            "I",
+            "--fix",
+            "--exit-zero",
+            "--quiet",
+            "--stdin-filename",
+            str(path),
+            "-",
+        ],
+        input=source,
+        capture_output=True,
+        text=True,
+        check=True,
+    ).stdout
+
+    return subprocess.run(
+        [ruff, "format", "--quiet", "--stdin-filename", str(path), "-"],
+        input=sorted_source,
+        capture_output=True,
+        text=True,
+        check=True,
+    ).stdout
+
+
+def render_stub(module: ModuleType, path: Path) -> str:
+    """Render the stub of a submodule from its runtime definitions.
+
+    Args:
+        module (ModuleType): Submodule exposed by the extension.
+        path (Path): Path of the existing stub of the submodule.
+
+    Returns:
+        str: The source of the generated stub.
+    """
+    existing = path.read_text(encoding="utf-8") if path.exists() else ""
+
+    generated = ast.fix_missing_locations(
+        generate_module(module, ast.parse(existing, filename=str(path)))
+    )
+    source = format_stub(ast.unparse(generated) + "\n", path)
+
+    comments = trailing_comments(existing)
+    if not comments:
+        return source
+
+    lines = [
+        f"{line}  # {comments[line.strip()]}" if line.strip() in comments else line
+        for line in source.splitlines()
+    ]
+
+    return format_stub("\n".join(lines) + "\n", path)
+
+
+def main() -> None:
+    """Write the stubs, or check that the existing stubs are up to date."""
+    parser = argparse.ArgumentParser(
+        description="Generate the stubs of the graphrecords extension."
+    )
+    parser.add_argument(
+        "--check",
+        action="store_true",
+        help="fail instead of writing if the stubs are out of date",
+    )
+    arguments = parser.parse_args()
+
+    outdated: List[Path] = []
+
+    for name, module in vars(extension).items():
+        if not isinstance(module, ModuleType):
+            continue
+
+        path = STUBS_ROOT / f"{name}.pyi"
+        source = render_stub(module, path)
+
+        if path.exists() and path.read_text(encoding="utf-8") == source:
+            continue
+
+        outdated.append(path)
+
+        if not arguments.check:
+            path.write_text(source, encoding="utf-8")
+            logger.info("Updated %s", path.relative_to(PROJECT_ROOT))
+
+    if arguments.check and outdated:
+        for path in outdated:
+            logger.error("Out of date: %s", path.relative_to(PROJECT_ROOT))
+
+        logger.error("Run `make stubs` to update the stubs")
+        sys.exit(1)
+
+
+if __name__ == "__main__":
+    main()
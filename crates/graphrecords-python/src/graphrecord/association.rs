@@ -0,0 +1,27 @@
+use graphrecords_core::graphrecord::association::CorrelationMethod;
+use pyo3::pyclass;
+
+#[pyclass(frozen, eq, eq_int)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PyCorrelationMethod {
+    Pearson = 0,
+    Spearman = 1,
+}
+
+impl From<CorrelationMethod> for PyCorrelationMethod {
+    fn from(value: CorrelationMethod) -> Self {
+        match value {
+            CorrelationMethod::Pearson => Self::Pearson,
+            CorrelationMethod::Spearman => Self::Spearman,
+        }
+    }
+}
+
+impl From<PyCorrelationMethod> for CorrelationMethod {
+    fn from(value: PyCorrelationMethod) -> Self {
+        match value {
+            PyCorrelationMethod::Pearson => Self::Pearson,
+            PyCorrelationMethod::Spearman => Self::Spearman,
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use super::PyGroup;
+use graphrecords_core::graphrecord::attribute_scope::AttributeScope;
+use pyo3::pyclass;
+
+#[pyclass(frozen, eq, eq_int)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PyAttributeScope {
+    All = 0,
+    Nodes = 1,
+    Edges = 2,
+}
+
+impl PyAttributeScope {
+    pub(crate) fn with_group(self, group: Option<PyGroup>) -> AttributeScope {
+        match (self, group) {
+            (_, Some(group)) => AttributeScope::Group(group.into()),
+            (Self::All, None) => AttributeScope::All,
+            (Self::Nodes, None) => AttributeScope::Nodes,
+            (Self::Edges, None) => AttributeScope::Edges,
+        }
+    }
+}
@@ -67,6 +67,14 @@ impl BorrowedGraphRecord {
         self.ptr.read()
     }
 
+    pub(super) fn try_read(&self) -> Option<RwLockReadGuard<'_, Option<NonNull<GraphRecord>>>> {
+        self.ptr.try_read()
+    }
+
+    pub(super) fn try_write(&self) -> Option<RwLockWriteGuard<'_, Option<NonNull<GraphRecord>>>> {
+        self.ptr.try_write()
+    }
+
     pub(super) fn write(&self) -> RwLockWriteGuard<'_, Option<NonNull<GraphRecord>>> {
         self.ptr.write()
     }
@@ -108,7 +116,10 @@ macro_rules! impl_scope {
                     let py_graphrecord = self.1.bind(self.0).get();
                     match &py_graphrecord.inner {
                         PyGraphRecordInner::Borrowed(borrowed) => {
-                            let mut guard = borrowed.write();
+                            let mut guard = super::lock_detached(
+                                || borrowed.try_write(),
+                                || drop(borrowed.write()),
+                            );
                             assert_eq!(
                                 guard.take(),
                                 Some(self.2),
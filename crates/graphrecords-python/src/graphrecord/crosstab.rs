@@ -0,0 +1,30 @@
+use graphrecords_core::graphrecord::crosstab::CrosstabNormalization;
+use pyo3::pyclass;
+
+#[pyclass(frozen, eq, eq_int)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PyCrosstabNormalization {
+    All = 0,
+    Rows = 1,
+    Columns = 2,
+}
+
+impl From<CrosstabNormalization> for PyCrosstabNormalization {
+    fn from(value: CrosstabNormalization) -> Self {
+        match value {
+            CrosstabNormalization::All => Self::All,
+            CrosstabNormalization::Rows => Self::Rows,
+            CrosstabNormalization::Columns => Self::Columns,
+        }
+    }
+}
+
+impl From<PyCrosstabNormalization> for CrosstabNormalization {
+    fn from(value: PyCrosstabNormalization) -> Self {
+        match value {
+            PyCrosstabNormalization::All => Self::All,
+            PyCrosstabNormalization::Rows => Self::Rows,
+            PyCrosstabNormalization::Columns => Self::Columns,
+        }
+    }
+}
@@ -73,6 +73,10 @@ pub(crate) fn convert_pyobject_to_datatype(ob: &Bound<'_, pyo3::PyAny>) -> PyRes
         Ok(DataType::Duration)
     }
 
+    const fn convert_geo(_ob: &Bound<'_, pyo3::PyAny>) -> PyResult<DataType> {
+        Ok(DataType::GeoPoint)
+    }
+
     const fn convert_null(_ob: &Bound<'_, pyo3::PyAny>) -> PyResult<DataType> {
         Ok(DataType::Null)
     }
@@ -81,6 +85,10 @@ pub(crate) fn convert_pyobject_to_datatype(ob: &Bound<'_, pyo3::PyAny>) -> PyRes
         Ok(DataType::Any)
     }
 
+    const fn convert_missing(_ob: &Bound<'_, pyo3::PyAny>) -> PyResult<DataType> {
+        Ok(DataType::Missing)
+    }
+
     fn convert_union(ob: &Bound<'_, pyo3::PyAny>) -> PyResult<DataType> {
         let union = ob
             .extract::<PyRef<PyUnion>>()
@@ -126,6 +134,8 @@ pub(crate) fn convert_pyobject_to_datatype(ob: &Bound<'_, pyo3::PyAny>) -> PyRes
             convert_datetime
         } else if ob.is_instance_of::<PyDuration>() {
             convert_duration
+        } else if ob.is_instance_of::<PyGeo>() {
+            convert_geo
         } else if ob.is_instance_of::<PyNull>() {
             convert_null
         } else if ob.is_instance_of::<PyAny>() {
@@ -134,6 +144,8 @@ pub(crate) fn convert_pyobject_to_datatype(ob: &Bound<'_, pyo3::PyAny>) -> PyRes
             convert_union
         } else if ob.is_instance_of::<PyOption>() {
             convert_option
+        } else if ob.is_instance_of::<PyMissingType>() {
+            convert_missing
         } else {
             throw_error
         }
@@ -163,12 +175,14 @@ impl<'py> IntoPyObject<'py> for PyDataType {
             DataType::Bool => PyBool {}.into_bound_py_any(py),
             DataType::DateTime => PyDateTime {}.into_bound_py_any(py),
             DataType::Duration => PyDuration {}.into_bound_py_any(py),
+            DataType::GeoPoint => PyGeo {}.into_bound_py_any(py),
             DataType::Null => PyNull {}.into_bound_py_any(py),
             DataType::Any => PyAny {}.into_bound_py_any(py),
             DataType::Union((dtype1, dtype2)) => {
                 PyUnion(((*dtype1).into(), (*dtype2).into())).into_bound_py_any(py)
             }
             DataType::Option(dtype) => PyOption((*dtype).into()).into_bound_py_any(py),
+            DataType::Missing => PyMissingType {}.into_bound_py_any(py),
         }
     }
 }
@@ -197,6 +211,10 @@ implement_pymethods!(PyDateTime);
 pub struct PyDuration;
 implement_pymethods!(PyDuration);
 
+#[pyclass(frozen)]
+pub struct PyGeo;
+implement_pymethods!(PyGeo);
+
 #[pyclass(frozen)]
 pub struct PyNull;
 implement_pymethods!(PyNull);
@@ -205,6 +223,10 @@ implement_pymethods!(PyNull);
 pub struct PyAny;
 implement_pymethods!(PyAny);
 
+#[pyclass(frozen)]
+pub struct PyMissingType;
+implement_pymethods!(PyMissingType);
+
 #[pyclass(frozen)]
 pub struct PyUnion((PyDataType, PyDataType));
 
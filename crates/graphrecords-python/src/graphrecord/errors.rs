@@ -1,35 +1,123 @@
-use graphrecords_core::errors::{GraphError, GraphRecordError};
+use graphrecords_core::errors::{GraphError, GraphRecordError as CoreGraphRecordError};
 use pyo3::{
-    PyErr,
-    exceptions::{PyAssertionError, PyIndexError, PyKeyError, PyRuntimeError, PyValueError},
+    Bound, Py, PyErr, Python, create_exception,
+    exceptions::{
+        PyAssertionError, PyException, PyIndexError, PyKeyError, PyRuntimeError, PyValueError,
+    },
+    ffi,
+    sync::PyOnceLock,
+    types::{PyAnyMethods, PyDict, PyDictMethods, PyType},
 };
 
+const MODULE: &str = "graphrecords._graphrecords.errors";
+
+create_exception!(
+    graphrecords._graphrecords.errors,
+    GraphRecordError,
+    PyException
+);
+
+/// Builds an exception type deriving from both `GraphRecordError` and `builtin`,
+/// so callers can catch every library error at once while `except` clauses
+/// on the built-in exception keep working.
+fn new_exception_type(py: Python<'_>, name: &str, builtin: &Bound<'_, PyType>) -> Py<PyType> {
+    let bases = (py.get_type::<GraphRecordError>(), builtin);
+    let dict = PyDict::new(py);
+    dict.set_item("__module__", MODULE)
+        .expect("Module must be settable");
+
+    py.get_type::<PyType>()
+        .call1((name, bases, dict))
+        .and_then(|exception_type| Ok(exception_type.cast_into::<PyType>()?))
+        .expect("Failed to initialize new exception type.")
+        .unbind()
+}
+
+/// Like `create_exception!`, but the exception also derives from
+/// `GraphRecordError`.
+macro_rules! create_graphrecord_exception {
+    ($name: ident, $builtin: ty) => {
+        #[repr(transparent)]
+        pub struct $name(pyo3::PyAny);
+
+        pyo3::impl_exception_boilerplate!($name);
+
+        pyo3::pyobject_native_type_core!(
+            $name,
+            $name::type_object_raw,
+            #module=::std::option::Option::Some(MODULE)
+        );
+
+        impl $name {
+            fn type_object_raw(py: Python<'_>) -> *mut ffi::PyTypeObject {
+                static TYPE_OBJECT: PyOnceLock<Py<PyType>> = PyOnceLock::new();
+
+                TYPE_OBJECT
+                    .get_or_init(py, || {
+                        new_exception_type(py, stringify!($name), &py.get_type::<$builtin>())
+                    })
+                    .as_ptr()
+                    .cast()
+            }
+        }
+    };
+}
+
+create_graphrecord_exception!(GraphRecordIndexError, PyIndexError);
+create_graphrecord_exception!(GraphRecordKeyError, PyKeyError);
+create_graphrecord_exception!(ConversionError, PyRuntimeError);
+create_graphrecord_exception!(GraphRecordAssertionError, PyAssertionError);
+create_graphrecord_exception!(SchemaError, PyValueError);
+create_graphrecord_exception!(QueryError, PyRuntimeError);
+create_graphrecord_exception!(ReadOnlyError, PyRuntimeError);
+
+create_exception!(
+    graphrecords._graphrecords.errors,
+    MissingNodeError,
+    GraphRecordIndexError
+);
+create_exception!(
+    graphrecords._graphrecords.errors,
+    DuplicateGroupError,
+    GraphRecordAssertionError
+);
+create_exception!(
+    graphrecords._graphrecords.errors,
+    TypeMismatchError,
+    SchemaError
+);
+
 #[repr(transparent)]
-pub struct PyGraphRecordError(GraphRecordError);
+pub struct PyGraphRecordError(CoreGraphRecordError);
 pub type PyGraphRecordResult<T> = Result<T, PyGraphRecordError>;
 
-impl From<GraphRecordError> for PyGraphRecordError {
-    fn from(error: GraphRecordError) -> Self {
+impl From<CoreGraphRecordError> for PyGraphRecordError {
+    fn from(error: CoreGraphRecordError) -> Self {
         Self(error)
     }
 }
 
 impl From<GraphError> for PyGraphRecordError {
     fn from(error: GraphError) -> Self {
-        Self(GraphRecordError::from(error))
+        Self(CoreGraphRecordError::from(error))
     }
 }
 
 impl From<PyGraphRecordError> for PyErr {
     fn from(error: PyGraphRecordError) -> Self {
+        let message = error.0.message();
+
         match error.0 {
-            GraphRecordError::IndexError(message) => PyIndexError::new_err(message),
-            GraphRecordError::KeyError(message) => PyKeyError::new_err(message),
-            GraphRecordError::ConversionError(message) | GraphRecordError::QueryError(message) => {
-                PyRuntimeError::new_err(message)
-            }
-            GraphRecordError::AssertionError(message) => PyAssertionError::new_err(message),
-            GraphRecordError::SchemaError(message) => PyValueError::new_err(message),
+            CoreGraphRecordError::IndexError(_) => GraphRecordIndexError::new_err(message),
+            CoreGraphRecordError::KeyError(_) => GraphRecordKeyError::new_err(message),
+            CoreGraphRecordError::ConversionError(_) => ConversionError::new_err(message),
+            CoreGraphRecordError::AssertionError(_) => GraphRecordAssertionError::new_err(message),
+            CoreGraphRecordError::SchemaError(_) => SchemaError::new_err(message),
+            CoreGraphRecordError::QueryError(_) => QueryError::new_err(message),
+            CoreGraphRecordError::ReadOnlyError(_) => ReadOnlyError::new_err(message),
+            CoreGraphRecordError::MissingNode { .. } => MissingNodeError::new_err(message),
+            CoreGraphRecordError::DuplicateGroup { .. } => DuplicateGroupError::new_err(message),
+            CoreGraphRecordError::TypeMismatch { .. } => TypeMismatchError::new_err(message),
         }
     }
 }
@@ -0,0 +1,93 @@
+use super::{
+    PyAttributes, PyGraphRecord, PyGroup, PyNodeIndex, errors::PyGraphRecordError, traits::DeepInto,
+};
+use graphrecords_core::graphrecord::ingest::ShardedIngest;
+use pyo3::prelude::*;
+
+#[pyclass(frozen)]
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct PyShardedIngest(ShardedIngest);
+
+impl From<ShardedIngest> for PyShardedIngest {
+    fn from(value: ShardedIngest) -> Self {
+        Self(value)
+    }
+}
+
+#[pymethods]
+impl PyShardedIngest {
+    #[new]
+    #[pyo3(signature = (shard_count=None))]
+    pub fn new(shard_count: Option<usize>) -> Self {
+        shard_count
+            .map_or_else(ShardedIngest::new, ShardedIngest::with_shard_count)
+            .into()
+    }
+
+    #[getter]
+    pub const fn shard_count(&self) -> usize {
+        self.0.shard_count()
+    }
+
+    #[pyo3(signature = (node_index, attributes, group=None))]
+    pub fn add_node(
+        &self,
+        node_index: PyNodeIndex,
+        attributes: PyAttributes,
+        group: Option<PyGroup>,
+    ) {
+        self.0.add_node(
+            node_index.into(),
+            attributes.deep_into(),
+            group.map(Into::into),
+        );
+    }
+
+    #[pyo3(signature = (nodes, group=None))]
+    pub fn add_nodes(&self, nodes: Vec<(PyNodeIndex, PyAttributes)>, group: Option<PyGroup>) {
+        self.0.add_nodes(nodes.deep_into(), group.map(Into::into));
+    }
+
+    #[pyo3(signature = (source_node_index, target_node_index, attributes, group=None))]
+    pub fn add_edge(
+        &self,
+        source_node_index: PyNodeIndex,
+        target_node_index: PyNodeIndex,
+        attributes: PyAttributes,
+        group: Option<PyGroup>,
+    ) {
+        self.0.add_edge(
+            source_node_index.into(),
+            target_node_index.into(),
+            attributes.deep_into(),
+            group.map(Into::into),
+        );
+    }
+
+    #[pyo3(signature = (edges, group=None))]
+    pub fn add_edges(
+        &self,
+        edges: Vec<(PyNodeIndex, PyNodeIndex, PyAttributes)>,
+        group: Option<PyGroup>,
+    ) {
+        self.0.add_edges(edges.deep_into(), group.map(Into::into));
+    }
+
+    pub fn pending_node_count(&self) -> usize {
+        self.0.pending_node_count()
+    }
+
+    pub fn pending_edge_count(&self) -> usize {
+        self.0.pending_edge_count()
+    }
+
+    pub fn commit(&self, graphrecord: &PyGraphRecord) -> PyResult<()> {
+        let mut graphrecord = graphrecord.inner_mut()?;
+
+        Ok(self
+            .0
+            .commit(&mut graphrecord)
+            .map_err(PyGraphRecordError::from)?)
+    }
+}
@@ -0,0 +1,74 @@
+use super::{PyAttributes, traits::DeepInto};
+use chrono::NaiveDateTime;
+use graphrecords_core::graphrecord::metadata::RecordMetadata;
+use pyo3::prelude::*;
+
+#[pyclass(frozen, eq)]
+#[repr(transparent)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PyRecordMetadata(RecordMetadata);
+
+impl From<RecordMetadata> for PyRecordMetadata {
+    fn from(value: RecordMetadata) -> Self {
+        Self(value)
+    }
+}
+
+impl From<PyRecordMetadata> for RecordMetadata {
+    fn from(value: PyRecordMetadata) -> Self {
+        value.0
+    }
+}
+
+#[pymethods]
+impl PyRecordMetadata {
+    #[new]
+    #[pyo3(signature = (title=None, description=None, created_at=None, source_datasets=Vec::new(), properties=PyAttributes::new()))]
+    pub fn new(
+        title: Option<String>,
+        description: Option<String>,
+        created_at: Option<NaiveDateTime>,
+        source_datasets: Vec<String>,
+        properties: PyAttributes,
+    ) -> Self {
+        Self(RecordMetadata {
+            title,
+            description,
+            created_at,
+            source_datasets,
+            properties: properties.deep_into(),
+        })
+    }
+
+    #[getter]
+    pub fn title(&self) -> Option<&str> {
+        self.0.title.as_deref()
+    }
+
+    #[getter]
+    pub fn description(&self) -> Option<&str> {
+        self.0.description.as_deref()
+    }
+
+    #[getter]
+    pub const fn created_at(&self) -> Option<NaiveDateTime> {
+        self.0.created_at
+    }
+
+    #[getter]
+    pub fn source_datasets(&self) -> Vec<String> {
+        self.0.source_datasets.clone()
+    }
+
+    #[getter]
+    pub fn properties(&self) -> PyAttributes {
+        self.0.properties.clone().deep_into()
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "RecordMetadata(title={:?}, description={:?}, created_at={:?}, source_datasets={:?})",
+            self.0.title, self.0.description, self.0.created_at, self.0.source_datasets
+        )
+    }
+}
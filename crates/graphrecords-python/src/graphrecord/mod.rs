@@ -1,12 +1,18 @@
 #![allow(clippy::new_without_default, clippy::significant_drop_tightening)]
 
+pub mod association;
 pub mod attribute;
+pub mod attribute_scope;
 mod borrowed;
 pub mod connector;
+pub mod crosstab;
 pub mod datatype;
 pub mod errors;
+pub mod ingest;
+pub mod metadata;
 pub mod overview;
 pub mod plugins;
+pub mod provenance;
 pub mod querying;
 pub mod schema;
 pub mod traits;
@@ -15,34 +21,49 @@ pub mod value;
 use crate::{
     conversion_lut::ConversionLut,
     graphrecord::{
-        overview::{PyGroupOverview, PyOverview},
-        plugins::PyPlugin,
+        overview::{PyGroupOverview, PyOverview, PyOverviewFormat},
+        plugins::into_plugin,
     },
 };
+use association::PyCorrelationMethod;
 use attribute::PyGraphRecordAttribute;
+use attribute_scope::PyAttributeScope;
 use borrowed::BorrowedGraphRecord;
+use chrono::{NaiveDateTime, TimeDelta};
 use connector::PyConnector;
+use crosstab::PyCrosstabNormalization;
 use errors::PyGraphRecordError;
 use graphrecords_core::{
     errors::GraphRecordError,
     graphrecord::{
         Attributes, EdgeDataFrameInput, EdgeIndex, GraphRecord, GraphRecordAttribute,
-        GraphRecordValue, Group, NodeDataFrameInput, connector::ConnectedGraphRecord,
-        plugins::Plugin,
+        GraphRecordValue, Group, NodeDataFrameInput,
+        anonymization::Generalization,
+        auto_index::AutoIndexFormat,
+        connector::ConnectedGraphRecord,
+        overview::{DEFAULT_TRUNCATE_DETAILS, OverviewFormat},
+        plugins::{DEFAULT_PLUGIN_PRIORITY, PluginPriority},
     },
     prelude::NodeIndex,
 };
+use metadata::PyRecordMetadata;
 use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use provenance::PyProvenanceTag;
 use pyo3::{
     exceptions::PyRuntimeError,
     prelude::*,
     types::{PyBytes, PyDict, PyFunction},
 };
 use pyo3_polars::PyDataFrame;
-use querying::{PyReturnOperand, edges::PyEdgeOperand, nodes::PyNodeOperand};
+use querying::{
+    PyReturnOperand, PyReturnValue,
+    edges::{PyEdgeIndicesOperand, PyEdgeOperand},
+    nodes::{PyEdgeDirection, PyNodeIndicesOperand, PyNodeOperand},
+    values::{PyEdgeMultipleValuesWithIndexOperand, PyNodeMultipleValuesWithIndexOperand},
+};
 use schema::PySchema;
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     ops::{Deref, DerefMut},
     ptr::NonNull,
 };
@@ -54,6 +75,12 @@ pub type PyGroup = PyGraphRecordAttribute;
 pub type PyPluginName = PyGraphRecordAttribute;
 pub type PyNodeIndex = PyGraphRecordAttribute;
 pub type PyEdgeIndex = EdgeIndex;
+type PyCrosstab = (
+    Vec<PyGraphRecordValue>,
+    Vec<PyGraphRecordValue>,
+    Vec<Vec<f64>>,
+);
+type PyGroupAdjacencyCounts = HashMap<(Option<PyGroup>, Option<PyGroup>), usize>;
 type Lut<T> = ConversionLut<usize, fn(&Bound<'_, PyAny>) -> PyResult<T>>;
 
 #[pyclass(frozen)]
@@ -144,10 +171,10 @@ impl Clone for PyGraphRecord {
     fn clone(&self) -> Self {
         match &self.inner {
             PyGraphRecordInner::Owned(lock) => Self {
-                inner: PyGraphRecordInner::Owned(RwLock::new(lock.read().clone())),
+                inner: PyGraphRecordInner::Owned(RwLock::new(read_detached(lock).clone())),
             },
             PyGraphRecordInner::Connected(lock) => Self {
-                inner: PyGraphRecordInner::Connected(RwLock::new(lock.read().clone())),
+                inner: PyGraphRecordInner::Connected(RwLock::new(read_detached(lock).clone())),
             },
             PyGraphRecordInner::Borrowed(_) => Self {
                 inner: PyGraphRecordInner::Borrowed(BorrowedGraphRecord::dead()),
@@ -156,13 +183,34 @@ impl Clone for PyGraphRecord {
     }
 }
 
+/// Acquires a lock guard without blocking while attached to the interpreter.
+/// Methods release the GIL while holding the guard, so a thread that blocks
+/// on the lock with the GIL held keeps the holder from ever returning.
+fn lock_detached<G>(try_lock: impl Fn() -> Option<G>, wait: impl Fn() + Send + Sync) -> G {
+    loop {
+        if let Some(guard) = try_lock() {
+            return guard;
+        }
+
+        Python::attach(|py| py.detach(&wait));
+    }
+}
+
+fn read_detached<T: Send + Sync>(lock: &RwLock<T>) -> RwLockReadGuard<'_, T> {
+    lock_detached(|| lock.try_read(), || drop(lock.read()))
+}
+
+fn write_detached<T: Send + Sync>(lock: &RwLock<T>) -> RwLockWriteGuard<'_, T> {
+    lock_detached(|| lock.try_write(), || drop(lock.write()))
+}
+
 impl PyGraphRecord {
     pub(crate) fn inner(&self) -> PyResult<InnerRef<'_>> {
         match &self.inner {
-            PyGraphRecordInner::Owned(lock) => Ok(InnerRef::Owned(lock.read())),
-            PyGraphRecordInner::Connected(lock) => Ok(InnerRef::Connected(lock.read())),
+            PyGraphRecordInner::Owned(lock) => Ok(InnerRef::Owned(read_detached(lock))),
+            PyGraphRecordInner::Connected(lock) => Ok(InnerRef::Connected(read_detached(lock))),
             PyGraphRecordInner::Borrowed(borrowed) => {
-                let guard = borrowed.read();
+                let guard = lock_detached(|| borrowed.try_read(), || drop(borrowed.read()));
                 if guard.is_some() {
                     Ok(InnerRef::Borrowed(guard))
                 } else {
@@ -178,7 +226,7 @@ impl PyGraphRecord {
         &self,
     ) -> PyResult<RwLockWriteGuard<'_, ConnectedGraphRecord<PyConnector>>> {
         match &self.inner {
-            PyGraphRecordInner::Connected(lock) => Ok(lock.write()),
+            PyGraphRecordInner::Connected(lock) => Ok(write_detached(lock)),
             _ => Err(PyRuntimeError::new_err(
                 "GraphRecord has no connector attached",
             )),
@@ -187,13 +235,13 @@ impl PyGraphRecord {
 
     pub(crate) fn inner_mut(&self) -> PyResult<InnerRefMut<'_>> {
         match &self.inner {
-            PyGraphRecordInner::Owned(lock) => Ok(InnerRefMut::Owned(lock.write())),
-            PyGraphRecordInner::Connected(lock) => Ok(InnerRefMut::Connected(lock.write())),
+            PyGraphRecordInner::Owned(lock) => Ok(InnerRefMut::Owned(write_detached(lock))),
+            PyGraphRecordInner::Connected(lock) => Ok(InnerRefMut::Connected(write_detached(lock))),
             PyGraphRecordInner::Borrowed(borrowed) => {
                 if !borrowed.is_mutable() {
                     return Err(PyRuntimeError::new_err("GraphRecord is read-only"));
                 }
-                let guard = borrowed.write();
+                let guard = lock_detached(|| borrowed.try_write(), || drop(borrowed.write()));
                 if guard.is_some() {
                     Ok(InnerRefMut::Borrowed(guard))
                 } else {
@@ -244,21 +292,22 @@ impl PyGraphRecord {
     }
 
     pub fn _to_bytes<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
-        let bytes = bincode::serialize(&*self.inner()?)
-            .map_err(|_| {
-                GraphRecordError::ConversionError("Could not serialize GraphRecord".into())
-            })
+        let graphrecord = self.inner()?;
+        let graphrecord: &GraphRecord = &graphrecord;
+
+        let bytes = py
+            .detach(|| graphrecord.to_bytes())
             .map_err(PyGraphRecordError::from)?;
 
         Ok(PyBytes::new(py, &bytes))
     }
 
     #[staticmethod]
-    pub fn _from_bytes(data: &Bound<'_, PyBytes>) -> PyResult<Self> {
-        let graphrecord: GraphRecord = bincode::deserialize(data.as_bytes())
-            .map_err(|_| {
-                GraphRecordError::ConversionError("Could not deserialize GraphRecord".into())
-            })
+    pub fn _from_bytes(py: Python<'_>, data: &Bound<'_, PyBytes>) -> PyResult<Self> {
+        let data = data.as_bytes();
+
+        let graphrecord = py
+            .detach(|| GraphRecord::from_bytes(data))
             .map_err(PyGraphRecordError::from)?;
 
         Ok(graphrecord.into())
@@ -270,18 +319,16 @@ impl PyGraphRecord {
     }
 
     #[staticmethod]
-    pub fn with_plugins(plugins: HashMap<PyPluginName, Py<PyAny>>) -> PyResult<Self> {
+    pub fn with_plugins(
+        py: Python<'_>,
+        plugins: Vec<(PyPluginName, Py<PyAny>, PluginPriority)>,
+    ) -> PyResult<Self> {
         let plugins = plugins
             .into_iter()
-            .map(|(name, plugin)| {
-                (
-                    name.into(),
-                    Box::new(PyPlugin::new(plugin)) as Box<dyn Plugin>,
-                )
-            })
-            .collect();
+            .map(|(name, plugin, priority)| (name.into(), into_plugin(py, plugin), priority));
 
-        let graphrecord = GraphRecord::with_plugins(plugins).map_err(PyGraphRecordError::from)?;
+        let graphrecord =
+            GraphRecord::with_prioritized_plugins(plugins).map_err(PyGraphRecordError::from)?;
 
         Ok(graphrecord.into())
     }
@@ -303,32 +350,34 @@ impl PyGraphRecord {
     #[staticmethod]
     #[pyo3(signature = (nodes_dataframes, edges_dataframes, schema=None))]
     pub fn from_dataframes(
+        py: Python<'_>,
         nodes_dataframes: Vec<(PyDataFrame, String)>,
         edges_dataframes: Vec<(PyDataFrame, String, String)>,
         schema: Option<PySchema>,
     ) -> PyResult<Self> {
-        Ok(
-            GraphRecord::from_dataframes(
-                nodes_dataframes,
-                edges_dataframes,
-                schema.map(Into::into),
-            )
+        Ok(py
+            .detach(|| {
+                GraphRecord::from_dataframes(
+                    nodes_dataframes,
+                    edges_dataframes,
+                    schema.map(Into::into),
+                )
+            })
             .map_err(PyGraphRecordError::from)?
-            .into(),
-        )
+            .into())
     }
 
     #[staticmethod]
     #[pyo3(signature = (nodes_dataframes, schema=None))]
     pub fn from_nodes_dataframes(
+        py: Python<'_>,
         nodes_dataframes: Vec<(PyDataFrame, String)>,
         schema: Option<PySchema>,
     ) -> PyResult<Self> {
-        Ok(
-            GraphRecord::from_nodes_dataframes(nodes_dataframes, schema.map(Into::into))
-                .map_err(PyGraphRecordError::from)?
-                .into(),
-        )
+        Ok(py
+            .detach(|| GraphRecord::from_nodes_dataframes(nodes_dataframes, schema.map(Into::into)))
+            .map_err(PyGraphRecordError::from)?
+            .into())
     }
 
     #[staticmethod]
@@ -354,16 +403,27 @@ impl PyGraphRecord {
     }
 
     #[allow(clippy::missing_panics_doc, reason = "infallible")]
-    pub fn to_dataframes(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
-        let export = self
-            .inner()?
-            .to_dataframes()
-            .map_err(PyGraphRecordError::from)?;
+    #[pyo3(signature = (sort=false))]
+    pub fn to_dataframes(&self, py: Python<'_>, sort: bool) -> PyResult<Py<PyAny>> {
+        let graphrecord = self.inner()?;
+
+        let export = if sort {
+            graphrecord.to_dataframes_sorted()
+        } else {
+            graphrecord.to_dataframes()
+        }
+        .map_err(PyGraphRecordError::from)?;
+
+        let mut groups: Vec<_> = export.groups.into_iter().collect();
+
+        if sort {
+            groups.sort_unstable_by(|(a, _), (b, _)| a.total_cmp(b));
+        }
 
         let outer_dict = PyDict::new(py);
         let inner_dict = PyDict::new(py);
 
-        for (group, group_export) in export.groups {
+        for (group, group_export) in groups {
             let group_dict = PyDict::new(py);
 
             let nodes_df = PyDataFrame(group_export.nodes);
@@ -431,11 +491,18 @@ impl PyGraphRecord {
         Ok(data)
     }
 
-    pub fn add_plugin(&self, name: PyPluginName, plugin: Py<PyAny>) -> PyResult<()> {
+    #[pyo3(signature = (name, plugin, priority=DEFAULT_PLUGIN_PRIORITY))]
+    pub fn add_plugin(
+        &self,
+        py: Python<'_>,
+        name: PyPluginName,
+        plugin: Py<PyAny>,
+        priority: PluginPriority,
+    ) -> PyResult<()> {
         let mut graphrecord = self.inner_mut()?;
 
         graphrecord
-            .add_plugin(name.into(), Box::new(PyPlugin::new(plugin)))
+            .add_plugin_with_priority(name.into(), into_plugin(py, plugin), priority)
             .map_err(PyGraphRecordError::from)?;
 
         Ok(())
@@ -451,6 +518,55 @@ impl PyGraphRecord {
         Ok(())
     }
 
+    #[pyo3(signature = (plugin, priority=DEFAULT_PLUGIN_PRIORITY))]
+    pub fn register_plugin(
+        &self,
+        py: Python<'_>,
+        plugin: Py<PyAny>,
+        priority: PluginPriority,
+    ) -> PyResult<u64> {
+        let mut graphrecord = self.inner_mut()?;
+
+        let handle = graphrecord
+            .register_plugin_with_priority(into_plugin(py, plugin), priority)
+            .map_err(PyGraphRecordError::from)?;
+
+        Ok(handle.id())
+    }
+
+    pub fn unregister_plugin(&self, handle: u64) -> PyResult<()> {
+        let mut graphrecord = self.inner_mut()?;
+
+        graphrecord
+            .unregister_plugin(handle.into())
+            .map_err(PyGraphRecordError::from)?;
+
+        Ok(())
+    }
+
+    pub fn plugin_priority(&self, name: PyPluginName) -> PyResult<PluginPriority> {
+        let priority = self
+            .inner()?
+            .plugin_priority(&name.into())
+            .map_err(PyGraphRecordError::from)?;
+
+        Ok(priority)
+    }
+
+    pub fn set_plugin_priority(
+        &self,
+        name: PyPluginName,
+        priority: PluginPriority,
+    ) -> PyResult<()> {
+        let mut graphrecord = self.inner_mut()?;
+
+        graphrecord
+            .set_plugin_priority(&name.into(), priority)
+            .map_err(PyGraphRecordError::from)?;
+
+        Ok(())
+    }
+
     #[getter]
     pub fn plugins(&self) -> PyResult<Vec<PyPluginName>> {
         Ok(self
@@ -461,17 +577,166 @@ impl PyGraphRecord {
             .collect())
     }
 
+    /// Registers a Python callable as UDF. The callable is called with the GIL
+    /// held for every value it is applied to, and each value is converted to
+    /// and from Python, so it is considerably slower than a Rust UDF.
+    pub fn register_udf(&self, name: String, udf: Py<PyAny>) -> PyResult<()> {
+        let mut graphrecord = self.inner_mut()?;
+
+        graphrecord
+            .register_udf(name, move |value| {
+                Python::attach(|py| {
+                    let result = udf
+                        .call1(py, (PyGraphRecordValue::from(value),))
+                        .map_err(|err| GraphRecordError::ConversionError(format!("{err}")))?;
+
+                    Ok(result
+                        .extract::<PyGraphRecordValue>(py)
+                        .map_err(|err| GraphRecordError::ConversionError(format!("{err}")))?
+                        .into())
+                })
+            })
+            .map_err(PyGraphRecordError::from)?;
+
+        Ok(())
+    }
+
+    pub fn remove_udf(&self, name: &str) -> PyResult<()> {
+        let mut graphrecord = self.inner_mut()?;
+
+        graphrecord
+            .remove_udf(name)
+            .map_err(PyGraphRecordError::from)?;
+
+        Ok(())
+    }
+
+    #[getter]
+    pub fn udfs(&self) -> PyResult<Vec<String>> {
+        Ok(self
+            .inner()?
+            .udf_names()
+            .into_iter()
+            .map(String::from)
+            .collect())
+    }
+
+    pub fn create_text_index(
+        &self,
+        group: PyGroup,
+        attribute: PyGraphRecordAttribute,
+    ) -> PyResult<()> {
+        let mut graphrecord = self.inner_mut()?;
+
+        graphrecord
+            .create_text_index(group.into(), attribute.into())
+            .map_err(PyGraphRecordError::from)?;
+
+        Ok(())
+    }
+
+    pub fn drop_text_index(
+        &self,
+        group: PyGroup,
+        attribute: PyGraphRecordAttribute,
+    ) -> PyResult<()> {
+        let mut graphrecord = self.inner_mut()?;
+
+        graphrecord
+            .drop_text_index(&group.into(), &attribute.into())
+            .map_err(PyGraphRecordError::from)?;
+
+        Ok(())
+    }
+
+    #[getter]
+    pub fn text_indices(&self) -> PyResult<Vec<(PyGroup, PyGraphRecordAttribute)>> {
+        Ok(self
+            .inner()?
+            .text_indices()
+            .into_iter()
+            .map(|(group, attribute)| (group.clone().into(), attribute.clone().into()))
+            .collect())
+    }
+
+    pub fn create_geo_index(
+        &self,
+        group: PyGroup,
+        attribute: PyGraphRecordAttribute,
+    ) -> PyResult<()> {
+        let mut graphrecord = self.inner_mut()?;
+
+        graphrecord
+            .create_geo_index(group.into(), attribute.into())
+            .map_err(PyGraphRecordError::from)?;
+
+        Ok(())
+    }
+
+    pub fn drop_geo_index(
+        &self,
+        group: PyGroup,
+        attribute: PyGraphRecordAttribute,
+    ) -> PyResult<()> {
+        let mut graphrecord = self.inner_mut()?;
+
+        graphrecord
+            .drop_geo_index(&group.into(), &attribute.into())
+            .map_err(PyGraphRecordError::from)?;
+
+        Ok(())
+    }
+
+    #[getter]
+    pub fn geo_indices(&self) -> PyResult<Vec<(PyGroup, PyGraphRecordAttribute)>> {
+        Ok(self
+            .inner()?
+            .geo_indices()
+            .into_iter()
+            .map(|(group, attribute)| (group.clone().into(), attribute.clone().into()))
+            .collect())
+    }
+
+    #[getter]
+    pub fn operation_context(&self) -> PyResult<PyAttributes> {
+        Ok(self.inner()?.operation_context().clone().deep_into())
+    }
+
+    pub fn set_operation_context(&self, context: PyAttributes) -> PyResult<PyAttributes> {
+        Ok(self
+            .inner_mut()?
+            .set_operation_context(context.deep_into())
+            .deep_into())
+    }
+
+    pub fn change_token(&self) -> PyResult<u64> {
+        Ok(self.inner()?.change_token().value())
+    }
+
+    pub fn is_modified_since(&self, token: u64) -> PyResult<bool> {
+        Ok(self.inner()?.is_modified_since(token.into()))
+    }
+
     pub fn get_schema(&self) -> PyResult<PySchema> {
         Ok(self.inner()?.get_schema().clone().into())
     }
 
     #[pyo3(signature = (schema, bypass_plugins=false))]
-    pub fn set_schema(&self, schema: PySchema, bypass_plugins: bool) -> PyResult<()> {
+    pub fn set_schema(
+        &self,
+        py: Python<'_>,
+        schema: PySchema,
+        bypass_plugins: bool,
+    ) -> PyResult<()> {
         let mut graphrecord = self.inner_mut()?;
 
         if bypass_plugins {
-            Ok(graphrecord
-                .set_schema_bypass_plugins(schema.into())
+            // Plugins may call back into Python, so the GIL is only released
+            // when they are bypassed.
+            let graphrecord: &mut GraphRecord = &mut graphrecord;
+
+            Ok(py
+                .detach(|| graphrecord.set_schema_bypass_plugins(schema.into()))
                 .map_err(PyGraphRecordError::from)?)
         } else {
             Ok(graphrecord
@@ -510,84 +775,238 @@ impl PyGraphRecord {
         }
     }
 
-    #[getter]
-    pub fn nodes(&self) -> PyResult<Vec<PyNodeIndex>> {
-        Ok(self
-            .inner()?
-            .node_indices()
-            .map(|node_index| node_index.clone().into())
-            .collect())
+    pub fn freeze(&self) -> PyResult<()> {
+        self.inner_mut()?.freeze();
+
+        Ok(())
     }
 
-    pub fn node(
-        &self,
-        node_index: Vec<PyNodeIndex>,
-    ) -> PyResult<HashMap<PyNodeIndex, PyAttributes>> {
-        let graphrecord = self.inner()?;
+    pub fn unfreeze(&self) -> PyResult<()> {
+        self.inner_mut()?.unfreeze();
 
-        node_index
-            .into_iter()
-            .map(|node_index| {
-                let node_attributes = graphrecord
-                    .node_attributes(&node_index)
-                    .map_err(PyGraphRecordError::from)?;
+        Ok(())
+    }
 
-                Ok((node_index, node_attributes.deep_into()))
-            })
-            .collect()
+    pub fn is_frozen(&self) -> PyResult<bool> {
+        Ok(self.inner()?.is_frozen())
     }
 
-    #[getter]
-    pub fn edges(&self) -> PyResult<Vec<EdgeIndex>> {
-        Ok(self.inner()?.edge_indices().copied().collect())
+    pub fn compact(&self) -> PyResult<HashMap<EdgeIndex, EdgeIndex>> {
+        Ok(self
+            .inner_mut()?
+            .compact()
+            .map_err(PyGraphRecordError::from)?)
     }
 
-    pub fn edge(&self, edge_index: Vec<EdgeIndex>) -> PyResult<HashMap<EdgeIndex, PyAttributes>> {
-        let graphrecord = self.inner()?;
+    pub fn set_recycle_edge_indices(&self, recycle_edge_indices: bool) -> PyResult<()> {
+        Ok(self
+            .inner_mut()?
+            .set_recycle_edge_indices(recycle_edge_indices)
+            .map_err(PyGraphRecordError::from)?)
+    }
 
-        edge_index
-            .into_iter()
-            .map(|edge_index| {
-                let edge_attributes = graphrecord
-                    .edge_attributes(&edge_index)
-                    .map_err(PyGraphRecordError::from)?;
+    pub fn recycle_edge_indices(&self) -> PyResult<bool> {
+        Ok(self.inner()?.recycle_edge_indices())
+    }
 
-                Ok((edge_index, edge_attributes.deep_into()))
-            })
-            .collect()
+    pub fn set_strict_queries(&self, strict_queries: bool) -> PyResult<()> {
+        self.inner_mut()?.set_strict_queries(strict_queries);
+
+        Ok(())
     }
 
-    #[getter]
-    pub fn groups(&self) -> PyResult<Vec<PyGroup>> {
+    pub fn strict_queries(&self) -> PyResult<bool> {
+        Ok(self.inner()?.strict_queries())
+    }
+
+    pub fn set_float_tolerance(&self, tolerance: f64) -> PyResult<()> {
         Ok(self
-            .inner()?
-            .groups()
-            .map(|group| group.clone().into())
-            .collect())
+            .inner_mut()?
+            .set_float_tolerance(tolerance)
+            .map_err(PyGraphRecordError::from)?)
     }
 
-    pub fn outgoing_edges(
-        &self,
-        node_index: Vec<PyNodeIndex>,
-    ) -> PyResult<HashMap<PyNodeIndex, Vec<EdgeIndex>>> {
-        let graphrecord = self.inner()?;
+    pub fn float_tolerance(&self) -> PyResult<f64> {
+        Ok(self.inner()?.float_tolerance())
+    }
 
-        node_index
-            .into_iter()
-            .map(|node_index| {
-                let edges = graphrecord
-                    .outgoing_edges(&node_index)
-                    .map_err(PyGraphRecordError::from)?
-                    .copied()
-                    .collect();
+    #[pyo3(signature = (seed=None))]
+    pub fn set_random_seed(&self, seed: Option<u64>) -> PyResult<()> {
+        self.inner_mut()?.set_random_seed(seed);
 
-                Ok((node_index, edges))
-            })
-            .collect()
+        Ok(())
     }
 
-    pub fn incoming_edges(
-        &self,
+    pub fn random_seed(&self) -> PyResult<Option<u64>> {
+        Ok(self.inner()?.random_seed())
+    }
+
+    pub fn metadata(&self) -> PyResult<PyRecordMetadata> {
+        Ok(self.inner()?.metadata().clone().into())
+    }
+
+    pub fn set_metadata(&self, metadata: PyRecordMetadata) -> PyResult<PyRecordMetadata> {
+        Ok(self
+            .inner_mut()?
+            .set_metadata(metadata.into())
+            .map_err(PyGraphRecordError::from)?
+            .into())
+    }
+
+    pub fn enable_provenance(&self) -> PyResult<()> {
+        Ok(self
+            .inner_mut()?
+            .enable_provenance()
+            .map_err(PyGraphRecordError::from)?)
+    }
+
+    pub fn disable_provenance(&self) -> PyResult<()> {
+        Ok(self
+            .inner_mut()?
+            .disable_provenance()
+            .map_err(PyGraphRecordError::from)?)
+    }
+
+    pub fn provenance_enabled(&self) -> PyResult<bool> {
+        Ok(self.inner()?.provenance_enabled())
+    }
+
+    #[pyo3(signature = (tag=None))]
+    pub fn set_provenance_tag(
+        &self,
+        tag: Option<PyProvenanceTag>,
+    ) -> PyResult<Option<PyProvenanceTag>> {
+        Ok(self
+            .inner_mut()?
+            .set_provenance_tag(tag.map(Into::into))
+            .map_err(PyGraphRecordError::from)?
+            .map(Into::into))
+    }
+
+    pub fn node_attribute_provenance(
+        &self,
+        node_index: PyNodeIndex,
+        attribute: PyGraphRecordAttribute,
+    ) -> PyResult<Option<PyProvenanceTag>> {
+        Ok(self
+            .inner()?
+            .node_attribute_provenance(&node_index.into(), &attribute.into())
+            .map_err(PyGraphRecordError::from)?
+            .cloned()
+            .map(Into::into))
+    }
+
+    pub fn edge_attribute_provenance(
+        &self,
+        edge_index: PyEdgeIndex,
+        attribute: PyGraphRecordAttribute,
+    ) -> PyResult<Option<PyProvenanceTag>> {
+        Ok(self
+            .inner()?
+            .edge_attribute_provenance(&edge_index, &attribute.into())
+            .map_err(PyGraphRecordError::from)?
+            .cloned()
+            .map(Into::into))
+    }
+
+    #[getter]
+    pub fn nodes(&self) -> PyResult<Vec<PyNodeIndex>> {
+        Ok(self
+            .inner()?
+            .node_indices()
+            .map(|node_index| node_index.clone().into())
+            .collect())
+    }
+
+    pub fn node(
+        &self,
+        node_index: Vec<PyNodeIndex>,
+    ) -> PyResult<HashMap<PyNodeIndex, PyAttributes>> {
+        let graphrecord = self.inner()?;
+
+        node_index
+            .into_iter()
+            .map(|node_index| {
+                let node_attributes = graphrecord
+                    .node_attributes(&node_index)
+                    .map_err(PyGraphRecordError::from)?;
+
+                Ok((node_index, node_attributes.deep_into()))
+            })
+            .collect()
+    }
+
+    #[getter]
+    pub fn edges(&self) -> PyResult<Vec<EdgeIndex>> {
+        Ok(self.inner()?.edge_indices().copied().collect())
+    }
+
+    pub fn nodes_sorted(&self) -> PyResult<Vec<PyNodeIndex>> {
+        Ok(self
+            .inner()?
+            .node_indices_sorted()
+            .map(|node_index| node_index.clone().into())
+            .collect())
+    }
+
+    pub fn edges_sorted(&self) -> PyResult<Vec<EdgeIndex>> {
+        Ok(self.inner()?.edge_indices_sorted().copied().collect())
+    }
+
+    pub fn groups_sorted(&self) -> PyResult<Vec<PyGroup>> {
+        Ok(self
+            .inner()?
+            .groups_sorted()
+            .map(|group| group.clone().into())
+            .collect())
+    }
+
+    pub fn edge(&self, edge_index: Vec<EdgeIndex>) -> PyResult<HashMap<EdgeIndex, PyAttributes>> {
+        let graphrecord = self.inner()?;
+
+        edge_index
+            .into_iter()
+            .map(|edge_index| {
+                let edge_attributes = graphrecord
+                    .edge_attributes(&edge_index)
+                    .map_err(PyGraphRecordError::from)?;
+
+                Ok((edge_index, edge_attributes.deep_into()))
+            })
+            .collect()
+    }
+
+    #[getter]
+    pub fn groups(&self) -> PyResult<Vec<PyGroup>> {
+        Ok(self
+            .inner()?
+            .groups()
+            .map(|group| group.clone().into())
+            .collect())
+    }
+
+    pub fn outgoing_edges(
+        &self,
+        node_index: Vec<PyNodeIndex>,
+    ) -> PyResult<HashMap<PyNodeIndex, Vec<EdgeIndex>>> {
+        let graphrecord = self.inner()?;
+
+        node_index
+            .into_iter()
+            .map(|node_index| {
+                let edges = graphrecord
+                    .outgoing_edges(&node_index)
+                    .map_err(PyGraphRecordError::from)?
+                    .copied()
+                    .collect();
+
+                Ok((node_index, edges))
+            })
+            .collect()
+    }
+
+    pub fn incoming_edges(
+        &self,
         node_index: Vec<PyNodeIndex>,
     ) -> PyResult<HashMap<PyNodeIndex, Vec<EdgeIndex>>> {
         let graphrecord = self.inner()?;
@@ -630,6 +1049,40 @@ impl PyGraphRecord {
             .collect()
     }
 
+    pub fn set_edge_undirected(
+        &self,
+        edge_index: Vec<EdgeIndex>,
+        undirected: bool,
+    ) -> PyResult<()> {
+        let mut graphrecord = self.inner_mut()?;
+
+        for edge_index in edge_index {
+            graphrecord
+                .set_edge_undirected(&edge_index, undirected)
+                .map_err(PyGraphRecordError::from)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn is_edge_undirected(
+        &self,
+        edge_index: Vec<EdgeIndex>,
+    ) -> PyResult<HashMap<EdgeIndex, bool>> {
+        let graphrecord = self.inner()?;
+
+        edge_index
+            .into_iter()
+            .map(|edge_index| {
+                let undirected = graphrecord
+                    .is_edge_undirected(&edge_index)
+                    .map_err(PyGraphRecordError::from)?;
+
+                Ok((edge_index, undirected))
+            })
+            .collect()
+    }
+
     pub fn edges_connecting(
         &self,
         source_node_indices: Vec<PyNodeIndex>,
@@ -666,6 +1119,16 @@ impl PyGraphRecord {
             .collect())
     }
 
+    pub fn induced_edges(&self, node_indices: Vec<PyNodeIndex>) -> PyResult<Vec<EdgeIndex>> {
+        let node_indices: Vec<GraphRecordAttribute> = node_indices.deep_into();
+
+        Ok(self
+            .inner()?
+            .induced_edges(node_indices.iter().collect())
+            .copied()
+            .collect())
+    }
+
     #[pyo3(signature = (node_indices, bypass_plugins=false))]
     pub fn remove_nodes(
         &self,
@@ -784,6 +1247,71 @@ impl PyGraphRecord {
         }
     }
 
+    pub fn add_node_auto(&self, attributes: PyAttributes) -> PyResult<PyNodeIndex> {
+        Ok(self
+            .inner_mut()?
+            .add_node_auto(attributes.deep_into())
+            .map_err(PyGraphRecordError::from)?
+            .into())
+    }
+
+    pub fn add_nodes_auto(&self, attributes: Vec<PyAttributes>) -> PyResult<Vec<PyNodeIndex>> {
+        Ok(self
+            .inner_mut()?
+            .add_nodes_auto(attributes.deep_into())
+            .map_err(PyGraphRecordError::from)?
+            .deep_into())
+    }
+
+    pub fn auto_index_prefix(&self) -> PyResult<Option<String>> {
+        Ok(match self.inner()?.auto_index_format() {
+            AutoIndexFormat::Numeric => None,
+            AutoIndexFormat::Prefixed(prefix) => Some(prefix.clone()),
+        })
+    }
+
+    #[pyo3(signature = (prefix=None))]
+    pub fn set_auto_index_prefix(&self, prefix: Option<String>) -> PyResult<()> {
+        let format = prefix.map_or(AutoIndexFormat::Numeric, AutoIndexFormat::Prefixed);
+
+        Ok(self
+            .inner_mut()?
+            .set_auto_index_format(format)
+            .map_err(PyGraphRecordError::from)?)
+    }
+
+    pub fn add_relation(
+        &self,
+        relation_index: PyNodeIndex,
+        attributes: PyAttributes,
+        members: Vec<(String, PyNodeIndex)>,
+    ) -> PyResult<Vec<EdgeIndex>> {
+        Ok(self
+            .inner_mut()?
+            .add_relation(
+                relation_index.into(),
+                attributes.deep_into(),
+                members
+                    .into_iter()
+                    .map(|(role, node_index)| (role, node_index.into()))
+                    .collect(),
+            )
+            .map_err(PyGraphRecordError::from)?)
+    }
+
+    pub fn relation_members(
+        &self,
+        relation_index: PyNodeIndex,
+    ) -> PyResult<Vec<(PyGraphRecordValue, PyNodeIndex)>> {
+        Ok(self
+            .inner()?
+            .relation_members(&relation_index)
+            .map_err(PyGraphRecordError::from)?
+            .into_iter()
+            .map(|(role, node_index)| (role.clone().into(), node_index.clone().into()))
+            .collect())
+    }
+
     #[pyo3(signature = (nodes, group, bypass_plugins=false))]
     pub fn add_nodes_with_group(
         &self,
@@ -1217,6 +1745,13 @@ impl PyGraphRecord {
         }
     }
 
+    pub fn reserve_group(&self, group: PyGroup, nodes: usize, edges: usize) -> PyResult<()> {
+        Ok(self
+            .inner_mut()?
+            .reserve_group(&group.into(), nodes, edges)
+            .map_err(PyGraphRecordError::from)?)
+    }
+
     #[pyo3(signature = (group, node_index, bypass_plugins=false))]
     pub fn add_nodes_to_group(
         &self,
@@ -1603,64 +2138,633 @@ impl PyGraphRecord {
         Ok(self.inner()?.group_count())
     }
 
-    pub fn contains_node(&self, node_index: PyNodeIndex) -> PyResult<bool> {
-        Ok(self.inner()?.contains_node(&node_index.into()))
-    }
+    pub fn stats(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let stats = self.inner()?.stats();
 
-    pub fn contains_edge(&self, edge_index: EdgeIndex) -> PyResult<bool> {
-        Ok(self.inner()?.contains_edge(&edge_index))
-    }
+        let stats_dict = PyDict::new(py);
 
-    pub fn contains_group(&self, group: PyGroup) -> PyResult<bool> {
-        Ok(self.inner()?.contains_group(&group.into()))
-    }
+        stats_dict.set_item("node_count", stats.node_count)?;
+        stats_dict.set_item("edge_count", stats.edge_count)?;
+        stats_dict.set_item("density", stats.density)?;
 
-    pub fn neighbors_outgoing(
-        &self,
-        node_indices: Vec<PyNodeIndex>,
-    ) -> PyResult<HashMap<PyNodeIndex, Vec<PyNodeIndex>>> {
-        let graphrecord = self.inner()?;
+        match stats.degree {
+            Some(degree) => {
+                let degree_dict = PyDict::new(py);
 
-        node_indices
-            .into_iter()
-            .map(|node_index| {
-                let neighbors = graphrecord
-                    .neighbors_outgoing(&node_index)
-                    .map_err(PyGraphRecordError::from)?
-                    .map(|neighbor| neighbor.clone().into())
-                    .collect();
+                degree_dict.set_item("min", degree.min)?;
+                degree_dict.set_item("mean", degree.mean)?;
+                degree_dict.set_item("max", degree.max)?;
 
-                Ok((node_index, neighbors))
-            })
-            .collect()
-    }
+                stats_dict.set_item("degree", degree_dict)?;
+            }
+            None => stats_dict.set_item("degree", py.None())?,
+        }
 
-    pub fn neighbors_incoming(
-        &self,
-        node_indices: Vec<PyNodeIndex>,
-    ) -> PyResult<HashMap<PyNodeIndex, Vec<PyNodeIndex>>> {
-        let graphrecord = self.inner()?;
+        stats_dict.set_item("connected_components", stats.connected_components)?;
 
-        node_indices
-            .into_iter()
-            .map(|node_index| {
-                let neighbors = graphrecord
-                    .neighbors_incoming(&node_index)
-                    .map_err(PyGraphRecordError::from)?
-                    .map(|neighbor| neighbor.clone().into())
-                    .collect();
+        let groups_dict = PyDict::new(py);
 
-                Ok((node_index, neighbors))
-            })
-            .collect()
+        for (group, group_stats) in stats.groups {
+            let group_dict = PyDict::new(py);
+
+            group_dict.set_item("node_count", group_stats.node_count)?;
+            group_dict.set_item("edge_count", group_stats.edge_count)?;
+
+            groups_dict.set_item(PyGraphRecordAttribute::from(group), group_dict)?;
+        }
+
+        stats_dict.set_item("groups", groups_dict)?;
+
+        Ok(stats_dict.into())
     }
 
-    pub fn neighbors_undirected(
+    pub fn correlation(
         &self,
-        node_indices: Vec<PyNodeIndex>,
-    ) -> PyResult<HashMap<PyNodeIndex, Vec<PyNodeIndex>>> {
-        let graphrecord = self.inner()?;
-
+        py: Python<'_>,
+        group: PyGroup,
+        attribute_a: PyGraphRecordAttribute,
+        attribute_b: PyGraphRecordAttribute,
+        method: PyCorrelationMethod,
+    ) -> PyResult<Py<PyAny>> {
+        let correlation = self
+            .inner()?
+            .correlation(
+                &group.into(),
+                &attribute_a.into(),
+                &attribute_b.into(),
+                method.into(),
+            )
+            .map_err(PyGraphRecordError::from)?;
+
+        let correlation_dict = PyDict::new(py);
+
+        correlation_dict.set_item("coefficient", correlation.coefficient)?;
+        correlation_dict.set_item("p_value", correlation.p_value)?;
+        correlation_dict.set_item("sample_size", correlation.sample_size)?;
+
+        Ok(correlation_dict.into())
+    }
+
+    pub fn chi_square(
+        &self,
+        py: Python<'_>,
+        group: PyGroup,
+        attribute_a: PyGraphRecordAttribute,
+        attribute_b: PyGraphRecordAttribute,
+    ) -> PyResult<Py<PyAny>> {
+        let chi_square = self
+            .inner()?
+            .chi_square(&group.into(), &attribute_a.into(), &attribute_b.into())
+            .map_err(PyGraphRecordError::from)?;
+
+        let chi_square_dict = PyDict::new(py);
+
+        chi_square_dict.set_item("statistic", chi_square.statistic)?;
+        chi_square_dict.set_item("degrees_of_freedom", chi_square.degrees_of_freedom)?;
+        chi_square_dict.set_item("p_value", chi_square.p_value)?;
+        chi_square_dict.set_item("cramers_v", chi_square.cramers_v)?;
+        chi_square_dict.set_item("sample_size", chi_square.sample_size)?;
+
+        Ok(chi_square_dict.into())
+    }
+
+    pub fn propensity_scores(
+        &self,
+        treated: PyGroup,
+        control: PyGroup,
+        covariates: Vec<PyGraphRecordAttribute>,
+    ) -> PyResult<HashMap<PyNodeIndex, f64>> {
+        let covariates: Vec<GraphRecordAttribute> = covariates.deep_into();
+
+        Ok(self
+            .inner()?
+            .propensity_scores(&treated.into(), &control.into(), &covariates)
+            .map_err(PyGraphRecordError::from)?
+            .into_iter()
+            .map(|(node_index, score)| (node_index.into(), score))
+            .collect())
+    }
+
+    #[pyo3(signature = (treated, control, covariates, edge_group, caliper=None))]
+    pub fn match_propensity_scores(
+        &self,
+        treated: PyGroup,
+        control: PyGroup,
+        covariates: Vec<PyGraphRecordAttribute>,
+        edge_group: PyGroup,
+        caliper: Option<f64>,
+    ) -> PyResult<Vec<(PyNodeIndex, PyNodeIndex, EdgeIndex)>> {
+        let covariates: Vec<GraphRecordAttribute> = covariates.deep_into();
+
+        Ok(self
+            .inner_mut()?
+            .match_propensity_scores(
+                &treated.into(),
+                &control.into(),
+                &covariates,
+                edge_group.into(),
+                caliper,
+            )
+            .map_err(PyGraphRecordError::from)?
+            .into_iter()
+            .map(|(treated_node, control_node, edge_index)| {
+                (treated_node.into(), control_node.into(), edge_index)
+            })
+            .collect())
+    }
+
+    pub fn cohort_table(
+        &self,
+        group_a: PyGroup,
+        group_b: PyGroup,
+        attributes: Vec<PyGraphRecordAttribute>,
+    ) -> PyResult<PyDataFrame> {
+        let attributes: Vec<GraphRecordAttribute> = attributes.deep_into();
+
+        let cohort_table = self
+            .inner()?
+            .cohort_table(&group_a.into(), &group_b.into(), &attributes)
+            .map_err(PyGraphRecordError::from)?;
+
+        Ok(PyDataFrame(
+            cohort_table
+                .to_dataframe()
+                .map_err(PyGraphRecordError::from)?,
+        ))
+    }
+
+    #[pyo3(signature = (group, row_attribute, column_attribute, normalization=None))]
+    pub fn crosstab(
+        &self,
+        group: PyGroup,
+        row_attribute: PyGraphRecordAttribute,
+        column_attribute: PyGraphRecordAttribute,
+        normalization: Option<PyCrosstabNormalization>,
+    ) -> PyResult<PyCrosstab> {
+        let crosstab = self
+            .inner()?
+            .crosstab(
+                &group.into(),
+                &row_attribute.into(),
+                &column_attribute.into(),
+                normalization.map(Into::into),
+            )
+            .map_err(PyGraphRecordError::from)?;
+
+        Ok((
+            crosstab.rows.deep_into(),
+            crosstab.columns.deep_into(),
+            crosstab.values,
+        ))
+    }
+
+    pub fn group_adjacency_counts(&self) -> PyResult<PyGroupAdjacencyCounts> {
+        Ok(self
+            .inner()?
+            .group_adjacency_counts()
+            .into_iter()
+            .map(|((source_group, target_group), count)| {
+                (
+                    (source_group.map(Into::into), target_group.map(Into::into)),
+                    count,
+                )
+            })
+            .collect())
+    }
+
+    pub fn time_to_event(
+        &self,
+        py: Python<'_>,
+        cohort_group: PyGroup,
+        index_date_attribute: PyGraphRecordAttribute,
+        event_edge_group: PyGroup,
+        censor_date: NaiveDateTime,
+    ) -> PyResult<Py<PyAny>> {
+        let time_to_event = self
+            .inner()?
+            .time_to_event(
+                &cohort_group.into(),
+                &index_date_attribute.into(),
+                &event_edge_group.into(),
+                censor_date,
+            )
+            .map_err(PyGraphRecordError::from)?;
+
+        let time_to_event_dict = PyDict::new(py);
+
+        for (node_index, node_time_to_event) in time_to_event {
+            let node_dict = PyDict::new(py);
+
+            node_dict.set_item("duration", node_time_to_event.duration)?;
+            node_dict.set_item("event", node_time_to_event.event)?;
+
+            time_to_event_dict.set_item(PyNodeIndex::from(node_index), node_dict)?;
+        }
+
+        Ok(time_to_event_dict.into())
+    }
+
+    #[pyo3(signature = (cohort_group, index_date_attribute, event_edge_group, start, end, washout=None))]
+    pub fn events_in_window(
+        &self,
+        cohort_group: PyGroup,
+        index_date_attribute: PyGraphRecordAttribute,
+        event_edge_group: PyGroup,
+        start: TimeDelta,
+        end: TimeDelta,
+        washout: Option<TimeDelta>,
+    ) -> PyResult<HashMap<PyNodeIndex, bool>> {
+        Ok(self
+            .inner()?
+            .events_in_window(
+                &cohort_group.into(),
+                &index_date_attribute.into(),
+                &event_edge_group.into(),
+                start,
+                end,
+                washout,
+            )
+            .map_err(PyGraphRecordError::from)?
+            .into_iter()
+            .map(|(node_index, event)| (node_index.into(), event))
+            .collect())
+    }
+
+    #[pyo3(signature = (cohort_group, index_date_attribute, event_edge_group, start, end, washout=None))]
+    pub fn event_rate_in_window(
+        &self,
+        cohort_group: PyGroup,
+        index_date_attribute: PyGraphRecordAttribute,
+        event_edge_group: PyGroup,
+        start: TimeDelta,
+        end: TimeDelta,
+        washout: Option<TimeDelta>,
+    ) -> PyResult<(usize, usize, Option<f64>)> {
+        let event_rate = self
+            .inner()?
+            .event_rate_in_window(
+                &cohort_group.into(),
+                &index_date_attribute.into(),
+                &event_edge_group.into(),
+                start,
+                end,
+                washout,
+            )
+            .map_err(PyGraphRecordError::from)?;
+
+        Ok((event_rate.count, event_rate.population, event_rate.rate))
+    }
+
+    pub fn k_anonymity(
+        &self,
+        group: PyGroup,
+        quasi_identifiers: Vec<PyGraphRecordAttribute>,
+    ) -> PyResult<usize> {
+        let quasi_identifiers: Vec<GraphRecordAttribute> = quasi_identifiers.deep_into();
+
+        Ok(self
+            .inner()?
+            .k_anonymity(&group.into(), &quasi_identifiers)
+            .map_err(PyGraphRecordError::from)?)
+    }
+
+    pub fn k_anonymity_violations(
+        &self,
+        group: PyGroup,
+        quasi_identifiers: Vec<PyGraphRecordAttribute>,
+        k: usize,
+    ) -> PyResult<Vec<PyNodeIndex>> {
+        let quasi_identifiers: Vec<GraphRecordAttribute> = quasi_identifiers.deep_into();
+
+        Ok(self
+            .inner()?
+            .k_anonymity_violations(&group.into(), &quasi_identifiers, k)
+            .map_err(PyGraphRecordError::from)?
+            .deep_into())
+    }
+
+    pub fn enforce_k_anonymity(
+        &self,
+        group: PyGroup,
+        quasi_identifiers: Vec<PyGraphRecordAttribute>,
+        k: usize,
+    ) -> PyResult<Vec<PyNodeIndex>> {
+        let quasi_identifiers: Vec<GraphRecordAttribute> = quasi_identifiers.deep_into();
+
+        Ok(self
+            .inner_mut()?
+            .enforce_k_anonymity(&group.into(), &quasi_identifiers, k)
+            .map_err(PyGraphRecordError::from)?
+            .deep_into())
+    }
+
+    pub fn bucket_attribute(
+        &self,
+        group: PyGroup,
+        attribute: PyGraphRecordAttribute,
+        width: i64,
+    ) -> PyResult<()> {
+        Ok(self
+            .inner_mut()?
+            .generalize(
+                &group.into(),
+                &attribute.into(),
+                Generalization::Bucket(width),
+            )
+            .map_err(PyGraphRecordError::from)?)
+    }
+
+    pub fn truncate_attribute(
+        &self,
+        group: PyGroup,
+        attribute: PyGraphRecordAttribute,
+        length: usize,
+    ) -> PyResult<()> {
+        Ok(self
+            .inner_mut()?
+            .generalize(
+                &group.into(),
+                &attribute.into(),
+                Generalization::Truncate(length),
+            )
+            .map_err(PyGraphRecordError::from)?)
+    }
+
+    #[pyo3(signature = (attribute, new_attribute, scope, group=None))]
+    pub fn rename_attribute(
+        &self,
+        attribute: PyGraphRecordAttribute,
+        new_attribute: PyGraphRecordAttribute,
+        scope: PyAttributeScope,
+        group: Option<PyGroup>,
+    ) -> PyResult<()> {
+        Ok(self
+            .inner_mut()?
+            .rename_attribute(
+                &scope.with_group(group),
+                &attribute.into(),
+                new_attribute.into(),
+            )
+            .map_err(PyGraphRecordError::from)?)
+    }
+
+    #[pyo3(signature = (attribute, scope, group=None))]
+    pub fn drop_attribute(
+        &self,
+        attribute: PyGraphRecordAttribute,
+        scope: PyAttributeScope,
+        group: Option<PyGroup>,
+    ) -> PyResult<()> {
+        Ok(self
+            .inner_mut()?
+            .drop_attribute(&scope.with_group(group), &attribute.into())
+            .map_err(PyGraphRecordError::from)?)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the python typing was not followed.
+    pub fn materialize_node_attribute(
+        &self,
+        attribute: PyGraphRecordAttribute,
+        query: &Bound<'_, PyFunction>,
+    ) -> PyResult<usize> {
+        Ok(self
+            .inner_mut()?
+            .materialize_node_attribute(attribute.into(), |nodes| {
+                query
+                    .call1((PyNodeOperand::from(nodes.clone()),))
+                    .expect("Call should succeed")
+                    .extract::<PyNodeMultipleValuesWithIndexOperand>()
+                    .expect("Extraction must succeed")
+                    .into()
+            })
+            .map_err(PyGraphRecordError::from)?)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the python typing was not followed.
+    pub fn materialize_edge_attribute(
+        &self,
+        attribute: PyGraphRecordAttribute,
+        query: &Bound<'_, PyFunction>,
+    ) -> PyResult<usize> {
+        Ok(self
+            .inner_mut()?
+            .materialize_edge_attribute(attribute.into(), |edges| {
+                query
+                    .call1((PyEdgeOperand::from(edges.clone()),))
+                    .expect("Call should succeed")
+                    .extract::<PyEdgeMultipleValuesWithIndexOperand>()
+                    .expect("Extraction must succeed")
+                    .into()
+            })
+            .map_err(PyGraphRecordError::from)?)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the python typing was not followed.
+    pub fn group_from_node_query(
+        &self,
+        group: PyGroup,
+        query: &Bound<'_, PyFunction>,
+    ) -> PyResult<usize> {
+        Ok(self
+            .inner_mut()?
+            .group_from_node_query(group.into(), |nodes| {
+                query
+                    .call1((PyNodeOperand::from(nodes.clone()),))
+                    .expect("Call should succeed")
+                    .extract::<PyNodeIndicesOperand>()
+                    .expect("Extraction must succeed")
+                    .into()
+            })
+            .map_err(PyGraphRecordError::from)?)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the python typing was not followed.
+    pub fn group_from_edge_query(
+        &self,
+        group: PyGroup,
+        query: &Bound<'_, PyFunction>,
+    ) -> PyResult<usize> {
+        Ok(self
+            .inner_mut()?
+            .group_from_edge_query(group.into(), |edges| {
+                query
+                    .call1((PyEdgeOperand::from(edges.clone()),))
+                    .expect("Call should succeed")
+                    .extract::<PyEdgeIndicesOperand>()
+                    .expect("Extraction must succeed")
+                    .into()
+            })
+            .map_err(PyGraphRecordError::from)?)
+    }
+
+    #[staticmethod]
+    pub fn concat(records: Vec<Self>) -> PyResult<Self> {
+        let records = records
+            .into_iter()
+            .map(GraphRecord::try_from)
+            .collect::<PyResult<Vec<_>>>()?;
+
+        Ok(GraphRecord::concat(records)
+            .map_err(PyGraphRecordError::from)?
+            .into())
+    }
+
+    pub fn split_by_groups(&self, groups: Vec<PyGroup>) -> PyResult<HashMap<PyGroup, Self>> {
+        let groups: Vec<Group> = groups.deep_into();
+
+        Ok(self
+            .inner()?
+            .split_by_groups(&groups)
+            .map_err(PyGraphRecordError::from)?
+            .into_iter()
+            .map(|(group, graphrecord)| (group.into(), graphrecord.into()))
+            .collect())
+    }
+
+    pub fn shrink_to_fit(&self) -> PyResult<()> {
+        self.inner_mut()?.shrink_to_fit();
+
+        Ok(())
+    }
+
+    pub fn maintain(&self) -> PyResult<HashMap<&'static str, usize>> {
+        let report = self.inner_mut()?.maintain();
+
+        Ok(HashMap::from([
+            ("bytes_released", report.bytes_released),
+            ("text_indices_rebuilt", report.text_indices_rebuilt),
+            ("geo_indices_rebuilt", report.geo_indices_rebuilt),
+        ]))
+    }
+
+    pub fn memory_usage(&self) -> PyResult<HashMap<&'static str, usize>> {
+        let memory_usage = self.inner()?.memory_usage();
+
+        Ok(HashMap::from([
+            ("nodes", memory_usage.nodes),
+            ("node_attributes", memory_usage.node_attributes),
+            ("edges", memory_usage.edges),
+            ("edge_attributes", memory_usage.edge_attributes),
+            ("groups", memory_usage.groups),
+            ("total", memory_usage.total()),
+        ]))
+    }
+
+    pub fn fingerprint(&self) -> PyResult<u64> {
+        Ok(self.inner()?.fingerprint())
+    }
+
+    pub fn equals(&self, other: &Self) -> PyResult<bool> {
+        Ok(self.inner()?.equals(&*other.inner()?))
+    }
+
+    pub fn is_subgraph_of(&self, other: &Self) -> PyResult<bool> {
+        Ok(self.inner()?.is_subgraph_of(&*other.inner()?))
+    }
+
+    pub fn __eq__(&self, other: &Self) -> PyResult<bool> {
+        self.equals(other)
+    }
+
+    pub fn contains_node(&self, node_index: PyNodeIndex) -> PyResult<bool> {
+        Ok(self.inner()?.contains_node(&node_index.into()))
+    }
+
+    pub fn contains_edge(&self, edge_index: EdgeIndex) -> PyResult<bool> {
+        Ok(self.inner()?.contains_edge(&edge_index))
+    }
+
+    pub fn contains_group(&self, group: PyGroup) -> PyResult<bool> {
+        Ok(self.inner()?.contains_group(&group.into()))
+    }
+
+    pub fn group_metadata(&self, group: PyGroup) -> PyResult<PyAttributes> {
+        Ok(self
+            .inner()?
+            .group_metadata(&group.into())
+            .map_err(PyGraphRecordError::from)?
+            .map(|(key, value)| (key.clone().into(), value.clone().into()))
+            .collect())
+    }
+
+    pub fn set_group_metadata(
+        &self,
+        group: PyGroup,
+        key: PyGraphRecordAttribute,
+        value: PyGraphRecordValue,
+    ) -> PyResult<()> {
+        Ok(self
+            .inner_mut()?
+            .set_group_metadata(&group.into(), key.into(), value.into())
+            .map_err(PyGraphRecordError::from)?)
+    }
+
+    pub fn remove_group_metadata(
+        &self,
+        group: PyGroup,
+        key: PyGraphRecordAttribute,
+    ) -> PyResult<PyGraphRecordValue> {
+        Ok(self
+            .inner_mut()?
+            .remove_group_metadata(&group.into(), &key.into())
+            .map_err(PyGraphRecordError::from)?
+            .into())
+    }
+
+    pub fn neighbors_outgoing(
+        &self,
+        node_indices: Vec<PyNodeIndex>,
+    ) -> PyResult<HashMap<PyNodeIndex, Vec<PyNodeIndex>>> {
+        let graphrecord = self.inner()?;
+
+        node_indices
+            .into_iter()
+            .map(|node_index| {
+                let neighbors = graphrecord
+                    .neighbors_outgoing(&node_index)
+                    .map_err(PyGraphRecordError::from)?
+                    .map(|neighbor| neighbor.clone().into())
+                    .collect();
+
+                Ok((node_index, neighbors))
+            })
+            .collect()
+    }
+
+    pub fn neighbors_incoming(
+        &self,
+        node_indices: Vec<PyNodeIndex>,
+    ) -> PyResult<HashMap<PyNodeIndex, Vec<PyNodeIndex>>> {
+        let graphrecord = self.inner()?;
+
+        node_indices
+            .into_iter()
+            .map(|node_index| {
+                let neighbors = graphrecord
+                    .neighbors_incoming(&node_index)
+                    .map_err(PyGraphRecordError::from)?
+                    .map(|neighbor| neighbor.clone().into())
+                    .collect();
+
+                Ok((node_index, neighbors))
+            })
+            .collect()
+    }
+
+    pub fn neighbors_undirected(
+        &self,
+        node_indices: Vec<PyNodeIndex>,
+    ) -> PyResult<HashMap<PyNodeIndex, Vec<PyNodeIndex>>> {
+        let graphrecord = self.inner()?;
+
         node_indices
             .into_iter()
             .map(|node_index| {
@@ -1675,6 +2779,43 @@ impl PyGraphRecord {
             .collect()
     }
 
+    #[pyo3(signature = (node_indices, direction, edge_group=None, edge_indices=None))]
+    pub fn neighbors_filtered(
+        &self,
+        node_indices: Vec<PyNodeIndex>,
+        direction: PyEdgeDirection,
+        edge_group: Option<PyGroup>,
+        edge_indices: Option<Vec<EdgeIndex>>,
+    ) -> PyResult<HashMap<PyNodeIndex, Vec<PyNodeIndex>>> {
+        let graphrecord = self.inner()?;
+
+        let edge_group: Option<Group> = edge_group.map(Into::into);
+        let edge_indices: Option<HashSet<EdgeIndex>> =
+            edge_indices.map(|edge_indices| edge_indices.into_iter().collect());
+
+        node_indices
+            .into_iter()
+            .map(|node_index| {
+                let neighbors = graphrecord
+                    .neighbors_filtered(
+                        &node_index,
+                        direction.clone().into(),
+                        edge_group.as_ref(),
+                        |edge_index, _| {
+                            edge_indices
+                                .as_ref()
+                                .is_none_or(|edge_indices| edge_indices.contains(edge_index))
+                        },
+                    )
+                    .map_err(PyGraphRecordError::from)?
+                    .map(|neighbor| neighbor.clone().into())
+                    .collect();
+
+                Ok((node_index, neighbors))
+            })
+            .collect()
+    }
+
     #[pyo3(signature = (bypass_plugins=false))]
     pub fn clear(&self, bypass_plugins: bool) -> PyResult<()> {
         let mut graphrecord = self.inner_mut()?;
@@ -1698,17 +2839,18 @@ impl PyGraphRecord {
     ) -> PyResult<Py<PyAny>> {
         let graphrecord = self.inner()?;
 
-        let result = graphrecord
-            .query_nodes(|nodes| {
-                let result = query
-                    .call1((PyNodeOperand::from(nodes.clone()),))
-                    .expect("Call should succeed");
+        let selection = graphrecord.query_nodes(|nodes| {
+            let result = query
+                .call1((PyNodeOperand::from(nodes.clone()),))
+                .expect("Call should succeed");
 
-                result
-                    .extract::<PyReturnOperand>()
-                    .expect("Extraction must succeed")
-            })
-            .evaluate()
+            result
+                .extract::<PyReturnOperand>()
+                .expect("Extraction must succeed")
+        });
+
+        let result = py
+            .detach(|| selection.evaluate().map(PyReturnValue::materialize))
             .map_err(PyGraphRecordError::from)?;
 
         Ok(result.into_pyobject(py)?.unbind())
@@ -1724,17 +2866,18 @@ impl PyGraphRecord {
     ) -> PyResult<Py<PyAny>> {
         let graphrecord = self.inner()?;
 
-        let result = graphrecord
-            .query_edges(|edges| {
-                let result = query
-                    .call1((PyEdgeOperand::from(edges.clone()),))
-                    .expect("Call should succeed");
+        let selection = graphrecord.query_edges(|edges| {
+            let result = query
+                .call1((PyEdgeOperand::from(edges.clone()),))
+                .expect("Call should succeed");
 
-                result
-                    .extract::<PyReturnOperand>()
-                    .expect("Extraction must succeed")
-            })
-            .evaluate()
+            result
+                .extract::<PyReturnOperand>()
+                .expect("Extraction must succeed")
+        });
+
+        let result = py
+            .detach(|| selection.evaluate().map(PyReturnValue::materialize))
             .map_err(PyGraphRecordError::from)?;
 
         Ok(result.into_pyobject(py)?.unbind())
@@ -1745,23 +2888,90 @@ impl PyGraphRecord {
         Clone::clone(self)
     }
 
-    pub fn overview(&self, truncate_details: Option<usize>) -> PyResult<PyOverview> {
-        Ok(self
-            .inner()?
-            .overview(truncate_details)
+    #[pyo3(signature = (truncate_details, format, categorical_threshold=None))]
+    pub fn overview(
+        &self,
+        py: Python<'_>,
+        truncate_details: Option<usize>,
+        format: PyOverviewFormat,
+        categorical_threshold: Option<usize>,
+    ) -> PyResult<PyOverview> {
+        let graphrecord = self.inner()?;
+        let graphrecord: &GraphRecord = &graphrecord;
+
+        Ok(py
+            .detach(|| graphrecord.overview(truncate_details, format.into(), categorical_threshold))
             .map_err(PyGraphRecordError::from)?
             .into())
     }
 
+    #[pyo3(signature = (sample_size, seed, truncate_details, format, categorical_threshold=None))]
+    pub fn overview_sampled(
+        &self,
+        py: Python<'_>,
+        sample_size: usize,
+        seed: u64,
+        truncate_details: Option<usize>,
+        format: PyOverviewFormat,
+        categorical_threshold: Option<usize>,
+    ) -> PyResult<PyOverview> {
+        let graphrecord = self.inner()?;
+        let graphrecord: &GraphRecord = &graphrecord;
+
+        Ok(py
+            .detach(|| {
+                graphrecord.overview_sampled(
+                    sample_size,
+                    seed,
+                    truncate_details,
+                    format.into(),
+                    categorical_threshold,
+                )
+            })
+            .map_err(PyGraphRecordError::from)?
+            .into())
+    }
+
+    #[pyo3(signature = (group, truncate_details, format, categorical_threshold=None))]
     pub fn group_overview(
         &self,
+        py: Python<'_>,
         group: PyGroup,
         truncate_details: Option<usize>,
+        format: PyOverviewFormat,
+        categorical_threshold: Option<usize>,
     ) -> PyResult<PyGroupOverview> {
-        Ok(self
-            .inner()?
-            .group_overview(&group.into(), truncate_details)
+        let graphrecord = self.inner()?;
+        let graphrecord: &GraphRecord = &graphrecord;
+        let group: Group = group.into();
+
+        Ok(py
+            .detach(|| {
+                graphrecord.group_overview(
+                    &group,
+                    truncate_details,
+                    format.into(),
+                    categorical_threshold,
+                )
+            })
             .map_err(PyGraphRecordError::from)?
             .into())
     }
+
+    pub fn _repr_html_(&self, py: Python<'_>) -> PyResult<String> {
+        let graphrecord = self.inner()?;
+        let graphrecord: &GraphRecord = &graphrecord;
+
+        Ok(py
+            .detach(|| {
+                graphrecord
+                    .overview(
+                        Some(DEFAULT_TRUNCATE_DETAILS),
+                        OverviewFormat::default(),
+                        None,
+                    )
+                    .map(|overview| overview.to_html())
+            })
+            .map_err(PyGraphRecordError::from)?)
+    }
 }
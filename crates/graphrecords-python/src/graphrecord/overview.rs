@@ -9,10 +9,41 @@ use crate::graphrecord::{
 };
 use graphrecords_core::graphrecord::overview::{
     AttributeOverview, AttributeOverviewData, EdgeGroupOverview, GroupOverview, NodeGroupOverview,
-    Overview,
+    Overview, OverviewFormat,
 };
 use pyo3::{prelude::*, types::PyDict};
 
+#[pyclass(frozen, eq, eq_int)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PyOverviewFormat {
+    Modern = 0,
+    Ascii = 1,
+    Markdown = 2,
+    Csv = 3,
+}
+
+impl From<OverviewFormat> for PyOverviewFormat {
+    fn from(value: OverviewFormat) -> Self {
+        match value {
+            OverviewFormat::Modern => Self::Modern,
+            OverviewFormat::Ascii => Self::Ascii,
+            OverviewFormat::Markdown => Self::Markdown,
+            OverviewFormat::Csv => Self::Csv,
+        }
+    }
+}
+
+impl From<PyOverviewFormat> for OverviewFormat {
+    fn from(value: PyOverviewFormat) -> Self {
+        match value {
+            PyOverviewFormat::Modern => Self::Modern,
+            PyOverviewFormat::Ascii => Self::Ascii,
+            PyOverviewFormat::Markdown => Self::Markdown,
+            PyOverviewFormat::Csv => Self::Csv,
+        }
+    }
+}
+
 #[pyclass(frozen)]
 #[repr(transparent)]
 #[derive(Debug, Clone)]
@@ -61,11 +92,25 @@ impl PyAttributeOverview {
         let dict = PyDict::new(py);
 
         match &self.0.data {
-            AttributeOverviewData::Categorical { distinct_values } => {
-                let distinct_values: Vec<PyGraphRecordValue> = distinct_values.clone().deep_into();
+            AttributeOverviewData::Categorical {
+                value_counts,
+                other_count,
+            } => {
+                let distinct_values: Vec<PyGraphRecordValue> = value_counts
+                    .iter()
+                    .map(|(value, _)| value.clone().into())
+                    .collect();
+                let value_counts: Vec<(PyGraphRecordValue, usize)> = value_counts
+                    .iter()
+                    .map(|(value, count)| (value.clone().into(), *count))
+                    .collect();
 
                 dict.set_item("distinct_values", distinct_values)
                     .expect("Setting item must succeed");
+                dict.set_item("value_counts", value_counts)
+                    .expect("Setting item must succeed");
+                dict.set_item("other_count", *other_count)
+                    .expect("Setting item must succeed");
                 dict.set_item("attribute_type", PyAttributeType::Categorical)
                     .expect("Setting item must succeed");
 
@@ -262,9 +307,37 @@ impl PyGroupOverview {
         self.0.edge_overview.clone().into()
     }
 
+    #[getter]
+    pub fn metadata(&self) -> HashMap<PyGraphRecordAttribute, PyGraphRecordValue> {
+        self.0.metadata.clone().deep_into()
+    }
+
+    #[getter]
+    pub fn structure<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let structure = &self.0.structure;
+        let dict = PyDict::new(py);
+
+        let connected_groups: Vec<(PyGraphRecordAttribute, usize)> = structure
+            .connected_groups
+            .iter()
+            .map(|(group, count)| (group.clone().into(), *count))
+            .collect();
+
+        dict.set_item("ungrouped_endpoints", structure.ungrouped_endpoints)?;
+        dict.set_item("isolated_nodes", structure.isolated_nodes)?;
+        dict.set_item("average_degree", structure.average_degree)?;
+        dict.set_item("connected_groups", connected_groups)?;
+
+        Ok(dict)
+    }
+
     pub fn __repr__(&self) -> PyResult<String> {
         Ok(format!("{}", self.0))
     }
+
+    pub fn _repr_html_(&self) -> String {
+        self.0.to_html()
+    }
 }
 
 #[pyclass(frozen)]
@@ -302,7 +375,16 @@ impl PyOverview {
         self.0.grouped_overviews.clone().deep_into()
     }
 
+    #[getter]
+    pub const fn is_approximate(&self) -> bool {
+        self.0.is_approximate()
+    }
+
     pub fn __repr__(&self) -> PyResult<String> {
         Ok(format!("{}", self.0))
     }
+
+    pub fn _repr_html_(&self) -> String {
+        self.0.to_html()
+    }
 }
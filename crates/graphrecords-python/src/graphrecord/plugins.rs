@@ -1,26 +1,30 @@
 use crate::{
-    graphrecord::traits::DeepInto,
-    prelude::{PyAttributes, PyGraphRecord, PyGroup, PyNodeIndex, PySchema},
+    graphrecord::{schema::PySchemaDiff, traits::DeepInto, value::PyGraphRecordValue},
+    prelude::{
+        PyAttributes, PyGraphRecord, PyGraphRecordAttribute, PyGroup, PyNodeIndex, PySchema,
+    },
 };
 use graphrecords_core::{
     errors::{GraphRecordError, GraphRecordResult},
     graphrecord::{
-        EdgeDataFrameInput, EdgeIndex, GraphRecord, NodeDataFrameInput,
+        EdgeDataFrameInput, EdgeIndex, GraphRecord, MissingNodePolicy, NodeDataFrameInput,
+        NullHandling,
         plugins::{
-            Plugin, PostAddEdgeContext, PostAddEdgeToGroupContext, PostAddEdgeToGroupsContext,
-            PostAddEdgeWithGroupContext, PostAddEdgeWithGroupsContext, PostAddEdgesContext,
-            PostAddEdgesDataframesContext, PostAddEdgesDataframesWithGroupContext,
-            PostAddEdgesDataframesWithGroupsContext, PostAddEdgesToGroupsContext,
-            PostAddEdgesWithGroupContext, PostAddEdgesWithGroupsContext, PostAddGroupContext,
-            PostAddNodeContext, PostAddNodeToGroupContext, PostAddNodeToGroupsContext,
-            PostAddNodeWithGroupContext, PostAddNodeWithGroupsContext, PostAddNodesContext,
-            PostAddNodesDataframesContext, PostAddNodesDataframesWithGroupContext,
-            PostAddNodesDataframesWithGroupsContext, PostAddNodesToGroupsContext,
-            PostAddNodesWithGroupContext, PostAddNodesWithGroupsContext, PostRemoveEdgeContext,
-            PostRemoveEdgeFromGroupContext, PostRemoveEdgeFromGroupsContext,
-            PostRemoveEdgesFromGroupsContext, PostRemoveGroupContext, PostRemoveNodeContext,
-            PostRemoveNodeFromGroupContext, PostRemoveNodeFromGroupsContext,
-            PostRemoveNodesFromGroupsContext, PreAddEdgeContext, PreAddEdgeToGroupContext,
+            MappingPlugin, Plugin, PostAddEdgeContext, PostAddEdgeToGroupContext,
+            PostAddEdgeToGroupsContext, PostAddEdgeWithGroupContext, PostAddEdgeWithGroupsContext,
+            PostAddEdgesContext, PostAddEdgesDataframesContext,
+            PostAddEdgesDataframesWithGroupContext, PostAddEdgesDataframesWithGroupsContext,
+            PostAddEdgesToGroupsContext, PostAddEdgesWithGroupContext,
+            PostAddEdgesWithGroupsContext, PostAddGroupContext, PostAddNodeContext,
+            PostAddNodeToGroupContext, PostAddNodeToGroupsContext, PostAddNodeWithGroupContext,
+            PostAddNodeWithGroupsContext, PostAddNodesContext, PostAddNodesDataframesContext,
+            PostAddNodesDataframesWithGroupContext, PostAddNodesDataframesWithGroupsContext,
+            PostAddNodesToGroupsContext, PostAddNodesWithGroupContext,
+            PostAddNodesWithGroupsContext, PostRemoveEdgeContext, PostRemoveEdgeFromGroupContext,
+            PostRemoveEdgeFromGroupsContext, PostRemoveEdgesFromGroupsContext,
+            PostRemoveGroupContext, PostRemoveNodeContext, PostRemoveNodeFromGroupContext,
+            PostRemoveNodeFromGroupsContext, PostRemoveNodesFromGroupsContext,
+            PostSetSchemaContext, PreAddEdgeContext, PreAddEdgeToGroupContext,
             PreAddEdgeToGroupsContext, PreAddEdgeWithGroupContext, PreAddEdgeWithGroupsContext,
             PreAddEdgesContext, PreAddEdgesDataframesContext,
             PreAddEdgesDataframesWithGroupContext, PreAddEdgesDataframesWithGroupsContext,
@@ -33,13 +37,14 @@ use graphrecords_core::{
             PreRemoveEdgeContext, PreRemoveEdgeFromGroupContext, PreRemoveEdgeFromGroupsContext,
             PreRemoveEdgesFromGroupsContext, PreRemoveGroupContext, PreRemoveNodeContext,
             PreRemoveNodeFromGroupContext, PreRemoveNodeFromGroupsContext,
-            PreRemoveNodesFromGroupsContext, PreSetSchemaContext,
+            PreRemoveNodesFromGroupsContext, PreSetSchemaContext, UnmappedValues, ValueMappings,
         },
     },
 };
 use pyo3::{IntoPyObjectExt, Py, PyAny, Python, pyclass, pymethods, types::PyAnyMethods};
 use pyo3_polars::PyDataFrame;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::HashMap;
 
 macro_rules! impl_pre_hook {
     ($method:ident, $py_context_type:ident, $core_context_type:ident) => {
@@ -159,6 +164,7 @@ fn py_to_node_dataframe_inputs(inputs: Vec<(PyDataFrame, String)>) -> Vec<NodeDa
         .map(|(dataframe, index_column)| NodeDataFrameInput {
             dataframe: dataframe.0,
             index_column,
+            nulls: NullHandling::default(),
         })
         .collect()
 }
@@ -188,6 +194,8 @@ fn py_to_edge_dataframe_inputs(
                 dataframe: dataframe.0,
                 source_index_column,
                 target_index_column,
+                nulls: NullHandling::default(),
+                missing_nodes: MissingNodePolicy::default(),
             },
         )
         .collect()
@@ -246,6 +254,62 @@ impl PyPreSetSchemaContext {
     }
 }
 
+#[pyclass(frozen)]
+#[derive(Debug)]
+pub struct PyPostSetSchemaContext {
+    old_schema: Py<PySchema>,
+    new_schema: Py<PySchema>,
+}
+
+impl Clone for PyPostSetSchemaContext {
+    fn clone(&self) -> Self {
+        Python::attach(|py| Self {
+            old_schema: self.old_schema.clone_ref(py),
+            new_schema: self.new_schema.clone_ref(py),
+        })
+    }
+}
+
+impl PyPostSetSchemaContext {
+    /// # Panics
+    ///
+    /// Panics if the python typing was not followed.
+    pub fn bind(py: Python<'_>, context: PostSetSchemaContext) -> Self {
+        Self {
+            old_schema: Py::new(py, PySchema::from(context.old_schema))
+                .expect("PySchema should be creatable"),
+            new_schema: Py::new(py, PySchema::from(context.new_schema))
+                .expect("PySchema should be creatable"),
+        }
+    }
+}
+
+#[pymethods]
+impl PyPostSetSchemaContext {
+    #[new]
+    pub const fn new(old_schema: Py<PySchema>, new_schema: Py<PySchema>) -> Self {
+        Self {
+            old_schema,
+            new_schema,
+        }
+    }
+
+    #[getter]
+    pub fn old_schema(&self, py: Python<'_>) -> Py<PySchema> {
+        self.old_schema.clone_ref(py)
+    }
+
+    #[getter]
+    pub fn new_schema(&self, py: Python<'_>) -> Py<PySchema> {
+        self.new_schema.clone_ref(py)
+    }
+
+    #[getter]
+    pub fn changes(&self) -> PySchemaDiff {
+        self.old_schema.get().diff(self.new_schema.get())
+    }
+}
+
 #[pyclass(frozen)]
 #[derive(Debug)]
 pub struct PyPreAddNodeContext {
@@ -4422,7 +4486,11 @@ impl Plugin for PyPlugin {
     }
 
     impl_pre_hook!(pre_set_schema, PyPreSetSchemaContext, PreSetSchemaContext);
-    impl_post_hook!(post_set_schema);
+    impl_post_hook!(
+        post_set_schema,
+        PyPostSetSchemaContext,
+        PostSetSchemaContext
+    );
     impl_post_hook!(pre_freeze_schema);
     impl_post_hook!(post_freeze_schema);
     impl_post_hook!(pre_unfreeze_schema);
@@ -4730,3 +4798,72 @@ impl Plugin for PyPlugin {
     impl_post_hook!(pre_clear);
     impl_post_hook!(post_clear);
 }
+
+type PyValueMappings =
+    HashMap<PyGraphRecordAttribute, Vec<(PyGraphRecordValue, PyGraphRecordValue)>>;
+
+fn py_to_value_mappings(mappings: PyValueMappings) -> ValueMappings {
+    mappings
+        .into_iter()
+        .map(|(attribute, mapping)| {
+            (
+                attribute.into(),
+                mapping
+                    .into_iter()
+                    .map(|(value, mapped_value)| (value.into(), mapped_value.into()))
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+fn unmapped_values_to_py(
+    unmapped_values: UnmappedValues,
+) -> HashMap<PyGraphRecordAttribute, Vec<PyGraphRecordValue>> {
+    unmapped_values
+        .into_iter()
+        .map(|(attribute, values)| {
+            (
+                attribute.into(),
+                values.into_iter().map(PyGraphRecordValue::from).collect(),
+            )
+        })
+        .collect()
+}
+
+/// Built-in plugin that runs natively instead of calling back into Python.
+#[pyclass(frozen)]
+#[derive(Debug, Clone)]
+pub struct PyMappingPlugin(MappingPlugin);
+
+#[pymethods]
+impl PyMappingPlugin {
+    #[new]
+    pub fn new(node_mappings: PyValueMappings, edge_mappings: PyValueMappings) -> Self {
+        Self(MappingPlugin::new(
+            py_to_value_mappings(node_mappings),
+            py_to_value_mappings(edge_mappings),
+        ))
+    }
+
+    pub fn unmapped_node_values(&self) -> HashMap<PyGraphRecordAttribute, Vec<PyGraphRecordValue>> {
+        unmapped_values_to_py(self.0.unmapped_node_values())
+    }
+
+    pub fn unmapped_edge_values(&self) -> HashMap<PyGraphRecordAttribute, Vec<PyGraphRecordValue>> {
+        unmapped_values_to_py(self.0.unmapped_edge_values())
+    }
+
+    pub fn clear_unmapped_values(&self) {
+        self.0.clear_unmapped_values();
+    }
+}
+
+/// Returns the native plugin behind `plugin` if it is a built-in one, or a
+/// plugin calling the hooks of the Python object otherwise.
+pub fn into_plugin(py: Python<'_>, plugin: Py<PyAny>) -> Box<dyn Plugin> {
+    match plugin.extract::<PyMappingPlugin>(py) {
+        Ok(mapping_plugin) => Box::new(mapping_plugin.0),
+        Err(_) => Box::new(PyPlugin::new(plugin)),
+    }
+}
@@ -0,0 +1,51 @@
+use chrono::NaiveDateTime;
+use graphrecords_core::graphrecord::provenance::ProvenanceTag;
+use pyo3::prelude::*;
+
+#[pyclass(frozen, eq, hash)]
+#[repr(transparent)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PyProvenanceTag(ProvenanceTag);
+
+impl From<ProvenanceTag> for PyProvenanceTag {
+    fn from(value: ProvenanceTag) -> Self {
+        Self(value)
+    }
+}
+
+impl From<PyProvenanceTag> for ProvenanceTag {
+    fn from(value: PyProvenanceTag) -> Self {
+        value.0
+    }
+}
+
+#[pymethods]
+impl PyProvenanceTag {
+    #[new]
+    #[pyo3(signature = (source, timestamp, batch_id=None))]
+    pub const fn new(source: String, timestamp: NaiveDateTime, batch_id: Option<String>) -> Self {
+        Self(ProvenanceTag::new(source, batch_id, timestamp))
+    }
+
+    #[getter]
+    pub fn source(&self) -> &str {
+        &self.0.source
+    }
+
+    #[getter]
+    pub fn batch_id(&self) -> Option<&str> {
+        self.0.batch_id.as_deref()
+    }
+
+    #[getter]
+    pub const fn timestamp(&self) -> NaiveDateTime {
+        self.0.timestamp
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "ProvenanceTag(source={:?}, batch_id={:?}, timestamp={})",
+            self.0.source, self.0.batch_id, self.0.timestamp
+        )
+    }
+}
@@ -5,7 +5,8 @@ use crate::graphrecord::{
     errors::PyGraphRecordError,
     querying::values::{
         PyEdgeMultipleValuesWithIndexGroupOperand, PyEdgeMultipleValuesWithIndexOperand,
-        PyNodeMultipleValuesWithIndexGroupOperand, PyNodeMultipleValuesWithIndexOperand,
+        PyGroupAggregate, PyNodeMultipleValuesWithIndexGroupOperand,
+        PyNodeMultipleValuesWithIndexOperand,
     },
 };
 use graphrecords_core::{
@@ -528,6 +529,14 @@ macro_rules! implement_attributes_tree_group_operand {
                 self.0.ungroup().into()
             }
 
+            pub fn sorted_by_key(&self) {
+                self.0.sorted_by_key();
+            }
+
+            pub fn sorted_by_aggregate(&self, aggregate: PyGroupAggregate) {
+                self.0.sorted_by_aggregate(aggregate);
+            }
+
             pub fn deep_clone(&self) -> $name {
                 self.0.deep_clone().into()
             }
@@ -1139,6 +1148,14 @@ macro_rules! implement_multiple_attributes_grouped_operand {
                 self.0.ungroup().into()
             }
 
+            pub fn sorted_by_key(&self) {
+                self.0.sorted_by_key();
+            }
+
+            pub fn sorted_by_aggregate(&self, aggregate: PyGroupAggregate) {
+                self.0.sorted_by_aggregate(aggregate);
+            }
+
             pub fn deep_clone(&self) -> $name {
                 self.0.deep_clone().into()
             }
@@ -1499,6 +1516,14 @@ macro_rules! implement_single_attribute_grouped_operand {
                 self.0.ungroup().into()
             }
 
+            pub fn sorted_by_key(&self) {
+                self.0.sorted_by_key();
+            }
+
+            pub fn sorted_by_aggregate(&self, aggregate: PyGroupAggregate) {
+                self.0.sorted_by_aggregate(aggregate);
+            }
+
             pub fn deep_clone(&self) -> $name {
                 self.0.deep_clone().into()
             }
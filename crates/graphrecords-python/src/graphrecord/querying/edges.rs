@@ -8,7 +8,10 @@ use crate::graphrecord::{
     querying::{
         attributes::PyEdgeAttributesTreeGroupOperand,
         nodes::PyNodeGroupOperand,
-        values::{PyEdgeMultipleValuesWithIndexGroupOperand, PyEdgeMultipleValuesWithIndexOperand},
+        values::{
+            PyEdgeMultipleValuesWithIndexGroupOperand, PyEdgeMultipleValuesWithIndexOperand,
+            PyGroupAggregate,
+        },
     },
 };
 use graphrecords_core::{
@@ -39,6 +42,7 @@ pub enum EdgeOperandGroupDiscriminator {
     TargetNode(),
     Parallel(),
     Attribute(PyGraphRecordAttribute),
+    EndpointGroups(),
 }
 
 impl From<EdgeOperandGroupDiscriminator> for edges::EdgeOperandGroupDiscriminator {
@@ -50,6 +54,7 @@ impl From<EdgeOperandGroupDiscriminator> for edges::EdgeOperandGroupDiscriminato
             EdgeOperandGroupDiscriminator::Attribute(attribute) => {
                 Self::Attribute(attribute.into())
             }
+            EdgeOperandGroupDiscriminator::EndpointGroups() => Self::EndpointGroups,
         }
     }
 }
@@ -103,6 +108,17 @@ impl PyEdgeOperand {
         self.0.target_node().into()
     }
 
+    /// # Panics
+    ///
+    /// Panics if the python typing was not followed.
+    pub fn induced_by(&self, query: &Bound<'_, PyFunction>) {
+        self.0.induced_by(|operand| {
+            query
+                .call1((PyNodeOperand::from(operand.clone()),))
+                .expect("Call must succeed");
+        });
+    }
+
     /// # Panics
     ///
     /// Panics if the python typing was not followed.
@@ -131,6 +147,32 @@ impl PyEdgeOperand {
         });
     }
 
+    /// # Panics
+    ///
+    /// Panics if the python typing was not followed.
+    pub fn all_of(&self, queries: Vec<Bound<'_, PyFunction>>) {
+        self.0.all_of(queries.iter().map(|query| {
+            move |operand: &mut Wrapper<EdgeOperand>| {
+                query
+                    .call1((Self::from(operand.clone()),))
+                    .expect("Call must succeed");
+            }
+        }));
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the python typing was not followed.
+    pub fn none_of(&self, queries: Vec<Bound<'_, PyFunction>>) {
+        self.0.none_of(queries.iter().map(|query| {
+            move |operand: &mut Wrapper<EdgeOperand>| {
+                query
+                    .call1((Self::from(operand.clone()),))
+                    .expect("Call must succeed");
+            }
+        }));
+    }
+
     pub fn group_by(&self, discriminator: EdgeOperandGroupDiscriminator) -> PyEdgeGroupOperand {
         self.0.group_by(discriminator.into()).into()
     }
@@ -189,6 +231,17 @@ impl PyEdgeGroupOperand {
         self.0.target_node().into()
     }
 
+    /// # Panics
+    ///
+    /// Panics if the python typing was not followed.
+    pub fn induced_by(&self, query: &Bound<'_, PyFunction>) {
+        self.0.induced_by(|operand| {
+            query
+                .call1((PyNodeOperand::from(operand.clone()),))
+                .expect("Call must succeed");
+        });
+    }
+
     /// # Panics
     ///
     /// Panics if the python typing was not followed.
@@ -217,6 +270,36 @@ impl PyEdgeGroupOperand {
         });
     }
 
+    /// # Panics
+    ///
+    /// Panics if the python typing was not followed.
+    pub fn all_of(&self, queries: Vec<Bound<'_, PyFunction>>) {
+        self.0.all_of(queries.iter().map(|query| {
+            move |operand: &mut Wrapper<EdgeOperand>| {
+                query
+                    .call1((PyEdgeOperand::from(operand.clone()),))
+                    .expect("Call must succeed");
+            }
+        }));
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the python typing was not followed.
+    pub fn none_of(&self, queries: Vec<Bound<'_, PyFunction>>) {
+        self.0.none_of(queries.iter().map(|query| {
+            move |operand: &mut Wrapper<EdgeOperand>| {
+                query
+                    .call1((PyEdgeOperand::from(operand.clone()),))
+                    .expect("Call must succeed");
+            }
+        }));
+    }
+
+    pub fn group_by(&self, discriminator: EdgeOperandGroupDiscriminator) -> Self {
+        self.0.group_by(discriminator.into()).into()
+    }
+
     pub fn deep_clone(&self) -> Self {
         self.0.deep_clone().into()
     }
@@ -411,6 +494,18 @@ impl PyEdgeIndicesOperand {
         self.0.is_min();
     }
 
+    pub fn sort(&self, descending: bool) {
+        self.0.sort(descending);
+    }
+
+    pub fn skip(&self, count: usize) {
+        self.0.skip(count);
+    }
+
+    pub fn limit(&self, count: usize) {
+        self.0.limit(count);
+    }
+
     /// # Panics
     ///
     /// Panics if the python typing was not followed.
@@ -563,6 +658,18 @@ impl PyEdgeIndicesGroupOperand {
         self.0.is_min();
     }
 
+    pub fn sort(&self, descending: bool) {
+        self.0.sort(descending);
+    }
+
+    pub fn skip(&self, count: usize) {
+        self.0.skip(count);
+    }
+
+    pub fn limit(&self, count: usize) {
+        self.0.limit(count);
+    }
+
     /// # Panics
     ///
     /// Panics if the python typing was not followed.
@@ -595,6 +702,14 @@ impl PyEdgeIndicesGroupOperand {
         self.0.ungroup().into()
     }
 
+    pub fn sorted_by_key(&self) {
+        self.0.sorted_by_key();
+    }
+
+    pub fn sorted_by_aggregate(&self, aggregate: PyGroupAggregate) {
+        self.0.sorted_by_aggregate(aggregate);
+    }
+
     pub fn deep_clone(&self) -> Self {
         self.0.deep_clone().into()
     }
@@ -847,6 +962,14 @@ impl PyEdgeIndexGroupOperand {
         self.0.ungroup().into()
     }
 
+    pub fn sorted_by_key(&self) {
+        self.0.sorted_by_key();
+    }
+
+    pub fn sorted_by_aggregate(&self, aggregate: PyGroupAggregate) {
+        self.0.sorted_by_aggregate(aggregate);
+    }
+
     pub fn deep_clone(&self) -> Self {
         self.0.deep_clone().into()
     }
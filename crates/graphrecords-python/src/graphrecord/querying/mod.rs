@@ -4,7 +4,7 @@ pub mod nodes;
 pub mod values;
 
 use super::{
-    Lut, PyNodeIndex, attribute::PyGraphRecordAttribute, errors::PyGraphRecordError,
+    Lut, PyGroup, PyNodeIndex, attribute::PyGraphRecordAttribute, errors::PyGraphRecordError,
     traits::DeepFrom, value::PyGraphRecordValue,
 };
 use crate::{
@@ -50,10 +50,13 @@ use graphrecords_core::{
             group_by::{GroupKey, GroupOperand},
             nodes::{NodeIndexOperand, NodeIndicesOperand},
             values::{
-                EdgeMultipleValuesWithIndexOperand, EdgeMultipleValuesWithoutIndexOperand,
+                EdgeHistogramOperand, EdgeMultipleValuesWithIndexOperand,
+                EdgeMultipleValuesWithoutIndexOperand, EdgeQuantilesOperand,
                 EdgeSingleValueWithIndexOperand, EdgeSingleValueWithoutIndexOperand,
-                NodeMultipleValuesWithIndexOperand, NodeMultipleValuesWithoutIndexOperand,
+                EdgeValueCountsOperand, NodeHistogramOperand, NodeMultipleValuesWithIndexOperand,
+                NodeMultipleValuesWithoutIndexOperand, NodeQuantilesOperand,
                 NodeSingleValueWithIndexOperand, NodeSingleValueWithoutIndexOperand,
+                NodeValueCountsOperand,
             },
             wrapper::{CardinalityWrapper, MatchMode, Wrapper},
         },
@@ -67,12 +70,15 @@ use pyo3::{
 };
 use std::collections::HashMap;
 use values::{
-    PyEdgeMultipleValuesWithIndexGroupOperand, PyEdgeMultipleValuesWithIndexOperand,
-    PyEdgeMultipleValuesWithoutIndexOperand, PyEdgeSingleValueWithIndexGroupOperand,
+    PyEdgeHistogramOperand, PyEdgeMultipleValuesWithIndexGroupOperand,
+    PyEdgeMultipleValuesWithIndexOperand, PyEdgeMultipleValuesWithoutIndexOperand,
+    PyEdgeQuantilesOperand, PyEdgeSingleValueWithIndexGroupOperand,
     PyEdgeSingleValueWithIndexOperand, PyEdgeSingleValueWithoutIndexOperand,
-    PyNodeMultipleValuesWithIndexGroupOperand, PyNodeMultipleValuesWithIndexOperand,
-    PyNodeMultipleValuesWithoutIndexOperand, PyNodeSingleValueWithIndexGroupOperand,
+    PyEdgeValueCountsOperand, PyNodeHistogramOperand, PyNodeMultipleValuesWithIndexGroupOperand,
+    PyNodeMultipleValuesWithIndexOperand, PyNodeMultipleValuesWithoutIndexOperand,
+    PyNodeQuantilesOperand, PyNodeSingleValueWithIndexGroupOperand,
     PyNodeSingleValueWithIndexOperand, PyNodeSingleValueWithoutIndexOperand,
+    PyNodeValueCountsOperand,
 };
 
 #[pyclass(frozen)]
@@ -106,6 +112,7 @@ pub enum PyGroupKey {
     Value(PyGraphRecordValue),
     OptionalValue(Option<PyGraphRecordValue>),
     TupleKey((Box<Self>, Box<Self>)),
+    OptionalGroup(Option<PyGroup>),
 }
 
 impl From<GroupKey<'_>> for PyGroupKey {
@@ -119,6 +126,9 @@ impl From<GroupKey<'_>> for PyGroupKey {
             GroupKey::TupleKey((left, right)) => {
                 Self::TupleKey((Box::new(Self::from(*left)), Box::new(Self::from(*right))))
             }
+            GroupKey::OptionalGroup(group) => {
+                Self::OptionalGroup(group.cloned().map(PyGroup::from))
+            }
         }
     }
 }
@@ -138,6 +148,7 @@ impl<'py> IntoPyObject<'py> for PyGroupKey {
                 let right = right.into_pyobject(py)?;
                 (left, right).into_bound_py_any(py)
             }
+            Self::OptionalGroup(group) => group.into_pyobject(py),
         }
     }
 }
@@ -183,6 +194,12 @@ pub enum PyReturnOperand {
     EdgeSingleValueWithIndexGroup(PyEdgeSingleValueWithIndexGroupOperand),
     EdgeSingleValueWithoutIndex(PyEdgeSingleValueWithoutIndexOperand),
     EdgeSingleValueWithoutIndexGroup(PyEdgeSingleValueWithoutIndexGroupOperand),
+    NodeValueCounts(PyNodeValueCountsOperand),
+    EdgeValueCounts(PyEdgeValueCountsOperand),
+    NodeHistogram(PyNodeHistogramOperand),
+    EdgeHistogram(PyEdgeHistogramOperand),
+    NodeQuantiles(PyNodeQuantilesOperand),
+    EdgeQuantiles(PyEdgeQuantilesOperand),
     Vector(Vec<Self>),
 }
 
@@ -308,6 +325,24 @@ impl<'a> ReturnOperand<'a> for PyReturnOperand {
             Self::EdgeSingleValueWithoutIndexGroup(operand) => operand
                 .evaluate(graphrecord)
                 .map(PyReturnValue::EdgeSingleValueWithoutIndexGroup),
+            Self::NodeValueCounts(operand) => operand
+                .evaluate(graphrecord)
+                .map(PyReturnValue::NodeValueCounts),
+            Self::EdgeValueCounts(operand) => operand
+                .evaluate(graphrecord)
+                .map(PyReturnValue::EdgeValueCounts),
+            Self::NodeHistogram(operand) => operand
+                .evaluate(graphrecord)
+                .map(PyReturnValue::NodeHistogram),
+            Self::EdgeHistogram(operand) => operand
+                .evaluate(graphrecord)
+                .map(PyReturnValue::EdgeHistogram),
+            Self::NodeQuantiles(operand) => operand
+                .evaluate(graphrecord)
+                .map(PyReturnValue::NodeQuantiles),
+            Self::EdgeQuantiles(operand) => operand
+                .evaluate(graphrecord)
+                .map(PyReturnValue::EdgeQuantiles),
             Self::Vector(operand) => operand
                 .iter()
                 .map(|item| item.evaluate(graphrecord))
@@ -315,6 +350,13 @@ impl<'a> ReturnOperand<'a> for PyReturnOperand {
                 .map(PyReturnValue::Vector),
         }
     }
+
+    fn operand_count(&self) -> usize {
+        match self {
+            Self::Vector(operand) => operand.iter().map(ReturnOperand::operand_count).sum(),
+            _ => 1,
+        }
+    }
 }
 
 static RETURNOPERAND_CONVERSION_LUT: Lut<PyReturnOperand> = ConversionLut::new();
@@ -662,6 +704,48 @@ pub(crate) fn convert_pyobject_to_pyreturnoperand(
         ))
     }
 
+    fn convert_py_node_value_counts_operand(ob: &Bound<'_, PyAny>) -> PyResult<PyReturnOperand> {
+        Ok(PyReturnOperand::NodeValueCounts(
+            ob.extract::<PyNodeValueCountsOperand>()
+                .expect("Extraction must succeed"),
+        ))
+    }
+
+    fn convert_py_edge_value_counts_operand(ob: &Bound<'_, PyAny>) -> PyResult<PyReturnOperand> {
+        Ok(PyReturnOperand::EdgeValueCounts(
+            ob.extract::<PyEdgeValueCountsOperand>()
+                .expect("Extraction must succeed"),
+        ))
+    }
+
+    fn convert_py_node_histogram_operand(ob: &Bound<'_, PyAny>) -> PyResult<PyReturnOperand> {
+        Ok(PyReturnOperand::NodeHistogram(
+            ob.extract::<PyNodeHistogramOperand>()
+                .expect("Extraction must succeed"),
+        ))
+    }
+
+    fn convert_py_edge_histogram_operand(ob: &Bound<'_, PyAny>) -> PyResult<PyReturnOperand> {
+        Ok(PyReturnOperand::EdgeHistogram(
+            ob.extract::<PyEdgeHistogramOperand>()
+                .expect("Extraction must succeed"),
+        ))
+    }
+
+    fn convert_py_node_quantiles_operand(ob: &Bound<'_, PyAny>) -> PyResult<PyReturnOperand> {
+        Ok(PyReturnOperand::NodeQuantiles(
+            ob.extract::<PyNodeQuantilesOperand>()
+                .expect("Extraction must succeed"),
+        ))
+    }
+
+    fn convert_py_edge_quantiles_operand(ob: &Bound<'_, PyAny>) -> PyResult<PyReturnOperand> {
+        Ok(PyReturnOperand::EdgeQuantiles(
+            ob.extract::<PyEdgeQuantilesOperand>()
+                .expect("Extraction must succeed"),
+        ))
+    }
+
     fn convert_py_list(ob: &Bound<'_, PyAny>) -> PyResult<PyReturnOperand> {
         Ok(PyReturnOperand::Vector(
             ob.extract::<Vec<PyReturnOperand>>()?,
@@ -760,6 +844,18 @@ pub(crate) fn convert_pyobject_to_pyreturnoperand(
             convert_py_edge_single_value_without_index_operand
         } else if ob.is_instance_of::<PyEdgeSingleValueWithoutIndexGroupOperand>() {
             convert_py_edge_single_value_without_index_group_operand
+        } else if ob.is_instance_of::<PyNodeValueCountsOperand>() {
+            convert_py_node_value_counts_operand
+        } else if ob.is_instance_of::<PyEdgeValueCountsOperand>() {
+            convert_py_edge_value_counts_operand
+        } else if ob.is_instance_of::<PyNodeHistogramOperand>() {
+            convert_py_node_histogram_operand
+        } else if ob.is_instance_of::<PyEdgeHistogramOperand>() {
+            convert_py_edge_histogram_operand
+        } else if ob.is_instance_of::<PyNodeQuantilesOperand>() {
+            convert_py_node_quantiles_operand
+        } else if ob.is_instance_of::<PyEdgeQuantilesOperand>() {
+            convert_py_edge_quantiles_operand
         } else if ob.is_instance_of::<PyList>() {
             convert_py_list
         } else {
@@ -875,16 +971,20 @@ pub enum PyReturnValue<'a> {
     EdgeSingleValueWithoutIndexGroup(
         <Wrapper<GroupOperand<EdgeSingleValueWithoutIndexOperand>> as ReturnOperand<'a>>::ReturnValue,
     ),
+    NodeValueCounts(<Wrapper<NodeValueCountsOperand> as ReturnOperand<'a>>::ReturnValue),
+    EdgeValueCounts(<Wrapper<EdgeValueCountsOperand> as ReturnOperand<'a>>::ReturnValue),
+    NodeHistogram(<Wrapper<NodeHistogramOperand> as ReturnOperand<'a>>::ReturnValue),
+    EdgeHistogram(<Wrapper<EdgeHistogramOperand> as ReturnOperand<'a>>::ReturnValue),
+    NodeQuantiles(<Wrapper<NodeQuantilesOperand> as ReturnOperand<'a>>::ReturnValue),
+    EdgeQuantiles(<Wrapper<EdgeQuantilesOperand> as ReturnOperand<'a>>::ReturnValue),
     Vector(Vec<Self>),
 }
 
-impl<'py> IntoPyObject<'py> for PyReturnValue<'_> {
-    type Target = PyAny;
-    type Output = Bound<'py, Self::Target>;
-    type Error = PyErr;
-
+impl PyReturnValue<'_> {
+    /// Consumes all iterators of the result without touching the Python
+    /// interpreter, so it can run while the GIL is released.
     #[allow(clippy::too_many_lines)]
-    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+    pub fn materialize(self) -> PyMaterializedValue {
         match self {
             PyReturnValue::NodeAttributesTree(iterator) => iterator
                 .map(|item| {
@@ -894,7 +994,7 @@ impl<'py> IntoPyObject<'py> for PyReturnValue<'_> {
                     )
                 })
                 .collect::<HashMap<_, _>>()
-                .into_bound_py_any(py),
+                .into_materialized(),
             PyReturnValue::NodeAttributesTreeGroup(iterator) => iterator
                 .map(|(key, items)| {
                     (
@@ -910,22 +1010,22 @@ impl<'py> IntoPyObject<'py> for PyReturnValue<'_> {
                     )
                 })
                 .collect::<Vec<_>>()
-                .into_bound_py_any(py),
+                .into_materialized(),
             PyReturnValue::EdgeAttributesTree(iterator) => iterator
-                .map(|item| (item.0, Vec::<PyGraphRecordAttribute>::deep_from(item.1)))
+                .map(|item| (*item.0, Vec::<PyGraphRecordAttribute>::deep_from(item.1)))
                 .collect::<HashMap<_, _>>()
-                .into_bound_py_any(py),
+                .into_materialized(),
             PyReturnValue::EdgeAttributesTreeGroup(iterator) => iterator
                 .map(|(key, items)| {
                     (
                         PyGroupKey::from(key),
                         items
-                            .map(|item| (item.0, Vec::<PyGraphRecordAttribute>::deep_from(item.1)))
+                            .map(|item| (*item.0, Vec::<PyGraphRecordAttribute>::deep_from(item.1)))
                             .collect::<HashMap<_, _>>(),
                     )
                 })
                 .collect::<Vec<_>>()
-                .into_bound_py_any(py),
+                .into_materialized(),
             PyReturnValue::NodeMultipleAttributesWithIndex(iterator) => iterator
                 .map(|item| {
                     (
@@ -934,7 +1034,7 @@ impl<'py> IntoPyObject<'py> for PyReturnValue<'_> {
                     )
                 })
                 .collect::<HashMap<_, _>>()
-                .into_bound_py_any(py),
+                .into_materialized(),
             PyReturnValue::NodeMultipleAttributesWithIndexGroup(iterator) => iterator
                 .map(|(key, items)| {
                     (
@@ -950,28 +1050,28 @@ impl<'py> IntoPyObject<'py> for PyReturnValue<'_> {
                     )
                 })
                 .collect::<Vec<_>>()
-                .into_bound_py_any(py),
+                .into_materialized(),
             PyReturnValue::NodeMultipleAttributesWithoutIndex(iterator)
             | PyReturnValue::EdgeMultipleAttributesWithoutIndex(iterator)
             | PyReturnValue::NodeIndices(iterator) => iterator
                 .map(PyGraphRecordAttribute::from)
                 .collect::<Vec<_>>()
-                .into_bound_py_any(py),
+                .into_materialized(),
             PyReturnValue::EdgeMultipleAttributesWithIndex(iterator) => iterator
-                .map(|item| (item.0, PyGraphRecordAttribute::from(item.1)))
+                .map(|item| (*item.0, PyGraphRecordAttribute::from(item.1)))
                 .collect::<HashMap<_, _>>()
-                .into_bound_py_any(py),
+                .into_materialized(),
             PyReturnValue::EdgeMultipleAttributesWithIndexGroup(iterator) => iterator
                 .map(|(key, items)| {
                     (
                         PyGroupKey::from(key),
                         items
-                            .map(|item| (item.0, PyGraphRecordAttribute::from(item.1)))
+                            .map(|item| (*item.0, PyGraphRecordAttribute::from(item.1)))
                             .collect::<HashMap<_, _>>(),
                     )
                 })
                 .collect::<Vec<_>>()
-                .into_bound_py_any(py),
+                .into_materialized(),
             PyReturnValue::NodeSingleAttributeWithIndex(attribute) => attribute
                 .map(|item| {
                     (
@@ -979,7 +1079,7 @@ impl<'py> IntoPyObject<'py> for PyReturnValue<'_> {
                         PyGraphRecordAttribute::from(item.1),
                     )
                 })
-                .into_bound_py_any(py),
+                .into_materialized(),
             PyReturnValue::NodeSingleAttributeWithIndexGroup(attribute) => attribute
                 .map(|(key, items)| {
                     (
@@ -993,11 +1093,11 @@ impl<'py> IntoPyObject<'py> for PyReturnValue<'_> {
                     )
                 })
                 .collect::<Vec<_>>()
-                .into_bound_py_any(py),
+                .into_materialized(),
             PyReturnValue::NodeSingleAttributeWithoutIndex(attribute)
             | PyReturnValue::EdgeSingleAttributeWithoutIndex(attribute) => attribute
                 .map(PyGraphRecordAttribute::from)
-                .into_bound_py_any(py),
+                .into_materialized(),
             PyReturnValue::NodeSingleAttributeWithoutIndexGroup(attribute) => attribute
                 .map(|(key, item)| {
                     (
@@ -1006,19 +1106,19 @@ impl<'py> IntoPyObject<'py> for PyReturnValue<'_> {
                     )
                 })
                 .collect::<Vec<_>>()
-                .into_bound_py_any(py),
+                .into_materialized(),
             PyReturnValue::EdgeSingleAttributeWithIndex(attribute) => attribute
-                .map(|item| (item.0, PyGraphRecordAttribute::from(item.1)))
-                .into_bound_py_any(py),
+                .map(|item| (*item.0, PyGraphRecordAttribute::from(item.1)))
+                .into_materialized(),
             PyReturnValue::EdgeSingleAttributeWithIndexGroup(attribute) => attribute
                 .map(|(key, items)| {
                     (
                         PyGroupKey::from(key),
-                        items.map(|item| (item.0, PyGraphRecordAttribute::from(item.1))),
+                        items.map(|item| (*item.0, PyGraphRecordAttribute::from(item.1))),
                     )
                 })
                 .collect::<Vec<_>>()
-                .into_bound_py_any(py),
+                .into_materialized(),
             PyReturnValue::EdgeSingleAttributeWithoutIndexGroup(attribute) => attribute
                 .map(|(key, item)| {
                     (
@@ -1027,19 +1127,19 @@ impl<'py> IntoPyObject<'py> for PyReturnValue<'_> {
                     )
                 })
                 .collect::<Vec<_>>()
-                .into_bound_py_any(py),
+                .into_materialized(),
             PyReturnValue::EdgeIndices(iterator) => {
-                iterator.collect::<Vec<_>>().into_bound_py_any(py)
+                iterator.collect::<Vec<_>>().into_materialized()
             }
             PyReturnValue::EdgeIndicesGroup(iterator) => iterator
                 .map(|(key, iterator)| (PyGroupKey::from(key), iterator.collect::<Vec<_>>()))
                 .collect::<Vec<_>>()
-                .into_bound_py_any(py),
-            PyReturnValue::EdgeIndex(index) => index.into_bound_py_any(py),
+                .into_materialized(),
+            PyReturnValue::EdgeIndex(index) => index.into_materialized(),
             PyReturnValue::EdgeIndexGroup(index) => index
                 .map(|(key, index)| (PyGroupKey::from(key), index))
                 .collect::<Vec<_>>()
-                .into_bound_py_any(py),
+                .into_materialized(),
             PyReturnValue::NodeIndicesGroup(iterator) => iterator
                 .map(|(key, iterator)| {
                     (
@@ -1050,9 +1150,9 @@ impl<'py> IntoPyObject<'py> for PyReturnValue<'_> {
                     )
                 })
                 .collect::<Vec<_>>()
-                .into_bound_py_any(py),
+                .into_materialized(),
             PyReturnValue::NodeIndex(index) => {
-                Option::<PyNodeIndex>::deep_from(index).into_bound_py_any(py)
+                Option::<PyNodeIndex>::deep_from(index).into_materialized()
             }
             PyReturnValue::NodeIndexGroup(index) => index
                 .map(|(key, index)| {
@@ -1062,7 +1162,7 @@ impl<'py> IntoPyObject<'py> for PyReturnValue<'_> {
                     )
                 })
                 .collect::<Vec<_>>()
-                .into_bound_py_any(py),
+                .into_materialized(),
             PyReturnValue::NodeMultipleValuesWithIndex(iterator) => iterator
                 .map(|item| {
                     (
@@ -1071,7 +1171,7 @@ impl<'py> IntoPyObject<'py> for PyReturnValue<'_> {
                     )
                 })
                 .collect::<HashMap<_, _>>()
-                .into_bound_py_any(py),
+                .into_materialized(),
             PyReturnValue::NodeMultipleValuesWithIndexGroup(iterator) => iterator
                 .map(|(key, items)| {
                     (
@@ -1087,27 +1187,27 @@ impl<'py> IntoPyObject<'py> for PyReturnValue<'_> {
                     )
                 })
                 .collect::<Vec<_>>()
-                .into_bound_py_any(py),
+                .into_materialized(),
             PyReturnValue::NodeMultipleValuesWithoutIndex(iterator)
             | PyReturnValue::EdgeMultipleValuesWithoutIndex(iterator) => iterator
                 .map(PyGraphRecordValue::from)
                 .collect::<Vec<_>>()
-                .into_bound_py_any(py),
+                .into_materialized(),
             PyReturnValue::EdgeMultipleValuesWithIndex(iterator) => iterator
-                .map(|item| (item.0, PyGraphRecordValue::from(item.1)))
+                .map(|item| (*item.0, PyGraphRecordValue::from(item.1)))
                 .collect::<HashMap<_, _>>()
-                .into_bound_py_any(py),
+                .into_materialized(),
             PyReturnValue::EdgeMultipleValuesWithIndexGroup(iterator) => iterator
                 .map(|(key, items)| {
                     (
                         PyGroupKey::from(key),
                         items
-                            .map(|item| (item.0, PyGraphRecordValue::from(item.1)))
+                            .map(|item| (*item.0, PyGraphRecordValue::from(item.1)))
                             .collect::<HashMap<_, _>>(),
                     )
                 })
                 .collect::<Vec<_>>()
-                .into_bound_py_any(py),
+                .into_materialized(),
             PyReturnValue::NodeSingleValueWithIndex(value) => value
                 .map(|item| {
                     (
@@ -1115,7 +1215,7 @@ impl<'py> IntoPyObject<'py> for PyReturnValue<'_> {
                         PyGraphRecordValue::from(item.1),
                     )
                 })
-                .into_bound_py_any(py),
+                .into_materialized(),
             PyReturnValue::NodeSingleValueWithIndexGroup(value) => value
                 .map(|(key, items)| {
                     (
@@ -1129,36 +1229,90 @@ impl<'py> IntoPyObject<'py> for PyReturnValue<'_> {
                     )
                 })
                 .collect::<Vec<_>>()
-                .into_bound_py_any(py),
+                .into_materialized(),
             PyReturnValue::NodeSingleValueWithoutIndex(value)
             | PyReturnValue::EdgeSingleValueWithoutIndex(value) => {
-                value.map(PyGraphRecordValue::from).into_bound_py_any(py)
+                value.map(PyGraphRecordValue::from).into_materialized()
             }
             PyReturnValue::NodeSingleValueWithoutIndexGroup(value) => value
                 .map(|(key, item)| (PyGroupKey::from(key), item.map(PyGraphRecordValue::from)))
                 .collect::<Vec<_>>()
-                .into_bound_py_any(py),
+                .into_materialized(),
             PyReturnValue::EdgeSingleValueWithIndex(value) => value
-                .map(|item| (item.0, PyGraphRecordValue::from(item.1)))
-                .into_bound_py_any(py),
+                .map(|item| (*item.0, PyGraphRecordValue::from(item.1)))
+                .into_materialized(),
             PyReturnValue::EdgeSingleValueWithIndexGroup(value) => value
                 .map(|(key, items)| {
                     (
                         PyGroupKey::from(key),
-                        items.map(|item| (item.0, PyGraphRecordValue::from(item.1))),
+                        items.map(|item| (*item.0, PyGraphRecordValue::from(item.1))),
                     )
                 })
                 .collect::<Vec<_>>()
-                .into_bound_py_any(py),
+                .into_materialized(),
             PyReturnValue::EdgeSingleValueWithoutIndexGroup(value) => value
                 .map(|(key, item)| (PyGroupKey::from(key), item.map(PyGraphRecordValue::from)))
                 .collect::<Vec<_>>()
-                .into_bound_py_any(py),
-            PyReturnValue::Vector(vector) => vector.into_bound_py_any(py),
+                .into_materialized(),
+            PyReturnValue::NodeValueCounts(value_counts)
+            | PyReturnValue::EdgeValueCounts(value_counts) => value_counts
+                .into_iter()
+                .map(|(value, count)| (PyGraphRecordValue::from(value), count))
+                .collect::<Vec<_>>()
+                .into_materialized(),
+            PyReturnValue::NodeHistogram(histogram) | PyReturnValue::EdgeHistogram(histogram) => {
+                histogram.into_materialized()
+            }
+            PyReturnValue::NodeQuantiles(quantiles) | PyReturnValue::EdgeQuantiles(quantiles) => {
+                quantiles.into_materialized()
+            }
+            PyReturnValue::Vector(vector) => vector
+                .into_iter()
+                .map(PyReturnValue::materialize)
+                .collect::<Vec<_>>()
+                .into_materialized(),
         }
     }
 }
 
+impl<'py> IntoPyObject<'py> for PyReturnValue<'_> {
+    type Target = PyAny;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        self.materialize().into_pyobject(py)
+    }
+}
+
+type MaterializedConversion =
+    Box<dyn for<'py> FnOnce(Python<'py>) -> PyResult<Bound<'py, PyAny>> + Send>;
+
+pub struct PyMaterializedValue(MaterializedConversion);
+
+impl<'py> IntoPyObject<'py> for PyMaterializedValue {
+    type Target = PyAny;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        (self.0)(py)
+    }
+}
+
+trait IntoMaterialized {
+    fn into_materialized(self) -> PyMaterializedValue;
+}
+
+impl<T> IntoMaterialized for T
+where
+    T: for<'py> IntoPyObject<'py> + Send + 'static,
+{
+    fn into_materialized(self) -> PyMaterializedValue {
+        PyMaterializedValue(Box::new(move |py| self.into_bound_py_any(py)))
+    }
+}
+
 #[repr(transparent)]
 pub struct PyGraphRecordAttributeCardinalityWrapper(CardinalityWrapper<GraphRecordAttribute>);
 
@@ -9,7 +9,10 @@ use crate::graphrecord::{
     querying::{
         attributes::PyNodeAttributesTreeGroupOperand,
         edges::PyEdgeGroupOperand,
-        values::{PyNodeMultipleValuesWithIndexGroupOperand, PyNodeMultipleValuesWithIndexOperand},
+        values::{
+            PyGroupAggregate, PyNodeMultipleValuesWithIndexGroupOperand,
+            PyNodeMultipleValuesWithIndexOperand,
+        },
     },
 };
 use graphrecords_core::{
@@ -126,6 +129,10 @@ impl PyNodeOperand {
         self.0.neighbors(direction.into()).into()
     }
 
+    pub fn degree(&self, direction: PyEdgeDirection) -> PyNodeMultipleValuesWithIndexOperand {
+        self.0.degree(direction.into()).into()
+    }
+
     /// # Panics
     ///
     /// Panics if the python typing was not followed.
@@ -154,6 +161,32 @@ impl PyNodeOperand {
         });
     }
 
+    /// # Panics
+    ///
+    /// Panics if the python typing was not followed.
+    pub fn all_of(&self, queries: Vec<Bound<'_, PyFunction>>) {
+        self.0.all_of(queries.iter().map(|query| {
+            move |operand: &mut Wrapper<NodeOperand>| {
+                query
+                    .call1((Self::from(operand.clone()),))
+                    .expect("Call must succeed");
+            }
+        }));
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the python typing was not followed.
+    pub fn none_of(&self, queries: Vec<Bound<'_, PyFunction>>) {
+        self.0.none_of(queries.iter().map(|query| {
+            move |operand: &mut Wrapper<NodeOperand>| {
+                query
+                    .call1((Self::from(operand.clone()),))
+                    .expect("Call must succeed");
+            }
+        }));
+    }
+
     pub fn group_by(&self, discriminator: NodeOperandGroupDiscriminator) -> PyNodeGroupOperand {
         self.0.group_by(discriminator.into()).into()
     }
@@ -211,6 +244,11 @@ impl PyNodeGroupOperand {
     pub fn neighbors(&self, direction: PyEdgeDirection) -> Self {
         self.0.neighbors(direction.into()).into()
     }
+
+    pub fn degree(&self, direction: PyEdgeDirection) -> PyNodeMultipleValuesWithIndexGroupOperand {
+        self.0.degree(direction.into()).into()
+    }
+
     /// # Panics
     ///
     /// Panics if the python typing was not followed.
@@ -239,6 +277,36 @@ impl PyNodeGroupOperand {
         });
     }
 
+    /// # Panics
+    ///
+    /// Panics if the python typing was not followed.
+    pub fn all_of(&self, queries: Vec<Bound<'_, PyFunction>>) {
+        self.0.all_of(queries.iter().map(|query| {
+            move |operand: &mut Wrapper<NodeOperand>| {
+                query
+                    .call1((PyNodeOperand::from(operand.clone()),))
+                    .expect("Call must succeed");
+            }
+        }));
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the python typing was not followed.
+    pub fn none_of(&self, queries: Vec<Bound<'_, PyFunction>>) {
+        self.0.none_of(queries.iter().map(|query| {
+            move |operand: &mut Wrapper<NodeOperand>| {
+                query
+                    .call1((PyNodeOperand::from(operand.clone()),))
+                    .expect("Call must succeed");
+            }
+        }));
+    }
+
+    pub fn group_by(&self, discriminator: NodeOperandGroupDiscriminator) -> Self {
+        self.0.group_by(discriminator.into()).into()
+    }
+
     pub fn deep_clone(&self) -> Self {
         self.0.deep_clone().into()
     }
@@ -472,6 +540,18 @@ impl PyNodeIndicesOperand {
         self.0.is_min();
     }
 
+    pub fn sort(&self, descending: bool) {
+        self.0.sort(descending);
+    }
+
+    pub fn skip(&self, count: usize) {
+        self.0.skip(count);
+    }
+
+    pub fn limit(&self, count: usize) {
+        self.0.limit(count);
+    }
+
     /// # Panics
     ///
     /// Panics if the python typing was not followed.
@@ -660,6 +740,18 @@ impl PyNodeIndicesGroupOperand {
         self.0.is_min();
     }
 
+    pub fn sort(&self, descending: bool) {
+        self.0.sort(descending);
+    }
+
+    pub fn skip(&self, count: usize) {
+        self.0.skip(count);
+    }
+
+    pub fn limit(&self, count: usize) {
+        self.0.limit(count);
+    }
+
     /// # Panics
     ///
     /// Panics if the python typing was not followed.
@@ -692,6 +784,14 @@ impl PyNodeIndicesGroupOperand {
         self.0.ungroup().into()
     }
 
+    pub fn sorted_by_key(&self) {
+        self.0.sorted_by_key();
+    }
+
+    pub fn sorted_by_aggregate(&self, aggregate: PyGroupAggregate) {
+        self.0.sorted_by_aggregate(aggregate);
+    }
+
     pub fn deep_clone(&self) -> Self {
         self.0.deep_clone().into()
     }
@@ -1016,6 +1116,14 @@ impl PyNodeIndexGroupOperand {
         self.0.ungroup().into()
     }
 
+    pub fn sorted_by_key(&self) {
+        self.0.sorted_by_key();
+    }
+
+    pub fn sorted_by_aggregate(&self, aggregate: PyGroupAggregate) {
+        self.0.sorted_by_aggregate(aggregate);
+    }
+
     pub fn deep_clone(&self) -> Self {
         self.0.deep_clone().into()
     }
@@ -1,4 +1,7 @@
-use crate::graphrecord::{errors::PyGraphRecordError, value::PyGraphRecordValue};
+use crate::graphrecord::{
+    attribute::PyGraphRecordAttribute, errors::PyGraphRecordError, value::PyGraphRecordValue,
+};
+use chrono::TimeDelta;
 use graphrecords_core::{
     errors::GraphRecordError,
     graphrecord::{
@@ -6,12 +9,13 @@ use graphrecords_core::{
         querying::{
             DeepClone,
             edges::EdgeOperand,
-            group_by::GroupOperand,
+            group_by::{GroupAggregate, GroupOperand},
             nodes::NodeOperand,
             values::{
-                MultipleValuesComparisonOperand, MultipleValuesWithIndexOperand,
-                MultipleValuesWithoutIndexOperand, SingleValueComparisonOperand,
-                SingleValueWithIndexOperand, SingleValueWithoutIndexOperand,
+                HistogramOperand, MultipleValuesComparisonOperand, MultipleValuesWithIndexOperand,
+                MultipleValuesWithoutIndexOperand, QuantilesOperand, RollingAggregation,
+                SingleValueComparisonOperand, SingleValueWithIndexOperand,
+                SingleValueWithoutIndexOperand, ValueCountsOperand,
             },
             wrapper::Wrapper,
         },
@@ -114,8 +118,62 @@ impl FromPyObject<'_, '_> for PyMultipleValuesComparisonOperand {
     }
 }
 
+#[repr(transparent)]
+pub struct PyGroupAggregate(GroupAggregate);
+
+impl From<PyGroupAggregate> for GroupAggregate {
+    fn from(aggregate: PyGroupAggregate) -> Self {
+        aggregate.0
+    }
+}
+
+impl FromPyObject<'_, '_> for PyGroupAggregate {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'_, '_, PyAny>) -> PyResult<Self> {
+        match ob.extract::<PyNodeSingleValueWithoutIndexGroupOperand>() {
+            Ok(operand) => Ok(Self(operand.0.into())),
+            _ => match ob.extract::<PyEdgeSingleValueWithoutIndexGroupOperand>() {
+                Ok(operand) => Ok(Self(operand.0.into())),
+                _ => Err(
+                    PyGraphRecordError::from(GraphRecordError::ConversionError(format!(
+                        "Failed to convert {} into SingleValueWithoutIndexGroupOperand",
+                        ob.to_owned()
+                    )))
+                    .into(),
+                ),
+            },
+        }
+    }
+}
+
+#[pyclass(frozen)]
+#[derive(Debug, Clone, Copy)]
+pub enum PyRollingAggregation {
+    Mean,
+    Sum,
+}
+
+impl From<RollingAggregation> for PyRollingAggregation {
+    fn from(aggregation: RollingAggregation) -> Self {
+        match aggregation {
+            RollingAggregation::Mean => Self::Mean,
+            RollingAggregation::Sum => Self::Sum,
+        }
+    }
+}
+
+impl From<PyRollingAggregation> for RollingAggregation {
+    fn from(aggregation: PyRollingAggregation) -> Self {
+        match aggregation {
+            PyRollingAggregation::Mean => Self::Mean,
+            PyRollingAggregation::Sum => Self::Sum,
+        }
+    }
+}
+
 macro_rules! implement_multiple_values_operand {
-    ($name:ident, $kind:ident, $generic:ty, $py_single_value_with_index_operand:ty, $py_single_value_without_index_operand:ty) => {
+    ($name:ident, $kind:ident, $generic:ty, $py_single_value_with_index_operand:ty, $py_single_value_without_index_operand:ty, $py_value_counts_operand:ty, $py_histogram_operand:ty, $py_quantiles_operand:ty $(, { $($methods:tt)* })?) => {
         #[pyclass(frozen)]
         #[repr(transparent)]
         #[derive(Clone)]
@@ -159,6 +217,10 @@ macro_rules! implement_multiple_values_operand {
                 self.0.median().into()
             }
 
+            pub fn quantile(&self, quantile: f64) -> $py_single_value_without_index_operand {
+                self.0.quantile(quantile).into()
+            }
+
             pub fn mode(&self) -> $py_single_value_without_index_operand {
                 self.0.mode().into()
             }
@@ -175,6 +237,22 @@ macro_rules! implement_multiple_values_operand {
                 self.0.count().into()
             }
 
+            pub fn count_distinct(&self) -> $py_single_value_without_index_operand {
+                self.0.count_distinct().into()
+            }
+
+            pub fn value_counts(&self) -> $py_value_counts_operand {
+                self.0.value_counts().into()
+            }
+
+            pub fn histogram(&self, bins: usize) -> $py_histogram_operand {
+                self.0.histogram(bins).into()
+            }
+
+            pub fn quantiles(&self, quantiles: Vec<f64>) -> $py_quantiles_operand {
+                self.0.quantiles(quantiles).into()
+            }
+
             pub fn sum(&self) -> $py_single_value_without_index_operand {
                 self.0.sum().into()
             }
@@ -219,6 +297,30 @@ macro_rules! implement_multiple_values_operand {
                 self.0.contains(value);
             }
 
+            pub fn levenshtein_at_most(
+                &self,
+                target: PySingleValueComparisonOperand,
+                distance: usize,
+            ) {
+                self.0.levenshtein_at_most(target, distance);
+            }
+
+            pub fn jaro_winkler_at_least(
+                &self,
+                target: PySingleValueComparisonOperand,
+                similarity: f64,
+            ) {
+                self.0.jaro_winkler_at_least(target, similarity);
+            }
+
+            pub fn is_close(&self, value: PySingleValueComparisonOperand, tolerance: f64) {
+                self.0.is_close(value, tolerance);
+            }
+
+            pub fn within_radius(&self, point: PySingleValueComparisonOperand, radius_km: f64) {
+                self.0.within_radius(point, radius_km);
+            }
+
             pub fn is_in(&self, values: PyMultipleValuesComparisonOperand) {
                 self.0.is_in(values);
             }
@@ -267,6 +369,10 @@ macro_rules! implement_multiple_values_operand {
                 self.0.abs();
             }
 
+            pub fn apply(&self, udf: String) {
+                self.0.apply(udf);
+            }
+
             pub fn sqrt(&self) {
                 self.0.sqrt();
             }
@@ -323,6 +429,10 @@ macro_rules! implement_multiple_values_operand {
                 self.0.is_null();
             }
 
+            pub fn is_missing(&self) {
+                self.0.is_missing();
+            }
+
             pub fn is_max(&self) {
                 self.0.is_max();
             }
@@ -331,6 +441,22 @@ macro_rules! implement_multiple_values_operand {
                 self.0.is_min();
             }
 
+            pub fn distinct(&self) {
+                self.0.distinct();
+            }
+
+            pub fn sort(&self, descending: bool) {
+                self.0.sort(descending);
+            }
+
+            pub fn skip(&self, count: usize) {
+                self.0.skip(count);
+            }
+
+            pub fn limit(&self, count: usize) {
+                self.0.limit(count);
+            }
+
             pub fn either_or(&self, either: &Bound<'_, PyFunction>, or: &Bound<'_, PyFunction>) {
                 self.0.either_or(
                     |operand| {
@@ -356,6 +482,8 @@ macro_rules! implement_multiple_values_operand {
             pub fn deep_clone(&self) -> $name {
                 self.0.deep_clone().into()
             }
+
+            $($($methods)*)?
         }
     };
 }
@@ -365,28 +493,202 @@ implement_multiple_values_operand!(
     MultipleValuesWithIndexOperand,
     NodeOperand,
     PyNodeSingleValueWithIndexOperand,
-    PyNodeSingleValueWithoutIndexOperand
+    PyNodeSingleValueWithoutIndexOperand,
+    PyNodeValueCountsOperand,
+    PyNodeHistogramOperand,
+    PyNodeQuantilesOperand,
+    {
+        pub fn provenance(&self) {
+            self.0.provenance();
+        }
+
+        pub fn text_search(&self, query: String) {
+            self.0.text_search(query);
+        }
+
+        pub fn rolling(
+            &self,
+            window: TimeDelta,
+            aggregation: PyRollingAggregation,
+            order_by_attribute: PyGraphRecordAttribute,
+        ) {
+            self.0
+                .rolling(window, aggregation.into(), order_by_attribute);
+        }
+
+        pub fn cumsum(&self, order_by_attribute: PyGraphRecordAttribute) {
+            self.0.cumsum(order_by_attribute);
+        }
+
+        pub fn lag(&self, n: usize, order_by_attribute: PyGraphRecordAttribute) {
+            self.0.lag(n, order_by_attribute);
+        }
+
+        pub fn lead(&self, n: usize, order_by_attribute: PyGraphRecordAttribute) {
+            self.0.lead(n, order_by_attribute);
+        }
+
+        pub fn argmax(&self) -> PyNodeSingleValueWithIndexOperand {
+            self.0.argmax().into()
+        }
+
+        pub fn argmin(&self) -> PyNodeSingleValueWithIndexOperand {
+            self.0.argmin().into()
+        }
+
+        pub fn first_by(
+            &self,
+            order_by_attribute: PyGraphRecordAttribute,
+        ) -> PyNodeSingleValueWithIndexOperand {
+            self.0.first_by(order_by_attribute).into()
+        }
+
+        pub fn last_by(
+            &self,
+            order_by_attribute: PyGraphRecordAttribute,
+        ) -> PyNodeSingleValueWithIndexOperand {
+            self.0.last_by(order_by_attribute).into()
+        }
+
+        pub fn random_weighted(
+            &self,
+
+            weight_attribute: PyGraphRecordAttribute,
+        ) -> PyNodeSingleValueWithIndexOperand {
+            self.0.random_weighted(weight_attribute).into()
+        }
+
+        /// # Panics
+        ///
+        /// Panics if the python typing was not followed.
+        pub fn when(
+            &self,
+            query: &Bound<'_, PyFunction>,
+            then: PySingleValueComparisonOperand,
+            otherwise: PySingleValueComparisonOperand,
+        ) {
+            self.0.when(
+                |operand| {
+                    query
+                        .call1((PyNodeMultipleValuesWithIndexOperand::from(operand.clone()),))
+                        .expect("Call must succeed");
+                },
+                then,
+                otherwise,
+            );
+        }
+    }
 );
 implement_multiple_values_operand!(
     PyNodeMultipleValuesWithoutIndexOperand,
     MultipleValuesWithoutIndexOperand,
     NodeOperand,
     PyNodeSingleValueWithoutIndexOperand,
-    PyNodeSingleValueWithoutIndexOperand
+    PyNodeSingleValueWithoutIndexOperand,
+    PyNodeValueCountsOperand,
+    PyNodeHistogramOperand,
+    PyNodeQuantilesOperand
 );
 implement_multiple_values_operand!(
     PyEdgeMultipleValuesWithIndexOperand,
     MultipleValuesWithIndexOperand,
     EdgeOperand,
     PyEdgeSingleValueWithIndexOperand,
-    PyEdgeSingleValueWithoutIndexOperand
+    PyEdgeSingleValueWithoutIndexOperand,
+    PyEdgeValueCountsOperand,
+    PyEdgeHistogramOperand,
+    PyEdgeQuantilesOperand,
+    {
+        pub fn provenance(&self) {
+            self.0.provenance();
+        }
+
+        pub fn text_search(&self, query: String) {
+            self.0.text_search(query);
+        }
+
+        pub fn rolling(
+            &self,
+            window: TimeDelta,
+            aggregation: PyRollingAggregation,
+            order_by_attribute: PyGraphRecordAttribute,
+        ) {
+            self.0
+                .rolling(window, aggregation.into(), order_by_attribute);
+        }
+
+        pub fn cumsum(&self, order_by_attribute: PyGraphRecordAttribute) {
+            self.0.cumsum(order_by_attribute);
+        }
+
+        pub fn lag(&self, n: usize, order_by_attribute: PyGraphRecordAttribute) {
+            self.0.lag(n, order_by_attribute);
+        }
+
+        pub fn lead(&self, n: usize, order_by_attribute: PyGraphRecordAttribute) {
+            self.0.lead(n, order_by_attribute);
+        }
+
+        pub fn argmax(&self) -> PyEdgeSingleValueWithIndexOperand {
+            self.0.argmax().into()
+        }
+
+        pub fn argmin(&self) -> PyEdgeSingleValueWithIndexOperand {
+            self.0.argmin().into()
+        }
+
+        pub fn first_by(
+            &self,
+            order_by_attribute: PyGraphRecordAttribute,
+        ) -> PyEdgeSingleValueWithIndexOperand {
+            self.0.first_by(order_by_attribute).into()
+        }
+
+        pub fn last_by(
+            &self,
+            order_by_attribute: PyGraphRecordAttribute,
+        ) -> PyEdgeSingleValueWithIndexOperand {
+            self.0.last_by(order_by_attribute).into()
+        }
+
+        pub fn random_weighted(
+            &self,
+
+            weight_attribute: PyGraphRecordAttribute,
+        ) -> PyEdgeSingleValueWithIndexOperand {
+            self.0.random_weighted(weight_attribute).into()
+        }
+
+        /// # Panics
+        ///
+        /// Panics if the python typing was not followed.
+        pub fn when(
+            &self,
+            query: &Bound<'_, PyFunction>,
+            then: PySingleValueComparisonOperand,
+            otherwise: PySingleValueComparisonOperand,
+        ) {
+            self.0.when(
+                |operand| {
+                    query
+                        .call1((PyEdgeMultipleValuesWithIndexOperand::from(operand.clone()),))
+                        .expect("Call must succeed");
+                },
+                then,
+                otherwise,
+            );
+        }
+    }
 );
 implement_multiple_values_operand!(
     PyEdgeMultipleValuesWithoutIndexOperand,
     MultipleValuesWithoutIndexOperand,
     EdgeOperand,
     PyEdgeSingleValueWithoutIndexOperand,
-    PyEdgeSingleValueWithoutIndexOperand
+    PyEdgeSingleValueWithoutIndexOperand,
+    PyEdgeValueCountsOperand,
+    PyEdgeHistogramOperand,
+    PyEdgeQuantilesOperand
 );
 
 macro_rules! implement_multiple_values_grouped_operand {
@@ -434,6 +736,10 @@ macro_rules! implement_multiple_values_grouped_operand {
                 self.0.median().into()
             }
 
+            pub fn quantile(&self, quantile: f64) -> $py_single_value_without_index_operand {
+                self.0.quantile(quantile).into()
+            }
+
             pub fn mode(&self) -> $py_single_value_without_index_operand {
                 self.0.mode().into()
             }
@@ -450,6 +756,10 @@ macro_rules! implement_multiple_values_grouped_operand {
                 self.0.count().into()
             }
 
+            pub fn count_distinct(&self) -> $py_single_value_without_index_operand {
+                self.0.count_distinct().into()
+            }
+
             pub fn sum(&self) -> $py_single_value_without_index_operand {
                 self.0.sum().into()
             }
@@ -494,6 +804,30 @@ macro_rules! implement_multiple_values_grouped_operand {
                 self.0.contains(value);
             }
 
+            pub fn levenshtein_at_most(
+                &self,
+                target: PySingleValueComparisonOperand,
+                distance: usize,
+            ) {
+                self.0.levenshtein_at_most(target, distance);
+            }
+
+            pub fn jaro_winkler_at_least(
+                &self,
+                target: PySingleValueComparisonOperand,
+                similarity: f64,
+            ) {
+                self.0.jaro_winkler_at_least(target, similarity);
+            }
+
+            pub fn is_close(&self, value: PySingleValueComparisonOperand, tolerance: f64) {
+                self.0.is_close(value, tolerance);
+            }
+
+            pub fn within_radius(&self, point: PySingleValueComparisonOperand, radius_km: f64) {
+                self.0.within_radius(point, radius_km);
+            }
+
             pub fn is_in(&self, values: PyMultipleValuesComparisonOperand) {
                 self.0.is_in(values);
             }
@@ -542,6 +876,10 @@ macro_rules! implement_multiple_values_grouped_operand {
                 self.0.abs();
             }
 
+            pub fn apply(&self, udf: String) {
+                self.0.apply(udf);
+            }
+
             pub fn sqrt(&self) {
                 self.0.sqrt();
             }
@@ -598,6 +936,87 @@ macro_rules! implement_multiple_values_grouped_operand {
                 self.0.is_null();
             }
 
+            pub fn is_missing(&self) {
+                self.0.is_missing();
+            }
+
+            pub fn provenance(&self) {
+                self.0.provenance();
+            }
+
+            pub fn text_search(&self, query: String) {
+                self.0.text_search(query);
+            }
+
+            pub fn rolling(
+                &self,
+                window: TimeDelta,
+                aggregation: PyRollingAggregation,
+                order_by_attribute: PyGraphRecordAttribute,
+            ) {
+                self.0
+                    .rolling(window, aggregation.into(), order_by_attribute);
+            }
+
+            pub fn cumsum(&self, order_by_attribute: PyGraphRecordAttribute) {
+                self.0.cumsum(order_by_attribute);
+            }
+
+            pub fn lag(&self, n: usize, order_by_attribute: PyGraphRecordAttribute) {
+                self.0.lag(n, order_by_attribute);
+            }
+
+            pub fn lead(&self, n: usize, order_by_attribute: PyGraphRecordAttribute) {
+                self.0.lead(n, order_by_attribute);
+            }
+
+            pub fn argmax(&self) -> $py_single_value_with_index_operand {
+                self.0.argmax().into()
+            }
+
+            pub fn argmin(&self) -> $py_single_value_with_index_operand {
+                self.0.argmin().into()
+            }
+
+            pub fn first_by(
+                &self,
+                order_by_attribute: PyGraphRecordAttribute,
+            ) -> $py_single_value_with_index_operand {
+                self.0.first_by(order_by_attribute).into()
+            }
+
+            pub fn last_by(
+                &self,
+                order_by_attribute: PyGraphRecordAttribute,
+            ) -> $py_single_value_with_index_operand {
+                self.0.last_by(order_by_attribute).into()
+            }
+
+            pub fn random_weighted(
+                &self,
+
+                weight_attribute: PyGraphRecordAttribute,
+            ) -> $py_single_value_with_index_operand {
+                self.0.random_weighted(weight_attribute).into()
+            }
+
+            pub fn when(
+                &self,
+                query: &Bound<'_, PyFunction>,
+                then: PySingleValueComparisonOperand,
+                otherwise: PySingleValueComparisonOperand,
+            ) {
+                self.0.when(
+                    |operand| {
+                        query
+                            .call1(($ungrouped_name::from(operand.clone()),))
+                            .expect("Call must succeed");
+                    },
+                    then,
+                    otherwise,
+                );
+            }
+
             pub fn is_max(&self) {
                 self.0.is_max();
             }
@@ -606,6 +1025,22 @@ macro_rules! implement_multiple_values_grouped_operand {
                 self.0.is_min();
             }
 
+            pub fn distinct(&self) {
+                self.0.distinct();
+            }
+
+            pub fn sort(&self, descending: bool) {
+                self.0.sort(descending);
+            }
+
+            pub fn skip(&self, count: usize) {
+                self.0.skip(count);
+            }
+
+            pub fn limit(&self, count: usize) {
+                self.0.limit(count);
+            }
+
             pub fn either_or(&self, either: &Bound<'_, PyFunction>, or: &Bound<'_, PyFunction>) {
                 self.0.either_or(
                     |operand| {
@@ -632,6 +1067,14 @@ macro_rules! implement_multiple_values_grouped_operand {
                 self.0.ungroup().into()
             }
 
+            pub fn sorted_by_key(&self) {
+                self.0.sorted_by_key();
+            }
+
+            pub fn sorted_by_aggregate(&self, aggregate: PyGroupAggregate) {
+                self.0.sorted_by_aggregate(aggregate);
+            }
+
             pub fn deep_clone(&self) -> $name {
                 self.0.deep_clone().into()
             }
@@ -721,6 +1164,30 @@ macro_rules! implement_single_value_operand {
                 self.0.contains(value);
             }
 
+            pub fn levenshtein_at_most(
+                &self,
+                target: PySingleValueComparisonOperand,
+                distance: usize,
+            ) {
+                self.0.levenshtein_at_most(target, distance);
+            }
+
+            pub fn jaro_winkler_at_least(
+                &self,
+                target: PySingleValueComparisonOperand,
+                similarity: f64,
+            ) {
+                self.0.jaro_winkler_at_least(target, similarity);
+            }
+
+            pub fn is_close(&self, value: PySingleValueComparisonOperand, tolerance: f64) {
+                self.0.is_close(value, tolerance);
+            }
+
+            pub fn within_radius(&self, point: PySingleValueComparisonOperand, radius_km: f64) {
+                self.0.within_radius(point, radius_km);
+            }
+
             pub fn is_in(&self, values: PyMultipleValuesComparisonOperand) {
                 self.0.is_in(values);
             }
@@ -769,6 +1236,10 @@ macro_rules! implement_single_value_operand {
                 self.0.abs();
             }
 
+            pub fn apply(&self, udf: String) {
+                self.0.apply(udf);
+            }
+
             pub fn sqrt(&self) {
                 self.0.sqrt();
             }
@@ -825,6 +1296,10 @@ macro_rules! implement_single_value_operand {
                 self.0.is_null();
             }
 
+            pub fn is_missing(&self) {
+                self.0.is_missing();
+            }
+
             pub fn either_or(&self, either: &Bound<'_, PyFunction>, or: &Bound<'_, PyFunction>) {
                 self.0.either_or(
                     |operand| {
@@ -940,6 +1415,30 @@ macro_rules! implement_single_value_group_operand {
                 self.0.contains(value);
             }
 
+            pub fn levenshtein_at_most(
+                &self,
+                target: PySingleValueComparisonOperand,
+                distance: usize,
+            ) {
+                self.0.levenshtein_at_most(target, distance);
+            }
+
+            pub fn jaro_winkler_at_least(
+                &self,
+                target: PySingleValueComparisonOperand,
+                similarity: f64,
+            ) {
+                self.0.jaro_winkler_at_least(target, similarity);
+            }
+
+            pub fn is_close(&self, value: PySingleValueComparisonOperand, tolerance: f64) {
+                self.0.is_close(value, tolerance);
+            }
+
+            pub fn within_radius(&self, point: PySingleValueComparisonOperand, radius_km: f64) {
+                self.0.within_radius(point, radius_km);
+            }
+
             pub fn is_in(&self, values: PyMultipleValuesComparisonOperand) {
                 self.0.is_in(values);
             }
@@ -988,6 +1487,10 @@ macro_rules! implement_single_value_group_operand {
                 self.0.abs();
             }
 
+            pub fn apply(&self, udf: String) {
+                self.0.apply(udf);
+            }
+
             pub fn sqrt(&self) {
                 self.0.sqrt();
             }
@@ -1044,6 +1547,10 @@ macro_rules! implement_single_value_group_operand {
                 self.0.is_null();
             }
 
+            pub fn is_missing(&self) {
+                self.0.is_missing();
+            }
+
             pub fn either_or(&self, either: &Bound<'_, PyFunction>, or: &Bound<'_, PyFunction>) {
                 self.0.either_or(
                     |operand| {
@@ -1070,6 +1577,14 @@ macro_rules! implement_single_value_group_operand {
                 self.0.ungroup().into()
             }
 
+            pub fn sorted_by_key(&self) {
+                self.0.sorted_by_key();
+            }
+
+            pub fn sorted_by_aggregate(&self, aggregate: PyGroupAggregate) {
+                self.0.sorted_by_aggregate(aggregate);
+            }
+
             pub fn deep_clone(&self) -> $name {
                 self.0.deep_clone().into()
             }
@@ -1105,3 +1620,46 @@ implement_single_value_group_operand!(
     SingleValueWithoutIndexOperand,
     EdgeOperand
 );
+
+macro_rules! implement_summary_operand {
+    ($name:ident, $kind:ident, $generic:ty) => {
+        #[pyclass(frozen)]
+        #[repr(transparent)]
+        #[derive(Clone)]
+        pub struct $name(Wrapper<$kind<$generic>>);
+
+        impl From<Wrapper<$kind<$generic>>> for $name {
+            fn from(operand: Wrapper<$kind<$generic>>) -> Self {
+                Self(operand)
+            }
+        }
+
+        impl From<$name> for Wrapper<$kind<$generic>> {
+            fn from(operand: $name) -> Self {
+                operand.0
+            }
+        }
+
+        impl Deref for $name {
+            type Target = Wrapper<$kind<$generic>>;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        #[pymethods]
+        impl $name {
+            pub fn deep_clone(&self) -> $name {
+                self.0.deep_clone().into()
+            }
+        }
+    };
+}
+
+implement_summary_operand!(PyNodeValueCountsOperand, ValueCountsOperand, NodeOperand);
+implement_summary_operand!(PyEdgeValueCountsOperand, ValueCountsOperand, EdgeOperand);
+implement_summary_operand!(PyNodeHistogramOperand, HistogramOperand, NodeOperand);
+implement_summary_operand!(PyEdgeHistogramOperand, HistogramOperand, EdgeOperand);
+implement_summary_operand!(PyNodeQuantilesOperand, QuantilesOperand, NodeOperand);
+implement_summary_operand!(PyEdgeQuantilesOperand, QuantilesOperand, EdgeOperand);
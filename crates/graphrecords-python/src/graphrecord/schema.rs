@@ -8,8 +8,12 @@ use super::{
 use graphrecords_core::{
     errors::GraphError,
     graphrecord::{
-        EdgeIndex, Group,
-        schema::{AttributeDataType, AttributeType, GroupSchema, Schema, SchemaType},
+        Attributes, EdgeIndex, Group,
+        schema::{
+            AttributeDataType, AttributeType, AttributeTypeInference, ConflictStrategy,
+            DataTypeWidening, GroupSchema, NonFiniteFloatPolicy, Schema, SchemaChange,
+            SchemaConflict, SchemaDiff, SchemaType,
+        },
     },
 };
 use parking_lot::RwLock;
@@ -172,14 +176,14 @@ impl PyGroupSchema {
     pub fn validate_node(&self, index: PyNodeIndex, attributes: PyAttributes) -> PyResult<()> {
         Ok(self
             .0
-            .validate_node(&index.into(), &attributes.deep_into())
+            .validate_node(&index.into(), &Attributes::deep_from(attributes))
             .map_err(PyGraphRecordError::from)?)
     }
 
     pub fn validate_edge(&self, index: EdgeIndex, attributes: PyAttributes) -> PyResult<()> {
         Ok(self
             .0
-            .validate_edge(&index, &attributes.deep_into())
+            .validate_edge(&index, &Attributes::deep_from(attributes))
             .map_err(PyGraphRecordError::from)?)
     }
 }
@@ -209,6 +213,120 @@ impl From<PySchemaType> for SchemaType {
     }
 }
 
+fn widening_from_rules(rules: Vec<(PyDataType, PyDataType)>) -> DataTypeWidening {
+    rules
+        .into_iter()
+        .fold(DataTypeWidening::default(), |widening, (from, into)| {
+            widening.with_rule(from.into(), into.into())
+        })
+}
+
+#[pyclass(frozen, eq, eq_int)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PyNonFiniteFloatPolicy {
+    Allow = 0,
+    Reject = 1,
+    CoerceToNull = 2,
+}
+
+impl From<NonFiniteFloatPolicy> for PyNonFiniteFloatPolicy {
+    fn from(value: NonFiniteFloatPolicy) -> Self {
+        match value {
+            NonFiniteFloatPolicy::Allow => Self::Allow,
+            NonFiniteFloatPolicy::Reject => Self::Reject,
+            NonFiniteFloatPolicy::CoerceToNull => Self::CoerceToNull,
+        }
+    }
+}
+
+impl From<PyNonFiniteFloatPolicy> for NonFiniteFloatPolicy {
+    fn from(value: PyNonFiniteFloatPolicy) -> Self {
+        match value {
+            PyNonFiniteFloatPolicy::Allow => Self::Allow,
+            PyNonFiniteFloatPolicy::Reject => Self::Reject,
+            PyNonFiniteFloatPolicy::CoerceToNull => Self::CoerceToNull,
+        }
+    }
+}
+
+#[pyclass(frozen, eq, eq_int)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PyConflictStrategy {
+    Widen = 0,
+    KeepFirst = 1,
+    KeepSecond = 2,
+    Reject = 3,
+}
+
+impl From<PyConflictStrategy> for ConflictStrategy {
+    fn from(value: PyConflictStrategy) -> Self {
+        match value {
+            PyConflictStrategy::Widen => Self::Widen,
+            PyConflictStrategy::KeepFirst => Self::KeepFirst,
+            PyConflictStrategy::KeepSecond => Self::KeepSecond,
+            PyConflictStrategy::Reject => Self::Reject,
+        }
+    }
+}
+
+type PySchemaConflict = (
+    Option<PyGroup>,
+    PyGraphRecordAttribute,
+    PyAttributeDataType,
+    PyAttributeDataType,
+);
+
+type PySchemaChange = (
+    Option<PyGroup>,
+    PyGraphRecordAttribute,
+    Option<PyAttributeDataType>,
+    Option<PyAttributeDataType>,
+);
+
+pub(crate) type PySchemaDiff = (
+    Vec<PyGroup>,
+    Vec<PyGroup>,
+    Vec<PySchemaChange>,
+    Vec<PySchemaChange>,
+);
+
+fn into_py_schema_changes(changes: Vec<SchemaChange>) -> Vec<PySchemaChange> {
+    changes
+        .into_iter()
+        .map(|change| {
+            (
+                change.group.map(Into::into),
+                change.attribute.into(),
+                change.old.map(Into::into),
+                change.new.map(Into::into),
+            )
+        })
+        .collect()
+}
+
+fn into_py_schema_diff(diff: SchemaDiff) -> PySchemaDiff {
+    (
+        diff.added_groups.deep_into(),
+        diff.removed_groups.deep_into(),
+        into_py_schema_changes(diff.node_changes),
+        into_py_schema_changes(diff.edge_changes),
+    )
+}
+
+fn into_py_schema_conflicts(conflicts: Vec<SchemaConflict>) -> Vec<PySchemaConflict> {
+    conflicts
+        .into_iter()
+        .map(|conflict| {
+            (
+                conflict.group.map(Into::into),
+                conflict.attribute.into(),
+                conflict.first.into(),
+                conflict.second.into(),
+            )
+        })
+        .collect()
+}
+
 #[pyclass(frozen)]
 #[repr(transparent)]
 #[derive(Debug)]
@@ -252,10 +370,17 @@ impl PySchema {
     }
 
     #[staticmethod]
-    pub fn infer(graphrecord: Bound<'_, PyGraphRecord>) -> PyResult<Self> {
+    #[pyo3(signature = (graphrecord, widening=Vec::new()))]
+    pub fn infer(
+        graphrecord: Bound<'_, PyGraphRecord>,
+        widening: Vec<(PyDataType, PyDataType)>,
+    ) -> PyResult<Self> {
         let graphrecord = graphrecord.get();
 
-        Ok(Schema::infer(&*graphrecord.inner()?).into())
+        Ok(
+            Schema::infer_with_widening(&*graphrecord.inner()?, widening_from_rules(widening))
+                .into(),
+        )
     }
 
     #[getter]
@@ -288,6 +413,78 @@ impl PySchema {
         self.0.read().schema_type().clone().into()
     }
 
+    #[getter]
+    pub fn widening(&self) -> Vec<(PyDataType, PyDataType)> {
+        self.0
+            .read()
+            .widening()
+            .rules()
+            .iter()
+            .map(|(from, into)| (from.clone().into(), into.clone().into()))
+            .collect()
+    }
+
+    pub fn set_widening(&self, widening: Vec<(PyDataType, PyDataType)>) {
+        self.0.write().set_widening(widening_from_rules(widening));
+    }
+
+    #[pyo3(signature = (graphrecord, max_categories=50, max_distinct_ratio=0.5, overrides=HashMap::new()))]
+    pub fn infer_attribute_types(
+        &self,
+        graphrecord: Bound<'_, PyGraphRecord>,
+        max_categories: usize,
+        max_distinct_ratio: f64,
+        overrides: HashMap<PyGraphRecordAttribute, PyAttributeType>,
+    ) -> PyResult<()> {
+        let graphrecord = graphrecord.get();
+
+        let inference = AttributeTypeInference {
+            max_categories,
+            max_distinct_ratio,
+            overrides: overrides
+                .into_iter()
+                .map(|(attribute, attribute_type)| (attribute.into(), attribute_type.into()))
+                .collect(),
+        };
+
+        Ok(self
+            .0
+            .write()
+            .infer_attribute_types_with(&*graphrecord.inner()?, &inference)
+            .map_err(PyGraphRecordError::from)?)
+    }
+
+    pub fn merge(
+        &self,
+        other: &Self,
+        strategy: PyConflictStrategy,
+    ) -> PyResult<(Self, Vec<PySchemaConflict>, Vec<PySchemaConflict>)> {
+        let merge = self
+            .0
+            .read()
+            .merge(&other.0.read(), strategy.into())
+            .map_err(PyGraphRecordError::from)?;
+
+        Ok((
+            merge.schema.into(),
+            into_py_schema_conflicts(merge.node_conflicts),
+            into_py_schema_conflicts(merge.edge_conflicts),
+        ))
+    }
+
+    pub fn diff(&self, new: &Self) -> PySchemaDiff {
+        into_py_schema_diff(self.0.read().diff(&new.0.read()))
+    }
+
+    #[getter]
+    pub fn non_finite_float_policy(&self) -> PyNonFiniteFloatPolicy {
+        self.0.read().non_finite_float_policy().into()
+    }
+
+    pub fn set_non_finite_float_policy(&self, policy: PyNonFiniteFloatPolicy) {
+        self.0.write().set_non_finite_float_policy(policy.into());
+    }
+
     #[pyo3(signature = (index, attributes, group=None))]
     pub fn validate_node(
         &self,
@@ -300,7 +497,7 @@ impl PySchema {
             .read()
             .validate_node(
                 &index.into(),
-                &attributes.deep_into(),
+                &Attributes::deep_from(attributes),
                 group.map(std::convert::Into::into).as_ref(),
             )
             .map_err(PyGraphRecordError::from)?)
@@ -318,7 +515,7 @@ impl PySchema {
             .read()
             .validate_edge(
                 &index,
-                &attributes.deep_into(),
+                &Attributes::deep_from(attributes),
                 group.map(std::convert::Into::into).as_ref(),
             )
             .map_err(PyGraphRecordError::from)?)
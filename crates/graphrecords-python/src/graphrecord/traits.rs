@@ -1,3 +1,4 @@
+use graphrecords_core::graphrecord::{AttributeMap, GraphRecordAttribute, GraphRecordValue};
 use graphrecords_utils::aliases::GrHashMap;
 use std::{
     collections::HashMap,
@@ -66,6 +67,20 @@ where
     }
 }
 
+impl<KF, VF, H> DeepFrom<&AttributeMap> for HashMap<KF, VF, H>
+where
+    KF: Hash + Eq + DeepFrom<GraphRecordAttribute>,
+    VF: DeepFrom<GraphRecordValue>,
+    H: BuildHasher + Default,
+{
+    fn deep_from(value: &AttributeMap) -> Self {
+        value
+            .iter()
+            .map(|(key, value)| (key.clone().deep_into(), value.clone().deep_into()))
+            .collect()
+    }
+}
+
 impl<K, KF, V, VF> DeepFrom<(K, V)> for (KF, VF)
 where
     KF: DeepFrom<K>,
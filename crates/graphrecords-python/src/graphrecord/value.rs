@@ -1,12 +1,19 @@
 use super::{Lut, traits::DeepFrom};
 use crate::{conversion_lut::ConversionLut, graphrecord::errors::PyGraphRecordError};
 use chrono::{NaiveDateTime, TimeDelta};
-use graphrecords_core::{errors::GraphRecordError, graphrecord::GraphRecordValue};
+use graphrecords_core::{
+    errors::GraphRecordError,
+    graphrecord::{GraphRecordValue, datatypes::GeoPoint},
+};
 use pyo3::{
     Borrowed, Bound, FromPyObject, IntoPyObject, IntoPyObjectExt, PyAny, PyErr, PyResult, Python,
+    pyclass, pymethods,
     types::{PyAnyMethods, PyBool, PyDateTime, PyDelta, PyFloat, PyInt, PyString},
 };
-use std::ops::Deref;
+use std::{
+    hash::{DefaultHasher, Hash, Hasher},
+    ops::Deref,
+};
 
 #[repr(transparent)]
 #[derive(Clone, Debug)]
@@ -44,6 +51,83 @@ impl Deref for PyGraphRecordValue {
     }
 }
 
+/// Marks a value that was never recorded, as opposed to `None`.
+#[pyclass(frozen, eq, hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PyMissing;
+
+#[pymethods]
+impl PyMissing {
+    #[new]
+    pub const fn new() -> Self {
+        Self
+    }
+
+    pub const fn __repr__(&self) -> &'static str {
+        "MISSING"
+    }
+}
+
+/// A location given by its latitude and longitude in degrees.
+#[pyclass(frozen)]
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PyGeoPoint(GeoPoint);
+
+impl From<GeoPoint> for PyGeoPoint {
+    fn from(value: GeoPoint) -> Self {
+        Self(value)
+    }
+}
+
+impl From<PyGeoPoint> for GeoPoint {
+    fn from(value: PyGeoPoint) -> Self {
+        value.0
+    }
+}
+
+#[pymethods]
+impl PyGeoPoint {
+    #[new]
+    pub fn new(latitude: f64, longitude: f64) -> PyResult<Self> {
+        Ok(Self(
+            GeoPoint::new(latitude, longitude).map_err(PyGraphRecordError::from)?,
+        ))
+    }
+
+    #[getter]
+    pub const fn latitude(&self) -> f64 {
+        self.0.latitude()
+    }
+
+    #[getter]
+    pub const fn longitude(&self) -> f64 {
+        self.0.longitude()
+    }
+
+    pub fn distance_km(&self, other: Self) -> f64 {
+        self.0.distance_km(&other.0)
+    }
+
+    pub fn __eq__(&self, other: Self) -> bool {
+        self == &other
+    }
+
+    pub fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        GraphRecordValue::GeoPoint(self.0).hash(&mut hasher);
+        hasher.finish()
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "GeoPoint(latitude={}, longitude={})",
+            self.0.latitude(),
+            self.0.longitude()
+        )
+    }
+}
+
 static GRAPHRECORDVALUE_CONVERSION_LUT: Lut<GraphRecordValue> = ConversionLut::new();
 
 #[allow(clippy::unnecessary_wraps)]
@@ -91,6 +175,18 @@ pub(crate) fn convert_pyobject_to_graphrecordvalue(
         Ok(GraphRecordValue::Null)
     }
 
+    const fn convert_missing(_ob: &Bound<'_, PyAny>) -> PyResult<GraphRecordValue> {
+        Ok(GraphRecordValue::Missing)
+    }
+
+    fn convert_geo_point(ob: &Bound<'_, PyAny>) -> PyResult<GraphRecordValue> {
+        Ok(GraphRecordValue::GeoPoint(
+            ob.extract::<PyGeoPoint>()
+                .expect("Extraction must succeed")
+                .into(),
+        ))
+    }
+
     fn throw_error(ob: &Bound<'_, PyAny>) -> PyResult<GraphRecordValue> {
         Err(
             PyGraphRecordError::from(GraphRecordError::ConversionError(format!(
@@ -117,6 +213,10 @@ pub(crate) fn convert_pyobject_to_graphrecordvalue(
             convert_duration
         } else if ob.is_none() {
             convert_null
+        } else if ob.is_instance_of::<PyMissing>() {
+            convert_missing
+        } else if ob.is_instance_of::<PyGeoPoint>() {
+            convert_geo_point
         } else {
             throw_error
         }
@@ -146,7 +246,9 @@ impl<'py> IntoPyObject<'py> for PyGraphRecordValue {
             GraphRecordValue::Bool(value) => value.into_bound_py_any(py),
             GraphRecordValue::DateTime(value) => value.into_bound_py_any(py),
             GraphRecordValue::Duration(value) => value.into_bound_py_any(py),
+            GraphRecordValue::GeoPoint(value) => PyGeoPoint(value).into_bound_py_any(py),
             GraphRecordValue::Null => py.None().into_bound_py_any(py),
+            GraphRecordValue::Missing => PyMissing.into_bound_py_any(py),
         }
     }
 }
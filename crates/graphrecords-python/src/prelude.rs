@@ -1,28 +1,40 @@
 pub use crate::graphrecord::{
     PyAttributes, PyEdgeIndex, PyGraphRecord, PyGroup, PyNodeIndex,
+    association::PyCorrelationMethod,
     attribute::PyGraphRecordAttribute,
+    attribute_scope::PyAttributeScope,
+    crosstab::PyCrosstabNormalization,
     datatype::{
-        PyAny, PyBool, PyDateTime, PyDuration, PyFloat, PyInt, PyNull, PyOption, PyString, PyUnion,
+        PyAny, PyBool, PyDateTime, PyDuration, PyFloat, PyGeo, PyInt, PyMissingType, PyNull,
+        PyOption, PyString, PyUnion,
     },
-    errors::PyGraphRecordError,
+    errors::{
+        ConversionError, DuplicateGroupError, GraphRecordAssertionError, GraphRecordError,
+        GraphRecordIndexError, GraphRecordKeyError, MissingNodeError, PyGraphRecordError,
+        QueryError, ReadOnlyError, SchemaError, TypeMismatchError,
+    },
+    ingest::PyShardedIngest,
+    metadata::PyRecordMetadata,
     overview::{
         PyAttributeOverview, PyEdgeGroupOverview, PyGroupOverview, PyNodeGroupOverview, PyOverview,
+        PyOverviewFormat,
     },
     plugins::{
-        PyPostAddEdgeContext, PyPostAddEdgeToGroupContext, PyPostAddEdgeToGroupsContext,
-        PyPostAddEdgeWithGroupContext, PyPostAddEdgeWithGroupsContext, PyPostAddEdgesContext,
-        PyPostAddEdgesDataframesContext, PyPostAddEdgesDataframesWithGroupContext,
-        PyPostAddEdgesDataframesWithGroupsContext, PyPostAddEdgesToGroupsContext,
-        PyPostAddEdgesWithGroupContext, PyPostAddEdgesWithGroupsContext, PyPostAddGroupContext,
-        PyPostAddNodeContext, PyPostAddNodeToGroupContext, PyPostAddNodeToGroupsContext,
-        PyPostAddNodeWithGroupContext, PyPostAddNodeWithGroupsContext, PyPostAddNodesContext,
-        PyPostAddNodesDataframesContext, PyPostAddNodesDataframesWithGroupContext,
-        PyPostAddNodesDataframesWithGroupsContext, PyPostAddNodesToGroupsContext,
-        PyPostAddNodesWithGroupContext, PyPostAddNodesWithGroupsContext, PyPostRemoveEdgeContext,
-        PyPostRemoveEdgeFromGroupContext, PyPostRemoveEdgeFromGroupsContext,
-        PyPostRemoveEdgesFromGroupsContext, PyPostRemoveGroupContext, PyPostRemoveNodeContext,
-        PyPostRemoveNodeFromGroupContext, PyPostRemoveNodeFromGroupsContext,
-        PyPostRemoveNodesFromGroupsContext, PyPreAddEdgeContext, PyPreAddEdgeToGroupContext,
+        PyMappingPlugin, PyPostAddEdgeContext, PyPostAddEdgeToGroupContext,
+        PyPostAddEdgeToGroupsContext, PyPostAddEdgeWithGroupContext,
+        PyPostAddEdgeWithGroupsContext, PyPostAddEdgesContext, PyPostAddEdgesDataframesContext,
+        PyPostAddEdgesDataframesWithGroupContext, PyPostAddEdgesDataframesWithGroupsContext,
+        PyPostAddEdgesToGroupsContext, PyPostAddEdgesWithGroupContext,
+        PyPostAddEdgesWithGroupsContext, PyPostAddGroupContext, PyPostAddNodeContext,
+        PyPostAddNodeToGroupContext, PyPostAddNodeToGroupsContext, PyPostAddNodeWithGroupContext,
+        PyPostAddNodeWithGroupsContext, PyPostAddNodesContext, PyPostAddNodesDataframesContext,
+        PyPostAddNodesDataframesWithGroupContext, PyPostAddNodesDataframesWithGroupsContext,
+        PyPostAddNodesToGroupsContext, PyPostAddNodesWithGroupContext,
+        PyPostAddNodesWithGroupsContext, PyPostRemoveEdgeContext, PyPostRemoveEdgeFromGroupContext,
+        PyPostRemoveEdgeFromGroupsContext, PyPostRemoveEdgesFromGroupsContext,
+        PyPostRemoveGroupContext, PyPostRemoveNodeContext, PyPostRemoveNodeFromGroupContext,
+        PyPostRemoveNodeFromGroupsContext, PyPostRemoveNodesFromGroupsContext,
+        PyPostSetSchemaContext, PyPreAddEdgeContext, PyPreAddEdgeToGroupContext,
         PyPreAddEdgeToGroupsContext, PyPreAddEdgeWithGroupContext, PyPreAddEdgeWithGroupsContext,
         PyPreAddEdgesContext, PyPreAddEdgesDataframesContext,
         PyPreAddEdgesDataframesWithGroupContext, PyPreAddEdgesDataframesWithGroupsContext,
@@ -37,6 +49,7 @@ pub use crate::graphrecord::{
         PyPreRemoveGroupContext, PyPreRemoveNodeContext, PyPreRemoveNodeFromGroupContext,
         PyPreRemoveNodeFromGroupsContext, PyPreRemoveNodesFromGroupsContext, PyPreSetSchemaContext,
     },
+    provenance::PyProvenanceTag,
     querying::{
         PyMatchMode,
         attributes::{
@@ -62,15 +75,21 @@ pub use crate::graphrecord::{
             PyNodeIndicesOperand, PyNodeOperand,
         },
         values::{
-            PyEdgeMultipleValuesWithIndexGroupOperand, PyEdgeMultipleValuesWithIndexOperand,
-            PyEdgeMultipleValuesWithoutIndexOperand, PyEdgeSingleValueWithIndexGroupOperand,
+            PyEdgeHistogramOperand, PyEdgeMultipleValuesWithIndexGroupOperand,
+            PyEdgeMultipleValuesWithIndexOperand, PyEdgeMultipleValuesWithoutIndexOperand,
+            PyEdgeQuantilesOperand, PyEdgeSingleValueWithIndexGroupOperand,
             PyEdgeSingleValueWithIndexOperand, PyEdgeSingleValueWithoutIndexGroupOperand,
-            PyEdgeSingleValueWithoutIndexOperand, PyNodeMultipleValuesWithIndexGroupOperand,
-            PyNodeMultipleValuesWithIndexOperand, PyNodeMultipleValuesWithoutIndexOperand,
+            PyEdgeSingleValueWithoutIndexOperand, PyEdgeValueCountsOperand, PyNodeHistogramOperand,
+            PyNodeMultipleValuesWithIndexGroupOperand, PyNodeMultipleValuesWithIndexOperand,
+            PyNodeMultipleValuesWithoutIndexOperand, PyNodeQuantilesOperand,
             PyNodeSingleValueWithIndexGroupOperand, PyNodeSingleValueWithIndexOperand,
             PyNodeSingleValueWithoutIndexGroupOperand, PyNodeSingleValueWithoutIndexOperand,
+            PyNodeValueCountsOperand, PyRollingAggregation,
         },
     },
-    schema::{PyAttributeDataType, PyAttributeType, PyGroupSchema, PySchema, PySchemaType},
-    value::PyGraphRecordValue,
+    schema::{
+        PyAttributeDataType, PyAttributeType, PyConflictStrategy, PyGroupSchema,
+        PyNonFiniteFloatPolicy, PySchema, PySchemaType,
+    },
+    value::{PyGeoPoint, PyGraphRecordValue, PyMissing},
 };
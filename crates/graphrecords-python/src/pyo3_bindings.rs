@@ -4,8 +4,24 @@ pub mod _graphrecords {
 
     #[pymodule]
     pub mod graphrecord {
+        #[pymodule_export]
+        use crate::prelude::PyAttributeScope;
+        #[pymodule_export]
+        use crate::prelude::PyCorrelationMethod;
+        #[pymodule_export]
+        use crate::prelude::PyCrosstabNormalization;
+        #[pymodule_export]
+        use crate::prelude::PyGeoPoint;
         #[pymodule_export]
         use crate::prelude::PyGraphRecord;
+        #[pymodule_export]
+        use crate::prelude::PyMissing;
+        #[pymodule_export]
+        use crate::prelude::PyProvenanceTag;
+        #[pymodule_export]
+        use crate::prelude::PyRecordMetadata;
+        #[pymodule_export]
+        use crate::prelude::PyShardedIngest;
     }
 
     #[pymodule]
@@ -21,8 +37,12 @@ pub mod _graphrecords {
         #[pymodule_export]
         use crate::prelude::PyFloat;
         #[pymodule_export]
+        use crate::prelude::PyGeo;
+        #[pymodule_export]
         use crate::prelude::PyInt;
         #[pymodule_export]
+        use crate::prelude::PyMissingType;
+        #[pymodule_export]
         use crate::prelude::PyNull;
         #[pymodule_export]
         use crate::prelude::PyOption;
@@ -39,8 +59,12 @@ pub mod _graphrecords {
         #[pymodule_export]
         use crate::prelude::PyAttributeType;
         #[pymodule_export]
+        use crate::prelude::PyConflictStrategy;
+        #[pymodule_export]
         use crate::prelude::PyGroupSchema;
         #[pymodule_export]
+        use crate::prelude::PyNonFiniteFloatPolicy;
+        #[pymodule_export]
         use crate::prelude::PySchema;
         #[pymodule_export]
         use crate::prelude::PySchemaType;
@@ -61,6 +85,8 @@ pub mod _graphrecords {
         #[pymodule_export]
         use crate::prelude::PyEdgeGroupOperand;
         #[pymodule_export]
+        use crate::prelude::PyEdgeHistogramOperand;
+        #[pymodule_export]
         use crate::prelude::PyEdgeIndexGroupOperand;
         #[pymodule_export]
         use crate::prelude::PyEdgeIndexOperand;
@@ -83,6 +109,8 @@ pub mod _graphrecords {
         #[pymodule_export]
         use crate::prelude::PyEdgeOperand;
         #[pymodule_export]
+        use crate::prelude::PyEdgeQuantilesOperand;
+        #[pymodule_export]
         use crate::prelude::PyEdgeSingleAttributeWithIndexGroupOperand;
         #[pymodule_export]
         use crate::prelude::PyEdgeSingleAttributeWithIndexOperand;
@@ -99,6 +127,8 @@ pub mod _graphrecords {
         #[pymodule_export]
         use crate::prelude::PyEdgeSingleValueWithoutIndexOperand;
         #[pymodule_export]
+        use crate::prelude::PyEdgeValueCountsOperand;
+        #[pymodule_export]
         use crate::prelude::PyMatchMode;
         #[pymodule_export]
         use crate::prelude::PyNodeAttributesTreeGroupOperand;
@@ -107,6 +137,8 @@ pub mod _graphrecords {
         #[pymodule_export]
         use crate::prelude::PyNodeGroupOperand;
         #[pymodule_export]
+        use crate::prelude::PyNodeHistogramOperand;
+        #[pymodule_export]
         use crate::prelude::PyNodeIndexGroupOperand;
         #[pymodule_export]
         use crate::prelude::PyNodeIndexOperand;
@@ -129,6 +161,8 @@ pub mod _graphrecords {
         #[pymodule_export]
         use crate::prelude::PyNodeOperand;
         #[pymodule_export]
+        use crate::prelude::PyNodeQuantilesOperand;
+        #[pymodule_export]
         use crate::prelude::PyNodeSingleAttributeWithIndexGroupOperand;
         #[pymodule_export]
         use crate::prelude::PyNodeSingleAttributeWithIndexOperand;
@@ -144,6 +178,10 @@ pub mod _graphrecords {
         use crate::prelude::PyNodeSingleValueWithoutIndexGroupOperand;
         #[pymodule_export]
         use crate::prelude::PyNodeSingleValueWithoutIndexOperand;
+        #[pymodule_export]
+        use crate::prelude::PyNodeValueCountsOperand;
+        #[pymodule_export]
+        use crate::prelude::PyRollingAggregation;
     }
 
     #[pymodule]
@@ -160,6 +198,8 @@ pub mod _graphrecords {
         use crate::prelude::PyNodeGroupOverview;
         #[pymodule_export]
         use crate::prelude::PyOverview;
+        #[pymodule_export]
+        use crate::prelude::PyOverviewFormat;
 
         #[pymodule_init]
         fn init(m: &Bound<'_, PyModule>) -> PyResult<()> {
@@ -170,8 +210,36 @@ pub mod _graphrecords {
         }
     }
 
+    #[pymodule]
+    pub mod errors {
+        #[pymodule_export]
+        use crate::prelude::ConversionError;
+        #[pymodule_export]
+        use crate::prelude::DuplicateGroupError;
+        #[pymodule_export]
+        use crate::prelude::GraphRecordAssertionError;
+        #[pymodule_export]
+        use crate::prelude::GraphRecordError;
+        #[pymodule_export]
+        use crate::prelude::GraphRecordIndexError;
+        #[pymodule_export]
+        use crate::prelude::GraphRecordKeyError;
+        #[pymodule_export]
+        use crate::prelude::MissingNodeError;
+        #[pymodule_export]
+        use crate::prelude::QueryError;
+        #[pymodule_export]
+        use crate::prelude::ReadOnlyError;
+        #[pymodule_export]
+        use crate::prelude::SchemaError;
+        #[pymodule_export]
+        use crate::prelude::TypeMismatchError;
+    }
+
     #[pymodule]
     pub mod plugins {
+        #[pymodule_export]
+        use crate::prelude::PyMappingPlugin;
         #[pymodule_export]
         use crate::prelude::PyPostAddEdgeContext;
         #[pymodule_export]
@@ -241,6 +309,8 @@ pub mod _graphrecords {
         #[pymodule_export]
         use crate::prelude::PyPostRemoveNodesFromGroupsContext;
         #[pymodule_export]
+        use crate::prelude::PyPostSetSchemaContext;
+        #[pymodule_export]
         use crate::prelude::PyPreAddEdgeContext;
         #[pymodule_export]
         use crate::prelude::PyPreAddEdgeToGroupContext;
@@ -326,6 +396,7 @@ pub mod _graphrecords {
             "schema",
             "querying",
             "overview",
+            "errors",
             "plugins",
         ] {
             let submodule = module.getattr(submodule_name)?;
@@ -0,0 +1,267 @@
+use extendr_api::prelude::*;
+use graphrecords_core::{
+    errors::{GraphRecordError, GraphRecordResult},
+    graphrecord::{
+        EdgeIndex, GraphRecordAttribute, GraphRecordValue, overview::OverviewFormat,
+        querying::plan::Comparison,
+    },
+};
+use polars::prelude::{DataFrame, IpcStreamReader, SerReader};
+use std::io::Cursor;
+
+fn error_message(error: &GraphRecordError) -> String {
+    format!("[{}] {}", error.code(), error.message())
+}
+
+pub fn graphrecord_error(error: GraphRecordError) -> Error {
+    Error::Other(error_message(&error))
+}
+
+fn conversion_error(message: impl Into<String>) -> Error {
+    graphrecord_error(GraphRecordError::ConversionError(message.into()))
+}
+
+/// Reads a data frame written by `arrow::write_to_raw(df, format = "stream")`.
+pub fn dataframe_from_arrow(bytes: &[u8]) -> GraphRecordResult<DataFrame> {
+    IpcStreamReader::new(Cursor::new(bytes))
+        .finish()
+        .map_err(|error| {
+            GraphRecordError::ConversionError(format!("Failed to read Arrow stream: {error}"))
+        })
+}
+
+/// Converts one of `==`, `!=`, `>`, `>=`, `<`, `<=`, `starts_with`,
+/// `ends_with` and `contains` to a comparison.
+pub fn comparison_from_str(comparison: &str) -> GraphRecordResult<Comparison> {
+    match comparison {
+        "==" => Ok(Comparison::EqualTo),
+        "!=" => Ok(Comparison::NotEqualTo),
+        ">" => Ok(Comparison::GreaterThan),
+        ">=" => Ok(Comparison::GreaterThanOrEqualTo),
+        "<" => Ok(Comparison::LessThan),
+        "<=" => Ok(Comparison::LessThanOrEqualTo),
+        "starts_with" => Ok(Comparison::StartsWith),
+        "ends_with" => Ok(Comparison::EndsWith),
+        "contains" => Ok(Comparison::Contains),
+        comparison => Err(GraphRecordError::ConversionError(format!(
+            "Unknown comparison {comparison}"
+        ))),
+    }
+}
+
+pub fn overview_format_from_str(format: &str) -> GraphRecordResult<OverviewFormat> {
+    match format {
+        "modern" => Ok(OverviewFormat::Modern),
+        "ascii" => Ok(OverviewFormat::Ascii),
+        "markdown" => Ok(OverviewFormat::Markdown),
+        "csv" => Ok(OverviewFormat::Csv),
+        format => Err(GraphRecordError::ConversionError(format!(
+            "Unknown overview format {format}"
+        ))),
+    }
+}
+
+/// Converts attributes holding non-negative whole numbers to edge indices.
+pub fn attributes_to_edge_indices(
+    attributes: Vec<GraphRecordAttribute>,
+) -> GraphRecordResult<Vec<EdgeIndex>> {
+    attributes
+        .into_iter()
+        .map(|edge_index| match edge_index {
+            GraphRecordAttribute::Int(index) => EdgeIndex::try_from(index).ok(),
+            GraphRecordAttribute::String(_) => None,
+        })
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(|| {
+            GraphRecordError::ConversionError(
+                "Edge indices must be non-negative whole numbers".to_string(),
+            )
+        })
+}
+
+fn expect_scalar(robj: &Robj) -> Result<()> {
+    if robj.len() == 1 {
+        Ok(())
+    } else {
+        Err(conversion_error(format!(
+            "Expected a single value, found {} values",
+            robj.len()
+        )))
+    }
+}
+
+/// Converts a string or a whole number to an attribute, node index or group.
+pub fn robj_to_attribute(robj: &Robj) -> Result<GraphRecordAttribute> {
+    expect_scalar(robj)?;
+
+    match robj.rtype() {
+        Rtype::Strings => robj.as_str().map(GraphRecordAttribute::from),
+        Rtype::Integers => robj
+            .as_integer()
+            .map(|value| GraphRecordAttribute::Int(i64::from(value))),
+        Rtype::Doubles => robj
+            .as_real()
+            .filter(|value| value.fract() == 0.0)
+            .map(|value| GraphRecordAttribute::Int(value as i64)),
+        _ => None,
+    }
+    .ok_or_else(|| {
+        conversion_error(format!(
+            "Cannot convert {:?} into GraphRecordAttribute",
+            robj.rtype()
+        ))
+    })
+}
+
+pub fn robj_to_attributes(robj: &Robj) -> Result<Vec<GraphRecordAttribute>> {
+    if robj.is_null() {
+        return Ok(Vec::new());
+    }
+
+    match robj.as_list() {
+        Some(list) => list
+            .values()
+            .map(|value| robj_to_attribute(&value))
+            .collect(),
+        None => (0..robj.len())
+            .map(|position| robj_to_attribute(&robj.slice(position)?))
+            .collect(),
+    }
+}
+
+/// Converts a single R value. `NULL` and `NA` are converted to
+/// [`GraphRecordValue::Null`].
+pub fn robj_to_value(robj: &Robj) -> Result<GraphRecordValue> {
+    if robj.is_null() || robj.is_na() {
+        return Ok(GraphRecordValue::Null);
+    }
+
+    expect_scalar(robj)?;
+
+    match robj.rtype() {
+        Rtype::Strings => robj.as_str().map(GraphRecordValue::from),
+        Rtype::Integers => robj
+            .as_integer()
+            .map(|value| GraphRecordValue::Int(i64::from(value))),
+        Rtype::Doubles => robj.as_real().map(GraphRecordValue::Float),
+        Rtype::Logicals => robj.as_bool().map(GraphRecordValue::Bool),
+        _ => None,
+    }
+    .ok_or_else(|| {
+        conversion_error(format!(
+            "Cannot convert {:?} into GraphRecordValue",
+            robj.rtype()
+        ))
+    })
+}
+
+/// Integer attributes are returned as doubles, as R integers cannot hold all
+/// values of an `i64`.
+pub fn attribute_to_robj(attribute: &GraphRecordAttribute) -> Robj {
+    match attribute {
+        GraphRecordAttribute::Int(value) => (*value as f64).into(),
+        GraphRecordAttribute::String(value) => value.into(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        attributes_to_edge_indices, comparison_from_str, dataframe_from_arrow, error_message,
+        overview_format_from_str,
+    };
+    use graphrecords_core::{
+        errors::GraphRecordError,
+        graphrecord::{GraphRecordAttribute, overview::OverviewFormat, querying::plan::Comparison},
+    };
+    use polars::prelude::{DataFrame, IpcStreamWriter, NamedFrom, SerWriter, Series};
+
+    // Only conversions that do not call into R are tested here, as the tests
+    // run without an R session
+
+    #[test]
+    fn test_error_message() {
+        assert_eq!(
+            "[GR0101] Cannot find node with index 0",
+            error_message(&GraphRecordError::MissingNode { index: 0.into() })
+        );
+    }
+
+    #[test]
+    fn test_dataframe_from_arrow() {
+        let mut dataframe = DataFrame::new(
+            2,
+            vec![
+                Series::new("index".into(), &["0", "1"]).into(),
+                Series::new("age".into(), &[20, 30]).into(),
+            ],
+        )
+        .unwrap();
+
+        let mut bytes = Vec::new();
+        IpcStreamWriter::new(&mut bytes)
+            .finish(&mut dataframe)
+            .unwrap();
+
+        assert_eq!(dataframe, dataframe_from_arrow(&bytes).unwrap());
+
+        assert!(matches!(
+            dataframe_from_arrow(&[0, 1, 2]),
+            Err(GraphRecordError::ConversionError(_))
+        ));
+    }
+
+    #[test]
+    fn test_comparison_from_str() {
+        assert_eq!(Comparison::EqualTo, comparison_from_str("==").unwrap());
+        assert_eq!(
+            Comparison::GreaterThanOrEqualTo,
+            comparison_from_str(">=").unwrap()
+        );
+        assert_eq!(
+            Comparison::StartsWith,
+            comparison_from_str("starts_with").unwrap()
+        );
+
+        assert_eq!(
+            "Unknown comparison =~",
+            comparison_from_str("=~").unwrap_err().message()
+        );
+    }
+
+    #[test]
+    fn test_overview_format_from_str() {
+        assert_eq!(
+            OverviewFormat::Markdown,
+            overview_format_from_str("markdown").unwrap()
+        );
+
+        assert_eq!(
+            "Unknown overview format html",
+            overview_format_from_str("html").unwrap_err().message()
+        );
+    }
+
+    #[test]
+    fn test_attributes_to_edge_indices() {
+        assert_eq!(
+            vec![0, 2],
+            attributes_to_edge_indices(vec![
+                GraphRecordAttribute::Int(0),
+                GraphRecordAttribute::Int(2)
+            ])
+            .unwrap()
+        );
+
+        for attribute in [
+            GraphRecordAttribute::Int(-1),
+            GraphRecordAttribute::Int(i64::from(u32::MAX) + 1),
+            GraphRecordAttribute::from("0"),
+        ] {
+            assert!(matches!(
+                attributes_to_edge_indices(vec![attribute]),
+                Err(GraphRecordError::ConversionError(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,256 @@
+//! R bindings for `GraphRecord`, built with extendr.
+//!
+//! Data frames are passed in as Arrow IPC streams, which the `arrow` package
+//! writes with `arrow::write_to_raw(df, format = "stream")`. Queries are built
+//! from filter functions like `gr_in_group` and `gr_attribute` and evaluated
+//! with [`RGraphRecord::query_nodes`] and [`RGraphRecord::query_edges`], which
+//! cover the filtering subset of the query API described by [`QueryPlan`].
+// The wrappers generated by extendr take arguments by value and bind them to
+// underscore-prefixed names
+#![allow(
+    clippy::needless_pass_by_value,
+    clippy::must_use_candidate,
+    clippy::used_underscore_binding
+)]
+
+mod conversion;
+
+use conversion::{
+    attribute_to_robj, attributes_to_edge_indices, comparison_from_str, dataframe_from_arrow,
+    graphrecord_error, overview_format_from_str, robj_to_attribute, robj_to_attributes,
+    robj_to_value,
+};
+use extendr_api::prelude::*;
+use graphrecords_core::{
+    GraphRecord,
+    graphrecord::{
+        EdgeDataFrameInput, MissingNodePolicy, NodeDataFrameInput, NullHandling,
+        querying::plan::{Filter, QueryPlan, QueryPlanResult},
+    },
+};
+
+/// Filter of a node or edge query, created by the `gr_*` filter functions.
+#[derive(Debug, Clone)]
+#[extendr]
+pub struct RFilter(Filter);
+
+#[extendr]
+impl RFilter {}
+
+fn filters_from_list(filters: &List) -> Result<Vec<Filter>> {
+    filters
+        .values()
+        .map(|filter| <&RFilter>::try_from(&filter).map(|filter| filter.0.clone()))
+        .collect()
+}
+
+/// Keeps the nodes or edges in `group`.
+#[extendr]
+pub fn gr_in_group(group: Robj) -> Result<RFilter> {
+    Ok(RFilter(Filter::InGroup(robj_to_attribute(&group)?)))
+}
+
+/// Keeps the nodes or edges that have `attribute`.
+#[extendr]
+pub fn gr_has_attribute(attribute: Robj) -> Result<RFilter> {
+    Ok(RFilter(Filter::HasAttribute(robj_to_attribute(
+        &attribute,
+    )?)))
+}
+
+/// Keeps the nodes or edges whose `attribute` compares to `value`. The
+/// comparison is one of `==`, `!=`, `>`, `>=`, `<`, `<=`, `starts_with`,
+/// `ends_with` and `contains`.
+#[extendr]
+pub fn gr_attribute(attribute: Robj, comparison: &str, value: Robj) -> Result<RFilter> {
+    Ok(RFilter(Filter::Attribute {
+        attribute: robj_to_attribute(&attribute)?,
+        comparison: comparison_from_str(comparison).map_err(graphrecord_error)?,
+        value: robj_to_value(&value)?,
+    }))
+}
+
+/// Keeps the nodes or edges not matched by all of `filters` together.
+#[extendr]
+pub fn gr_exclude(filters: List) -> Result<RFilter> {
+    Ok(RFilter(Filter::Exclude(filters_from_list(&filters)?)))
+}
+
+#[derive(Debug, Clone, Default)]
+#[extendr]
+pub struct RGraphRecord(GraphRecord);
+
+#[extendr]
+impl RGraphRecord {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a record from a nodes data frame and an optional edges data
+    /// frame, both as Arrow IPC streams.
+    pub fn from_arrow(
+        nodes: Raw,
+        index_column: &str,
+        edges: Nullable<Raw>,
+        source_index_column: &str,
+        target_index_column: &str,
+    ) -> Result<Self> {
+        let nodes = NodeDataFrameInput {
+            dataframe: dataframe_from_arrow(nodes.as_slice()).map_err(graphrecord_error)?,
+            index_column: index_column.to_string(),
+            nulls: NullHandling::default(),
+        };
+
+        let graphrecord = match edges {
+            Nullable::NotNull(edges) => GraphRecord::from_dataframes(
+                vec![nodes],
+                vec![EdgeDataFrameInput {
+                    dataframe: dataframe_from_arrow(edges.as_slice()).map_err(graphrecord_error)?,
+                    source_index_column: source_index_column.to_string(),
+                    target_index_column: target_index_column.to_string(),
+                    nulls: NullHandling::default(),
+                    missing_nodes: MissingNodePolicy::default(),
+                }],
+                None,
+            ),
+            Nullable::Null => GraphRecord::from_nodes_dataframes(vec![nodes], None),
+        }
+        .map_err(graphrecord_error)?;
+
+        Ok(Self(graphrecord))
+    }
+
+    /// Adds the nodes of an Arrow IPC stream, optionally to `group`.
+    pub fn add_nodes_arrow(&mut self, nodes: Raw, index_column: &str, group: Robj) -> Result<()> {
+        let nodes = NodeDataFrameInput {
+            dataframe: dataframe_from_arrow(nodes.as_slice()).map_err(graphrecord_error)?,
+            index_column: index_column.to_string(),
+            nulls: NullHandling::default(),
+        };
+
+        if group.is_null() {
+            self.0.add_nodes_dataframes(vec![nodes])
+        } else {
+            self.0
+                .add_nodes_dataframes_with_group(vec![nodes], robj_to_attribute(&group)?)
+        }
+        .map_err(graphrecord_error)
+    }
+
+    /// Adds the edges of an Arrow IPC stream, optionally to `group`, and
+    /// returns their indices.
+    pub fn add_edges_arrow(
+        &mut self,
+        edges: Raw,
+        source_index_column: &str,
+        target_index_column: &str,
+        group: Robj,
+    ) -> Result<Vec<f64>> {
+        let edges = EdgeDataFrameInput {
+            dataframe: dataframe_from_arrow(edges.as_slice()).map_err(graphrecord_error)?,
+            source_index_column: source_index_column.to_string(),
+            target_index_column: target_index_column.to_string(),
+            nulls: NullHandling::default(),
+            missing_nodes: MissingNodePolicy::default(),
+        };
+
+        let edge_indices = if group.is_null() {
+            self.0.add_edges_dataframes(vec![edges])
+        } else {
+            self.0
+                .add_edges_dataframes_with_group(vec![edges], &robj_to_attribute(&group)?)
+        }
+        .map_err(graphrecord_error)?;
+
+        Ok(edge_indices.into_iter().map(f64::from).collect())
+    }
+
+    pub fn add_group(&mut self, group: Robj, nodes: Robj, edges: Robj) -> Result<()> {
+        let edge_indices =
+            attributes_to_edge_indices(robj_to_attributes(&edges)?).map_err(graphrecord_error)?;
+
+        self.0
+            .add_group(
+                robj_to_attribute(&group)?,
+                Some(robj_to_attributes(&nodes)?),
+                Some(edge_indices),
+            )
+            .map_err(graphrecord_error)
+    }
+
+    pub fn node_count(&self) -> i32 {
+        self.0.node_count() as i32
+    }
+
+    pub fn edge_count(&self) -> i32 {
+        self.0.edge_count() as i32
+    }
+
+    pub fn group_count(&self) -> i32 {
+        self.0.group_count() as i32
+    }
+
+    #[allow(
+        clippy::needless_collect,
+        reason = "from_values needs an ExactSizeIterator"
+    )]
+    pub fn groups(&self) -> List {
+        List::from_values(
+            self.0
+                .groups_sorted()
+                .map(attribute_to_robj)
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Returns the indices of the nodes matched by all of `filters`.
+    pub fn query_nodes(&self, filters: List) -> Result<List> {
+        match QueryPlan::Nodes(filters_from_list(&filters)?)
+            .evaluate(&self.0)
+            .map_err(graphrecord_error)?
+        {
+            QueryPlanResult::Nodes(node_indices) => Ok(List::from_values(
+                node_indices.iter().map(attribute_to_robj),
+            )),
+            QueryPlanResult::Edges(_) => unreachable!(),
+        }
+    }
+
+    /// Returns the indices of the edges matched by all of `filters`.
+    pub fn query_edges(&self, filters: List) -> Result<Vec<f64>> {
+        match QueryPlan::Edges(filters_from_list(&filters)?)
+            .evaluate(&self.0)
+            .map_err(graphrecord_error)?
+        {
+            QueryPlanResult::Edges(edge_indices) => {
+                Ok(edge_indices.into_iter().map(f64::from).collect())
+            }
+            QueryPlanResult::Nodes(_) => unreachable!(),
+        }
+    }
+
+    /// Renders the overview as `modern`, `ascii`, `markdown` or `csv`.
+    pub fn overview(&self, format: &str, truncate_details: Nullable<i32>) -> Result<String> {
+        let format = overview_format_from_str(format).map_err(graphrecord_error)?;
+
+        let truncate_details = match truncate_details {
+            Nullable::NotNull(truncate_details) => Some(truncate_details.max(0) as usize),
+            Nullable::Null => None,
+        };
+
+        self.0
+            .overview(truncate_details, format, None)
+            .map(|overview| overview.to_string())
+            .map_err(graphrecord_error)
+    }
+}
+
+extendr_module! {
+    mod graphrecords;
+    impl RGraphRecord;
+    impl RFilter;
+    fn gr_in_group;
+    fn gr_has_attribute;
+    fn gr_attribute;
+    fn gr_exclude;
+}
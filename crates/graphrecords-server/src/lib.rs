@@ -0,0 +1,466 @@
+//! HTTP API serving a single `GraphRecord` to clients that cannot link against
+//! the Rust or Python libraries.
+//!
+//! | Method | Path        | Body / Query                         | Response              |
+//! |--------|-------------|--------------------------------------|-----------------------|
+//! | `GET`  | `/health`   |                                      | `ok`                  |
+//! | `POST` | `/load`     | [`LoadRequest`] as JSON              | [`Summary`] as JSON   |
+//! | `GET`  | `/summary`  |                                      | [`Summary`] as JSON   |
+//! | `POST` | `/query`    | [`QueryPlan`] as JSON                | [`QueryPlanResult`]   |
+//! | `GET`  | `/overview` | `format`, `truncate` query arguments | overview as text      |
+//!
+//! Errors are returned as JSON objects with the stable `code` and the `message`
+//! of the underlying [`GraphRecordError`].
+//!
+//! `/load` only reads records from the data directory passed to [`router`].
+//! Paths are resolved relative to it, and paths leaving it are rejected with
+//! `403 Forbidden`. Without a data directory, `/load` is disabled.
+
+use axum::{
+    Json, Router,
+    extract::{FromRef, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
+use graphrecords_core::{
+    GraphRecord,
+    errors::{GraphRecordError, GraphRecordResult},
+    graphrecord::{
+        overview::OverviewFormat,
+        querying::plan::{QueryPlan, QueryPlanResult},
+    },
+};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+pub type SharedGraphRecord = Arc<RwLock<GraphRecord>>;
+
+#[derive(Debug, Clone)]
+struct ServerState {
+    graphrecord: SharedGraphRecord,
+    data_directory: Option<Arc<Path>>,
+}
+
+impl FromRef<ServerState> for SharedGraphRecord {
+    fn from_ref(state: &ServerState) -> Self {
+        state.graphrecord.clone()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RecordFormat {
+    Ron,
+    Container,
+}
+
+impl RecordFormat {
+    fn infer(path: &Path) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("ron") => Self::Ron,
+            _ => Self::Container,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LoadRequest {
+    /// Relative to the data directory of the server.
+    pub path: PathBuf,
+    /// Inferred from the file extension when omitted: `.ron` files are read
+    /// as RON, everything else as the binary container format.
+    pub format: Option<RecordFormat>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Summary {
+    pub node_count: usize,
+    pub edge_count: usize,
+    pub group_count: usize,
+}
+
+impl From<&GraphRecord> for Summary {
+    fn from(graphrecord: &GraphRecord) -> Self {
+        Self {
+            node_count: graphrecord.node_count(),
+            edge_count: graphrecord.edge_count(),
+            group_count: graphrecord.group_count(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+struct OverviewArguments {
+    format: Option<String>,
+    truncate: Option<usize>,
+}
+
+pub fn load_graphrecord(
+    path: &Path,
+    format: Option<RecordFormat>,
+) -> GraphRecordResult<GraphRecord> {
+    match format.unwrap_or_else(|| RecordFormat::infer(path)) {
+        RecordFormat::Ron => GraphRecord::from_ron(path),
+        RecordFormat::Container => {
+            let bytes = fs::read(path).map_err(|_| {
+                GraphRecordError::ConversionError("Failed to read file".to_string())
+            })?;
+
+            GraphRecord::from_bytes(&bytes)
+        }
+    }
+}
+
+struct ApiError {
+    status: StatusCode,
+    error: GraphRecordError,
+}
+
+impl ApiError {
+    const fn forbidden(message: String) -> Self {
+        Self {
+            status: StatusCode::FORBIDDEN,
+            error: GraphRecordError::AssertionError(message),
+        }
+    }
+}
+
+impl From<GraphRecordError> for ApiError {
+    fn from(error: GraphRecordError) -> Self {
+        let status = match error {
+            GraphRecordError::IndexError(_)
+            | GraphRecordError::MissingNode { .. }
+            | GraphRecordError::KeyError(_) => StatusCode::NOT_FOUND,
+            GraphRecordError::ReadOnlyError(_) => StatusCode::CONFLICT,
+            _ => StatusCode::BAD_REQUEST,
+        };
+
+        Self { status, error }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let body = serde_json::json!({
+            "code": self.error.code(),
+            "message": self.error.message(),
+        });
+
+        (self.status, Json(body)).into_response()
+    }
+}
+
+/// Resolves `path` relative to `data_directory`, rejecting paths that leave
+/// it through `..`, absolute paths or symbolic links.
+fn resolve_load_path(data_directory: Option<&Path>, path: &Path) -> Result<PathBuf, ApiError> {
+    let Some(data_directory) = data_directory else {
+        return Err(ApiError::forbidden(
+            "Loading records is disabled, as the server has no data directory".to_string(),
+        ));
+    };
+
+    let data_directory = data_directory.canonicalize().map_err(|_| {
+        GraphRecordError::ConversionError("Failed to read data directory".to_string())
+    })?;
+    let resolved = data_directory
+        .join(path)
+        .canonicalize()
+        .map_err(|_| GraphRecordError::ConversionError("Failed to read file".to_string()))?;
+
+    if !resolved.starts_with(&data_directory) {
+        return Err(ApiError::forbidden(format!(
+            "Path {} is outside of the data directory",
+            path.display()
+        )));
+    }
+
+    Ok(resolved)
+}
+
+// Loading and querying are CPU bound, so they run on the blocking pool to keep
+// the async workers free for other connections.
+async fn run_blocking<T, F>(function: F) -> T
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    match tokio::task::spawn_blocking(function).await {
+        Ok(value) => value,
+        Err(error) => std::panic::resume_unwind(error.into_panic()),
+    }
+}
+
+async fn health() -> &'static str {
+    "ok"
+}
+
+async fn load(
+    State(state): State<ServerState>,
+    Json(request): Json<LoadRequest>,
+) -> Result<Json<Summary>, ApiError> {
+    let data_directory = state.data_directory.clone();
+
+    let loaded = run_blocking(move || {
+        let path = resolve_load_path(data_directory.as_deref(), &request.path)?;
+
+        Ok::<_, ApiError>(load_graphrecord(&path, request.format)?)
+    })
+    .await?;
+    let summary = Summary::from(&loaded);
+
+    *state.graphrecord.write() = loaded;
+
+    Ok(Json(summary))
+}
+
+async fn summary(State(graphrecord): State<SharedGraphRecord>) -> Json<Summary> {
+    Json(Summary::from(&*graphrecord.read()))
+}
+
+async fn query(
+    State(graphrecord): State<SharedGraphRecord>,
+    Json(plan): Json<QueryPlan>,
+) -> Result<Json<QueryPlanResult>, ApiError> {
+    let result = run_blocking(move || plan.evaluate(&graphrecord.read())).await?;
+
+    Ok(Json(result))
+}
+
+async fn overview(
+    State(graphrecord): State<SharedGraphRecord>,
+    Query(arguments): Query<OverviewArguments>,
+) -> Result<String, ApiError> {
+    let format = match arguments.format.as_deref() {
+        None | Some("modern") => OverviewFormat::Modern,
+        Some("ascii") => OverviewFormat::Ascii,
+        Some("markdown") => OverviewFormat::Markdown,
+        Some("csv") => OverviewFormat::Csv,
+        Some(format) => {
+            return Err(GraphRecordError::ConversionError(format!(
+                "Unknown overview format {format}"
+            ))
+            .into());
+        }
+    };
+
+    let overview = run_blocking(move || {
+        graphrecord
+            .read()
+            .overview(arguments.truncate, format, None)
+            .map(|overview| overview.to_string())
+    })
+    .await?;
+
+    Ok(overview)
+}
+
+/// Builds the API serving `graphrecord`. `/load` reads records from
+/// `data_directory` only, and is disabled when it is `None`.
+pub fn router(graphrecord: SharedGraphRecord, data_directory: Option<PathBuf>) -> Router {
+    Router::new()
+        .route("/health", get(health))
+        .route("/load", post(load))
+        .route("/summary", get(summary))
+        .route("/query", post(query))
+        .route("/overview", get(overview))
+        .with_state(ServerState {
+            graphrecord,
+            data_directory: data_directory.map(Arc::from),
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{SharedGraphRecord, router};
+    use axum::{
+        body::{Body, to_bytes},
+        http::{Request, StatusCode},
+    };
+    use graphrecords_core::GraphRecord;
+    use parking_lot::RwLock;
+    use serde_json::{Value, json};
+    use std::{
+        collections::HashMap,
+        path::{Path, PathBuf},
+        sync::Arc,
+    };
+    use tower::ServiceExt;
+
+    fn create_graphrecord() -> SharedGraphRecord {
+        let mut graphrecord = GraphRecord::new();
+
+        graphrecord
+            .add_node(
+                "0".into(),
+                vec![("age".into(), 20.into())].into_iter().collect(),
+            )
+            .unwrap();
+        graphrecord
+            .add_node(
+                "1".into(),
+                vec![("age".into(), 40.into())].into_iter().collect(),
+            )
+            .unwrap();
+        graphrecord
+            .add_edge("0".into(), "1".into(), HashMap::new())
+            .unwrap();
+
+        Arc::new(RwLock::new(graphrecord))
+    }
+
+    async fn send(graphrecord: SharedGraphRecord, request: Request<Body>) -> (StatusCode, Vec<u8>) {
+        send_with_data_directory(graphrecord, None, request).await
+    }
+
+    async fn send_with_data_directory(
+        graphrecord: SharedGraphRecord,
+        data_directory: Option<&Path>,
+        request: Request<Body>,
+    ) -> (StatusCode, Vec<u8>) {
+        let response = router(graphrecord, data_directory.map(Path::to_path_buf))
+            .oneshot(request)
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+        (status, body.to_vec())
+    }
+
+    fn post(uri: &str, body: &Value) -> Request<Body> {
+        Request::post(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_query() {
+        let plan = json!({
+            "Nodes": [{
+                "Attribute": {
+                    "attribute": { "String": "age" },
+                    "comparison": "GreaterThan",
+                    "value": { "Int": 30 },
+                },
+            }],
+        });
+
+        let (status, body) = send(create_graphrecord(), post("/query", &plan)).await;
+
+        assert_eq!(StatusCode::OK, status);
+        assert_eq!(
+            json!({ "Nodes": [{ "String": "1" }] }),
+            serde_json::from_slice::<Value>(&body).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_summary_and_overview() {
+        let (status, body) = send(
+            create_graphrecord(),
+            Request::get("/summary").body(Body::empty()).unwrap(),
+        )
+        .await;
+
+        assert_eq!(StatusCode::OK, status);
+        assert_eq!(
+            json!({ "node_count": 2, "edge_count": 1, "group_count": 0 }),
+            serde_json::from_slice::<Value>(&body).unwrap()
+        );
+
+        let (status, body) = send(
+            create_graphrecord(),
+            Request::get("/overview?format=ascii")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await;
+
+        assert_eq!(StatusCode::OK, status);
+        assert!(String::from_utf8(body).unwrap().contains("Ungrouped"));
+    }
+
+    fn create_data_directory(name: &str) -> PathBuf {
+        let directory =
+            std::env::temp_dir().join(format!("graphrecords-server-{name}-{}", std::process::id()));
+        let data_directory = directory.join("data");
+        std::fs::create_dir_all(&data_directory).unwrap();
+
+        let bytes = create_graphrecord().read().to_bytes().unwrap();
+        std::fs::write(data_directory.join("record.grcb"), &bytes).unwrap();
+        std::fs::write(directory.join("outside.grcb"), &bytes).unwrap();
+
+        directory
+    }
+
+    #[tokio::test]
+    async fn test_load() {
+        let directory = create_data_directory("load");
+        let data_directory = directory.join("data");
+
+        let graphrecord = Arc::new(RwLock::new(GraphRecord::new()));
+        let (status, _) = send_with_data_directory(
+            graphrecord.clone(),
+            Some(&data_directory),
+            post("/load", &json!({ "path": "record.grcb" })),
+        )
+        .await;
+
+        assert_eq!(StatusCode::OK, status);
+        assert_eq!(2, graphrecord.read().node_count());
+
+        let (status, body) = send_with_data_directory(
+            graphrecord,
+            Some(&data_directory),
+            post("/load", &json!({ "path": "missing.ron" })),
+        )
+        .await;
+
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(StatusCode::BAD_REQUEST, status);
+        assert_eq!(
+            "GR0300",
+            serde_json::from_slice::<Value>(&body).unwrap()["code"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_invalid_load() {
+        let directory = create_data_directory("invalid-load");
+        let data_directory = directory.join("data");
+        let outside = directory.join("outside.grcb");
+
+        for path in [json!("../outside.grcb"), json!(outside)] {
+            let graphrecord = Arc::new(RwLock::new(GraphRecord::new()));
+            let (status, _) = send_with_data_directory(
+                graphrecord.clone(),
+                Some(&data_directory),
+                post("/load", &json!({ "path": path })),
+            )
+            .await;
+
+            assert_eq!(StatusCode::FORBIDDEN, status);
+            assert_eq!(0, graphrecord.read().node_count());
+        }
+
+        let graphrecord = Arc::new(RwLock::new(GraphRecord::new()));
+        let (status, _) = send(
+            graphrecord.clone(),
+            post("/load", &json!({ "path": outside })),
+        )
+        .await;
+
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(StatusCode::FORBIDDEN, status);
+        assert_eq!(0, graphrecord.read().node_count());
+    }
+}
@@ -0,0 +1,42 @@
+use clap::Parser;
+use graphrecords_core::GraphRecord;
+use graphrecords_server::{load_graphrecord, router};
+use parking_lot::RwLock;
+use std::{net::SocketAddr, path::PathBuf, sync::Arc};
+
+/// Serve a `GraphRecord` over HTTP.
+#[derive(Debug, Parser)]
+#[command(version)]
+struct Arguments {
+    /// Address to listen on.
+    #[arg(long, default_value = "127.0.0.1:8000")]
+    address: SocketAddr,
+
+    /// Directory `POST /load` reads records from. Loading is disabled when
+    /// omitted.
+    #[arg(long)]
+    data_directory: Option<PathBuf>,
+
+    /// Record to load on startup. An empty record is served when omitted.
+    record: Option<PathBuf>,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let arguments = Arguments::parse();
+
+    let graphrecord = match arguments.record {
+        Some(path) => load_graphrecord(&path, None)?,
+        None => GraphRecord::new(),
+    };
+
+    let listener = tokio::net::TcpListener::bind(arguments.address).await?;
+
+    axum::serve(
+        listener,
+        router(Arc::new(RwLock::new(graphrecord)), arguments.data_directory),
+    )
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,474 @@
+//! Reproducible synthetic clinical records for benchmarks, documentation and
+//! tests that need realistic data without protected health information.
+//!
+//! [`generate`] builds a record of patients, their encounters and the
+//! diagnoses coded in each encounter. The same [`SynthConfig`] always yields
+//! the same record for a given version of this crate. The record and its
+//! groups carry metadata describing how they were generated.
+
+use chrono::{NaiveDate, NaiveDateTime, TimeDelta};
+use graphrecords_core::{
+    GraphRecord,
+    errors::{GraphRecordError, GraphRecordResult},
+    graphrecord::{Attributes, GraphRecordValue, Group, NodeIndex, metadata::RecordMetadata},
+};
+use rand::{
+    Rng, SeedableRng,
+    distr::{Distribution, weighted::WeightedIndex},
+    rngs::StdRng,
+    seq::IndexedRandom,
+};
+use std::collections::HashMap;
+
+pub const PATIENT_GROUP: &str = "patient";
+pub const ENCOUNTER_GROUP: &str = "encounter";
+pub const DIAGNOSIS_GROUP: &str = "diagnosis";
+pub const PATIENT_ENCOUNTER_GROUP: &str = "patient_encounter";
+pub const ENCOUNTER_DIAGNOSIS_GROUP: &str = "encounter_diagnosis";
+
+const ENCOUNTER_TYPES: [&str; 3] = ["outpatient", "emergency", "inpatient"];
+const ENCOUNTER_TYPE_WEIGHTS: [f64; 3] = [0.7, 0.2, 0.1];
+
+/// Distribution of a count, like the number of encounters of a patient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CountDistribution {
+    Fixed(usize),
+    /// Uniform between `min` and `max`, both inclusive.
+    Uniform {
+        min: usize,
+        max: usize,
+    },
+    Poisson {
+        mean: f64,
+    },
+}
+
+impl CountDistribution {
+    fn validate(&self, name: &str) -> GraphRecordResult<()> {
+        match self {
+            Self::Poisson { mean } if !mean.is_finite() || *mean < 0.0 => {
+                Err(GraphRecordError::AssertionError(format!(
+                    "Mean of {name} must be finite and non-negative, but is {mean}"
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    #[allow(clippy::while_float, reason = "Knuth's algorithm compares floats")]
+    fn sample(&self, rng: &mut StdRng) -> usize {
+        match *self {
+            Self::Fixed(count) => count,
+            Self::Uniform { min, max } => rng.random_range(min.min(max)..=max.max(min)),
+            Self::Poisson { mean } => {
+                // Knuth's algorithm underflows for large means, so the count
+                // is sampled as a sum of Poisson counts with smaller means.
+                let mut remaining = mean;
+                let mut count = 0;
+
+                while remaining > 0.0 {
+                    let chunk = remaining.min(30.0);
+                    let limit = (-chunk).exp();
+                    let mut product: f64 = rng.random();
+
+                    while product > limit {
+                        count += 1;
+                        product *= rng.random::<f64>();
+                    }
+
+                    remaining -= chunk;
+                }
+
+                count
+            }
+        }
+    }
+}
+
+/// Sizes and distributions of a synthetic record.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SynthConfig {
+    pub seed: u64,
+    pub patients: usize,
+    pub encounters_per_patient: CountDistribution,
+    /// Number of distinct diagnosis codes.
+    pub diagnosis_codes: usize,
+    /// Number of distinct diagnoses coded in an encounter, capped at
+    /// `diagnosis_codes`.
+    pub diagnoses_per_encounter: CountDistribution,
+    /// Exponent of the Zipf distribution of diagnosis codes. `0.0` makes all
+    /// codes equally common, larger values concentrate encounters on the
+    /// first codes.
+    pub diagnosis_skew: f64,
+    /// Encounters take place in the `span_days` days after `start`.
+    pub start: NaiveDateTime,
+    pub span_days: u32,
+}
+
+impl Default for SynthConfig {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            patients: 100,
+            encounters_per_patient: CountDistribution::Poisson { mean: 3.0 },
+            diagnosis_codes: 50,
+            diagnoses_per_encounter: CountDistribution::Uniform { min: 1, max: 3 },
+            diagnosis_skew: 1.0,
+            start: NaiveDate::from_ymd_opt(2020, 1, 1)
+                .expect("Date must be valid")
+                .and_hms_opt(0, 0, 0)
+                .expect("Time must be valid"),
+            span_days: 3 * 365,
+        }
+    }
+}
+
+impl SynthConfig {
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            ..Default::default()
+        }
+    }
+
+    fn validate(&self) -> GraphRecordResult<()> {
+        self.encounters_per_patient
+            .validate("encounters_per_patient")?;
+        self.diagnoses_per_encounter
+            .validate("diagnoses_per_encounter")?;
+
+        if !self.diagnosis_skew.is_finite() || self.diagnosis_skew < 0.0 {
+            return Err(GraphRecordError::AssertionError(format!(
+                "diagnosis_skew must be finite and non-negative, but is {}",
+                self.diagnosis_skew
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn metadata(&self) -> RecordMetadata {
+        RecordMetadata {
+            description: Some(
+                "Synthetic patients with their encounters and the diagnoses coded in each \
+                 encounter, generated by graphrecords-synth"
+                    .to_string(),
+            ),
+            source_datasets: vec!["graphrecords-synth".to_string()],
+            properties: HashMap::from([
+                ("seed".into(), (self.seed as i64).into()),
+                ("patients".into(), (self.patients as i64).into()),
+                (
+                    "diagnosis_codes".into(),
+                    (self.diagnosis_codes as i64).into(),
+                ),
+                ("diagnosis_skew".into(), self.diagnosis_skew.into()),
+                ("start".into(), self.start.into()),
+                ("span_days".into(), i64::from(self.span_days).into()),
+            ]),
+            ..RecordMetadata::new("Synthetic clinical record")
+        }
+    }
+}
+
+fn patient_index(patient: usize) -> NodeIndex {
+    format!("patient_{patient}").into()
+}
+
+fn encounter_index(encounter: usize) -> NodeIndex {
+    format!("encounter_{encounter}").into()
+}
+
+fn diagnosis_index(code: usize) -> NodeIndex {
+    format!("diagnosis_{code}").into()
+}
+
+fn patient_attributes(rng: &mut StdRng) -> Attributes {
+    let gender = if rng.random_bool(0.5) { "F" } else { "M" };
+
+    HashMap::from([
+        ("gender".into(), gender.into()),
+        ("age".into(), rng.random_range(18_i64..=90).into()),
+    ])
+}
+
+fn diagnosis_attributes(code: usize, rng: &mut StdRng) -> Attributes {
+    HashMap::from([
+        ("code".into(), format!("D{code:04}").into()),
+        ("chronic".into(), rng.random_bool(0.2).into()),
+    ])
+}
+
+/// Generates a synthetic record from `config`.
+///
+/// The record has the groups [`PATIENT_GROUP`], [`ENCOUNTER_GROUP`] and
+/// [`DIAGNOSIS_GROUP`]. Edges from patients to their encounters are in
+/// [`PATIENT_ENCOUNTER_GROUP`], edges from encounters to their diagnoses in
+/// [`ENCOUNTER_DIAGNOSIS_GROUP`].
+#[allow(clippy::missing_panics_doc, reason = "infallible")]
+pub fn generate(config: &SynthConfig) -> GraphRecordResult<GraphRecord> {
+    config.validate()?;
+
+    let mut rng = StdRng::seed_from_u64(config.seed);
+
+    let encounter_type =
+        WeightedIndex::new(ENCOUNTER_TYPE_WEIGHTS).expect("Encounter type weights must be valid");
+    let diagnosis_codes: Vec<usize> = (0..config.diagnosis_codes).collect();
+    let span_seconds = i64::from(config.span_days) * 24 * 60 * 60;
+
+    let patients: Vec<_> = (0..config.patients)
+        .map(|patient| (patient_index(patient), patient_attributes(&mut rng)))
+        .collect();
+    let diagnoses: Vec<_> = diagnosis_codes
+        .iter()
+        .map(|code| {
+            (
+                diagnosis_index(*code),
+                diagnosis_attributes(*code, &mut rng),
+            )
+        })
+        .collect();
+
+    let mut encounters = Vec::new();
+    let mut patient_encounters = Vec::new();
+    let mut encounter_diagnoses = Vec::new();
+
+    for patient in 0..config.patients {
+        let mut times: Vec<_> = (0..config.encounters_per_patient.sample(&mut rng))
+            .map(|_| config.start + TimeDelta::seconds(rng.random_range(0..=span_seconds)))
+            .collect();
+        times.sort_unstable();
+
+        for time in times {
+            let encounter = encounter_index(encounters.len());
+
+            encounters.push((
+                encounter.clone(),
+                HashMap::from([
+                    ("time".into(), time.into()),
+                    (
+                        "type".into(),
+                        ENCOUNTER_TYPES[encounter_type.sample(&mut rng)].into(),
+                    ),
+                ]),
+            ));
+            patient_encounters.push((patient_index(patient), encounter.clone(), Attributes::new()));
+
+            let count = config
+                .diagnoses_per_encounter
+                .sample(&mut rng)
+                .min(diagnosis_codes.len());
+            let codes = diagnosis_codes
+                .choose_multiple_weighted(&mut rng, count, |code| {
+                    1.0 / ((code + 1) as f64).powf(config.diagnosis_skew)
+                })
+                .map_err(|error| {
+                    GraphRecordError::AssertionError(format!("Failed to sample diagnoses: {error}"))
+                })?;
+
+            for (position, code) in codes.enumerate() {
+                encounter_diagnoses.push((
+                    encounter.clone(),
+                    diagnosis_index(*code),
+                    HashMap::from([
+                        ("time".into(), time.into()),
+                        ("primary".into(), GraphRecordValue::Bool(position == 0)),
+                    ]),
+                ));
+            }
+        }
+    }
+
+    let mut graphrecord = GraphRecord::with_capacity(
+        patients.len() + encounters.len() + diagnoses.len(),
+        patient_encounters.len() + encounter_diagnoses.len(),
+        None,
+    );
+
+    graphrecord.add_nodes_with_group(patients, PATIENT_GROUP.into())?;
+    graphrecord.add_nodes_with_group(encounters, ENCOUNTER_GROUP.into())?;
+    graphrecord.add_nodes_with_group(diagnoses, DIAGNOSIS_GROUP.into())?;
+    graphrecord.add_edges_with_group(patient_encounters, &PATIENT_ENCOUNTER_GROUP.into())?;
+    graphrecord.add_edges_with_group(encounter_diagnoses, &ENCOUNTER_DIAGNOSIS_GROUP.into())?;
+
+    for (group, description) in [
+        (PATIENT_GROUP, "Patients with their gender and age"),
+        (ENCOUNTER_GROUP, "Encounters with their time and type"),
+        (DIAGNOSIS_GROUP, "Diagnosis codes, some of them chronic"),
+        (
+            PATIENT_ENCOUNTER_GROUP,
+            "Edges from patients to their encounters",
+        ),
+        (
+            ENCOUNTER_DIAGNOSIS_GROUP,
+            "Edges from encounters to their diagnoses, the primary one flagged",
+        ),
+    ] {
+        graphrecord.set_group_metadata(
+            &Group::from(group),
+            "description".into(),
+            description.into(),
+        )?;
+    }
+
+    graphrecord.set_metadata(config.metadata())?;
+
+    Ok(graphrecord)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        CountDistribution, DIAGNOSIS_GROUP, ENCOUNTER_DIAGNOSIS_GROUP, ENCOUNTER_GROUP,
+        PATIENT_ENCOUNTER_GROUP, PATIENT_GROUP, SynthConfig, generate,
+    };
+    use graphrecords_core::{errors::GraphRecordError, graphrecord::GraphRecordValue};
+
+    fn fixed_config() -> SynthConfig {
+        SynthConfig {
+            patients: 10,
+            encounters_per_patient: CountDistribution::Fixed(2),
+            diagnosis_codes: 5,
+            diagnoses_per_encounter: CountDistribution::Fixed(3),
+            ..SynthConfig::new(7)
+        }
+    }
+
+    #[test]
+    fn test_generate() {
+        let graphrecord = generate(&fixed_config()).unwrap();
+
+        assert_eq!(10 + 20 + 5, graphrecord.node_count());
+        assert_eq!(20 + 60, graphrecord.edge_count());
+        assert_eq!(
+            10,
+            graphrecord
+                .nodes_in_group(&PATIENT_GROUP.into())
+                .unwrap()
+                .count()
+        );
+        assert_eq!(
+            20,
+            graphrecord
+                .nodes_in_group(&ENCOUNTER_GROUP.into())
+                .unwrap()
+                .count()
+        );
+        assert_eq!(
+            5,
+            graphrecord
+                .nodes_in_group(&DIAGNOSIS_GROUP.into())
+                .unwrap()
+                .count()
+        );
+        assert_eq!(
+            20,
+            graphrecord
+                .edges_in_group(&PATIENT_ENCOUNTER_GROUP.into())
+                .unwrap()
+                .count()
+        );
+        assert_eq!(
+            60,
+            graphrecord
+                .edges_in_group(&ENCOUNTER_DIAGNOSIS_GROUP.into())
+                .unwrap()
+                .count()
+        );
+
+        assert_eq!(
+            Some("Synthetic clinical record"),
+            graphrecord.metadata().title.as_deref()
+        );
+        assert_eq!(
+            Some(&GraphRecordValue::Int(7)),
+            graphrecord.metadata().properties.get(&"seed".into())
+        );
+        assert_eq!(
+            1,
+            graphrecord
+                .group_metadata(&PATIENT_GROUP.into())
+                .unwrap()
+                .count()
+        );
+    }
+
+    #[test]
+    fn test_generate_reproducible() {
+        let config = SynthConfig {
+            patients: 20,
+            ..SynthConfig::new(42)
+        };
+
+        let first = generate(&config).unwrap();
+        let second = generate(&config).unwrap();
+
+        assert_eq!(first.node_count(), second.node_count());
+        assert_eq!(first.edge_count(), second.edge_count());
+        for node_index in first.node_indices() {
+            assert_eq!(
+                first.node_attributes(node_index).unwrap(),
+                second.node_attributes(node_index).unwrap()
+            );
+        }
+
+        let other = generate(&SynthConfig { seed: 43, ..config }).unwrap();
+
+        assert_ne!(
+            first.node_attributes(&"encounter_0".into()).unwrap(),
+            other.node_attributes(&"encounter_0".into()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_count_distribution() {
+        let graphrecord = generate(&SynthConfig {
+            patients: 5,
+            encounters_per_patient: CountDistribution::Poisson { mean: 0.0 },
+            ..SynthConfig::new(0)
+        })
+        .unwrap();
+
+        assert_eq!(
+            0,
+            graphrecord
+                .nodes_in_group(&ENCOUNTER_GROUP.into())
+                .unwrap()
+                .count()
+        );
+
+        let graphrecord = generate(&SynthConfig {
+            patients: 1,
+            encounters_per_patient: CountDistribution::Uniform { min: 3, max: 3 },
+            ..SynthConfig::new(0)
+        })
+        .unwrap();
+
+        assert_eq!(
+            3,
+            graphrecord
+                .nodes_in_group(&ENCOUNTER_GROUP.into())
+                .unwrap()
+                .count()
+        );
+    }
+
+    #[test]
+    fn test_invalid_generate() {
+        assert!(
+            generate(&SynthConfig {
+                diagnosis_skew: -1.0,
+                ..SynthConfig::new(0)
+            })
+            .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
+        assert!(
+            generate(&SynthConfig {
+                encounters_per_patient: CountDistribution::Poisson { mean: f64::NAN },
+                ..SynthConfig::new(0)
+            })
+            .is_err_and(|e| matches!(e, GraphRecordError::AssertionError(_)))
+        );
+    }
+}
@@ -1,5 +1,150 @@
-use hashbrown::{DefaultHashBuilder, HashMap, HashSet, hash_map::Entry};
+//! Hash maps and sets used throughout the workspace.
+//!
+//! The hasher is selected by cargo feature: `fxhash`, `ahash` or `std-hash`.
+//! Without any of them, foldhash, the default of `hashbrown`, is used. If
+//! several are enabled, the first one in this order wins. With `fixed-seed`,
+//! hashers are seeded with a constant instead of per process randomness, so
+//! iteration orders are reproducible across runs. `fxhash` is unseeded and
+//! always reproducible.
 
-pub type GrHashMap<K, V, S = DefaultHashBuilder> = HashMap<K, V, S>;
+use hashbrown::{HashMap, HashSet, hash_map::Entry};
+
+pub type GrHashMap<K, V, S = GrBuildHasher> = HashMap<K, V, S>;
 pub type GrHashMapEntry<'a, K, V, S> = Entry<'a, K, V, S>;
-pub type GrHashSet<T> = HashSet<T>;
+pub type GrHashSet<T> = HashSet<T, GrBuildHasher>;
+
+#[cfg(feature = "fxhash")]
+pub type GrBuildHasher = rustc_hash::FxBuildHasher;
+
+#[cfg(all(
+    feature = "ahash",
+    not(feature = "fxhash"),
+    not(feature = "fixed-seed")
+))]
+pub type GrBuildHasher = ahash::RandomState;
+
+#[cfg(all(feature = "ahash", not(feature = "fxhash"), feature = "fixed-seed"))]
+pub type GrBuildHasher = fixed_ahash::FixedState;
+
+#[cfg(all(
+    feature = "std-hash",
+    not(any(feature = "fxhash", feature = "ahash")),
+    not(feature = "fixed-seed")
+))]
+pub type GrBuildHasher = std::hash::RandomState;
+
+#[cfg(all(
+    feature = "std-hash",
+    not(any(feature = "fxhash", feature = "ahash")),
+    feature = "fixed-seed"
+))]
+pub type GrBuildHasher = std::hash::BuildHasherDefault<std::hash::DefaultHasher>;
+
+#[cfg(all(
+    not(any(feature = "fxhash", feature = "ahash", feature = "std-hash")),
+    not(feature = "fixed-seed")
+))]
+pub type GrBuildHasher = hashbrown::DefaultHashBuilder;
+
+#[cfg(all(
+    not(any(feature = "fxhash", feature = "ahash", feature = "std-hash")),
+    feature = "fixed-seed"
+))]
+pub type GrBuildHasher = foldhash::fast::FixedState;
+
+#[cfg(all(feature = "ahash", not(feature = "fxhash"), feature = "fixed-seed"))]
+mod fixed_ahash {
+    use std::hash::BuildHasher;
+
+    const SEEDS: [u64; 4] = [
+        0x243f_6a88_85a3_08d3,
+        0x1319_8a2e_0370_7344,
+        0xa409_3822_299f_31d0,
+        0x082e_fa98_ec4e_6c89,
+    ];
+
+    /// `ahash` state with constant seeds, as `ahash::RandomState` is seeded
+    /// randomly by default.
+    #[derive(Debug, Clone)]
+    pub struct FixedState(ahash::RandomState);
+
+    impl Default for FixedState {
+        fn default() -> Self {
+            Self(ahash::RandomState::with_seeds(
+                SEEDS[0], SEEDS[1], SEEDS[2], SEEDS[3],
+            ))
+        }
+    }
+
+    impl BuildHasher for FixedState {
+        type Hasher = ahash::AHasher;
+
+        fn build_hasher(&self) -> Self::Hasher {
+            self.0.build_hasher()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::GrBuildHasher;
+    use std::{any::TypeId, hash::BuildHasher};
+
+    type Hasher = <GrBuildHasher as BuildHasher>::Hasher;
+
+    #[cfg(any(feature = "fxhash", feature = "fixed-seed"))]
+    #[test]
+    fn test_fixed_seed() {
+        assert_eq!(
+            GrBuildHasher::default().hash_one("lorem"),
+            GrBuildHasher::default().hash_one("lorem")
+        );
+    }
+
+    #[cfg(feature = "fxhash")]
+    #[test]
+    fn test_fxhash_precedence() {
+        assert_eq!(TypeId::of::<rustc_hash::FxHasher>(), TypeId::of::<Hasher>());
+    }
+
+    #[cfg(all(feature = "ahash", not(feature = "fxhash")))]
+    #[test]
+    fn test_ahash_precedence() {
+        assert_eq!(TypeId::of::<ahash::AHasher>(), TypeId::of::<Hasher>());
+    }
+
+    #[cfg(all(feature = "std-hash", not(any(feature = "fxhash", feature = "ahash"))))]
+    #[test]
+    fn test_std_hash_precedence() {
+        assert_eq!(
+            TypeId::of::<std::hash::DefaultHasher>(),
+            TypeId::of::<Hasher>()
+        );
+    }
+
+    #[cfg(not(any(
+        feature = "fxhash",
+        feature = "ahash",
+        feature = "std-hash",
+        feature = "fixed-seed"
+    )))]
+    #[test]
+    fn test_default_hasher() {
+        assert_eq!(
+            TypeId::of::<<hashbrown::DefaultHashBuilder as BuildHasher>::Hasher>(),
+            TypeId::of::<Hasher>()
+        );
+    }
+
+    #[cfg(all(
+        not(any(feature = "fxhash", feature = "ahash", feature = "std-hash")),
+        feature = "fixed-seed"
+    ))]
+    #[test]
+    fn test_default_hasher_fixed_seed() {
+        assert_eq!(
+            TypeId::of::<<foldhash::fast::FixedState as BuildHasher>::Hasher>(),
+            TypeId::of::<Hasher>()
+        );
+    }
+}
@@ -10,12 +10,27 @@ from graphrecords.datatype import (
     DateTime,
     Duration,
     Float,
+    Geo,
     Int,
+    Missing,
     Null,
     Option,
     String,
     Union,
 )
+from graphrecords.errors import (
+    ConversionError,
+    DuplicateGroupError,
+    GraphRecordAssertionError,
+    GraphRecordError,
+    GraphRecordIndexError,
+    GraphRecordKeyError,
+    MissingNodeError,
+    QueryError,
+    ReadOnlyError,
+    SchemaError,
+    TypeMismatchError,
+)
 from graphrecords.graphrecord import (
     EdgeIndex,
     EdgeQuery,
@@ -23,36 +38,68 @@ from graphrecords.graphrecord import (
     NodeIndex,
     NodeQuery,
 )
-from graphrecords.plugins import Plugin
+from graphrecords.ingest import ShardedIngest
+from graphrecords.overview import OverviewFormat
+from graphrecords.plugins import MappingPlugin, Plugin
 from graphrecords.querying import EdgeOperand, MatchMode, NodeOperand
-from graphrecords.schema import AttributeType, GroupSchema, Schema, SchemaType
+from graphrecords.schema import (
+    AttributeType,
+    ConflictStrategy,
+    GroupSchema,
+    NonFiniteFloatPolicy,
+    Schema,
+    SchemaType,
+)
+from graphrecords.types import MISSING, GeoPoint, ProvenanceTag, RecordMetadata
 
 __all__ = [
+    "MISSING",
     "Any",
     "AttributeType",
     "Bool",
+    "ConflictStrategy",
     "ConnectedGraphRecord",
     "Connector",
+    "ConversionError",
     "DateTime",
+    "DuplicateGroupError",
     "Duration",
     "EdgeIndex",
     "EdgeOperand",
     "EdgeQuery",
     "ExportConnector",
     "Float",
+    "Geo",
+    "GeoPoint",
     "GraphRecord",
+    "GraphRecordAssertionError",
+    "GraphRecordError",
+    "GraphRecordIndexError",
+    "GraphRecordKeyError",
     "GroupSchema",
     "IngestConnector",
     "Int",
+    "MappingPlugin",
     "MatchMode",
+    "Missing",
+    "MissingNodeError",
     "NodeIndex",
     "NodeOperand",
     "NodeQuery",
+    "NonFiniteFloatPolicy",
     "Null",
     "Option",
+    "OverviewFormat",
     "Plugin",
+    "ProvenanceTag",
+    "QueryError",
+    "ReadOnlyError",
+    "RecordMetadata",
     "Schema",
+    "SchemaError",
     "SchemaType",
+    "ShardedIngest",
     "String",
+    "TypeMismatchError",
     "Union",
 ]
@@ -7,10 +7,12 @@ PyDataType: TypeAlias = Union[
     PyBool,
     PyDateTime,
     PyDuration,
+    PyGeo,
     PyNull,
     PyAny,
     PyUnion,
     PyOption,
+    PyMissingType,
 ]
 
 class PyString: ...
@@ -19,8 +21,10 @@ class PyFloat: ...
 class PyBool: ...
 class PyDateTime: ...
 class PyDuration: ...
+class PyGeo: ...
 class PyNull: ...
 class PyAny: ...
+class PyMissingType: ...
 
 class PyUnion:
     dtype1: PyDataType
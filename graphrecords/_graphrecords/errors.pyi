@@ -0,0 +1,11 @@
+class GraphRecordError(Exception): ...
+class GraphRecordIndexError(GraphRecordError, IndexError): ...
+class GraphRecordKeyError(GraphRecordError, KeyError): ...
+class ConversionError(GraphRecordError, RuntimeError): ...
+class GraphRecordAssertionError(GraphRecordError, AssertionError): ...
+class SchemaError(GraphRecordError, ValueError): ...
+class QueryError(GraphRecordError, RuntimeError): ...
+class ReadOnlyError(GraphRecordError, RuntimeError): ...
+class MissingNodeError(GraphRecordIndexError): ...
+class DuplicateGroupError(GraphRecordAssertionError): ...
+class TypeMismatchError(SchemaError): ...
@@ -1,12 +1,30 @@
-from typing import Any, Callable, Dict, List, Optional, Sequence
+from datetime import datetime, timedelta
+from enum import Enum
+from typing import Any, Callable, Dict, List, Optional, Sequence, Tuple
 
-from graphrecords._graphrecords.overview import PyGroupOverview, PyOverview
-from graphrecords._graphrecords.querying import PyEdgeOperand, PyNodeOperand
+import polars as pl
+
+from graphrecords._graphrecords.overview import (
+    PyGroupOverview,
+    PyOverview,
+    PyOverviewFormat,
+)
+from graphrecords._graphrecords.querying import (
+    PyEdgeDirection,
+    PyEdgeIndicesOperand,
+    PyEdgeMultipleValuesWithIndexOperand,
+    PyEdgeOperand,
+    PyNodeIndicesOperand,
+    PyNodeMultipleValuesWithIndexOperand,
+    PyNodeOperand,
+)
 from graphrecords._graphrecords.schema import PySchema
 from graphrecords.querying import PyQueryReturnOperand, QueryResult
 from graphrecords.types import (
     Attributes,
     AttributesInput,
+    ChiSquareTest,
+    CorrelationTest,
     EdgeIndex,
     EdgeIndexInputList,
     EdgeTuple,
@@ -21,6 +39,8 @@ from graphrecords.types import (
     PolarsDataFramesExport,
     PolarsEdgeDataFrameInput,
     PolarsNodeDataFrameInput,
+    Stats,
+    TimeToEvent,
     _PyConnector,
     _PyPlugin,
 )
@@ -30,6 +50,10 @@ class PyGraphRecord:
     edges: List[EdgeIndex]
     groups: List[Group]
     plugins: List[PluginName]
+    udfs: List[str]
+    text_indices: List[Tuple[Group, GraphRecordAttribute]]
+    geo_indices: List[Tuple[Group, GraphRecordAttribute]]
+    operation_context: Attributes
 
     def __init__(self) -> None: ...
     def _to_bytes(self) -> bytes: ...
@@ -38,7 +62,9 @@ class PyGraphRecord:
     @staticmethod
     def with_schema(schema: PySchema) -> PyGraphRecord: ...
     @staticmethod
-    def with_plugins(plugins: Dict[PluginName, _PyPlugin]) -> PyGraphRecord: ...
+    def with_plugins(
+        plugins: Sequence[Tuple[PluginName, _PyPlugin, int]],
+    ) -> PyGraphRecord: ...
     @staticmethod
     def from_tuples(
         nodes: Sequence[NodeTuple],
@@ -61,16 +87,70 @@ class PyGraphRecord:
     @staticmethod
     def with_connector(connector: _PyConnector) -> PyGraphRecord: ...
     def to_ron(self, path: str) -> None: ...
-    def to_dataframes(self) -> PolarsDataFramesExport: ...
+    def to_dataframes(self, sort: bool = False) -> PolarsDataFramesExport: ...
     def disconnect(self) -> PyGraphRecord: ...
     def ingest(self, data: Any) -> None: ...  # noqa: ANN401
     def export(self) -> Any: ...  # noqa: ANN401
-    def add_plugin(self, name: PluginName, plugin: _PyPlugin) -> None: ...
+    def add_plugin(
+        self, name: PluginName, plugin: _PyPlugin, priority: int = 0
+    ) -> None: ...
     def remove_plugin(self, name: PluginName) -> None: ...
+    def register_plugin(self, plugin: _PyPlugin, priority: int = 0) -> int: ...
+    def unregister_plugin(self, handle: int) -> None: ...
+    def plugin_priority(self, name: PluginName) -> int: ...
+    def set_plugin_priority(self, name: PluginName, priority: int) -> None: ...
+    def register_udf(
+        self, name: str, udf: Callable[[GraphRecordValue], GraphRecordValue]
+    ) -> None: ...
+    def remove_udf(self, name: str) -> None: ...
+    def create_text_index(
+        self, group: Group, attribute: GraphRecordAttribute
+    ) -> None: ...
+    def drop_text_index(
+        self, group: Group, attribute: GraphRecordAttribute
+    ) -> None: ...
+    def create_geo_index(
+        self, group: Group, attribute: GraphRecordAttribute
+    ) -> None: ...
+    def drop_geo_index(
+        self, group: Group, attribute: GraphRecordAttribute
+    ) -> None: ...
+    def set_operation_context(self, context: Attributes) -> Attributes: ...
+    def change_token(self) -> int: ...
+    def nodes_sorted(self) -> List[NodeIndex]: ...
+    def edges_sorted(self) -> List[EdgeIndex]: ...
+    def groups_sorted(self) -> List[Group]: ...
+    def is_modified_since(self, token: int) -> bool: ...
     def get_schema(self) -> PySchema: ...
     def set_schema(self, schema: PySchema, bypass_plugins: bool = False) -> None: ...
     def freeze_schema(self, bypass_plugins: bool = False) -> None: ...
     def unfreeze_schema(self, bypass_plugins: bool = False) -> None: ...
+    def freeze(self) -> None: ...
+    def unfreeze(self) -> None: ...
+    def is_frozen(self) -> bool: ...
+    def compact(self) -> Dict[EdgeIndex, EdgeIndex]: ...
+    def set_recycle_edge_indices(self, recycle_edge_indices: bool) -> None: ...
+    def recycle_edge_indices(self) -> bool: ...
+    def set_strict_queries(self, strict_queries: bool) -> None: ...
+    def strict_queries(self) -> bool: ...
+    def set_float_tolerance(self, tolerance: float) -> None: ...
+    def float_tolerance(self) -> float: ...
+    def set_random_seed(self, seed: Optional[int] = None) -> None: ...
+    def random_seed(self) -> Optional[int]: ...
+    def metadata(self) -> PyRecordMetadata: ...
+    def set_metadata(self, metadata: PyRecordMetadata) -> PyRecordMetadata: ...
+    def enable_provenance(self) -> None: ...
+    def disable_provenance(self) -> None: ...
+    def provenance_enabled(self) -> bool: ...
+    def set_provenance_tag(
+        self, tag: Optional[PyProvenanceTag] = None
+    ) -> Optional[PyProvenanceTag]: ...
+    def node_attribute_provenance(
+        self, node_index: NodeIndex, attribute: GraphRecordAttribute
+    ) -> Optional[PyProvenanceTag]: ...
+    def edge_attribute_provenance(
+        self, edge_index: EdgeIndex, attribute: GraphRecordAttribute
+    ) -> Optional[PyProvenanceTag]: ...
     def node(self, node_index: NodeIndexInputList) -> Dict[NodeIndex, Attributes]: ...
     def edge(self, edge_index: EdgeIndexInputList) -> Dict[EdgeIndex, Attributes]: ...
     def outgoing_edges(
@@ -82,6 +162,12 @@ class PyGraphRecord:
     def edge_endpoints(
         self, edge_index: EdgeIndexInputList
     ) -> Dict[EdgeIndex, tuple[NodeIndex, NodeIndex]]: ...
+    def set_edge_undirected(
+        self, edge_index: EdgeIndexInputList, undirected: bool
+    ) -> None: ...
+    def is_edge_undirected(
+        self, edge_index: EdgeIndexInputList
+    ) -> Dict[EdgeIndex, bool]: ...
     def edges_connecting(
         self,
         source_node_indices: NodeIndexInputList,
@@ -92,6 +178,7 @@ class PyGraphRecord:
         source_node_indices: NodeIndexInputList,
         target_node_indices: NodeIndexInputList,
     ) -> List[EdgeIndex]: ...
+    def induced_edges(self, node_indices: NodeIndexInputList) -> List[EdgeIndex]: ...
     def remove_nodes(
         self, node_index: NodeIndexInputList, bypass_plugins: bool = False
     ) -> Dict[NodeIndex, Attributes]: ...
@@ -107,6 +194,19 @@ class PyGraphRecord:
     def remove_node_attribute(
         self, node_index: NodeIndexInputList, attribute: GraphRecordAttribute
     ) -> None: ...
+    def add_node_auto(self, attributes: Attributes) -> NodeIndex: ...
+    def add_nodes_auto(self, attributes: List[Attributes]) -> List[NodeIndex]: ...
+    def auto_index_prefix(self) -> Optional[str]: ...
+    def set_auto_index_prefix(self, prefix: Optional[str] = None) -> None: ...
+    def add_relation(
+        self,
+        relation_index: NodeIndex,
+        attributes: Attributes,
+        members: List[tuple[str, NodeIndex]],
+    ) -> List[EdgeIndex]: ...
+    def relation_members(
+        self, relation_index: NodeIndex
+    ) -> List[tuple[GraphRecordValue, NodeIndex]]: ...
     def add_nodes(
         self, nodes: Sequence[NodeTuple], bypass_plugins: bool = False
     ) -> None: ...
@@ -190,6 +290,7 @@ class PyGraphRecord:
     def remove_groups(
         self, group: GroupInputList, bypass_plugins: bool = False
     ) -> None: ...
+    def reserve_group(self, group: Group, nodes: int, edges: int) -> None: ...
     def add_nodes_to_group(
         self, group: Group, node_index: NodeIndexInputList, bypass_plugins: bool = False
     ) -> None: ...
@@ -278,9 +379,139 @@ class PyGraphRecord:
     def node_count(self) -> int: ...
     def edge_count(self) -> int: ...
     def group_count(self) -> int: ...
+    def stats(self) -> Stats: ...
+    def correlation(
+        self,
+        group: Group,
+        attribute_a: GraphRecordAttribute,
+        attribute_b: GraphRecordAttribute,
+        method: PyCorrelationMethod,
+    ) -> CorrelationTest: ...
+    def chi_square(
+        self,
+        group: Group,
+        attribute_a: GraphRecordAttribute,
+        attribute_b: GraphRecordAttribute,
+    ) -> ChiSquareTest: ...
+    def propensity_scores(
+        self,
+        treated: Group,
+        control: Group,
+        covariates: List[GraphRecordAttribute],
+    ) -> Dict[NodeIndex, float]: ...
+    def match_propensity_scores(
+        self,
+        treated: Group,
+        control: Group,
+        covariates: List[GraphRecordAttribute],
+        edge_group: Group,
+        caliper: Optional[float] = None,
+    ) -> List[Tuple[NodeIndex, NodeIndex, EdgeIndex]]: ...
+    def cohort_table(
+        self,
+        group_a: Group,
+        group_b: Group,
+        attributes: List[GraphRecordAttribute],
+    ) -> pl.DataFrame: ...
+    def crosstab(
+        self,
+        group: Group,
+        row_attribute: GraphRecordAttribute,
+        column_attribute: GraphRecordAttribute,
+        normalization: Optional[PyCrosstabNormalization] = None,
+    ) -> Tuple[List[GraphRecordValue], List[GraphRecordValue], List[List[float]]]: ...
+    def group_adjacency_counts(
+        self,
+    ) -> Dict[Tuple[Optional[Group], Optional[Group]], int]: ...
+    def time_to_event(
+        self,
+        cohort_group: Group,
+        index_date_attribute: GraphRecordAttribute,
+        event_edge_group: Group,
+        censor_date: datetime,
+    ) -> Dict[NodeIndex, TimeToEvent]: ...
+    def events_in_window(
+        self,
+        cohort_group: Group,
+        index_date_attribute: GraphRecordAttribute,
+        event_edge_group: Group,
+        start: timedelta,
+        end: timedelta,
+        washout: Optional[timedelta] = None,
+    ) -> Dict[NodeIndex, bool]: ...
+    def event_rate_in_window(
+        self,
+        cohort_group: Group,
+        index_date_attribute: GraphRecordAttribute,
+        event_edge_group: Group,
+        start: timedelta,
+        end: timedelta,
+        washout: Optional[timedelta] = None,
+    ) -> Tuple[int, int, Optional[float]]: ...
+    def k_anonymity(
+        self, group: Group, quasi_identifiers: List[GraphRecordAttribute]
+    ) -> int: ...
+    def k_anonymity_violations(
+        self, group: Group, quasi_identifiers: List[GraphRecordAttribute], k: int
+    ) -> List[NodeIndex]: ...
+    def enforce_k_anonymity(
+        self, group: Group, quasi_identifiers: List[GraphRecordAttribute], k: int
+    ) -> List[NodeIndex]: ...
+    def bucket_attribute(
+        self, group: Group, attribute: GraphRecordAttribute, width: int
+    ) -> None: ...
+    def truncate_attribute(
+        self, group: Group, attribute: GraphRecordAttribute, length: int
+    ) -> None: ...
+    def rename_attribute(
+        self,
+        attribute: GraphRecordAttribute,
+        new_attribute: GraphRecordAttribute,
+        scope: PyAttributeScope,
+        group: Optional[Group] = None,
+    ) -> None: ...
+    def drop_attribute(
+        self,
+        attribute: GraphRecordAttribute,
+        scope: PyAttributeScope,
+        group: Optional[Group] = None,
+    ) -> None: ...
+    def materialize_node_attribute(
+        self,
+        attribute: GraphRecordAttribute,
+        query: Callable[[PyNodeOperand], PyNodeMultipleValuesWithIndexOperand],
+    ) -> int: ...
+    def materialize_edge_attribute(
+        self,
+        attribute: GraphRecordAttribute,
+        query: Callable[[PyEdgeOperand], PyEdgeMultipleValuesWithIndexOperand],
+    ) -> int: ...
+    def group_from_node_query(
+        self, group: Group, query: Callable[[PyNodeOperand], PyNodeIndicesOperand]
+    ) -> int: ...
+    def group_from_edge_query(
+        self, group: Group, query: Callable[[PyEdgeOperand], PyEdgeIndicesOperand]
+    ) -> int: ...
+    @staticmethod
+    def concat(records: List[PyGraphRecord]) -> PyGraphRecord: ...
+    def split_by_groups(self, groups: List[Group]) -> Dict[Group, PyGraphRecord]: ...
+    def memory_usage(self) -> Dict[str, int]: ...
+    def shrink_to_fit(self) -> None: ...
+    def maintain(self) -> Dict[str, int]: ...
+    def fingerprint(self) -> int: ...
+    def equals(self, other: PyGraphRecord) -> bool: ...
+    def is_subgraph_of(self, other: PyGraphRecord) -> bool: ...
+    def __eq__(self, other: object) -> bool: ...
     def contains_node(self, node_index: NodeIndex) -> bool: ...
     def contains_edge(self, edge_index: EdgeIndex) -> bool: ...
     def contains_group(self, group: Group) -> bool: ...
+    def group_metadata(self, group: Group) -> Attributes: ...
+    def set_group_metadata(
+        self, group: Group, key: GraphRecordAttribute, value: GraphRecordValue
+    ) -> None: ...
+    def remove_group_metadata(
+        self, group: Group, key: GraphRecordAttribute
+    ) -> GraphRecordValue: ...
     def neighbors_outgoing(
         self, node_indices: NodeIndexInputList
     ) -> Dict[NodeIndex, List[NodeIndex]]: ...
@@ -290,6 +521,13 @@ class PyGraphRecord:
     def neighbors_undirected(
         self, node_indices: NodeIndexInputList
     ) -> Dict[NodeIndex, List[NodeIndex]]: ...
+    def neighbors_filtered(
+        self,
+        node_indices: NodeIndexInputList,
+        direction: PyEdgeDirection,
+        edge_group: Optional[Group] = None,
+        edge_indices: Optional[EdgeIndexInputList] = None,
+    ) -> Dict[NodeIndex, List[NodeIndex]]: ...
     def clear(self, bypass_plugins: bool = False) -> None: ...
     def query_nodes(
         self, query: Callable[[PyNodeOperand], PyQueryReturnOperand]
@@ -298,7 +536,108 @@ class PyGraphRecord:
         self, query: Callable[[PyEdgeOperand], PyQueryReturnOperand]
     ) -> QueryResult: ...
     def clone(self) -> PyGraphRecord: ...
-    def overview(self, truncate_details: Optional[int]) -> PyOverview: ...
+    def overview(
+        self,
+        truncate_details: Optional[int],
+        format: PyOverviewFormat,
+        categorical_threshold: Optional[int] = None,
+    ) -> PyOverview: ...
+    def overview_sampled(
+        self,
+        sample_size: int,
+        seed: int,
+        truncate_details: Optional[int],
+        format: PyOverviewFormat,
+        categorical_threshold: Optional[int] = None,
+    ) -> PyOverview: ...
     def group_overview(
-        self, group: Group, truncate_details: Optional[int]
+        self,
+        group: Group,
+        truncate_details: Optional[int],
+        format: PyOverviewFormat,
+        categorical_threshold: Optional[int] = None,
     ) -> PyGroupOverview: ...
+    def _repr_html_(self) -> str: ...
+
+class PyAttributeScope(Enum):
+    All = ...
+    Nodes = ...
+    Edges = ...
+
+class PyCorrelationMethod(Enum):
+    Pearson = ...
+    Spearman = ...
+
+class PyCrosstabNormalization(Enum):
+    All = ...
+    Rows = ...
+    Columns = ...
+
+class PyMissing:
+    def __init__(self) -> None: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+
+class PyGeoPoint:
+    latitude: float
+    longitude: float
+
+    def __init__(self, latitude: float, longitude: float) -> None: ...
+    def distance_km(self, other: PyGeoPoint) -> float: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+
+class PyProvenanceTag:
+    source: str
+    batch_id: Optional[str]
+    timestamp: datetime
+
+    def __init__(
+        self, source: str, timestamp: datetime, batch_id: Optional[str] = None
+    ) -> None: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+
+class PyRecordMetadata:
+    title: Optional[str]
+    description: Optional[str]
+    created_at: Optional[datetime]
+    source_datasets: List[str]
+    properties: Attributes
+
+    def __init__(
+        self,
+        title: Optional[str] = None,
+        description: Optional[str] = None,
+        created_at: Optional[datetime] = None,
+        source_datasets: List[str] = ...,
+        properties: Attributes = ...,
+    ) -> None: ...
+    def __eq__(self, other: object) -> bool: ...
+
+class PyShardedIngest:
+    shard_count: int
+
+    def __init__(self, shard_count: Optional[int] = None) -> None: ...
+    def add_node(
+        self,
+        node_index: NodeIndex,
+        attributes: AttributesInput,
+        group: Optional[Group] = None,
+    ) -> None: ...
+    def add_nodes(
+        self, nodes: Sequence[NodeTuple], group: Optional[Group] = None
+    ) -> None: ...
+    def add_edge(
+        self,
+        source_node_index: NodeIndex,
+        target_node_index: NodeIndex,
+        attributes: AttributesInput,
+        group: Optional[Group] = None,
+    ) -> None: ...
+    def add_edges(
+        self, edges: Sequence[EdgeTuple], group: Optional[Group] = None
+    ) -> None: ...
+    def pending_node_count(self) -> int: ...
+    def pending_edge_count(self) -> int: ...
+    def commit(self, graphrecord: PyGraphRecord) -> None: ...
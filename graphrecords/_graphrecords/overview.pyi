@@ -1,18 +1,27 @@
+from enum import Enum
 from typing import Dict, Union
 
 from typing_extensions import Final
 
 from graphrecords._graphrecords.datatype import PyDataType
 from graphrecords.types import (
+    Attributes,
     GraphRecordAttribute,
     PyCategoricalAttributeOverview,
     PyContinuousAttributeOverview,
     PyTemporalAttributeOverview,
     PyUnstructuredAttributeOverview,
+    StructureOverview,
 )
 
 PY_DEFAULT_TRUNCATE_DETAILS: Final[int] = ...
 
+class PyOverviewFormat(Enum):
+    Modern = ...
+    Ascii = ...
+    Markdown = ...
+    Csv = ...
+
 class PyAttributeOverview:
     data_type: PyDataType
     data: Union[
@@ -33,7 +42,14 @@ class PyEdgeGroupOverview:
 class PyGroupOverview:
     node_overview: PyNodeGroupOverview
     edge_overview: PyEdgeGroupOverview
+    metadata: Attributes
+    structure: StructureOverview
+
+    def _repr_html_(self) -> str: ...
 
 class PyOverview:
     ungrouped_overview: PyGroupOverview
     grouped_overviews: Dict[GraphRecordAttribute, PyGroupOverview]
+    is_approximate: bool
+
+    def _repr_html_(self) -> str: ...
@@ -1,9 +1,11 @@
-from typing import List, Optional, Tuple
+from typing import Dict, List, Optional, Tuple
 
-from graphrecords._graphrecords.schema import PySchema
+from graphrecords._graphrecords.schema import PyAttributeDataType, PySchema
 from graphrecords.types import (
     Attributes,
     EdgeIndex,
+    GraphRecordAttribute,
+    GraphRecordValue,
     Group,
     NodeIndex,
     PolarsEdgeDataFrameInput,
@@ -15,6 +17,36 @@ class PyPreSetSchemaContext:
     @property
     def schema(self) -> PySchema: ...
 
+class PyPostSetSchemaContext:
+    def __init__(self, old_schema: PySchema, new_schema: PySchema) -> None: ...
+    @property
+    def old_schema(self) -> PySchema: ...
+    @property
+    def new_schema(self) -> PySchema: ...
+    @property
+    def changes(
+        self,
+    ) -> Tuple[
+        List[Group],
+        List[Group],
+        List[
+            Tuple[
+                Optional[Group],
+                GraphRecordAttribute,
+                Optional[PyAttributeDataType],
+                Optional[PyAttributeDataType],
+            ]
+        ],
+        List[
+            Tuple[
+                Optional[Group],
+                GraphRecordAttribute,
+                Optional[PyAttributeDataType],
+                Optional[PyAttributeDataType],
+            ]
+        ],
+    ]: ...
+
 class PyPreAddNodeContext:
     def __init__(self, node_index: NodeIndex, attributes: Attributes) -> None: ...
     @property
@@ -543,3 +575,21 @@ class PyPostAddEdgesDataframesWithGroupsContext:
     def edges_dataframes(self) -> List[PolarsEdgeDataFrameInput]: ...
     @property
     def groups(self) -> List[Group]: ...
+
+class PyMappingPlugin:
+    def __init__(
+        self,
+        node_mappings: Dict[
+            GraphRecordAttribute, List[Tuple[GraphRecordValue, GraphRecordValue]]
+        ],
+        edge_mappings: Dict[
+            GraphRecordAttribute, List[Tuple[GraphRecordValue, GraphRecordValue]]
+        ],
+    ) -> None: ...
+    def unmapped_node_values(
+        self,
+    ) -> Dict[GraphRecordAttribute, List[GraphRecordValue]]: ...
+    def unmapped_edge_values(
+        self,
+    ) -> Dict[GraphRecordAttribute, List[GraphRecordValue]]: ...
+    def clear_unmapped_values(self) -> None: ...
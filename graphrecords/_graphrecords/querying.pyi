@@ -1,3 +1,4 @@
+from datetime import timedelta
 from enum import Enum
 from typing import (
     Callable,
@@ -24,6 +25,10 @@ class PyMatchMode(Enum):
     Any = ...
     All = ...
 
+class PyRollingAggregation(Enum):
+    Mean = ...
+    Sum = ...
+
 class NodeOperandGroupDiscriminator:
     class Attribute(NodeOperandGroupDiscriminator):
         def __init__(self, attribute: GraphRecordAttribute) -> None: ...
@@ -47,12 +52,17 @@ class PyNodeOperand:
     ) -> None: ...
     def edges(self, direction: PyEdgeDirection) -> PyEdgeOperand: ...
     def neighbors(self, direction: PyEdgeDirection) -> PyNodeOperand: ...
+    def degree(
+        self, direction: PyEdgeDirection
+    ) -> PyNodeMultipleValuesWithIndexOperand: ...
     def either_or(
         self,
         either: Callable[[PyNodeOperand], None],
         or_: Callable[[PyNodeOperand], None],
     ) -> None: ...
     def exclude(self, query: Callable[[PyNodeOperand], None]) -> None: ...
+    def all_of(self, queries: List[Callable[[PyNodeOperand], None]]) -> None: ...
+    def none_of(self, queries: List[Callable[[PyNodeOperand], None]]) -> None: ...
     def group_by(
         self, discriminator: NodeOperandGroupDiscriminator
     ) -> PyNodeGroupOperand: ...
@@ -77,12 +87,20 @@ class PyNodeGroupOperand:
     ) -> None: ...
     def edges(self, direction: PyEdgeDirection) -> PyEdgeGroupOperand: ...
     def neighbors(self, direction: PyEdgeDirection) -> PyNodeGroupOperand: ...
+    def degree(
+        self, direction: PyEdgeDirection
+    ) -> PyNodeMultipleValuesWithIndexGroupOperand: ...
     def either_or(
         self,
         either: Callable[[PyNodeOperand], None],
         or_: Callable[[PyNodeOperand], None],
     ) -> None: ...
     def exclude(self, query: Callable[[PyNodeOperand], None]) -> None: ...
+    def all_of(self, queries: List[Callable[[PyNodeOperand], None]]) -> None: ...
+    def none_of(self, queries: List[Callable[[PyNodeOperand], None]]) -> None: ...
+    def group_by(
+        self, discriminator: NodeOperandGroupDiscriminator
+    ) -> PyNodeGroupOperand: ...
     def deep_clone(self) -> PyNodeGroupOperand: ...
 
 PyNodeIndexComparisonOperand: TypeAlias = Union[NodeIndex, PyNodeIndexOperand]
@@ -122,6 +140,9 @@ class PyNodeIndicesOperand:
     def is_int(self) -> None: ...
     def is_max(self) -> None: ...
     def is_min(self) -> None: ...
+    def sort(self, descending: bool) -> None: ...
+    def skip(self, count: int) -> None: ...
+    def limit(self, count: int) -> None: ...
     def either_or(
         self,
         either: Callable[[PyNodeIndicesOperand], None],
@@ -163,6 +184,9 @@ class PyNodeIndicesGroupOperand:
     def is_int(self) -> None: ...
     def is_max(self) -> None: ...
     def is_min(self) -> None: ...
+    def sort(self, descending: bool) -> None: ...
+    def skip(self, count: int) -> None: ...
+    def limit(self, count: int) -> None: ...
     def either_or(
         self,
         either: Callable[[PyNodeIndicesOperand], None],
@@ -170,6 +194,14 @@ class PyNodeIndicesGroupOperand:
     ) -> None: ...
     def exclude(self, query: Callable[[PyNodeIndicesOperand], None]) -> None: ...
     def ungroup(self) -> PyNodeIndicesOperand: ...
+    def sorted_by_key(self) -> None: ...
+    def sorted_by_aggregate(
+        self,
+        aggregate: Union[
+            PyNodeSingleValueWithoutIndexGroupOperand,
+            PyEdgeSingleValueWithoutIndexGroupOperand,
+        ],
+    ) -> None: ...
     def deep_clone(self) -> PyNodeIndicesGroupOperand: ...
 
 class PyNodeIndexOperand:
@@ -239,6 +271,14 @@ class PyNodeIndexGroupOperand:
     ) -> None: ...
     def exclude(self, query: Callable[[PyNodeIndexOperand], None]) -> None: ...
     def ungroup(self) -> PyNodeIndicesOperand: ...
+    def sorted_by_key(self) -> None: ...
+    def sorted_by_aggregate(
+        self,
+        aggregate: Union[
+            PyNodeSingleValueWithoutIndexGroupOperand,
+            PyEdgeSingleValueWithoutIndexGroupOperand,
+        ],
+    ) -> None: ...
     def deep_clone(self) -> PyNodeIndexGroupOperand: ...
 
 class EdgeOperandGroupDiscriminator:
@@ -254,6 +294,9 @@ class EdgeOperandGroupDiscriminator:
     class Parallel(EdgeOperandGroupDiscriminator):
         def __init__(self) -> None: ...
 
+    class EndpointGroups(EdgeOperandGroupDiscriminator):
+        def __init__(self) -> None: ...
+
 class PyEdgeOperand:
     def attribute(
         self, attribute: GraphRecordAttribute
@@ -273,12 +316,15 @@ class PyEdgeOperand:
     ) -> None: ...
     def source_node(self) -> PyNodeOperand: ...
     def target_node(self) -> PyNodeOperand: ...
+    def induced_by(self, query: Callable[[PyNodeOperand], None]) -> None: ...
     def either_or(
         self,
         either: Callable[[PyEdgeOperand], None],
         or_: Callable[[PyEdgeOperand], None],
     ) -> None: ...
     def exclude(self, query: Callable[[PyEdgeOperand], None]) -> None: ...
+    def all_of(self, queries: List[Callable[[PyEdgeOperand], None]]) -> None: ...
+    def none_of(self, queries: List[Callable[[PyEdgeOperand], None]]) -> None: ...
     def group_by(
         self, discriminator: EdgeOperandGroupDiscriminator
     ) -> PyEdgeGroupOperand: ...
@@ -303,12 +349,18 @@ class PyEdgeGroupOperand:
     ) -> None: ...
     def source_node(self) -> PyNodeGroupOperand: ...
     def target_node(self) -> PyNodeGroupOperand: ...
+    def induced_by(self, query: Callable[[PyNodeOperand], None]) -> None: ...
     def either_or(
         self,
         either: Callable[[PyEdgeOperand], None],
         or_: Callable[[PyEdgeOperand], None],
     ) -> None: ...
     def exclude(self, query: Callable[[PyEdgeOperand], None]) -> None: ...
+    def all_of(self, queries: List[Callable[[PyEdgeOperand], None]]) -> None: ...
+    def none_of(self, queries: List[Callable[[PyEdgeOperand], None]]) -> None: ...
+    def group_by(
+        self, discriminator: EdgeOperandGroupDiscriminator
+    ) -> PyEdgeGroupOperand: ...
     def deep_clone(self) -> PyEdgeGroupOperand: ...
 
 PyEdgeIndexComparisonOperand: TypeAlias = Union[EdgeIndex, PyEdgeIndexOperand]
@@ -339,6 +391,9 @@ class PyEdgeIndicesOperand:
     def mod(self, index: PyEdgeIndexArithmeticOperand) -> None: ...
     def is_max(self) -> None: ...
     def is_min(self) -> None: ...
+    def sort(self, descending: bool) -> None: ...
+    def skip(self, count: int) -> None: ...
+    def limit(self, count: int) -> None: ...
     def either_or(
         self,
         either: Callable[[PyEdgeIndicesOperand], None],
@@ -371,6 +426,9 @@ class PyEdgeIndicesGroupOperand:
     def mod(self, index: PyEdgeIndexArithmeticOperand) -> None: ...
     def is_max(self) -> None: ...
     def is_min(self) -> None: ...
+    def sort(self, descending: bool) -> None: ...
+    def skip(self, count: int) -> None: ...
+    def limit(self, count: int) -> None: ...
     def either_or(
         self,
         either: Callable[[PyEdgeIndicesOperand], None],
@@ -378,6 +436,14 @@ class PyEdgeIndicesGroupOperand:
     ) -> None: ...
     def exclude(self, query: Callable[[PyEdgeIndicesOperand], None]) -> None: ...
     def ungroup(self) -> PyEdgeIndicesOperand: ...
+    def sorted_by_key(self) -> None: ...
+    def sorted_by_aggregate(
+        self,
+        aggregate: Union[
+            PyNodeSingleValueWithoutIndexGroupOperand,
+            PyEdgeSingleValueWithoutIndexGroupOperand,
+        ],
+    ) -> None: ...
     def deep_clone(self) -> PyEdgeIndicesGroupOperand: ...
 
 class PyEdgeIndexOperand:
@@ -429,6 +495,14 @@ class PyEdgeIndexGroupOperand:
     ) -> None: ...
     def exclude(self, query: Callable[[PyEdgeIndexOperand], None]) -> None: ...
     def ungroup(self) -> PyEdgeIndicesOperand: ...
+    def sorted_by_key(self) -> None: ...
+    def sorted_by_aggregate(
+        self,
+        aggregate: Union[
+            PyNodeSingleValueWithoutIndexGroupOperand,
+            PyEdgeSingleValueWithoutIndexGroupOperand,
+        ],
+    ) -> None: ...
     def deep_clone(self) -> PyEdgeIndexGroupOperand: ...
 
 PySingleValueComparisonOperand: TypeAlias = Union[
@@ -452,10 +526,15 @@ class PyNodeMultipleValuesWithIndexOperand:
     def min(self) -> PyNodeSingleValueWithIndexOperand: ...
     def mean(self) -> PyNodeSingleValueWithoutIndexOperand: ...
     def median(self) -> PyNodeSingleValueWithoutIndexOperand: ...
+    def quantile(self, quantile: float) -> PyNodeSingleValueWithoutIndexOperand: ...
     def mode(self) -> PyNodeSingleValueWithoutIndexOperand: ...
     def std(self) -> PyNodeSingleValueWithoutIndexOperand: ...
     def var(self) -> PyNodeSingleValueWithoutIndexOperand: ...
     def count(self) -> PyNodeSingleValueWithoutIndexOperand: ...
+    def count_distinct(self) -> PyNodeSingleValueWithoutIndexOperand: ...
+    def value_counts(self) -> PyNodeValueCountsOperand: ...
+    def histogram(self, bins: int) -> PyNodeHistogramOperand: ...
+    def quantiles(self, quantiles: List[float]) -> PyNodeQuantilesOperand: ...
     def sum(self) -> PyNodeSingleValueWithoutIndexOperand: ...
     def random(self) -> PyNodeSingleValueWithIndexOperand: ...
     def greater_than(self, value: PySingleValueComparisonOperand) -> None: ...
@@ -469,6 +548,18 @@ class PyNodeMultipleValuesWithIndexOperand:
     def starts_with(self, value: PySingleValueComparisonOperand) -> None: ...
     def ends_with(self, value: PySingleValueComparisonOperand) -> None: ...
     def contains(self, value: PySingleValueComparisonOperand) -> None: ...
+    def levenshtein_at_most(
+        self, target: PySingleValueComparisonOperand, distance: int
+    ) -> None: ...
+    def jaro_winkler_at_least(
+        self, target: PySingleValueComparisonOperand, similarity: float
+    ) -> None: ...
+    def is_close(
+        self, value: PySingleValueComparisonOperand, tolerance: float
+    ) -> None: ...
+    def within_radius(
+        self, point: PySingleValueComparisonOperand, radius_km: float
+    ) -> None: ...
     def is_in(self, values: PyMultipleValuesComparisonOperand) -> None: ...
     def is_not_in(self, values: PyMultipleValuesComparisonOperand) -> None: ...
     def add(self, value: PySingleValueArithmeticOperand) -> None: ...
@@ -481,6 +572,7 @@ class PyNodeMultipleValuesWithIndexOperand:
     def ceil(self) -> None: ...
     def floor(self) -> None: ...
     def abs(self) -> None: ...
+    def apply(self, udf: str) -> None: ...
     def sqrt(self) -> None: ...
     def trim(self) -> None: ...
     def trim_start(self) -> None: ...
@@ -495,8 +587,41 @@ class PyNodeMultipleValuesWithIndexOperand:
     def is_datetime(self) -> None: ...
     def is_duration(self) -> None: ...
     def is_null(self) -> None: ...
+    def is_missing(self) -> None: ...
+    def provenance(self) -> None: ...
+    def text_search(self, query: str) -> None: ...
+    def rolling(
+        self,
+        window: timedelta,
+        aggregation: PyRollingAggregation,
+        order_by_attribute: GraphRecordAttribute,
+    ) -> None: ...
+    def cumsum(self, order_by_attribute: GraphRecordAttribute) -> None: ...
+    def lag(self, n: int, order_by_attribute: GraphRecordAttribute) -> None: ...
+    def lead(self, n: int, order_by_attribute: GraphRecordAttribute) -> None: ...
+    def argmax(self) -> PyNodeSingleValueWithIndexOperand: ...
+    def argmin(self) -> PyNodeSingleValueWithIndexOperand: ...
+    def first_by(
+        self, order_by_attribute: GraphRecordAttribute
+    ) -> PyNodeSingleValueWithIndexOperand: ...
+    def last_by(
+        self, order_by_attribute: GraphRecordAttribute
+    ) -> PyNodeSingleValueWithIndexOperand: ...
+    def random_weighted(
+        self, weight_attribute: GraphRecordAttribute
+    ) -> PyNodeSingleValueWithIndexOperand: ...
+    def when(
+        self,
+        query: Callable[[PyNodeMultipleValuesWithIndexOperand], None],
+        then: PySingleValueComparisonOperand,
+        otherwise: PySingleValueComparisonOperand,
+    ) -> None: ...
     def is_max(self) -> None: ...
     def is_min(self) -> None: ...
+    def distinct(self) -> None: ...
+    def sort(self, descending: bool) -> None: ...
+    def skip(self, count: int) -> None: ...
+    def limit(self, count: int) -> None: ...
     def either_or(
         self,
         either: Callable[[PyNodeMultipleValuesWithIndexOperand], None],
@@ -512,10 +637,12 @@ class PyNodeMultipleValuesWithIndexGroupOperand:
     def min(self) -> PyNodeSingleValueWithIndexGroupOperand: ...
     def mean(self) -> PyNodeSingleValueWithoutIndexGroupOperand: ...
     def median(self) -> PyNodeSingleValueWithoutIndexGroupOperand: ...
+    def quantile(self, quantile: float) -> PyNodeSingleValueWithoutIndexGroupOperand: ...
     def mode(self) -> PyNodeSingleValueWithoutIndexGroupOperand: ...
     def std(self) -> PyNodeSingleValueWithoutIndexGroupOperand: ...
     def var(self) -> PyNodeSingleValueWithoutIndexGroupOperand: ...
     def count(self) -> PyNodeSingleValueWithoutIndexGroupOperand: ...
+    def count_distinct(self) -> PyNodeSingleValueWithoutIndexGroupOperand: ...
     def sum(self) -> PyNodeSingleValueWithoutIndexGroupOperand: ...
     def random(self) -> PyNodeSingleValueWithIndexGroupOperand: ...
     def greater_than(self, value: PySingleValueComparisonOperand) -> None: ...
@@ -529,6 +656,18 @@ class PyNodeMultipleValuesWithIndexGroupOperand:
     def starts_with(self, value: PySingleValueComparisonOperand) -> None: ...
     def ends_with(self, value: PySingleValueComparisonOperand) -> None: ...
     def contains(self, value: PySingleValueComparisonOperand) -> None: ...
+    def levenshtein_at_most(
+        self, target: PySingleValueComparisonOperand, distance: int
+    ) -> None: ...
+    def jaro_winkler_at_least(
+        self, target: PySingleValueComparisonOperand, similarity: float
+    ) -> None: ...
+    def is_close(
+        self, value: PySingleValueComparisonOperand, tolerance: float
+    ) -> None: ...
+    def within_radius(
+        self, point: PySingleValueComparisonOperand, radius_km: float
+    ) -> None: ...
     def is_in(self, values: PyMultipleValuesComparisonOperand) -> None: ...
     def is_not_in(self, values: PyMultipleValuesComparisonOperand) -> None: ...
     def add(self, value: PySingleValueArithmeticOperand) -> None: ...
@@ -541,6 +680,7 @@ class PyNodeMultipleValuesWithIndexGroupOperand:
     def ceil(self) -> None: ...
     def floor(self) -> None: ...
     def abs(self) -> None: ...
+    def apply(self, udf: str) -> None: ...
     def sqrt(self) -> None: ...
     def trim(self) -> None: ...
     def trim_start(self) -> None: ...
@@ -555,8 +695,41 @@ class PyNodeMultipleValuesWithIndexGroupOperand:
     def is_datetime(self) -> None: ...
     def is_duration(self) -> None: ...
     def is_null(self) -> None: ...
+    def is_missing(self) -> None: ...
+    def provenance(self) -> None: ...
+    def text_search(self, query: str) -> None: ...
+    def rolling(
+        self,
+        window: timedelta,
+        aggregation: PyRollingAggregation,
+        order_by_attribute: GraphRecordAttribute,
+    ) -> None: ...
+    def cumsum(self, order_by_attribute: GraphRecordAttribute) -> None: ...
+    def lag(self, n: int, order_by_attribute: GraphRecordAttribute) -> None: ...
+    def lead(self, n: int, order_by_attribute: GraphRecordAttribute) -> None: ...
+    def argmax(self) -> PyNodeSingleValueWithIndexGroupOperand: ...
+    def argmin(self) -> PyNodeSingleValueWithIndexGroupOperand: ...
+    def first_by(
+        self, order_by_attribute: GraphRecordAttribute
+    ) -> PyNodeSingleValueWithIndexGroupOperand: ...
+    def last_by(
+        self, order_by_attribute: GraphRecordAttribute
+    ) -> PyNodeSingleValueWithIndexGroupOperand: ...
+    def random_weighted(
+        self, weight_attribute: GraphRecordAttribute
+    ) -> PyNodeSingleValueWithIndexGroupOperand: ...
+    def when(
+        self,
+        query: Callable[[PyNodeMultipleValuesWithIndexOperand], None],
+        then: PySingleValueComparisonOperand,
+        otherwise: PySingleValueComparisonOperand,
+    ) -> None: ...
     def is_max(self) -> None: ...
     def is_min(self) -> None: ...
+    def distinct(self) -> None: ...
+    def sort(self, descending: bool) -> None: ...
+    def skip(self, count: int) -> None: ...
+    def limit(self, count: int) -> None: ...
     def either_or(
         self,
         either: Callable[[PyNodeMultipleValuesWithIndexOperand], None],
@@ -566,6 +739,14 @@ class PyNodeMultipleValuesWithIndexGroupOperand:
         self, query: Callable[[PyNodeMultipleValuesWithIndexOperand], None]
     ) -> None: ...
     def ungroup(self) -> PyNodeMultipleValuesWithIndexOperand: ...
+    def sorted_by_key(self) -> None: ...
+    def sorted_by_aggregate(
+        self,
+        aggregate: Union[
+            PyNodeSingleValueWithoutIndexGroupOperand,
+            PyEdgeSingleValueWithoutIndexGroupOperand,
+        ],
+    ) -> None: ...
     def deep_clone(self) -> PyNodeMultipleValuesWithIndexGroupOperand: ...
 
 class PyNodeMultipleValuesWithoutIndexOperand:
@@ -573,10 +754,15 @@ class PyNodeMultipleValuesWithoutIndexOperand:
     def min(self) -> PyNodeSingleValueWithoutIndexOperand: ...
     def mean(self) -> PyNodeSingleValueWithoutIndexOperand: ...
     def median(self) -> PyNodeSingleValueWithoutIndexOperand: ...
+    def quantile(self, quantile: float) -> PyNodeSingleValueWithoutIndexOperand: ...
     def mode(self) -> PyNodeSingleValueWithoutIndexOperand: ...
     def std(self) -> PyNodeSingleValueWithoutIndexOperand: ...
     def var(self) -> PyNodeSingleValueWithoutIndexOperand: ...
     def count(self) -> PyNodeSingleValueWithoutIndexOperand: ...
+    def count_distinct(self) -> PyNodeSingleValueWithoutIndexOperand: ...
+    def value_counts(self) -> PyNodeValueCountsOperand: ...
+    def histogram(self, bins: int) -> PyNodeHistogramOperand: ...
+    def quantiles(self, quantiles: List[float]) -> PyNodeQuantilesOperand: ...
     def sum(self) -> PyNodeSingleValueWithoutIndexOperand: ...
     def random(self) -> PyNodeSingleValueWithoutIndexOperand: ...
     def greater_than(self, value: PySingleValueComparisonOperand) -> None: ...
@@ -590,6 +776,18 @@ class PyNodeMultipleValuesWithoutIndexOperand:
     def starts_with(self, value: PySingleValueComparisonOperand) -> None: ...
     def ends_with(self, value: PySingleValueComparisonOperand) -> None: ...
     def contains(self, value: PySingleValueComparisonOperand) -> None: ...
+    def levenshtein_at_most(
+        self, target: PySingleValueComparisonOperand, distance: int
+    ) -> None: ...
+    def jaro_winkler_at_least(
+        self, target: PySingleValueComparisonOperand, similarity: float
+    ) -> None: ...
+    def is_close(
+        self, value: PySingleValueComparisonOperand, tolerance: float
+    ) -> None: ...
+    def within_radius(
+        self, point: PySingleValueComparisonOperand, radius_km: float
+    ) -> None: ...
     def is_in(self, values: PyMultipleValuesComparisonOperand) -> None: ...
     def is_not_in(self, values: PyMultipleValuesComparisonOperand) -> None: ...
     def add(self, value: PySingleValueArithmeticOperand) -> None: ...
@@ -602,6 +800,7 @@ class PyNodeMultipleValuesWithoutIndexOperand:
     def ceil(self) -> None: ...
     def floor(self) -> None: ...
     def abs(self) -> None: ...
+    def apply(self, udf: str) -> None: ...
     def sqrt(self) -> None: ...
     def trim(self) -> None: ...
     def trim_start(self) -> None: ...
@@ -616,8 +815,13 @@ class PyNodeMultipleValuesWithoutIndexOperand:
     def is_datetime(self) -> None: ...
     def is_duration(self) -> None: ...
     def is_null(self) -> None: ...
+    def is_missing(self) -> None: ...
     def is_max(self) -> None: ...
     def is_min(self) -> None: ...
+    def distinct(self) -> None: ...
+    def sort(self, descending: bool) -> None: ...
+    def skip(self, count: int) -> None: ...
+    def limit(self, count: int) -> None: ...
     def either_or(
         self,
         either: Callable[[PyNodeMultipleValuesWithoutIndexOperand], None],
@@ -633,10 +837,15 @@ class PyEdgeMultipleValuesWithIndexOperand:
     def min(self) -> PyEdgeSingleValueWithIndexOperand: ...
     def mean(self) -> PyEdgeSingleValueWithoutIndexOperand: ...
     def median(self) -> PyEdgeSingleValueWithoutIndexOperand: ...
+    def quantile(self, quantile: float) -> PyEdgeSingleValueWithoutIndexOperand: ...
     def mode(self) -> PyEdgeSingleValueWithoutIndexOperand: ...
     def std(self) -> PyEdgeSingleValueWithoutIndexOperand: ...
     def var(self) -> PyEdgeSingleValueWithoutIndexOperand: ...
     def count(self) -> PyEdgeSingleValueWithoutIndexOperand: ...
+    def count_distinct(self) -> PyEdgeSingleValueWithoutIndexOperand: ...
+    def value_counts(self) -> PyEdgeValueCountsOperand: ...
+    def histogram(self, bins: int) -> PyEdgeHistogramOperand: ...
+    def quantiles(self, quantiles: List[float]) -> PyEdgeQuantilesOperand: ...
     def sum(self) -> PyEdgeSingleValueWithoutIndexOperand: ...
     def random(self) -> PyEdgeSingleValueWithIndexOperand: ...
     def greater_than(self, value: PySingleValueComparisonOperand) -> None: ...
@@ -650,6 +859,18 @@ class PyEdgeMultipleValuesWithIndexOperand:
     def starts_with(self, value: PySingleValueComparisonOperand) -> None: ...
     def ends_with(self, value: PySingleValueComparisonOperand) -> None: ...
     def contains(self, value: PySingleValueComparisonOperand) -> None: ...
+    def levenshtein_at_most(
+        self, target: PySingleValueComparisonOperand, distance: int
+    ) -> None: ...
+    def jaro_winkler_at_least(
+        self, target: PySingleValueComparisonOperand, similarity: float
+    ) -> None: ...
+    def is_close(
+        self, value: PySingleValueComparisonOperand, tolerance: float
+    ) -> None: ...
+    def within_radius(
+        self, point: PySingleValueComparisonOperand, radius_km: float
+    ) -> None: ...
     def is_in(self, values: PyMultipleValuesComparisonOperand) -> None: ...
     def is_not_in(self, values: PyMultipleValuesComparisonOperand) -> None: ...
     def add(self, value: PySingleValueArithmeticOperand) -> None: ...
@@ -662,6 +883,7 @@ class PyEdgeMultipleValuesWithIndexOperand:
     def ceil(self) -> None: ...
     def floor(self) -> None: ...
     def abs(self) -> None: ...
+    def apply(self, udf: str) -> None: ...
     def sqrt(self) -> None: ...
     def trim(self) -> None: ...
     def trim_start(self) -> None: ...
@@ -676,8 +898,41 @@ class PyEdgeMultipleValuesWithIndexOperand:
     def is_datetime(self) -> None: ...
     def is_duration(self) -> None: ...
     def is_null(self) -> None: ...
+    def is_missing(self) -> None: ...
+    def provenance(self) -> None: ...
+    def text_search(self, query: str) -> None: ...
+    def rolling(
+        self,
+        window: timedelta,
+        aggregation: PyRollingAggregation,
+        order_by_attribute: GraphRecordAttribute,
+    ) -> None: ...
+    def cumsum(self, order_by_attribute: GraphRecordAttribute) -> None: ...
+    def lag(self, n: int, order_by_attribute: GraphRecordAttribute) -> None: ...
+    def lead(self, n: int, order_by_attribute: GraphRecordAttribute) -> None: ...
+    def argmax(self) -> PyEdgeSingleValueWithIndexOperand: ...
+    def argmin(self) -> PyEdgeSingleValueWithIndexOperand: ...
+    def first_by(
+        self, order_by_attribute: GraphRecordAttribute
+    ) -> PyEdgeSingleValueWithIndexOperand: ...
+    def last_by(
+        self, order_by_attribute: GraphRecordAttribute
+    ) -> PyEdgeSingleValueWithIndexOperand: ...
+    def random_weighted(
+        self, weight_attribute: GraphRecordAttribute
+    ) -> PyEdgeSingleValueWithIndexOperand: ...
+    def when(
+        self,
+        query: Callable[[PyEdgeMultipleValuesWithIndexOperand], None],
+        then: PySingleValueComparisonOperand,
+        otherwise: PySingleValueComparisonOperand,
+    ) -> None: ...
     def is_max(self) -> None: ...
     def is_min(self) -> None: ...
+    def distinct(self) -> None: ...
+    def sort(self, descending: bool) -> None: ...
+    def skip(self, count: int) -> None: ...
+    def limit(self, count: int) -> None: ...
     def either_or(
         self,
         either: Callable[[PyEdgeMultipleValuesWithIndexOperand], None],
@@ -693,10 +948,12 @@ class PyEdgeMultipleValuesWithIndexGroupOperand:
     def min(self) -> PyEdgeSingleValueWithIndexGroupOperand: ...
     def mean(self) -> PyEdgeSingleValueWithoutIndexGroupOperand: ...
     def median(self) -> PyEdgeSingleValueWithoutIndexGroupOperand: ...
+    def quantile(self, quantile: float) -> PyEdgeSingleValueWithoutIndexGroupOperand: ...
     def mode(self) -> PyEdgeSingleValueWithoutIndexGroupOperand: ...
     def std(self) -> PyEdgeSingleValueWithoutIndexGroupOperand: ...
     def var(self) -> PyEdgeSingleValueWithoutIndexGroupOperand: ...
     def count(self) -> PyEdgeSingleValueWithoutIndexGroupOperand: ...
+    def count_distinct(self) -> PyEdgeSingleValueWithoutIndexGroupOperand: ...
     def sum(self) -> PyEdgeSingleValueWithoutIndexGroupOperand: ...
     def random(self) -> PyEdgeSingleValueWithIndexGroupOperand: ...
     def greater_than(self, value: PySingleValueComparisonOperand) -> None: ...
@@ -710,6 +967,18 @@ class PyEdgeMultipleValuesWithIndexGroupOperand:
     def starts_with(self, value: PySingleValueComparisonOperand) -> None: ...
     def ends_with(self, value: PySingleValueComparisonOperand) -> None: ...
     def contains(self, value: PySingleValueComparisonOperand) -> None: ...
+    def levenshtein_at_most(
+        self, target: PySingleValueComparisonOperand, distance: int
+    ) -> None: ...
+    def jaro_winkler_at_least(
+        self, target: PySingleValueComparisonOperand, similarity: float
+    ) -> None: ...
+    def is_close(
+        self, value: PySingleValueComparisonOperand, tolerance: float
+    ) -> None: ...
+    def within_radius(
+        self, point: PySingleValueComparisonOperand, radius_km: float
+    ) -> None: ...
     def is_in(self, values: PyMultipleValuesComparisonOperand) -> None: ...
     def is_not_in(self, values: PyMultipleValuesComparisonOperand) -> None: ...
     def add(self, value: PySingleValueArithmeticOperand) -> None: ...
@@ -722,6 +991,7 @@ class PyEdgeMultipleValuesWithIndexGroupOperand:
     def ceil(self) -> None: ...
     def floor(self) -> None: ...
     def abs(self) -> None: ...
+    def apply(self, udf: str) -> None: ...
     def sqrt(self) -> None: ...
     def trim(self) -> None: ...
     def trim_start(self) -> None: ...
@@ -736,8 +1006,41 @@ class PyEdgeMultipleValuesWithIndexGroupOperand:
     def is_datetime(self) -> None: ...
     def is_duration(self) -> None: ...
     def is_null(self) -> None: ...
+    def is_missing(self) -> None: ...
+    def provenance(self) -> None: ...
+    def text_search(self, query: str) -> None: ...
+    def rolling(
+        self,
+        window: timedelta,
+        aggregation: PyRollingAggregation,
+        order_by_attribute: GraphRecordAttribute,
+    ) -> None: ...
+    def cumsum(self, order_by_attribute: GraphRecordAttribute) -> None: ...
+    def lag(self, n: int, order_by_attribute: GraphRecordAttribute) -> None: ...
+    def lead(self, n: int, order_by_attribute: GraphRecordAttribute) -> None: ...
+    def argmax(self) -> PyEdgeSingleValueWithIndexGroupOperand: ...
+    def argmin(self) -> PyEdgeSingleValueWithIndexGroupOperand: ...
+    def first_by(
+        self, order_by_attribute: GraphRecordAttribute
+    ) -> PyEdgeSingleValueWithIndexGroupOperand: ...
+    def last_by(
+        self, order_by_attribute: GraphRecordAttribute
+    ) -> PyEdgeSingleValueWithIndexGroupOperand: ...
+    def random_weighted(
+        self, weight_attribute: GraphRecordAttribute
+    ) -> PyEdgeSingleValueWithIndexGroupOperand: ...
+    def when(
+        self,
+        query: Callable[[PyEdgeMultipleValuesWithIndexOperand], None],
+        then: PySingleValueComparisonOperand,
+        otherwise: PySingleValueComparisonOperand,
+    ) -> None: ...
     def is_max(self) -> None: ...
     def is_min(self) -> None: ...
+    def distinct(self) -> None: ...
+    def sort(self, descending: bool) -> None: ...
+    def skip(self, count: int) -> None: ...
+    def limit(self, count: int) -> None: ...
     def either_or(
         self,
         either: Callable[[PyEdgeMultipleValuesWithIndexOperand], None],
@@ -747,6 +1050,14 @@ class PyEdgeMultipleValuesWithIndexGroupOperand:
         self, query: Callable[[PyEdgeMultipleValuesWithIndexOperand], None]
     ) -> None: ...
     def ungroup(self) -> PyEdgeMultipleValuesWithIndexOperand: ...
+    def sorted_by_key(self) -> None: ...
+    def sorted_by_aggregate(
+        self,
+        aggregate: Union[
+            PyNodeSingleValueWithoutIndexGroupOperand,
+            PyEdgeSingleValueWithoutIndexGroupOperand,
+        ],
+    ) -> None: ...
     def deep_clone(self) -> PyEdgeMultipleValuesWithIndexGroupOperand: ...
 
 class PyEdgeMultipleValuesWithoutIndexOperand:
@@ -754,10 +1065,15 @@ class PyEdgeMultipleValuesWithoutIndexOperand:
     def min(self) -> PyEdgeSingleValueWithoutIndexOperand: ...
     def mean(self) -> PyEdgeSingleValueWithoutIndexOperand: ...
     def median(self) -> PyEdgeSingleValueWithoutIndexOperand: ...
+    def quantile(self, quantile: float) -> PyEdgeSingleValueWithoutIndexOperand: ...
     def mode(self) -> PyEdgeSingleValueWithoutIndexOperand: ...
     def std(self) -> PyEdgeSingleValueWithoutIndexOperand: ...
     def var(self) -> PyEdgeSingleValueWithoutIndexOperand: ...
     def count(self) -> PyEdgeSingleValueWithoutIndexOperand: ...
+    def count_distinct(self) -> PyEdgeSingleValueWithoutIndexOperand: ...
+    def value_counts(self) -> PyEdgeValueCountsOperand: ...
+    def histogram(self, bins: int) -> PyEdgeHistogramOperand: ...
+    def quantiles(self, quantiles: List[float]) -> PyEdgeQuantilesOperand: ...
     def sum(self) -> PyEdgeSingleValueWithoutIndexOperand: ...
     def random(self) -> PyEdgeSingleValueWithoutIndexOperand: ...
     def greater_than(self, value: PySingleValueComparisonOperand) -> None: ...
@@ -771,6 +1087,18 @@ class PyEdgeMultipleValuesWithoutIndexOperand:
     def starts_with(self, value: PySingleValueComparisonOperand) -> None: ...
     def ends_with(self, value: PySingleValueComparisonOperand) -> None: ...
     def contains(self, value: PySingleValueComparisonOperand) -> None: ...
+    def levenshtein_at_most(
+        self, target: PySingleValueComparisonOperand, distance: int
+    ) -> None: ...
+    def jaro_winkler_at_least(
+        self, target: PySingleValueComparisonOperand, similarity: float
+    ) -> None: ...
+    def is_close(
+        self, value: PySingleValueComparisonOperand, tolerance: float
+    ) -> None: ...
+    def within_radius(
+        self, point: PySingleValueComparisonOperand, radius_km: float
+    ) -> None: ...
     def is_in(self, values: PyMultipleValuesComparisonOperand) -> None: ...
     def is_not_in(self, values: PyMultipleValuesComparisonOperand) -> None: ...
     def add(self, value: PySingleValueArithmeticOperand) -> None: ...
@@ -783,6 +1111,7 @@ class PyEdgeMultipleValuesWithoutIndexOperand:
     def ceil(self) -> None: ...
     def floor(self) -> None: ...
     def abs(self) -> None: ...
+    def apply(self, udf: str) -> None: ...
     def sqrt(self) -> None: ...
     def trim(self) -> None: ...
     def trim_start(self) -> None: ...
@@ -797,8 +1126,13 @@ class PyEdgeMultipleValuesWithoutIndexOperand:
     def is_datetime(self) -> None: ...
     def is_duration(self) -> None: ...
     def is_null(self) -> None: ...
+    def is_missing(self) -> None: ...
     def is_max(self) -> None: ...
     def is_min(self) -> None: ...
+    def distinct(self) -> None: ...
+    def sort(self, descending: bool) -> None: ...
+    def skip(self, count: int) -> None: ...
+    def limit(self, count: int) -> None: ...
     def either_or(
         self,
         either: Callable[[PyEdgeMultipleValuesWithoutIndexOperand], None],
@@ -821,6 +1155,18 @@ class PyNodeSingleValueWithIndexOperand:
     def starts_with(self, value: PySingleValueComparisonOperand) -> None: ...
     def ends_with(self, value: PySingleValueComparisonOperand) -> None: ...
     def contains(self, value: PySingleValueComparisonOperand) -> None: ...
+    def levenshtein_at_most(
+        self, target: PySingleValueComparisonOperand, distance: int
+    ) -> None: ...
+    def jaro_winkler_at_least(
+        self, target: PySingleValueComparisonOperand, similarity: float
+    ) -> None: ...
+    def is_close(
+        self, value: PySingleValueComparisonOperand, tolerance: float
+    ) -> None: ...
+    def within_radius(
+        self, point: PySingleValueComparisonOperand, radius_km: float
+    ) -> None: ...
     def is_in(self, values: PyMultipleValuesComparisonOperand) -> None: ...
     def is_not_in(self, values: PyMultipleValuesComparisonOperand) -> None: ...
     def add(self, value: PySingleValueArithmeticOperand) -> None: ...
@@ -833,6 +1179,7 @@ class PyNodeSingleValueWithIndexOperand:
     def ceil(self) -> None: ...
     def floor(self) -> None: ...
     def abs(self) -> None: ...
+    def apply(self, udf: str) -> None: ...
     def sqrt(self) -> None: ...
     def trim(self) -> None: ...
     def trim_start(self) -> None: ...
@@ -847,6 +1194,7 @@ class PyNodeSingleValueWithIndexOperand:
     def is_datetime(self) -> None: ...
     def is_duration(self) -> None: ...
     def is_null(self) -> None: ...
+    def is_missing(self) -> None: ...
     def either_or(
         self,
         either: Callable[[PyNodeSingleValueWithIndexOperand], None],
@@ -869,6 +1217,18 @@ class PyNodeSingleValueWithIndexGroupOperand:
     def starts_with(self, value: PySingleValueComparisonOperand) -> None: ...
     def ends_with(self, value: PySingleValueComparisonOperand) -> None: ...
     def contains(self, value: PySingleValueComparisonOperand) -> None: ...
+    def levenshtein_at_most(
+        self, target: PySingleValueComparisonOperand, distance: int
+    ) -> None: ...
+    def jaro_winkler_at_least(
+        self, target: PySingleValueComparisonOperand, similarity: float
+    ) -> None: ...
+    def is_close(
+        self, value: PySingleValueComparisonOperand, tolerance: float
+    ) -> None: ...
+    def within_radius(
+        self, point: PySingleValueComparisonOperand, radius_km: float
+    ) -> None: ...
     def is_in(self, values: PyMultipleValuesComparisonOperand) -> None: ...
     def is_not_in(self, values: PyMultipleValuesComparisonOperand) -> None: ...
     def add(self, value: PySingleValueArithmeticOperand) -> None: ...
@@ -881,6 +1241,7 @@ class PyNodeSingleValueWithIndexGroupOperand:
     def ceil(self) -> None: ...
     def floor(self) -> None: ...
     def abs(self) -> None: ...
+    def apply(self, udf: str) -> None: ...
     def sqrt(self) -> None: ...
     def trim(self) -> None: ...
     def trim_start(self) -> None: ...
@@ -895,6 +1256,7 @@ class PyNodeSingleValueWithIndexGroupOperand:
     def is_datetime(self) -> None: ...
     def is_duration(self) -> None: ...
     def is_null(self) -> None: ...
+    def is_missing(self) -> None: ...
     def either_or(
         self,
         either: Callable[[PyNodeSingleValueWithIndexOperand], None],
@@ -904,6 +1266,14 @@ class PyNodeSingleValueWithIndexGroupOperand:
         self, query: Callable[[PyNodeSingleValueWithIndexOperand], None]
     ) -> None: ...
     def ungroup(self) -> PyNodeMultipleValuesWithIndexOperand: ...
+    def sorted_by_key(self) -> None: ...
+    def sorted_by_aggregate(
+        self,
+        aggregate: Union[
+            PyNodeSingleValueWithoutIndexGroupOperand,
+            PyEdgeSingleValueWithoutIndexGroupOperand,
+        ],
+    ) -> None: ...
     def deep_clone(self) -> PyNodeSingleValueWithIndexGroupOperand: ...
 
 class PyNodeSingleValueWithoutIndexOperand:
@@ -918,6 +1288,18 @@ class PyNodeSingleValueWithoutIndexOperand:
     def starts_with(self, value: PySingleValueComparisonOperand) -> None: ...
     def ends_with(self, value: PySingleValueComparisonOperand) -> None: ...
     def contains(self, value: PySingleValueComparisonOperand) -> None: ...
+    def levenshtein_at_most(
+        self, target: PySingleValueComparisonOperand, distance: int
+    ) -> None: ...
+    def jaro_winkler_at_least(
+        self, target: PySingleValueComparisonOperand, similarity: float
+    ) -> None: ...
+    def is_close(
+        self, value: PySingleValueComparisonOperand, tolerance: float
+    ) -> None: ...
+    def within_radius(
+        self, point: PySingleValueComparisonOperand, radius_km: float
+    ) -> None: ...
     def is_in(self, values: PyMultipleValuesComparisonOperand) -> None: ...
     def is_not_in(self, values: PyMultipleValuesComparisonOperand) -> None: ...
     def add(self, value: PySingleValueArithmeticOperand) -> None: ...
@@ -930,6 +1312,7 @@ class PyNodeSingleValueWithoutIndexOperand:
     def ceil(self) -> None: ...
     def floor(self) -> None: ...
     def abs(self) -> None: ...
+    def apply(self, udf: str) -> None: ...
     def sqrt(self) -> None: ...
     def trim(self) -> None: ...
     def trim_start(self) -> None: ...
@@ -944,6 +1327,7 @@ class PyNodeSingleValueWithoutIndexOperand:
     def is_datetime(self) -> None: ...
     def is_duration(self) -> None: ...
     def is_null(self) -> None: ...
+    def is_missing(self) -> None: ...
     def either_or(
         self,
         either: Callable[[PyNodeSingleValueWithoutIndexOperand], None],
@@ -966,6 +1350,18 @@ class PyNodeSingleValueWithoutIndexGroupOperand:
     def starts_with(self, value: PySingleValueComparisonOperand) -> None: ...
     def ends_with(self, value: PySingleValueComparisonOperand) -> None: ...
     def contains(self, value: PySingleValueComparisonOperand) -> None: ...
+    def levenshtein_at_most(
+        self, target: PySingleValueComparisonOperand, distance: int
+    ) -> None: ...
+    def jaro_winkler_at_least(
+        self, target: PySingleValueComparisonOperand, similarity: float
+    ) -> None: ...
+    def is_close(
+        self, value: PySingleValueComparisonOperand, tolerance: float
+    ) -> None: ...
+    def within_radius(
+        self, point: PySingleValueComparisonOperand, radius_km: float
+    ) -> None: ...
     def is_in(self, values: PyMultipleValuesComparisonOperand) -> None: ...
     def is_not_in(self, values: PyMultipleValuesComparisonOperand) -> None: ...
     def add(self, value: PySingleValueArithmeticOperand) -> None: ...
@@ -978,6 +1374,7 @@ class PyNodeSingleValueWithoutIndexGroupOperand:
     def ceil(self) -> None: ...
     def floor(self) -> None: ...
     def abs(self) -> None: ...
+    def apply(self, udf: str) -> None: ...
     def sqrt(self) -> None: ...
     def trim(self) -> None: ...
     def trim_start(self) -> None: ...
@@ -992,6 +1389,7 @@ class PyNodeSingleValueWithoutIndexGroupOperand:
     def is_datetime(self) -> None: ...
     def is_duration(self) -> None: ...
     def is_null(self) -> None: ...
+    def is_missing(self) -> None: ...
     def either_or(
         self,
         either: Callable[[PyNodeSingleValueWithoutIndexOperand], None],
@@ -1001,6 +1399,14 @@ class PyNodeSingleValueWithoutIndexGroupOperand:
         self, query: Callable[[PyNodeSingleValueWithoutIndexOperand], None]
     ) -> None: ...
     def ungroup(self) -> PyNodeMultipleValuesWithoutIndexOperand: ...
+    def sorted_by_key(self) -> None: ...
+    def sorted_by_aggregate(
+        self,
+        aggregate: Union[
+            PyNodeSingleValueWithoutIndexGroupOperand,
+            PyEdgeSingleValueWithoutIndexGroupOperand,
+        ],
+    ) -> None: ...
     def deep_clone(self) -> PyNodeSingleValueWithoutIndexGroupOperand: ...
 
 class PyEdgeSingleValueWithIndexOperand:
@@ -1015,6 +1421,18 @@ class PyEdgeSingleValueWithIndexOperand:
     def starts_with(self, value: PySingleValueComparisonOperand) -> None: ...
     def ends_with(self, value: PySingleValueComparisonOperand) -> None: ...
     def contains(self, value: PySingleValueComparisonOperand) -> None: ...
+    def levenshtein_at_most(
+        self, target: PySingleValueComparisonOperand, distance: int
+    ) -> None: ...
+    def jaro_winkler_at_least(
+        self, target: PySingleValueComparisonOperand, similarity: float
+    ) -> None: ...
+    def is_close(
+        self, value: PySingleValueComparisonOperand, tolerance: float
+    ) -> None: ...
+    def within_radius(
+        self, point: PySingleValueComparisonOperand, radius_km: float
+    ) -> None: ...
     def is_in(self, values: PyMultipleValuesComparisonOperand) -> None: ...
     def is_not_in(self, values: PyMultipleValuesComparisonOperand) -> None: ...
     def add(self, value: PySingleValueArithmeticOperand) -> None: ...
@@ -1027,6 +1445,7 @@ class PyEdgeSingleValueWithIndexOperand:
     def ceil(self) -> None: ...
     def floor(self) -> None: ...
     def abs(self) -> None: ...
+    def apply(self, udf: str) -> None: ...
     def sqrt(self) -> None: ...
     def trim(self) -> None: ...
     def trim_start(self) -> None: ...
@@ -1041,6 +1460,7 @@ class PyEdgeSingleValueWithIndexOperand:
     def is_datetime(self) -> None: ...
     def is_duration(self) -> None: ...
     def is_null(self) -> None: ...
+    def is_missing(self) -> None: ...
     def either_or(
         self,
         either: Callable[[PyEdgeSingleValueWithIndexOperand], None],
@@ -1063,6 +1483,18 @@ class PyEdgeSingleValueWithIndexGroupOperand:
     def starts_with(self, value: PySingleValueComparisonOperand) -> None: ...
     def ends_with(self, value: PySingleValueComparisonOperand) -> None: ...
     def contains(self, value: PySingleValueComparisonOperand) -> None: ...
+    def levenshtein_at_most(
+        self, target: PySingleValueComparisonOperand, distance: int
+    ) -> None: ...
+    def jaro_winkler_at_least(
+        self, target: PySingleValueComparisonOperand, similarity: float
+    ) -> None: ...
+    def is_close(
+        self, value: PySingleValueComparisonOperand, tolerance: float
+    ) -> None: ...
+    def within_radius(
+        self, point: PySingleValueComparisonOperand, radius_km: float
+    ) -> None: ...
     def is_in(self, values: PyMultipleValuesComparisonOperand) -> None: ...
     def is_not_in(self, values: PyMultipleValuesComparisonOperand) -> None: ...
     def add(self, value: PySingleValueArithmeticOperand) -> None: ...
@@ -1075,6 +1507,7 @@ class PyEdgeSingleValueWithIndexGroupOperand:
     def ceil(self) -> None: ...
     def floor(self) -> None: ...
     def abs(self) -> None: ...
+    def apply(self, udf: str) -> None: ...
     def sqrt(self) -> None: ...
     def trim(self) -> None: ...
     def trim_start(self) -> None: ...
@@ -1089,6 +1522,7 @@ class PyEdgeSingleValueWithIndexGroupOperand:
     def is_datetime(self) -> None: ...
     def is_duration(self) -> None: ...
     def is_null(self) -> None: ...
+    def is_missing(self) -> None: ...
     def either_or(
         self,
         either: Callable[[PyEdgeSingleValueWithIndexOperand], None],
@@ -1098,6 +1532,14 @@ class PyEdgeSingleValueWithIndexGroupOperand:
         self, query: Callable[[PyEdgeSingleValueWithIndexOperand], None]
     ) -> None: ...
     def ungroup(self) -> PyEdgeMultipleValuesWithIndexOperand: ...
+    def sorted_by_key(self) -> None: ...
+    def sorted_by_aggregate(
+        self,
+        aggregate: Union[
+            PyNodeSingleValueWithoutIndexGroupOperand,
+            PyEdgeSingleValueWithoutIndexGroupOperand,
+        ],
+    ) -> None: ...
     def deep_clone(self) -> PyEdgeSingleValueWithIndexGroupOperand: ...
 
 class PyEdgeSingleValueWithoutIndexOperand:
@@ -1112,6 +1554,18 @@ class PyEdgeSingleValueWithoutIndexOperand:
     def starts_with(self, value: PySingleValueComparisonOperand) -> None: ...
     def ends_with(self, value: PySingleValueComparisonOperand) -> None: ...
     def contains(self, value: PySingleValueComparisonOperand) -> None: ...
+    def levenshtein_at_most(
+        self, target: PySingleValueComparisonOperand, distance: int
+    ) -> None: ...
+    def jaro_winkler_at_least(
+        self, target: PySingleValueComparisonOperand, similarity: float
+    ) -> None: ...
+    def is_close(
+        self, value: PySingleValueComparisonOperand, tolerance: float
+    ) -> None: ...
+    def within_radius(
+        self, point: PySingleValueComparisonOperand, radius_km: float
+    ) -> None: ...
     def is_in(self, values: PyMultipleValuesComparisonOperand) -> None: ...
     def is_not_in(self, values: PyMultipleValuesComparisonOperand) -> None: ...
     def add(self, value: PySingleValueArithmeticOperand) -> None: ...
@@ -1124,6 +1578,7 @@ class PyEdgeSingleValueWithoutIndexOperand:
     def ceil(self) -> None: ...
     def floor(self) -> None: ...
     def abs(self) -> None: ...
+    def apply(self, udf: str) -> None: ...
     def sqrt(self) -> None: ...
     def trim(self) -> None: ...
     def trim_start(self) -> None: ...
@@ -1138,6 +1593,7 @@ class PyEdgeSingleValueWithoutIndexOperand:
     def is_datetime(self) -> None: ...
     def is_duration(self) -> None: ...
     def is_null(self) -> None: ...
+    def is_missing(self) -> None: ...
     def either_or(
         self,
         either: Callable[[PyEdgeSingleValueWithoutIndexOperand], None],
@@ -1160,6 +1616,18 @@ class PyEdgeSingleValueWithoutIndexGroupOperand:
     def starts_with(self, value: PySingleValueComparisonOperand) -> None: ...
     def ends_with(self, value: PySingleValueComparisonOperand) -> None: ...
     def contains(self, value: PySingleValueComparisonOperand) -> None: ...
+    def levenshtein_at_most(
+        self, target: PySingleValueComparisonOperand, distance: int
+    ) -> None: ...
+    def jaro_winkler_at_least(
+        self, target: PySingleValueComparisonOperand, similarity: float
+    ) -> None: ...
+    def is_close(
+        self, value: PySingleValueComparisonOperand, tolerance: float
+    ) -> None: ...
+    def within_radius(
+        self, point: PySingleValueComparisonOperand, radius_km: float
+    ) -> None: ...
     def is_in(self, values: PyMultipleValuesComparisonOperand) -> None: ...
     def is_not_in(self, values: PyMultipleValuesComparisonOperand) -> None: ...
     def add(self, value: PySingleValueArithmeticOperand) -> None: ...
@@ -1172,6 +1640,7 @@ class PyEdgeSingleValueWithoutIndexGroupOperand:
     def ceil(self) -> None: ...
     def floor(self) -> None: ...
     def abs(self) -> None: ...
+    def apply(self, udf: str) -> None: ...
     def sqrt(self) -> None: ...
     def trim(self) -> None: ...
     def trim_start(self) -> None: ...
@@ -1186,6 +1655,7 @@ class PyEdgeSingleValueWithoutIndexGroupOperand:
     def is_datetime(self) -> None: ...
     def is_duration(self) -> None: ...
     def is_null(self) -> None: ...
+    def is_missing(self) -> None: ...
     def either_or(
         self,
         either: Callable[[PyEdgeSingleValueWithoutIndexOperand], None],
@@ -1195,6 +1665,14 @@ class PyEdgeSingleValueWithoutIndexGroupOperand:
         self, query: Callable[[PyEdgeSingleValueWithoutIndexOperand], None]
     ) -> None: ...
     def ungroup(self) -> PyEdgeMultipleValuesWithoutIndexOperand: ...
+    def sorted_by_key(self) -> None: ...
+    def sorted_by_aggregate(
+        self,
+        aggregate: Union[
+            PyNodeSingleValueWithoutIndexGroupOperand,
+            PyEdgeSingleValueWithoutIndexGroupOperand,
+        ],
+    ) -> None: ...
     def deep_clone(self) -> PyEdgeSingleValueWithoutIndexGroupOperand: ...
 
 PySingleAttributeComparisonOperand: TypeAlias = Union[
@@ -1302,6 +1780,14 @@ class PyNodeAttributesTreeGroupOperand:
     ) -> None: ...
     def exclude(self, query: Callable[[PyNodeAttributesTreeOperand], None]) -> None: ...
     def ungroup(self) -> PyNodeAttributesTreeOperand: ...
+    def sorted_by_key(self) -> None: ...
+    def sorted_by_aggregate(
+        self,
+        aggregate: Union[
+            PyNodeSingleValueWithoutIndexGroupOperand,
+            PyEdgeSingleValueWithoutIndexGroupOperand,
+        ],
+    ) -> None: ...
     def deep_clone(self) -> PyNodeAttributesTreeGroupOperand: ...
 
 class PyEdgeAttributesTreeOperand:
@@ -1393,6 +1879,14 @@ class PyEdgeAttributesTreeGroupOperand:
     ) -> None: ...
     def exclude(self, query: Callable[[PyEdgeAttributesTreeOperand], None]) -> None: ...
     def ungroup(self) -> PyEdgeAttributesTreeOperand: ...
+    def sorted_by_key(self) -> None: ...
+    def sorted_by_aggregate(
+        self,
+        aggregate: Union[
+            PyNodeSingleValueWithoutIndexGroupOperand,
+            PyEdgeSingleValueWithoutIndexGroupOperand,
+        ],
+    ) -> None: ...
     def deep_clone(self) -> PyEdgeAttributesTreeGroupOperand: ...
 
 class PyNodeMultipleAttributesWithIndexOperand:
@@ -1490,6 +1984,14 @@ class PyNodeMultipleAttributesWithIndexGroupOperand:
         self, query: Callable[[PyNodeMultipleAttributesWithIndexOperand], None]
     ) -> None: ...
     def ungroup(self) -> PyNodeMultipleAttributesWithIndexOperand: ...
+    def sorted_by_key(self) -> None: ...
+    def sorted_by_aggregate(
+        self,
+        aggregate: Union[
+            PyNodeSingleValueWithoutIndexGroupOperand,
+            PyEdgeSingleValueWithoutIndexGroupOperand,
+        ],
+    ) -> None: ...
     def deep_clone(self) -> PyNodeMultipleAttributesWithIndexGroupOperand: ...
 
 class PyNodeMultipleAttributesWithoutIndexOperand:
@@ -1634,6 +2136,14 @@ class PyEdgeMultipleAttributesWithIndexGroupOperand:
         self, query: Callable[[PyEdgeMultipleAttributesWithIndexOperand], None]
     ) -> None: ...
     def ungroup(self) -> PyEdgeMultipleAttributesWithIndexOperand: ...
+    def sorted_by_key(self) -> None: ...
+    def sorted_by_aggregate(
+        self,
+        aggregate: Union[
+            PyNodeSingleValueWithoutIndexGroupOperand,
+            PyEdgeSingleValueWithoutIndexGroupOperand,
+        ],
+    ) -> None: ...
     def deep_clone(self) -> PyEdgeMultipleAttributesWithIndexGroupOperand: ...
 
 class PyEdgeMultipleAttributesWithoutIndexOperand:
@@ -1762,6 +2272,14 @@ class PyNodeSingleAttributeWithIndexGroupOperand:
         self, query: Callable[[PyNodeSingleAttributeWithIndexOperand], None]
     ) -> None: ...
     def ungroup(self) -> PyNodeMultipleAttributesWithIndexOperand: ...
+    def sorted_by_key(self) -> None: ...
+    def sorted_by_aggregate(
+        self,
+        aggregate: Union[
+            PyNodeSingleValueWithoutIndexGroupOperand,
+            PyEdgeSingleValueWithoutIndexGroupOperand,
+        ],
+    ) -> None: ...
     def deep_clone(self) -> PyNodeSingleAttributeWithIndexGroupOperand: ...
 
 class PyNodeSingleAttributeWithoutIndexOperand:
@@ -1843,6 +2361,14 @@ class PyNodeSingleAttributeWithoutIndexGroupOperand:
         self, query: Callable[[PyNodeSingleAttributeWithoutIndexOperand], None]
     ) -> None: ...
     def ungroup(self) -> PyNodeMultipleAttributesWithoutIndexOperand: ...
+    def sorted_by_key(self) -> None: ...
+    def sorted_by_aggregate(
+        self,
+        aggregate: Union[
+            PyNodeSingleValueWithoutIndexGroupOperand,
+            PyEdgeSingleValueWithoutIndexGroupOperand,
+        ],
+    ) -> None: ...
     def deep_clone(self) -> PyNodeSingleAttributeWithoutIndexGroupOperand: ...
 
 class PyEdgeSingleAttributeWithIndexOperand:
@@ -1924,6 +2450,14 @@ class PyEdgeSingleAttributeWithIndexGroupOperand:
         self, query: Callable[[PyEdgeSingleAttributeWithIndexOperand], None]
     ) -> None: ...
     def ungroup(self) -> PyEdgeMultipleAttributesWithIndexOperand: ...
+    def sorted_by_key(self) -> None: ...
+    def sorted_by_aggregate(
+        self,
+        aggregate: Union[
+            PyNodeSingleValueWithoutIndexGroupOperand,
+            PyEdgeSingleValueWithoutIndexGroupOperand,
+        ],
+    ) -> None: ...
     def deep_clone(self) -> PyEdgeSingleAttributeWithIndexGroupOperand: ...
 
 class PyEdgeSingleAttributeWithoutIndexOperand:
@@ -2005,4 +2539,30 @@ class PyEdgeSingleAttributeWithoutIndexGroupOperand:
         self, query: Callable[[PyEdgeSingleAttributeWithoutIndexOperand], None]
     ) -> None: ...
     def ungroup(self) -> PyEdgeMultipleAttributesWithoutIndexOperand: ...
+    def sorted_by_key(self) -> None: ...
+    def sorted_by_aggregate(
+        self,
+        aggregate: Union[
+            PyNodeSingleValueWithoutIndexGroupOperand,
+            PyEdgeSingleValueWithoutIndexGroupOperand,
+        ],
+    ) -> None: ...
     def deep_clone(self) -> PyEdgeSingleAttributeWithoutIndexGroupOperand: ...
+
+class PyNodeValueCountsOperand:
+    def deep_clone(self) -> PyNodeValueCountsOperand: ...
+
+class PyNodeHistogramOperand:
+    def deep_clone(self) -> PyNodeHistogramOperand: ...
+
+class PyEdgeValueCountsOperand:
+    def deep_clone(self) -> PyEdgeValueCountsOperand: ...
+
+class PyEdgeHistogramOperand:
+    def deep_clone(self) -> PyEdgeHistogramOperand: ...
+
+class PyNodeQuantilesOperand:
+    def deep_clone(self) -> PyNodeQuantilesOperand: ...
+
+class PyEdgeQuantilesOperand:
+    def deep_clone(self) -> PyEdgeQuantilesOperand: ...
@@ -1,5 +1,5 @@
 from enum import Enum
-from typing import Dict, List, Optional
+from typing import Dict, List, Optional, Tuple
 
 from graphrecords._graphrecords.datatype import PyDataType
 from graphrecords._graphrecords.graphrecord import PyGraphRecord
@@ -45,10 +45,23 @@ class PySchemaType(Enum):
     Provided = ...
     Inferred = ...
 
+class PyNonFiniteFloatPolicy(Enum):
+    Allow = ...
+    Reject = ...
+    CoerceToNull = ...
+
+class PyConflictStrategy(Enum):
+    Widen = ...
+    KeepFirst = ...
+    KeepSecond = ...
+    Reject = ...
+
 class PySchema:
     groups: List[Group]
     ungrouped: PyGroupSchema
     schema_type: PySchemaType
+    widening: List[Tuple[PyDataType, PyDataType]]
+    non_finite_float_policy: PyNonFiniteFloatPolicy
 
     def __init__(
         self,
@@ -58,8 +71,63 @@ class PySchema:
         schema_type: PySchemaType = ...,
     ) -> None: ...
     @staticmethod
-    def infer(graphrecord: PyGraphRecord) -> PySchema: ...
+    def infer(
+        graphrecord: PyGraphRecord,
+        widening: List[Tuple[PyDataType, PyDataType]] = ...,
+    ) -> PySchema: ...
     def group(self, group: Group) -> PyGroupSchema: ...
+    def set_widening(self, widening: List[Tuple[PyDataType, PyDataType]]) -> None: ...
+    def infer_attribute_types(
+        self,
+        graphrecord: PyGraphRecord,
+        max_categories: int = ...,
+        max_distinct_ratio: float = ...,
+        overrides: Dict[GraphRecordAttribute, PyAttributeType] = ...,
+    ) -> None: ...
+    def merge(
+        self, other: PySchema, strategy: PyConflictStrategy
+    ) -> Tuple[
+        PySchema,
+        List[
+            Tuple[
+                Optional[Group],
+                GraphRecordAttribute,
+                PyAttributeDataType,
+                PyAttributeDataType,
+            ]
+        ],
+        List[
+            Tuple[
+                Optional[Group],
+                GraphRecordAttribute,
+                PyAttributeDataType,
+                PyAttributeDataType,
+            ]
+        ],
+    ]: ...
+    def diff(
+        self, new: PySchema
+    ) -> Tuple[
+        List[Group],
+        List[Group],
+        List[
+            Tuple[
+                Optional[Group],
+                GraphRecordAttribute,
+                Optional[PyAttributeDataType],
+                Optional[PyAttributeDataType],
+            ]
+        ],
+        List[
+            Tuple[
+                Optional[Group],
+                GraphRecordAttribute,
+                Optional[PyAttributeDataType],
+                Optional[PyAttributeDataType],
+            ]
+        ],
+    ]: ...
+    def set_non_finite_float_policy(self, policy: PyNonFiniteFloatPolicy) -> None: ...
     def validate_node(
         self, index: NodeIndex, attributes: Attributes, group: Optional[Group] = None
     ) -> None: ...
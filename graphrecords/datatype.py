@@ -12,7 +12,9 @@ from graphrecords._graphrecords.datatype import (
     PyDateTime,
     PyDuration,
     PyFloat,
+    PyGeo,
     PyInt,
+    PyMissingType,
     PyNull,
     PyOption,
     PyString,
@@ -26,10 +28,12 @@ PyDataType: TypeAlias = typing.Union[
     PyBool,
     PyDateTime,
     PyDuration,
+    PyGeo,
     PyNull,
     PyAny,
     PyUnion,
     PyOption,
+    PyMissingType,
 ]
 
 
@@ -68,10 +72,14 @@ class DataType(ABC):
             return DateTime()
         if isinstance(datatype, PyDuration):
             return Duration()
+        if isinstance(datatype, PyGeo):
+            return Geo()
         if isinstance(datatype, PyNull):
             return Null()
         if isinstance(datatype, PyAny):
             return Any()
+        if isinstance(datatype, PyMissingType):
+            return Missing()
         if isinstance(datatype, PyUnion):
             return Union(
                 DataType._from_py_data_type(datatype.dtype1),
@@ -278,6 +286,39 @@ class Duration(DataType):
         return isinstance(value, Duration)
 
 
+class Geo(DataType):
+    """Data type for geographic points (GeoPoint)."""
+
+    _geo: PyGeo
+
+    def __init__(self) -> None:
+        """Initializes the Geo data type."""
+        self._geo = PyGeo()
+
+    def _inner(self) -> PyDataType:
+        return self._geo
+
+    def __str__(self) -> str:
+        """Returns a user-friendly string representation of the data type."""
+        return "Geo"
+
+    def __repr__(self) -> str:
+        """Returns an official string representation of the data type."""
+        return "DataType.Geo"
+
+    def __eq__(self, value: object) -> bool:
+        """Checks if the data type of the value is equal to this data type.
+
+        Args:
+            value (object): The value to compare.
+
+        Returns:
+            bool: True if the data type is equal to this data type, otherwise
+                False.
+        """
+        return isinstance(value, Geo)
+
+
 class Null(DataType):
     """Data type for null values."""
 
@@ -344,6 +385,43 @@ class Any(DataType):
         return isinstance(value, Any)
 
 
+class Missing(DataType):
+    """Data type for missing values.
+
+    Unlike Null, Missing is not accepted by Option, so a schema can keep
+    values that were never recorded apart from recorded absences.
+    """
+
+    _missing: PyMissingType
+
+    def __init__(self) -> None:
+        """Initializes the Missing data type."""
+        self._missing = PyMissingType()
+
+    def _inner(self) -> PyDataType:
+        return self._missing
+
+    def __str__(self) -> str:
+        """Returns a user-friendly string representation of the data type."""
+        return "Missing"
+
+    def __repr__(self) -> str:
+        """Returns an official string representation of the data type."""
+        return "DataType.Missing"
+
+    def __eq__(self, value: object) -> bool:
+        """Checks if the data type of the value is equal to this data type.
+
+        Args:
+            value (object): The value to compare.
+
+        Returns:
+            bool: True if the data type is equal to this data type, otherwise
+                False.
+        """
+        return isinstance(value, Missing)
+
+
 U1 = TypeVar("U1", bound=DataType)
 U2 = TypeVar("U2", bound=DataType)
 
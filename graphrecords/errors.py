@@ -0,0 +1,45 @@
+"""Exceptions raised by the graphrecords library.
+
+Every exception subclasses `GraphRecordError`, so all library errors can be
+caught at once. Each one also subclasses the built-in exception type that was
+raised for the same failure before, so existing `except` clauses keep working:
+
+- GraphRecordIndexError (IndexError)
+    - MissingNodeError
+- GraphRecordKeyError (KeyError)
+- ConversionError (RuntimeError)
+- GraphRecordAssertionError (AssertionError)
+    - DuplicateGroupError
+- SchemaError (ValueError)
+    - TypeMismatchError
+- QueryError (RuntimeError)
+- ReadOnlyError (RuntimeError)
+"""
+
+from graphrecords._graphrecords.errors import (
+    ConversionError,
+    DuplicateGroupError,
+    GraphRecordAssertionError,
+    GraphRecordError,
+    GraphRecordIndexError,
+    GraphRecordKeyError,
+    MissingNodeError,
+    QueryError,
+    ReadOnlyError,
+    SchemaError,
+    TypeMismatchError,
+)
+
+__all__ = [
+    "ConversionError",
+    "DuplicateGroupError",
+    "GraphRecordAssertionError",
+    "GraphRecordError",
+    "GraphRecordIndexError",
+    "GraphRecordKeyError",
+    "MissingNodeError",
+    "QueryError",
+    "ReadOnlyError",
+    "SchemaError",
+    "TypeMismatchError",
+]
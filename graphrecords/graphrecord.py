@@ -12,35 +12,49 @@ simplified management and efficient querying.
 
 from __future__ import annotations
 
+from contextlib import contextmanager
+from datetime import datetime, timedelta
 from enum import Enum, auto
 from typing import (
     TYPE_CHECKING,
     Callable,
     Dict,
+    Iterator,
     List,
     Optional,
     Sequence,
+    Tuple,
     TypeVar,
     Union,
     overload,
 )
 
+import pandas as pd
 import polars as pl
 
-from graphrecords._graphrecords.graphrecord import PyGraphRecord
+from graphrecords._graphrecords.graphrecord import (
+    PyAttributeScope,
+    PyCorrelationMethod,
+    PyCrosstabNormalization,
+    PyGraphRecord,
+)
+from graphrecords._graphrecords.querying import PyEdgeDirection
 from graphrecords.builder import GraphRecordBuilder
 from graphrecords.indexers import EdgeIndexer, NodeIndexer
 from graphrecords.overview import (
     DEFAULT_TRUNCATE_DETAILS,
     GroupOverview,
     Overview,
+    OverviewFormat,
 )
-from graphrecords.plugins import Plugin, _PluginBridge
+from graphrecords.plugins import MappingPlugin, Plugin, _into_py_plugin
 from graphrecords.querying import (
     EdgeAttributesTreeGroupOperand,
     EdgeAttributesTreeGroupQueryResult,
     EdgeAttributesTreeOperand,
     EdgeAttributesTreeQueryResult,
+    EdgeHistogramOperand,
+    EdgeHistogramQueryResult,
     EdgeIndexGroupOperand,
     EdgeIndexGroupQueryResult,
     EdgeIndexOperand,
@@ -64,6 +78,8 @@ from graphrecords.querying import (
     EdgeMultipleValuesWithoutIndexOperand,
     EdgeMultipleValuesWithoutIndexQueryResult,
     EdgeOperand,
+    EdgeQuantilesOperand,
+    EdgeQuantilesQueryResult,
     EdgeQuery,
     EdgeSingleAttributeWithIndexGroupOperand,
     EdgeSingleAttributeWithIndexGroupQueryResult,
@@ -81,10 +97,14 @@ from graphrecords.querying import (
     EdgeSingleValueWithoutIndexGroupQueryResult,
     EdgeSingleValueWithoutIndexOperand,
     EdgeSingleValueWithoutIndexQueryResult,
+    EdgeValueCountsOperand,
+    EdgeValueCountsQueryResult,
     NodeAttributesTreeGroupOperand,
     NodeAttributesTreeGroupQueryResult,
     NodeAttributesTreeOperand,
     NodeAttributesTreeQueryResult,
+    NodeHistogramOperand,
+    NodeHistogramQueryResult,
     NodeIndexGroupOperand,
     NodeIndexGroupQueryResult,
     NodeIndexOperand,
@@ -108,6 +128,8 @@ from graphrecords.querying import (
     NodeMultipleValuesWithoutIndexOperand,
     NodeMultipleValuesWithoutIndexQueryResult,
     NodeOperand,
+    NodeQuantilesOperand,
+    NodeQuantilesQueryResult,
     NodeQuery,
     NodeSingleAttributeWithIndexGroupOperand,
     NodeSingleAttributeWithIndexGroupQueryResult,
@@ -125,6 +147,8 @@ from graphrecords.querying import (
     NodeSingleValueWithoutIndexGroupQueryResult,
     NodeSingleValueWithoutIndexOperand,
     NodeSingleValueWithoutIndexQueryResult,
+    NodeValueCountsOperand,
+    NodeValueCountsQueryResult,
     PyQueryReturnOperand,
     QueryResult,
     QueryReturnOperand,
@@ -132,10 +156,15 @@ from graphrecords.querying import (
 from graphrecords.schema import Schema
 from graphrecords.types import (
     Attributes,
+    ChiSquareTest,
+    CorrelationTest,
     EdgeIndex,
     EdgeIndexInputList,
     EdgeInput,
     EdgeTuple,
+    EventRate,
+    GraphRecordAttribute,
+    GraphRecordValue,
     Group,
     GroupInfo,
     GroupInputList,
@@ -152,6 +181,10 @@ from graphrecords.types import (
     PolarsDataFramesGroupExport,
     PolarsEdgeDataFrameInput,
     PolarsNodeDataFrameInput,
+    ProvenanceTag,
+    RecordMetadata,
+    Stats,
+    TimeToEvent,
     is_edge_tuple,
     is_node_tuple,
     is_pandas_edge_dataframe_input,
@@ -165,7 +198,14 @@ from graphrecords.types import (
 )
 
 if TYPE_CHECKING:
-    from graphrecords._graphrecords.querying import PyEdgeOperand, PyNodeOperand
+    from graphrecords._graphrecords.querying import (
+        PyEdgeIndicesOperand,
+        PyEdgeMultipleValuesWithIndexOperand,
+        PyEdgeOperand,
+        PyNodeIndicesOperand,
+        PyNodeMultipleValuesWithIndexOperand,
+        PyNodeOperand,
+    )
     from graphrecords.connectors import ConnectedGraphRecord, Connector
 
     ConnectorType = TypeVar("ConnectorType", bound=Connector)
@@ -263,6 +303,12 @@ def _convert_queryreturnoperand_to_pyqueryreturnoperand(
         ),
     ):
         return operand._multiple_values_operand
+    if isinstance(operand, (NodeValueCountsOperand, EdgeValueCountsOperand)):
+        return operand._value_counts_operand
+    if isinstance(operand, (NodeHistogramOperand, EdgeHistogramOperand)):
+        return operand._histogram_operand
+    if isinstance(operand, (NodeQuantilesOperand, EdgeQuantilesOperand)):
+        return operand._quantiles_operand
     if isinstance(operand, Sequence):
         return [
             _convert_queryreturnoperand_to_pyqueryreturnoperand(operand)
@@ -280,6 +326,63 @@ class EdgesDirection(Enum):
     UNDIRECTED = auto()
 
 
+class CorrelationMethod(Enum):
+    """Enum for specifying how the correlation between attributes is measured."""
+
+    PEARSON = auto()
+    SPEARMAN = auto()
+
+    def _into_py_correlation_method(self) -> PyCorrelationMethod:
+        """Converts the CorrelationMethod to a PyCorrelationMethod.
+
+        Returns:
+            PyCorrelationMethod: The converted PyCorrelationMethod.
+        """
+        if self == CorrelationMethod.PEARSON:
+            return PyCorrelationMethod.Pearson
+        return PyCorrelationMethod.Spearman
+
+
+class CrosstabNormalization(Enum):
+    """Enum for specifying how the cells of a contingency table are normalized."""
+
+    ALL = auto()
+    ROWS = auto()
+    COLUMNS = auto()
+
+    def _into_py_crosstab_normalization(self) -> PyCrosstabNormalization:
+        """Converts the CrosstabNormalization to a PyCrosstabNormalization.
+
+        Returns:
+            PyCrosstabNormalization: The converted PyCrosstabNormalization.
+        """
+        if self == CrosstabNormalization.ALL:
+            return PyCrosstabNormalization.All
+        if self == CrosstabNormalization.ROWS:
+            return PyCrosstabNormalization.Rows
+        return PyCrosstabNormalization.Columns
+
+
+class AttributeScope(Enum):
+    """Enum for specifying which nodes and edges an attribute is renamed for."""
+
+    ALL = auto()
+    NODES = auto()
+    EDGES = auto()
+
+    def _into_py_attribute_scope(self) -> PyAttributeScope:
+        """Converts the AttributeScope to a PyAttributeScope.
+
+        Returns:
+            PyAttributeScope: The converted PyAttributeScope.
+        """
+        if self == AttributeScope.ALL:
+            return PyAttributeScope.All
+        if self == AttributeScope.NODES:
+            return PyAttributeScope.Nodes
+        return PyAttributeScope.Edges
+
+
 class GraphRecord:
     """A class to manage medical records with node and edge data structures.
 
@@ -289,6 +392,9 @@ class GraphRecord:
 
     _graphrecord: PyGraphRecord
 
+    repr_format: OverviewFormat = OverviewFormat.Modern
+    """The format used by `__repr__` and by overviews without an explicit format."""
+
     def __init__(self) -> None:
         """Initializes a GraphRecord instance."""
         self._graphrecord = PyGraphRecord()
@@ -307,6 +413,25 @@ class GraphRecord:
         new_graphrecord._graphrecord = graphrecord
         return new_graphrecord
 
+    def __getstate__(self) -> bytes:
+        """Serializes the GraphRecord for pickling.
+
+        The record is written in a versioned binary container, so pickles can be
+        exchanged between worker processes and loaded by later versions.
+
+        Returns:
+            bytes: The serialized GraphRecord.
+        """
+        return self._graphrecord._to_bytes()
+
+    def __setstate__(self, state: bytes) -> None:
+        """Restores the GraphRecord from pickled bytes.
+
+        Args:
+            state (bytes): Bytes produced by `__getstate__`.
+        """
+        self._graphrecord = PyGraphRecord._from_bytes(state)
+
     @staticmethod
     def builder() -> GraphRecordBuilder:
         """Creates a GraphRecordBuilder instance to build a GraphRecord.
@@ -331,23 +456,34 @@ class GraphRecord:
         return graphrecord
 
     @classmethod
-    def with_plugins(cls, plugins: Dict[PluginName, Plugin]) -> GraphRecord:
+    def with_plugins(
+        cls,
+        plugins: Dict[PluginName, Union[Plugin, MappingPlugin]],
+        priorities: Optional[Dict[PluginName, int]] = None,
+    ) -> GraphRecord:
         """Creates a GraphRecord instance with the specified plugins.
 
+        Hooks run from the highest to the lowest priority. Plugins with the same
+        priority run in the order of the `plugins` dictionary.
+
         Args:
-            plugins (Dict[PluginName, Plugin]): A dictionary mapping plugin names to
-                plugin instances.
+            plugins (Dict[PluginName, Union[Plugin, MappingPlugin]]): A dictionary
+                mapping plugin names to plugin instances.
+            priorities (Optional[Dict[PluginName, int]]): Priorities of the plugins.
+                Plugins without an entry get priority 0. Defaults to None.
 
         Returns:
             GraphRecord: A new instance with the provided plugins.
         """
+        priorities = priorities or {}
+
         graphrecord = cls.__new__(cls)
 
         graphrecord._graphrecord = PyGraphRecord.with_plugins(
-            {
-                plugin_name: _PluginBridge(plugin)
+            [
+                (plugin_name, _into_py_plugin(plugin), priorities.get(plugin_name, 0))
                 for plugin_name, plugin in plugins.items()
-            }
+            ]
         )
 
         return graphrecord
@@ -518,14 +654,19 @@ class GraphRecord:
         """
         self._graphrecord.to_ron(path)
 
-    def to_pandas(self) -> PandasDataFramesExport:
+    def to_pandas(self, *, sort: bool = False) -> PandasDataFramesExport:
         """Exports the GraphRecord instance to Pandas DataFrames.
 
+        Args:
+            sort (bool): If True, rows are sorted by their index and columns and
+                groups are in a fixed order, so repeated exports are identical.
+                Defaults to False.
+
         Returns:
             PandasDataFramesExport: A dictionary containing 'ungrouped' and
                 'groups' DataFrames.
         """
-        export = self._graphrecord.to_dataframes()
+        export = self._graphrecord.to_dataframes(sort)
 
         def _convert_group_export(
             group_export: PolarsDataFramesGroupExport,
@@ -543,23 +684,38 @@ class GraphRecord:
             },
         }
 
-    def to_polars(self) -> PolarsDataFramesExport:
+    def to_polars(self, *, sort: bool = False) -> PolarsDataFramesExport:
         """Exports the GraphRecord instance to Polars DataFrames.
 
+        Args:
+            sort (bool): If True, rows are sorted by their index and columns and
+                groups are in a fixed order, so repeated exports are identical.
+                Defaults to False.
+
         Returns:
             PolarsDataFramesExport: A dictionary containing 'ungrouped' and
                 'groups' DataFrames.
         """
-        return self._graphrecord.to_dataframes()
+        return self._graphrecord.to_dataframes(sort)
 
-    def add_plugin(self, name: PluginName, plugin: Plugin) -> None:
+    def add_plugin(
+        self,
+        name: PluginName,
+        plugin: Union[Plugin, MappingPlugin],
+        priority: int = 0,
+    ) -> None:
         """Adds a plugin to the GraphRecord instance.
 
+        The plugin runs after all plugins with a priority greater than or equal to
+        its own. Plugins added from within a hook take effect with the next
+        operation.
+
         Args:
             name (PluginName): The name of the plugin.
-            plugin (Plugin): The plugin instance to add.
+            plugin (Union[Plugin, MappingPlugin]): The plugin instance to add.
+            priority (int): The priority of the plugin. Defaults to 0.
         """
-        self._graphrecord.add_plugin(name, _PluginBridge(plugin))
+        self._graphrecord.add_plugin(name, _into_py_plugin(plugin), priority)
 
     def remove_plugin(self, name: PluginName) -> None:
         """Removes a plugin from the GraphRecord instance.
@@ -569,18 +725,221 @@ class GraphRecord:
         """
         self._graphrecord.remove_plugin(name)
 
+    def register_plugin(
+        self, plugin: Union[Plugin, MappingPlugin], priority: int = 0
+    ) -> int:
+        """Registers an unnamed plugin, e.g. a listener, on the GraphRecord instance.
+
+        Follows the same ordering rules as `add_plugin`. Unnamed plugins are not
+        listed in `plugins`.
+
+        Args:
+            plugin (Union[Plugin, MappingPlugin]): The plugin instance to register.
+            priority (int): The priority of the plugin. Defaults to 0.
+
+        Returns:
+            int: A handle to pass to `unregister_plugin`.
+        """
+        return self._graphrecord.register_plugin(_into_py_plugin(plugin), priority)
+
+    def unregister_plugin(self, handle: int) -> None:
+        """Removes a plugin registered with `register_plugin`.
+
+        If called from within a hook, the running operation still calls the
+        remaining hooks of the removed plugin.
+
+        Args:
+            handle (int): The handle returned by `register_plugin`.
+        """
+        self._graphrecord.unregister_plugin(handle)
+
+    def plugin_priority(self, name: PluginName) -> int:
+        """Returns the priority of a plugin.
+
+        Args:
+            name (PluginName): The name of the plugin.
+
+        Returns:
+            int: The priority of the plugin.
+        """
+        return self._graphrecord.plugin_priority(name)
+
+    def set_plugin_priority(self, name: PluginName, priority: int) -> None:
+        """Changes the priority of a plugin without reinitializing it.
+
+        Args:
+            name (PluginName): The name of the plugin.
+            priority (int): The new priority of the plugin.
+        """
+        self._graphrecord.set_plugin_priority(name, priority)
+
     @property
     def plugins(self) -> List[PluginName]:
         """Lists the plugins registered in the GraphRecord instance.
 
         Returns a list of all plugin names currently registered with the GraphRecord
-        instance.
+        instance, in the order their hooks run.
 
         Returns:
             List[PluginName]: A list of plugin names.
         """
         return self._graphrecord.plugins
 
+    def register_udf(
+        self, name: str, udf: Callable[[GraphRecordValue], GraphRecordValue]
+    ) -> None:
+        """Registers a user-defined function (UDF) for use in queries.
+
+        Registered UDFs are applied to values in queries by name with `apply`, e.g.
+        `node.attribute("value").apply("my_udf")`.
+
+        Python UDFs are called once per value with the GIL held, and every value
+        is converted to and from Python, so they are considerably slower than the
+        built-in operations.
+
+        Args:
+            name (str): The name to register the UDF under.
+            udf (Callable[[GraphRecordValue], GraphRecordValue]): The function
+                transforming a single value.
+        """
+        self._graphrecord.register_udf(name, udf)
+
+    def remove_udf(self, name: str) -> None:
+        """Removes a user-defined function (UDF) from the GraphRecord instance.
+
+        Args:
+            name (str): The name of the UDF to remove.
+        """
+        self._graphrecord.remove_udf(name)
+
+    @property
+    def udfs(self) -> List[str]:
+        """Lists the names of the UDFs registered in the GraphRecord instance.
+
+        Returns:
+            List[str]: A list of UDF names in arbitrary order.
+        """
+        return self._graphrecord.udfs
+
+    def create_text_index(self, group: Group, attribute: GraphRecordAttribute) -> None:
+        """Creates a full-text index over a string attribute of a group's nodes.
+
+        The index speeds up `text_search` on the values of the attribute, e.g.
+        `node.attribute("note").text_search("heart failure")`. It is rebuilt on
+        the first search after the GraphRecord changed.
+
+        Args:
+            group (Group): The group whose nodes are indexed.
+            attribute (GraphRecordAttribute): The string attribute to index.
+        """
+        self._graphrecord.create_text_index(group, attribute)
+
+    def drop_text_index(self, group: Group, attribute: GraphRecordAttribute) -> None:
+        """Drops the full-text index over a string attribute of a group's nodes.
+
+        Args:
+            group (Group): The group of the index.
+            attribute (GraphRecordAttribute): The attribute of the index.
+        """
+        self._graphrecord.drop_text_index(group, attribute)
+
+    @property
+    def text_indices(self) -> List[Tuple[Group, GraphRecordAttribute]]:
+        """Lists the full-text indices of the GraphRecord instance.
+
+        Returns:
+            List[Tuple[Group, GraphRecordAttribute]]: The group and attribute of
+                each index in creation order.
+        """
+        return self._graphrecord.text_indices
+
+    def create_geo_index(self, group: Group, attribute: GraphRecordAttribute) -> None:
+        """Creates an r-tree index over a GeoPoint attribute of a group's nodes.
+
+        The index speeds up `within_radius` on the values of the attribute, e.g.
+        `node.attribute("location").within_radius(GeoPoint(52.52, 13.4), 10)`.
+        It is rebuilt on the first query after the GraphRecord changed.
+
+        Args:
+            group (Group): The group whose nodes are indexed.
+            attribute (GraphRecordAttribute): The GeoPoint attribute to index.
+        """
+        self._graphrecord.create_geo_index(group, attribute)
+
+    def drop_geo_index(self, group: Group, attribute: GraphRecordAttribute) -> None:
+        """Drops the r-tree index over a GeoPoint attribute of a group's nodes.
+
+        Args:
+            group (Group): The group of the index.
+            attribute (GraphRecordAttribute): The attribute of the index.
+        """
+        self._graphrecord.drop_geo_index(group, attribute)
+
+    @property
+    def geo_indices(self) -> List[Tuple[Group, GraphRecordAttribute]]:
+        """Lists the geo indices of the GraphRecord instance.
+
+        Returns:
+            List[Tuple[Group, GraphRecordAttribute]]: The group and attribute of
+                each index in creation order.
+        """
+        return self._graphrecord.geo_indices
+
+    @property
+    def operation_context(self) -> Attributes:
+        """Returns the metadata describing the current operation.
+
+        Plugin hooks can read this to learn who performs a mutation and why.
+
+        Returns:
+            Attributes: The current operation context.
+        """
+        return self._graphrecord.operation_context
+
+    @contextmanager
+    def with_operation_context(self, metadata: Attributes) -> Iterator[GraphRecord]:
+        """Adds metadata to the operation context for the duration of a block.
+
+        Nested blocks extend the outer context, overriding shared keys. The outer
+        context is restored when the block exits, even if it raised.
+
+        Args:
+            metadata (Attributes): The metadata to add, e.g. the user and reason
+                of the operation.
+
+        Yields:
+            GraphRecord: The GraphRecord instance itself.
+        """
+        context = {**self._graphrecord.operation_context, **metadata}
+        previous = self._graphrecord.set_operation_context(context)
+
+        try:
+            yield self
+        finally:
+            self._graphrecord.set_operation_context(previous)
+
+    def change_token(self) -> int:
+        """Returns a token identifying the current modification state.
+
+        Pass the token to `is_modified_since` later on to cheaply check whether
+        caches or saved files derived from the GraphRecord are stale.
+
+        Returns:
+            int: The change token.
+        """
+        return self._graphrecord.change_token()
+
+    def is_modified_since(self, token: int) -> bool:
+        """Checks whether the GraphRecord was modified after a token was taken.
+
+        Args:
+            token (int): A token returned by `change_token`.
+
+        Returns:
+            bool: True if the GraphRecord was modified since, False otherwise.
+        """
+        return self._graphrecord.is_modified_since(token)
+
     def get_schema(self) -> Schema:
         """Returns a copy of the GraphRecord's schema.
 
@@ -617,6 +976,220 @@ class GraphRecord:
         """
         self._graphrecord.unfreeze_schema(bypass_plugins)
 
+    def freeze(self) -> None:
+        """Freezes the GraphRecord, making it read-only.
+
+        While frozen, every method that modifies the data of the GraphRecord
+        raises a RuntimeError before any plugin hook runs. Settings that only
+        affect reading, like the query settings and the operation context, can
+        still be changed. The frozen state is kept when cloning or pickling.
+        """
+        self._graphrecord.freeze()
+
+    def unfreeze(self) -> None:
+        """Unfreezes the GraphRecord, allowing modifications again."""
+        self._graphrecord.unfreeze()
+
+    def is_frozen(self) -> bool:
+        """Checks whether the GraphRecord is frozen.
+
+        Returns:
+            bool: True if the GraphRecord is read-only, False otherwise.
+        """
+        return self._graphrecord.is_frozen()
+
+    def compact(self) -> Dict[EdgeIndex, EdgeIndex]:
+        """Reassigns the edge indices so that they are contiguous, starting at 0.
+
+        The relative order of the edges is kept. Edge indices stored outside of the
+        GraphRecord are not updated.
+
+        Returns:
+            Dict[EdgeIndex, EdgeIndex]: A mapping from every previous edge index to
+                its new edge index.
+        """
+        return self._graphrecord.compact()
+
+    def set_recycle_edge_indices(self, recycle_edge_indices: bool) -> None:
+        """Sets whether indices of removed edges are reused for new edges.
+
+        Args:
+            recycle_edge_indices (bool): If True, new edges reuse the indices of
+                removed edges. Otherwise, edge indices grow monotonically.
+        """
+        self._graphrecord.set_recycle_edge_indices(recycle_edge_indices)
+
+    def recycle_edge_indices(self) -> bool:
+        """Checks whether indices of removed edges are reused for new edges.
+
+        Returns:
+            bool: True if edge indices are recycled, False otherwise.
+        """
+        return self._graphrecord.recycle_edge_indices()
+
+    def set_strict_queries(self, strict_queries: bool) -> None:
+        """Sets whether queries validate accessed attributes against the schema.
+
+        In strict mode, accessing an attribute that is not part of the schema of
+        the groups a query is restricted to raises a ValueError listing close
+        matches, instead of silently matching nothing.
+
+        Args:
+            strict_queries (bool): If True, attribute accesses in queries are
+                validated against the schema.
+        """
+        self._graphrecord.set_strict_queries(strict_queries)
+
+    def strict_queries(self) -> bool:
+        """Checks whether queries validate accessed attributes against the schema.
+
+        Returns:
+            bool: True if strict queries are enabled, False otherwise.
+        """
+        return self._graphrecord.strict_queries()
+
+    def set_float_tolerance(self, tolerance: float) -> None:
+        """Sets the absolute tolerance for float equality in queries.
+
+        With a tolerance, `equal_to` and `not_equal_to` consider two numbers of
+        which at least one is a float equal if they differ by at most the
+        tolerance, so values that went through a unit conversion still match.
+
+        Args:
+            tolerance (float): The non-negative tolerance. 0 compares exactly,
+                which is the default.
+
+        Raises:
+            GraphRecordAssertionError: If the tolerance is negative or NaN.
+        """
+        self._graphrecord.set_float_tolerance(tolerance)
+
+    def float_tolerance(self) -> float:
+        """Returns the absolute tolerance for float equality in queries.
+
+        Returns:
+            float: The tolerance, 0 if floats are compared exactly.
+        """
+        return self._graphrecord.float_tolerance()
+
+    def set_random_seed(self, seed: Optional[int] = None) -> None:
+        """Seeds the `random` operations of queries.
+
+        With a seed, `random` picks the same nodes, edges, values and attributes
+        on every evaluation, independently of the order in which they were
+        added, so analyses using random selection are reproducible.
+
+        Args:
+            seed (Optional[int]): The seed, or None to pick unseeded, which is
+                the default.
+        """
+        self._graphrecord.set_random_seed(seed)
+
+    def random_seed(self) -> Optional[int]:
+        """Returns the seed of the `random` operations of queries.
+
+        Returns:
+            Optional[int]: The seed, or None if `random` picks unseeded.
+        """
+        return self._graphrecord.random_seed()
+
+    def metadata(self) -> RecordMetadata:
+        """Returns the descriptive header of the GraphRecord.
+
+        The header holds a title, description, creation time, source datasets
+        and arbitrary properties, and is persisted along with the GraphRecord.
+
+        Returns:
+            RecordMetadata: The metadata of the GraphRecord.
+        """
+        return self._graphrecord.metadata()
+
+    def set_metadata(self, metadata: RecordMetadata) -> RecordMetadata:
+        """Replaces the descriptive header of the GraphRecord.
+
+        The header is kept when the GraphRecord is cleared.
+
+        Args:
+            metadata (RecordMetadata): The new metadata.
+
+        Returns:
+            RecordMetadata: The previous metadata.
+
+        Raises:
+            ReadOnlyError: If the GraphRecord is frozen.
+        """
+        return self._graphrecord.set_metadata(metadata)
+
+    def enable_provenance(self) -> None:
+        """Starts recording the provenance of attribute values written from now on.
+
+        Values are tagged with the tag set by `set_provenance_tag`. Does nothing
+        if provenance tracking is already enabled.
+        """
+        self._graphrecord.enable_provenance()
+
+    def disable_provenance(self) -> None:
+        """Stops provenance tracking and drops all recorded tags."""
+        self._graphrecord.disable_provenance()
+
+    def provenance_enabled(self) -> bool:
+        """Checks whether provenance tracking is enabled.
+
+        Returns:
+            bool: True if provenance tracking is enabled, False otherwise.
+        """
+        return self._graphrecord.provenance_enabled()
+
+    def set_provenance_tag(
+        self, tag: Optional[ProvenanceTag] = None
+    ) -> Optional[ProvenanceTag]:
+        """Sets the tag attached to attribute values written from now on.
+
+        Values written while no tag is set have no provenance. The tag itself is
+        not persisted with the GraphRecord.
+
+        Args:
+            tag (Optional[ProvenanceTag]): The tag to attach, or None to write
+                untagged values. Defaults to None.
+
+        Returns:
+            Optional[ProvenanceTag]: The previously set tag.
+
+        Raises:
+            GraphRecordAssertionError: If provenance tracking is not enabled.
+        """
+        return self._graphrecord.set_provenance_tag(tag)
+
+    def node_attribute_provenance(
+        self, node_index: NodeIndex, attribute: GraphRecordAttribute
+    ) -> Optional[ProvenanceTag]:
+        """Returns the provenance tag of a node attribute value.
+
+        Args:
+            node_index (NodeIndex): The index of the node.
+            attribute (GraphRecordAttribute): The attribute.
+
+        Returns:
+            Optional[ProvenanceTag]: The tag of the value, or None if the value
+                is untagged or provenance tracking is disabled.
+        """
+        return self._graphrecord.node_attribute_provenance(node_index, attribute)
+
+    def edge_attribute_provenance(
+        self, edge_index: EdgeIndex, attribute: GraphRecordAttribute
+    ) -> Optional[ProvenanceTag]:
+        """Returns the provenance tag of an edge attribute value.
+
+        Args:
+            edge_index (EdgeIndex): The index of the edge.
+            attribute (GraphRecordAttribute): The attribute.
+
+        Returns:
+            Optional[ProvenanceTag]: The tag of the value, or None if the value
+                is untagged or provenance tracking is disabled.
+        """
+        return self._graphrecord.edge_attribute_provenance(edge_index, attribute)
+
     @property
     def nodes(self) -> List[NodeIndex]:
         """Lists the node indices in the GraphRecord instance.
@@ -678,6 +1251,39 @@ class GraphRecord:
         """
         return self._graphrecord.groups
 
+    def nodes_sorted(self) -> List[NodeIndex]:
+        """Lists the node indices in a deterministic order.
+
+        Integer indices come first in ascending order, followed by string indices
+        in lexicographic order.
+
+        Returns:
+            List[NodeIndex]: A sorted list of node indices.
+        """
+        return self._graphrecord.nodes_sorted()
+
+    def edges_sorted(self) -> List[EdgeIndex]:
+        """Lists the edge indices in ascending order.
+
+        Edge indices are assigned incrementally, so this is the order in which the
+        edges were added.
+
+        Returns:
+            List[EdgeIndex]: A sorted list of edge indices.
+        """
+        return self._graphrecord.edges_sorted()
+
+    def groups_sorted(self) -> List[Group]:
+        """Lists the groups in a deterministic order.
+
+        Integer groups come first in ascending order, followed by string groups in
+        lexicographic order.
+
+        Returns:
+            List[Group]: A sorted list of groups.
+        """
+        return self._graphrecord.groups_sorted()
+
     @overload
     def group(self, group: Group) -> GroupInfo: ...
 
@@ -864,17 +1470,94 @@ class GraphRecord:
 
         return endpoints[edge]
 
-    def edges_connecting(
+    def set_edge_undirected(
         self,
-        source_node: Union[
-            NodeIndex, NodeIndexInputList, NodeIndexQuery, NodeIndicesQuery
-        ],
-        target_node: Union[
-            NodeIndex, NodeIndexInputList, NodeIndexQuery, NodeIndicesQuery
-        ],
-        directed: EdgesDirection = EdgesDirection.OUTGOING,
-    ) -> List[EdgeIndex]:
-        """Retrieves the edges connecting the specified source and target nodes.
+        edge: Union[EdgeIndex, EdgeIndexInputList, EdgeIndexQuery, EdgeIndicesQuery],
+        undirected: bool = True,
+    ) -> None:
+        """Sets whether the specified edge(s) are undirected.
+
+        Undirected edges keep their source and target, but are also traversed from
+        target to source by neighbors, edges_connecting, outgoing_edges,
+        incoming_edges and queries.
+
+        Args:
+            edge (Union[EdgeIndex, EdgeIndexInputList, EdgeIndexQuery, EdgeIndicesQuery]):
+                One or more edge indices or an edge query.
+            undirected (bool): If True, the edges are undirected. Defaults to True.
+        """  # noqa: W505
+        if isinstance(edge, Callable):
+            query_result = self.query_edges(edge)
+
+            if isinstance(query_result, list):
+                self._graphrecord.set_edge_undirected(query_result, undirected)
+            elif query_result is not None:
+                self._graphrecord.set_edge_undirected([query_result], undirected)
+
+            return
+
+        self._graphrecord.set_edge_undirected(
+            edge if isinstance(edge, list) else [edge], undirected
+        )
+
+    @overload
+    def is_edge_undirected(self, edge: Union[EdgeIndex, EdgeIndexQuery]) -> bool: ...
+
+    @overload
+    def is_edge_undirected(
+        self, edge: Union[EdgeIndexInputList, EdgeIndicesQuery]
+    ) -> Dict[EdgeIndex, bool]: ...
+
+    def is_edge_undirected(
+        self,
+        edge: Union[EdgeIndex, EdgeIndexInputList, EdgeIndexQuery, EdgeIndicesQuery],
+    ) -> Union[bool, Dict[EdgeIndex, bool]]:
+        """Checks whether the specified edge(s) are undirected.
+
+        Args:
+            edge (Union[EdgeIndex, EdgeIndexInputList, EdgeIndexQuery, EdgeIndicesQuery]):
+                One or more edge indices or an edge query.
+
+        Returns:
+            Union[bool, Dict[EdgeIndex, bool]]: Whether the edge is undirected, or
+                a dictionary mapping each edge to it.
+
+        Raises:
+            IndexError: If the query returned no results.
+        """  # noqa: W505
+        if isinstance(edge, Callable):
+            query_result = self.query_edges(edge)
+
+            if isinstance(query_result, list):
+                return self._graphrecord.is_edge_undirected(query_result)
+            if query_result is not None:
+                return self._graphrecord.is_edge_undirected([query_result])[
+                    query_result
+                ]
+
+            msg = "The query returned no results"
+            raise IndexError(msg)
+
+        undirected = self._graphrecord.is_edge_undirected(
+            edge if isinstance(edge, list) else [edge]
+        )
+
+        if isinstance(edge, list):
+            return undirected
+
+        return undirected[edge]
+
+    def edges_connecting(
+        self,
+        source_node: Union[
+            NodeIndex, NodeIndexInputList, NodeIndexQuery, NodeIndicesQuery
+        ],
+        target_node: Union[
+            NodeIndex, NodeIndexInputList, NodeIndexQuery, NodeIndicesQuery
+        ],
+        directed: EdgesDirection = EdgesDirection.OUTGOING,
+    ) -> List[EdgeIndex]:
+        """Retrieves the edges connecting the specified source and target nodes.
 
         If a NodeOperation is provided for either the source or target nodes, it is
         first evaluated to obtain the corresponding node indices. The method then
@@ -930,6 +1613,25 @@ class GraphRecord:
             source_node_indices, target_node_indices
         )
 
+    def induced_edges(
+        self, nodes: Union[NodeIndexInputList, NodeIndicesQuery]
+    ) -> List[EdgeIndex]:
+        """Retrieves the edges whose source and target nodes are both in the given set.
+
+        Node indices that do not exist are ignored.
+
+        Args:
+            nodes (Union[NodeIndexInputList, NodeIndicesQuery]): The node indices
+                inducing the edges, or a node query to select them.
+
+        Returns:
+            List[EdgeIndex]: The edges between the given nodes.
+        """
+        if isinstance(nodes, Callable):
+            nodes = self.query_nodes(nodes)
+
+        return self._graphrecord.induced_edges(nodes)
+
     @overload
     def remove_nodes(
         self,
@@ -1034,6 +1736,95 @@ class GraphRecord:
 
         return None
 
+    def add_node_auto(self, attributes: Optional[Attributes] = None) -> NodeIndex:
+        """Adds a node under a generated index and returns that index.
+
+        Indices are drawn from a sequence stored in the GraphRecord, so they stay
+        unique across calls, removals and persistence. Indices that are already
+        taken are skipped.
+
+        Args:
+            attributes (Optional[Attributes]): The attributes of the node.
+                Defaults to no attributes.
+
+        Returns:
+            NodeIndex: The generated index of the node.
+        """
+        return self._graphrecord.add_node_auto(attributes or {})
+
+    def add_nodes_auto(self, attributes: List[Attributes]) -> List[NodeIndex]:
+        """Adds one node per attributes dictionary under generated indices.
+
+        Args:
+            attributes (List[Attributes]): The attributes of the nodes.
+
+        Returns:
+            List[NodeIndex]: The generated indices, in the order of `attributes`.
+        """
+        return self._graphrecord.add_nodes_auto(attributes)
+
+    def auto_index_prefix(self) -> Optional[str]:
+        """Returns the prefix of the indices generated by `add_node_auto`.
+
+        Returns:
+            Optional[str]: The prefix, or None if integer indices are generated.
+        """
+        return self._graphrecord.auto_index_prefix()
+
+    def set_auto_index_prefix(self, prefix: Optional[str] = None) -> None:
+        """Sets the prefix of the indices generated by `add_node_auto`.
+
+        With a prefix, indices are strings like `patient_0`. Without one, they are
+        integers. The sequence continues from where it stopped.
+
+        Args:
+            prefix (Optional[str]): The prefix, or None to generate integer
+                indices. Defaults to None.
+        """
+        self._graphrecord.set_auto_index_prefix(prefix)
+
+    def add_relation(
+        self,
+        relation: NodeIndex,
+        members: List[tuple[str, NodeIndex]],
+        attributes: Optional[Attributes] = None,
+    ) -> List[EdgeIndex]:
+        """Adds a relation between two or more nodes as a node of its own.
+
+        Relations like a prescription linking a patient, a drug and a prescriber
+        are stored as a relation node with one edge to each member. Each edge
+        carries the role of its member in the `role` attribute and its position
+        in `members` in the `position` attribute. The relation is validated
+        before anything is added, so an invalid relation leaves the
+        GraphRecord unchanged.
+
+        Args:
+            relation (NodeIndex): The index of the relation node.
+            members (List[tuple[str, NodeIndex]]): The role and node index of each
+                member.
+            attributes (Optional[Attributes]): The attributes of the relation.
+                Defaults to no attributes.
+
+        Returns:
+            List[EdgeIndex]: The indices of the edges to the members, in the
+                order of `members`.
+        """
+        return self._graphrecord.add_relation(relation, attributes or {}, members)
+
+    def relation_members(
+        self, relation: NodeIndex
+    ) -> List[tuple[GraphRecordValue, NodeIndex]]:
+        """Returns the role and node index of each member of a relation.
+
+        Args:
+            relation (NodeIndex): The index of the relation node.
+
+        Returns:
+            List[tuple[GraphRecordValue, NodeIndex]]: The members of the relation,
+                in the order they were added.
+        """
+        return self._graphrecord.relation_members(relation)
+
     def add_nodes_pandas(
         self,
         nodes: Union[PandasNodeDataFrameInput, List[PandasNodeDataFrameInput]],
@@ -1340,6 +2131,22 @@ class GraphRecord:
 
         self._graphrecord.remove_groups(groups, bypass_plugins)
 
+    def reserve_group(self, group: Group, nodes: int, edges: int) -> None:
+        """Reserves capacity in a group for nodes and edges that will be added.
+
+        Reserving capacity up front avoids repeated reallocations when adding
+        many nodes or edges to a group in bulk.
+
+        Args:
+            group (Group): The group to reserve capacity in.
+            nodes (int): The number of nodes to reserve capacity for.
+            edges (int): The number of edges to reserve capacity for.
+
+        Raises:
+            IndexError: If the group does not exist.
+        """
+        self._graphrecord.reserve_group(group, nodes, edges)
+
     def add_nodes_to_group(
         self,
         group: Union[Group, GroupInputList],
@@ -1660,130 +2467,1038 @@ class GraphRecord:
         """
         return self._graphrecord.group_count()
 
-    def contains_node(self, node: NodeIndex) -> bool:
-        """Checks whether a specific node exists in the GraphRecord.
+    def stats(self) -> Stats:
+        """Returns a lightweight structural summary of the GraphRecord.
+
+        The summary contains the node and edge counts, the density, the minimum,
+        mean and maximum node degree, the number of connected components and the
+        node and edge counts per group. Degrees count incoming and outgoing edges,
+        and connected components ignore edge direction. The density is the share
+        of ordered node pairs connected by an edge, where an undirected edge
+        between two different nodes counts in both directions. It is much cheaper
+        to compute than an overview.
+
+        Returns:
+            Stats: The structural summary of the GraphRecord.
+        """
+        return self._graphrecord.stats()
+
+    def correlation(
+        self,
+        group: Group,
+        attribute_a: GraphRecordAttribute,
+        attribute_b: GraphRecordAttribute,
+        method: CorrelationMethod = CorrelationMethod.PEARSON,
+    ) -> CorrelationTest:
+        """Returns the correlation between two numeric attributes of a group.
+
+        Nodes that lack one of the attributes or have a null or missing value of
+        it are skipped. The p-value belongs to the two-sided test of the
+        coefficient being zero and uses a t-distribution for both methods.
 
         Args:
-            node (NodeIndex): The index of the node to check.
+            group (Group): The group whose nodes are the sample.
+            attribute_a (GraphRecordAttribute): The first numeric attribute.
+            attribute_b (GraphRecordAttribute): The second numeric attribute.
+            method (CorrelationMethod, optional): Whether to measure the linear
+                (Pearson) or rank (Spearman) correlation. Defaults to
+                CorrelationMethod.PEARSON.
 
         Returns:
-            bool: True if the node exists, False otherwise.
+            CorrelationTest: The coefficient, p-value and sample size.
+
+        Raises:
+            IndexError: If the group does not exist.
+            ConversionError: If one of the attributes is not numeric.
+            GraphRecordAssertionError: If fewer than 3 nodes have both attributes,
+                one of the attributes is constant or has a NaN value.
         """
-        return self._graphrecord.contains_node(node)
+        return self._graphrecord.correlation(
+            group, attribute_a, attribute_b, method._into_py_correlation_method()
+        )
 
-    def contains_edge(self, edge: EdgeIndex) -> bool:
-        """Checks whether a specific edge exists in the GraphRecord.
+    def chi_square(
+        self,
+        group: Group,
+        attribute_a: GraphRecordAttribute,
+        attribute_b: GraphRecordAttribute,
+    ) -> ChiSquareTest:
+        """Tests the independence of two categorical attributes of a group.
+
+        Runs Pearson's chi-square test on the contingency table of the
+        attributes and reports Cramér's V as the effect size. Nodes that lack one
+        of the attributes or have a null or missing value of it are skipped.
 
         Args:
-            edge (EdgeIndex): The index of the edge to check.
+            group (Group): The group whose nodes are the sample.
+            attribute_a (GraphRecordAttribute): The first categorical attribute.
+            attribute_b (GraphRecordAttribute): The second categorical attribute.
 
         Returns:
-            bool: True if the edge exists, False otherwise.
+            ChiSquareTest: The statistic, degrees of freedom, p-value, Cramér's V
+                and sample size.
+
+        Raises:
+            IndexError: If the group does not exist.
+            GraphRecordAssertionError: If one of the attributes has fewer than 2
+                distinct values.
         """
-        return self._graphrecord.contains_edge(edge)
+        return self._graphrecord.chi_square(group, attribute_a, attribute_b)
 
-    def contains_group(self, group: Group) -> bool:
-        """Checks whether a specific group exists in the GraphRecord.
+    def propensity_scores(
+        self,
+        treated: Group,
+        control: Group,
+        covariates: List[GraphRecordAttribute],
+    ) -> Dict[NodeIndex, float]:
+        """Returns the propensity of the nodes in two groups to be treated.
+
+        The scores are the predictions of a logistic regression of the membership
+        in the treated group on the covariates. Integer, float and boolean
+        covariates are supported.
 
         Args:
-            group (Group): The name of the group to check.
+            treated (Group): The group of treated nodes.
+            control (Group): The group of control nodes.
+            covariates (List[GraphRecordAttribute]): The attributes to fit the
+                scores on. Every node needs all of them.
 
         Returns:
-            bool: True if the group exists, False otherwise.
+            Dict[NodeIndex, float]: The propensity score of every node in both
+                groups.
+
+        Raises:
+            IndexError: If one of the groups does not exist.
+            KeyError: If a node lacks one of the covariates.
+            GraphRecordAssertionError: If a group is empty or a node is in both
+                groups.
         """
-        return self._graphrecord.contains_group(group)
+        return self._graphrecord.propensity_scores(treated, control, covariates)
 
-    @overload
-    def neighbors(
+    def match_propensity_scores(
         self,
-        node: Union[NodeIndex, NodeIndexQuery],
-        directed: EdgesDirection = EdgesDirection.OUTGOING,
-    ) -> List[NodeIndex]: ...
+        treated: Group,
+        control: Group,
+        covariates: List[GraphRecordAttribute],
+        edge_group: Group,
+        caliper: Optional[float] = None,
+    ) -> List[Tuple[NodeIndex, NodeIndex, EdgeIndex]]:
+        """Matches treated to control nodes with the closest propensity score.
+
+        Every treated node, in the order of their indices, is matched to the
+        unmatched control node whose propensity score is closest to its own. An
+        edge from the treated to the control node is added to the new edge group
+        for every pair, carrying the difference of the scores as the `distance`
+        attribute.
 
-    @overload
-    def neighbors(
+        Args:
+            treated (Group): The group of treated nodes.
+            control (Group): The group of control nodes.
+            covariates (List[GraphRecordAttribute]): The attributes to fit the
+                propensity scores on.
+            edge_group (Group): The new group the edges of the pairs are added to.
+            caliper (Optional[float], optional): The largest difference of scores
+                a pair may have. Treated nodes without a control node within it
+                stay unmatched. Defaults to None.
+
+        Returns:
+            List[Tuple[NodeIndex, NodeIndex, EdgeIndex]]: The treated node,
+                control node and edge of every pair.
+
+        Raises:
+            IndexError: If one of the groups does not exist.
+            KeyError: If a node lacks one of the covariates.
+            DuplicateGroupError: If the edge group already exists.
+            GraphRecordAssertionError: If a group is empty or a node is in both
+                groups.
+        """
+        return self._graphrecord.match_propensity_scores(
+            treated, control, covariates, edge_group, caliper
+        )
+
+    def cohort_table(
         self,
-        node: Union[NodeIndexInputList, NodeIndicesQuery],
-        directed: EdgesDirection = EdgesDirection.OUTGOING,
-    ) -> Dict[NodeIndex, List[NodeIndex]]: ...
+        group_a: Group,
+        group_b: Group,
+        attributes: List[GraphRecordAttribute],
+    ) -> pl.DataFrame:
+        """Compares the attributes of the nodes in two groups in a baseline table.
+
+        The table starts with the size of each group. Numeric attributes get a
+        `mean ± std` and a `median` row and are compared with Welch's t-test.
+        Categorical attributes get a row per value with its count and the share
+        of the group's nodes that have the attribute, and are compared with a
+        chi-square test. An attribute is numeric if all of its values are
+        integers or floats. Nodes that lack an attribute or have a null or
+        missing value of it are counted in a `missing` row instead. The p-value
+        is on the first row of an attribute and is null if the test is undefined
+        for the data.
 
-    def neighbors(
+        Args:
+            group_a (Group): The first group to compare.
+            group_b (Group): The second group to compare.
+            attributes (List[GraphRecordAttribute]): The attributes to compare.
+
+        Returns:
+            pl.DataFrame: The table with the columns `attribute`, `statistic`, one
+                column per group named after it and `p_value`.
+
+        Raises:
+            IndexError: If one of the groups does not exist.
+            GraphRecordAssertionError: If both groups are the same.
+        """
+        return self._graphrecord.cohort_table(group_a, group_b, attributes)
+
+    def crosstab(
         self,
-        node: Union[NodeIndex, NodeIndexInputList, NodeIndexQuery, NodeIndicesQuery],
-        directed: EdgesDirection = EdgesDirection.OUTGOING,
-    ) -> Union[List[NodeIndex], Dict[NodeIndex, List[NodeIndex]]]:
-        """Retrieves the neighbors of the specified node(s) in the GraphRecord.
+        group: Group,
+        row_attribute: GraphRecordAttribute,
+        column_attribute: GraphRecordAttribute,
+        normalization: Optional[CrosstabNormalization] = None,
+    ) -> pd.DataFrame:
+        """Returns the contingency table of two attributes of the nodes in a group.
 
-        If a single node index is provided, returns a list of its neighboring
-        node indices. If multiple nodes are specified, returns a dictionary mapping
-        each node index to its list of neighboring nodes.
+        The table is computed in one pass over the group, without materializing
+        the attributes first. Nodes that lack one of the attributes are skipped.
+        Rows and columns hold the distinct values of the attributes in sorted
+        order.
 
         Args:
-            node (Union[NodeIndex, NodeIndexInputList, NodeIndexQuery, NodeIndicesQuery]):
-                One or more node indices or a query that returns node indices.
-            directed (EdgesDirection, optional): The direction to traverse edges.
-                Defaults to EdgesDirection.OUTGOING.
+            group (Group): The group whose nodes are counted.
+            row_attribute (GraphRecordAttribute): The attribute whose values
+                make up the rows.
+            column_attribute (GraphRecordAttribute): The attribute whose values
+                make up the columns.
+            normalization (Optional[CrosstabNormalization], optional): Divides
+                the counts by the total, row or column count. Defaults to None.
 
         Returns:
-            Union[List[NodeIndex], Dict[NodeIndex, List[NodeIndex]]]: Neighboring nodes.
-        """  # noqa: W505
-        if isinstance(node, Callable):
-            query_result = self.query_nodes(node)
+            pd.DataFrame: The number of nodes, or their share if normalized, for
+                every pair of values.
 
-            if query_result is None:
-                return []
+        Raises:
+            IndexError: If the group does not exist.
+        """
+        rows, columns, values = self._graphrecord.crosstab(
+            group,
+            row_attribute,
+            column_attribute,
+            normalization._into_py_crosstab_normalization()
+            if normalization is not None
+            else None,
+        )
 
-            node = query_result
+        return pd.DataFrame(values, index=rows, columns=columns)
 
-        node_indices = node if isinstance(node, list) else [node]
+    def group_adjacency_counts(
+        self,
+    ) -> Dict[Tuple[Optional[Group], Optional[Group]], int]:
+        """Returns the number of edges between every ordered pair of groups.
 
-        if directed == EdgesDirection.OUTGOING:
-            neighbors = self._graphrecord.neighbors_outgoing(node_indices)
-        elif directed == EdgesDirection.INCOMING:
-            neighbors = self._graphrecord.neighbors_incoming(node_indices)
-        else:
-            neighbors = self._graphrecord.neighbors_undirected(node_indices)
+        The keys are pairs of the source and target group of the edges, with `None`
+        standing for nodes that are in no group. Every pair is present, even if no
+        edge connects it. An edge whose endpoints are in several groups is counted
+        once for every combination of their groups.
 
-        if isinstance(node, list):
-            return neighbors
+        Returns:
+            Dict[Tuple[Optional[Group], Optional[Group]], int]: The edge counts per
+                pair of source and target group.
+        """
+        return self._graphrecord.group_adjacency_counts()
 
-        return neighbors[node]
+    def time_to_event(
+        self,
+        cohort_group: Group,
+        index_date_attribute: GraphRecordAttribute,
+        event_edge_group: Group,
+        censor_date: datetime,
+    ) -> Dict[NodeIndex, TimeToEvent]:
+        """Returns the time from the index date of every cohort node to its event.
+
+        Events are the edges in the event edge group that touch a node. Their
+        `index_date_attribute` holds either the datetime of the event or the
+        timedelta since the index date. Only the first event between the index
+        date and the censor date counts. Nodes without such an event are
+        censored at the censor date.
 
-    def clear(self, *, bypass_plugins: bool = False) -> None:
-        """Clears all data from the GraphRecord instance.
+        Args:
+            cohort_group (Group): The group of nodes to follow up.
+            index_date_attribute (GraphRecordAttribute): The datetime attribute
+                the follow-up of a node starts at, also holding the time of the
+                events.
+            event_edge_group (Group): The group of edges that mark the events.
+            censor_date (datetime): The end of the follow-up.
 
-        Removes all nodes, edges, and groups, effectively resetting the instance.
+        Returns:
+            Dict[NodeIndex, TimeToEvent]: The duration of the follow-up and
+                whether an event was observed, for every node in the cohort.
+
+        Raises:
+            IndexError: If one of the groups does not exist.
+            KeyError: If a node lacks the index date or an event edge its time.
+            GraphRecordAssertionError: If the index date of a node lies after the
+                censor date.
+        """
+        return self._graphrecord.time_to_event(
+            cohort_group, index_date_attribute, event_edge_group, censor_date
+        )
+
+    def events_in_window(
+        self,
+        cohort_group: Group,
+        index_date_attribute: GraphRecordAttribute,
+        event_edge_group: Group,
+        start: timedelta,
+        end: timedelta,
+        washout: Optional[timedelta] = None,
+    ) -> Dict[NodeIndex, bool]:
+        """Returns whether every cohort node has an event within a window.
+
+        The window spans from `start` (inclusive) to `end` (exclusive) relative to
+        the index date of a node. Events are read as in `time_to_event`. Nodes
+        with an event within the washout period right before the window are left
+        out, so that only new events count.
 
         Args:
-            bypass_plugins (bool): If True, plugin hooks are not called.
-                Defaults to False.
+            cohort_group (Group): The group of nodes to check.
+            index_date_attribute (GraphRecordAttribute): The datetime attribute
+                the window is relative to, also holding the time of the events.
+            event_edge_group (Group): The group of edges that mark the events.
+            start (timedelta): The start of the window.
+            end (timedelta): The end of the window.
+            washout (Optional[timedelta], optional): The period before the
+                window in which an event excludes a node. Defaults to None.
+
+        Returns:
+            Dict[NodeIndex, bool]: Whether a node has an event within the window,
+                for every node at risk.
+
+        Raises:
+            IndexError: If one of the groups does not exist.
+            KeyError: If a node lacks the index date or an event edge its time.
+            GraphRecordAssertionError: If the window is empty or the washout
+                period is negative.
         """
-        self._graphrecord.clear(bypass_plugins)
+        return self._graphrecord.events_in_window(
+            cohort_group, index_date_attribute, event_edge_group, start, end, washout
+        )
 
-    @overload
-    def query_nodes(
-        self, query: Callable[[NodeOperand], NodeAttributesTreeOperand]
-    ) -> NodeAttributesTreeQueryResult: ...
-    @overload
-    def query_nodes(
-        self, query: Callable[[NodeOperand], NodeAttributesTreeGroupOperand]
-    ) -> NodeAttributesTreeGroupQueryResult: ...
-    @overload
-    def query_nodes(
-        self, query: Callable[[NodeOperand], EdgeAttributesTreeOperand]
-    ) -> EdgeAttributesTreeQueryResult: ...
-    @overload
-    def query_nodes(
-        self, query: Callable[[NodeOperand], EdgeAttributesTreeGroupOperand]
-    ) -> EdgeAttributesTreeGroupQueryResult: ...
-    @overload
-    def query_nodes(
-        self, query: Callable[[NodeOperand], NodeMultipleAttributesWithIndexOperand]
-    ) -> NodeMultipleAttributesWithIndexQueryResult: ...
-    @overload
-    def query_nodes(
+    def event_rate_in_window(
         self,
-        query: Callable[[NodeOperand], NodeMultipleAttributesWithIndexGroupOperand],
+        cohort_group: Group,
+        index_date_attribute: GraphRecordAttribute,
+        event_edge_group: Group,
+        start: timedelta,
+        end: timedelta,
+        washout: Optional[timedelta] = None,
+    ) -> EventRate:
+        """Returns the share of the cohort nodes with an event within a window.
+
+        Counts the nodes as in `events_in_window`. With a washout period the rate
+        is the incidence of new events, without one the period prevalence.
+
+        Args:
+            cohort_group (Group): The group of nodes to check.
+            index_date_attribute (GraphRecordAttribute): The datetime attribute
+                the window is relative to, also holding the time of the events.
+            event_edge_group (Group): The group of edges that mark the events.
+            start (timedelta): The start of the window.
+            end (timedelta): The end of the window.
+            washout (Optional[timedelta], optional): The period before the
+                window in which an event excludes a node. Defaults to None.
+
+        Returns:
+            EventRate: The number of nodes with an event, the number of nodes at
+                risk and their ratio, which is None if no node is at risk.
+
+        Raises:
+            IndexError: If one of the groups does not exist.
+            KeyError: If a node lacks the index date or an event edge its time.
+            GraphRecordAssertionError: If the window is empty or the washout
+                period is negative.
+        """
+        count, population, rate = self._graphrecord.event_rate_in_window(
+            cohort_group, index_date_attribute, event_edge_group, start, end, washout
+        )
+
+        return {"count": count, "population": population, "rate": rate}
+
+    def k_anonymity(
+        self, group: Group, quasi_identifiers: List[GraphRecordAttribute]
+    ) -> int:
+        """Returns the k for which the nodes of a group are k-anonymous.
+
+        Nodes that share their values of all quasi-identifiers form an
+        equivalence class. The k is the size of the smallest class, so every node
+        is indistinguishable from at least k - 1 others. A missing attribute
+        counts as a value of its own.
+
+        Args:
+            group (Group): The group of nodes to check.
+            quasi_identifiers (List[GraphRecordAttribute]): The attributes that
+                could identify a node in combination.
+
+        Returns:
+            int: The size of the smallest equivalence class, or 0 if the group
+                is empty.
+
+        Raises:
+            IndexError: If the group does not exist.
+        """
+        return self._graphrecord.k_anonymity(group, quasi_identifiers)
+
+    def k_anonymity_violations(
+        self, group: Group, quasi_identifiers: List[GraphRecordAttribute], k: int
+    ) -> List[NodeIndex]:
+        """Returns the nodes of a group that prevent it from being k-anonymous.
+
+        Args:
+            group (Group): The group of nodes to check.
+            quasi_identifiers (List[GraphRecordAttribute]): The attributes that
+                could identify a node in combination.
+            k (int): The minimum size of an equivalence class.
+
+        Returns:
+            List[NodeIndex]: The sorted nodes in equivalence classes smaller
+                than k.
+
+        Raises:
+            IndexError: If the group does not exist.
+        """
+        return self._graphrecord.k_anonymity_violations(group, quasi_identifiers, k)
+
+    def enforce_k_anonymity(
+        self, group: Group, quasi_identifiers: List[GraphRecordAttribute], k: int
+    ) -> List[NodeIndex]:
+        """Removes the nodes of a group that prevent it from being k-anonymous.
+
+        The nodes returned by `k_anonymity_violations` are removed together with
+        their edges, so that the rest of the group is k-anonymous. Generalize the
+        quasi-identifiers first to lose as few nodes as possible.
+
+        Args:
+            group (Group): The group of nodes to check.
+            quasi_identifiers (List[GraphRecordAttribute]): The attributes that
+                could identify a node in combination.
+            k (int): The minimum size of an equivalence class.
+
+        Returns:
+            List[NodeIndex]: The sorted nodes that were removed.
+
+        Raises:
+            IndexError: If the group does not exist.
+        """
+        return self._graphrecord.enforce_k_anonymity(group, quasi_identifiers, k)
+
+    def bucket_attribute(
+        self, group: Group, attribute: GraphRecordAttribute, width: int
+    ) -> None:
+        """Replaces a numeric attribute of the nodes of a group with a range.
+
+        Each value is replaced with the label of the range of the given width it
+        falls into, e.g. an age of 42 with "40-49" for a width of 10. Nodes
+        without the attribute and null values are left as they are.
+
+        Args:
+            group (Group): The group of nodes to generalize.
+            attribute (GraphRecordAttribute): The numeric attribute to bucket.
+            width (int): The width of the ranges.
+
+        Raises:
+            IndexError: If the group does not exist.
+            GraphRecordAssertionError: If the width is not positive.
+            ConversionError: If a value is not numeric.
+        """
+        self._graphrecord.bucket_attribute(group, attribute, width)
+
+    def truncate_attribute(
+        self, group: Group, attribute: GraphRecordAttribute, length: int
+    ) -> None:
+        """Masks the trailing characters of an attribute of the nodes of a group.
+
+        Each value keeps its first characters and has the rest replaced with "*",
+        e.g. the zip code "12345" becomes "123**" for a length of 3. Integers are
+        converted to strings first. Nodes without the attribute and null values
+        are left as they are.
+
+        Args:
+            group (Group): The group of nodes to generalize.
+            attribute (GraphRecordAttribute): The string or integer attribute to
+                truncate.
+            length (int): The number of characters to keep.
+
+        Raises:
+            IndexError: If the group does not exist.
+            ConversionError: If a value is neither a string nor an integer.
+        """
+        self._graphrecord.truncate_attribute(group, attribute, length)
+
+    def rename_attribute(
+        self,
+        attribute: GraphRecordAttribute,
+        new_attribute: GraphRecordAttribute,
+        scope: Union[AttributeScope, Group] = AttributeScope.ALL,
+    ) -> None:
+        """Renames an attribute of many nodes and edges and in the schema at once.
+
+        The scope is either an AttributeScope, to rename the attribute of all
+        nodes, all edges or both, or a group, to rename it for the nodes and
+        edges of that group and in its schema only. Either every node and edge
+        is renamed or, if renaming fails, none.
+
+        Args:
+            attribute (GraphRecordAttribute): The attribute to rename.
+            new_attribute (GraphRecordAttribute): The new name of the attribute.
+            scope (Union[AttributeScope, Group], optional): The nodes and edges to
+                rename the attribute for. Defaults to AttributeScope.ALL.
+
+        Raises:
+            IndexError: If the group does not exist.
+            GraphRecordAssertionError: If a node or edge already has the new
+                attribute.
+            SchemaError: If the renamed attributes do not match the schema of
+                another group of the nodes and edges.
+        """
+        if isinstance(scope, AttributeScope):
+            self._graphrecord.rename_attribute(
+                attribute, new_attribute, scope._into_py_attribute_scope()
+            )
+        else:
+            self._graphrecord.rename_attribute(
+                attribute, new_attribute, PyAttributeScope.All, scope
+            )
+
+    def drop_attribute(
+        self,
+        attribute: GraphRecordAttribute,
+        scope: Union[AttributeScope, Group] = AttributeScope.ALL,
+    ) -> None:
+        """Removes an attribute from many nodes and edges and the schema at once.
+
+        The scope is either an AttributeScope, to drop the attribute from all
+        nodes, all edges or both, or a group, to drop it from the nodes and edges
+        of that group and from its schema only. Either the attribute is dropped
+        from every node and edge or, if dropping fails, from none.
+
+        Args:
+            attribute (GraphRecordAttribute): The attribute to drop.
+            scope (Union[AttributeScope, Group], optional): The nodes and edges to
+                drop the attribute from. Defaults to AttributeScope.ALL.
+
+        Raises:
+            IndexError: If the group does not exist.
+            SchemaError: If the remaining attributes do not match the schema of
+                another group of the nodes and edges.
+        """
+        if isinstance(scope, AttributeScope):
+            self._graphrecord.drop_attribute(
+                attribute, scope._into_py_attribute_scope()
+            )
+        else:
+            self._graphrecord.drop_attribute(attribute, PyAttributeScope.All, scope)
+
+    def materialize_node_attribute(
+        self,
+        attribute: GraphRecordAttribute,
+        query: Callable[[NodeOperand], NodeMultipleValuesWithIndexOperand],
+    ) -> int:
+        """Stores the values computed by a query as an attribute of their nodes.
+
+        Every value returned by the query is written to the given attribute of
+        the node it belongs to, replacing a previous value. The new attributes
+        are checked against the schema first, so either all values are stored
+        or none.
+
+        Args:
+            attribute (GraphRecordAttribute): The attribute to store the values in.
+            query (Callable[[NodeOperand], NodeMultipleValuesWithIndexOperand]): A
+                query returning the values to store, with their node indices.
+
+        Returns:
+            int: The number of nodes the attribute was written to.
+
+        Raises:
+            SchemaError: If the new attributes do not match the schema.
+        """
+
+        def _query(node: PyNodeOperand) -> PyNodeMultipleValuesWithIndexOperand:
+            result = query(NodeOperand._from_py_node_operand(node))
+
+            return result._multiple_values_operand
+
+        return self._graphrecord.materialize_node_attribute(attribute, _query)
+
+    def materialize_edge_attribute(
+        self,
+        attribute: GraphRecordAttribute,
+        query: Callable[[EdgeOperand], EdgeMultipleValuesWithIndexOperand],
+    ) -> int:
+        """Stores the values computed by a query as an attribute of their edges.
+
+        Every value returned by the query is written to the given attribute of
+        the edge it belongs to, replacing a previous value. The new attributes
+        are checked against the schema first, so either all values are stored
+        or none.
+
+        Args:
+            attribute (GraphRecordAttribute): The attribute to store the values in.
+            query (Callable[[EdgeOperand], EdgeMultipleValuesWithIndexOperand]): A
+                query returning the values to store, with their edge indices.
+
+        Returns:
+            int: The number of edges the attribute was written to.
+
+        Raises:
+            SchemaError: If the new attributes do not match the schema.
+        """
+
+        def _query(edge: PyEdgeOperand) -> PyEdgeMultipleValuesWithIndexOperand:
+            result = query(EdgeOperand._from_py_edge_operand(edge))
+
+            return result._multiple_values_operand
+
+        return self._graphrecord.materialize_edge_attribute(attribute, _query)
+
+    def group_from_node_query(self, group: Group, query: NodeIndicesQuery) -> int:
+        """Adds a group containing the nodes selected by a query.
+
+        The nodes are checked against the schema of the group before the group is
+        added, so either the group is added with all nodes or not at all.
+
+        Args:
+            group (Group): The name of the group to add.
+            query (NodeIndicesQuery): A query returning the indices of the nodes
+                to add to the group.
+
+        Returns:
+            int: The number of nodes in the group.
+
+        Raises:
+            SchemaError: If the nodes do not match the schema of the group.
+        """
+
+        def _query(node: PyNodeOperand) -> PyNodeIndicesOperand:
+            result = query(NodeOperand._from_py_node_operand(node))
+
+            return result._node_indices_operand
+
+        return self._graphrecord.group_from_node_query(group, _query)
+
+    def group_from_edge_query(self, group: Group, query: EdgeIndicesQuery) -> int:
+        """Adds a group containing the edges selected by a query.
+
+        The edges are checked against the schema of the group before the group is
+        added, so either the group is added with all edges or not at all.
+
+        Args:
+            group (Group): The name of the group to add.
+            query (EdgeIndicesQuery): A query returning the indices of the edges
+                to add to the group.
+
+        Returns:
+            int: The number of edges in the group.
+
+        Raises:
+            SchemaError: If the edges do not match the schema of the group.
+        """
+
+        def _query(edge: PyEdgeOperand) -> PyEdgeIndicesOperand:
+            result = query(EdgeOperand._from_py_edge_operand(edge))
+
+            return result._edge_indices_operand
+
+        return self._graphrecord.group_from_edge_query(group, _query)
+
+    @classmethod
+    def concat(cls, records: List[GraphRecord]) -> GraphRecord:
+        """Merges GraphRecords without shared nodes into one GraphRecord.
+
+        The edges are renumbered in the order of the GraphRecords, so that edge
+        indices of different GraphRecords do not collide. Groups that several
+        GraphRecords have are merged, and so are their schemas. Attributes that
+        only some schemas have become optional. The settings and metadata of the
+        first GraphRecord are kept.
+
+        Args:
+            records (List[GraphRecord]): The GraphRecords to merge.
+
+        Returns:
+            GraphRecord: The merged GraphRecord.
+
+        Raises:
+            GraphRecordAssertionError: If a node is part of several GraphRecords.
+        """
+        return cls._from_py_graphrecord(
+            PyGraphRecord.concat([record._graphrecord for record in records])
+        )
+
+    def split_by_groups(self, groups: GroupInputList) -> Dict[Group, GraphRecord]:
+        """Splits the GraphRecord into one independent GraphRecord per group.
+
+        Every GraphRecord contains the nodes of its group, the edges between
+        them and the group itself. An inferred schema is inferred anew from every
+        GraphRecord, a provided schema keeps only the schema of the group and of
+        ungrouped nodes and edges.
+
+        Args:
+            groups (GroupInputList): The groups to split the GraphRecord by.
+
+        Returns:
+            Dict[Group, GraphRecord]: The GraphRecord of every group.
+
+        Raises:
+            IndexError: If a group does not exist.
+        """
+        return {
+            group: GraphRecord._from_py_graphrecord(graphrecord)
+            for group, graphrecord in self._graphrecord.split_by_groups(groups).items()
+        }
+
+    def memory_usage(self) -> Dict[str, int]:
+        """Returns an estimate of the heap memory held by the GraphRecord in bytes.
+
+        The estimate is broken down into nodes, node attributes, edges, edge
+        attributes and groups, plus the total. It accounts for hash table capacity
+        and string buffers, but not for allocator overhead.
+
+        Returns:
+            Dict[str, int]: The estimated memory usage per component in bytes.
+        """
+        return self._graphrecord.memory_usage()
+
+    def shrink_to_fit(self) -> None:
+        """Releases capacity that nodes, edges and groups no longer use.
+
+        Useful after bulk deletions. The released memory shows up in
+        `memory_usage`.
+        """
+        self._graphrecord.shrink_to_fit()
+
+    def maintain(self) -> Dict[str, int]:
+        """Performs housekeeping and reports the actions taken.
+
+        Releases capacity that nodes, edges and groups no longer use, and builds
+        the text and geo indices that are outdated after changes, so the next
+        query using them does not have to. Applications can schedule this, e.g.
+        in idle periods. The content of the GraphRecord is not changed.
+
+        Returns:
+            Dict[str, int]: The estimated bytes released and the number of text
+                and geo indices rebuilt.
+        """
+        return self._graphrecord.maintain()
+
+    def fingerprint(self) -> int:
+        """Returns a deterministic hash over the content of the GraphRecord.
+
+        The hash covers nodes, edges, attributes, groups and the schema. It does
+        not depend on insertion or iteration order and is stable across
+        processes, so two GraphRecords can be compared without a deep diff. Edge
+        indices are not part of the fingerprint.
+
+        Returns:
+            int: The fingerprint of the GraphRecord.
+        """
+        return self._graphrecord.fingerprint()
+
+    def equals(self, other: GraphRecord) -> bool:
+        """Checks whether two GraphRecords have the same content.
+
+        Nodes, edges, attributes and groups are compared. Edges are compared by
+        their endpoints, attributes and groups, so edge index numbering is
+        ignored. Schemas are not compared.
+
+        Args:
+            other (GraphRecord): The GraphRecord to compare with.
+
+        Returns:
+            bool: True if both GraphRecords have the same content, False otherwise.
+        """
+        return self._graphrecord.equals(other._graphrecord)
+
+    def is_subgraph_of(self, other: GraphRecord) -> bool:
+        """Checks whether the content of this GraphRecord is part of another one.
+
+        Every node, edge and group must also be part of `other`, with equal
+        attributes. Edges are matched as in `equals`, and each edge of `other`
+        can only be matched once.
+
+        Args:
+            other (GraphRecord): The GraphRecord to compare with.
+
+        Returns:
+            bool: True if this GraphRecord is a subgraph of `other`, False
+                otherwise.
+        """
+        return self._graphrecord.is_subgraph_of(other._graphrecord)
+
+    def __eq__(self, other: object) -> bool:
+        """Checks whether two GraphRecords have the same content.
+
+        See `equals` for the semantics of the comparison.
+
+        Args:
+            other (object): The object to compare with.
+
+        Returns:
+            bool: True if `other` is a GraphRecord with the same content.
+        """
+        if not isinstance(other, GraphRecord):
+            return NotImplemented
+
+        return self.equals(other)
+
+    def contains_node(self, node: NodeIndex) -> bool:
+        """Checks whether a specific node exists in the GraphRecord.
+
+        Args:
+            node (NodeIndex): The index of the node to check.
+
+        Returns:
+            bool: True if the node exists, False otherwise.
+        """
+        return self._graphrecord.contains_node(node)
+
+    def contains_edge(self, edge: EdgeIndex) -> bool:
+        """Checks whether a specific edge exists in the GraphRecord.
+
+        Args:
+            edge (EdgeIndex): The index of the edge to check.
+
+        Returns:
+            bool: True if the edge exists, False otherwise.
+        """
+        return self._graphrecord.contains_edge(edge)
+
+    def contains_group(self, group: Group) -> bool:
+        """Checks whether a specific group exists in the GraphRecord.
+
+        Args:
+            group (Group): The name of the group to check.
+
+        Returns:
+            bool: True if the group exists, False otherwise.
+        """
+        return self._graphrecord.contains_group(group)
+
+    def group_metadata(self, group: Group) -> Attributes:
+        """Returns the metadata of a group, e.g. its description or color.
+
+        Args:
+            group (Group): The name of the group.
+
+        Returns:
+            Attributes: The metadata of the group.
+
+        Raises:
+            IndexError: If the group does not exist.
+        """
+        return self._graphrecord.group_metadata(group)
+
+    def set_group_metadata(
+        self, group: Group, key: GraphRecordAttribute, value: GraphRecordValue
+    ) -> None:
+        """Sets a metadata entry of a group.
+
+        Metadata describes the group itself and is removed along with it.
+
+        Args:
+            group (Group): The name of the group.
+            key (GraphRecordAttribute): The metadata key.
+            value (GraphRecordValue): The metadata value.
+
+        Raises:
+            IndexError: If the group does not exist.
+        """
+        self._graphrecord.set_group_metadata(group, key, value)
+
+    def remove_group_metadata(
+        self, group: Group, key: GraphRecordAttribute
+    ) -> GraphRecordValue:
+        """Removes a metadata entry of a group.
+
+        Args:
+            group (Group): The name of the group.
+            key (GraphRecordAttribute): The metadata key.
+
+        Returns:
+            GraphRecordValue: The removed value.
+
+        Raises:
+            IndexError: If the group does not exist.
+            KeyError: If the group has no metadata entry for the key.
+        """
+        return self._graphrecord.remove_group_metadata(group, key)
+
+    @overload
+    def neighbors(
+        self,
+        node: Union[NodeIndex, NodeIndexQuery],
+        directed: EdgesDirection = EdgesDirection.OUTGOING,
+    ) -> List[NodeIndex]: ...
+
+    @overload
+    def neighbors(
+        self,
+        node: Union[NodeIndexInputList, NodeIndicesQuery],
+        directed: EdgesDirection = EdgesDirection.OUTGOING,
+    ) -> Dict[NodeIndex, List[NodeIndex]]: ...
+
+    def neighbors(
+        self,
+        node: Union[NodeIndex, NodeIndexInputList, NodeIndexQuery, NodeIndicesQuery],
+        directed: EdgesDirection = EdgesDirection.OUTGOING,
+    ) -> Union[List[NodeIndex], Dict[NodeIndex, List[NodeIndex]]]:
+        """Retrieves the neighbors of the specified node(s) in the GraphRecord.
+
+        If a single node index is provided, returns a list of its neighboring
+        node indices. If multiple nodes are specified, returns a dictionary mapping
+        each node index to its list of neighboring nodes.
+
+        Args:
+            node (Union[NodeIndex, NodeIndexInputList, NodeIndexQuery, NodeIndicesQuery]):
+                One or more node indices or a query that returns node indices.
+            directed (EdgesDirection, optional): The direction to traverse edges.
+                Defaults to EdgesDirection.OUTGOING.
+
+        Returns:
+            Union[List[NodeIndex], Dict[NodeIndex, List[NodeIndex]]]: Neighboring nodes.
+        """  # noqa: W505
+        if isinstance(node, Callable):
+            query_result = self.query_nodes(node)
+
+            if query_result is None:
+                return []
+
+            node = query_result
+
+        node_indices = node if isinstance(node, list) else [node]
+
+        if directed == EdgesDirection.OUTGOING:
+            neighbors = self._graphrecord.neighbors_outgoing(node_indices)
+        elif directed == EdgesDirection.INCOMING:
+            neighbors = self._graphrecord.neighbors_incoming(node_indices)
+        else:
+            neighbors = self._graphrecord.neighbors_undirected(node_indices)
+
+        if isinstance(node, list):
+            return neighbors
+
+        return neighbors[node]
+
+    @overload
+    def neighbors_filtered(
+        self,
+        node: Union[NodeIndex, NodeIndexQuery],
+        directed: EdgesDirection = EdgesDirection.OUTGOING,
+        edge_group: Optional[Group] = None,
+        edge_predicate: Optional[EdgeIndicesQuery] = None,
+    ) -> List[NodeIndex]: ...
+
+    @overload
+    def neighbors_filtered(
+        self,
+        node: Union[NodeIndexInputList, NodeIndicesQuery],
+        directed: EdgesDirection = EdgesDirection.OUTGOING,
+        edge_group: Optional[Group] = None,
+        edge_predicate: Optional[EdgeIndicesQuery] = None,
+    ) -> Dict[NodeIndex, List[NodeIndex]]: ...
+
+    def neighbors_filtered(
+        self,
+        node: Union[NodeIndex, NodeIndexInputList, NodeIndexQuery, NodeIndicesQuery],
+        directed: EdgesDirection = EdgesDirection.OUTGOING,
+        edge_group: Optional[Group] = None,
+        edge_predicate: Optional[EdgeIndicesQuery] = None,
+    ) -> Union[List[NodeIndex], Dict[NodeIndex, List[NodeIndex]]]:
+        """Retrieves the neighbors of the node(s) reachable via matching edges.
+
+        Like `neighbors`, but only edges in `edge_group` and matched by
+        `edge_predicate` are traversed, so the edges and their endpoints do not
+        have to be looked up separately.
+
+        Args:
+            node (Union[NodeIndex, NodeIndexInputList, NodeIndexQuery, NodeIndicesQuery]):
+                One or more node indices or a query that returns node indices.
+            directed (EdgesDirection, optional): The direction to traverse edges.
+                Defaults to EdgesDirection.OUTGOING.
+            edge_group (Optional[Group], optional): The group the traversed edges
+                must belong to. Defaults to None.
+            edge_predicate (Optional[EdgeIndicesQuery], optional): A query the
+                traversed edges must match. Defaults to None.
+
+        Returns:
+            Union[List[NodeIndex], Dict[NodeIndex, List[NodeIndex]]]: Neighboring nodes.
+
+        Raises:
+            IndexError: If the edge group does not exist.
+        """  # noqa: W505
+        if isinstance(node, Callable):
+            query_result = self.query_nodes(node)
+
+            if query_result is None:
+                return []
+
+            node = query_result
+
+        node_indices = node if isinstance(node, list) else [node]
+
+        edge_indices = (
+            self.query_edges(edge_predicate) if edge_predicate is not None else None
+        )
+
+        direction = (
+            PyEdgeDirection.Outgoing
+            if directed == EdgesDirection.OUTGOING
+            else PyEdgeDirection.Incoming
+            if directed == EdgesDirection.INCOMING
+            else PyEdgeDirection.Both
+        )
+
+        neighbors = self._graphrecord.neighbors_filtered(
+            node_indices, direction, edge_group, edge_indices
+        )
+
+        if isinstance(node, list):
+            return neighbors
+
+        return neighbors[node]
+
+    def clear(self, *, bypass_plugins: bool = False) -> None:
+        """Clears all data from the GraphRecord instance.
+
+        Removes all nodes, edges, and groups, effectively resetting the instance.
+
+        Args:
+            bypass_plugins (bool): If True, plugin hooks are not called.
+                Defaults to False.
+        """
+        self._graphrecord.clear(bypass_plugins)
+
+    @overload
+    def query_nodes(
+        self, query: Callable[[NodeOperand], NodeAttributesTreeOperand]
+    ) -> NodeAttributesTreeQueryResult: ...
+    @overload
+    def query_nodes(
+        self, query: Callable[[NodeOperand], NodeAttributesTreeGroupOperand]
+    ) -> NodeAttributesTreeGroupQueryResult: ...
+    @overload
+    def query_nodes(
+        self, query: Callable[[NodeOperand], EdgeAttributesTreeOperand]
+    ) -> EdgeAttributesTreeQueryResult: ...
+    @overload
+    def query_nodes(
+        self, query: Callable[[NodeOperand], EdgeAttributesTreeGroupOperand]
+    ) -> EdgeAttributesTreeGroupQueryResult: ...
+    @overload
+    def query_nodes(
+        self, query: Callable[[NodeOperand], NodeMultipleAttributesWithIndexOperand]
+    ) -> NodeMultipleAttributesWithIndexQueryResult: ...
+    @overload
+    def query_nodes(
+        self,
+        query: Callable[[NodeOperand], NodeMultipleAttributesWithIndexGroupOperand],
     ) -> NodeMultipleAttributesWithIndexGroupQueryResult: ...
     @overload
     def query_nodes(
@@ -1925,6 +3640,30 @@ class GraphRecord:
         self, query: Callable[[NodeOperand], EdgeSingleValueWithoutIndexGroupOperand]
     ) -> EdgeSingleValueWithoutIndexGroupQueryResult: ...
     @overload
+    def query_nodes(
+        self, query: Callable[[NodeOperand], NodeValueCountsOperand]
+    ) -> NodeValueCountsQueryResult: ...
+    @overload
+    def query_nodes(
+        self, query: Callable[[NodeOperand], EdgeValueCountsOperand]
+    ) -> EdgeValueCountsQueryResult: ...
+    @overload
+    def query_nodes(
+        self, query: Callable[[NodeOperand], NodeHistogramOperand]
+    ) -> NodeHistogramQueryResult: ...
+    @overload
+    def query_nodes(
+        self, query: Callable[[NodeOperand], EdgeHistogramOperand]
+    ) -> EdgeHistogramQueryResult: ...
+    @overload
+    def query_nodes(
+        self, query: Callable[[NodeOperand], NodeQuantilesOperand]
+    ) -> NodeQuantilesQueryResult: ...
+    @overload
+    def query_nodes(
+        self, query: Callable[[NodeOperand], EdgeQuantilesOperand]
+    ) -> EdgeQuantilesQueryResult: ...
+    @overload
     def query_nodes(
         self, query: Callable[[NodeOperand], Sequence[QueryReturnOperand]]
     ) -> List[QueryResult]: ...
@@ -2114,6 +3853,30 @@ class GraphRecord:
         self, query: Callable[[EdgeOperand], EdgeSingleValueWithoutIndexGroupOperand]
     ) -> EdgeSingleValueWithoutIndexGroupQueryResult: ...
     @overload
+    def query_edges(
+        self, query: Callable[[EdgeOperand], NodeValueCountsOperand]
+    ) -> NodeValueCountsQueryResult: ...
+    @overload
+    def query_edges(
+        self, query: Callable[[EdgeOperand], EdgeValueCountsOperand]
+    ) -> EdgeValueCountsQueryResult: ...
+    @overload
+    def query_edges(
+        self, query: Callable[[EdgeOperand], NodeHistogramOperand]
+    ) -> NodeHistogramQueryResult: ...
+    @overload
+    def query_edges(
+        self, query: Callable[[EdgeOperand], EdgeHistogramOperand]
+    ) -> EdgeHistogramQueryResult: ...
+    @overload
+    def query_edges(
+        self, query: Callable[[EdgeOperand], NodeQuantilesOperand]
+    ) -> NodeQuantilesQueryResult: ...
+    @overload
+    def query_edges(
+        self, query: Callable[[EdgeOperand], EdgeQuantilesOperand]
+    ) -> EdgeQuantilesQueryResult: ...
+    @overload
     def query_edges(
         self, query: Callable[[EdgeOperand], Sequence[QueryReturnOperand]]
     ) -> List[QueryResult]: ...
@@ -2150,7 +3913,10 @@ class GraphRecord:
         return graphrecord
 
     def overview(
-        self, truncate_details: Optional[int] = DEFAULT_TRUNCATE_DETAILS
+        self,
+        truncate_details: Optional[int] = DEFAULT_TRUNCATE_DETAILS,
+        overview_format: Optional[OverviewFormat] = None,
+        categorical_threshold: Optional[int] = None,
     ) -> Overview:
         """Generates an overview of the GraphRecord instance.
 
@@ -2158,16 +3924,77 @@ class GraphRecord:
             truncate_details (int, optional): The maximum number of detail characters
                 to include in the overview. No truncation if None.
                 Defaults to DEFAULT_TRUNCATE_DETAILS.
+            overview_format (Optional[OverviewFormat], optional): The format to
+                render the overview in. Uses `GraphRecord.repr_format` if None.
+                Defaults to None.
+            categorical_threshold (Optional[int], optional): The maximum number of
+                distinct values listed for a categorical attribute. Attributes with
+                more distinct values only list their most frequent values and the
+                count of all other values. No limit if None. Defaults to None.
 
         Returns:
             Overview: An overview of the GraphRecord instance.
         """
+        overview_format = overview_format or self.repr_format
+
         return Overview._from_py_overview(
-            self._graphrecord.overview(truncate_details)
+            self._graphrecord.overview(
+                truncate_details,
+                overview_format._into_py_overview_format(),
+                categorical_threshold,
+            )
         )  # pragma: no cover
 
+    def overview_sampled(
+        self,
+        sample_size: int,
+        seed: int = 0,
+        truncate_details: Optional[int] = DEFAULT_TRUNCATE_DETAILS,
+        overview_format: Optional[OverviewFormat] = None,
+        categorical_threshold: Optional[int] = None,
+    ) -> Overview:
+        """Generates an approximate overview of the GraphRecord instance.
+
+        Node and edge counts are exact, but attribute statistics of every group are
+        estimated from a reproducible sample of at most `sample_size` nodes and
+        edges. The rendered overview is labeled as approximate.
+
+        Args:
+            sample_size (int): The maximum number of nodes and edges to sample per
+                group.
+            seed (int, optional): The seed used for sampling. Defaults to 0.
+            truncate_details (int, optional): The maximum number of detail characters
+                to include in the overview. No truncation if None.
+                Defaults to DEFAULT_TRUNCATE_DETAILS.
+            overview_format (Optional[OverviewFormat], optional): The format to
+                render the overview in. Uses `GraphRecord.repr_format` if None.
+                Defaults to None.
+            categorical_threshold (Optional[int], optional): The maximum number of
+                distinct values listed for a categorical attribute. Attributes with
+                more distinct values only list their most frequent values and the
+                count of all other values. No limit if None. Defaults to None.
+
+        Returns:
+            Overview: An approximate overview of the GraphRecord instance.
+        """
+        overview_format = overview_format or self.repr_format
+
+        return Overview._from_py_overview(
+            self._graphrecord.overview_sampled(
+                sample_size,
+                seed,
+                truncate_details,
+                overview_format._into_py_overview_format(),
+                categorical_threshold,
+            )
+        )
+
     def group_overview(
-        self, group: Group, truncate_details: Optional[int] = DEFAULT_TRUNCATE_DETAILS
+        self,
+        group: Group,
+        truncate_details: Optional[int] = DEFAULT_TRUNCATE_DETAILS,
+        overview_format: Optional[OverviewFormat] = None,
+        categorical_threshold: Optional[int] = None,
     ) -> GroupOverview:
         """Generates an overview of a specific group in the GraphRecord instance.
 
@@ -2176,18 +4003,45 @@ class GraphRecord:
             truncate_details (int, optional): The maximum number of detail characters
                 to include in the overview. No truncation if None.
                 Defaults to DEFAULT_TRUNCATE_DETAILS.
+            overview_format (Optional[OverviewFormat], optional): The format to
+                render the overview in. Uses `GraphRecord.repr_format` if None.
+                Defaults to None.
+            categorical_threshold (Optional[int], optional): The maximum number of
+                distinct values listed for a categorical attribute. Attributes with
+                more distinct values only list their most frequent values and the
+                count of all other values. No limit if None. Defaults to None.
 
         Returns:
             GroupOverview: An overview of the specified group.
         """
+        overview_format = overview_format or self.repr_format
+
         return GroupOverview._from_py_group_overview(  # pragma: no cover
-            self._graphrecord.group_overview(group, truncate_details)
+            self._graphrecord.group_overview(
+                group,
+                truncate_details,
+                overview_format._into_py_overview_format(),
+                categorical_threshold,
+            )
         )
 
     def __repr__(self) -> str:
         """Returns a string representation of the GraphRecord instance.
 
+        The format of the representation can be configured through
+        `GraphRecord.repr_format`.
+
         Returns:
             str: A string representation of the GraphRecord instance.
         """
         return self.overview().__repr__()  # pragma: no cover
+
+    def _repr_html_(self) -> str:
+        """Returns an HTML representation of the GraphRecord instance.
+
+        Used by Jupyter to render the overview of the GraphRecord as a table.
+
+        Returns:
+            str: An HTML representation of the GraphRecord instance.
+        """
+        return self._graphrecord._repr_html_()
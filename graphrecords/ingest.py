@@ -0,0 +1,128 @@
+"""Concurrent ingestion buffer for the graphrecords library."""
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING, Optional, Sequence
+
+from graphrecords._graphrecords.graphrecord import PyShardedIngest
+
+if TYPE_CHECKING:
+    from graphrecords.graphrecord import GraphRecord
+    from graphrecords.types import (
+        AttributesInput,
+        EdgeTuple,
+        Group,
+        NodeIndex,
+        NodeTuple,
+    )
+
+
+class ShardedIngest:
+    """Thread-safe staging buffer for adding nodes and edges to a GraphRecord.
+
+    Nodes and edges are buffered in independently locked shards, keyed by group,
+    so multiple threads can add data at the same time. The buffered data is
+    applied to a GraphRecord with `commit`.
+    """
+
+    _sharded_ingest: PyShardedIngest
+
+    def __init__(self, shard_count: Optional[int] = None) -> None:
+        """Creates a new, empty ingestion buffer.
+
+        Args:
+            shard_count (Optional[int]): The number of shards to buffer into.
+                Defaults to None, which uses the library default.
+        """
+        self._sharded_ingest = PyShardedIngest(shard_count)
+
+    @property
+    def shard_count(self) -> int:
+        """The number of shards of the buffer.
+
+        Returns:
+            int: The number of shards.
+        """
+        return self._sharded_ingest.shard_count
+
+    def add_node(
+        self,
+        node_index: NodeIndex,
+        attributes: AttributesInput,
+        group: Optional[Group] = None,
+    ) -> None:
+        """Buffers a node to be added on the next commit.
+
+        Args:
+            node_index (NodeIndex): The index of the node.
+            attributes (AttributesInput): The attributes of the node.
+            group (Optional[Group]): The group to add the node to. Defaults to None.
+        """
+        self._sharded_ingest.add_node(node_index, attributes, group)
+
+    def add_nodes(
+        self, nodes: Sequence[NodeTuple], group: Optional[Group] = None
+    ) -> None:
+        """Buffers multiple nodes to be added on the next commit.
+
+        Args:
+            nodes (Sequence[NodeTuple]): The node tuples to buffer.
+            group (Optional[Group]): The group to add the nodes to. Defaults to None.
+        """
+        self._sharded_ingest.add_nodes(nodes, group)
+
+    def add_edge(
+        self,
+        source_node_index: NodeIndex,
+        target_node_index: NodeIndex,
+        attributes: AttributesInput,
+        group: Optional[Group] = None,
+    ) -> None:
+        """Buffers an edge to be added on the next commit.
+
+        Args:
+            source_node_index (NodeIndex): The index of the source node.
+            target_node_index (NodeIndex): The index of the target node.
+            attributes (AttributesInput): The attributes of the edge.
+            group (Optional[Group]): The group to add the edge to. Defaults to None.
+        """
+        self._sharded_ingest.add_edge(
+            source_node_index, target_node_index, attributes, group
+        )
+
+    def add_edges(
+        self, edges: Sequence[EdgeTuple], group: Optional[Group] = None
+    ) -> None:
+        """Buffers multiple edges to be added on the next commit.
+
+        Args:
+            edges (Sequence[EdgeTuple]): The edge tuples to buffer.
+            group (Optional[Group]): The group to add the edges to. Defaults to None.
+        """
+        self._sharded_ingest.add_edges(edges, group)
+
+    def pending_node_count(self) -> int:
+        """Returns the number of buffered nodes.
+
+        Returns:
+            int: The number of nodes waiting to be committed.
+        """
+        return self._sharded_ingest.pending_node_count()
+
+    def pending_edge_count(self) -> int:
+        """Returns the number of buffered edges.
+
+        Returns:
+            int: The number of edges waiting to be committed.
+        """
+        return self._sharded_ingest.pending_edge_count()
+
+    def commit(self, graphrecord: GraphRecord) -> None:
+        """Adds all buffered nodes and edges to the GraphRecord.
+
+        All nodes are added before any edge. The buffer is empty afterwards.
+
+        Args:
+            graphrecord (GraphRecord): The GraphRecord to add the data to.
+        """
+        self._sharded_ingest.commit(graphrecord._graphrecord)
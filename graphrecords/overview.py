@@ -1,5 +1,6 @@
 """Overview functions and classes for the graphrecords library."""
 
+from enum import Enum, auto
 from typing import TYPE_CHECKING, Dict, Final, Union
 
 from graphrecords._graphrecords.overview import (
@@ -8,6 +9,7 @@ from graphrecords._graphrecords.overview import (
     PyGroupOverview,
     PyNodeGroupOverview,
     PyOverview,
+    PyOverviewFormat,
 )
 from graphrecords._graphrecords.schema import PyAttributeType
 from graphrecords.datatype import DataType
@@ -17,8 +19,10 @@ from graphrecords.types import GraphRecordAttribute
 if TYPE_CHECKING:
     from graphrecords._graphrecords.overview import PyAttributeOverview
     from graphrecords.types import (
+        Attributes,
         CategoricalAttributeOverview,
         ContinuousAttributeOverview,
+        StructureOverview,
         TemporalAttributeOverview,
         UnstructuredAttributeOverview,
     )
@@ -26,6 +30,33 @@ if TYPE_CHECKING:
 DEFAULT_TRUNCATE_DETAILS: Final[int] = PY_DEFAULT_TRUNCATE_DETAILS
 
 
+class OverviewFormat(Enum):
+    """Enumeration of the formats an overview can be rendered in.
+
+    `Modern` and `Ascii` render box-drawn tables, while `Markdown` and `Csv`
+    produce output that can be pasted into reports and spreadsheets.
+    """
+
+    Modern = auto()
+    Ascii = auto()
+    Markdown = auto()
+    Csv = auto()
+
+    def _into_py_overview_format(self) -> PyOverviewFormat:
+        """Converts the OverviewFormat to a PyOverviewFormat.
+
+        Returns:
+            PyOverviewFormat: The converted PyOverviewFormat.
+        """
+        if self == OverviewFormat.Modern:
+            return PyOverviewFormat.Modern
+        if self == OverviewFormat.Ascii:
+            return PyOverviewFormat.Ascii
+        if self == OverviewFormat.Markdown:
+            return PyOverviewFormat.Markdown
+        return PyOverviewFormat.Csv
+
+
 class AttributeOverview:
     """Overview data of an attribute."""
 
@@ -83,6 +114,8 @@ class AttributeOverview:
             return {
                 "attribute_type": AttributeType.Categorical,
                 "distinct_values": self._py_attribute_overview.data["distinct_values"],
+                "value_counts": self._py_attribute_overview.data["value_counts"],
+                "other_count": self._py_attribute_overview.data["other_count"],
             }
 
         if (
@@ -262,6 +295,29 @@ class GroupOverview:
             self._py_group_overview.edge_overview
         )
 
+    @property
+    def metadata(self) -> "Attributes":
+        """The metadata of the group.
+
+        Returns:
+            Attributes: The metadata of the group, empty for ungrouped nodes
+                and edges.
+        """
+        return self._py_group_overview.metadata
+
+    @property
+    def structure(self) -> "StructureOverview":
+        """The connectivity of the nodes of the group.
+
+        Contains the number of ungrouped nodes sharing an edge with the nodes of
+        the group, the number of nodes without edges, the average degree and the
+        most connected other groups with their number of shared edges.
+
+        Returns:
+            StructureOverview: The connectivity of the nodes of the group.
+        """
+        return self._py_group_overview.structure
+
     def __repr__(self) -> str:
         """Return the string representation of the GroupOverview.
 
@@ -270,6 +326,14 @@ class GroupOverview:
         """
         return self._py_group_overview.__repr__()
 
+    def _repr_html_(self) -> str:
+        """Return the HTML representation of the GroupOverview.
+
+        Returns:
+            str: The HTML representation of the GroupOverview.
+        """
+        return self._py_group_overview._repr_html_()
+
 
 class Overview:
     """Overview functions for the graphrecords library."""
@@ -314,6 +378,15 @@ class Overview:
             for attribute, py_overview in self._py_overview.grouped_overviews.items()
         }
 
+    @property
+    def is_approximate(self) -> bool:
+        """Whether the statistics were estimated from a sample.
+
+        Returns:
+            bool: True if the overview was created with `overview_sampled`.
+        """
+        return self._py_overview.is_approximate
+
     def __repr__(self) -> str:
         """Return the string representation of the Overview.
 
@@ -321,3 +394,14 @@ class Overview:
             str: The string representation of the Overview.
         """
         return self._py_overview.__repr__()
+
+    def _repr_html_(self) -> str:
+        """Return the HTML representation of the Overview.
+
+        Used by Jupyter to render the overview as a table with one section per
+        group.
+
+        Returns:
+            str: The HTML representation of the Overview.
+        """
+        return self._py_overview._repr_html_()
@@ -2,8 +2,9 @@
 
 from __future__ import annotations
 
-from typing import TYPE_CHECKING, List, Optional, Tuple
+from typing import TYPE_CHECKING, Dict, List, Optional, Set, Tuple, Union
 
+from graphrecords._graphrecords.plugins import PyMappingPlugin
 from graphrecords.types import _PyPlugin
 
 if TYPE_CHECKING:
@@ -43,6 +44,7 @@ if TYPE_CHECKING:
         PyPostRemoveNodeFromGroupContext,
         PyPostRemoveNodeFromGroupsContext,
         PyPostRemoveNodesFromGroupsContext,
+        PyPostSetSchemaContext,
         PyPreAddEdgeContext,
         PyPreAddEdgesContext,
         PyPreAddEdgesDataframesContext,
@@ -80,10 +82,12 @@ if TYPE_CHECKING:
         PyPreSetSchemaContext,
     )
     from graphrecords.graphrecord import GraphRecord
-    from graphrecords.schema import Schema
+    from graphrecords.schema import Schema, SchemaDiff
     from graphrecords.types import (
         Attributes,
         EdgeIndex,
+        GraphRecordAttribute,
+        GraphRecordValue,
         Group,
         NodeIndex,
         PolarsEdgeDataFrameInput,
@@ -116,8 +120,13 @@ class _PluginBridge(_PyPlugin):  # pyright: ignore[reportUnusedClass]
             PreSetSchemaContext._from_py_pre_set_schema_context(context),
         )._py_pre_set_schema_context
 
-    def post_set_schema(self, graphrecord: PyGraphRecord) -> None:
-        self._plugin.post_set_schema(self._graphrecord(graphrecord))
+    def post_set_schema(
+        self, graphrecord: PyGraphRecord, context: PyPostSetSchemaContext
+    ) -> None:
+        self._plugin.post_set_schema(
+            self._graphrecord(graphrecord),
+            PostSetSchemaContext._from_py_context(context),
+        )
 
     def pre_freeze_schema(self, graphrecord: PyGraphRecord) -> None:
         self._plugin.pre_freeze_schema(self._graphrecord(graphrecord))
@@ -729,6 +738,54 @@ class PreSetSchemaContext:
         return Schema._from_py_schema(self._py_pre_set_schema_context.schema)
 
 
+class PostSetSchemaContext:
+    """Context for the post_set_schema hook."""
+
+    _py_context: PyPostSetSchemaContext
+
+    def __init__(self, old_schema: Schema, new_schema: Schema) -> None:
+        """Initializes a PostSetSchemaContext.
+
+        Args:
+            old_schema (Schema): The schema that was replaced.
+            new_schema (Schema): The schema that was set.
+        """
+        from graphrecords._graphrecords.plugins import PyPostSetSchemaContext
+
+        self._py_context = PyPostSetSchemaContext(
+            old_schema._schema, new_schema._schema
+        )
+
+    @classmethod
+    def _from_py_context(
+        cls, py_context: PyPostSetSchemaContext
+    ) -> PostSetSchemaContext:
+        context = cls.__new__(cls)
+        context._py_context = py_context
+        return context
+
+    @property
+    def old_schema(self) -> Schema:
+        """The schema that was replaced."""
+        from graphrecords.schema import Schema
+
+        return Schema._from_py_schema(self._py_context.old_schema)
+
+    @property
+    def new_schema(self) -> Schema:
+        """The schema that was set."""
+        from graphrecords.schema import Schema
+
+        return Schema._from_py_schema(self._py_context.new_schema)
+
+    @property
+    def changes(self) -> SchemaDiff:
+        """The groups and attributes that differ between the old and new schema."""
+        from graphrecords.schema import _from_py_schema_diff
+
+        return _from_py_schema_diff(*self._py_context.changes)
+
+
 class PreAddNodeContext:
     """Context for the pre_add_node hook."""
 
@@ -3214,11 +3271,14 @@ class Plugin:
         """
         return context
 
-    def post_set_schema(self, graphrecord: GraphRecord) -> None:
+    def post_set_schema(
+        self, graphrecord: GraphRecord, context: PostSetSchemaContext
+    ) -> None:
         """Called after setting the schema.
 
         Args:
             graphrecord (GraphRecord): The GraphRecord instance.
+            context (PostSetSchemaContext): The operation context.
         """
         pass
 
@@ -4135,3 +4195,88 @@ class Plugin:
             graphrecord (GraphRecord): The GraphRecord instance.
         """
         pass
+
+
+class MappingPlugin:
+    """Built-in plugin that replaces attribute values with dictionary entries.
+
+    Applies a dictionary of values per attribute, e.g. from local lab codes to
+    LOINC codes, to the nodes and edges while they are added, so normalization
+    happens during ingestion. Values without an entry in the dictionary of their
+    attribute are kept as they are and reported by `unmapped_node_values` and
+    `unmapped_edge_values`. None values are neither mapped nor reported.
+
+    The plugin runs natively and is passed to `GraphRecord.add_plugin` like any
+    other plugin.
+    """
+
+    _mapping_plugin: PyMappingPlugin
+
+    def __init__(
+        self,
+        node_mappings: Optional[
+            Dict[GraphRecordAttribute, Dict[GraphRecordValue, GraphRecordValue]]
+        ] = None,
+        edge_mappings: Optional[
+            Dict[GraphRecordAttribute, Dict[GraphRecordValue, GraphRecordValue]]
+        ] = None,
+    ) -> None:
+        """Creates a mapping plugin from the dictionaries per attribute.
+
+        Args:
+            node_mappings (Optional[Dict[GraphRecordAttribute, Dict[GraphRecordValue, GraphRecordValue]]]):
+                The dictionaries to apply to node attributes. Defaults to None.
+            edge_mappings (Optional[Dict[GraphRecordAttribute, Dict[GraphRecordValue, GraphRecordValue]]]):
+                The dictionaries to apply to edge attributes. Defaults to None.
+        """  # noqa: W505
+        self._mapping_plugin = PyMappingPlugin(
+            {
+                attribute: list(mapping.items())
+                for attribute, mapping in (node_mappings or {}).items()
+            },
+            {
+                attribute: list(mapping.items())
+                for attribute, mapping in (edge_mappings or {}).items()
+            },
+        )
+
+    def unmapped_node_values(
+        self,
+    ) -> Dict[GraphRecordAttribute, Set[GraphRecordValue]]:
+        """Returns the node attribute values that had no dictionary entry.
+
+        Returns:
+            Dict[GraphRecordAttribute, Set[GraphRecordValue]]: The values per
+                attribute seen since the plugin was created or last cleared.
+        """
+        return {
+            attribute: set(values)
+            for attribute, values in self._mapping_plugin.unmapped_node_values().items()
+        }
+
+    def unmapped_edge_values(
+        self,
+    ) -> Dict[GraphRecordAttribute, Set[GraphRecordValue]]:
+        """Returns the edge attribute values that had no dictionary entry.
+
+        Returns:
+            Dict[GraphRecordAttribute, Set[GraphRecordValue]]: The values per
+                attribute seen since the plugin was created or last cleared.
+        """
+        return {
+            attribute: set(values)
+            for attribute, values in self._mapping_plugin.unmapped_edge_values().items()
+        }
+
+    def clear_unmapped_values(self) -> None:
+        """Forgets the unmapped values reported so far."""
+        self._mapping_plugin.clear_unmapped_values()
+
+
+def _into_py_plugin(
+    plugin: Union[Plugin, MappingPlugin],
+) -> Union[_PluginBridge, PyMappingPlugin]:
+    if isinstance(plugin, MappingPlugin):
+        return plugin._mapping_plugin
+
+    return _PluginBridge(plugin)
@@ -4,6 +4,7 @@
 
 from __future__ import annotations
 
+from datetime import timedelta
 from enum import Enum
 from typing import Callable, Dict, List, Optional, Sequence, Tuple, TypeAlias, Union
 
@@ -14,6 +15,7 @@ from graphrecords._graphrecords.querying import (
     PyEdgeAttributesTreeOperand,
     PyEdgeDirection,
     PyEdgeGroupOperand,
+    PyEdgeHistogramOperand,
     PyEdgeIndexGroupOperand,
     PyEdgeIndexOperand,
     PyEdgeIndicesGroupOperand,
@@ -25,6 +27,7 @@ from graphrecords._graphrecords.querying import (
     PyEdgeMultipleValuesWithIndexOperand,
     PyEdgeMultipleValuesWithoutIndexOperand,
     PyEdgeOperand,
+    PyEdgeQuantilesOperand,
     PyEdgeSingleAttributeWithIndexGroupOperand,
     PyEdgeSingleAttributeWithIndexOperand,
     PyEdgeSingleAttributeWithoutIndexGroupOperand,
@@ -33,10 +36,12 @@ from graphrecords._graphrecords.querying import (
     PyEdgeSingleValueWithIndexOperand,
     PyEdgeSingleValueWithoutIndexGroupOperand,
     PyEdgeSingleValueWithoutIndexOperand,
+    PyEdgeValueCountsOperand,
     PyMatchMode,
     PyNodeAttributesTreeGroupOperand,
     PyNodeAttributesTreeOperand,
     PyNodeGroupOperand,
+    PyNodeHistogramOperand,
     PyNodeIndexGroupOperand,
     PyNodeIndexOperand,
     PyNodeIndicesGroupOperand,
@@ -48,6 +53,7 @@ from graphrecords._graphrecords.querying import (
     PyNodeMultipleValuesWithIndexOperand,
     PyNodeMultipleValuesWithoutIndexOperand,
     PyNodeOperand,
+    PyNodeQuantilesOperand,
     PyNodeSingleAttributeWithIndexGroupOperand,
     PyNodeSingleAttributeWithIndexOperand,
     PyNodeSingleAttributeWithoutIndexGroupOperand,
@@ -56,6 +62,8 @@ from graphrecords._graphrecords.querying import (
     PyNodeSingleValueWithIndexOperand,
     PyNodeSingleValueWithoutIndexGroupOperand,
     PyNodeSingleValueWithoutIndexOperand,
+    PyNodeValueCountsOperand,
+    PyRollingAggregation,
 )
 from graphrecords.types import (
     EdgeIndex,
@@ -68,6 +76,9 @@ from graphrecords.types import (
 GroupKey: TypeAlias = Union[
     NodeIndex, GraphRecordValue, Tuple["GroupKey", "GroupKey"], None
 ]
+GroupAggregate: TypeAlias = Union[
+    "NodeSingleValueWithoutIndexGroupOperand", "EdgeSingleValueWithoutIndexGroupOperand"
+]
 
 
 PyQueryReturnOperand: TypeAlias = Union[
@@ -111,6 +122,12 @@ PyQueryReturnOperand: TypeAlias = Union[
     PyEdgeSingleValueWithIndexGroupOperand,
     PyEdgeSingleValueWithoutIndexOperand,
     PyEdgeSingleValueWithoutIndexGroupOperand,
+    PyNodeValueCountsOperand,
+    PyEdgeValueCountsOperand,
+    PyNodeHistogramOperand,
+    PyEdgeHistogramOperand,
+    PyNodeQuantilesOperand,
+    PyEdgeQuantilesOperand,
     Sequence["PyQueryReturnOperand"],
 ]
 
@@ -156,6 +173,12 @@ QueryReturnOperand: TypeAlias = Union[
     "EdgeSingleValueWithIndexGroupOperand",
     "EdgeSingleValueWithoutIndexOperand",
     "EdgeSingleValueWithoutIndexGroupOperand",
+    "NodeValueCountsOperand",
+    "EdgeValueCountsOperand",
+    "NodeHistogramOperand",
+    "EdgeHistogramOperand",
+    "NodeQuantilesOperand",
+    "EdgeQuantilesOperand",
     Sequence["QueryReturnOperand"],
 ]
 
@@ -244,6 +267,15 @@ EdgeSingleValueWithoutIndexGroupQueryResult: TypeAlias = List[
     Tuple[GroupKey, EdgeSingleValueWithoutIndexQueryResult]
 ]
 
+NodeValueCountsQueryResult: TypeAlias = List[Tuple[GraphRecordValue, int]]
+EdgeValueCountsQueryResult: TypeAlias = List[Tuple[GraphRecordValue, int]]
+
+NodeHistogramQueryResult: TypeAlias = List[Tuple[float, float, int]]
+EdgeHistogramQueryResult: TypeAlias = List[Tuple[float, float, int]]
+
+NodeQuantilesQueryResult: TypeAlias = List[Tuple[float, float]]
+EdgeQuantilesQueryResult: TypeAlias = List[Tuple[float, float]]
+
 #: A type alias for a query result.
 QueryResult: TypeAlias = Union[
     NodeAttributesTreeQueryResult,
@@ -285,6 +317,12 @@ QueryResult: TypeAlias = Union[
     EdgeSingleValueWithIndexGroupQueryResult,
     EdgeSingleValueWithoutIndexQueryResult,
     EdgeSingleValueWithoutIndexGroupQueryResult,
+    NodeValueCountsQueryResult,
+    EdgeValueCountsQueryResult,
+    NodeHistogramQueryResult,
+    EdgeHistogramQueryResult,
+    NodeQuantilesQueryResult,
+    EdgeQuantilesQueryResult,
     List["QueryResult"],
 ]
 
@@ -314,16 +352,21 @@ MultipleValuesComparisonOperand: TypeAlias = Union[
     "EdgeMultipleValuesWithoutIndexOperand",
 ]
 
-
-def _py_single_value_comparison_operand_from_single_value_comparison_operand(
-    single_value_comparison_operand: SingleValueComparisonOperand,
-) -> Union[
+PySingleValueComparisonOperand: TypeAlias = Union[
     GraphRecordValue,
     PyNodeSingleValueWithIndexOperand,
     PyNodeSingleValueWithoutIndexOperand,
     PyEdgeSingleValueWithIndexOperand,
     PyEdgeSingleValueWithoutIndexOperand,
-]:
+]
+WhenCallback: TypeAlias = Callable[
+    [PySingleValueComparisonOperand, PySingleValueComparisonOperand], None
+]
+
+
+def _py_single_value_comparison_operand_from_single_value_comparison_operand(
+    single_value_comparison_operand: SingleValueComparisonOperand,
+) -> PySingleValueComparisonOperand:
     if isinstance(single_value_comparison_operand, NodeSingleValueWithIndexOperand):
         return single_value_comparison_operand._single_value_operand
     if isinstance(single_value_comparison_operand, NodeSingleValueWithoutIndexOperand):
@@ -513,6 +556,65 @@ class MatchMode(Enum):
         return PyMatchMode.All if self == MatchMode.ALL else PyMatchMode.Any
 
 
+class RollingAggregation(Enum):
+    """Enumeration of aggregations for rolling windows over values."""
+
+    MEAN = 0
+    SUM = 1
+
+    def _into_py_rolling_aggregation(self) -> PyRollingAggregation:
+        return (
+            PyRollingAggregation.Mean
+            if self == RollingAggregation.MEAN
+            else PyRollingAggregation.Sum
+        )
+
+
+class When:
+    """Condition of a `when(...).then(...).otherwise(...)` expression on values."""
+
+    _callback: WhenCallback
+
+    def then(self, value: SingleValueComparisonOperand) -> WhenThen:
+        return WhenThen._from_callback(self._callback, value)
+
+    @classmethod
+    def _from_callback(cls, callback: WhenCallback) -> When:
+        when = cls()
+        when._callback = callback
+        return when
+
+
+class WhenThen:
+    """Condition and `then` value of a `when(...).then(...)` expression.
+
+    Calling `otherwise` replaces each value matching the condition with the
+    `then` value and every other value with the `otherwise` value.
+    """
+
+    _callback: WhenCallback
+    _then: SingleValueComparisonOperand
+
+    def otherwise(self, value: SingleValueComparisonOperand) -> None:
+        self._callback(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                self._then
+            ),
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                value
+            ),
+        )
+
+    @classmethod
+    def _from_callback(
+        cls, callback: WhenCallback, then: SingleValueComparisonOperand
+    ) -> WhenThen:
+        when_then = cls()
+        when_then._callback = callback
+        when_then._then = then
+        return when_then
+
+
 class NodeOperand:
     _node_operand: PyNodeOperand
 
@@ -568,6 +670,13 @@ class NodeOperand:
             self._node_operand.neighbors(edge_direction._into_py_edge_direction())
         )
 
+    def degree(
+        self, direction: EdgeDirection = EdgeDirection.BOTH
+    ) -> NodeMultipleValuesWithIndexOperand:
+        return NodeMultipleValuesWithIndexOperand._from_py_multiple_values_operand(
+            self._node_operand.degree(direction._into_py_edge_direction())
+        )
+
     def either_or(
         self,
         either: NodeQueryComponent,
@@ -583,6 +692,29 @@ class NodeOperand:
             lambda node: query(NodeOperand._from_py_node_operand(node))
         )
 
+    def not_(self, query: NodeQueryComponent) -> None:
+        self.exclude(query)
+
+    def all_of(self, queries: List[NodeQueryComponent]) -> None:
+        self._node_operand.all_of(
+            [
+                lambda node, query=query: query(
+                    NodeOperand._from_py_node_operand(node)
+                )
+                for query in queries
+            ]
+        )
+
+    def none_of(self, queries: List[NodeQueryComponent]) -> None:
+        self._node_operand.none_of(
+            [
+                lambda node, query=query: query(
+                    NodeOperand._from_py_node_operand(node)
+                )
+                for query in queries
+            ]
+        )
+
     def group_by(
         self, discriminator: NodeOperandGroupDiscriminator
     ) -> NodeGroupOperand:
@@ -655,6 +787,13 @@ class NodeGroupOperand:
             self._node_operand.neighbors(edge_direction._into_py_edge_direction())
         )
 
+    def degree(
+        self, direction: EdgeDirection = EdgeDirection.BOTH
+    ) -> NodeMultipleValuesWithIndexGroupOperand:
+        return NodeMultipleValuesWithIndexGroupOperand._from_py_multiple_values_operand(
+            self._node_operand.degree(direction._into_py_edge_direction())
+        )
+
     def either_or(
         self,
         either: NodeQueryComponent,
@@ -670,6 +809,36 @@ class NodeGroupOperand:
             lambda node: query(NodeOperand._from_py_node_operand(node))
         )
 
+    def not_(self, query: NodeQueryComponent) -> None:
+        self.exclude(query)
+
+    def all_of(self, queries: List[NodeQueryComponent]) -> None:
+        self._node_operand.all_of(
+            [
+                lambda node, query=query: query(
+                    NodeOperand._from_py_node_operand(node)
+                )
+                for query in queries
+            ]
+        )
+
+    def none_of(self, queries: List[NodeQueryComponent]) -> None:
+        self._node_operand.none_of(
+            [
+                lambda node, query=query: query(
+                    NodeOperand._from_py_node_operand(node)
+                )
+                for query in queries
+            ]
+        )
+
+    def group_by(
+        self, discriminator: NodeOperandGroupDiscriminator
+    ) -> NodeGroupOperand:
+        return NodeGroupOperand._from_py_node_group_operand(
+            self._node_operand.group_by(discriminator)
+        )
+
     def clone(self) -> NodeGroupOperand:
         return NodeGroupOperand._from_py_node_group_operand(
             self._node_operand.deep_clone()
@@ -731,6 +900,11 @@ class EdgeOperand:
     def target_node(self) -> NodeOperand:
         return NodeOperand._from_py_node_operand(self._edge_operand.target_node())
 
+    def induced_by(self, query: NodeQueryComponent) -> None:
+        self._edge_operand.induced_by(
+            lambda node: query(NodeOperand._from_py_node_operand(node))
+        )
+
     def either_or(
         self,
         either: EdgeQueryComponent,
@@ -746,6 +920,29 @@ class EdgeOperand:
             lambda edge: query(EdgeOperand._from_py_edge_operand(edge))
         )
 
+    def not_(self, query: EdgeQueryComponent) -> None:
+        self.exclude(query)
+
+    def all_of(self, queries: List[EdgeQueryComponent]) -> None:
+        self._edge_operand.all_of(
+            [
+                lambda edge, query=query: query(
+                    EdgeOperand._from_py_edge_operand(edge)
+                )
+                for query in queries
+            ]
+        )
+
+    def none_of(self, queries: List[EdgeQueryComponent]) -> None:
+        self._edge_operand.none_of(
+            [
+                lambda edge, query=query: query(
+                    EdgeOperand._from_py_edge_operand(edge)
+                )
+                for query in queries
+            ]
+        )
+
     def group_by(
         self, discriminator: EdgeOperandGroupDiscriminator
     ) -> EdgeGroupOperand:
@@ -816,6 +1013,11 @@ class EdgeGroupOperand:
             self._edge_operand.target_node()
         )
 
+    def induced_by(self, query: NodeQueryComponent) -> None:
+        self._edge_operand.induced_by(
+            lambda node: query(NodeOperand._from_py_node_operand(node))
+        )
+
     def either_or(
         self,
         either: EdgeQueryComponent,
@@ -831,6 +1033,36 @@ class EdgeGroupOperand:
             lambda edge: query(EdgeOperand._from_py_edge_operand(edge))
         )
 
+    def not_(self, query: EdgeQueryComponent) -> None:
+        self.exclude(query)
+
+    def all_of(self, queries: List[EdgeQueryComponent]) -> None:
+        self._edge_operand.all_of(
+            [
+                lambda edge, query=query: query(
+                    EdgeOperand._from_py_edge_operand(edge)
+                )
+                for query in queries
+            ]
+        )
+
+    def none_of(self, queries: List[EdgeQueryComponent]) -> None:
+        self._edge_operand.none_of(
+            [
+                lambda edge, query=query: query(
+                    EdgeOperand._from_py_edge_operand(edge)
+                )
+                for query in queries
+            ]
+        )
+
+    def group_by(
+        self, discriminator: EdgeOperandGroupDiscriminator
+    ) -> EdgeGroupOperand:
+        return EdgeGroupOperand._from_py_edge_group_operand(
+            self._edge_operand.group_by(discriminator)
+        )
+
     def clone(self) -> EdgeGroupOperand:
         return EdgeGroupOperand._from_py_edge_group_operand(
             self._edge_operand.deep_clone()
@@ -868,6 +1100,11 @@ class NodeMultipleValuesWithIndexOperand:
             self._multiple_values_operand.median()
         )
 
+    def quantile(self, quantile: float) -> NodeSingleValueWithoutIndexOperand:
+        return NodeSingleValueWithoutIndexOperand._from_py_single_value_operand(
+            self._multiple_values_operand.quantile(quantile)
+        )
+
     def mode(self) -> NodeSingleValueWithoutIndexOperand:
         return NodeSingleValueWithoutIndexOperand._from_py_single_value_operand(
             self._multiple_values_operand.mode()
@@ -888,6 +1125,26 @@ class NodeMultipleValuesWithIndexOperand:
             self._multiple_values_operand.count()
         )
 
+    def count_distinct(self) -> NodeSingleValueWithoutIndexOperand:
+        return NodeSingleValueWithoutIndexOperand._from_py_single_value_operand(
+            self._multiple_values_operand.count_distinct()
+        )
+
+    def value_counts(self) -> NodeValueCountsOperand:
+        return NodeValueCountsOperand._from_py_value_counts_operand(
+            self._multiple_values_operand.value_counts()
+        )
+
+    def histogram(self, bins: int) -> NodeHistogramOperand:
+        return NodeHistogramOperand._from_py_histogram_operand(
+            self._multiple_values_operand.histogram(bins)
+        )
+
+    def quantiles(self, quantiles: List[float]) -> NodeQuantilesOperand:
+        return NodeQuantilesOperand._from_py_quantiles_operand(
+            self._multiple_values_operand.quantiles(quantiles)
+        )
+
     def sum(self) -> NodeSingleValueWithoutIndexOperand:
         return NodeSingleValueWithoutIndexOperand._from_py_single_value_operand(
             self._multiple_values_operand.sum()
@@ -919,12 +1176,98 @@ class NodeMultipleValuesWithIndexOperand:
     def is_null(self) -> None:
         self._multiple_values_operand.is_null()
 
+    def is_missing(self) -> None:
+        self._multiple_values_operand.is_missing()
+
+    def provenance(self) -> None:
+        self._multiple_values_operand.provenance()
+
+    def text_search(self, query: str) -> None:
+        self._multiple_values_operand.text_search(query)
+
+    def rolling(
+        self,
+        window: timedelta,
+        aggregation: RollingAggregation,
+        order_by_attribute: GraphRecordAttribute,
+    ) -> None:
+        self._multiple_values_operand.rolling(
+            window, aggregation._into_py_rolling_aggregation(), order_by_attribute
+        )
+
+    def cumsum(self, order_by_attribute: GraphRecordAttribute) -> None:
+        self._multiple_values_operand.cumsum(order_by_attribute)
+
+    def lag(self, n: int, order_by_attribute: GraphRecordAttribute) -> None:
+        self._multiple_values_operand.lag(n, order_by_attribute)
+
+    def lead(self, n: int, order_by_attribute: GraphRecordAttribute) -> None:
+        self._multiple_values_operand.lead(n, order_by_attribute)
+
+    def argmax(self) -> NodeSingleValueWithIndexOperand:
+        return NodeSingleValueWithIndexOperand._from_py_single_value_operand(
+            self._multiple_values_operand.argmax()
+        )
+
+    def argmin(self) -> NodeSingleValueWithIndexOperand:
+        return NodeSingleValueWithIndexOperand._from_py_single_value_operand(
+            self._multiple_values_operand.argmin()
+        )
+
+    def first_by(
+        self, order_by_attribute: GraphRecordAttribute
+    ) -> NodeSingleValueWithIndexOperand:
+        return NodeSingleValueWithIndexOperand._from_py_single_value_operand(
+            self._multiple_values_operand.first_by(order_by_attribute)
+        )
+
+    def last_by(
+        self, order_by_attribute: GraphRecordAttribute
+    ) -> NodeSingleValueWithIndexOperand:
+        return NodeSingleValueWithIndexOperand._from_py_single_value_operand(
+            self._multiple_values_operand.last_by(order_by_attribute)
+        )
+
+    def random_weighted(
+        self, weight_attribute: GraphRecordAttribute
+    ) -> NodeSingleValueWithIndexOperand:
+        return NodeSingleValueWithIndexOperand._from_py_single_value_operand(
+            self._multiple_values_operand.random_weighted(weight_attribute)
+        )
+
+    def when(
+        self, query: Callable[[NodeMultipleValuesWithIndexOperand], None]
+    ) -> When:
+        return When._from_callback(
+            lambda then, otherwise: self._multiple_values_operand.when(
+                lambda values: query(
+                    NodeMultipleValuesWithIndexOperand._from_py_multiple_values_operand(
+                        values
+                    )
+                ),
+                then,
+                otherwise,
+            )
+        )
+
     def is_max(self) -> None:
         self._multiple_values_operand.is_max()
 
     def is_min(self) -> None:
         self._multiple_values_operand.is_min()
 
+    def distinct(self) -> None:
+        self._multiple_values_operand.distinct()
+
+    def sort(self, descending: bool = False) -> None:
+        self._multiple_values_operand.sort(descending)
+
+    def skip(self, count: int) -> None:
+        self._multiple_values_operand.skip(count)
+
+    def limit(self, count: int) -> None:
+        self._multiple_values_operand.limit(count)
+
     def greater_than(self, value: SingleValueComparisonOperand) -> None:
         self._multiple_values_operand.greater_than(
             _py_single_value_comparison_operand_from_single_value_comparison_operand(
@@ -1002,6 +1345,44 @@ class NodeMultipleValuesWithIndexOperand:
             )
         )
 
+    def levenshtein_at_most(
+        self, target: SingleValueComparisonOperand, distance: int
+    ) -> None:
+        self._multiple_values_operand.levenshtein_at_most(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                target
+            ),
+            distance,
+        )
+
+    def jaro_winkler_at_least(
+        self, target: SingleValueComparisonOperand, similarity: float
+    ) -> None:
+        self._multiple_values_operand.jaro_winkler_at_least(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                target
+            ),
+            similarity,
+        )
+
+    def is_close(self, value: SingleValueComparisonOperand, tolerance: float) -> None:
+        self._multiple_values_operand.is_close(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                value
+            ),
+            tolerance,
+        )
+
+    def within_radius(
+        self, point: SingleValueComparisonOperand, radius_km: float
+    ) -> None:
+        self._multiple_values_operand.within_radius(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                point
+            ),
+            radius_km,
+        )
+
     def add(self, value: SingleValueArithmeticOperand) -> None:
         self._multiple_values_operand.add(
             _py_single_value_comparison_operand_from_single_value_comparison_operand(
@@ -1056,6 +1437,9 @@ class NodeMultipleValuesWithIndexOperand:
     def absolute(self) -> None:
         self._multiple_values_operand.abs()
 
+    def apply(self, udf: str) -> None:
+        self._multiple_values_operand.apply(udf)
+
     def sqrt(self) -> None:
         self._multiple_values_operand.sqrt()
 
@@ -1143,6 +1527,11 @@ class NodeMultipleValuesWithIndexGroupOperand:
             self._multiple_values_operand.median()
         )
 
+    def quantile(self, quantile: float) -> NodeSingleValueWithoutIndexGroupOperand:
+        return NodeSingleValueWithoutIndexGroupOperand._from_py_single_value_operand(
+            self._multiple_values_operand.quantile(quantile)
+        )
+
     def mode(self) -> NodeSingleValueWithoutIndexGroupOperand:
         return NodeSingleValueWithoutIndexGroupOperand._from_py_single_value_operand(
             self._multiple_values_operand.mode()
@@ -1163,6 +1552,11 @@ class NodeMultipleValuesWithIndexGroupOperand:
             self._multiple_values_operand.count()
         )
 
+    def count_distinct(self) -> NodeSingleValueWithoutIndexGroupOperand:
+        return NodeSingleValueWithoutIndexGroupOperand._from_py_single_value_operand(
+            self._multiple_values_operand.count_distinct()
+        )
+
     def sum(self) -> NodeSingleValueWithoutIndexGroupOperand:
         return NodeSingleValueWithoutIndexGroupOperand._from_py_single_value_operand(
             self._multiple_values_operand.sum()
@@ -1194,12 +1588,98 @@ class NodeMultipleValuesWithIndexGroupOperand:
     def is_null(self) -> None:
         self._multiple_values_operand.is_null()
 
+    def is_missing(self) -> None:
+        self._multiple_values_operand.is_missing()
+
+    def provenance(self) -> None:
+        self._multiple_values_operand.provenance()
+
+    def text_search(self, query: str) -> None:
+        self._multiple_values_operand.text_search(query)
+
+    def rolling(
+        self,
+        window: timedelta,
+        aggregation: RollingAggregation,
+        order_by_attribute: GraphRecordAttribute,
+    ) -> None:
+        self._multiple_values_operand.rolling(
+            window, aggregation._into_py_rolling_aggregation(), order_by_attribute
+        )
+
+    def cumsum(self, order_by_attribute: GraphRecordAttribute) -> None:
+        self._multiple_values_operand.cumsum(order_by_attribute)
+
+    def lag(self, n: int, order_by_attribute: GraphRecordAttribute) -> None:
+        self._multiple_values_operand.lag(n, order_by_attribute)
+
+    def lead(self, n: int, order_by_attribute: GraphRecordAttribute) -> None:
+        self._multiple_values_operand.lead(n, order_by_attribute)
+
+    def argmax(self) -> NodeSingleValueWithIndexGroupOperand:
+        return NodeSingleValueWithIndexGroupOperand._from_py_single_value_operand(
+            self._multiple_values_operand.argmax()
+        )
+
+    def argmin(self) -> NodeSingleValueWithIndexGroupOperand:
+        return NodeSingleValueWithIndexGroupOperand._from_py_single_value_operand(
+            self._multiple_values_operand.argmin()
+        )
+
+    def first_by(
+        self, order_by_attribute: GraphRecordAttribute
+    ) -> NodeSingleValueWithIndexGroupOperand:
+        return NodeSingleValueWithIndexGroupOperand._from_py_single_value_operand(
+            self._multiple_values_operand.first_by(order_by_attribute)
+        )
+
+    def last_by(
+        self, order_by_attribute: GraphRecordAttribute
+    ) -> NodeSingleValueWithIndexGroupOperand:
+        return NodeSingleValueWithIndexGroupOperand._from_py_single_value_operand(
+            self._multiple_values_operand.last_by(order_by_attribute)
+        )
+
+    def random_weighted(
+        self, weight_attribute: GraphRecordAttribute
+    ) -> NodeSingleValueWithIndexGroupOperand:
+        return NodeSingleValueWithIndexGroupOperand._from_py_single_value_operand(
+            self._multiple_values_operand.random_weighted(weight_attribute)
+        )
+
+    def when(
+        self, query: Callable[[NodeMultipleValuesWithIndexOperand], None]
+    ) -> When:
+        return When._from_callback(
+            lambda then, otherwise: self._multiple_values_operand.when(
+                lambda values: query(
+                    NodeMultipleValuesWithIndexOperand._from_py_multiple_values_operand(
+                        values
+                    )
+                ),
+                then,
+                otherwise,
+            )
+        )
+
     def is_max(self) -> None:
         self._multiple_values_operand.is_max()
 
     def is_min(self) -> None:
         self._multiple_values_operand.is_min()
 
+    def distinct(self) -> None:
+        self._multiple_values_operand.distinct()
+
+    def sort(self, descending: bool = False) -> None:
+        self._multiple_values_operand.sort(descending)
+
+    def skip(self, count: int) -> None:
+        self._multiple_values_operand.skip(count)
+
+    def limit(self, count: int) -> None:
+        self._multiple_values_operand.limit(count)
+
     def greater_than(self, value: SingleValueComparisonOperand) -> None:
         self._multiple_values_operand.greater_than(
             _py_single_value_comparison_operand_from_single_value_comparison_operand(
@@ -1277,6 +1757,44 @@ class NodeMultipleValuesWithIndexGroupOperand:
             )
         )
 
+    def levenshtein_at_most(
+        self, target: SingleValueComparisonOperand, distance: int
+    ) -> None:
+        self._multiple_values_operand.levenshtein_at_most(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                target
+            ),
+            distance,
+        )
+
+    def jaro_winkler_at_least(
+        self, target: SingleValueComparisonOperand, similarity: float
+    ) -> None:
+        self._multiple_values_operand.jaro_winkler_at_least(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                target
+            ),
+            similarity,
+        )
+
+    def is_close(self, value: SingleValueComparisonOperand, tolerance: float) -> None:
+        self._multiple_values_operand.is_close(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                value
+            ),
+            tolerance,
+        )
+
+    def within_radius(
+        self, point: SingleValueComparisonOperand, radius_km: float
+    ) -> None:
+        self._multiple_values_operand.within_radius(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                point
+            ),
+            radius_km,
+        )
+
     def add(self, value: SingleValueArithmeticOperand) -> None:
         self._multiple_values_operand.add(
             _py_single_value_comparison_operand_from_single_value_comparison_operand(
@@ -1331,6 +1849,9 @@ class NodeMultipleValuesWithIndexGroupOperand:
     def absolute(self) -> None:
         self._multiple_values_operand.abs()
 
+    def apply(self, udf: str) -> None:
+        self._multiple_values_operand.apply(udf)
+
     def sqrt(self) -> None:
         self._multiple_values_operand.sqrt()
 
@@ -1386,6 +1907,14 @@ class NodeMultipleValuesWithIndexGroupOperand:
             self._multiple_values_operand.ungroup()
         )
 
+    def sorted_by_key(self) -> None:
+        self._multiple_values_operand.sorted_by_key()
+
+    def sorted_by_aggregate(self, aggregate: GroupAggregate) -> None:
+        self._multiple_values_operand.sorted_by_aggregate(
+            aggregate._single_value_operand
+        )
+
     def clone(self) -> NodeMultipleValuesWithIndexGroupOperand:
         return NodeMultipleValuesWithIndexGroupOperand._from_py_multiple_values_operand(
             self._multiple_values_operand.deep_clone()
@@ -1423,6 +1952,11 @@ class NodeMultipleValuesWithoutIndexOperand:
             self._multiple_values_operand.median()
         )
 
+    def quantile(self, quantile: float) -> NodeSingleValueWithoutIndexOperand:
+        return NodeSingleValueWithoutIndexOperand._from_py_single_value_operand(
+            self._multiple_values_operand.quantile(quantile)
+        )
+
     def mode(self) -> NodeSingleValueWithoutIndexOperand:
         return NodeSingleValueWithoutIndexOperand._from_py_single_value_operand(
             self._multiple_values_operand.mode()
@@ -1443,6 +1977,26 @@ class NodeMultipleValuesWithoutIndexOperand:
             self._multiple_values_operand.count()
         )
 
+    def count_distinct(self) -> NodeSingleValueWithoutIndexOperand:
+        return NodeSingleValueWithoutIndexOperand._from_py_single_value_operand(
+            self._multiple_values_operand.count_distinct()
+        )
+
+    def value_counts(self) -> NodeValueCountsOperand:
+        return NodeValueCountsOperand._from_py_value_counts_operand(
+            self._multiple_values_operand.value_counts()
+        )
+
+    def histogram(self, bins: int) -> NodeHistogramOperand:
+        return NodeHistogramOperand._from_py_histogram_operand(
+            self._multiple_values_operand.histogram(bins)
+        )
+
+    def quantiles(self, quantiles: List[float]) -> NodeQuantilesOperand:
+        return NodeQuantilesOperand._from_py_quantiles_operand(
+            self._multiple_values_operand.quantiles(quantiles)
+        )
+
     def sum(self) -> NodeSingleValueWithoutIndexOperand:
         return NodeSingleValueWithoutIndexOperand._from_py_single_value_operand(
             self._multiple_values_operand.sum()
@@ -1474,12 +2028,27 @@ class NodeMultipleValuesWithoutIndexOperand:
     def is_null(self) -> None:
         self._multiple_values_operand.is_null()
 
+    def is_missing(self) -> None:
+        self._multiple_values_operand.is_missing()
+
     def is_max(self) -> None:
         self._multiple_values_operand.is_max()
 
     def is_min(self) -> None:
         self._multiple_values_operand.is_min()
 
+    def distinct(self) -> None:
+        self._multiple_values_operand.distinct()
+
+    def sort(self, descending: bool = False) -> None:
+        self._multiple_values_operand.sort(descending)
+
+    def skip(self, count: int) -> None:
+        self._multiple_values_operand.skip(count)
+
+    def limit(self, count: int) -> None:
+        self._multiple_values_operand.limit(count)
+
     def greater_than(self, value: SingleValueComparisonOperand) -> None:
         self._multiple_values_operand.greater_than(
             _py_single_value_comparison_operand_from_single_value_comparison_operand(
@@ -1557,6 +2126,44 @@ class NodeMultipleValuesWithoutIndexOperand:
             )
         )
 
+    def levenshtein_at_most(
+        self, target: SingleValueComparisonOperand, distance: int
+    ) -> None:
+        self._multiple_values_operand.levenshtein_at_most(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                target
+            ),
+            distance,
+        )
+
+    def jaro_winkler_at_least(
+        self, target: SingleValueComparisonOperand, similarity: float
+    ) -> None:
+        self._multiple_values_operand.jaro_winkler_at_least(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                target
+            ),
+            similarity,
+        )
+
+    def is_close(self, value: SingleValueComparisonOperand, tolerance: float) -> None:
+        self._multiple_values_operand.is_close(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                value
+            ),
+            tolerance,
+        )
+
+    def within_radius(
+        self, point: SingleValueComparisonOperand, radius_km: float
+    ) -> None:
+        self._multiple_values_operand.within_radius(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                point
+            ),
+            radius_km,
+        )
+
     def add(self, value: SingleValueArithmeticOperand) -> None:
         self._multiple_values_operand.add(
             _py_single_value_comparison_operand_from_single_value_comparison_operand(
@@ -1611,6 +2218,9 @@ class NodeMultipleValuesWithoutIndexOperand:
     def absolute(self) -> None:
         self._multiple_values_operand.abs()
 
+    def apply(self, udf: str) -> None:
+        self._multiple_values_operand.apply(udf)
+
     def sqrt(self) -> None:
         self._multiple_values_operand.sqrt()
 
@@ -1698,6 +2308,11 @@ class EdgeMultipleValuesWithIndexOperand:
             self._multiple_values_operand.median()
         )
 
+    def quantile(self, quantile: float) -> EdgeSingleValueWithoutIndexOperand:
+        return EdgeSingleValueWithoutIndexOperand._from_py_single_value_operand(
+            self._multiple_values_operand.quantile(quantile)
+        )
+
     def mode(self) -> EdgeSingleValueWithoutIndexOperand:
         return EdgeSingleValueWithoutIndexOperand._from_py_single_value_operand(
             self._multiple_values_operand.mode()
@@ -1718,6 +2333,26 @@ class EdgeMultipleValuesWithIndexOperand:
             self._multiple_values_operand.count()
         )
 
+    def count_distinct(self) -> EdgeSingleValueWithoutIndexOperand:
+        return EdgeSingleValueWithoutIndexOperand._from_py_single_value_operand(
+            self._multiple_values_operand.count_distinct()
+        )
+
+    def value_counts(self) -> EdgeValueCountsOperand:
+        return EdgeValueCountsOperand._from_py_value_counts_operand(
+            self._multiple_values_operand.value_counts()
+        )
+
+    def histogram(self, bins: int) -> EdgeHistogramOperand:
+        return EdgeHistogramOperand._from_py_histogram_operand(
+            self._multiple_values_operand.histogram(bins)
+        )
+
+    def quantiles(self, quantiles: List[float]) -> EdgeQuantilesOperand:
+        return EdgeQuantilesOperand._from_py_quantiles_operand(
+            self._multiple_values_operand.quantiles(quantiles)
+        )
+
     def sum(self) -> EdgeSingleValueWithoutIndexOperand:
         return EdgeSingleValueWithoutIndexOperand._from_py_single_value_operand(
             self._multiple_values_operand.sum()
@@ -1749,12 +2384,98 @@ class EdgeMultipleValuesWithIndexOperand:
     def is_null(self) -> None:
         self._multiple_values_operand.is_null()
 
+    def is_missing(self) -> None:
+        self._multiple_values_operand.is_missing()
+
+    def provenance(self) -> None:
+        self._multiple_values_operand.provenance()
+
+    def text_search(self, query: str) -> None:
+        self._multiple_values_operand.text_search(query)
+
+    def rolling(
+        self,
+        window: timedelta,
+        aggregation: RollingAggregation,
+        order_by_attribute: GraphRecordAttribute,
+    ) -> None:
+        self._multiple_values_operand.rolling(
+            window, aggregation._into_py_rolling_aggregation(), order_by_attribute
+        )
+
+    def cumsum(self, order_by_attribute: GraphRecordAttribute) -> None:
+        self._multiple_values_operand.cumsum(order_by_attribute)
+
+    def lag(self, n: int, order_by_attribute: GraphRecordAttribute) -> None:
+        self._multiple_values_operand.lag(n, order_by_attribute)
+
+    def lead(self, n: int, order_by_attribute: GraphRecordAttribute) -> None:
+        self._multiple_values_operand.lead(n, order_by_attribute)
+
+    def argmax(self) -> EdgeSingleValueWithIndexOperand:
+        return EdgeSingleValueWithIndexOperand._from_py_single_value_operand(
+            self._multiple_values_operand.argmax()
+        )
+
+    def argmin(self) -> EdgeSingleValueWithIndexOperand:
+        return EdgeSingleValueWithIndexOperand._from_py_single_value_operand(
+            self._multiple_values_operand.argmin()
+        )
+
+    def first_by(
+        self, order_by_attribute: GraphRecordAttribute
+    ) -> EdgeSingleValueWithIndexOperand:
+        return EdgeSingleValueWithIndexOperand._from_py_single_value_operand(
+            self._multiple_values_operand.first_by(order_by_attribute)
+        )
+
+    def last_by(
+        self, order_by_attribute: GraphRecordAttribute
+    ) -> EdgeSingleValueWithIndexOperand:
+        return EdgeSingleValueWithIndexOperand._from_py_single_value_operand(
+            self._multiple_values_operand.last_by(order_by_attribute)
+        )
+
+    def random_weighted(
+        self, weight_attribute: GraphRecordAttribute
+    ) -> EdgeSingleValueWithIndexOperand:
+        return EdgeSingleValueWithIndexOperand._from_py_single_value_operand(
+            self._multiple_values_operand.random_weighted(weight_attribute)
+        )
+
+    def when(
+        self, query: Callable[[EdgeMultipleValuesWithIndexOperand], None]
+    ) -> When:
+        return When._from_callback(
+            lambda then, otherwise: self._multiple_values_operand.when(
+                lambda values: query(
+                    EdgeMultipleValuesWithIndexOperand._from_py_multiple_values_operand(
+                        values
+                    )
+                ),
+                then,
+                otherwise,
+            )
+        )
+
     def is_max(self) -> None:
         self._multiple_values_operand.is_max()
 
     def is_min(self) -> None:
         self._multiple_values_operand.is_min()
 
+    def distinct(self) -> None:
+        self._multiple_values_operand.distinct()
+
+    def sort(self, descending: bool = False) -> None:
+        self._multiple_values_operand.sort(descending)
+
+    def skip(self, count: int) -> None:
+        self._multiple_values_operand.skip(count)
+
+    def limit(self, count: int) -> None:
+        self._multiple_values_operand.limit(count)
+
     def greater_than(self, value: SingleValueComparisonOperand) -> None:
         self._multiple_values_operand.greater_than(
             _py_single_value_comparison_operand_from_single_value_comparison_operand(
@@ -1832,6 +2553,44 @@ class EdgeMultipleValuesWithIndexOperand:
             )
         )
 
+    def levenshtein_at_most(
+        self, target: SingleValueComparisonOperand, distance: int
+    ) -> None:
+        self._multiple_values_operand.levenshtein_at_most(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                target
+            ),
+            distance,
+        )
+
+    def jaro_winkler_at_least(
+        self, target: SingleValueComparisonOperand, similarity: float
+    ) -> None:
+        self._multiple_values_operand.jaro_winkler_at_least(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                target
+            ),
+            similarity,
+        )
+
+    def is_close(self, value: SingleValueComparisonOperand, tolerance: float) -> None:
+        self._multiple_values_operand.is_close(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                value
+            ),
+            tolerance,
+        )
+
+    def within_radius(
+        self, point: SingleValueComparisonOperand, radius_km: float
+    ) -> None:
+        self._multiple_values_operand.within_radius(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                point
+            ),
+            radius_km,
+        )
+
     def add(self, value: SingleValueArithmeticOperand) -> None:
         self._multiple_values_operand.add(
             _py_single_value_comparison_operand_from_single_value_comparison_operand(
@@ -1886,6 +2645,9 @@ class EdgeMultipleValuesWithIndexOperand:
     def absolute(self) -> None:
         self._multiple_values_operand.abs()
 
+    def apply(self, udf: str) -> None:
+        self._multiple_values_operand.apply(udf)
+
     def sqrt(self) -> None:
         self._multiple_values_operand.sqrt()
 
@@ -1973,6 +2735,11 @@ class EdgeMultipleValuesWithIndexGroupOperand:
             self._multiple_values_operand.median()
         )
 
+    def quantile(self, quantile: float) -> EdgeSingleValueWithoutIndexGroupOperand:
+        return EdgeSingleValueWithoutIndexGroupOperand._from_py_single_value_operand(
+            self._multiple_values_operand.quantile(quantile)
+        )
+
     def mode(self) -> EdgeSingleValueWithoutIndexGroupOperand:
         return EdgeSingleValueWithoutIndexGroupOperand._from_py_single_value_operand(
             self._multiple_values_operand.mode()
@@ -1993,6 +2760,11 @@ class EdgeMultipleValuesWithIndexGroupOperand:
             self._multiple_values_operand.count()
         )
 
+    def count_distinct(self) -> EdgeSingleValueWithoutIndexGroupOperand:
+        return EdgeSingleValueWithoutIndexGroupOperand._from_py_single_value_operand(
+            self._multiple_values_operand.count_distinct()
+        )
+
     def sum(self) -> EdgeSingleValueWithoutIndexGroupOperand:
         return EdgeSingleValueWithoutIndexGroupOperand._from_py_single_value_operand(
             self._multiple_values_operand.sum()
@@ -2024,12 +2796,98 @@ class EdgeMultipleValuesWithIndexGroupOperand:
     def is_null(self) -> None:
         self._multiple_values_operand.is_null()
 
+    def is_missing(self) -> None:
+        self._multiple_values_operand.is_missing()
+
+    def provenance(self) -> None:
+        self._multiple_values_operand.provenance()
+
+    def text_search(self, query: str) -> None:
+        self._multiple_values_operand.text_search(query)
+
+    def rolling(
+        self,
+        window: timedelta,
+        aggregation: RollingAggregation,
+        order_by_attribute: GraphRecordAttribute,
+    ) -> None:
+        self._multiple_values_operand.rolling(
+            window, aggregation._into_py_rolling_aggregation(), order_by_attribute
+        )
+
+    def cumsum(self, order_by_attribute: GraphRecordAttribute) -> None:
+        self._multiple_values_operand.cumsum(order_by_attribute)
+
+    def lag(self, n: int, order_by_attribute: GraphRecordAttribute) -> None:
+        self._multiple_values_operand.lag(n, order_by_attribute)
+
+    def lead(self, n: int, order_by_attribute: GraphRecordAttribute) -> None:
+        self._multiple_values_operand.lead(n, order_by_attribute)
+
+    def argmax(self) -> EdgeSingleValueWithIndexGroupOperand:
+        return EdgeSingleValueWithIndexGroupOperand._from_py_single_value_operand(
+            self._multiple_values_operand.argmax()
+        )
+
+    def argmin(self) -> EdgeSingleValueWithIndexGroupOperand:
+        return EdgeSingleValueWithIndexGroupOperand._from_py_single_value_operand(
+            self._multiple_values_operand.argmin()
+        )
+
+    def first_by(
+        self, order_by_attribute: GraphRecordAttribute
+    ) -> EdgeSingleValueWithIndexGroupOperand:
+        return EdgeSingleValueWithIndexGroupOperand._from_py_single_value_operand(
+            self._multiple_values_operand.first_by(order_by_attribute)
+        )
+
+    def last_by(
+        self, order_by_attribute: GraphRecordAttribute
+    ) -> EdgeSingleValueWithIndexGroupOperand:
+        return EdgeSingleValueWithIndexGroupOperand._from_py_single_value_operand(
+            self._multiple_values_operand.last_by(order_by_attribute)
+        )
+
+    def random_weighted(
+        self, weight_attribute: GraphRecordAttribute
+    ) -> EdgeSingleValueWithIndexGroupOperand:
+        return EdgeSingleValueWithIndexGroupOperand._from_py_single_value_operand(
+            self._multiple_values_operand.random_weighted(weight_attribute)
+        )
+
+    def when(
+        self, query: Callable[[EdgeMultipleValuesWithIndexOperand], None]
+    ) -> When:
+        return When._from_callback(
+            lambda then, otherwise: self._multiple_values_operand.when(
+                lambda values: query(
+                    EdgeMultipleValuesWithIndexOperand._from_py_multiple_values_operand(
+                        values
+                    )
+                ),
+                then,
+                otherwise,
+            )
+        )
+
     def is_max(self) -> None:
         self._multiple_values_operand.is_max()
 
     def is_min(self) -> None:
         self._multiple_values_operand.is_min()
 
+    def distinct(self) -> None:
+        self._multiple_values_operand.distinct()
+
+    def sort(self, descending: bool = False) -> None:
+        self._multiple_values_operand.sort(descending)
+
+    def skip(self, count: int) -> None:
+        self._multiple_values_operand.skip(count)
+
+    def limit(self, count: int) -> None:
+        self._multiple_values_operand.limit(count)
+
     def greater_than(self, value: SingleValueComparisonOperand) -> None:
         self._multiple_values_operand.greater_than(
             _py_single_value_comparison_operand_from_single_value_comparison_operand(
@@ -2107,6 +2965,44 @@ class EdgeMultipleValuesWithIndexGroupOperand:
             )
         )
 
+    def levenshtein_at_most(
+        self, target: SingleValueComparisonOperand, distance: int
+    ) -> None:
+        self._multiple_values_operand.levenshtein_at_most(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                target
+            ),
+            distance,
+        )
+
+    def jaro_winkler_at_least(
+        self, target: SingleValueComparisonOperand, similarity: float
+    ) -> None:
+        self._multiple_values_operand.jaro_winkler_at_least(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                target
+            ),
+            similarity,
+        )
+
+    def is_close(self, value: SingleValueComparisonOperand, tolerance: float) -> None:
+        self._multiple_values_operand.is_close(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                value
+            ),
+            tolerance,
+        )
+
+    def within_radius(
+        self, point: SingleValueComparisonOperand, radius_km: float
+    ) -> None:
+        self._multiple_values_operand.within_radius(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                point
+            ),
+            radius_km,
+        )
+
     def add(self, value: SingleValueArithmeticOperand) -> None:
         self._multiple_values_operand.add(
             _py_single_value_comparison_operand_from_single_value_comparison_operand(
@@ -2161,6 +3057,9 @@ class EdgeMultipleValuesWithIndexGroupOperand:
     def absolute(self) -> None:
         self._multiple_values_operand.abs()
 
+    def apply(self, udf: str) -> None:
+        self._multiple_values_operand.apply(udf)
+
     def sqrt(self) -> None:
         self._multiple_values_operand.sqrt()
 
@@ -2216,6 +3115,14 @@ class EdgeMultipleValuesWithIndexGroupOperand:
             self._multiple_values_operand.ungroup()
         )
 
+    def sorted_by_key(self) -> None:
+        self._multiple_values_operand.sorted_by_key()
+
+    def sorted_by_aggregate(self, aggregate: GroupAggregate) -> None:
+        self._multiple_values_operand.sorted_by_aggregate(
+            aggregate._single_value_operand
+        )
+
     def clone(self) -> EdgeMultipleValuesWithIndexGroupOperand:
         return EdgeMultipleValuesWithIndexGroupOperand._from_py_multiple_values_operand(
             self._multiple_values_operand.deep_clone()
@@ -2253,6 +3160,11 @@ class EdgeMultipleValuesWithoutIndexOperand:
             self._multiple_values_operand.median()
         )
 
+    def quantile(self, quantile: float) -> EdgeSingleValueWithoutIndexOperand:
+        return EdgeSingleValueWithoutIndexOperand._from_py_single_value_operand(
+            self._multiple_values_operand.quantile(quantile)
+        )
+
     def mode(self) -> EdgeSingleValueWithoutIndexOperand:
         return EdgeSingleValueWithoutIndexOperand._from_py_single_value_operand(
             self._multiple_values_operand.mode()
@@ -2273,6 +3185,26 @@ class EdgeMultipleValuesWithoutIndexOperand:
             self._multiple_values_operand.count()
         )
 
+    def count_distinct(self) -> EdgeSingleValueWithoutIndexOperand:
+        return EdgeSingleValueWithoutIndexOperand._from_py_single_value_operand(
+            self._multiple_values_operand.count_distinct()
+        )
+
+    def value_counts(self) -> EdgeValueCountsOperand:
+        return EdgeValueCountsOperand._from_py_value_counts_operand(
+            self._multiple_values_operand.value_counts()
+        )
+
+    def histogram(self, bins: int) -> EdgeHistogramOperand:
+        return EdgeHistogramOperand._from_py_histogram_operand(
+            self._multiple_values_operand.histogram(bins)
+        )
+
+    def quantiles(self, quantiles: List[float]) -> EdgeQuantilesOperand:
+        return EdgeQuantilesOperand._from_py_quantiles_operand(
+            self._multiple_values_operand.quantiles(quantiles)
+        )
+
     def sum(self) -> EdgeSingleValueWithoutIndexOperand:
         return EdgeSingleValueWithoutIndexOperand._from_py_single_value_operand(
             self._multiple_values_operand.sum()
@@ -2304,12 +3236,27 @@ class EdgeMultipleValuesWithoutIndexOperand:
     def is_null(self) -> None:
         self._multiple_values_operand.is_null()
 
+    def is_missing(self) -> None:
+        self._multiple_values_operand.is_missing()
+
     def is_max(self) -> None:
         self._multiple_values_operand.is_max()
 
     def is_min(self) -> None:
         self._multiple_values_operand.is_min()
 
+    def distinct(self) -> None:
+        self._multiple_values_operand.distinct()
+
+    def sort(self, descending: bool = False) -> None:
+        self._multiple_values_operand.sort(descending)
+
+    def skip(self, count: int) -> None:
+        self._multiple_values_operand.skip(count)
+
+    def limit(self, count: int) -> None:
+        self._multiple_values_operand.limit(count)
+
     def greater_than(self, value: SingleValueComparisonOperand) -> None:
         self._multiple_values_operand.greater_than(
             _py_single_value_comparison_operand_from_single_value_comparison_operand(
@@ -2387,6 +3334,44 @@ class EdgeMultipleValuesWithoutIndexOperand:
             )
         )
 
+    def levenshtein_at_most(
+        self, target: SingleValueComparisonOperand, distance: int
+    ) -> None:
+        self._multiple_values_operand.levenshtein_at_most(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                target
+            ),
+            distance,
+        )
+
+    def jaro_winkler_at_least(
+        self, target: SingleValueComparisonOperand, similarity: float
+    ) -> None:
+        self._multiple_values_operand.jaro_winkler_at_least(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                target
+            ),
+            similarity,
+        )
+
+    def is_close(self, value: SingleValueComparisonOperand, tolerance: float) -> None:
+        self._multiple_values_operand.is_close(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                value
+            ),
+            tolerance,
+        )
+
+    def within_radius(
+        self, point: SingleValueComparisonOperand, radius_km: float
+    ) -> None:
+        self._multiple_values_operand.within_radius(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                point
+            ),
+            radius_km,
+        )
+
     def add(self, value: SingleValueArithmeticOperand) -> None:
         self._multiple_values_operand.add(
             _py_single_value_comparison_operand_from_single_value_comparison_operand(
@@ -2441,6 +3426,9 @@ class EdgeMultipleValuesWithoutIndexOperand:
     def absolute(self) -> None:
         self._multiple_values_operand.abs()
 
+    def apply(self, udf: str) -> None:
+        self._multiple_values_operand.apply(udf)
+
     def sqrt(self) -> None:
         self._multiple_values_operand.sqrt()
 
@@ -2529,6 +3517,9 @@ class NodeSingleValueWithIndexOperand:
     def is_null(self) -> None:
         self._single_value_operand.is_null()
 
+    def is_missing(self) -> None:
+        self._single_value_operand.is_missing()
+
     def greater_than(self, value: SingleValueComparisonOperand) -> None:
         self._single_value_operand.greater_than(
             _py_single_value_comparison_operand_from_single_value_comparison_operand(
@@ -2588,22 +3579,60 @@ class NodeSingleValueWithIndexOperand:
     def starts_with(self, value: SingleValueComparisonOperand) -> None:
         self._single_value_operand.starts_with(
             _py_single_value_comparison_operand_from_single_value_comparison_operand(
-                value
-            )
+                value
+            )
+        )
+
+    def ends_with(self, value: SingleValueComparisonOperand) -> None:
+        self._single_value_operand.ends_with(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                value
+            )
+        )
+
+    def contains(self, value: SingleValueComparisonOperand) -> None:
+        self._single_value_operand.contains(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                value
+            )
+        )
+
+    def levenshtein_at_most(
+        self, target: SingleValueComparisonOperand, distance: int
+    ) -> None:
+        self._single_value_operand.levenshtein_at_most(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                target
+            ),
+            distance,
+        )
+
+    def jaro_winkler_at_least(
+        self, target: SingleValueComparisonOperand, similarity: float
+    ) -> None:
+        self._single_value_operand.jaro_winkler_at_least(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                target
+            ),
+            similarity,
         )
 
-    def ends_with(self, value: SingleValueComparisonOperand) -> None:
-        self._single_value_operand.ends_with(
+    def is_close(self, value: SingleValueComparisonOperand, tolerance: float) -> None:
+        self._single_value_operand.is_close(
             _py_single_value_comparison_operand_from_single_value_comparison_operand(
                 value
-            )
+            ),
+            tolerance,
         )
 
-    def contains(self, value: SingleValueComparisonOperand) -> None:
-        self._single_value_operand.contains(
+    def within_radius(
+        self, point: SingleValueComparisonOperand, radius_km: float
+    ) -> None:
+        self._single_value_operand.within_radius(
             _py_single_value_comparison_operand_from_single_value_comparison_operand(
-                value
-            )
+                point
+            ),
+            radius_km,
         )
 
     def add(self, value: SingleValueArithmeticOperand) -> None:
@@ -2660,6 +3689,9 @@ class NodeSingleValueWithIndexOperand:
     def absolute(self) -> None:
         self._single_value_operand.abs()
 
+    def apply(self, udf: str) -> None:
+        self._single_value_operand.apply(udf)
+
     def sqrt(self) -> None:
         self._single_value_operand.sqrt()
 
@@ -2740,6 +3772,9 @@ class NodeSingleValueWithIndexGroupOperand:
     def is_null(self) -> None:
         self._single_value_operand.is_null()
 
+    def is_missing(self) -> None:
+        self._single_value_operand.is_missing()
+
     def greater_than(self, value: SingleValueComparisonOperand) -> None:
         self._single_value_operand.greater_than(
             _py_single_value_comparison_operand_from_single_value_comparison_operand(
@@ -2817,6 +3852,44 @@ class NodeSingleValueWithIndexGroupOperand:
             )
         )
 
+    def levenshtein_at_most(
+        self, target: SingleValueComparisonOperand, distance: int
+    ) -> None:
+        self._single_value_operand.levenshtein_at_most(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                target
+            ),
+            distance,
+        )
+
+    def jaro_winkler_at_least(
+        self, target: SingleValueComparisonOperand, similarity: float
+    ) -> None:
+        self._single_value_operand.jaro_winkler_at_least(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                target
+            ),
+            similarity,
+        )
+
+    def is_close(self, value: SingleValueComparisonOperand, tolerance: float) -> None:
+        self._single_value_operand.is_close(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                value
+            ),
+            tolerance,
+        )
+
+    def within_radius(
+        self, point: SingleValueComparisonOperand, radius_km: float
+    ) -> None:
+        self._single_value_operand.within_radius(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                point
+            ),
+            radius_km,
+        )
+
     def add(self, value: SingleValueArithmeticOperand) -> None:
         self._single_value_operand.add(
             _py_single_value_comparison_operand_from_single_value_comparison_operand(
@@ -2871,6 +3944,9 @@ class NodeSingleValueWithIndexGroupOperand:
     def absolute(self) -> None:
         self._single_value_operand.abs()
 
+    def apply(self, udf: str) -> None:
+        self._single_value_operand.apply(udf)
+
     def sqrt(self) -> None:
         self._single_value_operand.sqrt()
 
@@ -2918,6 +3994,12 @@ class NodeSingleValueWithIndexGroupOperand:
             self._single_value_operand.ungroup()
         )
 
+    def sorted_by_key(self) -> None:
+        self._single_value_operand.sorted_by_key()
+
+    def sorted_by_aggregate(self, aggregate: GroupAggregate) -> None:
+        self._single_value_operand.sorted_by_aggregate(aggregate._single_value_operand)
+
     def clone(self) -> NodeSingleValueWithIndexGroupOperand:
         return NodeSingleValueWithIndexGroupOperand._from_py_single_value_operand(
             self._single_value_operand.deep_clone()
@@ -2956,6 +4038,9 @@ class NodeSingleValueWithoutIndexOperand:
     def is_null(self) -> None:
         self._single_value_operand.is_null()
 
+    def is_missing(self) -> None:
+        self._single_value_operand.is_missing()
+
     def greater_than(self, value: SingleValueComparisonOperand) -> None:
         self._single_value_operand.greater_than(
             _py_single_value_comparison_operand_from_single_value_comparison_operand(
@@ -3033,6 +4118,44 @@ class NodeSingleValueWithoutIndexOperand:
             )
         )
 
+    def levenshtein_at_most(
+        self, target: SingleValueComparisonOperand, distance: int
+    ) -> None:
+        self._single_value_operand.levenshtein_at_most(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                target
+            ),
+            distance,
+        )
+
+    def jaro_winkler_at_least(
+        self, target: SingleValueComparisonOperand, similarity: float
+    ) -> None:
+        self._single_value_operand.jaro_winkler_at_least(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                target
+            ),
+            similarity,
+        )
+
+    def is_close(self, value: SingleValueComparisonOperand, tolerance: float) -> None:
+        self._single_value_operand.is_close(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                value
+            ),
+            tolerance,
+        )
+
+    def within_radius(
+        self, point: SingleValueComparisonOperand, radius_km: float
+    ) -> None:
+        self._single_value_operand.within_radius(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                point
+            ),
+            radius_km,
+        )
+
     def add(self, value: SingleValueArithmeticOperand) -> None:
         self._single_value_operand.add(
             _py_single_value_comparison_operand_from_single_value_comparison_operand(
@@ -3087,6 +4210,9 @@ class NodeSingleValueWithoutIndexOperand:
     def absolute(self) -> None:
         self._single_value_operand.abs()
 
+    def apply(self, udf: str) -> None:
+        self._single_value_operand.apply(udf)
+
     def sqrt(self) -> None:
         self._single_value_operand.sqrt()
 
@@ -3169,6 +4295,9 @@ class NodeSingleValueWithoutIndexGroupOperand:
     def is_null(self) -> None:
         self._single_value_operand.is_null()
 
+    def is_missing(self) -> None:
+        self._single_value_operand.is_missing()
+
     def greater_than(self, value: SingleValueComparisonOperand) -> None:
         self._single_value_operand.greater_than(
             _py_single_value_comparison_operand_from_single_value_comparison_operand(
@@ -3246,6 +4375,44 @@ class NodeSingleValueWithoutIndexGroupOperand:
             )
         )
 
+    def levenshtein_at_most(
+        self, target: SingleValueComparisonOperand, distance: int
+    ) -> None:
+        self._single_value_operand.levenshtein_at_most(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                target
+            ),
+            distance,
+        )
+
+    def jaro_winkler_at_least(
+        self, target: SingleValueComparisonOperand, similarity: float
+    ) -> None:
+        self._single_value_operand.jaro_winkler_at_least(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                target
+            ),
+            similarity,
+        )
+
+    def is_close(self, value: SingleValueComparisonOperand, tolerance: float) -> None:
+        self._single_value_operand.is_close(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                value
+            ),
+            tolerance,
+        )
+
+    def within_radius(
+        self, point: SingleValueComparisonOperand, radius_km: float
+    ) -> None:
+        self._single_value_operand.within_radius(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                point
+            ),
+            radius_km,
+        )
+
     def add(self, value: SingleValueArithmeticOperand) -> None:
         self._single_value_operand.add(
             _py_single_value_comparison_operand_from_single_value_comparison_operand(
@@ -3300,6 +4467,9 @@ class NodeSingleValueWithoutIndexGroupOperand:
     def absolute(self) -> None:
         self._single_value_operand.abs()
 
+    def apply(self, udf: str) -> None:
+        self._single_value_operand.apply(udf)
+
     def sqrt(self) -> None:
         self._single_value_operand.sqrt()
 
@@ -3349,6 +4519,12 @@ class NodeSingleValueWithoutIndexGroupOperand:
             self._single_value_operand.ungroup()
         )
 
+    def sorted_by_key(self) -> None:
+        self._single_value_operand.sorted_by_key()
+
+    def sorted_by_aggregate(self, aggregate: GroupAggregate) -> None:
+        self._single_value_operand.sorted_by_aggregate(aggregate._single_value_operand)
+
     def clone(self) -> NodeSingleValueWithoutIndexGroupOperand:
         return NodeSingleValueWithoutIndexGroupOperand._from_py_single_value_operand(
             self._single_value_operand.deep_clone()
@@ -3387,6 +4563,9 @@ class EdgeSingleValueWithIndexOperand:
     def is_null(self) -> None:
         self._single_value_operand.is_null()
 
+    def is_missing(self) -> None:
+        self._single_value_operand.is_missing()
+
     def greater_than(self, value: SingleValueComparisonOperand) -> None:
         self._single_value_operand.greater_than(
             _py_single_value_comparison_operand_from_single_value_comparison_operand(
@@ -3464,6 +4643,44 @@ class EdgeSingleValueWithIndexOperand:
             )
         )
 
+    def levenshtein_at_most(
+        self, target: SingleValueComparisonOperand, distance: int
+    ) -> None:
+        self._single_value_operand.levenshtein_at_most(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                target
+            ),
+            distance,
+        )
+
+    def jaro_winkler_at_least(
+        self, target: SingleValueComparisonOperand, similarity: float
+    ) -> None:
+        self._single_value_operand.jaro_winkler_at_least(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                target
+            ),
+            similarity,
+        )
+
+    def is_close(self, value: SingleValueComparisonOperand, tolerance: float) -> None:
+        self._single_value_operand.is_close(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                value
+            ),
+            tolerance,
+        )
+
+    def within_radius(
+        self, point: SingleValueComparisonOperand, radius_km: float
+    ) -> None:
+        self._single_value_operand.within_radius(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                point
+            ),
+            radius_km,
+        )
+
     def add(self, value: SingleValueArithmeticOperand) -> None:
         self._single_value_operand.add(
             _py_single_value_comparison_operand_from_single_value_comparison_operand(
@@ -3518,6 +4735,9 @@ class EdgeSingleValueWithIndexOperand:
     def absolute(self) -> None:
         self._single_value_operand.abs()
 
+    def apply(self, udf: str) -> None:
+        self._single_value_operand.apply(udf)
+
     def sqrt(self) -> None:
         self._single_value_operand.sqrt()
 
@@ -3598,6 +4818,9 @@ class EdgeSingleValueWithIndexGroupOperand:
     def is_null(self) -> None:
         self._single_value_operand.is_null()
 
+    def is_missing(self) -> None:
+        self._single_value_operand.is_missing()
+
     def greater_than(self, value: SingleValueComparisonOperand) -> None:
         self._single_value_operand.greater_than(
             _py_single_value_comparison_operand_from_single_value_comparison_operand(
@@ -3675,6 +4898,44 @@ class EdgeSingleValueWithIndexGroupOperand:
             )
         )
 
+    def levenshtein_at_most(
+        self, target: SingleValueComparisonOperand, distance: int
+    ) -> None:
+        self._single_value_operand.levenshtein_at_most(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                target
+            ),
+            distance,
+        )
+
+    def jaro_winkler_at_least(
+        self, target: SingleValueComparisonOperand, similarity: float
+    ) -> None:
+        self._single_value_operand.jaro_winkler_at_least(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                target
+            ),
+            similarity,
+        )
+
+    def is_close(self, value: SingleValueComparisonOperand, tolerance: float) -> None:
+        self._single_value_operand.is_close(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                value
+            ),
+            tolerance,
+        )
+
+    def within_radius(
+        self, point: SingleValueComparisonOperand, radius_km: float
+    ) -> None:
+        self._single_value_operand.within_radius(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                point
+            ),
+            radius_km,
+        )
+
     def add(self, value: SingleValueArithmeticOperand) -> None:
         self._single_value_operand.add(
             _py_single_value_comparison_operand_from_single_value_comparison_operand(
@@ -3729,6 +4990,9 @@ class EdgeSingleValueWithIndexGroupOperand:
     def absolute(self) -> None:
         self._single_value_operand.abs()
 
+    def apply(self, udf: str) -> None:
+        self._single_value_operand.apply(udf)
+
     def sqrt(self) -> None:
         self._single_value_operand.sqrt()
 
@@ -3776,6 +5040,12 @@ class EdgeSingleValueWithIndexGroupOperand:
             self._single_value_operand.ungroup()
         )
 
+    def sorted_by_key(self) -> None:
+        self._single_value_operand.sorted_by_key()
+
+    def sorted_by_aggregate(self, aggregate: GroupAggregate) -> None:
+        self._single_value_operand.sorted_by_aggregate(aggregate._single_value_operand)
+
     def clone(self) -> EdgeSingleValueWithIndexGroupOperand:
         return EdgeSingleValueWithIndexGroupOperand._from_py_single_value_operand(
             self._single_value_operand.deep_clone()
@@ -3814,6 +5084,9 @@ class EdgeSingleValueWithoutIndexOperand:
     def is_null(self) -> None:
         self._single_value_operand.is_null()
 
+    def is_missing(self) -> None:
+        self._single_value_operand.is_missing()
+
     def greater_than(self, value: SingleValueComparisonOperand) -> None:
         self._single_value_operand.greater_than(
             _py_single_value_comparison_operand_from_single_value_comparison_operand(
@@ -3891,6 +5164,44 @@ class EdgeSingleValueWithoutIndexOperand:
             )
         )
 
+    def levenshtein_at_most(
+        self, target: SingleValueComparisonOperand, distance: int
+    ) -> None:
+        self._single_value_operand.levenshtein_at_most(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                target
+            ),
+            distance,
+        )
+
+    def jaro_winkler_at_least(
+        self, target: SingleValueComparisonOperand, similarity: float
+    ) -> None:
+        self._single_value_operand.jaro_winkler_at_least(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                target
+            ),
+            similarity,
+        )
+
+    def is_close(self, value: SingleValueComparisonOperand, tolerance: float) -> None:
+        self._single_value_operand.is_close(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                value
+            ),
+            tolerance,
+        )
+
+    def within_radius(
+        self, point: SingleValueComparisonOperand, radius_km: float
+    ) -> None:
+        self._single_value_operand.within_radius(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                point
+            ),
+            radius_km,
+        )
+
     def add(self, value: SingleValueArithmeticOperand) -> None:
         self._single_value_operand.add(
             _py_single_value_comparison_operand_from_single_value_comparison_operand(
@@ -3945,6 +5256,9 @@ class EdgeSingleValueWithoutIndexOperand:
     def absolute(self) -> None:
         self._single_value_operand.abs()
 
+    def apply(self, udf: str) -> None:
+        self._single_value_operand.apply(udf)
+
     def sqrt(self) -> None:
         self._single_value_operand.sqrt()
 
@@ -4027,6 +5341,9 @@ class EdgeSingleValueWithoutIndexGroupOperand:
     def is_null(self) -> None:
         self._single_value_operand.is_null()
 
+    def is_missing(self) -> None:
+        self._single_value_operand.is_missing()
+
     def greater_than(self, value: SingleValueComparisonOperand) -> None:
         self._single_value_operand.greater_than(
             _py_single_value_comparison_operand_from_single_value_comparison_operand(
@@ -4104,6 +5421,44 @@ class EdgeSingleValueWithoutIndexGroupOperand:
             )
         )
 
+    def levenshtein_at_most(
+        self, target: SingleValueComparisonOperand, distance: int
+    ) -> None:
+        self._single_value_operand.levenshtein_at_most(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                target
+            ),
+            distance,
+        )
+
+    def jaro_winkler_at_least(
+        self, target: SingleValueComparisonOperand, similarity: float
+    ) -> None:
+        self._single_value_operand.jaro_winkler_at_least(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                target
+            ),
+            similarity,
+        )
+
+    def is_close(self, value: SingleValueComparisonOperand, tolerance: float) -> None:
+        self._single_value_operand.is_close(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                value
+            ),
+            tolerance,
+        )
+
+    def within_radius(
+        self, point: SingleValueComparisonOperand, radius_km: float
+    ) -> None:
+        self._single_value_operand.within_radius(
+            _py_single_value_comparison_operand_from_single_value_comparison_operand(
+                point
+            ),
+            radius_km,
+        )
+
     def add(self, value: SingleValueArithmeticOperand) -> None:
         self._single_value_operand.add(
             _py_single_value_comparison_operand_from_single_value_comparison_operand(
@@ -4158,6 +5513,9 @@ class EdgeSingleValueWithoutIndexGroupOperand:
     def absolute(self) -> None:
         self._single_value_operand.abs()
 
+    def apply(self, udf: str) -> None:
+        self._single_value_operand.apply(udf)
+
     def sqrt(self) -> None:
         self._single_value_operand.sqrt()
 
@@ -4207,6 +5565,12 @@ class EdgeSingleValueWithoutIndexGroupOperand:
             self._single_value_operand.ungroup()
         )
 
+    def sorted_by_key(self) -> None:
+        self._single_value_operand.sorted_by_key()
+
+    def sorted_by_aggregate(self, aggregate: GroupAggregate) -> None:
+        self._single_value_operand.sorted_by_aggregate(aggregate._single_value_operand)
+
     def clone(self) -> EdgeSingleValueWithoutIndexGroupOperand:
         return EdgeSingleValueWithoutIndexGroupOperand._from_py_single_value_operand(
             self._single_value_operand.deep_clone()
@@ -4646,6 +6010,14 @@ class NodeAttributesTreeGroupOperand:
             self._attributes_tree_operand.ungroup()
         )
 
+    def sorted_by_key(self) -> None:
+        self._attributes_tree_operand.sorted_by_key()
+
+    def sorted_by_aggregate(self, aggregate: GroupAggregate) -> None:
+        self._attributes_tree_operand.sorted_by_aggregate(
+            aggregate._single_value_operand
+        )
+
     def clone(self) -> NodeAttributesTreeGroupOperand:
         return NodeAttributesTreeGroupOperand._from_py_attributes_tree_operand(
             self._attributes_tree_operand.deep_clone()
@@ -5085,6 +6457,14 @@ class EdgeAttributesTreeGroupOperand:
             self._attributes_tree_operand.ungroup()
         )
 
+    def sorted_by_key(self) -> None:
+        self._attributes_tree_operand.sorted_by_key()
+
+    def sorted_by_aggregate(self, aggregate: GroupAggregate) -> None:
+        self._attributes_tree_operand.sorted_by_aggregate(
+            aggregate._single_value_operand
+        )
+
     def clone(self) -> EdgeAttributesTreeGroupOperand:
         return EdgeAttributesTreeGroupOperand._from_py_attributes_tree_operand(
             self._attributes_tree_operand.deep_clone()
@@ -5552,6 +6932,14 @@ class NodeMultipleAttributesWithIndexGroupOperand:
             )
         )
 
+    def sorted_by_key(self) -> None:
+        self._multiple_attributes_operand.sorted_by_key()
+
+    def sorted_by_aggregate(self, aggregate: GroupAggregate) -> None:
+        self._multiple_attributes_operand.sorted_by_aggregate(
+            aggregate._single_value_operand
+        )
+
     def clone(self) -> NodeMultipleAttributesWithIndexGroupOperand:
         return NodeMultipleAttributesWithIndexGroupOperand._from_py_multiple_attributes_operand(
             self._multiple_attributes_operand.deep_clone()
@@ -6244,6 +7632,14 @@ class EdgeMultipleAttributesWithIndexGroupOperand:
             )
         )
 
+    def sorted_by_key(self) -> None:
+        self._multiple_attributes_operand.sorted_by_key()
+
+    def sorted_by_aggregate(self, aggregate: GroupAggregate) -> None:
+        self._multiple_attributes_operand.sorted_by_aggregate(
+            aggregate._single_value_operand
+        )
+
     def clone(self) -> EdgeMultipleAttributesWithIndexGroupOperand:
         return EdgeMultipleAttributesWithIndexGroupOperand._from_py_multiple_attributes_operand(
             self._multiple_attributes_operand.deep_clone()
@@ -6854,6 +8250,14 @@ class NodeSingleAttributeWithIndexGroupOperand:
             )
         )
 
+    def sorted_by_key(self) -> None:
+        self._single_attribute_operand.sorted_by_key()
+
+    def sorted_by_aggregate(self, aggregate: GroupAggregate) -> None:
+        self._single_attribute_operand.sorted_by_aggregate(
+            aggregate._single_value_operand
+        )
+
     def clone(self) -> NodeSingleAttributeWithIndexGroupOperand:
         return (
             NodeSingleAttributeWithIndexGroupOperand._from_py_single_attribute_operand(
@@ -7239,6 +8643,14 @@ class NodeSingleAttributeWithoutIndexGroupOperand:
             self._single_attribute_operand.ungroup()
         )
 
+    def sorted_by_key(self) -> None:
+        self._single_attribute_operand.sorted_by_key()
+
+    def sorted_by_aggregate(self, aggregate: GroupAggregate) -> None:
+        self._single_attribute_operand.sorted_by_aggregate(
+            aggregate._single_value_operand
+        )
+
     def clone(self) -> NodeSingleAttributeWithoutIndexGroupOperand:
         return NodeSingleAttributeWithoutIndexGroupOperand._from_py_single_attribute_operand(
             self._single_attribute_operand.deep_clone()
@@ -7624,6 +9036,14 @@ class EdgeSingleAttributeWithIndexGroupOperand:
             )
         )
 
+    def sorted_by_key(self) -> None:
+        self._single_attribute_operand.sorted_by_key()
+
+    def sorted_by_aggregate(self, aggregate: GroupAggregate) -> None:
+        self._single_attribute_operand.sorted_by_aggregate(
+            aggregate._single_value_operand
+        )
+
     def clone(self) -> EdgeSingleAttributeWithIndexGroupOperand:
         return (
             EdgeSingleAttributeWithIndexGroupOperand._from_py_single_attribute_operand(
@@ -8009,6 +9429,14 @@ class EdgeSingleAttributeWithoutIndexGroupOperand:
             self._single_attribute_operand.ungroup()
         )
 
+    def sorted_by_key(self) -> None:
+        self._single_attribute_operand.sorted_by_key()
+
+    def sorted_by_aggregate(self, aggregate: GroupAggregate) -> None:
+        self._single_attribute_operand.sorted_by_aggregate(
+            aggregate._single_value_operand
+        )
+
     def clone(self) -> EdgeSingleAttributeWithoutIndexGroupOperand:
         return EdgeSingleAttributeWithoutIndexGroupOperand._from_py_single_attribute_operand(
             self._single_attribute_operand.deep_clone()
@@ -8063,6 +9491,15 @@ class NodeIndicesOperand:
     def is_min(self) -> None:
         self._node_indices_operand.is_min()
 
+    def sort(self, descending: bool = False) -> None:
+        self._node_indices_operand.sort(descending)
+
+    def skip(self, count: int) -> None:
+        self._node_indices_operand.skip(count)
+
+    def limit(self, count: int) -> None:
+        self._node_indices_operand.limit(count)
+
     def greater_than(self, index: NodeIndexComparisonOperand) -> None:
         self._node_indices_operand.greater_than(
             _py_node_index_comparison_operand_from_node_index_comparison_operand(index)
@@ -8243,6 +9680,15 @@ class NodeIndicesGroupOperand:
     def is_min(self) -> None:
         self._node_indices_operand.is_min()
 
+    def sort(self, descending: bool = False) -> None:
+        self._node_indices_operand.sort(descending)
+
+    def skip(self, count: int) -> None:
+        self._node_indices_operand.skip(count)
+
+    def limit(self, count: int) -> None:
+        self._node_indices_operand.limit(count)
+
     def greater_than(self, index: NodeIndexComparisonOperand) -> None:
         self._node_indices_operand.greater_than(
             _py_node_index_comparison_operand_from_node_index_comparison_operand(index)
@@ -8374,6 +9820,12 @@ class NodeIndicesGroupOperand:
             self._node_indices_operand.ungroup()
         )
 
+    def sorted_by_key(self) -> None:
+        self._node_indices_operand.sorted_by_key()
+
+    def sorted_by_aggregate(self, aggregate: GroupAggregate) -> None:
+        self._node_indices_operand.sorted_by_aggregate(aggregate._single_value_operand)
+
     def clone(self) -> NodeIndicesGroupOperand:
         return NodeIndicesGroupOperand._from_py_node_indices_operand(
             self._node_indices_operand.deep_clone()
@@ -8677,6 +10129,12 @@ class NodeIndexGroupOperand:
             self._node_index_operand.ungroup()
         )
 
+    def sorted_by_key(self) -> None:
+        self._node_index_operand.sorted_by_key()
+
+    def sorted_by_aggregate(self, aggregate: GroupAggregate) -> None:
+        self._node_index_operand.sorted_by_aggregate(aggregate._single_value_operand)
+
     def clone(self) -> NodeIndexGroupOperand:
         return NodeIndexGroupOperand._from_py_node_index_operand(
             self._node_index_operand.deep_clone()
@@ -8725,6 +10183,15 @@ class EdgeIndicesOperand:
     def is_min(self) -> None:
         self._edge_indices_operand.is_min()
 
+    def sort(self, descending: bool = False) -> None:
+        self._edge_indices_operand.sort(descending)
+
+    def skip(self, count: int) -> None:
+        self._edge_indices_operand.skip(count)
+
+    def limit(self, count: int) -> None:
+        self._edge_indices_operand.limit(count)
+
     def greater_than(self, index: EdgeIndexComparisonOperand) -> None:
         self._edge_indices_operand.greater_than(
             _py_edge_index_comparison_operand_from_edge_index_comparison_operand(index)
@@ -8878,6 +10345,15 @@ class EdgeIndicesGroupOperand:
     def is_min(self) -> None:
         self._edge_indices_operand.is_min()
 
+    def sort(self, descending: bool = False) -> None:
+        self._edge_indices_operand.sort(descending)
+
+    def skip(self, count: int) -> None:
+        self._edge_indices_operand.skip(count)
+
+    def limit(self, count: int) -> None:
+        self._edge_indices_operand.limit(count)
+
     def greater_than(self, index: EdgeIndexComparisonOperand) -> None:
         self._edge_indices_operand.greater_than(
             _py_edge_index_comparison_operand_from_edge_index_comparison_operand(index)
@@ -8988,6 +10464,12 @@ class EdgeIndicesGroupOperand:
             self._edge_indices_operand.ungroup()
         )
 
+    def sorted_by_key(self) -> None:
+        self._edge_indices_operand.sorted_by_key()
+
+    def sorted_by_aggregate(self, aggregate: GroupAggregate) -> None:
+        self._edge_indices_operand.sorted_by_aggregate(aggregate._single_value_operand)
+
     def clone(self) -> EdgeIndicesGroupOperand:
         return EdgeIndicesGroupOperand._from_edge_indices_operand(
             self._edge_indices_operand.deep_clone()
@@ -9237,6 +10719,12 @@ class EdgeIndexGroupOperand:
             self._edge_index_operand.ungroup()
         )
 
+    def sorted_by_key(self) -> None:
+        self._edge_index_operand.sorted_by_key()
+
+    def sorted_by_aggregate(self, aggregate: GroupAggregate) -> None:
+        self._edge_index_operand.sorted_by_aggregate(aggregate._single_value_operand)
+
     def clone(self) -> EdgeIndexGroupOperand:
         return EdgeIndexGroupOperand._from_py_edge_index_operand(
             self._edge_index_operand.deep_clone()
@@ -9249,3 +10737,104 @@ class EdgeIndexGroupOperand:
         edge_index_operand = cls()
         edge_index_operand._edge_index_operand = py_edge_index_operand
         return edge_index_operand
+
+
+class NodeValueCountsOperand:
+    _value_counts_operand: PyNodeValueCountsOperand
+
+    def clone(self) -> NodeValueCountsOperand:
+        return NodeValueCountsOperand._from_py_value_counts_operand(
+            self._value_counts_operand.deep_clone()
+        )
+
+    @classmethod
+    def _from_py_value_counts_operand(
+        cls, py_value_counts_operand: PyNodeValueCountsOperand
+    ) -> NodeValueCountsOperand:
+        value_counts_operand = cls()
+        value_counts_operand._value_counts_operand = py_value_counts_operand
+        return value_counts_operand
+
+
+class NodeHistogramOperand:
+    _histogram_operand: PyNodeHistogramOperand
+
+    def clone(self) -> NodeHistogramOperand:
+        return NodeHistogramOperand._from_py_histogram_operand(
+            self._histogram_operand.deep_clone()
+        )
+
+    @classmethod
+    def _from_py_histogram_operand(
+        cls, py_histogram_operand: PyNodeHistogramOperand
+    ) -> NodeHistogramOperand:
+        histogram_operand = cls()
+        histogram_operand._histogram_operand = py_histogram_operand
+        return histogram_operand
+
+class EdgeValueCountsOperand:
+    _value_counts_operand: PyEdgeValueCountsOperand
+
+    def clone(self) -> EdgeValueCountsOperand:
+        return EdgeValueCountsOperand._from_py_value_counts_operand(
+            self._value_counts_operand.deep_clone()
+        )
+
+    @classmethod
+    def _from_py_value_counts_operand(
+        cls, py_value_counts_operand: PyEdgeValueCountsOperand
+    ) -> EdgeValueCountsOperand:
+        value_counts_operand = cls()
+        value_counts_operand._value_counts_operand = py_value_counts_operand
+        return value_counts_operand
+
+
+class EdgeHistogramOperand:
+    _histogram_operand: PyEdgeHistogramOperand
+
+    def clone(self) -> EdgeHistogramOperand:
+        return EdgeHistogramOperand._from_py_histogram_operand(
+            self._histogram_operand.deep_clone()
+        )
+
+    @classmethod
+    def _from_py_histogram_operand(
+        cls, py_histogram_operand: PyEdgeHistogramOperand
+    ) -> EdgeHistogramOperand:
+        histogram_operand = cls()
+        histogram_operand._histogram_operand = py_histogram_operand
+        return histogram_operand
+
+
+class NodeQuantilesOperand:
+    _quantiles_operand: PyNodeQuantilesOperand
+
+    def clone(self) -> NodeQuantilesOperand:
+        return NodeQuantilesOperand._from_py_quantiles_operand(
+            self._quantiles_operand.deep_clone()
+        )
+
+    @classmethod
+    def _from_py_quantiles_operand(
+        cls, py_quantiles_operand: PyNodeQuantilesOperand
+    ) -> NodeQuantilesOperand:
+        quantiles_operand = cls()
+        quantiles_operand._quantiles_operand = py_quantiles_operand
+        return quantiles_operand
+
+
+class EdgeQuantilesOperand:
+    _quantiles_operand: PyEdgeQuantilesOperand
+
+    def clone(self) -> EdgeQuantilesOperand:
+        return EdgeQuantilesOperand._from_py_quantiles_operand(
+            self._quantiles_operand.deep_clone()
+        )
+
+    @classmethod
+    def _from_py_quantiles_operand(
+        cls, py_quantiles_operand: PyEdgeQuantilesOperand
+    ) -> EdgeQuantilesOperand:
+        quantiles_operand = cls()
+        quantiles_operand._quantiles_operand = py_quantiles_operand
+        return quantiles_operand
@@ -11,6 +11,7 @@ from typing import (
     Optional,
     Tuple,
     TypeAlias,
+    TypedDict,
     Union,
     overload,
 )
@@ -18,7 +19,9 @@ from typing import (
 from graphrecords._graphrecords.schema import (
     PyAttributeDataType,
     PyAttributeType,
+    PyConflictStrategy,
     PyGroupSchema,
+    PyNonFiniteFloatPolicy,
     PySchema,
     PySchemaType,
 )
@@ -40,6 +43,7 @@ from graphrecords.types import (
 )
 
 if TYPE_CHECKING:
+    from graphrecords._graphrecords.datatype import PyDataType
     from graphrecords.graphrecord import GraphRecord
     from graphrecords.types import Group
 
@@ -351,6 +355,198 @@ class SchemaType(Enum):
         raise NotImplementedError(msg)
 
 
+class NonFiniteFloatPolicy(Enum):
+    """Enumeration of ways to handle NaN and infinite Float values."""
+
+    Allow = auto()
+    Reject = auto()
+    CoerceToNull = auto()
+
+    @staticmethod
+    def _from_py_non_finite_float_policy(
+        py_policy: PyNonFiniteFloatPolicy,
+    ) -> NonFiniteFloatPolicy:
+        """Converts a PyNonFiniteFloatPolicy to a NonFiniteFloatPolicy.
+
+        Args:
+            py_policy (PyNonFiniteFloatPolicy): The PyNonFiniteFloatPolicy to convert.
+
+        Returns:
+            NonFiniteFloatPolicy: The converted NonFiniteFloatPolicy.
+        """
+        if py_policy == PyNonFiniteFloatPolicy.Allow:
+            return NonFiniteFloatPolicy.Allow
+        if py_policy == PyNonFiniteFloatPolicy.Reject:
+            return NonFiniteFloatPolicy.Reject
+        if py_policy == PyNonFiniteFloatPolicy.CoerceToNull:
+            return NonFiniteFloatPolicy.CoerceToNull
+
+        msg = "Should never be reached"
+        raise NotImplementedError(msg)
+
+    def _into_py_non_finite_float_policy(self) -> PyNonFiniteFloatPolicy:
+        """Converts a NonFiniteFloatPolicy to a PyNonFiniteFloatPolicy.
+
+        Returns:
+            PyNonFiniteFloatPolicy: The converted PyNonFiniteFloatPolicy.
+        """
+        if self == NonFiniteFloatPolicy.Allow:
+            return PyNonFiniteFloatPolicy.Allow
+        if self == NonFiniteFloatPolicy.Reject:
+            return PyNonFiniteFloatPolicy.Reject
+        if self == NonFiniteFloatPolicy.CoerceToNull:
+            return PyNonFiniteFloatPolicy.CoerceToNull
+
+        msg = "Should never be reached"
+        raise NotImplementedError(msg)
+
+
+class ConflictStrategy(Enum):
+    """Enumeration of ways to resolve differing data types when merging schemas."""
+
+    Widen = auto()
+    KeepFirst = auto()
+    KeepSecond = auto()
+    Reject = auto()
+
+    def _into_py_conflict_strategy(self) -> PyConflictStrategy:
+        """Converts a ConflictStrategy to a PyConflictStrategy.
+
+        Returns:
+            PyConflictStrategy: The converted PyConflictStrategy.
+        """
+        if self == ConflictStrategy.Widen:
+            return PyConflictStrategy.Widen
+        if self == ConflictStrategy.KeepFirst:
+            return PyConflictStrategy.KeepFirst
+        if self == ConflictStrategy.KeepSecond:
+            return PyConflictStrategy.KeepSecond
+        if self == ConflictStrategy.Reject:
+            return PyConflictStrategy.Reject
+
+        msg = "Should never be reached"
+        raise NotImplementedError(msg)
+
+
+class SchemaConflict(TypedDict):
+    """An attribute that two merged schemas give different data types."""
+
+    group: Optional[Group]
+    attribute: GraphRecordAttribute
+    first: AttributeDataType
+    second: AttributeDataType
+
+
+class SchemaChange(TypedDict):
+    """An attribute that only one of two schemas has or that they type differently.
+
+    `old` is None for an added attribute, `new` is None for a removed one.
+    """
+
+    group: Optional[Group]
+    attribute: GraphRecordAttribute
+    old: Optional[AttributeDataType]
+    new: Optional[AttributeDataType]
+
+
+class SchemaDiff(TypedDict):
+    """The groups and attributes that differ between two schemas."""
+
+    added_groups: List[Group]
+    removed_groups: List[Group]
+    node_changes: List[SchemaChange]
+    edge_changes: List[SchemaChange]
+
+
+def _from_py_attribute_data_type(
+    attribute_data_type: PyAttributeDataType,
+) -> AttributeDataType:
+    # SAFETY: The typing is guaranteed to be correct
+    return (
+        DataType._from_py_data_type(attribute_data_type.data_type),
+        AttributeType._from_py_attribute_type(attribute_data_type.attribute_type),
+    )  # pyright: ignore[reportReturnType]
+
+
+def _from_py_schema_conflicts(
+    conflicts: List[
+        Tuple[
+            Optional[Group],
+            GraphRecordAttribute,
+            PyAttributeDataType,
+            PyAttributeDataType,
+        ]
+    ],
+) -> List[SchemaConflict]:
+    return [
+        {
+            "group": group,
+            "attribute": attribute,
+            "first": _from_py_attribute_data_type(first),
+            "second": _from_py_attribute_data_type(second),
+        }
+        for group, attribute, first, second in conflicts
+    ]
+
+
+def _from_py_schema_changes(
+    changes: List[
+        Tuple[
+            Optional[Group],
+            GraphRecordAttribute,
+            Optional[PyAttributeDataType],
+            Optional[PyAttributeDataType],
+        ]
+    ],
+) -> List[SchemaChange]:
+    return [
+        {
+            "group": group,
+            "attribute": attribute,
+            "old": _from_py_attribute_data_type(old) if old is not None else None,
+            "new": _from_py_attribute_data_type(new) if new is not None else None,
+        }
+        for group, attribute, old, new in changes
+    ]
+
+
+def _from_py_schema_diff(
+    added_groups: List[Group],
+    removed_groups: List[Group],
+    node_changes: List[
+        Tuple[
+            Optional[Group],
+            GraphRecordAttribute,
+            Optional[PyAttributeDataType],
+            Optional[PyAttributeDataType],
+        ]
+    ],
+    edge_changes: List[
+        Tuple[
+            Optional[Group],
+            GraphRecordAttribute,
+            Optional[PyAttributeDataType],
+            Optional[PyAttributeDataType],
+        ]
+    ],
+) -> SchemaDiff:
+    return {
+        "added_groups": added_groups,
+        "removed_groups": removed_groups,
+        "node_changes": _from_py_schema_changes(node_changes),
+        "edge_changes": _from_py_schema_changes(edge_changes),
+    }
+
+
+def _into_py_widening(
+    widening: List[Tuple[DataType, DataType]],
+) -> List[Tuple[PyDataType, PyDataType]]:
+    return [
+        (from_data_type._inner(), into_data_type._inner())
+        for from_data_type, into_data_type in widening
+    ]
+
+
 class Schema:
     """A schema for a collection of groups."""
 
@@ -393,18 +589,29 @@ class Schema:
             )
 
     @classmethod
-    def infer(cls, graphrecord: GraphRecord) -> Schema:
+    def infer(
+        cls,
+        graphrecord: GraphRecord,
+        widening: Optional[List[Tuple[DataType, DataType]]] = None,
+    ) -> Schema:
         """Infers a schema from a GraphRecord instance.
 
         Args:
             graphrecord (GraphRecord): The GraphRecord instance to infer the
                 schema from.
+            widening (Optional[List[Tuple[DataType, DataType]]], optional): Rules
+                that widen the first data type into the second one when an
+                attribute has differing data types, instead of inferring a Union.
+                E.g. `[(Int(), Float())]` infers a mix of 1 and 1.5 as Float.
+                Defaults to None.
 
         Returns:
             Schema: The inferred schema.
         """
         new_schema = cls()
-        new_schema._schema = PySchema.infer(graphrecord._graphrecord)
+        new_schema._schema = PySchema.infer(
+            graphrecord._graphrecord, _into_py_widening(widening or [])
+        )
         return new_schema
 
     @classmethod
@@ -462,6 +669,147 @@ class Schema:
         """
         return SchemaType._from_py_schema_type(self._schema.schema_type)
 
+    @property
+    def widening(self) -> List[Tuple[DataType, DataType]]:
+        """Retrieves the rules used to widen differing data types of an attribute.
+
+        Returns:
+            List[Tuple[DataType, DataType]]: The widening rules.
+        """
+        return [
+            (
+                DataType._from_py_data_type(from_data_type),
+                DataType._from_py_data_type(into_data_type),
+            )
+            for from_data_type, into_data_type in self._schema.widening
+        ]
+
+    def set_widening(self, widening: List[Tuple[DataType, DataType]]) -> None:
+        """Sets the rules used to widen differing data types of an attribute.
+
+        The rules form a lattice: with `[(Int(), Float()), (Float(), String())]`
+        an Int and a String are widened into a String. The data types already in
+        the schema are kept, use `Schema.infer` to infer them again.
+
+        Args:
+            widening (List[Tuple[DataType, DataType]]): The widening rules.
+        """
+        self._schema.set_widening(_into_py_widening(widening))
+
+    def infer_attribute_types(
+        self,
+        graphrecord: GraphRecord,
+        max_categories: int = 50,
+        max_distinct_ratio: float = 0.5,
+        overrides: Optional[Dict[GraphRecordAttribute, AttributeType]] = None,
+    ) -> None:
+        """Infers the attribute types of the schema from the values in a GraphRecord.
+
+        String and Int attributes with at most `max_categories` distinct values,
+        which make up at most `max_distinct_ratio` of their non-null values, are
+        Categorical. All other attributes get the attribute type inferred from
+        their data type. The data types of the schema are kept.
+
+        Args:
+            graphrecord (GraphRecord): The GraphRecord to infer the attribute
+                types from.
+            max_categories (int, optional): The maximum number of distinct values
+                of a Categorical attribute. Defaults to 50.
+            max_distinct_ratio (float, optional): The maximum ratio of distinct
+                to non-null values of a Categorical attribute. Defaults to 0.5.
+            overrides (Optional[Dict[GraphRecordAttribute, AttributeType]],
+                optional): Attribute types used instead of the inferred ones.
+                Defaults to None.
+
+        Raises:
+            SchemaError: If an override does not match the data type of its
+                attribute. The schema is left unchanged.
+        """
+        self._schema.infer_attribute_types(
+            graphrecord._graphrecord,
+            max_categories,
+            max_distinct_ratio,
+            {
+                attribute: attribute_type._into_py_attribute_type()
+                for attribute, attribute_type in (overrides or {}).items()
+            },
+        )
+
+    def merge(
+        self,
+        other: Schema,
+        strategy: ConflictStrategy = ConflictStrategy.Widen,
+    ) -> Tuple[Schema, List[SchemaConflict], List[SchemaConflict]]:
+        """Merges the schema with another schema into a new schema.
+
+        The schemas of groups that both schemas have are merged, an attribute that
+        only one of them has becomes optional. Attributes with differing data
+        types are resolved by the strategy and reported as conflicts. The merged
+        schema is only provided if both schemas are provided.
+
+        Args:
+            other (Schema): The schema to merge with.
+            strategy (ConflictStrategy, optional): How attributes with differing
+                data types are resolved. Defaults to ConflictStrategy.Widen.
+
+        Returns:
+            Tuple[Schema, List[SchemaConflict], List[SchemaConflict]]: The merged
+                schema and the conflicting node and edge attributes.
+
+        Raises:
+            SchemaError: If the strategy is Reject and the schemas conflict.
+        """
+        schema, node_conflicts, edge_conflicts = self._schema.merge(
+            other._schema, strategy._into_py_conflict_strategy()
+        )
+
+        return (
+            Schema._from_py_schema(schema),
+            _from_py_schema_conflicts(node_conflicts),
+            _from_py_schema_conflicts(edge_conflicts),
+        )
+
+    def diff(self, new: Schema) -> SchemaDiff:
+        """Returns the groups and attributes that differ from another schema.
+
+        The attributes of added and removed groups are listed as changes as well.
+
+        Args:
+            new (Schema): The schema to compare the schema with.
+
+        Returns:
+            SchemaDiff: The added and removed groups and the changed node and edge
+                attributes.
+        """
+        return _from_py_schema_diff(*self._schema.diff(new._schema))
+
+    @property
+    def non_finite_float_policy(self) -> NonFiniteFloatPolicy:
+        """Retrieves how NaN and infinite Float values are handled.
+
+        Returns:
+            NonFiniteFloatPolicy: The policy for NaN and infinite Float values.
+        """
+        return NonFiniteFloatPolicy._from_py_non_finite_float_policy(
+            self._schema.non_finite_float_policy
+        )
+
+    def set_non_finite_float_policy(self, policy: NonFiniteFloatPolicy) -> None:
+        """Sets how NaN and infinite Float values are handled.
+
+        With `Reject`, adding such a value raises a SchemaError, with
+        `CoerceToNull` it is stored as None. With `Allow` (the default) it is
+        stored as is and ignored by the mean, min, max and median aggregations.
+        The policy only applies to values added after it is set.
+
+        Args:
+            policy (NonFiniteFloatPolicy): The policy for NaN and infinite Float
+                values.
+        """
+        self._schema.set_non_finite_float_policy(
+            policy._into_py_non_finite_float_policy()
+        )
+
     def validate_node(
         self, index: NodeIndex, attributes: Attributes, group: Optional[Group] = None
     ) -> None:
@@ -11,6 +11,7 @@ from typing import (
     List,
     Literal,
     Mapping,
+    Optional,
     Sequence,
     Tuple,
     TypeAlias,
@@ -21,11 +22,21 @@ from typing import (
 import pandas as pd
 import polars as pl
 
+from graphrecords._graphrecords.graphrecord import (
+    PyGeoPoint,
+    PyMissing,
+    PyProvenanceTag,
+    PyRecordMetadata,
+)
+
 if TYPE_CHECKING:
     from typing_extensions import TypeIs
 
     from graphrecords._graphrecords.graphrecord import PyGraphRecord
-    from graphrecords._graphrecords.plugins import PyPreSetSchemaContext
+    from graphrecords._graphrecords.plugins import (
+        PyPostSetSchemaContext,
+        PyPreSetSchemaContext,
+    )
     from graphrecords._graphrecords.schema import PyAttributeType
     from graphrecords.schema import AttributeType
 
@@ -38,8 +49,25 @@ GraphRecordAttributeInputList: TypeAlias = Union[
     List[str], List[int], List[GraphRecordAttribute]
 ]
 
+#: Marks an attribute value that was never recorded, as opposed to ``None``,
+#: which records that the value is absent.
+MISSING = PyMissing()
+
+#: A location given by its latitude and longitude in degrees, stored as an
+#: attribute value for spatial queries such as ``within_radius``.
+GeoPoint = PyGeoPoint
+
+#: Source of an attribute value, with an optional batch id and the time it
+#: was recorded.
+ProvenanceTag = PyProvenanceTag
+
+#: Descriptive header of a GraphRecord, persisted along with it.
+RecordMetadata = PyRecordMetadata
+
 #: A type alias for the value of a GraphRecord attribute.
-GraphRecordValue: TypeAlias = Union[str, int, float, bool, datetime, timedelta, None]
+GraphRecordValue: TypeAlias = Union[
+    str, int, float, bool, datetime, timedelta, PyGeoPoint, PyMissing, None
+]
 
 #: A type alias for a node index.
 NodeIndex: TypeAlias = GraphRecordAttribute
@@ -137,6 +165,8 @@ class PyCategoricalAttributeOverview(TypedDict):
 
     attribute_type: Literal[PyAttributeType.Categorical]
     distinct_values: List[GraphRecordValue]
+    value_counts: List[Tuple[GraphRecordValue, int]]
+    other_count: int
 
 
 class PyContinuousAttributeOverview(TypedDict):
@@ -168,6 +198,8 @@ class CategoricalAttributeOverview(TypedDict):
 
     attribute_type: Literal[AttributeType.Categorical]
     distinct_values: List[GraphRecordValue]
+    value_counts: List[Tuple[GraphRecordValue, int]]
+    other_count: int
 
 
 class ContinuousAttributeOverview(TypedDict):
@@ -194,6 +226,15 @@ class UnstructuredAttributeOverview(TypedDict):
     distinct_count: int
 
 
+class StructureOverview(TypedDict):
+    """Dictionary for the connectivity of the nodes of a group."""
+
+    ungrouped_endpoints: int
+    isolated_nodes: int
+    average_degree: float
+    connected_groups: List[Tuple[Group, int]]
+
+
 class PolarsDataFramesGroupExport(TypedDict):
     """Dictionary for Polars DataFrames export for a group."""
 
@@ -222,6 +263,65 @@ class PandasDataFramesExport(TypedDict):
     groups: Dict[Group, PandasDataFramesGroupExport]
 
 
+class DegreeSummary(TypedDict):
+    """Dictionary summarizing the node degree distribution."""
+
+    min: int
+    mean: float
+    max: int
+
+
+class GroupStats(TypedDict):
+    """Dictionary with the node and edge counts of a group."""
+
+    node_count: int
+    edge_count: int
+
+
+class Stats(TypedDict):
+    """Dictionary with a structural summary of a GraphRecord."""
+
+    node_count: int
+    edge_count: int
+    density: float
+    degree: Optional[DegreeSummary]
+    connected_components: int
+    groups: Dict[Group, GroupStats]
+
+
+class CorrelationTest(TypedDict):
+    """Dictionary with the correlation between two numeric attributes."""
+
+    coefficient: float
+    p_value: float
+    sample_size: int
+
+
+class ChiSquareTest(TypedDict):
+    """Dictionary with a chi-square test between two categorical attributes."""
+
+    statistic: float
+    degrees_of_freedom: int
+    p_value: float
+    cramers_v: float
+    sample_size: int
+
+
+class TimeToEvent(TypedDict):
+    """Dictionary with the follow-up of a node until its first event."""
+
+    duration: timedelta
+    event: bool
+
+
+class EventRate(TypedDict):
+    """Dictionary with the share of the nodes at risk with an event in a window."""
+
+    count: int
+    population: int
+    rate: Optional[float]
+
+
 class _PyPlugin(ABC):  # pyright: ignore[reportUnusedClass]
     @abstractmethod
     def initialize(self, graphrecord: PyGraphRecord) -> None: ...
@@ -230,7 +330,9 @@ class _PyPlugin(ABC):  # pyright: ignore[reportUnusedClass]
         self, graphrecord: PyGraphRecord, context: PyPreSetSchemaContext
     ) -> PyPreSetSchemaContext: ...
     @abstractmethod
-    def post_set_schema(self, graphrecord: PyGraphRecord) -> None: ...
+    def post_set_schema(
+        self, graphrecord: PyGraphRecord, context: PyPostSetSchemaContext
+    ) -> None: ...
 
 
 class _PyConnector(ABC):  # pyright: ignore[reportUnusedClass]
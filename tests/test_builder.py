@@ -11,6 +11,7 @@ from graphrecords.plugins import (
     PostAddGroupContext,
     PostAddNodesContext,
     PostAddNodesWithGroupContext,
+    PostSetSchemaContext,
     PreAddEdgesContext,
     PreAddEdgesWithGroupContext,
     PreAddGroupContext,
@@ -88,7 +89,9 @@ class RecordingPlugin(Plugin):
         self.calls.append("pre_set_schema")
         return context
 
-    def post_set_schema(self, graphrecord: gr.GraphRecord) -> None:
+    def post_set_schema(
+        self, graphrecord: gr.GraphRecord, context: PostSetSchemaContext
+    ) -> None:
         self.calls.append("post_set_schema")
 
 
@@ -7,7 +7,9 @@ from graphrecords._graphrecords.datatype import (
     PyDateTime,
     PyDuration,
     PyFloat,
+    PyGeo,
     PyInt,
+    PyMissingType,
     PyNull,
     PyOption,
     PyString,
@@ -42,6 +44,10 @@ class TestDataType(unittest.TestCase):
         result = DataType._from_py_data_type(py_duration)
         assert isinstance(result, gr.Duration)
 
+        py_geo = PyGeo()
+        result = DataType._from_py_data_type(py_geo)
+        assert isinstance(result, gr.Geo)
+
         py_null = PyNull()
         result = DataType._from_py_data_type(py_null)
         assert isinstance(result, gr.Null)
@@ -50,6 +56,10 @@ class TestDataType(unittest.TestCase):
         result = DataType._from_py_data_type(py_any)
         assert isinstance(result, gr.Any)
 
+        py_missing = PyMissingType()
+        result = DataType._from_py_data_type(py_missing)
+        assert isinstance(result, gr.Missing)
+
         py_union = PyUnion(PyString(), PyInt())
         result = DataType._from_py_data_type(py_union)
         assert isinstance(result, gr.Union)
@@ -131,6 +141,17 @@ class TestDataType(unittest.TestCase):
         assert gr.Duration() == gr.Duration()
         assert gr.Duration() != gr.String()
 
+    def test_geo(self) -> None:
+        geo = gr.Geo()
+        assert isinstance(geo._inner(), PyGeo)
+
+        assert str(geo) == "Geo"
+
+        assert geo.__repr__() == "DataType.Geo"
+
+        assert gr.Geo() == gr.Geo()
+        assert gr.Geo() != gr.String()
+
     def test_null(self) -> None:
         null = gr.Null()
         assert isinstance(null._inner(), PyNull)
@@ -142,6 +163,17 @@ class TestDataType(unittest.TestCase):
         assert gr.Null() == gr.Null()
         assert gr.Null() != gr.String()
 
+    def test_missing(self) -> None:
+        missing = gr.Missing()
+        assert isinstance(missing._inner(), PyMissingType)
+
+        assert str(missing) == "Missing"
+
+        assert missing.__repr__() == "DataType.Missing"
+
+        assert gr.Missing() == gr.Missing()
+        assert gr.Missing() != gr.Null()
+
     def test_any(self) -> None:
         any = gr.Any()
         assert isinstance(any._inner(), PyAny)
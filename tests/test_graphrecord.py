@@ -1,16 +1,42 @@
+import pickle
 import tempfile
+import threading
 import unittest
+from datetime import datetime, timedelta
 from typing import List, Tuple
 
 import pandas as pd
 import polars as pl
 import pytest
 
-from graphrecords import GraphRecord
+from graphrecords import (
+    MISSING,
+    GeoPoint,
+    GraphRecord,
+    ProvenanceTag,
+    RecordMetadata,
+)
 from graphrecords._graphrecords.graphrecord import PyGraphRecord
 from graphrecords.builder import GraphRecordBuilder
 from graphrecords.datatype import Int
-from graphrecords.graphrecord import EdgesDirection
+from graphrecords.errors import (
+    ConversionError,
+    DuplicateGroupError,
+    GraphRecordAssertionError,
+    GraphRecordError,
+    GraphRecordIndexError,
+    MissingNodeError,
+    ReadOnlyError,
+    SchemaError,
+    TypeMismatchError,
+)
+from graphrecords.graphrecord import (
+    AttributeScope,
+    CorrelationMethod,
+    CrosstabNormalization,
+    EdgesDirection,
+)
+from graphrecords.overview import OverviewFormat
 from graphrecords.plugins import (
     Plugin,
     PostAddEdgesContext,
@@ -37,6 +63,7 @@ from graphrecords.plugins import (
     PostRemoveNodeContext,
     PostRemoveNodeFromGroupContext,
     PostRemoveNodesFromGroupsContext,
+    PostSetSchemaContext,
     PreAddEdgesContext,
     PreAddEdgesDataframesContext,
     PreAddEdgesDataframesWithGroupContext,
@@ -450,6 +477,31 @@ class TestGraphRecord(unittest.TestCase):
         assert nodes_df.shape[0] == graphrecord.node_count()
         assert edges_df.shape[0] == graphrecord.edge_count()
 
+    def test_to_polars_sorted(self) -> None:
+        graphrecord = create_graphrecord()
+
+        export = graphrecord.to_polars(sort=True)
+
+        nodes_df = export["ungrouped"]["nodes"]
+        edges_df = export["ungrouped"]["edges"]
+
+        assert nodes_df.columns == [
+            "node_index",
+            "adipiscing",
+            "amet",
+            "dolor",
+            "lorem",
+        ]
+        assert nodes_df["node_index"].to_list() == ["0", "1", "2", "3"]
+        assert edges_df.columns[:3] == [
+            "edge_index",
+            "source_node_index",
+            "target_node_index",
+        ]
+        assert edges_df["edge_index"].to_list() == [0, 1, 2, 3]
+
+        assert nodes_df.equals(graphrecord.to_polars(sort=True)["ungrouped"]["nodes"])
+
     def test_to_pandas(self) -> None:
         graphrecord = create_graphrecord()
 
@@ -602,6 +654,66 @@ class TestGraphRecord(unittest.TestCase):
 
         assert graphrecord.get_schema().schema_type == SchemaType.Inferred
 
+    def test_freeze(self) -> None:
+        graphrecord = create_graphrecord()
+
+        assert not graphrecord.is_frozen()
+
+        graphrecord.freeze()
+
+        assert graphrecord.is_frozen()
+        assert graphrecord.clone().is_frozen()
+
+        with pytest.raises(RuntimeError, match=r"GraphRecord is frozen"):
+            graphrecord.add_nodes([("4", {})])
+
+        with pytest.raises(RuntimeError, match=r"GraphRecord is frozen"):
+            graphrecord.remove_nodes("0")
+
+        with pytest.raises(RuntimeError, match=r"GraphRecord is frozen"):
+            graphrecord.node["0", "lorem"] = "changed"
+
+        assert graphrecord.node_count() == 4
+
+        graphrecord.set_strict_queries(True)
+        graphrecord.set_random_seed(42)
+
+        with graphrecord.with_operation_context({"user": "lorem"}):
+            assert graphrecord.operation_context == {"user": "lorem"}
+
+        assert graphrecord.strict_queries()
+        assert graphrecord.random_seed() == 42
+
+        graphrecord.unfreeze()
+
+        assert not graphrecord.is_frozen()
+
+        graphrecord.add_nodes([("4", {})])
+
+        assert graphrecord.node_count() == 5
+
+    def test_compact(self) -> None:
+        graphrecord = create_graphrecord()
+
+        graphrecord.remove_edges([0, 2])
+
+        assert graphrecord.compact() == {1: 0, 3: 1}
+        assert sorted(graphrecord.edges) == [0, 1]
+        assert graphrecord.add_edges(("0", "1", {})) == [2]
+
+    def test_recycle_edge_indices(self) -> None:
+        graphrecord = create_graphrecord()
+
+        assert not graphrecord.recycle_edge_indices()
+
+        graphrecord.set_recycle_edge_indices(True)
+
+        assert graphrecord.recycle_edge_indices()
+
+        graphrecord.remove_edges(1)
+
+        assert graphrecord.add_edges(("0", "1", {})) == [1]
+
     def test_nodes(self) -> None:
         graphrecord = create_graphrecord()
 
@@ -807,6 +919,59 @@ class TestGraphRecord(unittest.TestCase):
         with pytest.raises(IndexError):
             graphrecord.edge_endpoints([0, 50])
 
+    def test_set_edge_undirected(self) -> None:
+        graphrecord = create_graphrecord()
+
+        assert not graphrecord.is_edge_undirected(2)
+
+        graphrecord.set_edge_undirected(2)
+
+        assert graphrecord.is_edge_undirected([2, 3]) == {2: True, 3: False}
+        assert graphrecord.neighbors("2") == ["1"]
+        assert graphrecord.edges_connecting("2", "1") == [2]
+
+        edges_df = graphrecord.to_polars(sort=True)["ungrouped"]["edges"]
+
+        assert edges_df["undirected"].to_list() == [False, False, True, False]
+
+        def query(edge: EdgeOperand) -> EdgeIndicesOperand:
+            edge.index().is_in([2, 3])
+
+            return edge.index()
+
+        graphrecord.set_edge_undirected(query, undirected=False)
+
+        assert graphrecord.neighbors("2") == []
+
+        graphrecord.freeze()
+
+        with pytest.raises(ReadOnlyError):
+            graphrecord.set_edge_undirected(2)
+
+        graphrecord.unfreeze()
+
+        with pytest.raises(IndexError):
+            graphrecord.set_edge_undirected(50)
+
+        with pytest.raises(IndexError):
+            graphrecord.is_edge_undirected(50)
+
+    def test_induced_edges(self) -> None:
+        graphrecord = create_graphrecord()
+
+        assert sorted(graphrecord.induced_edges(["0", "1"])) == [0, 1]
+        assert sorted(graphrecord.induced_edges(["0", "1", "2"])) == [0, 1, 2]
+
+        # Node indices that do not exist are ignored
+        assert graphrecord.induced_edges(["0", "3", "50"]) == [3]
+
+        def query(node: NodeOperand) -> NodeIndicesOperand:
+            node.index().is_in(["1", "2"])
+
+            return node.index()
+
+        assert graphrecord.induced_edges(query) == [2]
+
     def test_edges_connecting(self) -> None:
         graphrecord = create_graphrecord()
 
@@ -981,6 +1146,118 @@ class TestGraphRecord(unittest.TestCase):
         with pytest.raises(IndexError):
             graphrecord.remove_nodes(["0", "50"])
 
+    def test_add_node_auto(self) -> None:
+        graphrecord = GraphRecord()
+        graphrecord.add_nodes((1, {}))
+
+        assert graphrecord.add_node_auto() == 0
+        assert graphrecord.add_nodes_auto([{"lorem": "ipsum"}, {}]) == [2, 3]
+        assert graphrecord.node[2] == {"lorem": "ipsum"}
+
+        assert graphrecord.auto_index_prefix() is None
+        graphrecord.set_auto_index_prefix("patient_")
+        assert graphrecord.auto_index_prefix() == "patient_"
+
+        assert graphrecord.add_node_auto({"age": 42}) == "patient_4"
+
+        restored = pickle.loads(pickle.dumps(graphrecord))
+        assert restored.add_node_auto() == "patient_5"
+
+    def test_add_relation(self) -> None:
+        graphrecord = GraphRecord.from_tuples(
+            [("patient", {}), ("drug", {}), ("prescriber", {})]
+        )
+
+        edges = graphrecord.add_relation(
+            "prescription",
+            [("patient", "patient"), ("drug", "drug"), ("prescriber", "prescriber")],
+            {"dose": 20},
+        )
+
+        assert edges == [0, 1, 2]
+        assert graphrecord.node["prescription"] == {"dose": 20}
+        assert graphrecord.edge[1] == {"role": "drug", "position": 1}
+        assert graphrecord.relation_members("prescription") == [
+            ("patient", "patient"),
+            ("drug", "drug"),
+            ("prescriber", "prescriber"),
+        ]
+
+        with pytest.raises(IndexError):
+            graphrecord.add_relation("other", [("patient", "patient"), ("drug", "50")])
+
+        with pytest.raises(AssertionError):
+            graphrecord.add_relation("other", [("patient", "patient")])
+
+        assert graphrecord.node_count() == 4
+
+    def test_register_udf(self) -> None:
+        graphrecord = GraphRecord()
+
+        assert graphrecord.udfs == []
+
+        graphrecord.register_udf("double", lambda value: value * 2)
+
+        assert graphrecord.udfs == ["double"]
+
+        with pytest.raises(KeyError):
+            graphrecord.register_udf("double", lambda value: value * 3)
+
+        graphrecord.remove_udf("double")
+
+        assert graphrecord.udfs == []
+
+        with pytest.raises(KeyError):
+            graphrecord.remove_udf("double")
+
+    def test_create_text_index(self) -> None:
+        graphrecord = GraphRecord.from_tuples([("0", {"note": "Acute heart failure"})])
+        graphrecord.add_group("notes", ["0"])
+
+        assert graphrecord.text_indices == []
+
+        graphrecord.create_text_index("notes", "note")
+
+        assert graphrecord.text_indices == [("notes", "note")]
+
+        with pytest.raises(AssertionError):
+            graphrecord.create_text_index("notes", "note")
+
+        with pytest.raises(IndexError):
+            graphrecord.create_text_index("patients", "note")
+
+        graphrecord.drop_text_index("notes", "note")
+
+        assert graphrecord.text_indices == []
+
+        with pytest.raises(KeyError):
+            graphrecord.drop_text_index("notes", "note")
+
+    def test_create_geo_index(self) -> None:
+        graphrecord = GraphRecord.from_tuples(
+            [("0", {"location": GeoPoint(52.52, 13.405)})]
+        )
+        graphrecord.add_group("facilities", ["0"])
+
+        assert graphrecord.geo_indices == []
+
+        graphrecord.create_geo_index("facilities", "location")
+
+        assert graphrecord.geo_indices == [("facilities", "location")]
+
+        with pytest.raises(AssertionError):
+            graphrecord.create_geo_index("facilities", "location")
+
+        with pytest.raises(IndexError):
+            graphrecord.create_geo_index("patients", "location")
+
+        graphrecord.drop_geo_index("facilities", "location")
+
+        assert graphrecord.geo_indices == []
+
+        with pytest.raises(KeyError):
+            graphrecord.drop_geo_index("facilities", "location")
+
     def test_add_nodes(self) -> None:
         graphrecord = GraphRecord()
 
@@ -1717,6 +1994,75 @@ class TestGraphRecord(unittest.TestCase):
         assert sorted(["0", "1"]) == sorted(nodes_and_edges["nodes"])
         assert sorted([0, 1]) == sorted(nodes_and_edges["edges"])
 
+    def test_structured_errors(self) -> None:
+        graphrecord = create_graphrecord()
+
+        with pytest.raises(MissingNodeError, match="Cannot find node with index 50"):
+            graphrecord.node["50"]
+
+        graphrecord.add_group("0")
+
+        with pytest.raises(DuplicateGroupError, match="Group 0 already exists"):
+            graphrecord.add_group("0")
+
+        graphrecord = GraphRecord.with_schema(
+            Schema(
+                ungrouped=GroupSchema(nodes={"lorem": Int()}),
+                schema_type=SchemaType.Provided,
+            )
+        )
+
+        with pytest.raises(
+            TypeMismatchError,
+            match=r"Attribute lorem of node with index 0 is of type String\. Expected Int\.",
+        ):
+            graphrecord.add_nodes([("0", {"lorem": "ipsum"})])
+
+    def test_pickle(self) -> None:
+        graphrecord = create_graphrecord()
+        graphrecord.add_group("group", nodes=["0", "1"], edges=[0])
+
+        restored = pickle.loads(pickle.dumps(graphrecord))
+
+        assert restored.node_count() == graphrecord.node_count()
+        assert restored.edge_count() == graphrecord.edge_count()
+        assert restored.node["0"] == graphrecord.node["0"]
+        assert sorted(restored.group("group")["nodes"]) == ["0", "1"]
+        assert restored.group("group")["edges"] == [0]
+
+    def test_exception_hierarchy(self) -> None:
+        assert issubclass(MissingNodeError, GraphRecordIndexError)
+        assert issubclass(GraphRecordIndexError, IndexError)
+        assert issubclass(DuplicateGroupError, GraphRecordAssertionError)
+        assert issubclass(GraphRecordAssertionError, AssertionError)
+        assert issubclass(TypeMismatchError, SchemaError)
+        assert issubclass(SchemaError, ValueError)
+        assert issubclass(ReadOnlyError, RuntimeError)
+
+        for error in (
+            ConversionError,
+            GraphRecordAssertionError,
+            GraphRecordIndexError,
+            ReadOnlyError,
+            SchemaError,
+            MissingNodeError,
+        ):
+            assert issubclass(error, GraphRecordError)
+            assert error.__module__ == "graphrecords._graphrecords.errors"
+
+        graphrecord = create_graphrecord()
+
+        with pytest.raises(GraphRecordIndexError):
+            graphrecord.remove_edges(50)
+
+        with pytest.raises(GraphRecordError):
+            graphrecord.remove_nodes("50")
+
+        graphrecord.freeze()
+
+        with pytest.raises(ReadOnlyError, match=r"GraphRecord is frozen"):
+            graphrecord.add_nodes([("4", {})])
+
     def test_invalid_add_group(self) -> None:
         graphrecord = create_graphrecord()
 
@@ -2612,131 +2958,1007 @@ class TestGraphRecord(unittest.TestCase):
 
         assert graphrecord.group_count() == 1
 
-    def test_contains_node(self) -> None:
-        graphrecord = create_graphrecord()
-
-        assert graphrecord.contains_node("0")
+    def test_stats(self) -> None:
+        stats = GraphRecord().stats()
 
-        assert not graphrecord.contains_node("50")
+        assert stats["node_count"] == 0
+        assert stats["degree"] is None
+        assert stats["connected_components"] == 0
 
-    def test_contains_edge(self) -> None:
         graphrecord = create_graphrecord()
+        graphrecord.add_group("0", ["0", "1"], [0])
 
-        assert graphrecord.contains_edge(0)
-
-        assert not graphrecord.contains_edge(50)
+        stats = graphrecord.stats()
 
-    def test_contains_group(self) -> None:
-        graphrecord = create_graphrecord()
+        assert stats["node_count"] == 4
+        assert stats["edge_count"] == 4
+        assert stats["density"] == pytest.approx(4 / 12)
+        assert stats["degree"] == {"min": 1, "mean": 2.0, "max": 3}
+        assert stats["connected_components"] == 1
+        assert stats["groups"] == {"0": {"node_count": 2, "edge_count": 1}}
 
-        assert not graphrecord.contains_group("0")
+    def test_correlation(self) -> None:
+        graphrecord = GraphRecord.from_tuples(
+            [
+                ("0", {"age": 1, "score": 2}),
+                ("1", {"age": 2, "score": 1}),
+                ("2", {"age": 3, "score": 100}),
+                ("3", {"age": 4, "score": 3}),
+                ("4", {"age": 5}),
+            ]
+        )
+        graphrecord.add_group("patients", ["0", "1", "2", "3", "4"])
 
-        graphrecord.add_group("0")
+        correlation = graphrecord.correlation(
+            "patients", "age", "score", CorrelationMethod.SPEARMAN
+        )
 
-        assert graphrecord.contains_group("0")
+        assert correlation["sample_size"] == 4
+        assert correlation["coefficient"] == pytest.approx(0.6)
+        assert correlation["p_value"] == pytest.approx(0.4)
 
-    def test_neighbors(self) -> None:
-        graphrecord = create_graphrecord()
+        correlation = graphrecord.correlation("patients", "age", "age")
 
-        neighbors = graphrecord.neighbors("0")
+        assert correlation["sample_size"] == 5
+        assert correlation["coefficient"] == pytest.approx(1)
 
-        assert sorted(["1", "3"]) == sorted(neighbors)
+        with pytest.raises(IndexError):
+            graphrecord.correlation("visits", "age", "score")
 
-        neighbors = graphrecord.neighbors(["0", "1"])
+        with pytest.raises(GraphRecordAssertionError):
+            graphrecord.correlation("patients", "age", "weight")
 
-        assert {key: sorted(value) for key, value in neighbors.items()} == {
-            "0": sorted(["1", "3"]),
-            "1": ["0", "2"],
-        }
+    def test_chi_square(self) -> None:
+        graphrecord = GraphRecord.from_tuples(
+            [
+                (str(index), {"sex": sex, "smoker": smoker})
+                for index, (sex, smoker) in enumerate(
+                    [("F", True)] * 3
+                    + [("F", False), ("M", True)]
+                    + [("M", False)] * 3
+                )
+            ]
+        )
+        graphrecord.add_group("patients", [str(index) for index in range(8)])
 
-        def query1(node: NodeOperand) -> NodeIndicesOperand:
-            node.index().is_in(["0", "1"])
+        chi_square = graphrecord.chi_square("patients", "sex", "smoker")
 
-            return node.index()
+        assert chi_square["sample_size"] == 8
+        assert chi_square["degrees_of_freedom"] == 1
+        assert chi_square["statistic"] == pytest.approx(2)
+        assert chi_square["p_value"] == pytest.approx(0.1572992)
+        assert chi_square["cramers_v"] == pytest.approx(0.5)
 
-        neighbors = graphrecord.neighbors(query1)
+        with pytest.raises(GraphRecordAssertionError):
+            graphrecord.chi_square("patients", "sex", "weight")
 
-        assert {key: sorted(value) for key, value in neighbors.items()} == {
-            "0": sorted(["1", "3"]),
-            "1": ["0", "2"],
-        }
+    def test_match_propensity_scores(self) -> None:
+        graphrecord = GraphRecord.from_tuples(
+            [
+                ("t0", {"age": 50}),
+                ("t1", {"age": 60}),
+                ("c0", {"age": 20}),
+                ("c1", {"age": 49}),
+                ("c2", {"age": 61}),
+                ("c3", {"age": 30}),
+            ]
+        )
+        graphrecord.add_group("treated", ["t0", "t1"])
+        graphrecord.add_group("control", ["c0", "c1", "c2", "c3"])
+
+        scores = graphrecord.propensity_scores("treated", "control", ["age"])
+
+        assert sorted(scores, key=scores.__getitem__) == [
+            "c0",
+            "c3",
+            "c1",
+            "t0",
+            "t1",
+            "c2",
+        ]
 
-        def query1_single(node: NodeOperand) -> NodeIndexOperand:
-            node.index().equal_to("0")
+        pairs = graphrecord.match_propensity_scores(
+            "treated", "control", ["age"], "matches"
+        )
 
-            return node.index().max()
+        assert [(treated, control) for treated, control, _ in pairs] == [
+            ("t0", "c1"),
+            ("t1", "c2"),
+        ]
+        assert sorted(graphrecord.group("matches")["edges"]) == sorted(
+            edge for _, _, edge in pairs
+        )
+        assert graphrecord.edge[pairs[0][2], "distance"] >= 0
 
-        neighbors = graphrecord.neighbors(query1_single)
+        assert (
+            graphrecord.match_propensity_scores(
+                "treated", "control", ["age"], "close_matches", caliper=0.0
+            )
+            == []
+        )
 
-        assert sorted(neighbors) == ["1", "3"]
+        with pytest.raises(DuplicateGroupError):
+            graphrecord.match_propensity_scores(
+                "treated", "control", ["age"], "matches"
+            )
 
-        def query1_not_found(node: NodeOperand) -> NodeIndexOperand:
-            max_index = node.index().max()
-            max_index.equal_to("non-found")
+        with pytest.raises(KeyError):
+            graphrecord.propensity_scores("treated", "control", ["weight"])
 
-            return max_index
+    def test_cohort_table(self) -> None:
+        graphrecord = GraphRecord.from_tuples(
+            [
+                ("0", {"age": 1, "sex": "F"}),
+                ("1", {"age": 2, "sex": "F"}),
+                ("2", {"age": 3, "sex": "M"}),
+                ("3", {"age": 3, "sex": "F"}),
+                ("4", {"age": 4, "sex": "M"}),
+                ("5", {"age": 5, "sex": "M"}),
+            ]
+        )
+        graphrecord.add_group("treated", ["0", "1", "2"])
+        graphrecord.add_group("control", ["3", "4", "5"])
 
-        neighbors = graphrecord.neighbors(query1_not_found)
+        cohort_table = graphrecord.cohort_table("treated", "control", ["age", "sex"])
 
-        assert neighbors == []
+        assert cohort_table.columns == [
+            "attribute",
+            "statistic",
+            "treated",
+            "control",
+            "p_value",
+        ]
+        assert cohort_table["statistic"].to_list() == [
+            "count",
+            "mean ± std",
+            "median",
+            "F",
+            "M",
+        ]
+        assert cohort_table["treated"].to_list() == [
+            "3",
+            "2.00 ± 1.00",
+            "2.00",
+            "2 (66.7%)",
+            "1 (33.3%)",
+        ]
+        assert cohort_table["p_value"][1] == pytest.approx(0.0704840)
+        assert cohort_table["p_value"][2] is None
 
-        neighbors = graphrecord.neighbors("0", directed=EdgesDirection.UNDIRECTED)
+        with pytest.raises(IndexError):
+            graphrecord.cohort_table("treated", "visits", ["age"])
 
-        assert sorted(["1", "3"]) == sorted(neighbors)
+        with pytest.raises(GraphRecordAssertionError):
+            graphrecord.cohort_table("treated", "treated", ["age"])
 
-        neighbors = graphrecord.neighbors(
-            ["0", "1"], directed=EdgesDirection.UNDIRECTED
+    def test_crosstab(self) -> None:
+        graphrecord = GraphRecord.from_tuples(
+            [
+                ("0", {"sex": "F", "smoker": True}),
+                ("1", {"sex": "F", "smoker": False}),
+                ("2", {"sex": "M", "smoker": True}),
+                ("3", {"sex": "F", "smoker": True}),
+                ("4", {"sex": "M"}),
+            ]
         )
+        graphrecord.add_group("patients", ["0", "1", "2", "3", "4"])
 
-        assert {key: sorted(value) for key, value in neighbors.items()} == {
-            "0": sorted(["1", "3"]),
-            "1": ["0", "2"],
-        }
-
-        def query2(node: NodeOperand) -> NodeIndicesOperand:
-            node.index().is_in(["0", "1"])
+        crosstab = graphrecord.crosstab("patients", "sex", "smoker")
 
-            return node.index()
+        assert list(crosstab.index) == ["F", "M"]
+        assert list(crosstab.columns) == [False, True]
+        assert crosstab.to_numpy().tolist() == [[1, 2], [0, 1]]
 
-        neighbors = graphrecord.neighbors(query2, directed=EdgesDirection.UNDIRECTED)
+        crosstab = graphrecord.crosstab(
+            "patients", "sex", "smoker", CrosstabNormalization.ALL
+        )
 
-        assert {key: sorted(value) for key, value in neighbors.items()} == {
-            "0": sorted(["1", "3"]),
-            "1": ["0", "2"],
-        }
+        assert crosstab.to_numpy().tolist() == [[0.25, 0.5], [0, 0.25]]
 
-    def test_neighbors_incoming(self) -> None:
-        graphrecord = create_graphrecord()
+        crosstab = graphrecord.crosstab(
+            "patients", "sex", "smoker", CrosstabNormalization.ROWS
+        )
 
-        neighbors = graphrecord.neighbors("0", directed=EdgesDirection.INCOMING)
+        assert crosstab.loc["F", True] == pytest.approx(2 / 3)
+        assert crosstab.loc["M", True] == pytest.approx(1)
 
-        assert neighbors == ["1"]
+        crosstab = graphrecord.crosstab(
+            "patients", "sex", "smoker", CrosstabNormalization.COLUMNS
+        )
 
-        neighbors = graphrecord.neighbors(["0", "2"], directed=EdgesDirection.INCOMING)
+        assert crosstab.loc["F", False] == pytest.approx(1)
+        assert crosstab.loc["M", True] == pytest.approx(1 / 3)
 
-        assert neighbors == {"0": ["1"], "2": ["1"]}
+        with pytest.raises(IndexError):
+            graphrecord.crosstab("visits", "sex", "smoker")
 
-    def test_neighbors_incoming_query(self) -> None:
+    def test_group_adjacency_counts(self) -> None:
         graphrecord = create_graphrecord()
 
-        def query(node: NodeOperand) -> NodeIndicesOperand:
-            node.index().is_in(["0", "2"])
-
-            return node.index()
-
-        neighbors = graphrecord.neighbors(query, directed=EdgesDirection.INCOMING)
+        assert graphrecord.group_adjacency_counts() == {(None, None): 4}
 
-        assert neighbors == {"0": ["1"], "2": ["1"]}
+        graphrecord.add_group("0", ["0", "1"])
 
-    def test_invalid_neighbors_incoming(self) -> None:
-        graphrecord = create_graphrecord()
+        assert graphrecord.group_adjacency_counts() == {
+            ("0", "0"): 2,
+            ("0", None): 2,
+            (None, "0"): 0,
+            (None, None): 0,
+        }
 
-        with pytest.raises(IndexError):
-            graphrecord.neighbors("50", directed=EdgesDirection.INCOMING)
+    def test_time_to_event(self) -> None:
+        graphrecord = GraphRecord.from_tuples(
+            [
+                ("0", {"time": datetime(2020, 1, 1)}),
+                ("1", {"time": datetime(2020, 1, 1)}),
+                ("2", {"time": datetime(2020, 1, 10)}),
+                ("death", {}),
+            ]
+        )
+        graphrecord.add_group("patients", ["0", "1", "2"])
+        graphrecord.add_edges(
+            [
+                ("0", "death", {"time": datetime(2020, 1, 11)}),
+                ("1", "death", {"time": timedelta(days=5)}),
+                ("2", "death", {"time": datetime(2020, 1, 5)}),
+            ],
+            "deaths",
+        )
+
+        assert graphrecord.time_to_event(
+            "patients", "time", "deaths", datetime(2020, 1, 31)
+        ) == {
+            "0": {"duration": timedelta(days=10), "event": True},
+            "1": {"duration": timedelta(days=5), "event": True},
+            "2": {"duration": timedelta(days=21), "event": False},
+        }
+
+        with pytest.raises(IndexError):
+            graphrecord.time_to_event(
+                "visits", "time", "deaths", datetime(2020, 1, 31)
+            )
+
+        with pytest.raises(GraphRecordAssertionError):
+            graphrecord.time_to_event(
+                "patients", "time", "deaths", datetime(2020, 1, 5)
+            )
+
+        graphrecord.add_nodes_to_group("patients", "death")
+
+        with pytest.raises(KeyError):
+            graphrecord.time_to_event(
+                "patients", "time", "deaths", datetime(2020, 1, 31)
+            )
+
+    def test_events_in_window(self) -> None:
+        graphrecord = GraphRecord.from_tuples(
+            [
+                ("0", {"time": datetime(2020, 1, 1)}),
+                ("1", {"time": datetime(2020, 1, 1)}),
+                ("2", {"time": datetime(2020, 1, 10)}),
+                ("diagnosis", {}),
+            ]
+        )
+        graphrecord.add_group("patients", ["0", "1", "2"])
+        graphrecord.add_edges(
+            [
+                ("0", "diagnosis", {"time": datetime(2020, 1, 11)}),
+                ("1", "diagnosis", {"time": timedelta(days=40)}),
+                ("2", "diagnosis", {"time": datetime(2020, 1, 5)}),
+            ],
+            "diagnoses",
+        )
+
+        assert graphrecord.events_in_window(
+            "patients", "time", "diagnoses", timedelta(0), timedelta(days=30)
+        ) == {"0": True, "1": False, "2": False}
+        assert graphrecord.events_in_window(
+            "patients",
+            "time",
+            "diagnoses",
+            timedelta(0),
+            timedelta(days=30),
+            washout=timedelta(days=7),
+        ) == {"0": True, "1": False}
+
+        event_rate = graphrecord.event_rate_in_window(
+            "patients",
+            "time",
+            "diagnoses",
+            timedelta(0),
+            timedelta(days=30),
+            washout=timedelta(days=7),
+        )
+
+        assert event_rate["count"] == 1
+        assert event_rate["population"] == 2
+        assert event_rate["rate"] == pytest.approx(0.5)
+
+        with pytest.raises(GraphRecordAssertionError):
+            graphrecord.events_in_window(
+                "patients", "time", "diagnoses", timedelta(days=30), timedelta(0)
+            )
+
+    def test_k_anonymity(self) -> None:
+        graphrecord = GraphRecord.from_tuples(
+            [
+                ("0", {"age": 41, "zip_code": "12345", "sex": "F"}),
+                ("1", {"age": 47, "zip_code": "12399", "sex": "F"}),
+                ("2", {"age": 45, "zip_code": "12310", "sex": "M"}),
+                ("3", {"age": 52, "zip_code": "54321", "sex": "M"}),
+                ("4", {"age": 58, "zip_code": "54399", "sex": "M"}),
+            ]
+        )
+        graphrecord.add_group("patients", ["0", "1", "2", "3", "4"])
+
+        assert graphrecord.k_anonymity("patients", ["age", "zip_code"]) == 1
+
+        graphrecord.bucket_attribute("patients", "age", 10)
+        graphrecord.truncate_attribute("patients", "zip_code", 3)
+
+        assert graphrecord.node["0", "age"] == "40-49"
+        assert graphrecord.node["0", "zip_code"] == "123**"
+        assert graphrecord.k_anonymity("patients", ["age", "zip_code"]) == 2
+
+        assert graphrecord.k_anonymity_violations("patients", ["sex"], 3) == [
+            "0",
+            "1",
+        ]
+        assert graphrecord.enforce_k_anonymity("patients", ["sex"], 3) == ["0", "1"]
+        assert graphrecord.node_count() == 3
+        assert graphrecord.k_anonymity("patients", ["sex"]) == 3
+
+        with pytest.raises(GraphRecordAssertionError):
+            graphrecord.bucket_attribute("patients", "age", 0)
+
+        with pytest.raises(ConversionError):
+            graphrecord.bucket_attribute("patients", "sex", 10)
+
+    def test_rename_attribute(self) -> None:
+        graphrecord = GraphRecord.from_tuples(
+            [(0, {"age": 40}), (1, {"age": 50})], [(0, 1, {"age": 1})]
+        )
+        graphrecord.add_group("patients", nodes=[0])
+
+        graphrecord.rename_attribute("age", "years", AttributeScope.NODES)
+
+        assert graphrecord.node[1] == {"years": 50}
+        assert graphrecord.edge[0] == {"age": 1}
+        assert "years" in graphrecord.get_schema().group("patients").nodes
+
+        graphrecord.rename_attribute("years", "age_in_years", "patients")
+
+        assert graphrecord.node[0] == {"age_in_years": 40}
+        assert graphrecord.node[1] == {"years": 50}
+
+        graphrecord.node[1, "age_in_years"] = 51
+
+        with pytest.raises(GraphRecordAssertionError):
+            graphrecord.rename_attribute("years", "age_in_years")
+
+        assert graphrecord.node[1] == {"years": 50, "age_in_years": 51}
+
+    def test_drop_attribute(self) -> None:
+        graphrecord = GraphRecord.from_tuples(
+            [(0, {"age": 40, "sex": "F"}), (1, {"age": 50})], [(0, 1, {"age": 1})]
+        )
+
+        graphrecord.drop_attribute("age", AttributeScope.EDGES)
+
+        assert graphrecord.edge[0] == {}
+        assert graphrecord.node[1] == {"age": 50}
+
+        graphrecord.drop_attribute("age")
+
+        assert graphrecord.node[0] == {"sex": "F"}
+        assert "age" not in graphrecord.get_schema().ungrouped.nodes
+
+        with pytest.raises(IndexError):
+            graphrecord.drop_attribute("sex", "invalid")
+
+    def test_materialize_attribute(self) -> None:
+        graphrecord = GraphRecord.from_tuples(
+            [(0, {"weight": 70}), (1, {"weight": 80}), (2, {})],
+            [(0, 1, {"duration": 2})],
+        )
+
+        def query_weight(node: NodeOperand) -> NodeMultipleValuesWithIndexOperand:
+            weight = node.attribute("weight")
+            weight.multiply(1000)
+
+            return weight
+
+        def query_duration(edge: EdgeOperand) -> EdgeMultipleValuesWithIndexOperand:
+            duration = edge.attribute("duration")
+            duration.multiply(24)
+
+            return duration
+
+        assert graphrecord.materialize_node_attribute("grams", query_weight) == 2
+        assert graphrecord.node[0] == {"weight": 70, "grams": 70000}
+        assert graphrecord.node[2] == {}
+
+        assert graphrecord.materialize_edge_attribute("hours", query_duration) == 1
+        assert graphrecord.edge[0] == {"duration": 2, "hours": 48}
+
+        graphrecord = GraphRecord.from_tuples(
+            [(0, {"weight": 70})],
+            schema=Schema(
+                ungrouped=GroupSchema(nodes={"weight": Int()}),
+                schema_type=SchemaType.Provided,
+            ),
+        )
+
+        with pytest.raises(SchemaError):
+            graphrecord.materialize_node_attribute("grams", query_weight)
+
+        assert graphrecord.node[0] == {"weight": 70}
+
+    def test_group_from_query(self) -> None:
+        graphrecord = GraphRecord.from_tuples(
+            [(0, {"weight": 70}), (1, {"weight": 80}), (2, {})],
+            [(0, 1, {"duration": 2})],
+        )
+
+        def query_heavy(node: NodeOperand) -> NodeIndicesOperand:
+            node.attribute("weight").greater_than(75)
+
+            return node.index()
+
+        def query_long(edge: EdgeOperand) -> EdgeIndicesOperand:
+            edge.attribute("duration").greater_than(1)
+
+            return edge.index()
+
+        assert graphrecord.group_from_node_query("heavy", query_heavy) == 1
+        assert graphrecord.nodes_in_group("heavy") == [1]
+        assert "weight" in graphrecord.get_schema().group("heavy").nodes
+
+        assert graphrecord.group_from_edge_query("long", query_long) == 1
+        assert graphrecord.edges_in_group("long") == [0]
+
+        graphrecord = GraphRecord.from_tuples(
+            [(0, {"weight": 80})],
+            schema=Schema(
+                ungrouped=GroupSchema(nodes={"weight": Int()}),
+                schema_type=SchemaType.Provided,
+            ),
+        )
+
+        with pytest.raises(SchemaError):
+            graphrecord.group_from_node_query("heavy", query_heavy)
+
+        assert "heavy" not in graphrecord.groups
+
+    def test_concat(self) -> None:
+        first = GraphRecord.from_tuples([(0, {"day": 1}), (1, {})], [(0, 1, {})])
+        first.add_group("patients", [0], [0])
+        second = GraphRecord.from_tuples([(2, {"day": 2}), (3, {})], [(2, 3, {})])
+        second.add_group("patients", [2], [0])
+
+        graphrecord = GraphRecord.concat([first, second])
+
+        assert sorted(graphrecord.nodes) == [0, 1, 2, 3]
+        assert sorted(graphrecord.edges) == [0, 1]
+        assert graphrecord.edge_endpoints(1) == (2, 3)
+        assert sorted(graphrecord.nodes_in_group("patients")) == [0, 2]
+        assert sorted(graphrecord.edges_in_group("patients")) == [0, 1]
+
+        assert GraphRecord.concat([]).node_count() == 0
+
+        with pytest.raises(GraphRecordAssertionError):
+            GraphRecord.concat([first, first])
+
+    def test_split_by_groups(self) -> None:
+        graphrecord = GraphRecord.from_tuples(
+            [(0, {"ward": "A"}), (1, {"ward": "B"}), (2, {"age": 40})],
+            [(0, 2, {}), (0, 1, {})],
+        )
+        graphrecord.add_group("cardiology", [0, 1], [1])
+        graphrecord.add_group("oncology", [2])
+
+        records = graphrecord.split_by_groups(["cardiology", "oncology"])
+
+        assert sorted(records["cardiology"].nodes) == [0, 1]
+        assert records["cardiology"].edges == [1]
+        assert records["cardiology"].groups == ["cardiology"]
+        assert records["cardiology"].edges_in_group("cardiology") == [1]
+
+        schema = records["cardiology"].get_schema()
+
+        assert "ward" in schema.group("cardiology").nodes
+        assert "oncology" not in schema.groups
+
+        assert records["oncology"].nodes == [2]
+        assert records["oncology"].edge_count() == 0
+
+        with pytest.raises(IndexError):
+            graphrecord.split_by_groups(["radiology"])
+
+    def test_fingerprint(self) -> None:
+        graphrecord = create_graphrecord()
+
+        reversed_graphrecord = GraphRecord.from_tuples(
+            create_nodes()[::-1], create_edges()[::-1]
+        )
+
+        assert graphrecord.fingerprint() == reversed_graphrecord.fingerprint()
+
+        graphrecord.node["0", "lorem"] = "changed"
+
+        assert graphrecord.fingerprint() != reversed_graphrecord.fingerprint()
+
+    def test_equals(self) -> None:
+        graphrecord = create_graphrecord()
+
+        reversed_graphrecord = GraphRecord.from_tuples(
+            create_nodes()[::-1], create_edges()[::-1]
+        )
+
+        assert graphrecord.equals(reversed_graphrecord)
+        assert graphrecord == reversed_graphrecord
+        assert graphrecord != GraphRecord()
+        assert graphrecord != "graphrecord"
+
+        reversed_graphrecord.node["0", "lorem"] = "changed"
+
+        assert graphrecord != reversed_graphrecord
+
+    def test_is_subgraph_of(self) -> None:
+        graphrecord = create_graphrecord()
+
+        subgraph = GraphRecord.from_tuples(create_nodes(), create_edges()[:2])
+
+        assert subgraph.is_subgraph_of(graphrecord)
+        assert not graphrecord.is_subgraph_of(subgraph)
+
+    def test_memory_usage(self) -> None:
+        assert GraphRecord().memory_usage()["total"] == 0
+
+        graphrecord = create_graphrecord()
+
+        memory_usage = graphrecord.memory_usage()
+
+        assert memory_usage["node_attributes"] > 0
+        assert memory_usage["groups"] == 0
+        assert memory_usage["total"] == sum(
+            value for key, value in memory_usage.items() if key != "total"
+        )
+
+    def test_shrink_to_fit(self) -> None:
+        graphrecord = GraphRecord.from_tuples([(index, {}) for index in range(1000)])
+        graphrecord.remove_nodes(list(range(1, 1000)))
+
+        memory_usage = graphrecord.memory_usage()
+
+        graphrecord.shrink_to_fit()
+
+        assert graphrecord.memory_usage()["nodes"] < memory_usage["nodes"]
+        assert graphrecord.nodes == [0]
+
+    def test_maintain(self) -> None:
+        graphrecord = GraphRecord.from_tuples(
+            [(index, {"note": "Heart failure"}) for index in range(1000)]
+        )
+        graphrecord.add_group("patients", nodes=list(range(1000)))
+        graphrecord.create_text_index("patients", "note")
+        graphrecord.remove_nodes(list(range(1, 1000)))
+
+        report = graphrecord.maintain()
+
+        assert report["bytes_released"] > 0
+        assert report["text_indices_rebuilt"] == 1
+        assert report["geo_indices_rebuilt"] == 0
+
+        assert graphrecord.maintain() == {
+            "bytes_released": 0,
+            "text_indices_rebuilt": 0,
+            "geo_indices_rebuilt": 0,
+        }
+
+    def test_reserve_group(self) -> None:
+        graphrecord = create_graphrecord()
+        graphrecord.add_group("group")
+
+        memory_usage = graphrecord.memory_usage()
+
+        graphrecord.reserve_group("group", 1000, 1000)
+
+        assert graphrecord.memory_usage()["groups"] > memory_usage["groups"]
+
+        with pytest.raises(IndexError):
+            graphrecord.reserve_group("invalid", 1000, 1000)
+
+    def test_overview_format(self) -> None:
+        graphrecord = create_graphrecord()
+
+        overview = repr(graphrecord.overview(overview_format=OverviewFormat.Csv))
+
+        assert overview.startswith(
+            "Group,Node Count,Attribute,Attribute Type,Data Type,Details\n"
+        )
+
+        overview = repr(graphrecord.overview(overview_format=OverviewFormat.Markdown))
+
+        assert overview.startswith("### Node Overview\n\n| Group")
+
+        graphrecord.repr_format = OverviewFormat.Ascii
+
+        assert repr(graphrecord).startswith("+")
+        assert repr(graphrecord).isascii()
+
+    def test_overview_sampled(self) -> None:
+        graphrecord = GraphRecord.from_tuples(
+            [(index, {"value": index}) for index in range(100)]
+        )
+
+        overview = graphrecord.overview_sampled(10, seed=42)
+
+        assert overview.is_approximate
+        assert overview.ungrouped_verview.node_overview.count == 100
+        assert "approximate, sample size 10" in repr(overview)
+        assert repr(overview) == repr(graphrecord.overview_sampled(10, seed=42))
+
+        assert not graphrecord.overview().is_approximate
+
+    def test_overview_categorical_threshold(self) -> None:
+        graphrecord = GraphRecord.from_tuples(
+            [(index, {"flag": index % 4 != 0}) for index in range(8)]
+        )
+
+        data = (
+            graphrecord.overview(categorical_threshold=1)
+            .ungrouped_verview.node_overview.attributes["flag"]
+            .data
+        )
+
+        assert data["distinct_values"] == [True]
+        assert data["value_counts"] == [(True, 6)]
+        assert data["other_count"] == 2
+
+        data = (
+            graphrecord.overview()
+            .ungrouped_verview.node_overview.attributes["flag"]
+            .data
+        )
+
+        assert data["distinct_values"] == [True, False]
+        assert data["value_counts"] == [(True, 6), (False, 2)]
+        assert data["other_count"] == 0
+
+        assert "Other value count: 2" in repr(
+            graphrecord.overview(truncate_details=None, categorical_threshold=1)
+        )
+
+    def test_overview_structure(self) -> None:
+        graphrecord = GraphRecord.from_tuples(
+            [(index, {}) for index in range(4)],
+            [(0, 2, {}), (1, 2, {}), (0, 3, {})],
+        )
+        graphrecord.add_group("patient", [0, 1])
+        graphrecord.add_group("diagnosis", [2])
+
+        overview = graphrecord.overview()
+
+        assert overview.grouped_overviews["patient"].structure == {
+            "ungrouped_endpoints": 1,
+            "isolated_nodes": 0,
+            "average_degree": 1.5,
+            "connected_groups": [("diagnosis", 2)],
+        }
+        assert overview.ungrouped_verview.structure["connected_groups"] == [
+            ("patient", 1)
+        ]
+        assert "Structure Overview" in repr(overview)
+
+    def test_missing_values(self) -> None:
+        graphrecord = GraphRecord.from_tuples(
+            [
+                ("0", {"value": 1}),
+                ("1", {"value": 3}),
+                ("2", {"value": None}),
+                ("3", {"value": MISSING}),
+            ]
+        )
+
+        assert graphrecord.node["3", "value"] == MISSING
+        assert graphrecord.node["2", "value"] is None
+        assert "Mean: 2" in repr(graphrecord.overview())
+
+    def test_geo_point_values(self) -> None:
+        berlin = GeoPoint(52.52, 13.405)
+        graphrecord = GraphRecord.from_tuples([("0", {"location": berlin})])
+
+        assert graphrecord.node["0", "location"] == berlin
+        assert graphrecord.node["0", "location"].latitude == 52.52
+        assert graphrecord.node["0", "location"].longitude == 13.405
+        assert repr(berlin) == "GeoPoint(latitude=52.52, longitude=13.405)"
+        assert len({berlin, GeoPoint(52.52, 13.405)}) == 1
+        assert berlin.distance_km(GeoPoint(48.1351, 11.582)) == pytest.approx(
+            504.4, abs=0.1
+        )
+
+        with pytest.raises(AssertionError):
+            GeoPoint(91, 0)
+
+        with pytest.raises(AssertionError):
+            GeoPoint(0, 181)
+
+    def test_provenance(self) -> None:
+        graphrecord = create_graphrecord()
+        lab = ProvenanceTag("lab", datetime(2024, 1, 1), batch_id="0")
+
+        with pytest.raises(GraphRecordAssertionError):
+            graphrecord.set_provenance_tag(lab)
+
+        graphrecord.enable_provenance()
+        assert graphrecord.provenance_enabled()
+        assert graphrecord.set_provenance_tag(lab) is None
+
+        graphrecord.node["0", "lorem"] = "amet"
+        graphrecord.edge[0, "sed"] = "do"
+
+        assert graphrecord.node_attribute_provenance("0", "lorem") == lab
+        assert graphrecord.node_attribute_provenance("1", "lorem") is None
+        assert graphrecord.edge_attribute_provenance(0, "sed") == lab
+
+        tag = graphrecord.node_attribute_provenance("0", "lorem")
+        assert tag is not None
+        assert tag.source == "lab"
+        assert tag.batch_id == "0"
+
+        def query(node: NodeOperand) -> NodeMultipleValuesWithIndexOperand:
+            values = node.attribute("lorem")
+            values.provenance()
+
+            return values
+
+        assert graphrecord.query_nodes(query) == {"0": "lab"}
+
+        graphrecord.disable_provenance()
+        assert graphrecord.node_attribute_provenance("0", "lorem") is None
+
+    def test_metadata(self) -> None:
+        graphrecord = create_graphrecord()
+
+        assert graphrecord.metadata() == RecordMetadata()
+
+        metadata = RecordMetadata(
+            title="Cohort",
+            created_at=datetime(2024, 1, 1),
+            source_datasets=["claims", "lab"],
+            properties={"version": 3},
+        )
+
+        assert graphrecord.set_metadata(metadata) == RecordMetadata()
+        assert graphrecord.metadata().title == "Cohort"
+        assert graphrecord.metadata().description is None
+        assert graphrecord.metadata().source_datasets == ["claims", "lab"]
+        assert graphrecord.metadata().properties == {"version": 3}
+
+        restored = pickle.loads(pickle.dumps(graphrecord))
+        assert restored.metadata() == metadata
+
+        with tempfile.NamedTemporaryFile() as f:
+            graphrecord.to_ron(f.name)
+
+            loaded_graphrecord = GraphRecord.from_ron(f.name)
+
+        assert loaded_graphrecord.metadata() == metadata
+
+    def test_repr_html(self) -> None:
+        graphrecord = create_graphrecord()
+        graphrecord.add_group("0", ["0"])
+
+        html = graphrecord._repr_html_()
+
+        assert html.startswith('<div class="graphrecords-overview">')
+        assert "<h4>Ungrouped</h4>" in html
+        assert "<h4>0</h4>" in html
+        assert html == graphrecord.overview(truncate_details=80)._repr_html_()
+
+    def test_contains_node(self) -> None:
+        graphrecord = create_graphrecord()
+
+        assert graphrecord.contains_node("0")
+
+        assert not graphrecord.contains_node("50")
+
+    def test_contains_edge(self) -> None:
+        graphrecord = create_graphrecord()
+
+        assert graphrecord.contains_edge(0)
+
+        assert not graphrecord.contains_edge(50)
+
+    def test_contains_group(self) -> None:
+        graphrecord = create_graphrecord()
+
+        assert not graphrecord.contains_group("0")
+
+        graphrecord.add_group("0")
+
+        assert graphrecord.contains_group("0")
+
+    def test_group_metadata(self) -> None:
+        graphrecord = create_graphrecord()
+        graphrecord.add_group("0", ["0"])
+
+        assert graphrecord.group_metadata("0") == {}
+
+        graphrecord.set_group_metadata("0", "color", "red")
+        graphrecord.set_group_metadata("0", "description", "Patients")
+
+        assert graphrecord.group_metadata("0") == {
+            "color": "red",
+            "description": "Patients",
+        }
+        assert graphrecord.overview().grouped_overviews["0"].metadata == {
+            "color": "red",
+            "description": "Patients",
+        }
+        assert "Group Metadata" in repr(graphrecord.overview())
+
+        assert graphrecord.remove_group_metadata("0", "color") == "red"
+        assert graphrecord.group_metadata("0") == {"description": "Patients"}
+
+        restored = pickle.loads(pickle.dumps(graphrecord))
+        assert restored.group_metadata("0") == {"description": "Patients"}
+
+        with pytest.raises(IndexError):
+            graphrecord.set_group_metadata("50", "color", "red")
+
+        with pytest.raises(KeyError):
+            graphrecord.remove_group_metadata("0", "color")
+
+    def test_neighbors(self) -> None:
+        graphrecord = create_graphrecord()
+
+        neighbors = graphrecord.neighbors("0")
+
+        assert sorted(["1", "3"]) == sorted(neighbors)
+
+        neighbors = graphrecord.neighbors(["0", "1"])
+
+        assert {key: sorted(value) for key, value in neighbors.items()} == {
+            "0": sorted(["1", "3"]),
+            "1": ["0", "2"],
+        }
+
+        def query1(node: NodeOperand) -> NodeIndicesOperand:
+            node.index().is_in(["0", "1"])
+
+            return node.index()
+
+        neighbors = graphrecord.neighbors(query1)
+
+        assert {key: sorted(value) for key, value in neighbors.items()} == {
+            "0": sorted(["1", "3"]),
+            "1": ["0", "2"],
+        }
+
+        def query1_single(node: NodeOperand) -> NodeIndexOperand:
+            node.index().equal_to("0")
+
+            return node.index().max()
+
+        neighbors = graphrecord.neighbors(query1_single)
+
+        assert sorted(neighbors) == ["1", "3"]
+
+        def query1_not_found(node: NodeOperand) -> NodeIndexOperand:
+            max_index = node.index().max()
+            max_index.equal_to("non-found")
+
+            return max_index
+
+        neighbors = graphrecord.neighbors(query1_not_found)
+
+        assert neighbors == []
+
+        neighbors = graphrecord.neighbors("0", directed=EdgesDirection.UNDIRECTED)
+
+        assert sorted(["1", "3"]) == sorted(neighbors)
+
+        neighbors = graphrecord.neighbors(
+            ["0", "1"], directed=EdgesDirection.UNDIRECTED
+        )
+
+        assert {key: sorted(value) for key, value in neighbors.items()} == {
+            "0": sorted(["1", "3"]),
+            "1": ["0", "2"],
+        }
+
+        def query2(node: NodeOperand) -> NodeIndicesOperand:
+            node.index().is_in(["0", "1"])
+
+            return node.index()
+
+        neighbors = graphrecord.neighbors(query2, directed=EdgesDirection.UNDIRECTED)
+
+        assert {key: sorted(value) for key, value in neighbors.items()} == {
+            "0": sorted(["1", "3"]),
+            "1": ["0", "2"],
+        }
+
+    def test_neighbors_incoming(self) -> None:
+        graphrecord = create_graphrecord()
+
+        neighbors = graphrecord.neighbors("0", directed=EdgesDirection.INCOMING)
+
+        assert neighbors == ["1"]
+
+        neighbors = graphrecord.neighbors(["0", "2"], directed=EdgesDirection.INCOMING)
+
+        assert neighbors == {"0": ["1"], "2": ["1"]}
+
+    def test_neighbors_incoming_query(self) -> None:
+        graphrecord = create_graphrecord()
+
+        def query(node: NodeOperand) -> NodeIndicesOperand:
+            node.index().is_in(["0", "2"])
+
+            return node.index()
+
+        neighbors = graphrecord.neighbors(query, directed=EdgesDirection.INCOMING)
+
+        assert neighbors == {"0": ["1"], "2": ["1"]}
+
+    def test_invalid_neighbors_incoming(self) -> None:
+        graphrecord = create_graphrecord()
+
+        with pytest.raises(IndexError):
+            graphrecord.neighbors("50", directed=EdgesDirection.INCOMING)
 
         with pytest.raises(IndexError):
             graphrecord.neighbors(["0", "50"], directed=EdgesDirection.INCOMING)
 
+    def test_neighbors_filtered(self) -> None:
+        graphrecord = create_graphrecord()
+
+        def edge_query(edge: EdgeOperand) -> EdgeIndicesOperand:
+            edge.has_attribute("sed")
+
+            return edge.index()
+
+        assert graphrecord.neighbors_filtered("0", edge_predicate=edge_query) == ["1"]
+        assert graphrecord.neighbors_filtered(
+            ["0", "1"], EdgesDirection.INCOMING, edge_predicate=edge_query
+        ) == {"0": ["1"], "1": ["0"]}
+        assert graphrecord.neighbors_filtered(
+            "0", EdgesDirection.UNDIRECTED, edge_predicate=edge_query
+        ) == ["1"]
+
+        graphrecord.add_group("group", edges=[1, 3])
+
+        assert graphrecord.neighbors_filtered("0", edge_group="group") == ["3"]
+        assert (
+            graphrecord.neighbors_filtered(
+                "0", edge_group="group", edge_predicate=edge_query
+            )
+            == []
+        )
+
+        def node_query(node: NodeOperand) -> NodeIndicesOperand:
+            node.index().is_in(["0", "1"])
+
+            return node.index()
+
+        neighbors = graphrecord.neighbors_filtered(
+            node_query, EdgesDirection.INCOMING, edge_group="group"
+        )
+
+        assert neighbors == {"0": ["1"], "1": []}
+
+    def test_invalid_neighbors_filtered(self) -> None:
+        graphrecord = create_graphrecord()
+
+        with pytest.raises(IndexError):
+            graphrecord.neighbors_filtered("50")
+
+        with pytest.raises(IndexError):
+            graphrecord.neighbors_filtered("0", edge_group="50")
+
     def test_invalid_neighbors(self) -> None:
         graphrecord = create_graphrecord()
 
@@ -2769,6 +3991,31 @@ class TestGraphRecord(unittest.TestCase):
         assert graphrecord.edge_count() == 0
         assert graphrecord.group_count() == 0
 
+    def test_sorted(self) -> None:
+        graphrecord = create_graphrecord()
+        graphrecord.add_nodes([(10, {})])
+        graphrecord.add_group("b")
+        graphrecord.add_group("a")
+
+        assert graphrecord.nodes_sorted() == [10, "0", "1", "2", "3"]
+        assert graphrecord.edges_sorted() == [0, 1, 2, 3]
+        assert graphrecord.groups_sorted() == ["a", "b"]
+
+    def test_change_token(self) -> None:
+        graphrecord = create_graphrecord()
+
+        token = graphrecord.change_token()
+
+        graphrecord.node_count()
+        assert not graphrecord.is_modified_since(token)
+
+        graphrecord.add_nodes([("new", {})])
+        assert graphrecord.is_modified_since(token)
+
+        token = graphrecord.change_token()
+        graphrecord.node["new", "lorem"] = "ipsum"
+        assert graphrecord.is_modified_since(token)
+
     def test_clone(self) -> None:
         graphrecord = create_graphrecord()
 
@@ -2786,6 +4033,67 @@ class TestGraphRecord(unittest.TestCase):
         assert graphrecord.edge_count() != cloned_graphrecord.edge_count()
         assert graphrecord.group_count() != cloned_graphrecord.group_count()
 
+    def test_strict_queries(self) -> None:
+        graphrecord = create_graphrecord()
+
+        assert not graphrecord.strict_queries()
+
+        def query(node: NodeOperand) -> NodeMultipleValuesWithIndexOperand:
+            return node.attribute("lorm")
+
+        assert graphrecord.query_nodes(query) == {}
+
+        graphrecord.set_strict_queries(True)
+
+        assert graphrecord.strict_queries()
+
+        with pytest.raises(ValueError, match=r"Did you mean: lorem\?"):
+            graphrecord.query_nodes(query)
+
+    def test_float_tolerance(self) -> None:
+        graphrecord = GraphRecord.from_tuples([("0", {"weight": 0.1 + 0.2})])
+
+        assert graphrecord.float_tolerance() == 0
+
+        def query(node: NodeOperand) -> NodeIndicesOperand:
+            node.attribute("weight").equal_to(0.3)
+
+            return node.index()
+
+        assert graphrecord.query_nodes(query) == []
+
+        graphrecord.set_float_tolerance(1e-9)
+
+        assert graphrecord.float_tolerance() == pytest.approx(1e-9)
+        assert graphrecord.query_nodes(query) == ["0"]
+
+        with pytest.raises(GraphRecordAssertionError):
+            graphrecord.set_float_tolerance(-1)
+
+    def test_random_seed(self) -> None:
+        nodes = [(str(index), {"value": index}) for index in range(100)]
+        graphrecord = GraphRecord.from_tuples(nodes)
+        reversed_graphrecord = GraphRecord.from_tuples(nodes[::-1])
+
+        assert graphrecord.random_seed() is None
+
+        graphrecord.set_random_seed(42)
+        reversed_graphrecord.set_random_seed(42)
+
+        assert graphrecord.random_seed() == 42
+
+        def query(node: NodeOperand) -> NodeIndexOperand:
+            return node.index().random()
+
+        assert graphrecord.query_nodes(query) == graphrecord.query_nodes(query)
+        assert graphrecord.query_nodes(query) == reversed_graphrecord.query_nodes(
+            query
+        )
+
+        graphrecord.set_random_seed()
+
+        assert graphrecord.random_seed() is None
+
     def test_query_nodes(self) -> None:
         graphrecord = create_graphrecord()
 
@@ -2854,6 +4162,35 @@ class TestGraphRecord(unittest.TestCase):
         assert is_edge_index_list(edge_indices)
         assert sorted(edge_indices) == [0, 1, 3]
 
+    def test_query_nodes_concurrent_add_nodes(self) -> None:
+        graphrecord = GraphRecord.from_tuples(
+            [(f"node{index}", {"value": index}) for index in range(10000)]
+        )
+
+        def query(node: NodeOperand) -> NodeIndicesOperand:
+            return node.index()
+
+        def run_queries() -> None:
+            for _ in range(50):
+                graphrecord.query_nodes(query)
+
+        def add_nodes() -> None:
+            for index in range(50):
+                graphrecord.add_nodes((f"added{index}", {}))
+
+        threads = [
+            threading.Thread(target=run_queries, daemon=True),
+            threading.Thread(target=add_nodes, daemon=True),
+        ]
+
+        for thread in threads:
+            thread.start()
+        for thread in threads:
+            thread.join(timeout=60)
+
+        assert not any(thread.is_alive() for thread in threads)
+        assert graphrecord.node_count() == 10050
+
     def test_query_edges(self) -> None:
         graphrecord = create_graphrecord()
 
@@ -3177,7 +4514,9 @@ class RecordingPlugin(Plugin):
         self.calls.append("pre_set_schema")
         return context
 
-    def post_set_schema(self, graphrecord: GraphRecord) -> None:
+    def post_set_schema(
+        self, graphrecord: GraphRecord, context: PostSetSchemaContext
+    ) -> None:
         self.calls.append("post_set_schema")
 
     def pre_freeze_schema(self, graphrecord: GraphRecord) -> None:
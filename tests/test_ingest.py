@@ -0,0 +1,63 @@
+import unittest
+from concurrent.futures import ThreadPoolExecutor
+
+import pytest
+
+from graphrecords import GraphRecord, ShardedIngest
+
+
+class TestShardedIngest(unittest.TestCase):
+    def test_shard_count(self) -> None:
+        assert ShardedIngest(4).shard_count == 4
+        assert ShardedIngest().shard_count > 0
+
+    def test_commit(self) -> None:
+        ingest = ShardedIngest()
+
+        ingest.add_node("0", {"foo": "bar"})
+        ingest.add_nodes([("1", {}), ("2", {})], "group")
+        ingest.add_edge("0", "1", {"foo": "bar"}, "group")
+        ingest.add_edges([("1", "2", {})])
+
+        assert ingest.pending_node_count() == 3
+        assert ingest.pending_edge_count() == 2
+
+        graphrecord = GraphRecord()
+        ingest.commit(graphrecord)
+
+        assert ingest.pending_node_count() == 0
+        assert ingest.pending_edge_count() == 0
+
+        assert graphrecord.node_count() == 3
+        assert graphrecord.edge_count() == 2
+        assert sorted(graphrecord.nodes_in_group("group")) == ["1", "2"]
+        assert len(graphrecord.edges_in_group("group")) == 1
+
+    def test_concurrent_add(self) -> None:
+        ingest = ShardedIngest()
+
+        def add_group(thread_index: int) -> None:
+            for node in range(100):
+                ingest.add_node(thread_index * 100 + node, {}, f"group_{thread_index}")
+
+        with ThreadPoolExecutor(max_workers=4) as executor:
+            list(executor.map(add_group, range(4)))
+
+        graphrecord = GraphRecord()
+        ingest.commit(graphrecord)
+
+        assert graphrecord.node_count() == 400
+        assert graphrecord.group_count() == 4
+
+    def test_invalid_commit(self) -> None:
+        ingest = ShardedIngest()
+
+        ingest.add_edge("0", "1", {})
+
+        with pytest.raises(IndexError):
+            ingest.commit(GraphRecord())
+
+
+if __name__ == "__main__":
+    run_test = unittest.TestLoader().loadTestsFromTestCase(TestShardedIngest)
+    unittest.TextTestRunner(verbosity=2).run(run_test)
@@ -1,10 +1,12 @@
 import unittest
-from typing import List
+from typing import TYPE_CHECKING, List, Optional
 
 import polars as pl
+import pytest
 
 from graphrecords import GraphRecord
 from graphrecords.plugins import (
+    MappingPlugin,
     Plugin,
     PostAddEdgeContext,
     PostAddEdgesContext,
@@ -40,6 +42,7 @@ from graphrecords.plugins import (
     PostRemoveNodeFromGroupContext,
     PostRemoveNodeFromGroupsContext,
     PostRemoveNodesFromGroupsContext,
+    PostSetSchemaContext,
     PreAddEdgeContext,
     PreAddEdgesContext,
     PreAddEdgesDataframesContext,
@@ -77,12 +80,17 @@ from graphrecords.plugins import (
     PreSetSchemaContext,
     _PluginBridge,
 )
-from graphrecords.schema import Schema
+from graphrecords.datatype import Float, Int
+from graphrecords.schema import AttributeType, GroupSchema, Schema
+
+if TYPE_CHECKING:
+    from graphrecords.types import Attributes
 
 
 class RecordingPlugin(Plugin):
     def __init__(self) -> None:
         self.calls: List[str] = []
+        self.post_set_schema_context: Optional[PostSetSchemaContext] = None
 
     def initialize(self, graphrecord: GraphRecord) -> None:
         self.calls.append("initialize")
@@ -93,8 +101,11 @@ class RecordingPlugin(Plugin):
         self.calls.append("pre_set_schema")
         return context
 
-    def post_set_schema(self, graphrecord: GraphRecord) -> None:
+    def post_set_schema(
+        self, graphrecord: GraphRecord, context: PostSetSchemaContext
+    ) -> None:
         self.calls.append("post_set_schema")
+        self.post_set_schema_context = context
 
     def pre_freeze_schema(self, graphrecord: GraphRecord) -> None:
         self.calls.append("pre_freeze_schema")
@@ -514,6 +525,28 @@ class TestContextConstruction(unittest.TestCase):
 
         assert isinstance(context.schema, Schema)
 
+    def test_post_set_schema_context(self) -> None:
+        old_schema = Schema(groups={"g": GroupSchema(nodes={"x": Int()})})
+        new_schema = Schema(groups={"g": GroupSchema(nodes={"x": Float()})})
+
+        context = PostSetSchemaContext(old_schema, new_schema)
+
+        assert context.old_schema.group("g").nodes["x"][0] == Int()
+        assert context.new_schema.group("g").nodes["x"][0] == Float()
+        assert context.changes == {
+            "added_groups": [],
+            "removed_groups": [],
+            "node_changes": [
+                {
+                    "group": "g",
+                    "attribute": "x",
+                    "old": (Int(), AttributeType.Continuous),
+                    "new": (Float(), AttributeType.Continuous),
+                }
+            ],
+            "edge_changes": [],
+        }
+
     def test_pre_add_node_context(self) -> None:
         context = PreAddNodeContext("a", {"x": 1})
 
@@ -962,6 +995,14 @@ class TestContextFromPyContext(unittest.TestCase):
 
         assert isinstance(reconstructed.schema, Schema)
 
+    def test_post_set_schema_from_py_context(self) -> None:
+        original = PostSetSchemaContext(Schema(), Schema())
+
+        reconstructed = PostSetSchemaContext._from_py_context(original._py_context)
+
+        assert isinstance(reconstructed.old_schema, Schema)
+        assert isinstance(reconstructed.new_schema, Schema)
+
     def test_pre_add_node_from_py_context(self) -> None:
         original = PreAddNodeContext("a", {"x": 1})
 
@@ -1624,7 +1665,7 @@ class TestPluginBaseDefaults(unittest.TestCase):
 
         schema_context = PreSetSchemaContext(Schema())
         assert plugin.pre_set_schema(graphrecord, schema_context) is schema_context
-        plugin.post_set_schema(graphrecord)
+        plugin.post_set_schema(graphrecord, PostSetSchemaContext(Schema(), Schema()))
 
         plugin.pre_freeze_schema(graphrecord)
         plugin.post_freeze_schema(graphrecord)
@@ -1947,6 +1988,38 @@ class TestPluginHooksFiring(unittest.TestCase):
 
         assert plugin.calls == ["pre_set_schema", "post_set_schema"]
 
+    def test_set_schema_hooks_changes(self) -> None:
+        plugin = RecordingPlugin()
+        graphrecord = GraphRecord.with_plugins({"recorder": plugin})
+        graphrecord.set_schema(Schema(groups={"g": GroupSchema(nodes={"x": Int()})}))
+
+        graphrecord.set_schema(Schema(groups={"h": GroupSchema(edges={"y": Int()})}))
+
+        context = plugin.post_set_schema_context
+        assert context is not None
+        changes = context.changes
+
+        assert context.old_schema.groups == ["g"]
+        assert context.new_schema.groups == ["h"]
+        assert changes["added_groups"] == ["h"]
+        assert changes["removed_groups"] == ["g"]
+        assert changes["node_changes"] == [
+            {
+                "group": "g",
+                "attribute": "x",
+                "old": (Int(), AttributeType.Continuous),
+                "new": None,
+            }
+        ]
+        assert changes["edge_changes"] == [
+            {
+                "group": "h",
+                "attribute": "y",
+                "old": None,
+                "new": (Int(), AttributeType.Continuous),
+            }
+        ]
+
     def test_freeze_schema_hooks(self) -> None:
         plugin = RecordingPlugin()
         graphrecord = GraphRecord.with_plugins({"recorder": plugin})
@@ -2394,6 +2467,105 @@ class TestMultiplePlugins(unittest.TestCase):
         assert "a" in graphrecord.nodes
         assert "extra" in graphrecord.nodes
 
+    def test_priority_order(self) -> None:
+        order: List[str] = []
+
+        class OrderPlugin(Plugin):
+            def __init__(self, name: str) -> None:
+                self.name = name
+
+            def pre_add_nodes(
+                self, graphrecord: GraphRecord, context: PreAddNodesContext
+            ) -> PreAddNodesContext:
+                order.append(self.name)
+                return context
+
+        graphrecord = GraphRecord.with_plugins(
+            {
+                "audit": OrderPlugin("audit"),
+                "validation": OrderPlugin("validation"),
+            },
+            priorities={"validation": 10},
+        )
+        graphrecord.add_plugin("metrics", OrderPlugin("metrics"))
+        graphrecord.add_plugin("cleanup", OrderPlugin("cleanup"), priority=-10)
+
+        assert graphrecord.plugins == ["validation", "audit", "metrics", "cleanup"]
+        assert graphrecord.plugin_priority("validation") == 10
+
+        graphrecord.add_nodes([("a", {})])
+
+        assert order == ["validation", "audit", "metrics", "cleanup"]
+
+        graphrecord.set_plugin_priority("cleanup", 20)
+        graphrecord.remove_plugin("validation")
+
+        assert graphrecord.plugins == ["cleanup", "audit", "metrics"]
+
+    def test_register_plugin(self) -> None:
+        graphrecord = GraphRecord()
+        graphrecord.add_nodes([("a", {})])
+
+        listener = RecordingPlugin()
+        handle = graphrecord.register_plugin(listener)
+
+        assert listener.calls == ["initialize"]
+        assert graphrecord.plugins == []
+
+        graphrecord.add_nodes([("b", {})])
+
+        assert "post_add_nodes" in listener.calls
+
+        graphrecord.unregister_plugin(handle)
+        listener.calls.clear()
+
+        graphrecord.add_nodes([("c", {})])
+
+        assert listener.calls == []
+
+        with pytest.raises(KeyError):
+            graphrecord.unregister_plugin(handle)
+
+
+
+class TestOperationContext(unittest.TestCase):
+    def test_hooks_receive_operation_context(self) -> None:
+        contexts: List[Attributes] = []
+
+        class ContextPlugin(Plugin):
+            def pre_add_nodes(
+                self, graphrecord: GraphRecord, context: PreAddNodesContext
+            ) -> PreAddNodesContext:
+                contexts.append(graphrecord.operation_context)
+                return context
+
+        graphrecord = GraphRecord.with_plugins({"context": ContextPlugin()})
+
+        with graphrecord.with_operation_context({"user": "alice"}):
+            graphrecord.add_nodes([("a", {})])
+
+            with graphrecord.with_operation_context({"reason": "import"}):
+                graphrecord.add_nodes([("b", {})])
+
+        graphrecord.add_nodes([("c", {})])
+
+        assert contexts == [
+            {"user": "alice"},
+            {"user": "alice", "reason": "import"},
+            {},
+        ]
+
+    def test_operation_context_restored_on_error(self) -> None:
+        graphrecord = GraphRecord()
+
+        with (
+            pytest.raises(ValueError),
+            graphrecord.with_operation_context({"user": "alice"}),
+        ):
+            raise ValueError
+
+        assert graphrecord.operation_context == {}
+
 
 class TestPluginBridgeSingularHooks(unittest.TestCase):
     def test_pre_add_node_bridge(self) -> None:
@@ -2486,5 +2658,46 @@ class TestPluginBridgeSingularHooks(unittest.TestCase):
         assert "finalize" in plugin.calls
 
 
+class TestMappingPlugin(unittest.TestCase):
+    def test_map_nodes(self) -> None:
+        plugin = MappingPlugin({"code": {"GLU": "2345-7", "HBA1C": "4548-4"}})
+        graphrecord = GraphRecord()
+        graphrecord.add_plugin("mapping", plugin)
+
+        graphrecord.add_nodes(
+            [
+                ("0", {"code": "GLU"}),
+                ("1", {"code": "LDL"}),
+                ("2", {"name": "GLU"}),
+            ]
+        )
+        graphrecord.add_nodes(
+            (pl.DataFrame({"index": ["3"], "code": ["HBA1C"]}), "index")
+        )
+
+        assert graphrecord.node["0", "code"] == "2345-7"
+        assert graphrecord.node["1", "code"] == "LDL"
+        assert graphrecord.node["2", "name"] == "GLU"
+        assert graphrecord.node["3", "code"] == "4548-4"
+
+        assert plugin.unmapped_node_values() == {"code": {"LDL"}}
+        assert plugin.unmapped_edge_values() == {}
+
+        plugin.clear_unmapped_values()
+
+        assert plugin.unmapped_node_values() == {}
+
+    def test_map_edges(self) -> None:
+        plugin = MappingPlugin(edge_mappings={"relation": {1: "parent", 2: "child"}})
+        graphrecord = GraphRecord()
+        graphrecord.add_nodes([("0", {}), ("1", {})])
+        graphrecord.register_plugin(plugin)
+
+        edge_index = graphrecord.add_edges(("0", "1", {"relation": 1}))[0]
+
+        assert graphrecord.edge[edge_index, "relation"] == "parent"
+        assert plugin.unmapped_edge_values() == {}
+
+
 if __name__ == "__main__":
     unittest.main()
@@ -6,7 +6,14 @@ from pathlib import Path
 import polars as pl
 import pytest
 
-from graphrecords import EdgeIndex, GraphRecord, NodeIndex
+from graphrecords import (
+    MISSING,
+    EdgeIndex,
+    GeoPoint,
+    GraphRecord,
+    NodeIndex,
+    QueryError,
+)
 from graphrecords.querying import (
     EdgeAttributesTreeGroupOperand,
     EdgeAttributesTreeOperand,
@@ -42,6 +49,7 @@ from graphrecords.querying import (
     NodeAttributesTreeGroupOperand,
     NodeAttributesTreeOperand,
     NodeAttributesTreeQueryResult,
+    NodeHistogramOperand,
     NodeIndexGroupOperand,
     NodeIndexOperand,
     NodeIndicesGroupOperand,
@@ -57,6 +65,7 @@ from graphrecords.querying import (
     NodeMultipleValuesWithoutIndexOperand,
     NodeOperand,
     NodeOperandGroupDiscriminator,
+    NodeQuantilesOperand,
     NodeSingleAttributeWithIndexGroupOperand,
     NodeSingleAttributeWithIndexOperand,
     NodeSingleAttributeWithoutIndexGroupOperand,
@@ -67,6 +76,7 @@ from graphrecords.querying import (
     NodeSingleValueWithoutIndexGroupOperand,
     NodeSingleValueWithoutIndexOperand,
     NodeSingleValueWithoutIndexQueryResult,
+    NodeValueCountsOperand,
     PyEdgeIndexOperand,
     PyEdgeIndicesOperand,
     PyEdgeMultipleAttributesWithIndexOperand,
@@ -87,6 +97,7 @@ from graphrecords.querying import (
     PyNodeSingleAttributeWithoutIndexOperand,
     PyNodeSingleValueWithIndexOperand,
     PyNodeSingleValueWithoutIndexOperand,
+    RollingAggregation,
     _py_edge_index_comparison_operand_from_edge_index_comparison_operand,
     _py_edge_indices_comparison_operand_from_edge_indices_comparison_operand,
     _py_multiple_attributes_comparison_operand_from_multiple_attributes_comparison_operand,
@@ -99,6 +110,79 @@ from graphrecords.querying import (
 from graphrecords.types import GraphRecordAttribute, GraphRecordValue
 
 
+def rolling_example_graphrecord() -> GraphRecord:
+    return GraphRecord.from_tuples(
+        [
+            ("m1", {"patient": "a", "time": datetime(2024, 1, 1), "value": 10}),
+            ("m2", {"patient": "a", "time": datetime(2024, 1, 3), "value": 20}),
+            ("m3", {"patient": "a", "time": datetime(2024, 1, 9), "value": 30}),
+            ("m4", {"patient": "b", "time": datetime(2024, 1, 2), "value": 40}),
+            ("m5", {"patient": "b", "value": 50}),
+        ]
+    )
+
+
+def fuzzy_example_graphrecord() -> GraphRecord:
+    return GraphRecord.from_tuples(
+        [
+            ("d1", {"patient": "a", "diagnosis": "diabetes"}),
+            ("d2", {"patient": "a", "diagnosis": "diabetis"}),
+            ("d3", {"patient": "b", "diagnosis": "diabetic"}),
+            ("d4", {"patient": "b", "diagnosis": "asthma"}),
+            ("d5", {"patient": "b", "diagnosis": 250}),
+        ]
+    )
+
+
+BERLIN = GeoPoint(52.52, 13.405)
+POTSDAM = GeoPoint(52.3906, 13.0645)
+MUNICH = GeoPoint(48.1351, 11.582)
+
+
+def geo_example_graphrecord() -> GraphRecord:
+    graphrecord = GraphRecord.from_tuples(
+        [
+            ("f1", {"region": "north", "location": BERLIN}),
+            ("f2", {"region": "north", "location": POTSDAM}),
+            ("f3", {"region": "south", "location": MUNICH}),
+            ("f4", {"region": "north", "location": "Berlin"}),
+            ("f5", {"region": "south", "location": BERLIN}),
+        ]
+    )
+    graphrecord.add_group("facilities", ["f1", "f2", "f3", "f4"])
+
+    return graphrecord
+
+
+def induced_example_graphrecord() -> GraphRecord:
+    graphrecord = GraphRecord.from_tuples(
+        [("a", {"age": 30}), ("b", {"age": 40}), ("c", {"age": 50}), ("d", {})],
+        [
+            ("a", "b", {"weight": 1}),
+            ("b", "a", {"weight": 2}),
+            ("a", "d", {"weight": 3}),
+            ("c", "b", {"weight": 4}),
+        ],
+    )
+    graphrecord.add_group("cohort", ["a", "b"])
+
+    return graphrecord
+
+
+def text_example_graphrecord() -> GraphRecord:
+    graphrecord = GraphRecord.from_tuples(
+        [
+            ("n1", {"patient": "a", "note": "Acute heart failure, NYHA III."}),
+            ("n2", {"patient": "a", "note": "Heart murmur"}),
+            ("n3", {"patient": "b", "note": "Failure to thrive; heart rate normal"}),
+            ("n4", {"patient": "b", "note": 42}),
+        ]
+    )
+    graphrecord.add_group("notes", ["n1", "n2", "n3"])
+
+    return graphrecord
+
+
 def query_node(node: NodeOperand) -> None:
     node.index().equal_to("pat_1")
 
@@ -584,6 +668,16 @@ class TestNodeOperand(unittest.TestCase):
 
         assert self.graphrecord.query_nodes(query) == {"pat_1": "M"}
 
+    def test_node_operand_attribute_large(self) -> None:
+        graphrecord = GraphRecord.from_tuples(
+            [(i, {"value": i} if i % 2 == 0 else {}) for i in range(10000)]
+        )
+
+        def query(node: NodeOperand) -> NodeMultipleValuesWithIndexOperand:
+            return node.attribute("value")
+
+        assert graphrecord.query_nodes(query) == {i: i for i in range(0, 10000, 2)}
+
     def test_node_operand_attributes(self) -> None:
         def query(node: NodeOperand) -> NodeAttributesTreeOperand:
             query_node(node)
@@ -710,6 +804,31 @@ class TestNodeOperand(unittest.TestCase):
         assert "pat_2" in self.graphrecord.query_nodes(query3)
         assert "procedure_171207006" in self.graphrecord.query_nodes(query3)
 
+    def test_node_operand_degree(self) -> None:
+        graphrecord = induced_example_graphrecord()
+
+        def query(node: NodeOperand) -> NodeMultipleValuesWithIndexOperand:
+            return node.degree(EdgeDirection.OUTGOING)
+
+        assert graphrecord.query_nodes(query) == {"a": 2, "b": 1, "c": 1, "d": 0}
+
+        def query_incoming(node: NodeOperand) -> NodeIndicesOperand:
+            node.degree(EdgeDirection.INCOMING).greater_than(1)
+            return node.index()
+
+        assert graphrecord.query_nodes(query_incoming) == ["b"]
+
+        def query_both(node: NodeOperand) -> NodeIndicesOperand:
+            node.degree().equal_to(3)
+            return node.index()
+
+        assert sorted(graphrecord.query_nodes(query_both)) == ["a", "b"]
+
+        # Undirected edges are also traversed from target to source
+        graphrecord.set_edge_undirected(2)
+
+        assert graphrecord.query_nodes(query)["d"] == 1
+
     def test_node_operand_either_or(self) -> None:
         def query(node: NodeOperand) -> NodeIndicesOperand:
             node.either_or(
@@ -728,6 +847,53 @@ class TestNodeOperand(unittest.TestCase):
 
         assert sorted(self.graphrecord.query_nodes(query)) == ["pat_2", "pat_4"]
 
+    def test_node_operand_all_of(self) -> None:
+        def query(node: NodeOperand) -> NodeIndicesOperand:
+            node.in_group("patient")
+            node.all_of(
+                [
+                    lambda node: node.attribute("age").greater_than(30),
+                    lambda node: node.attribute("age").less_than(90),
+                ]
+            )
+            return node.index()
+
+        assert sorted(self.graphrecord.query_nodes(query)) == ["pat_1", "pat_5"]
+
+    def test_node_operand_none_of(self) -> None:
+        def query(node: NodeOperand) -> NodeIndicesOperand:
+            node.in_group("patient")
+            node.none_of(
+                [
+                    lambda node: node.attribute("age").greater_than(90),
+                    lambda node: node.attribute("age").less_than(20),
+                ]
+            )
+            return node.index()
+
+        assert sorted(self.graphrecord.query_nodes(query)) == [
+            "pat_1",
+            "pat_2",
+            "pat_5",
+        ]
+
+    def test_node_operand_not(self) -> None:
+        def query(node: NodeOperand) -> NodeIndicesOperand:
+            node.in_group("patient")
+            node.not_(
+                lambda node: node.either_or(
+                    lambda node: node.attribute("age").greater_than(90),
+                    lambda node: node.attribute("age").less_than(20),
+                )
+            )
+            return node.index()
+
+        assert sorted(self.graphrecord.query_nodes(query)) == [
+            "pat_1",
+            "pat_2",
+            "pat_5",
+        ]
+
     def test_node_operand_clone(self) -> None:
         def query(node: NodeOperand) -> NodeIndicesOperand:
             node.in_group("patient")
@@ -774,6 +940,24 @@ class TestNodeGroupOperand(unittest.TestCase):
             ("M", {"pat_4": 19, "pat_5": 37, "pat_1": 42}),
         ]
 
+    def test_group_operand_group_by(self) -> None:
+        def query(node: NodeOperand) -> NodeSingleValueWithoutIndexGroupOperand:
+            node.in_group("patient")
+            group = node.group_by(NodeOperandGroupDiscriminator.Attribute("gender"))
+            return (
+                group.group_by(NodeOperandGroupDiscriminator.Attribute("age"))
+                .attribute("age")
+                .count()
+            )
+
+        assert sorted(self.graphrecord.query_nodes(query)) == [
+            (("F", 22), 1),
+            (("F", 96), 1),
+            (("M", 19), 1),
+            (("M", 37), 1),
+            (("M", 42), 1),
+        ]
+
     def test_group_operand_attributes(self) -> None:
         def query(node: NodeOperand) -> NodeAttributesTreeGroupOperand:
             node.has_attribute("gender")
@@ -831,6 +1015,27 @@ class TestNodeGroupOperand(unittest.TestCase):
             ("M", ["pat_1", "pat_4", "pat_5"]),
         ]
 
+    def test_group_operand_sorted_by_key(self) -> None:
+        def query(node: NodeOperand) -> NodeIndicesGroupOperand:
+            node.has_attribute("gender")
+            indices = node.group_by(
+                NodeOperandGroupDiscriminator.Attribute("gender")
+            ).index()
+            indices.sorted_by_key()
+            return indices
+
+        assert [key for key, _ in self.graphrecord.query_nodes(query)] == ["F", "M"]
+
+    def test_group_operand_sorted_by_aggregate(self) -> None:
+        def query(node: NodeOperand) -> NodeIndicesGroupOperand:
+            node.has_attribute("gender")
+            group = node.group_by(NodeOperandGroupDiscriminator.Attribute("gender"))
+            indices = group.index()
+            indices.sorted_by_aggregate(group.attribute("age").mean())
+            return indices
+
+        assert [key for key, _ in self.graphrecord.query_nodes(query)] == ["M", "F"]
+
     def test_group_operand_in_group(self) -> None:
         def query(node: NodeOperand) -> NodeIndicesGroupOperand:
             group = node.group_by(NodeOperandGroupDiscriminator.Attribute("gender"))
@@ -933,6 +1138,19 @@ class TestNodeGroupOperand(unittest.TestCase):
             ("M", ["pat_1"]),
         ]
 
+    def test_group_operand_degree(self) -> None:
+        graphrecord = induced_example_graphrecord()
+
+        def query(node: NodeOperand) -> NodeMultipleValuesWithIndexGroupOperand:
+            group = node.group_by(NodeOperandGroupDiscriminator.Attribute("age"))
+            return group.degree(EdgeDirection.OUTGOING)
+
+        assert sorted(graphrecord.query_nodes(query)) == [
+            (30, {"a": 2}),
+            (40, {"b": 1}),
+            (50, {"c": 1}),
+        ]
+
     def test_group_operand_either_or(self) -> None:
         def query(node: NodeOperand) -> NodeIndicesGroupOperand:
             group = node.group_by(NodeOperandGroupDiscriminator.Attribute("gender"))
@@ -997,6 +1215,17 @@ class TestEdgeOperand(unittest.TestCase):
 
         assert self.graphrecord.query_edges(query) == {0: datetime(2014, 2, 6, 0, 0)}
 
+    def test_edge_operand_attribute_large(self) -> None:
+        graphrecord = GraphRecord.from_tuples(
+            [(0, {})],
+            [(0, 0, {"value": i} if i % 2 == 0 else {}) for i in range(10000)],
+        )
+
+        def query(edge: EdgeOperand) -> EdgeMultipleValuesWithIndexOperand:
+            return edge.attribute("value")
+
+        assert graphrecord.query_edges(query) == {i: i for i in range(0, 10000, 2)}
+
     def test_edge_operand_attributes(self) -> None:
         def query(edge: EdgeOperand) -> EdgeAttributesTreeOperand:
             query_edge(edge)
@@ -1109,6 +1338,60 @@ class TestEdgeOperand(unittest.TestCase):
 
         assert sorted(self.graphrecord.query_edges(query)) == [0, 4]
 
+    def test_edge_operand_all_of(self) -> None:
+        def query(edge: EdgeOperand) -> EdgeIndicesOperand:
+            edge.index().less_than(5)
+            edge.all_of(
+                [
+                    lambda edge: edge.attribute("duration_days").greater_than(1),
+                    lambda edge: edge.attribute("duration_days").less_than(500),
+                ]
+            )
+            return edge.index()
+
+        assert sorted(self.graphrecord.query_edges(query)) == [2, 3]
+
+    def test_edge_operand_none_of(self) -> None:
+        def query(edge: EdgeOperand) -> EdgeIndicesOperand:
+            edge.index().less_than(5)
+            edge.none_of(
+                [
+                    lambda edge: query_edge(edge),
+                    lambda edge: edge.attribute("duration_days").greater_than(100),
+                ]
+            )
+            return edge.index()
+
+        assert sorted(self.graphrecord.query_edges(query)) == [3, 4]
+
+    def test_edge_operand_not(self) -> None:
+        def query_duration(edge: EdgeOperand) -> None:
+            edge.attribute("duration_days").greater_than(1)
+            edge.attribute("duration_days").less_than(500)
+
+        def query(edge: EdgeOperand) -> EdgeIndicesOperand:
+            edge.index().less_than(5)
+            edge.not_(query_duration)
+            return edge.index()
+
+        assert sorted(self.graphrecord.query_edges(query)) == [0, 1, 4]
+
+    def test_edge_operand_induced_by(self) -> None:
+        graphrecord = induced_example_graphrecord()
+
+        def query(edge: EdgeOperand) -> EdgeIndicesOperand:
+            edge.induced_by(lambda node: node.in_group("cohort"))
+            return edge.index()
+
+        assert sorted(graphrecord.query_edges(query)) == [0, 1]
+
+        def query_attribute(edge: EdgeOperand) -> EdgeIndicesOperand:
+            edge.induced_by(lambda node: node.has_attribute("age"))
+            edge.attribute("weight").greater_than(1)
+            return edge.index()
+
+        assert sorted(graphrecord.query_edges(query_attribute)) == [1, 3]
+
     def test_edge_operand_clone(self) -> None:
         def query(edge: EdgeOperand) -> EdgeIndicesOperand:
             edge.index().less_than(5)
@@ -1192,6 +1475,36 @@ class TestEdgeGroupOperand(unittest.TestCase):
             ("pat_1", [0, 1, 2, 3, 4]),
         ]
 
+    def test_edge_group_operand_endpoint_groups(self) -> None:
+        def query(edge: EdgeOperand) -> EdgeIndicesGroupOperand:
+            group = edge.group_by(EdgeOperandGroupDiscriminator.EndpointGroups())
+            return group.index()
+
+        result = sorted(
+            (key, len(indices)) for key, indices in self.graphrecord.query_edges(query)
+        )
+        assert result == [
+            (("patient", "diagnosis"), 60),
+            (("patient", "drug"), 50),
+            (("patient", "procedure"), 50),
+        ]
+
+    def test_edge_group_operand_group_by(self) -> None:
+        def query(edge: EdgeOperand) -> EdgeIndicesGroupOperand:
+            edge.in_group("patient_drug")
+            edge.source_node().index().equal_to("pat_1")
+            group = edge.group_by(EdgeOperandGroupDiscriminator.SourceNode())
+            return group.group_by(EdgeOperandGroupDiscriminator.TargetNode()).index()
+
+        result = sorted(
+            (key, sorted(indices))
+            for key, indices in self.graphrecord.query_edges(query)
+        )
+        assert result == [
+            (("pat_1", "drug_562251"), [64]),
+            (("pat_1", "drug_856987"), [60, 61, 62, 63, 65]),
+        ]
+
     def test_edge_group_operand_in_group(self) -> None:
         def query(edge: EdgeOperand) -> EdgeIndicesGroupOperand:
             group = edge.group_by(EdgeOperandGroupDiscriminator.SourceNode())
@@ -1363,6 +1676,20 @@ class TestEdgeGroupOperand(unittest.TestCase):
         )
         assert result == [("pat_1", [0, 1])]
 
+    def test_edge_group_operand_induced_by(self) -> None:
+        graphrecord = induced_example_graphrecord()
+
+        def query(edge: EdgeOperand) -> EdgeIndicesGroupOperand:
+            group = edge.group_by(EdgeOperandGroupDiscriminator.SourceNode())
+            group.induced_by(lambda node: node.has_attribute("age"))
+            return group.index()
+
+        assert sorted(graphrecord.query_edges(query)) == [
+            ("a", [0]),
+            ("b", [1]),
+            ("c", [3]),
+        ]
+
     def test_edge_group_operand_clone(self) -> None:
         def query(edge: EdgeOperand) -> EdgeIndicesGroupOperand:
             group = edge.group_by(EdgeOperandGroupDiscriminator.SourceNode())
@@ -1452,6 +1779,125 @@ class TestNodeMultipleValuesWithIndexGroupOperand(unittest.TestCase):
             ("M", 37.0),
         ]
 
+    def test_quantile(self) -> None:
+        def query(node: NodeOperand) -> NodeSingleValueWithoutIndexGroupOperand:
+            node.has_attribute("gender")
+            group = node.group_by(
+                NodeOperandGroupDiscriminator.Attribute("gender")
+            ).attribute("age")
+            return group.quantile(0.75)
+
+        assert sorted(self.graphrecord.query_nodes(query)) == [
+            ("F", 77.5),
+            ("M", 39.5),
+        ]
+
+    def test_rolling(self) -> None:
+        graphrecord = rolling_example_graphrecord()
+
+        def query(node: NodeOperand) -> NodeMultipleValuesWithIndexGroupOperand:
+            group = node.group_by(
+                NodeOperandGroupDiscriminator.Attribute("patient")
+            ).attribute("value")
+            group.rolling(timedelta(days=7), RollingAggregation.MEAN, "time")
+            return group
+
+        assert sorted(graphrecord.query_nodes(query)) == [
+            ("a", {"m1": 10.0, "m2": 15.0, "m3": 25.0}),
+            ("b", {"m4": 40.0}),
+        ]
+
+    def test_lag(self) -> None:
+        graphrecord = rolling_example_graphrecord()
+
+        def query(node: NodeOperand) -> NodeMultipleValuesWithIndexGroupOperand:
+            group = node.group_by(
+                NodeOperandGroupDiscriminator.Attribute("patient")
+            ).attribute("value")
+            group.lag(1, "time")
+            return group
+
+        assert sorted(graphrecord.query_nodes(query)) == [
+            ("a", {"m1": None, "m2": 10, "m3": 20}),
+            ("b", {"m4": None}),
+        ]
+
+    def test_when(self) -> None:
+        graphrecord = rolling_example_graphrecord()
+
+        def query(node: NodeOperand) -> NodeMultipleValuesWithIndexGroupOperand:
+            group = node.group_by(
+                NodeOperandGroupDiscriminator.Attribute("patient")
+            ).attribute("value")
+            group.when(lambda values: values.greater_than(15)).then(1).otherwise(0)
+            return group
+
+        assert sorted(graphrecord.query_nodes(query)) == [
+            ("a", {"m1": 0, "m2": 1, "m3": 1}),
+            ("b", {"m4": 1, "m5": 1}),
+        ]
+
+    def test_fuzzy_matching(self) -> None:
+        graphrecord = fuzzy_example_graphrecord()
+
+        def query(node: NodeOperand) -> NodeMultipleValuesWithIndexGroupOperand:
+            group = node.group_by(
+                NodeOperandGroupDiscriminator.Attribute("patient")
+            ).attribute("diagnosis")
+            group.levenshtein_at_most("diabetes", 1)
+            return group
+
+        assert sorted(graphrecord.query_nodes(query)) == [
+            ("a", {"d1": "diabetes", "d2": "diabetis"}),
+            ("b", {}),
+        ]
+
+        def query_jaro_winkler(
+            node: NodeOperand,
+        ) -> NodeMultipleValuesWithIndexGroupOperand:
+            group = node.group_by(
+                NodeOperandGroupDiscriminator.Attribute("patient")
+            ).attribute("diagnosis")
+            group.jaro_winkler_at_least("diabetes", 0.85)
+            return group
+
+        assert sorted(graphrecord.query_nodes(query_jaro_winkler)) == [
+            ("a", {"d1": "diabetes", "d2": "diabetis"}),
+            ("b", {"d3": "diabetic"}),
+        ]
+
+    def test_text_search(self) -> None:
+        graphrecord = text_example_graphrecord()
+        graphrecord.create_text_index("notes", "note")
+
+        def query(node: NodeOperand) -> NodeMultipleValuesWithIndexGroupOperand:
+            group = node.group_by(
+                NodeOperandGroupDiscriminator.Attribute("patient")
+            ).attribute("note")
+            group.text_search("heart failure")
+            return group
+
+        assert sorted(graphrecord.query_nodes(query)) == [
+            ("a", {"n1": "Acute heart failure, NYHA III."}),
+            ("b", {"n3": "Failure to thrive; heart rate normal"}),
+        ]
+
+    def test_within_radius(self) -> None:
+        graphrecord = geo_example_graphrecord()
+        graphrecord.create_geo_index("facilities", "location")
+
+        def query(node: NodeOperand) -> NodeMultipleValuesWithIndexGroupOperand:
+            group = node.group_by(
+                NodeOperandGroupDiscriminator.Attribute("region")
+            ).attribute("location")
+            group.within_radius(BERLIN, 50)
+            return group
+
+        assert sorted(graphrecord.query_nodes(query)) == [
+            ("north", {"f1": BERLIN, "f2": POTSDAM}),
+            ("south", {"f5": BERLIN}),
+        ]
+
     def test_mode(self) -> None:
         def query(node: NodeOperand) -> NodeSingleValueWithoutIndexGroupOperand:
             node.has_attribute("gender")
@@ -1505,6 +1951,21 @@ class TestNodeMultipleValuesWithIndexGroupOperand(unittest.TestCase):
             ("M", 3),
         ]
 
+    def test_count_distinct(self) -> None:
+        def query(node: NodeOperand) -> NodeSingleValueWithoutIndexGroupOperand:
+            node.has_attribute("gender")
+            group = node.group_by(
+                NodeOperandGroupDiscriminator.Attribute("gender")
+            ).attribute("age")
+            group.div(50)
+            group.floor()
+            return group.count_distinct()
+
+        assert sorted(self.graphrecord.query_nodes(query)) == [
+            ("F", 2),
+            ("M", 1),
+        ]
+
     def test_sum(self) -> None:
         def query(node: NodeOperand) -> NodeSingleValueWithoutIndexGroupOperand:
             node.has_attribute("gender")
@@ -1674,6 +2135,20 @@ class TestNodeMultipleValuesWithIndexGroupOperand(unittest.TestCase):
             ("M", {"pat_4": 19}),
         ]
 
+    def test_distinct(self) -> None:
+        def query(node: NodeOperand) -> NodeSingleValueWithoutIndexGroupOperand:
+            node.has_attribute("gender")
+            group = node.group_by(
+                NodeOperandGroupDiscriminator.Attribute("gender")
+            ).attribute("gender")
+            group.distinct()
+            return group.count()
+
+        assert sorted(self.graphrecord.query_nodes(query)) == [
+            ("F", 1),
+            ("M", 1),
+        ]
+
     def test_greater_than(self) -> None:
         def query(node: NodeOperand) -> NodeMultipleValuesWithIndexGroupOperand:
             node.has_attribute("gender")
@@ -2360,6 +2835,18 @@ class TestNodeMultipleValuesWithoutIndexOperand(unittest.TestCase):
 
         assert self.graphrecord.query_nodes(query) == 2
 
+    def test_count_distinct(self) -> None:
+        def query(node: NodeOperand) -> NodeSingleValueWithoutIndexOperand:
+            values = (
+                node.group_by(NodeOperandGroupDiscriminator.Attribute("gender"))
+                .attribute("gender")
+                .count()
+                .ungroup()
+            )
+            return values.count_distinct()
+
+        assert self.graphrecord.query_nodes(query) == 2
+
     def test_sum(self) -> None:
         def query(node: NodeOperand) -> NodeSingleValueWithoutIndexOperand:
             values = (
@@ -2553,6 +3040,19 @@ class TestNodeMultipleValuesWithoutIndexOperand(unittest.TestCase):
 
         assert self.graphrecord.query_nodes(query) == [pytest.approx(32.66, rel=1e-2)]
 
+    def test_distinct(self) -> None:
+        def query(node: NodeOperand) -> NodeMultipleValuesWithoutIndexOperand:
+            values = (
+                node.group_by(NodeOperandGroupDiscriminator.Attribute("gender"))
+                .attribute("gender")
+                .count_distinct()
+                .ungroup()
+            )
+            values.distinct()
+            return values
+
+        assert self.graphrecord.query_nodes(query) == [1]
+
     def test_greater_than(self) -> None:
         def query(node: NodeOperand) -> NodeMultipleValuesWithoutIndexOperand:
             values = (
@@ -3032,6 +3532,19 @@ class TestNodeMultipleValuesWithIndexOperand(unittest.TestCase):
             self.graphrecord.query_nodes(lambda node: node.attribute("age").count())
             == 5
         )
+        assert (
+            self.graphrecord.query_nodes(
+                lambda node: node.attribute("gender").count_distinct()
+            )
+            == 2
+        )
+
+        def query_distinct(node: NodeOperand) -> NodeSingleValueWithoutIndexOperand:
+            values = node.attribute("gender")
+            values.distinct()
+            return values.count()
+
+        assert self.graphrecord.query_nodes(query_distinct) == 2
         assert (
             self.graphrecord.query_nodes(lambda node: node.attribute("age").sum())
             == 216
@@ -3043,6 +3556,401 @@ class TestNodeMultipleValuesWithIndexOperand(unittest.TestCase):
 
         assert self.graphrecord.query_nodes(query_random) == ("pat_1", 42)
 
+    def test_node_multiple_values_operand_value_counts(self) -> None:
+        assert self.graphrecord.query_nodes(
+            lambda node: node.attribute("gender").value_counts()
+        ) == [("M", 3), ("F", 2)]
+
+        def query_no_values(node: NodeOperand) -> NodeValueCountsOperand:
+            values = node.attribute("gender")
+            values.equal_to("X")
+            return values.value_counts()
+
+        assert self.graphrecord.query_nodes(query_no_values) == []
+
+    def test_node_multiple_values_operand_histogram(self) -> None:
+        assert self.graphrecord.query_nodes(
+            lambda node: node.attribute("age").histogram(2)
+        ) == [(19.0, 57.5, 4), (57.5, 96.0, 1)]
+
+        def query_single_value(node: NodeOperand) -> NodeHistogramOperand:
+            query_node(node)
+            return node.attribute("age").histogram(3)
+
+        assert self.graphrecord.query_nodes(query_single_value) == [
+            (42.0, 42.0, 1),
+            (42.0, 42.0, 0),
+            (42.0, 42.0, 0),
+        ]
+
+        with pytest.raises(QueryError, match="at least one bin"):
+            self.graphrecord.query_nodes(
+                lambda node: node.attribute("age").histogram(0)
+            )
+
+        with pytest.raises(QueryError):
+            self.graphrecord.query_nodes(
+                lambda node: node.attribute("gender").histogram(2)
+            )
+
+    def test_node_multiple_values_operand_quantile(self) -> None:
+        assert (
+            self.graphrecord.query_nodes(
+                lambda node: node.attribute("age").quantile(0.5)
+            )
+            == 37.0
+        )
+        assert self.graphrecord.query_nodes(
+            lambda node: node.attribute("age").quantile(0.9)
+        ) == pytest.approx(74.4)
+
+        assert self.graphrecord.query_nodes(
+            lambda node: node.attribute("age").quantiles([0.0, 0.25, 1.0])
+        ) == [(0.0, 19.0), (0.25, 22.0), (1.0, 96.0)]
+
+        def query_no_values(node: NodeOperand) -> NodeQuantilesOperand:
+            values = node.attribute("age")
+            values.greater_than(100)
+            return values.quantiles([0.5])
+
+        assert self.graphrecord.query_nodes(query_no_values) == []
+
+        with pytest.raises(QueryError, match="between 0 and 1"):
+            self.graphrecord.query_nodes(
+                lambda node: node.attribute("age").quantile(1.5)
+            )
+
+        with pytest.raises(QueryError):
+            self.graphrecord.query_nodes(
+                lambda node: node.attribute("gender").quantiles([0.5])
+            )
+
+    def test_node_multiple_values_operand_rolling(self) -> None:
+        graphrecord = rolling_example_graphrecord()
+
+        def query_mean(node: NodeOperand) -> NodeMultipleValuesWithIndexOperand:
+            values = node.attribute("value")
+            values.rolling(timedelta(days=7), RollingAggregation.MEAN, "time")
+            return values
+
+        assert graphrecord.query_nodes(query_mean) == {
+            "m1": 10.0,
+            "m4": 25.0,
+            "m2": pytest.approx(70 / 3),
+            "m3": 25.0,
+        }
+
+        def query_sum(node: NodeOperand) -> NodeMultipleValuesWithIndexOperand:
+            values = node.attribute("value")
+            values.rolling(timedelta(days=7), RollingAggregation.SUM, "time")
+            return values
+
+        assert graphrecord.query_nodes(query_sum) == {
+            "m1": 10.0,
+            "m4": 50.0,
+            "m2": 70.0,
+            "m3": 50.0,
+        }
+
+        def query_invalid_window(
+            node: NodeOperand,
+        ) -> NodeMultipleValuesWithIndexOperand:
+            values = node.attribute("value")
+            values.rolling(timedelta(0), RollingAggregation.SUM, "time")
+            return values
+
+        with pytest.raises(QueryError, match="must be positive"):
+            graphrecord.query_nodes(query_invalid_window)
+
+        def query_invalid_order(
+            node: NodeOperand,
+        ) -> NodeMultipleValuesWithIndexOperand:
+            values = node.attribute("value")
+            values.rolling(timedelta(days=7), RollingAggregation.SUM, "patient")
+            return values
+
+        with pytest.raises(QueryError, match="DateTime"):
+            graphrecord.query_nodes(query_invalid_order)
+
+    def test_node_multiple_values_operand_cumsum(self) -> None:
+        graphrecord = rolling_example_graphrecord()
+
+        def query(node: NodeOperand) -> NodeMultipleValuesWithIndexOperand:
+            values = node.attribute("value")
+            values.cumsum("time")
+            return values
+
+        assert graphrecord.query_nodes(query) == {
+            "m1": 10,
+            "m4": 50,
+            "m2": 70,
+            "m3": 100,
+        }
+
+    def test_node_multiple_values_operand_lag_lead(self) -> None:
+        graphrecord = rolling_example_graphrecord()
+
+        def query_lag(node: NodeOperand) -> NodeMultipleValuesWithIndexOperand:
+            values = node.attribute("value")
+            values.lag(1, "time")
+            return values
+
+        assert graphrecord.query_nodes(query_lag) == {
+            "m1": None,
+            "m4": 10,
+            "m2": 40,
+            "m3": 20,
+        }
+
+        def query_lead(node: NodeOperand) -> NodeMultipleValuesWithIndexOperand:
+            values = node.attribute("value")
+            values.lead(2, "time")
+            return values
+
+        assert graphrecord.query_nodes(query_lead) == {
+            "m1": 20,
+            "m4": 30,
+            "m2": None,
+            "m3": None,
+        }
+
+        def query_invalid_order(
+            node: NodeOperand,
+        ) -> NodeMultipleValuesWithIndexOperand:
+            values = node.attribute("value")
+            values.lag(1, "patient")
+            return values
+
+        with pytest.raises(QueryError, match="DateTime"):
+            graphrecord.query_nodes(query_invalid_order)
+
+    def test_node_multiple_values_operand_when(self) -> None:
+        graphrecord = rolling_example_graphrecord()
+
+        def query(node: NodeOperand) -> NodeMultipleValuesWithIndexOperand:
+            values = node.attribute("value")
+            when = values.when(lambda values: values.greater_than(25))
+            when.then("abnormal").otherwise("normal")
+            return values
+
+        assert graphrecord.query_nodes(query) == {
+            "m1": "normal",
+            "m2": "normal",
+            "m3": "abnormal",
+            "m4": "abnormal",
+            "m5": "abnormal",
+        }
+
+        def query_sum(node: NodeOperand) -> NodeSingleValueWithoutIndexOperand:
+            values = node.attribute("value")
+            values.when(lambda values: values.less_than(25)).then(1).otherwise(0)
+            return values.sum()
+
+        assert graphrecord.query_nodes(query_sum) == 2
+
+    def test_node_multiple_values_operand_apply(self) -> None:
+        graphrecord = rolling_example_graphrecord()
+        graphrecord.register_udf("double", lambda value: value * 2)
+
+        def query(node: NodeOperand) -> NodeMultipleValuesWithIndexOperand:
+            values = node.attribute("value")
+            values.apply("double")
+            return values
+
+        assert graphrecord.query_nodes(query) == {
+            "m1": 20,
+            "m2": 40,
+            "m3": 60,
+            "m4": 80,
+            "m5": 100,
+        }
+
+        def query_single(node: NodeOperand) -> NodeSingleValueWithIndexOperand:
+            max_value = node.attribute("value").max()
+            max_value.apply("double")
+            return max_value
+
+        assert graphrecord.query_nodes(query_single) == ("m5", 100)
+
+        def query_missing(node: NodeOperand) -> NodeMultipleValuesWithIndexOperand:
+            values = node.attribute("value")
+            values.apply("triple")
+            return values
+
+        with pytest.raises(KeyError):
+            graphrecord.query_nodes(query_missing)
+
+        graphrecord.register_udf("fail", lambda value: value / "a")
+
+        def query_fail(node: NodeOperand) -> NodeMultipleValuesWithIndexOperand:
+            values = node.attribute("value")
+            values.apply("fail")
+            return values
+
+        with pytest.raises(RuntimeError, match="unsupported operand"):
+            graphrecord.query_nodes(query_fail)
+
+    def test_node_multiple_values_operand_fuzzy_matching(self) -> None:
+        graphrecord = fuzzy_example_graphrecord()
+
+        def query_levenshtein(node: NodeOperand) -> NodeMultipleValuesWithIndexOperand:
+            values = node.attribute("diagnosis")
+            values.levenshtein_at_most("diabetes", 1)
+            return values
+
+        assert graphrecord.query_nodes(query_levenshtein) == {
+            "d1": "diabetes",
+            "d2": "diabetis",
+        }
+
+        def query_jaro_winkler(node: NodeOperand) -> NodeMultipleValuesWithIndexOperand:
+            values = node.attribute("diagnosis")
+            values.jaro_winkler_at_least("diabetes", 0.85)
+            return values
+
+        assert graphrecord.query_nodes(query_jaro_winkler) == {
+            "d1": "diabetes",
+            "d2": "diabetis",
+            "d3": "diabetic",
+        }
+
+        def query_single(node: NodeOperand) -> NodeSingleValueWithIndexOperand:
+            node.index().equal_to("d4")
+            value = node.attribute("diagnosis").random()
+            value.levenshtein_at_most("asthmaa", 1)
+            return value
+
+        assert graphrecord.query_nodes(query_single) == ("d4", "asthma")
+
+        def query_invalid(node: NodeOperand) -> NodeMultipleValuesWithIndexOperand:
+            values = node.attribute("diagnosis")
+            values.levenshtein_at_most(250, 1)
+            return values
+
+        with pytest.raises(QueryError, match="Cannot fuzzy match"):
+            graphrecord.query_nodes(query_invalid)
+
+    def test_node_multiple_values_operand_is_close(self) -> None:
+        graphrecord = GraphRecord.from_tuples(
+            [("0", {"weight": 0.1 + 0.2}), ("1", {"weight": 0.5}), ("2", {"weight": 1})]
+        )
+
+        def query(node: NodeOperand) -> NodeMultipleValuesWithIndexOperand:
+            values = node.attribute("weight")
+            values.is_close(0.3, 1e-9)
+            return values
+
+        assert graphrecord.query_nodes(query) == {"0": pytest.approx(0.3)}
+
+        def query_int(node: NodeOperand) -> NodeMultipleValuesWithIndexOperand:
+            values = node.attribute("weight")
+            values.is_close(0.9, 0.2)
+            return values
+
+        assert graphrecord.query_nodes(query_int) == {"1": 1}
+
+        def query_invalid(node: NodeOperand) -> NodeMultipleValuesWithIndexOperand:
+            values = node.attribute("weight")
+            values.is_close("0.3", 0.1)
+            return values
+
+        with pytest.raises(QueryError, match="Cannot compare approximately"):
+            graphrecord.query_nodes(query_invalid)
+
+    def test_node_multiple_values_operand_within_radius(self) -> None:
+        graphrecord = geo_example_graphrecord()
+
+        def query(node: NodeOperand) -> NodeMultipleValuesWithIndexOperand:
+            values = node.attribute("location")
+            values.within_radius(BERLIN, 50)
+            return values
+
+        expected = {"f1": BERLIN, "f2": POTSDAM, "f5": BERLIN}
+
+        # Without an index, distances are measured on the fly
+        assert graphrecord.query_nodes(query) == expected
+
+        # Nodes outside of the indexed group are still measured
+        graphrecord.create_geo_index("facilities", "location")
+
+        assert graphrecord.query_nodes(query) == expected
+
+        # The index follows changes to the record
+        graphrecord.node["f2", "location"] = MUNICH
+
+        assert graphrecord.query_nodes(query) == {"f1": BERLIN, "f5": BERLIN}
+
+        def query_single(node: NodeOperand) -> NodeSingleValueWithIndexOperand:
+            node.index().equal_to("f3")
+            value = node.attribute("location").random()
+            value.within_radius(BERLIN, 600)
+            return value
+
+        assert graphrecord.query_nodes(query_single) == ("f3", MUNICH)
+
+        def query_invalid_center(
+            node: NodeOperand,
+        ) -> NodeMultipleValuesWithIndexOperand:
+            values = node.attribute("location")
+            values.within_radius("Berlin", 50)
+            return values
+
+        with pytest.raises(QueryError, match="Consider using a GeoPoint value"):
+            graphrecord.query_nodes(query_invalid_center)
+
+        def query_invalid_radius(
+            node: NodeOperand,
+        ) -> NodeMultipleValuesWithIndexOperand:
+            values = node.attribute("location")
+            values.within_radius(BERLIN, -1)
+            return values
+
+        with pytest.raises(QueryError, match="non-negative"):
+            graphrecord.query_nodes(query_invalid_radius)
+
+    def test_node_multiple_values_operand_text_search(self) -> None:
+        graphrecord = text_example_graphrecord()
+
+        def query(node: NodeOperand) -> NodeMultipleValuesWithIndexOperand:
+            values = node.attribute("note")
+            values.text_search("Heart Failure")
+            return values
+
+        expected = {
+            "n1": "Acute heart failure, NYHA III.",
+            "n3": "Failure to thrive; heart rate normal",
+        }
+
+        # Without an index, values are tokenized on the fly
+        assert graphrecord.query_nodes(query) == expected
+
+        graphrecord.create_text_index("notes", "note")
+
+        assert graphrecord.query_nodes(query) == expected
+
+        # The index follows changes to the record
+        graphrecord.node["n2", "note"] = "Heart failure"
+
+        assert graphrecord.query_nodes(query) == {**expected, "n2": "Heart failure"}
+
+        def query_lowercase(node: NodeOperand) -> NodeMultipleValuesWithIndexOperand:
+            values = node.attribute("note")
+            values.lowercase()
+            values.text_search("nyha")
+            return values
+
+        assert graphrecord.query_nodes(query_lowercase) == {
+            "n1": "acute heart failure, nyha iii."
+        }
+
+        def query_empty(node: NodeOperand) -> NodeMultipleValuesWithIndexOperand:
+            values = node.attribute("note")
+            values.text_search("  ,. ")
+            return values
+
+        with pytest.raises(QueryError, match="at least one word"):
+            graphrecord.query_nodes(query_empty)
+
     def test_node_multiple_values_operand_datatypes(self) -> None:
         def query1(node: NodeOperand) -> NodeIndicesOperand:
             query_node(node)
@@ -3111,6 +4019,15 @@ class TestNodeMultipleValuesWithIndexOperand(unittest.TestCase):
 
         assert self.graphrecord.query_nodes(query8) == ["pat_10"]
 
+        self.graphrecord.add_nodes(("pat_11", {"null_attribute": MISSING}))
+
+        def query9(node: NodeOperand) -> NodeIndicesOperand:
+            node.attribute("null_attribute").is_missing()
+            return node.index()
+
+        assert self.graphrecord.query_nodes(query9) == ["pat_11"]
+        assert self.graphrecord.query_nodes(query8) == ["pat_10"]
+
     def test_node_multiple_values_operand_comparisons(self) -> None:
         def query1(node: NodeOperand) -> NodeIndicesOperand:
             node.attribute("age").is_max()
@@ -3208,6 +4125,39 @@ class TestNodeMultipleValuesWithIndexOperand(unittest.TestCase):
             "pat_5",
         ]
 
+    def test_node_multiple_values_operand_numeric_comparisons(self) -> None:
+        graphrecord = GraphRecord.from_tuples(
+            [
+                (0, {"int": 1, "float": 1.5, "mixed": 1}),
+                (1, {"int": 2, "float": 2.5, "mixed": 2.5}),
+                (2, {"int": 3, "float": float("nan"), "mixed": "3"}),
+            ]
+        )
+
+        def query1(node: NodeOperand) -> NodeIndicesOperand:
+            node.attribute("int").greater_than(1.5)
+            return node.index()
+
+        assert sorted(graphrecord.query_nodes(query1)) == [1, 2]
+
+        def query2(node: NodeOperand) -> NodeIndicesOperand:
+            node.attribute("float").less_than_or_equal_to(2)
+            return node.index()
+
+        assert graphrecord.query_nodes(query2) == [0]
+
+        def query3(node: NodeOperand) -> NodeIndicesOperand:
+            node.attribute("float").equal_to(float("nan"))
+            return node.index()
+
+        assert graphrecord.query_nodes(query3) == [2]
+
+        def query4(node: NodeOperand) -> NodeIndicesOperand:
+            node.attribute("mixed").greater_than(1)
+            return node.index()
+
+        assert graphrecord.query_nodes(query4) == [1]
+
     def test_node_multiple_values_operand_operations(self) -> None:
         def query1(node: NodeOperand) -> NodeMultipleValuesWithIndexOperand:
             query_node(node)
@@ -3374,6 +4324,33 @@ class TestNodeMultipleValuesWithIndexOperand(unittest.TestCase):
 
         assert self.graphrecord.query_nodes(query20) == {"pat_1": 42}
 
+    def test_sort_skip_limit(self) -> None:
+        def query(node: NodeOperand) -> NodeMultipleValuesWithIndexOperand:
+            node.in_group("patient")
+            values = node.attribute("age")
+            values.sort(descending=True)
+            values.skip(1)
+            values.limit(2)
+            return values
+
+        assert list(self.graphrecord.query_nodes(query).items()) == [
+            ("pat_1", 42),
+            ("pat_5", 37),
+        ]
+
+    def test_first_by_last_by(self) -> None:
+        def query1(node: NodeOperand) -> NodeSingleValueWithIndexOperand:
+            node.in_group("patient")
+            return node.attribute("gender").first_by("age")
+
+        assert self.graphrecord.query_nodes(query1) == ("pat_4", "M")
+
+        def query2(node: NodeOperand) -> NodeSingleValueWithIndexOperand:
+            node.in_group("patient")
+            return node.attribute("gender").last_by("age")
+
+        assert self.graphrecord.query_nodes(query2) == ("pat_3", "F")
+
 
 class TestEdgeMultipleValuesWithIndexOperand(unittest.TestCase):
     def setUp(self) -> None:
@@ -3488,6 +4465,22 @@ class TestEdgeMultipleValuesWithIndexOperand(unittest.TestCase):
 
         assert self.graphrecord.query_edges(query_random) == (3, 12)
 
+    def test_random_weighted(self) -> None:
+        def query(edge: EdgeOperand) -> EdgeSingleValueWithIndexOperand:
+            query_specific_edge(edge, [3, 4])
+            return edge.attribute("duration_days").random_weighted("duration_days")
+
+        # Edge 4 has a weight of 0, so it is never selected
+        assert self.graphrecord.query_edges(query) == (3, 12.0)
+
+        def query_invalid(edge: EdgeOperand) -> EdgeSingleValueWithIndexOperand:
+            return edge.attribute("float_attribute").random_weighted(
+                "string_attribute"
+            )
+
+        with pytest.raises(QueryError, match="as weight"):
+            self.graphrecord.query_edges(query_invalid)
+
     def test_edge_multiple_values_operand_datatypes(self) -> None:
         def query1(edge: EdgeOperand) -> EdgeIndicesOperand:
             edge.attribute("string_attribute").is_string()
@@ -4675,6 +5668,52 @@ class TestEdgeMultipleValuesWithIndexGroupOperand(unittest.TestCase):
             ("pat_3", {28: 371.0, 26: 1113.0}),
         ]
 
+    def test_first_by_last_by(self) -> None:
+        def query1(edge: EdgeOperand) -> EdgeSingleValueWithIndexGroupOperand:
+            edge.in_group("patient_drug")
+            edge.source_node().index().equal_to("pat_1")
+            return (
+                edge.group_by(EdgeOperandGroupDiscriminator.SourceNode())
+                .attribute("cost")
+                .first_by("time")
+            )
+
+        assert self.graphrecord.query_edges(query1) == [("pat_1", (60, 215.58))]
+
+        def query2(edge: EdgeOperand) -> EdgeSingleValueWithIndexGroupOperand:
+            edge.in_group("patient_drug")
+            edge.source_node().index().equal_to("pat_1")
+            return (
+                edge.group_by(EdgeOperandGroupDiscriminator.SourceNode())
+                .attribute("cost")
+                .last_by("time")
+            )
+
+        assert self.graphrecord.query_edges(query2) == [("pat_1", (65, 162.03))]
+
+    def test_argmax_argmin(self) -> None:
+        def query1(edge: EdgeOperand) -> EdgeSingleValueWithIndexGroupOperand:
+            edge.in_group("patient_drug")
+            edge.source_node().index().equal_to("pat_1")
+            return (
+                edge.group_by(EdgeOperandGroupDiscriminator.SourceNode())
+                .attribute("cost")
+                .argmax()
+            )
+
+        assert self.graphrecord.query_edges(query1) == [("pat_1", (64, 742.23))]
+
+        def query2(edge: EdgeOperand) -> EdgeSingleValueWithIndexGroupOperand:
+            edge.in_group("patient_drug")
+            edge.source_node().index().equal_to("pat_1")
+            return (
+                edge.group_by(EdgeOperandGroupDiscriminator.SourceNode())
+                .attribute("cost")
+                .argmin()
+            )
+
+        assert self.graphrecord.query_edges(query2) == [("pat_1", (62, 105.15))]
+
 
 class TestEdgeMultipleValuesWithoutIndexOperand(unittest.TestCase):
     def setUp(self) -> None:
@@ -9727,6 +10766,17 @@ class TestNodeIndicesOperand(unittest.TestCase):
 
         assert sorted(self.graphrecord.query_nodes(query20)) == [10, 11]
 
+    def test_sort_skip_limit(self) -> None:
+        def query(node: NodeOperand) -> NodeIndicesOperand:
+            node.in_group("patient")
+            indices = node.index()
+            indices.sort()
+            indices.skip(1)
+            indices.limit(2)
+            return indices
+
+        assert self.graphrecord.query_nodes(query) == ["pat_2", "pat_3"]
+
 
 class TestNodeIndicesGroupOperand(unittest.TestCase):
     def setUp(self) -> None:
@@ -270,6 +270,165 @@ class TestSchema(unittest.TestCase):
 
         assert schema.schema_type == gr.SchemaType.Inferred
 
+    def test_widening(self) -> None:
+        graphrecord = gr.GraphRecord()
+        graphrecord.add_nodes([(0, {"key1": 1}), (1, {"key1": 1.5})])
+
+        schema = gr.Schema.infer(graphrecord)
+
+        assert schema.ungrouped.nodes["key1"][0] == gr.Union(gr.Int(), gr.Float())
+
+        schema = gr.Schema.infer(graphrecord, widening=[(gr.Int(), gr.Float())])
+
+        assert schema.ungrouped.nodes["key1"][0] == gr.Float()
+        assert schema.widening == [(gr.Int(), gr.Float())]
+
+        schema.set_widening([(gr.Int(), gr.Float()), (gr.Float(), gr.String())])
+        graphrecord.set_schema(schema)
+        graphrecord.add_nodes((2, {"key1": "a"}))
+
+        assert graphrecord.get_schema().ungrouped.nodes["key1"][0] == gr.String()
+
+    def test_non_finite_float_policy(self) -> None:
+        graphrecord = gr.GraphRecord()
+        graphrecord.add_nodes([(0, {"key1": float("nan")}), (1, {"key1": 1.0})])
+
+        schema = graphrecord.get_schema()
+
+        assert schema.non_finite_float_policy == gr.NonFiniteFloatPolicy.Allow
+
+        schema.set_non_finite_float_policy(gr.NonFiniteFloatPolicy.Reject)
+        graphrecord.set_schema(schema)
+
+        assert (
+            graphrecord.get_schema().non_finite_float_policy
+            == gr.NonFiniteFloatPolicy.Reject
+        )
+
+        with pytest.raises(ValueError, match=r"rejects non-finite floats"):
+            graphrecord.add_nodes((2, {"key1": float("inf")}))
+
+        schema.set_non_finite_float_policy(gr.NonFiniteFloatPolicy.CoerceToNull)
+        graphrecord.set_schema(schema)
+
+        graphrecord.add_nodes((2, {"key1": float("-inf")}))
+
+        assert graphrecord.node[2, "key1"] is None
+
+    def test_infer_attribute_types(self) -> None:
+        graphrecord = gr.GraphRecord()
+        graphrecord.add_nodes(
+            [
+                (
+                    index,
+                    {
+                        "gender": "M" if index % 2 == 0 else "F",
+                        "age": 20 + index,
+                        "name": f"name_{index}",
+                    },
+                )
+                for index in range(10)
+            ]
+        )
+
+        schema = gr.Schema.infer(graphrecord)
+        schema.infer_attribute_types(graphrecord)
+
+        assert schema.ungrouped.nodes == {
+            "gender": (gr.String(), gr.AttributeType.Categorical),
+            "age": (gr.Int(), gr.AttributeType.Continuous),
+            "name": (gr.String(), gr.AttributeType.Unstructured),
+        }
+
+        schema.infer_attribute_types(
+            graphrecord,
+            max_distinct_ratio=1.0,
+            overrides={"name": gr.AttributeType.Categorical},
+        )
+
+        assert schema.ungrouped.nodes["age"][1] == gr.AttributeType.Categorical
+        assert schema.ungrouped.nodes["name"][1] == gr.AttributeType.Categorical
+
+        with pytest.raises(ValueError, match=r"Cannot use Continuous for attribute"):
+            schema.infer_attribute_types(
+                graphrecord, overrides={"name": gr.AttributeType.Continuous}
+            )
+
+        assert schema.ungrouped.nodes["age"][1] == gr.AttributeType.Categorical
+
+    def test_merge(self) -> None:
+        first = gr.Schema(
+            groups={"patients": gr.GroupSchema(nodes={"age": gr.Int()})},
+            ungrouped=gr.GroupSchema(),
+        )
+        second = gr.Schema(
+            groups={"patients": gr.GroupSchema(nodes={"age": gr.Float()})},
+            ungrouped=gr.GroupSchema(edges={"weight": gr.Int()}),
+        )
+
+        schema, node_conflicts, edge_conflicts = first.merge(second)
+
+        assert schema.group("patients").nodes["age"][0] == gr.Union(
+            gr.Int(), gr.Float()
+        )
+        assert schema.ungrouped.edges["weight"][0] == gr.Int()
+        assert node_conflicts == [
+            {
+                "group": "patients",
+                "attribute": "age",
+                "first": (gr.Int(), gr.AttributeType.Continuous),
+                "second": (gr.Float(), gr.AttributeType.Continuous),
+            }
+        ]
+        assert edge_conflicts == []
+
+        schema, _, _ = first.merge(second, gr.ConflictStrategy.KeepSecond)
+
+        assert schema.group("patients").nodes["age"][0] == gr.Float()
+
+        with pytest.raises(ValueError, match=r"Cannot merge schemas"):
+            first.merge(second, gr.ConflictStrategy.Reject)
+
+    def test_diff(self) -> None:
+        old = gr.Schema(
+            groups={"patients": gr.GroupSchema(nodes={"age": gr.Int()})},
+            ungrouped=gr.GroupSchema(),
+        )
+        new = gr.Schema(
+            groups={
+                "patients": gr.GroupSchema(nodes={"age": gr.Float()}),
+                "diagnoses": gr.GroupSchema(),
+            },
+            ungrouped=gr.GroupSchema(edges={"weight": gr.Int()}),
+        )
+
+        assert old.diff(old) == {
+            "added_groups": [],
+            "removed_groups": [],
+            "node_changes": [],
+            "edge_changes": [],
+        }
+        assert old.diff(new) == {
+            "added_groups": ["diagnoses"],
+            "removed_groups": [],
+            "node_changes": [
+                {
+                    "group": "patients",
+                    "attribute": "age",
+                    "old": (gr.Int(), gr.AttributeType.Continuous),
+                    "new": (gr.Float(), gr.AttributeType.Continuous),
+                }
+            ],
+            "edge_changes": [
+                {
+                    "group": None,
+                    "attribute": "weight",
+                    "old": None,
+                    "new": (gr.Int(), gr.AttributeType.Continuous),
+                }
+            ],
+        }
+
     def test_validate_node(self) -> None:
         schema = gr.Schema(groups={}, ungrouped=gr.GroupSchema())
 